@@ -0,0 +1,16 @@
+-- Ledger of every point change, so balances on the leaderboard can be explained
+CREATE TABLE IF NOT EXISTS point_transactions (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    amount INTEGER NOT NULL,
+    balance_after INTEGER NOT NULL,
+    transaction_type TEXT NOT NULL,
+    task_id TEXT,
+    reward_id TEXT,
+    actor_id TEXT,
+    description TEXT,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_point_transactions_member ON point_transactions(household_id, user_id, created_at);
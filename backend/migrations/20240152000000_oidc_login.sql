@@ -0,0 +1,16 @@
+-- Pending OpenID Connect authorization requests, keyed by the `state` parameter
+CREATE TABLE IF NOT EXISTS oidc_auth_states (
+    state TEXT PRIMARY KEY NOT NULL,
+    nonce TEXT NOT NULL,
+    code_verifier TEXT NOT NULL,
+    -- Set when a logged-in user links their account instead of logging in
+    link_user_id TEXT REFERENCES users(id) ON DELETE CASCADE,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Short-lived one-time codes the frontend exchanges for tokens after an OIDC login
+CREATE TABLE IF NOT EXISTS oidc_login_codes (
+    code_hash TEXT PRIMARY KEY NOT NULL,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    expires_at DATETIME NOT NULL
+);
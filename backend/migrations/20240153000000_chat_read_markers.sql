@@ -0,0 +1,10 @@
+-- Per-member read position in the household chat
+CREATE TABLE IF NOT EXISTS chat_read_markers (
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    last_read_message_id TEXT NOT NULL,
+    -- created_at of the last read message; newer messages count as unread
+    last_read_at DATETIME NOT NULL,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (household_id, user_id)
+);
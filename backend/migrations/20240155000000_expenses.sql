@@ -0,0 +1,16 @@
+-- Shared household expenses (amounts stored in cents)
+CREATE TABLE IF NOT EXISTS expenses (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    paid_by TEXT NOT NULL REFERENCES users(id),
+    amount_cents INTEGER NOT NULL,
+    category TEXT NOT NULL DEFAULT 'other',
+    description TEXT NOT NULL,
+    receipt_note TEXT,
+    expense_date DATE NOT NULL,
+    created_by TEXT NOT NULL REFERENCES users(id),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_expenses_household_date ON expenses(household_id, expense_date);
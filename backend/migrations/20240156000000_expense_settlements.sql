@@ -0,0 +1,13 @@
+-- Payments between members that settle shared expenses
+CREATE TABLE IF NOT EXISTS expense_settlements (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    from_user_id TEXT NOT NULL REFERENCES users(id),
+    to_user_id TEXT NOT NULL REFERENCES users(id),
+    amount_cents INTEGER NOT NULL,
+    note TEXT,
+    created_by TEXT NOT NULL REFERENCES users(id),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_expense_settlements_household ON expense_settlements(household_id, created_at);
@@ -0,0 +1,28 @@
+-- Household recipes; ingredients are stored as a JSON array of {name, quantity, unit}
+CREATE TABLE IF NOT EXISTS recipes (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    ingredients TEXT NOT NULL DEFAULT '[]',
+    notes TEXT,
+    created_by TEXT NOT NULL REFERENCES users(id),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_recipes_household ON recipes(household_id);
+
+-- One planned meal per household, day and slot
+CREATE TABLE IF NOT EXISTS meal_plan_entries (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    plan_date DATE NOT NULL,
+    slot TEXT NOT NULL,
+    recipe_id TEXT REFERENCES recipes(id) ON DELETE SET NULL,
+    title TEXT,
+    notes TEXT,
+    created_by TEXT NOT NULL REFERENCES users(id),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(household_id, plan_date, slot)
+);
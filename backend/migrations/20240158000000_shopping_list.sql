@@ -0,0 +1,14 @@
+-- Shared household shopping list
+CREATE TABLE IF NOT EXISTS shopping_list_items (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    quantity REAL,
+    unit TEXT,
+    is_checked BOOLEAN NOT NULL DEFAULT 0,
+    added_by TEXT NOT NULL REFERENCES users(id),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_shopping_list_items_household ON shopping_list_items(household_id);
@@ -0,0 +1,3 @@
+-- Per-member vacation range; periods during a member's vacation are skipped instead of failed
+ALTER TABLE household_memberships ADD COLUMN vacation_start DATE;
+ALTER TABLE household_memberships ADD COLUMN vacation_end DATE;
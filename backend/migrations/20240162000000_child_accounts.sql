@@ -0,0 +1,4 @@
+-- Managed child accounts sign in with username and PIN and have no email address.
+-- They get a placeholder address on the reserved "child.invalid" domain; managed_by
+-- records the household owner who created the account.
+ALTER TABLE users ADD COLUMN managed_by TEXT REFERENCES users(id);
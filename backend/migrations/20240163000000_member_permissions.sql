@@ -0,0 +1,4 @@
+-- Custom permission matrix per membership, stored as JSON
+-- (manage_tasks, manage_rewards, adjust_points, review_completions, manage_members).
+-- NULL means the member has the defaults of their role.
+ALTER TABLE household_memberships ADD COLUMN permissions TEXT;
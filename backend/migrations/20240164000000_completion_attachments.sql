@@ -0,0 +1,13 @@
+-- Photos attached to task completions as proof for reviewers.
+-- The file itself lives under UPLOAD_DIR; the row records where and what it is.
+CREATE TABLE IF NOT EXISTS completion_attachments (
+    id TEXT PRIMARY KEY NOT NULL,
+    completion_id TEXT NOT NULL REFERENCES task_completions(id) ON DELETE CASCADE,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    uploaded_by TEXT NOT NULL REFERENCES users(id),
+    content_type TEXT NOT NULL,
+    size_bytes INTEGER NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_completion_attachments_completion ON completion_attachments(completion_id);
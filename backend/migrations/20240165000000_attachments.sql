@@ -0,0 +1,15 @@
+-- Files attached to notes and journal entries (warranties, manuals, receipts).
+-- entity_type/entity_id point at the owning record; the file lives under UPLOAD_DIR.
+CREATE TABLE IF NOT EXISTS attachments (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    entity_type TEXT NOT NULL CHECK(entity_type IN ('note', 'journal_entry')),
+    entity_id TEXT NOT NULL,
+    uploaded_by TEXT NOT NULL REFERENCES users(id),
+    file_name TEXT NOT NULL,
+    content_type TEXT NOT NULL,
+    size_bytes INTEGER NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_attachments_entity ON attachments(entity_type, entity_id);
@@ -0,0 +1,12 @@
+-- Weekly recap posted as an announcement (and optionally emailed) at the end of each week
+ALTER TABLE household_settings ADD COLUMN weekly_summary_enabled BOOLEAN NOT NULL DEFAULT 0;
+ALTER TABLE household_settings ADD COLUMN weekly_summary_email BOOLEAN NOT NULL DEFAULT 0;
+
+-- One row per generated recap, so each week is summarized only once
+CREATE TABLE IF NOT EXISTS weekly_summaries (
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    week_start DATE NOT NULL,
+    announcement_id TEXT REFERENCES announcements(id) ON DELETE SET NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (household_id, week_start)
+);
@@ -0,0 +1,11 @@
+-- Weekly point decay for members without task completions
+ALTER TABLE household_settings ADD COLUMN point_decay_mode TEXT NOT NULL DEFAULT 'none';
+ALTER TABLE household_settings ADD COLUMN point_decay_amount INTEGER NOT NULL DEFAULT 0;
+
+-- One row per household week that has been decayed, so each week is applied only once
+CREATE TABLE IF NOT EXISTS point_decay_runs (
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    week_start DATE NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (household_id, week_start)
+);
@@ -0,0 +1,26 @@
+-- Time-boxed household competitions
+CREATE TABLE IF NOT EXISTS challenges (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    title TEXT NOT NULL,
+    description TEXT NOT NULL DEFAULT '',
+    category_id TEXT REFERENCES task_categories(id) ON DELETE SET NULL,
+    start_date DATE NOT NULL,
+    end_date DATE NOT NULL,
+    reward_id TEXT REFERENCES rewards(id) ON DELETE SET NULL,
+    status TEXT NOT NULL DEFAULT 'active' CHECK(status IN ('active', 'closed')),
+    created_by TEXT NOT NULL REFERENCES users(id),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    closed_at DATETIME
+);
+
+CREATE INDEX IF NOT EXISTS idx_challenges_household ON challenges(household_id);
+CREATE INDEX IF NOT EXISTS idx_challenges_status ON challenges(status);
+
+-- Winners recorded when a challenge closes
+CREATE TABLE IF NOT EXISTS challenge_winners (
+    challenge_id TEXT NOT NULL REFERENCES challenges(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    completions INTEGER NOT NULL,
+    PRIMARY KEY (challenge_id, user_id)
+);
@@ -0,0 +1,11 @@
+-- Prerequisite ordering between tasks: task_id can only be completed after
+-- prerequisite_id was completed in the same period
+CREATE TABLE IF NOT EXISTS task_dependencies (
+    task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    prerequisite_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (task_id, prerequisite_id),
+    CHECK (task_id != prerequisite_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_task_dependencies_prerequisite ON task_dependencies(prerequisite_id);
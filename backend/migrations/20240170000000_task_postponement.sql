@@ -0,0 +1,4 @@
+-- Move a single occurrence of a scheduled task to another date without
+-- changing its recurrence
+ALTER TABLE tasks ADD COLUMN postponed_from DATE;
+ALTER TABLE tasks ADD COLUMN postponed_to DATE;
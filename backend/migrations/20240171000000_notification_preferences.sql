@@ -0,0 +1,7 @@
+-- Per-user notification preferences and quiet hours
+ALTER TABLE user_settings ADD COLUMN notify_chat BOOLEAN NOT NULL DEFAULT 1;
+ALTER TABLE user_settings ADD COLUMN notify_reviews BOOLEAN NOT NULL DEFAULT 1;
+ALTER TABLE user_settings ADD COLUMN notify_assignments BOOLEAN NOT NULL DEFAULT 1;
+ALTER TABLE user_settings ADD COLUMN notify_reminders BOOLEAN NOT NULL DEFAULT 1;
+ALTER TABLE user_settings ADD COLUMN quiet_hours_start TEXT;
+ALTER TABLE user_settings ADD COLUMN quiet_hours_end TEXT;
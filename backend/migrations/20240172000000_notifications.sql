@@ -0,0 +1,14 @@
+-- Personal in-app notifications (the household-wide feed is activity_logs)
+CREATE TABLE IF NOT EXISTS notifications (
+    id TEXT PRIMARY KEY NOT NULL,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    household_id TEXT REFERENCES households(id) ON DELETE CASCADE,
+    notification_type TEXT NOT NULL CHECK(notification_type IN ('task_assigned', 'completion_approved', 'completion_rejected', 'reward_confirmed')),
+    title TEXT NOT NULL,
+    entity_type TEXT,
+    entity_id TEXT,
+    read_at DATETIME,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_notifications_user ON notifications(user_id, created_at);
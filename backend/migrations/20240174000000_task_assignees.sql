@@ -0,0 +1,30 @@
+-- Multiple assignees per task. tasks.assigned_user_id keeps the first assignee.
+CREATE TABLE IF NOT EXISTS task_assignees (
+    task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    PRIMARY KEY (task_id, user_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_task_assignees_user ON task_assignees(user_id);
+
+INSERT OR IGNORE INTO task_assignees (task_id, user_id)
+SELECT id, assigned_user_id FROM tasks WHERE assigned_user_id IS NOT NULL;
+
+-- Period outcome per assignee for tasks where everyone completes on their own
+CREATE TABLE IF NOT EXISTS task_assignee_period_results (
+    id TEXT PRIMARY KEY NOT NULL,
+    task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    period_start DATE NOT NULL,
+    period_end DATE NOT NULL,
+    status TEXT NOT NULL CHECK(status IN ('completed', 'failed', 'skipped')),
+    completions_count INTEGER NOT NULL,
+    target_count INTEGER NOT NULL,
+    finalized_at DATETIME NOT NULL,
+    finalized_by TEXT NOT NULL DEFAULT 'system',
+    notes TEXT,
+    UNIQUE (task_id, user_id, period_start)
+);
+
+CREATE INDEX IF NOT EXISTS idx_assignee_period_results_user
+    ON task_assignee_period_results(user_id, task_id);
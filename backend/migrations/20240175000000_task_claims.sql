@@ -0,0 +1,10 @@
+-- Members volunteering for an unassigned task; a claim only covers one period
+CREATE TABLE IF NOT EXISTS task_claims (
+    task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    period_start DATE NOT NULL,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    claimed_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (task_id, period_start)
+);
+
+CREATE INDEX IF NOT EXISTS idx_task_claims_user ON task_claims(user_id);
@@ -0,0 +1,17 @@
+-- Optional owner approval before a reward purchase deducts points
+ALTER TABLE household_settings ADD COLUMN purchase_approval_required BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Purchases awaiting (or after) review; points are only deducted on approval
+CREATE TABLE IF NOT EXISTS reward_purchases (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    reward_id TEXT NOT NULL REFERENCES rewards(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    point_cost INTEGER NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK(status IN ('pending', 'approved', 'rejected')),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    reviewed_by TEXT REFERENCES users(id) ON DELETE SET NULL,
+    reviewed_at DATETIME
+);
+
+CREATE INDEX IF NOT EXISTS idx_reward_purchases_household_status ON reward_purchases(household_id, status);
@@ -0,0 +1,32 @@
+-- Rules that automatically assign a reward or punishment when a trigger fires
+CREATE TABLE IF NOT EXISTS automation_rules (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    trigger_type TEXT NOT NULL CHECK(trigger_type IN ('streak_reached', 'failures_in_week', 'points_below')),
+    threshold INTEGER NOT NULL,
+    task_id TEXT REFERENCES tasks(id) ON DELETE CASCADE,
+    reward_id TEXT REFERENCES rewards(id) ON DELETE CASCADE,
+    punishment_id TEXT REFERENCES punishments(id) ON DELETE CASCADE,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by TEXT NOT NULL REFERENCES users(id),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_automation_rules_household ON automation_rules(household_id);
+
+-- Audit trail of fired rules; trigger_key makes each occurrence fire only once
+CREATE TABLE IF NOT EXISTS automation_rule_runs (
+    id TEXT PRIMARY KEY NOT NULL,
+    rule_id TEXT NOT NULL REFERENCES automation_rules(id) ON DELETE CASCADE,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    trigger_key TEXT NOT NULL,
+    observed_value INTEGER NOT NULL,
+    reward_id TEXT REFERENCES rewards(id) ON DELETE SET NULL,
+    punishment_id TEXT REFERENCES punishments(id) ON DELETE SET NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (rule_id, user_id, trigger_key)
+);
+
+CREATE INDEX IF NOT EXISTS idx_automation_rule_runs_household ON automation_rule_runs(household_id, created_at);
@@ -0,0 +1,16 @@
+-- Outbound webhooks that receive household events
+CREATE TABLE IF NOT EXISTS webhooks (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    -- Comma-separated event names, e.g. 'task_completed,chat_message'
+    events TEXT NOT NULL,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by TEXT NOT NULL REFERENCES users(id),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    last_delivery_at DATETIME,
+    last_error TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhooks_household ON webhooks(household_id);
@@ -0,0 +1,2 @@
+-- Instance admins can disable accounts; disabled users can't log in or refresh sessions
+ALTER TABLE users ADD COLUMN disabled_at DATETIME;
@@ -0,0 +1,9 @@
+-- Deleted tasks, notes and journal entries go to a per-household trash first.
+-- They can be restored until the trash_purge job removes them after 30 days.
+ALTER TABLE tasks ADD COLUMN deleted_at DATETIME;
+ALTER TABLE notes ADD COLUMN deleted_at DATETIME;
+ALTER TABLE journal_entries ADD COLUMN deleted_at DATETIME;
+
+CREATE INDEX IF NOT EXISTS idx_tasks_deleted_at ON tasks(deleted_at);
+CREATE INDEX IF NOT EXISTS idx_notes_deleted_at ON notes(deleted_at);
+CREATE INDEX IF NOT EXISTS idx_journal_entries_deleted_at ON journal_entries(deleted_at);
@@ -0,0 +1,5 @@
+-- Version counters for optimistic locking. Every update increments the
+-- version; updates that carry an outdated version are rejected with 409.
+ALTER TABLE tasks ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE notes ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE household_settings ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
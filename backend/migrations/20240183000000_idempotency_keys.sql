@@ -0,0 +1,13 @@
+-- Responses to requests sent with an Idempotency-Key header, so a retried
+-- completion, purchase or points adjustment is answered without running twice
+CREATE TABLE IF NOT EXISTS idempotency_keys (
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    idempotency_key TEXT NOT NULL,
+    request_path TEXT NOT NULL,
+    status_code INTEGER,  -- NULL while the first request is still running
+    response_body TEXT,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (user_id, idempotency_key)
+);
+
+CREATE INDEX IF NOT EXISTS idx_idempotency_keys_created ON idempotency_keys(created_at);
@@ -0,0 +1,2 @@
+-- Colour theme per user; 'system' follows the device's prefers-color-scheme
+ALTER TABLE user_settings ADD COLUMN theme TEXT NOT NULL DEFAULT 'system';
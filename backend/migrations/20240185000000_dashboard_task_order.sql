@@ -0,0 +1,4 @@
+-- Manual order of the tasks on a user's dashboard
+-- NULL means not ordered: those tasks follow the ordered ones, sorted by due date and title
+
+ALTER TABLE user_dashboard_tasks ADD COLUMN sort_order INTEGER;
@@ -0,0 +1,21 @@
+-- Task tags: free labels in addition to the single category
+-- A task can carry any number of tags, e.g. "outside", "15-minutes" and "needs-car"
+
+CREATE TABLE task_tags (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    color TEXT,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(household_id, name)
+);
+
+CREATE INDEX idx_task_tags_household ON task_tags(household_id);
+
+CREATE TABLE task_tag_assignments (
+    task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    tag_id TEXT NOT NULL REFERENCES task_tags(id) ON DELETE CASCADE,
+    PRIMARY KEY (task_id, tag_id)
+);
+
+CREATE INDEX idx_task_tag_assignments_tag ON task_tag_assignments(tag_id);
@@ -0,0 +1,14 @@
+-- Saved task filters ("smart lists"): named filter criteria per user and household
+-- The criteria are stored as JSON, see shared::TaskFilter
+
+CREATE TABLE saved_task_filters (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    filter TEXT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(household_id, user_id, name)
+);
+
+CREATE INDEX idx_saved_task_filters_user ON saved_task_filters(household_id, user_id);
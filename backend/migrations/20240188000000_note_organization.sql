@@ -0,0 +1,7 @@
+-- Organize notes: pinning, a card color, a manual sort order and archiving
+-- Pinned notes come first; within each group notes with a sort order come first
+
+ALTER TABLE notes ADD COLUMN pinned BOOLEAN NOT NULL DEFAULT 0;
+ALTER TABLE notes ADD COLUMN color TEXT;
+ALTER TABLE notes ADD COLUMN sort_order INTEGER;
+ALTER TABLE notes ADD COLUMN archived BOOLEAN NOT NULL DEFAULT 0;
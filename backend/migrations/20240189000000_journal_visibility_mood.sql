@@ -0,0 +1,16 @@
+-- Convert the journal is_shared flag to a visibility level and add an optional mood score
+-- Visibility is 'private' (author only) or 'household' (all members)
+
+ALTER TABLE journal_entries ADD COLUMN visibility TEXT NOT NULL DEFAULT 'private';
+
+UPDATE journal_entries SET visibility = 'household' WHERE is_shared = 1;
+
+-- The old indexes reference is_shared, so they go before the column (SQLite 3.35+)
+DROP INDEX IF EXISTS idx_journal_entries_household_shared;
+DROP INDEX IF EXISTS idx_journal_entries_user_private;
+ALTER TABLE journal_entries DROP COLUMN is_shared;
+
+CREATE INDEX IF NOT EXISTS idx_journal_entries_visibility ON journal_entries(household_id, visibility, user_id);
+
+-- Mood from 1 (bad) to 5 (great); NULL when not recorded
+ALTER TABLE journal_entries ADD COLUMN mood INTEGER;
@@ -0,0 +1,16 @@
+-- Scheduled publishing and read confirmations for announcements
+
+-- When the announcement went live and was posted to the chat bridge; NULL while
+-- it waits for its start time. Existing announcements were posted when created.
+ALTER TABLE announcements ADD COLUMN published_at DATETIME;
+UPDATE announcements SET published_at = created_at;
+
+CREATE INDEX IF NOT EXISTS idx_announcements_unpublished ON announcements(published_at, starts_at);
+
+-- Members who confirmed they have read an announcement
+CREATE TABLE IF NOT EXISTS announcement_acknowledgments (
+    announcement_id TEXT NOT NULL REFERENCES announcements(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    acknowledged_at DATETIME NOT NULL,
+    PRIMARY KEY (announcement_id, user_id)
+);
@@ -0,0 +1,2 @@
+-- Estimated minutes one completion of a task takes, for workload balancing
+ALTER TABLE tasks ADD COLUMN effort_minutes INTEGER;
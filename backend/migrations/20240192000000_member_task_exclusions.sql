@@ -0,0 +1,12 @@
+-- Tasks a member must not be given (e.g. a child who may not use the oven).
+-- Honored when tasks are assigned, claimed or rebalanced.
+
+CREATE TABLE member_task_exclusions (
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (user_id, task_id)
+);
+
+CREATE INDEX idx_member_task_exclusions_household ON member_task_exclusions(household_id);
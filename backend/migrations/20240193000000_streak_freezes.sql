@@ -0,0 +1,10 @@
+-- Streak freezes: members buy them with points and spend one to turn a
+-- missed period into a skipped one, so it no longer breaks their streak.
+ALTER TABLE household_settings ADD COLUMN streak_freeze_price INTEGER NOT NULL DEFAULT 0;
+
+CREATE TABLE IF NOT EXISTS streak_freezes (
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    available INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (household_id, user_id)
+);
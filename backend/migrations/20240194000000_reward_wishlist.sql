@@ -0,0 +1,33 @@
+-- Reward wishlist: members keep rewards they are saving up for. notified_at
+-- remembers that the "you can afford it now" notification went out, and is
+-- cleared again once the reward drops out of reach.
+CREATE TABLE IF NOT EXISTS reward_wishlist (
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    reward_id TEXT NOT NULL REFERENCES rewards(id) ON DELETE CASCADE,
+    notified_at DATETIME,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (user_id, reward_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_reward_wishlist_household ON reward_wishlist(household_id);
+
+-- Rebuild notifications so the type check allows every notification type,
+-- including the purchase review ones (SQLite cannot alter a CHECK constraint)
+CREATE TABLE notifications_new (
+    id TEXT PRIMARY KEY NOT NULL,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    household_id TEXT REFERENCES households(id) ON DELETE CASCADE,
+    notification_type TEXT NOT NULL CHECK(notification_type IN ('task_assigned', 'completion_approved', 'completion_rejected', 'reward_confirmed', 'purchase_approved', 'purchase_rejected', 'reward_affordable')),
+    title TEXT NOT NULL,
+    entity_type TEXT,
+    entity_id TEXT,
+    read_at DATETIME,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+INSERT INTO notifications_new SELECT id, user_id, household_id, notification_type, title, entity_type, entity_id, read_at, created_at FROM notifications;
+DROP TABLE notifications;
+ALTER TABLE notifications_new RENAME TO notifications;
+
+CREATE INDEX IF NOT EXISTS idx_notifications_user ON notifications(user_id, created_at);
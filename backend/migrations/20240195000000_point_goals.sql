@@ -0,0 +1,15 @@
+-- Point goals: a member saves up towards a points target by a deadline.
+-- Progress is derived from the point ledger, completed_at is set once reached.
+CREATE TABLE IF NOT EXISTS point_goals (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    title TEXT NOT NULL,
+    target_points INTEGER NOT NULL,
+    deadline DATE NOT NULL,
+    created_by TEXT NOT NULL REFERENCES users(id),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    completed_at DATETIME
+);
+
+CREATE INDEX IF NOT EXISTS idx_point_goals_member ON point_goals(household_id, user_id);
@@ -0,0 +1,7 @@
+-- Household defaults for new tasks, so creators don't repeat the same choices
+-- in every task modal.
+ALTER TABLE household_settings ADD COLUMN default_requires_review BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE household_settings ADD COLUMN default_allow_exceed_target BOOLEAN NOT NULL DEFAULT TRUE;
+ALTER TABLE household_settings ADD COLUMN good_habit_points_multiplier REAL NOT NULL DEFAULT 1.0;
+ALTER TABLE household_settings ADD COLUMN bad_habit_points_multiplier REAL NOT NULL DEFAULT 1.0;
+ALTER TABLE household_settings ADD COLUMN review_points_threshold INTEGER;
@@ -0,0 +1,20 @@
+-- Photos in the household chat reuse the attachments table. Rebuild it so the
+-- entity type check allows chat messages (SQLite cannot alter a CHECK constraint).
+-- Thumbnails of image attachments are generated next to the files in UPLOAD_DIR.
+CREATE TABLE attachments_new (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    entity_type TEXT NOT NULL CHECK(entity_type IN ('note', 'journal_entry', 'chat_message')),
+    entity_id TEXT NOT NULL,
+    uploaded_by TEXT NOT NULL REFERENCES users(id),
+    file_name TEXT NOT NULL,
+    content_type TEXT NOT NULL,
+    size_bytes INTEGER NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+INSERT INTO attachments_new SELECT id, household_id, entity_type, entity_id, uploaded_by, file_name, content_type, size_bytes, created_at FROM attachments;
+DROP TABLE attachments;
+ALTER TABLE attachments_new RENAME TO attachments;
+
+CREATE INDEX IF NOT EXISTS idx_attachments_entity ON attachments(entity_type, entity_id);
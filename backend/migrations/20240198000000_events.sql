@@ -0,0 +1,38 @@
+-- Append-only log of changes to completions and period results. Every row is a
+-- +1/-1 delta for one task and day (the completion's due date or the period start).
+CREATE TABLE IF NOT EXISTS events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    task_id TEXT NOT NULL,
+    user_id TEXT,
+    kind TEXT NOT NULL CHECK(kind IN ('completion', 'period_completed', 'period_failed', 'period_skipped')),
+    day DATE NOT NULL,
+    delta INTEGER NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_events_task ON events(task_id, id);
+
+-- Per task and day sums of the events up to the cursor, refreshed by the event_rollup job
+CREATE TABLE IF NOT EXISTS task_daily_rollups (
+    task_id TEXT NOT NULL,
+    day DATE NOT NULL,
+    completions INTEGER NOT NULL DEFAULT 0,
+    periods_completed INTEGER NOT NULL DEFAULT 0,
+    periods_failed INTEGER NOT NULL DEFAULT 0,
+    periods_skipped INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (task_id, day)
+);
+
+CREATE TABLE IF NOT EXISTS event_rollup_cursor (
+    id INTEGER PRIMARY KEY CHECK(id = 1),
+    last_event_id INTEGER NOT NULL
+);
+
+INSERT INTO event_rollup_cursor (id, last_event_id) VALUES (1, 0);
+
+-- Backfill the log from the existing history; the first job run rolls it up
+INSERT INTO events (task_id, user_id, kind, day, delta, created_at)
+SELECT task_id, user_id, 'completion', due_date, 1, completed_at FROM task_completions;
+
+INSERT INTO events (task_id, user_id, kind, day, delta, created_at)
+SELECT task_id, NULL, 'period_' || status, period_start, 1, finalized_at FROM task_period_results;
@@ -0,0 +1,14 @@
+-- Materialized leaderboards per household and time window, recomputed by the
+-- leaderboard_refresh job and whenever points change. `window_start` tells
+-- whether a stored week or month leaderboard is still the current one.
+CREATE TABLE IF NOT EXISTS leaderboard_entries (
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    time_window TEXT NOT NULL CHECK(time_window IN ('week', 'month', 'all_time')),
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    rank INTEGER NOT NULL,
+    points INTEGER NOT NULL,
+    tasks_completed INTEGER NOT NULL,
+    window_start DATE,
+    computed_at DATETIME NOT NULL,
+    PRIMARY KEY (household_id, time_window, user_id)
+);
@@ -0,0 +1,4 @@
+-- Optional season a task is active in, as "MM-DD" month-days. A season may
+-- wrap around the new year; outside of it the task is not due.
+ALTER TABLE tasks ADD COLUMN active_from TEXT;
+ALTER TABLE tasks ADD COLUMN active_until TEXT;
@@ -0,0 +1,4 @@
+-- Optional end of a recurring task: a last day and/or a number of completed
+-- periods, after which the task_end job archives it
+ALTER TABLE tasks ADD COLUMN ends_on DATE;
+ALTER TABLE tasks ADD COLUMN max_occurrences INTEGER;
@@ -0,0 +1,18 @@
+-- Focus timer sessions bound to a task. A session runs until the member stops
+-- it (its focused time counts toward the task's statistics) or cancels it.
+
+CREATE TABLE focus_sessions (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    planned_minutes INTEGER NOT NULL,
+    status TEXT NOT NULL DEFAULT 'running' CHECK(status IN ('running', 'completed', 'cancelled')),
+    started_at DATETIME NOT NULL,
+    ended_at DATETIME,
+    focused_seconds INTEGER NOT NULL DEFAULT 0
+);
+
+-- A member has at most one running session
+CREATE UNIQUE INDEX idx_focus_sessions_running ON focus_sessions(user_id) WHERE status = 'running';
+CREATE INDEX idx_focus_sessions_task ON focus_sessions(task_id, started_at);
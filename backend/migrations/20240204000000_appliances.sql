@@ -0,0 +1,23 @@
+-- Appliance registry; maintenance reminders are regular tasks linked to the appliance
+CREATE TABLE IF NOT EXISTS appliances (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    location TEXT,
+    purchased_on DATE,
+    warranty_until DATE,
+    manual_url TEXT,
+    notes TEXT,
+    created_by TEXT NOT NULL REFERENCES users(id),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_appliances_household ON appliances(household_id);
+
+CREATE TABLE IF NOT EXISTS appliance_maintenance (
+    appliance_id TEXT NOT NULL REFERENCES appliances(id) ON DELETE CASCADE,
+    task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    interval_days INTEGER NOT NULL,
+    PRIMARY KEY (appliance_id, task_id)
+);
@@ -0,0 +1,40 @@
+-- Plants with their own watering and fertilizing tasks. Completing one of the
+-- tasks records the care time on the plant.
+CREATE TABLE IF NOT EXISTS plants (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    location TEXT,
+    water_interval_days INTEGER,
+    fertilize_interval_days INTEGER,
+    water_task_id TEXT REFERENCES tasks(id) ON DELETE SET NULL,
+    fertilize_task_id TEXT REFERENCES tasks(id) ON DELETE SET NULL,
+    last_watered_at DATETIME,
+    last_fertilized_at DATETIME,
+    created_by TEXT NOT NULL REFERENCES users(id),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_plants_household ON plants(household_id);
+CREATE INDEX IF NOT EXISTS idx_plants_water_task ON plants(water_task_id);
+CREATE INDEX IF NOT EXISTS idx_plants_fertilize_task ON plants(fertilize_task_id);
+
+-- Plant photos reuse the attachments table; rebuild it to allow the new entity type
+CREATE TABLE attachments_new (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    entity_type TEXT NOT NULL CHECK(entity_type IN ('note', 'journal_entry', 'chat_message', 'plant')),
+    entity_id TEXT NOT NULL,
+    uploaded_by TEXT NOT NULL REFERENCES users(id),
+    file_name TEXT NOT NULL,
+    content_type TEXT NOT NULL,
+    size_bytes INTEGER NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+INSERT INTO attachments_new SELECT id, household_id, entity_type, entity_id, uploaded_by, file_name, content_type, size_bytes, created_at FROM attachments;
+DROP TABLE attachments;
+ALTER TABLE attachments_new RENAME TO attachments;
+
+CREATE INDEX IF NOT EXISTS idx_attachments_entity ON attachments(entity_type, entity_id);
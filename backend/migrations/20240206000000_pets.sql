@@ -0,0 +1,36 @@
+-- Pets with feeding and medication schedules; each schedule is a daily task whose
+-- target is the number of times per day, and every completion lands in a shared log
+CREATE TABLE IF NOT EXISTS pets (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    species TEXT,
+    notes TEXT,
+    created_by TEXT NOT NULL REFERENCES users(id),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_pets_household ON pets(household_id);
+
+CREATE TABLE IF NOT EXISTS pet_schedules (
+    pet_id TEXT NOT NULL REFERENCES pets(id) ON DELETE CASCADE,
+    task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    kind TEXT NOT NULL CHECK (kind IN ('feeding', 'medication')),
+    times_per_day INTEGER NOT NULL,
+    PRIMARY KEY (pet_id, task_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_pet_schedules_task ON pet_schedules(task_id);
+
+CREATE TABLE IF NOT EXISTS pet_care_log (
+    id TEXT PRIMARY KEY NOT NULL,
+    pet_id TEXT NOT NULL REFERENCES pets(id) ON DELETE CASCADE,
+    task_id TEXT REFERENCES tasks(id) ON DELETE SET NULL,
+    kind TEXT NOT NULL CHECK (kind IN ('feeding', 'medication')),
+    title TEXT NOT NULL,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    logged_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_pet_care_log_pet ON pet_care_log(pet_id, logged_at);
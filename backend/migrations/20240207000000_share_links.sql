@@ -0,0 +1,12 @@
+-- Read-only links to a single page of a household (e.g. the shopping list) for guests without an account
+CREATE TABLE IF NOT EXISTS share_links (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    resource TEXT NOT NULL CHECK (resource IN ('shopping_list', 'meal_plan')),
+    token TEXT NOT NULL UNIQUE,
+    created_by TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    expires_at DATETIME
+);
+
+CREATE INDEX IF NOT EXISTS idx_share_links_household ON share_links(household_id);
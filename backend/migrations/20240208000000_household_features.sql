@@ -0,0 +1,2 @@
+-- Modules a household has switched off, as a JSON array of feature names
+ALTER TABLE household_settings ADD COLUMN disabled_features TEXT NOT NULL DEFAULT '[]';
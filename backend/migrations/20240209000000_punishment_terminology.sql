@@ -0,0 +1,4 @@
+-- Households can call punishments "consequences" or give them their own name
+ALTER TABLE household_settings ADD COLUMN punishment_terminology TEXT NOT NULL DEFAULT 'punishments';
+ALTER TABLE household_settings ADD COLUMN punishment_label_singular TEXT;
+ALTER TABLE household_settings ADD COLUMN punishment_label_plural TEXT;
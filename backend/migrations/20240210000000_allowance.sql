@@ -0,0 +1,25 @@
+-- Monthly allowance paid for members' points
+ALTER TABLE household_settings ADD COLUMN allowance_cents_per_point INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE household_settings ADD COLUMN allowance_point_handling TEXT NOT NULL DEFAULT 'reset';
+
+-- One row per member and paid month
+CREATE TABLE IF NOT EXISTS allowance_payouts (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    month DATE NOT NULL,
+    points INTEGER NOT NULL,
+    amount_cents INTEGER NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (household_id, user_id, month)
+);
+
+CREATE INDEX IF NOT EXISTS idx_allowance_payouts_household ON allowance_payouts(household_id, month);
+
+-- One row per household month that has been paid out, so each month is paid only once
+CREATE TABLE IF NOT EXISTS allowance_runs (
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    month DATE NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (household_id, month)
+);
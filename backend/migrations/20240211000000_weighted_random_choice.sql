@@ -0,0 +1,19 @@
+-- Weights for the options of random choice rewards and punishments
+ALTER TABLE reward_options ADD COLUMN weight INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE punishment_options ADD COLUMN weight INTEGER NOT NULL DEFAULT 1;
+
+-- Options picked in the last N picks of a member are not picked again (0 = repeats allowed)
+ALTER TABLE rewards ADD COLUMN no_repeat_picks INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE punishments ADD COLUMN no_repeat_picks INTEGER NOT NULL DEFAULT 0;
+
+-- History of random picks, newest last
+CREATE TABLE IF NOT EXISTS random_choice_picks (
+    id TEXT PRIMARY KEY NOT NULL,
+    household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    parent_id TEXT NOT NULL,
+    option_id TEXT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_random_choice_picks_parent ON random_choice_picks(parent_id, user_id, created_at);
@@ -0,0 +1,4 @@
+-- Who may review the completions of a task: any reviewer, one specific member,
+-- or any reviewer except the member who completed it
+ALTER TABLE tasks ADD COLUMN reviewer_type TEXT NOT NULL DEFAULT 'any_reviewer';
+ALTER TABLE tasks ADD COLUMN reviewer_id TEXT REFERENCES users(id) ON DELETE SET NULL;
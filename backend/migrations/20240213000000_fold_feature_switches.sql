@@ -0,0 +1,8 @@
+-- Rewards, punishments and chat are switched off through disabled_features like every other module
+UPDATE household_settings SET disabled_features = json_insert(disabled_features, '$[#]', 'rewards') WHERE NOT rewards_enabled;
+UPDATE household_settings SET disabled_features = json_insert(disabled_features, '$[#]', 'punishments') WHERE NOT punishments_enabled;
+UPDATE household_settings SET disabled_features = json_insert(disabled_features, '$[#]', 'chat') WHERE NOT chat_enabled;
+
+ALTER TABLE household_settings DROP COLUMN rewards_enabled;
+ALTER TABLE household_settings DROP COLUMN punishments_enabled;
+ALTER TABLE household_settings DROP COLUMN chat_enabled;
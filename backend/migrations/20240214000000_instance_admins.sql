@@ -0,0 +1,10 @@
+-- Emails are stored lowercased and unique regardless of case. Fails if two
+-- accounts differ only in the case of their email; merge those by hand first.
+UPDATE users SET email = LOWER(email);
+CREATE UNIQUE INDEX idx_users_email_lower ON users(LOWER(email));
+
+-- Instance admins are flagged on the account instead of being matched by email.
+-- The oldest regular account keeps the admin role it had without ADMIN_EMAILS.
+ALTER TABLE users ADD COLUMN is_admin BOOLEAN NOT NULL DEFAULT FALSE;
+UPDATE users SET is_admin = TRUE
+WHERE id = (SELECT id FROM users WHERE managed_by IS NULL ORDER BY created_at, rowid LIMIT 1);
@@ -1,4 +1,5 @@
 use std::env;
+use std::net::IpAddr;
 
 #[derive(Debug, Clone)]
 pub struct Config {
@@ -11,6 +12,66 @@ pub struct Config {
     pub static_files_path: Option<String>,
     pub cors_origins: Vec<String>,
     pub legal_dir: Option<String>,
+    /// Base64url-encoded P-256 private key for Web Push (VAPID). Push is disabled when unset.
+    pub vapid_private_key: Option<String>,
+    pub vapid_subject: String,
+    pub push_reminder_lead_minutes: i64,
+    /// OpenID Connect issuer (e.g. a Keycloak realm URL). OIDC login is disabled when unset.
+    pub oidc_issuer_url: Option<String>,
+    pub oidc_client_id: Option<String>,
+    pub oidc_client_secret: Option<String>,
+    /// Public URL of `/api/auth/oidc/callback`, registered as redirect URI at the provider
+    pub oidc_redirect_url: Option<String>,
+    /// Name shown on the login button
+    pub oidc_provider_name: String,
+    /// Sustained mutating requests (POST/PUT/DELETE) per minute per user or IP; 0 disables the limit
+    pub rate_limit_per_minute: u32,
+    /// Number of mutating requests a client may burst before being throttled
+    pub rate_limit_burst: u32,
+    /// Reverse proxies whose `X-Forwarded-For`/`Forwarded` headers name the client.
+    /// When empty, rate limits are keyed on the peer address and these headers are ignored.
+    pub trusted_proxies: Vec<IpAddr>,
+    /// SMTP server for outgoing email. Email is disabled when unset.
+    pub smtp_host: Option<String>,
+    pub smtp_port: u16,
+    /// `starttls`, `tls` or `none`
+    pub smtp_security: String,
+    pub smtp_username: Option<String>,
+    pub smtp_password: Option<String>,
+    /// Sender address, e.g. `Haushalt <noreply@example.com>`
+    pub smtp_from: String,
+    /// Public base URL of the app, used for links in emails
+    pub public_url: String,
+    /// Directory for uploaded files such as completion photos
+    pub upload_dir: String,
+    /// Largest accepted upload in bytes
+    pub max_upload_bytes: usize,
+    /// Minutes between background scheduler runs
+    pub job_check_interval_minutes: u32,
+    /// Background jobs the scheduler skips, by name (e.g. `weekly_summary`)
+    pub jobs_disabled: Vec<String>,
+    /// Per-job intervals in minutes, from `JOB_INTERVALS=auto_archive=60,missed_tasks=5`
+    pub job_intervals: Vec<(String, u32)>,
+    /// Telegram bot that posts household events to a group chat. Disabled unless token and chat are set.
+    pub telegram_bot_token: Option<String>,
+    pub telegram_chat_id: Option<String>,
+    /// Matrix account that posts household events to a room. Disabled unless all three are set.
+    pub matrix_homeserver_url: Option<String>,
+    pub matrix_access_token: Option<String>,
+    pub matrix_room_id: Option<String>,
+    /// Events posted to the chat bridge (e.g. `task_overdue`); all events when empty
+    pub chat_bridge_events: Vec<String>,
+    /// Directory for database snapshots
+    pub backup_dir: String,
+    /// Snapshots to keep; older ones are deleted after each backup. 0 keeps all.
+    pub backup_retention: usize,
+    /// UTC hour after which the nightly snapshot is taken. Nightly backups are disabled when unset.
+    pub backup_nightly_hour: Option<u32>,
+    /// OTLP/HTTP endpoint traces are exported to (e.g. `http://localhost:4318/v1/traces`).
+    /// Spans only go to the log when unset.
+    pub otlp_endpoint: Option<String>,
+    /// Serve Swagger UI for the OpenAPI document at `/api/docs`
+    pub openapi_ui_enabled: bool,
 }
 
 impl Config {
@@ -41,10 +102,100 @@ impl Config {
                 .filter(|s| !s.is_empty())
                 .collect(),
             legal_dir: env::var("LEGAL_DIR").ok(),
+            vapid_private_key: env::var("VAPID_PRIVATE_KEY").ok(),
+            vapid_subject: env::var("VAPID_SUBJECT")
+                .unwrap_or_else(|_| "mailto:admin@localhost".to_string()),
+            push_reminder_lead_minutes: env::var("PUSH_REMINDER_LEAD_MINUTES")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .expect("PUSH_REMINDER_LEAD_MINUTES must be a number"),
+            oidc_issuer_url: env::var("OIDC_ISSUER_URL").ok(),
+            oidc_client_id: env::var("OIDC_CLIENT_ID").ok(),
+            oidc_client_secret: env::var("OIDC_CLIENT_SECRET").ok(),
+            oidc_redirect_url: env::var("OIDC_REDIRECT_URL").ok(),
+            oidc_provider_name: env::var("OIDC_PROVIDER_NAME")
+                .unwrap_or_else(|_| "SSO".to_string()),
+            rate_limit_per_minute: env::var("RATE_LIMIT_PER_MINUTE")
+                .unwrap_or_else(|_| "120".to_string())
+                .parse()
+                .expect("RATE_LIMIT_PER_MINUTE must be a number"),
+            rate_limit_burst: env::var("RATE_LIMIT_BURST")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .expect("RATE_LIMIT_BURST must be a number"),
+            trusted_proxies: split_list(&env::var("TRUSTED_PROXIES").unwrap_or_default())
+                .iter()
+                .map(|ip| ip.parse().expect("TRUSTED_PROXIES must be a list of IP addresses"))
+                .collect(),
+            smtp_host: env::var("SMTP_HOST").ok(),
+            smtp_port: env::var("SMTP_PORT")
+                .unwrap_or_else(|_| "587".to_string())
+                .parse()
+                .expect("SMTP_PORT must be a number"),
+            smtp_security: env::var("SMTP_SECURITY").unwrap_or_else(|_| "starttls".to_string()),
+            smtp_username: env::var("SMTP_USERNAME").ok(),
+            smtp_password: env::var("SMTP_PASSWORD").ok(),
+            smtp_from: env::var("SMTP_FROM")
+                .unwrap_or_else(|_| "Haushalt <noreply@localhost>".to_string()),
+            public_url: env::var("PUBLIC_URL")
+                .unwrap_or_else(|_| "http://localhost:8080".to_string()),
+            upload_dir: env::var("UPLOAD_DIR").unwrap_or_else(|_| "uploads".to_string()),
+            max_upload_bytes: env::var("MAX_UPLOAD_BYTES")
+                .unwrap_or_else(|_| "5242880".to_string())
+                .parse()
+                .expect("MAX_UPLOAD_BYTES must be a number"),
+            job_check_interval_minutes: env::var("JOB_CHECK_INTERVAL_MINUTES")
+                .unwrap_or_else(|_| "1".to_string())
+                .parse()
+                .expect("JOB_CHECK_INTERVAL_MINUTES must be a number"),
+            jobs_disabled: split_list(&env::var("JOBS_DISABLED").unwrap_or_default()),
+            job_intervals: split_list(&env::var("JOB_INTERVALS").unwrap_or_default())
+                .into_iter()
+                .map(|entry| {
+                    let (job, minutes) = entry
+                        .split_once('=')
+                        .expect("JOB_INTERVALS entries must look like job_name=minutes");
+                    let minutes = minutes
+                        .trim()
+                        .parse()
+                        .expect("JOB_INTERVALS minutes must be a number");
+                    (job.trim().to_string(), minutes)
+                })
+                .collect(),
+            telegram_bot_token: env::var("TELEGRAM_BOT_TOKEN").ok(),
+            telegram_chat_id: env::var("TELEGRAM_CHAT_ID").ok(),
+            matrix_homeserver_url: env::var("MATRIX_HOMESERVER_URL").ok(),
+            matrix_access_token: env::var("MATRIX_ACCESS_TOKEN").ok(),
+            matrix_room_id: env::var("MATRIX_ROOM_ID").ok(),
+            chat_bridge_events: split_list(&env::var("CHAT_BRIDGE_EVENTS").unwrap_or_default()),
+            backup_dir: env::var("BACKUP_DIR").unwrap_or_else(|_| "backups".to_string()),
+            backup_retention: env::var("BACKUP_RETENTION")
+                .unwrap_or_else(|_| "7".to_string())
+                .parse()
+                .expect("BACKUP_RETENTION must be a number"),
+            backup_nightly_hour: env::var("BACKUP_NIGHTLY_HOUR").ok().map(|hour| {
+                hour.parse()
+                    .ok()
+                    .filter(|hour| *hour < 24)
+                    .expect("BACKUP_NIGHTLY_HOUR must be an hour between 0 and 23")
+            }),
+            otlp_endpoint: env::var("OTLP_ENDPOINT").ok().filter(|url| !url.is_empty()),
+            openapi_ui_enabled: env::var("OPENAPI_UI_ENABLED")
+                .map(|value| value.eq_ignore_ascii_case("true") || value == "1")
+                .unwrap_or(false),
         })
     }
 }
 
+/// Split a comma-separated environment value, dropping empty entries
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -63,6 +214,40 @@ mod tests {
         env::remove_var("STATIC_FILES_PATH");
         env::remove_var("CORS_ORIGINS");
         env::remove_var("LEGAL_DIR");
+        env::remove_var("VAPID_PRIVATE_KEY");
+        env::remove_var("VAPID_SUBJECT");
+        env::remove_var("PUSH_REMINDER_LEAD_MINUTES");
+        env::remove_var("OIDC_ISSUER_URL");
+        env::remove_var("OIDC_CLIENT_ID");
+        env::remove_var("OIDC_CLIENT_SECRET");
+        env::remove_var("OIDC_REDIRECT_URL");
+        env::remove_var("OIDC_PROVIDER_NAME");
+        env::remove_var("RATE_LIMIT_PER_MINUTE");
+        env::remove_var("RATE_LIMIT_BURST");
+        env::remove_var("TRUSTED_PROXIES");
+        env::remove_var("SMTP_HOST");
+        env::remove_var("SMTP_PORT");
+        env::remove_var("SMTP_SECURITY");
+        env::remove_var("SMTP_USERNAME");
+        env::remove_var("SMTP_PASSWORD");
+        env::remove_var("SMTP_FROM");
+        env::remove_var("PUBLIC_URL");
+        env::remove_var("UPLOAD_DIR");
+        env::remove_var("MAX_UPLOAD_BYTES");
+        env::remove_var("JOB_CHECK_INTERVAL_MINUTES");
+        env::remove_var("JOBS_DISABLED");
+        env::remove_var("JOB_INTERVALS");
+        env::remove_var("TELEGRAM_BOT_TOKEN");
+        env::remove_var("TELEGRAM_CHAT_ID");
+        env::remove_var("MATRIX_HOMESERVER_URL");
+        env::remove_var("MATRIX_ACCESS_TOKEN");
+        env::remove_var("MATRIX_ROOM_ID");
+        env::remove_var("CHAT_BRIDGE_EVENTS");
+        env::remove_var("BACKUP_DIR");
+        env::remove_var("BACKUP_RETENTION");
+        env::remove_var("BACKUP_NIGHTLY_HOUR");
+        env::remove_var("OTLP_ENDPOINT");
+        env::remove_var("OPENAPI_UI_ENABLED");
     }
 
     #[test]
@@ -82,6 +267,31 @@ mod tests {
         assert_eq!(config.refresh_token_expiration_days, 30);
         assert!(config.static_files_path.is_none());
         assert_eq!(config.cors_origins, vec!["http://localhost", "http://127.0.0.1"]);
+        assert!(config.vapid_private_key.is_none());
+        assert_eq!(config.vapid_subject, "mailto:admin@localhost");
+        assert!(config.oidc_issuer_url.is_none());
+        assert_eq!(config.oidc_provider_name, "SSO");
+        assert_eq!(config.push_reminder_lead_minutes, 30);
+        assert_eq!(config.rate_limit_per_minute, 120);
+        assert_eq!(config.rate_limit_burst, 30);
+        assert!(config.trusted_proxies.is_empty());
+        assert!(config.smtp_host.is_none());
+        assert_eq!(config.smtp_port, 587);
+        assert_eq!(config.smtp_security, "starttls");
+        assert_eq!(config.public_url, "http://localhost:8080");
+        assert_eq!(config.upload_dir, "uploads");
+        assert_eq!(config.max_upload_bytes, 5 * 1024 * 1024);
+        assert_eq!(config.job_check_interval_minutes, 1);
+        assert!(config.jobs_disabled.is_empty());
+        assert!(config.job_intervals.is_empty());
+        assert!(config.telegram_bot_token.is_none());
+        assert!(config.matrix_homeserver_url.is_none());
+        assert!(config.chat_bridge_events.is_empty());
+        assert_eq!(config.backup_dir, "backups");
+        assert_eq!(config.backup_retention, 7);
+        assert!(config.backup_nightly_hour.is_none());
+        assert!(config.otlp_endpoint.is_none());
+        assert!(!config.openapi_ui_enabled);
 
         clear_env();
     }
@@ -99,6 +309,19 @@ mod tests {
         env::set_var("REFRESH_TOKEN_EXPIRATION_DAYS", "7");
         env::set_var("STATIC_FILES_PATH", "./dist");
         env::set_var("CORS_ORIGINS", "https://example.com, https://app.example.com");
+        env::set_var("RATE_LIMIT_PER_MINUTE", "0");
+        env::set_var("RATE_LIMIT_BURST", "10");
+        env::set_var("TRUSTED_PROXIES", "127.0.0.1, ::1");
+        env::set_var("UPLOAD_DIR", "/var/lib/haushalt/uploads");
+        env::set_var("MAX_UPLOAD_BYTES", "1048576");
+        env::set_var("JOB_CHECK_INTERVAL_MINUTES", "2");
+        env::set_var("JOBS_DISABLED", "weekly_summary, auto_archive");
+        env::set_var("JOB_INTERVALS", "missed_tasks=5, period_finalization = 30");
+        env::set_var("BACKUP_DIR", "/var/backups/haushalt");
+        env::set_var("BACKUP_RETENTION", "14");
+        env::set_var("BACKUP_NIGHTLY_HOUR", "3");
+        env::set_var("OTLP_ENDPOINT", "http://collector:4318/v1/traces");
+        env::set_var("OPENAPI_UI_ENABLED", "true");
 
         let config = Config::from_env().unwrap();
 
@@ -110,6 +333,22 @@ mod tests {
         assert_eq!(config.refresh_token_expiration_days, 7);
         assert_eq!(config.static_files_path, Some("./dist".to_string()));
         assert_eq!(config.cors_origins, vec!["https://example.com", "https://app.example.com"]);
+        assert_eq!(config.rate_limit_per_minute, 0);
+        assert_eq!(config.rate_limit_burst, 10);
+        assert_eq!(config.trusted_proxies, vec![IpAddr::from([127, 0, 0, 1]), "::1".parse::<IpAddr>().unwrap()]);
+        assert_eq!(config.upload_dir, "/var/lib/haushalt/uploads");
+        assert_eq!(config.max_upload_bytes, 1048576);
+        assert_eq!(config.job_check_interval_minutes, 2);
+        assert_eq!(config.jobs_disabled, vec!["weekly_summary", "auto_archive"]);
+        assert_eq!(
+            config.job_intervals,
+            vec![("missed_tasks".to_string(), 5), ("period_finalization".to_string(), 30)]
+        );
+        assert_eq!(config.backup_dir, "/var/backups/haushalt");
+        assert_eq!(config.backup_retention, 14);
+        assert_eq!(config.backup_nightly_hour, Some(3));
+        assert_eq!(config.otlp_endpoint.as_deref(), Some("http://collector:4318/v1/traces"));
+        assert!(config.openapi_ui_enabled);
 
         // Clean up
         clear_env();
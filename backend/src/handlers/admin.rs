@@ -0,0 +1,395 @@
+use actix_web::{web, HttpResponse, Result};
+use shared::{AdminUser, ApiError, ApiSuccess, BackgroundJob, BackgroundJobStatus, DatabaseBackupInfo, HouseholdUsage};
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, Forbidden, NotFound, Unauthorized};
+use crate::models::AppState;
+use crate::services::admin as admin_service;
+use crate::services::backup::{self, BackupSettings};
+use crate::services::background_jobs::JobRunError;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/admin")
+            .route("/jobs", web::get().to(list_jobs))
+            .route("/jobs/{job}/run", web::post().to(run_job))
+            .route("/backup", web::post().to(create_backup))
+            .route("/users", web::get().to(list_users))
+            .route("/users/{user_id}/disable", web::post().to(disable_user))
+            .route("/users/{user_id}/enable", web::post().to(enable_user))
+            .route("/users/{user_id}/grant-admin", web::post().to(grant_admin))
+            .route("/users/{user_id}/revoke-admin", web::post().to(revoke_admin))
+            .route("/households", web::get().to(household_usage)),
+    );
+}
+
+/// Server admins are the accounts flagged as such, starting with the first
+/// registered user; anyone else gets 403. API tokens are refused, the admin
+/// endpoints need a login session.
+async fn require_admin(state: &AppState, req: &actix_web::HttpRequest) -> Result<Uuid, HttpResponse> {
+    let user_id = crate::middleware::auth::extract_user_id(req, &state.config.jwt_secret).map_err(|_| {
+        HttpResponse::Unauthorized().json(ApiError {
+            error: "unauthorized".to_string(),
+            message: "Invalid or missing token".to_string(),
+        })
+    })?;
+
+    if crate::middleware::auth::is_api_token_request(req) {
+        return Err(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "API tokens cannot use the admin endpoints".to_string(),
+        }));
+    }
+
+    let is_admin = admin_service::is_instance_admin(&state.db, &user_id)
+        .await
+        .map_err(|e| {
+            log::error!("Error fetching user: {:?}", e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch user".to_string(),
+            })
+        })?;
+    if !is_admin {
+        return Err(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "Only server admins can use the admin endpoints".to_string(),
+        }));
+    }
+
+    Ok(user_id)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/jobs",
+    tag = "admin",
+    summary = "List jobs",
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<BackgroundJobStatus>>),
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_jobs(state: web::Data<AppState>, req: actix_web::HttpRequest) -> Result<HttpResponse> {
+    if let Err(response) = require_admin(&state, &req).await {
+        return Ok(response);
+    }
+
+    Ok(HttpResponse::Ok().json(ApiSuccess::new(state.jobs.statuses())))
+}
+
+/// Run a job immediately and return its status once it has finished
+#[utoipa::path(
+    post,
+    path = "/api/admin/jobs/{job}/run",
+    tag = "admin",
+    summary = "Run a job immediately and return its status once it has finished",
+    params(("job" = String, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<BackgroundJobStatus>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn run_job(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> Result<HttpResponse> {
+    let user_id = match require_admin(&state, &req).await {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    let Ok(job) = path.into_inner().parse::<BackgroundJob>() else {
+        return Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Unknown background job".to_string(),
+        }));
+    };
+
+    log::info!("Background job {} triggered manually by {}", job.as_str(), user_id);
+
+    match state.jobs.run(job).await {
+        Ok(status) => Ok(HttpResponse::Ok().json(ApiSuccess::new(status))),
+        Err(JobRunError::AlreadyRunning) => Ok(HttpResponse::Conflict().json(ApiError {
+            error: "job_running".to_string(),
+            message: "The job is already running".to_string(),
+        })),
+        Err(JobRunError::Unavailable) => Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "job_unavailable".to_string(),
+            message: "The job is not available in this server configuration".to_string(),
+        })),
+        Err(JobRunError::ShuttingDown) => Ok(HttpResponse::ServiceUnavailable().json(ApiError {
+            error: "shutting_down".to_string(),
+            message: "The server is shutting down".to_string(),
+        })),
+    }
+}
+
+/// Write a database snapshot to `BACKUP_DIR` right away
+#[utoipa::path(
+    post,
+    path = "/api/admin/backup",
+    tag = "admin",
+    summary = "Create backup",
+    description = "Write a database snapshot to `BACKUP_DIR` right away",
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<DatabaseBackupInfo>),
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn create_backup(state: web::Data<AppState>, req: actix_web::HttpRequest) -> Result<HttpResponse> {
+    let user_id = match require_admin(&state, &req).await {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    log::info!("Database backup triggered manually by {}", user_id);
+
+    match backup::create_backup(&state.db, &BackupSettings::from_config(&state.config)).await {
+        Ok(info) => Ok(HttpResponse::Created().json(ApiSuccess::new(info))),
+        Err(e) => {
+            log::error!("Error creating database backup: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to create backup".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/users",
+    tag = "admin",
+    summary = "List users",
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<AdminUser>>),
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_users(state: web::Data<AppState>, req: actix_web::HttpRequest) -> Result<HttpResponse> {
+    if let Err(response) = require_admin(&state, &req).await {
+        return Ok(response);
+    }
+
+    match admin_service::list_users(&state.db).await {
+        Ok(users) => Ok(HttpResponse::Ok().json(ApiSuccess::new(users))),
+        Err(e) => {
+            log::error!("Error listing users: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to list users".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/users/{user_id}/disable",
+    tag = "admin",
+    summary = "Disable user",
+    params(("user_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<AdminUser>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn disable_user(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse> {
+    set_user_disabled(state, req, path.into_inner(), true).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/users/{user_id}/enable",
+    tag = "admin",
+    summary = "Enable user",
+    params(("user_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<AdminUser>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn enable_user(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse> {
+    set_user_disabled(state, req, path.into_inner(), false).await
+}
+
+async fn set_user_disabled(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    target_id: Uuid,
+    disabled: bool,
+) -> Result<HttpResponse> {
+    let admin_id = match require_admin(&state, &req).await {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    // Locking yourself out would leave the instance without an admin
+    if disabled && admin_id == target_id {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "cannot_disable_self".to_string(),
+            message: "You cannot disable your own account".to_string(),
+        }));
+    }
+
+    match admin_service::set_user_disabled(&state.db, &target_id, disabled).await {
+        Ok(user) => {
+            log::info!(
+                "Account {} {} by {}",
+                target_id,
+                if disabled { "disabled" } else { "enabled" },
+                admin_id
+            );
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(user)))
+        }
+        Err(admin_service::AdminError::UserNotFound) => Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "User not found".to_string(),
+        })),
+        Err(e) => {
+            log::error!("Error updating account: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to update account".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/users/{user_id}/grant-admin",
+    tag = "admin",
+    summary = "Grant admin",
+    params(("user_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<AdminUser>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn grant_admin(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse> {
+    set_user_admin(state, req, path.into_inner(), true).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/users/{user_id}/revoke-admin",
+    tag = "admin",
+    summary = "Revoke admin",
+    params(("user_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<AdminUser>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn revoke_admin(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse> {
+    set_user_admin(state, req, path.into_inner(), false).await
+}
+
+async fn set_user_admin(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    target_id: Uuid,
+    is_admin: bool,
+) -> Result<HttpResponse> {
+    let admin_id = match require_admin(&state, &req).await {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    // Revoking your own role could leave the instance without an admin
+    if !is_admin && admin_id == target_id {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "cannot_revoke_self".to_string(),
+            message: "You cannot revoke your own admin role".to_string(),
+        }));
+    }
+
+    match admin_service::set_user_admin(&state.db, &target_id, is_admin).await {
+        Ok(user) => {
+            log::info!(
+                "Admin role {} {} by {}",
+                if is_admin { "granted to" } else { "revoked from" },
+                target_id,
+                admin_id
+            );
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(user)))
+        }
+        Err(admin_service::AdminError::UserNotFound) => Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "User not found".to_string(),
+        })),
+        Err(e) => {
+            log::error!("Error updating admin role: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to update admin role".to_string(),
+            }))
+        }
+    }
+}
+
+/// Row counts and upload storage per household
+#[utoipa::path(
+    get,
+    path = "/api/admin/households",
+    tag = "admin",
+    summary = "Row counts and upload storage per household",
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<HouseholdUsage>>),
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn household_usage(state: web::Data<AppState>, req: actix_web::HttpRequest) -> Result<HttpResponse> {
+    if let Err(response) = require_admin(&state, &req).await {
+        return Ok(response);
+    }
+
+    match admin_service::household_usage(&state.db).await {
+        Ok(usage) => Ok(HttpResponse::Ok().json(ApiSuccess::new(usage))),
+        Err(e) => {
+            log::error!("Error loading household usage: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to load household usage".to_string(),
+            }))
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use actix_web::{web, HttpResponse, Result};
+use shared::{ApiError, ApiSuccess, Permission};
+
+use crate::handlers::openapi::{BadRequest, Forbidden, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::allowance as allowance_service;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::scope("/allowance").route("/payouts", web::get().to(list_payouts)));
+}
+
+/// Members who can adjust points see every payout, everyone else only their own
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/allowance/payouts",
+    tag = "allowance",
+    summary = "Members who can adjust points see every payout, everyone else only their own",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<shared::AllowancePayout>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_payouts(state: web::Data<AppState>, ctx: HouseholdContext) -> Result<HttpResponse> {
+    let user_filter = if ctx.has_permission(&state, Permission::AdjustPoints).await {
+        None
+    } else {
+        Some(ctx.user_id)
+    };
+
+    match allowance_service::list_payouts(&state.db, &ctx.household_id, user_filter.as_ref()).await {
+        Ok(payouts) => Ok(HttpResponse::Ok().json(ApiSuccess::new(payouts))),
+        Err(e) => {
+            log::error!("Error listing allowance payouts: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to list allowance payouts".to_string(),
+            }))
+        }
+    }
+}
@@ -1,9 +1,14 @@
 use actix_web::{web, HttpResponse, Result};
-use shared::{ApiError, ApiSuccess, CreateAnnouncementRequest, Role, UpdateAnnouncementRequest};
+use shared::{
+    Announcement, AnnouncementAcknowledgment, ApiError, ApiSuccess, CreateAnnouncementRequest, Role,
+    UpdateAnnouncementRequest,
+};
 use uuid::Uuid;
 
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Timestamp, Unauthorized};
+use crate::middleware::household::HouseholdContext;
 use crate::models::AppState;
-use crate::services::{announcements as announcements_service, households as household_service};
+use crate::services::{announcements as announcements_service, chat_bridge};
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -13,48 +18,63 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route("", web::post().to(create_announcement))
             .route("/{announcement_id}", web::get().to(get_announcement))
             .route("/{announcement_id}", web::put().to(update_announcement))
-            .route("/{announcement_id}", web::delete().to(delete_announcement)),
+            .route("/{announcement_id}", web::delete().to(delete_announcement))
+            .route("/{announcement_id}/acknowledge", web::post().to(acknowledge_announcement))
+            .route("/{announcement_id}/acknowledgments", web::get().to(list_acknowledgments)),
     );
 }
 
-/// List all announcements for a household (for management, any member can view)
-async fn list_announcements(
-    state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
-) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
+/// Parse the announcement ID and load the announcement; other households' announcements are not found
+async fn load_announcement(
+    state: &AppState,
+    ctx: &HouseholdContext,
+    announcement_id: &str,
+) -> Result<Announcement, HttpResponse> {
+    let announcement_id = Uuid::parse_str(announcement_id).map_err(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_id".to_string(),
+            message: "Invalid announcement ID format".to_string(),
+        })
+    })?;
 
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
+    match announcements_service::get_announcement(&state.db, &announcement_id).await {
+        Ok(Some(announcement)) => {
+            ctx.check_owns(&announcement.household_id, "Announcement")?;
+            Ok(announcement)
+        }
+        Ok(None) => Err(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Announcement not found".to_string(),
+        })),
+        Err(e) => {
+            log::error!("Error fetching announcement: {:?}", e);
+            Err(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch announcement".to_string(),
+            }))
         }
-    };
-
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
-    {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
     }
+}
 
-    match announcements_service::list_announcements(&state.db, &household_id).await {
+/// List all announcements for a household (for management, any member can view)
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/announcements",
+    tag = "announcements",
+    summary = "List all announcements for a household (for management, any member can view)",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<Announcement>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_announcements(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
+    match announcements_service::list_announcements(&state.db, &ctx.household_id).await {
         Ok(announcements) => Ok(HttpResponse::Ok().json(ApiSuccess::new(announcements))),
         Err(e) => {
             log::error!("Error listing announcements: {:?}", e);
@@ -67,43 +87,24 @@ async fn list_announcements(
 }
 
 /// List currently active announcements for display (any member can view)
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/announcements/active",
+    tag = "announcements",
+    summary = "List currently active announcements for display (any member can view)",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<Announcement>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn list_active_announcements(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
-    {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    match announcements_service::list_active_announcements(&state.db, &household_id).await {
+    match announcements_service::list_active_announcements(&state.db, &ctx.household_id, &ctx.user_id).await {
         Ok(announcements) => Ok(HttpResponse::Ok().json(ApiSuccess::new(announcements))),
         Err(e) => {
             log::error!("Error listing active announcements: {:?}", e);
@@ -116,35 +117,26 @@ async fn list_active_announcements(
 }
 
 /// Create a new announcement (owner only)
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/announcements",
+    tag = "announcements",
+    summary = "Create a new announcement (owner only)",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreateAnnouncementRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<Announcement>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn create_announcement(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
     body: web::Json<CreateAnnouncementRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    // Check owner permission
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.map(|r| r == Role::Owner).unwrap_or(false) {
+    if ctx.role() != Role::Owner {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "Only owners can create announcements".to_string(),
@@ -159,10 +151,22 @@ async fn create_announcement(
         }));
     }
 
-    match announcements_service::create_announcement(&state.db, &household_id, &user_id, &request)
+    match announcements_service::create_announcement(&state.db, &ctx.household_id, &ctx.user_id, &request)
         .await
     {
-        Ok(announcement) => Ok(HttpResponse::Created().json(ApiSuccess::new(announcement))),
+        Ok(announcement) => {
+            // Scheduled announcements are posted by the background job once they start
+            if announcement.published_at.is_some() {
+                chat_bridge::post_in_background(
+                    state.chat_bridge.as_ref(),
+                    &state.db,
+                    ctx.household_id,
+                    chat_bridge::BridgeEvent::AnnouncementCreated,
+                    chat_bridge::announcement_text(&announcement.title, &announcement.content),
+                );
+            }
+            Ok(HttpResponse::Created().json(ApiSuccess::new(announcement)))
+        }
         Err(e) => {
             log::error!("Error creating announcement: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
@@ -174,121 +178,69 @@ async fn create_announcement(
 }
 
 /// Get a single announcement
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/announcements/{announcement_id}",
+    tag = "announcements",
+    summary = "Get a single announcement",
+    params(("household_id" = Uuid, Path), ("announcement_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Announcement>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn get_announcement(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, announcement_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    let announcement_id = match Uuid::parse_str(&announcement_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid announcement ID format".to_string(),
-            }));
-        }
-    };
-
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
-    {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    match announcements_service::get_announcement(&state.db, &announcement_id).await {
-        Ok(Some(announcement)) => Ok(HttpResponse::Ok().json(ApiSuccess::new(announcement))),
-        Ok(None) => Ok(HttpResponse::NotFound().json(ApiError {
-            error: "not_found".to_string(),
-            message: "Announcement not found".to_string(),
-        })),
-        Err(e) => {
-            log::error!("Error fetching announcement: {:?}", e);
-            Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch announcement".to_string(),
-            }))
-        }
+    match load_announcement(&state, &ctx, &path.into_inner().1).await {
+        Ok(announcement) => Ok(HttpResponse::Ok().json(ApiSuccess::new(announcement))),
+        Err(response) => Ok(response),
     }
 }
 
 /// Update an announcement (owner only)
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/announcements/{announcement_id}",
+    tag = "announcements",
+    summary = "Update an announcement (owner only)",
+    params(("household_id" = Uuid, Path), ("announcement_id" = Uuid, Path)),
+    request_body = UpdateAnnouncementRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Announcement>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn update_announcement(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
     body: web::Json<UpdateAnnouncementRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, announcement_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    let announcement_id = match Uuid::parse_str(&announcement_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid announcement ID format".to_string(),
-            }));
-        }
-    };
-
-    // Check owner permission
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.map(|r| r == Role::Owner).unwrap_or(false) {
+    if ctx.role() != Role::Owner {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "Only owners can update announcements".to_string(),
         }));
     }
 
+    let (_, announcement_id) = path.into_inner();
+    let announcement = match load_announcement(&state, &ctx, &announcement_id).await {
+        Ok(announcement) => announcement,
+        Err(response) => return Ok(response),
+    };
+
     let request = body.into_inner();
 
-    match announcements_service::update_announcement(&state.db, &announcement_id, &request).await {
+    match announcements_service::update_announcement(&state.db, &announcement.id, &request).await {
         Ok(announcement) => Ok(HttpResponse::Ok().json(ApiSuccess::new(announcement))),
         Err(announcements_service::AnnouncementError::NotFound) => {
             Ok(HttpResponse::NotFound().json(ApiError {
@@ -307,53 +259,33 @@ async fn update_announcement(
 }
 
 /// Delete an announcement (owner only)
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/announcements/{announcement_id}",
+    tag = "announcements",
+    summary = "Delete an announcement (owner only)",
+    params(("household_id" = Uuid, Path), ("announcement_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
 async fn delete_announcement(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, announcement_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    let announcement_id = match Uuid::parse_str(&announcement_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid announcement ID format".to_string(),
-            }));
-        }
-    };
-
-    // Check owner permission
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.map(|r| r == Role::Owner).unwrap_or(false) {
+    if ctx.role() != Role::Owner {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "Only owners can delete announcements".to_string(),
         }));
     }
 
-    match announcements_service::delete_announcement(&state.db, &announcement_id).await {
+    let (_, announcement_id) = path.into_inner();
+    let announcement = match load_announcement(&state, &ctx, &announcement_id).await {
+        Ok(announcement) => announcement,
+        Err(response) => return Ok(response),
+    };
+
+    match announcements_service::delete_announcement(&state.db, &announcement.id).await {
         Ok(_) => Ok(HttpResponse::NoContent().finish()),
         Err(announcements_service::AnnouncementError::NotFound) => {
             Ok(HttpResponse::NotFound().json(ApiError {
@@ -370,3 +302,86 @@ async fn delete_announcement(
         }
     }
 }
+
+/// Confirm that the caller has read the announcement (any member)
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/announcements/{announcement_id}/acknowledge",
+    tag = "announcements",
+    summary = "Confirm that the caller has read the announcement (any member)",
+    params(("household_id" = Uuid, Path), ("announcement_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Timestamp>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn acknowledge_announcement(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, announcement_id) = path.into_inner();
+    let announcement = match load_announcement(&state, &ctx, &announcement_id).await {
+        Ok(announcement) => announcement,
+        Err(response) => return Ok(response),
+    };
+
+    match announcements_service::acknowledge_announcement(&state.db, &announcement.id, &ctx.user_id).await {
+        Ok(acknowledged_at) => Ok(HttpResponse::Ok().json(ApiSuccess::new(acknowledged_at))),
+        Err(e) => {
+            log::error!("Error acknowledging announcement: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to acknowledge announcement".to_string(),
+            }))
+        }
+    }
+}
+
+/// Which members have read the announcement (author and owners)
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/announcements/{announcement_id}/acknowledgments",
+    tag = "announcements",
+    summary = "Which members have read the announcement (author and owners)",
+    params(("household_id" = Uuid, Path), ("announcement_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<AnnouncementAcknowledgment>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn list_acknowledgments(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, announcement_id) = path.into_inner();
+    let announcement = match load_announcement(&state, &ctx, &announcement_id).await {
+        Ok(announcement) => announcement,
+        Err(response) => return Ok(response),
+    };
+
+    if announcement.created_by != ctx.user_id && ctx.role() != Role::Owner {
+        return Ok(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "Only the author and owners can see who read an announcement".to_string(),
+        }));
+    }
+
+    match announcements_service::list_acknowledgments(&state.db, &announcement).await {
+        Ok(checklist) => Ok(HttpResponse::Ok().json(ApiSuccess::new(checklist))),
+        Err(e) => {
+            log::error!("Error listing announcement acknowledgments: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to list acknowledgments".to_string(),
+            }))
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use actix_web::{web, HttpResponse, Result};
+use shared::{ApiError, ApiSuccess, ApiToken, CreateApiTokenRequest, CreatedApiToken};
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, NoContent, NotFound, Unauthorized};
+use crate::middleware::auth::{extract_user_id, is_api_token_request};
+use crate::models::AppState;
+use crate::services::api_tokens::{self as api_token_service, ApiTokenError};
+
+/// Authenticate a request that must come from a login session, not an API token
+fn session_user_id(state: &AppState, req: &actix_web::HttpRequest) -> std::result::Result<Uuid, HttpResponse> {
+    let user_id = extract_user_id(req, &state.config.jwt_secret).map_err(|_| {
+        HttpResponse::Unauthorized().json(ApiError {
+            error: "unauthorized".to_string(),
+            message: "Invalid or missing token".to_string(),
+        })
+    })?;
+
+    if is_api_token_request(req) {
+        return Err(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "API tokens cannot manage API tokens".to_string(),
+        }));
+    }
+
+    Ok(user_id)
+}
+
+fn api_token_error_response(error: ApiTokenError, context: &str) -> HttpResponse {
+    match error {
+        ApiTokenError::NotFound => HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: error.to_string(),
+        }),
+        ApiTokenError::EmptyName | ApiTokenError::TooManyTokens => HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: error.to_string(),
+        }),
+        ApiTokenError::DatabaseError(e) => {
+            log::error!("Error {}: {:?}", context, e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: format!("Failed {}", context),
+            })
+        }
+    }
+}
+
+/// List the current user's API tokens (without secrets)
+#[utoipa::path(
+    get,
+    path = "/api/users/me/api-tokens",
+    tag = "api_tokens",
+    summary = "List the current user's API tokens (without secrets)",
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<ApiToken>>),
+        Unauthorized,
+    ),
+)]
+pub async fn list_tokens(state: web::Data<AppState>, req: actix_web::HttpRequest) -> Result<HttpResponse> {
+    let user_id = match session_user_id(&state, &req) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match api_token_service::list_tokens(&state.db, &user_id).await {
+        Ok(tokens) => Ok(HttpResponse::Ok().json(ApiSuccess::new(tokens))),
+        Err(e) => Ok(api_token_error_response(e, "listing API tokens")),
+    }
+}
+
+/// Create an API token; the response is the only time the secret is shown
+#[utoipa::path(
+    post,
+    path = "/api/users/me/api-tokens",
+    tag = "api_tokens",
+    summary = "Create an API token; the response is the only time the secret is shown",
+    request_body = CreateApiTokenRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<CreatedApiToken>),
+        BadRequest,
+        Unauthorized,
+    ),
+)]
+pub async fn create_token(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    body: web::Json<CreateApiTokenRequest>,
+) -> Result<HttpResponse> {
+    let user_id = match session_user_id(&state, &req) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match api_token_service::create_token(&state.db, &user_id, &body).await {
+        Ok(created) => Ok(HttpResponse::Created().json(ApiSuccess::new(created))),
+        Err(e) => Ok(api_token_error_response(e, "creating API token")),
+    }
+}
+
+/// Revoke one of the current user's API tokens
+#[utoipa::path(
+    delete,
+    path = "/api/users/me/api-tokens/{token_id}",
+    tag = "api_tokens",
+    summary = "Revoke one of the current user's API tokens",
+    params(("token_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, NotFound),
+)]
+pub async fn revoke_token(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> Result<HttpResponse> {
+    let user_id = match session_user_id(&state, &req) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+    let token_id = match Uuid::parse_str(&path.into_inner()) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_id".to_string(),
+                message: "Invalid token ID format".to_string(),
+            }));
+        }
+    };
+
+    match api_token_service::revoke_token(&state.db, &user_id, &token_id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(api_token_error_response(e, "revoking API token")),
+    }
+}
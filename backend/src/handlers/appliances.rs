@@ -0,0 +1,300 @@
+use actix_web::{web, HttpResponse, Result};
+use shared::{
+    ApiError, ApiSuccess, Appliance, CreateApplianceRequest, CreateMaintenanceRequest, Permission,
+    UpdateApplianceRequest,
+};
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::appliances as appliance_service;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/appliances")
+            .route("", web::get().to(list_appliances))
+            .route("", web::post().to(create_appliance))
+            .route("/{appliance_id}", web::get().to(get_appliance))
+            .route("/{appliance_id}", web::put().to(update_appliance))
+            .route("/{appliance_id}", web::delete().to(delete_appliance))
+            .route("/{appliance_id}/maintenance", web::post().to(add_maintenance))
+            .route("/{appliance_id}/maintenance/{task_id}", web::delete().to(remove_maintenance)),
+    );
+}
+
+/// Maintenance reminders are tasks, so changing them needs the task permission
+async fn require_manage_tasks(state: &AppState, ctx: &HouseholdContext) -> Result<(), HttpResponse> {
+    if ctx.has_permission(state, Permission::ManageTasks).await {
+        return Ok(());
+    }
+    Err(HttpResponse::Forbidden().json(ApiError {
+        error: "forbidden".to_string(),
+        message: "You don't have permission to manage maintenance tasks".to_string(),
+    }))
+}
+
+fn parse_id(id: &str, what: &str) -> Result<Uuid, HttpResponse> {
+    Uuid::parse_str(id).map_err(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_id".to_string(),
+            message: format!("Invalid {} ID format", what),
+        })
+    })
+}
+
+fn validation_error(message: &str) -> HttpResponse {
+    HttpResponse::BadRequest().json(ApiError {
+        error: "validation_error".to_string(),
+        message: message.to_string(),
+    })
+}
+
+fn appliance_error_response(error: appliance_service::ApplianceError, context: &str) -> HttpResponse {
+    match error {
+        appliance_service::ApplianceError::NotFound | appliance_service::ApplianceError::MaintenanceNotFound => {
+            HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: error.to_string(),
+            })
+        }
+        appliance_service::ApplianceError::InvalidAssignee => validation_error(&error.to_string()),
+        e => {
+            log::error!("Error {}: {:?}", context, e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: format!("Failed {}", context),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/appliances",
+    tag = "appliances",
+    summary = "List appliances",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<Appliance>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_appliances(state: web::Data<AppState>, ctx: HouseholdContext) -> Result<HttpResponse> {
+    match appliance_service::list_appliances(&state.db, &ctx.household_id).await {
+        Ok(appliances) => Ok(HttpResponse::Ok().json(ApiSuccess::new(appliances))),
+        Err(e) => Ok(appliance_error_response(e, "listing appliances")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/appliances",
+    tag = "appliances",
+    summary = "Create appliance",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreateApplianceRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<Appliance>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn create_appliance(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    body: web::Json<CreateApplianceRequest>,
+) -> Result<HttpResponse> {
+    let request = body.into_inner();
+    if request.name.trim().is_empty() {
+        return Ok(validation_error("Appliance name is required"));
+    }
+
+    match appliance_service::create_appliance(&state.db, &ctx.household_id, &ctx.user_id, &request).await {
+        Ok(appliance) => Ok(HttpResponse::Created().json(ApiSuccess::new(appliance))),
+        Err(e) => Ok(appliance_error_response(e, "creating appliance")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/appliances/{appliance_id}",
+    tag = "appliances",
+    summary = "Get appliance",
+    params(("household_id" = Uuid, Path), ("appliance_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Appliance>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn get_appliance(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, appliance_id_str) = path.into_inner();
+    let appliance_id = match parse_id(&appliance_id_str, "appliance") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match appliance_service::get_appliance(&state.db, &ctx.household_id, &appliance_id).await {
+        Ok(appliance) => Ok(HttpResponse::Ok().json(ApiSuccess::new(appliance))),
+        Err(e) => Ok(appliance_error_response(e, "loading appliance")),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/appliances/{appliance_id}",
+    tag = "appliances",
+    summary = "Update appliance",
+    params(("household_id" = Uuid, Path), ("appliance_id" = Uuid, Path)),
+    request_body = UpdateApplianceRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Appliance>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn update_appliance(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+    body: web::Json<UpdateApplianceRequest>,
+) -> Result<HttpResponse> {
+    let (_, appliance_id_str) = path.into_inner();
+    let appliance_id = match parse_id(&appliance_id_str, "appliance") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    let request = body.into_inner();
+    if request.name.as_ref().is_some_and(|n| n.trim().is_empty()) {
+        return Ok(validation_error("Appliance name is required"));
+    }
+
+    match appliance_service::update_appliance(&state.db, &ctx.household_id, &appliance_id, &request).await {
+        Ok(appliance) => Ok(HttpResponse::Ok().json(ApiSuccess::new(appliance))),
+        Err(e) => Ok(appliance_error_response(e, "updating appliance")),
+    }
+}
+
+/// Delete the appliance; its maintenance tasks go to the trash
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/appliances/{appliance_id}",
+    tag = "appliances",
+    summary = "Delete the appliance; its maintenance tasks go to the trash",
+    params(("household_id" = Uuid, Path), ("appliance_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn delete_appliance(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_manage_tasks(&state, &ctx).await {
+        return Ok(response);
+    }
+    let (_, appliance_id_str) = path.into_inner();
+    let appliance_id = match parse_id(&appliance_id_str, "appliance") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match appliance_service::delete_appliance(&state.db, &ctx.household_id, &appliance_id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(appliance_error_response(e, "deleting appliance")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/appliances/{appliance_id}/maintenance",
+    tag = "appliances",
+    summary = "Add maintenance",
+    params(("household_id" = Uuid, Path), ("appliance_id" = Uuid, Path)),
+    request_body = CreateMaintenanceRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<Appliance>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn add_maintenance(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+    body: web::Json<CreateMaintenanceRequest>,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_manage_tasks(&state, &ctx).await {
+        return Ok(response);
+    }
+    let (_, appliance_id_str) = path.into_inner();
+    let appliance_id = match parse_id(&appliance_id_str, "appliance") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    let request = body.into_inner();
+    if request.title.trim().is_empty() {
+        return Ok(validation_error("Maintenance title is required"));
+    }
+    if request.interval_days < 1 {
+        return Ok(validation_error("The maintenance interval must be at least one day"));
+    }
+
+    match appliance_service::add_maintenance(&state.db, &ctx.household_id, &appliance_id, &request).await {
+        Ok(appliance) => Ok(HttpResponse::Created().json(ApiSuccess::new(appliance))),
+        Err(e) => Ok(appliance_error_response(e, "adding maintenance task")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/appliances/{appliance_id}/maintenance/{task_id}",
+    tag = "appliances",
+    summary = "Remove maintenance",
+    params(("household_id" = Uuid, Path), ("appliance_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Appliance>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn remove_maintenance(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String, String)>,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_manage_tasks(&state, &ctx).await {
+        return Ok(response);
+    }
+    let (_, appliance_id_str, task_id_str) = path.into_inner();
+    let appliance_id = match parse_id(&appliance_id_str, "appliance") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+    let task_id = match parse_id(&task_id_str, "task") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match appliance_service::remove_maintenance(&state.db, &ctx.household_id, &appliance_id, &task_id).await {
+        Ok(appliance) => Ok(HttpResponse::Ok().json(ApiSuccess::new(appliance))),
+        Err(e) => Ok(appliance_error_response(e, "removing maintenance task")),
+    }
+}
@@ -0,0 +1,515 @@
+use actix_web::{web, HttpResponse, Result};
+use futures::StreamExt;
+use shared::{ApiError, ApiSuccess, AttachmentEntity};
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, FileUpload, Forbidden, NotFound, Null, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::{
+    attachments as attachment_service, chat as chat_service, journal as journal_service, notes as notes_service,
+    plants as plant_service, websocket::WsManager,
+};
+
+/// Slack on top of the file size limit for multipart boundaries and part headers
+const MULTIPART_OVERHEAD_BYTES: usize = 16 * 1024;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/attachments")
+            .route("/{attachment_id}/thumbnail", web::get().to(download_thumbnail))
+            .route("/{entity_type}/{entity_id}", web::get().to(list_attachments))
+            .route("/{entity_type}/{entity_id}", web::post().to(upload_attachment))
+            .route("/{attachment_id}", web::get().to(download_attachment))
+            .route("/{attachment_id}", web::delete().to(delete_attachment)),
+    );
+}
+
+/// What a user may do with the attachments of a note, journal entry or chat message
+struct EntityAccess {
+    can_view: bool,
+    can_edit: bool,
+}
+
+/// Look up the owning note, journal entry or chat message. Returns `None` when it
+/// does not exist in this household; attachments follow the entry's visibility and
+/// only its author may add or remove files. Chat photos are visible to all members
+/// until the message is deleted; plant photos are shared by the whole household.
+async fn entity_access(
+    state: &AppState,
+    ctx: &HouseholdContext,
+    entity_type: AttachmentEntity,
+    entity_id: &Uuid,
+) -> Option<EntityAccess> {
+    let household_id = &ctx.household_id;
+    let user_id = &ctx.user_id;
+    match entity_type {
+        AttachmentEntity::Note => match notes_service::get_note(&state.db, entity_id).await {
+            Ok(Some(note)) if note.household_id == *household_id => Some(EntityAccess {
+                can_view: notes_service::can_view_note(&note, user_id),
+                can_edit: note.user_id == *user_id,
+            }),
+            Ok(_) => None,
+            Err(e) => {
+                log::error!("Error fetching note: {:?}", e);
+                None
+            }
+        },
+        AttachmentEntity::JournalEntry => match journal_service::get_journal_entry(&state.db, entity_id).await {
+            Ok(Some(entry)) if entry.household_id == *household_id => Some(EntityAccess {
+                can_view: journal_service::can_view_entry(&entry, user_id),
+                can_edit: entry.user_id == *user_id,
+            }),
+            Ok(_) => None,
+            Err(e) => {
+                log::error!("Error fetching journal entry: {:?}", e);
+                None
+            }
+        },
+        AttachmentEntity::ChatMessage => match chat_service::get_message(&state.db, entity_id).await {
+            Ok(Some(message)) if message.household_id == *household_id && !message.is_deleted => Some(EntityAccess {
+                can_view: true,
+                can_edit: message.user_id == *user_id,
+            }),
+            Ok(_) => None,
+            Err(e) => {
+                log::error!("Error fetching chat message: {:?}", e);
+                None
+            }
+        },
+        AttachmentEntity::Plant => match plant_service::get_plant(&state.db, household_id, entity_id).await {
+            Ok(_) => Some(EntityAccess {
+                can_view: true,
+                can_edit: true,
+            }),
+            Err(plant_service::PlantError::NotFound) => None,
+            Err(e) => {
+                log::error!("Error fetching plant: {:?}", e);
+                None
+            }
+        },
+    }
+}
+
+/// Content-Disposition for a download, with an ASCII fallback name and the UTF-8 original
+fn content_disposition(file_name: &str) -> String {
+    let ascii: String = file_name
+        .chars()
+        .map(|c| if c.is_ascii() && !c.is_ascii_control() && c != '"' && c != '\\' { c } else { '_' })
+        .collect();
+    let encoded: String = file_name
+        .bytes()
+        .map(|b| {
+            if b.is_ascii_alphanumeric() || b"-._~".contains(&b) {
+                (b as char).to_string()
+            } else {
+                format!("%{:02X}", b)
+            }
+        })
+        .collect();
+    format!("attachment; filename=\"{}\"; filename*=UTF-8''{}", ascii, encoded)
+}
+
+/// Parse the entity path and check that the entity's module is switched on
+fn parse_entity_path(
+    ctx: &HouseholdContext,
+    path: (String, String, String),
+) -> std::result::Result<(AttachmentEntity, Uuid), HttpResponse> {
+    let (_, entity_type_str, entity_id_str) = path;
+
+    let entity_type = entity_type_str.parse::<AttachmentEntity>().map_err(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_entity_type".to_string(),
+            message: "Attachments can belong to notes, journal entries, chat messages or plants".to_string(),
+        })
+    })?;
+    ctx.check_feature(entity_type.feature())?;
+    let entity_id = Uuid::parse_str(&entity_id_str).map_err(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_id".to_string(),
+            message: "Invalid entity ID format".to_string(),
+        })
+    })?;
+
+    Ok((entity_type, entity_id))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/attachments/{entity_type}/{entity_id}",
+    tag = "attachments",
+    summary = "List attachments",
+    params(("household_id" = Uuid, Path), ("entity_type" = String, Path), ("entity_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<shared::Attachment>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn list_attachments(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String, String)>,
+) -> Result<HttpResponse> {
+    let (entity_type, entity_id) = match parse_entity_path(&ctx, path.into_inner()) {
+        Ok(parsed) => parsed,
+        Err(response) => return Ok(response),
+    };
+
+    match entity_access(&state, &ctx, entity_type, &entity_id).await {
+        Some(access) if access.can_view => {}
+        _ => {
+            return Ok(HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: "Entry not found".to_string(),
+            }));
+        }
+    }
+
+    match attachment_service::list_for_entity(&state.db, entity_type, &entity_id).await {
+        Ok(attachments) => Ok(HttpResponse::Ok().json(ApiSuccess::new(attachments))),
+        Err(e) => {
+            log::error!("Error listing attachments: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to list attachments".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/attachments/{entity_type}/{entity_id}",
+    tag = "attachments",
+    summary = "Upload attachment",
+    params(("household_id" = Uuid, Path), ("entity_type" = String, Path), ("entity_id" = Uuid, Path)),
+    request_body(content = FileUpload, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<shared::Attachment>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn upload_attachment(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String, String)>,
+    mut payload: web::Payload,
+) -> Result<HttpResponse> {
+    let (entity_type, entity_id) = match parse_entity_path(&ctx, path.into_inner()) {
+        Ok(parsed) => parsed,
+        Err(response) => return Ok(response),
+    };
+
+    match entity_access(&state, &ctx, entity_type, &entity_id).await {
+        Some(access) if access.can_edit => {}
+        Some(access) if access.can_view => {
+            return Ok(HttpResponse::Forbidden().json(ApiError {
+                error: "forbidden".to_string(),
+                message: "Only the author can add attachments".to_string(),
+            }));
+        }
+        _ => {
+            return Ok(HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: "Entry not found".to_string(),
+            }));
+        }
+    }
+
+    let content_type = req
+        .headers()
+        .get(actix_web::http::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+
+    let limit = state.config.max_upload_bytes + MULTIPART_OVERHEAD_BYTES;
+    let mut body = web::BytesMut::new();
+    while let Some(chunk) = payload.next().await {
+        let chunk = match chunk {
+            Ok(chunk) => chunk,
+            Err(e) => {
+                log::error!("Error reading upload: {:?}", e);
+                return Ok(HttpResponse::BadRequest().json(ApiError {
+                    error: "invalid_upload".to_string(),
+                    message: "Failed to read upload".to_string(),
+                }));
+            }
+        };
+        if body.len() + chunk.len() > limit {
+            return Ok(HttpResponse::PayloadTooLarge().json(ApiError {
+                error: "file_too_large".to_string(),
+                message: format!("Files may be at most {} bytes", state.config.max_upload_bytes),
+            }));
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    let file = match attachment_service::parse_multipart(&content_type, &body) {
+        Ok(file) => file,
+        Err(e) => {
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_upload".to_string(),
+                message: e.to_string(),
+            }));
+        }
+    };
+    if file.data.len() > state.config.max_upload_bytes {
+        return Ok(HttpResponse::PayloadTooLarge().json(ApiError {
+            error: "file_too_large".to_string(),
+            message: format!("Files may be at most {} bytes", state.config.max_upload_bytes),
+        }));
+    }
+
+    match attachment_service::create_entity_attachment(
+        &state.db,
+        &state.config.upload_dir,
+        &ctx.household_id,
+        entity_type,
+        &entity_id,
+        &ctx.user_id,
+        &file,
+    )
+    .await
+    {
+        Ok(attachment) => {
+            if entity_type == AttachmentEntity::ChatMessage {
+                broadcast_chat_photo(&state, &req, &ctx.household_id, &entity_id).await;
+            }
+            Ok(HttpResponse::Created().json(ApiSuccess::new(attachment)))
+        }
+        Err(
+            e @ (attachment_service::AttachmentError::UnsupportedType
+            | attachment_service::AttachmentError::UnsupportedDocumentType
+            | attachment_service::AttachmentError::TooMany(_)),
+        ) => Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_upload".to_string(),
+            message: e.to_string(),
+        })),
+        Err(e) => {
+            log::error!("Error storing attachment: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to store attachment".to_string(),
+            }))
+        }
+    }
+}
+
+/// Push the chat message with its changed photos to the household, like an edit
+async fn broadcast_chat_photo(state: &AppState, req: &actix_web::HttpRequest, household_id: &Uuid, message_id: &Uuid) {
+    let Some(ws_manager) = req.app_data::<web::Data<std::sync::Arc<WsManager>>>() else {
+        return;
+    };
+    match chat_service::get_message_with_user(&state.db, message_id).await {
+        Ok(Some(message)) => ws_manager.broadcast_message_edited(household_id, message).await,
+        Ok(None) => {}
+        Err(e) => log::error!("Error fetching chat message: {:?}", e),
+    }
+}
+
+/// Load the attachment with the caller's access to it, if its module is switched on
+async fn load_attachment(
+    state: &AppState,
+    ctx: &HouseholdContext,
+    path: (String, String),
+) -> std::result::Result<(shared::Attachment, EntityAccess), HttpResponse> {
+    let (_, attachment_id_str) = path;
+    let attachment_id = Uuid::parse_str(&attachment_id_str).map_err(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_id".to_string(),
+            message: "Invalid attachment ID format".to_string(),
+        })
+    })?;
+
+    let not_found = || {
+        HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Attachment not found".to_string(),
+        })
+    };
+
+    let attachment = match attachment_service::get_entity_attachment(&state.db, &ctx.household_id, &attachment_id).await {
+        Ok(Some(attachment)) => attachment,
+        Ok(None) => return Err(not_found()),
+        Err(e) => {
+            log::error!("Error fetching attachment: {:?}", e);
+            return Err(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch attachment".to_string(),
+            }));
+        }
+    };
+
+    ctx.check_feature(attachment.entity_type.feature())?;
+
+    match entity_access(state, ctx, attachment.entity_type, &attachment.entity_id).await {
+        Some(access) if access.can_view => Ok((attachment, access)),
+        _ => Err(not_found()),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/attachments/{attachment_id}",
+    tag = "attachments",
+    summary = "Download attachment",
+    params(("household_id" = Uuid, Path), ("attachment_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", content_type = "application/octet-stream", body = Vec<u8>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn download_attachment(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let attachment = match load_attachment(&state, &ctx, path.into_inner()).await {
+        Ok((attachment, _)) => attachment,
+        Err(response) => return Ok(response),
+    };
+
+    match attachment_service::read_entity_attachment(&state.config.upload_dir, &attachment.id).await {
+        Ok(data) => Ok(HttpResponse::Ok()
+            .content_type(attachment.content_type.as_str())
+            .insert_header(("Content-Disposition", content_disposition(&attachment.file_name)))
+            .insert_header(("X-Content-Type-Options", "nosniff"))
+            .body(data)),
+        Err(attachment_service::AttachmentError::NotFound) => Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Attachment not found".to_string(),
+        })),
+        Err(e) => {
+            log::error!("Error reading attachment: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to read attachment".to_string(),
+            }))
+        }
+    }
+}
+
+/// Small JPEG preview of an image attachment, e.g. for the chat
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/attachments/{attachment_id}/thumbnail",
+    tag = "attachments",
+    summary = "Download thumbnail",
+    description = "Small JPEG preview of an image attachment, e.g. for the chat",
+    params(("household_id" = Uuid, Path), ("attachment_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", content_type = "image/jpeg", body = Vec<u8>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn download_thumbnail(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let attachment = match load_attachment(&state, &ctx, path.into_inner()).await {
+        Ok((attachment, _)) => attachment,
+        Err(response) => return Ok(response),
+    };
+
+    match attachment_service::read_thumbnail(&state.config.upload_dir, &attachment).await {
+        Ok(data) => Ok(HttpResponse::Ok()
+            .content_type(attachment_service::THUMBNAIL_CONTENT_TYPE)
+            .insert_header(("X-Content-Type-Options", "nosniff"))
+            .body(data)),
+        Err(attachment_service::AttachmentError::NotFound) => Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "No thumbnail for this attachment".to_string(),
+        })),
+        Err(e) => {
+            log::error!("Error reading thumbnail: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to read thumbnail".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/attachments/{attachment_id}",
+    tag = "attachments",
+    summary = "Delete attachment",
+    params(("household_id" = Uuid, Path), ("attachment_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Null>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn delete_attachment(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let attachment = match load_attachment(&state, &ctx, path.into_inner()).await {
+        Ok((attachment, access)) if access.can_edit => attachment,
+        Ok(_) => {
+            return Ok(HttpResponse::Forbidden().json(ApiError {
+                error: "forbidden".to_string(),
+                message: "Only the author can remove attachments".to_string(),
+            }));
+        }
+        Err(response) => return Ok(response),
+    };
+
+    match attachment_service::delete_entity_attachment(&state.db, &state.config.upload_dir, &attachment.id).await {
+        Ok(_) => {
+            if attachment.entity_type == AttachmentEntity::ChatMessage {
+                broadcast_chat_photo(&state, &req, &attachment.household_id, &attachment.entity_id).await;
+            }
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(())))
+        }
+        Err(attachment_service::AttachmentError::NotFound) => Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Attachment not found".to_string(),
+        })),
+        Err(e) => {
+            log::error!("Error deleting attachment: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to delete attachment".to_string(),
+            }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_disposition() {
+        assert_eq!(
+            content_disposition("manual.pdf"),
+            "attachment; filename=\"manual.pdf\"; filename*=UTF-8''manual.pdf"
+        );
+        assert_eq!(
+            content_disposition("Kühlschrank.pdf"),
+            "attachment; filename=\"K_hlschrank.pdf\"; filename*=UTF-8''K%C3%BChlschrank.pdf"
+        );
+    }
+}
@@ -1,8 +1,18 @@
 use actix_web::{web, HttpResponse, Result};
-use shared::{ApiError, ApiSuccess, AuthResponse, CreateUserRequest, LoginRequest, RefreshTokenRequest};
+use serde::Deserialize;
+use shared::{
+    ApiError, ApiSuccess, AuthResponse, CreateUserRequest, ForgotPasswordRequest, LoginRequest,
+    OidcAuthorizationUrl, OidcCodeExchangeRequest, OidcProviderInfo, RefreshTokenRequest,
+    ResetPasswordRequest, User,
+};
 
+use crate::handlers::openapi::{BadRequest, Forbidden, NotFound, Unauthorized};
+use crate::middleware::auth::is_api_token_request;
+use crate::middleware::client_ip;
 use crate::models::AppState;
 use crate::services::auth as auth_service;
+use crate::services::mail::{self as mail_service, MailSettings};
+use crate::services::oidc::{self as oidc_service, OidcOutcome, OidcSettings};
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -11,10 +21,26 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route("/login", web::post().to(login))
             .route("/refresh", web::post().to(refresh))
             .route("/logout", web::post().to(logout))
+            .route("/forgot-password", web::post().to(forgot_password))
+            .route("/reset-password", web::post().to(reset_password))
             .route("/me", web::get().to(get_current_user))
+            .route("/oidc/config", web::get().to(oidc_config))
+            .route("/oidc/login", web::get().to(oidc_login))
+            .route("/oidc/link", web::post().to(oidc_link))
+            .route("/oidc/callback", web::get().to(oidc_callback))
+            .route("/oidc/exchange", web::post().to(oidc_exchange))
     );
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/auth/register",
+    tag = "auth",
+    summary = "Register",
+    request_body = CreateUserRequest,
+    responses((status = 201, description = "Created", body = ApiSuccess<AuthResponse>), BadRequest),
+    security(()),
+)]
 async fn register(
     state: web::Data<AppState>,
     body: web::Json<CreateUserRequest>,
@@ -88,6 +114,15 @@ async fn register(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/auth/login",
+    tag = "auth",
+    summary = "Login",
+    request_body = LoginRequest,
+    responses((status = 200, description = "Success", body = ApiSuccess<AuthResponse>), BadRequest),
+    security(()),
+)]
 async fn login(
     state: web::Data<AppState>,
     body: web::Json<LoginRequest>,
@@ -96,11 +131,7 @@ async fn login(
     let request = body.into_inner();
 
     // Get client IP for rate limiting
-    let client_ip = req
-        .connection_info()
-        .realip_remote_addr()
-        .unwrap_or("unknown")
-        .to_string();
+    let client_ip = client_ip(&req, &state.config);
 
     // Check rate limit
     if !state.login_rate_limiter.check(&client_ip) {
@@ -110,8 +141,31 @@ async fn login(
         }));
     }
 
+    // A PIN has few possible values, so child accounts also lock after too many
+    // failed attempts from any address
+    let child_key = match auth_service::child_account_id(&state.db, &request.username).await {
+        Ok(child_id) => child_id.map(|id| format!("child-login:{}", id)),
+        Err(e) => {
+            log::error!("Login error: {:?}", e);
+            return Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to log in".to_string(),
+            }));
+        }
+    };
+    if child_key.as_ref().is_some_and(|key| !state.login_rate_limiter.check(key)) {
+        return Ok(HttpResponse::TooManyRequests().json(ApiError {
+            error: "account_locked".to_string(),
+            message: "This account is locked after too many wrong PINs. Please try again later.".to_string(),
+        }));
+    }
+
     match auth_service::login_user(&state.db, &request).await {
         Ok(user) => {
+            if let Some(key) = &child_key {
+                state.login_rate_limiter.clear(key);
+            }
+
             // Create access token
             let token = match auth_service::create_access_token(
                 &user.id,
@@ -152,9 +206,13 @@ async fn login(
                 user,
             })))
         }
+        Err(auth_service::AuthError::AccountDisabled) => Ok(account_disabled()),
         Err(e) => {
             // Record failed attempt for rate limiting
             state.login_rate_limiter.record(&client_ip);
+            if let Some(key) = &child_key {
+                state.login_rate_limiter.record(key);
+            }
 
             log::error!("Login error: {:?}", e);
             Ok(HttpResponse::Unauthorized().json(ApiError {
@@ -165,6 +223,15 @@ async fn login(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/auth/refresh",
+    tag = "auth",
+    summary = "Refresh",
+    request_body = RefreshTokenRequest,
+    responses((status = 200, description = "Success", body = ApiSuccess<AuthResponse>), BadRequest),
+    security(()),
+)]
 async fn refresh(
     state: web::Data<AppState>,
     body: web::Json<RefreshTokenRequest>,
@@ -199,6 +266,7 @@ async fn refresh(
                 message: "Refresh token has expired".to_string(),
             }))
         }
+        Err(auth_service::AuthError::AccountDisabled) => Ok(account_disabled()),
         Err(e) => {
             log::error!("Refresh token error: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
@@ -209,6 +277,15 @@ async fn refresh(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/auth/logout",
+    tag = "auth",
+    summary = "Logout",
+    request_body = RefreshTokenRequest,
+    responses((status = 200, description = "Success", body = ApiSuccess<String>), BadRequest),
+    security(()),
+)]
 async fn logout(
     state: web::Data<AppState>,
     body: web::Json<RefreshTokenRequest>,
@@ -224,6 +301,116 @@ async fn logout(
     Ok(HttpResponse::Ok().json(ApiSuccess::new("Logged out successfully")))
 }
 
+/// Email a password reset link. Always answers with success for unknown emails
+/// so the endpoint can't be used to find out which addresses have an account.
+#[utoipa::path(
+    post,
+    path = "/api/auth/forgot-password",
+    tag = "auth",
+    summary = "Forgot password",
+    description = "Email a password reset link. Always answers with success for unknown emails so the endpoint can't be used to find out which addresses have an account.",
+    request_body = ForgotPasswordRequest,
+    responses((status = 200, description = "Success", body = ApiSuccess<String>), BadRequest),
+    security(()),
+)]
+async fn forgot_password(
+    state: web::Data<AppState>,
+    body: web::Json<ForgotPasswordRequest>,
+    req: actix_web::HttpRequest,
+) -> Result<HttpResponse> {
+    let Some(settings) = MailSettings::from_config(&state.config) else {
+        return Ok(HttpResponse::ServiceUnavailable().json(ApiError {
+            error: "email_disabled".to_string(),
+            message: "Password reset by email is not available on this server".to_string(),
+        }));
+    };
+
+    // Reuse the login limiter so the endpoint can't be used to flood an inbox
+    let rate_limit_key = format!("forgot-password:{}", client_ip(&req, &state.config));
+    if !state.login_rate_limiter.check(&rate_limit_key) {
+        return Ok(HttpResponse::TooManyRequests().json(ApiError {
+            error: "rate_limited".to_string(),
+            message: "Too many password reset requests. Please try again later.".to_string(),
+        }));
+    }
+    state.login_rate_limiter.record(&rate_limit_key);
+
+    let request = body.into_inner();
+    match auth_service::create_password_reset_token(&state.db, &request.email).await {
+        Ok(Some((user, token))) => {
+            let mail = mail_service::password_reset_mail(
+                &settings,
+                &user.email,
+                &user.username,
+                &token,
+                auth_service::PASSWORD_RESET_TTL_MINUTES,
+            );
+            mail_service::send_in_background(settings, mail);
+        }
+        Ok(None) => {}
+        Err(e) => {
+            log::error!("Password reset error: {:?}", e);
+            return Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to start password reset".to_string(),
+            }));
+        }
+    }
+
+    Ok(HttpResponse::Ok().json(ApiSuccess::new("If an account uses this email, a reset link has been sent")))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/reset-password",
+    tag = "auth",
+    summary = "Reset password",
+    request_body = ResetPasswordRequest,
+    responses((status = 200, description = "Success", body = ApiSuccess<String>), BadRequest),
+    security(()),
+)]
+async fn reset_password(
+    state: web::Data<AppState>,
+    body: web::Json<ResetPasswordRequest>,
+) -> Result<HttpResponse> {
+    let request = body.into_inner();
+
+    if request.new_password.len() < 8 {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "Password must be at least 8 characters".to_string(),
+        }));
+    }
+
+    match auth_service::reset_password(&state.db, &request.token, &request.new_password).await {
+        Ok(_) => Ok(HttpResponse::Ok().json(ApiSuccess::new("Password has been reset"))),
+        Err(auth_service::AuthError::InvalidResetToken) => {
+            Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_token".to_string(),
+                message: "The reset link is invalid or has expired".to_string(),
+            }))
+        }
+        Err(e) => {
+            log::error!("Password reset error: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to reset password".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/auth/me",
+    tag = "auth",
+    summary = "Get current user",
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<User>),
+        Unauthorized,
+        NotFound,
+    ),
+)]
 async fn get_current_user(
     state: web::Data<AppState>,
     req: actix_web::HttpRequest,
@@ -253,3 +440,276 @@ async fn get_current_user(
         }
     }
 }
+
+fn oidc_not_configured() -> HttpResponse {
+    HttpResponse::NotFound().json(ApiError {
+        error: "oidc_disabled".to_string(),
+        message: "OIDC login is not configured".to_string(),
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/auth/oidc/config",
+    tag = "auth",
+    summary = "OIDC config",
+    responses((status = 200, description = "Success", body = ApiSuccess<OidcProviderInfo>)),
+    security(()),
+)]
+async fn oidc_config(state: web::Data<AppState>) -> Result<HttpResponse> {
+    let settings = OidcSettings::from_config(&state.config);
+
+    Ok(HttpResponse::Ok().json(ApiSuccess::new(OidcProviderInfo {
+        enabled: settings.is_some(),
+        provider_name: state.config.oidc_provider_name.clone(),
+    })))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/auth/oidc/login",
+    tag = "auth",
+    summary = "OIDC login",
+    responses((status = 200, description = "Success", body = ApiSuccess<OidcAuthorizationUrl>)),
+    security(()),
+)]
+async fn oidc_login(state: web::Data<AppState>) -> Result<HttpResponse> {
+    let Some(settings) = OidcSettings::from_config(&state.config) else {
+        return Ok(oidc_not_configured());
+    };
+
+    let client = reqwest::Client::new();
+    match oidc_service::begin_authorization(&state.db, &client, &settings, None).await {
+        Ok(url) => Ok(HttpResponse::Ok().json(ApiSuccess::new(OidcAuthorizationUrl { url }))),
+        Err(e) => {
+            log::error!("OIDC login error: {:?}", e);
+            Ok(HttpResponse::BadGateway().json(ApiError {
+                error: "oidc_error".to_string(),
+                message: "Failed to contact the identity provider".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/oidc/link",
+    tag = "auth",
+    summary = "OIDC link",
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<OidcAuthorizationUrl>),
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn oidc_link(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+) -> Result<HttpResponse> {
+    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::Unauthorized().json(ApiError {
+                error: "unauthorized".to_string(),
+                message: "Invalid or missing token".to_string(),
+            }));
+        }
+    };
+
+    // A leaked API token must not be enough to take over the account
+    if is_api_token_request(&req) {
+        return Ok(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "API tokens cannot link identity providers".to_string(),
+        }));
+    }
+
+    let Some(settings) = OidcSettings::from_config(&state.config) else {
+        return Ok(oidc_not_configured());
+    };
+
+    let client = reqwest::Client::new();
+    match oidc_service::begin_authorization(&state.db, &client, &settings, Some(&user_id)).await {
+        Ok(url) => Ok(HttpResponse::Ok().json(ApiSuccess::new(OidcAuthorizationUrl { url }))),
+        Err(e) => {
+            log::error!("OIDC link error: {:?}", e);
+            Ok(HttpResponse::BadGateway().json(ApiError {
+                error: "oidc_error".to_string(),
+                message: "Failed to contact the identity provider".to_string(),
+            }))
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+struct OidcCallbackQuery {
+    code: Option<String>,
+    state: Option<String>,
+    error: Option<String>,
+}
+
+fn redirect_to(location: &str) -> HttpResponse {
+    HttpResponse::Found()
+        .insert_header((actix_web::http::header::LOCATION, location.to_string()))
+        .finish()
+}
+
+/// Provider redirect target. Sends the browser back to the frontend with either
+/// a one-time login code or an error reason.
+#[utoipa::path(
+    get,
+    path = "/api/auth/oidc/callback",
+    tag = "auth",
+    summary = "OIDC callback",
+    description = "Provider redirect target. Sends the browser back to the frontend with either a one-time login code or an error reason.",
+    params(OidcCallbackQuery),
+    responses((status = 302, description = "Redirect back to the frontend")),
+    security(()),
+)]
+async fn oidc_callback(
+    state: web::Data<AppState>,
+    query: web::Query<OidcCallbackQuery>,
+) -> Result<HttpResponse> {
+    let Some(settings) = OidcSettings::from_config(&state.config) else {
+        return Ok(oidc_not_configured());
+    };
+
+    let query = query.into_inner();
+    let (code, auth_state) = match (query.code, query.state, query.error) {
+        (Some(code), Some(auth_state), None) => (code, auth_state),
+        _ => return Ok(redirect_to("/login?oidc_error=access_denied")),
+    };
+
+    let client = reqwest::Client::new();
+    match oidc_service::complete_authorization(&state.db, &client, &settings, &code, &auth_state).await {
+        Ok(OidcOutcome::LoggedIn(user)) => match oidc_service::create_login_code(&state.db, &user.id).await {
+            Ok(login_code) => Ok(redirect_to(&format!("/login?oidc_code={}", login_code))),
+            Err(e) => {
+                log::error!("OIDC login code error: {:?}", e);
+                Ok(redirect_to("/login?oidc_error=login_failed"))
+            }
+        },
+        Ok(OidcOutcome::Linked(_)) => Ok(redirect_to("/user-settings?oidc=linked")),
+        Err(oidc_service::OidcError::InvalidState) => Ok(redirect_to("/login?oidc_error=invalid_state")),
+        Err(oidc_service::OidcError::AlreadyLinked) => Ok(redirect_to("/login?oidc_error=already_linked")),
+        Err(oidc_service::OidcError::EmailInUse) => Ok(redirect_to("/login?oidc_error=email_in_use")),
+        Err(e) => {
+            log::error!("OIDC callback error: {:?}", e);
+            Ok(redirect_to("/login?oidc_error=login_failed"))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/oidc/exchange",
+    tag = "auth",
+    summary = "OIDC exchange",
+    request_body = OidcCodeExchangeRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<AuthResponse>),
+        BadRequest,
+        Forbidden,
+    ),
+    security(()),
+)]
+async fn oidc_exchange(
+    state: web::Data<AppState>,
+    body: web::Json<OidcCodeExchangeRequest>,
+) -> Result<HttpResponse> {
+    let request = body.into_inner();
+
+    let user_id = match oidc_service::redeem_login_code(&state.db, &request.code).await {
+        Ok(id) => id,
+        Err(oidc_service::OidcError::InvalidLoginCode) => {
+            return Ok(HttpResponse::Unauthorized().json(ApiError {
+                error: "invalid_code".to_string(),
+                message: "Invalid or expired login code".to_string(),
+            }));
+        }
+        Err(e) => {
+            log::error!("OIDC code exchange error: {:?}", e);
+            return Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to exchange login code".to_string(),
+            }));
+        }
+    };
+
+    let user = match auth_service::get_user_by_id(&state.db, &user_id).await {
+        Ok(Some(user)) => user,
+        Ok(None) => {
+            return Ok(HttpResponse::Unauthorized().json(ApiError {
+                error: "invalid_code".to_string(),
+                message: "Invalid or expired login code".to_string(),
+            }));
+        }
+        Err(e) => {
+            log::error!("Error fetching user: {:?}", e);
+            return Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch user".to_string(),
+            }));
+        }
+    };
+
+    match auth_service::ensure_account_enabled(&state.db, &user.id).await {
+        Ok(()) => {}
+        Err(auth_service::AuthError::AccountDisabled) => return Ok(account_disabled()),
+        Err(e) => {
+            log::error!("Error checking account status: {:?}", e);
+            return Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch user".to_string(),
+            }));
+        }
+    }
+
+    // Create access token
+    let token = match auth_service::create_access_token(
+        &user.id,
+        &state.config.jwt_secret,
+        state.config.access_token_expiration_minutes,
+    ) {
+        Ok(t) => t,
+        Err(e) => {
+            log::error!("JWT creation error: {:?}", e);
+            return Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "jwt_error".to_string(),
+                message: "Failed to create token".to_string(),
+            }));
+        }
+    };
+
+    // Create refresh token
+    let refresh_token = match auth_service::create_refresh_token(
+        &state.db,
+        &user.id,
+        state.config.refresh_token_expiration_days,
+    )
+    .await
+    {
+        Ok(t) => t,
+        Err(e) => {
+            log::error!("Refresh token creation error: {:?}", e);
+            return Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "token_error".to_string(),
+                message: "Failed to create refresh token".to_string(),
+            }));
+        }
+    };
+
+    Ok(HttpResponse::Ok().json(ApiSuccess::new(AuthResponse {
+        token,
+        refresh_token,
+        user,
+    })))
+}
+
+fn account_disabled() -> HttpResponse {
+    HttpResponse::Forbidden().json(ApiError {
+        error: "account_disabled".to_string(),
+        message: "This account has been disabled".to_string(),
+    })
+}
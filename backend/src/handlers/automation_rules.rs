@@ -0,0 +1,242 @@
+use actix_web::{web, HttpResponse, Result};
+use shared::{
+    ApiError, ApiSuccess, AutomationRule, AutomationRuleRun, CreateAutomationRuleRequest,
+    HouseholdFeature, Permission, UpdateAutomationRuleRequest,
+};
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::automation_rules as rule_service;
+
+/// Number of audit trail entries returned
+const RUN_HISTORY_LIMIT: i64 = 100;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/automation-rules")
+            .route("", web::get().to(list_rules))
+            .route("", web::post().to(create_rule))
+            // Static routes must come before dynamic /{rule_id} routes
+            .route("/runs", web::get().to(list_runs))
+            .route("/{rule_id}", web::put().to(update_rule))
+            .route("/{rule_id}", web::delete().to(delete_rule)),
+    );
+}
+
+/// Automation rules need permission to manage rewards and punishments
+async fn authorize_manager(state: &AppState, ctx: &HouseholdContext) -> std::result::Result<(), HttpResponse> {
+    if !ctx.has_permission(state, Permission::ManageRewards).await {
+        return Err(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "You do not have permission to manage automation rules".to_string(),
+        }));
+    }
+
+    Ok(())
+}
+
+fn parse_rule_id(id: &str) -> std::result::Result<Uuid, HttpResponse> {
+    Uuid::parse_str(id).map_err(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_id".to_string(),
+            message: "Invalid rule ID format".to_string(),
+        })
+    })
+}
+
+fn rule_error_response(error: rule_service::AutomationRuleError, context: &str) -> HttpResponse {
+    use rule_service::AutomationRuleError;
+
+    match error {
+        AutomationRuleError::NotFound => HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: error.to_string(),
+        }),
+        AutomationRuleError::EmptyName
+        | AutomationRuleError::InvalidThreshold
+        | AutomationRuleError::InvalidAction
+        | AutomationRuleError::InvalidTask
+        | AutomationRuleError::InvalidReward
+        | AutomationRuleError::InvalidPunishment => HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: error.to_string(),
+        }),
+        AutomationRuleError::DatabaseError(e) => {
+            log::error!("Error {}: {:?}", context, e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: format!("Failed {}", context),
+            })
+        }
+    }
+}
+
+/// Make sure a rule exists and belongs to the household from the path
+async fn check_rule_in_household(
+    state: &AppState,
+    household_id: &Uuid,
+    rule_id: &Uuid,
+) -> std::result::Result<(), HttpResponse> {
+    match rule_service::get_rule(&state.db, rule_id).await {
+        Ok(Some(rule)) if rule.household_id == *household_id => Ok(()),
+        Ok(_) => Err(rule_error_response(rule_service::AutomationRuleError::NotFound, "loading rule")),
+        Err(e) => Err(rule_error_response(e, "loading rule")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/automation-rules",
+    tag = "automation_rules",
+    summary = "List rules",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<AutomationRule>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_rules(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
+    match rule_service::list_rules(&state.db, &ctx.household_id).await {
+        Ok(rules) => Ok(HttpResponse::Ok().json(ApiSuccess::new(rules))),
+        Err(e) => Ok(rule_error_response(e, "listing rules")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/automation-rules",
+    tag = "automation_rules",
+    summary = "Create rule",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreateAutomationRuleRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<AutomationRule>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn create_rule(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    body: web::Json<CreateAutomationRuleRequest>,
+) -> Result<HttpResponse> {
+    if let Err(response) = authorize_manager(&state, &ctx).await {
+        return Ok(response);
+    }
+
+    if (body.reward_id.is_some() && !ctx.settings.is_feature_enabled(HouseholdFeature::Rewards))
+        || (body.punishment_id.is_some() && !ctx.settings.is_feature_enabled(HouseholdFeature::Punishments))
+    {
+        return Ok(HttpResponse::Forbidden().json(ApiError {
+            error: "feature_disabled".to_string(),
+            message: "Rewards or punishments are not enabled for this household".to_string(),
+        }));
+    }
+
+    match rule_service::create_rule(&state.db, &ctx.household_id, &ctx.user_id, &body).await {
+        Ok(rule) => Ok(HttpResponse::Created().json(ApiSuccess::new(rule))),
+        Err(e) => Ok(rule_error_response(e, "creating rule")),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/automation-rules/{rule_id}",
+    tag = "automation_rules",
+    summary = "Update rule",
+    params(("household_id" = Uuid, Path), ("rule_id" = Uuid, Path)),
+    request_body = UpdateAutomationRuleRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<AutomationRule>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn update_rule(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+    body: web::Json<UpdateAutomationRuleRequest>,
+) -> Result<HttpResponse> {
+    let (_, rule_id_str) = path.into_inner();
+    if let Err(response) = authorize_manager(&state, &ctx).await {
+        return Ok(response);
+    }
+    let rule_id = match parse_rule_id(&rule_id_str) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+    if let Err(response) = check_rule_in_household(&state, &ctx.household_id, &rule_id).await {
+        return Ok(response);
+    }
+
+    match rule_service::update_rule(&state.db, &rule_id, &body).await {
+        Ok(rule) => Ok(HttpResponse::Ok().json(ApiSuccess::new(rule))),
+        Err(e) => Ok(rule_error_response(e, "updating rule")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/automation-rules/{rule_id}",
+    tag = "automation_rules",
+    summary = "Delete rule",
+    params(("household_id" = Uuid, Path), ("rule_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn delete_rule(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, rule_id_str) = path.into_inner();
+    if let Err(response) = authorize_manager(&state, &ctx).await {
+        return Ok(response);
+    }
+    let rule_id = match parse_rule_id(&rule_id_str) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+    if let Err(response) = check_rule_in_household(&state, &ctx.household_id, &rule_id).await {
+        return Ok(response);
+    }
+
+    match rule_service::delete_rule(&state.db, &rule_id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(rule_error_response(e, "deleting rule")),
+    }
+}
+
+/// Audit trail of fired rules, newest first
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/automation-rules/runs",
+    tag = "automation_rules",
+    summary = "Audit trail of fired rules, newest first",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<AutomationRuleRun>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_runs(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
+    match rule_service::list_runs(&state.db, &ctx.household_id, RUN_HISTORY_LIMIT).await {
+        Ok(runs) => Ok(HttpResponse::Ok().json(ApiSuccess::new(runs))),
+        Err(e) => Ok(rule_error_response(e, "listing rule runs")),
+    }
+}
@@ -0,0 +1,276 @@
+//! Handlers for the iCalendar task feed and the in-app task calendar
+//!
+//! The feed can be fetched with a regular Bearer token or, for calendar clients that
+//! cannot send headers, with the user's personal feed token as `?token=` query parameter.
+
+use actix_web::{web, HttpResponse, Result};
+use chrono::{NaiveDate, Utc};
+use serde::Deserialize;
+use shared::{ApiError, ApiSuccess, CalendarFeedToken, TaskOccurrence};
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, Forbidden, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::{
+    calendar as calendar_service, household_settings as settings_service,
+    households as household_service, tasks as task_service,
+};
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CalendarFeedQuery {
+    token: Option<String>,
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct OccurrencesQuery {
+    from: NaiveDate,
+    to: NaiveDate,
+}
+
+/// Registers the calendar routes. Must be configured before `tasks::configure` so that
+/// `calendar.ics` and `occurrences` are not captured by the `/tasks/{task_id}` route.
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/tasks/calendar.ics", web::get().to(get_calendar_feed))
+        .route("/tasks/occurrences", web::get().to(get_task_occurrences));
+}
+
+/// Due dates of the household's tasks between `from` and `to` (inclusive),
+/// with the completions recorded for each
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/tasks/occurrences",
+    tag = "calendar",
+    summary = "Get task occurrences",
+    description = "Due dates of the household's tasks between `from` and `to` (inclusive), with the completions recorded for each",
+    params(("household_id" = Uuid, Path), OccurrencesQuery),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<TaskOccurrence>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn get_task_occurrences(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    query: web::Query<OccurrencesQuery>,
+) -> Result<HttpResponse> {
+    let OccurrencesQuery { from, to } = query.into_inner();
+    if to < from || (to - from).num_days() >= calendar_service::MAX_OCCURRENCE_RANGE_DAYS {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: format!(
+                "`to` must not be before `from`, and the range may span at most {} days",
+                calendar_service::MAX_OCCURRENCE_RANGE_DAYS
+            ),
+        }));
+    }
+
+    let tasks = match task_service::list_tasks(&state.db, &ctx.household_id).await {
+        Ok(tasks) => tasks,
+        Err(e) => {
+            log::error!("Error listing tasks for occurrences: {:?}", e);
+            return Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to list task occurrences".to_string(),
+            }));
+        }
+    };
+
+    match calendar_service::list_occurrences(&state.db, &ctx.household_id, &tasks, from, to).await {
+        Ok(occurrences) => Ok(HttpResponse::Ok().json(ApiSuccess::new(occurrences))),
+        Err(e) => {
+            log::error!("Error listing task occurrences: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to list task occurrences".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/tasks/calendar.ics",
+    tag = "calendar",
+    summary = "Get calendar feed",
+    params(("household_id" = Uuid, Path), CalendarFeedQuery),
+    responses(
+        (status = 200, description = "Success", content_type = "text/calendar", body = String),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn get_calendar_feed(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<CalendarFeedQuery>,
+) -> Result<HttpResponse> {
+    let user_id = match query.token.as_deref() {
+        Some(token) => match calendar_service::get_user_id_by_feed_token(&state.db, token).await {
+            Ok(id) => id,
+            Err(calendar_service::CalendarError::InvalidToken) => {
+                return Ok(HttpResponse::Unauthorized().json(ApiError {
+                    error: "unauthorized".to_string(),
+                    message: "Invalid feed token".to_string(),
+                }));
+            }
+            Err(e) => {
+                log::error!("Error resolving feed token: {:?}", e);
+                return Ok(HttpResponse::InternalServerError().json(ApiError {
+                    error: "internal_error".to_string(),
+                    message: "Failed to resolve feed token".to_string(),
+                }));
+            }
+        },
+        None => match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
+            Ok(id) => id,
+            Err(_) => {
+                return Ok(HttpResponse::Unauthorized().json(ApiError {
+                    error: "unauthorized".to_string(),
+                    message: "Invalid or missing token".to_string(),
+                }));
+            }
+        },
+    };
+
+    let household_id = match Uuid::parse_str(&path.into_inner()) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_id".to_string(),
+                message: "Invalid household ID format".to_string(),
+            }));
+        }
+    };
+
+    // Check membership
+    if !household_service::is_member(&state.db, &household_id, &user_id)
+        .await
+        .unwrap_or(false)
+    {
+        return Ok(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "You are not a member of this household".to_string(),
+        }));
+    }
+
+    let household = match household_service::get_household(&state.db, &household_id).await {
+        Ok(Some(household)) => household,
+        Ok(None) => {
+            return Ok(HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: "Household not found".to_string(),
+            }));
+        }
+        Err(e) => {
+            log::error!("Error fetching household: {:?}", e);
+            return Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch household".to_string(),
+            }));
+        }
+    };
+
+    let (timezone, week_start_day) = settings_service::get_or_create_settings(&state.db, &household_id)
+        .await
+        .map(|s| (s.timezone, s.week_start_day))
+        .unwrap_or_else(|_| ("UTC".to_string(), 0));
+
+    match task_service::list_tasks(&state.db, &household_id).await {
+        Ok(tasks) => {
+            let body = calendar_service::render_ical_feed(&household.name, &tasks, &timezone, week_start_day, Utc::now());
+            Ok(HttpResponse::Ok()
+                .content_type("text/calendar; charset=utf-8")
+                .insert_header(("Content-Disposition", "inline; filename=\"calendar.ics\""))
+                .body(body))
+        }
+        Err(e) => {
+            log::error!("Error listing tasks for calendar feed: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to render calendar feed".to_string(),
+            }))
+        }
+    }
+}
+
+/// Get the current user's feed token (created on first access)
+#[utoipa::path(
+    get,
+    path = "/api/users/me/calendar-token",
+    tag = "calendar",
+    summary = "Get the current user's feed token (created on first access)",
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<CalendarFeedToken>),
+        Unauthorized,
+    ),
+)]
+pub async fn get_feed_token(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+) -> Result<HttpResponse> {
+    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::Unauthorized().json(ApiError {
+                error: "unauthorized".to_string(),
+                message: "Invalid or missing token".to_string(),
+            }));
+        }
+    };
+
+    match calendar_service::get_or_create_feed_token(&state.db, &user_id).await {
+        Ok(token) => Ok(HttpResponse::Ok().json(ApiSuccess::new(token))),
+        Err(e) => {
+            log::error!("Error fetching calendar feed token: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch calendar feed token".to_string(),
+            }))
+        }
+    }
+}
+
+/// Replace the current user's feed token, revoking existing subscriptions
+#[utoipa::path(
+    post,
+    path = "/api/users/me/calendar-token",
+    tag = "calendar",
+    summary = "Replace the current user's feed token, revoking existing subscriptions",
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<CalendarFeedToken>),
+        Unauthorized,
+    ),
+)]
+pub async fn regenerate_feed_token(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+) -> Result<HttpResponse> {
+    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::Unauthorized().json(ApiError {
+                error: "unauthorized".to_string(),
+                message: "Invalid or missing token".to_string(),
+            }));
+        }
+    };
+
+    match calendar_service::regenerate_feed_token(&state.db, &user_id).await {
+        Ok(token) => Ok(HttpResponse::Ok().json(ApiSuccess::new(token))),
+        Err(e) => {
+            log::error!("Error regenerating calendar feed token: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to regenerate calendar feed token".to_string(),
+            }))
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use actix_web::{web, HttpResponse, Result};
+use shared::{ActivityType, ApiError, ApiSuccess, ChallengeWithStandings, CreateChallengeRequest, Permission};
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::{activity_logs as activity_log_service, challenges as challenge_service};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/challenges")
+            .route("", web::get().to(list_challenges))
+            .route("", web::post().to(create_challenge))
+            .route("/{challenge_id}", web::get().to(get_challenge))
+            .route("/{challenge_id}", web::delete().to(delete_challenge))
+            .route("/{challenge_id}/close", web::post().to(close_challenge)),
+    );
+}
+
+/// Challenges can be managed by anyone with permission to manage tasks
+async fn authorize_manager(state: &AppState, ctx: &HouseholdContext) -> std::result::Result<(), HttpResponse> {
+    if !ctx.has_permission(state, Permission::ManageTasks).await {
+        return Err(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "You do not have permission to manage challenges".to_string(),
+        }));
+    }
+
+    Ok(())
+}
+
+fn parse_challenge_id(id: &str) -> std::result::Result<Uuid, HttpResponse> {
+    Uuid::parse_str(id).map_err(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_id".to_string(),
+            message: "Invalid challenge ID format".to_string(),
+        })
+    })
+}
+
+fn challenge_error_response(error: challenge_service::ChallengeError, context: &str) -> HttpResponse {
+    use challenge_service::ChallengeError;
+
+    match error {
+        ChallengeError::NotFound => HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Challenge not found".to_string(),
+        }),
+        ChallengeError::AlreadyClosed => HttpResponse::Conflict().json(ApiError {
+            error: "already_closed".to_string(),
+            message: error.to_string(),
+        }),
+        ChallengeError::EmptyTitle
+        | ChallengeError::InvalidDateRange
+        | ChallengeError::InvalidCategory
+        | ChallengeError::InvalidReward => HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: error.to_string(),
+        }),
+        ChallengeError::DatabaseError(e) => {
+            log::error!("Error {}: {:?}", context, e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: format!("Failed {}", context),
+            })
+        }
+    }
+}
+
+/// Load a challenge and make sure it belongs to the household from the path
+async fn load_challenge(
+    state: &AppState,
+    household_id: &Uuid,
+    challenge_id: &Uuid,
+) -> std::result::Result<shared::Challenge, HttpResponse> {
+    match challenge_service::get_challenge(&state.db, challenge_id).await {
+        Ok(Some(challenge)) if challenge.household_id == *household_id => Ok(challenge),
+        Ok(_) => Err(challenge_error_response(
+            challenge_service::ChallengeError::NotFound,
+            "loading challenge",
+        )),
+        Err(e) => Err(challenge_error_response(e, "loading challenge")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/challenges",
+    tag = "challenges",
+    summary = "List challenges",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<ChallengeWithStandings>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_challenges(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
+    let challenges = match challenge_service::list_challenges(&state.db, &ctx.household_id).await {
+        Ok(challenges) => challenges,
+        Err(e) => return Ok(challenge_error_response(e, "listing challenges")),
+    };
+
+    let mut result = Vec::with_capacity(challenges.len());
+    for challenge in challenges {
+        match challenge_service::with_standings(&state.db, challenge).await {
+            Ok(entry) => result.push(entry),
+            Err(e) => return Ok(challenge_error_response(e, "loading challenge standings")),
+        }
+    }
+
+    Ok(HttpResponse::Ok().json(ApiSuccess::new(result)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/challenges",
+    tag = "challenges",
+    summary = "Create challenge",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreateChallengeRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<ChallengeWithStandings>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn create_challenge(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    body: web::Json<CreateChallengeRequest>,
+) -> Result<HttpResponse> {
+    if let Err(response) = authorize_manager(&state, &ctx).await {
+        return Ok(response);
+    }
+
+    let challenge = match challenge_service::create_challenge(&state.db, &ctx.household_id, &ctx.user_id, &body).await {
+        Ok(challenge) => challenge,
+        Err(e) => return Ok(challenge_error_response(e, "creating challenge")),
+    };
+
+    let details = serde_json::json!({ "name": challenge.title }).to_string();
+    let _ = activity_log_service::log_activity(
+        &state.db,
+        &ctx.household_id,
+        &ctx.user_id,
+        None,
+        ActivityType::ChallengeCreated,
+        Some("challenge"),
+        Some(&challenge.id),
+        Some(&details),
+    )
+    .await;
+
+    match challenge_service::with_standings(&state.db, challenge).await {
+        Ok(entry) => Ok(HttpResponse::Created().json(ApiSuccess::new(entry))),
+        Err(e) => Ok(challenge_error_response(e, "loading challenge standings")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/challenges/{challenge_id}",
+    tag = "challenges",
+    summary = "Get challenge",
+    params(("household_id" = Uuid, Path), ("challenge_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<ChallengeWithStandings>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn get_challenge(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, challenge_id_str) = path.into_inner();
+        let challenge_id = match parse_challenge_id(&challenge_id_str) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+    let challenge = match load_challenge(&state, &ctx.household_id, &challenge_id).await {
+        Ok(challenge) => challenge,
+        Err(response) => return Ok(response),
+    };
+
+    match challenge_service::with_standings(&state.db, challenge).await {
+        Ok(entry) => Ok(HttpResponse::Ok().json(ApiSuccess::new(entry))),
+        Err(e) => Ok(challenge_error_response(e, "loading challenge standings")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/challenges/{challenge_id}",
+    tag = "challenges",
+    summary = "Delete challenge",
+    params(("household_id" = Uuid, Path), ("challenge_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn delete_challenge(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, challenge_id_str) = path.into_inner();
+    if let Err(response) = authorize_manager(&state, &ctx).await {
+        return Ok(response);
+    }
+    let challenge_id = match parse_challenge_id(&challenge_id_str) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+    if let Err(response) = load_challenge(&state, &ctx.household_id, &challenge_id).await {
+        return Ok(response);
+    }
+
+    match challenge_service::delete_challenge(&state.db, &challenge_id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(challenge_error_response(e, "deleting challenge")),
+    }
+}
+
+/// Close a challenge early; winners are determined from the standings so far
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/challenges/{challenge_id}/close",
+    tag = "challenges",
+    summary = "Close a challenge early; winners are determined from the standings so far",
+    params(("household_id" = Uuid, Path), ("challenge_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<ChallengeWithStandings>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn close_challenge(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, challenge_id_str) = path.into_inner();
+    if let Err(response) = authorize_manager(&state, &ctx).await {
+        return Ok(response);
+    }
+    let challenge_id = match parse_challenge_id(&challenge_id_str) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+    if let Err(response) = load_challenge(&state, &ctx.household_id, &challenge_id).await {
+        return Ok(response);
+    }
+
+    let challenge = match challenge_service::close_challenge(&state.db, &challenge_id, chrono::Utc::now()).await {
+        Ok((challenge, _)) => challenge,
+        Err(e) => return Ok(challenge_error_response(e, "closing challenge")),
+    };
+
+    match challenge_service::with_standings(&state.db, challenge).await {
+        Ok(entry) => Ok(HttpResponse::Ok().json(ApiSuccess::new(entry))),
+        Err(e) => Ok(challenge_error_response(e, "loading challenge standings")),
+    }
+}
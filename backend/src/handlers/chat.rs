@@ -1,80 +1,56 @@
 use actix_web::{web, HttpResponse, Result};
-use shared::{ApiError, ApiSuccess, CreateChatMessageRequest, ListChatMessagesRequest, UpdateChatMessageRequest};
+use shared::{
+    ApiError, ApiSuccess, AttachmentEntity, ChatMessageWithUser, ChatReactionRequest,
+    ChatReactionSummary, ChatReadMarker, ChatUnreadCount, CreateChatMessageRequest,
+    ListChatMessagesRequest, MarkChatReadRequest, NotificationKind, UpdateChatMessageRequest,
+};
 use uuid::Uuid;
 
+use crate::handlers::notifications;
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
 use crate::models::AppState;
-use crate::services::{chat as chat_service, household_settings, households as household_service};
+use crate::services::{attachments as attachment_service, chat as chat_service, notifications as notification_service};
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/chat")
             .route("", web::get().to(list_messages))
             .route("", web::post().to(create_message))
+            // Read markers must come before /{message_id}
+            .route("/read", web::get().to(list_read_markers))
+            .route("/read", web::post().to(mark_read))
             .route("/{message_id}", web::put().to(update_message))
-            .route("/{message_id}", web::delete().to(delete_message)),
+            .route("/{message_id}", web::delete().to(delete_message))
+            .route("/{message_id}/replies", web::get().to(list_replies))
+            .route("/{message_id}/reactions", web::post().to(add_reaction))
+            .route("/{message_id}/reactions", web::delete().to(remove_reaction)),
     );
 }
 
 /// List chat messages for a household with pagination
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/chat",
+    tag = "chat",
+    summary = "List chat messages for a household with pagination",
+    params(("household_id" = Uuid, Path), ListChatMessagesRequest),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<ChatMessageWithUser>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn list_messages(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
     query: web::Query<ListChatMessagesRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
-    {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    // Check if chat feature is enabled
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-    if !settings.chat_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Chat is not enabled for this household".to_string(),
-        }));
-    }
-
     let limit = query.limit.unwrap_or(50).min(100);
     let before = query.before.as_ref();
 
-    match chat_service::list_messages(&state.db, &household_id, limit, before).await {
+    match chat_service::list_messages(&state.db, &ctx.household_id, limit, before).await {
         Ok(messages) => Ok(HttpResponse::Ok().json(ApiSuccess::new(messages))),
         Err(e) => {
             log::error!("Error listing chat messages: {:?}", e);
@@ -87,72 +63,59 @@ async fn list_messages(
 }
 
 /// Create a new chat message (REST fallback - prefer WebSocket)
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/chat",
+    tag = "chat",
+    summary = "Create a new chat message (REST fallback - prefer WebSocket)",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreateChatMessageRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<ChatMessageWithUser>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn create_message(
     state: web::Data<AppState>,
+    ctx: HouseholdContext,
     req: actix_web::HttpRequest,
-    path: web::Path<String>,
     body: web::Json<CreateChatMessageRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
+    let request = body.into_inner();
 
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
+    match chat_service::create_message(
+        &state.db,
+        &ctx.household_id,
+        &ctx.user_id,
+        &request.content,
+        request.reply_to_message_id.as_ref(),
+    )
+    .await
     {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    // Check if chat feature is enabled
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-    if !settings.chat_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Chat is not enabled for this household".to_string(),
-        }));
-    }
-
-    let content = body.into_inner().content;
-
-    match chat_service::create_message(&state.db, &household_id, &user_id, &content).await {
         Ok(message) => {
             // Get message with user for response
             match chat_service::get_message_with_user(&state.db, &message.id).await {
                 Ok(Some(msg_with_user)) => {
                     // Broadcast to WebSocket if available
                     if let Some(ws_manager) = req.app_data::<web::Data<std::sync::Arc<crate::services::websocket::WsManager>>>() {
-                        ws_manager.broadcast_new_message(&household_id, msg_with_user.clone()).await;
+                        ws_manager.broadcast_new_message(&ctx.household_id, msg_with_user.clone()).await;
                     }
+                    notifications::notify_members(
+                        &state.db,
+                        state.push.as_ref(),
+                        &ctx.household_id,
+                        &ctx.user_id,
+                        None,
+                        NotificationKind::Chat,
+                        notification_service::build_chat_message(
+                            &ctx.household_id,
+                            &msg_with_user.user.username,
+                            &msg_with_user.message.content,
+                        ),
+                    )
+                    .await;
                     Ok(HttpResponse::Created().json(ApiSuccess::new(msg_with_user)))
                 }
                 _ => Ok(HttpResponse::Created().json(ApiSuccess::new(message))),
@@ -164,6 +127,12 @@ async fn create_message(
                 message: "Message content cannot be empty".to_string(),
             }))
         }
+        Err(chat_service::ChatError::InvalidReply) => {
+            Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "validation_error".to_string(),
+                message: "The message you are replying to does not exist".to_string(),
+            }))
+        }
         Err(e) => {
             log::error!("Error creating chat message: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
@@ -175,33 +144,29 @@ async fn create_message(
 }
 
 /// Update a chat message (only the author can edit)
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/chat/{message_id}",
+    tag = "chat",
+    summary = "Update a chat message (only the author can edit)",
+    params(("household_id" = Uuid, Path), ("message_id" = Uuid, Path)),
+    request_body = UpdateChatMessageRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<ChatMessageWithUser>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn update_message(
     state: web::Data<AppState>,
+    ctx: HouseholdContext,
     req: actix_web::HttpRequest,
     path: web::Path<(String, String)>,
     body: web::Json<UpdateChatMessageRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, message_id_str) = path.into_inner();
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
+    let (_, message_id_str) = path.into_inner();
     let message_id = match Uuid::parse_str(&message_id_str) {
         Ok(id) => id,
         Err(_) => {
@@ -212,45 +177,16 @@ async fn update_message(
         }
     };
 
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
-    {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    // Check if chat feature is enabled
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-    if !settings.chat_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Chat is not enabled for this household".to_string(),
-        }));
-    }
-
     let content = body.into_inner().content;
 
-    match chat_service::update_message(&state.db, &message_id, &user_id, &content).await {
+    match chat_service::update_message(&state.db, &message_id, &ctx.user_id, &content).await {
         Ok(_message) => {
             // Get updated message with user
             match chat_service::get_message_with_user(&state.db, &message_id).await {
                 Ok(Some(msg_with_user)) => {
                     // Broadcast to WebSocket if available
                     if let Some(ws_manager) = req.app_data::<web::Data<std::sync::Arc<crate::services::websocket::WsManager>>>() {
-                        ws_manager.broadcast_message_edited(&household_id, msg_with_user.clone()).await;
+                        ws_manager.broadcast_message_edited(&ctx.household_id, msg_with_user.clone()).await;
                     }
                     Ok(HttpResponse::Ok().json(ApiSuccess::new(msg_with_user)))
                 }
@@ -286,78 +222,293 @@ async fn update_message(
 }
 
 /// Delete a chat message (only the author can delete)
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/chat/{message_id}",
+    tag = "chat",
+    summary = "Delete a chat message (only the author can delete)",
+    params(("household_id" = Uuid, Path), ("message_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
 async fn delete_message(
     state: web::Data<AppState>,
+    ctx: HouseholdContext,
     req: actix_web::HttpRequest,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
+    let (_, message_id_str) = path.into_inner();
+    let message_id = match Uuid::parse_str(&message_id_str) {
         Ok(id) => id,
         Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_id".to_string(),
+                message: "Invalid message ID format".to_string(),
             }));
         }
     };
 
-    let (household_id_str, message_id_str) = path.into_inner();
-    let household_id = match Uuid::parse_str(&household_id_str) {
+    match chat_service::delete_message(&state.db, &message_id, &ctx.user_id).await {
+        Ok(()) => {
+            if let Err(e) = attachment_service::delete_for_entity(&state.db, &state.config.upload_dir, AttachmentEntity::ChatMessage, &message_id).await {
+                log::error!("Error deleting chat message photos: {:?}", e);
+            }
+            // Broadcast to WebSocket if available
+            if let Some(ws_manager) = req.app_data::<web::Data<std::sync::Arc<crate::services::websocket::WsManager>>>() {
+                ws_manager.broadcast_message_deleted(&ctx.household_id, message_id).await;
+            }
+            Ok(HttpResponse::NoContent().finish())
+        }
+        Err(chat_service::ChatError::NotFound) => {
+            Ok(HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: "Message not found".to_string(),
+            }))
+        }
+        Err(chat_service::ChatError::NotAuthorized) => {
+            Ok(HttpResponse::Forbidden().json(ApiError {
+                error: "forbidden".to_string(),
+                message: "You can only delete your own messages".to_string(),
+            }))
+        }
+        Err(e) => {
+            log::error!("Error deleting chat message: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to delete message".to_string(),
+            }))
+        }
+    }
+}
+
+/// List how far each member has read the chat
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/chat/read",
+    tag = "chat",
+    summary = "List how far each member has read the chat",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<ChatReadMarker>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_read_markers(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
+    match chat_service::list_read_markers(&state.db, &ctx.household_id).await {
+        Ok(markers) => Ok(HttpResponse::Ok().json(ApiSuccess::new(markers))),
+        Err(e) => {
+            log::error!("Error listing chat read markers: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to list read markers".to_string(),
+            }))
+        }
+    }
+}
+
+/// Mark messages up to the given one as read
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/chat/read",
+    operation_id = "chat_mark_read",
+    tag = "chat",
+    summary = "Mark messages up to the given one as read",
+    params(("household_id" = Uuid, Path)),
+    request_body = MarkChatReadRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<ChatReadMarker>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn mark_read(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    req: actix_web::HttpRequest,
+    body: web::Json<MarkChatReadRequest>,
+) -> Result<HttpResponse> {
+    match chat_service::mark_read(&state.db, &ctx.household_id, &ctx.user_id, &body.message_id).await {
+        Ok(marker) => {
+            // Broadcast to WebSocket if available
+            if let Some(ws_manager) = req.app_data::<web::Data<std::sync::Arc<crate::services::websocket::WsManager>>>() {
+                ws_manager.broadcast_messages_read(&ctx.household_id, marker.clone()).await;
+            }
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(marker)))
+        }
+        Err(chat_service::ChatError::NotFound) => {
+            Ok(HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: "Message not found".to_string(),
+            }))
+        }
+        Err(e) => {
+            log::error!("Error marking chat as read: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to mark messages as read".to_string(),
+            }))
+        }
+    }
+}
+
+/// Unread chat message counts for every household of the current user
+#[utoipa::path(
+    get,
+    path = "/api/users/me/chat/unread",
+    tag = "chat",
+    summary = "Unread chat message counts for every household of the current user",
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<ChatUnreadCount>>),
+        BadRequest,
+        Unauthorized,
+        NotFound,
+    ),
+)]
+pub async fn get_unread_counts(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+) -> Result<HttpResponse> {
+    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
         Ok(id) => id,
         Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
+            return Ok(HttpResponse::Unauthorized().json(ApiError {
+                error: "unauthorized".to_string(),
+                message: "Invalid or missing token".to_string(),
             }));
         }
     };
 
-    let message_id = match Uuid::parse_str(&message_id_str) {
+    match chat_service::unread_counts(&state.db, &user_id).await {
+        Ok(counts) => Ok(HttpResponse::Ok().json(ApiSuccess::new(counts))),
+        Err(e) => {
+            log::error!("Error counting unread chat messages: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to count unread messages".to_string(),
+            }))
+        }
+    }
+}
+
+/// Resolve the message of a `/{message_id}/...` route and check that it
+/// belongs to the household
+async fn authorize_message_access(
+    state: &AppState,
+    ctx: &HouseholdContext,
+    message_id_str: &str,
+) -> std::result::Result<Uuid, HttpResponse> {
+    let message_id = match Uuid::parse_str(message_id_str) {
         Ok(id) => id,
         Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
+            return Err(HttpResponse::BadRequest().json(ApiError {
                 error: "invalid_id".to_string(),
                 message: "Invalid message ID format".to_string(),
             }));
         }
     };
 
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
-    {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
+    match chat_service::get_message(&state.db, &message_id).await {
+        Ok(Some(message)) if message.household_id == ctx.household_id && !message.is_deleted => Ok(message_id),
+        Ok(_) => Err(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Message not found".to_string(),
+        })),
+        Err(e) => {
+            log::error!("Error fetching chat message: {:?}", e);
+            Err(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch message".to_string(),
+            }))
+        }
     }
+}
 
-    // Check if chat feature is enabled
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
+/// List the replies to a message
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/chat/{message_id}/replies",
+    tag = "chat",
+    summary = "List the replies to a message",
+    params(("household_id" = Uuid, Path), ("message_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<ChatMessageWithUser>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn list_replies(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, message_id_str) = path.into_inner();
+    let message_id = match authorize_message_access(&state, &ctx, &message_id_str).await {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match chat_service::list_replies(&state.db, &message_id).await {
+        Ok(replies) => Ok(HttpResponse::Ok().json(ApiSuccess::new(replies))),
         Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
+            log::error!("Error listing chat replies: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
                 error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
+                message: "Failed to list replies".to_string(),
+            }))
         }
-    };
-    if !settings.chat_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Chat is not enabled for this household".to_string(),
-        }));
     }
+}
 
-    match chat_service::delete_message(&state.db, &message_id, &user_id).await {
-        Ok(()) => {
+/// React to a message with an emoji
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/chat/{message_id}/reactions",
+    tag = "chat",
+    summary = "React to a message with an emoji",
+    params(("household_id" = Uuid, Path), ("message_id" = Uuid, Path)),
+    request_body = ChatReactionRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<ChatReactionSummary>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn add_reaction(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    req: actix_web::HttpRequest,
+    path: web::Path<(String, String)>,
+    body: web::Json<ChatReactionRequest>,
+) -> Result<HttpResponse> {
+    let (_, message_id_str) = path.into_inner();
+    let message_id = match authorize_message_access(&state, &ctx, &message_id_str).await {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match chat_service::add_reaction(&state.db, &message_id, &ctx.user_id, body.emoji.trim()).await {
+        Ok(reactions) => {
             // Broadcast to WebSocket if available
             if let Some(ws_manager) = req.app_data::<web::Data<std::sync::Arc<crate::services::websocket::WsManager>>>() {
-                ws_manager.broadcast_message_deleted(&household_id, message_id).await;
+                ws_manager.broadcast_reactions_updated(&ctx.household_id, message_id, reactions.clone()).await;
             }
-            Ok(HttpResponse::NoContent().finish())
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(reactions)))
+        }
+        Err(chat_service::ChatError::InvalidReaction) => {
+            Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "validation_error".to_string(),
+                message: "Reactions must be a single emoji".to_string(),
+            }))
         }
         Err(chat_service::ChatError::NotFound) => {
             Ok(HttpResponse::NotFound().json(ApiError {
@@ -365,17 +516,58 @@ async fn delete_message(
                 message: "Message not found".to_string(),
             }))
         }
-        Err(chat_service::ChatError::NotAuthorized) => {
-            Ok(HttpResponse::Forbidden().json(ApiError {
-                error: "forbidden".to_string(),
-                message: "You can only delete your own messages".to_string(),
+        Err(e) => {
+            log::error!("Error adding chat reaction: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to add reaction".to_string(),
             }))
         }
+    }
+}
+
+/// Remove the current user's reaction from a message
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/chat/{message_id}/reactions",
+    tag = "chat",
+    summary = "Remove the current user's reaction from a message",
+    params(("household_id" = Uuid, Path), ("message_id" = Uuid, Path)),
+    request_body = ChatReactionRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<ChatReactionSummary>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn remove_reaction(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    req: actix_web::HttpRequest,
+    path: web::Path<(String, String)>,
+    body: web::Json<ChatReactionRequest>,
+) -> Result<HttpResponse> {
+    let (_, message_id_str) = path.into_inner();
+    let message_id = match authorize_message_access(&state, &ctx, &message_id_str).await {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match chat_service::remove_reaction(&state.db, &message_id, &ctx.user_id, body.emoji.trim()).await {
+        Ok(reactions) => {
+            // Broadcast to WebSocket if available
+            if let Some(ws_manager) = req.app_data::<web::Data<std::sync::Arc<crate::services::websocket::WsManager>>>() {
+                ws_manager.broadcast_reactions_updated(&ctx.household_id, message_id, reactions.clone()).await;
+            }
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(reactions)))
+        }
         Err(e) => {
-            log::error!("Error deleting chat message: {:?}", e);
+            log::error!("Error removing chat reaction: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
                 error: "internal_error".to_string(),
-                message: "Failed to delete message".to_string(),
+                message: "Failed to remove reaction".to_string(),
             }))
         }
     }
@@ -1,11 +1,15 @@
 use actix_web::{web, HttpResponse, Result};
+use serde::Deserialize;
 use shared::{
-    ApiError, ApiSuccess, DashboardTaskWithHousehold, DashboardTasksResponse,
-    DashboardTasksWithStatusResponse, IsTaskOnDashboardResponse,
+    AllDashboardTasksResponse, ApiError, ApiSuccess, DashboardTaskQuery, DashboardTaskWithHousehold,
+    DashboardTasksResponse, DashboardTasksWithStatusResponse, IsTaskOnDashboardResponse,
+    ReorderDashboardTasksRequest,
 };
 use uuid::Uuid;
 
+use crate::handlers::openapi::{BadRequest, NotFound, Null, Unauthorized};
 use crate::models::AppState;
+use crate::services::dashboard as dashboard_service;
 use crate::services::households as household_service;
 use crate::services::tasks as task_service;
 
@@ -15,6 +19,7 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route("/tasks", web::get().to(get_dashboard_task_ids))
             .route("/tasks/details", web::get().to(get_dashboard_tasks_with_status))
             .route("/tasks/all", web::get().to(get_all_tasks_across_households))
+            .route("/tasks/order", web::put().to(reorder_dashboard_tasks))
             .route("/tasks/{task_id}", web::get().to(is_task_on_dashboard))
             .route("/tasks/{task_id}", web::post().to(add_task_to_dashboard))
             .route("/tasks/{task_id}", web::delete().to(remove_task_from_dashboard)),
@@ -22,6 +27,16 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
 }
 
 /// Get all task IDs that the user has added to their dashboard
+#[utoipa::path(
+    get,
+    path = "/api/dashboard/tasks",
+    tag = "dashboard",
+    summary = "Get all task IDs that the user has added to their dashboard",
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<DashboardTasksResponse>),
+        Unauthorized,
+    ),
+)]
 async fn get_dashboard_task_ids(
     state: web::Data<AppState>,
     req: actix_web::HttpRequest,
@@ -53,6 +68,19 @@ async fn get_dashboard_task_ids(
 }
 
 /// Check if a specific task is on the user's dashboard
+#[utoipa::path(
+    get,
+    path = "/api/dashboard/tasks/{task_id}",
+    tag = "dashboard",
+    summary = "Check if a specific task is on the user's dashboard",
+    params(("task_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<IsTaskOnDashboardResponse>),
+        BadRequest,
+        Unauthorized,
+        NotFound,
+    ),
+)]
 async fn is_task_on_dashboard(
     state: web::Data<AppState>,
     req: actix_web::HttpRequest,
@@ -83,6 +111,19 @@ async fn is_task_on_dashboard(
 }
 
 /// Add a task to the user's dashboard
+#[utoipa::path(
+    post,
+    path = "/api/dashboard/tasks/{task_id}",
+    tag = "dashboard",
+    summary = "Add a task to the user's dashboard",
+    params(("task_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Null>),
+        BadRequest,
+        Unauthorized,
+        NotFound,
+    ),
+)]
 async fn add_task_to_dashboard(
     state: web::Data<AppState>,
     req: actix_web::HttpRequest,
@@ -111,6 +152,19 @@ async fn add_task_to_dashboard(
 }
 
 /// Remove a task from the user's dashboard
+#[utoipa::path(
+    delete,
+    path = "/api/dashboard/tasks/{task_id}",
+    tag = "dashboard",
+    summary = "Remove a task from the user's dashboard",
+    params(("task_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Null>),
+        BadRequest,
+        Unauthorized,
+        NotFound,
+    ),
+)]
 async fn remove_task_from_dashboard(
     state: web::Data<AppState>,
     req: actix_web::HttpRequest,
@@ -138,7 +192,103 @@ async fn remove_task_from_dashboard(
     }
 }
 
+/// Set the manual order of the user's dashboard tasks
+#[utoipa::path(
+    put,
+    path = "/api/dashboard/tasks/order",
+    tag = "dashboard",
+    summary = "Set the manual order of the user's dashboard tasks",
+    request_body = ReorderDashboardTasksRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Null>),
+        BadRequest,
+        Unauthorized,
+    ),
+)]
+async fn reorder_dashboard_tasks(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    body: web::Json<ReorderDashboardTasksRequest>,
+) -> Result<HttpResponse> {
+    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::Unauthorized().json(ApiError {
+                error: "unauthorized".to_string(),
+                message: "Invalid or missing authentication".to_string(),
+            }));
+        }
+    };
+
+    let user_id_str = user_id.to_string();
+
+    match task_service::reorder_dashboard_tasks(&state.db, &user_id_str, &body.task_ids).await {
+        Ok(()) => Ok(HttpResponse::Ok().json(ApiSuccess::new(()))),
+        Err(e) => Ok(HttpResponse::InternalServerError().json(ApiError {
+            error: "internal_error".to_string(),
+            message: e.to_string(),
+        })),
+    }
+}
+
+/// Query parameters of `/dashboard/tasks/all`, see `shared::DashboardTaskQuery::to_query_string`
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+struct AllTasksQuery {
+    households: Option<String>,
+    due_within: Option<i64>,
+    mine: Option<bool>,
+    group_by: Option<String>,
+}
+
+impl AllTasksQuery {
+    fn query(&self) -> std::result::Result<DashboardTaskQuery, HttpResponse> {
+        let invalid = |message: String| {
+            HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_filter".to_string(),
+                message,
+            })
+        };
+
+        let household_ids = self
+            .households
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(|id| Uuid::parse_str(id).map_err(|_| invalid(format!("Invalid household ID: {}", id))))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        if self.due_within.is_some_and(|days| !(0..=shared::MAX_DUE_WITHIN_DAYS).contains(&days)) {
+            return Err(invalid(format!("Due window must be between 0 and {} days", shared::MAX_DUE_WITHIN_DAYS)));
+        }
+
+        Ok(DashboardTaskQuery {
+            household_ids,
+            due_within_days: self.due_within,
+            assigned_to_me: self.mine.unwrap_or(false),
+            group_by: self
+                .group_by
+                .as_deref()
+                .filter(|value| !value.is_empty())
+                .map(|value| value.parse().map_err(|_| invalid(format!("Invalid grouping: {}", value))))
+                .transpose()?,
+        })
+    }
+}
+
 /// Get all dashboard tasks with their full status information
+#[utoipa::path(
+    get,
+    path = "/api/dashboard/tasks/details",
+    tag = "dashboard",
+    summary = "Get all dashboard tasks with their full status information",
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<DashboardTasksWithStatusResponse>),
+        Unauthorized,
+    ),
+)]
 async fn get_dashboard_tasks_with_status(
     state: web::Data<AppState>,
     req: actix_web::HttpRequest,
@@ -178,11 +328,26 @@ async fn get_dashboard_tasks_with_status(
     }
 }
 
-/// Get all tasks from all households the user is a member of
+/// Get all tasks from all households the user is a member of, filtered and
+/// optionally grouped, with summary counts.
 /// Used by the "Show all" toggle on the dashboard
+#[utoipa::path(
+    get,
+    path = "/api/dashboard/tasks/all",
+    tag = "dashboard",
+    summary = "Get all tasks across households",
+    description = "Get all tasks from all households the user is a member of, filtered and optionally grouped, with summary counts. Used by the \"Show all\" toggle on the dashboard",
+    params(AllTasksQuery),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<AllDashboardTasksResponse>),
+        BadRequest,
+        Unauthorized,
+    ),
+)]
 async fn get_all_tasks_across_households(
     state: web::Data<AppState>,
     req: actix_web::HttpRequest,
+    query: web::Query<AllTasksQuery>,
 ) -> Result<HttpResponse> {
     let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
         Ok(id) => id,
@@ -194,7 +359,12 @@ async fn get_all_tasks_across_households(
         }
     };
 
-    match task_service::get_all_tasks_across_households(&state.db, &user_id).await {
+    let query = match query.query() {
+        Ok(query) => query,
+        Err(response) => return Ok(response),
+    };
+
+    match task_service::get_all_tasks_across_households(&state.db, &user_id, &query.household_ids).await {
         Ok(tasks_with_households) => {
             let mut response_tasks = Vec::new();
             for (task_status, household_id) in tasks_with_households {
@@ -209,8 +379,9 @@ async fn get_all_tasks_across_households(
                     household_name,
                 });
             }
-            Ok(HttpResponse::Ok()
-                .json(ApiSuccess::new(DashboardTasksWithStatusResponse { tasks: response_tasks })))
+            let today = chrono::Utc::now().date_naive();
+            let response = dashboard_service::build_response(response_tasks, &query, &user_id, today);
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(response)))
         }
         Err(e) => Ok(HttpResponse::InternalServerError().json(ApiError {
             error: "internal_error".to_string(),
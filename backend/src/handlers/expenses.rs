@@ -0,0 +1,333 @@
+use actix_web::{web, HttpResponse, Result};
+use shared::{
+    ApiError, ApiSuccess, CreateExpenseRequest, CreateSettlementRequest, Expense, ExpenseBalances,
+    ExpenseMonthQuery, ExpenseMonthlySummary, ExpenseSettlement, ExpenseWithUser, Permission,
+    UpdateExpenseRequest,
+};
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::{expenses as expense_service, settlements as settlement_service};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/expenses")
+            .route("", web::get().to(list_expenses))
+            .route("", web::post().to(create_expense))
+            .route("/summary", web::get().to(get_monthly_summary))
+            .route("/balances", web::get().to(get_balances))
+            .route("/settlements", web::get().to(list_settlements))
+            .route("/settlements", web::post().to(create_settlement))
+            .route("/{expense_id}", web::put().to(update_expense))
+            .route("/{expense_id}", web::delete().to(delete_expense)),
+    );
+}
+
+/// Map service errors that are caused by the request rather than the server
+fn expense_error_response(error: expense_service::ExpenseError, action: &str) -> HttpResponse {
+    match error {
+        expense_service::ExpenseError::NotFound => HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Expense not found".to_string(),
+        }),
+        expense_service::ExpenseError::PermissionDenied => HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: format!("You do not have permission to {} this expense", action),
+        }),
+        expense_service::ExpenseError::InvalidAmount
+        | expense_service::ExpenseError::InvalidPayer
+        | expense_service::ExpenseError::InvalidMonth => HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: error.to_string(),
+        }),
+        expense_service::ExpenseError::DatabaseError(e) => {
+            log::error!("Error trying to {} expense: {:?}", action, e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: format!("Failed to {} expense", action),
+            })
+        }
+    }
+}
+
+/// Whether the user may edit expenses of, or record settlements for, other members
+async fn can_manage_expenses(state: &AppState, ctx: &HouseholdContext) -> bool {
+    ctx.has_permission(state, Permission::ManageTasks).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/expenses",
+    tag = "expenses",
+    summary = "List expenses",
+    params(("household_id" = Uuid, Path), ExpenseMonthQuery),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<ExpenseWithUser>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_expenses(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    query: web::Query<ExpenseMonthQuery>,
+) -> Result<HttpResponse> {
+    let (year, month) = expense_service::resolve_month(query.year, query.month);
+
+    match expense_service::list_expenses(&state.db, &ctx.household_id, year, month).await {
+        Ok(expenses) => Ok(HttpResponse::Ok().json(ApiSuccess::new(expenses))),
+        Err(e) => Ok(expense_error_response(e, "list")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/expenses",
+    tag = "expenses",
+    summary = "Create expense",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreateExpenseRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<Expense>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn create_expense(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    body: web::Json<CreateExpenseRequest>,
+) -> Result<HttpResponse> {
+    let request = body.into_inner();
+    if request.description.trim().is_empty() {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "Expense description is required".to_string(),
+        }));
+    }
+
+    match expense_service::create_expense(&state.db, &ctx.household_id, &ctx.user_id, &request).await {
+        Ok(expense) => Ok(HttpResponse::Created().json(ApiSuccess::new(expense))),
+        Err(e) => Ok(expense_error_response(e, "create")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/expenses/summary",
+    tag = "expenses",
+    summary = "Get monthly summary",
+    params(("household_id" = Uuid, Path), ExpenseMonthQuery),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<ExpenseMonthlySummary>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn get_monthly_summary(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    query: web::Query<ExpenseMonthQuery>,
+) -> Result<HttpResponse> {
+    let (year, month) = expense_service::resolve_month(query.year, query.month);
+
+    match expense_service::monthly_summary(&state.db, &ctx.household_id, year, month).await {
+        Ok(summary) => Ok(HttpResponse::Ok().json(ApiSuccess::new(summary))),
+        Err(e) => Ok(expense_error_response(e, "summarize")),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/expenses/{expense_id}",
+    tag = "expenses",
+    summary = "Update expense",
+    params(("household_id" = Uuid, Path), ("expense_id" = Uuid, Path)),
+    request_body = UpdateExpenseRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Expense>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn update_expense(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+    body: web::Json<UpdateExpenseRequest>,
+) -> Result<HttpResponse> {
+    let (_, expense_id_str) = path.into_inner();
+
+    let expense_id = match Uuid::parse_str(&expense_id_str) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_id".to_string(),
+                message: "Invalid expense ID format".to_string(),
+            }));
+        }
+    };
+
+    let request = body.into_inner();
+    if request.description.as_ref().is_some_and(|d| d.trim().is_empty()) {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "Expense description is required".to_string(),
+        }));
+    }
+
+    let can_manage = can_manage_expenses(&state, &ctx).await;
+
+    match expense_service::update_expense(&state.db, &ctx.household_id, &expense_id, &ctx.user_id, can_manage, &request)
+        .await
+    {
+        Ok(expense) => Ok(HttpResponse::Ok().json(ApiSuccess::new(expense))),
+        Err(e) => Ok(expense_error_response(e, "update")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/expenses/{expense_id}",
+    tag = "expenses",
+    summary = "Delete expense",
+    params(("household_id" = Uuid, Path), ("expense_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn delete_expense(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, expense_id_str) = path.into_inner();
+
+    let expense_id = match Uuid::parse_str(&expense_id_str) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_id".to_string(),
+                message: "Invalid expense ID format".to_string(),
+            }));
+        }
+    };
+
+    let can_manage = can_manage_expenses(&state, &ctx).await;
+
+    match expense_service::delete_expense(&state.db, &ctx.household_id, &expense_id, &ctx.user_id, can_manage).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(expense_error_response(e, "delete")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/expenses/balances",
+    tag = "expenses",
+    summary = "Get balances",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<ExpenseBalances>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn get_balances(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
+    match settlement_service::get_balances(&state.db, &ctx.household_id).await {
+        Ok(balances) => Ok(HttpResponse::Ok().json(ApiSuccess::new(balances))),
+        Err(e) => {
+            log::error!("Error computing expense balances: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to compute balances".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/expenses/settlements",
+    tag = "expenses",
+    summary = "List settlements",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<ExpenseSettlement>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_settlements(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
+    match settlement_service::list_settlements(&state.db, &ctx.household_id).await {
+        Ok(settlements) => Ok(HttpResponse::Ok().json(ApiSuccess::new(settlements))),
+        Err(e) => {
+            log::error!("Error listing settlements: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to list settlements".to_string(),
+            }))
+        }
+    }
+}
+
+/// Mark a debt as settled by recording a payment between two members
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/expenses/settlements",
+    tag = "expenses",
+    summary = "Mark a debt as settled by recording a payment between two members",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreateSettlementRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<ExpenseSettlement>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn create_settlement(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    body: web::Json<CreateSettlementRequest>,
+) -> Result<HttpResponse> {
+    let can_manage = can_manage_expenses(&state, &ctx).await;
+    let request = body.into_inner();
+
+    match settlement_service::record_settlement(&state.db, &ctx.household_id, &ctx.user_id, can_manage, &request).await {
+        Ok(settlement) => Ok(HttpResponse::Created().json(ApiSuccess::new(settlement))),
+        Err(settlement_service::SettlementError::PermissionDenied) => {
+            Ok(HttpResponse::Forbidden().json(ApiError {
+                error: "forbidden".to_string(),
+                message: "Only the involved members or a manager can record this settlement".to_string(),
+            }))
+        }
+        Err(e @ (settlement_service::SettlementError::InvalidAmount
+        | settlement_service::SettlementError::InvalidMembers)) => {
+            Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "validation_error".to_string(),
+                message: e.to_string(),
+            }))
+        }
+        Err(e) => {
+            log::error!("Error recording settlement: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to record settlement".to_string(),
+            }))
+        }
+    }
+}
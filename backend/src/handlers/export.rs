@@ -0,0 +1,211 @@
+use actix_web::{web, HttpResponse, Result};
+use futures::StreamExt;
+use shared::{ApiError, ApiSuccess, CsvExportQuery, HouseholdExport, Permission};
+
+use crate::handlers::openapi::{BadRequest, Forbidden, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::{csv_export, export as export_service};
+
+/// Registers the export/import routes. Must be configured before `households::configure`,
+/// so `/households/import` is not captured by the `/households/{id}` routes.
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/households/import", web::post().to(import_household))
+        .route("/households/{household_id}/export", web::get().to(export_household))
+        .route("/households/{household_id}/export/completions.csv", web::get().to(export_completions_csv))
+        .route("/households/{household_id}/export/points.csv", web::get().to(export_points_csv));
+}
+
+/// Only members who can manage the household may export it
+async fn require_export(state: &AppState, ctx: &HouseholdContext) -> std::result::Result<(), HttpResponse> {
+    if !ctx.has_permission(state, Permission::ManageTasks).await {
+        return Err(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "You don't have permission to export this household".to_string(),
+        }));
+    }
+    Ok(())
+}
+
+/// Stream CSV chunks as a file download
+fn csv_response<S>(filename: &str, rows: S) -> HttpResponse
+where
+    S: futures::Stream<Item = std::result::Result<String, csv_export::CsvExportError>> + 'static,
+{
+    HttpResponse::Ok()
+        .content_type("text/csv; charset=utf-8")
+        .insert_header(("Content-Disposition", format!("attachment; filename=\"{}\"", filename)))
+        .streaming(rows.map(|chunk| chunk.map(web::Bytes::from)))
+}
+
+fn csv_error_response(e: csv_export::CsvExportError) -> HttpResponse {
+    match e {
+        csv_export::CsvExportError::InvalidRange => HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "The start date must not be after the end date".to_string(),
+        }),
+        e => {
+            log::error!("Error exporting CSV: {:?}", e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to export data".to_string(),
+            })
+        }
+    }
+}
+
+/// Task completions as CSV, optionally limited to a range of due dates
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/export/completions.csv",
+    tag = "export",
+    summary = "Task completions as CSV, optionally limited to a range of due dates",
+    params(("household_id" = Uuid, Path), CsvExportQuery),
+    responses(
+        (status = 200, description = "Success", content_type = "text/csv", body = String),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn export_completions_csv(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    query: web::Query<CsvExportQuery>,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_export(&state, &ctx).await {
+        return Ok(response);
+    }
+
+    match csv_export::completions_csv(state.db.clone(), ctx.household_id, query.from, query.to) {
+        Ok(rows) => Ok(csv_response("completions.csv", rows)),
+        Err(e) => Ok(csv_error_response(e)),
+    }
+}
+
+/// Point changes as CSV, optionally limited to a range of dates
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/export/points.csv",
+    tag = "export",
+    summary = "Point changes as CSV, optionally limited to a range of dates",
+    params(("household_id" = Uuid, Path), CsvExportQuery),
+    responses(
+        (status = 200, description = "Success", content_type = "text/csv", body = String),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn export_points_csv(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    query: web::Query<CsvExportQuery>,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_export(&state, &ctx).await {
+        return Ok(response);
+    }
+
+    match csv_export::points_csv(state.db.clone(), ctx.household_id, query.from, query.to).await {
+        Ok(rows) => Ok(csv_response("points.csv", rows)),
+        Err(e) => Ok(csv_error_response(e)),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/export",
+    tag = "export",
+    summary = "Export household",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<HouseholdExport>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn export_household(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_export(&state, &ctx).await {
+        return Ok(response);
+    }
+
+    match export_service::export_household(&state.db, &ctx.household_id).await {
+        Ok(export) => Ok(HttpResponse::Ok().json(ApiSuccess::new(export))),
+        Err(export_service::ExportError::NotFound) => Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Household not found".to_string(),
+        })),
+        Err(e) => {
+            log::error!("Error exporting household: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to export household".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/import",
+    tag = "export",
+    summary = "Import household",
+    request_body = HouseholdExport,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<shared::Household>),
+        BadRequest,
+        Unauthorized,
+    ),
+)]
+async fn import_household(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    body: web::Json<HouseholdExport>,
+) -> Result<HttpResponse> {
+    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::Unauthorized().json(ApiError {
+                error: "unauthorized".to_string(),
+                message: "Invalid or missing token".to_string(),
+            }));
+        }
+    };
+
+    let data = body.into_inner();
+    if data.household_name.trim().is_empty() {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "Household name is required".to_string(),
+        }));
+    }
+
+    match export_service::import_household(&state.db, &user_id, &data).await {
+        Ok(household) => Ok(HttpResponse::Created().json(ApiSuccess::new(household))),
+        Err(export_service::ExportError::UnsupportedVersion(version)) => {
+            Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "validation_error".to_string(),
+                message: format!("Unsupported export version: {}", version),
+            }))
+        }
+        Err(export_service::ExportError::RewardError(crate::services::rewards::RewardError::InsufficientOptions))
+        | Err(export_service::ExportError::PunishmentError(
+            crate::services::punishments::PunishmentError::InsufficientOptions,
+        )) => Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "Random choice entries in the export need at least 2 options".to_string(),
+        })),
+        Err(e) => {
+            log::error!("Error importing household: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to import household".to_string(),
+            }))
+        }
+    }
+}
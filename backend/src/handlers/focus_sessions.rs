@@ -0,0 +1,145 @@
+use actix_web::{web, HttpRequest, HttpResponse, Result};
+use chrono::Utc;
+use shared::{ApiError, ApiSuccess, FocusSession, FocusSessionStatus, StartFocusSessionRequest};
+
+use crate::handlers::openapi::{BadRequest, Forbidden, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::focus_sessions as focus_service;
+
+/// The caller's focus timer on a task of the household
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/focus-sessions")
+            .route("", web::post().to(start_session))
+            .route("/current/stop", web::post().to(stop_session))
+            .route("/current/cancel", web::post().to(cancel_session)),
+    );
+}
+
+fn focus_error_response(e: focus_service::FocusSessionError, action: &str) -> HttpResponse {
+    match e {
+        focus_service::FocusSessionError::NotRunning => HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: e.to_string(),
+        }),
+        focus_service::FocusSessionError::TaskNotFound => HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: e.to_string(),
+        }),
+        focus_service::FocusSessionError::AlreadyRunning => HttpResponse::Conflict().json(ApiError {
+            error: "focus_session_running".to_string(),
+            message: e.to_string(),
+        }),
+        focus_service::FocusSessionError::InvalidLength => HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: e.to_string(),
+        }),
+        e => {
+            log::error!("Error trying to {} focus session: {:?}", action, e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: format!("Failed to {} focus session", action),
+            })
+        }
+    }
+}
+
+/// The caller's running focus session in any household, or null
+#[utoipa::path(
+    get,
+    path = "/api/users/me/focus-session",
+    tag = "focus_sessions",
+    summary = "The caller's running focus session in any household, or null",
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Option<FocusSession>>),
+        Unauthorized,
+    ),
+)]
+pub async fn get_current_session(state: web::Data<AppState>, req: HttpRequest) -> Result<HttpResponse> {
+    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::Unauthorized().json(ApiError {
+                error: "unauthorized".to_string(),
+                message: "Invalid or missing token".to_string(),
+            }));
+        }
+    };
+
+    match focus_service::get_running_session(&state.db, &user_id).await {
+        Ok(session) => Ok(HttpResponse::Ok().json(ApiSuccess::new(session))),
+        Err(e) => Ok(focus_error_response(e, "load")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/focus-sessions",
+    tag = "focus_sessions",
+    summary = "Start session",
+    params(("household_id" = Uuid, Path)),
+    request_body = StartFocusSessionRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<FocusSession>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn start_session(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    body: web::Json<StartFocusSessionRequest>,
+) -> Result<HttpResponse> {
+    match focus_service::start_session(&state.db, &ctx.household_id, &ctx.user_id, &body, Utc::now()).await {
+        Ok(session) => Ok(HttpResponse::Created().json(ApiSuccess::new(session))),
+        Err(e) => Ok(focus_error_response(e, "start")),
+    }
+}
+
+/// Stop the running session; its time counts toward the task
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/focus-sessions/current/stop",
+    tag = "focus_sessions",
+    summary = "Stop the running session; its time counts toward the task",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<FocusSession>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn stop_session(state: web::Data<AppState>, ctx: HouseholdContext) -> Result<HttpResponse> {
+    match focus_service::end_session(&state.db, &ctx.household_id, &ctx.user_id, FocusSessionStatus::Completed, Utc::now())
+        .await
+    {
+        Ok(session) => Ok(HttpResponse::Ok().json(ApiSuccess::new(session))),
+        Err(e) => Ok(focus_error_response(e, "stop")),
+    }
+}
+
+/// Cancel the running session without counting its time
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/focus-sessions/current/cancel",
+    tag = "focus_sessions",
+    summary = "Cancel the running session without counting its time",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<FocusSession>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn cancel_session(state: web::Data<AppState>, ctx: HouseholdContext) -> Result<HttpResponse> {
+    match focus_service::end_session(&state.db, &ctx.household_id, &ctx.user_id, FocusSessionStatus::Cancelled, Utc::now())
+        .await
+    {
+        Ok(session) => Ok(HttpResponse::Ok().json(ApiSuccess::new(session))),
+        Err(e) => Ok(focus_error_response(e, "cancel")),
+    }
+}
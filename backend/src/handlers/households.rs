@@ -1,36 +1,65 @@
 use actix_web::{web, HttpResponse, Result};
-use shared::{ActivityType, AdjustPointsRequest, AdjustPointsResponse, ApiError, ApiSuccess, CreateHouseholdRequest, CreateInvitationRequest, UpdateHouseholdRequest, UpdateHouseholdSettingsRequest, UpdateRoleRequest};
+use shared::{
+    ActivityType, AdjustPointsRequest, AdjustPointsResponse, AdminAuditLogPage, ApiError,
+    ApiSuccess, AuditAction, CreateChildAccountRequest, CreateHouseholdRequest,
+    CreateInvitationRequest, HouseholdMembership, HouseholdSettings, Invitation, LeaderboardEntry,
+    LeaderboardQuery, ListAdminAuditLogRequest, ListPointHistoryRequest, MemberPermissionsResponse,
+    MemberWithUser, Permission, PointHistoryPage, PointTransactionType, ResetChildPinRequest,
+    SetMemberVacationRequest, UpdateHouseholdRequest, UpdateHouseholdSettingsRequest,
+    UpdateMemberPermissionsRequest, UpdateRoleRequest, VersionConflict,
+};
 use uuid::Uuid;
 
+use crate::handlers::openapi::{BadRequest, Conflict, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
 use crate::models::AppState;
-use crate::services::{activity_logs as activity_log_service, households as household_service, household_settings as settings_service, invitations as invitation_service, solo_mode as solo_mode_service};
-use crate::handlers::{tasks, task_categories, rewards, punishments, point_conditions, activity_logs, chat, notes, journal, announcements, statistics};
+use crate::services::mail::{self as mail_service, MailSettings};
+use crate::services::{activity_logs as activity_log_service, audit_log as audit_log_service, auth as auth_service, households as household_service, household_settings as settings_service, invitations as invitation_service, leaderboard as leaderboard_service, permissions, points as points_service, solo_mode as solo_mode_service};
+use crate::handlers::{allowance, attachments, automation_rules, webhooks, sensors, calendar, challenges, invite_codes, task_comments, tasks, task_categories, task_tags, task_exclusions, streak_freezes, reward_wishlist, point_goals, saved_filters, focus_sessions, rewards, punishments, point_conditions, activity_logs, chat, notes, journal, announcements, statistics, expenses, meals, shopping_list, inventory, appliances, plants, pets, share_links, trash};
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/households")
             .route("", web::get().to(list_households))
             .route("", web::post().to(create_household))
-            .route("/{id}", web::get().to(get_household))
-            .route("/{id}", web::put().to(update_household))
-            .route("/{id}", web::delete().to(delete_household))
-            .route("/{id}/members", web::get().to(list_members))
-            .route("/{id}/invite", web::post().to(invite_member))
-            .route("/{id}/invitations", web::get().to(list_household_invitations))
-            .route("/{id}/invitations/{inv_id}", web::delete().to(cancel_invitation))
-            .route("/{id}/members/{user_id}", web::delete().to(remove_member))
-            .route("/{id}/members/{user_id}/role", web::put().to(update_member_role))
-            .route("/{id}/members/{user_id}/points", web::post().to(adjust_member_points))
-            .route("/{id}/leaderboard", web::get().to(get_leaderboard))
-            .route("/{id}/settings", web::get().to(get_household_settings))
-            .route("/{id}/settings", web::put().to(update_household_settings))
-            .route("/{id}/solo-mode/activate", web::post().to(activate_solo_mode))
-            .route("/{id}/solo-mode/request-exit", web::post().to(request_solo_mode_exit))
-            .route("/{id}/solo-mode/cancel-exit", web::post().to(cancel_solo_mode_exit))
+            .route("/{household_id}", web::get().to(get_household))
+            .route("/{household_id}", web::put().to(update_household))
+            .route("/{household_id}", web::delete().to(delete_household))
+            .route("/{household_id}/members", web::get().to(list_members))
+            .route("/{household_id}/invite", web::post().to(invite_member))
+            .route("/{household_id}/invitations", web::get().to(list_household_invitations))
+            .route("/{household_id}/invitations/{inv_id}", web::delete().to(cancel_invitation))
+            .route("/{household_id}/members/{user_id}", web::delete().to(remove_member))
+            .route("/{household_id}/members/{user_id}/role", web::put().to(update_member_role))
+            .route("/{household_id}/members/{user_id}/permissions", web::get().to(get_member_permissions))
+            .route("/{household_id}/members/{user_id}/permissions", web::put().to(update_member_permissions))
+            .route("/{household_id}/members/{user_id}/points", web::post().to(adjust_member_points))
+            .route("/{household_id}/members/{user_id}/points/history", web::get().to(get_points_history))
+            .route("/{household_id}/members/{user_id}/vacation", web::put().to(set_member_vacation))
+            .route("/{household_id}/transfer-ownership/{user_id}", web::post().to(transfer_ownership))
+            .route("/{household_id}/leave", web::post().to(leave_household))
+            .route("/{household_id}/child-accounts", web::post().to(create_child_account))
+            .route("/{household_id}/child-accounts/{user_id}/pin", web::put().to(reset_child_pin))
+            .route("/{household_id}/leaderboard", web::get().to(get_leaderboard))
+            .route("/{household_id}/settings", web::get().to(get_household_settings))
+            .route("/{household_id}/settings", web::put().to(update_household_settings))
+            .route("/{household_id}/audit-log", web::get().to(get_audit_log))
+            .route("/{household_id}/solo-mode/activate", web::post().to(activate_solo_mode))
+            .route("/{household_id}/solo-mode/request-exit", web::post().to(request_solo_mode_exit))
+            .route("/{household_id}/solo-mode/cancel-exit", web::post().to(cancel_solo_mode_exit))
             .service(
                 web::scope("/{household_id}")
+                    .configure(calendar::configure)
+                    .configure(task_comments::configure)
                     .configure(tasks::configure)
                     .configure(task_categories::configure)
+                    .configure(task_tags::configure)
+                    .configure(saved_filters::configure)
+                    .configure(focus_sessions::configure)
+                    .configure(task_exclusions::configure)
+                    .configure(streak_freezes::configure)
+                    .configure(reward_wishlist::configure)
+                    .configure(point_goals::configure)
                     .configure(rewards::configure)
                     .configure(punishments::configure)
                     .configure(point_conditions::configure)
@@ -38,12 +67,38 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
                     .configure(chat::configure)
                     .configure(notes::configure)
                     .configure(journal::configure)
+                    .configure(trash::configure)
+                    .configure(attachments::configure)
                     .configure(announcements::configure)
                     .configure(statistics::configure)
+                    .configure(expenses::configure)
+                    .configure(meals::configure)
+                    .configure(shopping_list::configure)
+                    .configure(inventory::configure)
+                    .configure(appliances::configure)
+                    .configure(plants::configure)
+                    .configure(pets::configure)
+                    .configure(share_links::configure)
+                    .configure(allowance::configure)
+                    .configure(challenges::configure)
+                    .configure(automation_rules::configure)
+                    .configure(webhooks::configure)
+                    .configure(sensors::configure)
+                    .configure(invite_codes::configure)
             )
     );
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households",
+    tag = "households",
+    summary = "List households",
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<shared::Household>>),
+        Unauthorized,
+    ),
+)]
 async fn list_households(
     state: web::Data<AppState>,
     req: actix_web::HttpRequest,
@@ -70,6 +125,19 @@ async fn list_households(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households",
+    tag = "households",
+    summary = "Create household",
+    request_body = CreateHouseholdRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<shared::Household>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn create_household(
     state: web::Data<AppState>,
     req: actix_web::HttpRequest,
@@ -85,6 +153,13 @@ async fn create_household(
         }
     };
 
+    if is_child_account(&state, &user_id).await {
+        return Ok(HttpResponse::Forbidden().json(ApiError {
+            error: "child_account_restricted".to_string(),
+            message: "Child accounts cannot create households".to_string(),
+        }));
+    }
+
     let request = body.into_inner();
     if request.name.is_empty() {
         return Ok(HttpResponse::BadRequest().json(ApiError {
@@ -105,40 +180,25 @@ async fn create_household(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}",
+    tag = "households",
+    summary = "Get household",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<shared::Household>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn get_household(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    match household_service::get_household(&state.db, &household_id).await {
+    match household_service::get_household(&state.db, &ctx.household_id).await {
         Ok(Some(household)) => Ok(HttpResponse::Ok().json(ApiSuccess::new(household))),
         Ok(None) => Ok(HttpResponse::NotFound().json(ApiError {
             error: "not_found".to_string(),
@@ -154,42 +214,34 @@ async fn get_household(
     }
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}",
+    tag = "households",
+    summary = "Update household",
+    params(("household_id" = Uuid, Path)),
+    request_body = UpdateHouseholdRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<shared::Household>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn update_household(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
     body: web::Json<UpdateHouseholdRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
     // Check if user is owner or admin
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.map(|r| r.can_manage_tasks()).unwrap_or(false) {
+    if !ctx.role().can_manage_tasks() {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "Only owners and admins can update household settings".to_string(),
         }));
     }
 
-    match household_service::update_household(&state.db, &household_id, &body.into_inner()).await {
+    match household_service::update_household(&state.db, &ctx.household_id, &body.into_inner()).await {
         Ok(household) => Ok(HttpResponse::Ok().json(ApiSuccess::new(household))),
         Err(e) => {
             log::error!("Error updating household: {:?}", e);
@@ -201,41 +253,27 @@ async fn update_household(
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}",
+    tag = "households",
+    summary = "Delete household",
+    params(("household_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden),
+)]
 async fn delete_household(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
     // Check if user is owner
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.map(|r| r.can_delete_household()).unwrap_or(false) {
+    if !ctx.role().can_delete_household() {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "Only owners can delete households".to_string(),
         }));
     }
 
-    match household_service::delete_household(&state.db, &household_id).await {
+    match household_service::delete_household(&state.db, &ctx.household_id).await {
         Ok(_) => Ok(HttpResponse::NoContent().finish()),
         Err(e) => {
             log::error!("Error deleting household: {:?}", e);
@@ -247,40 +285,24 @@ async fn delete_household(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/members",
+    tag = "households",
+    summary = "List members",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<MemberWithUser>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn list_members(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    match household_service::list_members(&state.db, &household_id).await {
+    match household_service::list_members(&state.db, &ctx.household_id).await {
         Ok(members) => Ok(HttpResponse::Ok().json(ApiSuccess::new(members))),
         Err(e) => {
             log::error!("Error listing members: {:?}", e);
@@ -292,38 +314,30 @@ async fn list_members(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/invite",
+    tag = "households",
+    summary = "Invite member",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreateInvitationRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<Invitation>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn invite_member(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
     body: web::Json<CreateInvitationRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
     // Check if user can manage members
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.map(|r| r.can_manage_members()).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ManageMembers).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
-            message: "Only owners and admins can invite members".to_string(),
+            message: "You do not have permission to invite members".to_string(),
         }));
     }
 
@@ -331,7 +345,7 @@ async fn invite_member(
     let member_role = request.role.unwrap_or(shared::Role::Member);
 
     // Only owner can invite as admins
-    if member_role == shared::Role::Admin && !role.map(|r| r == shared::Role::Owner).unwrap_or(false) {
+    if member_role == shared::Role::Admin && ctx.role() != shared::Role::Owner {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "Only owners can invite as admin".to_string(),
@@ -346,14 +360,22 @@ async fn invite_member(
         }));
     }
 
-    match invitation_service::create_invitation(&state.db, &household_id, &request.email, member_role, &user_id).await {
+    // Child accounts have no mailbox and are added directly by the owner
+    if shared::is_child_account_email(&request.email) {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "This email address cannot be invited".to_string(),
+        }));
+    }
+
+    match invitation_service::create_invitation(&state.db, &ctx.household_id, &request.email, member_role, &ctx.user_id).await {
         Ok(invitation) => {
             // Log activity
             let details = serde_json::json!({ "email": request.email }).to_string();
             let _ = activity_log_service::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 None,
                 ActivityType::InvitationSent,
                 Some("invitation"),
@@ -361,6 +383,29 @@ async fn invite_member(
                 Some(&details),
             ).await;
 
+            // People without an account would never see the invitation in the app, so email them
+            if let Some(settings) = MailSettings::from_config(&state.config) {
+                let has_account = auth_service::email_registered(&state.db, &request.email)
+                    .await
+                    .unwrap_or(true);
+                if !has_account {
+                    let household_name = household_service::get_household(&state.db, &ctx.household_id)
+                        .await
+                        .ok()
+                        .flatten()
+                        .map(|h| h.name)
+                        .unwrap_or_default();
+                    let inviter_name = auth_service::get_user_by_id(&state.db, &ctx.user_id)
+                        .await
+                        .ok()
+                        .flatten()
+                        .map(|u| u.username)
+                        .unwrap_or_default();
+                    let mail = mail_service::invitation_mail(&settings, &request.email, &household_name, &inviter_name);
+                    mail_service::send_in_background(settings, mail);
+                }
+            }
+
             Ok(HttpResponse::Created().json(ApiSuccess::new(invitation)))
         }
         Err(invitation_service::InvitationError::AlreadyExists) => {
@@ -386,41 +431,32 @@ async fn invite_member(
 }
 
 /// List pending invitations for a household
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/invitations",
+    tag = "households",
+    summary = "List pending invitations for a household",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<Invitation>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn list_household_invitations(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
     // Check if user can manage members
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.map(|r| r.can_manage_members()).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ManageMembers).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
-            message: "Only owners and admins can view invitations".to_string(),
+            message: "You do not have permission to view invitations".to_string(),
         }));
     }
 
-    match invitation_service::get_household_invitations(&state.db, &household_id).await {
+    match invitation_service::get_household_invitations(&state.db, &ctx.household_id).await {
         Ok(invitations) => Ok(HttpResponse::Ok().json(ApiSuccess::new(invitations))),
         Err(e) => {
             log::error!("Error listing invitations: {:?}", e);
@@ -433,32 +469,20 @@ async fn list_household_invitations(
 }
 
 /// Cancel a pending invitation
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/invitations/{inv_id}",
+    tag = "households",
+    summary = "Cancel a pending invitation",
+    params(("household_id" = Uuid, Path), ("inv_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
 async fn cancel_invitation(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, invitation_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, invitation_id_str) = path.into_inner();
 
     let invitation_id = match Uuid::parse_str(&invitation_id_str) {
         Ok(id) => id,
@@ -471,11 +495,10 @@ async fn cancel_invitation(
     };
 
     // Check if user can manage members
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.map(|r| r.can_manage_members()).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ManageMembers).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
-            message: "Only owners and admins can cancel invitations".to_string(),
+            message: "You do not have permission to cancel invitations".to_string(),
         }));
     }
 
@@ -497,7 +520,7 @@ async fn cancel_invitation(
         }
     };
 
-    if invitation.household_id != household_id {
+    if invitation.household_id != ctx.household_id {
         return Ok(HttpResponse::NotFound().json(ApiError {
             error: "not_found".to_string(),
             message: "Invitation not found".to_string(),
@@ -522,32 +545,21 @@ async fn cancel_invitation(
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/members/{user_id}",
+    tag = "households",
+    summary = "Remove member",
+    params(("household_id" = Uuid, Path), ("user_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
 async fn remove_member(
     state: web::Data<AppState>,
+    ctx: HouseholdContext,
     req: actix_web::HttpRequest,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let current_user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, target_user_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, target_user_id_str) = path.into_inner();
 
     let target_user_id = match Uuid::parse_str(&target_user_id_str) {
         Ok(id) => id,
@@ -560,11 +572,10 @@ async fn remove_member(
     };
 
     // Check permissions
-    let role = household_service::get_member_role(&state.db, &household_id, &current_user_id).await;
-    let target_role = household_service::get_member_role(&state.db, &household_id, &target_user_id).await;
+    let target_role = household_service::get_member_role(&state.db, &ctx.household_id, &target_user_id).await;
 
     // Users can leave by removing themselves
-    let is_self_removal = current_user_id == target_user_id;
+    let is_self_removal = ctx.user_id == target_user_id;
 
     // Cannot remove the owner
     if target_role == Some(shared::Role::Owner) {
@@ -575,20 +586,22 @@ async fn remove_member(
     }
 
     // Must be able to manage members or be removing self
-    if !is_self_removal && !role.map(|r| r.can_manage_members()).unwrap_or(false) {
+    if !is_self_removal
+        && !ctx.has_permission(&state, Permission::ManageMembers).await
+    {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
-            message: "Only owners and admins can remove members".to_string(),
+            message: "You do not have permission to remove members".to_string(),
         }));
     }
 
-    match household_service::remove_member(&state.db, &household_id, &target_user_id).await {
+    match household_service::remove_member(&state.db, &ctx.household_id, &target_user_id).await {
         Ok(_) => {
             // Log activity
             let _ = activity_log_service::log_activity(
                 &state.db,
-                &household_id,
-                &current_user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 if is_self_removal { None } else { Some(&target_user_id) },
                 ActivityType::MemberLeft,
                 Some("member"),
@@ -596,6 +609,18 @@ async fn remove_member(
                 None,
             ).await;
 
+            let _ = audit_log_service::record(
+                &state.db,
+                &ctx.household_id,
+                &ctx.user_id,
+                Some(&target_user_id),
+                AuditAction::MemberRemoved,
+                target_role.map(|r| r.as_str()),
+                None,
+            ).await;
+
+            crate::handlers::websocket::broadcast_leaderboard(&req, &state.db, &ctx.household_id).await;
+
             Ok(HttpResponse::NoContent().finish())
         }
         Err(e) => {
@@ -608,33 +633,26 @@ async fn remove_member(
     }
 }
 
-async fn update_member_role(
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/transfer-ownership/{user_id}",
+    tag = "households",
+    summary = "Transfer ownership",
+    params(("household_id" = Uuid, Path), ("user_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<HouseholdMembership>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn transfer_ownership(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
-    body: web::Json<UpdateRoleRequest>,
 ) -> Result<HttpResponse> {
-    let current_user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, target_user_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, target_user_id_str) = path.into_inner();
 
     let target_user_id = match Uuid::parse_str(&target_user_id_str) {
         Ok(id) => id,
@@ -646,66 +664,41 @@ async fn update_member_role(
         }
     };
 
-    // Only owner can change roles
-    let role = household_service::get_member_role(&state.db, &household_id, &current_user_id).await;
-    if !role.map(|r| r.can_manage_roles()).unwrap_or(false) {
+    if ctx.role() != shared::Role::Owner {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
-            message: "Only owners can change member roles".to_string(),
+            message: "Only the owner can transfer ownership".to_string(),
         }));
     }
 
-    let new_role = body.into_inner().role;
-
-    // Get target's current role
-    let target_role = household_service::get_member_role(&state.db, &household_id, &target_user_id).await;
-
-    // Cannot change owner's role (except via ownership transfer)
-    if target_role == Some(shared::Role::Owner) && new_role != shared::Role::Owner {
+    if target_user_id == ctx.user_id {
         return Ok(HttpResponse::BadRequest().json(ApiError {
-            error: "invalid_role".to_string(),
-            message: "Cannot change owner's role".to_string(),
+            error: "invalid_target".to_string(),
+            message: "You already own this household".to_string(),
         }));
     }
 
-    // Handle owner transfer
-    if new_role == shared::Role::Owner {
-        // Transfer ownership from current user to target user
-        match household_service::transfer_ownership(&state.db, &household_id, &current_user_id, &target_user_id).await {
-            Ok(membership) => {
-                // Log activity
-                let details = serde_json::json!({ "new_owner": target_user_id.to_string() }).to_string();
-                let _ = activity_log_service::log_activity(
-                    &state.db,
-                    &household_id,
-                    &current_user_id,
-                    Some(&target_user_id),
-                    ActivityType::MemberRoleChanged,
-                    Some("member"),
-                    None,
-                    Some(&details),
-                ).await;
+    let Some(target_role) = household_service::get_member_role(&state.db, &ctx.household_id, &target_user_id).await else {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "not_a_member".to_string(),
+            message: "Ownership can only be transferred to a household member".to_string(),
+        }));
+    };
 
-                return Ok(HttpResponse::Ok().json(ApiSuccess::new(membership)));
-            }
-            Err(e) => {
-                log::error!("Error transferring ownership: {:?}", e);
-                return Ok(HttpResponse::InternalServerError().json(ApiError {
-                    error: "internal_error".to_string(),
-                    message: "Failed to transfer ownership".to_string(),
-                }));
-            }
-        }
+    if is_child_account(&state, &target_user_id).await {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "child_account_restricted".to_string(),
+            message: "Child accounts can only be members".to_string(),
+        }));
     }
 
-    match household_service::update_member_role(&state.db, &household_id, &target_user_id, new_role).await {
+    match household_service::transfer_ownership(&state.db, &ctx.household_id, &ctx.user_id, &target_user_id).await {
         Ok(membership) => {
-            // Log activity
-            let details = serde_json::json!({ "new_role": format!("{:?}", new_role) }).to_string();
+            let details = serde_json::json!({ "new_owner": target_user_id.to_string() }).to_string();
             let _ = activity_log_service::log_activity(
                 &state.db,
-                &household_id,
-                &current_user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 Some(&target_user_id),
                 ActivityType::MemberRoleChanged,
                 Some("member"),
@@ -713,92 +706,608 @@ async fn update_member_role(
                 Some(&details),
             ).await;
 
+            let _ = audit_log_service::record(
+                &state.db,
+                &ctx.household_id,
+                &ctx.user_id,
+                Some(&target_user_id),
+                AuditAction::OwnershipTransferred,
+                Some(target_role.as_str()),
+                Some(shared::Role::Owner.as_str()),
+            ).await;
+
             Ok(HttpResponse::Ok().json(ApiSuccess::new(membership)))
         }
         Err(e) => {
-            log::error!("Error updating role: {:?}", e);
+            log::error!("Error transferring ownership: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
                 error: "internal_error".to_string(),
-                message: "Failed to update role".to_string(),
+                message: "Failed to transfer ownership".to_string(),
             }))
         }
     }
 }
 
-async fn get_leaderboard(
+/// Leave a household. Owners must hand over ownership first (or delete the household).
+/// Assigned tasks are unassigned and the member's points are forfeited.
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/leave",
+    tag = "households",
+    summary = "Leave household",
+    description = "Leave a household. Owners must hand over ownership first (or delete the household). Assigned tasks are unassigned and the member's points are forfeited.",
+    params(("household_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden),
+)]
+async fn leave_household(
     state: web::Data<AppState>,
+    ctx: HouseholdContext,
     req: actix_web::HttpRequest,
-    path: web::Path<String>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
+    if ctx.role() == shared::Role::Owner {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "owner_must_transfer".to_string(),
+            message: "Transfer ownership to another member before leaving, or delete the household".to_string(),
+        }));
+    }
+
+    match household_service::remove_member(&state.db, &ctx.household_id, &ctx.user_id).await {
+        Ok(_) => {
+            let _ = activity_log_service::log_activity(
+                &state.db,
+                &ctx.household_id,
+                &ctx.user_id,
+                None,
+                ActivityType::MemberLeft,
+                Some("member"),
+                None,
+                None,
+            ).await;
+
+            crate::handlers::websocket::broadcast_leaderboard(&req, &state.db, &ctx.household_id).await;
+
+            Ok(HttpResponse::NoContent().finish())
+        }
+        Err(e) => {
+            log::error!("Error leaving household: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to leave household".to_string(),
+            }))
+        }
+    }
+}
+
+/// Whether the user is a managed child account; unknown users count as regular accounts
+async fn is_child_account(state: &AppState, user_id: &Uuid) -> bool {
+    auth_service::get_user_by_id(&state.db, user_id)
+        .await
+        .ok()
+        .flatten()
+        .map(|u| u.is_child_account())
+        .unwrap_or(false)
+}
+
+/// Create a child account (username and PIN, no email) and add it as a member
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/child-accounts",
+    tag = "households",
+    summary = "Create a child account (username and PIN, no email) and add it as a member",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreateChildAccountRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<MemberWithUser>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn create_child_account(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    req: actix_web::HttpRequest,
+    body: web::Json<CreateChildAccountRequest>,
+) -> Result<HttpResponse> {
+    if ctx.role() != shared::Role::Owner {
+        return Ok(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "Only the owner can create child accounts".to_string(),
+        }));
+    }
+
+    let request = body.into_inner();
+    let username = request.username.trim();
+    if username.is_empty() {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "Username is required".to_string(),
+        }));
+    }
+    if !shared::is_valid_child_pin(&request.pin) {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "PIN must be 4 to 8 digits".to_string(),
+        }));
+    }
+
+    let child = match auth_service::create_child_account(&state.db, username, &request.pin, &ctx.user_id).await {
+        Ok(child) => child,
+        Err(auth_service::AuthError::UserAlreadyExists) => {
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "user_exists".to_string(),
+                message: "This username is already taken".to_string(),
+            }));
+        }
+        Err(e) => {
+            log::error!("Error creating child account: {:?}", e);
+            return Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to create child account".to_string(),
+            }));
+        }
+    };
+
+    match household_service::add_member(&state.db, &ctx.household_id, &child.id, shared::Role::Member).await {
+        Ok(membership) => {
+            let _ = activity_log_service::log_activity(
+                &state.db,
+                &ctx.household_id,
+                &ctx.user_id,
+                Some(&child.id),
+                ActivityType::MemberJoined,
+                Some("member"),
+                None,
+                None,
+            ).await;
+
+            crate::handlers::websocket::broadcast_leaderboard(&req, &state.db, &ctx.household_id).await;
+
+            Ok(HttpResponse::Created().json(ApiSuccess::new(MemberWithUser {
+                membership,
+                user: child,
+            })))
+        }
+        Err(e) => {
+            log::error!("Error adding child account to household: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to create child account".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/child-accounts/{user_id}/pin",
+    tag = "households",
+    summary = "Reset child pin",
+    params(("household_id" = Uuid, Path), ("user_id" = Uuid, Path)),
+    request_body = ResetChildPinRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<String>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn reset_child_pin(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+    body: web::Json<ResetChildPinRequest>,
+) -> Result<HttpResponse> {
+    let (_, target_user_id_str) = path.into_inner();
+
+    let target_user_id = match Uuid::parse_str(&target_user_id_str) {
+        Ok(id) => id,
         Err(_) => {
             return Ok(HttpResponse::BadRequest().json(ApiError {
                 error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
+                message: "Invalid user ID format".to_string(),
             }));
         }
     };
 
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
+    if ctx.role() != shared::Role::Owner {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
+            message: "Only the owner can reset a child's PIN".to_string(),
         }));
     }
 
-    match household_service::get_leaderboard(&state.db, &household_id).await {
-        Ok(leaderboard) => Ok(HttpResponse::Ok().json(ApiSuccess::new(leaderboard))),
+    if !household_service::is_member(&state.db, &ctx.household_id, &target_user_id).await.unwrap_or(false) {
+        return Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Member not found".to_string(),
+        }));
+    }
+
+    let request = body.into_inner();
+    if !shared::is_valid_child_pin(&request.pin) {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "PIN must be 4 to 8 digits".to_string(),
+        }));
+    }
+
+    match auth_service::set_child_pin(&state.db, &target_user_id, &request.pin).await {
+        Ok(()) => Ok(HttpResponse::Ok().json(ApiSuccess::new("PIN has been reset"))),
+        Err(auth_service::AuthError::ChildAccountRestricted) => Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "not_a_child_account".to_string(),
+            message: "Only child account PINs can be reset".to_string(),
+        })),
         Err(e) => {
-            log::error!("Error fetching leaderboard: {:?}", e);
+            log::error!("Error resetting child PIN: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
                 error: "internal_error".to_string(),
-                message: "Failed to fetch leaderboard".to_string(),
+                message: "Failed to reset PIN".to_string(),
             }))
         }
     }
 }
 
-/// Manually adjust a member's points (add or remove)
-async fn adjust_member_points(
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/members/{user_id}/role",
+    tag = "households",
+    summary = "Update member role",
+    params(("household_id" = Uuid, Path), ("user_id" = Uuid, Path)),
+    request_body = UpdateRoleRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<HouseholdMembership>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn update_member_role(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
-    body: web::Json<AdjustPointsRequest>,
+    body: web::Json<UpdateRoleRequest>,
 ) -> Result<HttpResponse> {
-    let current_user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
+    let (_, target_user_id_str) = path.into_inner();
+
+    let target_user_id = match Uuid::parse_str(&target_user_id_str) {
         Ok(id) => id,
         Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_id".to_string(),
+                message: "Invalid user ID format".to_string(),
             }));
         }
     };
 
-    let (household_id_str, target_user_id_str) = path.into_inner();
+    // Only owner can change roles
+    if !ctx.role().can_manage_roles() {
+        return Ok(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "Only owners can change member roles".to_string(),
+        }));
+    }
+
+    let new_role = body.into_inner().role;
+
+    // Get target's current role
+    let target_role = household_service::get_member_role(&state.db, &ctx.household_id, &target_user_id).await;
+
+    if new_role != shared::Role::Member && is_child_account(&state, &target_user_id).await {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "child_account_restricted".to_string(),
+            message: "Child accounts can only be members".to_string(),
+        }));
+    }
+
+    // Cannot change owner's role (except via ownership transfer)
+    if target_role == Some(shared::Role::Owner) && new_role != shared::Role::Owner {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_role".to_string(),
+            message: "Cannot change owner's role".to_string(),
+        }));
+    }
+
+    // Handle owner transfer
+    if new_role == shared::Role::Owner {
+        // Transfer ownership from current user to target user
+        match household_service::transfer_ownership(&state.db, &ctx.household_id, &ctx.user_id, &target_user_id).await {
+            Ok(membership) => {
+                // Log activity
+                let details = serde_json::json!({ "new_owner": target_user_id.to_string() }).to_string();
+                let _ = activity_log_service::log_activity(
+                    &state.db,
+                    &ctx.household_id,
+                    &ctx.user_id,
+                    Some(&target_user_id),
+                    ActivityType::MemberRoleChanged,
+                    Some("member"),
+                    None,
+                    Some(&details),
+                ).await;
+
+                let _ = audit_log_service::record(
+                    &state.db,
+                    &ctx.household_id,
+                    &ctx.user_id,
+                    Some(&target_user_id),
+                    AuditAction::OwnershipTransferred,
+                    target_role.map(|r| r.as_str()),
+                    Some(shared::Role::Owner.as_str()),
+                ).await;
+
+                return Ok(HttpResponse::Ok().json(ApiSuccess::new(membership)));
+            }
+            Err(e) => {
+                log::error!("Error transferring ownership: {:?}", e);
+                return Ok(HttpResponse::InternalServerError().json(ApiError {
+                    error: "internal_error".to_string(),
+                    message: "Failed to transfer ownership".to_string(),
+                }));
+            }
+        }
+    }
+
+    match household_service::update_member_role(&state.db, &ctx.household_id, &target_user_id, new_role).await {
+        Ok(membership) => {
+            // Log activity
+            let details = serde_json::json!({ "new_role": format!("{:?}", new_role) }).to_string();
+            let _ = activity_log_service::log_activity(
+                &state.db,
+                &ctx.household_id,
+                &ctx.user_id,
+                Some(&target_user_id),
+                ActivityType::MemberRoleChanged,
+                Some("member"),
+                None,
+                Some(&details),
+            ).await;
+
+            let _ = audit_log_service::record(
+                &state.db,
+                &ctx.household_id,
+                &ctx.user_id,
+                Some(&target_user_id),
+                AuditAction::RoleChanged,
+                target_role.map(|r| r.as_str()),
+                Some(new_role.as_str()),
+            ).await;
+
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(membership)))
+        }
+        Err(e) => {
+            log::error!("Error updating role: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to update role".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/members/{user_id}/permissions",
+    tag = "households",
+    summary = "Get member permissions",
+    params(("household_id" = Uuid, Path), ("user_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<MemberPermissionsResponse>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn get_member_permissions(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, target_user_id_str) = path.into_inner();
+
+    let target_user_id = match Uuid::parse_str(&target_user_id_str) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_id".to_string(),
+                message: "Invalid user ID format".to_string(),
+            }));
+        }
+    };
+
+    // Members can see their own permissions; member managers can see everyone's
+    if ctx.user_id != target_user_id
+        && !ctx.has_permission(&state, Permission::ManageMembers).await
+    {
+        return Ok(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "You do not have permission to view this member's permissions".to_string(),
+        }));
+    }
+
+    match permissions::get_member_permissions(&state.db, &ctx.household_id, &target_user_id).await {
+        Ok(Some((member_permissions, custom))) => Ok(HttpResponse::Ok().json(ApiSuccess::new(MemberPermissionsResponse {
+            user_id: target_user_id,
+            permissions: member_permissions,
+            custom,
+        }))),
+        Ok(None) => Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Member not found".to_string(),
+        })),
+        Err(e) => {
+            log::error!("Error fetching permissions: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch permissions".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/members/{user_id}/permissions",
+    tag = "households",
+    summary = "Update member permissions",
+    params(("household_id" = Uuid, Path), ("user_id" = Uuid, Path)),
+    request_body = UpdateMemberPermissionsRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<MemberPermissionsResponse>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn update_member_permissions(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+    body: web::Json<UpdateMemberPermissionsRequest>,
+) -> Result<HttpResponse> {
+    let (_, target_user_id_str) = path.into_inner();
 
-    let household_id = match Uuid::parse_str(&household_id_str) {
+    let target_user_id = match Uuid::parse_str(&target_user_id_str) {
         Ok(id) => id,
         Err(_) => {
             return Ok(HttpResponse::BadRequest().json(ApiError {
                 error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
+                message: "Invalid user ID format".to_string(),
+            }));
+        }
+    };
+
+    // Only owner can change permissions, just like roles
+    if !ctx.role().can_manage_roles() {
+        return Ok(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "Only owners can change member permissions".to_string(),
+        }));
+    }
+
+    match household_service::get_member_role(&state.db, &ctx.household_id, &target_user_id).await {
+        None => {
+            return Ok(HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: "Member not found".to_string(),
+            }));
+        }
+        Some(shared::Role::Owner) => {
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_role".to_string(),
+                message: "The owner always has every permission".to_string(),
             }));
         }
+        Some(_) => {}
+    }
+
+    let before = match permissions::get_member_permissions(&state.db, &ctx.household_id, &target_user_id).await {
+        Ok(Some((before, _))) => before,
+        Ok(None) | Err(_) => Default::default(),
     };
 
+    let request = body.into_inner();
+    if let Err(e) = permissions::set_member_permissions(&state.db, &ctx.household_id, &target_user_id, request.permissions.as_ref()).await {
+        log::error!("Error updating permissions: {:?}", e);
+        return Ok(HttpResponse::InternalServerError().json(ApiError {
+            error: "internal_error".to_string(),
+            message: "Failed to update permissions".to_string(),
+        }));
+    }
+
+    match permissions::get_member_permissions(&state.db, &ctx.household_id, &target_user_id).await {
+        Ok(Some((after, custom))) => {
+            let _ = audit_log_service::record(
+                &state.db,
+                &ctx.household_id,
+                &ctx.user_id,
+                Some(&target_user_id),
+                AuditAction::PermissionsChanged,
+                Some(&permissions::describe(&before)),
+                Some(&permissions::describe(&after)),
+            ).await;
+
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(MemberPermissionsResponse {
+                user_id: target_user_id,
+                permissions: after,
+                custom,
+            })))
+        }
+        Ok(None) => Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Member not found".to_string(),
+        })),
+        Err(e) => {
+            log::error!("Error fetching permissions: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch permissions".to_string(),
+            }))
+        }
+    }
+}
+
+/// `?window=week|month|all_time` (default all time)
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/leaderboard",
+    tag = "households",
+    summary = "Get leaderboard",
+    description = "`?window=week|month|all_time` (default all time)",
+    params(("household_id" = Uuid, Path), LeaderboardQuery),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<LeaderboardEntry>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn get_leaderboard(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    query: web::Query<LeaderboardQuery>,
+) -> Result<HttpResponse> {
+    match leaderboard_service::get_leaderboard(&state.db, &ctx.household_id, query.window).await {
+        Ok(leaderboard) => Ok(HttpResponse::Ok().json(ApiSuccess::new(leaderboard))),
+        Err(e) => {
+            log::error!("Error fetching leaderboard: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch leaderboard".to_string(),
+            }))
+        }
+    }
+}
+
+/// Manually adjust a member's points (add or remove)
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/members/{user_id}/points",
+    tag = "households",
+    summary = "Manually adjust a member's points (add or remove)",
+    params(("household_id" = Uuid, Path), ("user_id" = Uuid, Path)),
+    request_body = AdjustPointsRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<AdjustPointsResponse>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn adjust_member_points(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    req: actix_web::HttpRequest,
+    path: web::Path<(String, String)>,
+    body: web::Json<AdjustPointsRequest>,
+) -> Result<HttpResponse> {
+    let (_, target_user_id_str) = path.into_inner();
+
     let target_user_id = match Uuid::parse_str(&target_user_id_str) {
         Ok(id) => id,
         Err(_) => {
@@ -809,17 +1318,16 @@ async fn adjust_member_points(
         }
     };
 
-    // Only owner and admin can adjust points
-    let role = household_service::get_member_role(&state.db, &household_id, &current_user_id).await;
-    if !role.map(|r| r.can_manage_members()).unwrap_or(false) {
+    // Only members with the points permission can adjust points
+    if !ctx.has_permission(&state, Permission::AdjustPoints).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
-            message: "Only owners and admins can adjust member points".to_string(),
+            message: "You do not have permission to adjust member points".to_string(),
         }));
     }
 
     // Verify target user is a member
-    if !household_service::is_member(&state.db, &household_id, &target_user_id).await.unwrap_or(false) {
+    if !household_service::is_member(&state.db, &ctx.household_id, &target_user_id).await.unwrap_or(false) {
         return Ok(HttpResponse::BadRequest().json(ApiError {
             error: "invalid_user".to_string(),
             message: "Target user is not a member of this household".to_string(),
@@ -827,15 +1335,29 @@ async fn adjust_member_points(
     }
 
     let request = body.into_inner();
+    let source = points_service::TransactionSource {
+        actor_id: Some(ctx.user_id),
+        description: request.reason.clone().filter(|r| !r.trim().is_empty()),
+        ..Default::default()
+    };
 
-    match household_service::update_member_points(&state.db, &household_id, &target_user_id, request.points).await {
+    match points_service::apply_points(
+        &state.db,
+        &ctx.household_id,
+        &target_user_id,
+        request.points,
+        PointTransactionType::ManualAdjustment,
+        &source,
+    )
+    .await
+    {
         Ok(new_points) => {
             // Log activity
             let details = serde_json::json!({ "points": request.points }).to_string();
             let _ = activity_log_service::log_activity(
                 &state.db,
-                &household_id,
-                &current_user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 Some(&target_user_id),
                 ActivityType::PointsAdjusted,
                 Some("member"),
@@ -843,6 +1365,21 @@ async fn adjust_member_points(
                 Some(&details),
             ).await;
 
+            let before = (new_points - request.points).to_string();
+            let _ = audit_log_service::record(
+                &state.db,
+                &ctx.household_id,
+                &ctx.user_id,
+                Some(&target_user_id),
+                AuditAction::PointsAdjusted,
+                Some(&before),
+                Some(&new_points.to_string()),
+            ).await;
+
+            crate::handlers::websocket::broadcast_leaderboard(&req, &state.db, &ctx.household_id).await;
+            crate::handlers::reward_wishlist::notify_affordable(&req, &state.db, &ctx.household_id).await;
+            crate::handlers::point_goals::broadcast_progress(&req, &state.db, &ctx.household_id).await;
+
             Ok(HttpResponse::Ok().json(ApiSuccess::new(AdjustPointsResponse { new_points })))
         }
         Err(e) => {
@@ -855,106 +1392,194 @@ async fn adjust_member_points(
     }
 }
 
-/// Get household settings
-async fn get_household_settings(
+/// Set or clear a member's vacation window (self or owners/admins)
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/members/{user_id}/vacation",
+    tag = "households",
+    summary = "Set or clear a member's vacation window (self or owners/admins)",
+    params(("household_id" = Uuid, Path), ("user_id" = Uuid, Path)),
+    request_body = SetMemberVacationRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<HouseholdMembership>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn set_member_vacation(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+    body: web::Json<SetMemberVacationRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
+    let (_, target_user_id_str) = path.into_inner();
 
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
+    let target_user_id = match Uuid::parse_str(&target_user_id_str) {
         Ok(id) => id,
         Err(_) => {
             return Ok(HttpResponse::BadRequest().json(ApiError {
                 error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
+                message: "Invalid user ID format".to_string(),
             }));
         }
     };
 
-    // Check membership - any member can view settings
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
+    // Members manage their own vacation; member managers can manage anyone's
+    if ctx.user_id != target_user_id
+        && !ctx.has_permission(&state, Permission::ManageMembers).await
+    {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
+            message: "You do not have permission to set another member's vacation".to_string(),
         }));
     }
 
-    match settings_service::get_or_create_settings(&state.db, &household_id).await {
-        Ok(settings) => Ok(HttpResponse::Ok().json(ApiSuccess::new(settings))),
+    let request = body.into_inner();
+    if let (Some(start), Some(end)) = (request.vacation_start, request.vacation_end) {
+        if end < start {
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "validation_error".to_string(),
+                message: "Vacation end must not be before its start".to_string(),
+            }));
+        }
+    }
+
+    match household_service::set_member_vacation(
+        &state.db,
+        &ctx.household_id,
+        &target_user_id,
+        request.vacation_start,
+        request.vacation_end,
+    )
+    .await
+    {
+        Ok(membership) => Ok(HttpResponse::Ok().json(ApiSuccess::new(membership))),
+        Err(household_service::HouseholdError::NotFound) => Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_user".to_string(),
+            message: "Target user is not a member of this household".to_string(),
+        })),
         Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
+            log::error!("Error setting member vacation: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
                 error: "internal_error".to_string(),
-                message: "Failed to fetch settings".to_string(),
+                message: "Failed to set member vacation".to_string(),
             }))
         }
     }
 }
 
-/// Update household settings (owner only)
-async fn update_household_settings(
+/// List a member's point transactions, newest first
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/members/{user_id}/points/history",
+    tag = "households",
+    summary = "List a member's point transactions, newest first",
+    params(("household_id" = Uuid, Path), ("user_id" = Uuid, Path), ListPointHistoryRequest),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<PointHistoryPage>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn get_points_history(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
-    body: web::Json<UpdateHouseholdSettingsRequest>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+    query: web::Query<ListPointHistoryRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
+    let (_, target_user_id_str) = path.into_inner();
 
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
+    let target_user_id = match Uuid::parse_str(&target_user_id_str) {
         Ok(id) => id,
         Err(_) => {
             return Ok(HttpResponse::BadRequest().json(ApiError {
                 error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
+                message: "Invalid user ID format".to_string(),
             }));
         }
     };
 
-    // Check if Solo Mode is active - block all settings changes
-    let current_settings = settings_service::get_or_create_settings(&state.db, &household_id).await;
-    if let Ok(settings) = &current_settings {
-        if settings.solo_mode {
-            return Ok(HttpResponse::Forbidden().json(ApiError {
-                error: "solo_mode_active".to_string(),
-                message: "Settings cannot be changed while Solo Mode is active".to_string(),
-            }));
+    let limit = query.limit.unwrap_or(50).clamp(1, 100);
+    let offset = query.offset.unwrap_or(0).max(0);
+
+    match points_service::list_point_history(&state.db, &ctx.household_id, &target_user_id, limit, offset).await {
+        Ok(page) => Ok(HttpResponse::Ok().json(ApiSuccess::new(page))),
+        Err(e) => {
+            log::error!("Error fetching points history: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch points history".to_string(),
+            }))
         }
     }
+}
+
+/// Get household settings
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/settings",
+    tag = "households",
+    summary = "Get household settings",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<HouseholdSettings>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn get_household_settings(ctx: HouseholdContext) -> Result<HttpResponse> {
+    Ok(HttpResponse::Ok().json(ApiSuccess::new(ctx.settings)))
+}
+
+/// Update household settings (owner only)
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/settings",
+    tag = "households",
+    summary = "Update household settings (owner only)",
+    params(("household_id" = Uuid, Path)),
+    request_body = UpdateHouseholdSettingsRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<HouseholdSettings>),
+        Conflict,
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn update_household_settings(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    body: web::Json<UpdateHouseholdSettingsRequest>,
+) -> Result<HttpResponse> {
+    // Check if Solo Mode is active - block all settings changes
+    if ctx.settings.solo_mode {
+        return Ok(HttpResponse::Forbidden().json(ApiError {
+            error: "solo_mode_active".to_string(),
+            message: "Settings cannot be changed while Solo Mode is active".to_string(),
+        }));
+    }
 
     // Only owner can modify settings
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.map(|r| r == shared::Role::Owner).unwrap_or(false) {
+    if ctx.role() != shared::Role::Owner {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "Only owners can modify household settings".to_string(),
         }));
     }
 
-    match settings_service::update_settings(&state.db, &household_id, &body.into_inner()).await {
+    match settings_service::update_settings(&state.db, &ctx.household_id, &body.into_inner()).await {
         Ok(settings) => {
             // Log activity
             let _ = activity_log_service::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 None,
                 ActivityType::SettingsChanged,
                 Some("settings"),
@@ -962,8 +1587,27 @@ async fn update_household_settings(
                 None,
             ).await;
 
+            if let Some((before, after)) = audit_log_service::settings_diff(&ctx.settings, &settings) {
+                let _ = audit_log_service::record(
+                    &state.db,
+                    &ctx.household_id,
+                    &ctx.user_id,
+                    None,
+                    AuditAction::SettingsChanged,
+                    Some(&before),
+                    Some(&after),
+                ).await;
+            }
+
             Ok(HttpResponse::Ok().json(ApiSuccess::new(settings)))
         }
+        Err(settings_service::SettingsError::VersionConflict(current)) => {
+            Ok(HttpResponse::Conflict().json(VersionConflict {
+                error: "version_conflict".to_string(),
+                message: "The settings were changed in the meantime".to_string(),
+                current: *current,
+            }))
+        }
         Err(e) => {
             log::error!("Error updating settings: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
@@ -974,48 +1618,80 @@ async fn update_household_settings(
     }
 }
 
-/// Activate Solo Mode (owner only)
-async fn activate_solo_mode(
+/// List the household's admin audit log, newest first (owner only)
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/audit-log",
+    tag = "households",
+    summary = "List the household's admin audit log, newest first (owner only)",
+    params(("household_id" = Uuid, Path), ListAdminAuditLogRequest),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<AdminAuditLogPage>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn get_audit_log(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
+    query: web::Query<ListAdminAuditLogRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
+    if ctx.role() != shared::Role::Owner {
+        return Ok(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "Only owners can view the audit log".to_string(),
+        }));
+    }
 
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
+    let limit = query.limit.unwrap_or(50).clamp(1, 100);
+    let offset = query.offset.unwrap_or(0).max(0);
+
+    match audit_log_service::list_entries(&state.db, &ctx.household_id, limit, offset).await {
+        Ok(page) => Ok(HttpResponse::Ok().json(ApiSuccess::new(page))),
+        Err(e) => {
+            log::error!("Error fetching audit log: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch audit log".to_string(),
+            }))
         }
-    };
+    }
+}
 
+/// Activate Solo Mode (owner only)
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/solo-mode/activate",
+    tag = "households",
+    summary = "Activate Solo Mode (owner only)",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<HouseholdSettings>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn activate_solo_mode(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
     // Only owner can activate Solo Mode
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.map(|r| r == shared::Role::Owner).unwrap_or(false) {
+    if ctx.role() != shared::Role::Owner {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "Only owners can activate Solo Mode".to_string(),
         }));
     }
 
-    match solo_mode_service::activate_solo_mode(&state.db, &household_id).await {
+    match solo_mode_service::activate_solo_mode(&state.db, &ctx.household_id).await {
         Ok(settings) => {
             // Log activity
             let _ = activity_log_service::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 None,
                 ActivityType::SettingsChanged,
                 Some("solo_mode_activated"),
@@ -1043,47 +1719,30 @@ async fn activate_solo_mode(
 }
 
 /// Request to exit Solo Mode (any member)
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/solo-mode/request-exit",
+    tag = "households",
+    summary = "Request to exit Solo Mode (any member)",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<HouseholdSettings>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn request_solo_mode_exit(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    // Any member can request exit
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if role.is_none() {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    match solo_mode_service::request_solo_mode_exit(&state.db, &household_id).await {
+    match solo_mode_service::request_solo_mode_exit(&state.db, &ctx.household_id).await {
         Ok(settings) => {
             // Log activity
             let _ = activity_log_service::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 None,
                 ActivityType::SettingsChanged,
                 Some("solo_mode_exit_requested"),
@@ -1117,47 +1776,30 @@ async fn request_solo_mode_exit(
 }
 
 /// Cancel a pending Solo Mode exit request (any member)
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/solo-mode/cancel-exit",
+    tag = "households",
+    summary = "Cancel a pending Solo Mode exit request (any member)",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<HouseholdSettings>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn cancel_solo_mode_exit(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    // Any member can cancel exit
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if role.is_none() {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    match solo_mode_service::cancel_solo_mode_exit(&state.db, &household_id).await {
+    match solo_mode_service::cancel_solo_mode_exit(&state.db, &ctx.household_id).await {
         Ok(settings) => {
             // Log activity
             let _ = activity_log_service::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 None,
                 ActivityType::SettingsChanged,
                 Some("solo_mode_exit_cancelled"),
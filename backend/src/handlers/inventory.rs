@@ -0,0 +1,208 @@
+use actix_web::{web, HttpResponse, Result};
+use shared::{
+    AdjustInventoryRequest, ApiError, ApiSuccess, CreateInventoryItemRequest, InventoryAdjustment,
+    InventoryItem, UpdateInventoryItemRequest,
+};
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::inventory as inventory_service;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/inventory")
+            .route("", web::get().to(list_items))
+            .route("", web::post().to(create_item))
+            .route("/{item_id}", web::put().to(update_item))
+            .route("/{item_id}", web::delete().to(delete_item))
+            .route("/{item_id}/adjust", web::post().to(adjust_item)),
+    );
+}
+
+fn parse_item_id(id: &str) -> std::result::Result<Uuid, HttpResponse> {
+    Uuid::parse_str(id).map_err(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_id".to_string(),
+            message: "Invalid item ID format".to_string(),
+        })
+    })
+}
+
+fn name_required() -> HttpResponse {
+    HttpResponse::BadRequest().json(ApiError {
+        error: "validation_error".to_string(),
+        message: "Item name is required".to_string(),
+    })
+}
+
+fn inventory_error_response(error: inventory_service::InventoryError, context: &str) -> HttpResponse {
+    match error {
+        inventory_service::InventoryError::NotFound => HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Inventory item not found".to_string(),
+        }),
+        inventory_service::InventoryError::InvalidQuantity => HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: error.to_string(),
+        }),
+        e => {
+            log::error!("Error {}: {:?}", context, e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: format!("Failed {}", context),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/inventory",
+    operation_id = "inventory_list_items",
+    tag = "inventory",
+    summary = "List items",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<InventoryItem>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_items(state: web::Data<AppState>, ctx: HouseholdContext) -> Result<HttpResponse> {
+    match inventory_service::list_items(&state.db, &ctx.household_id).await {
+        Ok(items) => Ok(HttpResponse::Ok().json(ApiSuccess::new(items))),
+        Err(e) => Ok(inventory_error_response(e, "listing inventory")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/inventory",
+    tag = "inventory",
+    summary = "Create item",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreateInventoryItemRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<InventoryItem>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn create_item(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    body: web::Json<CreateInventoryItemRequest>,
+) -> Result<HttpResponse> {
+    let request = body.into_inner();
+    if request.name.trim().is_empty() {
+        return Ok(name_required());
+    }
+
+    match inventory_service::create_item(&state.db, &ctx.household_id, &ctx.user_id, &request).await {
+        Ok(item) => Ok(HttpResponse::Created().json(ApiSuccess::new(item))),
+        Err(e) => Ok(inventory_error_response(e, "creating inventory item")),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/inventory/{item_id}",
+    operation_id = "inventory_update_item",
+    tag = "inventory",
+    summary = "Update item",
+    params(("household_id" = Uuid, Path), ("item_id" = Uuid, Path)),
+    request_body = UpdateInventoryItemRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<InventoryItem>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn update_item(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+    body: web::Json<UpdateInventoryItemRequest>,
+) -> Result<HttpResponse> {
+    let (_, item_id_str) = path.into_inner();
+    let item_id = match parse_item_id(&item_id_str) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    let request = body.into_inner();
+    if request.name.as_ref().is_some_and(|n| n.trim().is_empty()) {
+        return Ok(name_required());
+    }
+
+    match inventory_service::update_item(&state.db, &ctx.household_id, &ctx.user_id, &item_id, &request).await {
+        Ok(item) => Ok(HttpResponse::Ok().json(ApiSuccess::new(item))),
+        Err(e) => Ok(inventory_error_response(e, "updating inventory item")),
+    }
+}
+
+/// Use up or restock an item; falling below the minimum puts it on the shopping list
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/inventory/{item_id}/adjust",
+    tag = "inventory",
+    summary = "Use up or restock an item; falling below the minimum puts it on the shopping list",
+    params(("household_id" = Uuid, Path), ("item_id" = Uuid, Path)),
+    request_body = AdjustInventoryRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<InventoryAdjustment>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn adjust_item(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+    body: web::Json<AdjustInventoryRequest>,
+) -> Result<HttpResponse> {
+    let (_, item_id_str) = path.into_inner();
+    let item_id = match parse_item_id(&item_id_str) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match inventory_service::adjust_item(&state.db, &ctx.household_id, &ctx.user_id, &item_id, &body).await {
+        Ok(adjustment) => Ok(HttpResponse::Ok().json(ApiSuccess::new(adjustment))),
+        Err(e) => Ok(inventory_error_response(e, "adjusting inventory item")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/inventory/{item_id}",
+    operation_id = "inventory_delete_item",
+    tag = "inventory",
+    summary = "Delete item",
+    params(("household_id" = Uuid, Path), ("item_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn delete_item(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, item_id_str) = path.into_inner();
+    let item_id = match parse_item_id(&item_id_str) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match inventory_service::delete_item(&state.db, &ctx.household_id, &item_id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(inventory_error_response(e, "deleting inventory item")),
+    }
+}
@@ -1,7 +1,8 @@
 use actix_web::{web, HttpResponse, Result};
-use shared::{ActivityType, ApiError, ApiSuccess};
+use shared::{ActivityType, ApiError, ApiSuccess, HouseholdMembership, InvitationWithHousehold, RedeemInviteCodeRequest};
 use uuid::Uuid;
 
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
 use crate::models::AppState;
 use crate::services::{activity_logs, auth as auth_service, invitations as invitation_service};
 
@@ -9,12 +10,24 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/invitations")
             .route("", web::get().to(list_user_invitations))
+            .route("/redeem-code", web::post().to(redeem_invite_code))
             .route("/{id}/accept", web::post().to(accept_invitation))
             .route("/{id}/decline", web::post().to(decline_invitation)),
     );
 }
 
 /// Get current user's pending invitations
+#[utoipa::path(
+    get,
+    path = "/api/invitations",
+    tag = "invitations",
+    summary = "Get current user's pending invitations",
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<InvitationWithHousehold>>),
+        Unauthorized,
+        NotFound,
+    ),
+)]
 async fn list_user_invitations(
     state: web::Data<AppState>,
     req: actix_web::HttpRequest,
@@ -60,6 +73,20 @@ async fn list_user_invitations(
 }
 
 /// Accept an invitation
+#[utoipa::path(
+    post,
+    path = "/api/invitations/{id}/accept",
+    tag = "invitations",
+    summary = "Accept an invitation",
+    params(("id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<HouseholdMembership>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn accept_invitation(
     state: web::Data<AppState>,
     req: actix_web::HttpRequest,
@@ -172,6 +199,14 @@ async fn accept_invitation(
 }
 
 /// Decline an invitation
+#[utoipa::path(
+    post,
+    path = "/api/invitations/{id}/decline",
+    tag = "invitations",
+    summary = "Decline an invitation",
+    params(("id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
 async fn decline_invitation(
     state: web::Data<AppState>,
     req: actix_web::HttpRequest,
@@ -238,3 +273,96 @@ async fn decline_invitation(
         }
     }
 }
+
+/// Join a household with a shareable invite code
+#[utoipa::path(
+    post,
+    path = "/api/invitations/redeem-code",
+    tag = "invitations",
+    summary = "Join a household with a shareable invite code",
+    request_body = RedeemInviteCodeRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<HouseholdMembership>),
+        BadRequest,
+        Unauthorized,
+        NotFound,
+    ),
+)]
+async fn redeem_invite_code(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    body: web::Json<RedeemInviteCodeRequest>,
+) -> Result<HttpResponse> {
+    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::Unauthorized().json(ApiError {
+                error: "unauthorized".to_string(),
+                message: "Invalid or missing token".to_string(),
+            }));
+        }
+    };
+
+    let user = match auth_service::get_user_by_id(&state.db, &user_id).await {
+        Ok(Some(u)) => u,
+        Ok(None) => {
+            return Ok(HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: "User not found".to_string(),
+            }));
+        }
+        Err(e) => {
+            log::error!("Error fetching user: {:?}", e);
+            return Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch user".to_string(),
+            }));
+        }
+    };
+
+    // Codes are short, so failed guesses count against the brute-force limiter
+    let rate_limit_key = format!("invite-code:{}", user_id);
+    if !state.login_rate_limiter.check(&rate_limit_key) {
+        return Ok(HttpResponse::TooManyRequests().json(ApiError {
+            error: "rate_limited".to_string(),
+            message: "Too many invalid codes. Please try again later.".to_string(),
+        }));
+    }
+
+    match invitation_service::redeem_invite_code(&state.db, &body.code, &user).await {
+        Ok(membership) => {
+            let _ = activity_logs::log_activity(
+                &state.db,
+                &membership.household_id,
+                &user_id,
+                None,
+                ActivityType::MemberJoined,
+                Some("member"),
+                None,
+                None,
+            ).await;
+
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(membership)))
+        }
+        Err(invitation_service::InvitationError::InvalidCode) => {
+            state.login_rate_limiter.record(&rate_limit_key);
+            Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_code".to_string(),
+                message: "This invite code is invalid, expired or used up".to_string(),
+            }))
+        }
+        Err(invitation_service::InvitationError::AlreadyMember) => {
+            Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "already_member".to_string(),
+                message: "You are already a member of this household".to_string(),
+            }))
+        }
+        Err(e) => {
+            log::error!("Error redeeming invite code: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to redeem invite code".to_string(),
+            }))
+        }
+    }
+}
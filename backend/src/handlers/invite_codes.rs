@@ -0,0 +1,210 @@
+use actix_web::{web, HttpResponse, Result};
+use shared::{ApiError, ApiSuccess, CreateInviteCodeRequest, InviteCode, Permission, Role};
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::invitations as invitation_service;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/invite-codes")
+            .route("", web::get().to(list_invite_codes))
+            .route("", web::post().to(create_invite_code))
+            .route("/{code_id}", web::delete().to(revoke_invite_code))
+            .route("/{code_id}/qr", web::get().to(invite_code_qr)),
+    );
+}
+
+/// Require permission to manage members
+async fn require_manager(state: &AppState, ctx: &HouseholdContext) -> std::result::Result<(), HttpResponse> {
+    if !ctx.has_permission(state, Permission::ManageMembers).await {
+        return Err(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "You do not have permission to manage invite codes".to_string(),
+        }));
+    }
+    Ok(())
+}
+
+fn parse_code_id(id: &str) -> std::result::Result<Uuid, HttpResponse> {
+    Uuid::parse_str(id).map_err(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_id".to_string(),
+            message: "Invalid invite code ID format".to_string(),
+        })
+    })
+}
+
+fn invite_code_error_response(error: invitation_service::InvitationError, context: &str) -> HttpResponse {
+    use invitation_service::InvitationError;
+
+    match error {
+        InvitationError::NotFound => HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Invite code not found".to_string(),
+        }),
+        InvitationError::InvalidCodeSettings => HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: error.to_string(),
+        }),
+        e => {
+            log::error!("Error {}: {:?}", context, e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: format!("Failed {}", context),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/invite-codes",
+    tag = "invite_codes",
+    summary = "List invite codes",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<InviteCode>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_invite_codes(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_manager(&state, &ctx).await {
+        return Ok(response);
+    }
+
+    match invitation_service::list_invite_codes(&state.db, &ctx.household_id).await {
+        Ok(codes) => Ok(HttpResponse::Ok().json(ApiSuccess::new(codes))),
+        Err(e) => Ok(invite_code_error_response(e, "listing invite codes")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/invite-codes",
+    tag = "invite_codes",
+    summary = "Create invite code",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreateInviteCodeRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<InviteCode>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn create_invite_code(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    body: web::Json<CreateInviteCodeRequest>,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_manager(&state, &ctx).await {
+        return Ok(response);
+    }
+
+    // Same role rules as targeted invitations
+    let role = body.role.unwrap_or(Role::Member);
+    if role == Role::Owner {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_role".to_string(),
+            message: "Cannot invite as owner".to_string(),
+        }));
+    }
+    if role == Role::Admin && ctx.role() != Role::Owner {
+        return Ok(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "Only owners can invite as admin".to_string(),
+        }));
+    }
+
+    match invitation_service::create_invite_code(&state.db, &ctx.household_id, &body, role, &ctx.user_id).await {
+        Ok(code) => Ok(HttpResponse::Created().json(ApiSuccess::new(code))),
+        Err(e) => Ok(invite_code_error_response(e, "creating invite code")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/invite-codes/{code_id}",
+    tag = "invite_codes",
+    summary = "Revoke invite code",
+    params(("household_id" = Uuid, Path), ("code_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn revoke_invite_code(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_manager(&state, &ctx).await {
+        return Ok(response);
+    }
+    let (_, code_id_str) = path.into_inner();
+    let code_id = match parse_code_id(&code_id_str) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match invitation_service::revoke_invite_code(&state.db, &ctx.household_id, &code_id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(invite_code_error_response(e, "revoking invite code")),
+    }
+}
+
+/// The code's join link as a scannable QR code (SVG)
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/invite-codes/{code_id}/qr",
+    tag = "invite_codes",
+    summary = "The code's join link as a scannable QR code (SVG)",
+    params(("household_id" = Uuid, Path), ("code_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", content_type = "image/svg+xml", body = String),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn invite_code_qr(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_manager(&state, &ctx).await {
+        return Ok(response);
+    }
+    let (_, code_id_str) = path.into_inner();
+    let code_id = match parse_code_id(&code_id_str) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    let code = match invitation_service::get_invite_code(&state.db, &ctx.household_id, &code_id).await {
+        Ok(code) => code,
+        Err(e) => return Ok(invite_code_error_response(e, "loading invite code")),
+    };
+
+    let link = invitation_service::invite_link(&state.config.public_url, &code.code);
+    match invitation_service::qr_code_svg(&link) {
+        Ok(svg) => Ok(HttpResponse::Ok()
+            .content_type("image/svg+xml")
+            .insert_header(("Cache-Control", "private, no-store"))
+            .insert_header(("X-Content-Type-Options", "nosniff"))
+            .body(svg)),
+        Err(e) => {
+            log::error!("Error rendering invite QR code: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed rendering QR code".to_string(),
+            }))
+        }
+    }
+}
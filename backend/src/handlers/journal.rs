@@ -1,59 +1,93 @@
 use actix_web::{web, HttpResponse, Result};
-use shared::{ApiError, ApiSuccess, CreateJournalEntryRequest, UpdateJournalEntryRequest};
+use chrono::{Days, NaiveDate, Utc};
+use serde::Deserialize;
+use shared::{
+    ApiError, ApiSuccess, AttachmentEntity, CreateJournalEntryRequest, JournalEntry,
+    JournalEntryWithUser, MoodPoint, UpdateJournalEntryRequest,
+};
 use uuid::Uuid;
 
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
 use crate::models::AppState;
-use crate::services::{households as household_service, journal as journal_service};
+use crate::services::{attachments as attachment_service, journal as journal_service};
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/journal")
             .route("", web::get().to(list_journal_entries))
             .route("", web::post().to(create_journal_entry))
+            .route("/mood", web::get().to(get_mood_over_time))
             .route("/{entry_id}", web::get().to(get_journal_entry))
             .route("/{entry_id}", web::put().to(update_journal_entry))
             .route("/{entry_id}", web::delete().to(delete_journal_entry)),
     );
 }
 
-async fn list_journal_entries(
-    state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
-) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct MoodQuery {
+    /// First day of the range (YYYY-MM-DD); defaults to 30 days before the end
+    pub start: Option<String>,
+    /// Last day of the range (YYYY-MM-DD); defaults to today
+    pub end: Option<String>,
+}
 
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+fn invalid_mood_response() -> HttpResponse {
+    HttpResponse::BadRequest().json(ApiError {
+        error: "validation_error".to_string(),
+        message: journal_service::JournalError::InvalidMood.to_string(),
+    })
+}
 
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
-    {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
+/// The journal entry, if it exists in the household from the path
+async fn household_entry(state: &AppState, ctx: &HouseholdContext, entry_id: &Uuid) -> Result<JournalEntry, HttpResponse> {
+    match journal_service::get_journal_entry(&state.db, entry_id).await {
+        Ok(Some(entry)) => {
+            ctx.check_owns(&entry.household_id, "Journal entry")?;
+            Ok(entry)
+        }
+        Ok(None) => Err(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Journal entry not found".to_string(),
+        })),
+        Err(e) => {
+            log::error!("Error fetching journal entry: {:?}", e);
+            Err(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch journal entry".to_string(),
+            }))
+        }
     }
+}
 
-    match journal_service::list_journal_entries(&state.db, &household_id, &user_id).await {
-        Ok(entries) => Ok(HttpResponse::Ok().json(ApiSuccess::new(entries))),
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/journal",
+    tag = "journal",
+    summary = "List journal entries",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<JournalEntryWithUser>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_journal_entries(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
+    match journal_service::list_journal_entries(&state.db, &ctx.household_id, &ctx.user_id).await {
+        Ok(mut entries) => {
+            let mut attachments = attachment_service::list_for_household(&state.db, &ctx.household_id, AttachmentEntity::JournalEntry)
+                .await
+                .unwrap_or_default();
+            for item in &mut entries {
+                item.attachments = attachments.remove(&item.entry.id).unwrap_or_default();
+            }
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(entries)))
+        }
         Err(e) => {
             log::error!("Error listing journal entries: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
@@ -64,43 +98,25 @@ async fn list_journal_entries(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/journal",
+    tag = "journal",
+    summary = "Create journal entry",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreateJournalEntryRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<shared::JournalEntry>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn create_journal_entry(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
     body: web::Json<CreateJournalEntryRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    // Check membership (any member can create journal entries)
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
-    {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
     let request = body.into_inner();
     if request.content.trim().is_empty() {
         return Ok(HttpResponse::BadRequest().json(ApiError {
@@ -109,8 +125,9 @@ async fn create_journal_entry(
         }));
     }
 
-    match journal_service::create_journal_entry(&state.db, &household_id, &user_id, &request).await {
+    match journal_service::create_journal_entry(&state.db, &ctx.household_id, &ctx.user_id, &request).await {
         Ok(entry) => Ok(HttpResponse::Created().json(ApiSuccess::new(entry))),
+        Err(journal_service::JournalError::InvalidMood) => Ok(invalid_mood_response()),
         Err(e) => {
             log::error!("Error creating journal entry: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
@@ -121,32 +138,77 @@ async fn create_journal_entry(
     }
 }
 
-async fn get_journal_entry(
+/// The caller's average mood per day, for the statistics page
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/journal/mood",
+    tag = "journal",
+    summary = "The caller's average mood per day, for the statistics page",
+    params(("household_id" = Uuid, Path), MoodQuery),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<MoodPoint>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn get_mood_over_time(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<(String, String)>,
+    ctx: HouseholdContext,
+    query: web::Query<MoodQuery>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
+    let parse = |value: &Option<String>| value.as_deref().map(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d")).transpose();
+    let (Ok(end), Ok(start)) = (parse(&query.end), parse(&query.start)) else {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_date".to_string(),
+            message: "Invalid date format. Use YYYY-MM-DD".to_string(),
+        }));
+    };
+    let end = end.unwrap_or_else(|| Utc::now().date_naive());
+    let start = start.or_else(|| end.checked_sub_days(Days::new(30)));
+    let Some(start) = start.filter(|start| shared::is_supported_date(*start) && shared::is_supported_date(end)) else {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_date".to_string(),
+            message: format!(
+                "Dates must lie between the years {} and {}",
+                shared::SUPPORTED_YEARS.start(),
+                shared::SUPPORTED_YEARS.end()
+            ),
+        }));
     };
 
-    let (household_id_str, entry_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
+    match journal_service::mood_over_time(&state.db, &ctx.household_id, &ctx.user_id, start, end).await {
+        Ok(points) => Ok(HttpResponse::Ok().json(ApiSuccess::new(points))),
+        Err(e) => {
+            log::error!("Error loading mood over time: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to load mood over time".to_string(),
+            }))
         }
-    };
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/journal/{entry_id}",
+    tag = "journal",
+    summary = "Get journal entry",
+    params(("household_id" = Uuid, Path), ("entry_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<shared::JournalEntry>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn get_journal_entry(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, entry_id_str) = path.into_inner();
 
     let entry_id = match Uuid::parse_str(&entry_id_str) {
         Ok(id) => id,
@@ -158,69 +220,43 @@ async fn get_journal_entry(
         }
     };
 
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
-    {
+    let entry = match household_entry(&state, &ctx, &entry_id).await {
+        Ok(entry) => entry,
+        Err(response) => return Ok(response),
+    };
+
+    // Check if user can view this entry
+    if !journal_service::can_view_entry(&entry, &ctx.user_id) {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
+            message: "You do not have permission to view this journal entry".to_string(),
         }));
     }
-
-    match journal_service::get_journal_entry(&state.db, &entry_id).await {
-        Ok(Some(entry)) => {
-            // Check if user can view this entry
-            if !journal_service::can_view_entry(&entry, &user_id) {
-                return Ok(HttpResponse::Forbidden().json(ApiError {
-                    error: "forbidden".to_string(),
-                    message: "You do not have permission to view this journal entry".to_string(),
-                }));
-            }
-            Ok(HttpResponse::Ok().json(ApiSuccess::new(entry)))
-        }
-        Ok(None) => Ok(HttpResponse::NotFound().json(ApiError {
-            error: "not_found".to_string(),
-            message: "Journal entry not found".to_string(),
-        })),
-        Err(e) => {
-            log::error!("Error fetching journal entry: {:?}", e);
-            Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch journal entry".to_string(),
-            }))
-        }
-    }
+    Ok(HttpResponse::Ok().json(ApiSuccess::new(entry)))
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/journal/{entry_id}",
+    tag = "journal",
+    summary = "Update journal entry",
+    params(("household_id" = Uuid, Path), ("entry_id" = Uuid, Path)),
+    request_body = UpdateJournalEntryRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<shared::JournalEntry>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn update_journal_entry(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
     body: web::Json<UpdateJournalEntryRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, entry_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, entry_id_str) = path.into_inner();
 
     let entry_id = match Uuid::parse_str(&entry_id_str) {
         Ok(id) => id,
@@ -232,21 +268,15 @@ async fn update_journal_entry(
         }
     };
 
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
-    {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
+    if let Err(response) = household_entry(&state, &ctx, &entry_id).await {
+        return Ok(response);
     }
 
     let request = body.into_inner();
 
-    match journal_service::update_journal_entry(&state.db, &entry_id, &user_id, &request).await {
+    match journal_service::update_journal_entry(&state.db, &entry_id, &ctx.user_id, &request).await {
         Ok(entry) => Ok(HttpResponse::Ok().json(ApiSuccess::new(entry))),
+        Err(journal_service::JournalError::InvalidMood) => Ok(invalid_mood_response()),
         Err(journal_service::JournalError::NotFound) => Ok(HttpResponse::NotFound().json(ApiError {
             error: "not_found".to_string(),
             message: "Journal entry not found".to_string(),
@@ -267,32 +297,20 @@ async fn update_journal_entry(
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/journal/{entry_id}",
+    tag = "journal",
+    summary = "Delete journal entry",
+    params(("household_id" = Uuid, Path), ("entry_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
 async fn delete_journal_entry(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, entry_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, entry_id_str) = path.into_inner();
 
     let entry_id = match Uuid::parse_str(&entry_id_str) {
         Ok(id) => id,
@@ -304,18 +322,12 @@ async fn delete_journal_entry(
         }
     };
 
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
-    {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
+    if let Err(response) = household_entry(&state, &ctx, &entry_id).await {
+        return Ok(response);
     }
 
-    match journal_service::delete_journal_entry(&state.db, &entry_id, &user_id).await {
+    match journal_service::delete_journal_entry(&state.db, &entry_id, &ctx.user_id).await {
+        // Attachments stay until the item is purged from the trash
         Ok(_) => Ok(HttpResponse::NoContent().finish()),
         Err(journal_service::JournalError::NotFound) => Ok(HttpResponse::NotFound().json(ApiError {
             error: "not_found".to_string(),
@@ -9,16 +9,40 @@ use std::path::PathBuf;
 use crate::config::Config;
 
 /// Get Impressum content
+#[utoipa::path(
+    get,
+    path = "/api/legal/impressum",
+    tag = "legal",
+    summary = "Get Impressum content",
+    responses((status = 200, description = "Success", content_type = "text/markdown", body = String)),
+    security(()),
+)]
 pub async fn get_impressum(config: web::Data<Config>) -> impl Responder {
     get_legal_file(&config, "impressum.md").await
 }
 
 /// Get Datenschutz (Privacy Policy) content
+#[utoipa::path(
+    get,
+    path = "/api/legal/datenschutz",
+    tag = "legal",
+    summary = "Get Datenschutz (Privacy Policy) content",
+    responses((status = 200, description = "Success", content_type = "text/markdown", body = String)),
+    security(()),
+)]
 pub async fn get_datenschutz(config: web::Data<Config>) -> impl Responder {
     get_legal_file(&config, "datenschutz.md").await
 }
 
 /// Get AGB (Terms of Service) content
+#[utoipa::path(
+    get,
+    path = "/api/legal/agb",
+    tag = "legal",
+    summary = "Get AGB (Terms of Service) content",
+    responses((status = 200, description = "Success", content_type = "text/markdown", body = String)),
+    security(()),
+)]
 pub async fn get_agb(config: web::Data<Config>) -> impl Responder {
     get_legal_file(&config, "agb.md").await
 }
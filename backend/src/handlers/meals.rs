@@ -0,0 +1,337 @@
+use actix_web::{web, HttpResponse, Result};
+use shared::{
+    ApiError, ApiSuccess, CreateRecipeRequest, MealPlanEntry, MealPlanQuery, Recipe,
+    SetMealPlanEntryRequest, ShoppingListItem, UpdateRecipeRequest, WeeklyMealPlan,
+};
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::handlers::shopping_list::shopping_list_error_response;
+use crate::services::{
+    meals as meal_service, shopping_list as shopping_list_service,
+};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/recipes")
+            .route("", web::get().to(list_recipes))
+            .route("", web::post().to(create_recipe))
+            .route("/{recipe_id}", web::get().to(get_recipe))
+            .route("/{recipe_id}", web::put().to(update_recipe))
+            .route("/{recipe_id}", web::delete().to(delete_recipe))
+            .route(
+                "/{recipe_id}/add-to-shopping-list",
+                web::post().to(add_recipe_to_shopping_list),
+            ),
+    )
+    .service(
+        web::scope("/meals")
+            .route("", web::get().to(get_week_plan))
+            .route("", web::put().to(set_plan_entry))
+            .route("/{entry_id}", web::delete().to(delete_plan_entry)),
+    );
+}
+
+fn parse_id(id: &str, label: &str) -> std::result::Result<Uuid, HttpResponse> {
+    Uuid::parse_str(id).map_err(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_id".to_string(),
+            message: format!("Invalid {} ID format", label),
+        })
+    })
+}
+
+fn meal_error_response(error: meal_service::MealError, context: &str) -> HttpResponse {
+    match error {
+        meal_service::MealError::RecipeNotFound => HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Recipe not found".to_string(),
+        }),
+        meal_service::MealError::EntryNotFound => HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Meal plan entry not found".to_string(),
+        }),
+        meal_service::MealError::EmptyEntry => HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: error.to_string(),
+        }),
+        meal_service::MealError::InvalidDate => HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_date".to_string(),
+            message: error.to_string(),
+        }),
+        meal_service::MealError::DatabaseError(e) => {
+            log::error!("Error {}: {:?}", context, e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: format!("Failed {}", context),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/recipes",
+    tag = "meals",
+    summary = "List recipes",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<Recipe>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_recipes(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
+    match meal_service::list_recipes(&state.db, &ctx.household_id).await {
+        Ok(recipes) => Ok(HttpResponse::Ok().json(ApiSuccess::new(recipes))),
+        Err(e) => Ok(meal_error_response(e, "listing recipes")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/recipes",
+    tag = "meals",
+    summary = "Create recipe",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreateRecipeRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<Recipe>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn create_recipe(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    body: web::Json<CreateRecipeRequest>,
+) -> Result<HttpResponse> {
+    let request = body.into_inner();
+    if request.name.trim().is_empty() {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "Recipe name is required".to_string(),
+        }));
+    }
+
+    match meal_service::create_recipe(&state.db, &ctx.household_id, &ctx.user_id, &request).await {
+        Ok(recipe) => Ok(HttpResponse::Created().json(ApiSuccess::new(recipe))),
+        Err(e) => Ok(meal_error_response(e, "creating recipe")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/recipes/{recipe_id}",
+    tag = "meals",
+    summary = "Get recipe",
+    params(("household_id" = Uuid, Path), ("recipe_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Recipe>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn get_recipe(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, recipe_id_str) = path.into_inner();
+        let recipe_id = match parse_id(&recipe_id_str, "recipe") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match meal_service::get_recipe(&state.db, &ctx.household_id, &recipe_id).await {
+        Ok(Some(recipe)) => Ok(HttpResponse::Ok().json(ApiSuccess::new(recipe))),
+        Ok(None) => Ok(meal_error_response(meal_service::MealError::RecipeNotFound, "fetching recipe")),
+        Err(e) => Ok(meal_error_response(e, "fetching recipe")),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/recipes/{recipe_id}",
+    tag = "meals",
+    summary = "Update recipe",
+    params(("household_id" = Uuid, Path), ("recipe_id" = Uuid, Path)),
+    request_body = UpdateRecipeRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Recipe>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn update_recipe(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+    body: web::Json<UpdateRecipeRequest>,
+) -> Result<HttpResponse> {
+    let (_, recipe_id_str) = path.into_inner();
+        let recipe_id = match parse_id(&recipe_id_str, "recipe") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    let request = body.into_inner();
+    if request.name.as_ref().is_some_and(|n| n.trim().is_empty()) {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "Recipe name is required".to_string(),
+        }));
+    }
+
+    match meal_service::update_recipe(&state.db, &ctx.household_id, &recipe_id, &request).await {
+        Ok(recipe) => Ok(HttpResponse::Ok().json(ApiSuccess::new(recipe))),
+        Err(e) => Ok(meal_error_response(e, "updating recipe")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/recipes/{recipe_id}",
+    tag = "meals",
+    summary = "Delete recipe",
+    params(("household_id" = Uuid, Path), ("recipe_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn delete_recipe(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, recipe_id_str) = path.into_inner();
+        let recipe_id = match parse_id(&recipe_id_str, "recipe") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match meal_service::delete_recipe(&state.db, &ctx.household_id, &recipe_id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(meal_error_response(e, "deleting recipe")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/recipes/{recipe_id}/add-to-shopping-list",
+    tag = "meals",
+    summary = "Add recipe to shopping list",
+    params(("household_id" = Uuid, Path), ("recipe_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<ShoppingListItem>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn add_recipe_to_shopping_list(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, recipe_id_str) = path.into_inner();
+        let recipe_id = match parse_id(&recipe_id_str, "recipe") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match shopping_list_service::add_recipe_ingredients(&state.db, &ctx.household_id, &ctx.user_id, &recipe_id)
+        .await
+    {
+        Ok(items) => Ok(HttpResponse::Ok().json(ApiSuccess::new(items))),
+        Err(e) => Ok(shopping_list_error_response(e, "adding recipe to shopping list")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/meals",
+    tag = "meals",
+    summary = "Get week plan",
+    params(("household_id" = Uuid, Path), MealPlanQuery),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<WeeklyMealPlan>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn get_week_plan(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    query: web::Query<MealPlanQuery>,
+) -> Result<HttpResponse> {
+    let date = query
+        .week_start
+        .unwrap_or_else(|| chrono::Utc::now().date_naive());
+
+    match meal_service::get_week_plan(&state.db, &ctx.household_id, date).await {
+        Ok(plan) => Ok(HttpResponse::Ok().json(ApiSuccess::new(plan))),
+        Err(e) => Ok(meal_error_response(e, "loading meal plan")),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/meals",
+    tag = "meals",
+    summary = "Set plan entry",
+    params(("household_id" = Uuid, Path)),
+    request_body = SetMealPlanEntryRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<MealPlanEntry>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn set_plan_entry(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    body: web::Json<SetMealPlanEntryRequest>,
+) -> Result<HttpResponse> {
+    match meal_service::set_plan_entry(&state.db, &ctx.household_id, &ctx.user_id, &body.into_inner()).await {
+        Ok(entry) => Ok(HttpResponse::Ok().json(ApiSuccess::new(entry))),
+        Err(e) => Ok(meal_error_response(e, "saving meal plan entry")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/meals/{entry_id}",
+    tag = "meals",
+    summary = "Delete plan entry",
+    params(("household_id" = Uuid, Path), ("entry_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn delete_plan_entry(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, entry_id_str) = path.into_inner();
+        let entry_id = match parse_id(&entry_id_str, "meal plan entry") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match meal_service::delete_plan_entry(&state.db, &ctx.household_id, &entry_id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(meal_error_response(e, "deleting meal plan entry")),
+    }
+}
@@ -5,6 +5,13 @@ pub mod users;
 pub mod households;
 pub mod tasks;
 pub mod task_categories;
+pub mod task_tags;
+pub mod saved_filters;
+pub mod focus_sessions;
+pub mod task_exclusions;
+pub mod streak_freezes;
+pub mod reward_wishlist;
+pub mod point_goals;
 pub mod rewards;
 pub mod punishments;
 pub mod point_conditions;
@@ -14,19 +21,51 @@ pub mod chat;
 pub mod websocket;
 pub mod notes;
 pub mod journal;
+pub mod attachments;
 pub mod announcements;
 pub mod dashboard;
 pub mod legal;
 pub mod statistics;
+pub mod calendar;
+pub mod notifications;
+pub mod task_comments;
+pub mod export;
+pub mod expenses;
+pub mod meals;
+pub mod shopping_list;
+pub mod inventory;
+pub mod appliances;
+pub mod plants;
+pub mod pets;
+pub mod share_links;
+pub mod allowance;
+pub mod admin;
+pub mod challenges;
+pub mod automation_rules;
+pub mod webhooks;
+pub mod api_tokens;
+pub mod sensors;
+pub mod notification_center;
+pub mod invite_codes;
+pub mod trash;
+pub mod openapi;
+
+#[cfg(test)]
+mod tests;
 
 pub fn configure_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/api")
             .configure(auth::configure)
             .configure(users::configure)
+            .configure(export::configure)
             .configure(households::configure)
             .configure(invitations::configure)
             .configure(dashboard::configure)
             .configure(legal::configure)
+            .configure(admin::configure)
+            .configure(notification_center::configure)
+            .configure(share_links::configure_public)
+            .configure(openapi::configure)
     );
 }
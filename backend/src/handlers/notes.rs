@@ -1,9 +1,15 @@
 use actix_web::{web, HttpResponse, Result};
-use shared::{ApiError, ApiSuccess, CreateNoteRequest, UpdateNoteRequest};
+use serde::Deserialize;
+use shared::{
+    ApiError, ApiSuccess, AttachmentEntity, CreateNoteRequest, Note, NoteWithUser,
+    UpdateNoteRequest, VersionConflict,
+};
 use uuid::Uuid;
 
+use crate::handlers::openapi::{BadRequest, Conflict, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
 use crate::models::AppState;
-use crate::services::{households as household_service, notes as notes_service};
+use crate::services::{attachments as attachment_service, notes as notes_service};
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -16,44 +22,63 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
     );
 }
 
-async fn list_notes(
-    state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
-) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+struct ListNotesQuery {
+    /// List the archived notes instead of the active ones
+    #[serde(default)]
+    archived: bool,
+}
 
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
+/// The note, if it exists in the household from the path
+async fn household_note(state: &AppState, ctx: &HouseholdContext, note_id: &Uuid) -> Result<Note, HttpResponse> {
+    match notes_service::get_note(&state.db, note_id).await {
+        Ok(Some(note)) => {
+            ctx.check_owns(&note.household_id, "Note")?;
+            Ok(note)
+        }
+        Ok(None) => Err(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Note not found".to_string(),
+        })),
+        Err(e) => {
+            log::error!("Error fetching note: {:?}", e);
+            Err(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch note".to_string(),
+            }))
         }
-    };
-
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
-    {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
     }
+}
 
-    match notes_service::list_notes(&state.db, &household_id, &user_id).await {
-        Ok(notes) => Ok(HttpResponse::Ok().json(ApiSuccess::new(notes))),
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/notes",
+    tag = "notes",
+    summary = "List notes",
+    params(("household_id" = Uuid, Path), ListNotesQuery),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<NoteWithUser>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_notes(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    query: web::Query<ListNotesQuery>,
+) -> Result<HttpResponse> {
+    match notes_service::list_notes(&state.db, &ctx.household_id, &ctx.user_id, query.archived).await {
+        Ok(mut notes) => {
+            let mut attachments = attachment_service::list_for_household(&state.db, &ctx.household_id, AttachmentEntity::Note)
+                .await
+                .unwrap_or_default();
+            for item in &mut notes {
+                item.attachments = attachments.remove(&item.note.id).unwrap_or_default();
+            }
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(notes)))
+        }
         Err(e) => {
             log::error!("Error listing notes: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
@@ -64,43 +89,25 @@ async fn list_notes(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/notes",
+    tag = "notes",
+    summary = "Create note",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreateNoteRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<shared::Note>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn create_note(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
     body: web::Json<CreateNoteRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    // Check membership (any member can create notes)
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
-    {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
     let request = body.into_inner();
     if request.title.trim().is_empty() {
         return Ok(HttpResponse::BadRequest().json(ApiError {
@@ -109,7 +116,7 @@ async fn create_note(
         }));
     }
 
-    match notes_service::create_note(&state.db, &household_id, &user_id, &request).await {
+    match notes_service::create_note(&state.db, &ctx.household_id, &ctx.user_id, &request).await {
         Ok(note) => Ok(HttpResponse::Created().json(ApiSuccess::new(note))),
         Err(e) => {
             log::error!("Error creating note: {:?}", e);
@@ -121,32 +128,26 @@ async fn create_note(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/notes/{note_id}",
+    tag = "notes",
+    summary = "Get note",
+    params(("household_id" = Uuid, Path), ("note_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<shared::Note>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn get_note(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, note_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, note_id_str) = path.into_inner();
 
     let note_id = match Uuid::parse_str(&note_id_str) {
         Ok(id) => id,
@@ -158,69 +159,44 @@ async fn get_note(
         }
     };
 
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
-    {
+    let note = match household_note(&state, &ctx, &note_id).await {
+        Ok(note) => note,
+        Err(response) => return Ok(response),
+    };
+
+    // Check if user can view this note
+    if !notes_service::can_view_note(&note, &ctx.user_id) {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
+            message: "You do not have permission to view this note".to_string(),
         }));
     }
-
-    match notes_service::get_note(&state.db, &note_id).await {
-        Ok(Some(note)) => {
-            // Check if user can view this note
-            if !notes_service::can_view_note(&note, &user_id) {
-                return Ok(HttpResponse::Forbidden().json(ApiError {
-                    error: "forbidden".to_string(),
-                    message: "You do not have permission to view this note".to_string(),
-                }));
-            }
-            Ok(HttpResponse::Ok().json(ApiSuccess::new(note)))
-        }
-        Ok(None) => Ok(HttpResponse::NotFound().json(ApiError {
-            error: "not_found".to_string(),
-            message: "Note not found".to_string(),
-        })),
-        Err(e) => {
-            log::error!("Error fetching note: {:?}", e);
-            Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch note".to_string(),
-            }))
-        }
-    }
+    Ok(HttpResponse::Ok().json(ApiSuccess::new(note)))
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/notes/{note_id}",
+    tag = "notes",
+    summary = "Update note",
+    params(("household_id" = Uuid, Path), ("note_id" = Uuid, Path)),
+    request_body = UpdateNoteRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<shared::Note>),
+        Conflict,
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn update_note(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
     body: web::Json<UpdateNoteRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, note_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, note_id_str) = path.into_inner();
 
     let note_id = match Uuid::parse_str(&note_id_str) {
         Ok(id) => id,
@@ -232,20 +208,13 @@ async fn update_note(
         }
     };
 
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
-    {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
+    if let Err(response) = household_note(&state, &ctx, &note_id).await {
+        return Ok(response);
     }
 
     let request = body.into_inner();
 
-    match notes_service::update_note(&state.db, &note_id, &user_id, &request).await {
+    match notes_service::update_note(&state.db, &note_id, &ctx.user_id, &request).await {
         Ok(note) => Ok(HttpResponse::Ok().json(ApiSuccess::new(note))),
         Err(notes_service::NoteError::NotFound) => Ok(HttpResponse::NotFound().json(ApiError {
             error: "not_found".to_string(),
@@ -257,6 +226,13 @@ async fn update_note(
                 message: "You do not have permission to edit this note".to_string(),
             }))
         }
+        Err(notes_service::NoteError::VersionConflict(current)) => {
+            Ok(HttpResponse::Conflict().json(VersionConflict {
+                error: "version_conflict".to_string(),
+                message: "The note was changed in the meantime".to_string(),
+                current: *current,
+            }))
+        }
         Err(e) => {
             log::error!("Error updating note: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
@@ -267,32 +243,20 @@ async fn update_note(
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/notes/{note_id}",
+    tag = "notes",
+    summary = "Delete note",
+    params(("household_id" = Uuid, Path), ("note_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
 async fn delete_note(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, note_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, note_id_str) = path.into_inner();
 
     let note_id = match Uuid::parse_str(&note_id_str) {
         Ok(id) => id,
@@ -304,18 +268,12 @@ async fn delete_note(
         }
     };
 
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
-    {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
+    if let Err(response) = household_note(&state, &ctx, &note_id).await {
+        return Ok(response);
     }
 
-    match notes_service::delete_note(&state.db, &note_id, &user_id).await {
+    match notes_service::delete_note(&state.db, &note_id, &ctx.user_id).await {
+        // Attachments stay until the item is purged from the trash
         Ok(_) => Ok(HttpResponse::NoContent().finish()),
         Err(notes_service::NoteError::NotFound) => Ok(HttpResponse::NotFound().json(ApiError {
             error: "not_found".to_string(),
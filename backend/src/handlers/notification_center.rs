@@ -0,0 +1,159 @@
+//! Handlers for the personal notification inbox and helpers to fill it
+
+use actix_web::{web, HttpRequest, HttpResponse, Result};
+use shared::{ApiError, ApiSuccess, Notification, NotificationList, NotificationType, WsServerMessage};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, NotFound, Null, Unauthorized};
+use crate::models::AppState;
+use crate::services::notification_center::{self as notification_center_service, NewNotification, NotificationCenterError};
+use crate::services::websocket::WsManager;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/notifications")
+            .route("", web::get().to(list_notifications))
+            .route("/read-all", web::post().to(mark_all_read))
+            .route("/{notification_id}/read", web::post().to(mark_read)),
+    );
+}
+
+/// Store a notification for `user_id` and push it to their open WebSocket sessions.
+/// Failures are logged; they never fail the action that caused the notification.
+pub async fn notify(
+    req: &HttpRequest,
+    pool: &SqlitePool,
+    user_id: Uuid,
+    household_id: Uuid,
+    notification_type: NotificationType,
+    title: &str,
+    entity: Option<(&'static str, Uuid)>,
+) {
+    let new_notification = NewNotification {
+        user_id,
+        household_id: Some(household_id),
+        notification_type,
+        title: title.to_string(),
+        entity_type: entity.map(|(entity_type, _)| entity_type),
+        entity_id: entity.map(|(_, id)| id),
+    };
+
+    let notification = match notification_center_service::create_notification(pool, &new_notification).await {
+        Ok(notification) => notification,
+        Err(e) => {
+            log::error!("Error creating notification: {:?}", e);
+            return;
+        }
+    };
+
+    if let Some(ws_manager) = req.app_data::<web::Data<Arc<WsManager>>>() {
+        ws_manager
+            .send_to_user(&user_id, WsServerMessage::NotificationCreated { notification })
+            .await;
+    }
+}
+
+fn authenticate(state: &AppState, req: &HttpRequest) -> std::result::Result<Uuid, HttpResponse> {
+    crate::middleware::auth::extract_user_id(req, &state.config.jwt_secret).map_err(|_| {
+        HttpResponse::Unauthorized().json(ApiError {
+            error: "unauthorized".to_string(),
+            message: "Invalid or missing token".to_string(),
+        })
+    })
+}
+
+fn internal_error(context: &str, error: NotificationCenterError) -> HttpResponse {
+    log::error!("Error {}: {:?}", context, error);
+    HttpResponse::InternalServerError().json(ApiError {
+        error: "internal_error".to_string(),
+        message: format!("Failed {}", context),
+    })
+}
+
+/// The current user's recent notifications and unread count
+#[utoipa::path(
+    get,
+    path = "/api/notifications",
+    tag = "notification_center",
+    summary = "The current user's recent notifications and unread count",
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<NotificationList>),
+        Unauthorized,
+    ),
+)]
+async fn list_notifications(state: web::Data<AppState>, req: HttpRequest) -> Result<HttpResponse> {
+    let user_id = match authenticate(&state, &req) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match notification_center_service::list_notifications(&state.db, &user_id).await {
+        Ok(list) => Ok(HttpResponse::Ok().json(ApiSuccess::new(list))),
+        Err(e) => Ok(internal_error("loading notifications", e)),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/notifications/{notification_id}/read",
+    operation_id = "notification_center_mark_read",
+    tag = "notification_center",
+    summary = "Mark read",
+    params(("notification_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Notification>),
+        BadRequest,
+        Unauthorized,
+        NotFound,
+    ),
+)]
+async fn mark_read(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> Result<HttpResponse> {
+    let user_id = match authenticate(&state, &req) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    let notification_id = match Uuid::parse_str(&path.into_inner()) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_id".to_string(),
+                message: "Invalid notification ID format".to_string(),
+            }));
+        }
+    };
+
+    match notification_center_service::mark_read(&state.db, &user_id, &notification_id).await {
+        Ok(notification) => Ok(HttpResponse::Ok().json(ApiSuccess::new(notification))),
+        Err(NotificationCenterError::NotFound) => Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Notification not found".to_string(),
+        })),
+        Err(e) => Ok(internal_error("marking notification as read", e)),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/notifications/read-all",
+    tag = "notification_center",
+    summary = "Mark all read",
+    responses((status = 200, description = "Success", body = ApiSuccess<Null>), Unauthorized),
+)]
+async fn mark_all_read(state: web::Data<AppState>, req: HttpRequest) -> Result<HttpResponse> {
+    let user_id = match authenticate(&state, &req) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match notification_center_service::mark_all_read(&state.db, &user_id).await {
+        Ok(_) => Ok(HttpResponse::Ok().json(ApiSuccess::new(()))),
+        Err(e) => Ok(internal_error("marking notifications as read", e)),
+    }
+}
@@ -0,0 +1,198 @@
+//! Handlers for Web Push subscriptions and helpers for pushing event notifications
+
+use actix_web::{web, HttpResponse, Result};
+use shared::{
+    ApiError, ApiSuccess, NotificationKind, Permission, PushConfig, PushMessage, PushSubscriptionRequest,
+    UnsubscribePushRequest,
+};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, Null, Unauthorized};
+use crate::models::AppState;
+use crate::services::notifications::PushSender;
+use crate::services::{households as household_service, notifications as notification_service, permissions};
+use crate::services::web_push::VapidKeys;
+
+/// Get the VAPID public key browsers need to subscribe
+#[utoipa::path(
+    get,
+    path = "/api/users/me/push/config",
+    tag = "notifications",
+    summary = "Get the VAPID public key browsers need to subscribe",
+    responses((status = 200, description = "Success", body = ApiSuccess<PushConfig>), Unauthorized),
+)]
+pub async fn get_push_config(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+) -> Result<HttpResponse> {
+    if crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret).is_err() {
+        return Ok(HttpResponse::Unauthorized().json(ApiError {
+            error: "unauthorized".to_string(),
+            message: "Invalid or missing token".to_string(),
+        }));
+    }
+
+    let public_key = state
+        .config
+        .vapid_private_key
+        .as_deref()
+        .and_then(|key| VapidKeys::from_base64(key).ok())
+        .map(|keys| keys.public_key_base64());
+
+    Ok(HttpResponse::Ok().json(ApiSuccess::new(PushConfig { public_key })))
+}
+
+/// Register a push subscription for the current user
+#[utoipa::path(
+    post,
+    path = "/api/users/me/push/subscriptions",
+    tag = "notifications",
+    summary = "Register a push subscription for the current user",
+    request_body = PushSubscriptionRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Null>),
+        BadRequest,
+        Unauthorized,
+    ),
+)]
+pub async fn subscribe(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    body: web::Json<PushSubscriptionRequest>,
+) -> Result<HttpResponse> {
+    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::Unauthorized().json(ApiError {
+                error: "unauthorized".to_string(),
+                message: "Invalid or missing token".to_string(),
+            }));
+        }
+    };
+
+    match notification_service::subscribe(&state.db, &user_id, &body.into_inner()).await {
+        Ok(()) => Ok(HttpResponse::Ok().json(ApiSuccess::new(()))),
+        Err(notification_service::NotificationError::InvalidSubscription(message)) => {
+            Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "validation_error".to_string(),
+                message,
+            }))
+        }
+        Err(e) => {
+            log::error!("Error saving push subscription: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to save push subscription".to_string(),
+            }))
+        }
+    }
+}
+
+/// Remove a push subscription of the current user
+#[utoipa::path(
+    delete,
+    path = "/api/users/me/push/subscriptions",
+    tag = "notifications",
+    summary = "Remove a push subscription of the current user",
+    request_body = UnsubscribePushRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Null>),
+        BadRequest,
+        Unauthorized,
+    ),
+)]
+pub async fn unsubscribe(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    body: web::Json<UnsubscribePushRequest>,
+) -> Result<HttpResponse> {
+    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::Unauthorized().json(ApiError {
+                error: "unauthorized".to_string(),
+                message: "Invalid or missing token".to_string(),
+            }));
+        }
+    };
+
+    match notification_service::unsubscribe(&state.db, &user_id, &body.endpoint).await {
+        Ok(()) => Ok(HttpResponse::Ok().json(ApiSuccess::new(()))),
+        Err(e) => {
+            log::error!("Error removing push subscription: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to remove push subscription".to_string(),
+            }))
+        }
+    }
+}
+
+/// Push a notification to household members other than `actor`, limited to
+/// members holding `permission` if given. Does nothing when Web Push is not configured.
+pub async fn notify_members(
+    pool: &SqlitePool,
+    push: Option<&PushSender>,
+    household_id: &Uuid,
+    actor: &Uuid,
+    permission: Option<Permission>,
+    kind: NotificationKind,
+    message: PushMessage,
+) {
+    let Some(sender) = push else {
+        return;
+    };
+
+    let members = match household_service::list_members(pool, household_id).await {
+        Ok(members) => members,
+        Err(e) => {
+            log::warn!("Error loading members for notification: {:?}", e);
+            return;
+        }
+    };
+
+    let mut recipients = Vec::new();
+    for member in members {
+        let user_id = member.user.id;
+        if user_id == *actor {
+            continue;
+        }
+        if let Some(permission) = permission {
+            if !permissions::has_permission(pool, household_id, &user_id, permission).await {
+                continue;
+            }
+        }
+        recipients.push(user_id);
+    }
+
+    notification_service::notify_users_in_background(
+        pool.clone(),
+        sender.clone(),
+        *household_id,
+        recipients,
+        kind,
+        message,
+    );
+}
+
+/// Push a notification to a single member. Does nothing when Web Push is not configured.
+pub fn notify_member(
+    pool: &SqlitePool,
+    push: Option<&PushSender>,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    kind: NotificationKind,
+    message: PushMessage,
+) {
+    if let Some(sender) = push {
+        notification_service::notify_users_in_background(
+            pool.clone(),
+            sender.clone(),
+            *household_id,
+            vec![*user_id],
+            kind,
+            message,
+        );
+    }
+}
@@ -0,0 +1,457 @@
+//! OpenAPI 3 description of the REST API, served at `/api/openapi.json`, and an
+//! optional Swagger UI for it at `/api/docs`
+//!
+//! Operations come from the `#[utoipa::path]` attributes on the handlers, which
+//! are collected in `ApiDoc`; schemas come from the `ToSchema` derives on the
+//! shared types. The tests fail when a route is registered without being listed
+//! in `ApiDoc` or a listed path is not routed.
+
+use actix_web::http::Method;
+use actix_web::{web, HttpResponse, Result};
+use serde_json::Value;
+use shared::{ApiError, LeaderboardWindow, VersionConflict};
+use std::borrow::Cow;
+use utoipa::openapi::path::{Parameter, ParameterBuilder, ParameterIn};
+use utoipa::openapi::response::ResponseBuilder;
+use utoipa::openapi::security::{Http, HttpAuthScheme, SecurityScheme};
+use utoipa::openapi::{Content, ObjectBuilder, Ref, RefOr, Required, Schema, Type};
+use utoipa::{IntoResponses, Modify, OpenApi, PartialSchema, ToSchema};
+use utoipa_swagger_ui::SwaggerUi;
+
+use super::{
+    activity_logs, admin, allowance, announcements, api_tokens, appliances, attachments, auth,
+    automation_rules, calendar, challenges, chat, dashboard, expenses, export, focus_sessions,
+    households, inventory, invitations, invite_codes, journal, legal, meals, notes,
+    notification_center, notifications, pets, plants, point_conditions, point_goals, punishments,
+    reward_wishlist, rewards, saved_filters, sensors, share_links, shopping_list, statistics,
+    streak_freezes, task_categories, task_comments, task_exclusions, task_tags, tasks, trash, users,
+    webhooks, websocket,
+};
+use crate::middleware::idempotency;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/openapi.json", web::get().to(get_openapi));
+}
+
+/// Swagger UI at `/api/docs`. Must be configured before the `/api` scope, which
+/// would otherwise answer its routes with 404.
+pub fn configure_ui(cfg: &mut web::ServiceConfig) {
+    cfg.service(SwaggerUi::new("/api/docs/{_:.*}").config(utoipa_swagger_ui::Config::from("/api/openapi.json")));
+}
+
+/// The document describes itself too, and is readable without logging in
+#[utoipa::path(
+    get,
+    path = "/api/openapi.json",
+    tag = "openapi",
+    summary = "This OpenAPI document",
+    responses((status = 200, description = "Success", content_type = "application/json", body = Value)),
+    security(()),
+)]
+async fn get_openapi() -> Result<HttpResponse> {
+    Ok(HttpResponse::Ok().json(ApiDoc::openapi()))
+}
+
+// The response types below only describe responses for the document and are
+// never constructed
+
+/// Payload of operations that have nothing to return, always `null`
+pub struct Null;
+
+impl PartialSchema for Null {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new().schema_type(Type::Null).into()
+    }
+}
+
+impl ToSchema for Null {
+    fn name() -> Cow<'static, str> {
+        Cow::Borrowed("Null")
+    }
+}
+
+/// Payload of operations that return a point in time
+#[allow(dead_code)]
+#[derive(ToSchema)]
+#[schema(value_type = String, format = DateTime)]
+pub struct Timestamp(chrono::DateTime<chrono::Utc>);
+
+/// `400` for a malformed ID or a request body that fails validation
+#[allow(dead_code)]
+#[derive(IntoResponses)]
+#[response(status = 400, description = "Invalid request")]
+pub struct BadRequest(ApiError);
+
+/// `401` without a valid access token
+#[allow(dead_code)]
+#[derive(IntoResponses)]
+#[response(status = 401, description = "Not logged in")]
+pub struct Unauthorized(ApiError);
+
+/// `403` for a missing role or permission, or a disabled module
+#[allow(dead_code)]
+#[derive(IntoResponses)]
+#[response(status = 403, description = "Not allowed")]
+pub struct Forbidden(ApiError);
+
+/// `404` for an entity that does not exist or belongs to another household
+#[allow(dead_code)]
+#[derive(IntoResponses)]
+#[response(status = 404, description = "Not found")]
+pub struct NotFound(ApiError);
+
+#[derive(IntoResponses)]
+#[response(status = 204, description = "Done")]
+pub struct NoContent;
+
+/// `409` for an update based on an outdated `version`
+#[allow(dead_code)]
+#[derive(IntoResponses)]
+#[response(status = 409, description = "The entity was changed in the meantime")]
+pub struct Conflict(#[to_schema] VersionConflict<Value>);
+
+/// Body of a file upload
+#[derive(ToSchema)]
+pub struct FileUpload {
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Household Manager API",
+        description = "Successful responses wrap their payload in `ApiSuccess`, errors are `ApiError`.",
+    ),
+    paths(
+        auth::register, auth::login, auth::refresh, auth::logout, auth::forgot_password, auth::reset_password,
+        auth::get_current_user, auth::oidc_config, auth::oidc_login, auth::oidc_link, auth::oidc_callback,
+        auth::oidc_exchange,
+        users::get_user_settings, users::update_user_settings, users::change_password, users::delete_account,
+        users::get_user, users::update_user,
+        calendar::get_feed_token, calendar::regenerate_feed_token, calendar::get_calendar_feed,
+        calendar::get_task_occurrences,
+        api_tokens::list_tokens, api_tokens::create_token, api_tokens::revoke_token,
+        notifications::get_push_config, notifications::subscribe, notifications::unsubscribe,
+        chat::get_unread_counts, chat::list_messages, chat::create_message, chat::list_read_markers,
+        chat::mark_read, chat::update_message, chat::delete_message, chat::list_replies, chat::add_reaction,
+        chat::remove_reaction,
+        focus_sessions::get_current_session, focus_sessions::start_session, focus_sessions::stop_session,
+        focus_sessions::cancel_session,
+        export::import_household, export::export_household, export::export_completions_csv,
+        export::export_points_csv,
+        households::list_households, households::create_household, households::get_household,
+        households::update_household, households::delete_household, households::list_members,
+        households::invite_member, households::list_household_invitations, households::cancel_invitation,
+        households::remove_member, households::update_member_role, households::get_member_permissions,
+        households::update_member_permissions, households::adjust_member_points,
+        households::get_points_history, households::set_member_vacation, households::transfer_ownership,
+        households::leave_household, households::create_child_account, households::reset_child_pin,
+        households::get_leaderboard, households::get_household_settings, households::update_household_settings,
+        households::get_audit_log, households::activate_solo_mode, households::request_solo_mode_exit,
+        households::cancel_solo_mode_exit,
+        task_comments::list_comments, task_comments::create_comment, task_comments::delete_comment,
+        tasks::list_tasks, tasks::create_task, tasks::get_due_tasks, tasks::get_all_tasks_with_status,
+        tasks::get_assigned_tasks, tasks::get_pending_reviews, tasks::list_archived_tasks,
+        tasks::approve_completion, tasks::reject_completion, tasks::upload_completion_attachment,
+        tasks::get_completion_attachment, tasks::list_suggestions, tasks::suggest_rebalance,
+        tasks::apply_rebalance, tasks::randomize_assignments, tasks::apply_assignment_plan, tasks::get_task,
+        tasks::update_task, tasks::delete_task, tasks::get_task_details, tasks::complete_task,
+        tasks::uncomplete_task, tasks::archive_task, tasks::unarchive_task, tasks::pause_task,
+        tasks::unpause_task, tasks::skip_task, tasks::postpone_task, tasks::override_period,
+        tasks::freeze_period, tasks::claim_task, tasks::release_task, tasks::approve_suggestion,
+        tasks::deny_suggestion, tasks::get_task_rewards, tasks::add_task_reward, tasks::remove_task_reward,
+        tasks::get_task_punishments, tasks::add_task_punishment, tasks::remove_task_punishment,
+        tasks::get_task_prerequisites, tasks::add_task_prerequisite, tasks::remove_task_prerequisite,
+        task_categories::list_categories, task_categories::create_category, task_categories::get_category,
+        task_categories::update_category, task_categories::delete_category,
+        task_tags::list_tags, task_tags::create_tag, task_tags::get_tag, task_tags::update_tag,
+        task_tags::delete_tag,
+        saved_filters::list_saved_filters, saved_filters::create_saved_filter,
+        saved_filters::delete_saved_filter,
+        task_exclusions::list_exclusions, task_exclusions::add_exclusion, task_exclusions::remove_exclusion,
+        streak_freezes::get_streak_freezes, streak_freezes::purchase_streak_freeze,
+        reward_wishlist::list_wishlist, reward_wishlist::add_to_wishlist,
+        reward_wishlist::remove_from_wishlist,
+        point_goals::list_goals, point_goals::create_goal, point_goals::delete_goal,
+        rewards::list_rewards, rewards::create_reward, rewards::list_user_rewards,
+        rewards::list_all_user_rewards, rewards::delete_user_reward, rewards::redeem_reward,
+        rewards::gift_reward, rewards::approve_redemption, rewards::reject_redemption,
+        rewards::pick_random_reward, rewards::list_pending_redemptions, rewards::list_pending_purchases,
+        rewards::approve_purchase, rewards::reject_purchase, rewards::get_reward, rewards::update_reward,
+        rewards::delete_reward, rewards::purchase_reward, rewards::assign_reward, rewards::unassign_reward,
+        rewards::get_reward_options, rewards::add_reward_option, rewards::update_reward_option_weight,
+        rewards::remove_reward_option,
+        punishments::list_punishments, punishments::create_punishment, punishments::list_user_punishments,
+        punishments::list_all_user_punishments, punishments::delete_user_punishment,
+        punishments::complete_punishment, punishments::approve_completion, punishments::reject_completion,
+        punishments::pick_random_punishment, punishments::list_pending_completions,
+        punishments::get_punishment, punishments::update_punishment, punishments::delete_punishment,
+        punishments::assign_punishment, punishments::unassign_punishment, punishments::get_punishment_options,
+        punishments::update_punishment_option_weight,
+        point_conditions::list_point_conditions, point_conditions::create_point_condition,
+        point_conditions::get_point_condition, point_conditions::update_point_condition,
+        point_conditions::delete_point_condition,
+        activity_logs::list_activities,
+        notes::list_notes, notes::create_note, notes::get_note, notes::update_note, notes::delete_note,
+        journal::list_journal_entries, journal::create_journal_entry, journal::get_mood_over_time,
+        journal::get_journal_entry, journal::update_journal_entry, journal::delete_journal_entry,
+        trash::list_trash, trash::restore_item, trash::purge_item,
+        attachments::download_thumbnail, attachments::list_attachments, attachments::upload_attachment,
+        attachments::download_attachment, attachments::delete_attachment,
+        announcements::list_announcements, announcements::list_active_announcements,
+        announcements::create_announcement, announcements::get_announcement,
+        announcements::update_announcement, announcements::delete_announcement,
+        announcements::acknowledge_announcement, announcements::list_acknowledgments,
+        statistics::get_weekly_statistics, statistics::calculate_weekly_statistics,
+        statistics::list_available_weeks, statistics::get_monthly_statistics,
+        statistics::calculate_monthly_statistics, statistics::list_available_months,
+        statistics::get_household_report, statistics::get_workload_report,
+        expenses::list_expenses, expenses::create_expense, expenses::get_monthly_summary,
+        expenses::get_balances, expenses::list_settlements, expenses::create_settlement,
+        expenses::update_expense, expenses::delete_expense,
+        meals::list_recipes, meals::create_recipe, meals::get_recipe, meals::update_recipe,
+        meals::delete_recipe, meals::add_recipe_to_shopping_list, meals::get_week_plan, meals::set_plan_entry,
+        meals::delete_plan_entry,
+        shopping_list::list_items, shopping_list::add_item, shopping_list::clear_checked,
+        shopping_list::update_item, shopping_list::delete_item,
+        inventory::list_items, inventory::create_item, inventory::update_item, inventory::delete_item,
+        inventory::adjust_item,
+        appliances::list_appliances, appliances::create_appliance, appliances::get_appliance,
+        appliances::update_appliance, appliances::delete_appliance, appliances::add_maintenance,
+        appliances::remove_maintenance,
+        plants::list_plants, plants::create_plant, plants::get_plant, plants::update_plant,
+        plants::delete_plant,
+        pets::list_pets, pets::create_pet, pets::get_pet, pets::delete_pet, pets::list_log, pets::add_schedule,
+        pets::remove_schedule, pets::log_care,
+        share_links::list_links, share_links::create_link, share_links::revoke_link,
+        share_links::get_shared_page,
+        allowance::list_payouts,
+        challenges::list_challenges, challenges::create_challenge, challenges::get_challenge,
+        challenges::delete_challenge, challenges::close_challenge,
+        automation_rules::list_rules, automation_rules::create_rule, automation_rules::list_runs,
+        automation_rules::update_rule, automation_rules::delete_rule,
+        webhooks::list_webhooks, webhooks::create_webhook, webhooks::update_webhook, webhooks::delete_webhook,
+        sensors::get_household_sensor, sensors::get_due_tasks,
+        invite_codes::list_invite_codes, invite_codes::create_invite_code, invite_codes::revoke_invite_code,
+        invite_codes::invite_code_qr,
+        invitations::list_user_invitations, invitations::redeem_invite_code, invitations::accept_invitation,
+        invitations::decline_invitation,
+        dashboard::get_dashboard_task_ids, dashboard::get_dashboard_tasks_with_status,
+        dashboard::get_all_tasks_across_households, dashboard::reorder_dashboard_tasks,
+        dashboard::is_task_on_dashboard, dashboard::add_task_to_dashboard,
+        dashboard::remove_task_from_dashboard,
+        legal::get_impressum, legal::get_datenschutz, legal::get_agb,
+        admin::list_jobs, admin::run_job, admin::create_backup, admin::list_users, admin::disable_user,
+        admin::enable_user, admin::grant_admin, admin::revoke_admin, admin::household_usage,
+        notification_center::list_notifications, notification_center::mark_all_read,
+        notification_center::mark_read,
+        get_openapi,
+        websocket::ws_handler,
+    ),
+    // Query parameter types are not collected from `IntoParams`
+    components(schemas(ApiError, LeaderboardWindow)),
+    modifiers(&Conventions),
+    security(("bearerAuth" = [])),
+)]
+pub struct ApiDoc;
+
+/// What holds for every operation: the Bearer token scheme, `ApiError` for
+/// failures and the `Idempotency-Key` header where retries are deduplicated
+struct Conventions;
+
+impl Modify for Conventions {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            let mut bearer = Http::new(HttpAuthScheme::Bearer);
+            bearer.description = Some("Access token from `/api/auth/login` or a personal API token".to_string());
+            components.add_security_scheme("bearerAuth", SecurityScheme::Http(bearer));
+        }
+
+        let idempotency_key: Parameter = ParameterBuilder::new()
+            .name(idempotency::IDEMPOTENCY_KEY_HEADER)
+            .parameter_in(ParameterIn::Header)
+            .required(Required::False)
+            .description(Some("Retries with the same key get the first response instead of running the request again"))
+            .schema(Some(
+                ObjectBuilder::new()
+                    .schema_type(Type::String)
+                    .min_length(Some(1))
+                    .max_length(Some(crate::services::idempotency::MAX_KEY_LEN)),
+            ))
+            .build();
+        let error = ResponseBuilder::new()
+            .description("Error")
+            .content("application/json", Content::new(Some(Ref::from_schema_name("ApiError"))))
+            .build();
+
+        for (path, item) in openapi.paths.paths.iter_mut() {
+            for (method, operation) in [
+                (Method::GET, item.get.as_mut()),
+                (Method::POST, item.post.as_mut()),
+                (Method::PUT, item.put.as_mut()),
+                (Method::DELETE, item.delete.as_mut()),
+                (Method::PATCH, item.patch.as_mut()),
+            ] {
+                let Some(operation) = operation else { continue };
+                if idempotency::is_idempotent_route(&method, path) {
+                    operation.parameters.get_or_insert_with(Vec::new).push(idempotency_key.clone());
+                }
+                operation.responses.responses.insert("default".to_string(), error.clone().into());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{create_migrated_pool, create_test_app_state};
+    use actix_web::{test as actix_test, App};
+    use serde_json::json;
+
+    /// A request path for a documented path, with placeholder values for its parameters
+    fn example_path(path: &str) -> String {
+        path.split('/')
+            .map(|segment| match segment.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+                Some("date") => "2024-01-01".to_string(),
+                Some(name) if name == "id" || name.ends_with("_id") => uuid::Uuid::new_v4().to_string(),
+                Some(_) => "example".to_string(),
+                None => segment.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Method and path of every documented operation
+    fn operations() -> Vec<(Method, String)> {
+        let document = ApiDoc::openapi();
+        let mut operations = Vec::new();
+        for (path, item) in &document.paths.paths {
+            for (method, operation) in [
+                (Method::GET, &item.get),
+                (Method::POST, &item.post),
+                (Method::PUT, &item.put),
+                (Method::DELETE, &item.delete),
+                (Method::PATCH, &item.patch),
+            ] {
+                if operation.is_some() {
+                    operations.push((method, path.clone()));
+                }
+            }
+        }
+        operations
+    }
+
+    #[test]
+    fn test_every_registered_route_is_documented() {
+        let handlers_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src/handlers");
+        let mut registrations = 0;
+        for entry in std::fs::read_dir(handlers_dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.file_name().is_some_and(|name| name == "tests.rs") {
+                continue;
+            }
+            let source = std::fs::read_to_string(&path).unwrap();
+            let source = source.split("#[cfg(test)]").next().unwrap_or_default();
+            registrations += ["web::get()", "web::post()", "web::put()", "web::delete()", "web::patch()"]
+                .iter()
+                .map(|method| source.matches(method).count())
+                .sum::<usize>();
+        }
+        assert_eq!(operations().len(), registrations, "every route needs a `#[utoipa::path]` listed in ApiDoc");
+    }
+
+    #[test]
+    fn test_document() {
+        let document = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+        let complete = &document["paths"]["/api/households/{household_id}/tasks/{task_id}/complete"]["post"];
+        assert_eq!(complete["parameters"][0]["name"], "household_id");
+        assert_eq!(complete["parameters"][2]["name"], idempotency::IDEMPOTENCY_KEY_HEADER);
+
+        let update = &document["paths"]["/api/households/{household_id}/tasks/{task_id}"]["put"];
+        assert_eq!(update["requestBody"]["content"]["application/json"]["schema"]["$ref"], "#/components/schemas/UpdateTaskRequest");
+        assert!(update["responses"]["409"]["content"]["application/json"]["schema"]["properties"]["current"].is_object());
+        assert_eq!(update["responses"]["default"]["content"]["application/json"]["schema"]["$ref"], "#/components/schemas/ApiError");
+        assert_eq!(update["responses"]["404"]["content"]["application/json"]["schema"]["$ref"], "#/components/schemas/ApiError");
+        // Success responses name the payload type
+        assert_eq!(update["responses"]["200"]["content"]["application/json"]["schema"]["$ref"], "#/components/schemas/ApiSuccess_Task");
+        assert!(document["components"]["schemas"]["ApiSuccess_Task"]["properties"]["data"]["properties"]["version"].is_object());
+        let list = &document["paths"]["/api/households/{household_id}/tasks"]["get"];
+        assert!(list["responses"]["403"].is_object());
+        assert!(list["responses"].get("404").is_none());
+        // Field-level schemas come from the shared types
+        assert_eq!(document["components"]["schemas"]["UpdateTaskRequest"]["properties"]["assignee_ids"]["items"]["format"], "uuid");
+
+        let login = &document["paths"]["/api/auth/login"]["post"];
+        assert_eq!(login["security"], json!([{}]));
+        assert!(update.get("security").is_none());
+        assert_eq!(document["security"], json!([{ "bearerAuth": [] }]));
+
+        // Operation IDs are unique
+        let mut operation_ids = std::collections::HashSet::new();
+        for item in document["paths"].as_object().unwrap().values() {
+            for operation in item.as_object().unwrap().values() {
+                let id = operation["operationId"].as_str().unwrap();
+                assert!(operation_ids.insert(id.to_string()), "operation ID {} is used twice", id);
+            }
+        }
+
+        // Every reference resolves
+        let text = document.to_string();
+        for reference in text.split("\"$ref\":\"#/components/").skip(1) {
+            let reference = reference.split('"').next().unwrap();
+            let (kind, name) = reference.split_once('/').unwrap();
+            assert!(document["components"][kind][name].is_object(), "missing component {}", reference);
+        }
+    }
+
+    #[actix_rt::test]
+    async fn test_every_documented_route_exists() {
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(web::Data::new(create_test_app_state(create_migrated_pool().await)))
+                .configure(super::super::websocket::configure)
+                .configure(super::super::configure_routes),
+        )
+        .await;
+
+        for (method, path) in operations() {
+            let request = actix_test::TestRequest::default()
+                .method(method.clone())
+                .uri(&example_path(&path))
+                .to_request();
+            let response = actix_test::call_service(&app, request).await;
+            let status = response.status();
+            let body = actix_test::read_body(response).await;
+            // Unmatched routes answer 404 without a body; handlers always explain theirs
+            assert!(
+                !(status == actix_web::http::StatusCode::NOT_FOUND && body.is_empty())
+                    && status != actix_web::http::StatusCode::METHOD_NOT_ALLOWED,
+                "{} {} is not routed ({})",
+                method,
+                path,
+                status
+            );
+        }
+    }
+
+    #[actix_rt::test]
+    async fn test_get_openapi_without_login() {
+        let app = actix_test::init_service(App::new().configure(configure)).await;
+        let response = actix_test::call_service(&app, actix_test::TestRequest::get().uri("/openapi.json").to_request()).await;
+        assert!(response.status().is_success());
+        let body: Value = actix_test::read_body_json(response).await;
+        assert!(body["paths"]["/api/openapi.json"]["get"].is_object());
+    }
+
+    #[actix_rt::test]
+    async fn test_swagger_ui() {
+        let app = actix_test::init_service(App::new().configure(configure_ui)).await;
+        let response = actix_test::call_service(&app, actix_test::TestRequest::get().uri("/api/docs/").to_request()).await;
+        assert!(response.status().is_success());
+        let body = actix_test::read_body(response).await;
+        assert!(String::from_utf8_lossy(&body).contains("swagger-ui"));
+    }
+}
@@ -0,0 +1,368 @@
+use actix_web::{web, HttpRequest, HttpResponse, Result};
+use shared::{
+    ApiError, ApiSuccess, CreatePetRequest, CreatePetScheduleRequest, LogPetCareRequest, Permission,
+    Pet, PetCareEntry,
+};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::{pets as pet_service, websocket::WsManager};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/pets")
+            .route("", web::get().to(list_pets))
+            .route("", web::post().to(create_pet))
+            .route("/{pet_id}", web::get().to(get_pet))
+            .route("/{pet_id}", web::delete().to(delete_pet))
+            .route("/{pet_id}/log", web::get().to(list_log))
+            .route("/{pet_id}/schedules", web::post().to(add_schedule))
+            .route("/{pet_id}/schedules/{task_id}", web::delete().to(remove_schedule))
+            .route("/{pet_id}/schedules/{task_id}/log", web::post().to(log_care)),
+    );
+}
+
+/// Push the latest care entry of a completed pet schedule to the household, so
+/// nobody feeds the pet a second time. Does nothing for other tasks.
+pub async fn broadcast_care(req: &HttpRequest, pool: &SqlitePool, household_id: &Uuid, task_id: &Uuid) {
+    let entry = match pet_service::latest_entry(pool, task_id).await {
+        Ok(Some(entry)) => entry,
+        Ok(None) => return,
+        Err(e) => {
+            log::error!("Error loading pet care entry for broadcast: {:?}", e);
+            return;
+        }
+    };
+    if let Some(ws_manager) = req.app_data::<web::Data<Arc<WsManager>>>() {
+        ws_manager.broadcast_pet_care_logged(household_id, entry).await;
+    }
+}
+
+/// Schedules are tasks, so changing them needs the task permission
+async fn require_manage_tasks(state: &AppState, ctx: &HouseholdContext) -> Result<(), HttpResponse> {
+    if ctx.has_permission(state, Permission::ManageTasks).await {
+        return Ok(());
+    }
+    Err(HttpResponse::Forbidden().json(ApiError {
+        error: "forbidden".to_string(),
+        message: "You don't have permission to manage pet schedules".to_string(),
+    }))
+}
+
+fn parse_id(id: &str, what: &str) -> Result<Uuid, HttpResponse> {
+    Uuid::parse_str(id).map_err(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_id".to_string(),
+            message: format!("Invalid {} ID format", what),
+        })
+    })
+}
+
+fn validation_error(message: &str) -> HttpResponse {
+    HttpResponse::BadRequest().json(ApiError {
+        error: "validation_error".to_string(),
+        message: message.to_string(),
+    })
+}
+
+fn pet_error_response(error: pet_service::PetError, context: &str) -> HttpResponse {
+    match error {
+        pet_service::PetError::NotFound | pet_service::PetError::ScheduleNotFound => {
+            HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: error.to_string(),
+            })
+        }
+        pet_service::PetError::InvalidTimesPerDay
+        | pet_service::PetError::MissingTitle
+        | pet_service::PetError::InvalidAssignee => validation_error(&error.to_string()),
+        pet_service::PetError::RecentlyLogged { .. } => HttpResponse::Conflict().json(ApiError {
+            error: "recently_logged".to_string(),
+            message: error.to_string(),
+        }),
+        pet_service::PetError::TaskError(e) => HttpResponse::BadRequest().json(ApiError {
+            error: "completion_error".to_string(),
+            message: e.to_string(),
+        }),
+        e => {
+            log::error!("Error {}: {:?}", context, e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: format!("Failed {}", context),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/pets",
+    tag = "pets",
+    summary = "List pets",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<Pet>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_pets(state: web::Data<AppState>, ctx: HouseholdContext) -> Result<HttpResponse> {
+    match pet_service::list_pets(&state.db, &ctx.household_id).await {
+        Ok(pets) => Ok(HttpResponse::Ok().json(ApiSuccess::new(pets))),
+        Err(e) => Ok(pet_error_response(e, "listing pets")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/pets",
+    tag = "pets",
+    summary = "Create pet",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreatePetRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<Pet>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn create_pet(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    body: web::Json<CreatePetRequest>,
+) -> Result<HttpResponse> {
+    let request = body.into_inner();
+    if request.name.trim().is_empty() {
+        return Ok(validation_error("Pet name is required"));
+    }
+
+    match pet_service::create_pet(&state.db, &ctx.household_id, &ctx.user_id, &request).await {
+        Ok(pet) => Ok(HttpResponse::Created().json(ApiSuccess::new(pet))),
+        Err(e) => Ok(pet_error_response(e, "creating pet")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/pets/{pet_id}",
+    tag = "pets",
+    summary = "Get pet",
+    params(("household_id" = Uuid, Path), ("pet_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Pet>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn get_pet(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, pet_id_str) = path.into_inner();
+    let pet_id = match parse_id(&pet_id_str, "pet") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match pet_service::get_pet(&state.db, &ctx.household_id, &pet_id).await {
+        Ok(pet) => Ok(HttpResponse::Ok().json(ApiSuccess::new(pet))),
+        Err(e) => Ok(pet_error_response(e, "loading pet")),
+    }
+}
+
+/// Delete the pet; its schedule tasks go to the trash
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/pets/{pet_id}",
+    tag = "pets",
+    summary = "Delete the pet; its schedule tasks go to the trash",
+    params(("household_id" = Uuid, Path), ("pet_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn delete_pet(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_manage_tasks(&state, &ctx).await {
+        return Ok(response);
+    }
+    let (_, pet_id_str) = path.into_inner();
+    let pet_id = match parse_id(&pet_id_str, "pet") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match pet_service::delete_pet(&state.db, &ctx.household_id, &pet_id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(pet_error_response(e, "deleting pet")),
+    }
+}
+
+/// The full care history of a pet, newest first
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/pets/{pet_id}/log",
+    tag = "pets",
+    summary = "The full care history of a pet, newest first",
+    params(("household_id" = Uuid, Path), ("pet_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<PetCareEntry>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn list_log(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, pet_id_str) = path.into_inner();
+    let pet_id = match parse_id(&pet_id_str, "pet") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+    if let Err(e) = pet_service::get_pet(&state.db, &ctx.household_id, &pet_id).await {
+        return Ok(pet_error_response(e, "loading pet"));
+    }
+
+    match pet_service::list_log(&state.db, &pet_id, None, 200).await {
+        Ok(log) => Ok(HttpResponse::Ok().json(ApiSuccess::new(log))),
+        Err(e) => Ok(pet_error_response(e, "loading pet care log")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/pets/{pet_id}/schedules",
+    tag = "pets",
+    summary = "Add schedule",
+    params(("household_id" = Uuid, Path), ("pet_id" = Uuid, Path)),
+    request_body = CreatePetScheduleRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<Pet>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn add_schedule(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+    body: web::Json<CreatePetScheduleRequest>,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_manage_tasks(&state, &ctx).await {
+        return Ok(response);
+    }
+    let (_, pet_id_str) = path.into_inner();
+    let pet_id = match parse_id(&pet_id_str, "pet") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match pet_service::add_schedule(&state.db, &ctx.household_id, &pet_id, &body.into_inner()).await {
+        Ok(pet) => Ok(HttpResponse::Created().json(ApiSuccess::new(pet))),
+        Err(e) => Ok(pet_error_response(e, "adding pet schedule")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/pets/{pet_id}/schedules/{task_id}",
+    tag = "pets",
+    summary = "Remove schedule",
+    params(("household_id" = Uuid, Path), ("pet_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Pet>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn remove_schedule(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String, String)>,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_manage_tasks(&state, &ctx).await {
+        return Ok(response);
+    }
+    let (_, pet_id_str, task_id_str) = path.into_inner();
+    let pet_id = match parse_id(&pet_id_str, "pet") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+    let task_id = match parse_id(&task_id_str, "task") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match pet_service::remove_schedule(&state.db, &ctx.household_id, &pet_id, &task_id).await {
+        Ok(pet) => Ok(HttpResponse::Ok().json(ApiSuccess::new(pet))),
+        Err(e) => Ok(pet_error_response(e, "removing pet schedule")),
+    }
+}
+
+/// Log a feeding or medication. Completes the schedule's task, so it earns points;
+/// answers 409 when someone else just did it, unless `force` is set.
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/pets/{pet_id}/schedules/{task_id}/log",
+    tag = "pets",
+    summary = "Log care",
+    description = "Log a feeding or medication. Completes the schedule's task, so it earns points; answers 409 when someone else just did it, unless `force` is set.",
+    params(("household_id" = Uuid, Path), ("pet_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    request_body = Option<LogPetCareRequest>,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<PetCareEntry>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn log_care(
+    req: HttpRequest,
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String, String)>,
+    body: Option<web::Json<LogPetCareRequest>>,
+) -> Result<HttpResponse> {
+    let (_, pet_id_str, task_id_str) = path.into_inner();
+    let pet_id = match parse_id(&pet_id_str, "pet") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+    let task_id = match parse_id(&task_id_str, "task") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+    let force = body.map(|b| b.force).unwrap_or(false);
+
+    match pet_service::log_care(&state.db, &ctx.household_id, &pet_id, &task_id, &ctx.user_id, force).await {
+        Ok(entry) => {
+            if let Some(ws_manager) = req.app_data::<web::Data<Arc<WsManager>>>() {
+                ws_manager.broadcast_pet_care_logged(&ctx.household_id, entry.clone()).await;
+            }
+            crate::handlers::websocket::broadcast_leaderboard(&req, &state.db, &ctx.household_id).await;
+            crate::handlers::reward_wishlist::notify_affordable(&req, &state.db, &ctx.household_id).await;
+            crate::handlers::point_goals::broadcast_progress(&req, &state.db, &ctx.household_id).await;
+            Ok(HttpResponse::Created().json(ApiSuccess::new(entry)))
+        }
+        Err(e) => Ok(pet_error_response(e, "logging pet care")),
+    }
+}
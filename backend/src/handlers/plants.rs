@@ -0,0 +1,242 @@
+use actix_web::{web, HttpResponse, Result};
+use shared::{ApiError, ApiSuccess, AttachmentEntity, CreatePlantRequest, Permission, Plant, UpdatePlantRequest};
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::{attachments as attachment_service, plants as plant_service};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/plants")
+            .route("", web::get().to(list_plants))
+            .route("", web::post().to(create_plant))
+            .route("/{plant_id}", web::get().to(get_plant))
+            .route("/{plant_id}", web::put().to(update_plant))
+            .route("/{plant_id}", web::delete().to(delete_plant)),
+    );
+}
+
+/// Watering and fertilizing reminders are tasks, so changing plants needs the task permission
+async fn require_manage_tasks(state: &AppState, ctx: &HouseholdContext) -> Result<(), HttpResponse> {
+    if ctx.has_permission(state, Permission::ManageTasks).await {
+        return Ok(());
+    }
+    Err(HttpResponse::Forbidden().json(ApiError {
+        error: "forbidden".to_string(),
+        message: "You don't have permission to manage plant care tasks".to_string(),
+    }))
+}
+
+fn parse_plant_id(id: &str) -> Result<Uuid, HttpResponse> {
+    Uuid::parse_str(id).map_err(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_id".to_string(),
+            message: "Invalid plant ID format".to_string(),
+        })
+    })
+}
+
+fn validation_error(message: &str) -> HttpResponse {
+    HttpResponse::BadRequest().json(ApiError {
+        error: "validation_error".to_string(),
+        message: message.to_string(),
+    })
+}
+
+fn plant_error_response(error: plant_service::PlantError, context: &str) -> HttpResponse {
+    match error {
+        plant_service::PlantError::NotFound => HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: error.to_string(),
+        }),
+        plant_service::PlantError::InvalidInterval => validation_error(&error.to_string()),
+        e => {
+            log::error!("Error {}: {:?}", context, e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: format!("Failed {}", context),
+            })
+        }
+    }
+}
+
+/// Attach the most recent photo of each plant
+async fn with_photos(state: &AppState, household_id: &Uuid, mut plants: Vec<Plant>) -> Vec<Plant> {
+    let mut photos = attachment_service::list_for_household(&state.db, household_id, AttachmentEntity::Plant)
+        .await
+        .unwrap_or_default();
+    for plant in &mut plants {
+        plant.photo = photos.remove(&plant.id).and_then(|mut list| list.pop());
+    }
+    plants
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/plants",
+    tag = "plants",
+    summary = "List plants",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<shared::Plant>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_plants(state: web::Data<AppState>, ctx: HouseholdContext) -> Result<HttpResponse> {
+    match plant_service::list_plants(&state.db, &ctx.household_id).await {
+        Ok(plants) => {
+            let plants = with_photos(&state, &ctx.household_id, plants).await;
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(plants)))
+        }
+        Err(e) => Ok(plant_error_response(e, "listing plants")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/plants",
+    tag = "plants",
+    summary = "Create plant",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreatePlantRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<shared::Plant>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn create_plant(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    body: web::Json<CreatePlantRequest>,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_manage_tasks(&state, &ctx).await {
+        return Ok(response);
+    }
+    let request = body.into_inner();
+    if request.name.trim().is_empty() {
+        return Ok(validation_error("Plant name is required"));
+    }
+
+    match plant_service::create_plant(&state.db, &ctx.household_id, &ctx.user_id, &request).await {
+        Ok(plant) => Ok(HttpResponse::Created().json(ApiSuccess::new(plant))),
+        Err(e) => Ok(plant_error_response(e, "creating plant")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/plants/{plant_id}",
+    tag = "plants",
+    summary = "Get plant",
+    params(("household_id" = Uuid, Path), ("plant_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<shared::Plant>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn get_plant(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, plant_id_str) = path.into_inner();
+    let plant_id = match parse_plant_id(&plant_id_str) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match plant_service::get_plant(&state.db, &ctx.household_id, &plant_id).await {
+        Ok(plant) => {
+            let plant = with_photos(&state, &ctx.household_id, vec![plant]).await.remove(0);
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(plant)))
+        }
+        Err(e) => Ok(plant_error_response(e, "loading plant")),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/plants/{plant_id}",
+    tag = "plants",
+    summary = "Update plant",
+    params(("household_id" = Uuid, Path), ("plant_id" = Uuid, Path)),
+    request_body = UpdatePlantRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<shared::Plant>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn update_plant(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+    body: web::Json<UpdatePlantRequest>,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_manage_tasks(&state, &ctx).await {
+        return Ok(response);
+    }
+    let (_, plant_id_str) = path.into_inner();
+    let plant_id = match parse_plant_id(&plant_id_str) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    let request = body.into_inner();
+    if request.name.as_ref().is_some_and(|n| n.trim().is_empty()) {
+        return Ok(validation_error("Plant name is required"));
+    }
+
+    match plant_service::update_plant(&state.db, &ctx.household_id, &plant_id, &request).await {
+        Ok(plant) => {
+            let plant = with_photos(&state, &ctx.household_id, vec![plant]).await.remove(0);
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(plant)))
+        }
+        Err(e) => Ok(plant_error_response(e, "updating plant")),
+    }
+}
+
+/// Delete the plant and its photos; its care tasks go to the trash
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/plants/{plant_id}",
+    tag = "plants",
+    summary = "Delete the plant and its photos; its care tasks go to the trash",
+    params(("household_id" = Uuid, Path), ("plant_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn delete_plant(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_manage_tasks(&state, &ctx).await {
+        return Ok(response);
+    }
+    let (_, plant_id_str) = path.into_inner();
+    let plant_id = match parse_plant_id(&plant_id_str) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    if let Err(e) = plant_service::delete_plant(&state.db, &ctx.household_id, &plant_id).await {
+        return Ok(plant_error_response(e, "deleting plant"));
+    }
+    if let Err(e) =
+        attachment_service::delete_for_entity(&state.db, &state.config.upload_dir, AttachmentEntity::Plant, &plant_id).await
+    {
+        log::error!("Error deleting plant photos: {:?}", e);
+    }
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -1,9 +1,11 @@
 use actix_web::{web, HttpResponse, Result};
-use shared::{ApiError, ApiSuccess, CreatePointConditionRequest, UpdatePointConditionRequest};
+use shared::{ApiError, ApiSuccess, CreatePointConditionRequest, Permission, PointCondition, UpdatePointConditionRequest};
 use uuid::Uuid;
 
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
 use crate::models::AppState;
-use crate::services::{households as household_service, points as points_service};
+use crate::services::points as points_service;
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -16,39 +18,55 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
     );
 }
 
-async fn list_point_conditions(
-    state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
-) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
+fn parse_condition_id(condition_id_str: &str) -> Result<Uuid, HttpResponse> {
+    Uuid::parse_str(condition_id_str).map_err(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_id".to_string(),
+            message: "Invalid condition ID format".to_string(),
+        })
+    })
+}
 
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
+/// The point condition, if it exists in the household from the path
+async fn household_condition(
+    state: &AppState,
+    ctx: &HouseholdContext,
+    condition_id: &Uuid,
+) -> Result<PointCondition, HttpResponse> {
+    match points_service::get_point_condition(&state.db, condition_id).await {
+        Ok(Some(condition)) => {
+            ctx.check_owns(&condition.household_id, "Point condition")?;
+            Ok(condition)
+        }
+        Ok(None) => Err(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Point condition not found".to_string(),
+        })),
+        Err(e) => {
+            log::error!("Error fetching point condition: {:?}", e);
+            Err(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch point condition".to_string(),
+            }))
         }
-    };
-
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
     }
+}
 
-    match points_service::list_point_conditions(&state.db, &household_id).await {
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/point-conditions",
+    tag = "point_conditions",
+    summary = "List point conditions",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<PointCondition>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_point_conditions(state: web::Data<AppState>, ctx: HouseholdContext) -> Result<HttpResponse> {
+    match points_service::list_point_conditions(&state.db, &ctx.household_id).await {
         Ok(conditions) => Ok(HttpResponse::Ok().json(ApiSuccess::new(conditions))),
         Err(e) => {
             log::error!("Error listing point conditions: {:?}", e);
@@ -60,37 +78,29 @@ async fn list_point_conditions(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/point-conditions",
+    tag = "point_conditions",
+    summary = "Create point condition",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreatePointConditionRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<PointCondition>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn create_point_condition(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
     body: web::Json<CreatePointConditionRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.map(|r| r.can_manage_tasks()).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::AdjustPoints).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
-            message: "Only owners and admins can create point conditions".to_string(),
+            message: "You do not have permission to create point conditions".to_string(),
         }));
     }
 
@@ -102,7 +112,7 @@ async fn create_point_condition(
         }));
     }
 
-    match points_service::create_point_condition(&state.db, &household_id, &request).await {
+    match points_service::create_point_condition(&state.db, &ctx.household_id, &request).await {
         Ok(condition) => Ok(HttpResponse::Created().json(ApiSuccess::new(condition))),
         Err(e) => {
             log::error!("Error creating point condition: {:?}", e);
@@ -114,112 +124,75 @@ async fn create_point_condition(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/point-conditions/{condition_id}",
+    tag = "point_conditions",
+    summary = "Get point condition",
+    params(("household_id" = Uuid, Path), ("condition_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<PointCondition>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn get_point_condition(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, condition_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    let condition_id = match Uuid::parse_str(&condition_id_str) {
+    let (_, condition_id_str) = path.into_inner();
+    let condition_id = match parse_condition_id(&condition_id_str) {
         Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid condition ID format".to_string(),
-            }));
-        }
+        Err(response) => return Ok(response),
     };
 
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    match points_service::get_point_condition(&state.db, &condition_id).await {
-        Ok(Some(condition)) => Ok(HttpResponse::Ok().json(ApiSuccess::new(condition))),
-        Ok(None) => Ok(HttpResponse::NotFound().json(ApiError {
-            error: "not_found".to_string(),
-            message: "Point condition not found".to_string(),
-        })),
-        Err(e) => {
-            log::error!("Error fetching point condition: {:?}", e);
-            Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch point condition".to_string(),
-            }))
-        }
+    match household_condition(&state, &ctx, &condition_id).await {
+        Ok(condition) => Ok(HttpResponse::Ok().json(ApiSuccess::new(condition))),
+        Err(response) => Ok(response),
     }
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/point-conditions/{condition_id}",
+    tag = "point_conditions",
+    summary = "Update point condition",
+    params(("household_id" = Uuid, Path), ("condition_id" = Uuid, Path)),
+    request_body = UpdatePointConditionRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<PointCondition>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn update_point_condition(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
     body: web::Json<UpdatePointConditionRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
+    let (_, condition_id_str) = path.into_inner();
+    let condition_id = match parse_condition_id(&condition_id_str) {
         Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
+        Err(response) => return Ok(response),
     };
 
-    let (household_id_str, condition_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    let condition_id = match Uuid::parse_str(&condition_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid condition ID format".to_string(),
-            }));
-        }
-    };
-
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.map(|r| r.can_manage_tasks()).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::AdjustPoints).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
-            message: "Only owners and admins can update point conditions".to_string(),
+            message: "You do not have permission to update point conditions".to_string(),
         }));
     }
 
+    if let Err(response) = household_condition(&state, &ctx, &condition_id).await {
+        return Ok(response);
+    }
+
     match points_service::update_point_condition(&state.db, &condition_id, &body.into_inner()).await {
         Ok(condition) => Ok(HttpResponse::Ok().json(ApiSuccess::new(condition))),
         Err(e) => {
@@ -232,51 +205,36 @@ async fn update_point_condition(
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/point-conditions/{condition_id}",
+    tag = "point_conditions",
+    summary = "Delete point condition",
+    params(("household_id" = Uuid, Path), ("condition_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
 async fn delete_point_condition(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
+    let (_, condition_id_str) = path.into_inner();
+    let condition_id = match parse_condition_id(&condition_id_str) {
         Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
+        Err(response) => return Ok(response),
     };
 
-    let (household_id_str, condition_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    let condition_id = match Uuid::parse_str(&condition_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid condition ID format".to_string(),
-            }));
-        }
-    };
-
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.map(|r| r.can_manage_tasks()).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::AdjustPoints).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
-            message: "Only owners and admins can delete point conditions".to_string(),
+            message: "You do not have permission to delete point conditions".to_string(),
         }));
     }
 
+    if let Err(response) = household_condition(&state, &ctx, &condition_id).await {
+        return Ok(response);
+    }
+
     match points_service::delete_point_condition(&state.db, &condition_id).await {
         Ok(_) => Ok(HttpResponse::NoContent().finish()),
         Err(e) => {
@@ -0,0 +1,193 @@
+use actix_web::{web, HttpRequest, HttpResponse, Result};
+use shared::{ApiError, ApiSuccess, CreatePointGoalRequest, PointGoalProgress, Role};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::{household_settings as settings_service, point_goals as goal_service, scheduler, websocket::WsManager};
+
+/// Points targets of a member; the member sets them, or the owner on their behalf
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/members/{user_id}/goals")
+            .route("", web::get().to(list_goals))
+            .route("", web::post().to(create_goal))
+            .route("/{goal_id}", web::delete().to(delete_goal)),
+    );
+}
+
+/// Recalculate running goals and push their progress to the household. Call after points change.
+pub async fn broadcast_progress(req: &HttpRequest, pool: &SqlitePool, household_id: &Uuid) {
+    let today = match settings_service::get_or_create_settings(pool, household_id).await {
+        Ok(settings) => scheduler::today_in_timezone(scheduler::parse_timezone(&settings.timezone)),
+        Err(e) => {
+            log::error!("Error fetching settings for goal progress: {:?}", e);
+            return;
+        }
+    };
+
+    let progress = match goal_service::refresh_progress(pool, household_id, today).await {
+        Ok(progress) => progress,
+        Err(e) => {
+            log::error!("Error refreshing goal progress: {:?}", e);
+            return;
+        }
+    };
+
+    let Some(ws_manager) = req.app_data::<web::Data<Arc<WsManager>>>() else {
+        return;
+    };
+    for goal in progress {
+        ws_manager.broadcast_goal_progress_updated(household_id, goal).await;
+    }
+}
+
+fn goal_error_response(e: goal_service::PointGoalError, action: &str) -> HttpResponse {
+    match e {
+        goal_service::PointGoalError::EmptyTitle
+        | goal_service::PointGoalError::InvalidTarget
+        | goal_service::PointGoalError::DeadlineInPast => HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: e.to_string(),
+        }),
+        goal_service::PointGoalError::NotMember => HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_user".to_string(),
+            message: "Target user is not a member of this household".to_string(),
+        }),
+        e => {
+            log::error!("Error trying to {} point goal: {:?}", action, e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: format!("Failed to {} point goal", action),
+            })
+        }
+    }
+}
+
+fn parse_id(id: &str, what: &str) -> Result<Uuid, HttpResponse> {
+    Uuid::parse_str(id).map_err(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_id".to_string(),
+            message: format!("Invalid {} ID format", what),
+        })
+    })
+}
+
+/// Members manage their own goals; the owner may manage anyone's
+fn check_can_manage(ctx: &HouseholdContext, user_id: &Uuid) -> Result<(), HttpResponse> {
+    if ctx.user_id == *user_id || ctx.role() == Role::Owner {
+        return Ok(());
+    }
+    Err(HttpResponse::Forbidden().json(ApiError {
+        error: "forbidden".to_string(),
+        message: "You can only manage your own goals".to_string(),
+    }))
+}
+
+fn household_today(ctx: &HouseholdContext) -> chrono::NaiveDate {
+    scheduler::today_in_timezone(scheduler::parse_timezone(&ctx.settings.timezone))
+}
+
+/// Every member can see the goals of the household, e.g. for progress bars on the dashboard
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/members/{user_id}/goals",
+    tag = "point_goals",
+    summary = "List goals",
+    description = "Every member can see the goals of the household, e.g. for progress bars on the dashboard",
+    params(("household_id" = Uuid, Path), ("user_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<PointGoalProgress>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn list_goals(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, user_id) = path.into_inner();
+    let user_id = match parse_id(&user_id, "user") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match goal_service::list_goals(&state.db, &ctx.household_id, &user_id, household_today(&ctx)).await {
+        Ok(goals) => Ok(HttpResponse::Ok().json(ApiSuccess::new(goals))),
+        Err(e) => Ok(goal_error_response(e, "list")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/members/{user_id}/goals",
+    tag = "point_goals",
+    summary = "Create goal",
+    params(("household_id" = Uuid, Path), ("user_id" = Uuid, Path)),
+    request_body = CreatePointGoalRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<PointGoalProgress>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn create_goal(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+    body: web::Json<CreatePointGoalRequest>,
+) -> Result<HttpResponse> {
+    let (_, user_id) = path.into_inner();
+    let user_id = match parse_id(&user_id, "user") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+    if let Err(response) = check_can_manage(&ctx, &user_id) {
+        return Ok(response);
+    }
+
+    match goal_service::create_goal(&state.db, &ctx.household_id, &user_id, &ctx.user_id, &body, household_today(&ctx)).await {
+        Ok(goal) => Ok(HttpResponse::Created().json(ApiSuccess::new(goal))),
+        Err(e) => Ok(goal_error_response(e, "create")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/members/{user_id}/goals/{goal_id}",
+    tag = "point_goals",
+    summary = "Delete goal",
+    params(("household_id" = Uuid, Path), ("user_id" = Uuid, Path), ("goal_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn delete_goal(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String, String)>,
+) -> Result<HttpResponse> {
+    let (_, user_id, goal_id) = path.into_inner();
+    let (user_id, goal_id) = match (parse_id(&user_id, "user"), parse_id(&goal_id, "goal")) {
+        (Ok(user_id), Ok(goal_id)) => (user_id, goal_id),
+        (Err(response), _) | (_, Err(response)) => return Ok(response),
+    };
+    if let Err(response) = check_can_manage(&ctx, &user_id) {
+        return Ok(response);
+    }
+
+    match goal_service::delete_goal(&state.db, &ctx.household_id, &user_id, &goal_id).await {
+        Ok(true) => Ok(HttpResponse::NoContent().finish()),
+        Ok(false) => Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Point goal not found".to_string(),
+        })),
+        Err(e) => Ok(goal_error_response(e, "delete")),
+    }
+}
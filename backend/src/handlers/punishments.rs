@@ -1,9 +1,15 @@
 use actix_web::{web, HttpResponse, Result};
-use shared::{ActivityType, ApiError, ApiSuccess, CreatePunishmentRequest, UpdatePunishmentRequest};
+use shared::{
+    ActivityType, ApiError, ApiSuccess, CreatePunishmentRequest, PendingPunishmentCompletion,
+    Permission, Punishment, RandomPickResult, UpdateOptionWeightRequest, UpdatePunishmentRequest,
+    UserPunishment, UserPunishmentWithUser, WeightedPunishmentOption,
+};
 use uuid::Uuid;
 
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Null, Unauthorized};
+use crate::middleware::household::HouseholdContext;
 use crate::models::AppState;
-use crate::services::{activity_logs, household_settings, households as household_service, punishments as punishment_service, solo_mode};
+use crate::services::{activity_logs, households as household_service, punishments as punishment_service};
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -26,60 +32,28 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route("/{punishment_id}/assign/{user_id}", web::post().to(assign_punishment))
             .route("/{punishment_id}/unassign/{user_id}", web::post().to(unassign_punishment))
             .route("/{punishment_id}/options", web::get().to(get_punishment_options))
+            .route("/{punishment_id}/options/{option_id}", web::put().to(update_punishment_option_weight))
     );
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/punishments",
+    tag = "punishments",
+    summary = "List punishments",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<Punishment>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn list_punishments(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    // Check if punishments feature is enabled
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-    if !settings.punishments_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Punishments are not enabled for this household".to_string(),
-        }));
-    }
-
-    match punishment_service::list_punishments(&state.db, &household_id).await {
+    match punishment_service::list_punishments(&state.db, &ctx.household_id).await {
         Ok(punishments) => Ok(HttpResponse::Ok().json(ApiSuccess::new(punishments))),
         Err(e) => {
             log::error!("Error listing punishments: {:?}", e);
@@ -91,54 +65,26 @@ async fn list_punishments(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/punishments",
+    tag = "punishments",
+    summary = "Create punishment",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreatePunishmentRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<Punishment>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn create_punishment(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
     body: web::Json<CreatePunishmentRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if punishments feature is enabled
-    if !settings.punishments_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Punishments are not enabled for this household".to_string(),
-        }));
-    }
-
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ManageRewards).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to create punishments".to_string(),
@@ -153,14 +99,14 @@ async fn create_punishment(
         }));
     }
 
-    match punishment_service::create_punishment(&state.db, &household_id, &request).await {
+    match punishment_service::create_punishment(&state.db, &ctx.household_id, &request).await {
         Ok(punishment) => {
             // Log activity
             let details = serde_json::json!({ "name": punishment.name }).to_string();
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 None,
                 ActivityType::PunishmentCreated,
                 Some("punishment"),
@@ -180,32 +126,26 @@ async fn create_punishment(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/punishments/{punishment_id}",
+    tag = "punishments",
+    summary = "Get punishment",
+    params(("household_id" = Uuid, Path), ("punishment_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Punishment>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn get_punishment(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, punishment_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, punishment_id_str) = path.into_inner();
 
     let punishment_id = match Uuid::parse_str(&punishment_id_str) {
         Ok(id) => id,
@@ -217,33 +157,11 @@ async fn get_punishment(
         }
     };
 
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    // Check if punishments feature is enabled
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-    if !settings.punishments_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Punishments are not enabled for this household".to_string(),
-        }));
-    }
-
     match punishment_service::get_punishment(&state.db, &punishment_id).await {
-        Ok(Some(punishment)) => Ok(HttpResponse::Ok().json(ApiSuccess::new(punishment))),
+        Ok(Some(punishment)) => match ctx.check_owns(&punishment.household_id, "Punishment") {
+            Ok(()) => Ok(HttpResponse::Ok().json(ApiSuccess::new(punishment))),
+            Err(response) => Ok(response),
+        },
         Ok(None) => Ok(HttpResponse::NotFound().json(ApiError {
             error: "not_found".to_string(),
             message: "Punishment not found".to_string(),
@@ -258,33 +176,28 @@ async fn get_punishment(
     }
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/punishments/{punishment_id}",
+    tag = "punishments",
+    summary = "Update punishment",
+    params(("household_id" = Uuid, Path), ("punishment_id" = Uuid, Path)),
+    request_body = UpdatePunishmentRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Punishment>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn update_punishment(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
     body: web::Json<UpdatePunishmentRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, punishment_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, punishment_id_str) = path.into_inner();
 
     let punishment_id = match Uuid::parse_str(&punishment_id_str) {
         Ok(id) => id,
@@ -296,28 +209,7 @@ async fn update_punishment(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if punishments feature is enabled
-    if !settings.punishments_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Punishments are not enabled for this household".to_string(),
-        }));
-    }
-
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ManageRewards).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to update punishments".to_string(),
@@ -336,32 +228,20 @@ async fn update_punishment(
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/punishments/{punishment_id}",
+    tag = "punishments",
+    summary = "Delete punishment",
+    params(("household_id" = Uuid, Path), ("punishment_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
 async fn delete_punishment(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, punishment_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, punishment_id_str) = path.into_inner();
 
     let punishment_id = match Uuid::parse_str(&punishment_id_str) {
         Ok(id) => id,
@@ -373,28 +253,7 @@ async fn delete_punishment(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if punishments feature is enabled
-    if !settings.punishments_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Punishments are not enabled for this household".to_string(),
-        }));
-    }
-
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ManageRewards).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to delete punishments".to_string(),
@@ -411,8 +270,8 @@ async fn delete_punishment(
             // Log activity
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 None,
                 ActivityType::PunishmentDeleted,
                 Some("punishment"),
@@ -432,32 +291,26 @@ async fn delete_punishment(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/punishments/{punishment_id}/assign/{user_id}",
+    tag = "punishments",
+    summary = "Assign punishment",
+    params(("household_id" = Uuid, Path), ("punishment_id" = Uuid, Path), ("user_id" = Uuid, Path)),
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<UserPunishment>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn assign_punishment(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String, String)>,
 ) -> Result<HttpResponse> {
-    let current_user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, punishment_id_str, target_user_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, punishment_id_str, target_user_id_str) = path.into_inner();
 
     let punishment_id = match Uuid::parse_str(&punishment_id_str) {
         Ok(id) => id,
@@ -479,28 +332,7 @@ async fn assign_punishment(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if punishments feature is enabled
-    if !settings.punishments_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Punishments are not enabled for this household".to_string(),
-        }));
-    }
-
-    let role = household_service::get_member_role(&state.db, &household_id, &current_user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ManageRewards).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to assign punishments".to_string(),
@@ -508,7 +340,7 @@ async fn assign_punishment(
     }
 
     // Verify target user is a member
-    if !household_service::is_member(&state.db, &household_id, &target_user_id).await.unwrap_or(false) {
+    if !household_service::is_member(&state.db, &ctx.household_id, &target_user_id).await.unwrap_or(false) {
         return Ok(HttpResponse::BadRequest().json(ApiError {
             error: "invalid_user".to_string(),
             message: "Target user is not a member of this household".to_string(),
@@ -520,13 +352,13 @@ async fn assign_punishment(
     let details = punishment.as_ref()
         .map(|p| serde_json::json!({ "name": p.name }).to_string());
 
-    match punishment_service::assign_punishment(&state.db, &punishment_id, &target_user_id, &household_id).await {
+    match punishment_service::assign_punishment(&state.db, &punishment_id, &target_user_id, &ctx.household_id).await {
         Ok(user_punishment) => {
             // Log activity
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &current_user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 Some(&target_user_id),
                 ActivityType::PunishmentAssigned,
                 Some("punishment"),
@@ -546,32 +378,26 @@ async fn assign_punishment(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/punishments/{punishment_id}/unassign/{user_id}",
+    tag = "punishments",
+    summary = "Unassign punishment",
+    params(("household_id" = Uuid, Path), ("punishment_id" = Uuid, Path), ("user_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Null>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn unassign_punishment(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String, String)>,
 ) -> Result<HttpResponse> {
-    let current_user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, punishment_id_str, target_user_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, punishment_id_str, target_user_id_str) = path.into_inner();
 
     let punishment_id = match Uuid::parse_str(&punishment_id_str) {
         Ok(id) => id,
@@ -593,35 +419,14 @@ async fn unassign_punishment(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if punishments feature is enabled
-    if !settings.punishments_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Punishments are not enabled for this household".to_string(),
-        }));
-    }
-
-    let role = household_service::get_member_role(&state.db, &household_id, &current_user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ManageRewards).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to unassign punishments".to_string(),
         }));
     }
 
-    match punishment_service::unassign_punishment(&state.db, &punishment_id, &target_user_id, &household_id).await {
+    match punishment_service::unassign_punishment(&state.db, &punishment_id, &target_user_id, &ctx.household_id).await {
         Ok(_) => Ok(HttpResponse::Ok().json(ApiSuccess::new(()))),
         Err(e) => {
             log::error!("Error unassigning punishment: {:?}", e);
@@ -633,57 +438,24 @@ async fn unassign_punishment(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/punishments/user-punishments",
+    tag = "punishments",
+    summary = "List user punishments",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<UserPunishment>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn list_user_punishments(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    // Check if punishments feature is enabled
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-    if !settings.punishments_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Punishments are not enabled for this household".to_string(),
-        }));
-    }
-
-    match punishment_service::list_user_punishments(&state.db, &user_id, &household_id).await {
+    match punishment_service::list_user_punishments(&state.db, &ctx.user_id, &ctx.household_id).await {
         Ok(punishments) => Ok(HttpResponse::Ok().json(ApiSuccess::new(punishments))),
         Err(e) => {
             log::error!("Error listing user punishments: {:?}", e);
@@ -695,57 +467,24 @@ async fn list_user_punishments(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/punishments/user-punishments/all",
+    tag = "punishments",
+    summary = "List all user punishments",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<UserPunishmentWithUser>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn list_all_user_punishments(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    // Check if punishments feature is enabled
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-    if !settings.punishments_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Punishments are not enabled for this household".to_string(),
-        }));
-    }
-
-    match punishment_service::list_all_user_punishments_in_household(&state.db, &household_id).await {
+    match punishment_service::list_all_user_punishments_in_household(&state.db, &ctx.household_id).await {
         Ok(punishments) => Ok(HttpResponse::Ok().json(ApiSuccess::new(punishments))),
         Err(e) => {
             log::error!("Error listing all user punishments: {:?}", e);
@@ -757,32 +496,20 @@ async fn list_all_user_punishments(
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/punishments/user-punishments/{id}",
+    tag = "punishments",
+    summary = "Delete user punishment",
+    params(("household_id" = Uuid, Path), ("id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
 async fn delete_user_punishment(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, user_punishment_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, user_punishment_id_str) = path.into_inner();
 
     let user_punishment_id = match Uuid::parse_str(&user_punishment_id_str) {
         Ok(id) => id,
@@ -794,29 +521,8 @@ async fn delete_user_punishment(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if punishments feature is enabled
-    if !settings.punishments_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Punishments are not enabled for this household".to_string(),
-        }));
-    }
-
     // Only users with manage permission can delete user punishments
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ManageRewards).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to remove punishment assignments".to_string(),
@@ -835,32 +541,26 @@ async fn delete_user_punishment(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/punishments/user-punishments/{id}/complete",
+    tag = "punishments",
+    summary = "Complete punishment",
+    params(("household_id" = Uuid, Path), ("id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<UserPunishment>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn complete_punishment(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, user_punishment_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, user_punishment_id_str) = path.into_inner();
 
     let user_punishment_id = match Uuid::parse_str(&user_punishment_id_str) {
         Ok(id) => id,
@@ -872,33 +572,7 @@ async fn complete_punishment(
         }
     };
 
-    // Any member can trigger completion (user or manager)
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    // Check if punishments feature is enabled
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-    if !settings.punishments_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Punishments are not enabled for this household".to_string(),
-        }));
-    }
-
-    match punishment_service::complete_punishment(&state.db, &user_punishment_id, &user_id).await {
+    match punishment_service::complete_punishment(&state.db, &user_punishment_id, &ctx.user_id).await {
         Ok((user_punishment, requires_confirmation)) => {
             // Get punishment details for logging
             let punishment = punishment_service::get_punishment(&state.db, &user_punishment.punishment_id).await.ok().flatten();
@@ -909,8 +583,8 @@ async fn complete_punishment(
             if !requires_confirmation {
                 let _ = activity_logs::log_activity(
                     &state.db,
-                    &household_id,
-                    &user_id,
+                    &ctx.household_id,
+                    &ctx.user_id,
                     Some(&user_punishment.user_id),
                     ActivityType::PunishmentCompleted,
                     Some("punishment"),
@@ -931,60 +605,31 @@ async fn complete_punishment(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/punishments/pending-confirmations",
+    tag = "punishments",
+    summary = "List pending completions",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<PendingPunishmentCompletion>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn list_pending_completions(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if punishments feature is enabled
-    if !settings.punishments_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Punishments are not enabled for this household".to_string(),
-        }));
-    }
-
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ReviewCompletions).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to view pending confirmations".to_string(),
         }));
     }
 
-    match punishment_service::list_pending_completions(&state.db, &household_id).await {
+    match punishment_service::list_pending_completions(&state.db, &ctx.household_id).await {
         Ok(pending) => Ok(HttpResponse::Ok().json(ApiSuccess::new(pending))),
         Err(e) => {
             log::error!("Error listing pending completions: {:?}", e);
@@ -996,32 +641,27 @@ async fn list_pending_completions(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/punishments/user-punishments/{id}/approve",
+    operation_id = "punishments_approve_completion",
+    tag = "punishments",
+    summary = "Approve completion",
+    params(("household_id" = Uuid, Path), ("id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<UserPunishment>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn approve_completion(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, user_punishment_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, user_punishment_id_str) = path.into_inner();
 
     let user_punishment_id = match Uuid::parse_str(&user_punishment_id_str) {
         Ok(id) => id,
@@ -1033,28 +673,7 @@ async fn approve_completion(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if punishments feature is enabled
-    if !settings.punishments_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Punishments are not enabled for this household".to_string(),
-        }));
-    }
-
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ReviewCompletions).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to approve completions".to_string(),
@@ -1071,8 +690,8 @@ async fn approve_completion(
             // Log activity
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 Some(&user_punishment.user_id),
                 ActivityType::PunishmentCompletionApproved,
                 Some("punishment"),
@@ -1092,32 +711,27 @@ async fn approve_completion(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/punishments/user-punishments/{id}/reject",
+    operation_id = "punishments_reject_completion",
+    tag = "punishments",
+    summary = "Reject completion",
+    params(("household_id" = Uuid, Path), ("id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<UserPunishment>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn reject_completion(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, user_punishment_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, user_punishment_id_str) = path.into_inner();
 
     let user_punishment_id = match Uuid::parse_str(&user_punishment_id_str) {
         Ok(id) => id,
@@ -1129,28 +743,7 @@ async fn reject_completion(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if punishments feature is enabled
-    if !settings.punishments_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Punishments are not enabled for this household".to_string(),
-        }));
-    }
-
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ReviewCompletions).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to reject completions".to_string(),
@@ -1167,8 +760,8 @@ async fn reject_completion(
             // Log activity
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 Some(&user_punishment.user_id),
                 ActivityType::PunishmentCompletionRejected,
                 Some("punishment"),
@@ -1188,32 +781,26 @@ async fn reject_completion(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/punishments/{punishment_id}/options",
+    tag = "punishments",
+    summary = "Get punishment options",
+    params(("household_id" = Uuid, Path), ("punishment_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<WeightedPunishmentOption>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn get_punishment_options(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, punishment_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, punishment_id_str) = path.into_inner();
 
     let punishment_id = match Uuid::parse_str(&punishment_id_str) {
         Ok(id) => id,
@@ -1225,29 +812,24 @@ async fn get_punishment_options(
         }
     };
 
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    // Check if punishments feature is enabled
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
+    let owned = match punishment_service::get_punishment(&state.db, &punishment_id).await {
+        Ok(Some(punishment)) => ctx.check_owns(&punishment.household_id, "Punishment"),
+        Ok(None) => {
+            return Ok(HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: "Punishment not found".to_string(),
+            }));
+        }
         Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
+            log::error!("Error fetching punishment: {:?}", e);
             return Ok(HttpResponse::InternalServerError().json(ApiError {
                 error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
+                message: "Failed to fetch punishment".to_string(),
             }));
         }
     };
-    if !settings.punishments_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Punishments are not enabled for this household".to_string(),
-        }));
+    if let Err(response) = owned {
+        return Ok(response);
     }
 
     match punishment_service::get_punishment_options(&state.db, &punishment_id).await {
@@ -1262,69 +844,98 @@ async fn get_punishment_options(
     }
 }
 
-async fn pick_random_punishment(
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/punishments/{punishment_id}/options/{option_id}",
+    tag = "punishments",
+    summary = "Update punishment option weight",
+    params(("household_id" = Uuid, Path), ("punishment_id" = Uuid, Path), ("option_id" = Uuid, Path)),
+    request_body = UpdateOptionWeightRequest,
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn update_punishment_option_weight(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<(String, String)>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String, String)>,
+    body: web::Json<UpdateOptionWeightRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
+    let (_, punishment_id_str, option_id_str) = path.into_inner();
 
-    let (household_id_str, user_punishment_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
+    let punishment_id = match Uuid::parse_str(&punishment_id_str) {
         Ok(id) => id,
         Err(_) => {
             return Ok(HttpResponse::BadRequest().json(ApiError {
                 error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
+                message: "Invalid punishment ID format".to_string(),
             }));
         }
     };
 
-    let user_punishment_id = match Uuid::parse_str(&user_punishment_id_str) {
+    let option_id = match Uuid::parse_str(&option_id_str) {
         Ok(id) => id,
         Err(_) => {
             return Ok(HttpResponse::BadRequest().json(ApiError {
                 error: "invalid_id".to_string(),
-                message: "Invalid user punishment ID format".to_string(),
+                message: "Invalid option ID format".to_string(),
             }));
         }
     };
 
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ManageRewards).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
+            message: "You do not have permission to manage punishment options".to_string(),
         }));
     }
 
-    // Check if punishments feature is enabled
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
+    match punishment_service::set_punishment_option_weight(&state.db, &ctx.household_id, &punishment_id, &option_id, body.weight).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(punishment_service::PunishmentError::OptionNotFound) => Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Option not found".to_string(),
+        })),
         Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
+            log::error!("Error updating punishment option weight: {:?}", e);
+            Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "option_error".to_string(),
+                message: e.to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/punishments/user-punishments/{id}/pick",
+    tag = "punishments",
+    summary = "Pick random punishment",
+    params(("household_id" = Uuid, Path), ("id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<RandomPickResult>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn pick_random_punishment(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, user_punishment_id_str) = path.into_inner();
+
+    let user_punishment_id = match Uuid::parse_str(&user_punishment_id_str) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_id".to_string(),
+                message: "Invalid user punishment ID format".to_string(),
             }));
         }
     };
-    if !settings.punishments_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Punishments are not enabled for this household".to_string(),
-        }));
-    }
 
-    match punishment_service::pick_random_option(&state.db, &user_punishment_id, &user_id).await {
+    match punishment_service::pick_random_option(&state.db, &user_punishment_id, &ctx.user_id).await {
         Ok(result) => {
             // Log activity
             let details = serde_json::json!({
@@ -1333,9 +944,9 @@ async fn pick_random_punishment(
 
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
-                Some(&user_id),
+                &ctx.household_id,
+                &ctx.user_id,
+                Some(&ctx.user_id),
                 ActivityType::PunishmentRandomPicked,
                 Some("punishment"),
                 Some(&result.picked_punishment.id),
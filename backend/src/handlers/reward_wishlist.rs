@@ -0,0 +1,154 @@
+use actix_web::{web, HttpRequest, HttpResponse, Result};
+use shared::{ApiError, ApiSuccess, NotificationType, WishlistItem};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::reward_wishlist as wishlist_service;
+
+/// The current member's reward wishlist
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/wishlist")
+            .route("", web::get().to(list_wishlist))
+            .route("/{reward_id}", web::post().to(add_to_wishlist))
+            .route("/{reward_id}", web::delete().to(remove_from_wishlist)),
+    );
+}
+
+/// Tell members about wishlisted rewards they can now afford. Call after points or rewards change.
+pub async fn notify_affordable(req: &HttpRequest, pool: &SqlitePool, household_id: &Uuid) {
+    let affordable = match wishlist_service::take_newly_affordable(pool, household_id).await {
+        Ok(affordable) => affordable,
+        Err(e) => {
+            log::error!("Error checking wishlists: {:?}", e);
+            return;
+        }
+    };
+
+    for (user_id, reward) in affordable {
+        crate::handlers::notification_center::notify(
+            req,
+            pool,
+            user_id,
+            *household_id,
+            NotificationType::RewardAffordable,
+            &reward.name,
+            Some(("reward", reward.id)),
+        )
+        .await;
+    }
+}
+
+fn parse_reward_id(reward_id: &str) -> std::result::Result<Uuid, HttpResponse> {
+    Uuid::parse_str(reward_id).map_err(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_id".to_string(),
+            message: "Invalid reward ID format".to_string(),
+        })
+    })
+}
+
+fn wishlist_error_response(error: wishlist_service::WishlistError) -> HttpResponse {
+    match error {
+        wishlist_service::WishlistError::RewardNotFound | wishlist_service::WishlistError::NotOnWishlist => {
+            HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: error.to_string(),
+            })
+        }
+        wishlist_service::WishlistError::NoPointCost => HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: error.to_string(),
+        }),
+        wishlist_service::WishlistError::DatabaseError(e) => {
+            log::error!("Error updating wishlist: {:?}", e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to update wishlist".to_string(),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/wishlist",
+    tag = "reward_wishlist",
+    summary = "List wishlist",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<WishlistItem>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_wishlist(state: web::Data<AppState>, ctx: HouseholdContext) -> Result<HttpResponse> {
+    match wishlist_service::list_wishlist(&state.db, &ctx.household_id, &ctx.user_id).await {
+        Ok(items) => Ok(HttpResponse::Ok().json(ApiSuccess::new(items))),
+        Err(e) => Ok(wishlist_error_response(e)),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/wishlist/{reward_id}",
+    tag = "reward_wishlist",
+    summary = "Add to wishlist",
+    params(("household_id" = Uuid, Path), ("reward_id" = Uuid, Path)),
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<Vec<WishlistItem>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn add_to_wishlist(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, reward_id) = path.into_inner();
+    let reward_id = match parse_reward_id(&reward_id) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    if let Err(e) = wishlist_service::add_to_wishlist(&state.db, &ctx.household_id, &ctx.user_id, &reward_id).await {
+        return Ok(wishlist_error_response(e));
+    }
+
+    match wishlist_service::list_wishlist(&state.db, &ctx.household_id, &ctx.user_id).await {
+        Ok(items) => Ok(HttpResponse::Created().json(ApiSuccess::new(items))),
+        Err(e) => Ok(wishlist_error_response(e)),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/wishlist/{reward_id}",
+    tag = "reward_wishlist",
+    summary = "Remove from wishlist",
+    params(("household_id" = Uuid, Path), ("reward_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn remove_from_wishlist(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, reward_id) = path.into_inner();
+    let reward_id = match parse_reward_id(&reward_id) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match wishlist_service::remove_from_wishlist(&state.db, &ctx.household_id, &ctx.user_id, &reward_id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(wishlist_error_response(e)),
+    }
+}
@@ -1,9 +1,17 @@
 use actix_web::{web, HttpResponse, Result};
-use shared::{ActivityType, ApiError, ApiSuccess, CreateRewardRequest, UpdateRewardRequest};
+use shared::{
+    ActivityType, ApiError, ApiSuccess, CreateRewardRequest, NotificationKind, NotificationType,
+    PendingRewardPurchase, PendingRewardRedemption, Permission, PurchaseRewardResponse,
+    RandomRewardPickResult, Reward, RewardOption, RewardPurchase, UpdateOptionWeightRequest,
+    UpdateRewardRequest, UserReward, UserRewardWithUser, WeightedRewardOption,
+};
+use std::sync::Arc;
 use uuid::Uuid;
 
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Null, Unauthorized};
+use crate::middleware::household::HouseholdContext;
 use crate::models::AppState;
-use crate::services::{activity_logs, household_settings, households as household_service, rewards as reward_service, solo_mode};
+use crate::services::{activity_logs, auth as auth_service, households as household_service, notifications as notification_service, rewards as reward_service, websocket::WsManager};
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -15,10 +23,14 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route("/user-rewards/all", web::get().to(list_all_user_rewards))
             .route("/user-rewards/{id}", web::delete().to(delete_user_reward))
             .route("/user-rewards/{id}/redeem", web::post().to(redeem_reward))
+            .route("/user-rewards/{id}/gift/{user_id}", web::post().to(gift_reward))
             .route("/user-rewards/{id}/approve", web::post().to(approve_redemption))
             .route("/user-rewards/{id}/reject", web::post().to(reject_redemption))
             .route("/user-rewards/{id}/pick", web::post().to(pick_random_reward))
             .route("/pending-confirmations", web::get().to(list_pending_redemptions))
+            .route("/purchases/pending", web::get().to(list_pending_purchases))
+            .route("/purchases/{id}/approve", web::post().to(approve_purchase))
+            .route("/purchases/{id}/reject", web::post().to(reject_purchase))
             // Dynamic routes after static routes
             .route("/{reward_id}", web::get().to(get_reward))
             .route("/{reward_id}", web::put().to(update_reward))
@@ -28,61 +40,29 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route("/{reward_id}/unassign/{user_id}", web::post().to(unassign_reward))
             .route("/{reward_id}/options", web::get().to(get_reward_options))
             .route("/{reward_id}/options/{option_id}", web::post().to(add_reward_option))
+            .route("/{reward_id}/options/{option_id}", web::put().to(update_reward_option_weight))
             .route("/{reward_id}/options/{option_id}", web::delete().to(remove_reward_option))
     );
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/rewards",
+    tag = "rewards",
+    summary = "List rewards",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<Reward>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn list_rewards(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    // Check if rewards feature is enabled
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-    if !settings.rewards_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Rewards are not enabled for this household".to_string(),
-        }));
-    }
-
-    match reward_service::list_rewards(&state.db, &household_id).await {
+    match reward_service::list_rewards(&state.db, &ctx.household_id).await {
         Ok(rewards) => Ok(HttpResponse::Ok().json(ApiSuccess::new(rewards))),
         Err(e) => {
             log::error!("Error listing rewards: {:?}", e);
@@ -94,54 +74,26 @@ async fn list_rewards(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/rewards",
+    tag = "rewards",
+    summary = "Create reward",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreateRewardRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<Reward>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn create_reward(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
     body: web::Json<CreateRewardRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if rewards feature is enabled
-    if !settings.rewards_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Rewards are not enabled for this household".to_string(),
-        }));
-    }
-
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ManageRewards).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to create rewards".to_string(),
@@ -156,14 +108,14 @@ async fn create_reward(
         }));
     }
 
-    match reward_service::create_reward(&state.db, &household_id, &request).await {
+    match reward_service::create_reward(&state.db, &ctx.household_id, &request).await {
         Ok(reward) => {
             // Log activity
             let details = serde_json::json!({ "name": reward.name }).to_string();
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 None,
                 ActivityType::RewardCreated,
                 Some("reward"),
@@ -183,32 +135,26 @@ async fn create_reward(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/rewards/{reward_id}",
+    tag = "rewards",
+    summary = "Get reward",
+    params(("household_id" = Uuid, Path), ("reward_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Reward>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn get_reward(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, reward_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, reward_id_str) = path.into_inner();
 
     let reward_id = match Uuid::parse_str(&reward_id_str) {
         Ok(id) => id,
@@ -220,33 +166,11 @@ async fn get_reward(
         }
     };
 
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    // Check if rewards feature is enabled
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-    if !settings.rewards_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Rewards are not enabled for this household".to_string(),
-        }));
-    }
-
     match reward_service::get_reward(&state.db, &reward_id).await {
-        Ok(Some(reward)) => Ok(HttpResponse::Ok().json(ApiSuccess::new(reward))),
+        Ok(Some(reward)) => match ctx.check_owns(&reward.household_id, "Reward") {
+            Ok(()) => Ok(HttpResponse::Ok().json(ApiSuccess::new(reward))),
+            Err(response) => Ok(response),
+        },
         Ok(None) => Ok(HttpResponse::NotFound().json(ApiError {
             error: "not_found".to_string(),
             message: "Reward not found".to_string(),
@@ -261,33 +185,29 @@ async fn get_reward(
     }
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/rewards/{reward_id}",
+    tag = "rewards",
+    summary = "Update reward",
+    params(("household_id" = Uuid, Path), ("reward_id" = Uuid, Path)),
+    request_body = UpdateRewardRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Reward>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn update_reward(
     state: web::Data<AppState>,
+    ctx: HouseholdContext,
     req: actix_web::HttpRequest,
     path: web::Path<(String, String)>,
     body: web::Json<UpdateRewardRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, reward_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, reward_id_str) = path.into_inner();
 
     let reward_id = match Uuid::parse_str(&reward_id_str) {
         Ok(id) => id,
@@ -299,28 +219,7 @@ async fn update_reward(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if rewards feature is enabled
-    if !settings.rewards_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Rewards are not enabled for this household".to_string(),
-        }));
-    }
-
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ManageRewards).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to update rewards".to_string(),
@@ -328,7 +227,11 @@ async fn update_reward(
     }
 
     match reward_service::update_reward(&state.db, &reward_id, &body.into_inner()).await {
-        Ok(reward) => Ok(HttpResponse::Ok().json(ApiSuccess::new(reward))),
+        Ok(reward) => {
+            // A lower price or the reward going back on sale can put wishlisted rewards in reach
+            crate::handlers::reward_wishlist::notify_affordable(&req, &state.db, &ctx.household_id).await;
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(reward)))
+        }
         Err(e) => {
             log::error!("Error updating reward: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
@@ -339,32 +242,20 @@ async fn update_reward(
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/rewards/{reward_id}",
+    tag = "rewards",
+    summary = "Delete reward",
+    params(("household_id" = Uuid, Path), ("reward_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
 async fn delete_reward(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, reward_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, reward_id_str) = path.into_inner();
 
     let reward_id = match Uuid::parse_str(&reward_id_str) {
         Ok(id) => id,
@@ -376,28 +267,7 @@ async fn delete_reward(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if rewards feature is enabled
-    if !settings.rewards_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Rewards are not enabled for this household".to_string(),
-        }));
-    }
-
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ManageRewards).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to delete rewards".to_string(),
@@ -414,8 +284,8 @@ async fn delete_reward(
             // Log activity
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 None,
                 ActivityType::RewardDeleted,
                 Some("reward"),
@@ -435,32 +305,28 @@ async fn delete_reward(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/rewards/{reward_id}/purchase",
+    tag = "rewards",
+    summary = "Purchase reward",
+    params(("household_id" = Uuid, Path), ("reward_id" = Uuid, Path)),
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<PurchaseRewardResponse>),
+        (status = 202, description = "Waiting for approval", body = ApiSuccess<PurchaseRewardResponse>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn purchase_reward(
     state: web::Data<AppState>,
+    ctx: HouseholdContext,
     req: actix_web::HttpRequest,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, reward_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, reward_id_str) = path.into_inner();
 
     let reward_id = match Uuid::parse_str(&reward_id_str) {
         Ok(id) => id,
@@ -472,51 +338,78 @@ async fn purchase_reward(
         }
     };
 
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    // Check if rewards feature is enabled
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-    if !settings.rewards_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Rewards are not enabled for this household".to_string(),
-        }));
-    }
-
     // Get reward details for logging
     let reward = reward_service::get_reward(&state.db, &reward_id).await.ok().flatten();
     let details = reward.as_ref()
         .map(|r| serde_json::json!({ "name": r.name }).to_string());
 
-    match reward_service::purchase_reward(&state.db, &reward_id, &user_id, &household_id).await {
+    // Members who cannot review purchases themselves need approval first
+    if ctx.settings.purchase_approval_required
+        && !ctx.has_permission(&state, Permission::ReviewCompletions).await
+    {
+        return match reward_service::request_purchase(&state.db, &reward_id, &ctx.user_id, &ctx.household_id).await {
+            Ok(purchase) => {
+                let _ = activity_logs::log_activity(
+                    &state.db,
+                    &ctx.household_id,
+                    &ctx.user_id,
+                    Some(&ctx.user_id),
+                    ActivityType::RewardPurchaseRequested,
+                    Some("reward"),
+                    Some(&reward_id),
+                    details.as_deref(),
+                ).await;
+
+                if let Some(ref reward) = reward {
+                    let requested_by = auth_service::get_user_by_id(&state.db, &ctx.user_id)
+                        .await
+                        .ok()
+                        .flatten()
+                        .map(|u| u.username)
+                        .unwrap_or_default();
+                    crate::handlers::notifications::notify_members(
+                        &state.db,
+                        state.push.as_ref(),
+                        &ctx.household_id,
+                        &ctx.user_id,
+                        Some(Permission::ReviewCompletions),
+                        NotificationKind::Reviews,
+                        notification_service::build_purchase_review_message(reward, &requested_by),
+                    )
+                    .await;
+                }
+
+                Ok(HttpResponse::Accepted().json(ApiSuccess::new(PurchaseRewardResponse::PendingApproval(purchase))))
+            }
+            Err(e) => {
+                log::error!("Error requesting reward purchase: {:?}", e);
+                Ok(HttpResponse::BadRequest().json(ApiError {
+                    error: "purchase_error".to_string(),
+                    message: e.to_string(),
+                }))
+            }
+        };
+    }
+
+    match reward_service::purchase_reward(&state.db, &reward_id, &ctx.user_id, &ctx.household_id).await {
         Ok(user_reward) => {
             // Log activity
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
-                Some(&user_id),
+                &ctx.household_id,
+                &ctx.user_id,
+                Some(&ctx.user_id),
                 ActivityType::RewardPurchased,
                 Some("reward"),
                 Some(&reward_id),
                 details.as_deref(),
             ).await;
 
-            Ok(HttpResponse::Created().json(ApiSuccess::new(user_reward)))
+            crate::handlers::websocket::broadcast_leaderboard(&req, &state.db, &ctx.household_id).await;
+            crate::handlers::reward_wishlist::notify_affordable(&req, &state.db, &ctx.household_id).await;
+            crate::handlers::point_goals::broadcast_progress(&req, &state.db, &ctx.household_id).await;
+
+            Ok(HttpResponse::Created().json(ApiSuccess::new(PurchaseRewardResponse::Purchased(user_reward))))
         }
         Err(e) => {
             log::error!("Error purchasing reward: {:?}", e);
@@ -528,32 +421,26 @@ async fn purchase_reward(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/rewards/{reward_id}/assign/{user_id}",
+    tag = "rewards",
+    summary = "Assign reward",
+    params(("household_id" = Uuid, Path), ("reward_id" = Uuid, Path), ("user_id" = Uuid, Path)),
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<UserReward>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn assign_reward(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String, String)>,
 ) -> Result<HttpResponse> {
-    let current_user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, reward_id_str, target_user_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, reward_id_str, target_user_id_str) = path.into_inner();
 
     let reward_id = match Uuid::parse_str(&reward_id_str) {
         Ok(id) => id,
@@ -575,28 +462,7 @@ async fn assign_reward(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if rewards feature is enabled
-    if !settings.rewards_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Rewards are not enabled for this household".to_string(),
-        }));
-    }
-
-    let role = household_service::get_member_role(&state.db, &household_id, &current_user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ManageRewards).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to assign rewards".to_string(),
@@ -604,7 +470,7 @@ async fn assign_reward(
     }
 
     // Verify target user is a member
-    if !household_service::is_member(&state.db, &household_id, &target_user_id).await.unwrap_or(false) {
+    if !household_service::is_member(&state.db, &ctx.household_id, &target_user_id).await.unwrap_or(false) {
         return Ok(HttpResponse::BadRequest().json(ApiError {
             error: "invalid_user".to_string(),
             message: "Target user is not a member of this household".to_string(),
@@ -616,13 +482,13 @@ async fn assign_reward(
     let details = reward.as_ref()
         .map(|r| serde_json::json!({ "name": r.name }).to_string());
 
-    match reward_service::assign_reward(&state.db, &reward_id, &target_user_id, &household_id).await {
+    match reward_service::assign_reward(&state.db, &reward_id, &target_user_id, &ctx.household_id).await {
         Ok(user_reward) => {
             // Log activity
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &current_user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 Some(&target_user_id),
                 ActivityType::RewardAssigned,
                 Some("reward"),
@@ -642,32 +508,26 @@ async fn assign_reward(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/rewards/{reward_id}/unassign/{user_id}",
+    tag = "rewards",
+    summary = "Unassign reward",
+    params(("household_id" = Uuid, Path), ("reward_id" = Uuid, Path), ("user_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Null>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn unassign_reward(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String, String)>,
 ) -> Result<HttpResponse> {
-    let current_user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, reward_id_str, target_user_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, reward_id_str, target_user_id_str) = path.into_inner();
 
     let reward_id = match Uuid::parse_str(&reward_id_str) {
         Ok(id) => id,
@@ -689,35 +549,14 @@ async fn unassign_reward(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if rewards feature is enabled
-    if !settings.rewards_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Rewards are not enabled for this household".to_string(),
-        }));
-    }
-
-    let role = household_service::get_member_role(&state.db, &household_id, &current_user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ManageRewards).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to unassign rewards".to_string(),
         }));
     }
 
-    match reward_service::unassign_reward(&state.db, &reward_id, &target_user_id, &household_id).await {
+    match reward_service::unassign_reward(&state.db, &reward_id, &target_user_id, &ctx.household_id).await {
         Ok(_) => Ok(HttpResponse::Ok().json(ApiSuccess::new(()))),
         Err(e) => {
             log::error!("Error unassigning reward: {:?}", e);
@@ -729,61 +568,28 @@ async fn unassign_reward(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/rewards/user-rewards",
+    tag = "rewards",
+    summary = "List user rewards",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<UserReward>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn list_user_rewards(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    // Check if rewards feature is enabled
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
+    match reward_service::list_user_rewards(&state.db, &ctx.user_id, &ctx.household_id).await {
+        Ok(rewards) => Ok(HttpResponse::Ok().json(ApiSuccess::new(rewards))),
         Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-    if !settings.rewards_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Rewards are not enabled for this household".to_string(),
-        }));
-    }
-
-    match reward_service::list_user_rewards(&state.db, &user_id, &household_id).await {
-        Ok(rewards) => Ok(HttpResponse::Ok().json(ApiSuccess::new(rewards))),
-        Err(e) => {
-            log::error!("Error listing user rewards: {:?}", e);
-            Ok(HttpResponse::InternalServerError().json(ApiError {
+            log::error!("Error listing user rewards: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
                 error: "internal_error".to_string(),
                 message: "Failed to list user rewards".to_string(),
             }))
@@ -791,57 +597,24 @@ async fn list_user_rewards(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/rewards/user-rewards/all",
+    tag = "rewards",
+    summary = "List all user rewards",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<UserRewardWithUser>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn list_all_user_rewards(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    // Check if rewards feature is enabled
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-    if !settings.rewards_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Rewards are not enabled for this household".to_string(),
-        }));
-    }
-
-    match reward_service::list_all_user_rewards_in_household(&state.db, &household_id).await {
+    match reward_service::list_all_user_rewards_in_household(&state.db, &ctx.household_id).await {
         Ok(rewards) => Ok(HttpResponse::Ok().json(ApiSuccess::new(rewards))),
         Err(e) => {
             log::error!("Error listing all user rewards: {:?}", e);
@@ -853,32 +626,20 @@ async fn list_all_user_rewards(
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/rewards/user-rewards/{id}",
+    tag = "rewards",
+    summary = "Delete user reward",
+    params(("household_id" = Uuid, Path), ("id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
 async fn delete_user_reward(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, user_reward_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, user_reward_id_str) = path.into_inner();
 
     let user_reward_id = match Uuid::parse_str(&user_reward_id_str) {
         Ok(id) => id,
@@ -890,29 +651,8 @@ async fn delete_user_reward(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if rewards feature is enabled
-    if !settings.rewards_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Rewards are not enabled for this household".to_string(),
-        }));
-    }
-
     // Only users with manage permission can delete user rewards
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ManageRewards).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to remove reward assignments".to_string(),
@@ -931,32 +671,26 @@ async fn delete_user_reward(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/rewards/user-rewards/{id}/redeem",
+    tag = "rewards",
+    summary = "Redeem reward",
+    params(("household_id" = Uuid, Path), ("id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<UserReward>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn redeem_reward(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, user_reward_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, user_reward_id_str) = path.into_inner();
 
     let user_reward_id = match Uuid::parse_str(&user_reward_id_str) {
         Ok(id) => id,
@@ -968,32 +702,7 @@ async fn redeem_reward(
         }
     };
 
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    // Check if rewards feature is enabled
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-    if !settings.rewards_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Rewards are not enabled for this household".to_string(),
-        }));
-    }
-
-    match reward_service::redeem_reward(&state.db, &user_reward_id, &user_id).await {
+    match reward_service::redeem_reward(&state.db, &user_reward_id, &ctx.user_id).await {
         Ok((user_reward, requires_confirmation)) => {
             // Get reward details for logging
             let reward = reward_service::get_reward(&state.db, &user_reward.reward_id).await.ok().flatten();
@@ -1004,9 +713,9 @@ async fn redeem_reward(
             if !requires_confirmation {
                 let _ = activity_logs::log_activity(
                     &state.db,
-                    &household_id,
-                    &user_id,
-                    Some(&user_id),
+                    &ctx.household_id,
+                    &ctx.user_id,
+                    Some(&ctx.user_id),
                     ActivityType::RewardRedeemed,
                     Some("reward"),
                     Some(&user_reward.reward_id),
@@ -1026,60 +735,125 @@ async fn redeem_reward(
     }
 }
 
-async fn list_pending_redemptions(
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/rewards/user-rewards/{id}/gift/{user_id}",
+    tag = "rewards",
+    summary = "Gift reward",
+    params(("household_id" = Uuid, Path), ("id" = Uuid, Path), ("user_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Option<UserReward>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn gift_reward(
     state: web::Data<AppState>,
+    ctx: HouseholdContext,
     req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    path: web::Path<(String, String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
+    let (_, user_reward_id_str, recipient_id_str) = path.into_inner();
+
+    let user_reward_id = match Uuid::parse_str(&user_reward_id_str) {
         Ok(id) => id,
         Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_id".to_string(),
+                message: "Invalid user reward ID format".to_string(),
             }));
         }
     };
 
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
+    let recipient_id = match Uuid::parse_str(&recipient_id_str) {
         Ok(id) => id,
         Err(_) => {
             return Ok(HttpResponse::BadRequest().json(ApiError {
                 error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
+                message: "Invalid user ID format".to_string(),
             }));
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
+    if !household_service::is_member(&state.db, &ctx.household_id, &recipient_id).await.unwrap_or(false) {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_recipient".to_string(),
+            message: "The recipient is not a member of this household".to_string(),
+        }));
+    }
+
+    match reward_service::gift_reward(&state.db, &ctx.household_id, &user_reward_id, &ctx.user_id, &recipient_id).await {
+        Ok((remaining, received)) => {
+            let reward = reward_service::get_reward(&state.db, &received.reward_id).await.ok().flatten();
+            let reward_name = reward.map(|r| r.name).unwrap_or_default();
+            let details = serde_json::json!({ "name": reward_name }).to_string();
+
+            let _ = activity_logs::log_activity(
+                &state.db,
+                &ctx.household_id,
+                &ctx.user_id,
+                Some(&recipient_id),
+                ActivityType::RewardGifted,
+                Some("reward"),
+                Some(&received.reward_id),
+                Some(&details),
+            ).await;
+
+            if let Some(ws_manager) = req.app_data::<web::Data<Arc<WsManager>>>() {
+                ws_manager
+                    .broadcast_reward_gifted(&ctx.household_id, received.reward_id, reward_name, ctx.user_id, recipient_id)
+                    .await;
+            }
+
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(remaining)))
+        }
+        Err(reward_service::RewardError::UserRewardNotFound) => Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "User reward not found".to_string(),
+        })),
+        Err(e @ (reward_service::RewardError::NothingToGift | reward_service::RewardError::CannotGiftToSelf)) => {
+            Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "gift_error".to_string(),
+                message: e.to_string(),
+            }))
+        }
         Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
+            log::error!("Error gifting reward: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
                 error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
+                message: "Failed to gift reward".to_string(),
+            }))
         }
-    };
-
-    // Check if rewards feature is enabled
-    if !settings.rewards_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Rewards are not enabled for this household".to_string(),
-        }));
     }
+}
 
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/rewards/pending-confirmations",
+    tag = "rewards",
+    summary = "List pending redemptions",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<PendingRewardRedemption>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_pending_redemptions(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
+    if !ctx.has_permission(&state, Permission::ReviewCompletions).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to view pending confirmations".to_string(),
         }));
     }
 
-    match reward_service::list_pending_redemptions(&state.db, &household_id).await {
+    match reward_service::list_pending_redemptions(&state.db, &ctx.household_id).await {
         Ok(pending) => Ok(HttpResponse::Ok().json(ApiSuccess::new(pending))),
         Err(e) => {
             log::error!("Error listing pending redemptions: {:?}", e);
@@ -1091,32 +865,27 @@ async fn list_pending_redemptions(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/rewards/user-rewards/{id}/approve",
+    tag = "rewards",
+    summary = "Approve redemption",
+    params(("household_id" = Uuid, Path), ("id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<UserReward>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn approve_redemption(
     state: web::Data<AppState>,
+    ctx: HouseholdContext,
     req: actix_web::HttpRequest,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, user_reward_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, user_reward_id_str) = path.into_inner();
 
     let user_reward_id = match Uuid::parse_str(&user_reward_id_str) {
         Ok(id) => id,
@@ -1128,28 +897,7 @@ async fn approve_redemption(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if rewards feature is enabled
-    if !settings.rewards_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Rewards are not enabled for this household".to_string(),
-        }));
-    }
-
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ReviewCompletions).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to approve redemptions".to_string(),
@@ -1166,8 +914,8 @@ async fn approve_redemption(
             // Log activity
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 Some(&user_reward.user_id),
                 ActivityType::RewardRedemptionApproved,
                 Some("reward"),
@@ -1175,6 +923,21 @@ async fn approve_redemption(
                 details.as_deref(),
             ).await;
 
+            if let Some(ref reward) = reward {
+                if user_reward.user_id != ctx.user_id {
+                    crate::handlers::notification_center::notify(
+                        &req,
+                        &state.db,
+                        user_reward.user_id,
+                        ctx.household_id,
+                        NotificationType::RewardConfirmed,
+                        &reward.name,
+                        Some(("reward", reward.id)),
+                    )
+                    .await;
+                }
+            }
+
             Ok(HttpResponse::Ok().json(ApiSuccess::new(user_reward)))
         }
         Err(e) => {
@@ -1187,32 +950,26 @@ async fn approve_redemption(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/rewards/user-rewards/{id}/reject",
+    tag = "rewards",
+    summary = "Reject redemption",
+    params(("household_id" = Uuid, Path), ("id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<UserReward>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn reject_redemption(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, user_reward_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, user_reward_id_str) = path.into_inner();
 
     let user_reward_id = match Uuid::parse_str(&user_reward_id_str) {
         Ok(id) => id,
@@ -1224,28 +981,7 @@ async fn reject_redemption(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if rewards feature is enabled
-    if !settings.rewards_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Rewards are not enabled for this household".to_string(),
-        }));
-    }
-
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ReviewCompletions).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to reject redemptions".to_string(),
@@ -1262,8 +998,8 @@ async fn reject_redemption(
             // Log activity
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 Some(&user_reward.user_id),
                 ActivityType::RewardRedemptionRejected,
                 Some("reward"),
@@ -1287,33 +1023,238 @@ async fn reject_redemption(
 // Random Choice Reward Handlers
 // ============================================================================
 
-async fn get_reward_options(
+/// Shared check for the purchase review endpoints
+async fn authorize_purchase_review(state: &AppState, ctx: &HouseholdContext) -> std::result::Result<(), HttpResponse> {
+    if !ctx.has_permission(state, Permission::ReviewCompletions).await {
+        return Err(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "You do not have permission to review reward purchases".to_string(),
+        }));
+    }
+
+    Ok(())
+}
+
+fn purchase_error_response(error: reward_service::RewardError, context: &str) -> HttpResponse {
+    match error {
+        reward_service::RewardError::PurchaseNotFound => HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: error.to_string(),
+        }),
+        reward_service::RewardError::PurchaseNotReviewable => HttpResponse::Conflict().json(ApiError {
+            error: "already_reviewed".to_string(),
+            message: error.to_string(),
+        }),
+        reward_service::RewardError::InsufficientPoints | reward_service::RewardError::NotPurchasable => {
+            HttpResponse::BadRequest().json(ApiError {
+                error: "purchase_error".to_string(),
+                message: error.to_string(),
+            })
+        }
+        e => {
+            log::error!("Error {}: {:?}", context, e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: format!("Failed {}", context),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/rewards/purchases/pending",
+    tag = "rewards",
+    summary = "List pending purchases",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<PendingRewardPurchase>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_pending_purchases(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
+    if let Err(response) = authorize_purchase_review(&state, &ctx).await {
+        return Ok(response);
+    }
+
+    match reward_service::list_pending_purchases(&state.db, &ctx.household_id).await {
+        Ok(pending) => Ok(HttpResponse::Ok().json(ApiSuccess::new(pending))),
+        Err(e) => Ok(purchase_error_response(e, "listing pending purchases")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/rewards/purchases/{id}/approve",
+    tag = "rewards",
+    summary = "Approve purchase",
+    params(("household_id" = Uuid, Path), ("id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<UserReward>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn approve_purchase(
     state: web::Data<AppState>,
+    ctx: HouseholdContext,
     req: actix_web::HttpRequest,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
+    let (_, purchase_id_str) = path.into_inner();
+    if let Err(response) = authorize_purchase_review(&state, &ctx).await {
+        return Ok(response);
+    }
+
+    let purchase_id = match Uuid::parse_str(&purchase_id_str) {
         Ok(id) => id,
         Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_id".to_string(),
+                message: "Invalid purchase ID format".to_string(),
             }));
         }
     };
 
-    let (household_id_str, reward_id_str) = path.into_inner();
+    match reward_service::approve_purchase(&state.db, &purchase_id, &ctx.household_id, &ctx.user_id).await {
+        Ok((purchase, user_reward)) => {
+            let reward = reward_service::get_reward(&state.db, &purchase.reward_id).await.ok().flatten();
+            let details = reward.as_ref()
+                .map(|r| serde_json::json!({ "name": r.name }).to_string());
+
+            let _ = activity_logs::log_activity(
+                &state.db,
+                &ctx.household_id,
+                &ctx.user_id,
+                Some(&purchase.user_id),
+                ActivityType::RewardPurchaseApproved,
+                Some("reward"),
+                Some(&purchase.reward_id),
+                details.as_deref(),
+            ).await;
+
+            if let Some(ref reward) = reward {
+                crate::handlers::notification_center::notify(
+                    &req,
+                    &state.db,
+                    purchase.user_id,
+                    ctx.household_id,
+                    NotificationType::PurchaseApproved,
+                    &reward.name,
+                    Some(("reward", reward.id)),
+                )
+                .await;
+            }
+
+            crate::handlers::websocket::broadcast_leaderboard(&req, &state.db, &ctx.household_id).await;
+            crate::handlers::reward_wishlist::notify_affordable(&req, &state.db, &ctx.household_id).await;
+            crate::handlers::point_goals::broadcast_progress(&req, &state.db, &ctx.household_id).await;
 
-    let household_id = match Uuid::parse_str(&household_id_str) {
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(user_reward)))
+        }
+        Err(e) => Ok(purchase_error_response(e, "approving purchase")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/rewards/purchases/{id}/reject",
+    tag = "rewards",
+    summary = "Reject purchase",
+    params(("household_id" = Uuid, Path), ("id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<RewardPurchase>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn reject_purchase(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    req: actix_web::HttpRequest,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, purchase_id_str) = path.into_inner();
+    if let Err(response) = authorize_purchase_review(&state, &ctx).await {
+        return Ok(response);
+    }
+
+    let purchase_id = match Uuid::parse_str(&purchase_id_str) {
         Ok(id) => id,
         Err(_) => {
             return Ok(HttpResponse::BadRequest().json(ApiError {
                 error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
+                message: "Invalid purchase ID format".to_string(),
             }));
         }
     };
 
+    match reward_service::reject_purchase(&state.db, &purchase_id, &ctx.household_id, &ctx.user_id).await {
+        Ok(purchase) => {
+            let reward = reward_service::get_reward(&state.db, &purchase.reward_id).await.ok().flatten();
+            let details = reward.as_ref()
+                .map(|r| serde_json::json!({ "name": r.name }).to_string());
+
+            let _ = activity_logs::log_activity(
+                &state.db,
+                &ctx.household_id,
+                &ctx.user_id,
+                Some(&purchase.user_id),
+                ActivityType::RewardPurchaseRejected,
+                Some("reward"),
+                Some(&purchase.reward_id),
+                details.as_deref(),
+            ).await;
+
+            if let Some(ref reward) = reward {
+                crate::handlers::notification_center::notify(
+                    &req,
+                    &state.db,
+                    purchase.user_id,
+                    ctx.household_id,
+                    NotificationType::PurchaseRejected,
+                    &reward.name,
+                    Some(("reward", reward.id)),
+                )
+                .await;
+            }
+
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(purchase)))
+        }
+        Err(e) => Ok(purchase_error_response(e, "rejecting purchase")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/rewards/{reward_id}/options",
+    tag = "rewards",
+    summary = "Get reward options",
+    params(("household_id" = Uuid, Path), ("reward_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<WeightedRewardOption>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn get_reward_options(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, reward_id_str) = path.into_inner();
+
     let reward_id = match Uuid::parse_str(&reward_id_str) {
         Ok(id) => id,
         Err(_) => {
@@ -1324,29 +1265,24 @@ async fn get_reward_options(
         }
     };
 
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    // Check if rewards feature is enabled
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
+    let owned = match reward_service::get_reward(&state.db, &reward_id).await {
+        Ok(Some(reward)) => ctx.check_owns(&reward.household_id, "Reward"),
+        Ok(None) => {
+            return Ok(HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: "Reward not found".to_string(),
+            }));
+        }
         Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
+            log::error!("Error fetching reward: {:?}", e);
             return Ok(HttpResponse::InternalServerError().json(ApiError {
                 error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
+                message: "Failed to fetch reward".to_string(),
             }));
         }
     };
-    if !settings.rewards_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Rewards are not enabled for this household".to_string(),
-        }));
+    if let Err(response) = owned {
+        return Ok(response);
     }
 
     match reward_service::get_reward_options(&state.db, &reward_id).await {
@@ -1361,32 +1297,26 @@ async fn get_reward_options(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/rewards/{reward_id}/options/{option_id}",
+    tag = "rewards",
+    summary = "Add reward option",
+    params(("household_id" = Uuid, Path), ("reward_id" = Uuid, Path), ("option_id" = Uuid, Path)),
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<RewardOption>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn add_reward_option(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, reward_id_str, option_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, reward_id_str, option_id_str) = path.into_inner();
 
     let reward_id = match Uuid::parse_str(&reward_id_str) {
         Ok(id) => id,
@@ -1408,27 +1338,7 @@ async fn add_reward_option(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    if !settings.rewards_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Rewards are not enabled for this household".to_string(),
-        }));
-    }
-
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ManageRewards).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to manage reward options".to_string(),
@@ -1447,32 +1357,20 @@ async fn add_reward_option(
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/rewards/{reward_id}/options/{option_id}",
+    tag = "rewards",
+    summary = "Remove reward option",
+    params(("household_id" = Uuid, Path), ("reward_id" = Uuid, Path), ("option_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
 async fn remove_reward_option(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, reward_id_str, option_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, reward_id_str, option_id_str) = path.into_inner();
 
     let reward_id = match Uuid::parse_str(&reward_id_str) {
         Ok(id) => id,
@@ -1494,27 +1392,7 @@ async fn remove_reward_option(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    if !settings.rewards_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Rewards are not enabled for this household".to_string(),
-        }));
-    }
-
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ManageRewards).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to manage reward options".to_string(),
@@ -1533,77 +1411,106 @@ async fn remove_reward_option(
     }
 }
 
-async fn pick_random_reward(
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/rewards/{reward_id}/options/{option_id}",
+    tag = "rewards",
+    summary = "Update reward option weight",
+    params(("household_id" = Uuid, Path), ("reward_id" = Uuid, Path), ("option_id" = Uuid, Path)),
+    request_body = UpdateOptionWeightRequest,
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn update_reward_option_weight(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<(String, String)>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String, String)>,
+    body: web::Json<UpdateOptionWeightRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
+    let (_, reward_id_str, option_id_str) = path.into_inner();
 
-    let (household_id_str, user_reward_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
+    let reward_id = match Uuid::parse_str(&reward_id_str) {
         Ok(id) => id,
         Err(_) => {
             return Ok(HttpResponse::BadRequest().json(ApiError {
                 error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
+                message: "Invalid reward ID format".to_string(),
             }));
         }
     };
 
-    let user_reward_id = match Uuid::parse_str(&user_reward_id_str) {
+    let option_id = match Uuid::parse_str(&option_id_str) {
         Ok(id) => id,
         Err(_) => {
             return Ok(HttpResponse::BadRequest().json(ApiError {
                 error: "invalid_id".to_string(),
-                message: "Invalid user reward ID format".to_string(),
+                message: "Invalid option ID format".to_string(),
             }));
         }
     };
 
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
+    if !ctx.has_permission(&state, Permission::ManageRewards).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
+            message: "You do not have permission to manage reward options".to_string(),
         }));
     }
 
-    // Check if rewards feature is enabled
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
+    match reward_service::set_reward_option_weight(&state.db, &ctx.household_id, &reward_id, &option_id, body.weight).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(reward_service::RewardError::OptionNotFound) => Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Option not found".to_string(),
+        })),
         Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
+            log::error!("Error updating reward option weight: {:?}", e);
+            Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "option_error".to_string(),
+                message: e.to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/rewards/user-rewards/{id}/pick",
+    tag = "rewards",
+    summary = "Pick random reward",
+    params(("household_id" = Uuid, Path), ("id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<RandomRewardPickResult>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn pick_random_reward(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, user_reward_id_str) = path.into_inner();
+
+    let user_reward_id = match Uuid::parse_str(&user_reward_id_str) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_id".to_string(),
+                message: "Invalid user reward ID format".to_string(),
             }));
         }
     };
-    if !settings.rewards_enabled {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "feature_disabled".to_string(),
-            message: "Rewards are not enabled for this household".to_string(),
-        }));
-    }
 
-    match reward_service::pick_random_reward(&state.db, &user_reward_id, &user_id).await {
+    match reward_service::pick_random_reward(&state.db, &user_reward_id, &ctx.user_id).await {
         Ok(result) => {
             // Log activity
             let details = serde_json::json!({ "picked_reward": result.picked_reward.name }).to_string();
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
-                Some(&user_id),
+                &ctx.household_id,
+                &ctx.user_id,
+                Some(&ctx.user_id),
                 ActivityType::RewardRandomPicked,
                 Some("reward"),
                 Some(&result.picked_reward.id),
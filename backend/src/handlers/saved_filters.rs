@@ -0,0 +1,118 @@
+use actix_web::{web, HttpResponse, Result};
+use shared::{ApiError, ApiSuccess, CreateSavedTaskFilterRequest, SavedTaskFilter};
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::task_filters as filter_service;
+
+/// The caller's own saved task filters ("smart lists") in the household
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/saved-filters")
+            .route("", web::get().to(list_saved_filters))
+            .route("", web::post().to(create_saved_filter))
+            .route("/{filter_id}", web::delete().to(delete_saved_filter)),
+    );
+}
+
+fn filter_error_response(e: filter_service::SavedFilterError, action: &str) -> HttpResponse {
+    match e {
+        filter_service::SavedFilterError::NotFound => HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Saved filter not found".to_string(),
+        }),
+        filter_service::SavedFilterError::EmptyName => HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "Filter name must not be empty".to_string(),
+        }),
+        filter_service::SavedFilterError::InvalidDueWindow => HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: format!("Due window must be between 0 and {} days", shared::MAX_DUE_WITHIN_DAYS),
+        }),
+        filter_service::SavedFilterError::DuplicateName => HttpResponse::Conflict().json(ApiError {
+            error: "duplicate_name".to_string(),
+            message: "A saved filter with this name already exists".to_string(),
+        }),
+        e => {
+            log::error!("Error trying to {} saved filter: {:?}", action, e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: format!("Failed to {} saved filter", action),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/saved-filters",
+    tag = "saved_filters",
+    summary = "List saved filters",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<SavedTaskFilter>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_saved_filters(state: web::Data<AppState>, ctx: HouseholdContext) -> Result<HttpResponse> {
+    match filter_service::list_saved_filters(&state.db, &ctx.household_id, &ctx.user_id).await {
+        Ok(filters) => Ok(HttpResponse::Ok().json(ApiSuccess::new(filters))),
+        Err(e) => Ok(filter_error_response(e, "list")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/saved-filters",
+    tag = "saved_filters",
+    summary = "Create saved filter",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreateSavedTaskFilterRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<SavedTaskFilter>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn create_saved_filter(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    body: web::Json<CreateSavedTaskFilterRequest>,
+) -> Result<HttpResponse> {
+    match filter_service::create_saved_filter(&state.db, &ctx.household_id, &ctx.user_id, &body).await {
+        Ok(filter) => Ok(HttpResponse::Created().json(ApiSuccess::new(filter))),
+        Err(e) => Ok(filter_error_response(e, "create")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/saved-filters/{filter_id}",
+    tag = "saved_filters",
+    summary = "Delete saved filter",
+    params(("household_id" = Uuid, Path), ("filter_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn delete_saved_filter(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, filter_id) = path.into_inner();
+    let Ok(filter_id) = Uuid::parse_str(&filter_id) else {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_id".to_string(),
+            message: "Invalid filter ID format".to_string(),
+        }));
+    };
+
+    match filter_service::delete_saved_filter(&state.db, &ctx.household_id, &ctx.user_id, &filter_id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(filter_error_response(e, "delete")),
+    }
+}
@@ -0,0 +1,83 @@
+//! Poll-friendly household endpoints for wall displays and home automation.
+//! Meant to be used with a read-only personal API token, e.g. from a Home Assistant REST sensor.
+
+use actix_web::{web, HttpResponse, Result};
+use shared::{ApiError, ApiSuccess, HouseholdSensor, SensorTask};
+
+use crate::handlers::openapi::{BadRequest, Forbidden, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::{sensors as sensor_service};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/sensor")
+            .route("", web::get().to(get_household_sensor))
+            .route("/tasks", web::get().to(get_due_tasks)),
+    );
+}
+
+fn sensor_error_response(error: sensor_service::SensorError) -> HttpResponse {
+    match error {
+        sensor_service::SensorError::NotFound => HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: error.to_string(),
+        }),
+        e => {
+            log::error!("Error building sensor data: {:?}", e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to build sensor data".to_string(),
+            })
+        }
+    }
+}
+
+/// Due and open task counts for the household and each member
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/sensor",
+    tag = "sensors",
+    summary = "Due and open task counts for the household and each member",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<HouseholdSensor>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn get_household_sensor(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
+    match sensor_service::household_sensor(&state.db, &ctx.household_id).await {
+        Ok(sensor) => Ok(HttpResponse::Ok().json(ApiSuccess::new(sensor))),
+        Err(e) => Ok(sensor_error_response(e)),
+    }
+}
+
+/// Tasks due today with their progress, open tasks first
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/sensor/tasks",
+    operation_id = "sensors_get_due_tasks",
+    tag = "sensors",
+    summary = "Tasks due today with their progress, open tasks first",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<SensorTask>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn get_due_tasks(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
+    match sensor_service::due_tasks(&state.db, &ctx.household_id).await {
+        Ok(tasks) => Ok(HttpResponse::Ok().json(ApiSuccess::new(tasks))),
+        Err(e) => Ok(sensor_error_response(e)),
+    }
+}
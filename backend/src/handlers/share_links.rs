@@ -0,0 +1,218 @@
+use actix_web::{web, HttpResponse, Result};
+use shared::{
+    ApiError, ApiSuccess, CreateShareLinkRequest, HouseholdFeature, Permission, ShareLink,
+    ShareResource, SharedContent, SharedPage,
+};
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::middleware::share_link::ShareContext;
+use crate::models::AppState;
+use crate::services::{
+    household_settings as settings_service, households as household_service, meals as meal_service, scheduler,
+    share_links as share_link_service, shopping_list as shopping_list_service,
+};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/share-links")
+            .route("", web::get().to(list_links))
+            .route("", web::post().to(create_link))
+            .route("/{link_id}", web::delete().to(revoke_link)),
+    );
+}
+
+/// Routes for guests; they authenticate with the share token alone
+pub fn configure_public(cfg: &mut web::ServiceConfig) {
+    cfg.route("/shared/{token}", web::get().to(get_shared_page));
+}
+
+/// Share links hand household data to outsiders, so they are managed like invitations
+async fn require_manage_members(state: &AppState, ctx: &HouseholdContext) -> Result<(), HttpResponse> {
+    if ctx.has_permission(state, Permission::ManageMembers).await {
+        return Ok(());
+    }
+    Err(HttpResponse::Forbidden().json(ApiError {
+        error: "forbidden".to_string(),
+        message: "You don't have permission to manage share links".to_string(),
+    }))
+}
+
+fn internal_error(context: &str, e: impl std::fmt::Debug) -> HttpResponse {
+    log::error!("Error {}: {:?}", context, e);
+    HttpResponse::InternalServerError().json(ApiError {
+        error: "internal_error".to_string(),
+        message: format!("Failed {}", context),
+    })
+}
+
+fn share_link_error_response(error: share_link_service::ShareLinkError, context: &str) -> HttpResponse {
+    match error {
+        share_link_service::ShareLinkError::NotFound => HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: error.to_string(),
+        }),
+        share_link_service::ShareLinkError::InvalidExpiry => HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: error.to_string(),
+        }),
+        e => internal_error(context, e),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/share-links",
+    tag = "share_links",
+    summary = "List links",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<ShareLink>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_links(state: web::Data<AppState>, ctx: HouseholdContext) -> Result<HttpResponse> {
+    if let Err(response) = require_manage_members(&state, &ctx).await {
+        return Ok(response);
+    }
+
+    match share_link_service::list_links(&state.db, &ctx.household_id).await {
+        Ok(links) => Ok(HttpResponse::Ok().json(ApiSuccess::new(links))),
+        Err(e) => Ok(share_link_error_response(e, "listing share links")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/share-links",
+    tag = "share_links",
+    summary = "Create link",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreateShareLinkRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<ShareLink>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn create_link(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    body: web::Json<CreateShareLinkRequest>,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_manage_members(&state, &ctx).await {
+        return Ok(response);
+    }
+
+    match share_link_service::create_link(&state.db, &ctx.household_id, &ctx.user_id, &body.into_inner()).await {
+        Ok(link) => Ok(HttpResponse::Created().json(ApiSuccess::new(link))),
+        Err(e) => Ok(share_link_error_response(e, "creating share link")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/share-links/{link_id}",
+    tag = "share_links",
+    summary = "Revoke link",
+    params(("household_id" = Uuid, Path), ("link_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn revoke_link(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_manage_members(&state, &ctx).await {
+        return Ok(response);
+    }
+    let (_, link_id_str) = path.into_inner();
+    let Ok(link_id) = Uuid::parse_str(&link_id_str) else {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_id".to_string(),
+            message: "Invalid share link ID format".to_string(),
+        }));
+    };
+
+    match share_link_service::revoke_link(&state.db, &ctx.household_id, &link_id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(share_link_error_response(e, "revoking share link")),
+    }
+}
+
+/// The read-only page behind a share link
+#[utoipa::path(
+    get,
+    path = "/api/shared/{token}",
+    tag = "share_links",
+    summary = "The read-only page behind a share link",
+    params(("token" = String, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<SharedPage>),
+        BadRequest,
+        NotFound,
+    ),
+    security(()),
+)]
+async fn get_shared_page(state: web::Data<AppState>, share: ShareContext) -> Result<HttpResponse> {
+    let household_id = share.link.household_id;
+    let feature = match share.link.resource {
+        ShareResource::ShoppingList => HouseholdFeature::ShoppingList,
+        ShareResource::MealPlan => HouseholdFeature::Meals,
+    };
+    match settings_service::disabled_features(&state.db, &household_id).await {
+        Ok(disabled) if disabled.contains(&feature) => {
+            return Ok(HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: "This link is invalid or has expired".to_string(),
+            }))
+        }
+        Ok(_) => {}
+        Err(e) => return Ok(internal_error("loading household settings", e)),
+    }
+    let household_name = match household_service::get_household(&state.db, &household_id).await {
+        Ok(Some(household)) => household.name,
+        Ok(None) => {
+            return Ok(HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: "This link is invalid or has expired".to_string(),
+            }))
+        }
+        Err(e) => return Ok(internal_error("loading household", e)),
+    };
+
+    let content = match share.link.resource {
+        ShareResource::ShoppingList => match shopping_list_service::list_items(&state.db, &household_id).await {
+            Ok(items) => SharedContent::ShoppingList(items),
+            Err(e) => return Ok(internal_error("loading shopping list", e)),
+        },
+        ShareResource::MealPlan => {
+            // "This week" as seen by the household, not by the server
+            let today = match settings_service::get_or_create_settings(&state.db, &household_id).await {
+                Ok(settings) => scheduler::today_in_timezone(scheduler::parse_timezone(&settings.timezone)),
+                Err(e) => return Ok(internal_error("loading household settings", e)),
+            };
+            let mut plan = match meal_service::get_week_plan(&state.db, &household_id, today).await {
+                Ok(plan) => plan,
+                Err(e) => return Ok(internal_error("loading meal plan", e)),
+            };
+            let recipes = match meal_service::list_recipes(&state.db, &household_id).await {
+                Ok(recipes) => recipes,
+                Err(e) => return Ok(internal_error("loading recipes", e)),
+            };
+            // Guests can't look up recipes, so the recipe name goes into the title
+            for entry in &mut plan.entries {
+                if let Some(recipe) = entry.recipe_id.and_then(|id| recipes.iter().find(|r| r.id == id)) {
+                    entry.title = Some(recipe.name.clone());
+                }
+            }
+            SharedContent::MealPlan(plan)
+        }
+    };
+
+    Ok(HttpResponse::Ok().json(ApiSuccess::new(SharedPage { household_name, content })))
+}
@@ -0,0 +1,194 @@
+use actix_web::{web, HttpResponse, Result};
+use shared::{ApiError, ApiSuccess, CreateShoppingListItemRequest, ShoppingListItem, UpdateShoppingListItemRequest};
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::{shopping_list as shopping_list_service};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/shopping-list")
+            .route("", web::get().to(list_items))
+            .route("", web::post().to(add_item))
+            .route("/checked", web::delete().to(clear_checked))
+            .route("/{item_id}", web::put().to(update_item))
+            .route("/{item_id}", web::delete().to(delete_item)),
+    );
+}
+
+fn parse_item_id(id: &str) -> std::result::Result<Uuid, HttpResponse> {
+    Uuid::parse_str(id).map_err(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_id".to_string(),
+            message: "Invalid item ID format".to_string(),
+        })
+    })
+}
+
+pub(crate) fn shopping_list_error_response(
+    error: shopping_list_service::ShoppingListError,
+    context: &str,
+) -> HttpResponse {
+    match error {
+        shopping_list_service::ShoppingListError::NotFound => HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Shopping list item not found".to_string(),
+        }),
+        shopping_list_service::ShoppingListError::RecipeNotFound => HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Recipe not found".to_string(),
+        }),
+        shopping_list_service::ShoppingListError::DatabaseError(e) => {
+            log::error!("Error {}: {:?}", context, e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: format!("Failed {}", context),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/shopping-list",
+    operation_id = "shopping_list_list_items",
+    tag = "shopping_list",
+    summary = "List items",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<ShoppingListItem>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_items(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
+    match shopping_list_service::list_items(&state.db, &ctx.household_id).await {
+        Ok(items) => Ok(HttpResponse::Ok().json(ApiSuccess::new(items))),
+        Err(e) => Ok(shopping_list_error_response(e, "listing shopping list")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/shopping-list",
+    tag = "shopping_list",
+    summary = "Add item",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreateShoppingListItemRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<ShoppingListItem>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn add_item(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    body: web::Json<CreateShoppingListItemRequest>,
+) -> Result<HttpResponse> {
+    let request = body.into_inner();
+    if request.name.trim().is_empty() {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "Item name is required".to_string(),
+        }));
+    }
+
+    match shopping_list_service::add_item(&state.db, &ctx.household_id, &ctx.user_id, &request).await {
+        Ok(item) => Ok(HttpResponse::Ok().json(ApiSuccess::new(item))),
+        Err(e) => Ok(shopping_list_error_response(e, "adding shopping list item")),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/shopping-list/{item_id}",
+    operation_id = "shopping_list_update_item",
+    tag = "shopping_list",
+    summary = "Update item",
+    params(("household_id" = Uuid, Path), ("item_id" = Uuid, Path)),
+    request_body = UpdateShoppingListItemRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<ShoppingListItem>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn update_item(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+    body: web::Json<UpdateShoppingListItemRequest>,
+) -> Result<HttpResponse> {
+    let (_, item_id_str) = path.into_inner();
+        let item_id = match parse_item_id(&item_id_str) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    let request = body.into_inner();
+    if request.name.as_ref().is_some_and(|n| n.trim().is_empty()) {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "Item name is required".to_string(),
+        }));
+    }
+
+    match shopping_list_service::update_item(&state.db, &ctx.household_id, &item_id, &request).await {
+        Ok(item) => Ok(HttpResponse::Ok().json(ApiSuccess::new(item))),
+        Err(e) => Ok(shopping_list_error_response(e, "updating shopping list item")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/shopping-list/{item_id}",
+    operation_id = "shopping_list_delete_item",
+    tag = "shopping_list",
+    summary = "Delete item",
+    params(("household_id" = Uuid, Path), ("item_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn delete_item(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, item_id_str) = path.into_inner();
+        let item_id = match parse_item_id(&item_id_str) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match shopping_list_service::delete_item(&state.db, &ctx.household_id, &item_id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(shopping_list_error_response(e, "deleting shopping list item")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/shopping-list/checked",
+    tag = "shopping_list",
+    summary = "Clear checked",
+    params(("household_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden),
+)]
+async fn clear_checked(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
+    match shopping_list_service::clear_checked(&state.db, &ctx.household_id).await {
+        Ok(_) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(shopping_list_error_response(e, "clearing checked items")),
+    }
+}
@@ -1,13 +1,14 @@
 use actix_web::{web, HttpResponse, Result};
 use chrono::NaiveDate;
-use shared::ApiError;
-use uuid::Uuid;
+use shared::{
+    ApiError, ApiSuccess, HouseholdReport, MonthlyStatisticsResponse, WeeklyStatisticsResponse,
+    WorkloadReport,
+};
 
+use crate::handlers::openapi::{BadRequest, Forbidden, Unauthorized};
+use crate::middleware::household::HouseholdContext;
 use crate::models::AppState;
-use crate::services::{
-    household_settings as settings_service, households as household_service,
-    statistics as statistics_service,
-};
+use crate::services::statistics as statistics_service;
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -17,49 +18,60 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route("/weekly/available", web::get().to(list_available_weeks))
             .route("/monthly", web::get().to(get_monthly_statistics))
             .route("/monthly/calculate", web::post().to(calculate_monthly_statistics))
-            .route("/monthly/available", web::get().to(list_available_months)),
+            .route("/monthly/available", web::get().to(list_available_months))
+            .route("/report", web::get().to(get_household_report))
+            .route("/workload", web::get().to(get_workload_report)),
     );
 }
 
-#[derive(Debug, serde::Deserialize)]
+#[derive(Debug, serde::Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct WeeklyStatsQuery {
     pub week_start: Option<String>,
 }
 
-#[derive(Debug, serde::Deserialize)]
+#[derive(Debug, serde::Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct MonthlyStatsQuery {
     pub month: Option<String>,
 }
 
+#[derive(Debug, serde::Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ReportQuery {
+    /// First day of the range (YYYY-MM-DD); defaults to the start of the current month
+    pub start: Option<String>,
+    /// Last day of the range (YYYY-MM-DD); defaults to today
+    pub end: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct WorkloadQuery {
+    /// First day of the range (YYYY-MM-DD); defaults to the start of the current month
+    pub start: Option<String>,
+    /// Last day of the range (YYYY-MM-DD); defaults to the end of the current month
+    pub end: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/statistics/weekly",
+    tag = "statistics",
+    summary = "Get weekly statistics",
+    params(("household_id" = Uuid, Path), WeeklyStatsQuery),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<WeeklyStatisticsResponse>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn get_weekly_statistics(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<Uuid>,
+    ctx: HouseholdContext,
     query: web::Query<WeeklyStatsQuery>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = path.into_inner();
-
-    // Verify membership
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
-    {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "Not a member of this household".to_string(),
-        }));
-    }
-
     // Parse week_start from query or use current week
     let week_start = match &query.week_start {
         Some(date_str) => match NaiveDate::parse_from_str(date_str, "%Y-%m-%d") {
@@ -72,19 +84,13 @@ async fn get_weekly_statistics(
             }
         },
         None => {
-            // Get current week start based on household settings
-            let settings = settings_service::get_or_create_settings(&state.db, &household_id)
-                .await
-                .map_err(|e| {
-                    log::error!("Error getting settings: {:?}", e);
-                    actix_web::error::ErrorInternalServerError("Failed to get settings")
-                })?;
+            // Current week start based on household settings
             let today = chrono::Local::now().date_naive();
-            statistics_service::get_week_start(today, settings.week_start_day)
+            statistics_service::get_week_start(today, ctx.settings.week_start_day)
         }
     };
 
-    match statistics_service::get_weekly_statistics(&state.db, &household_id, week_start).await {
+    match statistics_service::get_weekly_statistics(&state.db, &ctx.household_id, week_start).await {
         Ok(stats) => Ok(HttpResponse::Ok().json(shared::ApiSuccess::new(stats))),
         Err(e) => {
             log::error!("Error getting weekly statistics: {:?}", e);
@@ -96,33 +102,24 @@ async fn get_weekly_statistics(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/statistics/weekly/calculate",
+    tag = "statistics",
+    summary = "Calculate weekly statistics",
+    params(("household_id" = Uuid, Path), WeeklyStatsQuery),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<WeeklyStatisticsResponse>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn calculate_weekly_statistics(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<Uuid>,
+    ctx: HouseholdContext,
     query: web::Query<WeeklyStatsQuery>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = path.into_inner();
-
-    // Verify membership and role (at least admin to trigger calculation)
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if role.is_none() {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "Not a member of this household".to_string(),
-        }));
-    }
-
     // Parse week_start from query or use current week
     let week_start = match &query.week_start {
         Some(date_str) => match NaiveDate::parse_from_str(date_str, "%Y-%m-%d") {
@@ -135,23 +132,17 @@ async fn calculate_weekly_statistics(
             }
         },
         None => {
-            let settings = settings_service::get_or_create_settings(&state.db, &household_id)
-                .await
-                .map_err(|e| {
-                    log::error!("Error getting settings: {:?}", e);
-                    actix_web::error::ErrorInternalServerError("Failed to get settings")
-                })?;
             let today = chrono::Local::now().date_naive();
-            statistics_service::get_week_start(today, settings.week_start_day)
+            statistics_service::get_week_start(today, ctx.settings.week_start_day)
         }
     };
 
-    match statistics_service::calculate_weekly_statistics(&state.db, &household_id, week_start)
+    match statistics_service::calculate_weekly_statistics(&state.db, &ctx.household_id, week_start)
         .await
     {
         Ok(()) => {
             // Return the newly calculated statistics
-            match statistics_service::get_weekly_statistics(&state.db, &household_id, week_start)
+            match statistics_service::get_weekly_statistics(&state.db, &ctx.household_id, week_start)
                 .await
             {
                 Ok(stats) => Ok(HttpResponse::Ok().json(shared::ApiSuccess::new(stats))),
@@ -174,34 +165,24 @@ async fn calculate_weekly_statistics(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/statistics/weekly/available",
+    tag = "statistics",
+    summary = "List available weeks",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<NaiveDate>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn list_available_weeks(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<Uuid>,
+    ctx: HouseholdContext,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = path.into_inner();
-
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
-    {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "Not a member of this household".to_string(),
-        }));
-    }
-
-    match statistics_service::list_available_weeks(&state.db, &household_id).await {
+    match statistics_service::list_available_weeks(&state.db, &ctx.household_id).await {
         Ok(weeks) => Ok(HttpResponse::Ok().json(shared::ApiSuccess::new(weeks))),
         Err(e) => {
             log::error!("Error listing available weeks: {:?}", e);
@@ -213,34 +194,24 @@ async fn list_available_weeks(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/statistics/monthly",
+    tag = "statistics",
+    summary = "Get monthly statistics",
+    params(("household_id" = Uuid, Path), MonthlyStatsQuery),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<MonthlyStatisticsResponse>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn get_monthly_statistics(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<Uuid>,
+    ctx: HouseholdContext,
     query: web::Query<MonthlyStatsQuery>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = path.into_inner();
-
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
-    {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "Not a member of this household".to_string(),
-        }));
-    }
-
     // Parse month from query or use current month
     let month = match &query.month {
         Some(date_str) => match NaiveDate::parse_from_str(date_str, "%Y-%m-%d") {
@@ -255,7 +226,7 @@ async fn get_monthly_statistics(
         None => chrono::Local::now().date_naive(),
     };
 
-    match statistics_service::get_monthly_statistics(&state.db, &household_id, month).await {
+    match statistics_service::get_monthly_statistics(&state.db, &ctx.household_id, month).await {
         Ok(stats) => Ok(HttpResponse::Ok().json(shared::ApiSuccess::new(stats))),
         Err(e) => {
             log::error!("Error getting monthly statistics: {:?}", e);
@@ -267,32 +238,24 @@ async fn get_monthly_statistics(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/statistics/monthly/calculate",
+    tag = "statistics",
+    summary = "Calculate monthly statistics",
+    params(("household_id" = Uuid, Path), MonthlyStatsQuery),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<MonthlyStatisticsResponse>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn calculate_monthly_statistics(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<Uuid>,
+    ctx: HouseholdContext,
     query: web::Query<MonthlyStatsQuery>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = path.into_inner();
-
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if role.is_none() {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "Not a member of this household".to_string(),
-        }));
-    }
-
     let month = match &query.month {
         Some(date_str) => match NaiveDate::parse_from_str(date_str, "%Y-%m-%d") {
             Ok(date) => date,
@@ -306,9 +269,9 @@ async fn calculate_monthly_statistics(
         None => chrono::Local::now().date_naive(),
     };
 
-    match statistics_service::calculate_monthly_statistics(&state.db, &household_id, month).await {
+    match statistics_service::calculate_monthly_statistics(&state.db, &ctx.household_id, month).await {
         Ok(()) => {
-            match statistics_service::get_monthly_statistics(&state.db, &household_id, month).await
+            match statistics_service::get_monthly_statistics(&state.db, &ctx.household_id, month).await
             {
                 Ok(stats) => Ok(HttpResponse::Ok().json(shared::ApiSuccess::new(stats))),
                 Err(e) => {
@@ -330,40 +293,172 @@ async fn calculate_monthly_statistics(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/statistics/monthly/available",
+    tag = "statistics",
+    summary = "List available months",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<NaiveDate>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn list_available_months(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<Uuid>,
+    ctx: HouseholdContext,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
+    match statistics_service::list_available_months(&state.db, &ctx.household_id).await {
+        Ok(months) => Ok(HttpResponse::Ok().json(shared::ApiSuccess::new(months))),
+        Err(e) => {
+            log::error!("Error listing available months: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to list available months".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/statistics/report",
+    tag = "statistics",
+    summary = "Get household report",
+    params(("household_id" = Uuid, Path), ReportQuery),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<HouseholdReport>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn get_household_report(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    query: web::Query<ReportQuery>,
+) -> Result<HttpResponse> {
+    let timezone = crate::services::scheduler::parse_timezone(&ctx.settings.timezone);
+    let today = crate::services::scheduler::today_in_timezone(timezone);
+
+    let parse_date = |value: &Option<String>, default: NaiveDate| match value {
+        Some(date_str) => NaiveDate::parse_from_str(date_str, "%Y-%m-%d").ok(),
+        None => Some(default),
+    };
+    let (start, end) = match (
+        parse_date(&query.start, statistics_service::get_month_start(today)),
+        parse_date(&query.end, today),
+    ) {
+        (Some(start), Some(end)) => (start, end),
+        _ => {
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_date".to_string(),
+                message: "Invalid date format. Use YYYY-MM-DD".to_string(),
             }));
         }
     };
 
-    let household_id = path.into_inner();
+    if !shared::is_supported_date(start) || !shared::is_supported_date(end) {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_date".to_string(),
+            message: format!(
+                "Dates must lie between the years {} and {}",
+                shared::SUPPORTED_YEARS.start(),
+                shared::SUPPORTED_YEARS.end()
+            ),
+        }));
+    }
 
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
-    {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "Not a member of this household".to_string(),
+    if end < start || (end - start).num_days() >= statistics_service::MAX_REPORT_DAYS {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_range".to_string(),
+            message: format!(
+                "The range must start before it ends and span at most {} days",
+                statistics_service::MAX_REPORT_DAYS
+            ),
         }));
     }
 
-    match statistics_service::list_available_months(&state.db, &household_id).await {
-        Ok(months) => Ok(HttpResponse::Ok().json(shared::ApiSuccess::new(months))),
+    match statistics_service::get_household_report(&state.db, &ctx.household_id, start, end, timezone).await {
+        Ok(report) => Ok(HttpResponse::Ok().json(shared::ApiSuccess::new(report))),
         Err(e) => {
-            log::error!("Error listing available months: {:?}", e);
+            log::error!("Error building household report: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
                 error: "internal_error".to_string(),
-                message: "Failed to list available months".to_string(),
+                message: "Failed to build report".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/statistics/workload",
+    tag = "statistics",
+    summary = "Get workload report",
+    params(("household_id" = Uuid, Path), WorkloadQuery),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<WorkloadReport>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn get_workload_report(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    query: web::Query<WorkloadQuery>,
+) -> Result<HttpResponse> {
+    let timezone = crate::services::scheduler::parse_timezone(&ctx.settings.timezone);
+    let today = crate::services::scheduler::today_in_timezone(timezone);
+
+    let parse_date = |value: &Option<String>, default: NaiveDate| match value {
+        Some(date_str) => NaiveDate::parse_from_str(date_str, "%Y-%m-%d").ok(),
+        None => Some(default),
+    };
+    let (start, end) = match (
+        parse_date(&query.start, statistics_service::get_month_start(today)),
+        parse_date(&query.end, statistics_service::get_month_end(today)),
+    ) {
+        (Some(start), Some(end)) => (start, end),
+        _ => {
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_date".to_string(),
+                message: "Invalid date format. Use YYYY-MM-DD".to_string(),
+            }));
+        }
+    };
+
+    if !shared::is_supported_date(start) || !shared::is_supported_date(end) {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_date".to_string(),
+            message: format!(
+                "Dates must lie between the years {} and {}",
+                shared::SUPPORTED_YEARS.start(),
+                shared::SUPPORTED_YEARS.end()
+            ),
+        }));
+    }
+
+    if end < start || (end - start).num_days() >= statistics_service::MAX_REPORT_DAYS {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_range".to_string(),
+            message: format!(
+                "The range must start before it ends and span at most {} days",
+                statistics_service::MAX_REPORT_DAYS
+            ),
+        }));
+    }
+
+    match statistics_service::get_workload_report(&state.db, &ctx.household_id, start, end).await {
+        Ok(report) => Ok(HttpResponse::Ok().json(shared::ApiSuccess::new(report))),
+        Err(e) => {
+            log::error!("Error building workload report: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to build workload report".to_string(),
             }))
         }
     }
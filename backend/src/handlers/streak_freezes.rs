@@ -0,0 +1,104 @@
+use actix_web::{web, HttpRequest, HttpResponse, Result};
+use shared::{ApiError, ApiSuccess, StreakFreezeStatus};
+
+use crate::handlers::openapi::{BadRequest, Forbidden, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::{household_settings as settings_service, points as points_service};
+
+/// Streak freezes members buy with points; using one is done per task period
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/streak-freezes")
+            .route("", web::get().to(get_streak_freezes))
+            .route("/purchase", web::post().to(purchase_streak_freeze)),
+    );
+}
+
+async fn streak_freeze_price(state: &AppState, ctx: &HouseholdContext) -> Result<i64, HttpResponse> {
+    settings_service::get_or_create_settings(&state.db, &ctx.household_id)
+        .await
+        .map(|settings| settings.streak_freeze_price)
+        .map_err(|e| {
+            log::error!("Error fetching settings: {:?}", e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch household settings".to_string(),
+            })
+        })
+}
+
+fn internal_error(e: points_service::PointsError, message: &str) -> HttpResponse {
+    log::error!("{}: {:?}", message, e);
+    HttpResponse::InternalServerError().json(ApiError {
+        error: "internal_error".to_string(),
+        message: message.to_string(),
+    })
+}
+
+/// The current member's unused streak freezes and their price
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/streak-freezes",
+    tag = "streak_freezes",
+    summary = "The current member's unused streak freezes and their price",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<StreakFreezeStatus>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn get_streak_freezes(state: web::Data<AppState>, ctx: HouseholdContext) -> Result<HttpResponse> {
+    let price = match streak_freeze_price(&state, &ctx).await {
+        Ok(price) => price,
+        Err(response) => return Ok(response),
+    };
+
+    match points_service::available_streak_freezes(&state.db, &ctx.household_id, &ctx.user_id).await {
+        Ok(available) => Ok(HttpResponse::Ok().json(ApiSuccess::new(StreakFreezeStatus { available, price }))),
+        Err(e) => Ok(internal_error(e, "Failed to load streak freezes")),
+    }
+}
+
+/// Buy a streak freeze at the household's price
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/streak-freezes/purchase",
+    tag = "streak_freezes",
+    summary = "Buy a streak freeze at the household's price",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<StreakFreezeStatus>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn purchase_streak_freeze(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
+    let price = match streak_freeze_price(&state, &ctx).await {
+        Ok(price) => price,
+        Err(response) => return Ok(response),
+    };
+
+    match points_service::purchase_streak_freeze(&state.db, &ctx.household_id, &ctx.user_id, price).await {
+        Ok(available) => {
+            crate::handlers::websocket::broadcast_leaderboard(&req, &state.db, &ctx.household_id).await;
+            crate::handlers::reward_wishlist::notify_affordable(&req, &state.db, &ctx.household_id).await;
+            crate::handlers::point_goals::broadcast_progress(&req, &state.db, &ctx.household_id).await;
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(StreakFreezeStatus { available, price })))
+        }
+        Err(e @ (points_service::PointsError::StreakFreezesDisabled | points_service::PointsError::InsufficientPoints)) => {
+            Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "purchase_error".to_string(),
+                message: e.to_string(),
+            }))
+        }
+        Err(e) => Ok(internal_error(e, "Failed to purchase streak freeze")),
+    }
+}
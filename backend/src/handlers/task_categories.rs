@@ -1,9 +1,14 @@
 use actix_web::{web, HttpResponse, Result};
-use shared::{ApiError, ApiSuccess, CreateTaskCategoryRequest, TaskCategoriesResponse, UpdateTaskCategoryRequest};
+use shared::{
+    ApiError, ApiSuccess, CreateTaskCategoryRequest, Permission, TaskCategoriesResponse,
+    TaskCategory, UpdateTaskCategoryRequest,
+};
 use uuid::Uuid;
 
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
 use crate::models::AppState;
-use crate::services::{households as household_service, task_categories as category_service};
+use crate::services::task_categories as category_service;
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -16,42 +21,24 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
     );
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/categories",
+    tag = "task_categories",
+    summary = "List categories",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<TaskCategoriesResponse>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn list_categories(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
-    {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    match category_service::list_categories(&state.db, &household_id).await {
+    match category_service::list_categories(&state.db, &ctx.household_id).await {
         Ok(categories) => {
             Ok(HttpResponse::Ok().json(ApiSuccess::new(TaskCategoriesResponse { categories })))
         }
@@ -65,51 +52,33 @@ async fn list_categories(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/categories",
+    tag = "task_categories",
+    summary = "Create category",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreateTaskCategoryRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<TaskCategory>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn create_category(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
     body: web::Json<CreateTaskCategoryRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    // Check membership and admin role
-    let role = match household_service::get_member_role(&state.db, &household_id, &user_id).await {
-        Some(r) => r,
-        None => {
-            return Ok(HttpResponse::Forbidden().json(ApiError {
-                error: "forbidden".to_string(),
-                message: "You are not a member of this household".to_string(),
-            }));
-        }
-    };
-
-    if !role.can_manage_tasks() {
+    if !ctx.has_permission(&state, Permission::ManageTasks).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You don't have permission to manage categories".to_string(),
         }));
     }
 
-    match category_service::create_category(&state.db, &household_id, &body).await {
+    match category_service::create_category(&state.db, &ctx.household_id, &body).await {
         Ok(category) => Ok(HttpResponse::Created().json(ApiSuccess::new(category))),
         Err(category_service::TaskCategoryError::DuplicateName) => {
             Ok(HttpResponse::Conflict().json(ApiError {
@@ -127,31 +96,26 @@ async fn create_category(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/categories/{category_id}",
+    tag = "task_categories",
+    summary = "Get category",
+    params(("household_id" = Uuid, Path), ("category_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<TaskCategory>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn get_category(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, category_id_str) = path.into_inner();
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, category_id_str) = path.into_inner();
     let category_id = match Uuid::parse_str(&category_id_str) {
         Ok(id) => id,
         Err(_) => {
@@ -162,18 +126,11 @@ async fn get_category(
         }
     };
 
-    if !household_service::is_member(&state.db, &household_id, &user_id)
-        .await
-        .unwrap_or(false)
-    {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
     match category_service::get_category(&state.db, &category_id).await {
-        Ok(Some(category)) => Ok(HttpResponse::Ok().json(ApiSuccess::new(category))),
+        Ok(Some(category)) => match ctx.check_owns(&category.household_id, "Category") {
+            Ok(()) => Ok(HttpResponse::Ok().json(ApiSuccess::new(category))),
+            Err(response) => Ok(response),
+        },
         Ok(None) => Ok(HttpResponse::NotFound().json(ApiError {
             error: "not_found".to_string(),
             message: "Category not found".to_string(),
@@ -188,32 +145,28 @@ async fn get_category(
     }
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/categories/{category_id}",
+    tag = "task_categories",
+    summary = "Update category",
+    params(("household_id" = Uuid, Path), ("category_id" = Uuid, Path)),
+    request_body = UpdateTaskCategoryRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<TaskCategory>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn update_category(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
     body: web::Json<UpdateTaskCategoryRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, category_id_str) = path.into_inner();
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, category_id_str) = path.into_inner();
     let category_id = match Uuid::parse_str(&category_id_str) {
         Ok(id) => id,
         Err(_) => {
@@ -224,17 +177,7 @@ async fn update_category(
         }
     };
 
-    let role = match household_service::get_member_role(&state.db, &household_id, &user_id).await {
-        Some(r) => r,
-        None => {
-            return Ok(HttpResponse::Forbidden().json(ApiError {
-                error: "forbidden".to_string(),
-                message: "You are not a member of this household".to_string(),
-            }));
-        }
-    };
-
-    if !role.can_manage_tasks() {
+    if !ctx.has_permission(&state, Permission::ManageTasks).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You don't have permission to manage categories".to_string(),
@@ -265,31 +208,20 @@ async fn update_category(
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/categories/{category_id}",
+    tag = "task_categories",
+    summary = "Delete category",
+    params(("household_id" = Uuid, Path), ("category_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
 async fn delete_category(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, category_id_str) = path.into_inner();
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, category_id_str) = path.into_inner();
     let category_id = match Uuid::parse_str(&category_id_str) {
         Ok(id) => id,
         Err(_) => {
@@ -300,17 +232,7 @@ async fn delete_category(
         }
     };
 
-    let role = match household_service::get_member_role(&state.db, &household_id, &user_id).await {
-        Some(r) => r,
-        None => {
-            return Ok(HttpResponse::Forbidden().json(ApiError {
-                error: "forbidden".to_string(),
-                message: "You are not a member of this household".to_string(),
-            }));
-        }
-    };
-
-    if !role.can_manage_tasks() {
+    if !ctx.has_permission(&state, Permission::ManageTasks).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You don't have permission to manage categories".to_string(),
@@ -0,0 +1,212 @@
+use actix_web::{web, HttpResponse, Result};
+use shared::{ApiError, ApiSuccess, CreateTaskCommentRequest, Permission, TaskComment, TaskCommentWithUser};
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::{task_comments as comment_service, tasks as task_service};
+
+/// Registers the comment routes. Must be configured before `tasks::configure`,
+/// which owns the `/tasks` scope.
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/tasks/{task_id}/comments", web::get().to(list_comments))
+        .route("/tasks/{task_id}/comments", web::post().to(create_comment))
+        .route("/tasks/{task_id}/comments/{comment_id}", web::delete().to(delete_comment));
+}
+
+/// Check that the task exists and belongs to the household
+async fn task_in_household(state: &AppState, household_id: &Uuid, task_id: &Uuid) -> bool {
+    matches!(
+        task_service::get_task(&state.db, task_id).await,
+        Ok(Some(task)) if task.household_id == *household_id
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/tasks/{task_id}/comments",
+    tag = "task_comments",
+    summary = "List comments",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<TaskCommentWithUser>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn list_comments(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, task_id_str) = path.into_inner();
+
+    let task_id = match Uuid::parse_str(&task_id_str) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_id".to_string(),
+                message: "Invalid task ID format".to_string(),
+            }));
+        }
+    };
+
+    if !task_in_household(&state, &ctx.household_id, &task_id).await {
+        return Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Task not found".to_string(),
+        }));
+    }
+
+    match comment_service::list_comments(&state.db, &task_id).await {
+        Ok(comments) => Ok(HttpResponse::Ok().json(ApiSuccess::new(comments))),
+        Err(e) => {
+            log::error!("Error listing task comments: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to list comments".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/{task_id}/comments",
+    tag = "task_comments",
+    summary = "Create comment",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    request_body = CreateTaskCommentRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<TaskComment>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn create_comment(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+    body: web::Json<CreateTaskCommentRequest>,
+) -> Result<HttpResponse> {
+    let (_, task_id_str) = path.into_inner();
+
+    let task_id = match Uuid::parse_str(&task_id_str) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_id".to_string(),
+                message: "Invalid task ID format".to_string(),
+            }));
+        }
+    };
+
+    if !task_in_household(&state, &ctx.household_id, &task_id).await {
+        return Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Task not found".to_string(),
+        }));
+    }
+
+    let request = body.into_inner();
+    if request.content.trim().is_empty() {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "Comment cannot be empty".to_string(),
+        }));
+    }
+
+    match comment_service::create_comment(&state.db, &ctx.household_id, &task_id, &ctx.user_id, &request).await {
+        Ok(comment) => Ok(HttpResponse::Created().json(ApiSuccess::new(comment))),
+        Err(e) => {
+            log::error!("Error creating task comment: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to create comment".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/tasks/{task_id}/comments/{comment_id}",
+    tag = "task_comments",
+    summary = "Delete comment",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path), ("comment_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn delete_comment(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String, String)>,
+) -> Result<HttpResponse> {
+    let (_, task_id_str, comment_id_str) = path.into_inner();
+
+    let task_id = match Uuid::parse_str(&task_id_str) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_id".to_string(),
+                message: "Invalid task ID format".to_string(),
+            }));
+        }
+    };
+
+    let comment_id = match Uuid::parse_str(&comment_id_str) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_id".to_string(),
+                message: "Invalid comment ID format".to_string(),
+            }));
+        }
+    };
+
+    // Make sure the comment belongs to this task
+    match comment_service::get_comment(&state.db, &comment_id).await {
+        Ok(Some(comment)) if comment.task_id == task_id && comment.household_id == ctx.household_id => {}
+        Ok(_) => {
+            return Ok(HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: "Comment not found".to_string(),
+            }));
+        }
+        Err(e) => {
+            log::error!("Error fetching task comment: {:?}", e);
+            return Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch comment".to_string(),
+            }));
+        }
+    }
+
+    // Task managers may moderate other members' comments
+    let can_manage = ctx.has_permission(&state, Permission::ManageTasks).await;
+
+    match comment_service::delete_comment(&state.db, &comment_id, &ctx.user_id, can_manage).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(comment_service::TaskCommentError::NotFound) => Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Comment not found".to_string(),
+        })),
+        Err(comment_service::TaskCommentError::PermissionDenied) => {
+            Ok(HttpResponse::Forbidden().json(ApiError {
+                error: "forbidden".to_string(),
+                message: "You can only delete your own comments".to_string(),
+            }))
+        }
+        Err(e) => {
+            log::error!("Error deleting task comment: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to delete comment".to_string(),
+            }))
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use actix_web::{web, HttpResponse, Result};
+use shared::{AddTaskExclusionRequest, ApiError, ApiSuccess, MemberTaskExclusion, Permission};
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::task_exclusions as exclusion_service;
+
+/// Tasks a member must not be given; honored by assignment, claims and rebalancing
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/members/{user_id}/exclusions")
+            .route("", web::get().to(list_exclusions))
+            .route("", web::post().to(add_exclusion))
+            .route("/{task_id}", web::delete().to(remove_exclusion)),
+    );
+}
+
+fn exclusion_error_response(e: exclusion_service::TaskExclusionError, action: &str) -> HttpResponse {
+    match e {
+        exclusion_service::TaskExclusionError::TaskNotFound => HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Task not found".to_string(),
+        }),
+        exclusion_service::TaskExclusionError::NotMember => HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_user".to_string(),
+            message: "Target user is not a member of this household".to_string(),
+        }),
+        e => {
+            log::error!("Error trying to {} task exclusion: {:?}", action, e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: format!("Failed to {} task exclusion", action),
+            })
+        }
+    }
+}
+
+fn parse_id(id: &str, what: &str) -> Result<Uuid, HttpResponse> {
+    Uuid::parse_str(id).map_err(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_id".to_string(),
+            message: format!("Invalid {} ID format", what),
+        })
+    })
+}
+
+/// Only member managers decide what someone may not do, members can't exclude themselves
+async fn check_can_manage(state: &AppState, ctx: &HouseholdContext) -> Result<(), HttpResponse> {
+    if ctx.has_permission(state, Permission::ManageMembers).await {
+        return Ok(());
+    }
+    Err(HttpResponse::Forbidden().json(ApiError {
+        error: "forbidden".to_string(),
+        message: "You do not have permission to manage task exclusions".to_string(),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/members/{user_id}/exclusions",
+    tag = "task_exclusions",
+    summary = "List exclusions",
+    params(("household_id" = Uuid, Path), ("user_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<MemberTaskExclusion>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn list_exclusions(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, user_id) = path.into_inner();
+    let user_id = match parse_id(&user_id, "user") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match exclusion_service::list_member_exclusions(&state.db, &ctx.household_id, &user_id).await {
+        Ok(exclusions) => Ok(HttpResponse::Ok().json(ApiSuccess::new(exclusions))),
+        Err(e) => Ok(exclusion_error_response(e, "list")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/members/{user_id}/exclusions",
+    tag = "task_exclusions",
+    summary = "Add exclusion",
+    params(("household_id" = Uuid, Path), ("user_id" = Uuid, Path)),
+    request_body = AddTaskExclusionRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<MemberTaskExclusion>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn add_exclusion(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+    body: web::Json<AddTaskExclusionRequest>,
+) -> Result<HttpResponse> {
+    if let Err(response) = check_can_manage(&state, &ctx).await {
+        return Ok(response);
+    }
+    let (_, user_id) = path.into_inner();
+    let user_id = match parse_id(&user_id, "user") {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+
+    match exclusion_service::add_exclusion(&state.db, &ctx.household_id, &user_id, &body.task_id).await {
+        Ok(exclusion) => Ok(HttpResponse::Created().json(ApiSuccess::new(exclusion))),
+        Err(e) => Ok(exclusion_error_response(e, "add")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/members/{user_id}/exclusions/{task_id}",
+    tag = "task_exclusions",
+    summary = "Remove exclusion",
+    params(("household_id" = Uuid, Path), ("user_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn remove_exclusion(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String, String)>,
+) -> Result<HttpResponse> {
+    if let Err(response) = check_can_manage(&state, &ctx).await {
+        return Ok(response);
+    }
+    let (_, user_id, task_id) = path.into_inner();
+    let (user_id, task_id) = match (parse_id(&user_id, "user"), parse_id(&task_id, "task")) {
+        (Ok(user_id), Ok(task_id)) => (user_id, task_id),
+        (Err(response), _) | (_, Err(response)) => return Ok(response),
+    };
+
+    match exclusion_service::remove_exclusion(&state.db, &ctx.household_id, &user_id, &task_id).await {
+        Ok(true) => Ok(HttpResponse::NoContent().finish()),
+        Ok(false) => Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Task exclusion not found".to_string(),
+        })),
+        Err(e) => Ok(exclusion_error_response(e, "remove")),
+    }
+}
@@ -0,0 +1,220 @@
+use actix_web::{web, HttpResponse, Result};
+use shared::{ApiError, ApiSuccess, CreateTaskTagRequest, Permission, TaskTag, UpdateTaskTagRequest};
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::task_tags as tag_service;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/tags")
+            .route("", web::get().to(list_tags))
+            .route("", web::post().to(create_tag))
+            .route("/{tag_id}", web::get().to(get_tag))
+            .route("/{tag_id}", web::put().to(update_tag))
+            .route("/{tag_id}", web::delete().to(delete_tag)),
+    );
+}
+
+async fn require_manage_tasks(state: &AppState, ctx: &HouseholdContext) -> Result<(), HttpResponse> {
+    if ctx.has_permission(state, Permission::ManageTasks).await {
+        return Ok(());
+    }
+    Err(HttpResponse::Forbidden().json(ApiError {
+        error: "forbidden".to_string(),
+        message: "You don't have permission to manage tags".to_string(),
+    }))
+}
+
+/// Parse the tag ID and load the tag; tags of other households are not found
+async fn load_tag(state: &AppState, ctx: &HouseholdContext, tag_id: &str) -> Result<TaskTag, HttpResponse> {
+    let tag_id = Uuid::parse_str(tag_id).map_err(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_id".to_string(),
+            message: "Invalid tag ID format".to_string(),
+        })
+    })?;
+
+    match tag_service::get_tag(&state.db, &tag_id).await {
+        Ok(Some(tag)) => {
+            ctx.check_owns(&tag.household_id, "Tag")?;
+            Ok(tag)
+        }
+        Ok(None) => Err(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Tag not found".to_string(),
+        })),
+        Err(e) => {
+            log::error!("Error getting tag: {:?}", e);
+            Err(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to get tag".to_string(),
+            }))
+        }
+    }
+}
+
+fn tag_error_response(e: tag_service::TaskTagError, action: &str) -> HttpResponse {
+    match e {
+        tag_service::TaskTagError::NotFound => HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Tag not found".to_string(),
+        }),
+        tag_service::TaskTagError::EmptyName => HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "Tag name must not be empty".to_string(),
+        }),
+        tag_service::TaskTagError::DuplicateName => HttpResponse::Conflict().json(ApiError {
+            error: "duplicate_name".to_string(),
+            message: "A tag with this name already exists".to_string(),
+        }),
+        e => {
+            log::error!("Error trying to {} tag: {:?}", action, e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: format!("Failed to {} tag", action),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/tags",
+    tag = "task_tags",
+    summary = "List tags",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<TaskTag>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_tags(state: web::Data<AppState>, ctx: HouseholdContext) -> Result<HttpResponse> {
+    match tag_service::list_tags(&state.db, &ctx.household_id).await {
+        Ok(tags) => Ok(HttpResponse::Ok().json(ApiSuccess::new(tags))),
+        Err(e) => Ok(tag_error_response(e, "list")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tags",
+    tag = "task_tags",
+    summary = "Create tag",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreateTaskTagRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<TaskTag>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn create_tag(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    body: web::Json<CreateTaskTagRequest>,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_manage_tasks(&state, &ctx).await {
+        return Ok(response);
+    }
+
+    match tag_service::create_tag(&state.db, &ctx.household_id, &body).await {
+        Ok(tag) => Ok(HttpResponse::Created().json(ApiSuccess::new(tag))),
+        Err(e) => Ok(tag_error_response(e, "create")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/tags/{tag_id}",
+    tag = "task_tags",
+    summary = "Get tag",
+    params(("household_id" = Uuid, Path), ("tag_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<TaskTag>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn get_tag(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, tag_id) = path.into_inner();
+    match load_tag(&state, &ctx, &tag_id).await {
+        Ok(tag) => Ok(HttpResponse::Ok().json(ApiSuccess::new(tag))),
+        Err(response) => Ok(response),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/tags/{tag_id}",
+    tag = "task_tags",
+    summary = "Update tag",
+    params(("household_id" = Uuid, Path), ("tag_id" = Uuid, Path)),
+    request_body = UpdateTaskTagRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<TaskTag>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn update_tag(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+    body: web::Json<UpdateTaskTagRequest>,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_manage_tasks(&state, &ctx).await {
+        return Ok(response);
+    }
+    let (_, tag_id) = path.into_inner();
+    let tag = match load_tag(&state, &ctx, &tag_id).await {
+        Ok(tag) => tag,
+        Err(response) => return Ok(response),
+    };
+
+    match tag_service::update_tag(&state.db, &tag.id, &body).await {
+        Ok(tag) => Ok(HttpResponse::Ok().json(ApiSuccess::new(tag))),
+        Err(e) => Ok(tag_error_response(e, "update")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/tags/{tag_id}",
+    tag = "task_tags",
+    summary = "Delete tag",
+    params(("household_id" = Uuid, Path), ("tag_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn delete_tag(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_manage_tasks(&state, &ctx).await {
+        return Ok(response);
+    }
+    let (_, tag_id) = path.into_inner();
+    let tag = match load_tag(&state, &ctx, &tag_id).await {
+        Ok(tag) => tag,
+        Err(response) => return Ok(response),
+    };
+
+    match tag_service::delete_tag(&state.db, &tag.id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(tag_error_response(e, "delete")),
+    }
+}
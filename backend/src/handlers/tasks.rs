@@ -1,22 +1,50 @@
 use actix_web::{web, HttpResponse, Result};
+use futures::StreamExt;
 use serde::Deserialize;
 use shared::{
-    ActivityType, ApiError, ApiSuccess, CreateTaskRequest, HierarchyType,
-    RecurrenceType, RecurrenceValue, Task, UpdateTaskRequest,
+    ActivityType, ApiError, ApiSuccess, ApplyAssignmentPlanRequest, ApplyRebalanceRequest,
+    AssignmentPlan, AuditAction, CompletionAttachment, CompletionStatus, CreateTaskRequest,
+    HierarchyType, NotificationKind, NotificationType, OverridePeriodRequest, Paginated,
+    PaginationQuery, PendingReview, Permission, PostponeTaskRequest, RandomizeAssignmentsRequest,
+    RebalanceRequest, RebalanceSuggestion, RecurrenceType, RecurrenceValue, ReviewerType,
+    SkipTaskPeriodRequest, Task, TaskClaim, TaskCompletion, TaskFilter, TaskPeriodResult,
+    TaskPunishmentLink, TaskRewardLink, TaskWithDetails, TaskWithStatus, UpdateTaskRequest,
+    VersionConflict,
 };
 use uuid::Uuid;
 
+use crate::handlers::openapi::{BadRequest, Conflict, FileUpload, Forbidden, NoContent, NotFound, Null, Unauthorized};
+use crate::handlers::{notification_center, notifications};
+use crate::middleware::household::HouseholdContext;
 use crate::models::AppState;
 use crate::services::{
     activity_logs,
+    attachments as attachment_service,
+    audit_log,
+    auth as auth_service,
+    chat_bridge,
     household_settings,
     households as household_service,
-    solo_mode,
+    notifications as notification_service,
+    period_results,
+    permissions,
+    scheduler,
+    statistics,
+    task_claims,
     task_consequences,
+    task_exclusions,
+    task_dependencies,
+    task_filters,
+    task_rebalance,
+    task_tags,
     tasks as task_service,
 };
 
-#[derive(Debug, Deserialize)]
+/// Largest page of tasks a single request may ask for; without a limit all tasks are returned
+const MAX_TASK_PAGE: i64 = 500;
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
 struct AddLinkQuery {
     #[serde(default = "default_amount")]
     amount: i32,
@@ -26,6 +54,263 @@ fn default_amount() -> i32 {
     1
 }
 
+/// Filter parameters of the task lists, see `shared::TaskFilter::to_query_params`
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+struct TaskFilterQuery {
+    assignee: Option<String>,
+    tags: Option<String>,
+    category: Option<String>,
+    due_within: Option<i64>,
+    habit_type: Option<String>,
+}
+
+impl TaskFilterQuery {
+    fn filter(&self) -> Result<TaskFilter, HttpResponse> {
+        let invalid = |message: String| {
+            HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_filter".to_string(),
+                message,
+            })
+        };
+        let parse_id = |value: &Option<String>, what: &str| {
+            value
+                .as_deref()
+                .filter(|id| !id.is_empty())
+                .map(|id| Uuid::parse_str(id).map_err(|_| invalid(format!("Invalid {} ID: {}", what, id))))
+                .transpose()
+        };
+
+        let filter = TaskFilter {
+            assignee_id: parse_id(&self.assignee, "assignee")?,
+            tag_ids: task_tags::parse_tag_filter(self.tags.as_deref()).map_err(invalid)?,
+            category_id: parse_id(&self.category, "category")?,
+            due_within_days: self.due_within,
+            habit_type: self
+                .habit_type
+                .as_deref()
+                .filter(|value| !value.is_empty())
+                .map(|value| value.parse().map_err(|_| invalid(format!("Invalid habit type: {}", value))))
+                .transpose()?,
+        };
+        if !filter.has_valid_due_window() {
+            return Err(invalid(format!("Due window must be between 0 and {} days", shared::MAX_DUE_WITHIN_DAYS)));
+        }
+        Ok(filter)
+    }
+}
+
+/// Rejects effort estimates outside `1..=MAX_EFFORT_MINUTES`
+fn invalid_effort(effort_minutes: Option<i32>) -> Option<HttpResponse> {
+    effort_minutes
+        .filter(|minutes| !(1..=shared::MAX_EFFORT_MINUTES).contains(minutes))
+        .map(|_| {
+            HttpResponse::BadRequest().json(ApiError {
+                error: "validation_error".to_string(),
+                message: format!("Effort must be between 1 and {} minutes", shared::MAX_EFFORT_MINUTES),
+            })
+        })
+}
+
+/// Rejects intervals below 1 and out-of-range days of week or ordinals
+fn invalid_recurrence(recurrence_value: Option<&RecurrenceValue>) -> Option<HttpResponse> {
+    recurrence_value.filter(|value| !value.is_valid()).map(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "Invalid recurrence".to_string(),
+        })
+    })
+}
+
+/// Rejects season bounds that aren't "MM-DD" month-days
+fn invalid_season<'a>(bounds: impl IntoIterator<Item = Option<&'a str>>) -> Option<HttpResponse> {
+    bounds
+        .into_iter()
+        .flatten()
+        .find(|bound| shared::parse_month_day(bound).is_none())
+        .map(|bound| {
+            HttpResponse::BadRequest().json(ApiError {
+                error: "validation_error".to_string(),
+                message: format!("Invalid season date: {} (expected MM-DD)", bound),
+            })
+        })
+}
+
+/// The planning range from `start`, ending `default_days` later unless `end` is given.
+/// Rejects dates outside the supported years, reversed ranges and ranges longer
+/// than a statistics report.
+fn planning_range(
+    start: chrono::NaiveDate,
+    end: Option<chrono::NaiveDate>,
+    default_days: i64,
+) -> std::result::Result<(chrono::NaiveDate, chrono::NaiveDate), HttpResponse> {
+    let end = end.or_else(|| start.checked_add_days(chrono::Days::new(default_days.max(1).unsigned_abs() - 1)));
+    let Some(end) = end.filter(|end| shared::is_supported_date(start) && shared::is_supported_date(*end)) else {
+        return Err(HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_date".to_string(),
+            message: format!(
+                "Dates must lie between the years {} and {}",
+                shared::SUPPORTED_YEARS.start(),
+                shared::SUPPORTED_YEARS.end()
+            ),
+        }));
+    };
+    if end < start || (end - start).num_days() >= crate::services::statistics::MAX_REPORT_DAYS {
+        return Err(HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_range".to_string(),
+            message: format!(
+                "The range must start before it ends and span at most {} days",
+                crate::services::statistics::MAX_REPORT_DAYS
+            ),
+        }));
+    }
+    Ok((start, end))
+}
+
+/// Rejects a maximum number of occurrences below 1
+fn invalid_max_occurrences(max_occurrences: Option<i32>) -> Option<HttpResponse> {
+    max_occurrences.filter(|max| *max < 1).map(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "Maximum occurrences must be at least 1".to_string(),
+        })
+    })
+}
+
+/// The task's reviewer setting decides who may review a completion; without a
+/// completion of this household only the review permission counts
+async fn may_review(
+    state: &AppState,
+    completion: Option<&TaskCompletion>,
+    task: Option<&Task>,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    is_reviewer: bool,
+) -> bool {
+    match (completion, task) {
+        (Some(completion), Some(task)) if task.household_id == *household_id => {
+            let reviewer_is_member = match task.reviewer_id {
+                Some(reviewer_id) => household_service::get_member_role(&state.db, household_id, &reviewer_id).await.is_some(),
+                None => false,
+            };
+            task.can_review(user_id, &completion.user_id, is_reviewer, reviewer_is_member)
+        }
+        _ => is_reviewer,
+    }
+}
+
+/// Log and notify members who were newly assigned to `task` by `user_id`
+async fn notify_added_assignees(
+    req: &actix_web::HttpRequest,
+    state: &AppState,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    task: &Task,
+    added_assignees: &[Uuid],
+) {
+    let details = serde_json::json!({ "title": task.title }).to_string();
+    for assigned_id in added_assignees {
+        let _ = activity_logs::log_activity(
+            &state.db,
+            household_id,
+            user_id,
+            Some(assigned_id),
+            ActivityType::TaskAssigned,
+            Some("task"),
+            Some(&task.id),
+            Some(&details),
+        ).await;
+
+        if assigned_id != user_id {
+            notification_center::notify(
+                req,
+                &state.db,
+                *assigned_id,
+                *household_id,
+                NotificationType::TaskAssigned,
+                &task.title,
+                Some(("task", task.id)),
+            )
+            .await;
+            notifications::notify_member(
+                &state.db,
+                state.push.as_ref(),
+                household_id,
+                assigned_id,
+                NotificationKind::Assignments,
+                notification_service::build_assignment_message(task),
+            );
+        }
+    }
+}
+
+/// Members excluded from a task can't be assigned to it
+async fn check_exclusions(state: &AppState, task_id: &Uuid, assignees: &[Uuid]) -> std::result::Result<(), HttpResponse> {
+    match task_exclusions::excluded_members(&state.db, task_id, assignees).await {
+        Ok(excluded) if excluded.is_empty() => Ok(()),
+        Ok(_) => Err(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "A member is excluded from this task".to_string(),
+        })),
+        Err(e) => {
+            log::error!("Error checking task exclusions: {:?}", e);
+            Err(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to check task exclusions".to_string(),
+            }))
+        }
+    }
+}
+
+/// Assignees must be household members; in Hierarchy mode only Members can be assigned tasks
+async fn validate_assignees(
+    state: &AppState,
+    household_id: &Uuid,
+    settings: &shared::HouseholdSettings,
+    assignees: &[Uuid],
+) -> std::result::Result<(), HttpResponse> {
+    for assigned_id in assignees {
+        let Some(role) = household_service::get_member_role(&state.db, household_id, assigned_id).await else {
+            return Err(HttpResponse::BadRequest().json(ApiError {
+                error: "validation_error".to_string(),
+                message: "Tasks can only be assigned to household members".to_string(),
+            }));
+        };
+        if settings.hierarchy_type == HierarchyType::Hierarchy && !settings.hierarchy_type.can_be_assigned(&role) {
+            return Err(HttpResponse::BadRequest().json(ApiError {
+                error: "validation_error".to_string(),
+                message: "In Hierarchy mode, only Members can be assigned tasks".to_string(),
+            }));
+        }
+    }
+    Ok(())
+}
+
+/// A task reviewed by a specific member needs that member, and they must belong to the household
+async fn validate_reviewer(
+    state: &AppState,
+    household_id: &Uuid,
+    reviewer_type: ReviewerType,
+    reviewer_id: Option<Uuid>,
+) -> std::result::Result<(), HttpResponse> {
+    if reviewer_type != ReviewerType::Member {
+        return Ok(());
+    }
+    let Some(reviewer_id) = reviewer_id else {
+        return Err(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "Choose the member who reviews this task".to_string(),
+        }));
+    };
+    if household_service::get_member_role(&state.db, household_id, &reviewer_id).await.is_none() {
+        return Err(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "The reviewer must be a household member".to_string(),
+        }));
+    }
+    Ok(())
+}
+
 /// Check if this is a valid "Set Date" request in Solo Mode.
 /// Only allows setting a date on an unscheduled task, with no other field changes.
 fn is_solo_mode_set_date_request(request: &UpdateTaskRequest, task: &Task) -> bool {
@@ -50,6 +335,7 @@ fn is_solo_mode_set_date_request(request: &UpdateTaskRequest, task: &Task) -> bo
     let no_other_changes = request.title.is_none()
         && request.description.is_none()
         && request.assigned_user_id.is_none()
+        && request.assignee_ids.is_none()
         && request.target_count.is_none()
         && request.time_period.is_none()
         && request.allow_exceed_target.is_none()
@@ -59,6 +345,7 @@ fn is_solo_mode_set_date_request(request: &UpdateTaskRequest, task: &Task) -> bo
         && request.due_time.is_none()
         && request.habit_type.is_none()
         && request.category_id.is_none()
+        && request.tag_ids.is_none()
         && request.archived.is_none()
         && request.paused.is_none();
 
@@ -78,8 +365,15 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             // Review endpoints (must come before /{task_id} routes)
             .route("/completions/{completion_id}/approve", web::post().to(approve_completion))
             .route("/completions/{completion_id}/reject", web::post().to(reject_completion))
+            // Photo proof endpoints (must come before /{task_id} routes)
+            .route("/completions/{completion_id}/attachments", web::post().to(upload_completion_attachment))
+            .route("/attachments/{attachment_id}", web::get().to(get_completion_attachment))
             // Suggestion endpoints (must come before /{task_id} routes)
             .route("/suggestions", web::get().to(list_suggestions))
+            .route("/rebalance-suggestions", web::post().to(suggest_rebalance))
+            .route("/rebalance-suggestions/apply", web::post().to(apply_rebalance))
+            .route("/randomize-assignments", web::post().to(randomize_assignments))
+            .route("/randomize-assignments/apply", web::post().to(apply_assignment_plan))
             // Task CRUD (/{task_id} routes must come last as they're catch-all patterns)
             .route("/{task_id}", web::get().to(get_task))
             .route("/{task_id}", web::put().to(update_task))
@@ -91,6 +385,12 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route("/{task_id}/unarchive", web::post().to(unarchive_task))
             .route("/{task_id}/pause", web::post().to(pause_task))
             .route("/{task_id}/unpause", web::post().to(unpause_task))
+            .route("/{task_id}/skip", web::post().to(skip_task))
+            .route("/{task_id}/postpone", web::post().to(postpone_task))
+            .route("/{task_id}/periods/{date}/override", web::post().to(override_period))
+            .route("/{task_id}/periods/{date}/freeze", web::post().to(freeze_period))
+            .route("/{task_id}/claim", web::post().to(claim_task))
+            .route("/{task_id}/claim", web::delete().to(release_task))
             .route("/{task_id}/approve", web::post().to(approve_suggestion))
             .route("/{task_id}/deny", web::post().to(deny_suggestion))
             // Task rewards endpoints
@@ -101,44 +401,40 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route("/{task_id}/punishments", web::get().to(get_task_punishments))
             .route("/{task_id}/punishments/{punishment_id}", web::post().to(add_task_punishment))
             .route("/{task_id}/punishments/{punishment_id}", web::delete().to(remove_task_punishment))
+            // Task prerequisite endpoints
+            .route("/{task_id}/prerequisites", web::get().to(get_task_prerequisites))
+            .route("/{task_id}/prerequisites/{prerequisite_id}", web::post().to(add_task_prerequisite))
+            .route("/{task_id}/prerequisites/{prerequisite_id}", web::delete().to(remove_task_prerequisite))
     );
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/tasks",
+    tag = "tasks",
+    summary = "List tasks",
+    params(("household_id" = Uuid, Path), PaginationQuery),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Paginated<shared::Task>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn list_tasks(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
+    query: web::Query<PaginationQuery>,
+    filter_query: web::Query<TaskFilterQuery>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
+    let filter = match filter_query.filter() {
+        Ok(filter) => filter,
+        Err(response) => return Ok(response),
     };
 
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    match task_service::list_tasks(&state.db, &household_id).await {
-        Ok(tasks) => Ok(HttpResponse::Ok().json(ApiSuccess::new(tasks))),
+    let limit = query.capped_limit(MAX_TASK_PAGE);
+    match task_service::list_tasks_page(&state.db, &ctx.household_id, &filter, limit, query.offset_or_zero()).await {
+        Ok(page) => Ok(HttpResponse::Ok().json(ApiSuccess::new(page))),
         Err(e) => {
             log::error!("Error listing tasks: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
@@ -149,58 +445,39 @@ async fn list_tasks(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks",
+    tag = "tasks",
+    summary = "Create task",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreateTaskRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<shared::Task>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn create_task(
     state: web::Data<AppState>,
+    ctx: HouseholdContext,
     req: actix_web::HttpRequest,
-    path: web::Path<String>,
     body: web::Json<CreateTaskRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if user can manage tasks based on hierarchy type
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    let can_manage = role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false);
+    // Check the member's permission matrix (Solo Mode revokes task management for everyone)
+    let can_manage = ctx.has_permission(&state, Permission::ManageTasks).await;
 
     let mut request = body.into_inner();
     let is_suggestion = request.is_suggestion.unwrap_or(false);
 
     // Solo Mode special handling
-    let solo_mode_active = settings.solo_mode;
+    let solo_mode_active = ctx.settings.solo_mode;
     if solo_mode_active {
         // In Solo Mode, all tasks are treated as suggestions and auto-approved
-        // Override points with household defaults
-        request.points_reward = settings.default_points_reward;
-        request.points_penalty = settings.default_points_penalty;
+        // Points always come from the household defaults
+        request.points_reward = None;
+        request.points_penalty = None;
     }
 
     // If user can't manage and this is not a suggestion, deny access
@@ -214,13 +491,22 @@ async fn create_task(
 
     // If user is trying to suggest but suggestions are disabled, deny access
     // (Solo Mode bypasses this check since suggestions are auto-approved)
-    if !can_manage && is_suggestion && !settings.allow_task_suggestions && !solo_mode_active {
+    if !can_manage && is_suggestion && !ctx.settings.allow_task_suggestions && !solo_mode_active {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "Task suggestions are disabled for this household".to_string(),
         }));
     }
 
+    // Task managers who may not touch points get the household's default points
+    if can_manage && !ctx.has_permission(&state, Permission::AdjustPoints).await {
+        request.points_reward = None;
+        request.points_penalty = None;
+    }
+
+    // Whatever the creator left open comes from the household's task defaults
+    ctx.settings.apply_task_defaults(&mut request);
+
     // If user can manage but is_suggestion is set, ignore it (create normal task)
     // Suggestions are only for users without manage permission
     if request.title.is_empty() {
@@ -229,29 +515,39 @@ async fn create_task(
             message: "Task title is required".to_string(),
         }));
     }
+    if let Some(response) = invalid_effort(request.effort_minutes) {
+        return Ok(response);
+    }
+    if let Some(response) = invalid_recurrence(request.recurrence_value.as_ref()) {
+        return Ok(response);
+    }
+    if let Some(response) = invalid_season([request.active_from.as_deref(), request.active_until.as_deref()]) {
+        return Ok(response);
+    }
+    if let Some(response) = invalid_max_occurrences(request.max_occurrences) {
+        return Ok(response);
+    }
 
-    // Validate assignment in Hierarchy mode
-    if let Some(ref assigned_id) = request.assigned_user_id {
-        if settings.hierarchy_type == HierarchyType::Hierarchy {
-            let assigned_role = household_service::get_member_role(&state.db, &household_id, assigned_id).await;
-            if !assigned_role.as_ref().map(|r| settings.hierarchy_type.can_be_assigned(r)).unwrap_or(false) {
-                return Ok(HttpResponse::BadRequest().json(ApiError {
-                    error: "validation_error".to_string(),
-                    message: "In Hierarchy mode, only Members can be assigned tasks".to_string(),
-                }));
-            }
-        }
+    // Validate assignees (members only; in Hierarchy mode only the Member role)
+    let assignees = task_service::requested_assignees(request.assigned_user_id, request.assignee_ids.as_ref())
+        .unwrap_or_default();
+    if let Err(response) = validate_assignees(&state, &ctx.household_id, &ctx.settings, &assignees).await {
+        return Ok(response);
+    }
+    let reviewer_type = request.reviewer_type.unwrap_or_default();
+    if let Err(response) = validate_reviewer(&state, &ctx.household_id, reviewer_type, request.reviewer_id).await {
+        return Ok(response);
     }
 
     // Determine if this should be created as a suggestion
     // In Solo Mode, all tasks are created as suggestions by the user
     let suggested_by = if solo_mode_active || (is_suggestion && !can_manage) {
-        Some(&user_id)
+        Some(&ctx.user_id)
     } else {
         None
     };
 
-    match task_service::create_task(&state.db, &household_id, &request, suggested_by).await {
+    match task_service::create_task(&state.db, &ctx.household_id, &request, suggested_by).await {
         Ok(mut task) => {
             // Solo Mode: Auto-approve the suggestion and apply household defaults
             if solo_mode_active {
@@ -261,7 +557,7 @@ async fn create_task(
                 }
 
                 // Apply household default rewards
-                for default_reward in &settings.default_rewards {
+                for default_reward in &ctx.settings.default_rewards {
                     let _ = task_consequences::add_task_reward(
                         &state.db,
                         &task.id,
@@ -272,7 +568,7 @@ async fn create_task(
                 }
 
                 // Apply household default punishments
-                for default_punishment in &settings.default_punishments {
+                for default_punishment in &ctx.settings.default_punishments {
                     let _ = task_consequences::add_task_punishment(
                         &state.db,
                         &task.id,
@@ -287,9 +583,9 @@ async fn create_task(
             let details = serde_json::json!({ "title": task.title }).to_string();
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
-                request.assigned_user_id.as_ref(),
+                &ctx.household_id,
+                &ctx.user_id,
+                assignees.first(),
                 ActivityType::TaskCreated,
                 Some("task"),
                 Some(&task.id),
@@ -297,17 +593,38 @@ async fn create_task(
             ).await;
 
             // If task was assigned, also log assignment
-            if let Some(ref assigned_id) = request.assigned_user_id {
+            for assigned_id in &assignees {
                 let _ = activity_logs::log_activity(
                     &state.db,
-                    &household_id,
-                    &user_id,
+                    &ctx.household_id,
+                    &ctx.user_id,
                     Some(assigned_id),
                     ActivityType::TaskAssigned,
                     Some("task"),
                     Some(&task.id),
                     Some(&details),
                 ).await;
+
+                if *assigned_id != ctx.user_id {
+                    notification_center::notify(
+                        &req,
+                        &state.db,
+                        *assigned_id,
+                        ctx.household_id,
+                        NotificationType::TaskAssigned,
+                        &task.title,
+                        Some(("task", task.id)),
+                    )
+                    .await;
+                    notifications::notify_member(
+                        &state.db,
+                        state.push.as_ref(),
+                        &ctx.household_id,
+                        assigned_id,
+                        NotificationKind::Assignments,
+                        notification_service::build_assignment_message(&task),
+                    );
+                }
             }
 
             Ok(HttpResponse::Created().json(ApiSuccess::new(task)))
@@ -322,32 +639,26 @@ async fn create_task(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/tasks/{task_id}",
+    tag = "tasks",
+    summary = "Get task",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<TaskWithStatus>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn get_task(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, task_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, task_id_str) = path.into_inner();
 
     let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
@@ -359,15 +670,7 @@ async fn get_task(
         }
     };
 
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    match task_service::get_task_with_status(&state.db, &task_id, &user_id).await {
+    match task_service::get_task_with_status(&state.db, &task_id, &ctx.user_id).await {
         Ok(Some(task)) => Ok(HttpResponse::Ok().json(ApiSuccess::new(task))),
         Ok(None) => Ok(HttpResponse::NotFound().json(ApiError {
             error: "not_found".to_string(),
@@ -384,32 +687,26 @@ async fn get_task(
 }
 
 /// Get full task details including statistics for the detail view
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/tasks/{task_id}/details",
+    tag = "tasks",
+    summary = "Get full task details including statistics for the detail view",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<TaskWithDetails>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn get_task_details(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, task_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, task_id_str) = path.into_inner();
 
     let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
@@ -421,18 +718,10 @@ async fn get_task_details(
         }
     };
 
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    match task_service::get_task_with_details(&state.db, &task_id, &user_id).await {
+    match task_service::get_task_with_details(&state.db, &task_id, &ctx.user_id).await {
         Ok(Some(details)) => {
             // Verify task belongs to this household
-            if details.task.household_id != household_id {
+            if details.task.household_id != ctx.household_id {
                 return Ok(HttpResponse::NotFound().json(ApiError {
                     error: "not_found".to_string(),
                     message: "Task not found in this household".to_string(),
@@ -454,33 +743,30 @@ async fn get_task_details(
     }
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/tasks/{task_id}",
+    tag = "tasks",
+    summary = "Update task",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    request_body = UpdateTaskRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<shared::Task>),
+        Conflict,
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn update_task(
     state: web::Data<AppState>,
+    ctx: HouseholdContext,
     req: actix_web::HttpRequest,
     path: web::Path<(String, String)>,
     body: web::Json<UpdateTaskRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, task_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, task_id_str) = path.into_inner();
 
     let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
@@ -492,32 +778,16 @@ async fn update_task(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
     // Parse request and get existing task BEFORE permission check
     // (needed to check if this is a valid "Set Date" request in Solo Mode)
-    let request = body.into_inner();
+    let mut request = body.into_inner();
     let old_task = task_service::get_task(&state.db, &task_id).await.ok().flatten();
 
-    // Check if user can manage tasks based on hierarchy type
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    let can_manage = role
-        .as_ref()
-        .map(|r| solo_mode::can_manage_in_context(r, &settings))
-        .unwrap_or(false);
+    // Check the member's permission matrix (Solo Mode revokes task management for everyone)
+    let can_manage = ctx.has_permission(&state, Permission::ManageTasks).await;
 
     // In Solo Mode, allow only "Set Date" operation for unscheduled tasks
-    if settings.solo_mode && !can_manage {
+    if ctx.settings.solo_mode && !can_manage {
         if let Some(ref task) = old_task {
             if !is_solo_mode_set_date_request(&request, task) {
                 return Ok(HttpResponse::Forbidden().json(ApiError {
@@ -539,25 +809,51 @@ async fn update_task(
         }));
     }
 
-    // Validate assignment in Hierarchy mode - only if it's actually changing
-    if let Some(ref assigned_id) = request.assigned_user_id {
-        if settings.hierarchy_type == HierarchyType::Hierarchy {
-            // Check if assignment is actually changing
-            let old_assigned = old_task.as_ref().and_then(|t| t.assigned_user_id);
-            let is_changing = old_assigned.map(|old| &old != assigned_id).unwrap_or(true);
-
-            if is_changing {
-                let assigned_role = household_service::get_member_role(&state.db, &household_id, assigned_id).await;
-                if !assigned_role.as_ref().map(|r| settings.hierarchy_type.can_be_assigned(r)).unwrap_or(false) {
-                    return Ok(HttpResponse::BadRequest().json(ApiError {
-                        error: "validation_error".to_string(),
-                        message: "In Hierarchy mode, only Members can be assigned tasks".to_string(),
-                    }));
-                }
-            }
+    // Members who may not touch points keep the task's current points
+    if !ctx.has_permission(&state, Permission::AdjustPoints).await {
+        request.points_reward = None;
+        request.points_penalty = None;
+    }
+
+    if let Some(response) = invalid_effort(request.effort_minutes.flatten()) {
+        return Ok(response);
+    }
+    if let Some(response) = invalid_recurrence(request.recurrence_value.as_ref()) {
+        return Ok(response);
+    }
+    let season = [&request.active_from, &request.active_until].map(|bound| bound.as_ref().and_then(|b| b.as_deref()));
+    if let Some(response) = invalid_season(season) {
+        return Ok(response);
+    }
+    if let Some(response) = invalid_max_occurrences(request.max_occurrences.flatten()) {
+        return Ok(response);
+    }
+
+    // Validate assignees - only those actually being added
+    let old_assignees = old_task.as_ref().map(|t| t.assignees()).unwrap_or_default();
+    let added_assignees: Vec<Uuid> = task_service::requested_assignees(request.assigned_user_id, request.assignee_ids.as_ref())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|id| !old_assignees.contains(id))
+        .collect();
+    if let Err(response) = validate_assignees(&state, &ctx.household_id, &ctx.settings, &added_assignees).await {
+        return Ok(response);
+    }
+    if let Err(response) = check_exclusions(&state, &task_id, &added_assignees).await {
+        return Ok(response);
+    }
+
+    // Validate the reviewer the task ends up with when the request changes it
+    if request.reviewer_type.is_some() || request.reviewer_id.is_some() {
+        let reviewer_type = request.reviewer_type
+            .or(old_task.as_ref().map(|t| t.reviewer_type))
+            .unwrap_or_default();
+        let reviewer_id = request.reviewer_id
+            .unwrap_or(old_task.as_ref().and_then(|t| t.reviewer_id));
+        if let Err(response) = validate_reviewer(&state, &ctx.household_id, reviewer_type, reviewer_id).await {
+            return Ok(response);
         }
     }
-    let old_assigned = old_task.as_ref().and_then(|t| t.assigned_user_id);
 
     match task_service::update_task(&state.db, &task_id, &request).await {
         Ok(task) => {
@@ -565,8 +861,8 @@ async fn update_task(
             let details = serde_json::json!({ "title": task.title }).to_string();
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 task.assigned_user_id.as_ref(),
                 ActivityType::TaskUpdated,
                 Some("task"),
@@ -574,24 +870,17 @@ async fn update_task(
                 Some(&details),
             ).await;
 
-            // If assignment changed, log the assignment
-            if request.assigned_user_id != old_assigned {
-                if let Some(ref assigned_id) = request.assigned_user_id {
-                    let _ = activity_logs::log_activity(
-                        &state.db,
-                        &household_id,
-                        &user_id,
-                        Some(assigned_id),
-                        ActivityType::TaskAssigned,
-                        Some("task"),
-                        Some(&task.id),
-                        Some(&details),
-                    ).await;
-                }
-            }
+            notify_added_assignees(&req, &state, &ctx.household_id, &ctx.user_id, &task, &added_assignees).await;
 
             Ok(HttpResponse::Ok().json(ApiSuccess::new(task)))
         }
+        Err(task_service::TaskError::VersionConflict(current)) => {
+            Ok(HttpResponse::Conflict().json(VersionConflict {
+                error: "version_conflict".to_string(),
+                message: "The task was changed in the meantime".to_string(),
+                current: *current,
+            }))
+        }
         Err(e) => {
             log::error!("Error updating task: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
@@ -602,32 +891,20 @@ async fn update_task(
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/tasks/{task_id}",
+    tag = "tasks",
+    summary = "Delete task",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
 async fn delete_task(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, task_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, task_id_str) = path.into_inner();
 
     let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
@@ -639,21 +916,8 @@ async fn delete_task(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if user can manage tasks based on hierarchy type
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    // Check if user can manage tasks
+    if !ctx.has_permission(&state, Permission::ManageTasks).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to delete tasks".to_string(),
@@ -661,26 +925,39 @@ async fn delete_task(
     }
 
     // Get the task details before deletion for logging
-    let task = task_service::get_task(&state.db, &task_id).await.ok().flatten();
-    let details = task.as_ref()
-        .map(|t| serde_json::json!({ "title": t.title }).to_string());
+    let task = match task_service::get_task(&state.db, &task_id).await.ok().flatten() {
+        Some(task) if task.household_id == ctx.household_id => task,
+        _ => {
+            return Ok(HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: "Task not found".to_string(),
+            }));
+        }
+    };
+    let details = serde_json::json!({ "title": task.title }).to_string();
 
     match task_service::delete_task(&state.db, &task_id).await {
         Ok(_) => {
             // Log activity
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 None,
                 ActivityType::TaskDeleted,
                 Some("task"),
                 Some(&task_id),
-                details.as_deref(),
+                Some(&details),
             ).await;
 
             Ok(HttpResponse::NoContent().finish())
         }
+        Err(task_service::TaskError::NotFound) => {
+            Ok(HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: "Task not found".to_string(),
+            }))
+        }
         Err(e) => {
             log::error!("Error deleting task: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
@@ -691,77 +968,55 @@ async fn delete_task(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/tasks/archived",
+    tag = "tasks",
+    summary = "List archived tasks",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<shared::Task>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
 async fn list_archived_tasks(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
+    match task_service::list_archived_tasks(&state.db, &ctx.household_id).await {
+        Ok(tasks) => Ok(HttpResponse::Ok().json(ApiSuccess::new(tasks))),
+        Err(e) => {
+            log::error!("Error listing archived tasks: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to list archived tasks".to_string(),
+            }))
         }
-    };
-
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
-    }
-
-    match task_service::list_archived_tasks(&state.db, &household_id).await {
-        Ok(tasks) => Ok(HttpResponse::Ok().json(ApiSuccess::new(tasks))),
-        Err(e) => {
-            log::error!("Error listing archived tasks: {:?}", e);
-            Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to list archived tasks".to_string(),
-            }))
-        }
-    }
-}
+    }
+}
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/{task_id}/archive",
+    tag = "tasks",
+    summary = "Archive task",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<shared::Task>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn archive_task(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, task_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, task_id_str) = path.into_inner();
 
     let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
@@ -773,21 +1028,8 @@ async fn archive_task(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if user can manage tasks based on hierarchy type
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    // Check if user can manage tasks
+    if !ctx.has_permission(&state, Permission::ManageTasks).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to archive tasks".to_string(),
@@ -800,8 +1042,8 @@ async fn archive_task(
             let details = serde_json::json!({ "title": task.title }).to_string();
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 None,
                 ActivityType::TaskUpdated,
                 Some("task"),
@@ -827,32 +1069,26 @@ async fn archive_task(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/{task_id}/unarchive",
+    tag = "tasks",
+    summary = "Unarchive task",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<shared::Task>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn unarchive_task(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, task_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, task_id_str) = path.into_inner();
 
     let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
@@ -864,21 +1100,8 @@ async fn unarchive_task(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if user can manage tasks based on hierarchy type
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    // Check if user can manage tasks
+    if !ctx.has_permission(&state, Permission::ManageTasks).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to unarchive tasks".to_string(),
@@ -891,8 +1114,8 @@ async fn unarchive_task(
             let details = serde_json::json!({ "title": task.title }).to_string();
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 None,
                 ActivityType::TaskUpdated,
                 Some("task"),
@@ -918,32 +1141,26 @@ async fn unarchive_task(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/{task_id}/pause",
+    tag = "tasks",
+    summary = "Pause task",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<shared::Task>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn pause_task(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, task_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, task_id_str) = path.into_inner();
 
     let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
@@ -955,21 +1172,8 @@ async fn pause_task(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if user can manage tasks based on hierarchy type
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    // Check if user can manage tasks
+    if !ctx.has_permission(&state, Permission::ManageTasks).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to pause tasks".to_string(),
@@ -982,8 +1186,8 @@ async fn pause_task(
             let details = serde_json::json!({ "title": task.title, "paused": true }).to_string();
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 None,
                 ActivityType::TaskUpdated,
                 Some("task"),
@@ -1009,32 +1213,28 @@ async fn pause_task(
     }
 }
 
-async fn unpause_task(
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/{task_id}/skip",
+    tag = "tasks",
+    summary = "Skip task",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    request_body = SkipTaskPeriodRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<TaskPeriodResult>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn skip_task(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
+    body: web::Json<SkipTaskPeriodRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, task_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, task_id_str) = path.into_inner();
 
     let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
@@ -1046,35 +1246,47 @@ async fn unpause_task(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
+    let task = match task_service::get_task(&state.db, &task_id).await {
+        Ok(Some(task)) if task.household_id == ctx.household_id => task,
+        Ok(_) => {
+            return Ok(HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: "Task not found".to_string(),
+            }));
+        }
         Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
+            log::error!("Error fetching task: {:?}", e);
             return Ok(HttpResponse::InternalServerError().json(ApiError {
                 error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
+                message: "Failed to fetch task".to_string(),
             }));
         }
     };
 
-    // Check if user can manage tasks based on hierarchy type
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    // Tasks assigned to someone else, or shared by several assignees, can only be skipped by managers
+    if (!task.is_assigned_to(&ctx.user_id) || task.has_individual_completions())
+        && !ctx.has_permission(&state, Permission::ManageTasks).await
+    {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
-            message: "You do not have permission to unpause tasks".to_string(),
+            message: "You do not have permission to skip this task".to_string(),
         }));
     }
 
-    match task_service::unpause_task(&state.db, &task_id).await {
-        Ok(task) => {
+    let reason = body.into_inner().reason;
+    match task_service::skip_current_period(&state.db, &task_id, &ctx.user_id, reason.as_deref()).await {
+        Ok(result) => {
             // Log activity
-            let details = serde_json::json!({ "title": task.title, "paused": false }).to_string();
+            let details = serde_json::json!({
+                "title": task.title,
+                "skipped": true,
+                "reason": result.notes,
+            })
+            .to_string();
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 None,
                 ActivityType::TaskUpdated,
                 Some("task"),
@@ -1082,139 +1294,152 @@ async fn unpause_task(
                 Some(&details),
             ).await;
 
-            Ok(HttpResponse::Ok().json(ApiSuccess::new(task)))
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(result)))
         }
-        Err(task_service::TaskError::NotFound) => {
-            Ok(HttpResponse::NotFound().json(ApiError {
-                error: "not_found".to_string(),
-                message: "Task not found".to_string(),
+        Err(e @ (task_service::TaskError::NotSkippable
+        | task_service::TaskError::NotDueToday
+        | task_service::TaskError::AlreadyCompleted)) => {
+            Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "skip_error".to_string(),
+                message: e.to_string(),
             }))
         }
         Err(e) => {
-            log::error!("Error unpausing task: {:?}", e);
+            log::error!("Error skipping task period: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
                 error: "internal_error".to_string(),
-                message: "Failed to unpause task".to_string(),
+                message: "Failed to skip task".to_string(),
             }))
         }
     }
 }
 
-async fn complete_task(
+/// Move only the current occurrence to another date ("do it tomorrow instead")
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/{task_id}/postpone",
+    tag = "tasks",
+    summary = "Move only the current occurrence to another date (\"do it tomorrow instead\")",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    request_body = PostponeTaskRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<shared::Task>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn postpone_task(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
+    body: web::Json<PostponeTaskRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
+    let (_, task_id_str) = path.into_inner();
 
-    let (household_id_str, task_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
+    let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
         Err(_) => {
             return Ok(HttpResponse::BadRequest().json(ApiError {
                 error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
+                message: "Invalid task ID format".to_string(),
             }));
         }
     };
 
-    let task_id = match Uuid::parse_str(&task_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid task ID format".to_string(),
+    let task = match task_service::get_task(&state.db, &task_id).await {
+        Ok(Some(task)) if task.household_id == ctx.household_id => task,
+        Ok(_) => {
+            return Ok(HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: "Task not found".to_string(),
+            }));
+        }
+        Err(e) => {
+            log::error!("Error fetching task: {:?}", e);
+            return Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch task".to_string(),
             }));
         }
     };
 
-    // Check membership (any member can complete tasks)
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
+    // Tasks assigned to someone else, or shared by several assignees, can only be postponed by managers
+    if (!task.is_assigned_to(&ctx.user_id) || task.has_individual_completions())
+        && !ctx.has_permission(&state, Permission::ManageTasks).await
+    {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
+            message: "You do not have permission to postpone this task".to_string(),
         }));
     }
 
-    // Get settings to check for Solo Mode
-    let settings = household_settings::get_or_create_settings(&state.db, &household_id)
-        .await
-        .unwrap_or_default();
-
-    // Get the task details for logging
-    let task = task_service::get_task(&state.db, &task_id).await.ok().flatten();
-    let details = task.as_ref()
-        .map(|t| serde_json::json!({ "title": t.title }).to_string());
-
-    match task_service::complete_task(&state.db, &task_id, &user_id, &household_id).await {
-        Ok(mut completion) => {
-            // In Solo Mode, auto-approve completions that would otherwise be pending
-            // (since nobody can approve them in Solo Mode)
-            if settings.solo_mode && completion.status == shared::CompletionStatus::Pending {
-                if let Ok(approved) = task_service::approve_completion(&state.db, &completion.id).await {
-                    completion = approved;
-                }
-            }
-
-            // Log activity
+    let target_date = body.into_inner().target_date;
+    let today = chrono::Utc::now().date_naive();
+    match task_service::postpone_task(&state.db, &task_id, target_date, today).await {
+        Ok(updated) => {
+            let details = serde_json::json!({
+                "title": task.title,
+                "postponed_to": target_date,
+            })
+            .to_string();
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
-                Some(&user_id),
-                ActivityType::TaskCompleted,
+                &ctx.household_id,
+                &ctx.user_id,
+                None,
+                ActivityType::TaskUpdated,
                 Some("task"),
-                Some(&task_id),
-                details.as_deref(),
+                Some(&task.id),
+                Some(&details),
             ).await;
 
-            Ok(HttpResponse::Created().json(ApiSuccess::new(completion)))
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(updated)))
         }
-        Err(e) => {
-            log::error!("Error completing task: {:?}", e);
+        Err(e @ (task_service::TaskError::NotPostponable
+        | task_service::TaskError::NotDueToday
+        | task_service::TaskError::InvalidPostponeDate)) => {
             Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "completion_error".to_string(),
+                error: "postpone_error".to_string(),
                 message: e.to_string(),
             }))
         }
+        Err(e) => {
+            log::error!("Error postponing task: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to postpone task".to_string(),
+            }))
+        }
     }
 }
 
-async fn uncomplete_task(
+/// Correct the recorded outcome of a past period (owner only), e.g. when the
+/// background job misfired. Stored statistics covering the period are recalculated.
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/{task_id}/periods/{date}/override",
+    tag = "tasks",
+    summary = "Override period",
+    description = "Correct the recorded outcome of a past period (owner only), e.g. when the background job misfired. Stored statistics covering the period are recalculated.",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path), ("date" = chrono::NaiveDate, Path)),
+    request_body = OverridePeriodRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<TaskPeriodResult>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn override_period(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<(String, String)>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String, String)>,
+    body: web::Json<OverridePeriodRequest>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, task_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, task_id_str, date_str) = path.into_inner();
 
     let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
@@ -1226,192 +1451,758 @@ async fn uncomplete_task(
         }
     };
 
-    // Check membership (any member can uncomplete their own tasks)
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
+    let Ok(date) = chrono::NaiveDate::parse_from_str(&date_str, "%Y-%m-%d") else {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_date".to_string(),
+            message: "Invalid date format. Use YYYY-MM-DD".to_string(),
+        }));
+    };
+
+    if ctx.role() != shared::Role::Owner {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
+            message: "Only the owner can correct period results".to_string(),
+        }));
+    }
+
+    let request = body.into_inner();
+    if request.reason.trim().is_empty() {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "A reason is required".to_string(),
         }));
     }
 
-    match task_service::uncomplete_task(&state.db, &task_id, &user_id).await {
-        Ok(_) => Ok(HttpResponse::Ok().json(ApiSuccess::new(()))),
+    let task = match load_household_task(&state, &ctx.household_id, &task_id).await {
+        Ok(task) => task,
+        Err(response) => return Ok(response),
+    };
+
+    let today = scheduler::today_in_timezone(scheduler::parse_timezone(&ctx.settings.timezone));
+
+    let (period_start, _) = scheduler::get_period_bounds(&task, date);
+    let previous = period_results::get_period_result(&state.db, &task_id, period_start)
+        .await
+        .ok()
+        .flatten()
+        .map(|r| r.status);
+
+    match task_service::override_period(&state.db, &task_id, date, request.status, &ctx.user_id, &request.reason, today).await {
+        Ok(result) => {
+            if let Err(e) =
+                statistics::refresh_stored_statistics(&state.db, &ctx.household_id, result.period_start, result.period_end).await
+            {
+                log::warn!("Failed to refresh statistics after period override: {:?}", e);
+            }
+
+            let details = serde_json::json!({
+                "title": task.title,
+                "period_start": result.period_start,
+                "status": result.status,
+                "reason": result.notes,
+            })
+            .to_string();
+            let _ = activity_logs::log_activity(
+                &state.db,
+                &ctx.household_id,
+                &ctx.user_id,
+                None,
+                ActivityType::TaskUpdated,
+                Some("task"),
+                Some(&task.id),
+                Some(&details),
+            ).await;
+
+            let before = serde_json::json!({
+                "task_id": task.id,
+                "period_start": result.period_start,
+                "status": previous,
+            })
+            .to_string();
+            let after = serde_json::json!({
+                "task_id": task.id,
+                "period_start": result.period_start,
+                "status": result.status,
+                "reason": result.notes,
+            })
+            .to_string();
+            let _ = audit_log::record(
+                &state.db,
+                &ctx.household_id,
+                &ctx.user_id,
+                None,
+                AuditAction::PeriodOverridden,
+                Some(&before),
+                Some(&after),
+            ).await;
+
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(result)))
+        }
+        Err(e @ task_service::TaskError::PeriodNotEnded) => Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "period_not_ended".to_string(),
+            message: e.to_string(),
+        })),
         Err(e) => {
-            log::error!("Error uncompleting task: {:?}", e);
-            Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "uncomplete_error".to_string(),
-                message: e.to_string(),
+            log::error!("Error overriding period result: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to correct period result".to_string(),
             }))
         }
     }
 }
 
-async fn get_due_tasks(
+/// Spend a streak freeze on a missed period of one of the member's own tasks
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/{task_id}/periods/{date}/freeze",
+    tag = "tasks",
+    summary = "Spend a streak freeze on a missed period of one of the member's own tasks",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path), ("date" = chrono::NaiveDate, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<TaskPeriodResult>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn freeze_period(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
+    let (_, task_id_str, date_str) = path.into_inner();
 
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
+    let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
         Err(_) => {
             return Ok(HttpResponse::BadRequest().json(ApiError {
                 error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
+                message: "Invalid task ID format".to_string(),
             }));
         }
     };
 
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
+    let Ok(date) = chrono::NaiveDate::parse_from_str(&date_str, "%Y-%m-%d") else {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_date".to_string(),
+            message: "Invalid date format. Use YYYY-MM-DD".to_string(),
+        }));
+    };
+
+    let task = match load_household_task(&state, &ctx.household_id, &task_id).await {
+        Ok(task) => task,
+        Err(response) => return Ok(response),
+    };
+
+    if !task.is_assigned_to(&ctx.user_id) {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
+            message: "Streak freezes can only be used on your own tasks".to_string(),
         }));
     }
 
-    match task_service::get_due_tasks(&state.db, &household_id, &user_id).await {
-        Ok(tasks) => Ok(HttpResponse::Ok().json(ApiSuccess::new(tasks))),
-        Err(e) => {
-            log::error!("Error fetching due tasks: {:?}", e);
+    let (period_start, _) = scheduler::get_period_bounds(&task, date);
+    match period_results::apply_streak_freeze(
+        &state.db,
+        &ctx.household_id,
+        &task_id,
+        &ctx.user_id,
+        period_start,
+        task.has_individual_completions(),
+    )
+    .await
+    {
+        Ok(result) => {
+            let details = serde_json::json!({
+                "title": task.title,
+                "period_start": result.period_start,
+            })
+            .to_string();
+            let _ = activity_logs::log_activity(
+                &state.db,
+                &ctx.household_id,
+                &ctx.user_id,
+                None,
+                ActivityType::StreakFreezeUsed,
+                Some("task"),
+                Some(&task.id),
+                Some(&details),
+            ).await;
+
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(result)))
+        }
+        Err(e @ (period_results::StreakFreezeError::NotMissed | period_results::StreakFreezeError::NoneAvailable)) => {
+            Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "freeze_error".to_string(),
+                message: e.to_string(),
+            }))
+        }
+        Err(e) => {
+            log::error!("Error applying streak freeze: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
                 error: "internal_error".to_string(),
-                message: "Failed to fetch due tasks".to_string(),
+                message: "Failed to apply streak freeze".to_string(),
             }))
         }
     }
 }
 
-async fn get_all_tasks_with_status(
+/// Load a task and make sure it belongs to the household from the path
+async fn load_household_task(
+    state: &AppState,
+    household_id: &Uuid,
+    task_id: &Uuid,
+) -> std::result::Result<Task, HttpResponse> {
+    match task_service::get_task(&state.db, task_id).await {
+        Ok(Some(task)) if task.household_id == *household_id => Ok(task),
+        Ok(_) => Err(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Task not found".to_string(),
+        })),
+        Err(e) => {
+            log::error!("Error fetching task: {:?}", e);
+            Err(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch task".to_string(),
+            }))
+        }
+    }
+}
+
+fn claim_error_response(error: task_claims::TaskClaimError, context: &str) -> HttpResponse {
+    use task_claims::TaskClaimError;
+
+    match error {
+        TaskClaimError::AlreadyClaimed(_) => HttpResponse::Conflict().json(ApiError {
+            error: "already_claimed".to_string(),
+            message: error.to_string(),
+        }),
+        TaskClaimError::NotClaimed => HttpResponse::NotFound().json(ApiError {
+            error: "not_claimed".to_string(),
+            message: error.to_string(),
+        }),
+        TaskClaimError::Assigned | TaskClaimError::Inactive | TaskClaimError::Excluded => HttpResponse::BadRequest().json(ApiError {
+            error: "claim_error".to_string(),
+            message: error.to_string(),
+        }),
+        TaskClaimError::DatabaseError(e) => {
+            log::error!("Error {}: {:?}", context, e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: format!("Failed {}", context),
+            })
+        }
+    }
+}
+
+/// Volunteer for an unassigned task for the current period so nobody else starts on it
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/{task_id}/claim",
+    tag = "tasks",
+    summary = "Volunteer for an unassigned task for the current period so nobody else starts on it",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<TaskClaim>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn claim_task(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
+    let (_, task_id_str) = path.into_inner();
+
+    let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
         Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_id".to_string(),
+                message: "Invalid task ID format".to_string(),
             }));
         }
     };
 
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
+    let task = match load_household_task(&state, &ctx.household_id, &task_id).await {
+        Ok(task) => task,
+        Err(response) => return Ok(response),
+    };
+
+    let today = chrono::Utc::now().date_naive();
+    let claim = match task_claims::claim_task(&state.db, &task, &ctx.user_id, today).await {
+        Ok(claim) => claim,
+        Err(e) => return Ok(claim_error_response(e, "claiming task")),
+    };
+
+    let details = serde_json::json!({ "title": task.title }).to_string();
+    let _ = activity_logs::log_activity(
+        &state.db,
+        &ctx.household_id,
+        &ctx.user_id,
+        None,
+        ActivityType::TaskClaimed,
+        Some("task"),
+        Some(&task.id),
+        Some(&details),
+    )
+    .await;
+
+    Ok(HttpResponse::Ok().json(ApiSuccess::new(claim)))
+}
+
+/// Give a claimed task back; the claimer and task managers may release it
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/tasks/{task_id}/claim",
+    tag = "tasks",
+    summary = "Give a claimed task back; the claimer and task managers may release it",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn release_task(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, task_id_str) = path.into_inner();
+
+    let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
         Err(_) => {
             return Ok(HttpResponse::BadRequest().json(ApiError {
                 error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
+                message: "Invalid task ID format".to_string(),
             }));
         }
     };
 
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
+    let task = match load_household_task(&state, &ctx.household_id, &task_id).await {
+        Ok(task) => task,
+        Err(response) => return Ok(response),
+    };
+
+    let today = chrono::Utc::now().date_naive();
+    let week_start_day = household_settings::week_start_day(&state.db, &ctx.household_id).await.unwrap_or_default();
+    let period_start = task_claims::claim_period_start(&task, today, week_start_day);
+    let claim = match task_claims::get_claim(&state.db, &task_id, period_start).await {
+        Ok(Some(claim)) => claim,
+        Ok(None) => return Ok(claim_error_response(task_claims::TaskClaimError::NotClaimed, "releasing task")),
+        Err(e) => return Ok(claim_error_response(e, "releasing task")),
+    };
+
+    if claim.user_id != ctx.user_id
+        && !ctx.has_permission(&state, Permission::ManageTasks).await
+    {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
+            message: "Only the member who claimed this task can release it".to_string(),
         }));
     }
 
-    match task_service::get_all_tasks_with_status(&state.db, &household_id, &user_id).await {
-        Ok(tasks) => Ok(HttpResponse::Ok().json(ApiSuccess::new(tasks))),
-        Err(e) => {
-            log::error!("Error fetching all tasks with status: {:?}", e);
-            Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch tasks".to_string(),
-            }))
-        }
+    if let Err(e) = task_claims::release_claim(&state.db, &task, today).await {
+        return Ok(claim_error_response(e, "releasing task"));
     }
+
+    let details = serde_json::json!({ "title": task.title }).to_string();
+    let _ = activity_logs::log_activity(
+        &state.db,
+        &ctx.household_id,
+        &ctx.user_id,
+        Some(&claim.user_id),
+        ActivityType::TaskReleased,
+        Some("task"),
+        Some(&task.id),
+        Some(&details),
+    )
+    .await;
+
+    Ok(HttpResponse::NoContent().finish())
 }
 
-async fn get_assigned_tasks(
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/{task_id}/unpause",
+    tag = "tasks",
+    summary = "Unpause task",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<shared::Task>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn unpause_task(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
+    let (_, task_id_str) = path.into_inner();
 
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
+    let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
         Err(_) => {
             return Ok(HttpResponse::BadRequest().json(ApiError {
                 error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
+                message: "Invalid task ID format".to_string(),
             }));
         }
     };
 
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
+    // Check if user can manage tasks
+    if !ctx.has_permission(&state, Permission::ManageTasks).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
+            message: "You do not have permission to unpause tasks".to_string(),
         }));
     }
 
-    match task_service::list_user_assigned_tasks(&state.db, &household_id, &user_id).await {
-        Ok(tasks) => Ok(HttpResponse::Ok().json(ApiSuccess::new(tasks))),
+    match task_service::unpause_task(&state.db, &task_id).await {
+        Ok(task) => {
+            // Log activity
+            let details = serde_json::json!({ "title": task.title, "paused": false }).to_string();
+            let _ = activity_logs::log_activity(
+                &state.db,
+                &ctx.household_id,
+                &ctx.user_id,
+                None,
+                ActivityType::TaskUpdated,
+                Some("task"),
+                Some(&task.id),
+                Some(&details),
+            ).await;
+
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(task)))
+        }
+        Err(task_service::TaskError::NotFound) => {
+            Ok(HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: "Task not found".to_string(),
+            }))
+        }
         Err(e) => {
-            log::error!("Error fetching assigned tasks: {:?}", e);
+            log::error!("Error unpausing task: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
                 error: "internal_error".to_string(),
-                message: "Failed to fetch assigned tasks".to_string(),
+                message: "Failed to unpause task".to_string(),
             }))
         }
     }
 }
 
-// ============================================================================
-// Task Rewards/Punishments Endpoints
-// ============================================================================
-
-async fn get_task_rewards(
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/{task_id}/complete",
+    tag = "tasks",
+    summary = "Complete task",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<TaskCompletion>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn complete_task(
     state: web::Data<AppState>,
+    ctx: HouseholdContext,
     req: actix_web::HttpRequest,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
+    let (_, task_id_str) = path.into_inner();
+
+    let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
         Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_id".to_string(),
+                message: "Invalid task ID format".to_string(),
             }));
         }
     };
 
-    let (household_id_str, task_id_str) = path.into_inner();
+    // Get the task details for logging
+    let task = task_service::get_task(&state.db, &task_id).await.ok().flatten();
+    let details = task.as_ref()
+        .map(|t| serde_json::json!({ "title": t.title }).to_string());
+
+    match task_service::complete_task(&state.db, &task_id, &ctx.user_id, &ctx.household_id).await {
+        Ok(mut completion) => {
+            // In Solo Mode, auto-approve completions that would otherwise be pending
+            // (since nobody can approve them in Solo Mode)
+            if ctx.settings.solo_mode && completion.status == shared::CompletionStatus::Pending {
+                if let Ok(approved) = task_service::approve_completion(&state.db, &completion.id).await {
+                    completion = approved;
+                }
+            }
+
+            // Log activity
+            let _ = activity_logs::log_activity(
+                &state.db,
+                &ctx.household_id,
+                &ctx.user_id,
+                Some(&ctx.user_id),
+                ActivityType::TaskCompleted,
+                Some("task"),
+                Some(&task_id),
+                details.as_deref(),
+            ).await;
+
+            if completion.status == CompletionStatus::Pending {
+                if let Some(ref task) = task {
+                    let completed_by = auth_service::get_user_by_id(&state.db, &ctx.user_id)
+                        .await
+                        .ok()
+                        .flatten()
+                        .map(|u| u.username)
+                        .unwrap_or_default();
+                    notifications::notify_members(
+                        &state.db,
+                        state.push.as_ref(),
+                        &ctx.household_id,
+                        &ctx.user_id,
+                        Some(Permission::ReviewCompletions),
+                        NotificationKind::Reviews,
+                        notification_service::build_review_message(task, &completed_by),
+                    )
+                    .await;
+                    chat_bridge::post_in_background(
+                        state.chat_bridge.as_ref(),
+                        &state.db,
+                        ctx.household_id,
+                        chat_bridge::BridgeEvent::ReviewRequested,
+                        chat_bridge::review_requested_text(&task.title, &completed_by),
+                    );
+                }
+            }
+
+            crate::handlers::websocket::broadcast_leaderboard(&req, &state.db, &ctx.household_id).await;
+            crate::handlers::reward_wishlist::notify_affordable(&req, &state.db, &ctx.household_id).await;
+            crate::handlers::point_goals::broadcast_progress(&req, &state.db, &ctx.household_id).await;
+            crate::handlers::pets::broadcast_care(&req, &state.db, &ctx.household_id, &task_id).await;
+
+            Ok(HttpResponse::Created().json(ApiSuccess::new(completion)))
+        }
+        Err(e @ task_service::TaskError::PrerequisitesNotMet(_)) => {
+            Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "prerequisites_not_met".to_string(),
+                message: e.to_string(),
+            }))
+        }
+        Err(e @ task_service::TaskError::ClaimedByOther(_)) => {
+            Ok(HttpResponse::Conflict().json(ApiError {
+                error: "task_claimed".to_string(),
+                message: e.to_string(),
+            }))
+        }
+        Err(e) => {
+            log::error!("Error completing task: {:?}", e);
+            Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "completion_error".to_string(),
+                message: e.to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/{task_id}/uncomplete",
+    tag = "tasks",
+    summary = "Uncomplete task",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Null>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn uncomplete_task(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    req: actix_web::HttpRequest,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, task_id_str) = path.into_inner();
 
-    let household_id = match Uuid::parse_str(&household_id_str) {
+    let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
         Err(_) => {
             return Ok(HttpResponse::BadRequest().json(ApiError {
                 error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
+                message: "Invalid task ID format".to_string(),
             }));
         }
     };
 
+    match task_service::uncomplete_task(&state.db, &task_id, &ctx.user_id).await {
+        Ok(_) => {
+            crate::handlers::websocket::broadcast_leaderboard(&req, &state.db, &ctx.household_id).await;
+            crate::handlers::reward_wishlist::notify_affordable(&req, &state.db, &ctx.household_id).await;
+            crate::handlers::point_goals::broadcast_progress(&req, &state.db, &ctx.household_id).await;
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(())))
+        }
+        Err(e) => {
+            log::error!("Error uncompleting task: {:?}", e);
+            Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "uncomplete_error".to_string(),
+                message: e.to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/tasks/due",
+    operation_id = "tasks_get_due_tasks",
+    tag = "tasks",
+    summary = "Get due tasks",
+    params(("household_id" = Uuid, Path), TaskFilterQuery),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<TaskWithStatus>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn get_due_tasks(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    filter_query: web::Query<TaskFilterQuery>,
+) -> Result<HttpResponse> {
+    let filter = match filter_query.filter() {
+        Ok(filter) => filter,
+        Err(response) => return Ok(response),
+    };
+
+    match task_service::get_due_tasks(&state.db, &ctx.household_id, &ctx.user_id).await {
+        Ok(mut tasks) => {
+            let today = chrono::Utc::now().date_naive();
+            let week_start_day = household_settings::week_start_day(&state.db, &ctx.household_id).await.unwrap_or_default();
+            tasks.retain(|t| task_filters::matches(&filter, &t.task, today, week_start_day));
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(tasks)))
+        }
+        Err(e) => {
+            log::error!("Error fetching due tasks: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch due tasks".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/tasks/all",
+    tag = "tasks",
+    summary = "Get all tasks with status",
+    params(("household_id" = Uuid, Path), PaginationQuery),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Paginated<TaskWithStatus>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn get_all_tasks_with_status(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    query: web::Query<PaginationQuery>,
+    filter_query: web::Query<TaskFilterQuery>,
+) -> Result<HttpResponse> {
+    let filter = match filter_query.filter() {
+        Ok(filter) => filter,
+        Err(response) => return Ok(response),
+    };
+
+    let limit = query.capped_limit(MAX_TASK_PAGE);
+    match task_service::get_tasks_with_status_page(&state.db, &ctx.household_id, &ctx.user_id, &filter, limit, query.offset_or_zero()).await {
+        Ok(page) => Ok(HttpResponse::Ok().json(ApiSuccess::new(page))),
+        Err(e) => {
+            log::error!("Error fetching all tasks with status: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch tasks".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/tasks/assigned-to-me",
+    tag = "tasks",
+    summary = "Get assigned tasks",
+    params(("household_id" = Uuid, Path), TaskFilterQuery),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<shared::Task>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn get_assigned_tasks(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    filter_query: web::Query<TaskFilterQuery>,
+) -> Result<HttpResponse> {
+    let filter = match filter_query.filter() {
+        Ok(filter) => filter,
+        Err(response) => return Ok(response),
+    };
+
+    match task_service::list_user_assigned_tasks(&state.db, &ctx.household_id, &ctx.user_id).await {
+        Ok(tasks) => {
+            let week_start_day = household_settings::week_start_day(&state.db, &ctx.household_id).await.unwrap_or_default();
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(task_filters::apply(&filter, tasks, week_start_day))))
+        }
+        Err(e) => {
+            log::error!("Error fetching assigned tasks: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to fetch assigned tasks".to_string(),
+            }))
+        }
+    }
+}
+
+// ============================================================================
+// Task Rewards/Punishments Endpoints
+// ============================================================================
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/tasks/{task_id}/rewards",
+    tag = "tasks",
+    summary = "Get task rewards",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<TaskRewardLink>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn get_task_rewards(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, task_id_str) = path.into_inner();
+
     let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
         Err(_) => {
@@ -1422,12 +2213,8 @@ async fn get_task_rewards(
         }
     };
 
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
+    if let Err(response) = load_household_task(&state, &ctx.household_id, &task_id).await {
+        return Ok(response);
     }
 
     match task_consequences::get_task_rewards(&state.db, &task_id).await {
@@ -1442,35 +2229,29 @@ async fn get_task_rewards(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/{task_id}/rewards/{reward_id}",
+    tag = "tasks",
+    summary = "Add task reward",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path), ("reward_id" = Uuid, Path), AddLinkQuery),
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<Null>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn add_task_reward(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String, String)>,
     query: web::Query<AddLinkQuery>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, task_id_str, reward_id_str) = path.into_inner();
+    let (_, task_id_str, reward_id_str) = path.into_inner();
     let amount = query.amount;
 
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
     let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
         Err(_) => {
@@ -1491,21 +2272,8 @@ async fn add_task_reward(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if user can manage tasks based on hierarchy type
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    // Check if user can manage tasks
+    if !ctx.has_permission(&state, Permission::ManageTasks).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to link rewards to tasks".to_string(),
@@ -1530,32 +2298,20 @@ async fn add_task_reward(
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/tasks/{task_id}/rewards/{reward_id}",
+    tag = "tasks",
+    summary = "Remove task reward",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path), ("reward_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
 async fn remove_task_reward(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, task_id_str, reward_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, task_id_str, reward_id_str) = path.into_inner();
 
     let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
@@ -1577,21 +2333,8 @@ async fn remove_task_reward(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if user can manage tasks based on hierarchy type
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    // Check if user can manage tasks
+    if !ctx.has_permission(&state, Permission::ManageTasks).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to unlink rewards from tasks".to_string(),
@@ -1616,32 +2359,26 @@ async fn remove_task_reward(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/tasks/{task_id}/punishments",
+    tag = "tasks",
+    summary = "Get task punishments",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<TaskPunishmentLink>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn get_task_punishments(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, task_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, task_id_str) = path.into_inner();
 
     let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
@@ -1653,12 +2390,8 @@ async fn get_task_punishments(
         }
     };
 
-    // Check membership
-    if !household_service::is_member(&state.db, &household_id, &user_id).await.unwrap_or(false) {
-        return Ok(HttpResponse::Forbidden().json(ApiError {
-            error: "forbidden".to_string(),
-            message: "You are not a member of this household".to_string(),
-        }));
+    if let Err(response) = load_household_task(&state, &ctx.household_id, &task_id).await {
+        return Ok(response);
     }
 
     match task_consequences::get_task_punishments(&state.db, &task_id).await {
@@ -1673,35 +2406,29 @@ async fn get_task_punishments(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/{task_id}/punishments/{punishment_id}",
+    tag = "tasks",
+    summary = "Add task punishment",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path), ("punishment_id" = Uuid, Path), AddLinkQuery),
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<Null>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn add_task_punishment(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String, String)>,
     query: web::Query<AddLinkQuery>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, task_id_str, punishment_id_str) = path.into_inner();
+    let (_, task_id_str, punishment_id_str) = path.into_inner();
     let amount = query.amount;
 
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
-
     let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
         Err(_) => {
@@ -1722,21 +2449,8 @@ async fn add_task_punishment(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if user can manage tasks based on hierarchy type
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    // Check if user can manage tasks
+    if !ctx.has_permission(&state, Permission::ManageTasks).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to link punishments to tasks".to_string(),
@@ -1761,32 +2475,20 @@ async fn add_task_punishment(
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/tasks/{task_id}/punishments/{punishment_id}",
+    tag = "tasks",
+    summary = "Remove task punishment",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path), ("punishment_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
 async fn remove_task_punishment(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, task_id_str, punishment_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, task_id_str, punishment_id_str) = path.into_inner();
 
     let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
@@ -1808,21 +2510,8 @@ async fn remove_task_punishment(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if user can manage tasks based on hierarchy type
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    // Check if user can manage tasks
+    if !ctx.has_permission(&state, Permission::ManageTasks).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to unlink punishments from tasks".to_string(),
@@ -1851,54 +2540,214 @@ async fn remove_task_punishment(
 // Review Endpoints
 // ============================================================================
 
-async fn get_pending_reviews(
+/// Parse household, task and prerequisite IDs and require permission to manage tasks
+async fn authorize_prerequisite_change(
+    state: &AppState,
+    ctx: &HouseholdContext,
+    path: (String, String, String),
+) -> std::result::Result<(Uuid, Uuid), HttpResponse> {
+    let (_, task_id_str, prerequisite_id_str) = path;
+    let invalid_id = |label: &str| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_id".to_string(),
+            message: format!("Invalid {} ID format", label),
+        })
+    };
+    let task_id = Uuid::parse_str(&task_id_str).map_err(|_| invalid_id("task"))?;
+    let prerequisite_id = Uuid::parse_str(&prerequisite_id_str).map_err(|_| invalid_id("prerequisite"))?;
+
+    if !ctx.has_permission(state, Permission::ManageTasks).await {
+        return Err(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "You do not have permission to change task prerequisites".to_string(),
+        }));
+    }
+
+    load_household_task(state, &ctx.household_id, &task_id).await?;
+    Ok((task_id, prerequisite_id))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/tasks/{task_id}/prerequisites",
+    tag = "tasks",
+    summary = "Get task prerequisites",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<shared::Task>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn get_task_prerequisites(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
+    let (_, task_id_str) = path.into_inner();
 
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
+    let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
         Err(_) => {
             return Ok(HttpResponse::BadRequest().json(ApiError {
                 error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
+                message: "Invalid task ID format".to_string(),
             }));
         }
     };
 
-    // Get settings for hierarchy-aware permissions (only owners/managers can see pending reviews)
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
+    if let Err(response) = load_household_task(&state, &ctx.household_id, &task_id).await {
+        return Ok(response);
+    }
+
+    match task_dependencies::get_prerequisites(&state.db, &task_id).await {
+        Ok(prerequisites) => Ok(HttpResponse::Ok().json(ApiSuccess::new(prerequisites))),
         Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
+            log::error!("Error fetching task prerequisites: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
                 error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
+                message: "Failed to fetch task prerequisites".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/{task_id}/prerequisites/{prerequisite_id}",
+    tag = "tasks",
+    summary = "Add task prerequisite",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path), ("prerequisite_id" = Uuid, Path)),
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<Null>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn add_task_prerequisite(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String, String)>,
+) -> Result<HttpResponse> {
+    let (task_id, prerequisite_id) = match authorize_prerequisite_change(&state, &ctx, path.into_inner()).await {
+        Ok(ids) => ids,
+        Err(response) => return Ok(response),
+    };
+
+    match task_dependencies::add_dependency(&state.db, &task_id, &prerequisite_id).await {
+        Ok(()) => Ok(HttpResponse::Created().json(ApiSuccess::new(()))),
+        Err(task_dependencies::TaskDependencyError::AlreadyExists) => {
+            Ok(HttpResponse::Conflict().json(ApiError {
+                error: "already_exists".to_string(),
+                message: "Task already has this prerequisite".to_string(),
+            }))
+        }
+        Err(task_dependencies::TaskDependencyError::TaskNotFound) => {
+            Ok(HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: "Prerequisite task not found".to_string(),
+            }))
         }
+        Err(e @ (task_dependencies::TaskDependencyError::SelfDependency
+        | task_dependencies::TaskDependencyError::DifferentHousehold
+        | task_dependencies::TaskDependencyError::Circular)) => {
+            Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_prerequisite".to_string(),
+                message: e.to_string(),
+            }))
+        }
+        Err(e) => {
+            log::error!("Error adding task prerequisite: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to add task prerequisite".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/tasks/{task_id}/prerequisites/{prerequisite_id}",
+    tag = "tasks",
+    summary = "Remove task prerequisite",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path), ("prerequisite_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn remove_task_prerequisite(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String, String)>,
+) -> Result<HttpResponse> {
+    let (task_id, prerequisite_id) = match authorize_prerequisite_change(&state, &ctx, path.into_inner()).await {
+        Ok(ids) => ids,
+        Err(response) => return Ok(response),
     };
 
-    // Check if user can manage tasks based on hierarchy type
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    match task_dependencies::remove_dependency(&state.db, &task_id, &prerequisite_id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(task_dependencies::TaskDependencyError::NotFound) => {
+            Ok(HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: "Task does not have this prerequisite".to_string(),
+            }))
+        }
+        Err(e) => {
+            log::error!("Error removing task prerequisite: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to remove task prerequisite".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/tasks/pending-reviews",
+    tag = "tasks",
+    summary = "Get pending reviews",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<PendingReview>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn get_pending_reviews(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
+    // Members a review was delegated to see those reviews without the review permission
+    let Some(is_reviewer) = permissions::review_permission(&state.db, &ctx.household_id, &ctx.user_id).await else {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to view pending reviews".to_string(),
         }));
-    }
+    };
 
-    match task_service::list_pending_reviews(&state.db, &household_id).await {
-        Ok(reviews) => Ok(HttpResponse::Ok().json(ApiSuccess::new(reviews))),
+    match task_service::list_pending_reviews(&state.db, &ctx.household_id).await {
+        Ok(mut reviews) => {
+            let member_ids: Vec<Uuid> = household_service::list_members(&state.db, &ctx.household_id)
+                .await
+                .map(|members| members.iter().map(|member| member.user.id).collect())
+                .unwrap_or_default();
+            reviews.retain(|review| {
+                let reviewer_is_member = review.task.reviewer_id.is_some_and(|id| member_ids.contains(&id));
+                review.task.can_review(&ctx.user_id, &review.completion.user_id, is_reviewer, reviewer_is_member)
+            });
+            for review in &mut reviews {
+                review.attachments = attachment_service::list_for_completion(&state.db, &review.completion.id)
+                    .await
+                    .unwrap_or_default();
+            }
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(reviews)))
+        }
         Err(e) => {
             log::error!("Error fetching pending reviews: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
@@ -1909,32 +2758,28 @@ async fn get_pending_reviews(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/completions/{completion_id}/approve",
+    operation_id = "tasks_approve_completion",
+    tag = "tasks",
+    summary = "Approve completion",
+    params(("household_id" = Uuid, Path), ("completion_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<TaskCompletion>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn approve_completion(
     state: web::Data<AppState>,
+    ctx: HouseholdContext,
     req: actix_web::HttpRequest,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, completion_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, completion_id_str) = path.into_inner();
 
     let completion_id = match Uuid::parse_str(&completion_id_str) {
         Ok(id) => id,
@@ -1946,26 +2791,12 @@ async fn approve_completion(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if user can manage tasks based on hierarchy type
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    let Some(is_reviewer) = permissions::review_permission(&state.db, &ctx.household_id, &ctx.user_id).await else {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to approve task completions".to_string(),
         }));
-    }
+    };
 
     // Get completion details for logging
     let completion = task_service::get_completion(&state.db, &completion_id).await.ok().flatten();
@@ -1974,6 +2805,12 @@ async fn approve_completion(
     } else {
         None
     };
+    if !may_review(&state, completion.as_ref(), task.as_ref(), &ctx.household_id, &ctx.user_id, is_reviewer).await {
+        return Ok(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "You do not have permission to approve task completions".to_string(),
+        }));
+    }
     let details = task.as_ref()
         .map(|t| serde_json::json!({ "title": t.title }).to_string());
 
@@ -1982,8 +2819,8 @@ async fn approve_completion(
             // Log activity
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 completion.as_ref().map(|c| &c.user_id),
                 ActivityType::TaskCompletionApproved,
                 Some("task_completion"),
@@ -1991,6 +2828,25 @@ async fn approve_completion(
                 details.as_deref(),
             ).await;
 
+            if let (Some(c), Some(t)) = (completion.as_ref(), task.as_ref()) {
+                if c.user_id != ctx.user_id {
+                    notification_center::notify(
+                        &req,
+                        &state.db,
+                        c.user_id,
+                        ctx.household_id,
+                        NotificationType::CompletionApproved,
+                        &t.title,
+                        Some(("task", t.id)),
+                    )
+                    .await;
+                }
+            }
+
+            crate::handlers::websocket::broadcast_leaderboard(&req, &state.db, &ctx.household_id).await;
+            crate::handlers::reward_wishlist::notify_affordable(&req, &state.db, &ctx.household_id).await;
+            crate::handlers::point_goals::broadcast_progress(&req, &state.db, &ctx.household_id).await;
+
             Ok(HttpResponse::Ok().json(ApiSuccess::new(approved)))
         }
         Err(task_service::TaskError::NotFound) => {
@@ -2006,35 +2862,31 @@ async fn approve_completion(
                 message: "Failed to approve completion".to_string(),
             }))
         }
-    }
-}
-
-async fn reject_completion(
-    state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
-    path: web::Path<(String, String)>,
-) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, completion_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/completions/{completion_id}/reject",
+    operation_id = "tasks_reject_completion",
+    tag = "tasks",
+    summary = "Reject completion",
+    params(("household_id" = Uuid, Path), ("completion_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Null>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn reject_completion(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    req: actix_web::HttpRequest,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, completion_id_str) = path.into_inner();
 
     let completion_id = match Uuid::parse_str(&completion_id_str) {
         Ok(id) => id,
@@ -2046,26 +2898,12 @@ async fn reject_completion(
         }
     };
 
-    // Get settings for hierarchy-aware permissions
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    // Check if user can manage tasks based on hierarchy type
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    let Some(is_reviewer) = permissions::review_permission(&state.db, &ctx.household_id, &ctx.user_id).await else {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to reject task completions".to_string(),
         }));
-    }
+    };
 
     // Get completion details for logging before deletion
     let completion = task_service::get_completion(&state.db, &completion_id).await.ok().flatten();
@@ -2074,17 +2912,30 @@ async fn reject_completion(
     } else {
         None
     };
+    if !may_review(&state, completion.as_ref(), task.as_ref(), &ctx.household_id, &ctx.user_id, is_reviewer).await {
+        return Ok(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "You do not have permission to reject task completions".to_string(),
+        }));
+    }
     let details = task.as_ref()
         .map(|t| serde_json::json!({ "title": t.title }).to_string());
     let affected_user_id = completion.as_ref().map(|c| c.user_id);
 
-    match task_service::reject_completion(&state.db, &completion_id, &household_id).await {
+    // Rejected completions are deleted, so drop their photos as well
+    if completion.as_ref().is_some_and(|c| c.status == CompletionStatus::Pending) {
+        if let Err(e) = attachment_service::delete_for_completion(&state.db, &state.config.upload_dir, &completion_id).await {
+            log::error!("Error deleting completion attachments: {:?}", e);
+        }
+    }
+
+    match task_service::reject_completion(&state.db, &completion_id, &ctx.household_id).await {
         Ok(_) => {
             // Log activity
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 affected_user_id.as_ref(),
                 ActivityType::TaskCompletionRejected,
                 Some("task_completion"),
@@ -2092,6 +2943,25 @@ async fn reject_completion(
                 details.as_deref(),
             ).await;
 
+            if let (Some(affected), Some(t)) = (affected_user_id, task.as_ref()) {
+                if affected != ctx.user_id {
+                    notification_center::notify(
+                        &req,
+                        &state.db,
+                        affected,
+                        ctx.household_id,
+                        NotificationType::CompletionRejected,
+                        &t.title,
+                        Some(("task", t.id)),
+                    )
+                    .await;
+                }
+            }
+
+            crate::handlers::websocket::broadcast_leaderboard(&req, &state.db, &ctx.household_id).await;
+            crate::handlers::reward_wishlist::notify_affordable(&req, &state.db, &ctx.household_id).await;
+            crate::handlers::point_goals::broadcast_progress(&req, &state.db, &ctx.household_id).await;
+
             Ok(HttpResponse::Ok().json(ApiSuccess::new(())))
         }
         Err(task_service::TaskError::NotFound) => {
@@ -2110,56 +2980,219 @@ async fn reject_completion(
     }
 }
 
-// ============================================================================
-// Task Suggestion Endpoints
-// ============================================================================
-
-async fn list_suggestions(
+/// Slack on top of the file size limit for multipart boundaries and part headers
+const MULTIPART_OVERHEAD_BYTES: usize = 16 * 1024;
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/completions/{completion_id}/attachments",
+    tag = "tasks",
+    summary = "Upload completion attachment",
+    params(("household_id" = Uuid, Path), ("completion_id" = Uuid, Path)),
+    request_body(content = FileUpload, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<CompletionAttachment>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn upload_completion_attachment(
     state: web::Data<AppState>,
+    ctx: HouseholdContext,
     req: actix_web::HttpRequest,
-    path: web::Path<String>,
+    path: web::Path<(String, String)>,
+    mut payload: web::Payload,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
+    let (_, completion_id_str) = path.into_inner();
+
+    let completion_id = match Uuid::parse_str(&completion_id_str) {
         Ok(id) => id,
         Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_id".to_string(),
+                message: "Invalid completion ID format".to_string(),
+            }));
+        }
+    };
+
+    // Only the member who completed the task may attach proof, and only while it awaits review
+    let completion = task_service::get_completion(&state.db, &completion_id).await.ok().flatten();
+    let task = match &completion {
+        Some(c) => task_service::get_task(&state.db, &c.task_id).await.ok().flatten(),
+        None => None,
+    };
+    let completion = match (completion, task) {
+        (Some(c), Some(t)) if t.household_id == ctx.household_id && c.user_id == ctx.user_id => c,
+        _ => {
+            return Ok(HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: "Completion not found".to_string(),
+            }));
+        }
+    };
+
+    if completion.status != CompletionStatus::Pending {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "not_pending".to_string(),
+            message: "Photos can only be attached while the completion awaits review".to_string(),
+        }));
+    }
+
+    let content_type = req
+        .headers()
+        .get(actix_web::http::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+
+    let limit = state.config.max_upload_bytes + MULTIPART_OVERHEAD_BYTES;
+    let mut body = web::BytesMut::new();
+    while let Some(chunk) = payload.next().await {
+        let chunk = match chunk {
+            Ok(chunk) => chunk,
+            Err(e) => {
+                log::error!("Error reading upload: {:?}", e);
+                return Ok(HttpResponse::BadRequest().json(ApiError {
+                    error: "invalid_upload".to_string(),
+                    message: "Failed to read upload".to_string(),
+                }));
+            }
+        };
+        if body.len() + chunk.len() > limit {
+            return Ok(HttpResponse::PayloadTooLarge().json(ApiError {
+                error: "file_too_large".to_string(),
+                message: format!("Photos may be at most {} bytes", state.config.max_upload_bytes),
+            }));
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    let data = match attachment_service::parse_multipart(&content_type, &body) {
+        Ok(file) => file.data,
+        Err(e) => {
+            return Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_upload".to_string(),
+                message: e.to_string(),
             }));
         }
     };
+    if data.len() > state.config.max_upload_bytes {
+        return Ok(HttpResponse::PayloadTooLarge().json(ApiError {
+            error: "file_too_large".to_string(),
+            message: format!("Photos may be at most {} bytes", state.config.max_upload_bytes),
+        }));
+    }
+
+    match attachment_service::create_attachment(
+        &state.db,
+        &state.config.upload_dir,
+        &ctx.household_id,
+        &completion_id,
+        &ctx.user_id,
+        data,
+    )
+    .await
+    {
+        Ok(attachment) => Ok(HttpResponse::Created().json(ApiSuccess::new(attachment))),
+        Err(e @ (attachment_service::AttachmentError::UnsupportedType | attachment_service::AttachmentError::TooMany(_))) => {
+            Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "invalid_upload".to_string(),
+                message: e.to_string(),
+            }))
+        }
+        Err(e) => {
+            log::error!("Error storing completion attachment: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to store photo".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/tasks/attachments/{attachment_id}",
+    tag = "tasks",
+    summary = "Get completion attachment",
+    params(("household_id" = Uuid, Path), ("attachment_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", content_type = "application/octet-stream", body = Vec<u8>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn get_completion_attachment(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    let (_, attachment_id_str) = path.into_inner();
 
-    let household_id = match Uuid::parse_str(&path.into_inner()) {
+    let attachment_id = match Uuid::parse_str(&attachment_id_str) {
         Ok(id) => id,
         Err(_) => {
             return Ok(HttpResponse::BadRequest().json(ApiError {
                 error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
+                message: "Invalid attachment ID format".to_string(),
             }));
         }
     };
 
-    // Check if user can manage tasks based on hierarchy type
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
+    match attachment_service::read_attachment(&state.db, &state.config.upload_dir, &ctx.household_id, &attachment_id).await {
+        Ok((attachment, data)) => Ok(HttpResponse::Ok()
+            .content_type(attachment.content_type)
+            .insert_header(("X-Content-Type-Options", "nosniff"))
+            .insert_header(("Cache-Control", "private, max-age=86400"))
+            .body(data)),
+        Err(attachment_service::AttachmentError::NotFound) => Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Attachment not found".to_string(),
+        })),
         Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
+            log::error!("Error reading completion attachment: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
                 error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
+                message: "Failed to read photo".to_string(),
+            }))
         }
-    };
+    }
+}
+
+// ============================================================================
+// Task Suggestion Endpoints
+// ============================================================================
 
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/tasks/suggestions",
+    tag = "tasks",
+    summary = "List suggestions",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<shared::Task>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_suggestions(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
+    // Check if user can manage tasks
+    if !ctx.has_permission(&state, Permission::ManageTasks).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to view suggestions".to_string(),
         }));
     }
 
-    match task_service::list_suggested_tasks(&state.db, &household_id).await {
+    match task_service::list_suggested_tasks(&state.db, &ctx.household_id).await {
         Ok(tasks) => Ok(HttpResponse::Ok().json(ApiSuccess::new(tasks))),
         Err(e) => {
             log::error!("Error listing suggestions: {:?}", e);
@@ -2171,32 +3204,26 @@ async fn list_suggestions(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/{task_id}/approve",
+    tag = "tasks",
+    summary = "Approve suggestion",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<shared::Task>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn approve_suggestion(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, task_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, task_id_str) = path.into_inner();
 
     let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
@@ -2208,20 +3235,8 @@ async fn approve_suggestion(
         }
     };
 
-    // Check if user can manage tasks based on hierarchy type
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    // Check if user can manage tasks
+    if !ctx.has_permission(&state, Permission::ManageTasks).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to approve suggestions".to_string(),
@@ -2234,8 +3249,8 @@ async fn approve_suggestion(
             let details = serde_json::json!({ "title": task.title }).to_string();
             let _ = activity_logs::log_activity(
                 &state.db,
-                &household_id,
-                &user_id,
+                &ctx.household_id,
+                &ctx.user_id,
                 task.suggested_by.as_ref(),
                 ActivityType::TaskCreated, // Task is now active
                 Some("task"),
@@ -2261,32 +3276,26 @@ async fn approve_suggestion(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/{task_id}/deny",
+    tag = "tasks",
+    summary = "Deny suggestion",
+    params(("household_id" = Uuid, Path), ("task_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<shared::Task>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn deny_suggestion(
     state: web::Data<AppState>,
-    req: actix_web::HttpRequest,
+    ctx: HouseholdContext,
     path: web::Path<(String, String)>,
 ) -> Result<HttpResponse> {
-    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::Unauthorized().json(ApiError {
-                error: "unauthorized".to_string(),
-                message: "Invalid or missing token".to_string(),
-            }));
-        }
-    };
-
-    let (household_id_str, task_id_str) = path.into_inner();
-
-    let household_id = match Uuid::parse_str(&household_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            return Ok(HttpResponse::BadRequest().json(ApiError {
-                error: "invalid_id".to_string(),
-                message: "Invalid household ID format".to_string(),
-            }));
-        }
-    };
+    let (_, task_id_str) = path.into_inner();
 
     let task_id = match Uuid::parse_str(&task_id_str) {
         Ok(id) => id,
@@ -2298,20 +3307,8 @@ async fn deny_suggestion(
         }
     };
 
-    // Check if user can manage tasks based on hierarchy type
-    let settings = match household_settings::get_or_create_settings(&state.db, &household_id).await {
-        Ok(s) => s,
-        Err(e) => {
-            log::error!("Error fetching settings: {:?}", e);
-            return Ok(HttpResponse::InternalServerError().json(ApiError {
-                error: "internal_error".to_string(),
-                message: "Failed to fetch household settings".to_string(),
-            }));
-        }
-    };
-
-    let role = household_service::get_member_role(&state.db, &household_id, &user_id).await;
-    if !role.as_ref().map(|r| solo_mode::can_manage_in_context(r, &settings)).unwrap_or(false) {
+    // Check if user can manage tasks
+    if !ctx.has_permission(&state, Permission::ManageTasks).await {
         return Ok(HttpResponse::Forbidden().json(ApiError {
             error: "forbidden".to_string(),
             message: "You do not have permission to deny suggestions".to_string(),
@@ -2336,6 +3333,257 @@ async fn deny_suggestion(
     }
 }
 
+// ============================================================================
+// Workload Rebalancing Endpoints
+// ============================================================================
+
+/// Suggest reassignments that even out the estimated workload of the members
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/rebalance-suggestions",
+    tag = "tasks",
+    summary = "Suggest reassignments that even out the estimated workload of the members",
+    params(("household_id" = Uuid, Path)),
+    request_body = RebalanceRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<RebalanceSuggestion>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn suggest_rebalance(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    body: web::Json<RebalanceRequest>,
+) -> Result<HttpResponse> {
+    if !ctx.has_permission(&state, Permission::ManageTasks).await {
+        return Ok(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "You do not have permission to reassign tasks".to_string(),
+        }));
+    }
+
+
+    let request = body.into_inner();
+    let timezone = crate::services::scheduler::parse_timezone(&ctx.settings.timezone);
+    let start = request.start.unwrap_or_else(|| crate::services::scheduler::today_in_timezone(timezone));
+    let (start, end) = match planning_range(start, request.end, task_rebalance::DEFAULT_REBALANCE_DAYS) {
+        Ok(range) => range,
+        Err(response) => return Ok(response),
+    };
+
+    match task_rebalance::suggest_rebalance(&state.db, &ctx.household_id, &ctx.settings, start, end, &request.exclusions).await {
+        Ok(suggestion) => Ok(HttpResponse::Ok().json(ApiSuccess::new(suggestion))),
+        Err(e) => {
+            log::error!("Error suggesting rebalance: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to suggest assignments".to_string(),
+            }))
+        }
+    }
+}
+
+/// Apply the changes of a rebalance suggestion in one call
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/rebalance-suggestions/apply",
+    tag = "tasks",
+    summary = "Apply the changes of a rebalance suggestion in one call",
+    params(("household_id" = Uuid, Path)),
+    request_body = ApplyRebalanceRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<shared::Task>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn apply_rebalance(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    req: actix_web::HttpRequest,
+    body: web::Json<ApplyRebalanceRequest>,
+) -> Result<HttpResponse> {
+    if !ctx.has_permission(&state, Permission::ManageTasks).await {
+        return Ok(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "You do not have permission to reassign tasks".to_string(),
+        }));
+    }
+
+
+    let changes = body.into_inner().changes;
+    let new_assignees: Vec<Uuid> = changes.iter().map(|c| c.to_user_id).collect();
+    if let Err(response) = validate_assignees(&state, &ctx.household_id, &ctx.settings, &new_assignees).await {
+        return Ok(response);
+    }
+
+    match task_rebalance::apply_rebalance(&state.db, &ctx.household_id, &changes).await {
+        Ok(tasks) => {
+            for (task, change) in tasks.iter().zip(&changes) {
+                notify_added_assignees(&req, &state, &ctx.household_id, &ctx.user_id, task, &[change.to_user_id]).await;
+            }
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(tasks)))
+        }
+        Err(task_rebalance::RebalanceError::TaskNotFound) => Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Task not found".to_string(),
+        })),
+        Err(e @ task_rebalance::RebalanceError::Stale(_)) => Ok(HttpResponse::Conflict().json(ApiError {
+            error: "assignment_changed".to_string(),
+            message: e.to_string(),
+        })),
+        Err(e @ task_rebalance::RebalanceError::Excluded(_)) => Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: e.to_string(),
+        })),
+        Err(e) => {
+            log::error!("Error applying rebalance: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to reassign tasks".to_string(),
+            }))
+        }
+    }
+}
+
+/// Draw random assignees for a set of tasks, returning the plan without applying it
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/randomize-assignments",
+    tag = "tasks",
+    summary = "Draw random assignees for a set of tasks, returning the plan without applying it",
+    params(("household_id" = Uuid, Path)),
+    request_body = RandomizeAssignmentsRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<AssignmentPlan>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn randomize_assignments(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    body: web::Json<RandomizeAssignmentsRequest>,
+) -> Result<HttpResponse> {
+    if !ctx.has_permission(&state, Permission::ManageTasks).await {
+        return Ok(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "You do not have permission to reassign tasks".to_string(),
+        }));
+    }
+
+
+    let request = body.into_inner();
+    if request.task_ids.is_empty() {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "Select at least one task".to_string(),
+        }));
+    }
+    if let Err(response) = validate_assignees(&state, &ctx.household_id, &ctx.settings, &request.member_ids).await {
+        return Ok(response);
+    }
+
+    let timezone = crate::services::scheduler::parse_timezone(&ctx.settings.timezone);
+    let start = request.start.unwrap_or_else(|| crate::services::scheduler::today_in_timezone(timezone));
+    let (start, end) = match planning_range(start, request.end, task_rebalance::DEFAULT_RANDOMIZE_DAYS) {
+        Ok(range) => range,
+        Err(response) => return Ok(response),
+    };
+
+    match task_rebalance::randomize_assignments(&state.db, &ctx.household_id, &ctx.settings, &request, start, end).await {
+        Ok(plan) => Ok(HttpResponse::Ok().json(ApiSuccess::new(plan))),
+        Err(task_rebalance::RebalanceError::TaskNotFound) => Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Task not found".to_string(),
+        })),
+        Err(e @ task_rebalance::RebalanceError::NoEligibleMember(_)) => Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: e.to_string(),
+        })),
+        Err(e) => {
+            log::error!("Error randomizing assignments: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to draw assignments".to_string(),
+            }))
+        }
+    }
+}
+
+/// Apply the assignments of a randomized plan in one call
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/tasks/randomize-assignments/apply",
+    tag = "tasks",
+    summary = "Apply the assignments of a randomized plan in one call",
+    params(("household_id" = Uuid, Path)),
+    request_body = ApplyAssignmentPlanRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<shared::Task>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn apply_assignment_plan(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    req: actix_web::HttpRequest,
+    body: web::Json<ApplyAssignmentPlanRequest>,
+) -> Result<HttpResponse> {
+    if !ctx.has_permission(&state, Permission::ManageTasks).await {
+        return Ok(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "You do not have permission to reassign tasks".to_string(),
+        }));
+    }
+
+
+    let assignments = body.into_inner().assignments;
+    let new_assignees: Vec<Uuid> = assignments.iter().map(|a| a.user_id).collect();
+    if let Err(response) = validate_assignees(&state, &ctx.household_id, &ctx.settings, &new_assignees).await {
+        return Ok(response);
+    }
+
+    match task_rebalance::apply_assignment_plan(&state.db, &ctx.household_id, &assignments).await {
+        Ok(tasks) => {
+            for (task, assignment) in tasks.iter().zip(&assignments) {
+                if !assignment.current_assignee_ids.contains(&assignment.user_id) {
+                    notify_added_assignees(&req, &state, &ctx.household_id, &ctx.user_id, task, &[assignment.user_id]).await;
+                }
+            }
+            Ok(HttpResponse::Ok().json(ApiSuccess::new(tasks)))
+        }
+        Err(task_rebalance::RebalanceError::TaskNotFound) => Ok(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Task not found".to_string(),
+        })),
+        Err(e @ task_rebalance::RebalanceError::Stale(_)) => Ok(HttpResponse::Conflict().json(ApiError {
+            error: "assignment_changed".to_string(),
+            message: e.to_string(),
+        })),
+        Err(e @ task_rebalance::RebalanceError::Excluded(_)) => Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: e.to_string(),
+        })),
+        Err(e) => {
+            log::error!("Error applying assignment plan: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to reassign tasks".to_string(),
+            }))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2351,20 +3599,32 @@ mod tests {
             recurrence_type,
             recurrence_value,
             assigned_user_id: None,
+            assignee_ids: Vec::new(),
             target_count: 1,
             time_period: Some(TimePeriod::Day),
             allow_exceed_target: false,
             requires_review: false,
+            reviewer_type: ReviewerType::AnyReviewer,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: HabitType::Good,
             category_id: None,
             category_name: None,
             suggestion: None,
             suggested_by: None,
+            postponed_from: None,
+            postponed_to: None,
+            tag_ids: Vec::new(),
             archived: false,
             paused: false,
+            version: 1,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -2377,17 +3637,27 @@ mod tests {
             recurrence_type: Some(RecurrenceType::Custom),
             recurrence_value: Some(RecurrenceValue::CustomDates(vec![date])),
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: None,
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             archived: None,
             paused: None,
+            version: None,
         }
     }
 
@@ -2440,17 +3710,27 @@ mod tests {
             recurrence_type: Some(RecurrenceType::Custom),
             recurrence_value: Some(RecurrenceValue::CustomDates(vec![date])),
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: None,
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             archived: None,
             paused: None,
+            version: None,
         };
 
         // Should be rejected - trying to change other fields
@@ -2469,17 +3749,27 @@ mod tests {
             recurrence_type: Some(RecurrenceType::Daily),
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: None,
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             archived: None,
             paused: None,
+            version: None,
         };
 
         // Should be rejected - not setting to Custom type
@@ -2498,17 +3788,27 @@ mod tests {
             recurrence_type: Some(RecurrenceType::Custom),
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: None,
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             archived: None,
             paused: None,
+            version: None,
         };
 
         // Should be rejected - no dates provided
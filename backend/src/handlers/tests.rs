@@ -0,0 +1,1574 @@
+//! Integration tests that run requests through the full app: route wiring,
+//! middleware and extractors against a migrated in-memory database
+
+use actix_web::body::MessageBody;
+use actix_web::dev::{Service, ServiceResponse};
+use actix_web::http::StatusCode;
+use actix_web::middleware::from_fn;
+use actix_web::{test, web, App};
+use serde_json::{json, Value};
+
+use crate::middleware;
+use crate::test_utils::{create_migrated_pool, create_test_app_state};
+
+/// The app as wired in `main.rs`, without CORS, logging and static files
+macro_rules! test_app {
+    ($state:expr) => {
+        test::init_service(
+            App::new()
+                .app_data(web::Data::new($state))
+                .wrap(from_fn(middleware::rate_limit::limit_mutations))
+                .wrap(from_fn(middleware::idempotency::replay_idempotent_requests))
+                .wrap(from_fn(middleware::api_token::authenticate_api_tokens))
+                .wrap(from_fn(middleware::request_id::return_request_id))
+                .wrap(tracing_actix_web::TracingLogger::<middleware::request_id::RequestIdRootSpan>::new())
+                .configure(super::configure_routes),
+        )
+        .await
+    };
+}
+
+/// Send a request and return the status with the JSON body (`Null` if empty)
+async fn send<S, R, B>(app: &S, request: R) -> (StatusCode, Value)
+where
+    S: Service<R, Response = ServiceResponse<B>, Error = actix_web::Error>,
+    B: MessageBody,
+{
+    let response = test::call_service(app, request).await;
+    let status = response.status();
+    let body = test::read_body(response).await;
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+fn get(uri: &str, token: &str) -> test::TestRequest {
+    test::TestRequest::get()
+        .uri(uri)
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+}
+
+fn post(uri: &str, token: &str, body: Value) -> test::TestRequest {
+    test::TestRequest::post()
+        .uri(uri)
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .set_json(body)
+}
+
+/// Register a user and return their access token and ID
+async fn register<S, B>(app: &S, username: &str) -> (String, String)
+where
+    S: Service<actix_http::Request, Response = ServiceResponse<B>, Error = actix_web::Error>,
+    B: MessageBody,
+{
+    let request = test::TestRequest::post()
+        .uri("/api/auth/register")
+        .set_json(json!({
+            "username": username,
+            "email": format!("{}@example.com", username),
+            "password": "correct horse battery",
+        }))
+        .to_request();
+    let (status, body) = send(app, request).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    (
+        body["data"]["token"].as_str().unwrap().to_string(),
+        body["data"]["user"]["id"].as_str().unwrap().to_string(),
+    )
+}
+
+/// Create a household owned by the token's user and return its ID
+async fn create_household<S, B>(app: &S, token: &str, name: &str) -> String
+where
+    S: Service<actix_http::Request, Response = ServiceResponse<B>, Error = actix_web::Error>,
+    B: MessageBody,
+{
+    let (status, body) = send(app, post("/api/households", token, json!({ "name": name })).to_request()).await;
+    assert!(status.is_success(), "{}", body);
+    body["data"]["id"].as_str().unwrap().to_string()
+}
+
+/// Invite `username` to the household and accept the invitation as them
+async fn join_household<S, B>(app: &S, owner_token: &str, household_id: &str, username: &str, token: &str)
+where
+    S: Service<actix_http::Request, Response = ServiceResponse<B>, Error = actix_web::Error>,
+    B: MessageBody,
+{
+    let invite = post(
+        &format!("/api/households/{}/invite", household_id),
+        owner_token,
+        json!({ "email": format!("{}@example.com", username), "role": "member" }),
+    );
+    let (status, body) = send(app, invite.to_request()).await;
+    assert!(status.is_success(), "{}", body);
+
+    let (status, body) = send(app, get("/api/invitations", token).to_request()).await;
+    assert_eq!(status, StatusCode::OK);
+    let invitation_id = body["data"][0]["invitation"]["id"].as_str().unwrap().to_string();
+
+    let accept = post(&format!("/api/invitations/{}/accept", invitation_id), token, json!({}));
+    let (status, body) = send(app, accept.to_request()).await;
+    assert!(status.is_success(), "{}", body);
+}
+
+#[actix_web::test]
+async fn test_auth_flow() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (token, _) = register(&app, "alice").await;
+
+    let (status, body) = send(&app, get("/api/auth/me", &token).to_request()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"]["username"], "alice");
+
+    let request = test::TestRequest::get().uri("/api/auth/me").to_request();
+    assert_eq!(send(&app, request).await.0, StatusCode::UNAUTHORIZED);
+    let request = get("/api/auth/me", "not-a-jwt").to_request();
+    assert_eq!(send(&app, request).await.0, StatusCode::UNAUTHORIZED);
+
+    let login = |password: &str| {
+        test::TestRequest::post()
+            .uri("/api/auth/login")
+            .set_json(json!({ "username": "alice", "password": password }))
+            .to_request()
+    };
+    assert_eq!(send(&app, login("wrong password")).await.0, StatusCode::UNAUTHORIZED);
+    let (status, body) = send(&app, login("correct horse battery")).await;
+    assert_eq!(status, StatusCode::OK);
+    let refresh_token = body["data"]["refresh_token"].as_str().unwrap().to_string();
+
+    // Refresh tokens rotate: the old one is rejected once used
+    let refresh = || {
+        test::TestRequest::post()
+            .uri("/api/auth/refresh")
+            .set_json(json!({ "refresh_token": refresh_token }))
+            .to_request()
+    };
+    let (status, body) = send(&app, refresh()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_ne!(body["data"]["refresh_token"], refresh_token.as_str());
+    assert_eq!(send(&app, refresh()).await.0, StatusCode::UNAUTHORIZED);
+}
+
+#[actix_web::test]
+async fn test_child_account_locks_after_wrong_pins_from_any_address() {
+    let mut state = create_test_app_state(create_migrated_pool().await);
+    state.login_rate_limiter = std::sync::Arc::new(middleware::RateLimiter::new(3, 60));
+    let app = test_app!(state);
+    let (owner, _) = register(&app, "owner").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    let create = post(
+        &format!("/api/households/{}/child-accounts", household_id),
+        &owner,
+        json!({ "username": "kid", "pin": "1234" }),
+    );
+    let (status, body) = send(&app, create.to_request()).await;
+    assert!(status.is_success(), "{}", body);
+
+    let login = |username: &str, password: &str, peer: &str| {
+        test::TestRequest::post()
+            .uri("/api/auth/login")
+            .peer_addr(format!("{}:4711", peer).parse().unwrap())
+            .set_json(json!({ "username": username, "password": password }))
+            .to_request()
+    };
+    for peer in ["203.0.113.1", "203.0.113.2", "203.0.113.3"] {
+        assert_eq!(send(&app, login("kid", "0000", peer)).await.0, StatusCode::UNAUTHORIZED);
+    }
+
+    // Even the right PIN from a fresh address is refused while locked
+    let (status, body) = send(&app, login("kid", "1234", "203.0.113.4")).await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(body["error"], "account_locked");
+
+    // Regular accounts are only limited per address
+    let (status, _) = send(&app, login("owner", "correct horse battery", "203.0.113.4")).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[actix_web::test]
+async fn test_api_tokens_cannot_take_over_accounts() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (session, alice_id) = register(&app, "alice").await;
+    let (status, body) = send(
+        &app,
+        post("/api/users/me/api-tokens", &session, json!({ "name": "script", "read_only": false })).to_request(),
+    )
+    .await;
+    assert!(status.is_success(), "{}", body);
+    let api_token = body["data"]["secret"].as_str().unwrap().to_string();
+
+    let (status, _) = send(&app, post("/api/auth/oidc/link", &api_token, json!({})).to_request()).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let password = json!({ "current_password": "correct horse battery", "new_password": "staple battery horse" });
+    let (status, _) = send(&app, post("/api/users/me/password", &api_token, password).to_request()).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let delete = test::TestRequest::delete()
+        .uri("/api/users/me")
+        .insert_header(("Authorization", format!("Bearer {}", api_token)))
+        .set_json(json!({ "password": "correct horse battery" }));
+    let (status, _) = send(&app, delete.to_request()).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let update = test::TestRequest::put()
+        .uri(&format!("/api/users/{}", alice_id))
+        .insert_header(("Authorization", format!("Bearer {}", api_token)))
+        .set_json(json!({ "email": "mallory@example.com" }));
+    let (status, _) = send(&app, update.to_request()).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // Alice is the first account and so the instance admin, but only with her session
+    let (status, _) = send(&app, get("/api/admin/users", &api_token).to_request()).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = send(&app, get("/api/admin/users", &session).to_request()).await;
+    assert_eq!(status, StatusCode::OK);
+
+    // The token still works where it is meant to
+    let (status, _) = send(&app, get("/api/auth/me", &api_token).to_request()).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[actix_web::test]
+async fn test_household_lifecycle() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let (member, _) = register(&app, "member").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    let household_uri = format!("/api/households/{}", household_id);
+
+    let (status, body) = send(&app, get("/api/households", &owner).to_request()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"][0]["name"], "Home");
+
+    // Outsiders can't see the household
+    assert_eq!(send(&app, get(&household_uri, &member).to_request()).await.0, StatusCode::FORBIDDEN);
+
+    join_household(&app, &owner, &household_id, "member", &member).await;
+    let (status, body) = send(&app, get(&format!("{}/members", household_uri), &member).to_request()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"].as_array().unwrap().len(), 2);
+
+    // Only the owner may delete it
+    let delete = |token: &str| {
+        test::TestRequest::delete()
+            .uri(&household_uri)
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request()
+    };
+    assert_eq!(send(&app, delete(&member)).await.0, StatusCode::FORBIDDEN);
+    let (status, body) = send(&app, delete(&owner)).await;
+    assert!(status.is_success(), "{}", body);
+    let (_, body) = send(&app, get("/api/households", &member).to_request()).await;
+    assert_eq!(body["data"].as_array().unwrap().len(), 0);
+}
+
+#[actix_web::test]
+async fn test_completion_review_flow() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let (member, member_id) = register(&app, "member").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    join_household(&app, &owner, &household_id, "member", &member).await;
+    let tasks_uri = format!("/api/households/{}/tasks", household_id);
+
+    let task = json!({
+        "title": "Take out the trash",
+        "recurrence_type": "daily",
+        "requires_review": true,
+        "points_reward": 10,
+    });
+    let (status, body) = send(&app, post(&tasks_uri, &owner, task).to_request()).await;
+    assert!(status.is_success(), "{}", body);
+    let task_id = body["data"]["id"].as_str().unwrap().to_string();
+
+    // The completion waits for review; a retry with the same key is replayed, not repeated
+    let complete = || {
+        post(&format!("{}/{}/complete", tasks_uri, task_id), &member, json!({}))
+            .insert_header((middleware::idempotency::IDEMPOTENCY_KEY_HEADER, "complete-1"))
+            .to_request()
+    };
+    let (status, body) = send(&app, complete()).await;
+    assert!(status.is_success(), "{}", body);
+    assert_eq!(body["data"]["status"], "pending");
+    let completion_id = body["data"]["id"].as_str().unwrap().to_string();
+    let response = test::call_service(&app, complete()).await;
+    assert_eq!(response.headers().get("Idempotent-Replayed").unwrap(), "true");
+
+    let (status, body) = send(&app, get(&format!("{}/pending-reviews", tasks_uri), &owner).to_request()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"].as_array().unwrap().len(), 1);
+
+    // Members can't review their own completions
+    let approve = |token: &str| post(&format!("{}/completions/{}/approve", tasks_uri, completion_id), token, json!({}));
+    assert_eq!(send(&app, approve(&member).to_request()).await.0, StatusCode::FORBIDDEN);
+    let (status, body) = send(&app, approve(&owner).to_request()).await;
+    assert!(status.is_success(), "{}", body);
+
+    let (_, body) = send(&app, get(&format!("{}/pending-reviews", tasks_uri), &owner).to_request()).await;
+    assert_eq!(body["data"].as_array().unwrap().len(), 0);
+    let (_, body) = send(&app, get(&format!("/api/households/{}/members", household_id), &owner).to_request()).await;
+    let member_entry = body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|m| m["user"]["id"] == member_id.as_str())
+        .unwrap();
+    assert_eq!(member_entry["membership"]["points"], 10);
+}
+
+#[actix_web::test]
+async fn test_review_delegation() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let (alice, alice_id) = register(&app, "alice").await;
+    let (bob, _) = register(&app, "bob").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    join_household(&app, &owner, &household_id, "alice", &alice).await;
+    join_household(&app, &owner, &household_id, "bob", &bob).await;
+    let tasks_uri = format!("/api/households/{}/tasks", household_id);
+    let pending = |token: &str| get(&format!("{}/pending-reviews", tasks_uri), token).to_request();
+    let approve = |completion_id: &str, token: &str| {
+        post(&format!("{}/completions/{}/approve", tasks_uri, completion_id), token, json!({})).to_request()
+    };
+
+    // A specific member needs to be named
+    let task = json!({ "title": "Dishes", "recurrence_type": "daily", "requires_review": true, "reviewer_type": "member" });
+    assert_eq!(send(&app, post(&tasks_uri, &owner, task).to_request()).await.0, StatusCode::BAD_REQUEST);
+
+    let task = json!({
+        "title": "Dishes",
+        "recurrence_type": "daily",
+        "requires_review": true,
+        "reviewer_type": "member",
+        "reviewer_id": alice_id,
+    });
+    let (status, body) = send(&app, post(&tasks_uri, &owner, task).to_request()).await;
+    assert!(status.is_success(), "{}", body);
+    assert_eq!(body["data"]["reviewer_type"], "member");
+    let task_id = body["data"]["id"].as_str().unwrap().to_string();
+
+    let (_, body) = send(&app, post(&format!("{}/{}/complete", tasks_uri, task_id), &bob, json!({})).to_request()).await;
+    let completion_id = body["data"]["id"].as_str().unwrap().to_string();
+
+    // Only the delegated member sees and reviews it, even without the review permission
+    let (status, body) = send(&app, pending(&alice)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"].as_array().unwrap().len(), 1);
+    let (_, body) = send(&app, pending(&owner)).await;
+    assert_eq!(body["data"].as_array().unwrap().len(), 0);
+    assert_eq!(send(&app, approve(&completion_id, &owner)).await.0, StatusCode::FORBIDDEN);
+    let (status, body) = send(&app, approve(&completion_id, &alice)).await;
+    assert!(status.is_success(), "{}", body);
+
+    // Nobody reviews their own completion of an "anyone except completer" task
+    let task = json!({
+        "title": "Laundry",
+        "recurrence_type": "daily",
+        "requires_review": true,
+        "reviewer_type": "anyone_except_completer",
+    });
+    let (_, body) = send(&app, post(&tasks_uri, &owner, task).to_request()).await;
+    let task_id = body["data"]["id"].as_str().unwrap().to_string();
+    let (_, body) = send(&app, post(&format!("{}/{}/complete", tasks_uri, task_id), &owner, json!({})).to_request()).await;
+    assert_eq!(body["data"]["status"], "pending");
+    let completion_id = body["data"]["id"].as_str().unwrap().to_string();
+    let (_, body) = send(&app, pending(&owner)).await;
+    assert_eq!(body["data"].as_array().unwrap().len(), 0);
+    assert_eq!(send(&app, approve(&completion_id, &owner)).await.0, StatusCode::FORBIDDEN);
+    assert_eq!(send(&app, approve(&completion_id, &alice)).await.0, StatusCode::FORBIDDEN);
+}
+
+#[actix_rt::test]
+async fn test_review_delegation_fallback() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let (alice, alice_id) = register(&app, "alice").await;
+    let (bob, _) = register(&app, "bob").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    join_household(&app, &owner, &household_id, "alice", &alice).await;
+    join_household(&app, &owner, &household_id, "bob", &bob).await;
+    let tasks_uri = format!("/api/households/{}/tasks", household_id);
+    let pending = |token: &str| get(&format!("{}/pending-reviews", tasks_uri), token).to_request();
+    let review = |completion_id: &str, action: &str, token: &str| {
+        post(&format!("{}/completions/{}/{}", tasks_uri, completion_id, action), token, json!({})).to_request()
+    };
+    let delegated_task = json!({
+        "title": "Dishes",
+        "recurrence_type": "daily",
+        "requires_review": true,
+        "reviewer_type": "member",
+        "reviewer_id": alice_id,
+    });
+    let (_, body) = send(&app, post(&tasks_uri, &owner, delegated_task.clone()).to_request()).await;
+    let task_id = body["data"]["id"].as_str().unwrap().to_string();
+
+    // The named reviewer cannot review their own completion; the other reviewers take over
+    let (_, body) = send(&app, post(&format!("{}/{}/complete", tasks_uri, task_id), &alice, json!({})).to_request()).await;
+    assert_eq!(body["data"]["status"], "pending");
+    let completion_id = body["data"]["id"].as_str().unwrap().to_string();
+    let (_, body) = send(&app, pending(&alice)).await;
+    assert_eq!(body["data"].as_array().unwrap().len(), 0);
+    assert_eq!(send(&app, review(&completion_id, "approve", &alice)).await.0, StatusCode::FORBIDDEN);
+    assert_eq!(send(&app, review(&completion_id, "reject", &alice)).await.0, StatusCode::FORBIDDEN);
+    let (_, body) = send(&app, pending(&owner)).await;
+    assert_eq!(body["data"].as_array().unwrap().len(), 1);
+    let (status, body) = send(&app, review(&completion_id, "reject", &owner)).await;
+    assert!(status.is_success(), "{}", body);
+
+    // Once the named reviewer left, the review permission decides again
+    let (_, body) = send(&app, post(&tasks_uri, &owner, delegated_task).to_request()).await;
+    let task_id = body["data"]["id"].as_str().unwrap().to_string();
+    let (_, body) = send(&app, post(&format!("{}/{}/complete", tasks_uri, task_id), &bob, json!({})).to_request()).await;
+    let completion_id = body["data"]["id"].as_str().unwrap().to_string();
+    let (status, body) = send(&app, post(&format!("/api/households/{}/leave", household_id), &alice, json!({})).to_request()).await;
+    assert!(status.is_success(), "{}", body);
+    let (_, body) = send(&app, pending(&owner)).await;
+    assert_eq!(body["data"].as_array().unwrap().len(), 1);
+    assert_eq!(send(&app, review(&completion_id, "approve", &bob)).await.0, StatusCode::FORBIDDEN);
+    let (status, body) = send(&app, review(&completion_id, "approve", &owner)).await;
+    assert!(status.is_success(), "{}", body);
+}
+
+#[actix_web::test]
+async fn test_task_occurrences() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let (outsider, _) = register(&app, "outsider").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    let tasks_uri = format!("/api/households/{}/tasks", household_id);
+
+    let task = json!({ "title": "Water the plants", "recurrence_type": "daily" });
+    let (status, body) = send(&app, post(&tasks_uri, &owner, task).to_request()).await;
+    assert!(status.is_success(), "{}", body);
+    let task_id = body["data"]["id"].as_str().unwrap().to_string();
+    let (status, body) = send(&app, post(&format!("{}/{}/complete", tasks_uri, task_id), &owner, json!({})).to_request()).await;
+    assert!(status.is_success(), "{}", body);
+
+    let today = chrono::Utc::now().date_naive();
+    let occurrences_uri = |from: chrono::NaiveDate, to: chrono::NaiveDate| {
+        format!("{}/occurrences?from={}&to={}", tasks_uri, from, to)
+    };
+
+    // The task was created today, so it is due from today on, and today's occurrence is done
+    let uri = occurrences_uri(today - chrono::Duration::days(2), today + chrono::Duration::days(2));
+    let (status, body) = send(&app, get(&uri, &owner).to_request()).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let occurrences = body["data"].as_array().unwrap();
+    assert_eq!(occurrences.len(), 3);
+    assert_eq!(occurrences[0]["date"], today.to_string());
+    assert_eq!(occurrences[0]["completions"], 1);
+    assert_eq!(occurrences[1]["completions"], 0);
+
+    assert_eq!(send(&app, get(&uri, &outsider).to_request()).await.0, StatusCode::FORBIDDEN);
+    let reversed = occurrences_uri(today, today - chrono::Duration::days(1));
+    assert_eq!(send(&app, get(&reversed, &owner).to_request()).await.0, StatusCode::BAD_REQUEST);
+    let too_long = occurrences_uri(today, today + chrono::Duration::days(100));
+    assert_eq!(send(&app, get(&too_long, &owner).to_request()).await.0, StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_task_tags() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let (member, _) = register(&app, "member").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    join_household(&app, &owner, &household_id, "member", &member).await;
+    let tags_uri = format!("/api/households/{}/tags", household_id);
+    let tasks_uri = format!("/api/households/{}/tasks", household_id);
+
+    let (status, body) = send(&app, post(&tags_uri, &owner, json!({ "name": "outside" })).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    let tag_id = body["data"]["id"].as_str().unwrap().to_string();
+    let duplicate = post(&tags_uri, &owner, json!({ "name": "outside" }));
+    assert_eq!(send(&app, duplicate.to_request()).await.0, StatusCode::CONFLICT);
+    let by_member = post(&tags_uri, &member, json!({ "name": "inside" }));
+    assert_eq!(send(&app, by_member.to_request()).await.0, StatusCode::FORBIDDEN);
+
+    let tagged = json!({ "title": "Mow the lawn", "recurrence_type": "daily", "tag_ids": [tag_id] });
+    let (status, body) = send(&app, post(&tasks_uri, &owner, tagged).to_request()).await;
+    assert!(status.is_success(), "{}", body);
+    assert_eq!(body["data"]["tag_ids"], json!([tag_id]));
+    let plain = json!({ "title": "Dishes", "recurrence_type": "daily" });
+    let (status, body) = send(&app, post(&tasks_uri, &owner, plain).to_request()).await;
+    assert!(status.is_success(), "{}", body);
+
+    for list in ["", "/due", "/all"] {
+        let uri = format!("{}{}?tags={}", tasks_uri, list, tag_id);
+        let (status, body) = send(&app, get(&uri, &member).to_request()).await;
+        assert_eq!(status, StatusCode::OK, "{}", body);
+        let items = body["data"].get("items").unwrap_or(&body["data"]).as_array().unwrap().len();
+        assert_eq!(items, 1, "{}: {}", uri, body);
+    }
+    let invalid = format!("{}?tags=garden", tasks_uri);
+    assert_eq!(send(&app, get(&invalid, &member).to_request()).await.0, StatusCode::BAD_REQUEST);
+
+    let delete = test::TestRequest::delete()
+        .uri(&format!("{}/{}", tags_uri, tag_id))
+        .insert_header(("Authorization", format!("Bearer {}", owner)));
+    assert_eq!(send(&app, delete.to_request()).await.0, StatusCode::NO_CONTENT);
+    let (_, body) = send(&app, get(&format!("{}?tags={}", tasks_uri, tag_id), &member).to_request()).await;
+    assert_eq!(body["data"]["total"], 0);
+}
+
+#[actix_web::test]
+async fn test_saved_filters_and_list_filters() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, owner_id) = register(&app, "owner").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    let tasks_uri = format!("/api/households/{}/tasks", household_id);
+    let filters_uri = format!("/api/households/{}/saved-filters", household_id);
+
+    let mine = json!({ "title": "Laundry", "recurrence_type": "daily", "assignee_ids": [owner_id] });
+    let (status, body) = send(&app, post(&tasks_uri, &owner, mine).to_request()).await;
+    assert!(status.is_success(), "{}", body);
+    let bad = json!({ "title": "Snacking", "recurrence_type": "daily", "habit_type": "bad" });
+    let (status, body) = send(&app, post(&tasks_uri, &owner, bad).to_request()).await;
+    assert!(status.is_success(), "{}", body);
+
+    let filter = json!({ "name": "Mine", "filter": { "assignee_id": owner_id, "habit_type": "good" } });
+    let (status, body) = send(&app, post(&filters_uri, &owner, filter).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    let saved: shared::SavedTaskFilter = serde_json::from_value(body["data"].clone()).unwrap();
+    let (_, body) = send(&app, get(&filters_uri, &owner).to_request()).await;
+    assert_eq!(body["data"].as_array().unwrap().len(), 1);
+
+    // The saved filter's query parameters select only the assigned good habit
+    let uri = format!("{}?{}", tasks_uri, saved.filter.to_query_params().join("&"));
+    let (status, body) = send(&app, get(&uri, &owner).to_request()).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["data"]["total"], 1);
+    assert_eq!(body["data"]["items"][0]["title"], "Laundry");
+
+    let (_, body) = send(&app, get(&format!("{}/all?habit_type=bad", tasks_uri), &owner).to_request()).await;
+    assert_eq!(body["data"]["items"][0]["task"]["title"], "Snacking");
+    let invalid = format!("{}?habit_type=neutral", tasks_uri);
+    assert_eq!(send(&app, get(&invalid, &owner).to_request()).await.0, StatusCode::BAD_REQUEST);
+    let too_far = format!("{}?due_within={}", tasks_uri, i64::MAX);
+    assert_eq!(send(&app, get(&too_far, &owner).to_request()).await.0, StatusCode::BAD_REQUEST);
+    let filter = json!({ "name": "Forever", "filter": { "due_within_days": i64::MAX } });
+    assert_eq!(send(&app, post(&filters_uri, &owner, filter).to_request()).await.0, StatusCode::BAD_REQUEST);
+
+    let delete = test::TestRequest::delete()
+        .uri(&format!("{}/{}", filters_uri, saved.id))
+        .insert_header(("Authorization", format!("Bearer {}", owner)));
+    assert_eq!(send(&app, delete.to_request()).await.0, StatusCode::NO_CONTENT);
+}
+
+#[actix_rt::test]
+async fn test_task_effort_and_workload() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, owner_id) = register(&app, "owner").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    let tasks_uri = format!("/api/households/{}/tasks", household_id);
+
+    let too_long = json!({ "title": "Spring cleaning", "recurrence_type": "daily", "effort_minutes": 5000 });
+    assert_eq!(send(&app, post(&tasks_uri, &owner, too_long).to_request()).await.0, StatusCode::BAD_REQUEST);
+    let task = json!({ "title": "Vacuum", "recurrence_type": "daily", "assigned_user_id": owner_id, "effort_minutes": 20 });
+    let (status, body) = send(&app, post(&tasks_uri, &owner, task).to_request()).await;
+    assert!(status.is_success(), "{}", body);
+    assert_eq!(body["data"]["effort_minutes"], 20);
+
+    let today = chrono::Utc::now().date_naive();
+    let workload_uri = format!(
+        "/api/households/{}/statistics/workload?start={}&end={}",
+        household_id,
+        today,
+        today + chrono::Duration::days(2)
+    );
+    let (status, body) = send(&app, get(&workload_uri, &owner).to_request()).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let members = body["data"]["members"].as_array().unwrap();
+    assert_eq!(members[0]["task_count"], 3);
+    assert_eq!(members[0]["effort_minutes"], 60);
+
+    // Dates at the edge of chrono's range are rejected instead of overflowing
+    for report in ["workload", "report"] {
+        let uri = format!(
+            "/api/households/{}/statistics/{}?start=%2B262142-12-30&end=%2B262142-12-31",
+            household_id, report
+        );
+        assert_eq!(send(&app, get(&uri, &owner).to_request()).await.0, StatusCode::BAD_REQUEST);
+    }
+}
+
+#[actix_rt::test]
+async fn test_task_season() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    let tasks_uri = format!("/api/households/{}/tasks", household_id);
+
+    let invalid = json!({ "title": "Mow the lawn", "recurrence_type": "weekly", "active_from": "04-31" });
+    assert_eq!(send(&app, post(&tasks_uri, &owner, invalid).to_request()).await.0, StatusCode::BAD_REQUEST);
+    let task = json!({ "title": "Mow the lawn", "recurrence_type": "weekly", "active_from": "04-01", "active_until": "10-31" });
+    let (status, body) = send(&app, post(&tasks_uri, &owner, task).to_request()).await;
+    assert!(status.is_success(), "{}", body);
+    assert_eq!(body["data"]["active_from"], "04-01");
+    assert_eq!(body["data"]["active_until"], "10-31");
+
+    // A null clears the season, a missing field keeps it
+    let update = test::TestRequest::put()
+        .uri(&format!("{}/{}", tasks_uri, body["data"]["id"].as_str().unwrap()))
+        .insert_header(("Authorization", format!("Bearer {}", owner)))
+        .set_json(json!({ "active_from": null }));
+    let (status, body) = send(&app, update.to_request()).await;
+    assert!(status.is_success(), "{}", body);
+    assert_eq!(body["data"]["active_from"], Value::Null);
+    assert_eq!(body["data"]["active_until"], "10-31");
+}
+
+#[actix_rt::test]
+async fn test_task_end() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    let tasks_uri = format!("/api/households/{}/tasks", household_id);
+
+    let invalid = json!({ "title": "Physiotherapy", "recurrence_type": "daily", "max_occurrences": 0 });
+    assert_eq!(send(&app, post(&tasks_uri, &owner, invalid).to_request()).await.0, StatusCode::BAD_REQUEST);
+    let task = json!({ "title": "Physiotherapy", "recurrence_type": "daily", "ends_on": "2030-06-30", "max_occurrences": 20 });
+    let (status, body) = send(&app, post(&tasks_uri, &owner, task).to_request()).await;
+    assert!(status.is_success(), "{}", body);
+    assert_eq!(body["data"]["ends_on"], "2030-06-30");
+    assert_eq!(body["data"]["max_occurrences"], 20);
+
+    let update = test::TestRequest::put()
+        .uri(&format!("{}/{}", tasks_uri, body["data"]["id"].as_str().unwrap()))
+        .insert_header(("Authorization", format!("Bearer {}", owner)))
+        .set_json(json!({ "ends_on": null }));
+    let (status, body) = send(&app, update.to_request()).await;
+    assert!(status.is_success(), "{}", body);
+    assert_eq!(body["data"]["ends_on"], Value::Null);
+    assert_eq!(body["data"]["max_occurrences"], 20);
+}
+
+#[actix_rt::test]
+async fn test_focus_sessions() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    let tasks_uri = format!("/api/households/{}/tasks", household_id);
+    let focus_uri = format!("/api/households/{}/focus-sessions", household_id);
+
+    let (_, body) = send(&app, post(&tasks_uri, &owner, json!({ "title": "Study", "recurrence_type": "daily" })).to_request()).await;
+    let task_id = body["data"]["id"].as_str().unwrap().to_string();
+
+    let (status, body) = send(&app, get("/api/users/me/focus-session", &owner).to_request()).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["data"], Value::Null);
+
+    let start = json!({ "task_id": task_id, "planned_minutes": 50 });
+    let (status, body) = send(&app, post(&focus_uri, &owner, start.clone()).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    assert_eq!(body["data"]["status"], "running");
+    assert_eq!(body["data"]["task_title"], "Study");
+    assert_eq!(send(&app, post(&focus_uri, &owner, start).to_request()).await.0, StatusCode::CONFLICT);
+
+    // The running session is still there after a reload
+    let (_, body) = send(&app, get("/api/users/me/focus-session", &owner).to_request()).await;
+    assert_eq!(body["data"]["planned_minutes"], 50);
+
+    let (status, body) = send(&app, post(&format!("{}/current/stop", focus_uri), &owner, json!({})).to_request()).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["data"]["status"], "completed");
+    let cancel = post(&format!("{}/current/cancel", focus_uri), &owner, json!({}));
+    assert_eq!(send(&app, cancel.to_request()).await.0, StatusCode::NOT_FOUND);
+
+    let (_, body) = send(&app, get(&format!("{}/{}/details", tasks_uri, task_id), &owner).to_request()).await;
+    assert_eq!(body["data"]["statistics"]["focus_minutes_all_time"], 0);
+}
+
+#[actix_rt::test]
+async fn test_inventory() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let (outsider, _) = register(&app, "outsider").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    let inventory_uri = format!("/api/households/{}/inventory", household_id);
+
+    let paper = json!({ "name": "Toilet paper", "quantity": 3, "location": "Bathroom", "min_quantity": 2 });
+    let (status, body) = send(&app, post(&inventory_uri, &owner, paper).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    let item_id = body["data"]["id"].as_str().unwrap().to_string();
+    assert_eq!(send(&app, get(&inventory_uri, &outsider).to_request()).await.0, StatusCode::FORBIDDEN);
+    let invalid = json!({ "name": "Soap", "quantity": -1 });
+    assert_eq!(send(&app, post(&inventory_uri, &owner, invalid).to_request()).await.0, StatusCode::BAD_REQUEST);
+
+    let adjust_uri = format!("{}/{}/adjust", inventory_uri, item_id);
+    let (status, body) = send(&app, post(&adjust_uri, &owner, json!({ "delta": -2 })).to_request()).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["data"]["item"]["quantity"], 1.0);
+    assert_eq!(body["data"]["added_to_shopping_list"], true);
+
+    let (_, body) = send(&app, get(&format!("/api/households/{}/shopping-list", household_id), &owner).to_request()).await;
+    assert_eq!(body["data"][0]["name"], "Toilet paper");
+
+    let (status, body) = send(&app, get(&inventory_uri, &owner).to_request()).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["data"].as_array().unwrap().len(), 1);
+}
+
+#[actix_rt::test]
+async fn test_appliance_maintenance() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let (member, _) = register(&app, "member").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    join_household(&app, &owner, &household_id, "member", &member).await;
+    let appliances_uri = format!("/api/households/{}/appliances", household_id);
+
+    let machine = json!({ "name": "Coffee machine", "purchased_on": "2024-03-01", "warranty_until": "2026-03-01" });
+    let (status, body) = send(&app, post(&appliances_uri, &member, machine).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    let appliance_id = body["data"]["id"].as_str().unwrap().to_string();
+
+    // Maintenance reminders are tasks, so plain members cannot add them
+    let maintenance_uri = format!("{}/{}/maintenance", appliances_uri, appliance_id);
+    let descale = json!({ "title": "Descale", "interval_days": 90 });
+    assert_eq!(send(&app, post(&maintenance_uri, &member, descale.clone()).to_request()).await.0, StatusCode::FORBIDDEN);
+    let (status, body) = send(&app, post(&maintenance_uri, &owner, descale).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    let task_id = body["data"]["maintenance"][0]["task_id"].as_str().unwrap().to_string();
+
+    let (_, body) = send(&app, get(&format!("/api/households/{}/tasks/{}", household_id, task_id), &owner).to_request()).await;
+    assert_eq!(body["data"]["task"]["title"], "Coffee machine: Descale");
+    assert_eq!(body["data"]["task"]["recurrence_value"]["DayInterval"], 90);
+
+    let zero = json!({ "title": "Clean", "interval_days": 0 });
+    assert_eq!(send(&app, post(&maintenance_uri, &owner, zero).to_request()).await.0, StatusCode::BAD_REQUEST);
+
+    let delete = test::TestRequest::delete()
+        .uri(&format!("{}/{}", appliances_uri, appliance_id))
+        .insert_header(("Authorization", format!("Bearer {}", owner)));
+    assert_eq!(send(&app, delete.to_request()).await.0, StatusCode::NO_CONTENT);
+    let (status, _) = send(&app, get(&format!("/api/households/{}/tasks/{}", household_id, task_id), &owner).to_request()).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[actix_rt::test]
+async fn test_plant_care_tasks() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let (member, _) = register(&app, "member").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    join_household(&app, &owner, &household_id, "member", &member).await;
+    let plants_uri = format!("/api/households/{}/plants", household_id);
+
+    // Care reminders are tasks, so plain members cannot add plants
+    let fern = json!({ "name": "Fern", "location": "Bathroom", "water_interval_days": 4 });
+    assert_eq!(send(&app, post(&plants_uri, &member, fern.clone()).to_request()).await.0, StatusCode::FORBIDDEN);
+    let (status, body) = send(&app, post(&plants_uri, &owner, fern).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    let plant_id = body["data"]["id"].as_str().unwrap().to_string();
+    let task_id = body["data"]["water_task_id"].as_str().unwrap().to_string();
+    assert!(body["data"]["fertilize_task_id"].is_null());
+
+    let task_uri = format!("/api/households/{}/tasks/{}", household_id, task_id);
+    let (_, body) = send(&app, get(&task_uri, &owner).to_request()).await;
+    assert_eq!(body["data"]["task"]["title"], "Water Fern");
+    assert_eq!(body["data"]["task"]["recurrence_value"]["DayInterval"], 4);
+
+    // Any member can water it, which is recorded on the plant
+    let (status, body) = send(&app, post(&format!("{}/complete", task_uri), &member, json!({})).to_request()).await;
+    assert!(status.is_success(), "{}", body);
+    let (_, body) = send(&app, get(&format!("{}/{}", plants_uri, plant_id), &member).to_request()).await;
+    assert!(body["data"]["last_watered_at"].is_string(), "{}", body);
+    assert!(body["data"]["last_fertilized_at"].is_null());
+
+    let invalid = json!({ "fertilize_interval_days": 0 });
+    let request = test::TestRequest::put()
+        .uri(&format!("{}/{}", plants_uri, plant_id))
+        .insert_header(("Authorization", format!("Bearer {}", owner)))
+        .set_json(invalid);
+    assert_eq!(send(&app, request.to_request()).await.0, StatusCode::BAD_REQUEST);
+
+    let delete = test::TestRequest::delete()
+        .uri(&format!("{}/{}", plants_uri, plant_id))
+        .insert_header(("Authorization", format!("Bearer {}", owner)));
+    assert_eq!(send(&app, delete.to_request()).await.0, StatusCode::NO_CONTENT);
+    assert_eq!(send(&app, get(&task_uri, &owner).to_request()).await.0, StatusCode::NOT_FOUND);
+}
+
+#[actix_rt::test]
+async fn test_pet_feeding_log() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, owner_id) = register(&app, "owner").await;
+    let (member, _) = register(&app, "member").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    join_household(&app, &owner, &household_id, "member", &member).await;
+    let pets_uri = format!("/api/households/{}/pets", household_id);
+
+    let (status, body) = send(&app, post(&pets_uri, &member, json!({ "name": "Bello", "species": "Dog" })).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    let pet_id = body["data"]["id"].as_str().unwrap().to_string();
+
+    // Schedules are tasks, so plain members cannot add them
+    let schedules_uri = format!("{}/{}/schedules", pets_uri, pet_id);
+    let feeding = json!({ "kind": "feeding", "times_per_day": 2 });
+    assert_eq!(send(&app, post(&schedules_uri, &member, feeding.clone()).to_request()).await.0, StatusCode::FORBIDDEN);
+    let (status, body) = send(&app, post(&schedules_uri, &owner, feeding).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    let task_id = body["data"]["schedules"][0]["task_id"].as_str().unwrap().to_string();
+
+    // Completing the task from the dashboard lands in the log and earns points
+    let task_uri = format!("/api/households/{}/tasks/{}", household_id, task_id);
+    let (status, body) = send(&app, post(&format!("{}/complete", task_uri), &owner, json!({})).to_request()).await;
+    assert!(status.is_success(), "{}", body);
+    let (_, body) = send(&app, get(&format!("{}/{}", pets_uri, pet_id), &member).to_request()).await;
+    assert_eq!(body["data"]["recent_log"][0]["user_id"], owner_id.as_str());
+    assert_eq!(body["data"]["recent_log"][0]["title"], "Feed Bello");
+
+    let log_uri = format!("{}/{}", schedules_uri, task_id) + "/log";
+    let (status, body) = send(&app, post(&log_uri, &member, json!({})).to_request()).await;
+    assert_eq!(status, StatusCode::CONFLICT, "{}", body);
+    assert_eq!(body["error"], "recently_logged");
+    let (status, body) = send(&app, post(&log_uri, &member, json!({ "force": true })).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    assert_eq!(body["data"]["username"], "member");
+
+    let (_, body) = send(&app, get(&format!("{}/{}/log", pets_uri, pet_id), &owner).to_request()).await;
+    assert_eq!(body["data"].as_array().unwrap().len(), 2);
+}
+
+#[actix_rt::test]
+async fn test_allowance_payouts() {
+    let pool = create_migrated_pool().await;
+    let app = test_app!(create_test_app_state(pool.clone()));
+    let (owner, _) = register(&app, "owner").await;
+    let (child, child_id) = register(&app, "child").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    join_household(&app, &owner, &household_id, "child", &child).await;
+
+    let settings = test::TestRequest::put()
+        .uri(&format!("/api/households/{}/settings", household_id))
+        .insert_header(("Authorization", format!("Bearer {}", owner)))
+        .set_json(json!({ "allowance_cents_per_point": 5, "allowance_point_handling": "reset" }));
+    let (status, body) = send(&app, settings.to_request()).await;
+    assert!(status.is_success(), "{}", body);
+    assert_eq!(body["data"]["allowance_cents_per_point"], 5);
+
+    // Rates are capped so payouts cannot overflow
+    let capped = test::TestRequest::put()
+        .uri(&format!("/api/households/{}/settings", household_id))
+        .insert_header(("Authorization", format!("Bearer {}", owner)))
+        .set_json(json!({ "allowance_cents_per_point": i64::MAX }));
+    let (_, body) = send(&app, capped.to_request()).await;
+    assert_eq!(body["data"]["allowance_cents_per_point"], shared::MAX_ALLOWANCE_CENTS_PER_POINT);
+    let settings = test::TestRequest::put()
+        .uri(&format!("/api/households/{}/settings", household_id))
+        .insert_header(("Authorization", format!("Bearer {}", owner)))
+        .set_json(json!({ "allowance_cents_per_point": 5 }));
+    let (status, _) = send(&app, settings.to_request()).await;
+    assert!(status.is_success());
+
+    sqlx::query("UPDATE household_memberships SET points = 40 WHERE user_id = ?")
+        .bind(&child_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let report = crate::services::allowance::process_allowance_payouts(&pool, chrono::Utc::now() + chrono::Duration::days(40))
+        .await
+        .unwrap();
+    assert_eq!(report.payouts_created, 1);
+
+    let payouts_uri = format!("/api/households/{}/allowance/payouts", household_id);
+    let (status, body) = send(&app, get(&payouts_uri, &owner).to_request()).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["data"][0]["user_id"], child_id.as_str());
+    assert_eq!(body["data"][0]["amount_cents"], 200);
+    let (_, body) = send(&app, get(&payouts_uri, &child).to_request()).await;
+    assert_eq!(body["data"].as_array().unwrap().len(), 1);
+}
+
+#[actix_rt::test]
+async fn test_share_links() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let (member, _) = register(&app, "member").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    join_household(&app, &owner, &household_id, "member", &member).await;
+    let shopping_uri = format!("/api/households/{}/shopping-list", household_id);
+    let (status, _) = send(&app, post(&shopping_uri, &owner, json!({ "name": "Milk" })).to_request()).await;
+    assert!(status.is_success());
+
+    // Links hand data to outsiders, so plain members cannot create them
+    let links_uri = format!("/api/households/{}/share-links", household_id);
+    let request = json!({ "resource": "shopping_list", "expires_in_days": 7 });
+    assert_eq!(send(&app, post(&links_uri, &member, request.clone()).to_request()).await.0, StatusCode::FORBIDDEN);
+    let (status, body) = send(&app, post(&links_uri, &owner, request).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    let link_id = body["data"]["id"].as_str().unwrap().to_string();
+    let token = body["data"]["token"].as_str().unwrap().to_string();
+
+    // Guests need no account
+    let shared_uri = format!("/api/shared/{}", token);
+    let (status, body) = send(&app, test::TestRequest::get().uri(&shared_uri).to_request()).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["data"]["household_name"], "Home");
+    assert_eq!(body["data"]["content"]["resource"], "shopping_list");
+    assert_eq!(body["data"]["content"]["data"][0]["name"], "Milk");
+
+    // The token does not open the rest of the household
+    let (status, _) = send(
+        &app,
+        test::TestRequest::get()
+            .uri(&shopping_uri)
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let delete = test::TestRequest::delete()
+        .uri(&format!("{}/{}", links_uri, link_id))
+        .insert_header(("Authorization", format!("Bearer {}", owner)));
+    assert_eq!(send(&app, delete.to_request()).await.0, StatusCode::NO_CONTENT);
+    let (status, body) = send(&app, test::TestRequest::get().uri(&shared_uri).to_request()).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"], "not_found");
+}
+
+#[actix_rt::test]
+async fn test_disabled_features() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let household_id = create_household(&app, &owner, "Flat").await;
+    let journal_uri = format!("/api/households/{}/journal", household_id);
+    assert_eq!(send(&app, get(&journal_uri, &owner).to_request()).await.0, StatusCode::OK);
+    let chat_uri = format!("/api/households/{}/chat", household_id);
+    let (status, body) = send(&app, get(&chat_uri, &owner).to_request()).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["error"], "feature_disabled");
+
+    let settings = test::TestRequest::put()
+        .uri(&format!("/api/households/{}/settings", household_id))
+        .insert_header(("Authorization", format!("Bearer {}", owner)))
+        .set_json(json!({ "disabled_features": ["journal", "meals", "journal"] }));
+    let (status, body) = send(&app, settings.to_request()).await;
+    assert!(status.is_success(), "{}", body);
+    assert_eq!(body["data"]["disabled_features"], json!(["journal", "meals"]));
+
+    let (status, body) = send(&app, get(&journal_uri, &owner).to_request()).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["error"], "feature_disabled");
+    // Recipes belong to the meal plan
+    let recipes_uri = format!("/api/households/{}/recipes", household_id);
+    assert_eq!(send(&app, get(&recipes_uri, &owner).to_request()).await.0, StatusCode::FORBIDDEN);
+    let notes_uri = format!("/api/households/{}/notes", household_id);
+    assert_eq!(send(&app, get(&notes_uri, &owner).to_request()).await.0, StatusCode::OK);
+
+    // Outsiders learn nothing about which modules are switched off
+    let (stranger, _) = register(&app, "stranger").await;
+    let (status, body) = send(&app, get(&journal_uri, &stranger).to_request()).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["error"], "forbidden");
+
+    // Rewards, punishments and chat start off and are switched on like any other module
+    assert_eq!(send(&app, get(&chat_uri, &owner).to_request()).await.0, StatusCode::OK);
+}
+
+#[actix_rt::test]
+async fn test_attachments_follow_the_owning_module() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let household_id = create_household(&app, &owner, "Flat").await;
+    let note = json!({ "title": "Fridge", "content": "Warranty attached", "is_shared": true });
+    let (status, body) = send(&app, post(&format!("/api/households/{}/notes", household_id), &owner, note).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    let attachments_uri = format!("/api/households/{}/attachments/note/{}", household_id, body["data"]["id"].as_str().unwrap());
+
+    let upload = test::TestRequest::post()
+        .uri(&attachments_uri)
+        .insert_header(("Authorization", format!("Bearer {}", owner)))
+        .insert_header(("Content-Type", "multipart/form-data; boundary=XYZ"))
+        .set_payload(
+            "--XYZ\r\nContent-Disposition: form-data; name=\"file\"; filename=\"warranty.txt\"\r\n\r\nValid until 2027\r\n--XYZ--\r\n",
+        );
+    let (status, body) = send(&app, upload.to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    let attachment_uri = format!("/api/households/{}/attachments/{}", household_id, body["data"]["id"].as_str().unwrap());
+    assert_eq!(send(&app, get(&attachment_uri, &owner).to_request()).await.0, StatusCode::OK);
+
+    let settings = test::TestRequest::put()
+        .uri(&format!("/api/households/{}/settings", household_id))
+        .insert_header(("Authorization", format!("Bearer {}", owner)))
+        .set_json(json!({ "disabled_features": ["notes"] }));
+    let (status, body) = send(&app, settings.to_request()).await;
+    assert!(status.is_success(), "{}", body);
+
+    for uri in [&attachments_uri, &attachment_uri] {
+        let (status, body) = send(&app, get(uri, &owner).to_request()).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["error"], "feature_disabled");
+    }
+    let delete = test::TestRequest::delete()
+        .uri(&attachment_uri)
+        .insert_header(("Authorization", format!("Bearer {}", owner)));
+    assert_eq!(send(&app, delete.to_request()).await.0, StatusCode::FORBIDDEN);
+}
+
+#[actix_rt::test]
+async fn test_items_of_other_households_are_not_found() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (alice, _) = register(&app, "alice").await;
+    let (bob, _) = register(&app, "bob").await;
+    let alice_household = create_household(&app, &alice, "Alice's").await;
+    let bob_household = create_household(&app, &bob, "Bob's").await;
+
+    let condition = json!({ "name": "Bonus", "condition_type": "task_complete", "points_value": 5 });
+    let uri = format!("/api/households/{}/point-conditions", alice_household);
+    let (status, body) = send(&app, post(&uri, &alice, condition).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    let condition_id = body["data"]["id"].as_str().unwrap().to_string();
+
+    let announcement = json!({ "title": "Rent is due" });
+    let uri = format!("/api/households/{}/announcements", alice_household);
+    let (status, body) = send(&app, post(&uri, &alice, announcement).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    let announcement_id = body["data"]["id"].as_str().unwrap().to_string();
+
+    // Bob owns his own household but can't reach Alice's items through it
+    for uri in [
+        format!("/api/households/{}/point-conditions/{}", bob_household, condition_id),
+        format!("/api/households/{}/announcements/{}", bob_household, announcement_id),
+    ] {
+        assert_eq!(send(&app, get(&uri, &bob).to_request()).await.0, StatusCode::NOT_FOUND);
+        let delete = test::TestRequest::delete()
+            .uri(&uri)
+            .insert_header(("Authorization", format!("Bearer {}", bob)));
+        assert_eq!(send(&app, delete.to_request()).await.0, StatusCode::NOT_FOUND);
+    }
+
+    let uri = format!("/api/households/{}/point-conditions/{}", alice_household, condition_id);
+    assert_eq!(send(&app, get(&uri, &alice).to_request()).await.0, StatusCode::OK);
+    let uri = format!("/api/households/{}/announcements/{}", alice_household, announcement_id);
+    assert_eq!(send(&app, get(&uri, &alice).to_request()).await.0, StatusCode::OK);
+}
+
+#[actix_rt::test]
+async fn test_rebalance_suggestions() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, owner_id) = register(&app, "owner").await;
+    let (member, member_id) = register(&app, "member").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    join_household(&app, &owner, &household_id, "member", &member).await;
+    let tasks_uri = format!("/api/households/{}/tasks", household_id);
+
+    for (title, effort) in [("Vacuum", 30), ("Dishes", 20)] {
+        let task = json!({ "title": title, "recurrence_type": "daily", "assigned_user_id": owner_id, "effort_minutes": effort });
+        let (status, body) = send(&app, post(&tasks_uri, &owner, task).to_request()).await;
+        assert!(status.is_success(), "{}", body);
+    }
+
+    let suggest_uri = format!("{}/rebalance-suggestions", tasks_uri);
+    assert_eq!(send(&app, post(&suggest_uri, &member, json!({})).to_request()).await.0, StatusCode::FORBIDDEN);
+    for start in ["+262142-12-31", "-262143-01-01"] {
+        let (status, _) = send(&app, post(&suggest_uri, &owner, json!({ "start": start })).to_request()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+    let (status, body) = send(&app, post(&suggest_uri, &owner, json!({})).to_request()).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let changes = body["data"]["changes"].clone();
+    assert_eq!(changes.as_array().unwrap().len(), 1);
+    assert_eq!(changes[0]["task_title"], "Dishes");
+    assert_eq!(changes[0]["to_user_id"], member_id.as_str());
+
+    // An excluded member gets nothing
+    let excluded = json!({ "exclusions": [{ "user_id": member_id }] });
+    let (_, body) = send(&app, post(&suggest_uri, &owner, excluded).to_request()).await;
+    assert_eq!(body["data"]["changes"], json!([]));
+
+    let apply_uri = format!("{}/apply", suggest_uri);
+    let (status, body) = send(&app, post(&apply_uri, &owner, json!({ "changes": changes })).to_request()).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["data"][0]["assigned_user_id"], member_id.as_str());
+    // The suggestion is stale once applied
+    let (status, _) = send(&app, post(&apply_uri, &owner, json!({ "changes": changes })).to_request()).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+}
+
+#[actix_rt::test]
+async fn test_randomize_assignments() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, owner_id) = register(&app, "owner").await;
+    let (member, member_id) = register(&app, "member").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    join_household(&app, &owner, &household_id, "member", &member).await;
+    let tasks_uri = format!("/api/households/{}/tasks", household_id);
+
+    let mut task_ids = Vec::new();
+    for title in ["Vacuum", "Dishes", "Laundry", "Trash"] {
+        let task = json!({ "title": title, "recurrence_type": "weekly", "assigned_user_id": owner_id });
+        let (_, body) = send(&app, post(&tasks_uri, &owner, task).to_request()).await;
+        task_ids.push(body["data"]["id"].as_str().unwrap().to_string());
+    }
+
+    let randomize_uri = format!("{}/randomize-assignments", tasks_uri);
+    let request = json!({ "task_ids": task_ids });
+    assert_eq!(send(&app, post(&randomize_uri, &member, request.clone()).to_request()).await.0, StatusCode::FORBIDDEN);
+    let (status, _) = send(&app, post(&randomize_uri, &owner, json!({ "task_ids": [] })).to_request()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let far_future = json!({ "task_ids": task_ids, "start": "+262142-12-31" });
+    let (status, _) = send(&app, post(&randomize_uri, &owner, far_future).to_request()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // Dealt out evenly, and nothing changes before the plan is applied
+    let (status, body) = send(&app, post(&randomize_uri, &owner, request).to_request()).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let assignments = body["data"]["assignments"].clone();
+    assert_eq!(assignments.as_array().unwrap().len(), 4);
+    let to_member = assignments.as_array().unwrap().iter().filter(|a| a["user_id"] == member_id.as_str()).count();
+    assert_eq!(to_member, 2);
+    let (_, body) = send(&app, get(&format!("{}/{}", tasks_uri, task_ids[0]), &owner).to_request()).await;
+    assert_eq!(body["data"]["task"]["assigned_user_id"], owner_id.as_str());
+
+    // Only the selected members are drawn
+    let request = json!({ "task_ids": task_ids, "member_ids": [member_id], "weighted": true });
+    let (_, body) = send(&app, post(&randomize_uri, &owner, request).to_request()).await;
+    assert!(body["data"]["assignments"].as_array().unwrap().iter().all(|a| a["user_id"] == member_id.as_str()));
+
+    let apply_uri = format!("{}/apply", randomize_uri);
+    let (status, body) = send(&app, post(&apply_uri, &owner, json!({ "assignments": assignments })).to_request()).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let (_, body) = send(&app, get(&format!("{}/assigned-to-me", tasks_uri), &member).to_request()).await;
+    assert_eq!(body["data"].as_array().unwrap().len(), 2);
+    // The plan is stale once applied
+    let (status, _) = send(&app, post(&apply_uri, &owner, json!({ "assignments": assignments })).to_request()).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+}
+
+#[actix_rt::test]
+async fn test_member_task_exclusions() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, owner_id) = register(&app, "owner").await;
+    let (child, child_id) = register(&app, "child").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    join_household(&app, &owner, &household_id, "child", &child).await;
+    let tasks_uri = format!("/api/households/{}/tasks", household_id);
+
+    let task = json!({ "title": "Bake bread", "recurrence_type": "daily", "effort_minutes": 30 });
+    let (_, body) = send(&app, post(&tasks_uri, &owner, task).to_request()).await;
+    let baking_id = body["data"]["id"].as_str().unwrap().to_string();
+    let task = json!({ "title": "Dusting", "recurrence_type": "daily", "assigned_user_id": owner_id, "effort_minutes": 30 });
+    send(&app, post(&tasks_uri, &owner, task).to_request()).await;
+
+    let exclusions_uri = format!("/api/households/{}/members/{}/exclusions", household_id, child_id);
+    let exclusion = json!({ "task_id": baking_id });
+    assert_eq!(send(&app, post(&exclusions_uri, &child, exclusion.clone()).to_request()).await.0, StatusCode::FORBIDDEN);
+    let (status, body) = send(&app, post(&exclusions_uri, &owner, exclusion).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    let (_, body) = send(&app, get(&exclusions_uri, &child).to_request()).await;
+    assert_eq!(body["data"][0]["task_title"], "Bake bread");
+
+    let claim_uri = format!("{}/{}/claim", tasks_uri, baking_id);
+    assert_eq!(send(&app, post(&claim_uri, &child, json!({})).to_request()).await.0, StatusCode::BAD_REQUEST);
+    let update = test::TestRequest::put()
+        .uri(&format!("{}/{}", tasks_uri, baking_id))
+        .insert_header(("Authorization", format!("Bearer {}", owner)))
+        .set_json(json!({ "assignee_ids": [child_id] }));
+    assert_eq!(send(&app, update.to_request()).await.0, StatusCode::BAD_REQUEST);
+
+    // Baking moves to the owner, never to the child
+    let update = test::TestRequest::put()
+        .uri(&format!("{}/{}", tasks_uri, baking_id))
+        .insert_header(("Authorization", format!("Bearer {}", owner)))
+        .set_json(json!({ "assignee_ids": [owner_id] }));
+    assert!(send(&app, update.to_request()).await.0.is_success());
+    let (_, body) = send(&app, post(&format!("{}/rebalance-suggestions", tasks_uri), &owner, json!({})).to_request()).await;
+    let changes = body["data"]["changes"].as_array().unwrap();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0]["task_title"], "Dusting");
+
+    let delete = test::TestRequest::delete()
+        .uri(&format!("{}/{}", exclusions_uri, baking_id))
+        .insert_header(("Authorization", format!("Bearer {}", owner)));
+    assert_eq!(send(&app, delete.to_request()).await.0, StatusCode::NO_CONTENT);
+    let (_, body) = send(&app, get(&exclusions_uri, &owner).to_request()).await;
+    assert_eq!(body["data"], json!([]));
+}
+
+#[actix_rt::test]
+async fn test_period_override() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let (member, _) = register(&app, "member").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    join_household(&app, &owner, &household_id, "member", &member).await;
+    let tasks_uri = format!("/api/households/{}/tasks", household_id);
+
+    let task = json!({ "title": "Water plants", "recurrence_type": "daily" });
+    let (_, body) = send(&app, post(&tasks_uri, &owner, task).to_request()).await;
+    let task_id = body["data"]["id"].as_str().unwrap().to_string();
+
+    let past_day = chrono::Utc::now().date_naive() - chrono::Duration::days(2);
+    let override_uri = format!("{}/{}/periods/{}/override", tasks_uri, task_id, past_day);
+    let correction = json!({ "status": "completed", "reason": "Job was down" });
+    assert_eq!(send(&app, post(&override_uri, &member, correction.clone()).to_request()).await.0, StatusCode::FORBIDDEN);
+    let (status, _) = send(&app, post(&override_uri, &owner, json!({ "status": "completed", "reason": " " })).to_request()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, body) = send(&app, post(&override_uri, &owner, correction).to_request()).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["data"]["status"], "completed");
+    assert_eq!(body["data"]["notes"], "Job was down");
+
+    let future_uri = format!("{}/{}/periods/{}/override", tasks_uri, task_id, chrono::Utc::now().date_naive() + chrono::Duration::days(3));
+    let (status, _) = send(&app, post(&future_uri, &owner, json!({ "status": "failed", "reason": "Oops" })).to_request()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (_, body) = send(&app, get(&format!("/api/households/{}/audit-log", household_id), &owner).to_request()).await;
+    assert_eq!(body["data"]["entries"][0]["entry"]["action"], "period_overridden");
+}
+
+#[actix_rt::test]
+async fn test_streak_freezes() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let (member, member_id) = register(&app, "member").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    join_household(&app, &owner, &household_id, "member", &member).await;
+    let freezes_uri = format!("/api/households/{}/streak-freezes", household_id);
+    let purchase_uri = format!("{}/purchase", freezes_uri);
+
+    // Not for sale until the owner sets a price
+    assert_eq!(send(&app, post(&purchase_uri, &member, json!({})).to_request()).await.0, StatusCode::BAD_REQUEST);
+    let settings = test::TestRequest::put()
+        .uri(&format!("/api/households/{}/settings", household_id))
+        .insert_header(("Authorization", format!("Bearer {}", owner)))
+        .set_json(json!({ "streak_freeze_price": 20 }));
+    assert!(send(&app, settings.to_request()).await.0.is_success());
+    assert_eq!(send(&app, post(&purchase_uri, &member, json!({})).to_request()).await.0, StatusCode::BAD_REQUEST);
+
+    let points_uri = format!("/api/households/{}/members/{}/points", household_id, member_id);
+    send(&app, post(&points_uri, &owner, json!({ "points": 30 })).to_request()).await;
+    let (status, body) = send(&app, post(&purchase_uri, &member, json!({})).to_request()).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["data"], json!({ "available": 1, "price": 20 }));
+
+    let tasks_uri = format!("/api/households/{}/tasks", household_id);
+    let task = json!({ "title": "Read", "recurrence_type": "daily", "assigned_user_id": member_id });
+    let (_, body) = send(&app, post(&tasks_uri, &owner, task).to_request()).await;
+    let task_id = body["data"]["id"].as_str().unwrap().to_string();
+    let missed = chrono::Utc::now().date_naive() - chrono::Duration::days(2);
+    let override_uri = format!("{}/{}/periods/{}/override", tasks_uri, task_id, missed);
+    send(&app, post(&override_uri, &owner, json!({ "status": "failed", "reason": "Missed" })).to_request()).await;
+
+    let freeze_uri = format!("{}/{}/periods/{}/freeze", tasks_uri, task_id, missed);
+    assert_eq!(send(&app, post(&freeze_uri, &owner, json!({})).to_request()).await.0, StatusCode::FORBIDDEN);
+    let (status, body) = send(&app, post(&freeze_uri, &member, json!({})).to_request()).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["data"]["status"], "skipped");
+    assert_eq!(send(&app, post(&freeze_uri, &member, json!({})).to_request()).await.0, StatusCode::BAD_REQUEST);
+
+    let (_, body) = send(&app, get(&freezes_uri, &member).to_request()).await;
+    assert_eq!(body["data"]["available"], 0);
+}
+
+#[actix_rt::test]
+async fn test_reward_wishlist() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let (member, member_id) = register(&app, "member").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    join_household(&app, &owner, &household_id, "member", &member).await;
+    let settings = test::TestRequest::put()
+        .uri(&format!("/api/households/{}/settings", household_id))
+        .insert_header(("Authorization", format!("Bearer {}", owner)))
+        .set_json(json!({ "disabled_features": [] }));
+    assert!(send(&app, settings.to_request()).await.0.is_success());
+
+    let rewards_uri = format!("/api/households/{}/rewards", household_id);
+    let cinema = json!({ "name": "Cinema trip", "point_cost": 50, "is_purchasable": true });
+    let (_, body) = send(&app, post(&rewards_uri, &owner, cinema).to_request()).await;
+    let reward_id = body["data"]["id"].as_str().unwrap().to_string();
+
+    let wishlist_uri = format!("/api/households/{}/wishlist", household_id);
+    let entry_uri = format!("{}/{}", wishlist_uri, reward_id);
+    let (status, body) = send(&app, post(&entry_uri, &member, json!({})).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    assert_eq!(body["data"][0]["points_needed"], 50);
+
+    // Reaching the price sends one inbox notification
+    let points_uri = format!("/api/households/{}/members/{}/points", household_id, member_id);
+    send(&app, post(&points_uri, &owner, json!({ "points": 60 })).to_request()).await;
+    send(&app, post(&points_uri, &owner, json!({ "points": 5 })).to_request()).await;
+    let (_, body) = send(&app, get("/api/notifications", &member).to_request()).await;
+    let notifications = body["data"]["notifications"].as_array().unwrap();
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0]["notification_type"], "reward_affordable");
+    assert_eq!(notifications[0]["title"], "Cinema trip");
+
+    let (_, body) = send(&app, get(&wishlist_uri, &member).to_request()).await;
+    assert_eq!(body["data"][0]["points_needed"], 0);
+    let (_, body) = send(&app, get(&wishlist_uri, &owner).to_request()).await;
+    assert_eq!(body["data"], json!([]));
+
+    let remove = test::TestRequest::delete()
+        .uri(&entry_uri)
+        .insert_header(("Authorization", format!("Bearer {}", member)));
+    assert_eq!(send(&app, remove.to_request()).await.0, StatusCode::NO_CONTENT);
+}
+
+#[actix_rt::test]
+async fn test_weighted_random_reward_without_repeats() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let (member, member_id) = register(&app, "member").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    join_household(&app, &owner, &household_id, "member", &member).await;
+    let settings = test::TestRequest::put()
+        .uri(&format!("/api/households/{}/settings", household_id))
+        .insert_header(("Authorization", format!("Bearer {}", owner)))
+        .set_json(json!({ "disabled_features": [] }));
+    assert!(send(&app, settings.to_request()).await.0.is_success());
+
+    let rewards_uri = format!("/api/households/{}/rewards", household_id);
+    let mut option_ids = Vec::new();
+    for name in ["Ice cream", "Cinema"] {
+        let (_, body) = send(&app, post(&rewards_uri, &owner, json!({ "name": name, "is_purchasable": false })).to_request()).await;
+        option_ids.push(body["data"]["id"].as_str().unwrap().to_string());
+    }
+    let surprise = json!({
+        "name": "Surprise",
+        "is_purchasable": false,
+        "reward_type": "random_choice",
+        "option_ids": option_ids,
+        "no_repeat_picks": 1,
+    });
+    let (status, body) = send(&app, post(&rewards_uri, &owner, surprise).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    assert_eq!(body["data"]["no_repeat_picks"], 1);
+    let surprise_id = body["data"]["id"].as_str().unwrap().to_string();
+
+    let put_weight = |option_id: &str, weight: i32| {
+        test::TestRequest::put()
+            .uri(&format!("{}/{}/options/{}", rewards_uri, surprise_id, option_id))
+            .insert_header(("Authorization", format!("Bearer {}", owner)))
+            .set_json(json!({ "weight": weight }))
+            .to_request()
+    };
+    assert_eq!(send(&app, put_weight(&option_ids[0], 5)).await.0, StatusCode::NO_CONTENT);
+    assert_eq!(send(&app, put_weight(&option_ids[0], 0)).await.0, StatusCode::BAD_REQUEST);
+    assert_eq!(send(&app, put_weight(&surprise_id, 2)).await.0, StatusCode::NOT_FOUND);
+
+    // Saving the option list again keeps the weights
+    let resave = test::TestRequest::put()
+        .uri(&format!("{}/{}", rewards_uri, surprise_id))
+        .insert_header(("Authorization", format!("Bearer {}", owner)))
+        .set_json(json!({ "option_ids": option_ids }));
+    assert!(send(&app, resave.to_request()).await.0.is_success());
+    let (_, body) = send(&app, get(&format!("{}/{}/options", rewards_uri, surprise_id), &owner).to_request()).await;
+    let weights: Vec<(String, i64)> = body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|o| (o["id"].as_str().unwrap().to_string(), o["weight"].as_i64().unwrap()))
+        .collect();
+    assert!(weights.contains(&(option_ids[0].clone(), 5)));
+    assert!(weights.contains(&(option_ids[1].clone(), 1)));
+
+    // With two options and no repeat within one pick, consecutive picks alternate
+    let assign_uri = format!("{}/{}/assign/{}", rewards_uri, surprise_id, member_id);
+    send(&app, post(&assign_uri, &owner, json!({})).to_request()).await;
+    let (_, body) = send(&app, post(&assign_uri, &owner, json!({})).to_request()).await;
+    let user_reward_id = body["data"]["id"].as_str().unwrap().to_string();
+    let pick_uri = format!("{}/user-rewards/{}/pick", rewards_uri, user_reward_id);
+    let mut picked = Vec::new();
+    for _ in 0..2 {
+        let (status, body) = send(&app, post(&pick_uri, &member, json!({})).to_request()).await;
+        assert_eq!(status, StatusCode::OK, "{}", body);
+        picked.push(body["data"]["picked_reward"]["id"].as_str().unwrap().to_string());
+    }
+    assert_ne!(picked[0], picked[1]);
+}
+
+#[actix_rt::test]
+async fn test_point_goals() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let (member, member_id) = register(&app, "member").await;
+    let (other, _) = register(&app, "other").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    join_household(&app, &owner, &household_id, "member", &member).await;
+    join_household(&app, &owner, &household_id, "other", &other).await;
+    let goals_uri = format!("/api/households/{}/members/{}/goals", household_id, member_id);
+    let deadline = chrono::Utc::now().date_naive() + chrono::Duration::days(14);
+
+    let goal = json!({ "title": "Cinema trip", "target_points": 50, "deadline": deadline });
+    let (status, body) = send(&app, post(&goals_uri, &member, goal.clone()).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    assert_eq!(body["data"]["status"], "active");
+    assert_eq!(send(&app, post(&goals_uri, &other, goal).to_request()).await.0, StatusCode::FORBIDDEN);
+    let on_behalf = json!({ "title": "Bike", "target_points": 500, "deadline": deadline });
+    let (status, body) = send(&app, post(&goals_uri, &owner, on_behalf).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    let bike_id = body["data"]["goal"]["id"].as_str().unwrap().to_string();
+    let zero = json!({ "title": "Nothing", "target_points": 0, "deadline": deadline });
+    assert_eq!(send(&app, post(&goals_uri, &member, zero).to_request()).await.0, StatusCode::BAD_REQUEST);
+
+    let points_uri = format!("/api/households/{}/members/{}/points", household_id, member_id);
+    send(&app, post(&points_uri, &owner, json!({ "points": 60 })).to_request()).await;
+    let (status, body) = send(&app, get(&goals_uri, &other).to_request()).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let goals = body["data"].as_array().unwrap();
+    assert_eq!(goals.len(), 2);
+    assert_eq!(goals[0]["earned_points"], 60);
+    let statuses: Vec<_> = goals.iter().map(|g| g["status"].as_str().unwrap()).collect();
+    assert!(statuses.contains(&"completed") && statuses.contains(&"active"));
+
+    let remove = test::TestRequest::delete()
+        .uri(&format!("{}/{}", goals_uri, bike_id))
+        .insert_header(("Authorization", format!("Bearer {}", member)));
+    assert_eq!(send(&app, remove.to_request()).await.0, StatusCode::NO_CONTENT);
+}
+
+#[actix_rt::test]
+async fn test_create_task_uses_household_task_defaults() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    let settings = test::TestRequest::put()
+        .uri(&format!("/api/households/{}/settings", household_id))
+        .insert_header(("Authorization", format!("Bearer {}", owner)))
+        .set_json(json!({
+            "default_points_reward": 10,
+            "default_allow_exceed_target": false,
+            "bad_habit_points_multiplier": 2.0,
+            "review_points_threshold": 15
+        }));
+    let (status, body) = send(&app, settings.to_request()).await;
+    assert!(status.is_success(), "{}", body);
+    assert_eq!(body["data"]["review_points_threshold"], 15);
+
+    let tasks_uri = format!("/api/households/{}/tasks", household_id);
+    let good = json!({ "title": "Dishes", "recurrence_type": "daily" });
+    let (status, body) = send(&app, post(&tasks_uri, &owner, good).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    assert_eq!(body["data"]["points_reward"], 10);
+    assert_eq!(body["data"]["allow_exceed_target"], false);
+    assert_eq!(body["data"]["requires_review"], false);
+
+    // Bad habits are scaled, which puts this one above the review threshold
+    let bad = json!({ "title": "Snacking", "recurrence_type": "daily", "habit_type": "bad" });
+    let (_, body) = send(&app, post(&tasks_uri, &owner, bad).to_request()).await;
+    assert_eq!(body["data"]["points_reward"], 20);
+    assert_eq!(body["data"]["requires_review"], true);
+
+    let explicit = json!({ "title": "Laundry", "recurrence_type": "daily", "points_reward": 30, "requires_review": false, "allow_exceed_target": true });
+    let (_, body) = send(&app, post(&tasks_uri, &owner, explicit).to_request()).await;
+    assert_eq!(body["data"]["points_reward"], 30);
+    assert_eq!(body["data"]["requires_review"], false);
+    assert_eq!(body["data"]["allow_exceed_target"], true);
+}
+
+#[actix_rt::test]
+async fn test_dashboard_all_tasks_filters_groups_and_counts() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, owner_id) = register(&app, "owner").await;
+    let home = create_household(&app, &owner, "Home").await;
+    let cabin = create_household(&app, &owner, "Cabin").await;
+
+    let home_tasks = format!("/api/households/{}/tasks", home);
+    let mine = json!({ "title": "Dishes", "recurrence_type": "daily", "assigned_user_id": owner_id });
+    send(&app, post(&home_tasks, &owner, mine).to_request()).await;
+    let shared_task = json!({ "title": "Vacuum", "recurrence_type": "daily" });
+    send(&app, post(&home_tasks, &owner, shared_task).to_request()).await;
+    let cabin_task = json!({ "title": "Firewood", "recurrence_type": "daily" });
+    send(&app, post(&format!("/api/households/{}/tasks", cabin), &owner, cabin_task).to_request()).await;
+
+    let (status, body) = send(&app, get("/api/dashboard/tasks/all?group_by=household", &owner).to_request()).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["data"]["tasks"].as_array().unwrap().len(), 3);
+    assert_eq!(body["data"]["summary"]["total"], 3);
+    assert_eq!(body["data"]["summary"]["households"], 2);
+    assert_eq!(body["data"]["summary"]["assigned_to_me"], 1);
+    let labels: Vec<_> = body["data"]["groups"].as_array().unwrap().iter().map(|g| g["label"].as_str().unwrap()).collect();
+    assert_eq!(labels, vec!["Cabin", "Home"]);
+
+    let uri = format!("/api/dashboard/tasks/all?households={}&mine=true", home);
+    let (_, body) = send(&app, get(&uri, &owner).to_request()).await;
+    let tasks = body["data"]["tasks"].as_array().unwrap();
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0]["task_with_status"]["task"]["title"], "Dishes");
+    assert_eq!(body["data"]["groups"], json!([]));
+
+    let (_, body) = send(&app, get("/api/dashboard/tasks/all?due_within=0&group_by=due_window", &owner).to_request()).await;
+    assert_eq!(body["data"]["summary"]["due_today"], 3);
+    assert_eq!(body["data"]["groups"][0]["key"], "today");
+    assert_eq!(body["data"]["groups"][0]["task_ids"].as_array().unwrap().len(), 3);
+
+    let (status, _) = send(&app, get("/api/dashboard/tasks/all?group_by=color", &owner).to_request()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let too_far = format!("/api/dashboard/tasks/all?due_within={}", i64::MAX);
+    assert_eq!(send(&app, get(&too_far, &owner).to_request()).await.0, StatusCode::BAD_REQUEST);
+    let (status, _) = send(&app, get("/api/dashboard/tasks/all?due_within=3650", &owner).to_request()).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[actix_rt::test]
+async fn test_journal_visibility_and_mood() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let (member, _) = register(&app, "member").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    join_household(&app, &owner, &household_id, "member", &member).await;
+    let journal_uri = format!("/api/households/{}/journal", household_id);
+
+    let private = json!({ "content": "Just for me", "entry_date": "2026-03-01", "mood": 2 });
+    let (status, body) = send(&app, post(&journal_uri, &owner, private).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    assert_eq!(body["data"]["visibility"], "private");
+    let shared = json!({ "content": "For everyone", "entry_date": "2026-03-01", "visibility": "household", "mood": 4 });
+    let (status, body) = send(&app, post(&journal_uri, &owner, shared).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    let too_happy = json!({ "content": "Wow", "mood": 9 });
+    assert_eq!(send(&app, post(&journal_uri, &owner, too_happy).to_request()).await.0, StatusCode::BAD_REQUEST);
+
+    let (_, body) = send(&app, get(&journal_uri, &member).to_request()).await;
+    let entries = body["data"].as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["entry"]["content"], "For everyone");
+
+    // Only the caller's own moods are averaged
+    let mood_uri = format!("{}/mood?start=2026-03-01&end=2026-03-31", journal_uri);
+    let (status, body) = send(&app, get(&mood_uri, &owner).to_request()).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["data"], json!([{ "date": "2026-03-01", "average_mood": 3.0, "entries": 2 }]));
+    let (_, body) = send(&app, get(&mood_uri, &member).to_request()).await;
+    assert_eq!(body["data"], json!([]));
+    let invalid = format!("{}/mood?start=March", journal_uri);
+    assert_eq!(send(&app, get(&invalid, &owner).to_request()).await.0, StatusCode::BAD_REQUEST);
+    for range in ["end=-262143-01-01", "start=+262142-12-31", "start=0001-01-01&end=2026-03-31"] {
+        let out_of_range = format!("{}/mood?{}", journal_uri, range);
+        assert_eq!(send(&app, get(&out_of_range, &owner).to_request()).await.0, StatusCode::BAD_REQUEST);
+    }
+}
+
+#[actix_rt::test]
+async fn test_announcement_acknowledgments() {
+    let app = test_app!(create_test_app_state(create_migrated_pool().await));
+    let (owner, _) = register(&app, "owner").await;
+    let (member, member_id) = register(&app, "member").await;
+    let household_id = create_household(&app, &owner, "Home").await;
+    join_household(&app, &owner, &household_id, "member", &member).await;
+    let announcements_uri = format!("/api/households/{}/announcements", household_id);
+
+    let notice = json!({ "title": "Water shutoff", "content": "Tuesday 9-12" });
+    let (status, body) = send(&app, post(&announcements_uri, &owner, notice).to_request()).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    assert!(body["data"]["published_at"].is_string());
+    let announcement_id = body["data"]["id"].as_str().unwrap().to_string();
+    let acknowledge_uri = format!("{}/{}/acknowledge", announcements_uri, announcement_id);
+    let checklist_uri = format!("{}/{}/acknowledgments", announcements_uri, announcement_id);
+
+    let (status, body) = send(&app, post(&acknowledge_uri, &member, json!({})).to_request()).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let (_, body) = send(&app, get(&format!("{}/active", announcements_uri), &member).to_request()).await;
+    assert!(body["data"][0]["acknowledged_at"].is_string());
+
+    // Only the author and owners see the checklist
+    assert_eq!(send(&app, get(&checklist_uri, &member).to_request()).await.0, StatusCode::FORBIDDEN);
+    let (status, body) = send(&app, get(&checklist_uri, &owner).to_request()).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let checklist: Vec<shared::AnnouncementAcknowledgment> = serde_json::from_value(body["data"].clone()).unwrap();
+    assert_eq!(checklist.len(), 2);
+    for entry in checklist {
+        assert_eq!(entry.acknowledged_at.is_some(), entry.user_id.to_string() == member_id);
+    }
+}
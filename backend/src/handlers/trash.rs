@@ -0,0 +1,145 @@
+use actix_web::{web, HttpResponse, Result};
+use shared::{ApiError, ApiSuccess, Permission, TrashItem, TrashItemKind};
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::trash as trash_service;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/trash")
+            .route("", web::get().to(list_trash))
+            .route("/{kind}/{item_id}/restore", web::post().to(restore_item))
+            .route("/{kind}/{item_id}", web::delete().to(purge_item)),
+    );
+}
+
+/// Parse the item path; tasks additionally need permission to manage tasks
+async fn parse_item(
+    state: &AppState,
+    ctx: &HouseholdContext,
+    kind: &str,
+    item_id: &str,
+) -> Result<(TrashItemKind, Uuid), HttpResponse> {
+    let kind: TrashItemKind = kind.parse().map_err(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_kind".to_string(),
+            message: "Unknown trash item kind".to_string(),
+        })
+    })?;
+
+    let item_id = Uuid::parse_str(item_id).map_err(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_id".to_string(),
+            message: "Invalid item ID format".to_string(),
+        })
+    })?;
+
+    if kind == TrashItemKind::Task && !ctx.has_permission(state, Permission::ManageTasks).await {
+        return Err(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "You do not have permission to manage tasks".to_string(),
+        }));
+    }
+
+    Ok((kind, item_id))
+}
+
+fn trash_error_response(e: trash_service::TrashError, action: &str) -> HttpResponse {
+    match e {
+        trash_service::TrashError::NotFound => HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: "Item not found in trash".to_string(),
+        }),
+        e => {
+            log::error!("Error trying to {} trash item: {:?}", action, e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: format!("Failed to {} item", action),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/trash",
+    tag = "trash",
+    summary = "List trash",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<TrashItem>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_trash(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
+    let can_manage_tasks = ctx.has_permission(&state, Permission::ManageTasks).await;
+
+    match trash_service::list_trash(&state.db, &ctx.household_id, &ctx.user_id, can_manage_tasks).await {
+        Ok(items) => Ok(HttpResponse::Ok().json(ApiSuccess::new(items))),
+        Err(e) => {
+            log::error!("Error listing trash: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to list trash".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/trash/{kind}/{item_id}/restore",
+    tag = "trash",
+    summary = "Restore item",
+    params(("household_id" = Uuid, Path), ("kind" = String, Path), ("item_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn restore_item(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String, String)>,
+) -> Result<HttpResponse> {
+    let (_, kind, item_id) = path.into_inner();
+    let (kind, item_id) = match parse_item(&state, &ctx, &kind, &item_id).await {
+        Ok(item) => item,
+        Err(response) => return Ok(response),
+    };
+
+    match trash_service::restore(&state.db, &ctx.household_id, &ctx.user_id, kind, &item_id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(trash_error_response(e, "restore")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/trash/{kind}/{item_id}",
+    tag = "trash",
+    summary = "Purge item",
+    params(("household_id" = Uuid, Path), ("kind" = String, Path), ("item_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn purge_item(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String, String)>,
+) -> Result<HttpResponse> {
+    let (_, kind, item_id) = path.into_inner();
+    let (kind, item_id) = match parse_item(&state, &ctx, &kind, &item_id).await {
+        Ok(item) => item,
+        Err(response) => return Ok(response),
+    };
+
+    match trash_service::purge(&state.db, &state.config.upload_dir, &ctx.household_id, &ctx.user_id, kind, &item_id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(trash_error_response(e, "delete")),
+    }
+}
@@ -1,9 +1,16 @@
 use actix_web::{web, HttpResponse, Result};
-use shared::{ApiError, ApiSuccess, UpdateUserRequest, UpdateUserSettingsRequest};
+use shared::{
+    ApiError, ApiSuccess, AuditAction, ChangePasswordRequest, DeleteAccountRequest, Role,
+    UpdateUserRequest, UpdateUserSettingsRequest, User, UserSettings,
+};
 use uuid::Uuid;
 
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::auth::is_api_token_request;
 use crate::models::AppState;
+use crate::services::audit_log as audit_log_service;
 use crate::services::auth as auth_service;
+use crate::services::households as household_service;
 use crate::services::user_settings as settings_service;
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
@@ -12,11 +19,36 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             // User settings routes must come before /{id} to avoid matching "me" as an id
             .route("/me/settings", web::get().to(get_user_settings))
             .route("/me/settings", web::put().to(update_user_settings))
+            .route("/me/calendar-token", web::get().to(super::calendar::get_feed_token))
+            .route("/me/calendar-token", web::post().to(super::calendar::regenerate_feed_token))
+            .route("/me/api-tokens", web::get().to(super::api_tokens::list_tokens))
+            .route("/me/api-tokens", web::post().to(super::api_tokens::create_token))
+            .route("/me/api-tokens/{token_id}", web::delete().to(super::api_tokens::revoke_token))
+            .route("/me/push/config", web::get().to(super::notifications::get_push_config))
+            .route("/me/push/subscriptions", web::post().to(super::notifications::subscribe))
+            .route("/me/push/subscriptions", web::delete().to(super::notifications::unsubscribe))
+            .route("/me/chat/unread", web::get().to(super::chat::get_unread_counts))
+            .route("/me/focus-session", web::get().to(super::focus_sessions::get_current_session))
+            .route("/me/password", web::post().to(change_password))
+            .route("/me", web::delete().to(delete_account))
             .route("/{id}", web::get().to(get_user))
             .route("/{id}", web::put().to(update_user))
     );
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/users/{id}",
+    tag = "users",
+    summary = "Get user",
+    params(("id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<User>),
+        BadRequest,
+        NotFound,
+    ),
+    security(()),
+)]
 async fn get_user(
     state: web::Data<AppState>,
     path: web::Path<String>,
@@ -47,6 +79,21 @@ async fn get_user(
     }
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/users/{id}",
+    tag = "users",
+    summary = "Update user",
+    params(("id" = Uuid, Path)),
+    request_body = UpdateUserRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<User>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
 async fn update_user(
     state: web::Data<AppState>,
     req: actix_web::HttpRequest,
@@ -81,8 +128,22 @@ async fn update_user(
         }));
     }
 
+    // Changing the email would let a leaked API token reset the password
+    if is_api_token_request(&req) {
+        return Ok(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "API tokens cannot change the profile".to_string(),
+        }));
+    }
+
     match auth_service::update_user(&state.db, &target_user_id, &body.into_inner()).await {
         Ok(user) => Ok(HttpResponse::Ok().json(ApiSuccess::new(user))),
+        Err(e @ (auth_service::AuthError::ChildAccountRestricted | auth_service::AuthError::ReservedEmail)) => {
+            Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "validation_error".to_string(),
+                message: e.to_string(),
+            }))
+        }
         Err(e) => {
             log::error!("Error updating user: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
@@ -93,6 +154,16 @@ async fn update_user(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/users/me/settings",
+    tag = "users",
+    summary = "Get user settings",
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<UserSettings>),
+        Unauthorized,
+    ),
+)]
 async fn get_user_settings(
     state: web::Data<AppState>,
     req: actix_web::HttpRequest,
@@ -119,6 +190,18 @@ async fn get_user_settings(
     }
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/users/me/settings",
+    tag = "users",
+    summary = "Update user settings",
+    request_body = UpdateUserSettingsRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<UserSettings>),
+        BadRequest,
+        Unauthorized,
+    ),
+)]
 async fn update_user_settings(
     state: web::Data<AppState>,
     req: actix_web::HttpRequest,
@@ -142,6 +225,12 @@ async fn update_user_settings(
                 message: "Invalid language code. Supported: en, de".to_string(),
             }))
         }
+        Err(e @ settings_service::UserSettingsError::InvalidQuietHours) => {
+            Ok(HttpResponse::BadRequest().json(ApiError {
+                error: "validation_error".to_string(),
+                message: e.to_string(),
+            }))
+        }
         Err(e) => {
             log::error!("Error updating user settings: {:?}", e);
             Ok(HttpResponse::InternalServerError().json(ApiError {
@@ -151,3 +240,170 @@ async fn update_user_settings(
         }
     }
 }
+
+#[utoipa::path(
+    post,
+    path = "/api/users/me/password",
+    tag = "users",
+    summary = "Change password",
+    request_body = ChangePasswordRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<String>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn change_password(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    body: web::Json<ChangePasswordRequest>,
+) -> Result<HttpResponse> {
+    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::Unauthorized().json(ApiError {
+                error: "unauthorized".to_string(),
+                message: "Invalid or missing token".to_string(),
+            }));
+        }
+    };
+
+    // A leaked API token must not be enough to take over the account
+    if is_api_token_request(&req) {
+        return Ok(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "API tokens cannot change the password".to_string(),
+        }));
+    }
+
+    let request = body.into_inner();
+
+    if request.new_password.len() < 8 {
+        return Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "validation_error".to_string(),
+            message: "Password must be at least 8 characters".to_string(),
+        }));
+    }
+
+    match auth_service::change_password(
+        &state.db,
+        &user_id,
+        &request.current_password,
+        &request.new_password,
+        request.refresh_token.as_deref(),
+    )
+    .await
+    {
+        Ok(()) => Ok(HttpResponse::Ok().json(ApiSuccess::new("Password has been changed"))),
+        Err(auth_service::AuthError::InvalidCredentials) => Ok(HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_credentials".to_string(),
+            message: "Current password is incorrect".to_string(),
+        })),
+        Err(e) => {
+            log::error!("Error changing password: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to change password".to_string(),
+            }))
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/users/me",
+    tag = "users",
+    summary = "Delete account",
+    request_body = Option<DeleteAccountRequest>,
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn delete_account(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    body: Option<web::Json<DeleteAccountRequest>>,
+) -> Result<HttpResponse> {
+    let user_id = match crate::middleware::auth::extract_user_id(&req, &state.config.jwt_secret) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(HttpResponse::Unauthorized().json(ApiError {
+                error: "unauthorized".to_string(),
+                message: "Invalid or missing token".to_string(),
+            }));
+        }
+    };
+
+    // A leaked API token must not be enough to take over the account
+    if is_api_token_request(&req) {
+        return Ok(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "API tokens cannot delete the account".to_string(),
+        }));
+    }
+
+    // Accounts with a password must confirm it; OIDC-only accounts have nothing to confirm
+    let password = body.and_then(|b| b.into_inner().password);
+    match auth_service::has_password(&state.db, &user_id).await {
+        Ok(true) => {
+            let verified = match password {
+                Some(ref password) => auth_service::verify_password(&state.db, &user_id, password).await.is_ok(),
+                None => false,
+            };
+            if !verified {
+                return Ok(HttpResponse::BadRequest().json(ApiError {
+                    error: "invalid_credentials".to_string(),
+                    message: "Password is incorrect".to_string(),
+                }));
+            }
+        }
+        Ok(false) => {}
+        Err(auth_service::AuthError::UserNotFound) => {
+            return Ok(HttpResponse::NotFound().json(ApiError {
+                error: "not_found".to_string(),
+                message: "User not found".to_string(),
+            }));
+        }
+        Err(e) => {
+            log::error!("Error loading user for deletion: {:?}", e);
+            return Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to delete account".to_string(),
+            }));
+        }
+    }
+
+    let handovers = match household_service::leave_all_households(&state.db, &user_id).await {
+        Ok(handovers) => handovers,
+        Err(e) => {
+            log::error!("Error leaving households for account deletion: {:?}", e);
+            return Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to delete account".to_string(),
+            }));
+        }
+    };
+
+    for (household_id, new_owner_id) in handovers {
+        let _ = audit_log_service::record(
+            &state.db,
+            &household_id,
+            &user_id,
+            Some(&new_owner_id),
+            AuditAction::OwnershipTransferred,
+            None,
+            Some(Role::Owner.as_str()),
+        )
+        .await;
+    }
+
+    match auth_service::delete_account(&state.db, &user_id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => {
+            log::error!("Error deleting account: {:?}", e);
+            Ok(HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: "Failed to delete account".to_string(),
+            }))
+        }
+    }
+}
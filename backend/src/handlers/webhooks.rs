@@ -0,0 +1,202 @@
+use actix_web::{web, HttpResponse, Result};
+use shared::{ApiError, ApiSuccess, CreateWebhookRequest, Role, UpdateWebhookRequest, Webhook};
+use uuid::Uuid;
+
+use crate::handlers::openapi::{BadRequest, Forbidden, NoContent, NotFound, Unauthorized};
+use crate::middleware::household::HouseholdContext;
+use crate::models::AppState;
+use crate::services::webhooks as webhook_service;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/webhooks")
+            .route("", web::get().to(list_webhooks))
+            .route("", web::post().to(create_webhook))
+            .route("/{webhook_id}", web::put().to(update_webhook))
+            .route("/{webhook_id}", web::delete().to(delete_webhook)),
+    );
+}
+
+/// Require the household owner, since webhooks carry signing secrets and send
+/// household data to external services
+fn require_owner(ctx: &HouseholdContext) -> std::result::Result<(), HttpResponse> {
+    if ctx.role() != Role::Owner {
+        return Err(HttpResponse::Forbidden().json(ApiError {
+            error: "forbidden".to_string(),
+            message: "Only owners can manage webhooks".to_string(),
+        }));
+    }
+    Ok(())
+}
+
+fn parse_webhook_id(id: &str) -> std::result::Result<Uuid, HttpResponse> {
+    Uuid::parse_str(id).map_err(|_| {
+        HttpResponse::BadRequest().json(ApiError {
+            error: "invalid_id".to_string(),
+            message: "Invalid webhook ID format".to_string(),
+        })
+    })
+}
+
+fn webhook_error_response(error: webhook_service::WebhookError, context: &str) -> HttpResponse {
+    use webhook_service::WebhookError;
+
+    match error {
+        WebhookError::NotFound => HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: error.to_string(),
+        }),
+        WebhookError::InvalidUrl | WebhookError::EmptySecret | WebhookError::NoEvents => {
+            HttpResponse::BadRequest().json(ApiError {
+                error: "validation_error".to_string(),
+                message: error.to_string(),
+            })
+        }
+        WebhookError::DatabaseError(e) => {
+            log::error!("Error {}: {:?}", context, e);
+            HttpResponse::InternalServerError().json(ApiError {
+                error: "internal_error".to_string(),
+                message: format!("Failed {}", context),
+            })
+        }
+    }
+}
+
+/// Make sure a webhook exists and belongs to the household from the path
+async fn check_webhook_in_household(
+    state: &AppState,
+    household_id: &Uuid,
+    webhook_id: &Uuid,
+) -> std::result::Result<(), HttpResponse> {
+    match webhook_service::get_webhook(&state.db, webhook_id).await {
+        Ok(Some(webhook)) if webhook.household_id == *household_id => Ok(()),
+        Ok(_) => Err(webhook_error_response(webhook_service::WebhookError::NotFound, "loading webhook")),
+        Err(e) => Err(webhook_error_response(e, "loading webhook")),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/households/{household_id}/webhooks",
+    tag = "webhooks",
+    summary = "List webhooks",
+    params(("household_id" = Uuid, Path)),
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Vec<Webhook>>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn list_webhooks(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_owner(&ctx) {
+        return Ok(response);
+    }
+
+    match webhook_service::list_webhooks(&state.db, &ctx.household_id).await {
+        Ok(webhooks) => Ok(HttpResponse::Ok().json(ApiSuccess::new(webhooks))),
+        Err(e) => Ok(webhook_error_response(e, "listing webhooks")),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/households/{household_id}/webhooks",
+    tag = "webhooks",
+    summary = "Create webhook",
+    params(("household_id" = Uuid, Path)),
+    request_body = CreateWebhookRequest,
+    responses(
+        (status = 201, description = "Created", body = ApiSuccess<Webhook>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+    ),
+)]
+async fn create_webhook(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    body: web::Json<CreateWebhookRequest>,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_owner(&ctx) {
+        return Ok(response);
+    }
+
+    match webhook_service::create_webhook(&state.db, &ctx.household_id, &ctx.user_id, &body).await {
+        Ok(webhook) => Ok(HttpResponse::Created().json(ApiSuccess::new(webhook))),
+        Err(e) => Ok(webhook_error_response(e, "creating webhook")),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/households/{household_id}/webhooks/{webhook_id}",
+    tag = "webhooks",
+    summary = "Update webhook",
+    params(("household_id" = Uuid, Path), ("webhook_id" = Uuid, Path)),
+    request_body = UpdateWebhookRequest,
+    responses(
+        (status = 200, description = "Success", body = ApiSuccess<Webhook>),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+    ),
+)]
+async fn update_webhook(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+    body: web::Json<UpdateWebhookRequest>,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_owner(&ctx) {
+        return Ok(response);
+    }
+    let (_, webhook_id_str) = path.into_inner();
+    let webhook_id = match parse_webhook_id(&webhook_id_str) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+    if let Err(response) = check_webhook_in_household(&state, &ctx.household_id, &webhook_id).await {
+        return Ok(response);
+    }
+
+    match webhook_service::update_webhook(&state.db, &webhook_id, &body).await {
+        Ok(webhook) => Ok(HttpResponse::Ok().json(ApiSuccess::new(webhook))),
+        Err(e) => Ok(webhook_error_response(e, "updating webhook")),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/households/{household_id}/webhooks/{webhook_id}",
+    tag = "webhooks",
+    summary = "Delete webhook",
+    params(("household_id" = Uuid, Path), ("webhook_id" = Uuid, Path)),
+    responses(NoContent, BadRequest, Unauthorized, Forbidden, NotFound),
+)]
+async fn delete_webhook(
+    state: web::Data<AppState>,
+    ctx: HouseholdContext,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse> {
+    if let Err(response) = require_owner(&ctx) {
+        return Ok(response);
+    }
+    let (_, webhook_id_str) = path.into_inner();
+    let webhook_id = match parse_webhook_id(&webhook_id_str) {
+        Ok(id) => id,
+        Err(response) => return Ok(response),
+    };
+    if let Err(response) = check_webhook_in_household(&state, &ctx.household_id, &webhook_id).await {
+        return Ok(response);
+    }
+
+    match webhook_service::delete_webhook(&state.db, &webhook_id).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Ok(webhook_error_response(e, "deleting webhook")),
+    }
+}
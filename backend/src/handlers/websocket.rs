@@ -1,5 +1,5 @@
 use actix_web::{web, HttpRequest, HttpResponse, Result};
-use actix_ws::Message;
+use actix_ws::{CloseCode, CloseReason, Message};
 use futures::StreamExt;
 use sqlx::SqlitePool;
 use std::sync::Arc;
@@ -7,21 +7,50 @@ use tokio::sync::mpsc;
 use uuid::Uuid;
 
 use crate::config::Config;
-use crate::services::{chat as chat_service, households as household_service, websocket::WsManager};
-use shared::{WsClientMessage, WsServerMessage};
+use crate::models::AppState;
+use crate::services::notifications::{self as notification_service, PushSender};
+use crate::services::{
+    attachments as attachment_service, chat as chat_service, households as household_service,
+    leaderboard as leaderboard_service, websocket::WsManager,
+};
+use shared::{AttachmentEntity, NotificationKind, WsClientMessage, WsServerMessage};
 
 /// Configure the WebSocket route
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.route("/api/ws", web::get().to(ws_handler));
 }
 
+/// Recompute the stored leaderboards and push the all-time one to everyone subscribed
+/// to the household's points events. Called by handlers after an action that changed member points.
+pub async fn broadcast_leaderboard(req: &HttpRequest, pool: &SqlitePool, household_id: &Uuid) {
+    let leaderboard = match leaderboard_service::refresh(pool, household_id).await {
+        Ok(leaderboard) => leaderboard,
+        Err(e) => {
+            log::error!("Error refreshing leaderboard for broadcast: {:?}", e);
+            return;
+        }
+    };
+    if let Some(ws_manager) = req.app_data::<web::Data<Arc<WsManager>>>() {
+        ws_manager.broadcast_leaderboard_updated(household_id, leaderboard).await;
+    }
+}
+
 /// WebSocket connection handler
+#[utoipa::path(
+    get,
+    path = "/api/ws",
+    tag = "websocket",
+    summary = "WebSocket connection handler",
+    responses((status = 101, description = "Switched to the WebSocket protocol")),
+    security(()),
+)]
 async fn ws_handler(
     req: HttpRequest,
     body: web::Payload,
     ws_manager: web::Data<Arc<WsManager>>,
     pool: web::Data<SqlitePool>,
     config: web::Data<Config>,
+    state: web::Data<AppState>,
 ) -> Result<HttpResponse> {
     let (response, session, mut msg_stream) = actix_ws::handle(&req, body)?;
 
@@ -34,6 +63,8 @@ async fn ws_handler(
     let ws_manager_clone = ws_manager.clone();
     let pool_clone = pool.clone();
     let config_clone = config.clone();
+    let push = state.push.clone();
+    let mut shutdown = ws_manager.shutdown_signal();
 
     // Spawn task to handle incoming messages
     actix_rt::spawn(async move {
@@ -51,8 +82,22 @@ async fn ws_handler(
             }
         });
 
-        // Handle incoming messages
-        while let Some(Ok(msg)) = msg_stream.next().await {
+        // Handle incoming messages until the client leaves or the server shuts down
+        let mut close_reason = None;
+        loop {
+            let msg = tokio::select! {
+                msg = msg_stream.next() => msg,
+                Ok(_) = shutdown.wait_for(|down| *down) => {
+                    close_reason = Some(CloseReason {
+                        code: CloseCode::Away,
+                        description: Some("Server shutting down".to_string()),
+                    });
+                    break;
+                }
+            };
+            let Some(Ok(msg)) = msg else {
+                break;
+            };
             match msg {
                 Message::Text(text) => {
                     if let Ok(client_msg) = serde_json::from_str::<WsClientMessage>(&text) {
@@ -62,6 +107,7 @@ async fn ws_handler(
                             &ws_manager_clone,
                             &pool_clone,
                             &config_clone,
+                            push.as_ref(),
                         )
                         .await;
                     } else {
@@ -89,12 +135,45 @@ async fn ws_handler(
         // Cleanup
         ws_manager_clone.disconnect(&session_id).await;
         send_task.abort();
-        let _ = session.close(None).await;
+        let _ = session.close(close_reason).await;
     });
 
     Ok(response)
 }
 
+/// Whether the session's user may receive the household's events; tells the client if not
+async fn check_member(session_id: &Uuid, household_id: &Uuid, ws_manager: &Arc<WsManager>, pool: &SqlitePool) -> bool {
+    let Some((user_id, _)) = ws_manager.get_session_user(session_id).await else {
+        ws_manager
+            .send_to_session(
+                session_id,
+                WsServerMessage::Error {
+                    code: "not_authenticated".to_string(),
+                    message: "You must authenticate first".to_string(),
+                },
+            )
+            .await;
+        return false;
+    };
+
+    if !household_service::is_member(pool, household_id, &user_id)
+        .await
+        .unwrap_or(false)
+    {
+        ws_manager
+            .send_to_session(
+                session_id,
+                WsServerMessage::Error {
+                    code: "forbidden".to_string(),
+                    message: "You are not a member of this household".to_string(),
+                },
+            )
+            .await;
+        return false;
+    }
+    true
+}
+
 /// Handle incoming WebSocket messages from clients
 async fn handle_client_message(
     session_id: &Uuid,
@@ -102,6 +181,7 @@ async fn handle_client_message(
     ws_manager: &Arc<WsManager>,
     pool: &SqlitePool,
     config: &Config,
+    push: Option<&PushSender>,
 ) {
     match message {
         WsClientMessage::Authenticate { token } => {
@@ -144,48 +224,40 @@ async fn handle_client_message(
         }
 
         WsClientMessage::JoinRoom { household_id } => {
-            // Check if authenticated
-            let user_info = ws_manager.get_session_user(session_id).await;
-            if user_info.is_none() {
-                ws_manager
-                    .send_to_session(
-                        session_id,
-                        WsServerMessage::Error {
-                            code: "not_authenticated".to_string(),
-                            message: "You must authenticate first".to_string(),
-                        },
-                    )
-                    .await;
-                return;
+            if check_member(session_id, &household_id, ws_manager, pool).await {
+                ws_manager.join_room(session_id, household_id).await;
             }
+        }
 
-            let (user_id, _) = user_info.unwrap();
+        WsClientMessage::LeaveRoom => {
+            ws_manager.leave_room(session_id).await;
+        }
 
-            // Check membership
-            if !household_service::is_member(pool, &household_id, &user_id)
-                .await
-                .unwrap_or(false)
-            {
-                ws_manager
-                    .send_to_session(
-                        session_id,
-                        WsServerMessage::Error {
-                            code: "forbidden".to_string(),
-                            message: "You are not a member of this household".to_string(),
-                        },
-                    )
-                    .await;
-                return;
+        WsClientMessage::Subscribe {
+            household_id,
+            categories,
+        } => {
+            if check_member(session_id, &household_id, ws_manager, pool).await {
+                ws_manager.subscribe(session_id, household_id, &categories).await;
             }
+        }
 
-            ws_manager.join_room(session_id, household_id).await;
+        WsClientMessage::Unsubscribe {
+            household_id,
+            categories,
+        } => {
+            ws_manager.unsubscribe(session_id, household_id, &categories).await;
         }
 
-        WsClientMessage::LeaveRoom => {
-            ws_manager.leave_room(session_id).await;
+        WsClientMessage::Typing { household_id } => {
+            // Membership was checked on subscribing; throttled notices are dropped silently
+            ws_manager.relay_typing(session_id, household_id).await;
         }
 
-        WsClientMessage::SendMessage { content } => {
+        WsClientMessage::SendMessage {
+            content,
+            reply_to_message_id,
+        } => {
             // Check if in a room
             let household_id = match ws_manager.get_session_household(session_id).await {
                 Some(id) => id,
@@ -209,15 +281,38 @@ async fn handle_client_message(
             };
 
             // Create message
-            match chat_service::create_message(pool, &household_id, &user_id, &content).await {
+            match chat_service::create_message(
+                pool,
+                &household_id,
+                &user_id,
+                &content,
+                reply_to_message_id.as_ref(),
+            )
+            .await
+            {
                 Ok(message) => {
                     // Get message with user info
                     if let Ok(Some(msg_with_user)) =
                         chat_service::get_message_with_user(pool, &message.id).await
                     {
+                        let notification = notification_service::build_chat_message(
+                            &household_id,
+                            &msg_with_user.user.username,
+                            &msg_with_user.message.content,
+                        );
                         ws_manager
                             .broadcast_new_message(&household_id, msg_with_user)
                             .await;
+                        super::notifications::notify_members(
+                            pool,
+                            push,
+                            &household_id,
+                            &user_id,
+                            None,
+                            NotificationKind::Chat,
+                            notification,
+                        )
+                        .await;
                     }
                 }
                 Err(chat_service::ChatError::EmptyContent) => {
@@ -231,6 +326,17 @@ async fn handle_client_message(
                         )
                         .await;
                 }
+                Err(chat_service::ChatError::InvalidReply) => {
+                    ws_manager
+                        .send_to_session(
+                            session_id,
+                            WsServerMessage::Error {
+                                code: "invalid_reply".to_string(),
+                                message: "The message you are replying to does not exist".to_string(),
+                            },
+                        )
+                        .await;
+                }
                 Err(e) => {
                     log::error!("Error creating message via WebSocket: {:?}", e);
                     ws_manager
@@ -342,6 +448,9 @@ async fn handle_client_message(
 
             match chat_service::delete_message(pool, &message_id, &user_id).await {
                 Ok(()) => {
+                    if let Err(e) = attachment_service::delete_for_entity(pool, &config.upload_dir, AttachmentEntity::ChatMessage, &message_id).await {
+                        log::error!("Error deleting chat message photos: {:?}", e);
+                    }
                     ws_manager
                         .broadcast_message_deleted(&household_id, message_id)
                         .await;
@@ -383,6 +492,60 @@ async fn handle_client_message(
             }
         }
 
+        WsClientMessage::MarkRead { message_id } => {
+            let household_id = match ws_manager.get_session_household(session_id).await {
+                Some(id) => id,
+                None => {
+                    ws_manager
+                        .send_to_session(
+                            session_id,
+                            WsServerMessage::Error {
+                                code: "not_in_room".to_string(),
+                                message: "You must join a room first".to_string(),
+                            },
+                        )
+                        .await;
+                    return;
+                }
+            };
+
+            let user_id = match ws_manager.get_session_user(session_id).await {
+                Some((uid, _)) => uid,
+                None => return,
+            };
+
+            match chat_service::mark_read(pool, &household_id, &user_id, &message_id).await {
+                Ok(marker) => {
+                    ws_manager
+                        .broadcast_messages_read(&household_id, marker)
+                        .await;
+                }
+                Err(chat_service::ChatError::NotFound) => {
+                    ws_manager
+                        .send_to_session(
+                            session_id,
+                            WsServerMessage::Error {
+                                code: "not_found".to_string(),
+                                message: "Message not found".to_string(),
+                            },
+                        )
+                        .await;
+                }
+                Err(e) => {
+                    log::error!("Error marking messages read via WebSocket: {:?}", e);
+                    ws_manager
+                        .send_to_session(
+                            session_id,
+                            WsServerMessage::Error {
+                                code: "mark_read_failed".to_string(),
+                                message: "Failed to mark messages as read".to_string(),
+                            },
+                        )
+                        .await;
+                }
+            }
+        }
+
         WsClientMessage::Ping => {
             ws_manager
                 .send_to_session(session_id, WsServerMessage::Pong)
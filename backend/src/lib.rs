@@ -6,6 +6,7 @@ pub mod handlers;
 pub mod middleware;
 pub mod models;
 pub mod services;
+pub mod telemetry;
 
 #[cfg(test)]
 pub mod test_utils;
@@ -1,11 +1,12 @@
 use actix_cors::Cors;
 use actix_files::{Files, NamedFile};
-use actix_web::{middleware::Logger, web, App, HttpServer};
+use actix_web::{middleware::{from_fn, Logger}, web, App, HttpServer};
+use tracing_actix_web::TracingLogger;
 use sqlx::sqlite::SqlitePoolOptions;
 use std::sync::Arc;
 
 use backend::config::Config;
-use backend::{handlers, middleware, models, services};
+use backend::{handlers, middleware, models, services, telemetry};
 
 async fn index(config: web::Data<models::AppState>) -> actix_web::Result<NamedFile> {
     let static_path = config.config.static_files_path.as_deref().unwrap_or("./static");
@@ -17,12 +18,12 @@ async fn main() -> std::io::Result<()> {
     // Load .env file if present
     dotenvy::dotenv().ok();
 
-    // Initialize logger
-    env_logger::init_from_env(env_logger::Env::default().default_filter_or("info"));
-
     // Load configuration
     let config = Config::from_env().expect("Failed to load configuration");
 
+    // Initialize logging and, if configured, span export
+    let tracer_provider = telemetry::init(&config);
+
     log::info!("Starting server at {}:{}", config.host, config.port);
 
     if let Some(ref path) = config.static_files_path {
@@ -44,29 +45,78 @@ async fn main() -> std::io::Result<()> {
 
     log::info!("Database migrations completed");
 
+    // Configure Web Push if a VAPID key is available
+    let push = match config.vapid_private_key.as_deref() {
+        Some(key) => {
+            let vapid_keys = services::web_push::VapidKeys::from_base64(key)
+                .expect("VAPID_PRIVATE_KEY must be a base64url-encoded P-256 private key");
+            log::info!("Web Push notifications enabled");
+            Some(services::notifications::PushSender {
+                vapid_keys,
+                subject: config.vapid_subject.clone(),
+            })
+        }
+        None => {
+            log::info!("VAPID_PRIVATE_KEY not set, Web Push notifications disabled");
+            None
+        }
+    };
+    // Configure the Matrix/Telegram chat bridge if credentials are available
+    let chat_bridge = services::chat_bridge::ChatBridge::from_config(&config);
+    match chat_bridge {
+        Some(ref bridge) => log::info!("Chat bridge enabled for {} chat(s)", bridge.targets().len()),
+        None => log::info!("No Matrix or Telegram chat configured, chat bridge disabled"),
+    }
+
+    let reminders = push.clone().map(|sender| services::notifications::ReminderConfig {
+        sender,
+        lead_minutes: config.push_reminder_lead_minutes,
+    });
+
     // Start background job scheduler
-    let pool_for_scheduler = Arc::new(pool.clone());
-    tokio::spawn(async move {
-        services::background_jobs::start_scheduler(
-            pool_for_scheduler,
-            services::background_jobs::JobConfig::default(),
-        )
-        .await;
+    let job_runner = Arc::new(services::background_jobs::JobRunner::new(
+        pool.clone(),
+        services::background_jobs::JobConfig::from_config(
+            &config,
+            reminders,
+            services::mail::MailSettings::from_config(&config),
+            chat_bridge.clone(),
+            Some(services::backup::BackupSettings::from_config(&config)),
+        ),
+    ));
+    let runner_for_scheduler = job_runner.clone();
+    let scheduler = tokio::spawn(async move {
+        services::background_jobs::start_scheduler(runner_for_scheduler).await;
     });
     log::info!("Background job scheduler started");
 
     // Create WebSocket manager
     let ws_manager = services::websocket::WsManager::new();
-    let ws_manager_data = web::Data::new(ws_manager);
+    let ws_manager_data = web::Data::new(ws_manager.clone());
 
     // Create rate limiter for login (5 attempts per 15 minutes)
     let login_rate_limiter = Arc::new(middleware::RateLimiter::new(5, 15 * 60));
 
+    // Create rate limiter for mutating requests (disabled when RATE_LIMIT_PER_MINUTE=0)
+    let mutation_rate_limiter = if config.rate_limit_per_minute > 0 {
+        Some(Arc::new(middleware::TokenBucketLimiter::new(
+            config.rate_limit_burst,
+            config.rate_limit_per_minute,
+        )))
+    } else {
+        log::info!("RATE_LIMIT_PER_MINUTE is 0, rate limiting of mutating requests disabled");
+        None
+    };
+
     // Create app state
     let app_state = web::Data::new(models::AppState {
         db: pool.clone(),
         config: config.clone(),
         login_rate_limiter,
+        mutation_rate_limiter,
+        jobs: job_runner.clone(),
+        push,
+        chat_bridge,
     });
 
     // Create pool and config data for WebSocket handler
@@ -74,9 +124,10 @@ async fn main() -> std::io::Result<()> {
     let config_data = web::Data::new(config.clone());
 
     let static_files_path = config.static_files_path.clone();
+    let openapi_ui_enabled = config.openapi_ui_enabled;
 
-    // Start HTTP server
-    HttpServer::new(move || {
+    // Start HTTP server; signals are handled below so shutdown can run in order
+    let server = HttpServer::new(move || {
         let ws_manager = ws_manager_data.clone();
         let pool = pool_data.clone();
         let config = config_data.clone();
@@ -89,7 +140,8 @@ async fn main() -> std::io::Result<()> {
                 })
             })
             .allowed_methods(vec!["GET", "POST", "PUT", "DELETE", "OPTIONS"])
-            .allowed_headers(vec!["Authorization", "Content-Type"])
+            .allowed_headers(vec!["Authorization", "Content-Type", middleware::idempotency::IDEMPOTENCY_KEY_HEADER])
+            .expose_headers(vec![middleware::request_id::REQUEST_ID_HEADER])
             .max_age(3600);
 
         let mut app = App::new()
@@ -97,22 +149,99 @@ async fn main() -> std::io::Result<()> {
             .app_data(ws_manager.clone())
             .app_data(pool.clone())
             .app_data(config.clone())
-            .wrap(Logger::default())
+            .wrap(from_fn(middleware::rate_limit::limit_mutations))
+            // Inside the API token check, so requests authenticated by a token know their user
+            .wrap(from_fn(middleware::idempotency::replay_idempotent_requests))
+            .wrap(from_fn(middleware::api_token::authenticate_api_tokens))
+            // Inside the access logger, so it sees the X-Request-Id response header
+            .wrap(from_fn(middleware::request_id::return_request_id))
+            .wrap(Logger::new(r#"%a "%r" %s %b "%{Referer}i" "%{User-Agent}i" %T req=%{x-request-id}o"#))
+            // Outermost of the logging layers, as it assigns the request ID
+            .wrap(TracingLogger::<middleware::request_id::RequestIdRootSpan>::new())
             .wrap(cors)
-            .configure(handlers::configure_routes)
-            .configure(handlers::websocket::configure);
+            // Before the `/api` scope, which would otherwise answer `/api/ws` and `/api/docs` with 404
+            .configure(handlers::websocket::configure)
+            .configure(|cfg| {
+                if openapi_ui_enabled {
+                    handlers::openapi::configure_ui(cfg);
+                }
+            })
+            .configure(handlers::configure_routes);
 
         // Serve static files if path is configured
         if let Some(ref path) = static_files_path {
             app = app
                 .service(Files::new("/pkg", format!("{}/pkg", path)))
                 .service(Files::new("/assets", format!("{}/assets", path)).show_files_listing())
+                // Build output at the root (bundle, sw.js, manifest, icons); anything else is a client-side route
+                .service(
+                    Files::new("/", path.clone())
+                        .index_file("index.html")
+                        .default_handler(web::route().to(index)),
+                )
                 .default_service(web::route().to(index));
         }
 
         app
     })
     .bind((config.host.as_str(), config.port))?
-    .run()
-    .await
+    .disable_signals()
+    .run();
+
+    // Graceful shutdown: let running jobs (e.g. period finalization) finish without
+    // starting new ones, close WebSocket sessions with a going-away frame, then stop
+    // the HTTP server after in-flight requests are done
+    let server_handle = server.handle();
+    tokio::spawn(async move {
+        shutdown_signal().await;
+        log::info!("Shutdown requested, waiting for background jobs");
+        job_runner.shutdown().await;
+        let _ = scheduler.await;
+        log::info!("Closing WebSocket sessions");
+        ws_manager.close_all(std::time::Duration::from_secs(WS_CLOSE_TIMEOUT_SECS)).await;
+        log::info!("Stopping HTTP server");
+        server_handle.stop(true).await;
+    });
+
+    server.await?;
+    log::info!("Server stopped");
+    if let Some(provider) = tracer_provider {
+        if let Err(e) = provider.shutdown() {
+            eprintln!("Failed to flush exported spans: {}", e);
+        }
+    }
+    Ok(())
+}
+
+/// Seconds to wait for WebSocket clients to acknowledge the close frame
+const WS_CLOSE_TIMEOUT_SECS: u64 = 5;
+
+/// Resolves on Ctrl+C or, on Unix, SIGTERM (as sent by systemd or `docker stop`)
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            log::error!("Failed to listen for Ctrl+C: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                log::error!("Failed to listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
 }
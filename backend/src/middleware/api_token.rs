@@ -0,0 +1,75 @@
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::Method;
+use actix_web::middleware::Next;
+use actix_web::{web, Error, HttpMessage, HttpResponse};
+use shared::ApiError;
+
+use crate::models::AppState;
+use crate::services::api_tokens;
+
+/// Methods a read-only token may use
+fn is_read_only_method(method: &Method) -> bool {
+    matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+}
+
+/// The API token from a `Bearer` Authorization header, if the header carries one
+fn bearer_api_token(req: &ServiceRequest) -> Option<String> {
+    let header = req.headers().get("Authorization")?.to_str().ok()?;
+    let token = header.strip_prefix("Bearer ")?;
+    api_tokens::is_api_token(token).then(|| token.to_string())
+}
+
+/// Middleware resolving personal API tokens. A valid token is attached to the request,
+/// where `extract_user_id` picks it up instead of a JWT. Unknown tokens are rejected
+/// with 401, and read-only tokens with 403 on anything but reads.
+pub async fn authenticate_api_tokens(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, Error> {
+    let state = req.app_data::<web::Data<AppState>>().cloned();
+    if let (Some(token), Some(state)) = (bearer_api_token(&req), state) {
+        let rejection = match api_tokens::authenticate(&state.db, &token).await {
+            Ok(Some(auth)) if auth.read_only && !is_read_only_method(req.method()) => {
+                Some(HttpResponse::Forbidden().json(ApiError {
+                    error: "read_only_token".to_string(),
+                    message: "This API token is read-only".to_string(),
+                }))
+            }
+            Ok(Some(auth)) => {
+                req.extensions_mut().insert(auth);
+                None
+            }
+            Ok(None) => Some(HttpResponse::Unauthorized().json(ApiError {
+                error: "unauthorized".to_string(),
+                message: "Invalid or revoked API token".to_string(),
+            })),
+            Err(e) => {
+                log::error!("Error checking API token: {:?}", e);
+                Some(HttpResponse::InternalServerError().json(ApiError {
+                    error: "internal_error".to_string(),
+                    message: "Failed to check API token".to_string(),
+                }))
+            }
+        };
+
+        if let Some(response) = rejection {
+            return Ok(req.into_response(response).map_into_right_body());
+        }
+    }
+
+    next.call(req).await.map(ServiceResponse::map_into_left_body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_only_methods() {
+        assert!(is_read_only_method(&Method::GET));
+        assert!(is_read_only_method(&Method::HEAD));
+        assert!(!is_read_only_method(&Method::POST));
+        assert!(!is_read_only_method(&Method::DELETE));
+    }
+}
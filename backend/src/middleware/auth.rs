@@ -1,6 +1,7 @@
-use actix_web::HttpRequest;
+use actix_web::{HttpMessage, HttpRequest};
 use uuid::Uuid;
 
+use crate::services::api_tokens::ApiTokenAuth;
 use crate::services::auth as auth_service;
 
 /// JWT Claims structure
@@ -16,8 +17,13 @@ pub fn validate_token(token: &str, jwt_secret: &str) -> Result<TokenClaims, Auth
         .map_err(|_| AuthMiddlewareError::InvalidToken)
 }
 
-/// Extract user ID from the Authorization header
+/// Extract user ID from the Authorization header.
+/// Personal API tokens have already been resolved by the `api_token` middleware.
 pub fn extract_user_id(req: &HttpRequest, jwt_secret: &str) -> Result<Uuid, AuthMiddlewareError> {
+    if let Some(auth) = req.extensions().get::<ApiTokenAuth>() {
+        return Ok(auth.user_id);
+    }
+
     let auth_header = req
         .headers()
         .get("Authorization")
@@ -37,6 +43,12 @@ pub fn extract_user_id(req: &HttpRequest, jwt_secret: &str) -> Result<Uuid, Auth
         .map_err(|_| AuthMiddlewareError::InvalidToken)
 }
 
+/// Whether the request is authenticated with a personal API token rather than a login session.
+/// Token management requires a session, so a leaked token can't be used to mint new ones.
+pub fn is_api_token_request(req: &HttpRequest) -> bool {
+    req.extensions().get::<ApiTokenAuth>().is_some()
+}
+
 #[derive(Debug)]
 pub enum AuthMiddlewareError {
     MissingToken,
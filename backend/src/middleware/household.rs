@@ -0,0 +1,192 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use actix_web::dev::Payload;
+use actix_web::error::InternalError;
+use actix_web::{web, FromRequest, HttpRequest, HttpResponse};
+use shared::{ApiError, HouseholdFeature, HouseholdMembership, HouseholdSettings, Permission, Role};
+use uuid::Uuid;
+
+use crate::middleware::auth::extract_user_id;
+use crate::models::AppState;
+use crate::services::{household_settings as settings_service, households as household_service, permissions};
+
+/// Path segment holding the household ID in `/households/{household_id}/...`
+const HOUSEHOLD_ID_SEGMENT: &str = "household_id";
+
+/// The caller's access to the household in the request path. Taking this as a
+/// handler argument authenticates the caller, parses the household ID and
+/// checks membership before the handler runs; requests from non-members are
+/// answered with 403 and never reach the handler. Neither do members' requests
+/// to a module the household has switched off.
+#[derive(Debug, Clone)]
+pub struct HouseholdContext {
+    pub household_id: Uuid,
+    pub user_id: Uuid,
+    pub membership: HouseholdMembership,
+    pub settings: HouseholdSettings,
+}
+
+impl HouseholdContext {
+    pub fn role(&self) -> Role {
+        self.membership.role
+    }
+
+    pub async fn has_permission(&self, state: &AppState, permission: Permission) -> bool {
+        permissions::has_permission(&state.db, &self.household_id, &self.user_id, permission).await
+    }
+
+    /// 403 when the household has switched the module off. Needed where the
+    /// module isn't known from the path, e.g. for attachments.
+    pub fn check_feature(&self, feature: HouseholdFeature) -> Result<(), HttpResponse> {
+        if self.settings.is_feature_enabled(feature) {
+            return Ok(());
+        }
+        Err(feature_disabled(feature))
+    }
+
+    /// 404 for an item that does not belong to this household. Items of other
+    /// households are reported as missing rather than forbidden, so IDs can't be probed.
+    pub fn check_owns(&self, item_household_id: &Uuid, item: &str) -> Result<(), HttpResponse> {
+        if *item_household_id == self.household_id {
+            return Ok(());
+        }
+        Err(HttpResponse::NotFound().json(ApiError {
+            error: "not_found".to_string(),
+            message: format!("{} not found", item),
+        }))
+    }
+}
+
+/// The module addressed by `/api/households/{household_id}/{module}/...`
+fn feature_for_path(path: &str) -> Option<HouseholdFeature> {
+    let mut segments = path.trim_start_matches('/').split('/');
+    match (segments.next(), segments.next(), segments.next(), segments.next()) {
+        (Some("api"), Some("households"), Some(_), Some(module)) => HouseholdFeature::from_path_segment(module),
+        _ => None,
+    }
+}
+
+fn feature_disabled(feature: HouseholdFeature) -> HttpResponse {
+    api_error(
+        HttpResponse::Forbidden(),
+        "feature_disabled",
+        &format!("The {} module is not enabled for this household", feature.as_str()),
+    )
+}
+
+fn api_error(mut response: actix_web::HttpResponseBuilder, error: &str, message: &str) -> HttpResponse {
+    response.json(ApiError {
+        error: error.to_string(),
+        message: message.to_string(),
+    })
+}
+
+async fn load_context(req: &HttpRequest) -> Result<HouseholdContext, HttpResponse> {
+    let Some(state) = req.app_data::<web::Data<AppState>>() else {
+        log::error!("HouseholdContext used without AppState");
+        return Err(api_error(HttpResponse::InternalServerError(), "internal_error", "Server misconfigured"));
+    };
+
+    let user_id = extract_user_id(req, &state.config.jwt_secret)
+        .map_err(|_| api_error(HttpResponse::Unauthorized(), "unauthorized", "Invalid or missing token"))?;
+
+    let household_id = req
+        .match_info()
+        .get(HOUSEHOLD_ID_SEGMENT)
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .ok_or_else(|| api_error(HttpResponse::BadRequest(), "invalid_id", "Invalid household ID format"))?;
+
+    let membership = match household_service::get_membership(&state.db, &household_id, &user_id).await {
+        Ok(Some(membership)) => membership,
+        Ok(None) => {
+            return Err(api_error(
+                HttpResponse::Forbidden(),
+                "forbidden",
+                "You are not a member of this household",
+            ))
+        }
+        Err(e) => {
+            log::error!("Error loading membership: {:?}", e);
+            return Err(api_error(HttpResponse::InternalServerError(), "internal_error", "Failed to check membership"));
+        }
+    };
+
+    let settings = settings_service::get_or_create_settings(&state.db, &household_id)
+        .await
+        .map_err(|e| {
+            log::error!("Error loading household settings: {:?}", e);
+            api_error(HttpResponse::InternalServerError(), "internal_error", "Failed to load household settings")
+        })?;
+
+    if let Some(feature) = feature_for_path(req.path()).filter(|feature| !settings.is_feature_enabled(*feature)) {
+        return Err(feature_disabled(feature));
+    }
+
+    Ok(HouseholdContext {
+        household_id,
+        user_id,
+        membership,
+        settings,
+    })
+}
+
+impl FromRequest for HouseholdContext {
+    type Error = actix_web::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let req = req.clone();
+        Box::pin(async move {
+            load_context(&req)
+                .await
+                .map_err(|response| InternalError::from_response("household access denied", response).into())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn test_items_of_other_households_are_not_found() {
+        let household_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let ctx = HouseholdContext {
+            household_id,
+            user_id,
+            membership: HouseholdMembership {
+                id: Uuid::new_v4(),
+                household_id,
+                user_id,
+                role: Role::Member,
+                points: 0,
+                joined_at: Utc::now(),
+                vacation_start: None,
+                vacation_end: None,
+            },
+            settings: HouseholdSettings::default(),
+        };
+
+        assert!(ctx.check_owns(&household_id, "Note").is_ok());
+        let response = ctx.check_owns(&Uuid::new_v4(), "Note").unwrap_err();
+        assert_eq!(response.status(), actix_web::http::StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_feature_for_path() {
+        let household_id = Uuid::new_v4();
+        assert_eq!(
+            feature_for_path(&format!("/api/households/{}/recipes/abc", household_id)),
+            Some(HouseholdFeature::Meals)
+        );
+        assert_eq!(
+            feature_for_path(&format!("/api/households/{}/chat", household_id)),
+            Some(HouseholdFeature::Chat)
+        );
+        assert_eq!(feature_for_path(&format!("/api/households/{}/tasks", household_id)), None);
+        assert_eq!(feature_for_path(&format!("/api/households/{}", household_id)), None);
+    }
+}
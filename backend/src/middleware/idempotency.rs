@@ -0,0 +1,142 @@
+use actix_web::body::{self, BoxBody, EitherBody, MessageBody};
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::{Method, StatusCode};
+use actix_web::middleware::Next;
+use actix_web::{web, Error, HttpResponse};
+use chrono::Utc;
+use shared::ApiError;
+
+use crate::middleware::auth::extract_user_id;
+use crate::models::AppState;
+use crate::services::idempotency::{self, IdempotencyClaim, MAX_KEY_LEN};
+
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// Set on responses that were replayed from an earlier request
+pub const IDEMPOTENT_REPLAYED_HEADER: &str = "Idempotent-Replayed";
+
+/// Endpoints that honour the Idempotency-Key header: task and punishment
+/// completions, reward purchases and points adjustments
+pub(crate) fn is_idempotent_route(method: &Method, path: &str) -> bool {
+    *method == Method::POST
+        && (path.ends_with("/complete")
+            || path.ends_with("/purchase")
+            || (path.contains("/members/") && path.ends_with("/points")))
+}
+
+fn api_error(status: StatusCode, error: &str, message: &str) -> HttpResponse {
+    HttpResponse::build(status).json(ApiError {
+        error: error.to_string(),
+        message: message.to_string(),
+    })
+}
+
+/// Middleware making retried requests safe. The first successful response for
+/// an `Idempotency-Key` is stored and returned again for every retry with the
+/// same key instead of running the request a second time. Failed requests
+/// release the key so they can be retried.
+pub async fn replay_idempotent_requests(
+    req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody, BoxBody>>, Error> {
+    let key = req
+        .headers()
+        .get(IDEMPOTENCY_KEY_HEADER)
+        .map(|value| value.to_str().unwrap_or_default().trim().to_string());
+    let state = req.app_data::<web::Data<AppState>>().cloned();
+    let (Some(key), Some(state)) = (key, state) else {
+        return next.call(req).await.map(ServiceResponse::map_into_left_body);
+    };
+    if !is_idempotent_route(req.method(), req.path()) {
+        return next.call(req).await.map(ServiceResponse::map_into_left_body);
+    }
+    // Unauthenticated requests are rejected by the handler anyway
+    let Ok(user_id) = extract_user_id(req.request(), &state.config.jwt_secret) else {
+        return next.call(req).await.map(ServiceResponse::map_into_left_body);
+    };
+
+    if key.is_empty() || key.len() > MAX_KEY_LEN {
+        let response = api_error(
+            StatusCode::BAD_REQUEST,
+            "invalid_idempotency_key",
+            "Idempotency-Key must be between 1 and 255 characters",
+        );
+        return Ok(req.into_response(response).map_into_right_body());
+    }
+
+    let path = req.path().to_string();
+    let early_response = match idempotency::claim(&state.db, &user_id, &key, &path, Utc::now()).await {
+        Ok(IdempotencyClaim::New) => None,
+        Ok(IdempotencyClaim::Replay { status, body }) => Some(
+            HttpResponse::build(StatusCode::from_u16(status).unwrap_or(StatusCode::OK))
+                .content_type("application/json")
+                .insert_header((IDEMPOTENT_REPLAYED_HEADER, "true"))
+                .body(body),
+        ),
+        Ok(IdempotencyClaim::InProgress) => Some(api_error(
+            StatusCode::CONFLICT,
+            "request_in_progress",
+            "A request with this Idempotency-Key is still being processed",
+        )),
+        Ok(IdempotencyClaim::KeyReused) => Some(api_error(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "idempotency_key_reused",
+            "This Idempotency-Key was already used for a different request",
+        )),
+        Err(e) => {
+            log::error!("Error checking idempotency key: {:?}", e);
+            Some(api_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "internal_error",
+                "Failed to check idempotency key",
+            ))
+        }
+    };
+    if let Some(response) = early_response {
+        return Ok(req.into_response(response).map_into_right_body());
+    }
+
+    let response = match next.call(req).await {
+        Ok(response) if response.status().is_success() => response,
+        result => {
+            if let Err(e) = idempotency::release(&state.db, &user_id, &key).await {
+                log::error!("Error releasing idempotency key: {:?}", e);
+            }
+            return result.map(ServiceResponse::map_into_left_body);
+        }
+    };
+
+    let status = response.status();
+    let (request, response) = response.into_parts();
+    let (response, response_body) = response.into_parts();
+    let bytes = body::to_bytes(response_body)
+        .await
+        .map_err(|e| actix_web::error::ErrorInternalServerError(e.into().to_string()))?;
+    if let Err(e) =
+        idempotency::store_response(&state.db, &user_id, &key, status.as_u16(), &String::from_utf8_lossy(&bytes)).await
+    {
+        log::error!("Error storing idempotent response: {:?}", e);
+    }
+
+    let response = response.set_body(bytes).map_into_boxed_body();
+    Ok(ServiceResponse::new(request, response).map_into_right_body())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_idempotent_routes() {
+        assert!(is_idempotent_route(&Method::POST, "/api/households/h1/tasks/t1/complete"));
+        assert!(is_idempotent_route(
+            &Method::POST,
+            "/api/households/h1/punishments/user-punishments/p1/complete"
+        ));
+        assert!(is_idempotent_route(&Method::POST, "/api/households/h1/rewards/r1/purchase"));
+        assert!(is_idempotent_route(&Method::POST, "/api/households/h1/members/u1/points"));
+        assert!(!is_idempotent_route(&Method::POST, "/api/households/h1/tasks/t1/uncomplete"));
+        assert!(!is_idempotent_route(&Method::GET, "/api/households/h1/members/u1/points"));
+        assert!(!is_idempotent_route(&Method::POST, "/api/households/h1/tasks"));
+    }
+}
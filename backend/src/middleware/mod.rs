@@ -1,4 +1,9 @@
+pub mod api_token;
 pub mod auth;
+pub mod household;
+pub mod idempotency;
 pub mod rate_limit;
+pub mod request_id;
+pub mod share_link;
 
-pub use rate_limit::RateLimiter;
+pub use rate_limit::{client_ip, RateLimiter, TokenBucketLimiter};
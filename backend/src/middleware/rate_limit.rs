@@ -1,7 +1,19 @@
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::Method;
+use actix_web::middleware::Next;
+use actix_web::{web, Error, HttpRequest, HttpResponse};
+use shared::ApiError;
 use std::collections::HashMap;
 use std::sync::Mutex;
 use std::time::{Duration, Instant};
 
+use crate::config::Config;
+use crate::models::AppState;
+
+/// Buckets are pruned once the map grows beyond this many keys
+const MAX_TRACKED_KEYS: usize = 10_000;
+
 /// In-memory rate limiter for protecting against brute force attacks
 pub struct RateLimiter {
     /// Maps keys (e.g., IP address or username) to list of attempt timestamps
@@ -56,7 +68,6 @@ impl RateLimiter {
     }
 
     /// Clear all attempts for a key (e.g., after successful login)
-    #[allow(dead_code)]
     pub fn clear(&self, key: &str) {
         let mut attempts = self.attempts.lock().unwrap();
         attempts.remove(key);
@@ -80,6 +91,126 @@ impl RateLimiter {
     }
 }
 
+/// Token bucket rate limiter for general API traffic.
+/// Each key may burst up to `capacity` requests; tokens refill at a steady rate.
+pub struct TokenBucketLimiter {
+    buckets: Mutex<HashMap<String, TokenBucket>>,
+    capacity: f64,
+    refill_per_sec: f64,
+}
+
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucketLimiter {
+    /// Create a new token bucket limiter
+    ///
+    /// # Arguments
+    /// * `capacity` - Maximum burst size
+    /// * `per_minute` - Sustained requests per minute
+    pub fn new(capacity: u32, per_minute: u32) -> Self {
+        Self {
+            buckets: Mutex::new(HashMap::new()),
+            capacity: f64::from(capacity.max(1)),
+            refill_per_sec: f64::from(per_minute) / 60.0,
+        }
+    }
+
+    /// Take a token for a key. When the bucket is empty, returns how long until the next token.
+    pub fn try_acquire(&self, key: &str) -> Result<(), Duration> {
+        self.try_acquire_at(key, Instant::now())
+    }
+
+    fn try_acquire_at(&self, key: &str, now: Instant) -> Result<(), Duration> {
+        let mut buckets = self.buckets.lock().unwrap();
+
+        if buckets.len() >= MAX_TRACKED_KEYS {
+            // Drop keys whose bucket has refilled completely; they behave like new keys anyway
+            let capacity = self.capacity;
+            let refill_per_sec = self.refill_per_sec;
+            buckets.retain(|_, bucket| {
+                let elapsed = now.saturating_duration_since(bucket.last_refill).as_secs_f64();
+                bucket.tokens + elapsed * refill_per_sec < capacity
+            });
+        }
+
+        let bucket = buckets.entry(key.to_string()).or_insert(TokenBucket {
+            tokens: self.capacity,
+            last_refill: now,
+        });
+
+        let elapsed = now.saturating_duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else if self.refill_per_sec > 0.0 {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / self.refill_per_sec))
+        } else {
+            Err(Duration::from_secs(60))
+        }
+    }
+}
+
+/// Client address to key rate limits on. Forwarded headers are only believed when the
+/// connection comes from one of `TRUSTED_PROXIES`; otherwise a client could pick a new
+/// `X-Forwarded-For` value for every attempt and never hit a limit.
+pub fn client_ip(req: &HttpRequest, config: &Config) -> String {
+    match req.peer_addr().map(|addr| addr.ip()) {
+        Some(peer) if config.trusted_proxies.contains(&peer) => req
+            .connection_info()
+            .realip_remote_addr()
+            .unwrap_or("unknown")
+            .to_string(),
+        Some(peer) => peer.to_string(),
+        None => "unknown".to_string(),
+    }
+}
+
+/// Whether a request method changes state and is subject to the mutation limit
+fn is_mutating(method: &Method) -> bool {
+    matches!(*method, Method::POST | Method::PUT | Method::PATCH | Method::DELETE)
+}
+
+/// Middleware limiting mutating requests per user (or per IP for anonymous requests).
+/// Responds with 429 and a `Retry-After` header once a client's bucket is empty.
+pub async fn limit_mutations(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, Error> {
+    let limiter = req
+        .app_data::<web::Data<AppState>>()
+        .filter(|_| is_mutating(req.method()))
+        .and_then(|state| {
+            state.mutation_rate_limiter.as_ref().map(|limiter| {
+                let key = match crate::middleware::auth::extract_user_id(req.request(), &state.config.jwt_secret) {
+                    Ok(user_id) => format!("user:{}", user_id),
+                    Err(_) => format!("ip:{}", client_ip(req.request(), &state.config)),
+                };
+                (limiter.clone(), key)
+            })
+        });
+
+    if let Some((limiter, key)) = limiter {
+        if let Err(retry_after) = limiter.try_acquire(&key) {
+            log::warn!("Rate limit exceeded for {}", key);
+            let response = HttpResponse::TooManyRequests()
+                .insert_header(("Retry-After", retry_after.as_secs().max(1).to_string()))
+                .json(ApiError {
+                    error: "rate_limited".to_string(),
+                    message: "Too many requests. Please slow down and try again later.".to_string(),
+                });
+            return Ok(req.into_response(response).map_into_right_body());
+        }
+    }
+
+    next.call(req).await.map(ServiceResponse::map_into_left_body)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -150,4 +281,71 @@ mod tests {
         limiter.record("test_key");
         assert_eq!(limiter.remaining("test_key"), 1);
     }
+
+    #[test]
+    fn test_client_ip_ignores_forwarded_header_from_untrusted_peer() {
+        let config = crate::test_utils::create_test_config();
+        let req = actix_web::test::TestRequest::default()
+            .peer_addr("203.0.113.7:4711".parse().unwrap())
+            .insert_header(("X-Forwarded-For", "198.51.100.1"))
+            .to_http_request();
+
+        assert_eq!(client_ip(&req, &config), "203.0.113.7");
+    }
+
+    #[test]
+    fn test_client_ip_uses_forwarded_header_from_trusted_proxy() {
+        let mut config = crate::test_utils::create_test_config();
+        config.trusted_proxies = vec!["127.0.0.1".parse().unwrap()];
+        let req = actix_web::test::TestRequest::default()
+            .peer_addr("127.0.0.1:4711".parse().unwrap())
+            .insert_header(("X-Forwarded-For", "198.51.100.1"))
+            .to_http_request();
+
+        assert_eq!(client_ip(&req, &config), "198.51.100.1");
+    }
+
+    #[test]
+    fn test_token_bucket_allows_burst_then_limits() {
+        let limiter = TokenBucketLimiter::new(3, 60);
+        let now = Instant::now();
+
+        for _ in 0..3 {
+            assert!(limiter.try_acquire_at("test_key", now).is_ok());
+        }
+        let retry_after = limiter.try_acquire_at("test_key", now).unwrap_err();
+        assert_eq!(retry_after.as_secs(), 1);
+
+        // Different key has its own bucket
+        assert!(limiter.try_acquire_at("other_key", now).is_ok());
+    }
+
+    #[test]
+    fn test_token_bucket_refills_over_time() {
+        let limiter = TokenBucketLimiter::new(2, 60); // one token per second
+        let now = Instant::now();
+
+        assert!(limiter.try_acquire_at("test_key", now).is_ok());
+        assert!(limiter.try_acquire_at("test_key", now).is_ok());
+        assert!(limiter.try_acquire_at("test_key", now).is_err());
+
+        let later = now + Duration::from_millis(1500);
+        assert!(limiter.try_acquire_at("test_key", later).is_ok());
+        assert!(limiter.try_acquire_at("test_key", later).is_err());
+
+        // Refill never exceeds capacity
+        let much_later = later + Duration::from_secs(60);
+        assert!(limiter.try_acquire_at("test_key", much_later).is_ok());
+        assert!(limiter.try_acquire_at("test_key", much_later).is_ok());
+        assert!(limiter.try_acquire_at("test_key", much_later).is_err());
+    }
+
+    #[test]
+    fn test_is_mutating() {
+        assert!(is_mutating(&Method::POST));
+        assert!(is_mutating(&Method::PUT));
+        assert!(is_mutating(&Method::DELETE));
+        assert!(!is_mutating(&Method::GET));
+        assert!(!is_mutating(&Method::OPTIONS));
+    }
 }
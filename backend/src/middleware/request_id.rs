@@ -0,0 +1,136 @@
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::header::{HeaderName, HeaderValue};
+use actix_web::middleware::Next;
+use actix_web::{Error, HttpMessage};
+use tracing::field::Empty;
+use tracing::Span;
+use tracing_actix_web::{DefaultRootSpanBuilder, RootSpanBuilder};
+use uuid::Uuid;
+
+pub const REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+/// Longest request ID accepted from a client or proxy
+const MAX_REQUEST_ID_LEN: usize = 64;
+
+/// ID of the request, stored in the request extensions by [`RequestIdRootSpan`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
+/// Reuse an ID set by a reverse proxy if it looks sane, otherwise generate one
+fn request_id_for(req: &ServiceRequest) -> String {
+    req.headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|id| {
+            !id.is_empty()
+                && id.len() <= MAX_REQUEST_ID_LEN
+                && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        })
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string())
+}
+
+/// Root span for `TracingLogger` carrying the request ID, so every span and log
+/// line of a request can be found by it. The query string is left out of
+/// `http.target` because share links and WebSocket logins carry tokens in it.
+pub struct RequestIdRootSpan;
+
+impl RootSpanBuilder for RequestIdRootSpan {
+    fn on_request_start(request: &ServiceRequest) -> Span {
+        let request_id = request_id_for(request);
+        let method = request.method().as_str();
+        let route = request.match_pattern().unwrap_or_else(|| "default".to_string());
+        let span = tracing::info_span!(
+            "HTTP request",
+            http.method = %method,
+            http.route = %route,
+            http.target = %request.path(),
+            http.status_code = Empty,
+            otel.name = %format!("{} {}", method, route),
+            otel.kind = "server",
+            otel.status_code = Empty,
+            request_id = %request_id,
+            exception.message = Empty,
+            exception.details = Empty,
+        );
+        request.extensions_mut().insert(RequestId(request_id));
+        span
+    }
+
+    fn on_request_end<B: MessageBody>(span: Span, outcome: &Result<ServiceResponse<B>, Error>) {
+        DefaultRootSpanBuilder::on_request_end(span, outcome);
+    }
+}
+
+/// Middleware returning the request ID in the `X-Request-Id` header, so a
+/// user-reported error can be matched with the server log. Must run inside
+/// `TracingLogger`, which assigns the ID.
+pub async fn return_request_id(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let request_id = req.extensions().get::<RequestId>().cloned();
+    let mut response = next.call(req).await?;
+    if let Some(value) = request_id.and_then(|id| HeaderValue::from_str(&id.0).ok()) {
+        response
+            .headers_mut()
+            .insert(HeaderName::from_static("x-request-id"), value);
+    }
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::middleware::from_fn;
+    use actix_web::{test, web, App, HttpRequest, HttpResponse};
+    use tracing_actix_web::TracingLogger;
+
+    async fn echo_request_id(req: HttpRequest) -> HttpResponse {
+        let request_id = req.extensions().get::<RequestId>().cloned();
+        HttpResponse::Ok().body(request_id.map(|id| id.0).unwrap_or_default())
+    }
+
+    #[actix_web::test]
+    async fn test_request_id_is_generated_and_returned() {
+        let app = test::init_service(
+            App::new()
+                .wrap(from_fn(return_request_id))
+                .wrap(TracingLogger::<RequestIdRootSpan>::new())
+                .route("/", web::get().to(echo_request_id)),
+        )
+        .await;
+
+        let response = test::call_service(&app, test::TestRequest::get().uri("/").to_request()).await;
+        let header = response.headers().get(REQUEST_ID_HEADER).unwrap().to_str().unwrap().to_string();
+        assert!(Uuid::parse_str(&header).is_ok());
+        let body = test::read_body(response).await;
+        assert_eq!(body, header.as_bytes());
+    }
+
+    #[actix_web::test]
+    async fn test_request_id_from_proxy_is_kept_if_valid() {
+        let app = test::init_service(
+            App::new()
+                .wrap(from_fn(return_request_id))
+                .wrap(TracingLogger::<RequestIdRootSpan>::new())
+                .route("/", web::get().to(echo_request_id)),
+        )
+        .await;
+
+        let request = test::TestRequest::get()
+            .uri("/")
+            .insert_header((REQUEST_ID_HEADER, "proxy-123"))
+            .to_request();
+        let response = test::call_service(&app, request).await;
+        assert_eq!(response.headers().get(REQUEST_ID_HEADER).unwrap(), "proxy-123");
+
+        let request = test::TestRequest::get()
+            .uri("/")
+            .insert_header((REQUEST_ID_HEADER, "bad id!"))
+            .to_request();
+        let response = test::call_service(&app, request).await;
+        assert_ne!(response.headers().get(REQUEST_ID_HEADER).unwrap(), "bad id!");
+    }
+}
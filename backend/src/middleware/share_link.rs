@@ -0,0 +1,65 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use actix_web::dev::Payload;
+use actix_web::error::InternalError;
+use actix_web::{web, FromRequest, HttpRequest, HttpResponse};
+use shared::{ApiError, ShareLink};
+
+use crate::models::AppState;
+use crate::services::share_links as share_link_service;
+
+/// Path segment holding the token in `/shared/{token}`
+const TOKEN_SEGMENT: &str = "token";
+
+/// Guest access granted by the share link in the request path. Unlike
+/// `HouseholdContext` this needs no login, but it only ever unlocks the one
+/// resource the link was created for; unknown, revoked and expired tokens are
+/// answered with 404 and never reach the handler.
+#[derive(Debug, Clone)]
+pub struct ShareContext {
+    pub link: ShareLink,
+}
+
+fn api_error(mut response: actix_web::HttpResponseBuilder, error: &str, message: &str) -> HttpResponse {
+    response.json(ApiError {
+        error: error.to_string(),
+        message: message.to_string(),
+    })
+}
+
+async fn load_context(req: &HttpRequest) -> Result<ShareContext, HttpResponse> {
+    let Some(state) = req.app_data::<web::Data<AppState>>() else {
+        log::error!("ShareContext used without AppState");
+        return Err(api_error(HttpResponse::InternalServerError(), "internal_error", "Server misconfigured"));
+    };
+
+    let token = req.match_info().get(TOKEN_SEGMENT).unwrap_or_default();
+
+    match share_link_service::resolve_token(&state.db, token).await {
+        Ok(link) => Ok(ShareContext { link }),
+        Err(share_link_service::ShareLinkError::NotFound) => Err(api_error(
+            HttpResponse::NotFound(),
+            "not_found",
+            "This link is invalid or has expired",
+        )),
+        Err(e) => {
+            log::error!("Error resolving share link: {:?}", e);
+            Err(api_error(HttpResponse::InternalServerError(), "internal_error", "Failed to load share link"))
+        }
+    }
+}
+
+impl FromRequest for ShareContext {
+    type Error = actix_web::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let req = req.clone();
+        Box::pin(async move {
+            load_context(&req)
+                .await
+                .map_err(|response| InternalError::from_response("share link denied", response).into())
+        })
+    }
+}
@@ -0,0 +1,59 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for monthly allowance payouts
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct AllowancePayoutRow {
+    pub id: String,
+    pub household_id: String,
+    pub user_id: String,
+    pub month: NaiveDate,
+    pub points: i64,
+    pub amount_cents: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AllowancePayoutRow {
+    pub fn to_shared(&self) -> shared::AllowancePayout {
+        shared::AllowancePayout {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            user_id: Uuid::parse_str(&self.user_id).unwrap(),
+            month: self.month,
+            points: self.points,
+            amount_cents: self.amount_cents,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allowance_payout_row_to_shared() {
+        let id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let month = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let row = AllowancePayoutRow {
+            id: id.to_string(),
+            household_id: Uuid::new_v4().to_string(),
+            user_id: user_id.to_string(),
+            month,
+            points: 120,
+            amount_cents: 1200,
+            created_at: Utc::now(),
+        };
+
+        let shared = row.to_shared();
+
+        assert_eq!(shared.id, id);
+        assert_eq!(shared.user_id, user_id);
+        assert_eq!(shared.month, month);
+        assert_eq!(shared.points, 120);
+        assert_eq!(shared.amount_cents, 1200);
+    }
+}
@@ -13,6 +13,7 @@ pub struct AnnouncementRow {
     pub content: String,
     pub starts_at: Option<DateTime<Utc>>,
     pub ends_at: Option<DateTime<Utc>>,
+    pub published_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -27,6 +28,8 @@ impl AnnouncementRow {
             content: self.content.clone(),
             starts_at: self.starts_at,
             ends_at: self.ends_at,
+            published_at: self.published_at,
+            acknowledged_at: None,
             created_at: self.created_at,
             updated_at: self.updated_at,
         }
@@ -52,6 +55,7 @@ mod tests {
             content: "# Important\n\nThis is a test announcement.".to_string(),
             starts_at: Some(now),
             ends_at: None,
+            published_at: Some(now),
             created_at: now,
             updated_at: now,
         };
@@ -65,6 +69,8 @@ mod tests {
         assert_eq!(shared.content, "# Important\n\nThis is a test announcement.");
         assert_eq!(shared.starts_at, Some(now));
         assert_eq!(shared.ends_at, None);
+        assert_eq!(shared.published_at, Some(now));
+        assert_eq!(shared.acknowledged_at, None);
     }
 
     #[test]
@@ -81,6 +87,7 @@ mod tests {
             content: "Content".to_string(),
             starts_at: Some(start),
             ends_at: Some(end),
+            published_at: None,
             created_at: now,
             updated_at: now,
         };
@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for personal API tokens
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ApiTokenRow {
+    pub id: String,
+    pub user_id: String,
+    pub name: String,
+    pub token_hash: String,
+    pub read_only: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl ApiTokenRow {
+    pub fn to_shared(&self) -> shared::ApiToken {
+        shared::ApiToken {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            name: self.name.clone(),
+            read_only: self.read_only,
+            created_at: self.created_at,
+            last_used_at: self.last_used_at,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for appliances
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ApplianceRow {
+    pub id: String,
+    pub household_id: String,
+    pub name: String,
+    pub location: Option<String>,
+    pub purchased_on: Option<NaiveDate>,
+    pub warranty_until: Option<NaiveDate>,
+    pub manual_url: Option<String>,
+    pub notes: Option<String>,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ApplianceRow {
+    /// Maintenance tasks are loaded separately
+    pub fn to_shared(&self) -> shared::Appliance {
+        shared::Appliance {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            name: self.name.clone(),
+            location: self.location.clone(),
+            purchased_on: self.purchased_on,
+            warranty_until: self.warranty_until,
+            manual_url: self.manual_url.clone(),
+            notes: self.notes.clone(),
+            created_by: Uuid::parse_str(&self.created_by).unwrap(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            maintenance: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for files attached to notes, journal entries and chat messages
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct AttachmentRow {
+    pub id: String,
+    pub household_id: String,
+    pub entity_type: String,
+    pub entity_id: String,
+    pub uploaded_by: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AttachmentRow {
+    pub fn to_shared(&self) -> shared::Attachment {
+        shared::Attachment {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            entity_type: self.entity_type.parse().unwrap_or(shared::AttachmentEntity::Note),
+            entity_id: Uuid::parse_str(&self.entity_id).unwrap(),
+            uploaded_by: Uuid::parse_str(&self.uploaded_by).unwrap(),
+            file_name: self.file_name.clone(),
+            content_type: self.content_type.clone(),
+            size_bytes: self.size_bytes,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attachment_row_to_shared() {
+        let now = Utc::now();
+        let id = Uuid::new_v4();
+        let entity_id = Uuid::new_v4();
+
+        let row = AttachmentRow {
+            id: id.to_string(),
+            household_id: Uuid::new_v4().to_string(),
+            entity_type: "journal_entry".to_string(),
+            entity_id: entity_id.to_string(),
+            uploaded_by: Uuid::new_v4().to_string(),
+            file_name: "warranty.pdf".to_string(),
+            content_type: "application/pdf".to_string(),
+            size_bytes: 2048,
+            created_at: now,
+        };
+
+        let shared = row.to_shared();
+
+        assert_eq!(shared.id, id);
+        assert_eq!(shared.entity_type, shared::AttachmentEntity::JournalEntry);
+        assert_eq!(shared.entity_id, entity_id);
+        assert_eq!(shared.file_name, "warranty.pdf");
+        assert_eq!(shared.size_bytes, 2048);
+        assert_eq!(shared.created_at, now);
+    }
+}
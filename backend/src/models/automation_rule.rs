@@ -0,0 +1,70 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for automation rules
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct AutomationRuleRow {
+    pub id: String,
+    pub household_id: String,
+    pub name: String,
+    pub trigger_type: String,
+    pub threshold: i64,
+    pub task_id: Option<String>,
+    pub reward_id: Option<String>,
+    pub punishment_id: Option<String>,
+    pub enabled: bool,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AutomationRuleRow {
+    pub fn to_shared(&self) -> shared::AutomationRule {
+        shared::AutomationRule {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            name: self.name.clone(),
+            trigger: self.trigger_type.parse().unwrap_or_default(),
+            threshold: self.threshold,
+            task_id: self.task_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            reward_id: self.reward_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            punishment_id: self.punishment_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            enabled: self.enabled,
+            created_by: Uuid::parse_str(&self.created_by).unwrap(),
+            created_at: self.created_at,
+        }
+    }
+}
+
+/// Database model for an audit trail entry, joined with the rule and member names
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct AutomationRuleRunRow {
+    pub id: String,
+    pub rule_id: String,
+    pub rule_name: String,
+    pub user_id: String,
+    pub username: String,
+    pub trigger_type: String,
+    pub observed_value: i64,
+    pub reward_id: Option<String>,
+    pub punishment_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AutomationRuleRunRow {
+    pub fn to_shared(&self) -> shared::AutomationRuleRun {
+        shared::AutomationRuleRun {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            rule_id: Uuid::parse_str(&self.rule_id).unwrap(),
+            rule_name: self.rule_name.clone(),
+            user_id: Uuid::parse_str(&self.user_id).unwrap(),
+            username: self.username.clone(),
+            trigger: self.trigger_type.parse().unwrap_or_default(),
+            observed_value: self.observed_value,
+            reward_id: self.reward_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            punishment_id: self.punishment_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            created_at: self.created_at,
+        }
+    }
+}
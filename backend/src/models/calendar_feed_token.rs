@@ -0,0 +1,41 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Database model for calendar feed tokens
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct CalendarFeedTokenRow {
+    pub user_id: String,
+    pub token: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl CalendarFeedTokenRow {
+    pub fn to_shared(&self) -> shared::CalendarFeedToken {
+        shared::CalendarFeedToken {
+            token: self.token.clone(),
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_calendar_feed_token_row_to_shared() {
+        let now = Utc::now();
+        let row = CalendarFeedTokenRow {
+            user_id: Uuid::new_v4().to_string(),
+            token: "feed-token".to_string(),
+            created_at: now,
+        };
+
+        let shared = row.to_shared();
+
+        assert_eq!(shared.token, "feed-token");
+        assert_eq!(shared.created_at, now);
+    }
+}
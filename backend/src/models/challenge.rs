@@ -0,0 +1,80 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use shared::ChallengeStatus;
+use sqlx::FromRow;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Database model for challenges
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ChallengeRow {
+    pub id: String,
+    pub household_id: String,
+    pub title: String,
+    pub description: String,
+    pub category_id: Option<String>,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub reward_id: Option<String>,
+    pub status: String,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub closed_at: Option<DateTime<Utc>>,
+}
+
+impl ChallengeRow {
+    /// Convert to shared type. Note: winner_ids is empty - winners are
+    /// loaded separately from the challenge_winners table.
+    pub fn to_shared(&self) -> shared::Challenge {
+        shared::Challenge {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            title: self.title.clone(),
+            description: self.description.clone(),
+            category_id: self.category_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            start_date: self.start_date,
+            end_date: self.end_date,
+            reward_id: self.reward_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            status: ChallengeStatus::from_str(&self.status).unwrap_or_default(),
+            created_by: Uuid::parse_str(&self.created_by).unwrap(),
+            created_at: self.created_at,
+            closed_at: self.closed_at,
+            winner_ids: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_challenge_row_to_shared() {
+        let now = Utc::now();
+        let id = Uuid::new_v4();
+        let category_id = Uuid::new_v4();
+
+        let row = ChallengeRow {
+            id: id.to_string(),
+            household_id: Uuid::new_v4().to_string(),
+            title: "Spring cleaning".to_string(),
+            description: String::new(),
+            category_id: Some(category_id.to_string()),
+            start_date: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(),
+            reward_id: None,
+            status: "closed".to_string(),
+            created_by: Uuid::new_v4().to_string(),
+            created_at: now,
+            closed_at: Some(now),
+        };
+
+        let shared = row.to_shared();
+
+        assert_eq!(shared.id, id);
+        assert_eq!(shared.category_id, Some(category_id));
+        assert_eq!(shared.reward_id, None);
+        assert_eq!(shared.status, ChallengeStatus::Closed);
+        assert!(shared.winner_ids.is_empty());
+    }
+}
@@ -14,6 +14,7 @@ pub struct ChatMessageRow {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub deleted_at: Option<DateTime<Utc>>,
+    pub reply_to_message_id: Option<String>,
 }
 
 impl ChatMessageRow {
@@ -27,6 +28,7 @@ impl ChatMessageRow {
             created_at: self.created_at,
             updated_at: self.updated_at,
             is_deleted: self.deleted_at.is_some(),
+            reply_to_message_id: self.reply_to_message_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
         }
     }
 }
@@ -42,6 +44,8 @@ pub struct ChatMessageWithUserRow {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub deleted_at: Option<DateTime<Utc>>,
+    pub reply_to_message_id: Option<String>,
+    pub reply_count: i64,
     // User fields
     pub username: String,
     pub email: String,
@@ -60,6 +64,7 @@ impl ChatMessageWithUserRow {
                 created_at: self.created_at,
                 updated_at: self.updated_at,
                 is_deleted: self.deleted_at.is_some(),
+                reply_to_message_id: self.reply_to_message_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
             },
             user: shared::User {
                 id: Uuid::parse_str(&self.user_id).unwrap(),
@@ -68,6 +73,9 @@ impl ChatMessageWithUserRow {
                 created_at: self.user_created_at,
                 updated_at: self.user_updated_at,
             },
+            reactions: Vec::new(),
+            reply_count: self.reply_count,
+            attachments: Vec::new(),
         }
     }
 }
@@ -91,6 +99,7 @@ mod tests {
             created_at: now,
             updated_at: now,
             deleted_at: None,
+            reply_to_message_id: None,
         };
 
         let shared = row.to_shared();
@@ -117,6 +126,7 @@ mod tests {
             created_at: now,
             updated_at: now,
             deleted_at: Some(now),
+            reply_to_message_id: None,
         };
 
         let shared = row.to_shared();
@@ -129,6 +139,7 @@ mod tests {
         let id = Uuid::new_v4();
         let household_id = Uuid::new_v4();
         let user_id = Uuid::new_v4();
+        let parent_id = Uuid::new_v4();
 
         let row = ChatMessageWithUserRow {
             id: id.to_string(),
@@ -138,6 +149,8 @@ mod tests {
             created_at: now,
             updated_at: now,
             deleted_at: None,
+            reply_to_message_id: Some(parent_id.to_string()),
+            reply_count: 2,
             username: "testuser".to_string(),
             email: "test@example.com".to_string(),
             user_created_at: now,
@@ -150,5 +163,8 @@ mod tests {
         assert_eq!(shared.message.content, "Hello!");
         assert_eq!(shared.user.id, user_id);
         assert_eq!(shared.user.username, "testuser");
+        assert_eq!(shared.message.reply_to_message_id, Some(parent_id));
+        assert_eq!(shared.reply_count, 2);
+        assert!(shared.reactions.is_empty());
     }
 }
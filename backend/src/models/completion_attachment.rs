@@ -0,0 +1,61 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for photos attached to task completions
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct CompletionAttachmentRow {
+    pub id: String,
+    pub completion_id: String,
+    pub household_id: String,
+    pub uploaded_by: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl CompletionAttachmentRow {
+    pub fn to_shared(&self) -> shared::CompletionAttachment {
+        shared::CompletionAttachment {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            completion_id: Uuid::parse_str(&self.completion_id).unwrap(),
+            uploaded_by: Uuid::parse_str(&self.uploaded_by).unwrap(),
+            content_type: self.content_type.clone(),
+            size_bytes: self.size_bytes,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_completion_attachment_row_to_shared() {
+        let now = Utc::now();
+        let id = Uuid::new_v4();
+        let completion_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+
+        let row = CompletionAttachmentRow {
+            id: id.to_string(),
+            completion_id: completion_id.to_string(),
+            household_id: Uuid::new_v4().to_string(),
+            uploaded_by: user_id.to_string(),
+            content_type: "image/jpeg".to_string(),
+            size_bytes: 1024,
+            created_at: now,
+        };
+
+        let shared = row.to_shared();
+
+        assert_eq!(shared.id, id);
+        assert_eq!(shared.completion_id, completion_id);
+        assert_eq!(shared.uploaded_by, user_id);
+        assert_eq!(shared.content_type, "image/jpeg");
+        assert_eq!(shared.size_bytes, 1024);
+        assert_eq!(shared.created_at, now);
+    }
+}
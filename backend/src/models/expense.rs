@@ -0,0 +1,123 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for household expenses
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ExpenseRow {
+    pub id: String,
+    pub household_id: String,
+    pub paid_by: String,
+    pub amount_cents: i64,
+    pub category: String,
+    pub description: String,
+    pub receipt_note: Option<String>,
+    pub expense_date: NaiveDate,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ExpenseRow {
+    pub fn to_shared(&self) -> shared::Expense {
+        shared::Expense {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            paid_by: Uuid::parse_str(&self.paid_by).unwrap(),
+            amount_cents: self.amount_cents,
+            category: self.category.parse().unwrap_or_default(),
+            description: self.description.clone(),
+            receipt_note: self.receipt_note.clone(),
+            expense_date: self.expense_date,
+            created_by: Uuid::parse_str(&self.created_by).unwrap(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+/// Database model for expense settlements
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ExpenseSettlementRow {
+    pub id: String,
+    pub household_id: String,
+    pub from_user_id: String,
+    pub to_user_id: String,
+    pub amount_cents: i64,
+    pub note: Option<String>,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ExpenseSettlementRow {
+    pub fn to_shared(&self) -> shared::ExpenseSettlement {
+        shared::ExpenseSettlement {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            from_user_id: Uuid::parse_str(&self.from_user_id).unwrap(),
+            to_user_id: Uuid::parse_str(&self.to_user_id).unwrap(),
+            amount_cents: self.amount_cents,
+            note: self.note.clone(),
+            created_by: Uuid::parse_str(&self.created_by).unwrap(),
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::ExpenseCategory;
+
+    #[test]
+    fn test_expense_row_to_shared() {
+        let now = Utc::now();
+        let id = Uuid::new_v4();
+        let payer = Uuid::new_v4();
+
+        let row = ExpenseRow {
+            id: id.to_string(),
+            household_id: Uuid::new_v4().to_string(),
+            paid_by: payer.to_string(),
+            amount_cents: 1299,
+            category: "groceries".to_string(),
+            description: "Weekly shopping".to_string(),
+            receipt_note: Some("Receipt in the drawer".to_string()),
+            expense_date: NaiveDate::from_ymd_opt(2024, 3, 9).unwrap(),
+            created_by: payer.to_string(),
+            created_at: now,
+            updated_at: now,
+        };
+
+        let shared = row.to_shared();
+
+        assert_eq!(shared.id, id);
+        assert_eq!(shared.paid_by, payer);
+        assert_eq!(shared.amount_cents, 1299);
+        assert_eq!(shared.category, ExpenseCategory::Groceries);
+        assert_eq!(shared.receipt_note.as_deref(), Some("Receipt in the drawer"));
+    }
+
+    #[test]
+    fn test_expense_row_unknown_category_falls_back_to_other() {
+        let now = Utc::now();
+        let user = Uuid::new_v4().to_string();
+
+        let row = ExpenseRow {
+            id: Uuid::new_v4().to_string(),
+            household_id: Uuid::new_v4().to_string(),
+            paid_by: user.clone(),
+            amount_cents: 500,
+            category: "mystery".to_string(),
+            description: "Something".to_string(),
+            receipt_note: None,
+            expense_date: NaiveDate::from_ymd_opt(2024, 3, 9).unwrap(),
+            created_by: user,
+            created_at: now,
+            updated_at: now,
+        };
+
+        assert_eq!(row.to_shared().category, ExpenseCategory::Other);
+    }
+}
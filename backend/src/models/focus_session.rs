@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for focus sessions, joined with the task title
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct FocusSessionRow {
+    pub id: String,
+    pub household_id: String,
+    pub task_id: String,
+    pub task_title: String,
+    pub user_id: String,
+    pub planned_minutes: i32,
+    pub status: String,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub focused_seconds: i64,
+}
+
+impl FocusSessionRow {
+    pub fn to_shared(&self) -> shared::FocusSession {
+        shared::FocusSession {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            task_id: Uuid::parse_str(&self.task_id).unwrap(),
+            task_title: self.task_title.clone(),
+            user_id: Uuid::parse_str(&self.user_id).unwrap(),
+            planned_minutes: self.planned_minutes,
+            status: self.status.parse().unwrap_or(shared::FocusSessionStatus::Cancelled),
+            started_at: self.started_at,
+            ended_at: self.ended_at,
+            focused_seconds: self.focused_seconds,
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
-use shared::HierarchyType;
+use shared::{AllowancePointHandling, HierarchyType, PointDecayMode, PunishmentTerminology};
 use sqlx::FromRow;
 use std::str::FromStr;
 use uuid::Uuid;
@@ -14,9 +14,6 @@ pub struct HouseholdSettingsRow {
     pub role_label_member: String,
     pub hierarchy_type: String,
     pub timezone: String,
-    pub rewards_enabled: bool,
-    pub punishments_enabled: bool,
-    pub chat_enabled: bool,
     pub vacation_mode: bool,
     pub vacation_start: Option<NaiveDate>,
     pub vacation_end: Option<NaiveDate>,
@@ -28,6 +25,24 @@ pub struct HouseholdSettingsRow {
     pub solo_mode: bool,
     pub solo_mode_exit_requested_at: Option<DateTime<Utc>>,
     pub solo_mode_previous_hierarchy_type: Option<String>,
+    pub weekly_summary_enabled: bool,
+    pub weekly_summary_email: bool,
+    pub point_decay_mode: String,
+    pub point_decay_amount: i32,
+    pub purchase_approval_required: bool,
+    pub streak_freeze_price: i64,
+    pub default_requires_review: bool,
+    pub default_allow_exceed_target: bool,
+    pub good_habit_points_multiplier: f64,
+    pub bad_habit_points_multiplier: f64,
+    pub review_points_threshold: Option<i64>,
+    pub disabled_features: String,
+    pub punishment_terminology: String,
+    pub punishment_label_singular: Option<String>,
+    pub punishment_label_plural: Option<String>,
+    pub allowance_cents_per_point: i64,
+    pub allowance_point_handling: String,
+    pub version: i64,
     pub updated_at: DateTime<Utc>,
 }
 
@@ -44,9 +59,6 @@ impl HouseholdSettingsRow {
             hierarchy_type: HierarchyType::from_str(&self.hierarchy_type)
                 .unwrap_or_default(),
             timezone: self.timezone.clone(),
-            rewards_enabled: self.rewards_enabled,
-            punishments_enabled: self.punishments_enabled,
-            chat_enabled: self.chat_enabled,
             vacation_mode: self.vacation_mode,
             vacation_start: self.vacation_start,
             vacation_end: self.vacation_end,
@@ -63,6 +75,24 @@ impl HouseholdSettingsRow {
                 .solo_mode_previous_hierarchy_type
                 .as_ref()
                 .and_then(|s| HierarchyType::from_str(s).ok()),
+            weekly_summary_enabled: self.weekly_summary_enabled,
+            weekly_summary_email: self.weekly_summary_email,
+            point_decay_mode: PointDecayMode::from_str(&self.point_decay_mode).unwrap_or_default(),
+            point_decay_amount: self.point_decay_amount,
+            purchase_approval_required: self.purchase_approval_required,
+            streak_freeze_price: self.streak_freeze_price,
+            default_requires_review: self.default_requires_review,
+            default_allow_exceed_target: self.default_allow_exceed_target,
+            good_habit_points_multiplier: self.good_habit_points_multiplier,
+            bad_habit_points_multiplier: self.bad_habit_points_multiplier,
+            review_points_threshold: self.review_points_threshold,
+            disabled_features: serde_json::from_str(&self.disabled_features).unwrap_or_default(),
+            punishment_terminology: PunishmentTerminology::from_str(&self.punishment_terminology).unwrap_or_default(),
+            punishment_label_singular: self.punishment_label_singular.clone(),
+            punishment_label_plural: self.punishment_label_plural.clone(),
+            allowance_cents_per_point: self.allowance_cents_per_point,
+            allowance_point_handling: AllowancePointHandling::from_str(&self.allowance_point_handling).unwrap_or_default(),
+            version: self.version,
             updated_at: self.updated_at,
         }
     }
@@ -71,6 +101,7 @@ impl HouseholdSettingsRow {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use shared::HouseholdFeature;
 
     #[test]
     fn test_household_settings_row_to_shared() {
@@ -85,9 +116,6 @@ mod tests {
             role_label_member: "Child".to_string(),
             hierarchy_type: "hierarchy".to_string(),
             timezone: "America/New_York".to_string(),
-            rewards_enabled: true,
-            punishments_enabled: false,
-            chat_enabled: true,
             vacation_mode: false,
             vacation_start: None,
             vacation_end: None,
@@ -99,12 +127,31 @@ mod tests {
             solo_mode: false,
             solo_mode_exit_requested_at: None,
             solo_mode_previous_hierarchy_type: None,
+            weekly_summary_enabled: false,
+            weekly_summary_email: false,
+            point_decay_mode: "none".to_string(),
+            point_decay_amount: 0,
+            purchase_approval_required: false,
+            streak_freeze_price: 0,
+            default_requires_review: false,
+            default_allow_exceed_target: true,
+            good_habit_points_multiplier: 1.0,
+            bad_habit_points_multiplier: 1.0,
+            review_points_threshold: None,
+            disabled_features: r#"["journal","pets"]"#.to_string(),
+            punishment_terminology: "punishments".to_string(),
+            punishment_label_singular: None,
+            punishment_label_plural: None,
+            allowance_cents_per_point: 0,
+            allowance_point_handling: "reset".to_string(),
+            version: 1,
             updated_at: now,
         };
 
         let shared = row.to_shared();
 
         assert_eq!(shared.household_id, household_id);
+        assert_eq!(shared.disabled_features, vec![HouseholdFeature::Journal, HouseholdFeature::Pets]);
         assert!(shared.dark_mode);
         assert_eq!(shared.default_points_reward, Some(10));
         assert_eq!(shared.default_points_penalty, Some(5));
@@ -113,9 +160,8 @@ mod tests {
         assert_eq!(shared.role_label_member, "Child");
         assert_eq!(shared.hierarchy_type, HierarchyType::Hierarchy);
         assert_eq!(shared.timezone, "America/New_York");
-        assert!(shared.rewards_enabled);
-        assert!(!shared.punishments_enabled);
-        assert!(shared.chat_enabled);
+        assert!(shared.is_feature_enabled(HouseholdFeature::Rewards));
+        assert!(!shared.is_feature_enabled(HouseholdFeature::Pets));
         assert!(!shared.vacation_mode);
         assert!(shared.vacation_start.is_none());
         assert!(shared.vacation_end.is_none());
@@ -139,9 +185,6 @@ mod tests {
             role_label_member: "Member".to_string(),
             hierarchy_type: "invalid".to_string(),
             timezone: "UTC".to_string(),
-            rewards_enabled: false,
-            punishments_enabled: false,
-            chat_enabled: false,
             vacation_mode: false,
             vacation_start: None,
             vacation_end: None,
@@ -153,6 +196,24 @@ mod tests {
             solo_mode: false,
             solo_mode_exit_requested_at: None,
             solo_mode_previous_hierarchy_type: None,
+            weekly_summary_enabled: false,
+            weekly_summary_email: false,
+            point_decay_mode: "none".to_string(),
+            point_decay_amount: 0,
+            purchase_approval_required: false,
+            streak_freeze_price: 0,
+            default_requires_review: false,
+            default_allow_exceed_target: true,
+            good_habit_points_multiplier: 1.0,
+            bad_habit_points_multiplier: 1.0,
+            review_points_threshold: None,
+            disabled_features: "[]".to_string(),
+            punishment_terminology: "punishments".to_string(),
+            punishment_label_singular: None,
+            punishment_label_plural: None,
+            allowance_cents_per_point: 0,
+            allowance_point_handling: "reset".to_string(),
+            version: 1,
             updated_at: now,
         };
 
@@ -176,9 +237,6 @@ mod tests {
             role_label_member: "Member".to_string(),
             hierarchy_type: "organized".to_string(),
             timezone: "UTC".to_string(),
-            rewards_enabled: false,
-            punishments_enabled: false,
-            chat_enabled: false,
             vacation_mode: false,
             vacation_start: None,
             vacation_end: None,
@@ -190,6 +248,24 @@ mod tests {
             solo_mode: true,
             solo_mode_exit_requested_at: Some(exit_requested_at),
             solo_mode_previous_hierarchy_type: Some("hierarchy".to_string()),
+            weekly_summary_enabled: false,
+            weekly_summary_email: false,
+            point_decay_mode: "none".to_string(),
+            point_decay_amount: 0,
+            purchase_approval_required: false,
+            streak_freeze_price: 0,
+            default_requires_review: false,
+            default_allow_exceed_target: true,
+            good_habit_points_multiplier: 1.0,
+            bad_habit_points_multiplier: 1.0,
+            review_points_threshold: None,
+            disabled_features: "[]".to_string(),
+            punishment_terminology: "punishments".to_string(),
+            punishment_label_singular: None,
+            punishment_label_plural: None,
+            allowance_cents_per_point: 0,
+            allowance_point_handling: "reset".to_string(),
+            version: 1,
             updated_at: now,
         };
 
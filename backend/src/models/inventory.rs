@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for inventory items
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct InventoryItemRow {
+    pub id: String,
+    pub household_id: String,
+    pub name: String,
+    pub quantity: f64,
+    pub unit: Option<String>,
+    pub location: Option<String>,
+    pub min_quantity: Option<f64>,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl InventoryItemRow {
+    pub fn to_shared(&self) -> shared::InventoryItem {
+        shared::InventoryItem {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            name: self.name.clone(),
+            quantity: self.quantity,
+            unit: self.unit.clone(),
+            location: self.location.clone(),
+            min_quantity: self.min_quantity,
+            created_by: Uuid::parse_str(&self.created_by).unwrap(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
@@ -33,6 +33,36 @@ impl InvitationRow {
     }
 }
 
+/// Database model for shareable household invite codes
+#[derive(Debug, Clone, FromRow)]
+pub struct InviteCodeRow {
+    pub id: String,
+    pub household_id: String,
+    pub code: String,
+    pub role: String,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub max_uses: Option<i32>,
+    pub use_count: i32,
+}
+
+impl InviteCodeRow {
+    pub fn to_shared(&self) -> shared::InviteCode {
+        shared::InviteCode {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            code: self.code.clone(),
+            role: self.role.parse().unwrap_or(shared::Role::Member),
+            created_by: Uuid::parse_str(&self.created_by).unwrap(),
+            created_at: self.created_at,
+            expires_at: self.expires_at,
+            max_uses: self.max_uses,
+            use_count: self.use_count,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -12,7 +12,8 @@ pub struct JournalEntryRow {
     pub title: String,
     pub content: String,
     pub entry_date: NaiveDate,
-    pub is_shared: bool,
+    pub visibility: String,
+    pub mood: Option<i32>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -26,7 +27,8 @@ impl JournalEntryRow {
             title: self.title.clone(),
             content: self.content.clone(),
             entry_date: self.entry_date,
-            is_shared: self.is_shared,
+            visibility: self.visibility.parse().unwrap_or_default(),
+            mood: self.mood,
             created_at: self.created_at,
             updated_at: self.updated_at,
         }
@@ -52,7 +54,8 @@ mod tests {
             title: "My Day".to_string(),
             content: "Today was a great day!".to_string(),
             entry_date,
-            is_shared: true,
+            visibility: "household".to_string(),
+            mood: Some(4),
             created_at: now,
             updated_at: now,
         };
@@ -65,7 +68,8 @@ mod tests {
         assert_eq!(shared.title, "My Day");
         assert_eq!(shared.content, "Today was a great day!");
         assert_eq!(shared.entry_date, entry_date);
-        assert!(shared.is_shared);
+        assert_eq!(shared.visibility, shared::JournalVisibility::Household);
+        assert_eq!(shared.mood, Some(4));
     }
 
     #[test]
@@ -80,13 +84,15 @@ mod tests {
             title: "Private thoughts".to_string(),
             content: "Secret content".to_string(),
             entry_date,
-            is_shared: false,
+            visibility: "private".to_string(),
+            mood: None,
             created_at: now,
             updated_at: now,
         };
 
         let shared = row.to_shared();
 
-        assert!(!shared.is_shared);
+        assert_eq!(shared.visibility, shared::JournalVisibility::Private);
+        assert_eq!(shared.mood, None);
     }
 }
@@ -0,0 +1,134 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for recipes
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct RecipeRow {
+    pub id: String,
+    pub household_id: String,
+    pub name: String,
+    /// JSON array of ingredients
+    pub ingredients: String,
+    pub notes: Option<String>,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl RecipeRow {
+    pub fn to_shared(&self) -> shared::Recipe {
+        shared::Recipe {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            name: self.name.clone(),
+            ingredients: serde_json::from_str(&self.ingredients).unwrap_or_default(),
+            notes: self.notes.clone(),
+            created_by: Uuid::parse_str(&self.created_by).unwrap(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+/// Database model for meal plan entries
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct MealPlanEntryRow {
+    pub id: String,
+    pub household_id: String,
+    pub plan_date: NaiveDate,
+    pub slot: String,
+    pub recipe_id: Option<String>,
+    pub title: Option<String>,
+    pub notes: Option<String>,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl MealPlanEntryRow {
+    pub fn to_shared(&self) -> shared::MealPlanEntry {
+        shared::MealPlanEntry {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            plan_date: self.plan_date,
+            slot: self.slot.parse().unwrap_or_default(),
+            recipe_id: self.recipe_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            title: self.title.clone(),
+            notes: self.notes.clone(),
+            created_by: Uuid::parse_str(&self.created_by).unwrap(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::MealSlot;
+
+    #[test]
+    fn test_recipe_row_parses_ingredients() {
+        let now = Utc::now();
+        let row = RecipeRow {
+            id: Uuid::new_v4().to_string(),
+            household_id: Uuid::new_v4().to_string(),
+            name: "Pancakes".to_string(),
+            ingredients: r#"[{"name":"Flour","quantity":200.0,"unit":"g"},{"name":"Eggs","quantity":2.0,"unit":null}]"#.to_string(),
+            notes: None,
+            created_by: Uuid::new_v4().to_string(),
+            created_at: now,
+            updated_at: now,
+        };
+
+        let shared = row.to_shared();
+
+        assert_eq!(shared.ingredients.len(), 2);
+        assert_eq!(shared.ingredients[0].name, "Flour");
+        assert_eq!(shared.ingredients[0].quantity, Some(200.0));
+        assert_eq!(shared.ingredients[1].unit, None);
+    }
+
+    #[test]
+    fn test_recipe_row_invalid_ingredients_are_empty() {
+        let now = Utc::now();
+        let row = RecipeRow {
+            id: Uuid::new_v4().to_string(),
+            household_id: Uuid::new_v4().to_string(),
+            name: "Broken".to_string(),
+            ingredients: "not json".to_string(),
+            notes: None,
+            created_by: Uuid::new_v4().to_string(),
+            created_at: now,
+            updated_at: now,
+        };
+
+        assert!(row.to_shared().ingredients.is_empty());
+    }
+
+    #[test]
+    fn test_meal_plan_entry_row_to_shared() {
+        let now = Utc::now();
+        let recipe_id = Uuid::new_v4();
+        let row = MealPlanEntryRow {
+            id: Uuid::new_v4().to_string(),
+            household_id: Uuid::new_v4().to_string(),
+            plan_date: NaiveDate::from_ymd_opt(2024, 5, 6).unwrap(),
+            slot: "lunch".to_string(),
+            recipe_id: Some(recipe_id.to_string()),
+            title: None,
+            notes: Some("Double portion".to_string()),
+            created_by: Uuid::new_v4().to_string(),
+            created_at: now,
+            updated_at: now,
+        };
+
+        let shared = row.to_shared();
+
+        assert_eq!(shared.slot, MealSlot::Lunch);
+        assert_eq!(shared.recipe_id, Some(recipe_id));
+        assert_eq!(shared.notes.as_deref(), Some("Double portion"));
+    }
+}
@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
@@ -12,6 +12,8 @@ pub struct MembershipRow {
     pub role: String,
     pub points: i64,
     pub joined_at: DateTime<Utc>,
+    pub vacation_start: Option<NaiveDate>,
+    pub vacation_end: Option<NaiveDate>,
 }
 
 impl MembershipRow {
@@ -23,6 +25,8 @@ impl MembershipRow {
             role: self.role.parse().unwrap_or(shared::Role::Member),
             points: self.points,
             joined_at: self.joined_at,
+            vacation_start: self.vacation_start,
+            vacation_end: self.vacation_end,
         }
     }
 }
@@ -46,6 +50,8 @@ mod tests {
             role: "admin".to_string(),
             points: 100,
             joined_at: now,
+            vacation_start: None,
+            vacation_end: None,
         };
 
         let shared = row.to_shared();
@@ -68,6 +74,8 @@ mod tests {
             role: "invalid_role".to_string(),
             points: 0,
             joined_at: now,
+            vacation_start: None,
+            vacation_end: None,
         };
 
         let shared = row.to_shared();
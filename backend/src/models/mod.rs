@@ -2,7 +2,10 @@ use std::sync::Arc;
 use sqlx::SqlitePool;
 
 use crate::config::Config;
-use crate::middleware::RateLimiter;
+use crate::middleware::{RateLimiter, TokenBucketLimiter};
+use crate::services::background_jobs::JobRunner;
+use crate::services::chat_bridge::ChatBridge;
+use crate::services::notifications::PushSender;
 
 pub mod user;
 pub mod household;
@@ -11,10 +14,16 @@ pub mod user_settings;
 pub mod membership;
 pub mod task;
 pub mod task_category;
+pub mod task_tag;
+pub mod saved_task_filter;
+pub mod focus_session;
 pub mod task_completion;
+pub mod completion_attachment;
+pub mod attachment;
 pub mod task_period_result;
 pub mod point_condition;
 pub mod reward;
+pub mod reward_purchase;
 pub mod punishment;
 pub mod invitation;
 pub mod activity_log;
@@ -24,6 +33,25 @@ pub mod journal;
 pub mod announcement;
 pub mod refresh_token;
 pub mod statistics;
+pub mod calendar_feed_token;
+pub mod push_subscription;
+pub mod task_comment;
+pub mod point_transaction;
+pub mod expense;
+pub mod meal;
+pub mod shopping_list;
+pub mod inventory;
+pub mod appliance;
+pub mod plant;
+pub mod pet;
+pub mod share_link;
+pub mod allowance_payout;
+pub mod challenge;
+pub mod automation_rule;
+pub mod webhook;
+pub mod api_token;
+pub mod notification;
+pub mod point_goal;
 
 pub use user::*;
 pub use household::*;
@@ -32,10 +60,16 @@ pub use user_settings::*;
 pub use membership::*;
 pub use task::*;
 pub use task_category::*;
+pub use task_tag::*;
+pub use saved_task_filter::*;
+pub use focus_session::*;
 pub use task_completion::*;
+pub use completion_attachment::*;
+pub use attachment::*;
 pub use task_period_result::*;
 pub use point_condition::*;
 pub use reward::*;
+pub use reward_purchase::*;
 pub use punishment::*;
 pub use invitation::*;
 pub use activity_log::*;
@@ -45,10 +79,37 @@ pub use journal::*;
 pub use announcement::*;
 pub use refresh_token::*;
 pub use statistics::*;
+pub use calendar_feed_token::*;
+pub use push_subscription::*;
+pub use task_comment::*;
+pub use point_transaction::*;
+pub use expense::*;
+pub use meal::*;
+pub use shopping_list::*;
+pub use inventory::*;
+pub use appliance::*;
+pub use plant::*;
+pub use pet::*;
+pub use share_link::*;
+pub use allowance_payout::*;
+pub use challenge::*;
+pub use automation_rule::*;
+pub use webhook::*;
+pub use api_token::*;
+pub use notification::*;
+pub use point_goal::*;
 
 /// Application state shared across all handlers
 pub struct AppState {
     pub db: SqlitePool,
     pub config: Config,
     pub login_rate_limiter: Arc<RateLimiter>,
+    /// Limits POST/PUT/DELETE requests per user or IP (None when disabled)
+    pub mutation_rate_limiter: Option<Arc<TokenBucketLimiter>>,
+    /// Background jobs, for the server admin endpoints
+    pub jobs: Arc<JobRunner>,
+    /// Web Push credentials for event notifications (None when push is disabled)
+    pub push: Option<PushSender>,
+    /// Matrix/Telegram chat that mirrors selected household events (None when not configured)
+    pub chat_bridge: Option<ChatBridge>,
 }
@@ -12,6 +12,11 @@ pub struct NoteRow {
     pub title: String,
     pub content: String,
     pub is_shared: bool,
+    pub pinned: bool,
+    pub color: Option<String>,
+    pub sort_order: Option<i64>,
+    pub archived: bool,
+    pub version: i64,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -25,6 +30,11 @@ impl NoteRow {
             title: self.title.clone(),
             content: self.content.clone(),
             is_shared: self.is_shared,
+            pinned: self.pinned,
+            color: self.color.clone(),
+            sort_order: self.sort_order,
+            archived: self.archived,
+            version: self.version,
             created_at: self.created_at,
             updated_at: self.updated_at,
         }
@@ -49,6 +59,11 @@ mod tests {
             title: "Test Note".to_string(),
             content: "# Hello\n\nThis is a test note.".to_string(),
             is_shared: true,
+            pinned: false,
+            color: None,
+            sort_order: None,
+            archived: false,
+            version: 1,
             created_at: now,
             updated_at: now,
         };
@@ -74,6 +89,11 @@ mod tests {
             title: "Private Note".to_string(),
             content: "Secret content".to_string(),
             is_shared: false,
+            pinned: false,
+            color: None,
+            sort_order: None,
+            archived: false,
+            version: 1,
             created_at: now,
             updated_at: now,
         };
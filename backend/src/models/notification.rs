@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::NotificationType;
+use sqlx::FromRow;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Database model for personal in-app notifications
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct NotificationRow {
+    pub id: String,
+    pub user_id: String,
+    pub household_id: Option<String>,
+    pub notification_type: String,
+    pub title: String,
+    pub entity_type: Option<String>,
+    pub entity_id: Option<String>,
+    pub read_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl NotificationRow {
+    pub fn to_shared(&self) -> shared::Notification {
+        shared::Notification {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            user_id: Uuid::parse_str(&self.user_id).unwrap(),
+            household_id: self.household_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            notification_type: NotificationType::from_str(&self.notification_type).unwrap_or_default(),
+            title: self.title.clone(),
+            entity_type: self.entity_type.clone(),
+            entity_id: self.entity_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            read_at: self.read_at,
+            created_at: self.created_at,
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for pets
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct PetRow {
+    pub id: String,
+    pub household_id: String,
+    pub name: String,
+    pub species: Option<String>,
+    pub notes: Option<String>,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl PetRow {
+    /// Schedules and the recent log are loaded separately
+    pub fn to_shared(&self) -> shared::Pet {
+        shared::Pet {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            name: self.name.clone(),
+            species: self.species.clone(),
+            notes: self.notes.clone(),
+            created_by: Uuid::parse_str(&self.created_by).unwrap(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            schedules: Vec::new(),
+            recent_log: Vec::new(),
+        }
+    }
+}
+
+/// A pet care log entry joined with the member's username
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct PetCareEntryRow {
+    pub id: String,
+    pub pet_id: String,
+    pub task_id: Option<String>,
+    pub kind: String,
+    pub title: String,
+    pub user_id: String,
+    pub username: String,
+    pub logged_at: DateTime<Utc>,
+}
+
+impl PetCareEntryRow {
+    pub fn to_shared(&self) -> shared::PetCareEntry {
+        shared::PetCareEntry {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            pet_id: Uuid::parse_str(&self.pet_id).unwrap(),
+            task_id: self.task_id.as_deref().and_then(|id| Uuid::parse_str(id).ok()),
+            kind: self.kind.parse().unwrap_or(shared::PetCareKind::Feeding),
+            title: self.title.clone(),
+            user_id: Uuid::parse_str(&self.user_id).unwrap(),
+            username: self.username.clone(),
+            logged_at: self.logged_at,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for plants
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct PlantRow {
+    pub id: String,
+    pub household_id: String,
+    pub name: String,
+    pub location: Option<String>,
+    pub water_interval_days: Option<i32>,
+    pub fertilize_interval_days: Option<i32>,
+    pub water_task_id: Option<String>,
+    pub fertilize_task_id: Option<String>,
+    pub last_watered_at: Option<DateTime<Utc>>,
+    pub last_fertilized_at: Option<DateTime<Utc>>,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl PlantRow {
+    /// The photo is filled in by the handler
+    pub fn to_shared(&self) -> shared::Plant {
+        shared::Plant {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            name: self.name.clone(),
+            location: self.location.clone(),
+            water_interval_days: self.water_interval_days,
+            fertilize_interval_days: self.fertilize_interval_days,
+            water_task_id: self.water_task_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            fertilize_task_id: self.fertilize_task_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            last_watered_at: self.last_watered_at,
+            last_fertilized_at: self.last_fertilized_at,
+            created_by: Uuid::parse_str(&self.created_by).unwrap(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            photo: None,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for point goals
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct PointGoalRow {
+    pub id: String,
+    pub household_id: String,
+    pub user_id: String,
+    pub title: String,
+    pub target_points: i64,
+    pub deadline: NaiveDate,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl PointGoalRow {
+    pub fn to_shared(&self) -> shared::PointGoal {
+        shared::PointGoal {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            user_id: Uuid::parse_str(&self.user_id).unwrap(),
+            title: self.title.clone(),
+            target_points: self.target_points,
+            deadline: self.deadline,
+            created_by: Uuid::parse_str(&self.created_by).unwrap(),
+            created_at: self.created_at,
+            completed_at: self.completed_at,
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for point ledger entries
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct PointTransactionRow {
+    pub id: String,
+    pub household_id: String,
+    pub user_id: String,
+    pub amount: i64,
+    pub balance_after: i64,
+    pub transaction_type: String,
+    pub task_id: Option<String>,
+    pub reward_id: Option<String>,
+    pub actor_id: Option<String>,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PointTransactionRow {
+    pub fn to_shared(&self) -> shared::PointTransaction {
+        shared::PointTransaction {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            user_id: Uuid::parse_str(&self.user_id).unwrap(),
+            amount: self.amount,
+            balance_after: self.balance_after,
+            transaction_type: self
+                .transaction_type
+                .parse()
+                .unwrap_or(shared::PointTransactionType::ManualAdjustment),
+            task_id: self.task_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            reward_id: self.reward_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            actor_id: self.actor_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            description: self.description.clone(),
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::PointTransactionType;
+
+    #[test]
+    fn test_point_transaction_row_to_shared() {
+        let now = Utc::now();
+        let id = Uuid::new_v4();
+        let household_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let task_id = Uuid::new_v4();
+
+        let row = PointTransactionRow {
+            id: id.to_string(),
+            household_id: household_id.to_string(),
+            user_id: user_id.to_string(),
+            amount: -5,
+            balance_after: 20,
+            transaction_type: "task_missed".to_string(),
+            task_id: Some(task_id.to_string()),
+            reward_id: None,
+            actor_id: None,
+            description: Some("Take out trash".to_string()),
+            created_at: now,
+        };
+
+        let shared = row.to_shared();
+
+        assert_eq!(shared.id, id);
+        assert_eq!(shared.household_id, household_id);
+        assert_eq!(shared.user_id, user_id);
+        assert_eq!(shared.amount, -5);
+        assert_eq!(shared.balance_after, 20);
+        assert_eq!(shared.transaction_type, PointTransactionType::TaskMissed);
+        assert_eq!(shared.task_id, Some(task_id));
+        assert_eq!(shared.reward_id, None);
+        assert_eq!(shared.description.as_deref(), Some("Take out trash"));
+    }
+}
@@ -12,6 +12,7 @@ pub struct PunishmentRow {
     pub description: String,
     pub requires_confirmation: bool,
     pub punishment_type: String,
+    pub no_repeat_picks: i32,
     pub created_at: DateTime<Utc>,
 }
 
@@ -24,6 +25,7 @@ impl PunishmentRow {
             description: self.description.clone(),
             requires_confirmation: self.requires_confirmation,
             punishment_type: self.punishment_type.parse().unwrap_or_default(),
+            no_repeat_picks: self.no_repeat_picks,
             created_at: self.created_at,
         }
     }
@@ -38,6 +40,7 @@ pub struct TaskPunishmentRow {
     pub description: String,
     pub requires_confirmation: bool,
     pub punishment_type: String,
+    pub no_repeat_picks: i32,
     pub created_at: DateTime<Utc>,
     pub amount: i32,
 }
@@ -52,6 +55,7 @@ impl TaskPunishmentRow {
                 description: self.description.clone(),
                 requires_confirmation: self.requires_confirmation,
                 punishment_type: self.punishment_type.parse().unwrap_or_default(),
+                no_repeat_picks: self.no_repeat_picks,
                 created_at: self.created_at,
             },
             amount: self.amount,
@@ -68,6 +72,7 @@ pub struct HouseholdDefaultPunishmentRow {
     pub description: String,
     pub requires_confirmation: bool,
     pub punishment_type: String,
+    pub no_repeat_picks: i32,
     pub created_at: DateTime<Utc>,
     pub amount: i32,
 }
@@ -82,6 +87,7 @@ impl HouseholdDefaultPunishmentRow {
                 description: self.description.clone(),
                 requires_confirmation: self.requires_confirmation,
                 punishment_type: self.punishment_type.parse().unwrap_or_default(),
+                no_repeat_picks: self.no_repeat_picks,
                 created_at: self.created_at,
             },
             amount: self.amount,
@@ -124,6 +130,7 @@ pub struct PunishmentOptionRow {
     pub id: String,
     pub parent_punishment_id: String,
     pub option_punishment_id: String,
+    pub weight: i32,
     pub created_at: DateTime<Utc>,
 }
 
@@ -134,6 +141,7 @@ impl PunishmentOptionRow {
             id: Uuid::parse_str(&self.id).unwrap(),
             parent_punishment_id: Uuid::parse_str(&self.parent_punishment_id).unwrap(),
             option_punishment_id: Uuid::parse_str(&self.option_punishment_id).unwrap(),
+            weight: self.weight,
             created_at: self.created_at,
         }
     }
@@ -156,6 +164,7 @@ mod tests {
             description: "Do an extra chore as punishment".to_string(),
             requires_confirmation: true,
             punishment_type: "standard".to_string(),
+            no_repeat_picks: 0,
             created_at: now,
         };
 
@@ -182,6 +191,7 @@ mod tests {
             description: "Pick one randomly".to_string(),
             requires_confirmation: false,
             punishment_type: "random_choice".to_string(),
+            no_repeat_picks: 0,
             created_at: now,
         };
 
@@ -202,6 +212,7 @@ mod tests {
             id: id.to_string(),
             parent_punishment_id: parent_id.to_string(),
             option_punishment_id: option_id.to_string(),
+            weight: 3,
             created_at: now,
         };
 
@@ -210,6 +221,7 @@ mod tests {
         assert_eq!(shared.id, id);
         assert_eq!(shared.parent_punishment_id, parent_id);
         assert_eq!(shared.option_punishment_id, option_id);
+        assert_eq!(shared.weight, 3);
     }
 
     #[test]
@@ -14,6 +14,7 @@ pub struct RewardRow {
     pub is_purchasable: bool,
     pub requires_confirmation: bool,
     pub reward_type: String,
+    pub no_repeat_picks: i32,
     pub created_at: DateTime<Utc>,
 }
 
@@ -28,6 +29,7 @@ impl RewardRow {
             is_purchasable: self.is_purchasable,
             requires_confirmation: self.requires_confirmation,
             reward_type: self.reward_type.parse().unwrap_or_default(),
+            no_repeat_picks: self.no_repeat_picks,
             created_at: self.created_at,
         }
     }
@@ -44,6 +46,7 @@ pub struct TaskRewardRow {
     pub is_purchasable: bool,
     pub requires_confirmation: bool,
     pub reward_type: String,
+    pub no_repeat_picks: i32,
     pub created_at: DateTime<Utc>,
     pub amount: i32,
 }
@@ -60,6 +63,7 @@ impl TaskRewardRow {
                 is_purchasable: self.is_purchasable,
                 requires_confirmation: self.requires_confirmation,
                 reward_type: self.reward_type.parse().unwrap_or_default(),
+                no_repeat_picks: self.no_repeat_picks,
                 created_at: self.created_at,
             },
             amount: self.amount,
@@ -78,6 +82,7 @@ pub struct HouseholdDefaultRewardRow {
     pub is_purchasable: bool,
     pub requires_confirmation: bool,
     pub reward_type: String,
+    pub no_repeat_picks: i32,
     pub created_at: DateTime<Utc>,
     pub amount: i32,
 }
@@ -94,6 +99,7 @@ impl HouseholdDefaultRewardRow {
                 is_purchasable: self.is_purchasable,
                 requires_confirmation: self.requires_confirmation,
                 reward_type: self.reward_type.parse().unwrap_or_default(),
+                no_repeat_picks: self.no_repeat_picks,
                 created_at: self.created_at,
             },
             amount: self.amount,
@@ -136,6 +142,7 @@ pub struct RewardOptionRow {
     pub id: String,
     pub parent_reward_id: String,
     pub option_reward_id: String,
+    pub weight: i32,
     pub created_at: DateTime<Utc>,
 }
 
@@ -146,6 +153,7 @@ impl RewardOptionRow {
             id: Uuid::parse_str(&self.id).unwrap(),
             parent_reward_id: Uuid::parse_str(&self.parent_reward_id).unwrap(),
             option_reward_id: Uuid::parse_str(&self.option_reward_id).unwrap(),
+            weight: self.weight,
             created_at: self.created_at,
         }
     }
@@ -170,6 +178,7 @@ mod tests {
             is_purchasable: true,
             requires_confirmation: false,
             reward_type: "standard".to_string(),
+            no_repeat_picks: 0,
             created_at: now,
         };
 
@@ -199,6 +208,7 @@ mod tests {
             is_purchasable: false,
             requires_confirmation: false,
             reward_type: "random_choice".to_string(),
+            no_repeat_picks: 0,
             created_at: now,
         };
 
@@ -219,6 +229,7 @@ mod tests {
             id: id.to_string(),
             parent_reward_id: parent_id.to_string(),
             option_reward_id: option_id.to_string(),
+            weight: 3,
             created_at: now,
         };
 
@@ -227,6 +238,7 @@ mod tests {
         assert_eq!(shared.id, id);
         assert_eq!(shared.parent_reward_id, parent_id);
         assert_eq!(shared.option_reward_id, option_id);
+        assert_eq!(shared.weight, 3);
     }
 
     #[test]
@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for reward purchases that need approval
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct RewardPurchaseRow {
+    pub id: String,
+    pub household_id: String,
+    pub reward_id: String,
+    pub user_id: String,
+    pub point_cost: i64,
+    pub status: String,
+    pub created_at: DateTime<Utc>,
+    pub reviewed_by: Option<String>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+impl RewardPurchaseRow {
+    pub fn to_shared(&self) -> shared::RewardPurchase {
+        shared::RewardPurchase {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            reward_id: Uuid::parse_str(&self.reward_id).unwrap(),
+            user_id: Uuid::parse_str(&self.user_id).unwrap(),
+            point_cost: self.point_cost,
+            status: self.status.parse().unwrap_or_default(),
+            created_at: self.created_at,
+            reviewed_by: self.reviewed_by.as_deref().and_then(|id| Uuid::parse_str(id).ok()),
+            reviewed_at: self.reviewed_at,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for saved task filters
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct SavedTaskFilterRow {
+    pub id: String,
+    pub household_id: String,
+    pub user_id: String,
+    pub name: String,
+    /// JSON encoded `shared::TaskFilter`
+    pub filter: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl SavedTaskFilterRow {
+    pub fn to_shared(&self) -> shared::SavedTaskFilter {
+        shared::SavedTaskFilter {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            user_id: Uuid::parse_str(&self.user_id).unwrap(),
+            name: self.name.clone(),
+            filter: serde_json::from_str(&self.filter).unwrap_or_default(),
+            created_at: self.created_at,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for share links
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ShareLinkRow {
+    pub id: String,
+    pub household_id: String,
+    pub resource: String,
+    pub token: String,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl ShareLinkRow {
+    pub fn to_shared(&self) -> shared::ShareLink {
+        shared::ShareLink {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            resource: self.resource.parse().unwrap_or(shared::ShareResource::ShoppingList),
+            token: self.token.clone(),
+            created_by: Uuid::parse_str(&self.created_by).unwrap(),
+            created_at: self.created_at,
+            expires_at: self.expires_at,
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for shopping list items
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ShoppingListItemRow {
+    pub id: String,
+    pub household_id: String,
+    pub name: String,
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+    pub is_checked: bool,
+    pub added_by: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ShoppingListItemRow {
+    pub fn to_shared(&self) -> shared::ShoppingListItem {
+        shared::ShoppingListItem {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            name: self.name.clone(),
+            quantity: self.quantity,
+            unit: self.unit.clone(),
+            is_checked: self.is_checked,
+            added_by: Uuid::parse_str(&self.added_by).unwrap(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shopping_list_item_row_to_shared() {
+        let now = Utc::now();
+        let id = Uuid::new_v4();
+        let row = ShoppingListItemRow {
+            id: id.to_string(),
+            household_id: Uuid::new_v4().to_string(),
+            name: "Milk".to_string(),
+            quantity: Some(1.5),
+            unit: Some("l".to_string()),
+            is_checked: false,
+            added_by: Uuid::new_v4().to_string(),
+            created_at: now,
+            updated_at: now,
+        };
+
+        let shared = row.to_shared();
+
+        assert_eq!(shared.id, id);
+        assert_eq!(shared.name, "Milk");
+        assert_eq!(shared.quantity, Some(1.5));
+        assert_eq!(shared.unit.as_deref(), Some("l"));
+        assert!(!shared.is_checked);
+    }
+}
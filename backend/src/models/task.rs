@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
@@ -17,15 +17,25 @@ pub struct TaskRow {
     pub time_period: Option<String>,
     pub allow_exceed_target: bool,
     pub requires_review: bool,
+    pub reviewer_type: String,
+    pub reviewer_id: Option<String>,
     pub points_reward: Option<i64>,
     pub points_penalty: Option<i64>,
     pub due_time: Option<String>,
+    pub effort_minutes: Option<i32>,
+    pub active_from: Option<String>,
+    pub active_until: Option<String>,
+    pub ends_on: Option<NaiveDate>,
+    pub max_occurrences: Option<i32>,
     pub habit_type: String,
     pub category_id: Option<String>,
     pub archived: bool,
     pub paused: bool,
     pub suggestion: Option<String>,
     pub suggested_by: Option<String>,
+    pub postponed_from: Option<NaiveDate>,
+    pub postponed_to: Option<NaiveDate>,
+    pub version: i64,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -45,9 +55,16 @@ pub struct TaskRowWithCategory {
     pub time_period: Option<String>,
     pub allow_exceed_target: bool,
     pub requires_review: bool,
+    pub reviewer_type: String,
+    pub reviewer_id: Option<String>,
     pub points_reward: Option<i64>,
     pub points_penalty: Option<i64>,
     pub due_time: Option<String>,
+    pub effort_minutes: Option<i32>,
+    pub active_from: Option<String>,
+    pub active_until: Option<String>,
+    pub ends_on: Option<NaiveDate>,
+    pub max_occurrences: Option<i32>,
     pub habit_type: String,
     pub category_id: Option<String>,
     pub category_name: Option<String>,
@@ -55,6 +72,9 @@ pub struct TaskRowWithCategory {
     pub paused: bool,
     pub suggestion: Option<String>,
     pub suggested_by: Option<String>,
+    pub postponed_from: Option<NaiveDate>,
+    pub postponed_to: Option<NaiveDate>,
+    pub version: i64,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -66,6 +86,7 @@ impl TaskRow {
         });
 
         let time_period = self.time_period.as_ref().and_then(|p| p.parse().ok());
+        let assigned_user_id = self.assigned_user_id.as_ref().and_then(|id| Uuid::parse_str(id).ok());
 
         shared::Task {
             id: Uuid::parse_str(&self.id).unwrap(),
@@ -74,21 +95,34 @@ impl TaskRow {
             description: self.description.clone(),
             recurrence_type: self.recurrence_type.parse().unwrap_or(shared::RecurrenceType::Daily),
             recurrence_value,
-            assigned_user_id: self.assigned_user_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            assigned_user_id,
+            // Only the first assignee is stored on the task itself; see `tasks::load_assignees`
+            assignee_ids: assigned_user_id.into_iter().collect(),
             target_count: self.target_count,
             time_period,
             allow_exceed_target: self.allow_exceed_target,
             requires_review: self.requires_review,
+            reviewer_type: self.reviewer_type.parse().unwrap_or_default(),
+            reviewer_id: self.reviewer_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
             points_reward: self.points_reward,
             points_penalty: self.points_penalty,
             due_time: self.due_time.clone(),
+            effort_minutes: self.effort_minutes,
+            active_from: self.active_from.clone(),
+            active_until: self.active_until.clone(),
+            ends_on: self.ends_on,
+            max_occurrences: self.max_occurrences,
             habit_type: self.habit_type.parse().unwrap_or(shared::HabitType::Good),
             category_id: self.category_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
             category_name: None,
+            tag_ids: Vec::new(),
             archived: self.archived,
             paused: self.paused,
             suggestion: self.suggestion.as_ref().and_then(|s| s.parse().ok()),
             suggested_by: self.suggested_by.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            postponed_from: self.postponed_from,
+            postponed_to: self.postponed_to,
+            version: self.version,
             created_at: self.created_at,
             updated_at: self.updated_at,
         }
@@ -103,6 +137,7 @@ impl TaskRowWithCategory {
         });
 
         let time_period = self.time_period.as_ref().and_then(|p| p.parse().ok());
+        let assigned_user_id = self.assigned_user_id.as_ref().and_then(|id| Uuid::parse_str(id).ok());
 
         shared::Task {
             id: Uuid::parse_str(&self.id).unwrap(),
@@ -111,21 +146,34 @@ impl TaskRowWithCategory {
             description: self.description.clone(),
             recurrence_type: self.recurrence_type.parse().unwrap_or(shared::RecurrenceType::Daily),
             recurrence_value,
-            assigned_user_id: self.assigned_user_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            assigned_user_id,
+            // Only the first assignee is stored on the task itself; see `tasks::load_assignees`
+            assignee_ids: assigned_user_id.into_iter().collect(),
             target_count: self.target_count,
             time_period,
             allow_exceed_target: self.allow_exceed_target,
             requires_review: self.requires_review,
+            reviewer_type: self.reviewer_type.parse().unwrap_or_default(),
+            reviewer_id: self.reviewer_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
             points_reward: self.points_reward,
             points_penalty: self.points_penalty,
             due_time: self.due_time.clone(),
+            effort_minutes: self.effort_minutes,
+            active_from: self.active_from.clone(),
+            active_until: self.active_until.clone(),
+            ends_on: self.ends_on,
+            max_occurrences: self.max_occurrences,
             habit_type: self.habit_type.parse().unwrap_or(shared::HabitType::Good),
             category_id: self.category_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
             category_name: self.category_name.clone(),
+            tag_ids: Vec::new(),
             archived: self.archived,
             paused: self.paused,
             suggestion: self.suggestion.as_ref().and_then(|s| s.parse().ok()),
             suggested_by: self.suggested_by.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            postponed_from: self.postponed_from,
+            postponed_to: self.postponed_to,
+            version: self.version,
             created_at: self.created_at,
             updated_at: self.updated_at,
         }
@@ -155,15 +203,25 @@ mod tests {
             time_period: None,
             allow_exceed_target: true,
             requires_review: false,
+            reviewer_type: "any_reviewer".to_string(),
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: "good".to_string(),
             category_id: None,
             archived: false,
             paused: false,
             suggestion: None,
             suggested_by: None,
+            postponed_from: None,
+            postponed_to: None,
+            version: 1,
             created_at: now,
             updated_at: now,
         };
@@ -198,15 +256,25 @@ mod tests {
             time_period: None,
             allow_exceed_target: false,
             requires_review: false,
+            reviewer_type: "any_reviewer".to_string(),
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: "good".to_string(),
             category_id: None,
             archived: false,
             paused: false,
             suggestion: None,
             suggested_by: None,
+            postponed_from: None,
+            postponed_to: None,
+            version: 1,
             created_at: now,
             updated_at: now,
         };
@@ -236,15 +304,25 @@ mod tests {
             time_period: None,
             allow_exceed_target: true,
             requires_review: false,
+            reviewer_type: "any_reviewer".to_string(),
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: "good".to_string(),
             category_id: None,
             archived: false,
             paused: false,
             suggestion: None,
             suggested_by: None,
+            postponed_from: None,
+            postponed_to: None,
+            version: 1,
             created_at: now,
             updated_at: now,
         };
@@ -263,15 +341,25 @@ mod tests {
             time_period: None,
             allow_exceed_target: false,
             requires_review: false,
+            reviewer_type: "any_reviewer".to_string(),
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: "good".to_string(),
             category_id: None,
             archived: false,
             paused: false,
             suggestion: None,
             suggested_by: None,
+            postponed_from: None,
+            postponed_to: None,
+            version: 1,
             created_at: now,
             updated_at: now,
         };
@@ -295,15 +383,25 @@ mod tests {
             time_period: None,
             allow_exceed_target: true,
             requires_review: false,
+            reviewer_type: "any_reviewer".to_string(),
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: "good".to_string(),
             category_id: None,
             archived: false,
             paused: false,
             suggestion: None,
             suggested_by: None,
+            postponed_from: None,
+            postponed_to: None,
+            version: 1,
             created_at: now,
             updated_at: now,
         };
@@ -323,15 +421,25 @@ mod tests {
             time_period: None,
             allow_exceed_target: true,
             requires_review: false,
+            reviewer_type: "any_reviewer".to_string(),
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: "bad".to_string(),
             category_id: None,
             archived: false,
             paused: false,
             suggestion: None,
             suggested_by: None,
+            postponed_from: None,
+            postponed_to: None,
+            version: 1,
             created_at: now,
             updated_at: now,
         };
@@ -0,0 +1,63 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for task comments
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct TaskCommentRow {
+    pub id: String,
+    pub task_id: String,
+    pub household_id: String,
+    pub user_id: String,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TaskCommentRow {
+    pub fn to_shared(&self) -> shared::TaskComment {
+        shared::TaskComment {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            task_id: Uuid::parse_str(&self.task_id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            user_id: Uuid::parse_str(&self.user_id).unwrap(),
+            content: self.content.clone(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_task_comment_row_to_shared() {
+        let now = Utc::now();
+        let id = Uuid::new_v4();
+        let task_id = Uuid::new_v4();
+        let household_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+
+        let row = TaskCommentRow {
+            id: id.to_string(),
+            task_id: task_id.to_string(),
+            household_id: household_id.to_string(),
+            user_id: user_id.to_string(),
+            content: "Dishwasher is broken, skip today".to_string(),
+            created_at: now,
+            updated_at: now,
+        };
+
+        let shared = row.to_shared();
+
+        assert_eq!(shared.id, id);
+        assert_eq!(shared.task_id, task_id);
+        assert_eq!(shared.household_id, household_id);
+        assert_eq!(shared.user_id, user_id);
+        assert_eq!(shared.content, "Dishwasher is broken, skip today");
+        assert_eq!(shared.created_at, now);
+    }
+}
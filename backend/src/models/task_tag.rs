@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for task tags
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct TaskTagRow {
+    pub id: String,
+    pub household_id: String,
+    pub name: String,
+    pub color: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TaskTagRow {
+    pub fn to_shared(&self) -> shared::TaskTag {
+        shared::TaskTag {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            name: self.name.clone(),
+            color: self.color.clone(),
+            created_at: self.created_at,
+        }
+    }
+}
@@ -7,6 +7,13 @@ use uuid::Uuid;
 pub struct UserSettingsRow {
     pub user_id: String,
     pub language: String,
+    pub theme: String,
+    pub notify_chat: bool,
+    pub notify_reviews: bool,
+    pub notify_assignments: bool,
+    pub notify_reminders: bool,
+    pub quiet_hours_start: Option<String>,
+    pub quiet_hours_end: Option<String>,
     pub updated_at: DateTime<Utc>,
 }
 
@@ -15,6 +22,15 @@ impl UserSettingsRow {
         shared::UserSettings {
             user_id: Uuid::parse_str(&self.user_id).unwrap(),
             language: self.language.clone(),
+            theme: self.theme.parse().unwrap_or_default(),
+            notification_preferences: shared::NotificationPreferences {
+                chat: self.notify_chat,
+                reviews: self.notify_reviews,
+                assignments: self.notify_assignments,
+                reminders: self.notify_reminders,
+                quiet_hours_start: self.quiet_hours_start.clone(),
+                quiet_hours_end: self.quiet_hours_end.clone(),
+            },
             updated_at: self.updated_at,
         }
     }
@@ -32,6 +48,13 @@ mod tests {
         let row = UserSettingsRow {
             user_id: user_id.to_string(),
             language: "de".to_string(),
+            theme: "dark".to_string(),
+            notify_chat: false,
+            notify_reviews: true,
+            notify_assignments: true,
+            notify_reminders: true,
+            quiet_hours_start: Some("22:00".to_string()),
+            quiet_hours_end: Some("07:00".to_string()),
             updated_at: now,
         };
 
@@ -39,5 +62,8 @@ mod tests {
 
         assert_eq!(shared.user_id, user_id);
         assert_eq!(shared.language, "de");
+        assert_eq!(shared.theme, shared::Theme::Dark);
+        assert!(!shared.notification_preferences.chat);
+        assert_eq!(shared.notification_preferences.quiet_hours_start.as_deref(), Some("22:00"));
     }
 }
@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for outbound webhooks
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct WebhookRow {
+    pub id: String,
+    pub household_id: String,
+    pub url: String,
+    pub secret: String,
+    pub events: String,
+    pub enabled: bool,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub last_delivery_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+}
+
+impl WebhookRow {
+    pub fn to_shared(&self) -> shared::Webhook {
+        shared::Webhook {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            url: self.url.clone(),
+            events: self.events.split(',').filter_map(|e| e.parse().ok()).collect(),
+            enabled: self.enabled,
+            created_at: self.created_at,
+            last_delivery_at: self.last_delivery_at,
+            last_error: self.last_error.clone(),
+        }
+    }
+
+    pub fn subscribes_to(&self, event: shared::WebhookEvent) -> bool {
+        self.events.split(',').any(|e| e == event.as_str())
+    }
+}
@@ -4,7 +4,7 @@ use thiserror::Error;
 use uuid::Uuid;
 
 use crate::models::ActivityLogRow;
-use shared::{ActivityLog, ActivityLogWithUsers, ActivityType, User};
+use shared::{ActivityLog, ActivityLogWithUsers, ActivityType, Paginated, User};
 
 #[derive(Debug, Error)]
 pub enum ActivityLogError {
@@ -135,9 +135,15 @@ pub async fn list_household_activities(
     pool: &SqlitePool,
     household_id: &Uuid,
     limit: Option<i64>,
-) -> Result<Vec<ActivityLogWithUsers>, ActivityLogError> {
+    offset: i64,
+) -> Result<Paginated<ActivityLogWithUsers>, ActivityLogError> {
     let limit = limit.unwrap_or(100);
 
+    let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM activity_logs WHERE household_id = ?")
+        .bind(household_id.to_string())
+        .fetch_one(pool)
+        .await?;
+
     let rows: Vec<JoinedActivityRow> = sqlx::query_as(
         r#"
         SELECT
@@ -152,15 +158,21 @@ pub async fn list_household_activities(
         LEFT JOIN users affected ON al.affected_user_id = affected.id
         WHERE al.household_id = ?
         ORDER BY al.created_at DESC
-        LIMIT ?
+        LIMIT ? OFFSET ?
         "#,
     )
     .bind(household_id.to_string())
     .bind(limit)
+    .bind(offset)
     .fetch_all(pool)
     .await?;
 
-    Ok(rows.into_iter().map(|row| row.into_activity_log_with_users()).collect())
+    Ok(Paginated {
+        items: rows.into_iter().map(|row| row.into_activity_log_with_users()).collect(),
+        total,
+        limit,
+        offset,
+    })
 }
 
 /// List activities affecting a specific user (for non-owners)
@@ -169,9 +181,19 @@ pub async fn list_user_activities(
     household_id: &Uuid,
     user_id: &Uuid,
     limit: Option<i64>,
-) -> Result<Vec<ActivityLogWithUsers>, ActivityLogError> {
+    offset: i64,
+) -> Result<Paginated<ActivityLogWithUsers>, ActivityLogError> {
     let limit = limit.unwrap_or(100);
 
+    let total: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM activity_logs WHERE household_id = ? AND (affected_user_id = ? OR actor_id = ?)",
+    )
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .bind(user_id.to_string())
+    .fetch_one(pool)
+    .await?;
+
     let rows: Vec<JoinedActivityRow> = sqlx::query_as(
         r#"
         SELECT
@@ -186,17 +208,23 @@ pub async fn list_user_activities(
         LEFT JOIN users affected ON al.affected_user_id = affected.id
         WHERE al.household_id = ? AND (al.affected_user_id = ? OR al.actor_id = ?)
         ORDER BY al.created_at DESC
-        LIMIT ?
+        LIMIT ? OFFSET ?
         "#,
     )
     .bind(household_id.to_string())
     .bind(user_id.to_string())
     .bind(user_id.to_string())
     .bind(limit)
+    .bind(offset)
     .fetch_all(pool)
     .await?;
 
-    Ok(rows.into_iter().map(|row| row.into_activity_log_with_users()).collect())
+    Ok(Paginated {
+        items: rows.into_iter().map(|row| row.into_activity_log_with_users()).collect(),
+        total,
+        limit,
+        offset,
+    })
 }
 
 /// Get a single activity log by ID
@@ -0,0 +1,267 @@
+//! Operator tooling for instance admins
+//!
+//! Instance admins are flagged on their account. The first account registered
+//! on the instance is the admin, so a fresh self-hosted install has an operator
+//! without extra configuration, and admins can grant the role to others.
+
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::services::auth as auth_service;
+use shared::{AdminUser, HouseholdUsage};
+
+#[derive(Debug, Error)]
+pub enum AdminError {
+    #[error("User not found")]
+    UserNotFound,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+    #[error("Auth error: {0}")]
+    Auth(#[from] auth_service::AuthError),
+}
+
+#[derive(sqlx::FromRow)]
+struct AdminUserRow {
+    id: String,
+    username: String,
+    email: String,
+    created_at: DateTime<Utc>,
+    disabled_at: Option<DateTime<Utc>>,
+    household_count: i64,
+    is_admin: bool,
+}
+
+impl From<AdminUserRow> for AdminUser {
+    fn from(row: AdminUserRow) -> Self {
+        AdminUser {
+            id: Uuid::parse_str(&row.id).unwrap(),
+            username: row.username,
+            email: row.email,
+            created_at: row.created_at,
+            disabled_at: row.disabled_at,
+            household_count: row.household_count,
+            is_admin: row.is_admin,
+        }
+    }
+}
+
+const ADMIN_USER_QUERY: &str = r#"
+    SELECT u.id, u.username, u.email, u.created_at, u.disabled_at, u.is_admin,
+           (SELECT COUNT(*) FROM household_memberships m WHERE m.user_id = u.id) AS household_count
+    FROM users u
+"#;
+
+async fn get_admin_user(pool: &SqlitePool, user_id: &Uuid) -> Result<AdminUser, AdminError> {
+    let row: AdminUserRow = sqlx::query_as(&format!("{} WHERE u.id = ?", ADMIN_USER_QUERY))
+        .bind(user_id.to_string())
+        .fetch_one(pool)
+        .await?;
+    Ok(row.into())
+}
+
+/// Whether the user may use the `/api/admin` endpoints
+pub async fn is_instance_admin(pool: &SqlitePool, user_id: &Uuid) -> Result<bool, AdminError> {
+    let is_admin: Option<bool> = sqlx::query_scalar("SELECT is_admin FROM users WHERE id = ?")
+        .bind(user_id.to_string())
+        .fetch_optional(pool)
+        .await?;
+    Ok(is_admin.unwrap_or(false))
+}
+
+/// All accounts, oldest first
+pub async fn list_users(pool: &SqlitePool) -> Result<Vec<AdminUser>, AdminError> {
+    let rows: Vec<AdminUserRow> = sqlx::query_as(&format!("{} ORDER BY u.created_at, u.rowid", ADMIN_USER_QUERY))
+        .fetch_all(pool)
+        .await?;
+    Ok(rows.into_iter().map(AdminUser::from).collect())
+}
+
+/// Disable or re-enable an account. Disabling ends the user's sessions: refresh
+/// tokens are deleted, API tokens stop working and login is refused.
+pub async fn set_user_disabled(pool: &SqlitePool, user_id: &Uuid, disabled: bool) -> Result<AdminUser, AdminError> {
+    let disabled_at = disabled.then(Utc::now);
+    let result = sqlx::query(
+        "UPDATE users SET disabled_at = CASE WHEN ? IS NULL THEN NULL ELSE COALESCE(disabled_at, ?) END WHERE id = ?",
+    )
+    .bind(disabled_at)
+    .bind(disabled_at)
+    .bind(user_id.to_string())
+    .execute(pool)
+    .await?;
+    if result.rows_affected() == 0 {
+        return Err(AdminError::UserNotFound);
+    }
+
+    if disabled {
+        auth_service::delete_user_refresh_tokens(pool, user_id).await?;
+    }
+
+    get_admin_user(pool, user_id).await
+}
+
+/// Grant or revoke the instance admin role. Child accounts can't be admins.
+pub async fn set_user_admin(pool: &SqlitePool, user_id: &Uuid, is_admin: bool) -> Result<AdminUser, AdminError> {
+    let result = sqlx::query("UPDATE users SET is_admin = ? WHERE id = ? AND managed_by IS NULL")
+        .bind(is_admin)
+        .bind(user_id.to_string())
+        .execute(pool)
+        .await?;
+    if result.rows_affected() == 0 {
+        return Err(AdminError::UserNotFound);
+    }
+
+    get_admin_user(pool, user_id).await
+}
+
+#[derive(sqlx::FromRow)]
+struct HouseholdUsageRow {
+    id: String,
+    name: String,
+    created_at: DateTime<Utc>,
+    member_count: i64,
+    task_count: i64,
+    completion_count: i64,
+    point_transaction_count: i64,
+    chat_message_count: i64,
+    attachment_count: i64,
+    attachment_bytes: i64,
+}
+
+/// Row counts and upload storage per household, largest uploads first
+pub async fn household_usage(pool: &SqlitePool) -> Result<Vec<HouseholdUsage>, AdminError> {
+    let rows: Vec<HouseholdUsageRow> = sqlx::query_as(
+        r#"
+        SELECT h.id, h.name, h.created_at,
+               (SELECT COUNT(*) FROM household_memberships m WHERE m.household_id = h.id) AS member_count,
+               (SELECT COUNT(*) FROM tasks t WHERE t.household_id = h.id AND t.deleted_at IS NULL) AS task_count,
+               (SELECT COUNT(*) FROM task_completions tc JOIN tasks t ON t.id = tc.task_id
+                WHERE t.household_id = h.id) AS completion_count,
+               (SELECT COUNT(*) FROM point_transactions pt WHERE pt.household_id = h.id) AS point_transaction_count,
+               (SELECT COUNT(*) FROM chat_messages c WHERE c.household_id = h.id) AS chat_message_count,
+               (SELECT COUNT(*) FROM completion_attachments ca WHERE ca.household_id = h.id)
+                 + (SELECT COUNT(*) FROM attachments a WHERE a.household_id = h.id) AS attachment_count,
+               (SELECT COALESCE(SUM(ca.size_bytes), 0) FROM completion_attachments ca WHERE ca.household_id = h.id)
+                 + (SELECT COALESCE(SUM(a.size_bytes), 0) FROM attachments a WHERE a.household_id = h.id) AS attachment_bytes
+        FROM households h
+        ORDER BY attachment_bytes DESC, h.created_at
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| HouseholdUsage {
+            id: Uuid::parse_str(&row.id).unwrap(),
+            name: row.name,
+            created_at: row.created_at,
+            member_count: row.member_count,
+            task_count: row.task_count,
+            completion_count: row.completion_count,
+            point_transaction_count: row.point_transaction_count,
+            chat_message_count: row.chat_message_count,
+            attachment_count: row.attachment_count,
+            attachment_bytes: row.attachment_bytes,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::api_tokens;
+    use crate::test_utils::{create_test_household, create_test_membership, create_test_pool, create_test_task, create_test_user};
+    use shared::{CreateApiTokenRequest, CreateUserRequest, Role};
+
+    #[tokio::test]
+    async fn test_first_registered_user_is_admin() {
+        let pool = create_test_pool().await;
+        let register = |username: &str, email: &str| CreateUserRequest {
+            username: username.to_string(),
+            email: email.to_string(),
+            password: "password123".to_string(),
+        };
+        let first = auth_service::register_user(&pool, &register("first", "first@test.com")).await.unwrap();
+        let second = auth_service::register_user(&pool, &register("second", "second@test.com")).await.unwrap();
+
+        assert!(is_instance_admin(&pool, &first.id).await.unwrap());
+        assert!(!is_instance_admin(&pool, &second.id).await.unwrap());
+        assert!(!is_instance_admin(&pool, &Uuid::new_v4()).await.unwrap());
+
+        let users = list_users(&pool).await.unwrap();
+        assert_eq!(users.len(), 2);
+        assert_eq!(users[0].id, first.id);
+        assert!(users[0].is_admin);
+        assert!(!users[1].is_admin);
+    }
+
+    #[tokio::test]
+    async fn test_set_user_admin() {
+        let pool = create_test_pool().await;
+        let user_id = create_test_user(&pool, "alice@test.com", Role::Member).await;
+        assert!(!is_instance_admin(&pool, &user_id).await.unwrap());
+
+        let user = set_user_admin(&pool, &user_id, true).await.unwrap();
+        assert!(user.is_admin);
+        assert!(is_instance_admin(&pool, &user_id).await.unwrap());
+
+        let user = set_user_admin(&pool, &user_id, false).await.unwrap();
+        assert!(!user.is_admin);
+        assert!(!is_instance_admin(&pool, &user_id).await.unwrap());
+
+        assert!(matches!(
+            set_user_admin(&pool, &Uuid::new_v4(), true).await,
+            Err(AdminError::UserNotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_disabled_user_loses_api_tokens() {
+        let pool = create_test_pool().await;
+        let user_id = create_test_user(&pool, "alice@test.com", Role::Member).await;
+        let created = api_tokens::create_token(
+            &pool,
+            &user_id,
+            &CreateApiTokenRequest { name: "Dashboard".to_string(), read_only: true },
+        )
+        .await
+        .unwrap();
+
+        let user = set_user_disabled(&pool, &user_id, true).await.unwrap();
+        assert!(user.disabled_at.is_some());
+        assert!(matches!(
+            auth_service::ensure_account_enabled(&pool, &user_id).await,
+            Err(auth_service::AuthError::AccountDisabled)
+        ));
+        assert!(api_tokens::authenticate(&pool, &created.secret).await.unwrap().is_none());
+
+        let user = set_user_disabled(&pool, &user_id, false).await.unwrap();
+        assert!(user.disabled_at.is_none());
+        assert!(auth_service::ensure_account_enabled(&pool, &user_id).await.is_ok());
+        assert!(api_tokens::authenticate(&pool, &created.secret).await.unwrap().is_some());
+
+        assert!(matches!(
+            set_user_disabled(&pool, &Uuid::new_v4(), true).await,
+            Err(AdminError::UserNotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_household_usage_counts_rows() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let user_id = create_test_user(&pool, "alice@test.com", Role::Member).await;
+        create_test_membership(&pool, &household_id, &user_id, Role::Member).await;
+        create_test_task(&pool, &household_id).build().await;
+        create_test_task(&pool, &household_id).build().await;
+
+        let usage = household_usage(&pool).await.unwrap();
+        let usage = usage.iter().find(|u| u.id == household_id).unwrap();
+        assert_eq!(usage.member_count, 1);
+        assert_eq!(usage.task_count, 2);
+        assert_eq!(usage.completion_count, 0);
+        assert_eq!(usage.attachment_bytes, 0);
+    }
+}
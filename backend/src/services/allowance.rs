@@ -0,0 +1,401 @@
+//! Monthly allowance
+//!
+//! Households can exchange points for pocket money. At the start of each
+//! household month every member with the Member role gets a payout of
+//! `HouseholdSettings::allowance_cents_per_point` for their points of the month
+//! that just ended. With `AllowancePointHandling::Reset` the whole balance is
+//! paid out and taken away; with `CarryOver` only the points earned during the
+//! month are paid and the balance stays. A summary announcement lists the payouts.
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use sqlx::SqlitePool;
+use std::str::FromStr;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::AllowancePayoutRow;
+use crate::services::points::{self, TransactionSource};
+use crate::services::{announcements, scheduler};
+use shared::{AllowancePayout, AllowancePointHandling, CreateAnnouncementRequest, PointTransactionType};
+
+/// How long the payout announcement stays visible
+const ANNOUNCEMENT_DAYS: i64 = 7;
+
+#[derive(Debug, Error)]
+pub enum AllowanceError {
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("Points error: {0}")]
+    Points(#[from] points::PointsError),
+    #[error("Announcement error: {0}")]
+    Announcement(#[from] announcements::AnnouncementError),
+}
+
+/// Report from paying out allowances
+#[derive(Debug, Clone, Default)]
+pub struct AllowanceReport {
+    pub households_checked: u32,
+    pub months_processed: u32,
+    pub payouts_created: u32,
+    pub cents_paid: i64,
+}
+
+/// First day of the last complete month at local date `today`
+pub fn payout_month(today: NaiveDate) -> NaiveDate {
+    let first = today.with_day(1).unwrap_or(today);
+    (first - chrono::Duration::days(1)).with_day(1).unwrap_or(first)
+}
+
+/// First day of the month after `month`
+fn next_month(month: NaiveDate) -> NaiveDate {
+    (month + chrono::Duration::days(32)).with_day(1).unwrap_or(month)
+}
+
+/// Points a payout is computed from: the whole positive balance when points
+/// are reset, otherwise the points gained during the month
+pub fn payout_points(handling: AllowancePointHandling, balance: i64, earned: i64) -> i64 {
+    match handling {
+        AllowancePointHandling::Reset => balance.max(0),
+        AllowancePointHandling::CarryOver => earned.max(0),
+    }
+}
+
+/// Format cents as "12.34"
+fn format_cents(cents: i64) -> String {
+    format!("{}.{:02}", cents / 100, cents % 100)
+}
+
+/// Title and content of the payout announcement
+fn render_announcement(month: NaiveDate, payouts: &[(String, i64, i64)]) -> (String, String) {
+    let title = format!("Allowance for {}", month.format("%B %Y"));
+    let mut body = String::new();
+    for (username, points, cents) in payouts {
+        body.push_str(&format!("- {}: {} points = {}\n", username, points, format_cents(*cents)));
+    }
+    let total = payouts.iter().fold(0i64, |total, (_, _, cents)| total.saturating_add(*cents));
+    body.push_str(&format!("\nTotal: {}", format_cents(total)));
+    (title, body)
+}
+
+/// Pay out the last complete month of every household with an allowance rate.
+/// Each household month is paid once; members who joined after the month are left out.
+pub async fn process_allowance_payouts(pool: &SqlitePool, now: DateTime<Utc>) -> Result<AllowanceReport, AllowanceError> {
+    let mut report = AllowanceReport::default();
+
+    let households: Vec<(String, String, i64, String, String)> = sqlx::query_as(
+        r#"
+        SELECT s.household_id, s.timezone, s.allowance_cents_per_point, s.allowance_point_handling, h.owner_id
+        FROM household_settings s
+        JOIN households h ON s.household_id = h.id
+        WHERE s.allowance_cents_per_point > 0
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    for (household_id, timezone, cents_per_point, handling, owner_id) in households {
+        report.households_checked += 1;
+
+        let (Ok(household_uuid), Ok(owner_uuid)) = (Uuid::parse_str(&household_id), Uuid::parse_str(&owner_id)) else {
+            continue;
+        };
+        let handling = AllowancePointHandling::from_str(&handling).unwrap_or_default();
+        let tz = scheduler::parse_timezone(&timezone);
+        let month = payout_month(now.with_timezone(&tz).date_naive());
+
+        // The month claim and all payouts commit together, so a failed run leaves
+        // the month unclaimed and is retried on the next tick
+        let mut tx = pool.begin().await?;
+
+        let claimed = sqlx::query("INSERT OR IGNORE INTO allowance_runs (household_id, month, created_at) VALUES (?, ?, ?)")
+            .bind(&household_id)
+            .bind(month)
+            .bind(now)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+        if claimed == 0 {
+            continue;
+        }
+
+        let from = scheduler::local_midnight_utc(month, tz).format("%Y-%m-%d %H:%M:%S").to_string();
+        let until = scheduler::local_midnight_utc(next_month(month), tz)
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string();
+
+        let members: Vec<(String, String, i64)> = sqlx::query_as(
+            r#"
+            SELECT m.user_id, u.username, m.points
+            FROM household_memberships m
+            JOIN users u ON m.user_id = u.id
+            WHERE m.household_id = ? AND m.role = 'member' AND julianday(m.joined_at) < julianday(?)
+            ORDER BY u.username
+            "#,
+        )
+        .bind(&household_id)
+        .bind(&until)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let mut payouts = Vec::new();
+        for (user_id, username, balance) in members {
+            let Ok(user_uuid) = Uuid::parse_str(&user_id) else {
+                continue;
+            };
+            let earned: i64 = sqlx::query_scalar(
+                r#"
+                SELECT COALESCE(SUM(amount), 0)
+                FROM point_transactions
+                WHERE household_id = ? AND user_id = ? AND transaction_type != 'allowance_payout'
+                  AND julianday(created_at) >= julianday(?) AND julianday(created_at) < julianday(?)
+                "#,
+            )
+            .bind(&household_id)
+            .bind(&user_id)
+            .bind(&from)
+            .bind(&until)
+            .fetch_one(&mut *tx)
+            .await?;
+
+            let points = payout_points(handling, balance, earned);
+            if points == 0 {
+                continue;
+            }
+            let Some(amount_cents) = points.checked_mul(cents_per_point) else {
+                log::warn!(
+                    "Skipping allowance of {} points at {} cents for user {} in household {}: amount overflows",
+                    points,
+                    cents_per_point,
+                    user_id,
+                    household_id
+                );
+                continue;
+            };
+
+            sqlx::query(
+                r#"
+                INSERT INTO allowance_payouts (id, household_id, user_id, month, points, amount_cents, created_at)
+                VALUES (?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(Uuid::new_v4().to_string())
+            .bind(&household_id)
+            .bind(&user_id)
+            .bind(month)
+            .bind(points)
+            .bind(amount_cents)
+            .bind(now)
+            .execute(&mut *tx)
+            .await?;
+
+            if handling == AllowancePointHandling::Reset {
+                points::apply_points_in(
+                    &mut tx,
+                    &household_uuid,
+                    &user_uuid,
+                    -points,
+                    PointTransactionType::AllowancePayout,
+                    &TransactionSource {
+                        description: Some(format!("Allowance for {}", month.format("%m/%Y"))),
+                        ..Default::default()
+                    },
+                )
+                .await?;
+            }
+
+            payouts.push((username, points, amount_cents));
+        }
+
+        tx.commit().await?;
+        report.months_processed += 1;
+        report.payouts_created += payouts.len() as u32;
+        report.cents_paid = payouts.iter().fold(report.cents_paid, |total, (_, _, cents)| total.saturating_add(*cents));
+
+        if payouts.is_empty() {
+            continue;
+        }
+
+        // Announcements need an author; the summary is posted in the owner's name
+        let (title, content) = render_announcement(month, &payouts);
+        announcements::create_announcement(
+            pool,
+            &household_uuid,
+            &owner_uuid,
+            &CreateAnnouncementRequest {
+                title,
+                content: Some(content),
+                starts_at: Some(now),
+                ends_at: Some(now + chrono::Duration::days(ANNOUNCEMENT_DAYS)),
+            },
+        )
+        .await?;
+    }
+
+    Ok(report)
+}
+
+/// Payouts of a household, newest month first; `user_id` limits them to one member
+pub async fn list_payouts(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: Option<&Uuid>,
+) -> Result<Vec<AllowancePayout>, AllowanceError> {
+    let rows: Vec<AllowancePayoutRow> = sqlx::query_as(
+        r#"
+        SELECT * FROM allowance_payouts
+        WHERE household_id = ? AND (? IS NULL OR user_id = ?)
+        ORDER BY month DESC, created_at DESC
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(user_id.map(|id| id.to_string()))
+    .bind(user_id.map(|id| id.to_string()))
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.iter().map(|row| row.to_shared()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use chrono::TimeZone;
+    use shared::Role;
+
+    #[test]
+    fn test_payout_month() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(payout_month(date(2024, 2, 1)), date(2024, 1, 1));
+        assert_eq!(payout_month(date(2024, 3, 31)), date(2024, 2, 1));
+        assert_eq!(payout_month(date(2024, 1, 15)), date(2023, 12, 1));
+        assert_eq!(next_month(date(2024, 1, 1)), date(2024, 2, 1));
+    }
+
+    #[test]
+    fn test_payout_points() {
+        assert_eq!(payout_points(AllowancePointHandling::Reset, 150, 40), 150);
+        assert_eq!(payout_points(AllowancePointHandling::Reset, -10, 40), 0);
+        assert_eq!(payout_points(AllowancePointHandling::CarryOver, 150, 40), 40);
+        assert_eq!(payout_points(AllowancePointHandling::CarryOver, 150, -5), 0);
+    }
+
+    #[tokio::test]
+    async fn test_process_allowance_payouts_once_per_month() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let child = create_test_user(&pool, "child@test.com", Role::Member).await;
+        let parent = create_test_user(&pool, "parent@test.com", Role::Member).await;
+        create_test_membership(&pool, &household_id, &child, Role::Member).await;
+        create_test_membership(&pool, &household_id, &parent, Role::Admin).await;
+        sqlx::query("UPDATE household_memberships SET joined_at = '2024-01-01 00:00:00' WHERE household_id = ?")
+            .bind(household_id.to_string())
+            .execute(&pool)
+            .await
+            .unwrap();
+        set_user_points(&pool, &household_id, &child, 120).await;
+        set_user_points(&pool, &household_id, &parent, 80).await;
+
+        let first_of_february = Utc.with_ymd_and_hms(2024, 2, 1, 8, 0, 0).unwrap();
+
+        // No rate: nothing happens
+        let report = process_allowance_payouts(&pool, first_of_february).await.unwrap();
+        assert_eq!(report.households_checked, 0);
+
+        sqlx::query("UPDATE household_settings SET allowance_cents_per_point = 10 WHERE household_id = ?")
+            .bind(household_id.to_string())
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let report = process_allowance_payouts(&pool, first_of_february).await.unwrap();
+        assert_eq!(report.payouts_created, 1);
+        assert_eq!(report.cents_paid, 1200);
+
+        let payouts = list_payouts(&pool, &household_id, None).await.unwrap();
+        assert_eq!(payouts.len(), 1);
+        assert_eq!(payouts[0].user_id, child);
+        assert_eq!(payouts[0].month, NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        assert!(list_payouts(&pool, &household_id, Some(&parent)).await.unwrap().is_empty());
+
+        // Reset takes the paid points away; admins are not paid
+        assert_points_balance(&pool, &household_id, &child, 0).await;
+        assert_points_balance(&pool, &household_id, &parent, 80).await;
+
+        let (title,): (String,) = sqlx::query_as("SELECT title FROM announcements WHERE household_id = ?")
+            .bind(household_id.to_string())
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(title, "Allowance for January 2024");
+
+        // The same month is never paid twice
+        let report = process_allowance_payouts(&pool, first_of_february + chrono::Duration::hours(5)).await.unwrap();
+        assert_eq!(report.months_processed, 0);
+    }
+
+    async fn allowance_household(pool: &SqlitePool, cents_per_point: i64) -> (Uuid, Uuid, Uuid) {
+        let household_id = create_test_household(pool).await;
+        let first = create_test_user(pool, "first@test.com", Role::Member).await;
+        let second = create_test_user(pool, "second@test.com", Role::Member).await;
+        create_test_membership(pool, &household_id, &first, Role::Member).await;
+        create_test_membership(pool, &household_id, &second, Role::Member).await;
+        sqlx::query("UPDATE household_memberships SET joined_at = '2024-01-01 00:00:00' WHERE household_id = ?")
+            .bind(household_id.to_string())
+            .execute(pool)
+            .await
+            .unwrap();
+        sqlx::query("UPDATE household_settings SET allowance_cents_per_point = ? WHERE household_id = ?")
+            .bind(cents_per_point)
+            .bind(household_id.to_string())
+            .execute(pool)
+            .await
+            .unwrap();
+        (household_id, first, second)
+    }
+
+    #[tokio::test]
+    async fn test_process_allowance_payouts_skips_overflow() {
+        let pool = create_test_pool().await;
+        let (household_id, first, second) = allowance_household(&pool, i64::MAX / 2).await;
+        set_user_points(&pool, &household_id, &first, 3).await;
+        set_user_points(&pool, &household_id, &second, 1).await;
+
+        let report = process_allowance_payouts(&pool, Utc.with_ymd_and_hms(2024, 2, 1, 8, 0, 0).unwrap()).await.unwrap();
+        assert_eq!(report.payouts_created, 1);
+        assert_eq!(report.cents_paid, i64::MAX / 2);
+
+        // The overflowing payout is left out and its points stay
+        assert!(list_payouts(&pool, &household_id, Some(&first)).await.unwrap().is_empty());
+        assert_points_balance(&pool, &household_id, &first, 3).await;
+        assert_points_balance(&pool, &household_id, &second, 0).await;
+    }
+
+    #[tokio::test]
+    async fn test_process_allowance_payouts_failed_run_is_retried() {
+        let pool = create_test_pool().await;
+        let (household_id, first, second) = allowance_household(&pool, 10).await;
+        set_user_points(&pool, &household_id, &first, 20).await;
+        set_user_points(&pool, &household_id, &second, 30).await;
+        let first_of_february = Utc.with_ymd_and_hms(2024, 2, 1, 8, 0, 0).unwrap();
+
+        // Deducting the second member's points fails after the first member was paid
+        sqlx::query(&format!(
+            "CREATE TRIGGER fail_payout BEFORE INSERT ON point_transactions WHEN NEW.user_id = '{}' BEGIN SELECT RAISE(ABORT, 'fail'); END",
+            second
+        ))
+        .execute(&pool)
+        .await
+        .unwrap();
+        assert!(process_allowance_payouts(&pool, first_of_february).await.is_err());
+        assert!(list_payouts(&pool, &household_id, None).await.unwrap().is_empty());
+        assert_points_balance(&pool, &household_id, &first, 20).await;
+
+        // Nothing was claimed, so the next run pays the month
+        sqlx::query("DROP TRIGGER fail_payout").execute(&pool).await.unwrap();
+        let report = process_allowance_payouts(&pool, first_of_february).await.unwrap();
+        assert_eq!(report.months_processed, 1);
+        assert_eq!(report.payouts_created, 2);
+        assert_eq!(report.cents_paid, 500);
+    }
+}
@@ -1,10 +1,11 @@
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use sqlx::SqlitePool;
+use std::collections::HashMap;
 use thiserror::Error;
 use uuid::Uuid;
 
 use crate::models::AnnouncementRow;
-use shared::{Announcement, CreateAnnouncementRequest, UpdateAnnouncementRequest};
+use shared::{Announcement, AnnouncementAcknowledgment, CreateAnnouncementRequest, UpdateAnnouncementRequest};
 
 #[derive(Debug, Error)]
 pub enum AnnouncementError {
@@ -14,6 +15,20 @@ pub enum AnnouncementError {
     DatabaseError(#[from] sqlx::Error),
 }
 
+/// Report from publishing scheduled announcements
+#[derive(Debug, Clone, Default)]
+pub struct AnnouncementPublishReport {
+    /// Announcements that went live in this run, for the chat bridge
+    pub published: Vec<Announcement>,
+    /// Announcements whose end passed before their start was ever reached
+    pub expired: u32,
+}
+
+/// Whether an announcement starting at `starts_at` is live at `now`
+fn has_started(starts_at: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+    starts_at.is_none_or(|starts_at| starts_at <= now)
+}
+
 pub async fn create_announcement(
     pool: &SqlitePool,
     household_id: &Uuid,
@@ -23,11 +38,13 @@ pub async fn create_announcement(
     let id = Uuid::new_v4();
     let now = Utc::now();
     let content = request.content.as_deref().unwrap_or("");
+    // Scheduled announcements are published by the background job
+    let published_at = has_started(request.starts_at, now).then_some(now);
 
     sqlx::query(
         r#"
-        INSERT INTO announcements (id, household_id, created_by, title, content, starts_at, ends_at, created_at, updated_at)
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+        INSERT INTO announcements (id, household_id, created_by, title, content, starts_at, ends_at, published_at, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
         "#,
     )
     .bind(id.to_string())
@@ -37,6 +54,7 @@ pub async fn create_announcement(
     .bind(content)
     .bind(request.starts_at)
     .bind(request.ends_at)
+    .bind(published_at)
     .bind(now)
     .bind(now)
     .execute(pool)
@@ -50,6 +68,8 @@ pub async fn create_announcement(
         content: content.to_string(),
         starts_at: request.starts_at,
         ends_at: request.ends_at,
+        published_at,
+        acknowledged_at: None,
         created_at: now,
         updated_at: now,
     })
@@ -87,13 +107,15 @@ pub async fn list_announcements(
     Ok(rows.into_iter().map(|r| r.to_shared()).collect())
 }
 
-/// List currently active announcements for a household (for display)
+/// List currently active announcements for a household (for display), with
+/// the user's acknowledgment time filled in
 /// Active means:
 /// - starts_at IS NULL OR starts_at <= NOW
 /// - ends_at IS NULL OR ends_at > NOW
 pub async fn list_active_announcements(
     pool: &SqlitePool,
     household_id: &Uuid,
+    user_id: &Uuid,
 ) -> Result<Vec<Announcement>, AnnouncementError> {
     let now = Utc::now();
 
@@ -112,7 +134,25 @@ pub async fn list_active_announcements(
     .fetch_all(pool)
     .await?;
 
-    Ok(rows.into_iter().map(|r| r.to_shared()).collect())
+    let acknowledged: HashMap<String, DateTime<Utc>> = sqlx::query_as(
+        r#"
+        SELECT k.announcement_id, k.acknowledged_at
+        FROM announcement_acknowledgments k
+        JOIN announcements a ON k.announcement_id = a.id
+        WHERE a.household_id = ? AND k.user_id = ?
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .collect();
+
+    Ok(rows
+        .into_iter()
+        .map(|r| Announcement { acknowledged_at: acknowledged.get(&r.id).copied(), ..r.to_shared() })
+        .collect())
 }
 
 pub async fn update_announcement(
@@ -178,12 +218,164 @@ pub async fn delete_announcement(
     Ok(())
 }
 
+/// Record that the user has read the announcement; acknowledging again keeps
+/// the first time. Returns when it was acknowledged.
+pub async fn acknowledge_announcement(
+    pool: &SqlitePool,
+    announcement_id: &Uuid,
+    user_id: &Uuid,
+) -> Result<DateTime<Utc>, AnnouncementError> {
+    sqlx::query(
+        r#"
+        INSERT INTO announcement_acknowledgments (announcement_id, user_id, acknowledged_at)
+        VALUES (?, ?, ?)
+        ON CONFLICT (announcement_id, user_id) DO NOTHING
+        "#,
+    )
+    .bind(announcement_id.to_string())
+    .bind(user_id.to_string())
+    .bind(Utc::now())
+    .execute(pool)
+    .await?;
+
+    let (acknowledged_at,): (DateTime<Utc>,) = sqlx::query_as(
+        "SELECT acknowledged_at FROM announcement_acknowledgments WHERE announcement_id = ? AND user_id = ?",
+    )
+    .bind(announcement_id.to_string())
+    .bind(user_id.to_string())
+    .fetch_one(pool)
+    .await?;
+
+    Ok(acknowledged_at)
+}
+
+/// The read checklist of an announcement: every household member and when
+/// they acknowledged it, if they did
+pub async fn list_acknowledgments(
+    pool: &SqlitePool,
+    announcement: &Announcement,
+) -> Result<Vec<AnnouncementAcknowledgment>, AnnouncementError> {
+    let rows: Vec<(String, String, Option<DateTime<Utc>>)> = sqlx::query_as(
+        r#"
+        SELECT u.id, u.username, k.acknowledged_at
+        FROM household_memberships m
+        JOIN users u ON m.user_id = u.id
+        LEFT JOIN announcement_acknowledgments k ON k.user_id = u.id AND k.announcement_id = ?
+        WHERE m.household_id = ?
+        ORDER BY u.username COLLATE NOCASE ASC
+        "#,
+    )
+    .bind(announcement.id.to_string())
+    .bind(announcement.household_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|(user_id, username, acknowledged_at)| {
+            Some(AnnouncementAcknowledgment { user_id: Uuid::parse_str(&user_id).ok()?, username, acknowledged_at })
+        })
+        .collect())
+}
+
+/// Publish scheduled announcements whose start time has come. Announcements
+/// that already ended are marked as published without being announced.
+pub async fn process_scheduled_announcements(
+    pool: &SqlitePool,
+    now: DateTime<Utc>,
+) -> Result<AnnouncementPublishReport, AnnouncementError> {
+    let mut report = AnnouncementPublishReport::default();
+
+    let due: Vec<AnnouncementRow> = sqlx::query_as(
+        "SELECT * FROM announcements WHERE published_at IS NULL AND (starts_at IS NULL OR starts_at <= ?)",
+    )
+    .bind(now)
+    .fetch_all(pool)
+    .await?;
+
+    for row in due {
+        sqlx::query("UPDATE announcements SET published_at = ? WHERE id = ?")
+            .bind(now)
+            .bind(&row.id)
+            .execute(pool)
+            .await?;
+
+        if row.ends_at.is_some_and(|ends_at| ends_at <= now) {
+            report.expired += 1;
+        } else {
+            report.published.push(Announcement { published_at: Some(now), ..row.to_shared() });
+        }
+    }
+
+    Ok(report)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::*;
+    use chrono::Duration;
+    use shared::Role;
 
     #[test]
     fn test_announcement_error_display() {
         assert_eq!(AnnouncementError::NotFound.to_string(), "Announcement not found");
     }
+
+    fn request(title: &str, starts_at: Option<DateTime<Utc>>, ends_at: Option<DateTime<Utc>>) -> CreateAnnouncementRequest {
+        CreateAnnouncementRequest { title: title.to_string(), content: None, starts_at, ends_at }
+    }
+
+    #[tokio::test]
+    async fn test_scheduled_announcements_are_published_once() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let author = create_test_user(&pool, "alice@test.com", Role::Owner).await;
+        let now = Utc::now();
+
+        let immediate = create_announcement(&pool, &household_id, &author, &request("Now", None, None)).await.unwrap();
+        assert!(immediate.published_at.is_some());
+        let later = request("Water shutoff", Some(now + Duration::hours(2)), None);
+        let scheduled = create_announcement(&pool, &household_id, &author, &later).await.unwrap();
+        assert!(scheduled.published_at.is_none());
+        let missed = request("Missed", Some(now + Duration::hours(1)), Some(now + Duration::hours(2)));
+        create_announcement(&pool, &household_id, &author, &missed).await.unwrap();
+
+        let report = process_scheduled_announcements(&pool, now).await.unwrap();
+        assert!(report.published.is_empty());
+
+        let report = process_scheduled_announcements(&pool, now + Duration::hours(3)).await.unwrap();
+        assert_eq!(report.published.iter().map(|a| a.id).collect::<Vec<_>>(), vec![scheduled.id]);
+        assert_eq!(report.expired, 1);
+
+        let report = process_scheduled_announcements(&pool, now + Duration::hours(4)).await.unwrap();
+        assert!(report.published.is_empty());
+        assert_eq!(report.expired, 0);
+    }
+
+    #[tokio::test]
+    async fn test_acknowledgment_checklist() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let alice = create_test_user(&pool, "alice@test.com", Role::Member).await;
+        let bob = create_test_user(&pool, "bob@test.com", Role::Member).await;
+        for user_id in [alice, bob] {
+            create_test_membership(&pool, &household_id, &user_id, Role::Member).await;
+        }
+        let announcement = create_announcement(&pool, &household_id, &alice, &request("Notice", None, None)).await.unwrap();
+
+        let first = acknowledge_announcement(&pool, &announcement.id, &bob).await.unwrap();
+        // Acknowledging twice keeps the first time
+        assert_eq!(acknowledge_announcement(&pool, &announcement.id, &bob).await.unwrap(), first);
+
+        let checklist = list_acknowledgments(&pool, &announcement).await.unwrap();
+        let status: Vec<(Uuid, bool)> = checklist.iter().map(|a| (a.user_id, a.acknowledged_at.is_some())).collect();
+        assert!(status.contains(&(alice, false)));
+        assert!(status.contains(&(bob, true)));
+
+        let active = list_active_announcements(&pool, &household_id, &bob).await.unwrap();
+        assert_eq!(active[0].acknowledged_at, Some(first));
+        let active = list_active_announcements(&pool, &household_id, &alice).await.unwrap();
+        assert_eq!(active[0].acknowledged_at, None);
+    }
 }
@@ -0,0 +1,200 @@
+//! Long-lived personal API tokens
+//!
+//! Wall-mounted dashboards and home automation can't go through the login and
+//! JWT refresh flow, so users create named tokens instead. A token acts as its
+//! owner on every endpoint (read-only tokens only on GET requests). Tokens are
+//! recognised by their prefix; only a SHA-256 hash is stored.
+
+use chrono::Utc;
+use sha2::{Digest, Sha256};
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::ApiTokenRow;
+use shared::{ApiToken, CreateApiTokenRequest, CreatedApiToken};
+
+/// Prefix that tells API tokens apart from JWTs in the Authorization header
+pub const TOKEN_PREFIX: &str = "hh_";
+
+/// Maximum number of tokens per user
+const MAX_TOKENS_PER_USER: i64 = 20;
+
+#[derive(Debug, Error)]
+pub enum ApiTokenError {
+    #[error("API token not found")]
+    NotFound,
+    #[error("Token name must not be empty")]
+    EmptyName,
+    #[error("You cannot have more than {MAX_TOKENS_PER_USER} API tokens")]
+    TooManyTokens,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+/// The user a valid token acts for, as attached to the request by the middleware
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ApiTokenAuth {
+    pub token_id: Uuid,
+    pub user_id: Uuid,
+    pub read_only: bool,
+}
+
+pub fn is_api_token(token: &str) -> bool {
+    token.starts_with(TOKEN_PREFIX)
+}
+
+fn generate_token() -> String {
+    format!("{}{}{}", TOKEN_PREFIX, Uuid::new_v4().simple(), Uuid::new_v4().simple())
+}
+
+fn hash_token(token: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(token.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Create a token; the returned secret is not stored and can't be retrieved again
+pub async fn create_token(
+    pool: &SqlitePool,
+    user_id: &Uuid,
+    request: &CreateApiTokenRequest,
+) -> Result<CreatedApiToken, ApiTokenError> {
+    let name = request.name.trim();
+    if name.is_empty() {
+        return Err(ApiTokenError::EmptyName);
+    }
+
+    let existing: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM api_tokens WHERE user_id = ?")
+        .bind(user_id.to_string())
+        .fetch_one(pool)
+        .await?;
+    if existing >= MAX_TOKENS_PER_USER {
+        return Err(ApiTokenError::TooManyTokens);
+    }
+
+    let secret = generate_token();
+    let row = ApiTokenRow {
+        id: Uuid::new_v4().to_string(),
+        user_id: user_id.to_string(),
+        name: name.to_string(),
+        token_hash: hash_token(&secret),
+        read_only: request.read_only,
+        created_at: Utc::now(),
+        last_used_at: None,
+    };
+
+    sqlx::query(
+        r#"
+        INSERT INTO api_tokens (id, user_id, name, token_hash, read_only, created_at)
+        VALUES (?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(&row.id)
+    .bind(&row.user_id)
+    .bind(&row.name)
+    .bind(&row.token_hash)
+    .bind(row.read_only)
+    .bind(row.created_at)
+    .execute(pool)
+    .await?;
+
+    Ok(CreatedApiToken {
+        token: row.to_shared(),
+        secret,
+    })
+}
+
+pub async fn list_tokens(pool: &SqlitePool, user_id: &Uuid) -> Result<Vec<ApiToken>, ApiTokenError> {
+    let rows: Vec<ApiTokenRow> = sqlx::query_as("SELECT * FROM api_tokens WHERE user_id = ? ORDER BY created_at DESC")
+        .bind(user_id.to_string())
+        .fetch_all(pool)
+        .await?;
+    Ok(rows.iter().map(|row| row.to_shared()).collect())
+}
+
+/// Revoke one of the user's tokens
+pub async fn revoke_token(pool: &SqlitePool, user_id: &Uuid, token_id: &Uuid) -> Result<(), ApiTokenError> {
+    let result = sqlx::query("DELETE FROM api_tokens WHERE id = ? AND user_id = ?")
+        .bind(token_id.to_string())
+        .bind(user_id.to_string())
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiTokenError::NotFound);
+    }
+    Ok(())
+}
+
+/// Look up a presented token and record its use. Returns None for unknown or revoked tokens
+/// and for tokens of disabled accounts.
+pub async fn authenticate(pool: &SqlitePool, token: &str) -> Result<Option<ApiTokenAuth>, ApiTokenError> {
+    let row: Option<ApiTokenRow> = sqlx::query_as(
+        r#"
+        SELECT t.* FROM api_tokens t
+        JOIN users u ON u.id = t.user_id
+        WHERE t.token_hash = ? AND u.disabled_at IS NULL
+        "#,
+    )
+    .bind(hash_token(token))
+    .fetch_optional(pool)
+    .await?;
+
+    let Some(row) = row else {
+        return Ok(None);
+    };
+
+    sqlx::query("UPDATE api_tokens SET last_used_at = ? WHERE id = ?")
+        .bind(Utc::now())
+        .bind(&row.id)
+        .execute(pool)
+        .await?;
+
+    Ok(Some(ApiTokenAuth {
+        token_id: Uuid::parse_str(&row.id).unwrap(),
+        user_id: Uuid::parse_str(&row.user_id).unwrap(),
+        read_only: row.read_only,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{create_test_pool, create_test_user};
+    use shared::Role;
+
+    #[tokio::test]
+    async fn test_token_lifecycle() {
+        let pool = create_test_pool().await;
+        let user_id = create_test_user(&pool, "kiosk@test.com", Role::Member).await;
+
+        let request = CreateApiTokenRequest { name: "  Hallway tablet ".to_string(), read_only: true };
+        let created = create_token(&pool, &user_id, &request).await.unwrap();
+        assert!(is_api_token(&created.secret));
+        assert_eq!(created.token.name, "Hallway tablet");
+
+        let auth = authenticate(&pool, &created.secret).await.unwrap().unwrap();
+        assert_eq!(auth.user_id, user_id);
+        assert!(auth.read_only);
+        assert!(list_tokens(&pool, &user_id).await.unwrap()[0].last_used_at.is_some());
+
+        assert!(authenticate(&pool, "hh_unknown").await.unwrap().is_none());
+
+        revoke_token(&pool, &user_id, &created.token.id).await.unwrap();
+        assert!(authenticate(&pool, &created.secret).await.unwrap().is_none());
+        assert!(matches!(
+            revoke_token(&pool, &user_id, &created.token.id).await,
+            Err(ApiTokenError::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_empty_name_rejected() {
+        let pool = create_test_pool().await;
+        let user_id = create_test_user(&pool, "kiosk@test.com", Role::Member).await;
+
+        let request = CreateApiTokenRequest { name: " ".to_string(), read_only: false };
+        assert!(matches!(create_token(&pool, &user_id, &request).await, Err(ApiTokenError::EmptyName)));
+    }
+}
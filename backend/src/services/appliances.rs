@@ -0,0 +1,390 @@
+//! Appliance registry. Maintenance reminders are ordinary every-N-days tasks,
+//! so they show up on the dashboard and count toward statistics like any chore.
+
+use chrono::Utc;
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::ApplianceRow;
+use crate::services::tasks as task_service;
+use shared::{
+    Appliance, ApplianceMaintenance, CreateApplianceRequest, CreateMaintenanceRequest, CreateTaskRequest,
+    RecurrenceType, RecurrenceValue, UpdateApplianceRequest,
+};
+
+#[derive(Debug, Error)]
+pub enum ApplianceError {
+    #[error("Appliance not found")]
+    NotFound,
+    #[error("Maintenance task not found")]
+    MaintenanceNotFound,
+    #[error("The assigned user is not a member of this household")]
+    InvalidAssignee,
+    #[error("Task error: {0}")]
+    TaskError(#[from] task_service::TaskError),
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+fn clean_text(value: &Option<String>) -> Option<String> {
+    value
+        .as_ref()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+async fn get_row(pool: &SqlitePool, household_id: &Uuid, appliance_id: &Uuid) -> Result<ApplianceRow, ApplianceError> {
+    sqlx::query_as("SELECT * FROM appliances WHERE id = ? AND household_id = ?")
+        .bind(appliance_id.to_string())
+        .bind(household_id.to_string())
+        .fetch_optional(pool)
+        .await?
+        .ok_or(ApplianceError::NotFound)
+}
+
+/// Maintenance tasks of an appliance; tasks in the trash are left out
+async fn list_maintenance(pool: &SqlitePool, appliance_id: &str) -> Result<Vec<ApplianceMaintenance>, ApplianceError> {
+    let rows: Vec<(String, String, i32)> = sqlx::query_as(
+        r#"
+        SELECT t.id, t.title, m.interval_days
+        FROM appliance_maintenance m
+        JOIN tasks t ON m.task_id = t.id
+        WHERE m.appliance_id = ? AND t.deleted_at IS NULL
+        ORDER BY m.interval_days ASC, t.title COLLATE NOCASE ASC
+        "#,
+    )
+    .bind(appliance_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(task_id, title, interval_days)| ApplianceMaintenance {
+            task_id: Uuid::parse_str(&task_id).unwrap(),
+            title,
+            interval_days,
+        })
+        .collect())
+}
+
+async fn with_maintenance(pool: &SqlitePool, row: &ApplianceRow) -> Result<Appliance, ApplianceError> {
+    let mut appliance = row.to_shared();
+    appliance.maintenance = list_maintenance(pool, &row.id).await?;
+    Ok(appliance)
+}
+
+pub async fn list_appliances(pool: &SqlitePool, household_id: &Uuid) -> Result<Vec<Appliance>, ApplianceError> {
+    let rows: Vec<ApplianceRow> =
+        sqlx::query_as("SELECT * FROM appliances WHERE household_id = ? ORDER BY name COLLATE NOCASE ASC")
+            .bind(household_id.to_string())
+            .fetch_all(pool)
+            .await?;
+
+    let mut appliances = Vec::with_capacity(rows.len());
+    for row in &rows {
+        appliances.push(with_maintenance(pool, row).await?);
+    }
+    Ok(appliances)
+}
+
+pub async fn get_appliance(pool: &SqlitePool, household_id: &Uuid, appliance_id: &Uuid) -> Result<Appliance, ApplianceError> {
+    let row = get_row(pool, household_id, appliance_id).await?;
+    with_maintenance(pool, &row).await
+}
+
+pub async fn create_appliance(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    request: &CreateApplianceRequest,
+) -> Result<Appliance, ApplianceError> {
+    let now = Utc::now();
+    let row = ApplianceRow {
+        id: Uuid::new_v4().to_string(),
+        household_id: household_id.to_string(),
+        name: request.name.trim().to_string(),
+        location: clean_text(&request.location),
+        purchased_on: request.purchased_on,
+        warranty_until: request.warranty_until,
+        manual_url: clean_text(&request.manual_url),
+        notes: clean_text(&request.notes),
+        created_by: user_id.to_string(),
+        created_at: now,
+        updated_at: now,
+    };
+
+    sqlx::query(
+        r#"
+        INSERT INTO appliances (id, household_id, name, location, purchased_on, warranty_until, manual_url, notes, created_by, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(&row.id)
+    .bind(&row.household_id)
+    .bind(&row.name)
+    .bind(&row.location)
+    .bind(row.purchased_on)
+    .bind(row.warranty_until)
+    .bind(&row.manual_url)
+    .bind(&row.notes)
+    .bind(&row.created_by)
+    .bind(now)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    Ok(row.to_shared())
+}
+
+pub async fn update_appliance(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    appliance_id: &Uuid,
+    request: &UpdateApplianceRequest,
+) -> Result<Appliance, ApplianceError> {
+    let mut row = get_row(pool, household_id, appliance_id).await?;
+
+    if let Some(ref name) = request.name {
+        row.name = name.trim().to_string();
+    }
+    if request.location.is_some() {
+        row.location = clean_text(&request.location);
+    }
+    if let Some(purchased_on) = request.purchased_on {
+        row.purchased_on = purchased_on;
+    }
+    if let Some(warranty_until) = request.warranty_until {
+        row.warranty_until = warranty_until;
+    }
+    if request.manual_url.is_some() {
+        row.manual_url = clean_text(&request.manual_url);
+    }
+    if request.notes.is_some() {
+        row.notes = clean_text(&request.notes);
+    }
+    row.updated_at = Utc::now();
+
+    sqlx::query(
+        r#"
+        UPDATE appliances
+        SET name = ?, location = ?, purchased_on = ?, warranty_until = ?, manual_url = ?, notes = ?, updated_at = ?
+        WHERE id = ?
+        "#,
+    )
+    .bind(&row.name)
+    .bind(&row.location)
+    .bind(row.purchased_on)
+    .bind(row.warranty_until)
+    .bind(&row.manual_url)
+    .bind(&row.notes)
+    .bind(row.updated_at)
+    .bind(&row.id)
+    .execute(pool)
+    .await?;
+
+    with_maintenance(pool, &row).await
+}
+
+/// Delete an appliance and move its maintenance tasks to the trash
+pub async fn delete_appliance(pool: &SqlitePool, household_id: &Uuid, appliance_id: &Uuid) -> Result<(), ApplianceError> {
+    let row = get_row(pool, household_id, appliance_id).await?;
+
+    for maintenance in list_maintenance(pool, &row.id).await? {
+        task_service::delete_task(pool, &maintenance.task_id).await?;
+    }
+
+    sqlx::query("DELETE FROM appliances WHERE id = ?")
+        .bind(&row.id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Generate a recurring task for the appliance, due every `interval_days` days from today
+pub async fn add_maintenance(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    appliance_id: &Uuid,
+    request: &CreateMaintenanceRequest,
+) -> Result<Appliance, ApplianceError> {
+    let row = get_row(pool, household_id, appliance_id).await?;
+
+    if let Some(user_id) = request.assigned_user_id {
+        let is_member: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM household_memberships WHERE household_id = ? AND user_id = ?)",
+        )
+        .bind(household_id.to_string())
+        .bind(user_id.to_string())
+        .fetch_one(pool)
+        .await?;
+        if !is_member {
+            return Err(ApplianceError::InvalidAssignee);
+        }
+    }
+
+    let task_request = CreateTaskRequest {
+        title: format!("{}: {}", row.name, request.title.trim()),
+        description: Some(format!("Maintenance for {}", row.name)),
+        recurrence_type: RecurrenceType::Daily,
+        recurrence_value: Some(RecurrenceValue::DayInterval(request.interval_days)),
+        assigned_user_id: request.assigned_user_id,
+        assignee_ids: None,
+        target_count: None,
+        time_period: None,
+        allow_exceed_target: None,
+        requires_review: None,
+        reviewer_type: None,
+        reviewer_id: None,
+        points_reward: None,
+        points_penalty: None,
+        due_time: None,
+        effort_minutes: None,
+        active_from: None,
+        active_until: None,
+        ends_on: None,
+        max_occurrences: None,
+        habit_type: None,
+        category_id: None,
+        tag_ids: None,
+        is_suggestion: None,
+    };
+    let task = task_service::create_task(pool, household_id, &task_request, None).await?;
+
+    sqlx::query("INSERT INTO appliance_maintenance (appliance_id, task_id, interval_days) VALUES (?, ?, ?)")
+        .bind(&row.id)
+        .bind(task.id.to_string())
+        .bind(i32::from(request.interval_days))
+        .execute(pool)
+        .await?;
+
+    with_maintenance(pool, &row).await
+}
+
+/// Stop a maintenance reminder; its task goes to the trash
+pub async fn remove_maintenance(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    appliance_id: &Uuid,
+    task_id: &Uuid,
+) -> Result<Appliance, ApplianceError> {
+    let row = get_row(pool, household_id, appliance_id).await?;
+
+    let result = sqlx::query("DELETE FROM appliance_maintenance WHERE appliance_id = ? AND task_id = ?")
+        .bind(&row.id)
+        .bind(task_id.to_string())
+        .execute(pool)
+        .await?;
+    if result.rows_affected() == 0 {
+        return Err(ApplianceError::MaintenanceNotFound);
+    }
+
+    match task_service::delete_task(pool, task_id).await {
+        Ok(()) | Err(task_service::TaskError::NotFound) => {}
+        Err(e) => return Err(e.into()),
+    }
+
+    with_maintenance(pool, &row).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use chrono::NaiveDate;
+    use shared::Role;
+
+    fn coffee_machine() -> CreateApplianceRequest {
+        CreateApplianceRequest {
+            name: "Coffee machine".to_string(),
+            location: Some("Kitchen".to_string()),
+            purchased_on: NaiveDate::from_ymd_opt(2024, 3, 1),
+            warranty_until: NaiveDate::from_ymd_opt(2026, 3, 1),
+            manual_url: Some(" ".to_string()),
+            notes: None,
+        }
+    }
+
+    fn descale(assigned_user_id: Option<Uuid>) -> CreateMaintenanceRequest {
+        CreateMaintenanceRequest {
+            title: "Descale".to_string(),
+            interval_days: 90,
+            assigned_user_id,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_maintenance_creates_recurring_task() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user = test_utils::create_test_user(&pool, "fixer@test.com", Role::Member).await;
+
+        let appliance = create_appliance(&pool, &household_id, &user, &coffee_machine()).await.unwrap();
+        assert_eq!(appliance.manual_url, None);
+        assert!(appliance.under_warranty(NaiveDate::from_ymd_opt(2025, 6, 1).unwrap()));
+        assert!(!appliance.under_warranty(NaiveDate::from_ymd_opt(2026, 3, 2).unwrap()));
+
+        let with_task = add_maintenance(&pool, &household_id, &appliance.id, &descale(None)).await.unwrap();
+        assert_eq!(with_task.maintenance.len(), 1);
+        let maintenance = &with_task.maintenance[0];
+        assert_eq!(maintenance.title, "Coffee machine: Descale");
+        assert_eq!(maintenance.interval_days, 90);
+
+        let task = task_service::get_task(&pool, &maintenance.task_id).await.unwrap().unwrap();
+        assert_eq!(task.recurrence_type, RecurrenceType::Daily);
+        assert_eq!(task.recurrence_value, Some(RecurrenceValue::DayInterval(90)));
+        assert_eq!(task.household_id, household_id);
+
+        // Only household members can be assigned
+        assert!(matches!(
+            add_maintenance(&pool, &household_id, &appliance.id, &descale(Some(Uuid::new_v4()))).await,
+            Err(ApplianceError::InvalidAssignee)
+        ));
+
+        let without = remove_maintenance(&pool, &household_id, &appliance.id, &maintenance.task_id).await.unwrap();
+        assert!(without.maintenance.is_empty());
+        assert!(task_service::get_task(&pool, &maintenance.task_id).await.unwrap().is_none());
+        assert!(matches!(
+            remove_maintenance(&pool, &household_id, &appliance.id, &maintenance.task_id).await,
+            Err(ApplianceError::MaintenanceNotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update_and_delete_appliance() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user = test_utils::create_test_user(&pool, "fixer@test.com", Role::Member).await;
+
+        let appliance = create_appliance(&pool, &household_id, &user, &coffee_machine()).await.unwrap();
+        let task_id = add_maintenance(&pool, &household_id, &appliance.id, &descale(None)).await.unwrap().maintenance[0].task_id;
+
+        let updated = update_appliance(
+            &pool,
+            &household_id,
+            &appliance.id,
+            &UpdateApplianceRequest {
+                warranty_until: Some(None),
+                notes: Some("Serial 1234".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(updated.warranty_until, None);
+        assert_eq!(updated.purchased_on, appliance.purchased_on);
+        assert_eq!(updated.notes.as_deref(), Some("Serial 1234"));
+        assert_eq!(updated.maintenance.len(), 1);
+
+        // Appliances of other households are not found
+        assert!(matches!(
+            get_appliance(&pool, &Uuid::new_v4(), &appliance.id).await,
+            Err(ApplianceError::NotFound)
+        ));
+
+        delete_appliance(&pool, &household_id, &appliance.id).await.unwrap();
+        assert!(list_appliances(&pool, &household_id).await.unwrap().is_empty());
+        assert!(task_service::get_task(&pool, &task_id).await.unwrap().is_none());
+    }
+}
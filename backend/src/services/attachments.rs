@@ -0,0 +1,685 @@
+use chrono::Utc;
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::{AttachmentRow, CompletionAttachmentRow};
+use shared::{Attachment, AttachmentEntity, CompletionAttachment};
+
+/// Photos a member may attach to a single completion
+pub const MAX_ATTACHMENTS_PER_COMPLETION: i64 = 3;
+
+/// Files a single note or journal entry may carry
+pub const MAX_ATTACHMENTS_PER_ENTITY: i64 = 10;
+
+/// Name of the multipart form field carrying the photo
+const FILE_FIELD: &str = "file";
+
+/// Longest edge of generated image thumbnails, in pixels
+pub const THUMBNAIL_SIZE: u32 = 320;
+
+/// Thumbnails are always stored as JPEG
+pub const THUMBNAIL_CONTENT_TYPE: &str = "image/jpeg";
+
+#[derive(Debug, Error)]
+pub enum AttachmentError {
+    #[error("Attachment not found")]
+    NotFound,
+    #[error("Invalid upload: {0}")]
+    InvalidUpload(&'static str),
+    #[error("Only JPEG, PNG, GIF and WebP images are supported")]
+    UnsupportedType,
+    #[error("Only images, PDF and plain text files are supported")]
+    UnsupportedDocumentType,
+    #[error("At most {0} attachments are allowed")]
+    TooMany(i64),
+    #[error("File error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+fn find(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    if from > haystack.len() {
+        return None;
+    }
+    haystack[from..]
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|pos| pos + from)
+}
+
+/// Extract the boundary from a `multipart/form-data; boundary=...` content type
+fn multipart_boundary(content_type: &str) -> Option<&str> {
+    let mut parts = content_type.split(';');
+    if !parts.next()?.trim().eq_ignore_ascii_case("multipart/form-data") {
+        return None;
+    }
+    parts
+        .filter_map(|param| param.trim().split_once('='))
+        .find(|(name, _)| name.eq_ignore_ascii_case("boundary"))
+        .map(|(_, value)| value.trim_matches('"'))
+        .filter(|boundary| !boundary.is_empty())
+}
+
+/// The `file` field of a multipart upload
+#[derive(Debug)]
+pub struct UploadedFile<'a> {
+    /// File name as sent by the client, if any
+    pub file_name: Option<String>,
+    pub data: &'a [u8],
+}
+
+/// Read the `filename="..."` parameter of a Content-Disposition header line
+fn disposition_file_name(line: &str) -> Option<String> {
+    let start = line.to_ascii_lowercase().find("filename=\"")? + "filename=\"".len();
+    let rest = &line[start..];
+    Some(rest[..rest.find('"')?].to_string())
+}
+
+/// Return the `file` field of a `multipart/form-data` body.
+/// Other fields are ignored.
+pub fn parse_multipart<'a>(content_type: &str, body: &'a [u8]) -> Result<UploadedFile<'a>, AttachmentError> {
+    let boundary = multipart_boundary(content_type).ok_or(AttachmentError::InvalidUpload("expected multipart/form-data"))?;
+    let delimiter = format!("--{}", boundary).into_bytes();
+    let mut part_delimiter = b"\r\n".to_vec();
+    part_delimiter.extend_from_slice(&delimiter);
+
+    let mut pos = find(body, &delimiter, 0).ok_or(AttachmentError::InvalidUpload("missing boundary"))? + delimiter.len();
+    loop {
+        // The closing delimiter is followed by "--"
+        if body[pos..].starts_with(b"--") {
+            return Err(AttachmentError::InvalidUpload("no file field"));
+        }
+        let headers_end = find(body, b"\r\n\r\n", pos).ok_or(AttachmentError::InvalidUpload("malformed part"))?;
+        let headers = String::from_utf8_lossy(&body[pos..headers_end]);
+        let data_start = headers_end + 4;
+        let data_end = find(body, &part_delimiter, data_start).ok_or(AttachmentError::InvalidUpload("unterminated part"))?;
+
+        let file_field = headers.lines().find(|line| {
+            let line = line.to_ascii_lowercase();
+            line.starts_with("content-disposition:") && line.contains(&format!(" name=\"{}\"", FILE_FIELD))
+        });
+        if let Some(disposition) = file_field {
+            return Ok(UploadedFile {
+                file_name: disposition_file_name(disposition),
+                data: &body[data_start..data_end],
+            });
+        }
+        pos = data_end + part_delimiter.len();
+    }
+}
+
+/// Detect the image type from the file's magic bytes; the client's content type is not trusted
+pub fn sniff_image_type(data: &[u8]) -> Option<&'static str> {
+    if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("image/jpeg")
+    } else if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+        Some("image/gif")
+    } else if data.len() >= 12 && data.starts_with(b"RIFF") && &data[8..12] == b"WEBP" {
+        Some("image/webp")
+    } else {
+        None
+    }
+}
+
+/// Detect the type of a document upload: images, PDF, or UTF-8 plain text
+pub fn sniff_document_type(data: &[u8]) -> Option<&'static str> {
+    if let Some(image) = sniff_image_type(data) {
+        Some(image)
+    } else if data.starts_with(b"%PDF-") {
+        Some("application/pdf")
+    } else if !data.is_empty() && !data.contains(&0) && std::str::from_utf8(data).is_ok() {
+        Some("text/plain")
+    } else {
+        None
+    }
+}
+
+/// Strip any path and characters that would break a Content-Disposition header
+pub fn sanitize_file_name(name: Option<&str>) -> String {
+    let name = name.unwrap_or_default();
+    let base = name.rsplit(['/', '\\']).next().unwrap_or_default();
+    let cleaned: String = base
+        .chars()
+        .filter(|c| !c.is_control() && *c != '"')
+        .take(200)
+        .collect();
+    let cleaned = cleaned.trim();
+    if cleaned.is_empty() || cleaned == "." || cleaned == ".." {
+        "attachment".to_string()
+    } else {
+        cleaned.to_string()
+    }
+}
+
+fn attachment_path(upload_dir: &str, attachment_id: &Uuid) -> PathBuf {
+    Path::new(upload_dir).join("completions").join(attachment_id.to_string())
+}
+
+/// Store a photo for a completion on disk and record it
+pub async fn create_attachment(
+    pool: &SqlitePool,
+    upload_dir: &str,
+    household_id: &Uuid,
+    completion_id: &Uuid,
+    user_id: &Uuid,
+    data: &[u8],
+) -> Result<CompletionAttachment, AttachmentError> {
+    let content_type = sniff_image_type(data).ok_or(AttachmentError::UnsupportedType)?;
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM completion_attachments WHERE completion_id = ?")
+        .bind(completion_id.to_string())
+        .fetch_one(pool)
+        .await?;
+    if count >= MAX_ATTACHMENTS_PER_COMPLETION {
+        return Err(AttachmentError::TooMany(MAX_ATTACHMENTS_PER_COMPLETION));
+    }
+
+    let id = Uuid::new_v4();
+    let now = Utc::now();
+    let path = attachment_path(upload_dir, &id);
+    if let Some(dir) = path.parent() {
+        tokio::fs::create_dir_all(dir).await?;
+    }
+    tokio::fs::write(&path, data).await?;
+
+    let result = sqlx::query(
+        r#"
+        INSERT INTO completion_attachments (id, completion_id, household_id, uploaded_by, content_type, size_bytes, created_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(id.to_string())
+    .bind(completion_id.to_string())
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .bind(content_type)
+    .bind(data.len() as i64)
+    .bind(now)
+    .execute(pool)
+    .await;
+
+    if let Err(e) = result {
+        let _ = tokio::fs::remove_file(&path).await;
+        return Err(e.into());
+    }
+
+    Ok(CompletionAttachment {
+        id,
+        completion_id: *completion_id,
+        uploaded_by: *user_id,
+        content_type: content_type.to_string(),
+        size_bytes: data.len() as i64,
+        created_at: now,
+    })
+}
+
+pub async fn list_for_completion(
+    pool: &SqlitePool,
+    completion_id: &Uuid,
+) -> Result<Vec<CompletionAttachment>, AttachmentError> {
+    let rows: Vec<CompletionAttachmentRow> =
+        sqlx::query_as("SELECT * FROM completion_attachments WHERE completion_id = ? ORDER BY created_at ASC")
+            .bind(completion_id.to_string())
+            .fetch_all(pool)
+            .await?;
+
+    Ok(rows.iter().map(|r| r.to_shared()).collect())
+}
+
+/// Load an attachment's metadata and file contents, scoped to the household
+pub async fn read_attachment(
+    pool: &SqlitePool,
+    upload_dir: &str,
+    household_id: &Uuid,
+    attachment_id: &Uuid,
+) -> Result<(CompletionAttachment, Vec<u8>), AttachmentError> {
+    let row: Option<CompletionAttachmentRow> =
+        sqlx::query_as("SELECT * FROM completion_attachments WHERE id = ? AND household_id = ?")
+            .bind(attachment_id.to_string())
+            .bind(household_id.to_string())
+            .fetch_optional(pool)
+            .await?;
+    let attachment = row.ok_or(AttachmentError::NotFound)?.to_shared();
+
+    let data = match tokio::fs::read(attachment_path(upload_dir, attachment_id)).await {
+        Ok(data) => data,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Err(AttachmentError::NotFound),
+        Err(e) => return Err(e.into()),
+    };
+    Ok((attachment, data))
+}
+
+/// Delete a completion's photos from disk and database, e.g. before the completion is removed
+pub async fn delete_for_completion(
+    pool: &SqlitePool,
+    upload_dir: &str,
+    completion_id: &Uuid,
+) -> Result<(), AttachmentError> {
+    for attachment in list_for_completion(pool, completion_id).await? {
+        if let Err(e) = tokio::fs::remove_file(attachment_path(upload_dir, &attachment.id)).await {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                log::warn!("Failed to delete attachment file {}: {}", attachment.id, e);
+            }
+        }
+    }
+
+    sqlx::query("DELETE FROM completion_attachments WHERE completion_id = ?")
+        .bind(completion_id.to_string())
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+// ============================================================================
+// Note, journal entry and chat message attachments
+// ============================================================================
+
+fn document_path(upload_dir: &str, attachment_id: &Uuid) -> PathBuf {
+    Path::new(upload_dir).join("attachments").join(attachment_id.to_string())
+}
+
+fn thumbnail_path(upload_dir: &str, attachment_id: &Uuid) -> PathBuf {
+    Path::new(upload_dir).join("thumbnails").join(attachment_id.to_string())
+}
+
+/// Scale an image down to fit `THUMBNAIL_SIZE` and encode it as JPEG.
+/// Transparent areas become white. Returns `None` if the image cannot be decoded.
+pub fn make_thumbnail(data: &[u8]) -> Option<Vec<u8>> {
+    let image = image::load_from_memory(data).ok()?.thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE);
+    let mut rgb = image::RgbImage::new(image.width(), image.height());
+    for (target, source) in rgb.pixels_mut().zip(image.to_rgba8().pixels()) {
+        let alpha = source[3] as u16;
+        for channel in 0..3 {
+            target[channel] = ((source[channel] as u16 * alpha + 255 * (255 - alpha)) / 255) as u8;
+        }
+    }
+
+    let mut encoded = std::io::Cursor::new(Vec::new());
+    rgb.write_to(&mut encoded, image::ImageFormat::Jpeg).ok()?;
+    Some(encoded.into_inner())
+}
+
+/// Generate and store the thumbnail of an image; failures only cost the preview
+async fn store_thumbnail(upload_dir: &str, attachment_id: &Uuid, data: Vec<u8>) -> Option<Vec<u8>> {
+    let thumbnail = tokio::task::spawn_blocking(move || make_thumbnail(&data)).await.ok().flatten()?;
+    let path = thumbnail_path(upload_dir, attachment_id);
+    let stored = match path.parent() {
+        Some(dir) => tokio::fs::create_dir_all(dir).await.and(tokio::fs::write(&path, &thumbnail).await),
+        None => tokio::fs::write(&path, &thumbnail).await,
+    };
+    if let Err(e) = stored {
+        log::warn!("Failed to store thumbnail {}: {}", attachment_id, e);
+    }
+    Some(thumbnail)
+}
+
+/// Store a file for a note, journal entry or chat message on disk and record it.
+/// Chat messages only take images.
+pub async fn create_entity_attachment(
+    pool: &SqlitePool,
+    upload_dir: &str,
+    household_id: &Uuid,
+    entity_type: AttachmentEntity,
+    entity_id: &Uuid,
+    user_id: &Uuid,
+    file: &UploadedFile<'_>,
+) -> Result<Attachment, AttachmentError> {
+    let content_type = match entity_type {
+        AttachmentEntity::ChatMessage | AttachmentEntity::Plant => sniff_image_type(file.data).ok_or(AttachmentError::UnsupportedType)?,
+        _ => sniff_document_type(file.data).ok_or(AttachmentError::UnsupportedDocumentType)?,
+    };
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM attachments WHERE entity_type = ? AND entity_id = ?")
+        .bind(entity_type.as_str())
+        .bind(entity_id.to_string())
+        .fetch_one(pool)
+        .await?;
+    if count >= MAX_ATTACHMENTS_PER_ENTITY {
+        return Err(AttachmentError::TooMany(MAX_ATTACHMENTS_PER_ENTITY));
+    }
+
+    let id = Uuid::new_v4();
+    let now = Utc::now();
+    let file_name = sanitize_file_name(file.file_name.as_deref());
+    let path = document_path(upload_dir, &id);
+    if let Some(dir) = path.parent() {
+        tokio::fs::create_dir_all(dir).await?;
+    }
+    tokio::fs::write(&path, file.data).await?;
+
+    let result = sqlx::query(
+        r#"
+        INSERT INTO attachments (id, household_id, entity_type, entity_id, uploaded_by, file_name, content_type, size_bytes, created_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(id.to_string())
+    .bind(household_id.to_string())
+    .bind(entity_type.as_str())
+    .bind(entity_id.to_string())
+    .bind(user_id.to_string())
+    .bind(&file_name)
+    .bind(content_type)
+    .bind(file.data.len() as i64)
+    .bind(now)
+    .execute(pool)
+    .await;
+
+    if let Err(e) = result {
+        let _ = tokio::fs::remove_file(&path).await;
+        return Err(e.into());
+    }
+
+    if content_type.starts_with("image/") {
+        store_thumbnail(upload_dir, &id, file.data.to_vec()).await;
+    }
+
+    Ok(Attachment {
+        id,
+        household_id: *household_id,
+        entity_type,
+        entity_id: *entity_id,
+        uploaded_by: *user_id,
+        file_name,
+        content_type: content_type.to_string(),
+        size_bytes: file.data.len() as i64,
+        created_at: now,
+    })
+}
+
+pub async fn list_for_entity(
+    pool: &SqlitePool,
+    entity_type: AttachmentEntity,
+    entity_id: &Uuid,
+) -> Result<Vec<Attachment>, AttachmentError> {
+    let rows: Vec<AttachmentRow> =
+        sqlx::query_as("SELECT * FROM attachments WHERE entity_type = ? AND entity_id = ? ORDER BY created_at ASC")
+            .bind(entity_type.as_str())
+            .bind(entity_id.to_string())
+            .fetch_all(pool)
+            .await?;
+
+    Ok(rows.iter().map(|r| r.to_shared()).collect())
+}
+
+/// All attachments of one entity type in a household, grouped by entity, for list views
+pub async fn list_for_household(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    entity_type: AttachmentEntity,
+) -> Result<HashMap<Uuid, Vec<Attachment>>, AttachmentError> {
+    let rows: Vec<AttachmentRow> =
+        sqlx::query_as("SELECT * FROM attachments WHERE household_id = ? AND entity_type = ? ORDER BY created_at ASC")
+            .bind(household_id.to_string())
+            .bind(entity_type.as_str())
+            .fetch_all(pool)
+            .await?;
+
+    let mut grouped: HashMap<Uuid, Vec<Attachment>> = HashMap::new();
+    for row in rows {
+        let attachment = row.to_shared();
+        grouped.entry(attachment.entity_id).or_default().push(attachment);
+    }
+    Ok(grouped)
+}
+
+pub async fn get_entity_attachment(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    attachment_id: &Uuid,
+) -> Result<Option<Attachment>, AttachmentError> {
+    let row: Option<AttachmentRow> = sqlx::query_as("SELECT * FROM attachments WHERE id = ? AND household_id = ?")
+        .bind(attachment_id.to_string())
+        .bind(household_id.to_string())
+        .fetch_optional(pool)
+        .await?;
+
+    Ok(row.map(|r| r.to_shared()))
+}
+
+pub async fn read_entity_attachment(upload_dir: &str, attachment_id: &Uuid) -> Result<Vec<u8>, AttachmentError> {
+    match tokio::fs::read(document_path(upload_dir, attachment_id)).await {
+        Ok(data) => Ok(data),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(AttachmentError::NotFound),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Thumbnail of an image attachment. Images stored before thumbnails existed
+/// get theirs generated on first request.
+pub async fn read_thumbnail(upload_dir: &str, attachment: &Attachment) -> Result<Vec<u8>, AttachmentError> {
+    if !attachment.is_image() {
+        return Err(AttachmentError::NotFound);
+    }
+    match tokio::fs::read(thumbnail_path(upload_dir, &attachment.id)).await {
+        Ok(data) => return Ok(data),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e.into()),
+    }
+
+    let original = read_entity_attachment(upload_dir, &attachment.id).await?;
+    store_thumbnail(upload_dir, &attachment.id, original)
+        .await
+        .ok_or(AttachmentError::NotFound)
+}
+
+async fn remove_document(upload_dir: &str, attachment_id: &Uuid) {
+    for path in [document_path(upload_dir, attachment_id), thumbnail_path(upload_dir, attachment_id)] {
+        if let Err(e) = tokio::fs::remove_file(path).await {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                log::warn!("Failed to delete attachment file {}: {}", attachment_id, e);
+            }
+        }
+    }
+}
+
+pub async fn delete_entity_attachment(
+    pool: &SqlitePool,
+    upload_dir: &str,
+    attachment_id: &Uuid,
+) -> Result<(), AttachmentError> {
+    let result = sqlx::query("DELETE FROM attachments WHERE id = ?")
+        .bind(attachment_id.to_string())
+        .execute(pool)
+        .await?;
+    if result.rows_affected() == 0 {
+        return Err(AttachmentError::NotFound);
+    }
+
+    remove_document(upload_dir, attachment_id).await;
+    Ok(())
+}
+
+/// Delete all files of a note or journal entry, e.g. before the entry is removed
+pub async fn delete_for_entity(
+    pool: &SqlitePool,
+    upload_dir: &str,
+    entity_type: AttachmentEntity,
+    entity_id: &Uuid,
+) -> Result<(), AttachmentError> {
+    for attachment in list_for_entity(pool, entity_type, entity_id).await? {
+        remove_document(upload_dir, &attachment.id).await;
+    }
+
+    sqlx::query("DELETE FROM attachments WHERE entity_type = ? AND entity_id = ?")
+        .bind(entity_type.as_str())
+        .bind(entity_id.to_string())
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use shared::Role;
+
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\nrest-of-image";
+
+    fn multipart_body(boundary: &str, file: &[u8]) -> Vec<u8> {
+        let mut body = format!(
+            "--{b}\r\nContent-Disposition: form-data; name=\"note\"\r\n\r\nclean\r\n--{b}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"room.png\"\r\nContent-Type: image/png\r\n\r\n",
+            b = boundary
+        )
+        .into_bytes();
+        body.extend_from_slice(file);
+        body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+        body
+    }
+
+    #[test]
+    fn test_parse_multipart() {
+        let body = multipart_body("XyZ", PNG);
+        let file = parse_multipart("multipart/form-data; boundary=XyZ", &body).unwrap();
+        assert_eq!(file.data, PNG);
+        assert_eq!(file.file_name.as_deref(), Some("room.png"));
+        assert_eq!(parse_multipart("multipart/form-data; boundary=\"XyZ\"", &body).unwrap().data, PNG);
+
+        assert!(matches!(
+            parse_multipart("application/json", &body),
+            Err(AttachmentError::InvalidUpload(_))
+        ));
+        let no_file = b"--XyZ\r\nContent-Disposition: form-data; name=\"note\"\r\n\r\nhi\r\n--XyZ--\r\n";
+        assert!(parse_multipart("multipart/form-data; boundary=XyZ", no_file).is_err());
+    }
+
+    #[test]
+    fn test_sniff_image_type() {
+        assert_eq!(sniff_image_type(PNG), Some("image/png"));
+        assert_eq!(sniff_image_type(&[0xFF, 0xD8, 0xFF, 0xE0]), Some("image/jpeg"));
+        assert_eq!(sniff_image_type(b"RIFF\0\0\0\0WEBPVP8 "), Some("image/webp"));
+        assert_eq!(sniff_image_type(b"<html></html>"), None);
+    }
+
+    #[tokio::test]
+    async fn test_create_read_and_delete_attachment() {
+        let pool = test_utils::create_test_pool().await;
+        let upload_dir = std::env::temp_dir().join(format!("haushalt-test-{}", Uuid::new_v4()));
+        let upload_dir = upload_dir.to_str().unwrap();
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user_id = test_utils::create_test_user(&pool, "proof@test.com", Role::Member).await;
+        let task = test_utils::create_test_task(&pool, &household_id).build().await;
+        let completion_id = Uuid::new_v4();
+        sqlx::query("INSERT INTO task_completions (id, task_id, user_id, due_date, status) VALUES (?, ?, ?, '2024-01-01', 'pending')")
+            .bind(completion_id.to_string())
+            .bind(task.id.to_string())
+            .bind(user_id.to_string())
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let attachment = create_attachment(&pool, upload_dir, &household_id, &completion_id, &user_id, PNG)
+            .await
+            .unwrap();
+        assert_eq!(attachment.content_type, "image/png");
+
+        let (_, data) = read_attachment(&pool, upload_dir, &household_id, &attachment.id).await.unwrap();
+        assert_eq!(data, PNG);
+        assert!(matches!(
+            read_attachment(&pool, upload_dir, &Uuid::new_v4(), &attachment.id).await,
+            Err(AttachmentError::NotFound)
+        ));
+        assert!(matches!(
+            create_attachment(&pool, upload_dir, &household_id, &completion_id, &user_id, b"not an image").await,
+            Err(AttachmentError::UnsupportedType)
+        ));
+
+        delete_for_completion(&pool, upload_dir, &completion_id).await.unwrap();
+        assert!(list_for_completion(&pool, &completion_id).await.unwrap().is_empty());
+        assert!(!attachment_path(upload_dir, &attachment.id).exists());
+
+        let _ = std::fs::remove_dir_all(upload_dir);
+    }
+
+    #[test]
+    fn test_sniff_document_type_and_file_name() {
+        assert_eq!(sniff_document_type(b"%PDF-1.7\n"), Some("application/pdf"));
+        assert_eq!(sniff_document_type("Garantie bis 2027".as_bytes()), Some("text/plain"));
+        assert_eq!(sniff_document_type(&[0x4D, 0x5A, 0x00, 0x90]), None);
+
+        assert_eq!(sanitize_file_name(Some("C:\\Users\\me\\manual.pdf")), "manual.pdf");
+        assert_eq!(sanitize_file_name(Some("../\"evil\".txt")), "evil.txt");
+        assert_eq!(sanitize_file_name(None), "attachment");
+    }
+
+    #[tokio::test]
+    async fn test_entity_attachments() {
+        let pool = test_utils::create_test_pool().await;
+        let upload_dir = std::env::temp_dir().join(format!("haushalt-test-{}", Uuid::new_v4()));
+        let upload_dir = upload_dir.to_str().unwrap();
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user_id = test_utils::create_test_user(&pool, "docs@test.com", Role::Member).await;
+        let note_id = Uuid::new_v4();
+        let file = UploadedFile { file_name: Some("warranty.pdf".to_string()), data: b"%PDF-1.4 warranty" };
+
+        let attachment = create_entity_attachment(&pool, upload_dir, &household_id, AttachmentEntity::Note, &note_id, &user_id, &file)
+            .await
+            .unwrap();
+        assert_eq!(attachment.file_name, "warranty.pdf");
+        assert_eq!(attachment.content_type, "application/pdf");
+
+        let grouped = list_for_household(&pool, &household_id, AttachmentEntity::Note).await.unwrap();
+        assert_eq!(grouped[&note_id], vec![attachment.clone()]);
+        assert!(list_for_household(&pool, &household_id, AttachmentEntity::JournalEntry).await.unwrap().is_empty());
+        assert_eq!(read_entity_attachment(upload_dir, &attachment.id).await.unwrap(), file.data);
+
+        delete_for_entity(&pool, upload_dir, AttachmentEntity::Note, &note_id).await.unwrap();
+        assert!(get_entity_attachment(&pool, &household_id, &attachment.id).await.unwrap().is_none());
+        assert!(!document_path(upload_dir, &attachment.id).exists());
+
+        let _ = std::fs::remove_dir_all(upload_dir);
+    }
+    #[tokio::test]
+    async fn test_chat_image_attachment_gets_thumbnail() {
+        let pool = test_utils::create_test_pool().await;
+        let upload_dir = std::env::temp_dir().join(format!("haushalt-test-{}", Uuid::new_v4()));
+        let upload_dir = upload_dir.to_str().unwrap();
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user_id = test_utils::create_test_user(&pool, "photo@test.com", Role::Member).await;
+        let message_id = Uuid::new_v4();
+
+        let mut png = std::io::Cursor::new(Vec::new());
+        image::RgbaImage::from_pixel(800, 400, image::Rgba([0, 128, 255, 0]))
+            .write_to(&mut png, image::ImageFormat::Png)
+            .unwrap();
+        let png = png.into_inner();
+        let file = UploadedFile { file_name: Some("detergents.png".to_string()), data: &png };
+
+        let attachment =
+            create_entity_attachment(&pool, upload_dir, &household_id, AttachmentEntity::ChatMessage, &message_id, &user_id, &file)
+                .await
+                .unwrap();
+        assert!(attachment.is_image());
+        assert!(thumbnail_path(upload_dir, &attachment.id).exists());
+
+        let thumbnail = read_thumbnail(upload_dir, &attachment).await.unwrap();
+        assert_eq!(sniff_image_type(&thumbnail), Some(THUMBNAIL_CONTENT_TYPE));
+        let decoded = image::load_from_memory(&thumbnail).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (THUMBNAIL_SIZE, THUMBNAIL_SIZE / 2));
+        // Transparent pixels are flattened onto white
+        assert!(decoded.to_rgb8().get_pixel(0, 0).0.iter().all(|c| *c > 240));
+
+        // Generated again when missing
+        std::fs::remove_file(thumbnail_path(upload_dir, &attachment.id)).unwrap();
+        assert!(read_thumbnail(upload_dir, &attachment).await.is_ok());
+
+        // Chat messages only take images
+        let pdf = UploadedFile { file_name: Some("manual.pdf".to_string()), data: b"%PDF-1.4 manual" };
+        assert!(matches!(
+            create_entity_attachment(&pool, upload_dir, &household_id, AttachmentEntity::ChatMessage, &message_id, &user_id, &pdf).await,
+            Err(AttachmentError::UnsupportedType)
+        ));
+
+        delete_entity_attachment(&pool, upload_dir, &attachment.id).await.unwrap();
+        assert!(!thumbnail_path(upload_dir, &attachment.id).exists());
+
+        let _ = std::fs::remove_dir_all(upload_dir);
+    }
+}
@@ -0,0 +1,250 @@
+use chrono::Utc;
+use serde_json::{Map, Value};
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use shared::{AdminAuditEntry, AdminAuditEntryWithUsers, AdminAuditLogPage, AuditAction, HouseholdSettings, User};
+
+#[derive(Debug, Error)]
+pub enum AuditLogError {
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+/// Record a security-relevant action in the household's audit log
+pub async fn record(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    actor_id: &Uuid,
+    target_user_id: Option<&Uuid>,
+    action: AuditAction,
+    before_value: Option<&str>,
+    after_value: Option<&str>,
+) -> Result<AdminAuditEntry, AuditLogError> {
+    let id = Uuid::new_v4();
+    let now = Utc::now();
+
+    sqlx::query(
+        r#"
+        INSERT INTO admin_audit_log (id, household_id, actor_id, target_user_id, action, before_value, after_value, created_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(id.to_string())
+    .bind(household_id.to_string())
+    .bind(actor_id.to_string())
+    .bind(target_user_id.map(|u| u.to_string()))
+    .bind(action.as_str())
+    .bind(before_value)
+    .bind(after_value)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    Ok(AdminAuditEntry {
+        id,
+        household_id: *household_id,
+        actor_id: *actor_id,
+        target_user_id: target_user_id.copied(),
+        action,
+        before_value: before_value.map(|s| s.to_string()),
+        after_value: after_value.map(|s| s.to_string()),
+        created_at: now,
+    })
+}
+
+/// Reduce two settings snapshots to JSON objects holding only the fields that changed.
+/// Returns `None` when nothing relevant changed.
+pub fn settings_diff(before: &HouseholdSettings, after: &HouseholdSettings) -> Option<(String, String)> {
+    let (Ok(Value::Object(before)), Ok(Value::Object(after))) =
+        (serde_json::to_value(before), serde_json::to_value(after))
+    else {
+        return None;
+    };
+
+    let mut changed_before = Map::new();
+    let mut changed_after = Map::new();
+    for (key, new_value) in after {
+        if key == "household_id" || key == "updated_at" {
+            continue;
+        }
+        let old_value = before.get(&key).cloned().unwrap_or(Value::Null);
+        if old_value != new_value {
+            changed_before.insert(key.clone(), old_value);
+            changed_after.insert(key, new_value);
+        }
+    }
+
+    if changed_after.is_empty() {
+        return None;
+    }
+    Some((
+        Value::Object(changed_before).to_string(),
+        Value::Object(changed_after).to_string(),
+    ))
+}
+
+// Struct for joined queries
+#[derive(sqlx::FromRow)]
+struct JoinedAuditRow {
+    id: String,
+    household_id: String,
+    actor_id: String,
+    target_user_id: Option<String>,
+    action: String,
+    before_value: Option<String>,
+    after_value: Option<String>,
+    created_at: chrono::DateTime<chrono::Utc>,
+    actor_username: String,
+    actor_email: String,
+    actor_created_at: chrono::DateTime<chrono::Utc>,
+    actor_updated_at: chrono::DateTime<chrono::Utc>,
+    target_username: Option<String>,
+    target_email: Option<String>,
+    target_created_at: Option<chrono::DateTime<chrono::Utc>>,
+    target_updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl JoinedAuditRow {
+    fn into_entry_with_users(self) -> AdminAuditEntryWithUsers {
+        let entry = AdminAuditEntry {
+            id: Uuid::parse_str(&self.id).unwrap(),
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            actor_id: Uuid::parse_str(&self.actor_id).unwrap(),
+            target_user_id: self.target_user_id.as_ref().map(|s| Uuid::parse_str(s).unwrap()),
+            action: self.action.parse().unwrap_or(AuditAction::SettingsChanged),
+            before_value: self.before_value,
+            after_value: self.after_value,
+            created_at: self.created_at,
+        };
+
+        let actor = User {
+            id: entry.actor_id,
+            username: self.actor_username,
+            email: self.actor_email,
+            created_at: self.actor_created_at,
+            updated_at: self.actor_updated_at,
+        };
+
+        let target_user = match (
+            entry.target_user_id,
+            self.target_username,
+            self.target_email,
+            self.target_created_at,
+            self.target_updated_at,
+        ) {
+            (Some(id), Some(username), Some(email), Some(created_at), Some(updated_at)) => Some(User {
+                id,
+                username,
+                email,
+                created_at,
+                updated_at,
+            }),
+            _ => None,
+        };
+
+        AdminAuditEntryWithUsers {
+            entry,
+            actor,
+            target_user,
+        }
+    }
+}
+
+/// List a page of the household's audit log, newest first
+pub async fn list_entries(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    limit: i64,
+    offset: i64,
+) -> Result<AdminAuditLogPage, AuditLogError> {
+    let rows: Vec<JoinedAuditRow> = sqlx::query_as(
+        r#"
+        SELECT
+            a.id, a.household_id, a.actor_id, a.target_user_id,
+            a.action, a.before_value, a.after_value, a.created_at,
+            actor.username as actor_username, actor.email as actor_email,
+            actor.created_at as actor_created_at, actor.updated_at as actor_updated_at,
+            target.username as target_username, target.email as target_email,
+            target.created_at as target_created_at, target.updated_at as target_updated_at
+        FROM admin_audit_log a
+        JOIN users actor ON a.actor_id = actor.id
+        LEFT JOIN users target ON a.target_user_id = target.id
+        WHERE a.household_id = ?
+        ORDER BY a.created_at DESC, a.rowid DESC
+        LIMIT ? OFFSET ?
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
+    .await?;
+
+    let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM admin_audit_log WHERE household_id = ?")
+        .bind(household_id.to_string())
+        .fetch_one(pool)
+        .await?;
+
+    Ok(AdminAuditLogPage {
+        entries: rows.into_iter().map(|row| row.into_entry_with_users()).collect(),
+        total,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{create_test_household, create_test_pool, create_test_user};
+    use shared::Role;
+
+    #[tokio::test]
+    async fn test_record_and_list_entries() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let owner = create_test_user(&pool, "auditor@test.com", Role::Owner).await;
+        let member = create_test_user(&pool, "member@test.com", Role::Member).await;
+
+        record(&pool, &household_id, &owner, Some(&member), AuditAction::RoleChanged, Some("member"), Some("admin"))
+            .await
+            .unwrap();
+        record(&pool, &household_id, &owner, Some(&member), AuditAction::PointsAdjusted, Some("10"), Some("15"))
+            .await
+            .unwrap();
+        record(&pool, &household_id, &owner, None, AuditAction::SettingsChanged, None, None)
+            .await
+            .unwrap();
+
+        let page = list_entries(&pool, &household_id, 2, 0).await.unwrap();
+        assert_eq!(page.total, 3);
+        assert_eq!(page.entries.len(), 2);
+        assert_eq!(page.entries[0].entry.action, AuditAction::SettingsChanged);
+        assert!(page.entries[0].target_user.is_none());
+        assert_eq!(page.entries[1].entry.action, AuditAction::PointsAdjusted);
+        assert_eq!(page.entries[1].target_user.as_ref().map(|u| u.id), Some(member));
+        assert_eq!(page.entries[1].actor.id, owner);
+
+        let page = list_entries(&pool, &household_id, 2, 2).await.unwrap();
+        assert_eq!(page.entries.len(), 1);
+        assert_eq!(page.entries[0].entry.before_value.as_deref(), Some("member"));
+        assert_eq!(page.entries[0].entry.after_value.as_deref(), Some("admin"));
+    }
+
+    #[test]
+    fn test_settings_diff_only_includes_changed_fields() {
+        let before = HouseholdSettings::default();
+        let mut after = before.clone();
+        after.updated_at = Utc::now();
+        assert!(settings_diff(&before, &after).is_none());
+
+        after.dark_mode = !before.dark_mode;
+        after.timezone = "Europe/Berlin".to_string();
+        let (old, new) = settings_diff(&before, &after).unwrap();
+        let old: Value = serde_json::from_str(&old).unwrap();
+        let new: Value = serde_json::from_str(&new).unwrap();
+        assert_eq!(new.as_object().unwrap().len(), 2);
+        assert_eq!(new["timezone"], "Europe/Berlin");
+        assert_eq!(old["dark_mode"], Value::Bool(before.dark_mode));
+    }
+}
@@ -12,7 +12,7 @@ use thiserror::Error;
 use uuid::Uuid;
 
 use crate::models::{UserRow, RefreshTokenRow};
-use shared::{CreateUserRequest, UpdateUserRequest, User};
+use shared::{is_child_account_email, CreateUserRequest, UpdateUserRequest, User, CHILD_ACCOUNT_EMAIL_DOMAIN};
 
 #[derive(Debug, Error)]
 pub enum AuthError {
@@ -26,6 +26,14 @@ pub enum AuthError {
     InvalidRefreshToken,
     #[error("Refresh token expired")]
     RefreshTokenExpired,
+    #[error("Invalid or expired password reset token")]
+    InvalidResetToken,
+    #[error("This email address cannot be used")]
+    ReservedEmail,
+    #[error("Child accounts cannot use this feature")]
+    ChildAccountRestricted,
+    #[error("Account is disabled")]
+    AccountDisabled,
     #[error("Database error: {0}")]
     DatabaseError(#[from] sqlx::Error),
     #[error("Password hashing error")]
@@ -42,12 +50,20 @@ pub struct Claims {
 }
 
 pub async fn register_user(pool: &SqlitePool, request: &CreateUserRequest) -> Result<User, AuthError> {
+    // Placeholder addresses are reserved for child accounts
+    if is_child_account_email(&request.email) {
+        return Err(AuthError::ReservedEmail);
+    }
+
+    // Emails are stored lowercased, so addresses differing only in case collide
+    let email = request.email.to_lowercase();
+
     // Check if user exists
     let existing = sqlx::query_scalar::<_, i64>(
         "SELECT COUNT(*) FROM users WHERE username = ? OR email = ?"
     )
     .bind(&request.username)
-    .bind(&request.email)
+    .bind(&email)
     .fetch_one(pool)
     .await?;
 
@@ -66,15 +82,16 @@ pub async fn register_user(pool: &SqlitePool, request: &CreateUserRequest) -> Re
     let id = Uuid::new_v4();
     let now = Utc::now();
 
+    // The first account on the instance becomes its admin
     sqlx::query(
         r#"
-        INSERT INTO users (id, username, email, password_hash, created_at, updated_at)
-        VALUES (?, ?, ?, ?, ?, ?)
+        INSERT INTO users (id, username, email, password_hash, is_admin, created_at, updated_at)
+        SELECT ?, ?, ?, ?, NOT EXISTS (SELECT 1 FROM users WHERE managed_by IS NULL), ?, ?
         "#
     )
     .bind(id.to_string())
     .bind(&request.username)
-    .bind(&request.email)
+    .bind(&email)
     .bind(&password_hash)
     .bind(now)
     .bind(now)
@@ -84,7 +101,7 @@ pub async fn register_user(pool: &SqlitePool, request: &CreateUserRequest) -> Re
     Ok(User {
         id,
         username: request.username.clone(),
-        email: request.email.clone(),
+        email,
         created_at: now,
         updated_at: now,
     })
@@ -110,7 +127,48 @@ pub async fn login_user(pool: &SqlitePool, request: &shared::LoginRequest) -> Re
         .verify_password(request.password.as_bytes(), &parsed_hash)
         .map_err(|_| AuthError::InvalidCredentials)?;
 
-    Ok(user.to_shared())
+    // Checked after the password, so the disabled state isn't revealed to guessers
+    let user = user.to_shared();
+    ensure_account_enabled(pool, &user.id).await?;
+
+    Ok(user)
+}
+
+/// The child account a login name (username or email) belongs to, if any
+pub async fn child_account_id(pool: &SqlitePool, login: &str) -> Result<Option<Uuid>, AuthError> {
+    let user: Option<(String, String)> = sqlx::query_as(
+        "SELECT id, email FROM users WHERE LOWER(username) = LOWER(?) OR LOWER(email) = LOWER(?)"
+    )
+    .bind(login)
+    .bind(login)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(user
+        .filter(|(_, email)| is_child_account_email(email))
+        .and_then(|(id, _)| Uuid::parse_str(&id).ok()))
+}
+
+/// Fails with `AccountDisabled` if an instance admin has disabled the account
+pub async fn ensure_account_enabled(pool: &SqlitePool, user_id: &Uuid) -> Result<(), AuthError> {
+    let disabled_at: Option<Option<chrono::DateTime<Utc>>> =
+        sqlx::query_scalar("SELECT disabled_at FROM users WHERE id = ?")
+            .bind(user_id.to_string())
+            .fetch_optional(pool)
+            .await?;
+    match disabled_at {
+        Some(Some(_)) => Err(AuthError::AccountDisabled),
+        _ => Ok(()),
+    }
+}
+
+/// Whether an account uses this email address (case insensitive)
+pub async fn email_registered(pool: &SqlitePool, email: &str) -> Result<bool, AuthError> {
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE LOWER(email) = LOWER(?)")
+        .bind(email.trim())
+        .fetch_one(pool)
+        .await?;
+    Ok(count > 0)
 }
 
 pub async fn get_user_by_id(pool: &SqlitePool, user_id: &Uuid) -> Result<Option<User>, AuthError> {
@@ -150,11 +208,19 @@ pub async fn update_user(pool: &SqlitePool, user_id: &Uuid, request: &UpdateUser
     }
 
     if let Some(ref email) = request.email {
+        if user.to_shared().is_child_account() {
+            return Err(AuthError::ChildAccountRestricted);
+        }
+        if is_child_account_email(email) {
+            return Err(AuthError::ReservedEmail);
+        }
+
         // Check if email is taken
+        let email = email.to_lowercase();
         let existing = sqlx::query_scalar::<_, i64>(
             "SELECT COUNT(*) FROM users WHERE email = ? AND id != ?"
         )
-        .bind(email)
+        .bind(&email)
         .bind(user_id.to_string())
         .fetch_one(pool)
         .await?;
@@ -162,7 +228,7 @@ pub async fn update_user(pool: &SqlitePool, user_id: &Uuid, request: &UpdateUser
         if existing > 0 {
             return Err(AuthError::UserAlreadyExists);
         }
-        user.email = email.clone();
+        user.email = email;
     }
 
     let now = Utc::now();
@@ -274,6 +340,7 @@ pub async fn refresh_tokens(
     let user = get_user_by_id(pool, &user_id)
         .await?
         .ok_or(AuthError::UserNotFound)?;
+    ensure_account_enabled(pool, &user_id).await?;
 
     // Delete the old refresh token (rotation)
     sqlx::query("DELETE FROM refresh_tokens WHERE id = ?")
@@ -308,8 +375,7 @@ pub fn create_access_token(user_id: &Uuid, secret: &str, expiration_minutes: i64
     Ok(token)
 }
 
-/// Delete all refresh tokens for a user (used on logout)
-#[allow(dead_code)]
+/// Delete all refresh tokens for a user, ending their sessions once the access token expires
 pub async fn delete_user_refresh_tokens(pool: &SqlitePool, user_id: &Uuid) -> Result<(), AuthError> {
     sqlx::query("DELETE FROM refresh_tokens WHERE user_id = ?")
         .bind(user_id.to_string())
@@ -328,6 +394,273 @@ pub async fn delete_refresh_token(pool: &SqlitePool, refresh_token: &str) -> Res
     Ok(())
 }
 
+/// How long a password reset link stays valid
+pub const PASSWORD_RESET_TTL_MINUTES: i64 = 60;
+
+/// Start a password reset for the account with this email.
+/// Returns the user and the raw token to send, or `None` if no account uses the email.
+/// Earlier reset tokens of the user are invalidated.
+pub async fn create_password_reset_token(pool: &SqlitePool, email: &str) -> Result<Option<(User, String)>, AuthError> {
+    let user: Option<UserRow> = sqlx::query_as("SELECT * FROM users WHERE LOWER(email) = LOWER(?)")
+        .bind(email.trim())
+        .fetch_optional(pool)
+        .await?;
+    // Child accounts have no mailbox; their PIN is reset by the household owner
+    let Some(user) = user.filter(|u| !is_child_account_email(&u.email)) else {
+        return Ok(None);
+    };
+
+    sqlx::query("DELETE FROM password_reset_tokens WHERE user_id = ?")
+        .bind(&user.id)
+        .execute(pool)
+        .await?;
+
+    let (token, hash) = generate_refresh_token();
+    let now = Utc::now();
+
+    sqlx::query(
+        r#"
+        INSERT INTO password_reset_tokens (id, user_id, token_hash, expires_at, created_at)
+        VALUES (?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(&user.id)
+    .bind(&hash)
+    .bind(now + Duration::minutes(PASSWORD_RESET_TTL_MINUTES))
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    Ok(Some((user.to_shared(), token)))
+}
+
+/// Set a new password using a reset token. The token is consumed and all
+/// sessions (refresh tokens) of the user are revoked.
+pub async fn reset_password(pool: &SqlitePool, token: &str, new_password: &str) -> Result<User, AuthError> {
+    let token_hash = hash_refresh_token(token);
+
+    let row: Option<(String, String, chrono::DateTime<Utc>)> = sqlx::query_as(
+        "SELECT id, user_id, expires_at FROM password_reset_tokens WHERE token_hash = ?",
+    )
+    .bind(&token_hash)
+    .fetch_optional(pool)
+    .await?;
+    let (token_id, user_id, expires_at) = row.ok_or(AuthError::InvalidResetToken)?;
+
+    sqlx::query("DELETE FROM password_reset_tokens WHERE id = ?")
+        .bind(&token_id)
+        .execute(pool)
+        .await?;
+
+    if expires_at < Utc::now() {
+        return Err(AuthError::InvalidResetToken);
+    }
+
+    let salt = SaltString::generate(&mut OsRng);
+    let password_hash = Argon2::default()
+        .hash_password(new_password.as_bytes(), &salt)
+        .map_err(|_| AuthError::HashingError)?
+        .to_string();
+
+    sqlx::query("UPDATE users SET password_hash = ?, updated_at = ? WHERE id = ?")
+        .bind(&password_hash)
+        .bind(Utc::now())
+        .bind(&user_id)
+        .execute(pool)
+        .await?;
+
+    sqlx::query("DELETE FROM refresh_tokens WHERE user_id = ?")
+        .bind(&user_id)
+        .execute(pool)
+        .await?;
+
+    let user_id = Uuid::parse_str(&user_id).map_err(|_| AuthError::UserNotFound)?;
+    get_user_by_id(pool, &user_id).await?.ok_or(AuthError::UserNotFound)
+}
+
+/// Check a user's password. Accounts without a password (OIDC-only) never match.
+pub async fn verify_password(pool: &SqlitePool, user_id: &Uuid, password: &str) -> Result<(), AuthError> {
+    let user: UserRow = sqlx::query_as("SELECT * FROM users WHERE id = ?")
+        .bind(user_id.to_string())
+        .fetch_optional(pool)
+        .await?
+        .ok_or(AuthError::UserNotFound)?;
+
+    let password_hash = user.password_hash.as_ref().ok_or(AuthError::InvalidCredentials)?;
+    let parsed_hash = PasswordHash::new(password_hash).map_err(|_| AuthError::InvalidCredentials)?;
+
+    Argon2::default()
+        .verify_password(password.as_bytes(), &parsed_hash)
+        .map_err(|_| AuthError::InvalidCredentials)
+}
+
+/// Whether the account can sign in with a password
+pub async fn has_password(pool: &SqlitePool, user_id: &Uuid) -> Result<bool, AuthError> {
+    let user: UserRow = sqlx::query_as("SELECT * FROM users WHERE id = ?")
+        .bind(user_id.to_string())
+        .fetch_optional(pool)
+        .await?
+        .ok_or(AuthError::UserNotFound)?;
+    Ok(user.password_hash.is_some())
+}
+
+/// Change a user's password after checking the current one.
+/// All refresh tokens except `keep_refresh_token` (the calling session) are revoked.
+pub async fn change_password(
+    pool: &SqlitePool,
+    user_id: &Uuid,
+    current_password: &str,
+    new_password: &str,
+    keep_refresh_token: Option<&str>,
+) -> Result<(), AuthError> {
+    verify_password(pool, user_id, current_password).await?;
+
+    let salt = SaltString::generate(&mut OsRng);
+    let password_hash = Argon2::default()
+        .hash_password(new_password.as_bytes(), &salt)
+        .map_err(|_| AuthError::HashingError)?
+        .to_string();
+
+    sqlx::query("UPDATE users SET password_hash = ?, updated_at = ? WHERE id = ?")
+        .bind(&password_hash)
+        .bind(Utc::now())
+        .bind(user_id.to_string())
+        .execute(pool)
+        .await?;
+
+    let keep_hash = keep_refresh_token.map(hash_refresh_token).unwrap_or_default();
+    sqlx::query("DELETE FROM refresh_tokens WHERE user_id = ? AND token_hash != ?")
+        .bind(user_id.to_string())
+        .bind(&keep_hash)
+        .execute(pool)
+        .await?;
+
+    sqlx::query("DELETE FROM password_reset_tokens WHERE user_id = ?")
+        .bind(user_id.to_string())
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Remove a user's personal data and credentials.
+/// The user row is kept with an anonymized name and email because shared
+/// household history (chat, expenses, activity) still references it.
+/// Household memberships must be dissolved beforehand.
+pub async fn delete_account(pool: &SqlitePool, user_id: &Uuid) -> Result<(), AuthError> {
+    let mut tx = pool.begin().await?;
+
+    for table in [
+        "refresh_tokens",
+        "password_reset_tokens",
+        "oidc_login_codes",
+        "calendar_feed_tokens",
+        "push_subscriptions",
+        "user_settings",
+    ] {
+        sqlx::query(&format!("DELETE FROM {} WHERE user_id = ?", table))
+            .bind(user_id.to_string())
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    let result = sqlx::query(
+        r#"
+        UPDATE users
+        SET username = ?, email = ?, password_hash = NULL, oidc_subject = NULL, oidc_provider = NULL, updated_at = ?
+        WHERE id = ?
+        "#,
+    )
+    .bind(format!("deleted-{}", user_id.simple()))
+    .bind(format!("{}@deleted.invalid", user_id.simple()))
+    .bind(Utc::now())
+    .bind(user_id.to_string())
+    .execute(&mut *tx)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AuthError::UserNotFound);
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Create a managed child account that signs in with username and PIN.
+/// The account gets a placeholder email on the reserved child domain.
+pub async fn create_child_account(
+    pool: &SqlitePool,
+    username: &str,
+    pin: &str,
+    managed_by: &Uuid,
+) -> Result<User, AuthError> {
+    let existing = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM users WHERE LOWER(username) = LOWER(?)")
+        .bind(username)
+        .fetch_one(pool)
+        .await?;
+    if existing > 0 {
+        return Err(AuthError::UserAlreadyExists);
+    }
+
+    let salt = SaltString::generate(&mut OsRng);
+    let pin_hash = Argon2::default()
+        .hash_password(pin.as_bytes(), &salt)
+        .map_err(|_| AuthError::HashingError)?
+        .to_string();
+
+    let id = Uuid::new_v4();
+    let email = format!("{}@{}", id.simple(), CHILD_ACCOUNT_EMAIL_DOMAIN);
+    let now = Utc::now();
+
+    sqlx::query(
+        r#"
+        INSERT INTO users (id, username, email, password_hash, managed_by, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(id.to_string())
+    .bind(username)
+    .bind(&email)
+    .bind(&pin_hash)
+    .bind(managed_by.to_string())
+    .bind(now)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    Ok(User {
+        id,
+        username: username.to_string(),
+        email,
+        created_at: now,
+        updated_at: now,
+    })
+}
+
+/// Set a new PIN for a child account and sign it out everywhere
+pub async fn set_child_pin(pool: &SqlitePool, child_id: &Uuid, pin: &str) -> Result<(), AuthError> {
+    let child = get_user_by_id(pool, child_id).await?.ok_or(AuthError::UserNotFound)?;
+    if !child.is_child_account() {
+        return Err(AuthError::ChildAccountRestricted);
+    }
+
+    let salt = SaltString::generate(&mut OsRng);
+    let pin_hash = Argon2::default()
+        .hash_password(pin.as_bytes(), &salt)
+        .map_err(|_| AuthError::HashingError)?
+        .to_string();
+
+    sqlx::query("UPDATE users SET password_hash = ?, updated_at = ? WHERE id = ?")
+        .bind(&pin_hash)
+        .bind(Utc::now())
+        .bind(child_id.to_string())
+        .execute(pool)
+        .await?;
+
+    delete_user_refresh_tokens(pool, child_id).await
+}
+
 /// Clean up expired refresh tokens (can be called periodically)
 #[allow(dead_code)]
 pub async fn cleanup_expired_refresh_tokens(pool: &SqlitePool) -> Result<u64, AuthError> {
@@ -418,6 +751,9 @@ mod tests {
                 password_hash TEXT,
                 oidc_subject TEXT,
                 oidc_provider TEXT,
+                managed_by TEXT REFERENCES users(id),
+                disabled_at DATETIME,
+                is_admin BOOLEAN NOT NULL DEFAULT FALSE,
                 created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
                 updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
             )
@@ -427,6 +763,35 @@ mod tests {
         .await
         .unwrap();
 
+        for table in ["refresh_tokens", "password_reset_tokens"] {
+            sqlx::query(&format!(
+                r#"
+                CREATE TABLE IF NOT EXISTS {} (
+                    id TEXT PRIMARY KEY NOT NULL,
+                    user_id TEXT NOT NULL REFERENCES users(id),
+                    token_hash TEXT NOT NULL,
+                    expires_at DATETIME NOT NULL,
+                    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+                )
+                "#,
+                table
+            ))
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
+        // Per-user tables cleared on account deletion; only the user_id column matters here
+        for table in ["oidc_login_codes", "calendar_feed_tokens", "push_subscriptions", "user_settings"] {
+            sqlx::query(&format!(
+                "CREATE TABLE IF NOT EXISTS {} (user_id TEXT NOT NULL REFERENCES users(id))",
+                table
+            ))
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
         pool
     }
 
@@ -489,6 +854,31 @@ mod tests {
         assert_eq!(result.unwrap().username, "testuser");
     }
 
+    #[tokio::test]
+    async fn test_login_rejected_for_disabled_account() {
+        let pool = setup_test_db().await;
+        let user_id = create_test_user_with_password(&pool, "testuser", "test@example.com", "password123").await;
+        sqlx::query("UPDATE users SET disabled_at = ? WHERE id = ?")
+            .bind(Utc::now())
+            .bind(user_id.to_string())
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let request = shared::LoginRequest {
+            username: "testuser".to_string(),
+            password: "password123".to_string(),
+        };
+        assert!(matches!(login_user(&pool, &request).await, Err(AuthError::AccountDisabled)));
+
+        // A wrong password doesn't reveal that the account is disabled
+        let request = shared::LoginRequest {
+            username: "testuser".to_string(),
+            password: "wrong".to_string(),
+        };
+        assert!(matches!(login_user(&pool, &request).await, Err(AuthError::InvalidCredentials)));
+    }
+
     #[tokio::test]
     async fn test_login_case_insensitive_username() {
         let pool = setup_test_db().await;
@@ -518,4 +908,183 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap().username, "testuser");
     }
+
+    #[tokio::test]
+    async fn test_password_reset_flow() {
+        let pool = setup_test_db().await;
+        let user_id = create_test_user_with_password(&pool, "testuser", "test@example.com", "password123").await;
+        create_refresh_token(&pool, &user_id, 30).await.unwrap();
+
+        assert!(email_registered(&pool, "TEST@example.com").await.unwrap());
+        assert!(!email_registered(&pool, "nobody@example.com").await.unwrap());
+        assert!(create_password_reset_token(&pool, "nobody@example.com").await.unwrap().is_none());
+
+        let (user, token) = create_password_reset_token(&pool, "TEST@example.com").await.unwrap().unwrap();
+        assert_eq!(user.id, user_id);
+
+        reset_password(&pool, &token, "newpassword456").await.unwrap();
+
+        let login = |password: &str| shared::LoginRequest {
+            username: "testuser".to_string(),
+            password: password.to_string(),
+        };
+        assert!(login_user(&pool, &login("password123")).await.is_err());
+        assert!(login_user(&pool, &login("newpassword456")).await.is_ok());
+
+        // Sessions are revoked and the token can only be used once
+        let sessions: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM refresh_tokens WHERE user_id = ?")
+            .bind(user_id.to_string())
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(sessions, 0);
+        assert!(matches!(
+            reset_password(&pool, &token, "another-password").await,
+            Err(AuthError::InvalidResetToken)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_password_reset_token_expires() {
+        let pool = setup_test_db().await;
+        create_test_user_with_password(&pool, "testuser", "test@example.com", "password123").await;
+
+        let (_, token) = create_password_reset_token(&pool, "test@example.com").await.unwrap().unwrap();
+        sqlx::query("UPDATE password_reset_tokens SET expires_at = ?")
+            .bind(Utc::now() - Duration::minutes(1))
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            reset_password(&pool, &token, "newpassword456").await,
+            Err(AuthError::InvalidResetToken)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_change_password_keeps_current_session() {
+        let pool = setup_test_db().await;
+        let user_id = create_test_user_with_password(&pool, "testuser", "test@example.com", "password123").await;
+        let current = create_refresh_token(&pool, &user_id, 30).await.unwrap();
+        let other = create_refresh_token(&pool, &user_id, 30).await.unwrap();
+
+        assert!(matches!(
+            change_password(&pool, &user_id, "wrong-password", "newpassword456", Some(&current)).await,
+            Err(AuthError::InvalidCredentials)
+        ));
+
+        change_password(&pool, &user_id, "password123", "newpassword456", Some(&current))
+            .await
+            .unwrap();
+
+        assert!(verify_password(&pool, &user_id, "newpassword456").await.is_ok());
+        assert!(verify_password(&pool, &user_id, "password123").await.is_err());
+        assert!(refresh_tokens(&pool, &current, "secret", 15, 30).await.is_ok());
+        assert!(refresh_tokens(&pool, &other, "secret", 15, 30).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_delete_account_anonymizes_user() {
+        let pool = setup_test_db().await;
+        let user_id = create_test_user_with_password(&pool, "testuser", "test@example.com", "password123").await;
+        create_refresh_token(&pool, &user_id, 30).await.unwrap();
+        assert!(has_password(&pool, &user_id).await.unwrap());
+
+        delete_account(&pool, &user_id).await.unwrap();
+
+        let user = get_user_by_id(&pool, &user_id).await.unwrap().unwrap();
+        assert_ne!(user.username, "testuser");
+        assert!(user.email.ends_with("@deleted.invalid"));
+        assert!(!has_password(&pool, &user_id).await.unwrap());
+        assert!(!email_registered(&pool, "test@example.com").await.unwrap());
+
+        let login = shared::LoginRequest {
+            username: "testuser".to_string(),
+            password: "password123".to_string(),
+        };
+        assert!(login_user(&pool, &login).await.is_err());
+
+        let sessions: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM refresh_tokens WHERE user_id = ?")
+            .bind(user_id.to_string())
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(sessions, 0);
+    }
+
+    #[tokio::test]
+    async fn test_child_account_signs_in_with_pin() {
+        let pool = setup_test_db().await;
+        let parent = create_test_user_with_password(&pool, "parent", "parent@example.com", "password123").await;
+
+        let child = create_child_account(&pool, "kiddo", "1234", &parent).await.unwrap();
+        assert!(child.is_child_account());
+        assert!(matches!(
+            create_child_account(&pool, "KIDDO", "5678", &parent).await,
+            Err(AuthError::UserAlreadyExists)
+        ));
+
+        let login = |pin: &str| shared::LoginRequest {
+            username: "kiddo".to_string(),
+            password: pin.to_string(),
+        };
+        assert!(login_user(&pool, &login("1234")).await.is_ok());
+
+        set_child_pin(&pool, &child.id, "4321").await.unwrap();
+        assert!(login_user(&pool, &login("1234")).await.is_err());
+        assert!(login_user(&pool, &login("4321")).await.is_ok());
+
+        // Email features are off limits
+        assert!(create_password_reset_token(&pool, &child.email).await.unwrap().is_none());
+        let update = UpdateUserRequest {
+            username: None,
+            email: Some("kid@example.com".to_string()),
+        };
+        assert!(matches!(
+            update_user(&pool, &child.id, &update).await,
+            Err(AuthError::ChildAccountRestricted)
+        ));
+        assert!(matches!(
+            set_child_pin(&pool, &parent, "1111").await,
+            Err(AuthError::ChildAccountRestricted)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_register_rejects_child_domain() {
+        let pool = setup_test_db().await;
+        let request = CreateUserRequest {
+            username: "sneaky".to_string(),
+            email: "sneaky@child.invalid".to_string(),
+            password: "password123".to_string(),
+        };
+        assert!(matches!(register_user(&pool, &request).await, Err(AuthError::ReservedEmail)));
+    }
+
+    #[tokio::test]
+    async fn test_emails_are_unique_regardless_of_case() {
+        let pool = setup_test_db().await;
+        let request = CreateUserRequest {
+            username: "alice".to_string(),
+            email: "Alice@Example.com".to_string(),
+            password: "password123".to_string(),
+        };
+        let alice = register_user(&pool, &request).await.unwrap();
+        assert_eq!(alice.email, "alice@example.com");
+
+        let request = CreateUserRequest {
+            username: "mallory".to_string(),
+            email: "ALICE@example.com".to_string(),
+            password: "password123".to_string(),
+        };
+        assert!(matches!(register_user(&pool, &request).await, Err(AuthError::UserAlreadyExists)));
+
+        let bob = create_test_user_with_password(&pool, "bob", "bob@example.com", "password123").await;
+        let update = UpdateUserRequest { username: None, email: Some("ALICE@EXAMPLE.COM".to_string()) };
+        assert!(matches!(update_user(&pool, &bob, &update).await, Err(AuthError::UserAlreadyExists)));
+
+        let update = UpdateUserRequest { username: None, email: Some("Bob.New@Example.com".to_string()) };
+        assert_eq!(update_user(&pool, &bob, &update).await.unwrap().email, "bob.new@example.com");
+    }
 }
@@ -0,0 +1,604 @@
+//! Automation rules: rewards and punishments assigned by the scheduler
+//!
+//! Admins define rules that fire for a member when a trigger is met: the
+//! streak of one of their tasks reaches N, N of their task periods failed in
+//! the last household week, or their points drop below a threshold. The
+//! background job evaluates every enabled rule, assigns the rule's reward or
+//! punishment and records the run. Each occurrence fires only once, keyed by
+//! the streak run, the week or (for low balances) the current week.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::{AutomationRuleRow, AutomationRuleRunRow, MembershipRow, TaskPeriodResultRow};
+use crate::services::{activity_logs, period_results, punishments, rewards, scheduler, statistics};
+use shared::{
+    ActivityType, AutomationRule, AutomationRuleRun, CreateAutomationRuleRequest, HouseholdMembership, PeriodStatus,
+    RuleTrigger, UpdateAutomationRuleRequest,
+};
+
+#[derive(Debug, Error)]
+pub enum AutomationRuleError {
+    #[error("Automation rule not found")]
+    NotFound,
+    #[error("Rule name must not be empty")]
+    EmptyName,
+    #[error("Threshold must be at least 1 for this trigger")]
+    InvalidThreshold,
+    #[error("Choose exactly one reward or punishment")]
+    InvalidAction,
+    #[error("Task does not belong to this household")]
+    InvalidTask,
+    #[error("Reward does not belong to this household")]
+    InvalidReward,
+    #[error("Punishment does not belong to this household")]
+    InvalidPunishment,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+/// Report from evaluating automation rules
+#[derive(Debug, Clone, Default)]
+pub struct AutomationRuleReport {
+    pub rules_checked: u32,
+    pub rules_fired: u32,
+}
+
+/// A member for whom a rule's trigger is met
+#[derive(Debug, Clone, PartialEq)]
+struct TriggerMatch {
+    user_id: Uuid,
+    /// Identifies the occurrence so it fires only once
+    trigger_key: String,
+    observed_value: i64,
+}
+
+fn validate_threshold(trigger: RuleTrigger, threshold: i64) -> Result<(), AutomationRuleError> {
+    match trigger {
+        RuleTrigger::StreakReached | RuleTrigger::FailuresInWeek if threshold < 1 => {
+            Err(AutomationRuleError::InvalidThreshold)
+        }
+        _ => Ok(()),
+    }
+}
+
+async fn belongs_to_household(
+    pool: &SqlitePool,
+    table: &str,
+    id: &Uuid,
+    household_id: &Uuid,
+) -> Result<bool, AutomationRuleError> {
+    let count: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {} WHERE id = ? AND household_id = ?", table))
+        .bind(id.to_string())
+        .bind(household_id.to_string())
+        .fetch_one(pool)
+        .await?;
+    Ok(count > 0)
+}
+
+pub async fn create_rule(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    request: &CreateAutomationRuleRequest,
+) -> Result<AutomationRule, AutomationRuleError> {
+    let name = request.name.trim();
+    if name.is_empty() {
+        return Err(AutomationRuleError::EmptyName);
+    }
+    validate_threshold(request.trigger, request.threshold)?;
+
+    match (request.reward_id, request.punishment_id) {
+        (Some(reward_id), None) => {
+            if !belongs_to_household(pool, "rewards", &reward_id, household_id).await? {
+                return Err(AutomationRuleError::InvalidReward);
+            }
+        }
+        (None, Some(punishment_id)) => {
+            if !belongs_to_household(pool, "punishments", &punishment_id, household_id).await? {
+                return Err(AutomationRuleError::InvalidPunishment);
+            }
+        }
+        _ => return Err(AutomationRuleError::InvalidAction),
+    }
+
+    // Point balances are not tied to a task
+    let task_id = request.task_id.filter(|_| request.trigger != RuleTrigger::PointsBelow);
+    if let Some(task_id) = task_id {
+        if !belongs_to_household(pool, "tasks", &task_id, household_id).await? {
+            return Err(AutomationRuleError::InvalidTask);
+        }
+    }
+
+    let rule = AutomationRule {
+        id: Uuid::new_v4(),
+        household_id: *household_id,
+        name: name.to_string(),
+        trigger: request.trigger,
+        threshold: request.threshold,
+        task_id,
+        reward_id: request.reward_id,
+        punishment_id: request.punishment_id,
+        enabled: true,
+        created_by: *user_id,
+        created_at: Utc::now(),
+    };
+
+    sqlx::query(
+        r#"
+        INSERT INTO automation_rules (id, household_id, name, trigger_type, threshold, task_id, reward_id, punishment_id, enabled, created_by, created_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, TRUE, ?, ?)
+        "#,
+    )
+    .bind(rule.id.to_string())
+    .bind(household_id.to_string())
+    .bind(&rule.name)
+    .bind(rule.trigger.as_str())
+    .bind(rule.threshold)
+    .bind(rule.task_id.map(|id| id.to_string()))
+    .bind(rule.reward_id.map(|id| id.to_string()))
+    .bind(rule.punishment_id.map(|id| id.to_string()))
+    .bind(user_id.to_string())
+    .bind(rule.created_at)
+    .execute(pool)
+    .await?;
+
+    Ok(rule)
+}
+
+pub async fn get_rule(pool: &SqlitePool, rule_id: &Uuid) -> Result<Option<AutomationRule>, AutomationRuleError> {
+    let row: Option<AutomationRuleRow> = sqlx::query_as("SELECT * FROM automation_rules WHERE id = ?")
+        .bind(rule_id.to_string())
+        .fetch_optional(pool)
+        .await?;
+
+    Ok(row.map(|r| r.to_shared()))
+}
+
+pub async fn list_rules(pool: &SqlitePool, household_id: &Uuid) -> Result<Vec<AutomationRule>, AutomationRuleError> {
+    let rows: Vec<AutomationRuleRow> =
+        sqlx::query_as("SELECT * FROM automation_rules WHERE household_id = ? ORDER BY created_at DESC")
+            .bind(household_id.to_string())
+            .fetch_all(pool)
+            .await?;
+
+    Ok(rows.iter().map(|r| r.to_shared()).collect())
+}
+
+pub async fn update_rule(
+    pool: &SqlitePool,
+    rule_id: &Uuid,
+    request: &UpdateAutomationRuleRequest,
+) -> Result<AutomationRule, AutomationRuleError> {
+    let mut rule = get_rule(pool, rule_id).await?.ok_or(AutomationRuleError::NotFound)?;
+
+    if let Some(ref name) = request.name {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(AutomationRuleError::EmptyName);
+        }
+        rule.name = name.to_string();
+    }
+    if let Some(threshold) = request.threshold {
+        validate_threshold(rule.trigger, threshold)?;
+        rule.threshold = threshold;
+    }
+    if let Some(enabled) = request.enabled {
+        rule.enabled = enabled;
+    }
+
+    sqlx::query("UPDATE automation_rules SET name = ?, threshold = ?, enabled = ? WHERE id = ?")
+        .bind(&rule.name)
+        .bind(rule.threshold)
+        .bind(rule.enabled)
+        .bind(rule_id.to_string())
+        .execute(pool)
+        .await?;
+
+    Ok(rule)
+}
+
+/// Delete a rule; its audit trail goes with it
+pub async fn delete_rule(pool: &SqlitePool, rule_id: &Uuid) -> Result<(), AutomationRuleError> {
+    sqlx::query("DELETE FROM automation_rule_runs WHERE rule_id = ?")
+        .bind(rule_id.to_string())
+        .execute(pool)
+        .await?;
+
+    let result = sqlx::query("DELETE FROM automation_rules WHERE id = ?")
+        .bind(rule_id.to_string())
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AutomationRuleError::NotFound);
+    }
+    Ok(())
+}
+
+/// The household's audit trail of fired rules, newest first
+pub async fn list_runs(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    limit: i64,
+) -> Result<Vec<AutomationRuleRun>, AutomationRuleError> {
+    let rows: Vec<AutomationRuleRunRow> = sqlx::query_as(
+        r#"
+        SELECT r.id, r.rule_id, ar.name AS rule_name, r.user_id, u.username, ar.trigger_type,
+               r.observed_value, r.reward_id, r.punishment_id, r.created_at
+        FROM automation_rule_runs r
+        JOIN automation_rules ar ON r.rule_id = ar.id
+        JOIN users u ON r.user_id = u.id
+        WHERE r.household_id = ?
+        ORDER BY r.created_at DESC
+        LIMIT ?
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.iter().map(|r| r.to_shared()).collect())
+}
+
+/// Current streak of a task's periods and the start of the oldest period in that run
+fn streak_with_start(rows: &[TaskPeriodResultRow], membership: &HouseholdMembership) -> (i64, Option<NaiveDate>) {
+    let streak = period_results::streak_from_periods(rows, Some(membership));
+    let start = usize::try_from(streak - 1).ok().and_then(|index| {
+        rows.iter()
+            .filter(|row| row.status.parse::<PeriodStatus>() == Ok(PeriodStatus::Completed))
+            .nth(index)
+            .map(|row| row.period_start)
+    });
+    (streak as i64, start)
+}
+
+/// Ids of the non-archived tasks assigned to a member, optionally just one task
+async fn assigned_task_ids(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    task_id: Option<&Uuid>,
+) -> Result<Vec<String>, AutomationRuleError> {
+    let task_id = task_id.map(|id| id.to_string());
+    let ids = sqlx::query_scalar(
+        r#"
+        SELECT t.id FROM tasks t
+        JOIN task_assignees ta ON ta.task_id = t.id
+        WHERE t.household_id = ? AND ta.user_id = ? AND t.archived = FALSE AND t.deleted_at IS NULL AND (? IS NULL OR t.id = ?)
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .bind(&task_id)
+    .bind(&task_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(ids)
+}
+
+/// Members for whom the rule's trigger is met at local date `today`
+async fn find_matches(
+    pool: &SqlitePool,
+    rule: &AutomationRule,
+    members: &[HouseholdMembership],
+    today: NaiveDate,
+    week_start_day: i32,
+) -> Result<Vec<TriggerMatch>, AutomationRuleError> {
+    let mut matches = Vec::new();
+    let current_week = statistics::get_week_start(today, week_start_day);
+
+    for member in members.iter().filter(|m| !m.is_on_vacation(today)) {
+        match rule.trigger {
+            RuleTrigger::PointsBelow => {
+                // Once per week while the balance stays low
+                if member.points < rule.threshold {
+                    matches.push(TriggerMatch {
+                        user_id: member.user_id,
+                        trigger_key: format!("week:{}", current_week),
+                        observed_value: member.points,
+                    });
+                }
+            }
+            RuleTrigger::FailuresInWeek => {
+                let week_start = current_week - chrono::Duration::days(7);
+                let week_end = statistics::get_week_end(week_start);
+                let task_ids = assigned_task_ids(pool, &rule.household_id, &member.user_id, rule.task_id.as_ref()).await?;
+
+                let mut failures = 0;
+                for task_id in task_ids {
+                    let periods: Vec<(NaiveDate, NaiveDate)> = sqlx::query_as(
+                        r#"
+                        SELECT period_start, period_end FROM task_period_results
+                        WHERE task_id = ? AND status = 'failed' AND period_end >= ? AND period_end <= ?
+                        "#,
+                    )
+                    .bind(&task_id)
+                    .bind(week_start)
+                    .bind(week_end)
+                    .fetch_all(pool)
+                    .await?;
+                    failures += periods
+                        .iter()
+                        .filter(|(start, end)| !member.is_on_vacation_during(*start, *end))
+                        .count() as i64;
+                }
+
+                if failures >= rule.threshold {
+                    matches.push(TriggerMatch {
+                        user_id: member.user_id,
+                        trigger_key: format!("week:{}", week_start),
+                        observed_value: failures,
+                    });
+                }
+            }
+            RuleTrigger::StreakReached => {
+                let task_ids = assigned_task_ids(pool, &rule.household_id, &member.user_id, rule.task_id.as_ref()).await?;
+                for task_id in task_ids {
+                    let rows: Vec<TaskPeriodResultRow> = sqlx::query_as(
+                        "SELECT * FROM task_period_results WHERE task_id = ? ORDER BY period_start DESC",
+                    )
+                    .bind(&task_id)
+                    .fetch_all(pool)
+                    .await?;
+
+                    // One firing per streak run and task
+                    let (streak, start) = streak_with_start(&rows, member);
+                    if let (true, Some(start)) = (streak >= rule.threshold, start) {
+                        matches.push(TriggerMatch {
+                            user_id: member.user_id,
+                            trigger_key: format!("task:{}:{}", task_id, start),
+                            observed_value: streak,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(matches)
+}
+
+/// Record a run and hand out the rule's reward or punishment.
+/// Returns false if this occurrence already fired.
+async fn fire_rule(
+    pool: &SqlitePool,
+    rule: &AutomationRule,
+    matched: &TriggerMatch,
+    now: DateTime<Utc>,
+) -> Result<bool, AutomationRuleError> {
+    let inserted = sqlx::query(
+        r#"
+        INSERT OR IGNORE INTO automation_rule_runs (id, rule_id, household_id, user_id, trigger_key, observed_value, reward_id, punishment_id, created_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(rule.id.to_string())
+    .bind(rule.household_id.to_string())
+    .bind(matched.user_id.to_string())
+    .bind(&matched.trigger_key)
+    .bind(matched.observed_value)
+    .bind(rule.reward_id.map(|id| id.to_string()))
+    .bind(rule.punishment_id.map(|id| id.to_string()))
+    .bind(now)
+    .execute(pool)
+    .await?
+    .rows_affected();
+    if inserted == 0 {
+        return Ok(false);
+    }
+
+    if let Some(reward_id) = rule.reward_id {
+        if let Err(e) = rewards::assign_reward(pool, &reward_id, &matched.user_id, &rule.household_id).await {
+            log::warn!("Automation rule {} failed to assign reward: {}", rule.id, e);
+        }
+    }
+    if let Some(punishment_id) = rule.punishment_id {
+        if let Err(e) = punishments::assign_punishment(pool, &punishment_id, &matched.user_id, &rule.household_id).await {
+            log::warn!("Automation rule {} failed to assign punishment: {}", rule.id, e);
+        }
+    }
+
+    let details = serde_json::json!({ "name": rule.name, "value": matched.observed_value }).to_string();
+    let _ = activity_logs::log_activity(
+        pool,
+        &rule.household_id,
+        &rule.created_by,
+        Some(&matched.user_id),
+        ActivityType::AutomationRuleApplied,
+        Some("automation_rule"),
+        Some(&rule.id),
+        Some(&details),
+    )
+    .await;
+
+    Ok(true)
+}
+
+/// Evaluate every enabled rule of households that are not on vacation
+pub async fn process_automation_rules(
+    pool: &SqlitePool,
+    now: DateTime<Utc>,
+) -> Result<AutomationRuleReport, AutomationRuleError> {
+    let mut report = AutomationRuleReport::default();
+
+    let rows: Vec<AutomationRuleRow> = sqlx::query_as(
+        r#"
+        SELECT r.* FROM automation_rules r
+        LEFT JOIN household_settings s ON r.household_id = s.household_id
+        WHERE r.enabled = TRUE AND COALESCE(s.vacation_mode, FALSE) = FALSE
+        ORDER BY r.household_id
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    for row in rows {
+        report.rules_checked += 1;
+        let rule = row.to_shared();
+
+        let settings: Option<(String, i32)> =
+            sqlx::query_as("SELECT timezone, week_start_day FROM household_settings WHERE household_id = ?")
+                .bind(&row.household_id)
+                .fetch_optional(pool)
+                .await?;
+        let (timezone, week_start_day) = settings.unwrap_or_else(|| ("UTC".to_string(), 0));
+        let today = now.with_timezone(&scheduler::parse_timezone(&timezone)).date_naive();
+
+        let members: Vec<HouseholdMembership> =
+            sqlx::query_as::<_, MembershipRow>("SELECT * FROM household_memberships WHERE household_id = ?")
+                .bind(&row.household_id)
+                .fetch_all(pool)
+                .await?
+                .iter()
+                .map(|m| m.to_shared())
+                .collect();
+
+        for matched in find_matches(pool, &rule, &members, today, week_start_day).await? {
+            if fire_rule(pool, &rule, &matched, now).await? {
+                report.rules_fired += 1;
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use shared::Role;
+
+    async fn count_user_rewards(pool: &SqlitePool, user_id: &Uuid) -> i64 {
+        sqlx::query_scalar("SELECT COALESCE(SUM(amount), 0) FROM user_rewards WHERE user_id = ?")
+            .bind(user_id.to_string())
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_create_rule_validates_action() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let admin = create_test_user(&pool, "admin@test.com", Role::Admin).await;
+        let reward_id = create_test_reward(&pool, &household_id, "Ice cream", None).await;
+        let punishment_id = create_test_punishment(&pool, &household_id, "Dishes").await;
+
+        let mut request = CreateAutomationRuleRequest {
+            name: "Low balance".to_string(),
+            trigger: RuleTrigger::PointsBelow,
+            threshold: 0,
+            task_id: None,
+            reward_id: Some(reward_id),
+            punishment_id: Some(punishment_id),
+        };
+        let err = create_rule(&pool, &household_id, &admin, &request).await.unwrap_err();
+        assert!(matches!(err, AutomationRuleError::InvalidAction));
+
+        request.reward_id = None;
+        let rule = create_rule(&pool, &household_id, &admin, &request).await.unwrap();
+        assert_eq!(rule.punishment_id, Some(punishment_id));
+        assert!(rule.enabled);
+
+        request.trigger = RuleTrigger::StreakReached;
+        let err = create_rule(&pool, &household_id, &admin, &request).await.unwrap_err();
+        assert!(matches!(err, AutomationRuleError::InvalidThreshold));
+    }
+
+    #[tokio::test]
+    async fn test_points_below_fires_once_per_week() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let admin = create_test_user(&pool, "admin@test.com", Role::Admin).await;
+        let child = create_test_user(&pool, "child@test.com", Role::Member).await;
+        create_test_membership(&pool, &household_id, &child, Role::Member).await;
+        set_user_points(&pool, &household_id, &child, -5).await;
+        let reward_id = create_test_reward(&pool, &household_id, "Pep talk", None).await;
+
+        let request = CreateAutomationRuleRequest {
+            name: "Cheer up".to_string(),
+            trigger: RuleTrigger::PointsBelow,
+            threshold: 0,
+            task_id: None,
+            reward_id: Some(reward_id),
+            punishment_id: None,
+        };
+        let rule = create_rule(&pool, &household_id, &admin, &request).await.unwrap();
+
+        let now = Utc::now();
+        let report = process_automation_rules(&pool, now).await.unwrap();
+        assert_eq!(report.rules_fired, 1);
+        assert_eq!(count_user_rewards(&pool, &child).await, 1);
+
+        // The same week does not fire again
+        let report = process_automation_rules(&pool, now).await.unwrap();
+        assert_eq!(report.rules_fired, 0);
+
+        let runs = list_runs(&pool, &household_id, 50).await.unwrap();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].rule_id, rule.id);
+        assert_eq!(runs[0].user_id, child);
+        assert_eq!(runs[0].observed_value, -5);
+
+        // Disabled rules are skipped
+        let update = UpdateAutomationRuleRequest { enabled: Some(false), ..Default::default() };
+        update_rule(&pool, &rule.id, &update).await.unwrap();
+        let report = process_automation_rules(&pool, now + chrono::Duration::days(7)).await.unwrap();
+        assert_eq!(report.rules_checked, 0);
+    }
+
+    #[tokio::test]
+    async fn test_streak_reached_fires_once_per_run() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let admin = create_test_user(&pool, "admin@test.com", Role::Admin).await;
+        let child = create_test_user(&pool, "child@test.com", Role::Member).await;
+        create_test_membership(&pool, &household_id, &child, Role::Member).await;
+        let task = create_test_task(&pool, &household_id).with_assigned_user(child).build().await;
+        let reward_id = create_test_reward(&pool, &household_id, "Sticker", None).await;
+
+        let day = |d: u32| NaiveDate::from_ymd_opt(2024, 3, d).unwrap();
+        for d in 1..=3 {
+            period_results::finalize_period(&pool, &task.id, day(d), day(d), PeriodStatus::Completed, 1, 1, "system", None)
+                .await
+                .unwrap();
+        }
+
+        let request = CreateAutomationRuleRequest {
+            name: "Three in a row".to_string(),
+            trigger: RuleTrigger::StreakReached,
+            threshold: 3,
+            task_id: Some(task.id),
+            reward_id: Some(reward_id),
+            punishment_id: None,
+        };
+        create_rule(&pool, &household_id, &admin, &request).await.unwrap();
+
+        let now = Utc::now();
+        assert_eq!(process_automation_rules(&pool, now).await.unwrap().rules_fired, 1);
+
+        // A longer streak is the same run
+        period_results::finalize_period(&pool, &task.id, day(4), day(4), PeriodStatus::Completed, 1, 1, "system", None)
+            .await
+            .unwrap();
+        assert_eq!(process_automation_rules(&pool, now).await.unwrap().rules_fired, 0);
+
+        // After a failure a new run has to reach the threshold again
+        period_results::finalize_period(&pool, &task.id, day(5), day(5), PeriodStatus::Failed, 0, 1, "system", None)
+            .await
+            .unwrap();
+        for d in 6..=8 {
+            period_results::finalize_period(&pool, &task.id, day(d), day(d), PeriodStatus::Completed, 1, 1, "system", None)
+                .await
+                .unwrap();
+        }
+        assert_eq!(process_automation_rules(&pool, now).await.unwrap().rules_fired, 1);
+        assert_eq!(count_user_rewards(&pool, &child).await, 2);
+    }
+}
@@ -1,16 +1,20 @@
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use sqlx::SqlitePool;
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 use thiserror::Error;
+use tokio::sync::Notify;
 use tokio::time;
 use uuid::Uuid;
 
+use crate::config::Config;
 use crate::models::{MembershipRow, TaskRow};
 use crate::services::{
-    activity_logs, household_settings, period_results, points as points_service, scheduler,
-    solo_mode, task_consequences, tasks as tasks_service,
+    activity_logs, allowance, announcements, automation_rules, backup, challenges, chat_bridge, events, household_settings, leaderboard, mail, notifications, period_results, point_decay, points as points_service, scheduler,
+    solo_mode, task_consequences, tasks as tasks_service, trash, weekly_summary,
 };
-use shared::{ActivityType, HouseholdSettings, PeriodStatus, RecurrenceType, RecurrenceValue};
+use shared::{ActivityType, BackgroundJob, BackgroundJobStatus, HouseholdMembership, HouseholdSettings, PeriodStatus, RecurrenceType, RecurrenceValue};
 
 #[derive(Debug, Error)]
 pub enum BackgroundJobError {
@@ -38,6 +42,8 @@ pub struct MissedTaskReport {
     /// For bad habits that were avoided
     pub rewards_assigned: i64,
     pub points_added: i64,
+    /// Titles of the missed good-habit tasks per household, for the chat bridge
+    pub overdue_tasks: HashMap<Uuid, Vec<String>>,
 }
 
 /// Report from auto-archiving tasks
@@ -59,120 +65,541 @@ pub struct PeriodFinalizationReport {
 /// Configuration for the background job scheduler
 #[derive(Debug, Clone)]
 pub struct JobConfig {
-    /// Interval in minutes between missed task checks
+    /// Interval in minutes between scheduler runs
     /// Since we support different timezones and due times, we check more frequently
     pub check_interval_minutes: u32,
+    /// Web Push reminder settings, None disables due-task reminders
+    pub reminders: Option<notifications::ReminderConfig>,
+    /// SMTP settings for emailing weekly summaries, None sends no summary mail
+    pub mail: Option<mail::MailSettings>,
+    /// Matrix/Telegram chat for overdue task messages, None posts nothing
+    pub chat_bridge: Option<chat_bridge::ChatBridge>,
+    /// Backup directory and nightly schedule, None takes no nightly snapshots
+    pub backup: Option<backup::BackupSettings>,
+    /// Upload directory, for deleting attachment files of purged trash items
+    pub upload_dir: String,
+    /// Jobs the scheduler skips; they can still be triggered manually
+    pub disabled: HashSet<BackgroundJob>,
+    /// Per-job intervals in minutes; jobs without an entry run on every check
+    pub intervals: HashMap<BackgroundJob, u32>,
 }
 
 impl Default for JobConfig {
     fn default() -> Self {
         Self {
             check_interval_minutes: 1, // Run every minute
+            reminders: None,
+            mail: None,
+            chat_bridge: None,
+            backup: None,
+            upload_dir: "uploads".to_string(),
+            disabled: HashSet::new(),
+            intervals: HashMap::new(),
         }
     }
 }
 
-/// Start the background job scheduler
-/// This runs in a loop and checks for missed tasks at the configured interval
-pub async fn start_scheduler(pool: Arc<SqlitePool>, config: JobConfig) {
-    log::info!(
-        "Background job scheduler started. Missed task check every {} minutes",
-        config.check_interval_minutes
-    );
+impl JobConfig {
+    /// Build the scheduler configuration from `JOB_*` environment settings.
+    /// Unknown job names are logged and ignored.
+    pub fn from_config(
+        config: &Config,
+        reminders: Option<notifications::ReminderConfig>,
+        mail: Option<mail::MailSettings>,
+        chat_bridge: Option<chat_bridge::ChatBridge>,
+        backup: Option<backup::BackupSettings>,
+    ) -> Self {
+        let parse_job = |name: &str| {
+            let job = name.parse::<BackgroundJob>().ok();
+            if job.is_none() {
+                log::warn!("Unknown background job '{}' in configuration, ignoring", name);
+            }
+            job
+        };
 
-    let interval = std::time::Duration::from_secs((config.check_interval_minutes * 60) as u64);
+        Self {
+            check_interval_minutes: config.job_check_interval_minutes.max(1),
+            reminders,
+            mail,
+            chat_bridge,
+            backup,
+            upload_dir: config.upload_dir.clone(),
+            disabled: config.jobs_disabled.iter().filter_map(|name| parse_job(name)).collect(),
+            intervals: config
+                .job_intervals
+                .iter()
+                .filter_map(|(name, minutes)| parse_job(name).map(|job| (job, *minutes)))
+                .collect(),
+        }
+    }
 
-    loop {
-        time::sleep(interval).await;
+    pub fn is_enabled(&self, job: BackgroundJob) -> bool {
+        !self.disabled.contains(&job) && self.is_available(job)
+    }
 
-        // Process missed tasks
-        match process_missed_tasks(&pool).await {
-            Ok(report) => {
-                if report.missed_tasks > 0 {
-                    log::info!(
-                        "Missed task processing complete: checked {} tasks, found {} missed, assigned {} punishments/{} rewards, deducted {}/added {} points",
-                        report.tasks_checked,
-                        report.missed_tasks,
-                        report.punishments_assigned,
-                        report.rewards_assigned,
-                        report.points_deducted,
-                        report.points_added
-                    );
-                } else {
-                    log::debug!(
-                        "Missed task check complete: checked {} tasks, no missed tasks found",
-                        report.tasks_checked
-                    );
+    /// Whether the job can run at all; push reminders need VAPID keys and
+    /// nightly backups need `BACKUP_NIGHTLY_HOUR`
+    pub fn is_available(&self, job: BackgroundJob) -> bool {
+        match job {
+            BackgroundJob::PushReminders => self.reminders.is_some(),
+            BackgroundJob::DatabaseBackup => self.backup.as_ref().is_some_and(|b| b.nightly_hour.is_some()),
+            _ => true,
+        }
+    }
+
+    pub fn interval_minutes(&self, job: BackgroundJob) -> u32 {
+        self.intervals
+            .get(&job)
+            .copied()
+            .unwrap_or(self.check_interval_minutes)
+            .max(self.check_interval_minutes)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct JobState {
+    running: bool,
+    last_started_at: Option<DateTime<Utc>>,
+    last_finished_at: Option<DateTime<Utc>>,
+    last_success: Option<bool>,
+    last_message: Option<String>,
+    run_count: u64,
+}
+
+/// Why a job could not be started
+#[derive(Debug, Error)]
+pub enum JobRunError {
+    #[error("Job is already running")]
+    AlreadyRunning,
+    #[error("Job is not available in this configuration")]
+    Unavailable,
+    #[error("The server is shutting down")]
+    ShuttingDown,
+}
+
+/// Runs background jobs and remembers the outcome of their last run,
+/// shared between the scheduler loop and the admin endpoints
+pub struct JobRunner {
+    pool: SqlitePool,
+    config: JobConfig,
+    push_client: reqwest::Client,
+    states: Mutex<HashMap<BackgroundJob, JobState>>,
+    /// Set once shutdown begins; no new job runs are started afterwards
+    shutting_down: AtomicBool,
+    /// Wakes the scheduler loop and `shutdown` waiters
+    shutdown_notify: Notify,
+}
+
+impl JobRunner {
+    pub fn new(pool: SqlitePool, config: JobConfig) -> Self {
+        Self {
+            pool,
+            config,
+            push_client: reqwest::Client::new(),
+            states: Mutex::new(HashMap::new()),
+            shutting_down: AtomicBool::new(false),
+            shutdown_notify: Notify::new(),
+        }
+    }
+
+    pub fn is_shutting_down(&self) -> bool {
+        self.shutting_down.load(Ordering::SeqCst)
+    }
+
+    fn any_running(&self) -> bool {
+        self.states.lock().unwrap().values().any(|state| state.running)
+    }
+
+    /// Stop starting jobs and wait until the ones in flight (e.g. a period
+    /// finalization) have finished, so no job is cut off halfway
+    pub async fn shutdown(&self) {
+        self.shutting_down.store(true, Ordering::SeqCst);
+        self.shutdown_notify.notify_waiters();
+
+        loop {
+            let finished = self.shutdown_notify.notified();
+            if !self.any_running() {
+                return;
+            }
+            log::info!("Waiting for running background jobs to finish");
+            finished.await;
+        }
+    }
+
+    pub fn config(&self) -> &JobConfig {
+        &self.config
+    }
+
+    /// Status of every job, in `BackgroundJob::ALL` order
+    pub fn statuses(&self) -> Vec<BackgroundJobStatus> {
+        BackgroundJob::ALL.into_iter().map(|job| self.status(job)).collect()
+    }
+
+    pub fn status(&self, job: BackgroundJob) -> BackgroundJobStatus {
+        let state = self.states.lock().unwrap().get(&job).cloned().unwrap_or_default();
+        BackgroundJobStatus {
+            job,
+            enabled: self.config.is_enabled(job),
+            interval_minutes: self.config.interval_minutes(job),
+            running: state.running,
+            last_started_at: state.last_started_at,
+            last_finished_at: state.last_finished_at,
+            last_duration_ms: match (state.last_started_at, state.last_finished_at) {
+                (Some(started), Some(finished)) if finished >= started => {
+                    Some((finished - started).num_milliseconds())
                 }
+                _ => None,
+            },
+            last_success: state.last_success,
+            last_message: state.last_message,
+            run_count: state.run_count,
+        }
+    }
+
+    /// Whether the scheduler should run the job now
+    fn is_due(&self, job: BackgroundJob, now: DateTime<Utc>) -> bool {
+        if !self.config.is_enabled(job) {
+            return false;
+        }
+        let states = self.states.lock().unwrap();
+        match states.get(&job).and_then(|s| s.last_started_at) {
+            // Allow a few seconds of drift so a job with the check interval runs every check
+            Some(last) => now - last >= Duration::minutes(self.config.interval_minutes(job) as i64) - Duration::seconds(5),
+            None => true,
+        }
+    }
+
+    /// Run a job now and record its outcome
+    pub async fn run(&self, job: BackgroundJob) -> Result<BackgroundJobStatus, JobRunError> {
+        if !self.config.is_available(job) {
+            return Err(JobRunError::Unavailable);
+        }
+        {
+            let mut states = self.states.lock().unwrap();
+            // Checked under the lock, so `shutdown` can't miss a job that is just starting
+            if self.is_shutting_down() {
+                return Err(JobRunError::ShuttingDown);
             }
-            Err(e) => {
-                log::error!("Error processing missed tasks: {}", e);
+            let state = states.entry(job).or_default();
+            if state.running {
+                return Err(JobRunError::AlreadyRunning);
             }
+            state.running = true;
+            state.last_started_at = Some(Utc::now());
         }
 
-        // Process auto-archive
-        match process_auto_archive(&pool).await {
-            Ok(report) => {
-                if report.tasks_archived > 0 {
-                    log::info!(
-                        "Auto-archive complete: checked {} tasks, archived {}",
-                        report.tasks_checked,
-                        report.tasks_archived
+        let result = self.execute(job).await;
+
+        {
+            let mut states = self.states.lock().unwrap();
+            let state = states.entry(job).or_default();
+            state.running = false;
+            state.last_finished_at = Some(Utc::now());
+            state.run_count += 1;
+            match result {
+                Ok(message) => {
+                    state.last_success = Some(true);
+                    state.last_message = Some(message);
+                }
+                Err(message) => {
+                    log::error!("Error processing {}: {}", job.as_str(), message);
+                    state.last_success = Some(false);
+                    state.last_message = Some(message);
+                }
+            }
+        }
+        self.shutdown_notify.notify_waiters();
+
+        Ok(self.status(job))
+    }
+
+    /// Execute one job; returns a summary of what it did
+    async fn execute(&self, job: BackgroundJob) -> Result<String, String> {
+        let pool = &self.pool;
+        match job {
+            BackgroundJob::MissedTasks => {
+                let report = process_missed_tasks(pool).await.map_err(|e| e.to_string())?;
+                let message = format!(
+                    "checked {} tasks, found {} missed, assigned {} punishments/{} rewards, deducted {}/added {} points",
+                    report.tasks_checked,
+                    report.missed_tasks,
+                    report.punishments_assigned,
+                    report.rewards_assigned,
+                    report.points_deducted,
+                    report.points_added
+                );
+                for (household_id, titles) in &report.overdue_tasks {
+                    chat_bridge::post_in_background(
+                        self.config.chat_bridge.as_ref(),
+                        pool,
+                        *household_id,
+                        chat_bridge::BridgeEvent::TaskOverdue,
+                        chat_bridge::overdue_text(titles),
                     );
+                }
+                if report.missed_tasks > 0 {
+                    log::info!("Missed task processing complete: {}", message);
                 } else {
-                    log::debug!(
-                        "Auto-archive check complete: checked {} tasks, none eligible",
-                        report.tasks_checked
-                    );
+                    log::debug!("Missed task check complete: {}", message);
                 }
+                Ok(message)
             }
-            Err(e) => {
-                log::error!("Error processing auto-archive: {}", e);
+            BackgroundJob::AutoArchive => {
+                let report = process_auto_archive(pool).await.map_err(|e| e.to_string())?;
+                let message = format!("checked {} tasks, archived {}", report.tasks_checked, report.tasks_archived);
+                if report.tasks_archived > 0 {
+                    log::info!("Auto-archive complete: {}", message);
+                } else {
+                    log::debug!("Auto-archive check complete: {}", message);
+                }
+                Ok(message)
             }
-        }
-
-        // Process period finalization (create failed/skipped records for ended periods)
-        match process_period_finalization(&pool).await {
-            Ok(report) => {
+            BackgroundJob::PeriodFinalization => {
+                // Create failed/skipped records for ended periods
+                let report = process_period_finalization(pool).await.map_err(|e| e.to_string())?;
                 let total = report.periods_completed + report.periods_failed + report.periods_skipped;
+                let message = format!(
+                    "checked {} tasks, finalized {} periods (completed: {}, failed: {}, skipped: {})",
+                    report.tasks_checked, total, report.periods_completed, report.periods_failed, report.periods_skipped
+                );
                 if total > 0 {
-                    log::info!(
-                        "Period finalization complete: checked {} tasks, finalized {} periods (completed: {}, failed: {}, skipped: {})",
-                        report.tasks_checked,
-                        total,
-                        report.periods_completed,
-                        report.periods_failed,
-                        report.periods_skipped
-                    );
+                    log::info!("Period finalization complete: {}", message);
                 } else {
-                    log::debug!(
-                        "Period finalization check complete: checked {} tasks, no periods to finalize",
-                        report.tasks_checked
-                    );
+                    log::debug!("Period finalization check complete: {}", message);
                 }
+                Ok(message)
             }
-            Err(e) => {
-                log::error!("Error processing period finalization: {}", e);
-            }
-        }
-
-        // Process Solo Mode expiration (deactivate after 48h cooldown)
-        match solo_mode::check_and_deactivate_expired_solo_modes(&pool).await {
-            Ok(deactivated) => {
+            BackgroundJob::SoloModeExpiry => {
+                // Deactivate Solo Mode after the 48h cooldown
+                let deactivated = solo_mode::check_and_deactivate_expired_solo_modes(pool)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                let message = format!("deactivated {} households", deactivated.len());
                 if !deactivated.is_empty() {
-                    log::info!(
-                        "Solo Mode expiration check complete: deactivated {} households",
-                        deactivated.len()
+                    log::info!("Solo Mode expiration check complete: {}", message);
+                } else {
+                    log::debug!("Solo Mode expiration check complete: {}", message);
+                }
+                Ok(message)
+            }
+            BackgroundJob::PushReminders => {
+                let Some(ref reminder_config) = self.config.reminders else {
+                    return Err("Web Push is not configured".to_string());
+                };
+                let report = notifications::process_due_reminders(pool, &self.push_client, reminder_config)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                let message = format!(
+                    "{} tasks due soon, sent {} reminders, removed {} expired subscriptions",
+                    report.tasks_checked, report.reminders_sent, report.subscriptions_removed
+                );
+                if report.reminders_sent > 0 || report.subscriptions_removed > 0 {
+                    log::info!("Push reminders complete: {}", message);
+                } else {
+                    log::debug!("Push reminder check complete: {}", message);
+                }
+                Ok(message)
+            }
+            BackgroundJob::WeeklySummary => {
+                // Post weekly summaries for households whose week is ending
+                let report = weekly_summary::process_weekly_summaries(pool, self.config.mail.as_ref(), Utc::now())
+                    .await
+                    .map_err(|e| e.to_string())?;
+                let message = format!(
+                    "checked {} households, created {} summaries, sent {} emails",
+                    report.households_checked, report.summaries_created, report.emails_sent
+                );
+                if report.summaries_created > 0 {
+                    log::info!("Weekly summaries complete: {}", message);
+                } else {
+                    log::debug!("Weekly summary check complete: {}", message);
+                }
+                Ok(message)
+            }
+            BackgroundJob::PointDecay => {
+                // Decay points of members who were inactive last week
+                let report = point_decay::process_point_decay(pool, Utc::now()).await.map_err(|e| e.to_string())?;
+                let message = format!(
+                    "checked {} households, processed {} weeks, decayed {} members by {} points",
+                    report.households_checked, report.weeks_processed, report.members_decayed, report.points_removed
+                );
+                if report.members_decayed > 0 {
+                    log::info!("Point decay complete: {}", message);
+                } else {
+                    log::debug!("Point decay check complete: {}", message);
+                }
+                Ok(message)
+            }
+            BackgroundJob::ChallengeClosing => {
+                // Close challenges whose end date has passed and reward the winners
+                let report = challenges::process_challenge_closing(pool, Utc::now()).await.map_err(|e| e.to_string())?;
+                let message = format!(
+                    "checked {} challenges, closed {}, assigned {} rewards",
+                    report.challenges_checked, report.challenges_closed, report.rewards_assigned
+                );
+                if report.challenges_closed > 0 {
+                    log::info!("Challenge closing complete: {}", message);
+                } else {
+                    log::debug!("Challenge closing check complete: {}", message);
+                }
+                Ok(message)
+            }
+            BackgroundJob::AutomationRules => {
+                // Assign rewards and punishments for rules whose trigger is met
+                let report = automation_rules::process_automation_rules(pool, Utc::now())
+                    .await
+                    .map_err(|e| e.to_string())?;
+                let message = format!("checked {} rules, fired {}", report.rules_checked, report.rules_fired);
+                if report.rules_fired > 0 {
+                    log::info!("Automation rules complete: {}", message);
+                } else {
+                    log::debug!("Automation rule check complete: {}", message);
+                }
+                Ok(message)
+            }
+            BackgroundJob::DatabaseBackup => {
+                let Some(ref settings) = self.config.backup else {
+                    return Err("Backups are not configured".to_string());
+                };
+                // Snapshot once per day after the configured hour
+                let backup = backup::run_nightly_backup(pool, settings, Utc::now())
+                    .await
+                    .map_err(|e| e.to_string())?;
+                Ok(match backup {
+                    Some(info) => format!(
+                        "wrote {} ({} bytes), removed {} old backups",
+                        info.file_name, info.size_bytes, info.removed_backups
+                    ),
+                    None => "no backup due".to_string(),
+                })
+            }
+            BackgroundJob::TrashPurge => {
+                // Delete trash items whose retention period has ended
+                let report = trash::purge_expired(pool, &self.config.upload_dir, Utc::now())
+                    .await
+                    .map_err(|e| e.to_string())?;
+                let message = format!(
+                    "purged {} tasks, {} notes, {} journal entries",
+                    report.tasks_purged, report.notes_purged, report.journal_entries_purged
+                );
+                if report.tasks_purged + report.notes_purged + report.journal_entries_purged > 0 {
+                    log::info!("Trash purge complete: {}", message);
+                } else {
+                    log::debug!("Trash purge check complete: {}", message);
+                }
+                Ok(message)
+            }
+            BackgroundJob::AnnouncementPublishing => {
+                // Announce scheduled announcements whose start time has come
+                let report = announcements::process_scheduled_announcements(pool, Utc::now())
+                    .await
+                    .map_err(|e| e.to_string())?;
+                for announcement in &report.published {
+                    chat_bridge::post_in_background(
+                        self.config.chat_bridge.as_ref(),
+                        pool,
+                        announcement.household_id,
+                        chat_bridge::BridgeEvent::AnnouncementCreated,
+                        chat_bridge::announcement_text(&announcement.title, &announcement.content),
                     );
+                }
+                let message = format!(
+                    "published {} announcements, skipped {} expired",
+                    report.published.len(),
+                    report.expired
+                );
+                if report.published.is_empty() {
+                    log::debug!("Announcement publishing check complete: {}", message);
                 } else {
-                    log::debug!("Solo Mode expiration check complete: no expired solo modes");
+                    log::info!("Announcement publishing complete: {}", message);
                 }
+                Ok(message)
             }
-            Err(e) => {
-                log::error!("Error processing Solo Mode expiration: {}", e);
+            BackgroundJob::EventRollup => {
+                // Sum the events recorded since the last run into the daily rollups
+                let rolled_up = events::refresh_rollups(pool).await.map_err(|e| e.to_string())?;
+                let message = format!("rolled up {} events", rolled_up);
+                log::debug!("Event rollup complete: {}", message);
+                Ok(message)
+            }
+            BackgroundJob::LeaderboardRefresh => {
+                // Recompute the stored leaderboards, so points changed by other jobs
+                // show up and the week and month windows roll over
+                let report = leaderboard::refresh_all(pool).await.map_err(|e| e.to_string())?;
+                let message = format!("refreshed {} households", report.households_refreshed);
+                log::debug!("Leaderboard refresh complete: {}", message);
+                Ok(message)
+            }
+            BackgroundJob::TaskEnd => {
+                // Archive tasks whose end date has passed or that reached their occurrences
+                let report = process_task_end(pool).await.map_err(|e| e.to_string())?;
+                let message = format!("checked {} tasks, archived {}", report.tasks_checked, report.tasks_archived);
+                if report.tasks_archived > 0 {
+                    log::info!("Task end complete: {}", message);
+                } else {
+                    log::debug!("Task end check complete: {}", message);
+                }
+                Ok(message)
+            }
+            BackgroundJob::AllowancePayout => {
+                // Pay out last month's allowance once the household month has ended
+                let report = allowance::process_allowance_payouts(pool, Utc::now())
+                    .await
+                    .map_err(|e| e.to_string())?;
+                let message = format!(
+                    "checked {} households, processed {} months, created {} payouts",
+                    report.households_checked, report.months_processed, report.payouts_created
+                );
+                if report.payouts_created > 0 {
+                    log::info!("Allowance payout complete: {}", message);
+                } else {
+                    log::debug!("Allowance payout check complete: {}", message);
+                }
+                Ok(message)
+            }
+        }
+    }
+}
+
+/// Start the background job scheduler
+/// This runs in a loop and runs every enabled job whose interval has passed
+pub async fn start_scheduler(runner: Arc<JobRunner>) {
+    let config = runner.config();
+    log::info!(
+        "Background job scheduler started. Checking jobs every {} minutes",
+        config.check_interval_minutes
+    );
+    for job in BackgroundJob::ALL {
+        if config.is_enabled(job) {
+            log::info!("Job {} runs every {} minutes", job.as_str(), config.interval_minutes(job));
+        } else {
+            log::info!("Job {} is disabled", job.as_str());
+        }
+    }
+
+    let interval = std::time::Duration::from_secs((config.check_interval_minutes * 60) as u64);
+
+    loop {
+        let shutdown = runner.shutdown_notify.notified();
+        if runner.is_shutting_down() {
+            break;
+        }
+        tokio::select! {
+            _ = time::sleep(interval) => {}
+            _ = shutdown => {}
+        }
+
+        for job in BackgroundJob::ALL {
+            if runner.is_shutting_down() {
+                break;
+            }
+            if runner.is_due(job, Utc::now()) {
+                // Errors are recorded in the job status and logged by `run`
+                let _ = runner.run(job).await;
             }
         }
     }
+    log::info!("Background job scheduler stopped");
 }
 
 /// Process all missed tasks
@@ -180,7 +607,7 @@ pub async fn start_scheduler(pool: Arc<SqlitePool>, config: JobConfig) {
 /// 1. Gets all tasks from all households
 /// 2. For each household, uses the household's timezone to determine "yesterday"
 /// 3. Checks if each task was due yesterday (in the household's timezone) and is now overdue
-/// 4. For missed tasks, deducts points and assigns punishments
+/// 4. For missed tasks, deducts points and assigns punishments (members on vacation are spared)
 pub async fn process_missed_tasks(pool: &SqlitePool) -> Result<MissedTaskReport, BackgroundJobError> {
     let now_utc = Utc::now();
 
@@ -190,15 +617,18 @@ pub async fn process_missed_tasks(pool: &SqlitePool) -> Result<MissedTaskReport,
     let mut points_deducted: i64 = 0;
     let mut rewards_assigned: i64 = 0;
     let mut points_added: i64 = 0;
+    let mut overdue_tasks: HashMap<Uuid, Vec<String>> = HashMap::new();
 
     // Get all tasks
-    let tasks: Vec<TaskRow> = sqlx::query_as("SELECT * FROM tasks")
+    let tasks: Vec<TaskRow> = sqlx::query_as("SELECT * FROM tasks WHERE deleted_at IS NULL")
         .fetch_all(pool)
         .await?;
 
-    // Cache household settings to avoid repeated lookups
+    // Cache household settings and memberships to avoid repeated lookups
     let mut settings_cache: std::collections::HashMap<Uuid, HouseholdSettings> =
         std::collections::HashMap::new();
+    let mut memberships_cache: std::collections::HashMap<Uuid, Vec<HouseholdMembership>> =
+        std::collections::HashMap::new();
 
     for task_row in tasks {
         let task = task_row.to_shared();
@@ -242,7 +672,7 @@ pub async fn process_missed_tasks(pool: &SqlitePool) -> Result<MissedTaskReport,
         }
 
         // Check if task was due yesterday
-        if !scheduler::is_task_due_on_date(&task, yesterday_local) {
+        if !scheduler::is_task_due_on_date(&task, yesterday_local, settings.week_start_day) {
             continue;
         }
 
@@ -254,15 +684,13 @@ pub async fn process_missed_tasks(pool: &SqlitePool) -> Result<MissedTaskReport,
         tasks_checked += 1;
 
         // Check if task was completed yesterday (in local timezone)
-        let completion_count = sqlx::query_scalar::<_, i64>(
-            "SELECT COUNT(*) FROM task_completions WHERE task_id = ? AND due_date = ?",
-        )
-        .bind(task.id.to_string())
-        .bind(yesterday_local)
-        .fetch_one(pool)
-        .await?;
+        let completions = completions_by_user(pool, &task.id, yesterday_local, yesterday_local).await?;
+
+        // With several assignees everyone has to complete it on their own
+        let assignees = tasks_service::get_assignee_ids(pool, &task.id).await?;
+        let individual = assignees.len() > 1;
 
-        if completion_count > 0 {
+        if !individual && !completions.is_empty() {
             // Task was completed, skip
             continue;
         }
@@ -281,15 +709,45 @@ pub async fn process_missed_tasks(pool: &SqlitePool) -> Result<MissedTaskReport,
             continue;
         }
 
-        // Task was not completed in time
-        missed_tasks += 1;
-
-        // Determine who to apply consequences to
-        let affected_users: Vec<Uuid> = if let Some(assigned_user_id) = task.assigned_user_id {
+        // Determine who to apply consequences to, leaving out members on vacation
+        let memberships = match memberships_cache.get(&task.household_id) {
+            Some(m) => m.clone(),
+            None => {
+                let m = get_household_memberships(pool, &task.household_id).await?;
+                memberships_cache.insert(task.household_id, m.clone());
+                m
+            }
+        };
+        let affected_users: Vec<Uuid> = if individual {
+            assignees
+                .iter()
+                .filter(|assignee| !completions.contains_key(assignee))
+                .copied()
+                .collect()
+        } else if let Some(assigned_user_id) = task.assigned_user_id {
             vec![assigned_user_id]
         } else {
-            get_household_member_ids(pool, &task.household_id).await?
+            memberships.iter().map(|m| m.user_id).collect()
         };
+        let affected_users: Vec<Uuid> = affected_users
+            .into_iter()
+            .filter(|user_id| {
+                !memberships
+                    .iter()
+                    .any(|m| m.user_id == *user_id && m.is_on_vacation(yesterday_local))
+            })
+            .collect();
+
+        if affected_users.is_empty() {
+            // Everyone responsible is on vacation (or has completed it) - no consequences
+            continue;
+        }
+
+        // Task was not completed in time
+        missed_tasks += 1;
+        if !task.habit_type.is_inverted() {
+            overdue_tasks.entry(task.household_id).or_default().push(task.title.clone());
+        }
 
         // Check if the user had a streak that was broken (for good habits)
         let had_previous_completion = sqlx::query_scalar::<_, i64>(
@@ -364,14 +822,37 @@ pub async fn process_missed_tasks(pool: &SqlitePool) -> Result<MissedTaskReport,
         points_deducted,
         rewards_assigned,
         points_added,
+        overdue_tasks,
     })
 }
 
-/// Get all member IDs for a household
-async fn get_household_member_ids(
+/// Number of completions per user with a due date in the given range
+async fn completions_by_user(
+    pool: &SqlitePool,
+    task_id: &Uuid,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<HashMap<Uuid, i64>, BackgroundJobError> {
+    let rows: Vec<(String, i64)> = sqlx::query_as(
+        "SELECT user_id, COUNT(*) FROM task_completions WHERE task_id = ? AND due_date >= ? AND due_date <= ? GROUP BY user_id",
+    )
+    .bind(task_id.to_string())
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|(user_id, count)| Uuid::parse_str(&user_id).ok().map(|id| (id, count)))
+        .collect())
+}
+
+/// Get all memberships for a household
+async fn get_household_memberships(
     pool: &SqlitePool,
     household_id: &Uuid,
-) -> Result<Vec<Uuid>, BackgroundJobError> {
+) -> Result<Vec<HouseholdMembership>, BackgroundJobError> {
     let memberships: Vec<MembershipRow> = sqlx::query_as(
         "SELECT * FROM household_memberships WHERE household_id = ?",
     )
@@ -379,10 +860,26 @@ async fn get_household_member_ids(
     .fetch_all(pool)
     .await?;
 
-    Ok(memberships
-        .into_iter()
-        .filter_map(|m| Uuid::parse_str(&m.user_id).ok())
-        .collect())
+    Ok(memberships.iter().map(|m| m.to_shared()).collect())
+}
+
+/// Whether a member is on vacation on the given date
+fn member_on_vacation(user_id: &Uuid, memberships: &[HouseholdMembership], date: NaiveDate) -> bool {
+    memberships.iter().any(|m| m.user_id == *user_id && m.is_on_vacation(date))
+}
+
+/// Check whether everyone responsible for a task is on vacation on the given date.
+/// Assigned tasks follow their assignees; unassigned tasks need the whole household away.
+fn responsible_members_on_vacation(
+    assignees: &[Uuid],
+    memberships: &[HouseholdMembership],
+    date: NaiveDate,
+) -> bool {
+    if assignees.is_empty() {
+        !memberships.is_empty() && memberships.iter().all(|m| m.is_on_vacation(date))
+    } else {
+        assignees.iter().all(|assignee| member_on_vacation(assignee, memberships, date))
+    }
 }
 
 /// Process auto-archiving of completed one-time and custom tasks
@@ -398,7 +895,7 @@ pub async fn process_auto_archive(pool: &SqlitePool) -> Result<AutoArchiveReport
     // Get all non-archived tasks that are candidates for auto-archive (OneTime or Custom)
     // Exclude suggestions (only process regular or approved tasks)
     let tasks: Vec<TaskRow> = sqlx::query_as(
-        "SELECT * FROM tasks WHERE archived = 0 AND (recurrence_type = 'onetime' OR recurrence_type = 'custom') AND (suggestion IS NULL OR suggestion = 'approved')",
+        "SELECT * FROM tasks WHERE archived = 0 AND deleted_at IS NULL AND (recurrence_type = 'onetime' OR recurrence_type = 'custom') AND (suggestion IS NULL OR suggestion = 'approved')",
     )
     .fetch_all(pool)
     .await?;
@@ -519,12 +1016,78 @@ async fn is_eligible_for_auto_archive(
     }
 }
 
+/// Archive tasks that are over: their end date has passed, or they have as many
+/// completed periods as their maximum number of occurrences
+pub async fn process_task_end(pool: &SqlitePool) -> Result<AutoArchiveReport, BackgroundJobError> {
+    let mut tasks_checked: u32 = 0;
+    let mut tasks_archived: u32 = 0;
+
+    let tasks: Vec<TaskRow> = sqlx::query_as(
+        "SELECT * FROM tasks WHERE archived = 0 AND deleted_at IS NULL AND (ends_on IS NOT NULL OR max_occurrences IS NOT NULL) AND (suggestion IS NULL OR suggestion = 'approved')",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut timezone_cache: HashMap<Uuid, chrono_tz::Tz> = HashMap::new();
+
+    for task_row in tasks {
+        let task = task_row.to_shared();
+        tasks_checked += 1;
+
+        let tz = match timezone_cache.get(&task.household_id) {
+            Some(tz) => *tz,
+            None => {
+                let settings = household_settings::get_or_create_settings(pool, &task.household_id)
+                    .await
+                    .unwrap_or_default();
+                let tz = scheduler::parse_timezone(&settings.timezone);
+                timezone_cache.insert(task.household_id, tz);
+                tz
+            }
+        };
+        let today = scheduler::today_in_timezone(tz);
+
+        // The period of the last day is finalized the day after it ends, so wait for that
+        let ended = task.ends_on.is_some_and(|ends_on| {
+            let (_, period_end) = scheduler::get_period_bounds(&task, ends_on);
+            period_end + Duration::days(1) < today
+        });
+        let used_up = match task.max_occurrences {
+            Some(max) => events::task_totals(pool, &task.id, None, None).await?.periods_completed >= i64::from(max),
+            None => false,
+        };
+        if !ended && !used_up {
+            continue;
+        }
+
+        tasks_service::archive_task(pool, &task.id).await?;
+        let _ = activity_logs::log_activity(
+            pool,
+            &task.household_id,
+            &Uuid::nil(), // System actor
+            None,
+            ActivityType::TaskAutoArchived,
+            Some("task"),
+            Some(&task.id),
+            Some(&task.title),
+        )
+        .await;
+        tasks_archived += 1;
+    }
+
+    Ok(AutoArchiveReport {
+        tasks_checked,
+        tasks_archived,
+    })
+}
+
 /// Process period finalization for all tasks
 /// This function:
 /// 1. Gets all scheduled tasks from all households (not OneTime)
 /// 2. For each household, uses the household's timezone to determine "yesterday"
 /// 3. For each task due yesterday without a period result, creates one
-/// 4. Status is: completed (if target met), failed (if not met), skipped (if paused/vacation)
+/// 4. Status is: completed (if target met), failed (if not met), skipped (if paused/vacation,
+///    including a vacation of the responsible members)
 pub async fn process_period_finalization(pool: &SqlitePool) -> Result<PeriodFinalizationReport, BackgroundJobError> {
     let mut tasks_checked: u32 = 0;
     let mut periods_completed: u32 = 0;
@@ -533,14 +1096,16 @@ pub async fn process_period_finalization(pool: &SqlitePool) -> Result<PeriodFina
 
     // Get all scheduled tasks (not OneTime, not archived, not pending suggestions)
     let tasks: Vec<TaskRow> = sqlx::query_as(
-        "SELECT * FROM tasks WHERE recurrence_type != 'onetime' AND archived = 0 AND (suggestion IS NULL OR suggestion = 'approved')",
+        "SELECT * FROM tasks WHERE recurrence_type != 'onetime' AND archived = 0 AND deleted_at IS NULL AND (suggestion IS NULL OR suggestion = 'approved')",
     )
     .fetch_all(pool)
     .await?;
 
-    // Cache household settings to avoid repeated lookups
+    // Cache household settings and memberships to avoid repeated lookups
     let mut settings_cache: std::collections::HashMap<Uuid, HouseholdSettings> =
         std::collections::HashMap::new();
+    let mut memberships_cache: std::collections::HashMap<Uuid, Vec<HouseholdMembership>> =
+        std::collections::HashMap::new();
 
     for task_row in tasks {
         let task = task_row.to_shared();
@@ -564,7 +1129,7 @@ pub async fn process_period_finalization(pool: &SqlitePool) -> Result<PeriodFina
         let yesterday_local = today_local - Duration::days(1);
 
         // Check if task was due yesterday
-        if !scheduler::is_task_due_on_date(&task, yesterday_local) {
+        if !scheduler::is_task_due_on_date(&task, yesterday_local, settings.week_start_day) {
             continue;
         }
 
@@ -582,21 +1147,58 @@ pub async fn process_period_finalization(pool: &SqlitePool) -> Result<PeriodFina
         tasks_checked += 1;
 
         // Count completions for this period
-        let completions_count: i64 = sqlx::query_scalar(
-            "SELECT COUNT(*) FROM task_completions WHERE task_id = ? AND due_date >= ? AND due_date <= ?",
-        )
-        .bind(task.id.to_string())
-        .bind(period_start)
-        .bind(period_end)
-        .fetch_one(pool)
-        .await?;
+        let completions = completions_by_user(pool, &task.id, period_start, period_end).await?;
+        let completions_count: i64 = completions.values().sum();
+        let assignees = tasks_service::get_assignee_ids(pool, &task.id).await?;
+
+        let memberships = match memberships_cache.get(&task.household_id) {
+            Some(m) => m.clone(),
+            None => {
+                let m = get_household_memberships(pool, &task.household_id).await?;
+                memberships_cache.insert(task.household_id, m.clone());
+                m
+            }
+        };
+
+        let paused_or_away = task.paused || household_settings::is_household_on_vacation(&settings, yesterday_local);
+
+        // With several assignees, each one's own period is recorded and the task's
+        // target counts as met once every assignee who is around has met it
+        let target_met = if assignees.len() > 1 {
+            let mut everyone_done = true;
+            for assignee in &assignees {
+                let own = completions.get(assignee).copied().unwrap_or(0);
+                let status = if paused_or_away || member_on_vacation(assignee, &memberships, yesterday_local) {
+                    PeriodStatus::Skipped
+                } else if own >= task.target_count as i64 {
+                    PeriodStatus::Completed
+                } else {
+                    everyone_done = false;
+                    PeriodStatus::Failed
+                };
+                let _ = period_results::finalize_assignee_period(
+                    pool,
+                    &task.id,
+                    assignee,
+                    period_start,
+                    period_end,
+                    status,
+                    own as i32,
+                    task.target_count,
+                )
+                .await;
+            }
+            everyone_done
+        } else {
+            completions_count >= task.target_count as i64
+        };
 
         // Determine status
-        let status = if task.paused || household_settings::is_household_on_vacation(&settings, yesterday_local) {
-            // Task was paused or household on vacation - skip
+        let status = if paused_or_away || responsible_members_on_vacation(&assignees, &memberships, yesterday_local) {
+            // Task was paused, household or responsible members on vacation - skip
             periods_skipped += 1;
             PeriodStatus::Skipped
-        } else if completions_count >= task.target_count as i64 {
+        } else if target_met {
             // Target was met
             periods_completed += 1;
             PeriodStatus::Completed
@@ -639,6 +1241,30 @@ mod tests {
         assert_eq!(config.check_interval_minutes, 1);
     }
 
+    #[test]
+    fn test_job_config_enabled_and_intervals() {
+        let config = JobConfig {
+            check_interval_minutes: 2,
+            disabled: HashSet::from([BackgroundJob::WeeklySummary]),
+            intervals: HashMap::from([(BackgroundJob::AutoArchive, 60), (BackgroundJob::MissedTasks, 1)]),
+            ..Default::default()
+        };
+
+        assert!(config.is_enabled(BackgroundJob::MissedTasks));
+        assert!(!config.is_enabled(BackgroundJob::WeeklySummary));
+        assert!(config.is_available(BackgroundJob::WeeklySummary));
+        // Push reminders need VAPID keys
+        assert!(!config.is_available(BackgroundJob::PushReminders));
+        assert!(!config.is_enabled(BackgroundJob::PushReminders));
+        // Nightly backups need an hour
+        assert!(!config.is_available(BackgroundJob::DatabaseBackup));
+
+        assert_eq!(config.interval_minutes(BackgroundJob::AutoArchive), 60);
+        assert_eq!(config.interval_minutes(BackgroundJob::PeriodFinalization), 2);
+        // Jobs can't run more often than the scheduler checks
+        assert_eq!(config.interval_minutes(BackgroundJob::MissedTasks), 2);
+    }
+
     #[test]
     fn test_background_job_error_display() {
         let err = BackgroundJobError::Database(sqlx::Error::RowNotFound);
@@ -655,6 +1281,44 @@ mod tests {
         assert_eq!(report.tasks_archived, 3);
     }
 
+    fn membership_with_vacation(
+        user_id: Uuid,
+        vacation_start: Option<NaiveDate>,
+        vacation_end: Option<NaiveDate>,
+    ) -> HouseholdMembership {
+        HouseholdMembership {
+            id: Uuid::new_v4(),
+            household_id: Uuid::new_v4(),
+            user_id,
+            role: shared::Role::Member,
+            points: 0,
+            joined_at: Utc::now(),
+            vacation_start,
+            vacation_end,
+        }
+    }
+
+    #[test]
+    fn test_responsible_members_on_vacation() {
+        let date = NaiveDate::from_ymd_opt(2024, 8, 10).unwrap();
+        let away = Uuid::new_v4();
+        let home = Uuid::new_v4();
+        let memberships = vec![
+            membership_with_vacation(away, NaiveDate::from_ymd_opt(2024, 8, 1), NaiveDate::from_ymd_opt(2024, 8, 15)),
+            membership_with_vacation(home, None, None),
+        ];
+
+        assert!(responsible_members_on_vacation(&[away], &memberships, date));
+        assert!(!responsible_members_on_vacation(&[home], &memberships, date));
+        // Several assignees: skipped only when all of them are away
+        assert!(!responsible_members_on_vacation(&[away, home], &memberships, date));
+
+        // Unassigned tasks are only skipped when the whole household is away
+        assert!(!responsible_members_on_vacation(&[], &memberships, date));
+        let all_away = vec![memberships[0].clone()];
+        assert!(responsible_members_on_vacation(&[], &all_away, date));
+    }
+
     async fn setup_test_db() -> SqlitePool {
         let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
 
@@ -707,6 +1371,7 @@ mod tests {
                 vacation_mode BOOLEAN NOT NULL DEFAULT 0,
                 vacation_start DATE,
                 vacation_end DATE,
+                permissions TEXT,
                 auto_archive_days INTEGER DEFAULT 7,
                 allow_task_suggestions BOOLEAN NOT NULL DEFAULT 1,
                 week_start_day INTEGER NOT NULL DEFAULT 0,
@@ -715,6 +1380,24 @@ mod tests {
                 solo_mode BOOLEAN NOT NULL DEFAULT 0,
                 solo_mode_exit_requested_at DATETIME,
                 solo_mode_previous_hierarchy_type TEXT,
+                weekly_summary_enabled BOOLEAN NOT NULL DEFAULT 0,
+                weekly_summary_email BOOLEAN NOT NULL DEFAULT 0,
+                point_decay_mode TEXT NOT NULL DEFAULT 'none',
+                point_decay_amount INTEGER NOT NULL DEFAULT 0,
+                purchase_approval_required BOOLEAN NOT NULL DEFAULT FALSE,
+                streak_freeze_price INTEGER NOT NULL DEFAULT 0,
+                default_requires_review BOOLEAN NOT NULL DEFAULT FALSE,
+                default_allow_exceed_target BOOLEAN NOT NULL DEFAULT TRUE,
+                good_habit_points_multiplier REAL NOT NULL DEFAULT 1.0,
+                bad_habit_points_multiplier REAL NOT NULL DEFAULT 1.0,
+                review_points_threshold INTEGER,
+                disabled_features TEXT NOT NULL DEFAULT '[]',
+                punishment_terminology TEXT NOT NULL DEFAULT 'punishments',
+                punishment_label_singular TEXT,
+                punishment_label_plural TEXT,
+                allowance_cents_per_point INTEGER NOT NULL DEFAULT 0,
+                allowance_point_handling TEXT NOT NULL DEFAULT 'reset',
+                version INTEGER NOT NULL DEFAULT 1,
                 updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
             )
             "#,
@@ -735,6 +1418,7 @@ mod tests {
                 is_purchasable BOOLEAN NOT NULL DEFAULT 0,
                 requires_confirmation BOOLEAN NOT NULL DEFAULT 0,
                 reward_type TEXT NOT NULL DEFAULT 'standard',
+                no_repeat_picks INTEGER NOT NULL DEFAULT 0,
                 created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
             )
             "#,
@@ -752,6 +1436,7 @@ mod tests {
                 description TEXT NOT NULL DEFAULT '',
                 requires_confirmation BOOLEAN NOT NULL DEFAULT 0,
                 punishment_type TEXT NOT NULL DEFAULT 'standard',
+                no_repeat_picks INTEGER NOT NULL DEFAULT 0,
                 created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
             )
             "#,
@@ -821,14 +1506,25 @@ mod tests {
                 points_reward INTEGER NOT NULL DEFAULT 0,
                 points_penalty INTEGER NOT NULL DEFAULT 0,
                 due_time TEXT,
+                effort_minutes INTEGER,
+                active_from TEXT,
+                active_until TEXT,
+                ends_on DATE,
+                max_occurrences INTEGER,
+                reviewer_type TEXT NOT NULL DEFAULT 'any_reviewer',
+                reviewer_id TEXT,
                 habit_type TEXT NOT NULL DEFAULT 'good',
                 category_id TEXT REFERENCES task_categories(id),
                 archived BOOLEAN NOT NULL DEFAULT 0,
                 paused BOOLEAN NOT NULL DEFAULT 0,
                 suggestion TEXT CHECK(suggestion IN ('suggested', 'approved', 'denied')),
                 suggested_by TEXT REFERENCES users(id),
+                postponed_from DATE,
+                postponed_to DATE,
                 created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
-                updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+                version INTEGER NOT NULL DEFAULT 1,
+                updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                deleted_at DATETIME
             )
             "#,
         )
@@ -854,6 +1550,48 @@ mod tests {
         .await
         .unwrap();
 
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS task_assignees (
+                task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+                user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+                PRIMARY KEY (task_id, user_id)
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS task_tags (
+                id TEXT PRIMARY KEY NOT NULL,
+                household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+                name TEXT NOT NULL,
+                color TEXT,
+                created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                UNIQUE(household_id, name)
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS task_tag_assignments (
+                task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+                tag_id TEXT NOT NULL REFERENCES task_tags(id) ON DELETE CASCADE,
+                PRIMARY KEY (task_id, tag_id)
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
         sqlx::query(
             r#"
             CREATE TABLE IF NOT EXISTS activity_logs (
@@ -1110,4 +1848,84 @@ mod tests {
         assert_eq!(report.tasks_checked, 0);
         assert_eq!(report.tasks_archived, 0);
     }
+
+    #[tokio::test]
+    async fn test_job_runner_records_status() {
+        let pool = setup_test_db().await;
+        let config = JobConfig {
+            intervals: HashMap::from([(BackgroundJob::AutoArchive, 60)]),
+            disabled: HashSet::from([BackgroundJob::SoloModeExpiry]),
+            ..Default::default()
+        };
+        let runner = JobRunner::new(pool, config);
+        let now = Utc::now();
+
+        let status = runner.status(BackgroundJob::AutoArchive);
+        assert!(status.enabled);
+        assert_eq!(status.interval_minutes, 60);
+        assert_eq!(status.run_count, 0);
+        assert!(status.last_success.is_none());
+        assert!(runner.is_due(BackgroundJob::AutoArchive, now));
+
+        let status = runner.run(BackgroundJob::AutoArchive).await.unwrap();
+        assert_eq!(status.run_count, 1);
+        assert_eq!(status.last_success, Some(true));
+        assert_eq!(status.last_message.as_deref(), Some("checked 0 tasks, archived 0"));
+        assert!(!status.running);
+
+        // Not due again until the interval has passed
+        assert!(!runner.is_due(BackgroundJob::AutoArchive, Utc::now()));
+        assert!(runner.is_due(BackgroundJob::AutoArchive, Utc::now() + Duration::minutes(60)));
+
+        // Disabled jobs are skipped by the scheduler but can be run manually
+        assert!(!runner.is_due(BackgroundJob::SoloModeExpiry, now));
+        assert!(matches!(runner.run(BackgroundJob::PushReminders).await, Err(JobRunError::Unavailable)));
+
+        assert_eq!(runner.statuses().len(), BackgroundJob::ALL.len());
+    }
+
+    #[tokio::test]
+    async fn test_job_runner_shutdown_stops_scheduler() {
+        let pool = setup_test_db().await;
+        let runner = Arc::new(JobRunner::new(pool, JobConfig::default()));
+        let scheduler = tokio::spawn(start_scheduler(runner.clone()));
+
+        runner.shutdown().await;
+        tokio::time::timeout(std::time::Duration::from_secs(5), scheduler)
+            .await
+            .expect("scheduler should stop after shutdown")
+            .unwrap();
+
+        assert!(matches!(runner.run(BackgroundJob::AutoArchive).await, Err(JobRunError::ShuttingDown)));
+        assert_eq!(runner.status(BackgroundJob::AutoArchive).run_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_process_task_end() {
+        let pool = crate::test_utils::create_test_pool().await;
+        let household_id = crate::test_utils::create_test_household(&pool).await;
+        let today = Utc::now().date_naive();
+        let task = |title: &str| crate::test_utils::create_test_task(&pool, &household_id).with_title(title);
+
+        let ended = task("Ended").with_ends_on(today - Duration::days(10)).build().await;
+        let ends_today = task("Ends today").with_ends_on(today).build().await;
+        let done = task("Done").with_max_occurrences(2).build().await;
+        let one_left = task("One left").with_max_occurrences(3).build().await;
+        for task_id in [&done.id, &one_left.id] {
+            for days_ago in 1..=2 {
+                let period_start = today - Duration::days(days_ago);
+                events::record_period_change(&pool, task_id, period_start, None, Some(PeriodStatus::Completed))
+                    .await
+                    .unwrap();
+            }
+        }
+
+        let report = process_task_end(&pool).await.unwrap();
+        assert_eq!(report.tasks_checked, 4);
+        assert_eq!(report.tasks_archived, 2);
+        for (task, archived) in [(ended, true), (ends_today, false), (done, true), (one_left, false)] {
+            let row = tasks_service::get_task(&pool, &task.id).await.unwrap().unwrap();
+            assert_eq!(row.archived, archived, "{}", task.title);
+        }
+    }
 }
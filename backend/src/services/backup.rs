@@ -0,0 +1,221 @@
+//! Consistent snapshots of the SQLite database
+//!
+//! `VACUUM INTO` writes a compacted copy of the live database in a single
+//! read transaction, so a snapshot is consistent even while requests keep
+//! writing. Snapshots are named after their UTC creation time; after each one
+//! the oldest are deleted so at most `BACKUP_RETENTION` remain.
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Timelike, Utc};
+use sqlx::SqlitePool;
+use thiserror::Error;
+
+use crate::config::Config;
+use shared::DatabaseBackupInfo;
+
+const FILE_PREFIX: &str = "household-";
+const FILE_SUFFIX: &str = ".db";
+/// Microseconds keep snapshots taken within the same second apart
+const TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S%.6f";
+
+#[derive(Debug, Error)]
+pub enum BackupError {
+    #[error("File error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+/// Where snapshots go and how many are kept
+#[derive(Debug, Clone)]
+pub struct BackupSettings {
+    pub dir: PathBuf,
+    /// Snapshots to keep; 0 keeps all
+    pub retention: usize,
+    /// UTC hour after which the nightly snapshot is taken; None disables the nightly job
+    pub nightly_hour: Option<u32>,
+}
+
+impl BackupSettings {
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            dir: PathBuf::from(&config.backup_dir),
+            retention: config.backup_retention,
+            nightly_hour: config.backup_nightly_hour,
+        }
+    }
+}
+
+fn file_name(created_at: DateTime<Utc>) -> String {
+    format!("{}{}{}", FILE_PREFIX, created_at.format(TIMESTAMP_FORMAT), FILE_SUFFIX)
+}
+
+/// Snapshot file names in the backup directory, newest first
+async fn list_backups(dir: &Path) -> Result<Vec<String>, BackupError> {
+    let mut names = Vec::new();
+    let mut entries = match tokio::fs::read_dir(dir).await {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(names),
+        Err(e) => return Err(e.into()),
+    };
+    while let Some(entry) = entries.next_entry().await? {
+        if let Some(name) = entry.file_name().to_str() {
+            if name.starts_with(FILE_PREFIX) && name.ends_with(FILE_SUFFIX) {
+                names.push(name.to_string());
+            }
+        }
+    }
+    // The timestamp format sorts chronologically
+    names.sort_unstable_by(|a, b| b.cmp(a));
+    Ok(names)
+}
+
+/// Delete the oldest snapshots beyond the retention count
+async fn prune_backups(settings: &BackupSettings) -> Result<u32, BackupError> {
+    if settings.retention == 0 {
+        return Ok(0);
+    }
+    let mut removed = 0;
+    for name in list_backups(&settings.dir).await?.iter().skip(settings.retention) {
+        tokio::fs::remove_file(settings.dir.join(name)).await?;
+        removed += 1;
+    }
+    Ok(removed)
+}
+
+/// Write a snapshot of the database to the backup directory
+pub async fn create_backup(pool: &SqlitePool, settings: &BackupSettings) -> Result<DatabaseBackupInfo, BackupError> {
+    tokio::fs::create_dir_all(&settings.dir).await?;
+
+    let created_at = Utc::now();
+    let file_name = file_name(created_at);
+    let path = settings.dir.join(&file_name);
+
+    sqlx::query("VACUUM INTO ?")
+        .bind(path.to_string_lossy().into_owned())
+        .execute(pool)
+        .await?;
+
+    let size_bytes = tokio::fs::metadata(&path).await?.len();
+    let removed_backups = prune_backups(settings).await?;
+
+    log::info!("Database backup written to {} ({} bytes)", path.display(), size_bytes);
+
+    Ok(DatabaseBackupInfo {
+        file_name,
+        size_bytes,
+        created_at,
+        removed_backups,
+    })
+}
+
+/// Take the nightly snapshot unless it is too early or one was already taken today (UTC)
+pub async fn run_nightly_backup(
+    pool: &SqlitePool,
+    settings: &BackupSettings,
+    now: DateTime<Utc>,
+) -> Result<Option<DatabaseBackupInfo>, BackupError> {
+    let Some(hour) = settings.nightly_hour else {
+        return Ok(None);
+    };
+    if now.hour() < hour {
+        return Ok(None);
+    }
+
+    let today_prefix = format!("{}{}", FILE_PREFIX, now.format("%Y%m%d"));
+    if list_backups(&settings.dir).await?.iter().any(|name| name.starts_with(&today_prefix)) {
+        return Ok(None);
+    }
+
+    create_backup(pool, settings).await.map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::run_migrations;
+    use chrono::TimeZone;
+    use uuid::Uuid;
+
+    /// `VACUUM INTO` from an in-memory database writes to memory as well, so
+    /// these tests use a database file
+    async fn create_file_pool(dir: &Path) -> SqlitePool {
+        tokio::fs::create_dir_all(dir).await.unwrap();
+        let pool = SqlitePool::connect(&format!("sqlite:{}?mode=rwc", dir.join("live.db").display()))
+            .await
+            .unwrap();
+        run_migrations(&pool).await;
+        pool
+    }
+
+    fn test_settings(retention: usize, nightly_hour: Option<u32>) -> BackupSettings {
+        BackupSettings {
+            dir: std::env::temp_dir().join(format!("haushalt-test-{}", Uuid::new_v4())),
+            retention,
+            nightly_hour,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_backup_and_prune() {
+        let settings = test_settings(2, None);
+        let pool = create_file_pool(&settings.dir.join("live")).await;
+
+        // Older snapshots that retention should remove
+        for day in ["20240101", "20240102"] {
+            tokio::fs::write(settings.dir.join(format!("household-{}-030000.db", day)), b"old").await.unwrap();
+        }
+        tokio::fs::write(settings.dir.join("notes.txt"), b"keep").await.unwrap();
+
+        let info = create_backup(&pool, &settings).await.unwrap();
+        assert!(info.size_bytes > 0);
+        assert_eq!(info.removed_backups, 1);
+
+        // The snapshot is a readable SQLite database with the schema
+        let url = format!("sqlite:{}", settings.dir.join(&info.file_name).display());
+        let snapshot = SqlitePool::connect(&url).await.unwrap();
+        let tables: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM sqlite_master WHERE name = 'households'")
+            .fetch_one(&snapshot)
+            .await
+            .unwrap();
+        assert_eq!(tables, 1);
+        snapshot.close().await;
+
+        let names = list_backups(&settings.dir).await.unwrap();
+        assert_eq!(names, vec![info.file_name.clone(), "household-20240102-030000.db".to_string()]);
+        assert!(settings.dir.join("notes.txt").exists());
+
+        // A second snapshot right after the first gets its own file
+        let next = create_backup(&pool, &settings).await.unwrap();
+        assert_ne!(next.file_name, info.file_name);
+        assert_eq!(list_backups(&settings.dir).await.unwrap(), vec![next.file_name, info.file_name]);
+
+        pool.close().await;
+        tokio::fs::remove_dir_all(&settings.dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_nightly_backup_runs_once_per_day() {
+        let live_dir = std::env::temp_dir().join(format!("haushalt-test-{}", Uuid::new_v4()));
+        let pool = create_file_pool(&live_dir).await;
+        let now = Utc::now();
+
+        let disabled = test_settings(7, None);
+        assert!(run_nightly_backup(&pool, &disabled, now).await.unwrap().is_none());
+
+        // Too early in the day
+        let settings = test_settings(7, Some(23));
+        let early = Utc.from_utc_datetime(&now.date_naive().and_hms_opt(22, 0, 0).unwrap());
+        assert!(run_nightly_backup(&pool, &settings, early).await.unwrap().is_none());
+
+        let settings = test_settings(7, Some(0));
+        assert!(run_nightly_backup(&pool, &settings, now).await.unwrap().is_some());
+        assert!(run_nightly_backup(&pool, &settings, now).await.unwrap().is_none());
+        assert_eq!(list_backups(&settings.dir).await.unwrap().len(), 1);
+
+        pool.close().await;
+        tokio::fs::remove_dir_all(&settings.dir).await.unwrap();
+        tokio::fs::remove_dir_all(&live_dir).await.unwrap();
+    }
+}
@@ -0,0 +1,671 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::CalendarFeedTokenRow;
+use crate::services::{household_settings, scheduler};
+use shared::{CalendarFeedToken, RecurrenceType, RecurrenceValue, Task, TaskOccurrence};
+
+#[derive(Debug, Error)]
+pub enum CalendarError {
+    #[error("Invalid feed token")]
+    InvalidToken,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+/// Maximum number of days searched when looking for the first occurrence of a task
+const FIRST_OCCURRENCE_SEARCH_DAYS: i64 = 366;
+
+/// Longest date range expanded into occurrences by a single request
+pub const MAX_OCCURRENCE_RANGE_DAYS: i64 = 62;
+
+/// Generate a new random feed token
+fn generate_feed_token() -> String {
+    format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+}
+
+/// Get the user's feed token, creating one if it doesn't exist yet
+pub async fn get_or_create_feed_token(
+    pool: &SqlitePool,
+    user_id: &Uuid,
+) -> Result<CalendarFeedToken, CalendarError> {
+    let existing: Option<CalendarFeedTokenRow> =
+        sqlx::query_as("SELECT * FROM calendar_feed_tokens WHERE user_id = ?")
+            .bind(user_id.to_string())
+            .fetch_optional(pool)
+            .await?;
+
+    if let Some(row) = existing {
+        return Ok(row.to_shared());
+    }
+
+    regenerate_feed_token(pool, user_id).await
+}
+
+/// Replace the user's feed token, invalidating all existing subscriptions
+pub async fn regenerate_feed_token(
+    pool: &SqlitePool,
+    user_id: &Uuid,
+) -> Result<CalendarFeedToken, CalendarError> {
+    let token = generate_feed_token();
+    let now = Utc::now();
+
+    sqlx::query(
+        r#"
+        INSERT INTO calendar_feed_tokens (user_id, token, created_at)
+        VALUES (?, ?, ?)
+        ON CONFLICT(user_id) DO UPDATE SET token = excluded.token, created_at = excluded.created_at
+        "#,
+    )
+    .bind(user_id.to_string())
+    .bind(&token)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    Ok(CalendarFeedToken {
+        token,
+        created_at: now,
+    })
+}
+
+/// Resolve a feed token to the user it belongs to
+pub async fn get_user_id_by_feed_token(
+    pool: &SqlitePool,
+    token: &str,
+) -> Result<Uuid, CalendarError> {
+    let row: Option<CalendarFeedTokenRow> =
+        sqlx::query_as("SELECT * FROM calendar_feed_tokens WHERE token = ?")
+            .bind(token)
+            .fetch_optional(pool)
+            .await?;
+
+    row.and_then(|r| Uuid::parse_str(&r.user_id).ok())
+        .ok_or(CalendarError::InvalidToken)
+}
+
+/// Occurrences of the household's tasks between `from` and `to` (inclusive),
+/// together with the completions recorded for each of them
+pub async fn list_occurrences(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    tasks: &[Task],
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<TaskOccurrence>, CalendarError> {
+    let rows: Vec<(String, NaiveDate, i64)> = sqlx::query_as(
+        r#"
+        SELECT c.task_id, c.due_date, COUNT(*)
+        FROM task_completions c
+        JOIN tasks t ON t.id = c.task_id
+        WHERE t.household_id = ? AND c.due_date BETWEEN ? AND ? AND c.status != 'rejected'
+        GROUP BY c.task_id, c.due_date
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await?;
+
+    let completions = rows
+        .into_iter()
+        .filter_map(|(task_id, date, count)| Some(((Uuid::parse_str(&task_id).ok()?, date), count as i32)))
+        .collect();
+
+    let week_start_day = household_settings::week_start_day(pool, household_id).await?;
+
+    Ok(expand_occurrences(tasks, &completions, from, to, week_start_day))
+}
+
+/// Expand task recurrences into one occurrence per due date in `from..=to`.
+///
+/// Paused and one-time tasks have no schedule of their own; they only show up
+/// on dates where they were completed.
+pub fn expand_occurrences(
+    tasks: &[Task],
+    completions: &HashMap<(Uuid, NaiveDate), i32>,
+    from: NaiveDate,
+    to: NaiveDate,
+    week_start_day: i32,
+) -> Vec<TaskOccurrence> {
+    let mut occurrences = Vec::new();
+    for task in tasks {
+        let scheduled = !task.paused && !task.archived && task.recurrence_type != RecurrenceType::OneTime;
+        for date in from.iter_days().take_while(|d| *d <= to) {
+            let count = completions.get(&(task.id, date)).copied().unwrap_or(0);
+            if count > 0 || (scheduled && scheduler::is_task_due_on_date(task, date, week_start_day)) {
+                occurrences.push(TaskOccurrence {
+                    task_id: task.id,
+                    title: task.title.clone(),
+                    date,
+                    due_time: task.due_time.clone(),
+                    habit_type: task.habit_type,
+                    target_count: task.target_count,
+                    completions: count,
+                });
+            }
+        }
+    }
+
+    occurrences.sort_by(|a, b| {
+        (a.date, a.due_time.is_none(), &a.due_time, &a.title).cmp(&(b.date, b.due_time.is_none(), &b.due_time, &b.title))
+    });
+    occurrences
+}
+
+/// Render tasks as an iCalendar (RFC 5545) document.
+///
+/// Each schedulable task becomes one VEVENT with an RRULE (or RDATE list for custom dates).
+/// Tasks with a due time are emitted as timed events in the household timezone,
+/// all other tasks as all-day events. One-time, paused and archived tasks are skipped.
+/// `week_start_day` is the household's week start (0 = Monday), which decides
+/// the weeks that every-N-weeks rules count.
+pub fn render_ical_feed(
+    calendar_name: &str,
+    tasks: &[Task],
+    timezone: &str,
+    week_start_day: i32,
+    generated_at: DateTime<Utc>,
+) -> String {
+    let mut lines: Vec<String> = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Haushalt//Household Tasks//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        format!("X-WR-CALNAME:{}", escape_text(calendar_name)),
+        format!("X-WR-TIMEZONE:{}", timezone),
+    ];
+
+    for task in tasks {
+        lines.extend(render_task_event(task, timezone, week_start_day, generated_at));
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    lines
+        .iter()
+        .map(|line| fold_line(line))
+        .collect::<Vec<_>>()
+        .join("\r\n")
+        + "\r\n"
+}
+
+/// Render a single task as VEVENT lines (empty if the task has no schedule)
+fn render_task_event(task: &Task, timezone: &str, week_start_day: i32, generated_at: DateTime<Utc>) -> Vec<String> {
+    if task.archived || task.paused {
+        return Vec::new();
+    }
+
+    let (first_date, recurrence) = match task_schedule(task, week_start_day) {
+        Some(schedule) => schedule,
+        None => return Vec::new(),
+    };
+
+    let mut lines = vec![
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{}@haushalt", task.id),
+        format!("DTSTAMP:{}", format_utc(generated_at)),
+        format!("LAST-MODIFIED:{}", format_utc(task.updated_at)),
+        format!("SUMMARY:{}", escape_text(&task.title)),
+    ];
+
+    if !task.description.is_empty() {
+        lines.push(format!("DESCRIPTION:{}", escape_text(&task.description)));
+    }
+    if let Some(ref category) = task.category_name {
+        lines.push(format!("CATEGORIES:{}", escape_text(category)));
+    }
+
+    match task.due_time.as_deref() {
+        Some(due_time) => {
+            let time = scheduler::parse_due_time(Some(due_time));
+            lines.push(format!(
+                "DTSTART;TZID={}:{}T{}",
+                timezone,
+                first_date.format("%Y%m%d"),
+                time.format("%H%M%S")
+            ));
+            lines.push("DURATION:PT15M".to_string());
+        }
+        None => {
+            lines.push(format!("DTSTART;VALUE=DATE:{}", first_date.format("%Y%m%d")));
+        }
+    }
+
+    match recurrence {
+        Recurrence::Rule(rule) => {
+            // UNTIL must be a UTC date-time when DTSTART has a time
+            let until = task.ends_on.and_then(|ends_on| match task.due_time {
+                Some(_) => scheduler::get_task_deadline_utc(task, ends_on, timezone).map(format_utc),
+                None => Some(ends_on.format("%Y%m%d").to_string()),
+            });
+            match until {
+                Some(until) => lines.push(format!("RRULE:{};UNTIL={}", rule, until)),
+                None => lines.push(format!("RRULE:{}", rule)),
+            }
+        }
+        Recurrence::Dates(dates) if !dates.is_empty() => {
+            let value_type = if task.due_time.is_some() {
+                format!("TZID={}", timezone)
+            } else {
+                "VALUE=DATE".to_string()
+            };
+            let time_suffix = task
+                .due_time
+                .as_deref()
+                .map(|t| format!("T{}", scheduler::parse_due_time(Some(t)).format("%H%M%S")))
+                .unwrap_or_default();
+            let formatted: Vec<String> = dates
+                .iter()
+                .map(|d| format!("{}{}", d.format("%Y%m%d"), time_suffix))
+                .collect();
+            lines.push(format!("RDATE;{}:{}", value_type, formatted.join(",")));
+        }
+        Recurrence::Dates(_) => {}
+    }
+
+    lines.push("END:VEVENT".to_string());
+    lines
+}
+
+/// How an event repeats after its first occurrence
+#[derive(Debug, PartialEq)]
+enum Recurrence {
+    /// An RRULE value, e.g. `FREQ=WEEKLY;BYDAY=MO`
+    Rule(String),
+    /// Additional explicit dates (RDATE)
+    Dates(Vec<NaiveDate>),
+}
+
+/// Determine the first occurrence and the repetition rule of a task
+fn task_schedule(task: &Task, week_start_day: i32) -> Option<(NaiveDate, Recurrence)> {
+    match task.recurrence_type {
+        RecurrenceType::OneTime => None,
+
+        RecurrenceType::Custom => {
+            let mut dates = match &task.recurrence_value {
+                Some(RecurrenceValue::CustomDates(dates)) => dates.clone(),
+                _ => return None,
+            };
+            dates.sort();
+            dates.dedup();
+            let first = *dates.first()?;
+            Some((first, Recurrence::Dates(dates.into_iter().skip(1).collect())))
+        }
+
+        RecurrenceType::Daily => {
+            let rule = match &task.recurrence_value {
+                Some(RecurrenceValue::DayInterval(days)) if *days > 1 => format!("FREQ=DAILY;INTERVAL={}", days),
+                _ => "FREQ=DAILY".to_string(),
+            };
+            Some((first_occurrence(task, week_start_day)?, Recurrence::Rule(rule)))
+        }
+
+        RecurrenceType::Weekly => {
+            if let Some(RecurrenceValue::WeekInterval { weeks, weekday }) = &task.recurrence_value {
+                // WKST makes calendar apps count the weeks from the household's week start
+                let wkst = ical_weekday(((week_start_day + 1) % 7) as u8);
+                let rule = format!("FREQ=WEEKLY;INTERVAL={};BYDAY={};WKST={}", weeks, ical_weekday(*weekday), wkst);
+                return Some((first_occurrence(task, week_start_day)?, Recurrence::Rule(rule)));
+            }
+            let day = match &task.recurrence_value {
+                Some(RecurrenceValue::WeekDay(day)) => *day,
+                _ => task.created_at.weekday().num_days_from_sunday() as u8,
+            };
+            let first = first_date_matching(task, |d| d.weekday().num_days_from_sunday() as u8 == day)?;
+            Some((first, Recurrence::Rule(format!("FREQ=WEEKLY;BYDAY={}", ical_weekday(day)))))
+        }
+
+        RecurrenceType::Weekdays => {
+            let mut days = match &task.recurrence_value {
+                Some(RecurrenceValue::Weekdays(days)) => days.clone(),
+                _ => vec![1, 2, 3, 4, 5],
+            };
+            days.sort();
+            days.dedup();
+            let by_day: Vec<&str> = days.iter().map(|d| ical_weekday(*d)).collect();
+            Some((
+                first_occurrence(task, week_start_day)?,
+                Recurrence::Rule(format!("FREQ=WEEKLY;BYDAY={}", by_day.join(","))),
+            ))
+        }
+
+        RecurrenceType::Monthly => {
+            if let Some(RecurrenceValue::MonthWeekday { ordinal, weekday }) = &task.recurrence_value {
+                let rule = format!("FREQ=MONTHLY;BYDAY={}{}", ordinal, ical_weekday(*weekday));
+                return Some((first_occurrence(task, week_start_day)?, Recurrence::Rule(rule)));
+            }
+            let day = match &task.recurrence_value {
+                Some(RecurrenceValue::MonthDay(day)) => *day as u32,
+                _ => task.created_at.day(),
+            };
+            // Days beyond the 28th fall back to the last day of shorter months,
+            // mirroring the scheduler's clamping behaviour.
+            let rule = if day > 28 {
+                format!("FREQ=MONTHLY;BYMONTHDAY={},-1;BYSETPOS=1", day)
+            } else {
+                format!("FREQ=MONTHLY;BYMONTHDAY={}", day)
+            };
+            Some((first_occurrence(task, week_start_day)?, Recurrence::Rule(rule)))
+        }
+    }
+}
+
+/// First date on or after task creation where the scheduler considers the task due
+fn first_occurrence(task: &Task, week_start_day: i32) -> Option<NaiveDate> {
+    first_date_matching(task, |d| scheduler::is_task_due_on_date(task, d, week_start_day))
+}
+
+fn first_date_matching(task: &Task, predicate: impl Fn(NaiveDate) -> bool) -> Option<NaiveDate> {
+    let start = task.created_at.date_naive();
+    (0..FIRST_OCCURRENCE_SEARCH_DAYS)
+        .map(|offset| start + chrono::Duration::days(offset))
+        .find(|d| predicate(*d))
+}
+
+/// Map the app's weekday numbering (0 = Sunday) to iCalendar day codes
+fn ical_weekday(day: u8) -> &'static str {
+    match day {
+        0 => "SU",
+        1 => "MO",
+        2 => "TU",
+        3 => "WE",
+        4 => "TH",
+        5 => "FR",
+        6 => "SA",
+        _ => "MO",
+    }
+}
+
+fn format_utc(dt: DateTime<Utc>) -> String {
+    dt.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escape a TEXT value according to RFC 5545 section 3.3.11
+fn escape_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Fold content lines longer than 75 octets (RFC 5545 section 3.1)
+fn fold_line(line: &str) -> String {
+    const MAX_OCTETS: usize = 75;
+
+    if line.len() <= MAX_OCTETS {
+        return line.to_string();
+    }
+
+    let mut folded = String::with_capacity(line.len() + line.len() / MAX_OCTETS * 3);
+    let mut current_len = 0;
+    for ch in line.chars() {
+        let ch_len = ch.len_utf8();
+        if current_len + ch_len > MAX_OCTETS {
+            folded.push_str("\r\n ");
+            // The leading space counts towards the continuation line length
+            current_len = 1;
+        }
+        folded.push(ch);
+        current_len += ch_len;
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use shared::{HabitType, ReviewerType};
+
+    fn create_task(recurrence_type: RecurrenceType, recurrence_value: Option<RecurrenceValue>) -> Task {
+        Task {
+            id: Uuid::new_v4(),
+            household_id: Uuid::new_v4(),
+            title: "Take out trash".to_string(),
+            description: String::new(),
+            recurrence_type,
+            recurrence_value,
+            assigned_user_id: None,
+            assignee_ids: Vec::new(),
+            target_count: 1,
+            time_period: None,
+            allow_exceed_target: true,
+            requires_review: false,
+            reviewer_type: ReviewerType::AnyReviewer,
+            reviewer_id: None,
+            points_reward: None,
+            points_penalty: None,
+            due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
+            habit_type: HabitType::Good,
+            category_id: None,
+            category_name: None,
+            tag_ids: Vec::new(),
+            archived: false,
+            paused: false,
+            suggestion: None,
+            suggested_by: None,
+            postponed_from: None,
+            postponed_to: None,
+            // 2024-01-01 is a Monday
+            version: 1,
+            created_at: Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_calendar_error_display() {
+        assert_eq!(CalendarError::InvalidToken.to_string(), "Invalid feed token");
+    }
+
+    #[test]
+    fn test_escape_text() {
+        assert_eq!(escape_text("a,b;c\\d\ne"), "a\\,b\\;c\\\\d\\ne");
+    }
+
+    #[test]
+    fn test_fold_line_short_unchanged() {
+        assert_eq!(fold_line("SUMMARY:Short"), "SUMMARY:Short");
+    }
+
+    #[test]
+    fn test_fold_line_long() {
+        let line = format!("SUMMARY:{}", "x".repeat(100));
+        let folded = fold_line(&line);
+        let parts: Vec<&str> = folded.split("\r\n").collect();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].len(), 75);
+        assert!(parts[1].starts_with(' '));
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+
+    #[test]
+    fn test_fold_line_respects_utf8_boundaries() {
+        let line = format!("SUMMARY:{}", "ä".repeat(60));
+        let folded = fold_line(&line);
+        assert_eq!(folded.replace("\r\n ", ""), line);
+        assert!(folded.split("\r\n").all(|part| part.len() <= 75));
+    }
+
+    #[test]
+    fn test_schedule_daily() {
+        let task = create_task(RecurrenceType::Daily, None);
+        let (first, rule) = task_schedule(&task, 0).unwrap();
+        assert_eq!(first, NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        assert_eq!(rule, Recurrence::Rule("FREQ=DAILY".to_string()));
+    }
+
+    #[test]
+    fn test_schedule_weekly_on_saturday() {
+        let task = create_task(RecurrenceType::Weekly, Some(RecurrenceValue::WeekDay(6)));
+        let (first, rule) = task_schedule(&task, 0).unwrap();
+        assert_eq!(first, NaiveDate::from_ymd_opt(2024, 1, 6).unwrap());
+        assert_eq!(rule, Recurrence::Rule("FREQ=WEEKLY;BYDAY=SA".to_string()));
+    }
+
+    #[test]
+    fn test_schedule_weekdays() {
+        let task = create_task(RecurrenceType::Weekdays, Some(RecurrenceValue::Weekdays(vec![5, 1, 3])));
+        let (first, rule) = task_schedule(&task, 0).unwrap();
+        assert_eq!(first, NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        assert_eq!(rule, Recurrence::Rule("FREQ=WEEKLY;BYDAY=MO,WE,FR".to_string()));
+    }
+
+    #[test]
+    fn test_schedule_monthly_clamps_late_days() {
+        let task = create_task(RecurrenceType::Monthly, Some(RecurrenceValue::MonthDay(31)));
+        let (first, rule) = task_schedule(&task, 0).unwrap();
+        assert_eq!(first, NaiveDate::from_ymd_opt(2024, 1, 31).unwrap());
+        assert_eq!(rule, Recurrence::Rule("FREQ=MONTHLY;BYMONTHDAY=31,-1;BYSETPOS=1".to_string()));
+
+        let task = create_task(RecurrenceType::Monthly, Some(RecurrenceValue::MonthDay(15)));
+        let (_, rule) = task_schedule(&task, 0).unwrap();
+        assert_eq!(rule, Recurrence::Rule("FREQ=MONTHLY;BYMONTHDAY=15".to_string()));
+    }
+
+    #[test]
+    fn test_schedule_intervals_and_nth_weekday() {
+        let task = create_task(RecurrenceType::Daily, Some(RecurrenceValue::DayInterval(3)));
+        let (first, rule) = task_schedule(&task, 0).unwrap();
+        assert_eq!(first, NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        assert_eq!(rule, Recurrence::Rule("FREQ=DAILY;INTERVAL=3".to_string()));
+
+        let task = create_task(RecurrenceType::Weekly, Some(RecurrenceValue::WeekInterval { weeks: 2, weekday: 2 }));
+        let (first, rule) = task_schedule(&task, 0).unwrap();
+        assert_eq!(first, NaiveDate::from_ymd_opt(2024, 1, 2).unwrap());
+        assert_eq!(rule, Recurrence::Rule("FREQ=WEEKLY;INTERVAL=2;BYDAY=TU;WKST=MO".to_string()));
+
+        // With Sunday weeks the first Sunday after creation lies in the first active week
+        let task = create_task(RecurrenceType::Weekly, Some(RecurrenceValue::WeekInterval { weeks: 2, weekday: 0 }));
+        let (first, rule) = task_schedule(&task, 6).unwrap();
+        assert_eq!(first, NaiveDate::from_ymd_opt(2024, 1, 14).unwrap());
+        assert_eq!(rule, Recurrence::Rule("FREQ=WEEKLY;INTERVAL=2;BYDAY=SU;WKST=SU".to_string()));
+
+        let task = create_task(RecurrenceType::Monthly, Some(RecurrenceValue::MonthWeekday { ordinal: 1, weekday: 6 }));
+        let (first, rule) = task_schedule(&task, 0).unwrap();
+        assert_eq!(first, NaiveDate::from_ymd_opt(2024, 1, 6).unwrap());
+        assert_eq!(rule, Recurrence::Rule("FREQ=MONTHLY;BYDAY=1SA".to_string()));
+    }
+
+    #[test]
+    fn test_schedule_custom_dates() {
+        let d1 = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        let d2 = NaiveDate::from_ymd_opt(2024, 2, 5).unwrap();
+        let task = create_task(RecurrenceType::Custom, Some(RecurrenceValue::CustomDates(vec![d1, d2])));
+        let (first, rule) = task_schedule(&task, 0).unwrap();
+        assert_eq!(first, d2);
+        assert_eq!(rule, Recurrence::Dates(vec![d1]));
+    }
+
+    #[test]
+    fn test_schedule_onetime_is_skipped() {
+        let task = create_task(RecurrenceType::OneTime, None);
+        assert!(task_schedule(&task, 0).is_none());
+    }
+
+    #[test]
+    fn test_render_ical_feed_all_day_event() {
+        let task = create_task(RecurrenceType::Daily, None);
+        let now = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+        let ical = render_ical_feed("Home", std::slice::from_ref(&task), "Europe/Berlin", 0, now);
+
+        assert!(ical.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ical.ends_with("END:VCALENDAR\r\n"));
+        assert!(ical.contains("X-WR-CALNAME:Home\r\n"));
+        assert!(ical.contains(&format!("UID:{}@haushalt\r\n", task.id)));
+        assert!(ical.contains("DTSTAMP:20240501T120000Z\r\n"));
+        assert!(ical.contains("DTSTART;VALUE=DATE:20240101\r\n"));
+        assert!(ical.contains("RRULE:FREQ=DAILY\r\n"));
+        assert!(ical.contains("SUMMARY:Take out trash\r\n"));
+    }
+
+    #[test]
+    fn test_render_ical_feed_timed_event() {
+        let mut task = create_task(RecurrenceType::Weekly, Some(RecurrenceValue::WeekDay(1)));
+        task.due_time = Some("08:30".to_string());
+        let ical = render_ical_feed("Home", &[task], "Europe/Berlin", 0, Utc::now());
+
+        assert!(ical.contains("DTSTART;TZID=Europe/Berlin:20240101T083000\r\n"));
+        assert!(ical.contains("DURATION:PT15M\r\n"));
+    }
+
+    #[test]
+    fn test_render_ical_feed_until_end_date() {
+        let mut task = create_task(RecurrenceType::Weekly, Some(RecurrenceValue::WeekDay(1)));
+        task.ends_on = Some(NaiveDate::from_ymd_opt(2024, 3, 25).unwrap());
+        let ical = render_ical_feed("Home", std::slice::from_ref(&task), "Europe/Berlin", 0, Utc::now());
+        assert!(ical.contains("RRULE:FREQ=WEEKLY;BYDAY=MO;UNTIL=20240325\r\n"));
+
+        task.due_time = Some("08:30".to_string());
+        let ical = render_ical_feed("Home", &[task], "Europe/Berlin", 0, Utc::now());
+        assert!(ical.contains("RRULE:FREQ=WEEKLY;BYDAY=MO;UNTIL=20240325T073000Z\r\n"));
+    }
+
+    #[test]
+    fn test_render_ical_feed_skips_paused_and_onetime() {
+        let mut paused = create_task(RecurrenceType::Daily, None);
+        paused.paused = true;
+        let onetime = create_task(RecurrenceType::OneTime, None);
+        let ical = render_ical_feed("Home", &[paused, onetime], "UTC", 0, Utc::now());
+
+        assert!(!ical.contains("BEGIN:VEVENT"));
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 1, day).unwrap()
+    }
+
+    #[test]
+    fn test_expand_occurrences_follows_recurrence() {
+        // Mondays in January 2024: 1, 8, 15, 22, 29
+        let weekly = create_task(RecurrenceType::Weekly, Some(RecurrenceValue::WeekDay(1)));
+        let occurrences = expand_occurrences(&[weekly], &HashMap::new(), date(1), date(31), 0);
+
+        let dates: Vec<u32> = occurrences.iter().map(|o| o.date.day()).collect();
+        assert_eq!(dates, vec![1, 8, 15, 22, 29]);
+        assert!(occurrences.iter().all(|o| o.completions == 0 && !o.is_completed()));
+    }
+
+    #[test]
+    fn test_expand_occurrences_marks_completions() {
+        let daily = create_task(RecurrenceType::Daily, None);
+        let onetime = create_task(RecurrenceType::OneTime, None);
+        let completions = HashMap::from([((daily.id, date(2)), 1), ((onetime.id, date(3)), 1)]);
+        let occurrences = expand_occurrences(&[daily.clone(), onetime.clone()], &completions, date(1), date(3), 0);
+
+        assert_eq!(occurrences.len(), 4);
+        assert!(occurrences.iter().any(|o| o.task_id == daily.id && o.date == date(2) && o.is_completed()));
+        // One-time tasks appear only where they were completed
+        let onetime_dates: Vec<NaiveDate> = occurrences.iter().filter(|o| o.task_id == onetime.id).map(|o| o.date).collect();
+        assert_eq!(onetime_dates, vec![date(3)]);
+    }
+
+    #[test]
+    fn test_expand_occurrences_sorted_by_date_and_time() {
+        let mut morning = create_task(RecurrenceType::Daily, None);
+        morning.title = "Zebra feeding".to_string();
+        morning.due_time = Some("08:00".to_string());
+        let all_day = create_task(RecurrenceType::Daily, None);
+        let occurrences = expand_occurrences(&[all_day, morning], &HashMap::new(), date(1), date(2), 0);
+
+        let order: Vec<(u32, &str)> = occurrences.iter().map(|o| (o.date.day(), o.title.as_str())).collect();
+        assert_eq!(
+            order,
+            vec![(1, "Zebra feeding"), (1, "Take out trash"), (2, "Zebra feeding"), (2, "Take out trash")]
+        );
+    }
+}
@@ -0,0 +1,473 @@
+//! Challenges: time-boxed competitions inside a household
+//!
+//! Members compete on the number of approved completions of matching tasks
+//! (optionally limited to one category) between a start and end date. Once
+//! the end date has passed in the household's timezone the background job
+//! closes the challenge and assigns its reward to every winner.
+
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::ChallengeRow;
+use crate::services::{activity_logs, rewards, scheduler};
+use shared::{ActivityType, Challenge, ChallengeStanding, ChallengeWithStandings, CreateChallengeRequest};
+
+#[derive(Debug, Error)]
+pub enum ChallengeError {
+    #[error("Challenge not found")]
+    NotFound,
+    #[error("Challenge title must not be empty")]
+    EmptyTitle,
+    #[error("End date must not be before start date")]
+    InvalidDateRange,
+    #[error("Category does not belong to this household")]
+    InvalidCategory,
+    #[error("Reward does not belong to this household")]
+    InvalidReward,
+    #[error("Challenge is already closed")]
+    AlreadyClosed,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+/// Report from closing ended challenges
+#[derive(Debug, Clone, Default)]
+pub struct ChallengeClosingReport {
+    pub challenges_checked: u32,
+    pub challenges_closed: u32,
+    pub rewards_assigned: u32,
+}
+
+pub async fn create_challenge(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    request: &CreateChallengeRequest,
+) -> Result<Challenge, ChallengeError> {
+    let title = request.title.trim();
+    if title.is_empty() {
+        return Err(ChallengeError::EmptyTitle);
+    }
+    if request.end_date < request.start_date {
+        return Err(ChallengeError::InvalidDateRange);
+    }
+
+    if let Some(category_id) = request.category_id {
+        if !belongs_to_household(pool, "task_categories", &category_id, household_id).await? {
+            return Err(ChallengeError::InvalidCategory);
+        }
+    }
+    if let Some(reward_id) = request.reward_id {
+        if !belongs_to_household(pool, "rewards", &reward_id, household_id).await? {
+            return Err(ChallengeError::InvalidReward);
+        }
+    }
+
+    let id = Uuid::new_v4();
+    let now = Utc::now();
+    let description = request.description.as_deref().unwrap_or("").trim().to_string();
+
+    sqlx::query(
+        r#"
+        INSERT INTO challenges (id, household_id, title, description, category_id, start_date, end_date, reward_id, status, created_by, created_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, 'active', ?, ?)
+        "#,
+    )
+    .bind(id.to_string())
+    .bind(household_id.to_string())
+    .bind(title)
+    .bind(&description)
+    .bind(request.category_id.map(|id| id.to_string()))
+    .bind(request.start_date)
+    .bind(request.end_date)
+    .bind(request.reward_id.map(|id| id.to_string()))
+    .bind(user_id.to_string())
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    Ok(Challenge {
+        id,
+        household_id: *household_id,
+        title: title.to_string(),
+        description,
+        category_id: request.category_id,
+        start_date: request.start_date,
+        end_date: request.end_date,
+        reward_id: request.reward_id,
+        status: shared::ChallengeStatus::Active,
+        created_by: *user_id,
+        created_at: now,
+        closed_at: None,
+        winner_ids: Vec::new(),
+    })
+}
+
+async fn belongs_to_household(
+    pool: &SqlitePool,
+    table: &str,
+    id: &Uuid,
+    household_id: &Uuid,
+) -> Result<bool, ChallengeError> {
+    let count: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {} WHERE id = ? AND household_id = ?", table))
+        .bind(id.to_string())
+        .bind(household_id.to_string())
+        .fetch_one(pool)
+        .await?;
+    Ok(count > 0)
+}
+
+async fn load_winners(pool: &SqlitePool, challenge_id: &str) -> Result<Vec<Uuid>, ChallengeError> {
+    let ids: Vec<String> = sqlx::query_scalar("SELECT user_id FROM challenge_winners WHERE challenge_id = ?")
+        .bind(challenge_id)
+        .fetch_all(pool)
+        .await?;
+
+    Ok(ids.iter().filter_map(|id| Uuid::parse_str(id).ok()).collect())
+}
+
+pub async fn get_challenge(pool: &SqlitePool, challenge_id: &Uuid) -> Result<Option<Challenge>, ChallengeError> {
+    let row: Option<ChallengeRow> = sqlx::query_as("SELECT * FROM challenges WHERE id = ?")
+        .bind(challenge_id.to_string())
+        .fetch_optional(pool)
+        .await?;
+
+    match row {
+        Some(row) => {
+            let mut challenge = row.to_shared();
+            challenge.winner_ids = load_winners(pool, &row.id).await?;
+            Ok(Some(challenge))
+        }
+        None => Ok(None),
+    }
+}
+
+/// List a household's challenges, active ones first, then newest end date first
+pub async fn list_challenges(pool: &SqlitePool, household_id: &Uuid) -> Result<Vec<Challenge>, ChallengeError> {
+    let rows: Vec<ChallengeRow> = sqlx::query_as(
+        r#"
+        SELECT * FROM challenges
+        WHERE household_id = ?
+        ORDER BY CASE status WHEN 'active' THEN 0 ELSE 1 END, end_date DESC, created_at DESC
+        "#,
+    )
+    .bind(household_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    let mut challenges = Vec::with_capacity(rows.len());
+    for row in rows {
+        let mut challenge = row.to_shared();
+        challenge.winner_ids = load_winners(pool, &row.id).await?;
+        challenges.push(challenge);
+    }
+
+    Ok(challenges)
+}
+
+pub async fn delete_challenge(pool: &SqlitePool, challenge_id: &Uuid) -> Result<(), ChallengeError> {
+    let result = sqlx::query("DELETE FROM challenges WHERE id = ?")
+        .bind(challenge_id.to_string())
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(ChallengeError::NotFound);
+    }
+
+    Ok(())
+}
+
+/// Approved completions of matching good-habit tasks per member within the
+/// challenge dates, most completions first
+pub async fn get_standings(pool: &SqlitePool, challenge: &Challenge) -> Result<Vec<ChallengeStanding>, ChallengeError> {
+    let category_id = challenge.category_id.map(|id| id.to_string());
+
+    let rows: Vec<(String, String, i64)> = sqlx::query_as(
+        r#"
+        SELECT m.user_id, u.username, COUNT(c.id) AS completions
+        FROM household_memberships m
+        JOIN users u ON m.user_id = u.id
+        LEFT JOIN task_completions c
+            ON c.user_id = m.user_id
+            AND c.status = 'approved'
+            AND c.due_date >= ? AND c.due_date <= ?
+            AND c.task_id IN (
+                SELECT id FROM tasks
+                WHERE household_id = ? AND habit_type != 'bad' AND (? IS NULL OR category_id = ?)
+            )
+        WHERE m.household_id = ?
+        GROUP BY m.user_id, u.username
+        ORDER BY completions DESC, u.username
+        "#,
+    )
+    .bind(challenge.start_date)
+    .bind(challenge.end_date)
+    .bind(challenge.household_id.to_string())
+    .bind(&category_id)
+    .bind(&category_id)
+    .bind(challenge.household_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|(user_id, username, completions)| {
+            Uuid::parse_str(&user_id).ok().map(|user_id| ChallengeStanding {
+                user_id,
+                username,
+                completions,
+            })
+        })
+        .collect())
+}
+
+pub async fn with_standings(pool: &SqlitePool, challenge: Challenge) -> Result<ChallengeWithStandings, ChallengeError> {
+    let standings = get_standings(pool, &challenge).await?;
+    Ok(ChallengeWithStandings { challenge, standings })
+}
+
+/// Members with the highest completion count; nobody wins without a completion
+pub fn winners(standings: &[ChallengeStanding]) -> Vec<&ChallengeStanding> {
+    let best = standings.iter().map(|s| s.completions).max().unwrap_or(0);
+    if best == 0 {
+        return Vec::new();
+    }
+    standings.iter().filter(|s| s.completions == best).collect()
+}
+
+/// Close a challenge, record its winners and assign the reward to each of them.
+/// Returns the closed challenge and the number of rewards assigned.
+pub async fn close_challenge(
+    pool: &SqlitePool,
+    challenge_id: &Uuid,
+    now: DateTime<Utc>,
+) -> Result<(Challenge, u32), ChallengeError> {
+    let challenge = get_challenge(pool, challenge_id).await?.ok_or(ChallengeError::NotFound)?;
+
+    // Claim the challenge first so a manual close and the job never both reward
+    let claimed = sqlx::query("UPDATE challenges SET status = 'closed', closed_at = ? WHERE id = ? AND status = 'active'")
+        .bind(now)
+        .bind(challenge_id.to_string())
+        .execute(pool)
+        .await?
+        .rows_affected();
+    if claimed == 0 {
+        return Err(ChallengeError::AlreadyClosed);
+    }
+
+    let standings = get_standings(pool, &challenge).await?;
+    let mut rewards_assigned = 0;
+    let mut winner_ids = Vec::new();
+
+    for winner in winners(&standings) {
+        sqlx::query("INSERT INTO challenge_winners (challenge_id, user_id, completions) VALUES (?, ?, ?)")
+            .bind(challenge_id.to_string())
+            .bind(winner.user_id.to_string())
+            .bind(winner.completions)
+            .execute(pool)
+            .await?;
+        winner_ids.push(winner.user_id);
+
+        if let Some(reward_id) = challenge.reward_id {
+            match rewards::assign_reward(pool, &reward_id, &winner.user_id, &challenge.household_id).await {
+                Ok(_) => rewards_assigned += 1,
+                Err(e) => log::warn!("Failed to assign challenge reward to {}: {}", winner.user_id, e),
+            }
+        }
+
+        let details = serde_json::json!({ "name": challenge.title, "completions": winner.completions }).to_string();
+        let _ = activity_logs::log_activity(
+            pool,
+            &challenge.household_id,
+            &challenge.created_by,
+            Some(&winner.user_id),
+            ActivityType::ChallengeWon,
+            Some("challenge"),
+            Some(challenge_id),
+            Some(&details),
+        )
+        .await;
+    }
+
+    let challenge = Challenge {
+        status: shared::ChallengeStatus::Closed,
+        closed_at: Some(now),
+        winner_ids,
+        ..challenge
+    };
+
+    Ok((challenge, rewards_assigned))
+}
+
+/// Close every active challenge whose end date has passed in its household's timezone
+pub async fn process_challenge_closing(
+    pool: &SqlitePool,
+    now: DateTime<Utc>,
+) -> Result<ChallengeClosingReport, ChallengeError> {
+    let mut report = ChallengeClosingReport::default();
+
+    let active: Vec<(String, chrono::NaiveDate, Option<String>)> = sqlx::query_as(
+        r#"
+        SELECT c.id, c.end_date, s.timezone
+        FROM challenges c
+        LEFT JOIN household_settings s ON c.household_id = s.household_id
+        WHERE c.status = 'active'
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    for (challenge_id, end_date, timezone) in active {
+        report.challenges_checked += 1;
+
+        let tz = scheduler::parse_timezone(timezone.as_deref().unwrap_or("UTC"));
+        if now.with_timezone(&tz).date_naive() <= end_date {
+            continue;
+        }
+        let Ok(challenge_uuid) = Uuid::parse_str(&challenge_id) else {
+            continue;
+        };
+
+        match close_challenge(pool, &challenge_uuid, now).await {
+            Ok((_, rewards_assigned)) => {
+                report.challenges_closed += 1;
+                report.rewards_assigned += rewards_assigned;
+            }
+            Err(ChallengeError::AlreadyClosed) => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use chrono::{NaiveDate, TimeZone};
+    use shared::{ChallengeStatus, Role};
+
+    fn standing(name: &str, completions: i64) -> ChallengeStanding {
+        ChallengeStanding {
+            user_id: Uuid::new_v4(),
+            username: name.to_string(),
+            completions,
+        }
+    }
+
+    #[test]
+    fn test_winners_share_ties_and_need_a_completion() {
+        let standings = vec![standing("a", 4), standing("b", 4), standing("c", 1)];
+        let names: Vec<_> = winners(&standings).iter().map(|s| s.username.as_str()).collect();
+        assert_eq!(names, vec!["a", "b"]);
+
+        assert!(winners(&[standing("a", 0), standing("b", 0)]).is_empty());
+        assert!(winners(&[]).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_create_challenge_validates() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let owner = create_test_user(&pool, "admin@test.com", Role::Owner).await;
+
+        let mut request = CreateChallengeRequest {
+            title: "  ".to_string(),
+            description: None,
+            category_id: None,
+            start_date: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(),
+            reward_id: None,
+        };
+        let err = create_challenge(&pool, &household_id, &owner, &request).await.unwrap_err();
+        assert!(matches!(err, ChallengeError::EmptyTitle));
+
+        request.title = "March madness".to_string();
+        request.end_date = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+        let err = create_challenge(&pool, &household_id, &owner, &request).await.unwrap_err();
+        assert!(matches!(err, ChallengeError::InvalidDateRange));
+    }
+
+    #[tokio::test]
+    async fn test_challenge_closes_after_end_and_rewards_winner() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let alice = create_test_user(&pool, "alice@test.com", Role::Member).await;
+        let bob = create_test_user(&pool, "bob@test.com", Role::Member).await;
+        create_test_membership(&pool, &household_id, &alice, Role::Member).await;
+        create_test_membership(&pool, &household_id, &bob, Role::Member).await;
+        let reward_id = create_test_reward(&pool, &household_id, "Pizza night", None).await;
+        let task = create_test_task(&pool, &household_id).build().await;
+
+        let complete = |user: Uuid, date: &'static str| {
+            let pool = pool.clone();
+            let task_id = task.id;
+            async move {
+                sqlx::query("INSERT INTO task_completions (id, task_id, user_id, due_date) VALUES (?, ?, ?, ?)")
+                    .bind(Uuid::new_v4().to_string())
+                    .bind(task_id.to_string())
+                    .bind(user.to_string())
+                    .bind(date)
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+            }
+        };
+        complete(alice, "2024-03-02").await;
+        complete(alice, "2024-03-03").await;
+        complete(bob, "2024-03-02").await;
+        // Outside the challenge dates
+        complete(bob, "2024-04-01").await;
+        complete(bob, "2024-04-02").await;
+
+        let challenge = create_challenge(
+            &pool,
+            &household_id,
+            &alice,
+            &CreateChallengeRequest {
+                title: "March madness".to_string(),
+                description: None,
+                category_id: None,
+                start_date: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+                end_date: NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(),
+                reward_id: Some(reward_id),
+            },
+        )
+        .await
+        .unwrap();
+
+        let standings = get_standings(&pool, &challenge).await.unwrap();
+        assert_eq!(standings[0].user_id, alice);
+        assert_eq!(standings[0].completions, 2);
+        assert_eq!(standings[1].completions, 1);
+
+        // Still running on the last day
+        let last_day = Utc.with_ymd_and_hms(2024, 3, 31, 20, 0, 0).unwrap();
+        let report = process_challenge_closing(&pool, last_day).await.unwrap();
+        assert_eq!(report.challenges_closed, 0);
+
+        let report = process_challenge_closing(&pool, last_day + chrono::Duration::hours(5)).await.unwrap();
+        assert_eq!(report.challenges_closed, 1);
+        assert_eq!(report.rewards_assigned, 1);
+
+        let closed = get_challenge(&pool, &challenge.id).await.unwrap().unwrap();
+        assert_eq!(closed.status, ChallengeStatus::Closed);
+        assert_eq!(closed.winner_ids, vec![alice]);
+        assert_activity_logged(&pool, &household_id, "challenge_won").await;
+
+        let amount: i64 = sqlx::query_scalar("SELECT amount FROM user_rewards WHERE user_id = ? AND reward_id = ?")
+            .bind(alice.to_string())
+            .bind(reward_id.to_string())
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(amount, 1);
+
+        let err = close_challenge(&pool, &challenge.id, last_day).await.unwrap_err();
+        assert!(matches!(err, ChallengeError::AlreadyClosed));
+    }
+}
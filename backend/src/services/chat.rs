@@ -3,8 +3,12 @@ use sqlx::SqlitePool;
 use thiserror::Error;
 use uuid::Uuid;
 
-use crate::models::ChatMessageWithUserRow;
-use shared::{ChatMessage, ChatMessageWithUser};
+use crate::models::{AttachmentRow, ChatMessageWithUserRow};
+use crate::services::webhooks;
+use shared::{
+    AttachmentEntity, ChatMessage, ChatMessageWithUser, ChatReactionSummary, ChatReadMarker, ChatUnreadCount,
+    WebhookEvent,
+};
 
 #[derive(Debug, Error)]
 pub enum ChatError {
@@ -14,41 +18,66 @@ pub enum ChatError {
     NotAuthorized,
     #[error("Message content cannot be empty")]
     EmptyContent,
+    #[error("Reply target not found")]
+    InvalidReply,
+    #[error("Invalid reaction")]
+    InvalidReaction,
     #[error("Database error: {0}")]
     DatabaseError(#[from] sqlx::Error),
 }
 
-/// Create a new chat message
+/// Maximum length of a reaction in characters (emoji with modifiers span several)
+const MAX_REACTION_CHARS: usize = 8;
+
+/// Columns selected for `ChatMessageWithUserRow`; expects `chat_messages m JOIN users u`
+const MESSAGE_WITH_USER_COLUMNS: &str = r#"
+    m.id, m.household_id, m.user_id, m.content,
+    m.created_at, m.updated_at, m.deleted_at, m.reply_to_message_id,
+    (SELECT COUNT(*) FROM chat_messages r WHERE r.reply_to_message_id = m.id AND r.deleted_at IS NULL) as reply_count,
+    u.username, u.email,
+    u.created_at as user_created_at, u.updated_at as user_updated_at
+"#;
+
+/// Create a new chat message, optionally as a reply to another message of the household
 pub async fn create_message(
     pool: &SqlitePool,
     household_id: &Uuid,
     user_id: &Uuid,
     content: &str,
+    reply_to: Option<&Uuid>,
 ) -> Result<ChatMessage, ChatError> {
     let content = content.trim();
     if content.is_empty() {
         return Err(ChatError::EmptyContent);
     }
 
+    if let Some(parent_id) = reply_to {
+        match get_message(pool, parent_id).await? {
+            Some(parent) if parent.household_id == *household_id && !parent.is_deleted => {}
+            _ => return Err(ChatError::InvalidReply),
+        }
+    }
+
     let id = Uuid::new_v4();
     let now = Utc::now();
 
     sqlx::query(
         r#"
-        INSERT INTO chat_messages (id, household_id, user_id, content, created_at, updated_at)
-        VALUES (?, ?, ?, ?, ?, ?)
+        INSERT INTO chat_messages (id, household_id, user_id, content, reply_to_message_id, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?)
         "#,
     )
     .bind(id.to_string())
     .bind(household_id.to_string())
     .bind(user_id.to_string())
     .bind(content)
+    .bind(reply_to.map(|id| id.to_string()))
     .bind(now)
     .bind(now)
     .execute(pool)
     .await?;
 
-    Ok(ChatMessage {
+    let message = ChatMessage {
         id,
         household_id: *household_id,
         user_id: *user_id,
@@ -56,7 +85,12 @@ pub async fn create_message(
         created_at: now,
         updated_at: now,
         is_deleted: false,
-    })
+        reply_to_message_id: reply_to.copied(),
+    };
+
+    webhooks::dispatch(pool, household_id, WebhookEvent::ChatMessage, serde_json::json!({ "message": message })).await;
+
+    Ok(message)
 }
 
 /// Get a single message by ID
@@ -73,10 +107,11 @@ pub async fn get_message(
         created_at: chrono::DateTime<chrono::Utc>,
         updated_at: chrono::DateTime<chrono::Utc>,
         deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+        reply_to_message_id: Option<String>,
     }
 
     let row: Option<Row> = sqlx::query_as(
-        "SELECT id, household_id, user_id, content, created_at, updated_at, deleted_at FROM chat_messages WHERE id = ?",
+        "SELECT id, household_id, user_id, content, created_at, updated_at, deleted_at, reply_to_message_id FROM chat_messages WHERE id = ?",
     )
     .bind(message_id.to_string())
     .fetch_optional(pool)
@@ -90,6 +125,7 @@ pub async fn get_message(
         created_at: r.created_at,
         updated_at: r.updated_at,
         is_deleted: r.deleted_at.is_some(),
+        reply_to_message_id: r.reply_to_message_id.and_then(|id| Uuid::parse_str(&id).ok()),
     }))
 }
 
@@ -98,23 +134,23 @@ pub async fn get_message_with_user(
     pool: &SqlitePool,
     message_id: &Uuid,
 ) -> Result<Option<ChatMessageWithUser>, ChatError> {
-    let row: Option<ChatMessageWithUserRow> = sqlx::query_as(
+    let row: Option<ChatMessageWithUserRow> = sqlx::query_as(&format!(
         r#"
-        SELECT
-            m.id, m.household_id, m.user_id, m.content,
-            m.created_at, m.updated_at, m.deleted_at,
-            u.username, u.email,
-            u.created_at as user_created_at, u.updated_at as user_updated_at
+        SELECT {}
         FROM chat_messages m
         JOIN users u ON m.user_id = u.id
         WHERE m.id = ?
         "#,
-    )
+        MESSAGE_WITH_USER_COLUMNS
+    ))
     .bind(message_id.to_string())
     .fetch_optional(pool)
     .await?;
 
-    Ok(row.map(|r| r.to_shared()))
+    let mut messages: Vec<ChatMessageWithUser> = row.into_iter().map(|r| r.to_shared()).collect();
+    attach_reactions(pool, &mut messages).await?;
+    attach_files(pool, &mut messages).await?;
+    Ok(messages.pop())
 }
 
 /// List messages for a household with pagination
@@ -136,20 +172,17 @@ pub async fn list_messages(
         .await?;
 
         if let Some(created_at) = before_created_at {
-            sqlx::query_as(
+            sqlx::query_as(&format!(
                 r#"
-                SELECT
-                    m.id, m.household_id, m.user_id, m.content,
-                    m.created_at, m.updated_at, m.deleted_at,
-                    u.username, u.email,
-                    u.created_at as user_created_at, u.updated_at as user_updated_at
+                SELECT {}
                 FROM chat_messages m
                 JOIN users u ON m.user_id = u.id
                 WHERE m.household_id = ? AND m.deleted_at IS NULL AND m.created_at < ?
                 ORDER BY m.created_at DESC
                 LIMIT ?
                 "#,
-            )
+                MESSAGE_WITH_USER_COLUMNS
+            ))
             .bind(household_id.to_string())
             .bind(created_at)
             .bind(limit)
@@ -159,27 +192,190 @@ pub async fn list_messages(
             Vec::new()
         }
     } else {
-        sqlx::query_as(
+        sqlx::query_as(&format!(
             r#"
-            SELECT
-                m.id, m.household_id, m.user_id, m.content,
-                m.created_at, m.updated_at, m.deleted_at,
-                u.username, u.email,
-                u.created_at as user_created_at, u.updated_at as user_updated_at
+            SELECT {}
             FROM chat_messages m
             JOIN users u ON m.user_id = u.id
             WHERE m.household_id = ? AND m.deleted_at IS NULL
             ORDER BY m.created_at DESC
             LIMIT ?
             "#,
-        )
+            MESSAGE_WITH_USER_COLUMNS
+        ))
         .bind(household_id.to_string())
         .bind(limit)
         .fetch_all(pool)
         .await?
     };
 
-    Ok(rows.into_iter().map(|r| r.to_shared()).collect())
+    let mut messages: Vec<ChatMessageWithUser> = rows.into_iter().map(|r| r.to_shared()).collect();
+    attach_reactions(pool, &mut messages).await?;
+    attach_files(pool, &mut messages).await?;
+    Ok(messages)
+}
+
+/// List the replies to a message, oldest first
+pub async fn list_replies(
+    pool: &SqlitePool,
+    message_id: &Uuid,
+) -> Result<Vec<ChatMessageWithUser>, ChatError> {
+    let rows: Vec<ChatMessageWithUserRow> = sqlx::query_as(&format!(
+        r#"
+        SELECT {}
+        FROM chat_messages m
+        JOIN users u ON m.user_id = u.id
+        WHERE m.reply_to_message_id = ? AND m.deleted_at IS NULL
+        ORDER BY m.created_at ASC
+        "#,
+        MESSAGE_WITH_USER_COLUMNS
+    ))
+    .bind(message_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    let mut messages: Vec<ChatMessageWithUser> = rows.into_iter().map(|r| r.to_shared()).collect();
+    attach_reactions(pool, &mut messages).await?;
+    attach_files(pool, &mut messages).await?;
+    Ok(messages)
+}
+
+/// Group reaction rows (ordered by time) into one summary per emoji
+fn summarize_reactions(rows: impl IntoIterator<Item = (String, Uuid)>) -> Vec<ChatReactionSummary> {
+    let mut summaries: Vec<ChatReactionSummary> = Vec::new();
+    for (emoji, user_id) in rows {
+        match summaries.iter_mut().find(|s| s.emoji == emoji) {
+            Some(summary) => summary.user_ids.push(user_id),
+            None => summaries.push(ChatReactionSummary {
+                emoji,
+                user_ids: vec![user_id],
+            }),
+        }
+    }
+    summaries
+}
+
+/// Load the reactions of all given messages in one query
+async fn attach_reactions(pool: &SqlitePool, messages: &mut [ChatMessageWithUser]) -> Result<(), ChatError> {
+    if messages.is_empty() {
+        return Ok(());
+    }
+
+    let placeholders = vec!["?"; messages.len()].join(", ");
+    let sql = format!(
+        "SELECT message_id, emoji, user_id FROM chat_reactions WHERE message_id IN ({}) ORDER BY created_at ASC",
+        placeholders
+    );
+    let mut query = sqlx::query_as::<_, (String, String, String)>(&sql);
+    for message in messages.iter() {
+        query = query.bind(message.message.id.to_string());
+    }
+    let rows = query.fetch_all(pool).await?;
+
+    for message in messages.iter_mut() {
+        let id = message.message.id.to_string();
+        message.reactions = summarize_reactions(
+            rows.iter()
+                .filter(|(message_id, _, _)| *message_id == id)
+                .map(|(_, emoji, user_id)| (emoji.clone(), Uuid::parse_str(user_id).unwrap())),
+        );
+    }
+
+    Ok(())
+}
+
+/// Load the photos of all given messages in one query
+async fn attach_files(pool: &SqlitePool, messages: &mut [ChatMessageWithUser]) -> Result<(), ChatError> {
+    if messages.is_empty() {
+        return Ok(());
+    }
+
+    let placeholders = vec!["?"; messages.len()].join(", ");
+    let sql = format!(
+        "SELECT * FROM attachments WHERE entity_type = ? AND entity_id IN ({}) ORDER BY created_at ASC",
+        placeholders
+    );
+    let mut query = sqlx::query_as::<_, AttachmentRow>(&sql).bind(AttachmentEntity::ChatMessage.as_str());
+    for message in messages.iter() {
+        query = query.bind(message.message.id.to_string());
+    }
+    let rows = query.fetch_all(pool).await?;
+
+    for message in messages.iter_mut() {
+        let id = message.message.id.to_string();
+        message.attachments = rows.iter().filter(|row| row.entity_id == id).map(|row| row.to_shared()).collect();
+    }
+
+    Ok(())
+}
+
+/// Reactions are short emoji sequences, not free text
+pub fn is_valid_reaction(emoji: &str) -> bool {
+    let count = emoji.chars().count();
+    count > 0
+        && count <= MAX_REACTION_CHARS
+        && !emoji.chars().any(|c| c.is_ascii_alphanumeric() || c.is_whitespace())
+}
+
+/// List the reactions on a message
+pub async fn list_reactions(pool: &SqlitePool, message_id: &Uuid) -> Result<Vec<ChatReactionSummary>, ChatError> {
+    let rows: Vec<(String, String)> = sqlx::query_as(
+        "SELECT emoji, user_id FROM chat_reactions WHERE message_id = ? ORDER BY created_at ASC",
+    )
+    .bind(message_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    Ok(summarize_reactions(
+        rows.into_iter()
+            .map(|(emoji, user_id)| (emoji, Uuid::parse_str(&user_id).unwrap())),
+    ))
+}
+
+/// Add a reaction; reacting twice with the same emoji is a no-op
+pub async fn add_reaction(
+    pool: &SqlitePool,
+    message_id: &Uuid,
+    user_id: &Uuid,
+    emoji: &str,
+) -> Result<Vec<ChatReactionSummary>, ChatError> {
+    if !is_valid_reaction(emoji) {
+        return Err(ChatError::InvalidReaction);
+    }
+
+    let message = get_message(pool, message_id).await?.ok_or(ChatError::NotFound)?;
+    if message.is_deleted {
+        return Err(ChatError::NotFound);
+    }
+
+    sqlx::query(
+        "INSERT OR IGNORE INTO chat_reactions (message_id, user_id, emoji, created_at) VALUES (?, ?, ?, ?)",
+    )
+    .bind(message_id.to_string())
+    .bind(user_id.to_string())
+    .bind(emoji)
+    .bind(Utc::now())
+    .execute(pool)
+    .await?;
+
+    list_reactions(pool, message_id).await
+}
+
+/// Remove the user's own reaction
+pub async fn remove_reaction(
+    pool: &SqlitePool,
+    message_id: &Uuid,
+    user_id: &Uuid,
+    emoji: &str,
+) -> Result<Vec<ChatReactionSummary>, ChatError> {
+    sqlx::query("DELETE FROM chat_reactions WHERE message_id = ? AND user_id = ? AND emoji = ?")
+        .bind(message_id.to_string())
+        .bind(user_id.to_string())
+        .bind(emoji)
+        .execute(pool)
+        .await?;
+
+    list_reactions(pool, message_id).await
 }
 
 /// Update message content - only the author can edit
@@ -221,6 +417,7 @@ pub async fn update_message(
         created_at: message.created_at,
         updated_at: now,
         is_deleted: false,
+        reply_to_message_id: message.reply_to_message_id,
     })
 }
 
@@ -252,9 +449,132 @@ pub async fn delete_message(
     Ok(())
 }
 
+#[derive(sqlx::FromRow)]
+struct ChatReadMarkerRow {
+    household_id: String,
+    user_id: String,
+    last_read_message_id: String,
+    last_read_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl ChatReadMarkerRow {
+    fn to_shared(&self) -> ChatReadMarker {
+        ChatReadMarker {
+            household_id: Uuid::parse_str(&self.household_id).unwrap(),
+            user_id: Uuid::parse_str(&self.user_id).unwrap(),
+            last_read_message_id: Uuid::parse_str(&self.last_read_message_id).unwrap(),
+            last_read_at: self.last_read_at,
+        }
+    }
+}
+
+/// Mark all messages up to and including `message_id` as read.
+/// The marker never moves backwards, so reading an older message is a no-op.
+pub async fn mark_read(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    message_id: &Uuid,
+) -> Result<ChatReadMarker, ChatError> {
+    let result = sqlx::query(
+        r#"
+        INSERT INTO chat_read_markers (household_id, user_id, last_read_message_id, last_read_at, updated_at)
+        SELECT m.household_id, ?, m.id, m.created_at, ?
+        FROM chat_messages m
+        WHERE m.id = ? AND m.household_id = ?
+        ON CONFLICT(household_id, user_id) DO UPDATE SET
+            last_read_message_id = excluded.last_read_message_id,
+            last_read_at = excluded.last_read_at,
+            updated_at = excluded.updated_at
+        WHERE excluded.last_read_at > chat_read_markers.last_read_at
+        "#,
+    )
+    .bind(user_id.to_string())
+    .bind(Utc::now())
+    .bind(message_id.to_string())
+    .bind(household_id.to_string())
+    .execute(pool)
+    .await?;
+
+    // Nothing inserted or updated: either the marker is already further along,
+    // or the message does not belong to this household
+    if result.rows_affected() == 0 {
+        match get_message(pool, message_id).await? {
+            Some(message) if message.household_id == *household_id => {}
+            _ => return Err(ChatError::NotFound),
+        }
+    }
+
+    let row: ChatReadMarkerRow = sqlx::query_as(
+        r#"
+        SELECT household_id, user_id, last_read_message_id, last_read_at
+        FROM chat_read_markers
+        WHERE household_id = ? AND user_id = ?
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .fetch_one(pool)
+    .await?;
+
+    Ok(row.to_shared())
+}
+
+/// List the read markers of all members of a household
+pub async fn list_read_markers(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+) -> Result<Vec<ChatReadMarker>, ChatError> {
+    let rows: Vec<ChatReadMarkerRow> = sqlx::query_as(
+        r#"
+        SELECT household_id, user_id, last_read_message_id, last_read_at
+        FROM chat_read_markers
+        WHERE household_id = ?
+        "#,
+    )
+    .bind(household_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.iter().map(|r| r.to_shared()).collect())
+}
+
+/// Count unread messages per household the user is a member of.
+/// Own and deleted messages never count as unread.
+pub async fn unread_counts(pool: &SqlitePool, user_id: &Uuid) -> Result<Vec<ChatUnreadCount>, ChatError> {
+    let rows: Vec<(String, i64)> = sqlx::query_as(
+        r#"
+        SELECT hm.household_id, COUNT(m.id)
+        FROM household_memberships hm
+        LEFT JOIN chat_read_markers r
+            ON r.household_id = hm.household_id AND r.user_id = hm.user_id
+        LEFT JOIN chat_messages m
+            ON m.household_id = hm.household_id
+            AND m.deleted_at IS NULL
+            AND m.user_id != hm.user_id
+            AND (r.last_read_at IS NULL OR m.created_at > r.last_read_at)
+        WHERE hm.user_id = ?
+        GROUP BY hm.household_id
+        "#,
+    )
+    .bind(user_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(household_id, unread_count)| ChatUnreadCount {
+            household_id: Uuid::parse_str(&household_id).unwrap(),
+            unread_count,
+        })
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils;
+    use shared::Role;
 
     #[test]
     fn test_chat_error_display() {
@@ -262,4 +582,156 @@ mod tests {
         assert_eq!(ChatError::NotAuthorized.to_string(), "Not authorized to modify this message");
         assert_eq!(ChatError::EmptyContent.to_string(), "Message content cannot be empty");
     }
+
+    async fn unread_for(pool: &SqlitePool, user_id: &Uuid, household_id: &Uuid) -> i64 {
+        unread_counts(pool, user_id)
+            .await
+            .unwrap()
+            .into_iter()
+            .find(|c| c.household_id == *household_id)
+            .map(|c| c.unread_count)
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_unread_counts_and_mark_read() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let alice = test_utils::create_test_user(&pool, "alice@test.com", Role::Member).await;
+        let bob = test_utils::create_test_user(&pool, "bob@test.com", Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &alice, Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &bob, Role::Member).await;
+
+        let first = create_message(&pool, &household_id, &alice, "buy milk", None).await.unwrap();
+        let second = create_message(&pool, &household_id, &alice, "and eggs", None).await.unwrap();
+        create_message(&pool, &household_id, &bob, "ok", None).await.unwrap();
+
+        // Own messages are never unread
+        assert_eq!(unread_for(&pool, &bob, &household_id).await, 2);
+        assert_eq!(unread_for(&pool, &alice, &household_id).await, 1);
+
+        let marker = mark_read(&pool, &household_id, &bob, &second.id).await.unwrap();
+        assert_eq!(marker.last_read_message_id, second.id);
+        assert_eq!(unread_for(&pool, &bob, &household_id).await, 0);
+
+        // Reading an older message does not move the marker back
+        let marker = mark_read(&pool, &household_id, &bob, &first.id).await.unwrap();
+        assert_eq!(marker.last_read_message_id, second.id);
+
+        let markers = list_read_markers(&pool, &household_id).await.unwrap();
+        assert_eq!(markers.len(), 1);
+        assert_eq!(markers[0].user_id, bob);
+    }
+
+    #[tokio::test]
+    async fn test_messages_carry_their_photos() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user = test_utils::create_test_user(&pool, "alice@test.com", Role::Member).await;
+
+        let with_photo = create_message(&pool, &household_id, &user, "Which of these two detergents?", None).await.unwrap();
+        let without = create_message(&pool, &household_id, &user, "the left one", None).await.unwrap();
+        let attachment_id = Uuid::new_v4();
+        sqlx::query(
+            "INSERT INTO attachments (id, household_id, entity_type, entity_id, uploaded_by, file_name, content_type, size_bytes) VALUES (?, ?, 'chat_message', ?, ?, 'shelf.jpg', 'image/jpeg', 42)",
+        )
+        .bind(attachment_id.to_string())
+        .bind(household_id.to_string())
+        .bind(with_photo.id.to_string())
+        .bind(user.to_string())
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let messages = list_messages(&pool, &household_id, 10, None).await.unwrap();
+        let photos = |id: Uuid| messages.iter().find(|m| m.message.id == id).unwrap().attachments.clone();
+        assert_eq!(photos(with_photo.id).iter().map(|a| a.id).collect::<Vec<_>>(), vec![attachment_id]);
+        assert!(photos(without.id).is_empty());
+
+        let single = get_message_with_user(&pool, &with_photo.id).await.unwrap().unwrap();
+        assert_eq!(single.attachments.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_mark_read_rejects_foreign_message() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user = test_utils::create_test_user(&pool, "alice@test.com", Role::Member).await;
+
+        let message = create_message(&pool, &household_id, &user, "hello", None).await.unwrap();
+
+        let result = mark_read(&pool, &Uuid::new_v4(), &user, &message.id).await;
+        assert!(matches!(result, Err(ChatError::NotFound)));
+
+        let result = mark_read(&pool, &household_id, &user, &Uuid::new_v4()).await;
+        assert!(matches!(result, Err(ChatError::NotFound)));
+    }
+
+    #[test]
+    fn test_is_valid_reaction() {
+        assert!(is_valid_reaction("👍"));
+        assert!(is_valid_reaction("👍🏽"));
+        assert!(is_valid_reaction("👨‍👩‍👧"));
+        assert!(!is_valid_reaction(""));
+        assert!(!is_valid_reaction("lol"));
+        assert!(!is_valid_reaction("👍 👍"));
+        assert!(!is_valid_reaction("🎉🎉🎉🎉🎉🎉🎉🎉🎉"));
+    }
+
+    #[test]
+    fn test_summarize_reactions_keeps_first_seen_order() {
+        let alice = Uuid::new_v4();
+        let bob = Uuid::new_v4();
+
+        let summaries = summarize_reactions(vec![
+            ("❤️".to_string(), alice),
+            ("👍".to_string(), bob),
+            ("❤️".to_string(), bob),
+        ]);
+
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].emoji, "❤️");
+        assert_eq!(summaries[0].user_ids, vec![alice, bob]);
+        assert_eq!(summaries[1].user_ids, vec![bob]);
+    }
+
+    #[tokio::test]
+    async fn test_replies_and_reactions() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let alice = test_utils::create_test_user(&pool, "alice@test.com", Role::Member).await;
+        let bob = test_utils::create_test_user(&pool, "bob@test.com", Role::Member).await;
+
+        let parent = create_message(&pool, &household_id, &alice, "buy milk", None).await.unwrap();
+        let reply = create_message(&pool, &household_id, &bob, "on it", Some(&parent.id)).await.unwrap();
+        assert_eq!(reply.reply_to_message_id, Some(parent.id));
+
+        let replies = list_replies(&pool, &parent.id).await.unwrap();
+        assert_eq!(replies.len(), 1);
+        assert_eq!(replies[0].message.id, reply.id);
+
+        add_reaction(&pool, &reply.id, &alice, "👍").await.unwrap();
+        let reactions = add_reaction(&pool, &reply.id, &alice, "👍").await.unwrap();
+        assert_eq!(reactions.len(), 1);
+        assert_eq!(reactions[0].user_ids, vec![alice]);
+
+        let listed = list_messages(&pool, &household_id, 50, None).await.unwrap();
+        let listed_parent = listed.iter().find(|m| m.message.id == parent.id).unwrap();
+        let listed_reply = listed.iter().find(|m| m.message.id == reply.id).unwrap();
+        assert_eq!(listed_parent.reply_count, 1);
+        assert_eq!(listed_reply.reactions.len(), 1);
+
+        let reactions = remove_reaction(&pool, &reply.id, &alice, "👍").await.unwrap();
+        assert!(reactions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reply_to_unknown_message_fails() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user = test_utils::create_test_user(&pool, "alice@test.com", Role::Member).await;
+
+        let result = create_message(&pool, &household_id, &user, "hi", Some(&Uuid::new_v4())).await;
+        assert!(matches!(result, Err(ChatError::InvalidReply)));
+    }
 }
@@ -0,0 +1,271 @@
+//! Posts household events to a Matrix room or Telegram group chat
+//!
+//! Many families already coordinate in a group chat, so selected events (overdue
+//! tasks, completions waiting for review, new announcements) are mirrored there
+//! as short text messages. The bridge is configured server-wide through the
+//! environment; each message is prefixed with the household name. Posting runs
+//! detached from the request and failures are only logged.
+
+use std::collections::HashSet;
+use std::str::FromStr;
+use std::time::Duration;
+
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::config::Config;
+use crate::services::households as household_service;
+
+const TELEGRAM_API_URL: &str = "https://api.telegram.org";
+const REQUEST_TIMEOUT_SECS: u64 = 10;
+
+#[derive(Debug, Error)]
+pub enum ChatBridgeError {
+    #[error("HTTP error: {0}")]
+    Http(#[from] reqwest::Error),
+    #[error("Invalid Matrix homeserver URL")]
+    InvalidUrl,
+    #[error("Chat service replied with HTTP {0}")]
+    Status(u16),
+}
+
+/// Household events that can be posted to the chat
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BridgeEvent {
+    TaskOverdue,
+    ReviewRequested,
+    AnnouncementCreated,
+}
+
+impl BridgeEvent {
+    pub const ALL: [BridgeEvent; 3] = [
+        BridgeEvent::TaskOverdue,
+        BridgeEvent::ReviewRequested,
+        BridgeEvent::AnnouncementCreated,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BridgeEvent::TaskOverdue => "task_overdue",
+            BridgeEvent::ReviewRequested => "review_requested",
+            BridgeEvent::AnnouncementCreated => "announcement_created",
+        }
+    }
+}
+
+impl FromStr for BridgeEvent {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BridgeEvent::ALL.into_iter().find(|event| event.as_str() == s).ok_or(())
+    }
+}
+
+/// Where messages are posted
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChatTarget {
+    Telegram { bot_token: String, chat_id: String },
+    Matrix { homeserver_url: String, access_token: String, room_id: String },
+}
+
+impl ChatTarget {
+    async fn send(&self, client: &reqwest::Client, text: &str) -> Result<(), ChatBridgeError> {
+        let response = match self {
+            ChatTarget::Telegram { bot_token, chat_id } => {
+                client
+                    .post(format!("{}/bot{}/sendMessage", TELEGRAM_API_URL, bot_token))
+                    .json(&serde_json::json!({ "chat_id": chat_id, "text": text }))
+                    .send()
+                    .await?
+            }
+            ChatTarget::Matrix { homeserver_url, access_token, room_id } => {
+                let url = matrix_send_url(homeserver_url, room_id, &Uuid::new_v4().to_string())?;
+                client
+                    .put(url)
+                    .bearer_auth(access_token)
+                    .json(&serde_json::json!({ "msgtype": "m.text", "body": text }))
+                    .send()
+                    .await?
+            }
+        };
+
+        if !response.status().is_success() {
+            return Err(ChatBridgeError::Status(response.status().as_u16()));
+        }
+        Ok(())
+    }
+}
+
+/// `PUT` endpoint for a room message; the transaction id makes retries idempotent on the server
+fn matrix_send_url(homeserver_url: &str, room_id: &str, txn_id: &str) -> Result<reqwest::Url, ChatBridgeError> {
+    let mut url = reqwest::Url::parse(homeserver_url).map_err(|_| ChatBridgeError::InvalidUrl)?;
+    url.path_segments_mut()
+        .map_err(|_| ChatBridgeError::InvalidUrl)?
+        .pop_if_empty()
+        .extend(["_matrix", "client", "v3", "rooms", room_id, "send", "m.room.message", txn_id]);
+    Ok(url)
+}
+
+/// Parse `CHAT_BRIDGE_EVENTS`; unknown names are logged and ignored, no names means all events
+fn parse_events(names: &[String]) -> HashSet<BridgeEvent> {
+    if names.is_empty() {
+        return BridgeEvent::ALL.into_iter().collect();
+    }
+    names
+        .iter()
+        .filter_map(|name| {
+            let event = name.parse::<BridgeEvent>().ok();
+            if event.is_none() {
+                log::warn!("Unknown chat bridge event '{}' in configuration, ignoring", name);
+            }
+            event
+        })
+        .collect()
+}
+
+/// Configured chat targets and the events they receive
+#[derive(Debug, Clone)]
+pub struct ChatBridge {
+    targets: Vec<ChatTarget>,
+    events: HashSet<BridgeEvent>,
+    client: reqwest::Client,
+}
+
+impl ChatBridge {
+    pub fn new(targets: Vec<ChatTarget>, events: HashSet<BridgeEvent>) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+            .build()
+            .unwrap_or_default();
+        Self { targets, events, client }
+    }
+
+    /// Returns `None` unless Telegram or Matrix is fully configured
+    pub fn from_config(config: &Config) -> Option<Self> {
+        let non_empty = |value: &Option<String>| value.clone().filter(|v| !v.trim().is_empty());
+
+        let mut targets = Vec::new();
+        if let (Some(bot_token), Some(chat_id)) = (non_empty(&config.telegram_bot_token), non_empty(&config.telegram_chat_id)) {
+            targets.push(ChatTarget::Telegram { bot_token, chat_id });
+        }
+        if let (Some(homeserver_url), Some(access_token), Some(room_id)) = (
+            non_empty(&config.matrix_homeserver_url),
+            non_empty(&config.matrix_access_token),
+            non_empty(&config.matrix_room_id),
+        ) {
+            targets.push(ChatTarget::Matrix { homeserver_url, access_token, room_id });
+        }
+        if targets.is_empty() {
+            return None;
+        }
+
+        Some(Self::new(targets, parse_events(&config.chat_bridge_events)))
+    }
+
+    pub fn targets(&self) -> &[ChatTarget] {
+        &self.targets
+    }
+
+    pub fn posts(&self, event: BridgeEvent) -> bool {
+        self.events.contains(&event)
+    }
+
+    /// Send a message to every target, logging failures
+    pub async fn post(&self, text: &str) {
+        for target in &self.targets {
+            if let Err(e) = target.send(&self.client, text).await {
+                log::warn!("Failed to post to chat bridge: {}", e);
+            }
+        }
+    }
+}
+
+/// Prefix a message with the household it is about
+pub fn format_message(household_name: &str, text: &str) -> String {
+    format!("[{}] {}", household_name, text)
+}
+
+pub fn overdue_text(task_titles: &[String]) -> String {
+    format!("Overdue: {}", task_titles.join(", "))
+}
+
+pub fn review_requested_text(task_title: &str, completed_by: &str) -> String {
+    format!("{} completed \"{}\" and it needs a review", completed_by, task_title)
+}
+
+pub fn announcement_text(title: &str, content: &str) -> String {
+    if content.trim().is_empty() {
+        format!("📢 {}", title)
+    } else {
+        format!("📢 {}\n{}", title, content.trim())
+    }
+}
+
+/// Post a household event in the background. Does nothing when the bridge is not
+/// configured or the event is not selected.
+pub fn post_in_background(
+    bridge: Option<&ChatBridge>,
+    pool: &SqlitePool,
+    household_id: Uuid,
+    event: BridgeEvent,
+    text: String,
+) {
+    let Some(bridge) = bridge.filter(|bridge| bridge.posts(event)) else {
+        return;
+    };
+    let bridge = bridge.clone();
+    let pool = pool.clone();
+    tokio::spawn(async move {
+        let household_name = match household_service::get_household(&pool, &household_id).await {
+            Ok(Some(household)) => household.name,
+            Ok(None) => return,
+            Err(e) => {
+                log::warn!("Error loading household for chat bridge: {:?}", e);
+                return;
+            }
+        };
+        bridge.post(&format_message(&household_name, &text)).await;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_events() {
+        assert_eq!(parse_events(&[]).len(), BridgeEvent::ALL.len());
+
+        let events = parse_events(&["review_requested".to_string(), "bogus".to_string()]);
+        assert_eq!(events, HashSet::from([BridgeEvent::ReviewRequested]));
+    }
+
+    #[test]
+    fn test_posts_only_selected_events() {
+        let target = ChatTarget::Telegram { bot_token: "123:abc".to_string(), chat_id: "-1001".to_string() };
+        let bridge = ChatBridge::new(vec![target], HashSet::from([BridgeEvent::TaskOverdue]));
+        assert!(bridge.posts(BridgeEvent::TaskOverdue));
+        assert!(!bridge.posts(BridgeEvent::AnnouncementCreated));
+    }
+
+    #[test]
+    fn test_matrix_send_url() {
+        let url = matrix_send_url("https://matrix.example.org/", "!room:example.org", "txn1").unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://matrix.example.org/_matrix/client/v3/rooms/!room:example.org/send/m.room.message/txn1"
+        );
+        assert!(matrix_send_url("not a url", "!room", "txn").is_err());
+    }
+
+    #[test]
+    fn test_message_texts() {
+        assert_eq!(format_message("Home", "hi"), "[Home] hi");
+        assert_eq!(
+            overdue_text(&["Dishes".to_string(), "Laundry".to_string()]),
+            "Overdue: Dishes, Laundry"
+        );
+        assert_eq!(announcement_text("Party", " "), "📢 Party");
+    }
+}
@@ -0,0 +1,302 @@
+//! Tabular CSV exports of household history for analysis in a spreadsheet
+//!
+//! Unlike the JSON export, which backs up the household configuration, these
+//! exports contain one row per completion or point change. Rows are read page
+//! by page and streamed, so large histories never have to fit in memory.
+//! Date filters are inclusive and refer to the household's local dates.
+
+use std::future::Future;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use futures::stream::{self, Stream, StreamExt};
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::services::scheduler;
+
+/// Rows fetched per query while streaming
+const PAGE_SIZE: i64 = 500;
+
+pub const COMPLETIONS_HEADER: &str = "completed_at,due_date,task_id,task,user_id,user,status\r\n";
+pub const POINTS_HEADER: &str =
+    "created_at,user_id,user,amount,balance_after,type,task_id,reward_id,description\r\n";
+
+#[derive(Debug, Error)]
+pub enum CsvExportError {
+    #[error("Invalid date range")]
+    InvalidRange,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+/// Quote a field if needed. Text that a spreadsheet would evaluate as a formula
+/// is prefixed with `'`, since titles and names come from other members.
+fn text_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@']) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+fn validate_range(from: Option<NaiveDate>, to: Option<NaiveDate>) -> Result<(), CsvExportError> {
+    match (from, to) {
+        (Some(from), Some(to)) if from > to => Err(CsvExportError::InvalidRange),
+        _ => Ok(()),
+    }
+}
+
+/// Header followed by the pages returned by `fetch_page(offset)` until one comes back short
+fn paged_csv<F, Fut>(header: &'static str, mut fetch_page: F) -> impl Stream<Item = Result<String, CsvExportError>>
+where
+    F: FnMut(i64) -> Fut + 'static,
+    Fut: Future<Output = Result<Vec<String>, CsvExportError>> + 'static,
+{
+    let pages = stream::unfold(Some(0), move |offset: Option<i64>| {
+        let page = offset.map(&mut fetch_page);
+        async move {
+            let (offset, page) = (offset?, page?);
+            match page.await {
+                Ok(lines) if lines.is_empty() => None,
+                Ok(lines) => {
+                    let next = (lines.len() as i64 == PAGE_SIZE).then_some(offset + PAGE_SIZE);
+                    Some((Ok(lines.concat()), next))
+                }
+                Err(e) => Some((Err(e), None)),
+            }
+        }
+    });
+    stream::once(async move { Ok(header.to_string()) }).chain(pages)
+}
+
+#[derive(sqlx::FromRow)]
+struct CompletionCsvRow {
+    completed_at: DateTime<Utc>,
+    due_date: NaiveDate,
+    task_id: String,
+    task_title: String,
+    user_id: String,
+    username: String,
+    status: String,
+}
+
+impl CompletionCsvRow {
+    fn to_line(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{}\r\n",
+            self.completed_at.to_rfc3339(),
+            self.due_date,
+            self.task_id,
+            text_field(&self.task_title),
+            self.user_id,
+            text_field(&self.username),
+            self.status
+        )
+    }
+}
+
+/// All task completions of the household, oldest first, filtered by due date
+pub fn completions_csv(
+    pool: SqlitePool,
+    household_id: Uuid,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+) -> Result<impl Stream<Item = Result<String, CsvExportError>>, CsvExportError> {
+    validate_range(from, to)?;
+
+    Ok(paged_csv(COMPLETIONS_HEADER, move |offset| {
+        let pool = pool.clone();
+        async move {
+            let rows: Vec<CompletionCsvRow> = sqlx::query_as(
+                r#"
+                SELECT tc.completed_at, tc.due_date, tc.task_id, t.title AS task_title,
+                       tc.user_id, u.username, tc.status
+                FROM task_completions tc
+                JOIN tasks t ON t.id = tc.task_id
+                JOIN users u ON u.id = tc.user_id
+                WHERE t.household_id = ?
+                  AND (? IS NULL OR tc.due_date >= ?)
+                  AND (? IS NULL OR tc.due_date <= ?)
+                ORDER BY tc.completed_at, tc.id
+                LIMIT ? OFFSET ?
+                "#,
+            )
+            .bind(household_id.to_string())
+            .bind(from)
+            .bind(from)
+            .bind(to)
+            .bind(to)
+            .bind(PAGE_SIZE)
+            .bind(offset)
+            .fetch_all(&pool)
+            .await?;
+            Ok(rows.iter().map(CompletionCsvRow::to_line).collect())
+        }
+    }))
+}
+
+#[derive(sqlx::FromRow)]
+struct PointCsvRow {
+    created_at: DateTime<Utc>,
+    user_id: String,
+    username: String,
+    amount: i64,
+    balance_after: i64,
+    transaction_type: String,
+    task_id: Option<String>,
+    reward_id: Option<String>,
+    description: Option<String>,
+}
+
+impl PointCsvRow {
+    fn to_line(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{}\r\n",
+            self.created_at.to_rfc3339(),
+            self.user_id,
+            text_field(&self.username),
+            self.amount,
+            self.balance_after,
+            self.transaction_type,
+            self.task_id.as_deref().unwrap_or_default(),
+            self.reward_id.as_deref().unwrap_or_default(),
+            text_field(self.description.as_deref().unwrap_or_default())
+        )
+    }
+}
+
+/// The household's point ledger, oldest first, filtered by the local date of the change
+pub async fn points_csv(
+    pool: SqlitePool,
+    household_id: Uuid,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+) -> Result<impl Stream<Item = Result<String, CsvExportError>>, CsvExportError> {
+    validate_range(from, to)?;
+
+    let timezone: Option<String> = sqlx::query_scalar("SELECT timezone FROM household_settings WHERE household_id = ?")
+        .bind(household_id.to_string())
+        .fetch_optional(&pool)
+        .await?;
+    let tz = scheduler::parse_timezone(timezone.as_deref().unwrap_or("UTC"));
+    let start = from.map(|date| scheduler::local_midnight_utc(date, tz).to_rfc3339());
+    let end = to
+        .and_then(|date| date.succ_opt())
+        .map(|date| scheduler::local_midnight_utc(date, tz).to_rfc3339());
+
+    Ok(paged_csv(POINTS_HEADER, move |offset| {
+        let pool = pool.clone();
+        let (start, end) = (start.clone(), end.clone());
+        async move {
+            let rows: Vec<PointCsvRow> = sqlx::query_as(
+                r#"
+                SELECT pt.created_at, pt.user_id, u.username, pt.amount, pt.balance_after,
+                       pt.transaction_type, pt.task_id, pt.reward_id, pt.description
+                FROM point_transactions pt
+                JOIN users u ON u.id = pt.user_id
+                WHERE pt.household_id = ?
+                  AND (? IS NULL OR julianday(pt.created_at) >= julianday(?))
+                  AND (? IS NULL OR julianday(pt.created_at) < julianday(?))
+                ORDER BY pt.created_at, pt.id
+                LIMIT ? OFFSET ?
+                "#,
+            )
+            .bind(household_id.to_string())
+            .bind(&start)
+            .bind(&start)
+            .bind(&end)
+            .bind(&end)
+            .bind(PAGE_SIZE)
+            .bind(offset)
+            .fetch_all(&pool)
+            .await?;
+            Ok(rows.iter().map(PointCsvRow::to_line).collect())
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::{points as points_service, tasks as task_service};
+    use crate::test_utils::{create_test_household, create_test_membership, create_test_pool, create_test_task, create_test_user};
+    use futures::TryStreamExt;
+    use shared::{PointTransactionType, Role};
+
+    #[test]
+    fn test_text_field_escaping() {
+        assert_eq!(text_field("Dishes"), "Dishes");
+        assert_eq!(text_field("Wash, dry"), "\"Wash, dry\"");
+        assert_eq!(text_field("The \"big\" clean"), "\"The \"\"big\"\" clean\"");
+        assert_eq!(text_field("=HYPERLINK(\"x\")"), "\"'=HYPERLINK(\"\"x\"\")\"");
+    }
+
+    #[tokio::test]
+    async fn test_completions_csv_filters_by_due_date() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let user_id = create_test_user(&pool, "alice@test.com", Role::Member).await;
+        create_test_membership(&pool, &household_id, &user_id, Role::Member).await;
+        let task = create_test_task(&pool, &household_id).build().await;
+
+        task_service::complete_task(&pool, &task.id, &user_id, &household_id).await.unwrap();
+
+        let today = Utc::now().date_naive();
+        let csv: String = completions_csv(pool.clone(), household_id, Some(today - chrono::Duration::days(1)), None)
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap()
+            .concat();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], COMPLETIONS_HEADER.trim_end());
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].contains(",alice,approved"));
+
+        let csv: String = completions_csv(pool.clone(), household_id, Some(today + chrono::Duration::days(1)), None)
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap()
+            .concat();
+        assert_eq!(csv, COMPLETIONS_HEADER);
+
+        assert!(matches!(
+            completions_csv(pool, household_id, Some(today), Some(today - chrono::Duration::days(1))),
+            Err(CsvExportError::InvalidRange)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_points_csv_lists_ledger() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let user_id = create_test_user(&pool, "alice@test.com", Role::Member).await;
+        create_test_membership(&pool, &household_id, &user_id, Role::Member).await;
+
+        let source = points_service::TransactionSource {
+            description: Some("-bonus".to_string()),
+            ..Default::default()
+        };
+        points_service::apply_points(&pool, &household_id, &user_id, 5, PointTransactionType::ManualAdjustment, &source)
+            .await
+            .unwrap();
+
+        let csv: String = points_csv(pool, household_id, None, None)
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap()
+            .concat();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].contains(",alice,5,5,manual_adjustment,,,'-bonus"));
+    }
+}
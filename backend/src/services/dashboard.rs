@@ -0,0 +1,105 @@
+//! Filtering, grouping and summary counts for the tasks of all of a user's households
+
+use chrono::{Days, NaiveDate};
+use uuid::Uuid;
+
+use shared::{
+    AllDashboardTasksResponse, DashboardGroupBy, DashboardTaskGroup, DashboardTaskQuery, DashboardTaskSummary,
+    DashboardTaskWithHousehold, DueWindow,
+};
+
+fn is_assigned_to(task: &DashboardTaskWithHousehold, user_id: &Uuid) -> bool {
+    task.task_with_status.task.assignees().contains(user_id)
+}
+
+/// Keep the tasks matching the due window and assignment criteria of the query.
+/// The household criterion is applied while loading the tasks.
+pub fn filter_tasks(
+    tasks: Vec<DashboardTaskWithHousehold>,
+    query: &DashboardTaskQuery,
+    user_id: &Uuid,
+    today: NaiveDate,
+) -> Vec<DashboardTaskWithHousehold> {
+    tasks
+        .into_iter()
+        .filter(|task| !query.assigned_to_me || is_assigned_to(task, user_id))
+        .filter(|task| {
+            query.due_within_days.is_none_or(|days| {
+                // A window reaching past the last representable date has no upper bound
+                let last_day = today.checked_add_days(Days::new(days.max(0).unsigned_abs()));
+                task.task_with_status
+                    .next_due_date
+                    .is_some_and(|due| last_day.is_none_or(|last_day| due <= last_day))
+            })
+        })
+        .collect()
+}
+
+pub fn summarize(tasks: &[DashboardTaskWithHousehold], user_id: &Uuid, today: NaiveDate) -> DashboardTaskSummary {
+    let count = |predicate: &dyn Fn(&DashboardTaskWithHousehold) -> bool| {
+        tasks.iter().filter(|task| predicate(task)).count() as i32
+    };
+    let mut households: Vec<Uuid> = tasks.iter().map(|task| task.household_id).collect();
+    households.sort();
+    households.dedup();
+
+    DashboardTaskSummary {
+        total: tasks.len() as i32,
+        due_today: count(&|task| {
+            DueWindow::for_due_date(task.task_with_status.next_due_date, today) == DueWindow::Today
+        }),
+        completed_today: count(&|task| {
+            task.task_with_status.completions_today >= task.task_with_status.task.target_count
+        }),
+        assigned_to_me: count(&|task| is_assigned_to(task, user_id)),
+        households: households.len() as i32,
+    }
+}
+
+/// Split the tasks into groups, keeping their order within each group.
+/// Households are sorted by name, due windows from soonest to unscheduled.
+pub fn group_tasks(
+    tasks: &[DashboardTaskWithHousehold],
+    group_by: DashboardGroupBy,
+    today: NaiveDate,
+) -> Vec<DashboardTaskGroup> {
+    let mut groups: Vec<DashboardTaskGroup> = Vec::new();
+    for task in tasks {
+        let (key, label) = match group_by {
+            DashboardGroupBy::Household => (task.household_id.to_string(), task.household_name.clone()),
+            DashboardGroupBy::DueWindow => {
+                let window = DueWindow::for_due_date(task.task_with_status.next_due_date, today).as_str();
+                (window.to_string(), window.to_string())
+            }
+        };
+        let task_id = task.task_with_status.task.id;
+        match groups.iter_mut().find(|group| group.key == key) {
+            Some(group) => group.task_ids.push(task_id),
+            None => groups.push(DashboardTaskGroup { key, label, task_ids: vec![task_id] }),
+        }
+    }
+
+    match group_by {
+        DashboardGroupBy::Household => groups.sort_by_key(|group| group.label.to_lowercase()),
+        DashboardGroupBy::DueWindow => {
+            let order = [DueWindow::Today, DueWindow::ThisWeek, DueWindow::Later, DueWindow::Unscheduled];
+            groups.sort_by_key(|group| order.iter().position(|window| window.as_str() == group.key));
+        }
+    }
+    groups
+}
+
+/// Filter the tasks and add the requested groups and the summary counts
+pub fn build_response(
+    tasks: Vec<DashboardTaskWithHousehold>,
+    query: &DashboardTaskQuery,
+    user_id: &Uuid,
+    today: NaiveDate,
+) -> AllDashboardTasksResponse {
+    let tasks = filter_tasks(tasks, query, user_id, today);
+    AllDashboardTasksResponse {
+        groups: query.group_by.map(|group_by| group_tasks(&tasks, group_by, today)).unwrap_or_default(),
+        summary: summarize(&tasks, user_id, today),
+        tasks,
+    }
+}
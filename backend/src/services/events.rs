@@ -0,0 +1,309 @@
+//! Append-only log of completion and period result changes, summed into daily
+//! rollups per task so statistics don't have to count the history on every request
+
+use chrono::{NaiveDate, Utc};
+use shared::PeriodStatus;
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    Completion,
+    PeriodCompleted,
+    PeriodFailed,
+    PeriodSkipped,
+}
+
+impl EventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventKind::Completion => "completion",
+            EventKind::PeriodCompleted => "period_completed",
+            EventKind::PeriodFailed => "period_failed",
+            EventKind::PeriodSkipped => "period_skipped",
+        }
+    }
+
+    pub fn for_period(status: PeriodStatus) -> Self {
+        match status {
+            PeriodStatus::Completed => EventKind::PeriodCompleted,
+            PeriodStatus::Failed => EventKind::PeriodFailed,
+            PeriodStatus::Skipped => EventKind::PeriodSkipped,
+        }
+    }
+}
+
+/// Sums of the events of a task, or of a day range of it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EventTotals {
+    pub completions: i64,
+    pub periods_completed: i64,
+    pub periods_failed: i64,
+    pub periods_skipped: i64,
+}
+
+impl EventTotals {
+    fn add(&mut self, kind: &str, delta: i64) {
+        match kind {
+            "completion" => self.completions += delta,
+            "period_completed" => self.periods_completed += delta,
+            "period_failed" => self.periods_failed += delta,
+            "period_skipped" => self.periods_skipped += delta,
+            _ => {}
+        }
+    }
+
+    fn merge(&mut self, other: EventTotals) {
+        self.completions += other.completions;
+        self.periods_completed += other.periods_completed;
+        self.periods_failed += other.periods_failed;
+        self.periods_skipped += other.periods_skipped;
+    }
+}
+
+/// Append an event. `day` is the completion's due date or the period start.
+pub async fn record(
+    pool: &SqlitePool,
+    task_id: &Uuid,
+    user_id: Option<&Uuid>,
+    kind: EventKind,
+    day: NaiveDate,
+    delta: i64,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("INSERT INTO events (task_id, user_id, kind, day, delta, created_at) VALUES (?, ?, ?, ?, ?, ?)")
+        .bind(task_id.to_string())
+        .bind(user_id.map(|id| id.to_string()))
+        .bind(kind.as_str())
+        .bind(day)
+        .bind(delta)
+        .bind(Utc::now())
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// Record a period result going from `old` to `new` (None when there is no result)
+pub async fn record_period_change(
+    pool: &SqlitePool,
+    task_id: &Uuid,
+    period_start: NaiveDate,
+    old: Option<PeriodStatus>,
+    new: Option<PeriodStatus>,
+) -> Result<(), sqlx::Error> {
+    if old == new {
+        return Ok(());
+    }
+    if let Some(old) = old {
+        record(pool, task_id, None, EventKind::for_period(old), period_start, -1).await?;
+    }
+    if let Some(new) = new {
+        record(pool, task_id, None, EventKind::for_period(new), period_start, 1).await?;
+    }
+    Ok(())
+}
+
+/// Add the events recorded since the last run to the daily rollups.
+/// Returns the number of events rolled up.
+pub async fn refresh_rollups(pool: &SqlitePool) -> Result<i64, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let last_event_id: i64 = sqlx::query_scalar("SELECT last_event_id FROM event_rollup_cursor WHERE id = 1")
+        .fetch_one(&mut *tx)
+        .await?;
+    let newest_event_id: Option<i64> = sqlx::query_scalar("SELECT MAX(id) FROM events")
+        .fetch_one(&mut *tx)
+        .await?;
+    let Some(newest_event_id) = newest_event_id.filter(|id| *id > last_event_id) else {
+        return Ok(0);
+    };
+
+    sqlx::query(
+        r#"
+        INSERT INTO task_daily_rollups (task_id, day, completions, periods_completed, periods_failed, periods_skipped)
+        SELECT task_id, day,
+            SUM(CASE WHEN kind = 'completion' THEN delta ELSE 0 END),
+            SUM(CASE WHEN kind = 'period_completed' THEN delta ELSE 0 END),
+            SUM(CASE WHEN kind = 'period_failed' THEN delta ELSE 0 END),
+            SUM(CASE WHEN kind = 'period_skipped' THEN delta ELSE 0 END)
+        FROM events
+        WHERE id > ? AND id <= ?
+        GROUP BY task_id, day
+        ON CONFLICT (task_id, day) DO UPDATE SET
+            completions = completions + excluded.completions,
+            periods_completed = periods_completed + excluded.periods_completed,
+            periods_failed = periods_failed + excluded.periods_failed,
+            periods_skipped = periods_skipped + excluded.periods_skipped
+        "#,
+    )
+    .bind(last_event_id)
+    .bind(newest_event_id)
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query("UPDATE event_rollup_cursor SET last_event_id = ? WHERE id = 1")
+        .bind(newest_event_id)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+    Ok(newest_event_id - last_event_id)
+}
+
+/// Totals of a task for days in `start..=end`; None leaves that side open.
+/// Events not rolled up yet are added, so the totals are always current.
+pub async fn task_totals(
+    pool: &SqlitePool,
+    task_id: &Uuid,
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+) -> Result<EventTotals, sqlx::Error> {
+    let (completions, periods_completed, periods_failed, periods_skipped): (i64, i64, i64, i64) = sqlx::query_as(
+        r#"
+        SELECT COALESCE(SUM(completions), 0), COALESCE(SUM(periods_completed), 0),
+            COALESCE(SUM(periods_failed), 0), COALESCE(SUM(periods_skipped), 0)
+        FROM task_daily_rollups
+        WHERE task_id = ? AND (? IS NULL OR day >= ?) AND (? IS NULL OR day <= ?)
+        "#,
+    )
+    .bind(task_id.to_string())
+    .bind(start)
+    .bind(start)
+    .bind(end)
+    .bind(end)
+    .fetch_one(pool)
+    .await?;
+    let mut totals = EventTotals { completions, periods_completed, periods_failed, periods_skipped };
+
+    let pending: Vec<(String, i64)> = sqlx::query_as(
+        r#"
+        SELECT kind, SUM(delta) FROM events
+        WHERE task_id = ? AND id > (SELECT last_event_id FROM event_rollup_cursor WHERE id = 1)
+        AND (? IS NULL OR day >= ?) AND (? IS NULL OR day <= ?)
+        GROUP BY kind
+        "#,
+    )
+    .bind(task_id.to_string())
+    .bind(start)
+    .bind(start)
+    .bind(end)
+    .bind(end)
+    .fetch_all(pool)
+    .await?;
+    for (kind, delta) in pending {
+        totals.add(&kind, delta);
+    }
+
+    Ok(totals)
+}
+
+/// Totals per task of a household for days in `start..=end`, including tasks
+/// that are archived or in the trash. Tasks without events are left out.
+pub async fn household_task_totals(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Result<HashMap<Uuid, EventTotals>, sqlx::Error> {
+    let rolled_up: Vec<(String, i64, i64, i64, i64)> = sqlx::query_as(
+        r#"
+        SELECT r.task_id, SUM(r.completions), SUM(r.periods_completed), SUM(r.periods_failed), SUM(r.periods_skipped)
+        FROM task_daily_rollups r
+        JOIN tasks t ON r.task_id = t.id
+        WHERE t.household_id = ? AND r.day >= ? AND r.day <= ?
+        GROUP BY r.task_id
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(start)
+    .bind(end)
+    .fetch_all(pool)
+    .await?;
+
+    let pending: Vec<(String, String, i64)> = sqlx::query_as(
+        r#"
+        SELECT e.task_id, e.kind, SUM(e.delta)
+        FROM events e
+        JOIN tasks t ON e.task_id = t.id
+        WHERE e.id > (SELECT last_event_id FROM event_rollup_cursor WHERE id = 1)
+        AND t.household_id = ? AND e.day >= ? AND e.day <= ?
+        GROUP BY e.task_id, e.kind
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(start)
+    .bind(end)
+    .fetch_all(pool)
+    .await?;
+
+    let mut totals: HashMap<Uuid, EventTotals> = HashMap::new();
+    for (task_id, completions, periods_completed, periods_failed, periods_skipped) in rolled_up {
+        let Ok(task_id) = Uuid::parse_str(&task_id) else { continue };
+        totals.entry(task_id).or_default().merge(EventTotals {
+            completions,
+            periods_completed,
+            periods_failed,
+            periods_skipped,
+        });
+    }
+    for (task_id, kind, delta) in pending {
+        let Ok(task_id) = Uuid::parse_str(&task_id) else { continue };
+        totals.entry(task_id).or_default().add(&kind, delta);
+    }
+    Ok(totals)
+}
+
+/// Drop the events and rollups of a permanently deleted task
+pub async fn delete_for_task(pool: &SqlitePool, task_id: &Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM events WHERE task_id = ?")
+        .bind(task_id.to_string())
+        .execute(pool)
+        .await?;
+    sqlx::query("DELETE FROM task_daily_rollups WHERE task_id = ?")
+        .bind(task_id.to_string())
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 1, d).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_totals_combine_rollups_and_pending_events() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let task = test_utils::create_test_task(&pool, &household_id).build().await;
+
+        record(&pool, &task.id, None, EventKind::Completion, day(1), 1).await.unwrap();
+        record_period_change(&pool, &task.id, day(1), None, Some(PeriodStatus::Failed)).await.unwrap();
+        assert_eq!(refresh_rollups(&pool).await.unwrap(), 2);
+        assert_eq!(refresh_rollups(&pool).await.unwrap(), 0);
+
+        // A late completion turns the failed period into a completed one
+        record(&pool, &task.id, None, EventKind::Completion, day(1), 1).await.unwrap();
+        record_period_change(&pool, &task.id, day(1), Some(PeriodStatus::Failed), Some(PeriodStatus::Completed))
+            .await
+            .unwrap();
+        record_period_change(&pool, &task.id, day(2), None, Some(PeriodStatus::Skipped)).await.unwrap();
+
+        let expected = EventTotals { completions: 2, periods_completed: 1, periods_failed: 0, periods_skipped: 1 };
+        assert_eq!(task_totals(&pool, &task.id, None, None).await.unwrap(), expected);
+        let by_task = household_task_totals(&pool, &household_id, day(1), day(31)).await.unwrap();
+        assert_eq!(by_task.get(&task.id), Some(&expected));
+
+        // Rolling up doesn't change the totals, only where they come from
+        refresh_rollups(&pool).await.unwrap();
+        assert_eq!(task_totals(&pool, &task.id, None, None).await.unwrap(), expected);
+        assert_eq!(
+            task_totals(&pool, &task.id, Some(day(2)), Some(day(2))).await.unwrap(),
+            EventTotals { periods_skipped: 1, ..Default::default() }
+        );
+    }
+}
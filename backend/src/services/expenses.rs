@@ -0,0 +1,504 @@
+use chrono::{Datelike, NaiveDate, Utc};
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::ExpenseRow;
+use crate::services::households as household_service;
+use shared::{
+    CreateExpenseRequest, Expense, ExpenseCategoryTotal, ExpenseMemberTotal,
+    ExpenseMonthlySummary, ExpenseWithUser, UpdateExpenseRequest, User,
+};
+
+#[derive(Debug, Error)]
+pub enum ExpenseError {
+    #[error("Expense not found")]
+    NotFound,
+    #[error("Permission denied")]
+    PermissionDenied,
+    #[error("Amount must be greater than zero")]
+    InvalidAmount,
+    #[error("Payer is not a member of this household")]
+    InvalidPayer,
+    #[error("Invalid month")]
+    InvalidMonth,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+/// First day of the given month and first day of the following month
+pub fn month_bounds(year: i32, month: u32) -> Option<(NaiveDate, NaiveDate)> {
+    let start = NaiveDate::from_ymd_opt(year, month, 1)?;
+    let end = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)?
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)?
+    };
+    Some((start, end))
+}
+
+/// Resolve an optional year/month pair, falling back to the current month
+pub fn resolve_month(year: Option<i32>, month: Option<u32>) -> (i32, u32) {
+    let today = Utc::now().date_naive();
+    (year.unwrap_or(today.year()), month.unwrap_or(today.month()))
+}
+
+/// The creator, the payer and household managers may edit or delete an expense
+pub fn can_modify_expense(expense: &Expense, user_id: &Uuid, can_manage: bool) -> bool {
+    can_manage || expense.created_by == *user_id || expense.paid_by == *user_id
+}
+
+async fn ensure_payer_is_member(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    payer: &Uuid,
+) -> Result<(), ExpenseError> {
+    if household_service::is_member(pool, household_id, payer)
+        .await
+        .unwrap_or(false)
+    {
+        Ok(())
+    } else {
+        Err(ExpenseError::InvalidPayer)
+    }
+}
+
+pub async fn create_expense(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    request: &CreateExpenseRequest,
+) -> Result<Expense, ExpenseError> {
+    if request.amount_cents <= 0 {
+        return Err(ExpenseError::InvalidAmount);
+    }
+
+    let paid_by = request.paid_by.unwrap_or(*user_id);
+    ensure_payer_is_member(pool, household_id, &paid_by).await?;
+
+    let id = Uuid::new_v4();
+    let now = Utc::now();
+    let expense_date = request.expense_date.unwrap_or_else(|| now.date_naive());
+    let receipt_note = request
+        .receipt_note
+        .as_ref()
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty());
+
+    sqlx::query(
+        r#"
+        INSERT INTO expenses (id, household_id, paid_by, amount_cents, category, description,
+                              receipt_note, expense_date, created_by, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(id.to_string())
+    .bind(household_id.to_string())
+    .bind(paid_by.to_string())
+    .bind(request.amount_cents)
+    .bind(request.category.as_str())
+    .bind(request.description.trim())
+    .bind(&receipt_note)
+    .bind(expense_date)
+    .bind(user_id.to_string())
+    .bind(now)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    Ok(Expense {
+        id,
+        household_id: *household_id,
+        paid_by,
+        amount_cents: request.amount_cents,
+        category: request.category,
+        description: request.description.trim().to_string(),
+        receipt_note,
+        expense_date,
+        created_by: *user_id,
+        created_at: now,
+        updated_at: now,
+    })
+}
+
+pub async fn get_expense(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    expense_id: &Uuid,
+) -> Result<Option<Expense>, ExpenseError> {
+    let row: Option<ExpenseRow> =
+        sqlx::query_as("SELECT * FROM expenses WHERE id = ? AND household_id = ?")
+            .bind(expense_id.to_string())
+            .bind(household_id.to_string())
+            .fetch_optional(pool)
+            .await?;
+
+    Ok(row.map(|r| r.to_shared()))
+}
+
+/// List the expenses of one month, newest first
+pub async fn list_expenses(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    year: i32,
+    month: u32,
+) -> Result<Vec<ExpenseWithUser>, ExpenseError> {
+    #[derive(sqlx::FromRow)]
+    struct ExpenseWithUserRow {
+        #[sqlx(flatten)]
+        expense: ExpenseRow,
+        u_username: String,
+        u_email: String,
+        u_created_at: chrono::DateTime<chrono::Utc>,
+        u_updated_at: chrono::DateTime<chrono::Utc>,
+    }
+
+    let (start, end) = month_bounds(year, month).ok_or(ExpenseError::InvalidMonth)?;
+
+    let rows: Vec<ExpenseWithUserRow> = sqlx::query_as(
+        r#"
+        SELECT e.*,
+            u.username as u_username, u.email as u_email,
+            u.created_at as u_created_at, u.updated_at as u_updated_at
+        FROM expenses e
+        JOIN users u ON e.paid_by = u.id
+        WHERE e.household_id = ? AND e.expense_date >= ? AND e.expense_date < ?
+        ORDER BY e.expense_date DESC, e.created_at DESC
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(start)
+    .bind(end)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let expense = row.expense.to_shared();
+            let payer = User {
+                id: expense.paid_by,
+                username: row.u_username,
+                email: row.u_email,
+                created_at: row.u_created_at,
+                updated_at: row.u_updated_at,
+            };
+            ExpenseWithUser { expense, payer }
+        })
+        .collect())
+}
+
+pub async fn update_expense(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    expense_id: &Uuid,
+    user_id: &Uuid,
+    can_manage: bool,
+    request: &UpdateExpenseRequest,
+) -> Result<Expense, ExpenseError> {
+    let mut row: ExpenseRow =
+        sqlx::query_as("SELECT * FROM expenses WHERE id = ? AND household_id = ?")
+            .bind(expense_id.to_string())
+            .bind(household_id.to_string())
+            .fetch_optional(pool)
+            .await?
+            .ok_or(ExpenseError::NotFound)?;
+
+    if !can_modify_expense(&row.to_shared(), user_id, can_manage) {
+        return Err(ExpenseError::PermissionDenied);
+    }
+
+    if let Some(amount_cents) = request.amount_cents {
+        if amount_cents <= 0 {
+            return Err(ExpenseError::InvalidAmount);
+        }
+        row.amount_cents = amount_cents;
+    }
+    if let Some(paid_by) = request.paid_by {
+        ensure_payer_is_member(pool, household_id, &paid_by).await?;
+        row.paid_by = paid_by.to_string();
+    }
+    if let Some(category) = request.category {
+        row.category = category.as_str().to_string();
+    }
+    if let Some(ref description) = request.description {
+        row.description = description.trim().to_string();
+    }
+    if let Some(ref receipt_note) = request.receipt_note {
+        let trimmed = receipt_note.trim();
+        row.receipt_note = (!trimmed.is_empty()).then(|| trimmed.to_string());
+    }
+    if let Some(expense_date) = request.expense_date {
+        row.expense_date = expense_date;
+    }
+
+    let now = Utc::now();
+    row.updated_at = now;
+
+    sqlx::query(
+        r#"
+        UPDATE expenses
+        SET paid_by = ?, amount_cents = ?, category = ?, description = ?,
+            receipt_note = ?, expense_date = ?, updated_at = ?
+        WHERE id = ?
+        "#,
+    )
+    .bind(&row.paid_by)
+    .bind(row.amount_cents)
+    .bind(&row.category)
+    .bind(&row.description)
+    .bind(&row.receipt_note)
+    .bind(row.expense_date)
+    .bind(now)
+    .bind(expense_id.to_string())
+    .execute(pool)
+    .await?;
+
+    Ok(row.to_shared())
+}
+
+pub async fn delete_expense(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    expense_id: &Uuid,
+    user_id: &Uuid,
+    can_manage: bool,
+) -> Result<(), ExpenseError> {
+    let expense = get_expense(pool, household_id, expense_id)
+        .await?
+        .ok_or(ExpenseError::NotFound)?;
+
+    if !can_modify_expense(&expense, user_id, can_manage) {
+        return Err(ExpenseError::PermissionDenied);
+    }
+
+    sqlx::query("DELETE FROM expenses WHERE id = ?")
+        .bind(expense_id.to_string())
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Totals per paying member and per category for one month
+pub async fn monthly_summary(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    year: i32,
+    month: u32,
+) -> Result<ExpenseMonthlySummary, ExpenseError> {
+    let (start, end) = month_bounds(year, month).ok_or(ExpenseError::InvalidMonth)?;
+
+    let members: Vec<(String, String, i64)> = sqlx::query_as(
+        r#"
+        SELECT e.paid_by, u.username, SUM(e.amount_cents) as total
+        FROM expenses e
+        JOIN users u ON e.paid_by = u.id
+        WHERE e.household_id = ? AND e.expense_date >= ? AND e.expense_date < ?
+        GROUP BY e.paid_by, u.username
+        ORDER BY total DESC, u.username ASC
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(start)
+    .bind(end)
+    .fetch_all(pool)
+    .await?;
+
+    let categories: Vec<(String, i64)> = sqlx::query_as(
+        r#"
+        SELECT category, SUM(amount_cents) as total
+        FROM expenses
+        WHERE household_id = ? AND expense_date >= ? AND expense_date < ?
+        GROUP BY category
+        ORDER BY total DESC, category ASC
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(start)
+    .bind(end)
+    .fetch_all(pool)
+    .await?;
+
+    let by_member: Vec<ExpenseMemberTotal> = members
+        .into_iter()
+        .map(|(user_id, username, total_cents)| ExpenseMemberTotal {
+            user_id: Uuid::parse_str(&user_id).unwrap(),
+            username,
+            total_cents,
+        })
+        .collect();
+
+    let by_category = categories
+        .into_iter()
+        .map(|(category, total_cents)| ExpenseCategoryTotal {
+            category: category.parse().unwrap_or_default(),
+            total_cents,
+        })
+        .collect();
+
+    Ok(ExpenseMonthlySummary {
+        year,
+        month,
+        total_cents: by_member.iter().map(|m| m.total_cents).sum(),
+        by_member,
+        by_category,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use shared::{ExpenseCategory, Role};
+
+    fn request(amount_cents: i64, category: ExpenseCategory, date: NaiveDate) -> CreateExpenseRequest {
+        CreateExpenseRequest {
+            amount_cents,
+            category,
+            description: "Purchase".to_string(),
+            paid_by: None,
+            receipt_note: None,
+            expense_date: Some(date),
+        }
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_expense_error_display() {
+        assert_eq!(ExpenseError::NotFound.to_string(), "Expense not found");
+        assert_eq!(ExpenseError::InvalidAmount.to_string(), "Amount must be greater than zero");
+    }
+
+    #[test]
+    fn test_month_bounds() {
+        assert_eq!(month_bounds(2024, 2), Some((date(2024, 2, 1), date(2024, 3, 1))));
+        assert_eq!(month_bounds(2024, 12), Some((date(2024, 12, 1), date(2025, 1, 1))));
+        assert_eq!(month_bounds(2024, 13), None);
+        assert_eq!(month_bounds(2024, 0), None);
+    }
+
+    #[test]
+    fn test_can_modify_expense() {
+        let creator = Uuid::new_v4();
+        let payer = Uuid::new_v4();
+        let other = Uuid::new_v4();
+        let expense = Expense {
+            id: Uuid::new_v4(),
+            household_id: Uuid::new_v4(),
+            paid_by: payer,
+            amount_cents: 100,
+            category: ExpenseCategory::Other,
+            description: "Test".to_string(),
+            receipt_note: None,
+            expense_date: date(2024, 1, 1),
+            created_by: creator,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+
+        assert!(can_modify_expense(&expense, &creator, false));
+        assert!(can_modify_expense(&expense, &payer, false));
+        assert!(!can_modify_expense(&expense, &other, false));
+        assert!(can_modify_expense(&expense, &other, true));
+    }
+
+    #[tokio::test]
+    async fn test_create_and_summarize_month() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let alice = test_utils::create_test_user(&pool, "alice@test.com", Role::Member).await;
+        let bob = test_utils::create_test_user(&pool, "bob@test.com", Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &alice, Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &bob, Role::Member).await;
+
+        create_expense(&pool, &household_id, &alice, &request(1000, ExpenseCategory::Groceries, date(2024, 3, 2)))
+            .await
+            .unwrap();
+        create_expense(&pool, &household_id, &alice, &request(500, ExpenseCategory::Transport, date(2024, 3, 31)))
+            .await
+            .unwrap();
+        let mut for_bob = request(2500, ExpenseCategory::Groceries, date(2024, 3, 15));
+        for_bob.paid_by = Some(bob);
+        create_expense(&pool, &household_id, &alice, &for_bob).await.unwrap();
+        // Outside of the month
+        create_expense(&pool, &household_id, &bob, &request(9999, ExpenseCategory::Rent, date(2024, 4, 1)))
+            .await
+            .unwrap();
+
+        let listed = list_expenses(&pool, &household_id, 2024, 3).await.unwrap();
+        assert_eq!(listed.len(), 3);
+        assert_eq!(listed[0].expense.expense_date, date(2024, 3, 31));
+
+        let summary = monthly_summary(&pool, &household_id, 2024, 3).await.unwrap();
+        assert_eq!(summary.total_cents, 4000);
+        assert_eq!(summary.by_member.len(), 2);
+        assert_eq!(summary.by_member[0].user_id, bob);
+        assert_eq!(summary.by_member[0].total_cents, 2500);
+        assert_eq!(summary.by_member[1].total_cents, 1500);
+        assert_eq!(summary.by_category[0].category, ExpenseCategory::Groceries);
+        assert_eq!(summary.by_category[0].total_cents, 3500);
+    }
+
+    #[tokio::test]
+    async fn test_create_expense_validation() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let alice = test_utils::create_test_user(&pool, "alice@test.com", Role::Member).await;
+        let outsider = test_utils::create_test_user(&pool, "outsider@test.com", Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &alice, Role::Member).await;
+
+        let zero = request(0, ExpenseCategory::Other, date(2024, 3, 1));
+        assert!(matches!(
+            create_expense(&pool, &household_id, &alice, &zero).await,
+            Err(ExpenseError::InvalidAmount)
+        ));
+
+        let mut foreign_payer = request(100, ExpenseCategory::Other, date(2024, 3, 1));
+        foreign_payer.paid_by = Some(outsider);
+        assert!(matches!(
+            create_expense(&pool, &household_id, &alice, &foreign_payer).await,
+            Err(ExpenseError::InvalidPayer)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update_and_delete_permissions() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let alice = test_utils::create_test_user(&pool, "alice@test.com", Role::Member).await;
+        let bob = test_utils::create_test_user(&pool, "bob@test.com", Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &alice, Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &bob, Role::Member).await;
+
+        let expense = create_expense(&pool, &household_id, &alice, &request(1000, ExpenseCategory::Other, date(2024, 3, 1)))
+            .await
+            .unwrap();
+
+        let update = UpdateExpenseRequest {
+            amount_cents: Some(1200),
+            receipt_note: Some("  ".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            update_expense(&pool, &household_id, &expense.id, &bob, false, &update).await,
+            Err(ExpenseError::PermissionDenied)
+        ));
+
+        let updated = update_expense(&pool, &household_id, &expense.id, &alice, false, &update)
+            .await
+            .unwrap();
+        assert_eq!(updated.amount_cents, 1200);
+        assert_eq!(updated.receipt_note, None);
+
+        assert!(matches!(
+            delete_expense(&pool, &household_id, &expense.id, &bob, false).await,
+            Err(ExpenseError::PermissionDenied)
+        ));
+        delete_expense(&pool, &household_id, &expense.id, &bob, true).await.unwrap();
+        assert!(get_expense(&pool, &household_id, &expense.id).await.unwrap().is_none());
+    }
+}
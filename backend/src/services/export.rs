@@ -0,0 +1,522 @@
+use chrono::Utc;
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::services::{
+    household_settings, households, points, punishments, rewards, task_categories,
+    task_consequences, tasks,
+};
+use shared::{
+    CreateHouseholdRequest, CreatePointConditionRequest, CreatePunishmentRequest,
+    CreateRewardRequest, CreateTaskCategoryRequest, CreateTaskRequest, DefaultPunishmentEntry,
+    DefaultRewardEntry, ExportOptionLink, ExportTaskLink, Household, HouseholdExport,
+    HouseholdSettings, PunishmentType, ReviewerType, RewardType, SuggestionStatus,
+    UpdateHouseholdSettingsRequest, UpdatePunishmentRequest, UpdateRewardRequest,
+    UpdateTaskRequest, HOUSEHOLD_EXPORT_VERSION,
+};
+
+#[derive(Debug, Error)]
+pub enum ExportError {
+    #[error("Household not found")]
+    NotFound,
+    #[error("Unsupported export version: {0}")]
+    UnsupportedVersion(u32),
+    #[error("Household error: {0}")]
+    HouseholdError(#[from] households::HouseholdError),
+    #[error("Settings error: {0}")]
+    SettingsError(#[from] household_settings::SettingsError),
+    #[error("Category error: {0}")]
+    TaskCategoryError(#[from] task_categories::TaskCategoryError),
+    #[error("Task error: {0}")]
+    TaskError(#[from] tasks::TaskError),
+    #[error("Reward error: {0}")]
+    RewardError(#[from] rewards::RewardError),
+    #[error("Punishment error: {0}")]
+    PunishmentError(#[from] punishments::PunishmentError),
+    #[error("Points error: {0}")]
+    PointsError(#[from] points::PointsError),
+    #[error("Task consequence error: {0}")]
+    TaskConsequenceError(#[from] task_consequences::TaskConsequenceError),
+}
+
+/// Collect the complete configuration of a household into an export document
+pub async fn export_household(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+) -> Result<HouseholdExport, ExportError> {
+    let household = households::get_household(pool, household_id)
+        .await?
+        .ok_or(ExportError::NotFound)?;
+
+    let settings = household_settings::get_or_create_settings(pool, household_id).await?;
+    let categories = task_categories::list_categories(pool, household_id).await?;
+
+    // Active and archived tasks, without open or denied suggestions
+    let mut task_list = tasks::list_tasks(pool, household_id).await?;
+    task_list.extend(tasks::list_archived_tasks(pool, household_id).await?);
+    task_list.retain(|t| matches!(t.suggestion, None | Some(SuggestionStatus::Approved)));
+
+    let reward_list = rewards::list_rewards(pool, household_id).await?;
+    let mut reward_options = Vec::new();
+    for reward in reward_list.iter().filter(|r| r.reward_type.is_random_choice()) {
+        for option in rewards::get_reward_options(pool, &reward.id).await? {
+            reward_options.push(ExportOptionLink {
+                parent_id: reward.id,
+                option_id: option.reward.id,
+                weight: option.weight,
+            });
+        }
+    }
+
+    let punishment_list = punishments::list_punishments(pool, household_id).await?;
+    let mut punishment_options = Vec::new();
+    for punishment in punishment_list.iter().filter(|p| p.punishment_type.is_random_choice()) {
+        for option in punishments::get_punishment_options(pool, &punishment.id).await? {
+            punishment_options.push(ExportOptionLink {
+                parent_id: punishment.id,
+                option_id: option.punishment.id,
+                weight: option.weight,
+            });
+        }
+    }
+
+    let mut task_rewards = Vec::new();
+    let mut task_punishments = Vec::new();
+    for task in &task_list {
+        for link in task_consequences::get_task_rewards(pool, &task.id).await? {
+            task_rewards.push(ExportTaskLink {
+                task_id: task.id,
+                target_id: link.reward.id,
+                amount: link.amount,
+            });
+        }
+        for link in task_consequences::get_task_punishments(pool, &task.id).await? {
+            task_punishments.push(ExportTaskLink {
+                task_id: task.id,
+                target_id: link.punishment.id,
+                amount: link.amount,
+            });
+        }
+    }
+
+    let point_conditions = points::list_point_conditions(pool, household_id).await?;
+
+    Ok(HouseholdExport {
+        version: HOUSEHOLD_EXPORT_VERSION,
+        exported_at: Utc::now(),
+        household_name: household.name,
+        settings,
+        categories,
+        tasks: task_list,
+        rewards: reward_list,
+        reward_options,
+        punishments: punishment_list,
+        punishment_options,
+        task_rewards,
+        task_punishments,
+        point_conditions,
+    })
+}
+
+/// Recreate a household from an export document.
+/// The importing user becomes the owner of the new household. If any step fails,
+/// the partially imported household is removed again.
+pub async fn import_household(
+    pool: &SqlitePool,
+    owner_id: &Uuid,
+    data: &HouseholdExport,
+) -> Result<Household, ExportError> {
+    if data.version == 0 || data.version > HOUSEHOLD_EXPORT_VERSION {
+        return Err(ExportError::UnsupportedVersion(data.version));
+    }
+
+    let request = CreateHouseholdRequest {
+        name: data.household_name.clone(),
+    };
+    let household = households::create_household(pool, owner_id, &request).await?;
+
+    if let Err(e) = import_contents(pool, &household.id, data).await {
+        if let Err(cleanup_error) = households::delete_household(pool, &household.id).await {
+            log::error!("Failed to clean up partial household import: {:?}", cleanup_error);
+        }
+        return Err(e);
+    }
+
+    Ok(household)
+}
+
+async fn import_contents(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    data: &HouseholdExport,
+) -> Result<(), ExportError> {
+    // Categories
+    let mut category_ids = HashMap::new();
+    for category in &data.categories {
+        let request = CreateTaskCategoryRequest {
+            name: category.name.clone(),
+            color: category.color.clone(),
+            sort_order: Some(category.sort_order),
+        };
+        let created = task_categories::create_category(pool, household_id, &request).await?;
+        category_ids.insert(category.id, created.id);
+    }
+
+    // Rewards: create everything as standard first, then turn random choice
+    // rewards back into random choice once all their options exist
+    let mut reward_ids = HashMap::new();
+    for reward in &data.rewards {
+        let request = CreateRewardRequest {
+            name: reward.name.clone(),
+            description: Some(reward.description.clone()),
+            point_cost: reward.point_cost,
+            is_purchasable: reward.is_purchasable,
+            requires_confirmation: Some(reward.requires_confirmation),
+            reward_type: Some(RewardType::Standard),
+            option_ids: None,
+            no_repeat_picks: Some(reward.no_repeat_picks),
+        };
+        let created = rewards::create_reward(pool, household_id, &request).await?;
+        reward_ids.insert(reward.id, created.id);
+    }
+    for reward in data.rewards.iter().filter(|r| r.reward_type.is_random_choice()) {
+        let request = UpdateRewardRequest {
+            name: None,
+            description: None,
+            point_cost: None,
+            is_purchasable: None,
+            requires_confirmation: None,
+            reward_type: Some(RewardType::RandomChoice),
+            option_ids: Some(Some(mapped_option_ids(&data.reward_options, &reward.id, &reward_ids))),
+            no_repeat_picks: None,
+        };
+        rewards::update_reward(pool, &reward_ids[&reward.id], &request).await?;
+        for (option_id, weight) in mapped_option_weights(&data.reward_options, &reward.id, &reward_ids) {
+            rewards::set_reward_option_weight(pool, household_id, &reward_ids[&reward.id], &option_id, weight).await?;
+        }
+    }
+
+    // Punishments, same two-step approach as rewards
+    let mut punishment_ids = HashMap::new();
+    for punishment in &data.punishments {
+        let request = CreatePunishmentRequest {
+            name: punishment.name.clone(),
+            description: Some(punishment.description.clone()),
+            requires_confirmation: Some(punishment.requires_confirmation),
+            punishment_type: Some(PunishmentType::Standard),
+            option_ids: None,
+            no_repeat_picks: Some(punishment.no_repeat_picks),
+        };
+        let created = punishments::create_punishment(pool, household_id, &request).await?;
+        punishment_ids.insert(punishment.id, created.id);
+    }
+    for punishment in data.punishments.iter().filter(|p| p.punishment_type.is_random_choice()) {
+        let request = UpdatePunishmentRequest {
+            name: None,
+            description: None,
+            requires_confirmation: None,
+            punishment_type: Some(PunishmentType::RandomChoice),
+            option_ids: Some(Some(mapped_option_ids(
+                &data.punishment_options,
+                &punishment.id,
+                &punishment_ids,
+            ))),
+            no_repeat_picks: None,
+        };
+        punishments::update_punishment(pool, &punishment_ids[&punishment.id], &request).await?;
+        for (option_id, weight) in mapped_option_weights(&data.punishment_options, &punishment.id, &punishment_ids) {
+            punishments::set_punishment_option_weight(pool, household_id, &punishment_ids[&punishment.id], &option_id, weight)
+                .await?;
+        }
+    }
+
+    // Tasks. Assignments and named reviewers are dropped because the members of the new household differ.
+    let mut task_ids = HashMap::new();
+    for task in &data.tasks {
+        let request = CreateTaskRequest {
+            title: task.title.clone(),
+            description: Some(task.description.clone()),
+            recurrence_type: task.recurrence_type.clone(),
+            recurrence_value: task.recurrence_value.clone(),
+            assigned_user_id: None,
+            assignee_ids: None,
+            target_count: Some(task.target_count),
+            time_period: task.time_period,
+            allow_exceed_target: Some(task.allow_exceed_target),
+            requires_review: Some(task.requires_review),
+            reviewer_type: Some(task.reviewer_type).filter(|reviewer_type| *reviewer_type != ReviewerType::Member),
+            reviewer_id: None,
+            points_reward: task.points_reward,
+            points_penalty: task.points_penalty,
+            due_time: task.due_time.clone(),
+            effort_minutes: task.effort_minutes,
+            active_from: task.active_from.clone(),
+            active_until: task.active_until.clone(),
+            ends_on: task.ends_on,
+            max_occurrences: task.max_occurrences,
+            habit_type: Some(task.habit_type),
+            category_id: task.category_id.and_then(|id| category_ids.get(&id).copied()),
+            tag_ids: None,
+            is_suggestion: None,
+        };
+        let created = tasks::create_task(pool, household_id, &request, None).await?;
+
+        if task.archived || task.paused {
+            let update = UpdateTaskRequest {
+                title: None,
+                description: None,
+                recurrence_type: None,
+                recurrence_value: None,
+                assigned_user_id: None,
+                assignee_ids: None,
+                target_count: None,
+                time_period: None,
+                allow_exceed_target: None,
+                requires_review: None,
+                reviewer_type: None,
+                reviewer_id: None,
+                points_reward: None,
+                points_penalty: None,
+                due_time: None,
+                effort_minutes: None,
+                active_from: None,
+                active_until: None,
+                ends_on: None,
+                max_occurrences: None,
+                habit_type: None,
+                category_id: None,
+                tag_ids: None,
+                archived: Some(task.archived),
+                paused: Some(task.paused),
+                version: None,
+            };
+            tasks::update_task(pool, &created.id, &update).await?;
+        }
+
+        task_ids.insert(task.id, created.id);
+    }
+
+    // Task consequences
+    for link in &data.task_rewards {
+        if let (Some(task_id), Some(reward_id)) = (task_ids.get(&link.task_id), reward_ids.get(&link.target_id)) {
+            task_consequences::add_task_reward(pool, task_id, reward_id, link.amount).await?;
+        }
+    }
+    for link in &data.task_punishments {
+        if let (Some(task_id), Some(punishment_id)) =
+            (task_ids.get(&link.task_id), punishment_ids.get(&link.target_id))
+        {
+            task_consequences::add_task_punishment(pool, task_id, punishment_id, link.amount).await?;
+        }
+    }
+
+    // Point conditions. Task-specific conditions whose task is missing are skipped
+    // instead of silently becoming household-wide conditions.
+    for condition in &data.point_conditions {
+        let task_id = match condition.task_id {
+            Some(id) => match task_ids.get(&id) {
+                Some(new_id) => Some(*new_id),
+                None => continue,
+            },
+            None => None,
+        };
+        let request = CreatePointConditionRequest {
+            name: condition.name.clone(),
+            condition_type: condition.condition_type.clone(),
+            points_value: condition.points_value,
+            streak_threshold: condition.streak_threshold,
+            multiplier: condition.multiplier,
+            task_id,
+        };
+        points::create_point_condition(pool, household_id, &request).await?;
+    }
+
+    // Settings last, so default rewards and punishments can be resolved
+    let request = settings_import_request(&data.settings, &reward_ids, &punishment_ids);
+    household_settings::update_settings(pool, household_id, &request).await?;
+
+    Ok(())
+}
+
+/// Resolve the new option IDs of a random choice reward/punishment
+fn mapped_option_ids(links: &[ExportOptionLink], parent_id: &Uuid, ids: &HashMap<Uuid, Uuid>) -> Vec<Uuid> {
+    links
+        .iter()
+        .filter(|link| link.parent_id == *parent_id)
+        .filter_map(|link| ids.get(&link.option_id).copied())
+        .collect()
+}
+
+/// Options of a parent with a non-default weight, mapped to their new IDs
+fn mapped_option_weights(links: &[ExportOptionLink], parent_id: &Uuid, ids: &HashMap<Uuid, Uuid>) -> Vec<(Uuid, i32)> {
+    links
+        .iter()
+        .filter(|link| link.parent_id == *parent_id && link.weight != 1)
+        .filter_map(|link| ids.get(&link.option_id).map(|id| (*id, link.weight)))
+        .collect()
+}
+
+/// Build the settings update for an imported household.
+/// Vacation and Solo Mode are temporary states and are not carried over; if the
+/// export was taken during Solo Mode, the hierarchy from before Solo Mode is restored.
+fn settings_import_request(
+    settings: &HouseholdSettings,
+    reward_ids: &HashMap<Uuid, Uuid>,
+    punishment_ids: &HashMap<Uuid, Uuid>,
+) -> UpdateHouseholdSettingsRequest {
+    let hierarchy_type = match settings.solo_mode_previous_hierarchy_type {
+        Some(previous) if settings.solo_mode => previous,
+        _ => settings.hierarchy_type,
+    };
+
+    UpdateHouseholdSettingsRequest {
+        dark_mode: Some(settings.dark_mode),
+        role_label_owner: Some(settings.role_label_owner.clone()),
+        role_label_admin: Some(settings.role_label_admin.clone()),
+        role_label_member: Some(settings.role_label_member.clone()),
+        hierarchy_type: Some(hierarchy_type),
+        timezone: Some(settings.timezone.clone()),
+        vacation_mode: None,
+        vacation_start: None,
+        vacation_end: None,
+        auto_archive_days: Some(settings.auto_archive_days),
+        allow_task_suggestions: Some(settings.allow_task_suggestions),
+        week_start_day: Some(settings.week_start_day),
+        default_points_reward: Some(settings.default_points_reward),
+        default_points_penalty: Some(settings.default_points_penalty),
+        default_rewards: Some(
+            settings
+                .default_rewards
+                .iter()
+                .filter_map(|link| {
+                    reward_ids.get(&link.reward.id).map(|id| DefaultRewardEntry {
+                        reward_id: *id,
+                        amount: link.amount,
+                    })
+                })
+                .collect(),
+        ),
+        default_punishments: Some(
+            settings
+                .default_punishments
+                .iter()
+                .filter_map(|link| {
+                    punishment_ids.get(&link.punishment.id).map(|id| DefaultPunishmentEntry {
+                        punishment_id: *id,
+                        amount: link.amount,
+                    })
+                })
+                .collect(),
+        ),
+        weekly_summary_enabled: Some(settings.weekly_summary_enabled),
+        weekly_summary_email: Some(settings.weekly_summary_email),
+        point_decay_mode: Some(settings.point_decay_mode),
+        point_decay_amount: Some(settings.point_decay_amount),
+        purchase_approval_required: Some(settings.purchase_approval_required),
+        streak_freeze_price: Some(settings.streak_freeze_price),
+        default_requires_review: Some(settings.default_requires_review),
+        default_allow_exceed_target: Some(settings.default_allow_exceed_target),
+        good_habit_points_multiplier: Some(settings.good_habit_points_multiplier),
+        bad_habit_points_multiplier: Some(settings.bad_habit_points_multiplier),
+        review_points_threshold: Some(settings.review_points_threshold),
+        disabled_features: Some(settings.disabled_features.clone()),
+        punishment_terminology: Some(settings.punishment_terminology),
+        punishment_label_singular: Some(settings.punishment_label_singular.clone()),
+        punishment_label_plural: Some(settings.punishment_label_plural.clone()),
+        allowance_cents_per_point: Some(settings.allowance_cents_per_point),
+        allowance_point_handling: Some(settings.allowance_point_handling),
+        version: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::{HierarchyType, HouseholdDefaultRewardLink, Reward};
+
+    fn create_reward(id: Uuid) -> Reward {
+        Reward {
+            id,
+            household_id: Uuid::new_v4(),
+            name: "Ice cream".to_string(),
+            description: String::new(),
+            point_cost: Some(10),
+            is_purchasable: true,
+            requires_confirmation: false,
+            reward_type: RewardType::Standard,
+            no_repeat_picks: 0,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_export_error_display() {
+        assert_eq!(ExportError::NotFound.to_string(), "Household not found");
+        assert_eq!(
+            ExportError::UnsupportedVersion(7).to_string(),
+            "Unsupported export version: 7"
+        );
+    }
+
+    #[test]
+    fn test_mapped_option_ids() {
+        let parent = Uuid::new_v4();
+        let other_parent = Uuid::new_v4();
+        let option_a = Uuid::new_v4();
+        let option_b = Uuid::new_v4();
+        let missing = Uuid::new_v4();
+        let new_a = Uuid::new_v4();
+        let new_b = Uuid::new_v4();
+
+        let links = vec![
+            ExportOptionLink { parent_id: parent, option_id: option_a, weight: 1 },
+            ExportOptionLink { parent_id: parent, option_id: missing, weight: 2 },
+            ExportOptionLink { parent_id: other_parent, option_id: option_b, weight: 1 },
+            ExportOptionLink { parent_id: parent, option_id: option_b, weight: 4 },
+        ];
+        let ids = HashMap::from([(option_a, new_a), (option_b, new_b)]);
+
+        assert_eq!(mapped_option_ids(&links, &parent, &ids), vec![new_a, new_b]);
+        assert_eq!(mapped_option_weights(&links, &parent, &ids), vec![(new_b, 4)]);
+    }
+
+    #[test]
+    fn test_settings_import_request_maps_defaults() {
+        let old_reward = Uuid::new_v4();
+        let new_reward = Uuid::new_v4();
+        let settings = HouseholdSettings {
+            timezone: "Europe/Berlin".to_string(),
+            default_rewards: vec![
+                HouseholdDefaultRewardLink { reward: create_reward(old_reward), amount: 2 },
+                HouseholdDefaultRewardLink { reward: create_reward(Uuid::new_v4()), amount: 1 },
+            ],
+            ..Default::default()
+        };
+        let reward_ids = HashMap::from([(old_reward, new_reward)]);
+
+        let request = settings_import_request(&settings, &reward_ids, &HashMap::new());
+
+        assert_eq!(request.timezone.as_deref(), Some("Europe/Berlin"));
+        let defaults = request.default_rewards.unwrap();
+        assert_eq!(defaults.len(), 1);
+        assert_eq!(defaults[0].reward_id, new_reward);
+        assert_eq!(defaults[0].amount, 2);
+        assert!(request.vacation_mode.is_none());
+    }
+
+    #[test]
+    fn test_settings_import_request_restores_hierarchy_after_solo_mode() {
+        let settings = HouseholdSettings {
+            hierarchy_type: HierarchyType::Equals,
+            solo_mode: true,
+            solo_mode_previous_hierarchy_type: Some(HierarchyType::Hierarchy),
+            ..Default::default()
+        };
+
+        let request = settings_import_request(&settings, &HashMap::new(), &HashMap::new());
+
+        assert_eq!(request.hierarchy_type, Some(HierarchyType::Hierarchy));
+    }
+}
@@ -0,0 +1,226 @@
+//! Focus timer sessions: a member starts a timer on a task and stops or cancels
+//! it later. Sessions live on the server, so a running timer survives reloads,
+//! and the focused time of stopped sessions counts toward the task's statistics.
+
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::FocusSessionRow;
+use shared::{FocusSession, FocusSessionStatus, StartFocusSessionRequest, DEFAULT_FOCUS_MINUTES, MAX_FOCUS_MINUTES};
+
+#[derive(Debug, Error)]
+pub enum FocusSessionError {
+    #[error("No focus session is running")]
+    NotRunning,
+    #[error("Task not found")]
+    TaskNotFound,
+    #[error("A focus session is already running")]
+    AlreadyRunning,
+    #[error("Focus sessions must be between 1 and {MAX_FOCUS_MINUTES} minutes")]
+    InvalidLength,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+const SELECT_SESSION: &str = r#"
+    SELECT f.id, f.household_id, f.task_id, t.title AS task_title, f.user_id, f.planned_minutes,
+        f.status, f.started_at, f.ended_at, f.focused_seconds
+    FROM focus_sessions f
+    JOIN tasks t ON f.task_id = t.id
+"#;
+
+/// The user's running session in any household
+pub async fn get_running_session(pool: &SqlitePool, user_id: &Uuid) -> Result<Option<FocusSession>, FocusSessionError> {
+    let row: Option<FocusSessionRow> =
+        sqlx::query_as(&format!("{} WHERE f.user_id = ? AND f.status = 'running'", SELECT_SESSION))
+            .bind(user_id.to_string())
+            .fetch_optional(pool)
+            .await?;
+    Ok(row.map(|r| r.to_shared()))
+}
+
+async fn get_session(pool: &SqlitePool, session_id: &str) -> Result<FocusSession, FocusSessionError> {
+    let row: FocusSessionRow = sqlx::query_as(&format!("{} WHERE f.id = ?", SELECT_SESSION))
+        .bind(session_id)
+        .fetch_one(pool)
+        .await?;
+    Ok(row.to_shared())
+}
+
+/// Start a session on a task of the household
+pub async fn start_session(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    request: &StartFocusSessionRequest,
+    now: DateTime<Utc>,
+) -> Result<FocusSession, FocusSessionError> {
+    let planned_minutes = request.planned_minutes.unwrap_or(DEFAULT_FOCUS_MINUTES);
+    if !(1..=MAX_FOCUS_MINUTES).contains(&planned_minutes) {
+        return Err(FocusSessionError::InvalidLength);
+    }
+
+    let task_exists: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM tasks WHERE id = ? AND household_id = ? AND deleted_at IS NULL)",
+    )
+    .bind(request.task_id.to_string())
+    .bind(household_id.to_string())
+    .fetch_one(pool)
+    .await?;
+    if !task_exists {
+        return Err(FocusSessionError::TaskNotFound);
+    }
+
+    let id = Uuid::new_v4().to_string();
+    sqlx::query(
+        r#"
+        INSERT INTO focus_sessions (id, household_id, task_id, user_id, planned_minutes, status, started_at)
+        VALUES (?, ?, ?, ?, ?, 'running', ?)
+        "#,
+    )
+    .bind(&id)
+    .bind(household_id.to_string())
+    .bind(request.task_id.to_string())
+    .bind(user_id.to_string())
+    .bind(planned_minutes)
+    .bind(now)
+    .execute(pool)
+    .await
+    .map_err(|e| match e {
+        sqlx::Error::Database(ref db) if db.message().contains("UNIQUE constraint failed") => {
+            FocusSessionError::AlreadyRunning
+        }
+        e => FocusSessionError::DatabaseError(e),
+    })?;
+
+    get_session(pool, &id).await
+}
+
+/// End the user's running session in the household. A completed session counts
+/// the elapsed time, at most the planned length; a cancelled one counts nothing.
+pub async fn end_session(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    status: FocusSessionStatus,
+    now: DateTime<Utc>,
+) -> Result<FocusSession, FocusSessionError> {
+    let running: Option<(String, DateTime<Utc>, i32)> = sqlx::query_as(
+        "SELECT id, started_at, planned_minutes FROM focus_sessions WHERE household_id = ? AND user_id = ? AND status = 'running'",
+    )
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .fetch_optional(pool)
+    .await?;
+    let Some((id, started_at, planned_minutes)) = running else {
+        return Err(FocusSessionError::NotRunning);
+    };
+
+    let focused_seconds = match status {
+        FocusSessionStatus::Completed => (now - started_at).num_seconds().clamp(0, i64::from(planned_minutes) * 60),
+        _ => 0,
+    };
+    sqlx::query("UPDATE focus_sessions SET status = ?, ended_at = ?, focused_seconds = ? WHERE id = ?")
+        .bind(status.as_str())
+        .bind(now)
+        .bind(focused_seconds)
+        .bind(&id)
+        .execute(pool)
+        .await?;
+
+    get_session(pool, &id).await
+}
+
+/// Minutes of completed sessions on a task, optionally only those started since `since`
+pub async fn focus_minutes(
+    pool: &SqlitePool,
+    task_id: &Uuid,
+    since: Option<DateTime<Utc>>,
+) -> Result<i64, sqlx::Error> {
+    let seconds: i64 = sqlx::query_scalar(
+        r#"
+        SELECT COALESCE(SUM(focused_seconds), 0) FROM focus_sessions
+        WHERE task_id = ? AND status = 'completed' AND (? IS NULL OR started_at >= ?)
+        "#,
+    )
+    .bind(task_id.to_string())
+    .bind(since)
+    .bind(since)
+    .fetch_one(pool)
+    .await?;
+    Ok(seconds / 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use chrono::Duration;
+
+    #[tokio::test]
+    async fn test_focus_session_lifecycle() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user_id = test_utils::create_test_user(&pool, "focus@example.com", shared::Role::Member).await;
+        let task = test_utils::create_test_task(&pool, &household_id).with_title("Study").build().await;
+        let start = Utc::now() - Duration::hours(2);
+        let request = StartFocusSessionRequest { task_id: task.id, planned_minutes: None };
+
+        let session = start_session(&pool, &household_id, &user_id, &request, start).await.unwrap();
+        assert_eq!(session.task_title, "Study");
+        assert_eq!(session.planned_minutes, DEFAULT_FOCUS_MINUTES);
+        assert_eq!(get_running_session(&pool, &user_id).await.unwrap(), Some(session));
+        assert!(matches!(
+            start_session(&pool, &household_id, &user_id, &request, start).await,
+            Err(FocusSessionError::AlreadyRunning)
+        ));
+
+        // Stopping after 10 minutes counts 10 minutes
+        let stopped = end_session(&pool, &household_id, &user_id, FocusSessionStatus::Completed, start + Duration::minutes(10))
+            .await
+            .unwrap();
+        assert_eq!(stopped.focused_seconds, 600);
+        assert_eq!(get_running_session(&pool, &user_id).await.unwrap(), None);
+
+        // A forgotten timer counts at most its planned length, a cancelled one nothing
+        let later = start + Duration::minutes(30);
+        start_session(&pool, &household_id, &user_id, &request, later).await.unwrap();
+        end_session(&pool, &household_id, &user_id, FocusSessionStatus::Completed, later + Duration::hours(1))
+            .await
+            .unwrap();
+        start_session(&pool, &household_id, &user_id, &request, later).await.unwrap();
+        let cancelled = end_session(&pool, &household_id, &user_id, FocusSessionStatus::Cancelled, later + Duration::minutes(5))
+            .await
+            .unwrap();
+        assert_eq!(cancelled.focused_seconds, 0);
+        assert!(matches!(
+            end_session(&pool, &household_id, &user_id, FocusSessionStatus::Cancelled, later).await,
+            Err(FocusSessionError::NotRunning)
+        ));
+
+        assert_eq!(focus_minutes(&pool, &task.id, None).await.unwrap(), 35);
+        assert_eq!(focus_minutes(&pool, &task.id, Some(later)).await.unwrap(), 25);
+    }
+
+    #[tokio::test]
+    async fn test_start_session_validates_task_and_length() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user_id = test_utils::create_test_user(&pool, "focus@example.com", shared::Role::Member).await;
+        let task = test_utils::create_test_task(&pool, &household_id).build().await;
+
+        // Tasks of other households are not found
+        let request = StartFocusSessionRequest { task_id: task.id, planned_minutes: Some(25) };
+        assert!(matches!(
+            start_session(&pool, &Uuid::new_v4(), &user_id, &request, Utc::now()).await,
+            Err(FocusSessionError::TaskNotFound)
+        ));
+        let request = StartFocusSessionRequest { task_id: task.id, planned_minutes: Some(MAX_FOCUS_MINUTES + 1) };
+        assert!(matches!(
+            start_session(&pool, &household_id, &user_id, &request, Utc::now()).await,
+            Err(FocusSessionError::InvalidLength)
+        ));
+    }
+}
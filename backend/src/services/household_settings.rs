@@ -4,14 +4,29 @@ use thiserror::Error;
 use uuid::Uuid;
 
 use crate::models::{HouseholdDefaultPunishmentRow, HouseholdDefaultRewardRow, HouseholdSettingsRow};
-use shared::{HierarchyType, HouseholdSettings, UpdateHouseholdSettingsRequest};
+use shared::{
+    AllowancePointHandling, HierarchyType, HouseholdFeature, HouseholdSettings, PointDecayMode, PunishmentTerminology, UpdateHouseholdSettingsRequest,
+    MAX_ALLOWANCE_CENTS_PER_POINT,
+};
 
 #[derive(Debug, Error)]
 pub enum SettingsError {
+    #[error("Settings were changed in the meantime")]
+    VersionConflict(Box<HouseholdSettings>),
     #[error("Database error: {0}")]
     DatabaseError(#[from] sqlx::Error),
 }
 
+/// Longest custom name for punishments
+const MAX_LABEL_LEN: usize = 40;
+
+/// Trim a custom label; blank labels fall back to the default wording
+fn clean_label(label: Option<&str>) -> Option<String> {
+    label
+        .map(|l| l.trim().chars().take(MAX_LABEL_LEN).collect::<String>())
+        .filter(|l| !l.is_empty())
+}
+
 /// Load default rewards from junction table
 async fn load_default_rewards(
     pool: &SqlitePool,
@@ -20,7 +35,7 @@ async fn load_default_rewards(
     let rows: Vec<HouseholdDefaultRewardRow> = sqlx::query_as(
         r#"
         SELECT r.id, r.household_id, r.name, r.description, r.point_cost,
-               r.is_purchasable, r.requires_confirmation, r.reward_type, r.created_at,
+               r.is_purchasable, r.requires_confirmation, r.reward_type, r.no_repeat_picks, r.created_at,
                hdr.amount
         FROM household_default_rewards hdr
         JOIN rewards r ON r.id = hdr.reward_id
@@ -42,7 +57,7 @@ async fn load_default_punishments(
     let rows: Vec<HouseholdDefaultPunishmentRow> = sqlx::query_as(
         r#"
         SELECT p.id, p.household_id, p.name, p.description,
-               p.requires_confirmation, p.punishment_type, p.created_at,
+               p.requires_confirmation, p.punishment_type, p.no_repeat_picks, p.created_at,
                hdp.amount
         FROM household_default_punishments hdp
         JOIN punishments p ON p.id = hdp.punishment_id
@@ -85,13 +100,14 @@ pub async fn get_or_create_settings(
     let default_timezone = "UTC";
     sqlx::query(
         r#"
-        INSERT INTO household_settings (household_id, dark_mode, role_label_owner, role_label_admin, role_label_member, hierarchy_type, timezone, rewards_enabled, punishments_enabled, chat_enabled, vacation_mode, vacation_start, vacation_end, auto_archive_days, allow_task_suggestions, week_start_day, default_points_reward, default_points_penalty, solo_mode, solo_mode_exit_requested_at, solo_mode_previous_hierarchy_type, updated_at)
-        VALUES (?, FALSE, 'Owner', 'Admin', 'Member', ?, ?, FALSE, FALSE, FALSE, FALSE, NULL, NULL, 7, TRUE, 0, NULL, NULL, FALSE, NULL, NULL, ?)
+        INSERT INTO household_settings (household_id, dark_mode, role_label_owner, role_label_admin, role_label_member, hierarchy_type, timezone, vacation_mode, vacation_start, vacation_end, auto_archive_days, allow_task_suggestions, week_start_day, default_points_reward, default_points_penalty, solo_mode, solo_mode_exit_requested_at, solo_mode_previous_hierarchy_type, weekly_summary_enabled, weekly_summary_email, point_decay_mode, point_decay_amount, purchase_approval_required, streak_freeze_price, default_requires_review, default_allow_exceed_target, good_habit_points_multiplier, bad_habit_points_multiplier, review_points_threshold, disabled_features, updated_at)
+        VALUES (?, FALSE, 'Owner', 'Admin', 'Member', ?, ?, FALSE, NULL, NULL, 7, TRUE, 0, NULL, NULL, FALSE, NULL, NULL, FALSE, FALSE, 'none', 0, FALSE, 0, FALSE, TRUE, 1.0, 1.0, NULL, ?, ?)
         "#,
     )
     .bind(&household_id_str)
     .bind(default_hierarchy.as_str())
     .bind(default_timezone)
+    .bind(serde_json::to_string(&HouseholdFeature::OFF_BY_DEFAULT).unwrap_or_else(|_| "[]".to_string()))
     .bind(now)
     .execute(pool)
     .await?;
@@ -104,9 +120,6 @@ pub async fn get_or_create_settings(
         role_label_member: "Member".to_string(),
         hierarchy_type: default_hierarchy,
         timezone: default_timezone.to_string(),
-        rewards_enabled: false,
-        punishments_enabled: false,
-        chat_enabled: false,
         vacation_mode: false,
         vacation_start: None,
         vacation_end: None,
@@ -120,6 +133,24 @@ pub async fn get_or_create_settings(
         solo_mode: false,
         solo_mode_exit_requested_at: None,
         solo_mode_previous_hierarchy_type: None,
+        weekly_summary_enabled: false,
+        weekly_summary_email: false,
+        point_decay_mode: PointDecayMode::None,
+        point_decay_amount: 0,
+        purchase_approval_required: false,
+        streak_freeze_price: 0,
+        default_requires_review: false,
+        default_allow_exceed_target: true,
+        good_habit_points_multiplier: 1.0,
+        bad_habit_points_multiplier: 1.0,
+        review_points_threshold: None,
+        disabled_features: HouseholdFeature::OFF_BY_DEFAULT.to_vec(),
+        punishment_terminology: PunishmentTerminology::Punishments,
+        punishment_label_singular: None,
+        punishment_label_plural: None,
+        allowance_cents_per_point: 0,
+        allowance_point_handling: AllowancePointHandling::Reset,
+        version: 1,
         updated_at: now,
     })
 }
@@ -135,6 +166,11 @@ pub async fn update_settings(
     // Ensure settings exist first
     let mut settings = get_or_create_settings(pool, household_id).await?;
 
+    if request.version.is_some_and(|version| version != settings.version) {
+        return Err(SettingsError::VersionConflict(Box::new(settings)));
+    }
+    let expected_version = settings.version;
+
     // Apply updates
     if let Some(dark_mode) = request.dark_mode {
         settings.dark_mode = dark_mode;
@@ -154,15 +190,6 @@ pub async fn update_settings(
     if let Some(ref timezone) = request.timezone {
         settings.timezone = timezone.clone();
     }
-    if let Some(rewards_enabled) = request.rewards_enabled {
-        settings.rewards_enabled = rewards_enabled;
-    }
-    if let Some(punishments_enabled) = request.punishments_enabled {
-        settings.punishments_enabled = punishments_enabled;
-    }
-    if let Some(chat_enabled) = request.chat_enabled {
-        settings.chat_enabled = chat_enabled;
-    }
     if let Some(vacation_mode) = request.vacation_mode {
         settings.vacation_mode = vacation_mode;
     }
@@ -187,17 +214,75 @@ pub async fn update_settings(
     if let Some(ref default_points_penalty) = request.default_points_penalty {
         settings.default_points_penalty = *default_points_penalty;
     }
+    if let Some(weekly_summary_enabled) = request.weekly_summary_enabled {
+        settings.weekly_summary_enabled = weekly_summary_enabled;
+    }
+    if let Some(weekly_summary_email) = request.weekly_summary_email {
+        settings.weekly_summary_email = weekly_summary_email;
+    }
+    if let Some(point_decay_mode) = request.point_decay_mode {
+        settings.point_decay_mode = point_decay_mode;
+    }
+    if let Some(point_decay_amount) = request.point_decay_amount {
+        settings.point_decay_amount = point_decay_amount.max(0);
+    }
+    if settings.point_decay_mode == PointDecayMode::Percent {
+        settings.point_decay_amount = settings.point_decay_amount.min(100);
+    }
+    if let Some(purchase_approval_required) = request.purchase_approval_required {
+        settings.purchase_approval_required = purchase_approval_required;
+    }
+    if let Some(streak_freeze_price) = request.streak_freeze_price {
+        settings.streak_freeze_price = streak_freeze_price.max(0);
+    }
+    if let Some(default_requires_review) = request.default_requires_review {
+        settings.default_requires_review = default_requires_review;
+    }
+    if let Some(default_allow_exceed_target) = request.default_allow_exceed_target {
+        settings.default_allow_exceed_target = default_allow_exceed_target;
+    }
+    if let Some(multiplier) = request.good_habit_points_multiplier {
+        settings.good_habit_points_multiplier = multiplier.max(0.0);
+    }
+    if let Some(multiplier) = request.bad_habit_points_multiplier {
+        settings.bad_habit_points_multiplier = multiplier.max(0.0);
+    }
+    if let Some(review_points_threshold) = request.review_points_threshold {
+        settings.review_points_threshold = review_points_threshold.map(|t| t.max(0));
+    }
+    if let Some(ref disabled_features) = request.disabled_features {
+        let mut disabled_features = disabled_features.clone();
+        disabled_features.sort();
+        disabled_features.dedup();
+        settings.disabled_features = disabled_features;
+    }
+    if let Some(punishment_terminology) = request.punishment_terminology {
+        settings.punishment_terminology = punishment_terminology;
+    }
+    if let Some(ref label) = request.punishment_label_singular {
+        settings.punishment_label_singular = clean_label(label.as_deref());
+    }
+    if let Some(ref label) = request.punishment_label_plural {
+        settings.punishment_label_plural = clean_label(label.as_deref());
+    }
+    if let Some(allowance_cents_per_point) = request.allowance_cents_per_point {
+        settings.allowance_cents_per_point = allowance_cents_per_point.clamp(0, MAX_ALLOWANCE_CENTS_PER_POINT);
+    }
+    if let Some(allowance_point_handling) = request.allowance_point_handling {
+        settings.allowance_point_handling = allowance_point_handling;
+    }
 
     let now = Utc::now();
     settings.updated_at = now;
+    settings.version += 1;
 
     // Update main settings table
     // Note: solo_mode fields are NOT updated here - they are managed via dedicated endpoints
-    sqlx::query(
+    let result = sqlx::query(
         r#"
         UPDATE household_settings
-        SET dark_mode = ?, role_label_owner = ?, role_label_admin = ?, role_label_member = ?, hierarchy_type = ?, timezone = ?, rewards_enabled = ?, punishments_enabled = ?, chat_enabled = ?, vacation_mode = ?, vacation_start = ?, vacation_end = ?, auto_archive_days = ?, allow_task_suggestions = ?, week_start_day = ?, default_points_reward = ?, default_points_penalty = ?, updated_at = ?
-        WHERE household_id = ?
+        SET dark_mode = ?, role_label_owner = ?, role_label_admin = ?, role_label_member = ?, hierarchy_type = ?, timezone = ?, vacation_mode = ?, vacation_start = ?, vacation_end = ?, auto_archive_days = ?, allow_task_suggestions = ?, week_start_day = ?, default_points_reward = ?, default_points_penalty = ?, weekly_summary_enabled = ?, weekly_summary_email = ?, point_decay_mode = ?, point_decay_amount = ?, purchase_approval_required = ?, streak_freeze_price = ?, default_requires_review = ?, default_allow_exceed_target = ?, good_habit_points_multiplier = ?, bad_habit_points_multiplier = ?, review_points_threshold = ?, disabled_features = ?, punishment_terminology = ?, punishment_label_singular = ?, punishment_label_plural = ?, allowance_cents_per_point = ?, allowance_point_handling = ?, updated_at = ?, version = version + 1
+        WHERE household_id = ? AND version = ?
         "#,
     )
     .bind(settings.dark_mode)
@@ -206,9 +291,6 @@ pub async fn update_settings(
     .bind(&settings.role_label_member)
     .bind(settings.hierarchy_type.as_str())
     .bind(&settings.timezone)
-    .bind(settings.rewards_enabled)
-    .bind(settings.punishments_enabled)
-    .bind(settings.chat_enabled)
     .bind(settings.vacation_mode)
     .bind(settings.vacation_start)
     .bind(settings.vacation_end)
@@ -217,11 +299,35 @@ pub async fn update_settings(
     .bind(settings.week_start_day)
     .bind(settings.default_points_reward)
     .bind(settings.default_points_penalty)
+    .bind(settings.weekly_summary_enabled)
+    .bind(settings.weekly_summary_email)
+    .bind(settings.point_decay_mode.as_str())
+    .bind(settings.point_decay_amount)
+    .bind(settings.purchase_approval_required)
+    .bind(settings.streak_freeze_price)
+    .bind(settings.default_requires_review)
+    .bind(settings.default_allow_exceed_target)
+    .bind(settings.good_habit_points_multiplier)
+    .bind(settings.bad_habit_points_multiplier)
+    .bind(settings.review_points_threshold)
+    .bind(serde_json::to_string(&settings.disabled_features).unwrap_or_else(|_| "[]".to_string()))
+    .bind(settings.punishment_terminology.as_str())
+    .bind(&settings.punishment_label_singular)
+    .bind(&settings.punishment_label_plural)
+    .bind(settings.allowance_cents_per_point)
+    .bind(settings.allowance_point_handling.as_str())
     .bind(now)
     .bind(&household_id_str)
+    .bind(expected_version)
     .execute(pool)
     .await?;
 
+    // Someone else saved between our read and write
+    if result.rows_affected() == 0 {
+        let current = get_or_create_settings(pool, household_id).await?;
+        return Err(SettingsError::VersionConflict(Box::new(current)));
+    }
+
     // Handle default rewards (delete-all + insert-new pattern)
     if let Some(ref default_rewards) = request.default_rewards {
         // Delete existing default rewards
@@ -271,6 +377,34 @@ pub async fn update_settings(
     Ok(settings)
 }
 
+/// Modules the household has switched off. Unlike `get_or_create_settings`
+/// this never creates settings; without them the default modules are off.
+pub async fn disabled_features(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+) -> Result<Vec<HouseholdFeature>, SettingsError> {
+    let disabled: Option<(String,)> =
+        sqlx::query_as("SELECT disabled_features FROM household_settings WHERE household_id = ?")
+            .bind(household_id.to_string())
+            .fetch_optional(pool)
+            .await?;
+
+    Ok(match disabled {
+        Some((json,)) => serde_json::from_str(&json).unwrap_or_default(),
+        None => HouseholdFeature::OFF_BY_DEFAULT.to_vec(),
+    })
+}
+
+/// Day the household's weeks start on (0 = Monday, 6 = Sunday), Monday when
+/// it has no settings yet. Like `disabled_features` this never creates settings.
+pub async fn week_start_day(pool: &SqlitePool, household_id: &Uuid) -> Result<i32, sqlx::Error> {
+    let day: Option<i32> = sqlx::query_scalar("SELECT week_start_day FROM household_settings WHERE household_id = ?")
+        .bind(household_id.to_string())
+        .fetch_optional(pool)
+        .await?;
+    Ok(day.unwrap_or_default())
+}
+
 /// Check if a household is currently on vacation
 ///
 /// Returns true if vacation_mode is enabled AND the current date falls within
@@ -299,6 +433,14 @@ mod tests {
         assert!(error.to_string().contains("Database error"));
     }
 
+    #[test]
+    fn test_clean_label() {
+        assert_eq!(clean_label(Some("  Consequence ")), Some("Consequence".to_string()));
+        assert_eq!(clean_label(Some("   ")), None);
+        assert_eq!(clean_label(None), None);
+        assert_eq!(clean_label(Some(&"x".repeat(100))).unwrap().len(), MAX_LABEL_LEN);
+    }
+
     #[test]
     fn test_is_household_on_vacation_mode_off() {
         let settings = HouseholdSettings {
@@ -1,11 +1,11 @@
-use chrono::Utc;
-use sqlx::SqlitePool;
+use chrono::{NaiveDate, Utc};
+use sqlx::{SqliteConnection, SqlitePool};
 use thiserror::Error;
 use uuid::Uuid;
 
 use crate::models::{HouseholdRow, MembershipRow, UserRow};
 use shared::{
-    CreateHouseholdRequest, Household, HouseholdMembership, LeaderboardEntry, MemberWithUser,
+    CreateHouseholdRequest, Household, HouseholdMembership, MemberWithUser,
     Role, UpdateHouseholdRequest,
 };
 
@@ -146,6 +146,16 @@ pub async fn delete_household(pool: &SqlitePool, household_id: &Uuid) -> Result<
         .execute(pool)
         .await?;
 
+    sqlx::query("DELETE FROM events WHERE task_id IN (SELECT id FROM tasks WHERE household_id = ?)")
+        .bind(household_id.to_string())
+        .execute(pool)
+        .await?;
+
+    sqlx::query("DELETE FROM task_daily_rollups WHERE task_id IN (SELECT id FROM tasks WHERE household_id = ?)")
+        .bind(household_id.to_string())
+        .execute(pool)
+        .await?;
+
     sqlx::query("DELETE FROM punishments WHERE household_id = ?")
         .bind(household_id.to_string())
         .execute(pool)
@@ -166,11 +176,27 @@ pub async fn delete_household(pool: &SqlitePool, household_id: &Uuid) -> Result<
         .execute(pool)
         .await?;
 
+    sqlx::query("DELETE FROM point_transactions WHERE household_id = ?")
+        .bind(household_id.to_string())
+        .execute(pool)
+        .await?;
+
     sqlx::query("DELETE FROM household_memberships WHERE household_id = ?")
         .bind(household_id.to_string())
         .execute(pool)
         .await?;
 
+    // No ON DELETE CASCADE on these, so they would block the delete below
+    sqlx::query("DELETE FROM activity_logs WHERE household_id = ?")
+        .bind(household_id.to_string())
+        .execute(pool)
+        .await?;
+
+    sqlx::query("DELETE FROM household_settings WHERE household_id = ?")
+        .bind(household_id.to_string())
+        .execute(pool)
+        .await?;
+
     sqlx::query("DELETE FROM households WHERE id = ?")
         .bind(household_id.to_string())
         .execute(pool)
@@ -229,13 +255,66 @@ pub async fn list_members(pool: &SqlitePool, household_id: &Uuid) -> Result<Vec<
     Ok(result)
 }
 
+/// Add a user to a household with the given role
+pub async fn add_member(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    role: Role,
+) -> Result<HouseholdMembership, HouseholdError> {
+    sqlx::query(
+        r#"
+        INSERT INTO household_memberships (id, household_id, user_id, role, points, joined_at)
+        VALUES (?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .bind(role.as_str())
+    .bind(0i64)
+    .bind(Utc::now())
+    .execute(pool)
+    .await?;
+
+    get_membership(pool, household_id, user_id)
+        .await?
+        .ok_or(HouseholdError::NotFound)
+}
+
+/// Remove a member from a household.
+/// They are removed from the assignees of their tasks; their points are forfeited with the membership
+/// while the point history stays for the record.
 pub async fn remove_member(pool: &SqlitePool, household_id: &Uuid, user_id: &Uuid) -> Result<(), HouseholdError> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("DELETE FROM task_assignees WHERE user_id = ? AND task_id IN (SELECT id FROM tasks WHERE household_id = ?)")
+        .bind(user_id.to_string())
+        .bind(household_id.to_string())
+        .execute(&mut *tx)
+        .await?;
+
+    // The next remaining assignee (if any) becomes the task's first assignee
+    sqlx::query(
+        r#"
+        UPDATE tasks SET assigned_user_id = (
+            SELECT a.user_id FROM task_assignees a WHERE a.task_id = tasks.id ORDER BY a.rowid LIMIT 1
+        )
+        WHERE household_id = ? AND assigned_user_id = ?
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .execute(&mut *tx)
+    .await?;
+
     sqlx::query("DELETE FROM household_memberships WHERE household_id = ? AND user_id = ?")
         .bind(household_id.to_string())
         .bind(user_id.to_string())
-        .execute(pool)
+        .execute(&mut *tx)
         .await?;
 
+    tx.commit().await?;
     Ok(())
 }
 
@@ -245,7 +324,7 @@ pub async fn update_member_role(
     user_id: &Uuid,
     role: Role,
 ) -> Result<HouseholdMembership, HouseholdError> {
-    sqlx::query("UPDATE household_memberships SET role = ? WHERE household_id = ? AND user_id = ?")
+    sqlx::query("UPDATE household_memberships SET role = ?, permissions = NULL WHERE household_id = ? AND user_id = ?")
         .bind(role.as_str())
         .bind(household_id.to_string())
         .bind(user_id.to_string())
@@ -275,7 +354,7 @@ pub async fn transfer_ownership(
     let mut tx = pool.begin().await?;
 
     // Demote current owner to admin
-    sqlx::query("UPDATE household_memberships SET role = ? WHERE household_id = ? AND user_id = ?")
+    sqlx::query("UPDATE household_memberships SET role = ?, permissions = NULL WHERE household_id = ? AND user_id = ?")
         .bind(Role::Admin.as_str())
         .bind(household_id.to_string())
         .bind(current_owner_id.to_string())
@@ -283,13 +362,21 @@ pub async fn transfer_ownership(
         .await?;
 
     // Promote new owner
-    sqlx::query("UPDATE household_memberships SET role = ? WHERE household_id = ? AND user_id = ?")
+    sqlx::query("UPDATE household_memberships SET role = ?, permissions = NULL WHERE household_id = ? AND user_id = ?")
         .bind(Role::Owner.as_str())
         .bind(household_id.to_string())
         .bind(new_owner_id.to_string())
         .execute(&mut *tx)
         .await?;
 
+    // Keep the household's owner reference in sync
+    sqlx::query("UPDATE households SET owner_id = ?, updated_at = ? WHERE id = ?")
+        .bind(new_owner_id.to_string())
+        .bind(Utc::now())
+        .bind(household_id.to_string())
+        .execute(&mut *tx)
+        .await?;
+
     // Commit the transaction
     tx.commit().await?;
 
@@ -310,12 +397,23 @@ pub async fn update_member_points(
     household_id: &Uuid,
     user_id: &Uuid,
     points_delta: i64,
+) -> Result<i64, HouseholdError> {
+    let mut conn = pool.acquire().await?;
+    update_member_points_in(&mut conn, household_id, user_id, points_delta).await
+}
+
+/// Same as `update_member_points`, on a given connection or open transaction
+pub async fn update_member_points_in(
+    conn: &mut SqliteConnection,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    points_delta: i64,
 ) -> Result<i64, HouseholdError> {
     sqlx::query("UPDATE household_memberships SET points = points + ? WHERE household_id = ? AND user_id = ?")
         .bind(points_delta)
         .bind(household_id.to_string())
         .bind(user_id.to_string())
-        .execute(pool)
+        .execute(&mut *conn)
         .await?;
 
     let new_points = sqlx::query_scalar::<_, i64>(
@@ -323,46 +421,143 @@ pub async fn update_member_points(
     )
     .bind(household_id.to_string())
     .bind(user_id.to_string())
-    .fetch_one(pool)
+    .fetch_one(&mut *conn)
     .await?;
 
     Ok(new_points)
 }
 
-pub async fn get_leaderboard(pool: &SqlitePool, household_id: &Uuid) -> Result<Vec<LeaderboardEntry>, HouseholdError> {
-    let members = list_members(pool, household_id).await?;
+/// Take `cost` points from a member only if the balance covers it, in a single
+/// statement so concurrent spends cannot both succeed. Returns the new balance,
+/// or `None` if the member has too few points.
+pub async fn spend_member_points_in(
+    conn: &mut SqliteConnection,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    cost: i64,
+) -> Result<Option<i64>, HouseholdError> {
+    let result = sqlx::query(
+        "UPDATE household_memberships SET points = points - ? WHERE household_id = ? AND user_id = ? AND points >= ?",
+    )
+    .bind(cost)
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .bind(cost)
+    .execute(&mut *conn)
+    .await?;
 
-    let mut entries: Vec<LeaderboardEntry> = Vec::new();
+    if result.rows_affected() == 0 {
+        return Ok(None);
+    }
 
-    for (rank, member) in members.iter().enumerate() {
-        // Count task completions
-        let tasks_completed = sqlx::query_scalar::<_, i64>(
-            r#"
-            SELECT COUNT(*) FROM task_completions tc
-            JOIN tasks t ON tc.task_id = t.id
-            WHERE t.household_id = ? AND tc.user_id = ?
-            "#,
-        )
-        .bind(household_id.to_string())
-        .bind(member.user.id.to_string())
-        .fetch_one(pool)
+    let new_points = sqlx::query_scalar::<_, i64>(
+        "SELECT points FROM household_memberships WHERE household_id = ? AND user_id = ?",
+    )
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .fetch_one(&mut *conn)
+    .await?;
+
+    Ok(Some(new_points))
+}
+
+pub async fn get_membership(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+) -> Result<Option<HouseholdMembership>, HouseholdError> {
+    let membership: Option<MembershipRow> = sqlx::query_as(
+        "SELECT * FROM household_memberships WHERE household_id = ? AND user_id = ?",
+    )
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(membership.map(|m| m.to_shared()))
+}
+
+/// Set or clear a member's vacation range. Clearing the start date ends the vacation.
+pub async fn set_member_vacation(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    vacation_start: Option<NaiveDate>,
+    vacation_end: Option<NaiveDate>,
+) -> Result<HouseholdMembership, HouseholdError> {
+    // An end date without a start date has no meaning
+    let vacation_end = vacation_start.and(vacation_end);
+
+    let result = sqlx::query(
+        "UPDATE household_memberships SET vacation_start = ?, vacation_end = ? WHERE household_id = ? AND user_id = ?",
+    )
+    .bind(vacation_start)
+    .bind(vacation_end)
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .execute(pool)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(HouseholdError::NotFound);
+    }
+
+    get_membership(pool, household_id, user_id)
+        .await?
+        .ok_or(HouseholdError::NotFound)
+}
+
+/// Dissolve all of a user's memberships, e.g. before their account is deleted.
+/// Owned households pass to the longest-standing admin (or member if there is no admin);
+/// households the user is the only member of are deleted.
+/// Returns the `(household_id, new_owner_id)` pairs of every ownership handover.
+pub async fn leave_all_households(pool: &SqlitePool, user_id: &Uuid) -> Result<Vec<(Uuid, Uuid)>, HouseholdError> {
+    let memberships: Vec<MembershipRow> = sqlx::query_as("SELECT * FROM household_memberships WHERE user_id = ?")
+        .bind(user_id.to_string())
+        .fetch_all(pool)
         .await?;
 
-        entries.push(LeaderboardEntry {
-            user: member.user.clone(),
-            points: member.membership.points,
-            rank: (rank + 1) as i32,
-            tasks_completed,
-            current_streak: 0, // TODO: Calculate actual streak
-        });
+    let mut handovers = Vec::new();
+    for membership in memberships {
+        let membership = membership.to_shared();
+        let household_id = membership.household_id;
+
+        if membership.role == Role::Owner {
+            let successor: Option<String> = sqlx::query_scalar(
+                r#"
+                SELECT user_id FROM household_memberships
+                WHERE household_id = ? AND user_id != ?
+                ORDER BY CASE role WHEN 'admin' THEN 0 ELSE 1 END, joined_at ASC
+                LIMIT 1
+                "#,
+            )
+            .bind(household_id.to_string())
+            .bind(user_id.to_string())
+            .fetch_optional(pool)
+            .await?;
+
+            match successor.and_then(|id| Uuid::parse_str(&id).ok()) {
+                Some(new_owner_id) => {
+                    transfer_ownership(pool, &household_id, user_id, &new_owner_id).await?;
+                    handovers.push((household_id, new_owner_id));
+                }
+                None => {
+                    delete_household(pool, &household_id).await?;
+                    continue;
+                }
+            }
+        }
+
+        remove_member(pool, &household_id, user_id).await?;
     }
 
-    Ok(entries)
+    Ok(handovers)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils;
 
     #[test]
     fn test_role_from_str() {
@@ -371,4 +566,97 @@ mod tests {
         assert_eq!("member".parse(), Ok(Role::Member));
         assert!("invalid".parse::<Role>().is_err());
     }
+
+    #[tokio::test]
+    async fn test_set_member_vacation() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user_id = test_utils::create_test_user(&pool, "traveller@test.com", Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &user_id, Role::Member).await;
+
+        let start = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 7, 14).unwrap();
+        let membership = set_member_vacation(&pool, &household_id, &user_id, Some(start), Some(end))
+            .await
+            .unwrap();
+        assert_eq!(membership.vacation_start, Some(start));
+        assert_eq!(membership.vacation_end, Some(end));
+        assert!(membership.is_on_vacation(NaiveDate::from_ymd_opt(2024, 7, 5).unwrap()));
+
+        // An end date alone clears the vacation
+        let membership = set_member_vacation(&pool, &household_id, &user_id, None, Some(end))
+            .await
+            .unwrap();
+        assert_eq!(membership.vacation_start, None);
+        assert_eq!(membership.vacation_end, None);
+
+        let stranger = Uuid::new_v4();
+        assert!(matches!(
+            set_member_vacation(&pool, &household_id, &stranger, Some(start), None).await,
+            Err(HouseholdError::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_leave_all_households() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let leaving = test_utils::create_test_user(&pool, "leaving@test.com", Role::Owner).await;
+        let member = test_utils::create_test_user(&pool, "member@test.com", Role::Member).await;
+        let admin = test_utils::create_test_user(&pool, "admin@test.com", Role::Admin).await;
+        test_utils::create_test_membership(&pool, &household_id, &leaving, Role::Owner).await;
+        test_utils::create_test_membership(&pool, &household_id, &member, Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &admin, Role::Admin).await;
+
+        let solo = create_household(&pool, &leaving, &CreateHouseholdRequest { name: "Solo".to_string() })
+            .await
+            .unwrap();
+
+        let handovers = leave_all_households(&pool, &leaving).await.unwrap();
+
+        // The admin is preferred over the member who joined earlier
+        assert_eq!(handovers, vec![(household_id, admin)]);
+        assert_eq!(get_member_role(&pool, &household_id, &admin).await, Some(Role::Owner));
+        assert!(!is_member(&pool, &household_id, &leaving).await.unwrap());
+        assert!(get_household(&pool, &solo.id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_transfer_ownership_updates_household_owner() {
+        let pool = test_utils::create_test_pool().await;
+        let owner = test_utils::create_test_user(&pool, "founder@test.com", Role::Owner).await;
+        let member = test_utils::create_test_user(&pool, "heir@test.com", Role::Member).await;
+        let household = create_household(&pool, &owner, &CreateHouseholdRequest { name: "Home".to_string() })
+            .await
+            .unwrap();
+        test_utils::create_test_membership(&pool, &household.id, &member, Role::Member).await;
+
+        let membership = transfer_ownership(&pool, &household.id, &owner, &member).await.unwrap();
+
+        assert_eq!(membership.role, Role::Owner);
+        assert_eq!(get_member_role(&pool, &household.id, &owner).await, Some(Role::Admin));
+        assert_eq!(get_household(&pool, &household.id).await.unwrap().unwrap().owner_id, member);
+    }
+
+    #[tokio::test]
+    async fn test_remove_member_unassigns_tasks() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user_id = test_utils::create_test_user(&pool, "leaver@test.com", Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &user_id, Role::Member).await;
+        let task = test_utils::create_test_task(&pool, &household_id)
+            .with_assigned_user(user_id)
+            .build()
+            .await;
+
+        remove_member(&pool, &household_id, &user_id).await.unwrap();
+
+        assert!(!is_member(&pool, &household_id, &user_id).await.unwrap());
+        let assigned: Option<String> = sqlx::query_scalar("SELECT assigned_user_id FROM tasks WHERE id = ?")
+            .bind(task.id.to_string())
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert!(assigned.is_none());
+    }
 }
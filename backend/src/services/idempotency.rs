@@ -0,0 +1,171 @@
+//! Idempotency keys for requests that must not run twice
+//!
+//! Completing a task, buying a reward or adjusting points is not safe to
+//! repeat, but flaky mobile connections and the frontend's retry after a token
+//! refresh can send the same request again. Clients attach an `Idempotency-Key`
+//! header; the first successful response is stored for `IDEMPOTENCY_KEY_TTL_HOURS`
+//! and returned again for every retry with the same key.
+
+use chrono::{DateTime, Duration, Utc};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+/// How long a stored response is replayed
+pub const IDEMPOTENCY_KEY_TTL_HOURS: i64 = 24;
+
+/// Longest key accepted from a client
+pub const MAX_KEY_LEN: usize = 255;
+
+/// Outcome of claiming a key for a request
+#[derive(Debug, PartialEq)]
+pub enum IdempotencyClaim {
+    /// First request with this key; run it and store the response
+    New,
+    /// The request already succeeded; answer with the stored response
+    Replay { status: u16, body: String },
+    /// The first request with this key has not finished yet
+    InProgress,
+    /// The key was already used for a different endpoint
+    KeyReused,
+}
+
+#[derive(sqlx::FromRow)]
+struct StoredResponse {
+    request_path: String,
+    status_code: Option<i64>,
+    response_body: Option<String>,
+}
+
+/// Claim `key` for a request to `request_path`. Expired keys are dropped first,
+/// so a key can be reused once its response is no longer replayed.
+pub async fn claim(
+    pool: &SqlitePool,
+    user_id: &Uuid,
+    key: &str,
+    request_path: &str,
+    now: DateTime<Utc>,
+) -> Result<IdempotencyClaim, sqlx::Error> {
+    sqlx::query("DELETE FROM idempotency_keys WHERE created_at < ?")
+        .bind(now - Duration::hours(IDEMPOTENCY_KEY_TTL_HOURS))
+        .execute(pool)
+        .await?;
+
+    let inserted = sqlx::query(
+        "INSERT OR IGNORE INTO idempotency_keys (user_id, idempotency_key, request_path, created_at) VALUES (?, ?, ?, ?)",
+    )
+    .bind(user_id.to_string())
+    .bind(key)
+    .bind(request_path)
+    .bind(now)
+    .execute(pool)
+    .await?;
+    if inserted.rows_affected() == 1 {
+        return Ok(IdempotencyClaim::New);
+    }
+
+    let stored: Option<StoredResponse> = sqlx::query_as(
+        "SELECT request_path, status_code, response_body FROM idempotency_keys WHERE user_id = ? AND idempotency_key = ?",
+    )
+    .bind(user_id.to_string())
+    .bind(key)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(match stored {
+        Some(stored) if stored.request_path != request_path => IdempotencyClaim::KeyReused,
+        Some(StoredResponse {
+            status_code: Some(status),
+            response_body,
+            ..
+        }) => IdempotencyClaim::Replay {
+            status: status as u16,
+            body: response_body.unwrap_or_default(),
+        },
+        // Still running, or released by the first request a moment ago
+        _ => IdempotencyClaim::InProgress,
+    })
+}
+
+/// Store the response of a successful request for replay
+pub async fn store_response(
+    pool: &SqlitePool,
+    user_id: &Uuid,
+    key: &str,
+    status: u16,
+    body: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE idempotency_keys SET status_code = ?, response_body = ? WHERE user_id = ? AND idempotency_key = ?",
+    )
+    .bind(status as i64)
+    .bind(body)
+    .bind(user_id.to_string())
+    .bind(key)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Give up a claimed key after a failed request so the client can retry it
+pub async fn release(pool: &SqlitePool, user_id: &Uuid, key: &str) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM idempotency_keys WHERE user_id = ? AND idempotency_key = ?")
+        .bind(user_id.to_string())
+        .bind(key)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{create_test_pool, create_test_user};
+    use shared::Role;
+
+    const PATH: &str = "/api/households/h1/tasks/t1/complete";
+
+    #[tokio::test]
+    async fn test_stored_response_is_replayed() {
+        let pool = create_test_pool().await;
+        let user_id = create_test_user(&pool, "alice@test.com", Role::Owner).await;
+        let now = Utc::now();
+
+        assert_eq!(claim(&pool, &user_id, "key-1", PATH, now).await.unwrap(), IdempotencyClaim::New);
+        assert_eq!(
+            claim(&pool, &user_id, "key-1", PATH, now).await.unwrap(),
+            IdempotencyClaim::InProgress
+        );
+
+        store_response(&pool, &user_id, "key-1", 200, r#"{"success":true}"#).await.unwrap();
+        assert_eq!(
+            claim(&pool, &user_id, "key-1", PATH, now).await.unwrap(),
+            IdempotencyClaim::Replay {
+                status: 200,
+                body: r#"{"success":true}"#.to_string()
+            }
+        );
+        assert_eq!(
+            claim(&pool, &user_id, "key-1", "/api/households/h1/rewards/r1/purchase", now).await.unwrap(),
+            IdempotencyClaim::KeyReused
+        );
+
+        // Keys are per user
+        let other = create_test_user(&pool, "bob@test.com", Role::Member).await;
+        assert_eq!(claim(&pool, &other, "key-1", PATH, now).await.unwrap(), IdempotencyClaim::New);
+    }
+
+    #[tokio::test]
+    async fn test_released_and_expired_keys_can_be_claimed_again() {
+        let pool = create_test_pool().await;
+        let user_id = create_test_user(&pool, "alice@test.com", Role::Owner).await;
+        let now = Utc::now();
+
+        claim(&pool, &user_id, "key-1", PATH, now).await.unwrap();
+        release(&pool, &user_id, "key-1").await.unwrap();
+        assert_eq!(claim(&pool, &user_id, "key-1", PATH, now).await.unwrap(), IdempotencyClaim::New);
+
+        store_response(&pool, &user_id, "key-1", 200, "{}").await.unwrap();
+        let later = now + Duration::hours(IDEMPOTENCY_KEY_TTL_HOURS + 1);
+        assert_eq!(claim(&pool, &user_id, "key-1", PATH, later).await.unwrap(), IdempotencyClaim::New);
+    }
+}
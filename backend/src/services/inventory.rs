@@ -0,0 +1,349 @@
+use chrono::Utc;
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::InventoryItemRow;
+use crate::services::shopping_list::{self as shopping_list_service, ShoppingListError};
+use shared::{
+    AdjustInventoryRequest, CreateInventoryItemRequest, CreateShoppingListItemRequest, InventoryAdjustment,
+    InventoryItem, UpdateInventoryItemRequest,
+};
+
+#[derive(Debug, Error)]
+pub enum InventoryError {
+    #[error("Inventory item not found")]
+    NotFound,
+    #[error("Quantities must be finite and not negative")]
+    InvalidQuantity,
+    #[error("Shopping list error: {0}")]
+    ShoppingList(#[from] ShoppingListError),
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+fn clean_text(value: &Option<String>) -> Option<String> {
+    value
+        .as_ref()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+fn valid_quantity(quantity: f64) -> bool {
+    quantity.is_finite() && quantity >= 0.0
+}
+
+fn round_quantity(quantity: f64) -> f64 {
+    (quantity * 1000.0).round() / 1000.0
+}
+
+pub async fn list_items(pool: &SqlitePool, household_id: &Uuid) -> Result<Vec<InventoryItem>, InventoryError> {
+    let rows: Vec<InventoryItemRow> = sqlx::query_as(
+        r#"
+        SELECT * FROM inventory_items
+        WHERE household_id = ?
+        ORDER BY location IS NULL, location COLLATE NOCASE ASC, name COLLATE NOCASE ASC
+        "#,
+    )
+    .bind(household_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.to_shared()).collect())
+}
+
+async fn get_item(pool: &SqlitePool, household_id: &Uuid, item_id: &Uuid) -> Result<InventoryItemRow, InventoryError> {
+    sqlx::query_as("SELECT * FROM inventory_items WHERE id = ? AND household_id = ?")
+        .bind(item_id.to_string())
+        .bind(household_id.to_string())
+        .fetch_optional(pool)
+        .await?
+        .ok_or(InventoryError::NotFound)
+}
+
+async fn save_item(pool: &SqlitePool, item: &InventoryItemRow) -> Result<(), InventoryError> {
+    sqlx::query(
+        r#"
+        UPDATE inventory_items
+        SET name = ?, quantity = ?, unit = ?, location = ?, min_quantity = ?, updated_at = ?
+        WHERE id = ?
+        "#,
+    )
+    .bind(&item.name)
+    .bind(item.quantity)
+    .bind(&item.unit)
+    .bind(&item.location)
+    .bind(item.min_quantity)
+    .bind(item.updated_at)
+    .bind(&item.id)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Put the item on the shopping list if it just fell below its minimum.
+/// Returns whether it was added; the household decides how much to buy.
+async fn add_to_shopping_list_if_low(
+    pool: &SqlitePool,
+    user_id: &Uuid,
+    was_low: bool,
+    item: &InventoryItem,
+) -> Result<bool, InventoryError> {
+    if was_low || !item.is_low() {
+        return Ok(false);
+    }
+
+    let request = CreateShoppingListItemRequest {
+        name: item.name.clone(),
+        quantity: None,
+        unit: None,
+    };
+    shopping_list_service::add_item(pool, &item.household_id, user_id, &request).await?;
+    Ok(true)
+}
+
+pub async fn create_item(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    request: &CreateInventoryItemRequest,
+) -> Result<InventoryItem, InventoryError> {
+    if !valid_quantity(request.quantity) || request.min_quantity.is_some_and(|m| !valid_quantity(m)) {
+        return Err(InventoryError::InvalidQuantity);
+    }
+
+    let now = Utc::now();
+    let row = InventoryItemRow {
+        id: Uuid::new_v4().to_string(),
+        household_id: household_id.to_string(),
+        name: request.name.trim().to_string(),
+        quantity: round_quantity(request.quantity),
+        unit: clean_text(&request.unit),
+        location: clean_text(&request.location),
+        min_quantity: request.min_quantity,
+        created_by: user_id.to_string(),
+        created_at: now,
+        updated_at: now,
+    };
+
+    sqlx::query(
+        r#"
+        INSERT INTO inventory_items (id, household_id, name, quantity, unit, location, min_quantity, created_by, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(&row.id)
+    .bind(&row.household_id)
+    .bind(&row.name)
+    .bind(row.quantity)
+    .bind(&row.unit)
+    .bind(&row.location)
+    .bind(row.min_quantity)
+    .bind(&row.created_by)
+    .bind(now)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    let item = row.to_shared();
+    add_to_shopping_list_if_low(pool, user_id, false, &item).await?;
+    Ok(item)
+}
+
+pub async fn update_item(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    item_id: &Uuid,
+    request: &UpdateInventoryItemRequest,
+) -> Result<InventoryItem, InventoryError> {
+    if request.quantity.is_some_and(|q| !valid_quantity(q))
+        || request.min_quantity.flatten().is_some_and(|m| !valid_quantity(m))
+    {
+        return Err(InventoryError::InvalidQuantity);
+    }
+
+    let mut row = get_item(pool, household_id, item_id).await?;
+    let was_low = row.to_shared().is_low();
+
+    if let Some(ref name) = request.name {
+        row.name = name.trim().to_string();
+    }
+    if let Some(quantity) = request.quantity {
+        row.quantity = round_quantity(quantity);
+    }
+    if request.unit.is_some() {
+        row.unit = clean_text(&request.unit);
+    }
+    if request.location.is_some() {
+        row.location = clean_text(&request.location);
+    }
+    if let Some(min_quantity) = request.min_quantity {
+        row.min_quantity = min_quantity;
+    }
+    row.updated_at = Utc::now();
+    save_item(pool, &row).await?;
+
+    let item = row.to_shared();
+    add_to_shopping_list_if_low(pool, user_id, was_low, &item).await?;
+    Ok(item)
+}
+
+/// Use up or restock an item. The quantity never goes below zero.
+pub async fn adjust_item(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    item_id: &Uuid,
+    request: &AdjustInventoryRequest,
+) -> Result<InventoryAdjustment, InventoryError> {
+    if !request.delta.is_finite() {
+        return Err(InventoryError::InvalidQuantity);
+    }
+
+    let mut row = get_item(pool, household_id, item_id).await?;
+    let was_low = row.to_shared().is_low();
+
+    row.quantity = round_quantity((row.quantity + request.delta).max(0.0));
+    row.updated_at = Utc::now();
+    save_item(pool, &row).await?;
+
+    let item = row.to_shared();
+    let added_to_shopping_list = add_to_shopping_list_if_low(pool, user_id, was_low, &item).await?;
+    Ok(InventoryAdjustment {
+        item,
+        added_to_shopping_list,
+    })
+}
+
+pub async fn delete_item(pool: &SqlitePool, household_id: &Uuid, item_id: &Uuid) -> Result<(), InventoryError> {
+    let result = sqlx::query("DELETE FROM inventory_items WHERE id = ? AND household_id = ?")
+        .bind(item_id.to_string())
+        .bind(household_id.to_string())
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(InventoryError::NotFound);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use shared::{Role, UpdateShoppingListItemRequest};
+
+    fn tabs(quantity: f64, min_quantity: Option<f64>) -> CreateInventoryItemRequest {
+        CreateInventoryItemRequest {
+            name: "Dishwasher tabs".to_string(),
+            quantity,
+            unit: None,
+            location: Some(" Kitchen ".to_string()),
+            min_quantity,
+        }
+    }
+
+    fn adjust(delta: f64) -> AdjustInventoryRequest {
+        AdjustInventoryRequest { delta }
+    }
+
+    #[tokio::test]
+    async fn test_adjust_item_adds_to_shopping_list_once() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user = test_utils::create_test_user(&pool, "stock@test.com", Role::Member).await;
+
+        let item = create_item(&pool, &household_id, &user, &tabs(3.0, Some(2.0))).await.unwrap();
+        assert_eq!(item.location.as_deref(), Some("Kitchen"));
+
+        let used = adjust_item(&pool, &household_id, &user, &item.id, &adjust(-1.0)).await.unwrap();
+        assert_eq!(used.item.quantity, 2.0);
+        assert!(!used.added_to_shopping_list);
+        assert!(shopping_list_service::list_items(&pool, &household_id).await.unwrap().is_empty());
+
+        let low = adjust_item(&pool, &household_id, &user, &item.id, &adjust(-1.0)).await.unwrap();
+        assert!(low.added_to_shopping_list);
+        let list = shopping_list_service::list_items(&pool, &household_id).await.unwrap();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].name, "Dishwasher tabs");
+
+        // Staying below the minimum does not add it again, and stock never goes negative
+        let empty = adjust_item(&pool, &household_id, &user, &item.id, &adjust(-5.0)).await.unwrap();
+        assert!(!empty.added_to_shopping_list);
+        assert_eq!(empty.item.quantity, 0.0);
+
+        // After restocking, the next shortage puts it back on the list
+        shopping_list_service::update_item(
+            &pool,
+            &household_id,
+            &list[0].id,
+            &UpdateShoppingListItemRequest {
+                is_checked: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        adjust_item(&pool, &household_id, &user, &item.id, &adjust(10.0)).await.unwrap();
+        let again = adjust_item(&pool, &household_id, &user, &item.id, &adjust(-9.0)).await.unwrap();
+        assert!(again.added_to_shopping_list);
+        let list = shopping_list_service::list_items(&pool, &household_id).await.unwrap();
+        assert_eq!(list.len(), 1);
+        assert!(!list[0].is_checked);
+    }
+
+    #[tokio::test]
+    async fn test_create_and_update_item() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user = test_utils::create_test_user(&pool, "stock@test.com", Role::Member).await;
+
+        let item = create_item(&pool, &household_id, &user, &tabs(10.0, None)).await.unwrap();
+        assert!(matches!(
+            create_item(&pool, &household_id, &user, &tabs(-1.0, None)).await,
+            Err(InventoryError::InvalidQuantity)
+        ));
+
+        // Raising the minimum above the stock counts as dropping below it
+        let updated = update_item(
+            &pool,
+            &household_id,
+            &user,
+            &item.id,
+            &UpdateInventoryItemRequest {
+                min_quantity: Some(Some(12.0)),
+                location: Some(String::new()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert!(updated.is_low());
+        assert_eq!(updated.location, None);
+        assert_eq!(shopping_list_service::list_items(&pool, &household_id).await.unwrap().len(), 1);
+
+        let cleared = update_item(
+            &pool,
+            &household_id,
+            &user,
+            &item.id,
+            &UpdateInventoryItemRequest {
+                min_quantity: Some(None),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(cleared.min_quantity, None);
+        assert_eq!(list_items(&pool, &household_id).await.unwrap(), vec![cleared]);
+
+        delete_item(&pool, &household_id, &item.id).await.unwrap();
+        assert!(matches!(
+            adjust_item(&pool, &household_id, &user, &item.id, &adjust(1.0)).await,
+            Err(InventoryError::NotFound)
+        ));
+    }
+}
@@ -1,12 +1,21 @@
 use chrono::{Duration, Utc};
+use rand::seq::SliceRandom;
 use sqlx::SqlitePool;
 use thiserror::Error;
 use uuid::Uuid;
 
-use crate::models::{HouseholdRow, InvitationRow, UserRow};
-use shared::{HouseholdMembership, Invitation, InvitationStatus, InvitationWithHousehold, Role, User};
+use crate::models::{HouseholdRow, InvitationRow, InviteCodeRow, UserRow};
+use shared::{
+    normalize_invite_code, CreateInviteCodeRequest, HouseholdMembership, Invitation, InvitationStatus, InvitationWithHousehold,
+    InviteCode, Role, User,
+};
 
 const INVITATION_EXPIRY_DAYS: i64 = 7;
+/// Longest validity of an invite code (30 days)
+const MAX_INVITE_CODE_HOURS: i64 = 24 * 30;
+const INVITE_CODE_LENGTH: usize = 8;
+/// Letters and digits that can't be confused when read aloud or typed (no 0/O, 1/I/L)
+const INVITE_CODE_ALPHABET: &[u8] = b"ABCDEFGHJKMNPQRSTUVWXYZ23456789";
 
 #[derive(Debug, Error)]
 pub enum InvitationError {
@@ -20,6 +29,10 @@ pub enum InvitationError {
     Expired,
     #[error("Invitation is not for this user")]
     NotForUser,
+    #[error("Invite code is invalid, expired or used up")]
+    InvalidCode,
+    #[error("Invite codes must be valid for 1 to 720 hours and allow at least one use")]
+    InvalidCodeSettings,
     #[error("Database error: {0}")]
     DatabaseError(#[from] sqlx::Error),
 }
@@ -245,6 +258,8 @@ pub async fn accept_invitation(
         role: invitation.role,
         points: 0,
         joined_at: now,
+        vacation_start: None,
+        vacation_end: None,
     })
 }
 
@@ -297,6 +312,189 @@ pub async fn cancel_invitation(
     Ok(())
 }
 
+fn generate_invite_code() -> String {
+    let mut rng = rand::thread_rng();
+    (0..INVITE_CODE_LENGTH)
+        .map(|_| *INVITE_CODE_ALPHABET.choose(&mut rng).unwrap() as char)
+        .collect()
+}
+
+/// Create a shareable invite code (default validity: 7 days, unlimited uses)
+pub async fn create_invite_code(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    request: &CreateInviteCodeRequest,
+    role: Role,
+    created_by: &Uuid,
+) -> Result<InviteCode, InvitationError> {
+    let hours = request.expires_in_hours.unwrap_or(INVITATION_EXPIRY_DAYS * 24);
+    if !(1..=MAX_INVITE_CODE_HOURS).contains(&hours) || request.max_uses.is_some_and(|max| max < 1) {
+        return Err(InvitationError::InvalidCodeSettings);
+    }
+
+    let id = Uuid::new_v4();
+    let code = generate_invite_code();
+    let now = Utc::now();
+    let expires_at = now + Duration::hours(hours);
+
+    sqlx::query(
+        r#"
+        INSERT INTO household_invite_codes (id, household_id, code, role, created_by, created_at, expires_at, max_uses, use_count)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, 0)
+        "#,
+    )
+    .bind(id.to_string())
+    .bind(household_id.to_string())
+    .bind(&code)
+    .bind(role.as_str())
+    .bind(created_by.to_string())
+    .bind(now)
+    .bind(expires_at)
+    .bind(request.max_uses)
+    .execute(pool)
+    .await?;
+
+    Ok(InviteCode {
+        id,
+        household_id: *household_id,
+        code,
+        role,
+        created_by: *created_by,
+        created_at: now,
+        expires_at,
+        max_uses: request.max_uses,
+        use_count: 0,
+    })
+}
+
+/// Look up an invite code of a household (also expired or used-up ones)
+pub async fn get_invite_code(pool: &SqlitePool, household_id: &Uuid, code_id: &Uuid) -> Result<InviteCode, InvitationError> {
+    let row: Option<InviteCodeRow> = sqlx::query_as("SELECT * FROM household_invite_codes WHERE id = ? AND household_id = ?")
+        .bind(code_id.to_string())
+        .bind(household_id.to_string())
+        .fetch_optional(pool)
+        .await?;
+
+    row.map(|r| r.to_shared()).ok_or(InvitationError::NotFound)
+}
+
+/// Link that opens the frontend join page for a code
+pub fn invite_link(public_url: &str, code: &str) -> String {
+    format!("{}/join/{}", public_url.trim_end_matches('/'), code)
+}
+
+/// Render a link as a QR code SVG image
+pub fn qr_code_svg(link: &str) -> Result<String, qrcode::types::QrError> {
+    let code = qrcode::QrCode::new(link.as_bytes())?;
+    Ok(code
+        .render::<qrcode::render::svg::Color>()
+        .min_dimensions(256, 256)
+        .quiet_zone(true)
+        .build())
+}
+
+/// Invite codes of a household that can still be redeemed, newest first
+pub async fn list_invite_codes(pool: &SqlitePool, household_id: &Uuid) -> Result<Vec<InviteCode>, InvitationError> {
+    let rows: Vec<InviteCodeRow> = sqlx::query_as(
+        r#"
+        SELECT * FROM household_invite_codes
+        WHERE household_id = ? AND expires_at > ? AND (max_uses IS NULL OR use_count < max_uses)
+        ORDER BY created_at DESC
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(Utc::now())
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.to_shared()).collect())
+}
+
+pub async fn revoke_invite_code(pool: &SqlitePool, household_id: &Uuid, code_id: &Uuid) -> Result<(), InvitationError> {
+    let result = sqlx::query("DELETE FROM household_invite_codes WHERE id = ? AND household_id = ?")
+        .bind(code_id.to_string())
+        .bind(household_id.to_string())
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(InvitationError::NotFound);
+    }
+
+    Ok(())
+}
+
+/// Join the household an invite code belongs to, using up one of its uses
+pub async fn redeem_invite_code(
+    pool: &SqlitePool,
+    code: &str,
+    user: &User,
+) -> Result<HouseholdMembership, InvitationError> {
+    let invite_code: InviteCodeRow = sqlx::query_as("SELECT * FROM household_invite_codes WHERE code = ?")
+        .bind(normalize_invite_code(code))
+        .fetch_optional(pool)
+        .await?
+        .ok_or(InvitationError::InvalidCode)?;
+    let invite_code = invite_code.to_shared();
+
+    let is_member = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM household_memberships WHERE household_id = ? AND user_id = ?",
+    )
+    .bind(invite_code.household_id.to_string())
+    .bind(user.id.to_string())
+    .fetch_one(pool)
+    .await?;
+
+    if is_member > 0 {
+        return Err(InvitationError::AlreadyMember);
+    }
+
+    let now = Utc::now();
+
+    // Checking and counting the use in one statement keeps concurrent redemptions within max_uses
+    let claimed = sqlx::query(
+        r#"
+        UPDATE household_invite_codes SET use_count = use_count + 1
+        WHERE id = ? AND expires_at > ? AND (max_uses IS NULL OR use_count < max_uses)
+        "#,
+    )
+    .bind(invite_code.id.to_string())
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    if claimed.rows_affected() == 0 {
+        return Err(InvitationError::InvalidCode);
+    }
+
+    let membership_id = Uuid::new_v4();
+    sqlx::query(
+        r#"
+        INSERT INTO household_memberships (id, household_id, user_id, role, points, joined_at)
+        VALUES (?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(membership_id.to_string())
+    .bind(invite_code.household_id.to_string())
+    .bind(user.id.to_string())
+    .bind(invite_code.role.as_str())
+    .bind(0i64)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    Ok(HouseholdMembership {
+        id: membership_id,
+        household_id: invite_code.household_id,
+        user_id: user.id,
+        role: invite_code.role,
+        points: 0,
+        joined_at: now,
+        vacation_start: None,
+        vacation_end: None,
+    })
+}
+
 /// Expire old invitations (internal helper)
 async fn expire_old_invitations(pool: &SqlitePool) -> Result<(), InvitationError> {
     let now = Utc::now();
@@ -314,8 +512,75 @@ async fn expire_old_invitations(pool: &SqlitePool) -> Result<(), InvitationError
 mod tests {
     use super::*;
 
+    use crate::services::auth as auth_service;
+    use crate::test_utils::*;
+
+    #[test]
+    fn test_invite_link_qr_code() {
+        let link = invite_link("https://haushalt.example.com/", "ABCD2345");
+        assert_eq!(link, "https://haushalt.example.com/join/ABCD2345");
+
+        let svg = qr_code_svg(&link).unwrap();
+        assert!(svg.contains("<svg"));
+        assert!(svg.contains("</svg>"));
+    }
+
     #[test]
     fn test_invitation_expiry_days() {
         assert_eq!(INVITATION_EXPIRY_DAYS, 7);
     }
+
+    #[test]
+    fn test_generate_invite_code_uses_unambiguous_alphabet() {
+        let code = generate_invite_code();
+        assert_eq!(code.len(), INVITE_CODE_LENGTH);
+        assert!(code.bytes().all(|b| INVITE_CODE_ALPHABET.contains(&b)));
+    }
+
+    #[tokio::test]
+    async fn test_redeem_invite_code_respects_max_uses() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let owner_id: String = sqlx::query_scalar("SELECT owner_id FROM households WHERE id = ?")
+            .bind(household_id.to_string())
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let owner_id = Uuid::parse_str(&owner_id).unwrap();
+
+        let request = CreateInviteCodeRequest {
+            role: None,
+            expires_in_hours: Some(24),
+            max_uses: Some(1),
+        };
+        let code = create_invite_code(&pool, &household_id, &request, Role::Member, &owner_id)
+            .await
+            .unwrap();
+
+        let grandma_id = create_test_user(&pool, "grandma@example.com", Role::Member).await;
+        let grandma = auth_service::get_user_by_id(&pool, &grandma_id).await.unwrap().unwrap();
+        let typed = format!("{}-{}", &code.code[..4], code.code[4..].to_lowercase());
+        let membership = redeem_invite_code(&pool, &typed, &grandma).await.unwrap();
+        assert_eq!(membership.household_id, household_id);
+        assert_eq!(membership.role, Role::Member);
+
+        assert!(matches!(
+            redeem_invite_code(&pool, &code.code, &grandma).await,
+            Err(InvitationError::AlreadyMember)
+        ));
+
+        let cousin_id = create_test_user(&pool, "cousin@example.com", Role::Member).await;
+        let cousin = auth_service::get_user_by_id(&pool, &cousin_id).await.unwrap().unwrap();
+        assert!(matches!(
+            redeem_invite_code(&pool, &code.code, &cousin).await,
+            Err(InvitationError::InvalidCode)
+        ));
+        assert!(list_invite_codes(&pool, &household_id).await.unwrap().is_empty());
+
+        let invalid = CreateInviteCodeRequest { max_uses: Some(0), ..Default::default() };
+        assert!(matches!(
+            create_invite_code(&pool, &household_id, &invalid, Role::Member, &owner_id).await,
+            Err(InvitationError::InvalidCodeSettings)
+        ));
+    }
 }
@@ -4,7 +4,10 @@ use thiserror::Error;
 use uuid::Uuid;
 
 use crate::models::JournalEntryRow;
-use shared::{CreateJournalEntryRequest, JournalEntry, JournalEntryWithUser, UpdateJournalEntryRequest, User};
+use shared::{
+    CreateJournalEntryRequest, JournalEntry, JournalEntryWithUser, JournalVisibility, MoodPoint,
+    UpdateJournalEntryRequest, User, MOOD_MAX, MOOD_MIN,
+};
 
 #[derive(Debug, Error)]
 pub enum JournalError {
@@ -12,16 +15,27 @@ pub enum JournalError {
     NotFound,
     #[error("Permission denied")]
     PermissionDenied,
+    #[error("Mood must be between {MOOD_MIN} and {MOOD_MAX}")]
+    InvalidMood,
     #[error("Database error: {0}")]
     DatabaseError(#[from] sqlx::Error),
 }
 
+fn validate_mood(mood: Option<i32>) -> Result<(), JournalError> {
+    match mood {
+        Some(mood) if !(MOOD_MIN..=MOOD_MAX).contains(&mood) => Err(JournalError::InvalidMood),
+        _ => Ok(()),
+    }
+}
+
 pub async fn create_journal_entry(
     pool: &SqlitePool,
     household_id: &Uuid,
     user_id: &Uuid,
     request: &CreateJournalEntryRequest,
 ) -> Result<JournalEntry, JournalError> {
+    validate_mood(request.mood)?;
+
     let id = Uuid::new_v4();
     let now = Utc::now();
     let title = request.title.as_deref().unwrap_or("");
@@ -29,8 +43,8 @@ pub async fn create_journal_entry(
 
     sqlx::query(
         r#"
-        INSERT INTO journal_entries (id, household_id, user_id, title, content, entry_date, is_shared, created_at, updated_at)
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+        INSERT INTO journal_entries (id, household_id, user_id, title, content, entry_date, visibility, mood, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
         "#,
     )
     .bind(id.to_string())
@@ -39,7 +53,8 @@ pub async fn create_journal_entry(
     .bind(title)
     .bind(&request.content)
     .bind(entry_date)
-    .bind(request.is_shared)
+    .bind(request.visibility.as_str())
+    .bind(request.mood)
     .bind(now)
     .bind(now)
     .execute(pool)
@@ -52,14 +67,15 @@ pub async fn create_journal_entry(
         title: title.to_string(),
         content: request.content.clone(),
         entry_date,
-        is_shared: request.is_shared,
+        visibility: request.visibility,
+        mood: request.mood,
         created_at: now,
         updated_at: now,
     })
 }
 
 pub async fn get_journal_entry(pool: &SqlitePool, entry_id: &Uuid) -> Result<Option<JournalEntry>, JournalError> {
-    let entry: Option<JournalEntryRow> = sqlx::query_as("SELECT * FROM journal_entries WHERE id = ?")
+    let entry: Option<JournalEntryRow> = sqlx::query_as("SELECT * FROM journal_entries WHERE id = ? AND deleted_at IS NULL")
         .bind(entry_id.to_string())
         .fetch_optional(pool)
         .await?;
@@ -68,8 +84,8 @@ pub async fn get_journal_entry(pool: &SqlitePool, entry_id: &Uuid) -> Result<Opt
 }
 
 /// List all journal entries visible to a user in a household:
-/// - All shared entries (is_shared = true)
-/// - User's private entries (is_shared = false AND user_id = current_user)
+/// - All entries visible to the household
+/// - The user's own private entries
 pub async fn list_journal_entries(
     pool: &SqlitePool,
     household_id: &Uuid,
@@ -84,7 +100,8 @@ pub async fn list_journal_entries(
         j_title: String,
         j_content: String,
         j_entry_date: NaiveDate,
-        j_is_shared: bool,
+        j_visibility: String,
+        j_mood: Option<i32>,
         j_created_at: chrono::DateTime<chrono::Utc>,
         j_updated_at: chrono::DateTime<chrono::Utc>,
         // User fields
@@ -100,13 +117,13 @@ pub async fn list_journal_entries(
         SELECT
             j.id as j_id, j.household_id as j_household_id, j.user_id as j_user_id,
             j.title as j_title, j.content as j_content, j.entry_date as j_entry_date,
-            j.is_shared as j_is_shared, j.created_at as j_created_at, j.updated_at as j_updated_at,
+            j.visibility as j_visibility, j.mood as j_mood, j.created_at as j_created_at, j.updated_at as j_updated_at,
             u.id as u_id, u.username as u_username, u.email as u_email,
             u.created_at as u_created_at, u.updated_at as u_updated_at
         FROM journal_entries j
         JOIN users u ON j.user_id = u.id
-        WHERE j.household_id = ?
-          AND (j.is_shared = true OR j.user_id = ?)
+        WHERE j.household_id = ? AND j.deleted_at IS NULL
+          AND (j.visibility = 'household' OR j.user_id = ?)
         ORDER BY j.entry_date DESC, j.created_at DESC
         "#,
     )
@@ -125,7 +142,8 @@ pub async fn list_journal_entries(
                 title: row.j_title,
                 content: row.j_content,
                 entry_date: row.j_entry_date,
-                is_shared: row.j_is_shared,
+                visibility: row.j_visibility.parse().unwrap_or_default(),
+                mood: row.j_mood,
                 created_at: row.j_created_at,
                 updated_at: row.j_updated_at,
             },
@@ -136,13 +154,14 @@ pub async fn list_journal_entries(
                 created_at: row.u_created_at,
                 updated_at: row.u_updated_at,
             },
+            attachments: Vec::new(),
         })
         .collect())
 }
 
 /// Check if user can view a journal entry
 pub fn can_view_entry(entry: &JournalEntry, user_id: &Uuid) -> bool {
-    entry.is_shared || entry.user_id == *user_id
+    entry.visibility == JournalVisibility::Household || entry.user_id == *user_id
 }
 
 pub async fn update_journal_entry(
@@ -151,7 +170,7 @@ pub async fn update_journal_entry(
     user_id: &Uuid,
     request: &UpdateJournalEntryRequest,
 ) -> Result<JournalEntry, JournalError> {
-    let mut entry: JournalEntryRow = sqlx::query_as("SELECT * FROM journal_entries WHERE id = ?")
+    let mut entry: JournalEntryRow = sqlx::query_as("SELECT * FROM journal_entries WHERE id = ? AND deleted_at IS NULL")
         .bind(entry_id.to_string())
         .fetch_optional(pool)
         .await?
@@ -171,8 +190,12 @@ pub async fn update_journal_entry(
     if let Some(entry_date) = request.entry_date {
         entry.entry_date = entry_date;
     }
-    if let Some(is_shared) = request.is_shared {
-        entry.is_shared = is_shared;
+    if let Some(visibility) = request.visibility {
+        entry.visibility = visibility.as_str().to_string();
+    }
+    if let Some(mood) = request.mood {
+        validate_mood(mood)?;
+        entry.mood = mood;
     }
 
     let now = Utc::now();
@@ -180,14 +203,15 @@ pub async fn update_journal_entry(
 
     sqlx::query(
         r#"
-        UPDATE journal_entries SET title = ?, content = ?, entry_date = ?, is_shared = ?, updated_at = ?
+        UPDATE journal_entries SET title = ?, content = ?, entry_date = ?, visibility = ?, mood = ?, updated_at = ?
         WHERE id = ?
         "#,
     )
     .bind(&entry.title)
     .bind(&entry.content)
     .bind(entry.entry_date)
-    .bind(entry.is_shared)
+    .bind(&entry.visibility)
+    .bind(entry.mood)
     .bind(now)
     .bind(entry_id.to_string())
     .execute(pool)
@@ -201,7 +225,7 @@ pub async fn delete_journal_entry(
     entry_id: &Uuid,
     user_id: &Uuid,
 ) -> Result<(), JournalError> {
-    let entry: JournalEntryRow = sqlx::query_as("SELECT * FROM journal_entries WHERE id = ?")
+    let entry: JournalEntryRow = sqlx::query_as("SELECT * FROM journal_entries WHERE id = ? AND deleted_at IS NULL")
         .bind(entry_id.to_string())
         .fetch_optional(pool)
         .await?
@@ -212,7 +236,9 @@ pub async fn delete_journal_entry(
         return Err(JournalError::PermissionDenied);
     }
 
-    sqlx::query("DELETE FROM journal_entries WHERE id = ?")
+    // Moved to the trash; the trash service restores or purges it
+    sqlx::query("UPDATE journal_entries SET deleted_at = ? WHERE id = ?")
+        .bind(Utc::now())
         .bind(entry_id.to_string())
         .execute(pool)
         .await?;
@@ -220,14 +246,49 @@ pub async fn delete_journal_entry(
     Ok(())
 }
 
+/// The user's average mood per day between `start` and `end` (inclusive), from
+/// their own entries that have a mood. Days without a mood are left out.
+pub async fn mood_over_time(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Result<Vec<MoodPoint>, JournalError> {
+    let rows: Vec<(NaiveDate, f64, i32)> = sqlx::query_as(
+        r#"
+        SELECT entry_date, AVG(mood), COUNT(*)
+        FROM journal_entries
+        WHERE household_id = ? AND user_id = ? AND deleted_at IS NULL AND mood IS NOT NULL
+          AND entry_date >= ? AND entry_date <= ?
+        GROUP BY entry_date
+        ORDER BY entry_date ASC
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .bind(start)
+    .bind(end)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(date, average_mood, entries)| MoodPoint { date, average_mood: average_mood as f32, entries })
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::*;
+    use shared::Role;
 
     #[test]
     fn test_journal_error_display() {
         assert_eq!(JournalError::NotFound.to_string(), "Journal entry not found");
         assert_eq!(JournalError::PermissionDenied.to_string(), "Permission denied");
+        assert_eq!(JournalError::InvalidMood.to_string(), "Mood must be between 1 and 5");
     }
 
     #[test]
@@ -239,7 +300,8 @@ mod tests {
             title: "Test".to_string(),
             content: "Content".to_string(),
             entry_date: NaiveDate::from_ymd_opt(2026, 2, 18).unwrap(),
-            is_shared: true,
+            visibility: JournalVisibility::Household,
+            mood: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -258,7 +320,8 @@ mod tests {
             title: "Test".to_string(),
             content: "Content".to_string(),
             entry_date: NaiveDate::from_ymd_opt(2026, 2, 18).unwrap(),
-            is_shared: false,
+            visibility: JournalVisibility::Private,
+            mood: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -267,4 +330,77 @@ mod tests {
         assert!(!can_view_entry(&entry, &other_user));
         assert!(can_view_entry(&entry, &entry.user_id));
     }
+
+    fn entry_request(visibility: JournalVisibility, mood: Option<i32>, day: u32) -> CreateJournalEntryRequest {
+        CreateJournalEntryRequest {
+            title: None,
+            content: "Entry".to_string(),
+            entry_date: NaiveDate::from_ymd_opt(2026, 3, day),
+            visibility,
+            mood,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_respects_visibility() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let alice = create_test_user(&pool, "alice@test.com", Role::Member).await;
+        let bob = create_test_user(&pool, "bob@test.com", Role::Member).await;
+
+        let private = entry_request(JournalVisibility::Private, None, 1);
+        let shared = entry_request(JournalVisibility::Household, None, 2);
+        let private_entry = create_journal_entry(&pool, &household_id, &alice, &private).await.unwrap();
+        let shared_entry = create_journal_entry(&pool, &household_id, &alice, &shared).await.unwrap();
+
+        let ids = |entries: Vec<JournalEntryWithUser>| entries.into_iter().map(|e| e.entry.id).collect::<Vec<_>>();
+        assert_eq!(
+            ids(list_journal_entries(&pool, &household_id, &alice).await.unwrap()),
+            vec![shared_entry.id, private_entry.id]
+        );
+        assert_eq!(ids(list_journal_entries(&pool, &household_id, &bob).await.unwrap()), vec![shared_entry.id]);
+
+        // Making the entry private hides it from everyone else again
+        let request = UpdateJournalEntryRequest {
+            title: None,
+            content: None,
+            entry_date: None,
+            visibility: Some(JournalVisibility::Private),
+            mood: None,
+        };
+        update_journal_entry(&pool, &shared_entry.id, &alice, &request).await.unwrap();
+        assert!(list_journal_entries(&pool, &household_id, &bob).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_mood_over_time() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let alice = create_test_user(&pool, "alice@test.com", Role::Member).await;
+        let bob = create_test_user(&pool, "bob@test.com", Role::Member).await;
+
+        for (mood, day) in [(Some(2), 1), (Some(5), 1), (None, 2), (Some(4), 3)] {
+            create_journal_entry(&pool, &household_id, &alice, &entry_request(JournalVisibility::Private, mood, day))
+                .await
+                .unwrap();
+        }
+        create_journal_entry(&pool, &household_id, &bob, &entry_request(JournalVisibility::Household, Some(1), 1))
+            .await
+            .unwrap();
+        assert!(matches!(
+            create_journal_entry(&pool, &household_id, &alice, &entry_request(JournalVisibility::Private, Some(6), 1)).await,
+            Err(JournalError::InvalidMood)
+        ));
+
+        let start = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2026, 3, 31).unwrap();
+        let points = mood_over_time(&pool, &household_id, &alice, start, end).await.unwrap();
+        assert_eq!(
+            points,
+            vec![
+                MoodPoint { date: start, average_mood: 3.5, entries: 2 },
+                MoodPoint { date: NaiveDate::from_ymd_opt(2026, 3, 3).unwrap(), average_mood: 4.0, entries: 1 },
+            ]
+        );
+    }
 }
@@ -0,0 +1,308 @@
+//! Household leaderboards for this week, this month and all time. They are stored
+//! in `leaderboard_entries` so requests don't rank the point ledger every time;
+//! the leaderboard_refresh job and point-changing handlers recompute them.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::Tz;
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::UserRow;
+use crate::services::{households as household_service, scheduler, statistics};
+use shared::{LeaderboardEntry, LeaderboardWindow};
+
+#[derive(Debug, Error)]
+pub enum LeaderboardError {
+    #[error("Household error: {0}")]
+    Household(#[from] household_service::HouseholdError),
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+}
+
+/// Report from refreshing the leaderboards of all households
+#[derive(Debug, Clone)]
+pub struct LeaderboardRefreshReport {
+    pub households_refreshed: u32,
+}
+
+/// First household-local day counted by the window, None for all time
+pub fn window_start(window: LeaderboardWindow, today: NaiveDate, week_start_day: i32) -> Option<NaiveDate> {
+    match window {
+        LeaderboardWindow::Week => Some(statistics::get_week_start(today, week_start_day)),
+        LeaderboardWindow::Month => Some(statistics::get_month_start(today)),
+        LeaderboardWindow::AllTime => None,
+    }
+}
+
+/// Timezone and week start day of the household, the defaults if it has no settings yet
+async fn household_calendar(pool: &SqlitePool, household_id: &Uuid) -> Result<(Tz, i32), sqlx::Error> {
+    let settings: Option<(String, i32)> =
+        sqlx::query_as("SELECT timezone, week_start_day FROM household_settings WHERE household_id = ?")
+            .bind(household_id.to_string())
+            .fetch_optional(pool)
+            .await?;
+    Ok(settings
+        .map(|(timezone, week_start_day)| (scheduler::parse_timezone(&timezone), week_start_day))
+        .unwrap_or((chrono_tz::UTC, 0)))
+}
+
+fn current_window_start(window: LeaderboardWindow, (timezone, week_start_day): (Tz, i32)) -> Option<NaiveDate> {
+    window_start(window, scheduler::today_in_timezone(timezone), week_start_day)
+}
+
+/// Rank the members by the points they gained since `since` (their balance for
+/// all time), keeping the member order (longest member first) for ties
+async fn compute(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    since: Option<DateTime<Utc>>,
+) -> Result<Vec<LeaderboardEntry>, LeaderboardError> {
+    let members = household_service::list_members(pool, household_id).await?;
+    let since = since.map(|at| at.format("%Y-%m-%d %H:%M:%S").to_string());
+
+    let points: HashMap<String, i64> = match &since {
+        Some(since) => sqlx::query_as::<_, (String, i64)>(
+            r#"
+            SELECT user_id, COALESCE(SUM(amount), 0)
+            FROM point_transactions
+            WHERE household_id = ? AND julianday(created_at) >= julianday(?)
+            GROUP BY user_id
+            "#,
+        )
+        .bind(household_id.to_string())
+        .bind(since)
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .collect(),
+        None => members
+            .iter()
+            .map(|m| (m.user.id.to_string(), m.membership.points))
+            .collect(),
+    };
+
+    let completions: HashMap<String, i64> = sqlx::query_as::<_, (String, i64)>(
+        r#"
+        SELECT tc.user_id, COUNT(*)
+        FROM task_completions tc
+        JOIN tasks t ON tc.task_id = t.id
+        WHERE t.household_id = ? AND (? IS NULL OR julianday(tc.completed_at) >= julianday(?))
+        GROUP BY tc.user_id
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(&since)
+    .bind(&since)
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .collect();
+
+    let mut entries: Vec<LeaderboardEntry> = members
+        .into_iter()
+        .map(|member| {
+            let user_id = member.user.id.to_string();
+            LeaderboardEntry {
+                points: points.get(&user_id).copied().unwrap_or_default(),
+                tasks_completed: completions.get(&user_id).copied().unwrap_or_default(),
+                user: member.user,
+                rank: 0,
+            }
+        })
+        .collect();
+    entries.sort_by_key(|entry| std::cmp::Reverse(entry.points));
+    for (index, entry) in entries.iter_mut().enumerate() {
+        entry.rank = (index + 1) as i32;
+    }
+    Ok(entries)
+}
+
+/// Recompute one window of a household and replace its stored entries
+async fn refresh_window(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    window: LeaderboardWindow,
+    calendar: (Tz, i32),
+) -> Result<Vec<LeaderboardEntry>, LeaderboardError> {
+    let start = current_window_start(window, calendar);
+    let timezone = calendar.0;
+    let entries = compute(pool, household_id, start.map(|date| scheduler::local_midnight_utc(date, timezone))).await?;
+
+    let mut tx = pool.begin().await?;
+    sqlx::query("DELETE FROM leaderboard_entries WHERE household_id = ? AND time_window = ?")
+        .bind(household_id.to_string())
+        .bind(window.as_str())
+        .execute(&mut *tx)
+        .await?;
+    let now = Utc::now();
+    for entry in &entries {
+        sqlx::query(
+            r#"
+            INSERT INTO leaderboard_entries (household_id, time_window, user_id, rank, points, tasks_completed, window_start, computed_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(household_id.to_string())
+        .bind(window.as_str())
+        .bind(entry.user.id.to_string())
+        .bind(entry.rank)
+        .bind(entry.points)
+        .bind(entry.tasks_completed)
+        .bind(start)
+        .bind(now)
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await?;
+
+    Ok(entries)
+}
+
+/// Recompute and store every window of a household. Returns the all-time
+/// leaderboard, which is what the WebSocket update carries.
+pub async fn refresh(pool: &SqlitePool, household_id: &Uuid) -> Result<Vec<LeaderboardEntry>, LeaderboardError> {
+    let calendar = household_calendar(pool, household_id).await?;
+    let mut all_time = Vec::new();
+    for window in LeaderboardWindow::ALL {
+        let entries = refresh_window(pool, household_id, window, calendar).await?;
+        if window == LeaderboardWindow::AllTime {
+            all_time = entries;
+        }
+    }
+    Ok(all_time)
+}
+
+/// The stored leaderboard of a window. It is recomputed first when nothing is
+/// stored yet or a new week or month has begun since it was computed.
+pub async fn get_leaderboard(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    window: LeaderboardWindow,
+) -> Result<Vec<LeaderboardEntry>, LeaderboardError> {
+    let calendar = household_calendar(pool, household_id).await?;
+
+    let rows: Vec<(String, i32, i64, i64, Option<NaiveDate>)> = sqlx::query_as(
+        r#"
+        SELECT user_id, rank, points, tasks_completed, window_start
+        FROM leaderboard_entries
+        WHERE household_id = ? AND time_window = ?
+        ORDER BY rank
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(window.as_str())
+    .fetch_all(pool)
+    .await?;
+
+    let current = current_window_start(window, calendar);
+    if rows.is_empty() || rows.iter().any(|(_, _, _, _, start)| *start != current) {
+        return refresh_window(pool, household_id, window, calendar).await;
+    }
+
+    // Members who left since the last refresh drop out here
+    let users: Vec<UserRow> = sqlx::query_as(
+        r#"
+        SELECT u.* FROM users u
+        JOIN household_memberships m ON m.user_id = u.id
+        WHERE m.household_id = ?
+        "#,
+    )
+    .bind(household_id.to_string())
+    .fetch_all(pool)
+    .await?;
+    let mut users: HashMap<String, UserRow> = users.into_iter().map(|u| (u.id.clone(), u)).collect();
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|(user_id, rank, points, tasks_completed, _)| {
+            let user = users.remove(&user_id)?;
+            Some(LeaderboardEntry {
+                user: user.to_shared(),
+                points,
+                rank,
+                tasks_completed,
+            })
+        })
+        .collect())
+}
+
+/// Recompute the leaderboards of all households
+pub async fn refresh_all(pool: &SqlitePool) -> Result<LeaderboardRefreshReport, LeaderboardError> {
+    let household_ids: Vec<String> = sqlx::query_scalar("SELECT id FROM households").fetch_all(pool).await?;
+
+    let mut households_refreshed = 0;
+    for household_id in household_ids {
+        let Ok(household_id) = Uuid::parse_str(&household_id) else {
+            continue;
+        };
+        refresh(pool, &household_id).await?;
+        households_refreshed += 1;
+    }
+    Ok(LeaderboardRefreshReport { households_refreshed })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use shared::Role;
+
+    async fn add_points(pool: &SqlitePool, household_id: &Uuid, user_id: &Uuid, amount: i64, created_at: DateTime<Utc>) {
+        sqlx::query("INSERT INTO point_transactions (id, household_id, user_id, amount, balance_after, transaction_type, created_at) VALUES (?, ?, ?, ?, 0, 'manual_adjustment', ?)")
+            .bind(Uuid::new_v4().to_string())
+            .bind(household_id.to_string())
+            .bind(user_id.to_string())
+            .bind(amount)
+            .bind(created_at)
+            .execute(pool)
+            .await
+            .unwrap();
+        sqlx::query("UPDATE household_memberships SET points = points + ? WHERE household_id = ? AND user_id = ?")
+            .bind(amount)
+            .bind(household_id.to_string())
+            .bind(user_id.to_string())
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn test_window_start() {
+        // Wednesday, weeks starting on Monday
+        let today = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap();
+        assert_eq!(window_start(LeaderboardWindow::Week, today, 0), NaiveDate::from_ymd_opt(2024, 5, 13));
+        assert_eq!(window_start(LeaderboardWindow::Month, today, 0), NaiveDate::from_ymd_opt(2024, 5, 1));
+        assert_eq!(window_start(LeaderboardWindow::AllTime, today, 0), None);
+    }
+
+    #[tokio::test]
+    async fn test_week_leaderboard_ignores_older_points() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let veteran = test_utils::create_test_user(&pool, "veteran@test.com", Role::Owner).await;
+        let newcomer = test_utils::create_test_user(&pool, "newcomer@test.com", Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &veteran, Role::Owner).await;
+        test_utils::create_test_membership(&pool, &household_id, &newcomer, Role::Member).await;
+
+        add_points(&pool, &household_id, &veteran, 500, Utc::now() - chrono::Duration::days(60)).await;
+        add_points(&pool, &household_id, &veteran, 5, Utc::now()).await;
+        add_points(&pool, &household_id, &newcomer, 20, Utc::now()).await;
+
+        let all_time = get_leaderboard(&pool, &household_id, LeaderboardWindow::AllTime).await.unwrap();
+        assert_eq!((all_time[0].user.id, all_time[0].points), (veteran, 505));
+
+        let week = get_leaderboard(&pool, &household_id, LeaderboardWindow::Week).await.unwrap();
+        assert_eq!((week[0].user.id, week[0].points, week[0].rank), (newcomer, 20, 1));
+        assert_eq!((week[1].user.id, week[1].points, week[1].rank), (veteran, 5, 2));
+
+        // Stored entries are served until the next refresh
+        add_points(&pool, &household_id, &veteran, 50, Utc::now()).await;
+        let week = get_leaderboard(&pool, &household_id, LeaderboardWindow::Week).await.unwrap();
+        assert_eq!(week[0].user.id, newcomer);
+        refresh_all(&pool).await.unwrap();
+        let week = get_leaderboard(&pool, &household_id, LeaderboardWindow::Week).await.unwrap();
+        assert_eq!((week[0].user.id, week[0].points), (veteran, 55));
+    }
+}
@@ -0,0 +1,403 @@
+//! Outgoing email over SMTP
+//!
+//! A small SMTP client for transactional mail such as invitations and password
+//! resets. It supports implicit TLS (port 465), STARTTLS (port 587) and plain
+//! connections to a local relay, with optional `AUTH PLAIN`. Messages are sent as
+//! UTF-8 plain text.
+
+use std::sync::Arc;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::Utc;
+use thiserror::Error;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio_rustls::rustls::pki_types::ServerName;
+use tokio_rustls::rustls::{ClientConfig, RootCertStore};
+use tokio_rustls::TlsConnector;
+use uuid::Uuid;
+
+use crate::config::Config;
+
+#[derive(Debug, Error)]
+pub enum MailError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("TLS error: {0}")]
+    Tls(String),
+    #[error("SMTP server replied {code}: {message}")]
+    Smtp { code: u16, message: String },
+    #[error("Invalid email header value")]
+    InvalidHeader,
+}
+
+/// How the connection to the SMTP server is secured
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmtpSecurity {
+    /// TLS from the first byte (usually port 465)
+    Tls,
+    /// Plain connection upgraded with STARTTLS (usually port 587)
+    StartTls,
+    /// No encryption, only for a trusted local relay
+    None,
+}
+
+impl SmtpSecurity {
+    fn parse(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "tls" | "ssl" => Some(SmtpSecurity::Tls),
+            "starttls" => Some(SmtpSecurity::StartTls),
+            "none" => Some(SmtpSecurity::None),
+            _ => None,
+        }
+    }
+}
+
+/// SMTP settings taken from the environment
+#[derive(Debug, Clone)]
+pub struct MailSettings {
+    pub host: String,
+    pub port: u16,
+    pub security: SmtpSecurity,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    /// Sender, e.g. `Haushalt <noreply@example.com>`
+    pub from: String,
+    /// Public base URL of the app, used for links in emails
+    pub public_url: String,
+}
+
+impl MailSettings {
+    /// Returns `None` unless an SMTP host is configured
+    pub fn from_config(config: &Config) -> Option<Self> {
+        let host = config.smtp_host.clone().filter(|h| !h.is_empty())?;
+        let security = SmtpSecurity::parse(&config.smtp_security).unwrap_or_else(|| {
+            log::warn!("Unknown SMTP_SECURITY '{}', using starttls", config.smtp_security);
+            SmtpSecurity::StartTls
+        });
+        Some(Self {
+            host,
+            port: config.smtp_port,
+            security,
+            username: config.smtp_username.clone().filter(|u| !u.is_empty()),
+            password: config.smtp_password.clone(),
+            from: config.smtp_from.clone(),
+            public_url: config.public_url.trim_end_matches('/').to_string(),
+        })
+    }
+}
+
+/// A plain text email
+#[derive(Debug, Clone)]
+pub struct OutgoingMail {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+/// Send the mail in the background so slow SMTP servers don't delay the response.
+/// Failures are logged.
+pub fn send_in_background(settings: MailSettings, mail: OutgoingMail) {
+    tokio::spawn(async move {
+        if let Err(e) = send_mail(&settings, &mail).await {
+            log::error!("Failed to send email to {}: {}", mail.to, e);
+        }
+    });
+}
+
+/// Invitation for someone who has no account yet
+pub fn invitation_mail(settings: &MailSettings, to: &str, household_name: &str, inviter_name: &str) -> OutgoingMail {
+    let register_url = format!("{}/register?email={}", settings.public_url, encode_query_value(to));
+    OutgoingMail {
+        to: to.to_string(),
+        subject: format!("{} invited you to \"{}\"", inviter_name, household_name),
+        body: format!(
+            "Hello,\n\n\
+             {inviter} invited you to join the household \"{household}\" on Haushalt.\n\n\
+             Create your account with this email address to accept the invitation:\n\
+             {url}\n\n\
+             If you did not expect this invitation, you can ignore this email.\n",
+            inviter = inviter_name,
+            household = household_name,
+            url = register_url,
+        ),
+    }
+}
+
+/// Password reset link
+pub fn password_reset_mail(settings: &MailSettings, to: &str, username: &str, token: &str, valid_minutes: i64) -> OutgoingMail {
+    let reset_url = format!("{}/reset-password?token={}", settings.public_url, encode_query_value(token));
+    OutgoingMail {
+        to: to.to_string(),
+        subject: "Reset your Haushalt password".to_string(),
+        body: format!(
+            "Hello {username},\n\n\
+             someone asked to reset the password of your Haushalt account.\n\
+             Choose a new password here (the link is valid for {minutes} minutes):\n\
+             {url}\n\n\
+             If you did not ask for this, you can ignore this email and your password stays unchanged.\n",
+            username = username,
+            minutes = valid_minutes,
+            url = reset_url,
+        ),
+    }
+}
+
+/// Percent-encode a value for use in a URL query string
+fn encode_query_value(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Extract the bare address from a mailbox like `Name <user@example.com>`
+fn envelope_address(mailbox: &str) -> &str {
+    match (mailbox.rfind('<'), mailbox.rfind('>')) {
+        (Some(start), Some(end)) if start < end => mailbox[start + 1..end].trim(),
+        _ => mailbox.trim(),
+    }
+}
+
+/// Encode a header value as an RFC 2047 encoded word when it isn't plain ASCII
+fn encode_header(value: &str) -> String {
+    if value.is_ascii() {
+        value.to_string()
+    } else {
+        format!("=?UTF-8?B?{}?=", STANDARD.encode(value))
+    }
+}
+
+/// Build the full message (headers and base64 encoded body) for the DATA command
+fn build_message(from: &str, mail: &OutgoingMail) -> Result<String, MailError> {
+    if [from, mail.to.as_str(), mail.subject.as_str()]
+        .iter()
+        .any(|v| v.contains('\r') || v.contains('\n'))
+    {
+        return Err(MailError::InvalidHeader);
+    }
+
+    let domain = envelope_address(from).rsplit('@').next().unwrap_or("localhost");
+    let body = STANDARD.encode(mail.body.replace("\r\n", "\n").replace('\n', "\r\n"));
+    let wrapped_body = body
+        .as_bytes()
+        .chunks(76)
+        .map(|line| std::str::from_utf8(line).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("\r\n");
+
+    Ok(format!(
+        "From: {from}\r\n\
+         To: {to}\r\n\
+         Subject: {subject}\r\n\
+         Date: {date}\r\n\
+         Message-ID: <{id}@{domain}>\r\n\
+         MIME-Version: 1.0\r\n\
+         Content-Type: text/plain; charset=utf-8\r\n\
+         Content-Transfer-Encoding: base64\r\n\
+         \r\n\
+         {body}\r\n",
+        from = from,
+        to = mail.to,
+        subject = encode_header(&mail.subject),
+        date = Utc::now().to_rfc2822(),
+        id = Uuid::new_v4(),
+        domain = domain,
+        body = wrapped_body,
+    ))
+}
+
+trait MailStream: AsyncRead + AsyncWrite + Unpin + Send {}
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> MailStream for T {}
+
+struct SmtpConnection {
+    stream: BufReader<Box<dyn MailStream>>,
+}
+
+impl SmtpConnection {
+    fn new(stream: Box<dyn MailStream>) -> Self {
+        Self {
+            stream: BufReader::new(stream),
+        }
+    }
+
+    /// Read a (possibly multi-line) reply and return its code and text
+    async fn read_reply(&mut self) -> Result<(u16, String), MailError> {
+        let mut text = String::new();
+        loop {
+            let mut line = String::new();
+            if self.stream.read_line(&mut line).await? == 0 {
+                return Err(MailError::Io(std::io::ErrorKind::UnexpectedEof.into()));
+            }
+            let line = line.trim_end();
+            let code = line.get(..3).and_then(|c| c.parse::<u16>().ok()).ok_or_else(|| MailError::Smtp {
+                code: 0,
+                message: line.to_string(),
+            })?;
+            text.push_str(line.get(4..).unwrap_or_default());
+            // "250-" continues a multi-line reply, "250 " ends it
+            if line.as_bytes().get(3) != Some(&b'-') {
+                return Ok((code, text));
+            }
+            text.push('\n');
+        }
+    }
+
+    /// Expect a reply with the given code
+    async fn expect(&mut self, expected: u16) -> Result<String, MailError> {
+        let (code, message) = self.read_reply().await?;
+        if code != expected {
+            return Err(MailError::Smtp { code, message });
+        }
+        Ok(message)
+    }
+
+    /// Send a command line and expect a reply with the given code
+    async fn command(&mut self, line: &str, expected: u16) -> Result<String, MailError> {
+        let stream = self.stream.get_mut();
+        stream.write_all(line.as_bytes()).await?;
+        stream.write_all(b"\r\n").await?;
+        stream.flush().await?;
+        self.expect(expected).await
+    }
+
+    fn into_inner(self) -> Box<dyn MailStream> {
+        self.stream.into_inner()
+    }
+}
+
+async fn wrap_tls(stream: Box<dyn MailStream>, host: &str) -> Result<Box<dyn MailStream>, MailError> {
+    let mut roots = RootCertStore::empty();
+    roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+    let config = ClientConfig::builder_with_provider(Arc::new(tokio_rustls::rustls::crypto::ring::default_provider()))
+        .with_safe_default_protocol_versions()
+        .map_err(|e| MailError::Tls(e.to_string()))?
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    let server_name = ServerName::try_from(host.to_string()).map_err(|e| MailError::Tls(e.to_string()))?;
+    let tls = TlsConnector::from(Arc::new(config)).connect(server_name, stream).await?;
+    Ok(Box::new(tls))
+}
+
+/// Deliver a mail through the configured SMTP server
+pub async fn send_mail(settings: &MailSettings, mail: &OutgoingMail) -> Result<(), MailError> {
+    let message = build_message(&settings.from, mail)?;
+
+    let tcp: Box<dyn MailStream> = Box::new(TcpStream::connect((settings.host.as_str(), settings.port)).await?);
+    let stream = match settings.security {
+        SmtpSecurity::Tls => wrap_tls(tcp, &settings.host).await?,
+        _ => tcp,
+    };
+
+    let mut conn = SmtpConnection::new(stream);
+    conn.expect(220).await?;
+    conn.command("EHLO haushalt", 250).await?;
+
+    if settings.security == SmtpSecurity::StartTls {
+        conn.command("STARTTLS", 220).await?;
+        conn = SmtpConnection::new(wrap_tls(conn.into_inner(), &settings.host).await?);
+        conn.command("EHLO haushalt", 250).await?;
+    }
+
+    if let Some(username) = &settings.username {
+        let credentials = format!("\0{}\0{}", username, settings.password.as_deref().unwrap_or_default());
+        conn.command(&format!("AUTH PLAIN {}", STANDARD.encode(credentials)), 235).await?;
+    }
+
+    conn.command(&format!("MAIL FROM:<{}>", envelope_address(&settings.from)), 250).await?;
+    conn.command(&format!("RCPT TO:<{}>", envelope_address(&mail.to)), 250).await?;
+    conn.command("DATA", 354).await?;
+    // The body is base64, so no line can start with a dot and needs stuffing
+    conn.command(&format!("{}.", message), 250).await?;
+    let _ = conn.command("QUIT", 221).await;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> MailSettings {
+        MailSettings {
+            host: "smtp.example.com".to_string(),
+            port: 587,
+            security: SmtpSecurity::StartTls,
+            username: None,
+            password: None,
+            from: "Haushalt <noreply@example.com>".to_string(),
+            public_url: "https://haushalt.example.com".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_envelope_address() {
+        assert_eq!(envelope_address("Haushalt <noreply@example.com>"), "noreply@example.com");
+        assert_eq!(envelope_address("user@example.com"), "user@example.com");
+    }
+
+    #[test]
+    fn test_smtp_security_parse() {
+        assert_eq!(SmtpSecurity::parse("STARTTLS"), Some(SmtpSecurity::StartTls));
+        assert_eq!(SmtpSecurity::parse("ssl"), Some(SmtpSecurity::Tls));
+        assert_eq!(SmtpSecurity::parse("none"), Some(SmtpSecurity::None));
+        assert_eq!(SmtpSecurity::parse("maybe"), None);
+    }
+
+    #[test]
+    fn test_build_message() {
+        let mail = OutgoingMail {
+            to: "anna@example.com".to_string(),
+            subject: "Grüße".to_string(),
+            body: "Hallo\nWelt".to_string(),
+        };
+        let message = build_message("Haushalt <noreply@example.com>", &mail).unwrap();
+
+        assert!(message.contains("To: anna@example.com\r\n"));
+        assert!(message.contains(&format!("Subject: =?UTF-8?B?{}?=\r\n", STANDARD.encode("Grüße"))));
+        assert!(message.contains("@example.com>\r\n"));
+        assert!(message.ends_with(&format!("\r\n\r\n{}\r\n", STANDARD.encode("Hallo\r\nWelt"))));
+    }
+
+    #[test]
+    fn test_build_message_rejects_header_injection() {
+        let mail = OutgoingMail {
+            to: "anna@example.com\r\nBcc: eve@example.com".to_string(),
+            subject: "Hi".to_string(),
+            body: String::new(),
+        };
+        assert!(matches!(
+            build_message("noreply@example.com", &mail),
+            Err(MailError::InvalidHeader)
+        ));
+    }
+
+    #[test]
+    fn test_mail_links() {
+        let invitation = invitation_mail(&settings(), "new+user@example.com", "Family", "Anna");
+        assert!(invitation.body.contains("https://haushalt.example.com/register?email=new%2Buser%40example.com"));
+        assert!(invitation.subject.contains("Family"));
+
+        let reset = password_reset_mail(&settings(), "anna@example.com", "anna", "abc-123", 60);
+        assert!(reset.body.contains("https://haushalt.example.com/reset-password?token=abc-123"));
+        assert!(reset.body.contains("60 minutes"));
+    }
+
+    #[tokio::test]
+    async fn test_read_multiline_reply() {
+        let (client, mut server) = tokio::io::duplex(1024);
+        server
+            .write_all(b"250-smtp.example.com\r\n250-STARTTLS\r\n250 AUTH PLAIN\r\n")
+            .await
+            .unwrap();
+
+        let mut conn = SmtpConnection::new(Box::new(client));
+        let (code, text) = conn.read_reply().await.unwrap();
+        assert_eq!(code, 250);
+        assert_eq!(text, "smtp.example.com\nSTARTTLS\nAUTH PLAIN");
+    }
+}
@@ -0,0 +1,466 @@
+use chrono::{Datelike, Days, Duration, NaiveDate, Utc};
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::{MealPlanEntryRow, RecipeRow};
+use shared::{
+    CreateRecipeRequest, MealPlanEntry, Recipe, RecipeIngredient, SetMealPlanEntryRequest,
+    UpdateRecipeRequest, WeeklyMealPlan,
+};
+
+#[derive(Debug, Error)]
+pub enum MealError {
+    #[error("Recipe not found")]
+    RecipeNotFound,
+    #[error("Meal plan entry not found")]
+    EntryNotFound,
+    #[error("A meal needs a recipe or a title")]
+    EmptyEntry,
+    #[error("Dates must lie between the years {} and {}", shared::SUPPORTED_YEARS.start(), shared::SUPPORTED_YEARS.end())]
+    InvalidDate,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+/// Monday of the week containing `date`
+pub fn week_start_for(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+/// Drop ingredients without a name and trim the rest
+fn clean_ingredients(ingredients: &[RecipeIngredient]) -> Vec<RecipeIngredient> {
+    ingredients
+        .iter()
+        .filter(|i| !i.name.trim().is_empty())
+        .map(|i| RecipeIngredient {
+            name: i.name.trim().to_string(),
+            quantity: i.quantity.filter(|q| q.is_finite() && *q > 0.0),
+            unit: i
+                .unit
+                .as_ref()
+                .map(|u| u.trim().to_string())
+                .filter(|u| !u.is_empty()),
+        })
+        .collect()
+}
+
+fn non_empty(value: &Option<String>) -> Option<String> {
+    value
+        .as_ref()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+pub async fn create_recipe(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    request: &CreateRecipeRequest,
+) -> Result<Recipe, MealError> {
+    let id = Uuid::new_v4();
+    let now = Utc::now();
+    let ingredients = clean_ingredients(&request.ingredients);
+    let notes = non_empty(&request.notes);
+
+    sqlx::query(
+        r#"
+        INSERT INTO recipes (id, household_id, name, ingredients, notes, created_by, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(id.to_string())
+    .bind(household_id.to_string())
+    .bind(request.name.trim())
+    .bind(serde_json::to_string(&ingredients).unwrap_or_else(|_| "[]".to_string()))
+    .bind(&notes)
+    .bind(user_id.to_string())
+    .bind(now)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    Ok(Recipe {
+        id,
+        household_id: *household_id,
+        name: request.name.trim().to_string(),
+        ingredients,
+        notes,
+        created_by: *user_id,
+        created_at: now,
+        updated_at: now,
+    })
+}
+
+pub async fn get_recipe(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    recipe_id: &Uuid,
+) -> Result<Option<Recipe>, MealError> {
+    let row: Option<RecipeRow> =
+        sqlx::query_as("SELECT * FROM recipes WHERE id = ? AND household_id = ?")
+            .bind(recipe_id.to_string())
+            .bind(household_id.to_string())
+            .fetch_optional(pool)
+            .await?;
+
+    Ok(row.map(|r| r.to_shared()))
+}
+
+pub async fn list_recipes(pool: &SqlitePool, household_id: &Uuid) -> Result<Vec<Recipe>, MealError> {
+    let rows: Vec<RecipeRow> =
+        sqlx::query_as("SELECT * FROM recipes WHERE household_id = ? ORDER BY name COLLATE NOCASE ASC")
+            .bind(household_id.to_string())
+            .fetch_all(pool)
+            .await?;
+
+    Ok(rows.into_iter().map(|r| r.to_shared()).collect())
+}
+
+pub async fn update_recipe(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    recipe_id: &Uuid,
+    request: &UpdateRecipeRequest,
+) -> Result<Recipe, MealError> {
+    let mut recipe = get_recipe(pool, household_id, recipe_id)
+        .await?
+        .ok_or(MealError::RecipeNotFound)?;
+
+    if let Some(ref name) = request.name {
+        recipe.name = name.trim().to_string();
+    }
+    if let Some(ref ingredients) = request.ingredients {
+        recipe.ingredients = clean_ingredients(ingredients);
+    }
+    if request.notes.is_some() {
+        recipe.notes = non_empty(&request.notes);
+    }
+    recipe.updated_at = Utc::now();
+
+    sqlx::query("UPDATE recipes SET name = ?, ingredients = ?, notes = ?, updated_at = ? WHERE id = ?")
+        .bind(&recipe.name)
+        .bind(serde_json::to_string(&recipe.ingredients).unwrap_or_else(|_| "[]".to_string()))
+        .bind(&recipe.notes)
+        .bind(recipe.updated_at)
+        .bind(recipe_id.to_string())
+        .execute(pool)
+        .await?;
+
+    Ok(recipe)
+}
+
+pub async fn delete_recipe(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    recipe_id: &Uuid,
+) -> Result<(), MealError> {
+    // Keep planned meals but turn them into free-text entries named after the recipe
+    sqlx::query(
+        r#"
+        UPDATE meal_plan_entries
+        SET title = COALESCE(title, (SELECT name FROM recipes WHERE id = ?)), recipe_id = NULL
+        WHERE recipe_id = ? AND household_id = ?
+        "#,
+    )
+    .bind(recipe_id.to_string())
+    .bind(recipe_id.to_string())
+    .bind(household_id.to_string())
+    .execute(pool)
+    .await?;
+
+    let result = sqlx::query("DELETE FROM recipes WHERE id = ? AND household_id = ?")
+        .bind(recipe_id.to_string())
+        .bind(household_id.to_string())
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(MealError::RecipeNotFound);
+    }
+
+    Ok(())
+}
+
+/// All meals of the Monday-to-Sunday week containing `date`
+pub async fn get_week_plan(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    date: NaiveDate,
+) -> Result<WeeklyMealPlan, MealError> {
+    if !shared::is_supported_date(date) {
+        return Err(MealError::InvalidDate);
+    }
+    let week_start = week_start_for(date);
+    let week_end = week_start
+        .checked_add_days(Days::new(7))
+        .ok_or(MealError::InvalidDate)?;
+
+    let rows: Vec<MealPlanEntryRow> = sqlx::query_as(
+        r#"
+        SELECT * FROM meal_plan_entries
+        WHERE household_id = ? AND plan_date >= ? AND plan_date < ?
+        ORDER BY plan_date ASC
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(week_start)
+    .bind(week_end)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(WeeklyMealPlan {
+        week_start,
+        entries: rows.into_iter().map(|r| r.to_shared()).collect(),
+    })
+}
+
+/// Create or replace the meal planned for a day and slot
+pub async fn set_plan_entry(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    request: &SetMealPlanEntryRequest,
+) -> Result<MealPlanEntry, MealError> {
+    let title = non_empty(&request.title);
+    if request.recipe_id.is_none() && title.is_none() {
+        return Err(MealError::EmptyEntry);
+    }
+    if let Some(ref recipe_id) = request.recipe_id {
+        if get_recipe(pool, household_id, recipe_id).await?.is_none() {
+            return Err(MealError::RecipeNotFound);
+        }
+    }
+
+    let now = Utc::now();
+
+    sqlx::query(
+        r#"
+        INSERT INTO meal_plan_entries (id, household_id, plan_date, slot, recipe_id, title, notes, created_by, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        ON CONFLICT(household_id, plan_date, slot) DO UPDATE SET
+            recipe_id = excluded.recipe_id,
+            title = excluded.title,
+            notes = excluded.notes,
+            updated_at = excluded.updated_at
+        "#,
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(household_id.to_string())
+    .bind(request.plan_date)
+    .bind(request.slot.as_str())
+    .bind(request.recipe_id.map(|id| id.to_string()))
+    .bind(&title)
+    .bind(non_empty(&request.notes))
+    .bind(user_id.to_string())
+    .bind(now)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    let row: MealPlanEntryRow = sqlx::query_as(
+        "SELECT * FROM meal_plan_entries WHERE household_id = ? AND plan_date = ? AND slot = ?",
+    )
+    .bind(household_id.to_string())
+    .bind(request.plan_date)
+    .bind(request.slot.as_str())
+    .fetch_one(pool)
+    .await?;
+
+    Ok(row.to_shared())
+}
+
+pub async fn delete_plan_entry(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    entry_id: &Uuid,
+) -> Result<(), MealError> {
+    let result = sqlx::query("DELETE FROM meal_plan_entries WHERE id = ? AND household_id = ?")
+        .bind(entry_id.to_string())
+        .bind(household_id.to_string())
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(MealError::EntryNotFound);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use shared::{MealSlot, Role};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn ingredient(name: &str, quantity: Option<f64>, unit: Option<&str>) -> RecipeIngredient {
+        RecipeIngredient {
+            name: name.to_string(),
+            quantity,
+            unit: unit.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_week_start_for() {
+        // 2024-05-06 is a Monday
+        assert_eq!(week_start_for(date(2024, 5, 6)), date(2024, 5, 6));
+        assert_eq!(week_start_for(date(2024, 5, 9)), date(2024, 5, 6));
+        assert_eq!(week_start_for(date(2024, 5, 12)), date(2024, 5, 6));
+    }
+
+    #[test]
+    fn test_clean_ingredients() {
+        let cleaned = clean_ingredients(&[
+            ingredient("  Flour ", Some(200.0), Some(" g ")),
+            ingredient("   ", Some(1.0), None),
+            ingredient("Salt", Some(-1.0), Some("")),
+        ]);
+
+        assert_eq!(cleaned.len(), 2);
+        assert_eq!(cleaned[0], ingredient("Flour", Some(200.0), Some("g")));
+        assert_eq!(cleaned[1], ingredient("Salt", None, None));
+    }
+
+    #[tokio::test]
+    async fn test_recipe_crud() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user = test_utils::create_test_user(&pool, "cook@test.com", Role::Member).await;
+
+        let recipe = create_recipe(
+            &pool,
+            &household_id,
+            &user,
+            &CreateRecipeRequest {
+                name: "Pancakes".to_string(),
+                ingredients: vec![ingredient("Flour", Some(200.0), Some("g"))],
+                notes: Some("Rest the batter".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+
+        let updated = update_recipe(
+            &pool,
+            &household_id,
+            &recipe.id,
+            &UpdateRecipeRequest {
+                ingredients: Some(vec![
+                    ingredient("Flour", Some(250.0), Some("g")),
+                    ingredient("Eggs", Some(2.0), None),
+                ]),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(updated.ingredients.len(), 2);
+        assert_eq!(updated.notes.as_deref(), Some("Rest the batter"));
+
+        let listed = list_recipes(&pool, &household_id).await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].ingredients[0].quantity, Some(250.0));
+
+        delete_recipe(&pool, &household_id, &recipe.id).await.unwrap();
+        assert!(matches!(
+            delete_recipe(&pool, &household_id, &recipe.id).await,
+            Err(MealError::RecipeNotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_plan_entries_replace_per_slot() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user = test_utils::create_test_user(&pool, "cook@test.com", Role::Member).await;
+
+        let recipe = create_recipe(
+            &pool,
+            &household_id,
+            &user,
+            &CreateRecipeRequest {
+                name: "Soup".to_string(),
+                ingredients: vec![],
+                notes: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        let mut request = SetMealPlanEntryRequest {
+            plan_date: date(2024, 5, 8),
+            slot: MealSlot::Dinner,
+            recipe_id: Some(recipe.id),
+            title: None,
+            notes: None,
+        };
+        let first = set_plan_entry(&pool, &household_id, &user, &request).await.unwrap();
+
+        request.recipe_id = None;
+        request.title = Some("Leftovers".to_string());
+        let second = set_plan_entry(&pool, &household_id, &user, &request).await.unwrap();
+        assert_eq!(first.id, second.id);
+        assert_eq!(second.title.as_deref(), Some("Leftovers"));
+
+        request.slot = MealSlot::Lunch;
+        request.recipe_id = Some(recipe.id);
+        request.title = None;
+        set_plan_entry(&pool, &household_id, &user, &request).await.unwrap();
+
+        // Next week's Monday is not part of the plan
+        request.plan_date = date(2024, 5, 13);
+        set_plan_entry(&pool, &household_id, &user, &request).await.unwrap();
+
+        let plan = get_week_plan(&pool, &household_id, date(2024, 5, 12)).await.unwrap();
+        assert_eq!(plan.week_start, date(2024, 5, 6));
+        assert_eq!(plan.entries.len(), 2);
+        assert!(matches!(
+            get_week_plan(&pool, &household_id, NaiveDate::MAX).await,
+            Err(MealError::InvalidDate)
+        ));
+
+        // Deleting the recipe keeps the planned meal under the recipe's name
+        delete_recipe(&pool, &household_id, &recipe.id).await.unwrap();
+        let plan = get_week_plan(&pool, &household_id, date(2024, 5, 8)).await.unwrap();
+        let lunch = plan.entries.iter().find(|e| e.slot == MealSlot::Lunch).unwrap();
+        assert_eq!(lunch.recipe_id, None);
+        assert_eq!(lunch.title.as_deref(), Some("Soup"));
+
+        delete_plan_entry(&pool, &household_id, &second.id).await.unwrap();
+        assert!(matches!(
+            delete_plan_entry(&pool, &household_id, &second.id).await,
+            Err(MealError::EntryNotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_plan_entry_validation() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user = test_utils::create_test_user(&pool, "cook@test.com", Role::Member).await;
+
+        let mut request = SetMealPlanEntryRequest {
+            plan_date: date(2024, 5, 8),
+            slot: MealSlot::Breakfast,
+            recipe_id: None,
+            title: Some("  ".to_string()),
+            notes: None,
+        };
+        assert!(matches!(
+            set_plan_entry(&pool, &household_id, &user, &request).await,
+            Err(MealError::EmptyEntry)
+        ));
+
+        request.recipe_id = Some(Uuid::new_v4());
+        assert!(matches!(
+            set_plan_entry(&pool, &household_id, &user, &request).await,
+            Err(MealError::RecipeNotFound)
+        ));
+    }
+}
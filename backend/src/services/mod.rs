@@ -1,10 +1,14 @@
+pub mod admin;
 pub mod auth;
 pub mod households;
 pub mod household_settings;
 pub mod solo_mode;
 pub mod user_settings;
 pub mod tasks;
+pub mod dashboard;
 pub mod task_categories;
+pub mod task_tags;
+pub mod task_filters;
 pub mod points;
 pub mod rewards;
 pub mod punishments;
@@ -19,4 +23,47 @@ pub mod notes;
 pub mod journal;
 pub mod announcements;
 pub mod period_results;
+pub mod events;
+pub mod leaderboard;
 pub mod statistics;
+pub mod calendar;
+pub mod web_push;
+pub mod notifications;
+pub mod task_comments;
+pub mod export;
+pub mod csv_export;
+pub mod backup;
+pub mod oidc;
+pub mod expenses;
+pub mod settlements;
+pub mod meals;
+pub mod shopping_list;
+pub mod inventory;
+pub mod appliances;
+pub mod plants;
+pub mod pets;
+pub mod share_links;
+pub mod allowance;
+pub mod audit_log;
+pub mod mail;
+pub mod permissions;
+pub mod attachments;
+pub mod weekly_summary;
+pub mod point_decay;
+pub mod challenges;
+pub mod automation_rules;
+pub mod webhooks;
+pub mod chat_bridge;
+pub mod api_tokens;
+pub mod sensors;
+pub mod task_dependencies;
+pub mod task_claims;
+pub mod focus_sessions;
+pub mod task_exclusions;
+pub mod reward_wishlist;
+pub mod point_goals;
+pub mod task_rebalance;
+pub mod random_choice;
+pub mod notification_center;
+pub mod trash;
+pub mod idempotency;
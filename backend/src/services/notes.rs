@@ -12,6 +12,8 @@ pub enum NoteError {
     NotFound,
     #[error("Permission denied")]
     PermissionDenied,
+    #[error("Note was changed in the meantime")]
+    VersionConflict(Box<Note>),
     #[error("Database error: {0}")]
     DatabaseError(#[from] sqlx::Error),
 }
@@ -28,8 +30,8 @@ pub async fn create_note(
 
     sqlx::query(
         r#"
-        INSERT INTO notes (id, household_id, user_id, title, content, is_shared, created_at, updated_at)
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        INSERT INTO notes (id, household_id, user_id, title, content, is_shared, color, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
         "#,
     )
     .bind(id.to_string())
@@ -38,6 +40,7 @@ pub async fn create_note(
     .bind(&request.title)
     .bind(content)
     .bind(request.is_shared)
+    .bind(&request.color)
     .bind(now)
     .bind(now)
     .execute(pool)
@@ -50,13 +53,18 @@ pub async fn create_note(
         title: request.title.clone(),
         content: content.to_string(),
         is_shared: request.is_shared,
+        pinned: false,
+        color: request.color.clone(),
+        sort_order: None,
+        archived: false,
+        version: 1,
         created_at: now,
         updated_at: now,
     })
 }
 
 pub async fn get_note(pool: &SqlitePool, note_id: &Uuid) -> Result<Option<Note>, NoteError> {
-    let note: Option<NoteRow> = sqlx::query_as("SELECT * FROM notes WHERE id = ?")
+    let note: Option<NoteRow> = sqlx::query_as("SELECT * FROM notes WHERE id = ? AND deleted_at IS NULL")
         .bind(note_id.to_string())
         .fetch_optional(pool)
         .await?;
@@ -67,10 +75,14 @@ pub async fn get_note(pool: &SqlitePool, note_id: &Uuid) -> Result<Option<Note>,
 /// List all notes visible to a user in a household:
 /// - All shared notes (is_shared = true)
 /// - User's private notes (is_shared = false AND user_id = current_user)
+///
+/// Either the active or the archived notes are listed. Pinned notes come first,
+/// then notes by their manual sort order, then the rest by last update.
 pub async fn list_notes(
     pool: &SqlitePool,
     household_id: &Uuid,
     user_id: &Uuid,
+    archived: bool,
 ) -> Result<Vec<NoteWithUser>, NoteError> {
     #[derive(sqlx::FromRow)]
     struct NoteWithUserRow {
@@ -81,6 +93,11 @@ pub async fn list_notes(
         n_title: String,
         n_content: String,
         n_is_shared: bool,
+        n_pinned: bool,
+        n_color: Option<String>,
+        n_sort_order: Option<i64>,
+        n_archived: bool,
+        n_version: i64,
         n_created_at: chrono::DateTime<chrono::Utc>,
         n_updated_at: chrono::DateTime<chrono::Utc>,
         // User fields
@@ -96,18 +113,22 @@ pub async fn list_notes(
         SELECT
             n.id as n_id, n.household_id as n_household_id, n.user_id as n_user_id,
             n.title as n_title, n.content as n_content, n.is_shared as n_is_shared,
+            n.pinned as n_pinned, n.color as n_color, n.sort_order as n_sort_order, n.archived as n_archived,
+            n.version as n_version,
             n.created_at as n_created_at, n.updated_at as n_updated_at,
             u.id as u_id, u.username as u_username, u.email as u_email,
             u.created_at as u_created_at, u.updated_at as u_updated_at
         FROM notes n
         JOIN users u ON n.user_id = u.id
-        WHERE n.household_id = ?
+        WHERE n.household_id = ? AND n.deleted_at IS NULL
           AND (n.is_shared = true OR n.user_id = ?)
-        ORDER BY n.updated_at DESC
+          AND n.archived = ?
+        ORDER BY n.pinned DESC, n.sort_order IS NULL, n.sort_order, n.updated_at DESC
         "#,
     )
     .bind(household_id.to_string())
     .bind(user_id.to_string())
+    .bind(archived)
     .fetch_all(pool)
     .await?;
 
@@ -121,6 +142,11 @@ pub async fn list_notes(
                 title: row.n_title,
                 content: row.n_content,
                 is_shared: row.n_is_shared,
+                pinned: row.n_pinned,
+                color: row.n_color,
+                sort_order: row.n_sort_order,
+                archived: row.n_archived,
+                version: row.n_version,
                 created_at: row.n_created_at,
                 updated_at: row.n_updated_at,
             },
@@ -131,6 +157,7 @@ pub async fn list_notes(
                 created_at: row.u_created_at,
                 updated_at: row.u_updated_at,
             },
+            attachments: Vec::new(),
         })
         .collect())
 }
@@ -146,7 +173,7 @@ pub async fn update_note(
     user_id: &Uuid,
     request: &UpdateNoteRequest,
 ) -> Result<Note, NoteError> {
-    let mut note: NoteRow = sqlx::query_as("SELECT * FROM notes WHERE id = ?")
+    let mut note: NoteRow = sqlx::query_as("SELECT * FROM notes WHERE id = ? AND deleted_at IS NULL")
         .bind(note_id.to_string())
         .fetch_optional(pool)
         .await?
@@ -157,6 +184,11 @@ pub async fn update_note(
         return Err(NoteError::PermissionDenied);
     }
 
+    if request.version.is_some_and(|version| version != note.version) {
+        return Err(NoteError::VersionConflict(Box::new(note.to_shared())));
+    }
+    let expected_version = note.version;
+
     if let Some(ref title) = request.title {
         note.title = title.clone();
     }
@@ -166,24 +198,49 @@ pub async fn update_note(
     if let Some(is_shared) = request.is_shared {
         note.is_shared = is_shared;
     }
+    if let Some(pinned) = request.pinned {
+        note.pinned = pinned;
+    }
+    if let Some(ref color) = request.color {
+        note.color = Some(color.clone()).filter(|c| !c.is_empty());
+    }
+    if let Some(sort_order) = request.sort_order {
+        note.sort_order = Some(sort_order);
+    }
+    if let Some(archived) = request.archived {
+        note.archived = archived;
+    }
 
     let now = Utc::now();
     note.updated_at = now;
+    note.version += 1;
 
-    sqlx::query(
+    let result = sqlx::query(
         r#"
-        UPDATE notes SET title = ?, content = ?, is_shared = ?, updated_at = ?
-        WHERE id = ?
+        UPDATE notes SET title = ?, content = ?, is_shared = ?, pinned = ?, color = ?, sort_order = ?, archived = ?,
+            updated_at = ?, version = version + 1
+        WHERE id = ? AND version = ?
         "#,
     )
     .bind(&note.title)
     .bind(&note.content)
     .bind(note.is_shared)
+    .bind(note.pinned)
+    .bind(&note.color)
+    .bind(note.sort_order)
+    .bind(note.archived)
     .bind(now)
     .bind(note_id.to_string())
+    .bind(expected_version)
     .execute(pool)
     .await?;
 
+    // Someone else saved between our read and write
+    if result.rows_affected() == 0 {
+        let current = get_note(pool, note_id).await?.ok_or(NoteError::NotFound)?;
+        return Err(NoteError::VersionConflict(Box::new(current)));
+    }
+
     Ok(note.to_shared())
 }
 
@@ -192,7 +249,7 @@ pub async fn delete_note(
     note_id: &Uuid,
     user_id: &Uuid,
 ) -> Result<(), NoteError> {
-    let note: NoteRow = sqlx::query_as("SELECT * FROM notes WHERE id = ?")
+    let note: NoteRow = sqlx::query_as("SELECT * FROM notes WHERE id = ? AND deleted_at IS NULL")
         .bind(note_id.to_string())
         .fetch_optional(pool)
         .await?
@@ -203,7 +260,9 @@ pub async fn delete_note(
         return Err(NoteError::PermissionDenied);
     }
 
-    sqlx::query("DELETE FROM notes WHERE id = ?")
+    // Moved to the trash; the trash service restores or purges it
+    sqlx::query("UPDATE notes SET deleted_at = ? WHERE id = ?")
+        .bind(Utc::now())
         .bind(note_id.to_string())
         .execute(pool)
         .await?;
@@ -230,6 +289,11 @@ mod tests {
             title: "Test".to_string(),
             content: "Content".to_string(),
             is_shared: true,
+            pinned: false,
+            color: None,
+            sort_order: None,
+            archived: false,
+            version: 1,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -248,6 +312,11 @@ mod tests {
             title: "Test".to_string(),
             content: "Content".to_string(),
             is_shared: false,
+            pinned: false,
+            color: None,
+            sort_order: None,
+            archived: false,
+            version: 1,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -257,4 +326,83 @@ mod tests {
         assert!(can_view_note(&note, &note.user_id));
     }
 
+    #[tokio::test]
+    async fn test_update_note_rejects_outdated_version() {
+        use crate::test_utils::{create_test_household, create_test_pool, create_test_user};
+        use shared::Role;
+
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let user_id = create_test_user(&pool, "alice@test.com", Role::Owner).await;
+        let request = CreateNoteRequest {
+            title: "Shopping".to_string(),
+            content: None,
+            is_shared: true,
+            color: None,
+        };
+        let note = create_note(&pool, &household_id, &user_id, &request).await.unwrap();
+
+        let edit = |content: &str, version: Option<i64>| UpdateNoteRequest {
+            title: None,
+            content: Some(content.to_string()),
+            is_shared: None,
+            pinned: None,
+            color: None,
+            sort_order: None,
+            archived: None,
+            version,
+        };
+
+        let updated = update_note(&pool, &note.id, &user_id, &edit("Milk", Some(note.version))).await.unwrap();
+        assert_eq!(updated.version, 2);
+
+        match update_note(&pool, &note.id, &user_id, &edit("Bread", Some(note.version))).await {
+            Err(NoteError::VersionConflict(current)) => {
+                assert_eq!(current.content, "Milk");
+                assert_eq!(current.version, 2);
+            }
+            other => panic!("expected version conflict, got {:?}", other.map(|n| n.content)),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_notes_pinned_ordered_and_archived() {
+        use crate::test_utils::{create_test_household, create_test_pool, create_test_user};
+        use shared::Role;
+
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let user_id = create_test_user(&pool, "alice@test.com", Role::Owner).await;
+        let mut ids = Vec::new();
+        for title in ["Recipes", "Wifi", "Plumber", "Old lease"] {
+            let request = CreateNoteRequest {
+                title: title.to_string(),
+                content: None,
+                is_shared: true,
+                color: None,
+            };
+            ids.push(create_note(&pool, &household_id, &user_id, &request).await.unwrap().id);
+        }
+
+        let change = |pinned, sort_order, archived| UpdateNoteRequest {
+            title: None,
+            content: None,
+            is_shared: None,
+            pinned,
+            color: Some("#fef3c7".to_string()),
+            sort_order,
+            archived,
+            version: None,
+        };
+        update_note(&pool, &ids[1], &user_id, &change(Some(true), None, None)).await.unwrap();
+        update_note(&pool, &ids[2], &user_id, &change(None, Some(0), None)).await.unwrap();
+        update_note(&pool, &ids[3], &user_id, &change(None, None, Some(true))).await.unwrap();
+
+        let titles = |notes: Vec<NoteWithUser>| notes.into_iter().map(|n| n.note.title).collect::<Vec<_>>();
+        let active = list_notes(&pool, &household_id, &user_id, false).await.unwrap();
+        assert_eq!(active[0].note.color.as_deref(), Some("#fef3c7"));
+        assert_eq!(titles(active), vec!["Wifi", "Plumber", "Recipes"]);
+        let archived = list_notes(&pool, &household_id, &user_id, true).await.unwrap();
+        assert_eq!(titles(archived), vec!["Old lease"]);
+    }
 }
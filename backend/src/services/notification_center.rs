@@ -0,0 +1,172 @@
+//! Personal in-app notifications ("inbox"), as opposed to the household-wide activity log
+
+use chrono::Utc;
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::NotificationRow;
+use shared::{Notification, NotificationList, NotificationType};
+
+/// How many notifications the inbox returns
+const INBOX_LIMIT: i64 = 50;
+
+#[derive(Debug, Error)]
+pub enum NotificationCenterError {
+    #[error("Notification not found")]
+    NotFound,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+/// A notification to store for one user
+#[derive(Debug, Clone)]
+pub struct NewNotification {
+    pub user_id: Uuid,
+    pub household_id: Option<Uuid>,
+    pub notification_type: NotificationType,
+    pub title: String,
+    pub entity_type: Option<&'static str>,
+    pub entity_id: Option<Uuid>,
+}
+
+pub async fn create_notification(
+    pool: &SqlitePool,
+    notification: &NewNotification,
+) -> Result<Notification, NotificationCenterError> {
+    let id = Uuid::new_v4();
+    let now = Utc::now();
+
+    sqlx::query(
+        r#"
+        INSERT INTO notifications (id, user_id, household_id, notification_type, title, entity_type, entity_id, created_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(id.to_string())
+    .bind(notification.user_id.to_string())
+    .bind(notification.household_id.map(|id| id.to_string()))
+    .bind(notification.notification_type.as_str())
+    .bind(&notification.title)
+    .bind(notification.entity_type)
+    .bind(notification.entity_id.map(|id| id.to_string()))
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    Ok(Notification {
+        id,
+        user_id: notification.user_id,
+        household_id: notification.household_id,
+        notification_type: notification.notification_type,
+        title: notification.title.clone(),
+        entity_type: notification.entity_type.map(str::to_string),
+        entity_id: notification.entity_id,
+        read_at: None,
+        created_at: now,
+    })
+}
+
+/// The user's most recent notifications, newest first, with the unread count
+pub async fn list_notifications(
+    pool: &SqlitePool,
+    user_id: &Uuid,
+) -> Result<NotificationList, NotificationCenterError> {
+    let rows: Vec<NotificationRow> = sqlx::query_as(
+        "SELECT * FROM notifications WHERE user_id = ? ORDER BY created_at DESC LIMIT ?",
+    )
+    .bind(user_id.to_string())
+    .bind(INBOX_LIMIT)
+    .fetch_all(pool)
+    .await?;
+
+    let unread_count: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM notifications WHERE user_id = ? AND read_at IS NULL")
+            .bind(user_id.to_string())
+            .fetch_one(pool)
+            .await?;
+
+    Ok(NotificationList {
+        notifications: rows.into_iter().map(|r| r.to_shared()).collect(),
+        unread_count,
+    })
+}
+
+/// Mark one of the user's notifications as read; already read ones keep their timestamp
+pub async fn mark_read(
+    pool: &SqlitePool,
+    user_id: &Uuid,
+    notification_id: &Uuid,
+) -> Result<Notification, NotificationCenterError> {
+    sqlx::query("UPDATE notifications SET read_at = ? WHERE id = ? AND user_id = ? AND read_at IS NULL")
+        .bind(Utc::now())
+        .bind(notification_id.to_string())
+        .bind(user_id.to_string())
+        .execute(pool)
+        .await?;
+
+    let row: Option<NotificationRow> = sqlx::query_as("SELECT * FROM notifications WHERE id = ? AND user_id = ?")
+        .bind(notification_id.to_string())
+        .bind(user_id.to_string())
+        .fetch_optional(pool)
+        .await?;
+
+    row.map(|r| r.to_shared()).ok_or(NotificationCenterError::NotFound)
+}
+
+/// Mark all of the user's notifications as read, returning how many changed
+pub async fn mark_all_read(pool: &SqlitePool, user_id: &Uuid) -> Result<u64, NotificationCenterError> {
+    let result = sqlx::query("UPDATE notifications SET read_at = ? WHERE user_id = ? AND read_at IS NULL")
+        .bind(Utc::now())
+        .bind(user_id.to_string())
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    fn assignment(user_id: Uuid, title: &str) -> NewNotification {
+        NewNotification {
+            user_id,
+            household_id: None,
+            notification_type: NotificationType::TaskAssigned,
+            title: title.to_string(),
+            entity_type: Some("task"),
+            entity_id: Some(Uuid::new_v4()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_notifications_are_personal_and_can_be_marked_read() {
+        let pool = create_test_pool().await;
+        let alice = create_test_user(&pool, "alice@test.com", shared::Role::Member).await;
+        let bob = create_test_user(&pool, "bob@test.com", shared::Role::Member).await;
+
+        let first = create_notification(&pool, &assignment(alice, "Dishes")).await.unwrap();
+        create_notification(&pool, &assignment(alice, "Laundry")).await.unwrap();
+        create_notification(&pool, &assignment(bob, "Trash")).await.unwrap();
+
+        let inbox = list_notifications(&pool, &alice).await.unwrap();
+        assert_eq!(inbox.notifications.len(), 2);
+        assert_eq!(inbox.unread_count, 2);
+
+        // Bob cannot mark Alice's notification
+        assert!(matches!(
+            mark_read(&pool, &bob, &first.id).await,
+            Err(NotificationCenterError::NotFound)
+        ));
+
+        let read = mark_read(&pool, &alice, &first.id).await.unwrap();
+        assert!(read.is_read());
+        assert_eq!(list_notifications(&pool, &alice).await.unwrap().unread_count, 1);
+
+        assert_eq!(mark_all_read(&pool, &alice).await.unwrap(), 1);
+        assert_eq!(list_notifications(&pool, &alice).await.unwrap().unread_count, 0);
+        assert_eq!(list_notifications(&pool, &bob).await.unwrap().unread_count, 1);
+    }
+}
@@ -0,0 +1,559 @@
+//! Web Push subscriptions and due-task reminders
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::{PushSubscriptionRow, TaskRow};
+use crate::services::web_push::{self, VapidKeys, WebPushError};
+use crate::services::user_settings::{self, UserSettingsError};
+use crate::services::{household_settings, scheduler, tasks as task_service};
+use shared::{HouseholdSettings, NotificationKind, NotificationPreferences, PushMessage, PushSubscriptionRequest, Reward, Task};
+
+#[derive(Debug, Error)]
+pub enum NotificationError {
+    #[error("Invalid subscription: {0}")]
+    InvalidSubscription(String),
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+/// VAPID credentials used to deliver Web Push messages
+#[derive(Debug, Clone)]
+pub struct PushSender {
+    pub vapid_keys: VapidKeys,
+    pub subject: String,
+}
+
+/// Configuration for sending task reminders from the background scheduler
+#[derive(Debug, Clone)]
+pub struct ReminderConfig {
+    pub sender: PushSender,
+    /// How many minutes before a task's due time the reminder is sent
+    pub lead_minutes: i64,
+}
+
+/// Report from processing due-task reminders
+#[derive(Debug, Clone, Default)]
+pub struct ReminderReport {
+    pub tasks_checked: u32,
+    pub reminders_sent: u32,
+    pub subscriptions_removed: u32,
+}
+
+/// Store a push subscription for a user
+/// Re-subscribing with a known endpoint updates its keys and owner
+pub async fn subscribe(
+    pool: &SqlitePool,
+    user_id: &Uuid,
+    request: &PushSubscriptionRequest,
+) -> Result<(), NotificationError> {
+    if !request.endpoint.starts_with("https://") {
+        return Err(NotificationError::InvalidSubscription(
+            "Push endpoint must use https".to_string(),
+        ));
+    }
+    if request.keys.p256dh.trim().is_empty() || request.keys.auth.trim().is_empty() {
+        return Err(NotificationError::InvalidSubscription(
+            "Subscription keys are required".to_string(),
+        ));
+    }
+
+    sqlx::query(
+        r#"
+        INSERT INTO push_subscriptions (id, user_id, endpoint, p256dh, auth, created_at)
+        VALUES (?, ?, ?, ?, ?, ?)
+        ON CONFLICT(endpoint) DO UPDATE SET
+            user_id = excluded.user_id,
+            p256dh = excluded.p256dh,
+            auth = excluded.auth
+        "#,
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(user_id.to_string())
+    .bind(&request.endpoint)
+    .bind(&request.keys.p256dh)
+    .bind(&request.keys.auth)
+    .bind(Utc::now())
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Remove a user's push subscription
+pub async fn unsubscribe(
+    pool: &SqlitePool,
+    user_id: &Uuid,
+    endpoint: &str,
+) -> Result<(), NotificationError> {
+    sqlx::query("DELETE FROM push_subscriptions WHERE user_id = ? AND endpoint = ?")
+        .bind(user_id.to_string())
+        .bind(endpoint)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// List all push subscriptions of a user
+pub async fn list_subscriptions(
+    pool: &SqlitePool,
+    user_id: &Uuid,
+) -> Result<Vec<PushSubscriptionRow>, NotificationError> {
+    let rows: Vec<PushSubscriptionRow> =
+        sqlx::query_as("SELECT * FROM push_subscriptions WHERE user_id = ?")
+            .bind(user_id.to_string())
+            .fetch_all(pool)
+            .await?;
+
+    Ok(rows)
+}
+
+/// Whether a user wants a notification of `kind` right now, judged by their
+/// preferences and the current time in the household's timezone
+pub async fn wants_notification(
+    pool: &SqlitePool,
+    user_id: &Uuid,
+    kind: NotificationKind,
+    timezone: &str,
+    now_utc: DateTime<Utc>,
+) -> Result<bool, NotificationError> {
+    let preferences = match user_settings::get_notification_preferences(pool, user_id).await {
+        Ok(preferences) => preferences,
+        Err(UserSettingsError::DatabaseError(e)) => return Err(e.into()),
+        Err(_) => NotificationPreferences::default(),
+    };
+
+    let local_time = now_utc.with_timezone(&scheduler::parse_timezone(timezone)).time();
+    Ok(preferences.allows(kind, local_time))
+}
+
+/// Deliver a payload to all devices of a user
+/// Returns (messages sent, expired subscriptions removed)
+async fn send_to_user(
+    pool: &SqlitePool,
+    client: &reqwest::Client,
+    sender: &PushSender,
+    user_id: &str,
+    payload: &[u8],
+) -> Result<(u32, u32), NotificationError> {
+    let subscriptions: Vec<PushSubscriptionRow> =
+        sqlx::query_as("SELECT * FROM push_subscriptions WHERE user_id = ?")
+            .bind(user_id)
+            .fetch_all(pool)
+            .await?;
+
+    let mut sent = 0;
+    let mut removed = 0;
+    for subscription in subscriptions {
+        match web_push::send(client, &sender.vapid_keys, &sender.subject, &subscription, payload).await {
+            Ok(()) => sent += 1,
+            Err(WebPushError::SubscriptionGone) => {
+                sqlx::query("DELETE FROM push_subscriptions WHERE id = ?")
+                    .bind(&subscription.id)
+                    .execute(pool)
+                    .await?;
+                removed += 1;
+            }
+            Err(e) => {
+                log::warn!("Failed to send push message to user {}: {}", user_id, e);
+            }
+        }
+    }
+
+    Ok((sent, removed))
+}
+
+/// Push a message to the given household members, honouring each member's
+/// notification preferences and quiet hours
+pub async fn notify_users(
+    pool: &SqlitePool,
+    client: &reqwest::Client,
+    sender: &PushSender,
+    household_id: &Uuid,
+    recipients: &[Uuid],
+    kind: NotificationKind,
+    message: &PushMessage,
+) -> Result<u32, NotificationError> {
+    let settings = household_settings::get_or_create_settings(pool, household_id)
+        .await
+        .unwrap_or_default();
+    let now_utc = Utc::now();
+    let payload = serde_json::to_vec(message).unwrap_or_default();
+
+    let mut sent = 0;
+    for user_id in recipients {
+        if !wants_notification(pool, user_id, kind, &settings.timezone, now_utc).await? {
+            continue;
+        }
+        sent += send_to_user(pool, client, sender, &user_id.to_string(), &payload).await?.0;
+    }
+
+    Ok(sent)
+}
+
+/// Like `notify_users`, but runs detached so request handlers don't wait on push services
+pub fn notify_users_in_background(
+    pool: SqlitePool,
+    sender: PushSender,
+    household_id: Uuid,
+    recipients: Vec<Uuid>,
+    kind: NotificationKind,
+    message: PushMessage,
+) {
+    if recipients.is_empty() {
+        return;
+    }
+    tokio::spawn(async move {
+        let client = reqwest::Client::new();
+        if let Err(e) = notify_users(&pool, &client, &sender, &household_id, &recipients, kind, &message).await {
+            log::warn!("Failed to send {:?} notifications: {}", kind, e);
+        }
+    });
+}
+
+/// Determine whether a reminder for `task` should be sent now
+/// Returns the local due date when the task's due time is within `lead` from `now_utc`
+pub fn reminder_due_date(
+    task: &Task,
+    timezone: &str,
+    week_start_day: i32,
+    now_utc: DateTime<Utc>,
+    lead: Duration,
+) -> Option<NaiveDate> {
+    if task.due_time.is_none() || task.archived || task.paused {
+        return None;
+    }
+
+    let tz = scheduler::parse_timezone(timezone);
+    let today_local = now_utc.with_timezone(&tz).date_naive();
+    if !scheduler::is_task_due_on_date(task, today_local, week_start_day) {
+        return None;
+    }
+
+    let deadline = scheduler::get_task_deadline_utc(task, today_local, timezone)?;
+    if deadline > now_utc && deadline - now_utc <= lead {
+        Some(today_local)
+    } else {
+        None
+    }
+}
+
+/// Build the notification shown for a due task
+pub fn build_reminder_message(task: &Task) -> PushMessage {
+    PushMessage {
+        title: task.title.clone(),
+        body: format!("Due at {}", task.due_time.as_deref().unwrap_or_default()),
+        url: format!("/households/{}", task.household_id),
+    }
+}
+
+/// Build the notification for a task assigned to the recipient
+pub fn build_assignment_message(task: &Task) -> PushMessage {
+    PushMessage {
+        title: task.title.clone(),
+        body: "This task was assigned to you".to_string(),
+        url: format!("/households/{}/tasks", task.household_id),
+    }
+}
+
+/// Build the notification for a completion waiting for review
+pub fn build_review_message(task: &Task, completed_by: &str) -> PushMessage {
+    PushMessage {
+        title: task.title.clone(),
+        body: format!("{} completed this task and it needs a review", completed_by),
+        url: format!("/households/{}/tasks", task.household_id),
+    }
+}
+
+/// Build the notification for a reward purchase waiting for approval
+pub fn build_purchase_review_message(reward: &Reward, requested_by: &str) -> PushMessage {
+    PushMessage {
+        title: reward.name.clone(),
+        body: format!("{} wants to buy this reward and it needs your approval", requested_by),
+        url: format!("/households/{}", reward.household_id),
+    }
+}
+
+/// Build the notification for a new chat message, shortening long messages
+pub fn build_chat_message(household_id: &Uuid, sender: &str, content: &str) -> PushMessage {
+    const MAX_CHARS: usize = 120;
+    let body = if content.chars().count() > MAX_CHARS {
+        format!("{}…", content.chars().take(MAX_CHARS).collect::<String>())
+    } else {
+        content.to_string()
+    };
+    PushMessage {
+        title: sender.to_string(),
+        body,
+        url: format!("/households/{}/chat", household_id),
+    }
+}
+
+/// Send reminders for tasks whose due time is approaching
+/// Each user is reminded at most once per task and due date
+pub async fn process_due_reminders(
+    pool: &SqlitePool,
+    client: &reqwest::Client,
+    config: &ReminderConfig,
+) -> Result<ReminderReport, NotificationError> {
+    let now_utc = Utc::now();
+    let lead = Duration::minutes(config.lead_minutes);
+    let mut report = ReminderReport::default();
+
+    let tasks: Vec<TaskRow> = sqlx::query_as(
+        "SELECT * FROM tasks WHERE archived = 0 AND paused = 0 AND deleted_at IS NULL AND due_time IS NOT NULL AND (suggestion IS NULL OR suggestion = 'approved')",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    // Cache household settings to avoid repeated lookups
+    let mut settings_cache: std::collections::HashMap<Uuid, HouseholdSettings> =
+        std::collections::HashMap::new();
+
+    for task_row in tasks {
+        let task = task_row.to_shared();
+
+        let settings = if let Some(s) = settings_cache.get(&task.household_id) {
+            s.clone()
+        } else {
+            let s = household_settings::get_or_create_settings(pool, &task.household_id)
+                .await
+                .unwrap_or_default();
+            settings_cache.insert(task.household_id, s.clone());
+            s
+        };
+
+        let Some(due_date) = reminder_due_date(&task, &settings.timezone, settings.week_start_day, now_utc, lead) else {
+            continue;
+        };
+
+        // No reminders while the household is on vacation
+        if household_settings::is_household_on_vacation(&settings, due_date) {
+            continue;
+        }
+
+        report.tasks_checked += 1;
+
+        let completions: Vec<(String, i64)> = sqlx::query_as(
+            "SELECT user_id, COUNT(*) FROM task_completions WHERE task_id = ? AND due_date = ? GROUP BY user_id",
+        )
+        .bind(task.id.to_string())
+        .bind(due_date)
+        .fetch_all(pool)
+        .await?;
+        let completion_count: i64 = completions.iter().map(|(_, count)| count).sum();
+
+        let assignees = task_service::get_assignee_ids(pool, &task.id).await?;
+        let recipients: Vec<String> = if assignees.len() > 1 {
+            // Everyone completes on their own, so only remind those who haven't yet
+            assignees
+                .iter()
+                .map(|id| id.to_string())
+                .filter(|id| {
+                    completions.iter().find(|(user_id, _)| user_id == id).map_or(0, |(_, count)| *count)
+                        < task.target_count as i64
+                })
+                .collect()
+        } else if completion_count >= task.target_count as i64 {
+            continue;
+        } else if let Some(assigned_user_id) = task.assigned_user_id {
+            vec![assigned_user_id.to_string()]
+        } else {
+            sqlx::query_scalar("SELECT user_id FROM household_memberships WHERE household_id = ?")
+                .bind(task.household_id.to_string())
+                .fetch_all(pool)
+                .await?
+        };
+
+        let payload = serde_json::to_vec(&build_reminder_message(&task)).unwrap_or_default();
+
+        for user_id in recipients {
+            // Muted reminders are not recorded, so they still arrive after quiet hours
+            let Ok(user_uuid) = Uuid::parse_str(&user_id) else {
+                continue;
+            };
+            if !wants_notification(pool, &user_uuid, NotificationKind::Reminders, &settings.timezone, now_utc).await? {
+                continue;
+            }
+
+            // Record the reminder first so a failing push service is not retried every minute
+            let inserted = sqlx::query(
+                "INSERT OR IGNORE INTO task_reminders_sent (task_id, user_id, due_date, sent_at) VALUES (?, ?, ?, ?)",
+            )
+            .bind(task.id.to_string())
+            .bind(&user_id)
+            .bind(due_date)
+            .bind(now_utc)
+            .execute(pool)
+            .await?;
+
+            if inserted.rows_affected() == 0 {
+                continue;
+            }
+
+            let (sent, removed) = send_to_user(pool, client, &config.sender, &user_id, &payload).await?;
+            report.reminders_sent += sent;
+            report.subscriptions_removed += removed;
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use shared::{HabitType, RecurrenceType, ReviewerType};
+
+    fn create_task(due_time: Option<&str>) -> Task {
+        let created = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        Task {
+            id: Uuid::new_v4(),
+            household_id: Uuid::new_v4(),
+            title: "Take out trash".to_string(),
+            description: String::new(),
+            recurrence_type: RecurrenceType::Daily,
+            recurrence_value: None,
+            assigned_user_id: None,
+            assignee_ids: Vec::new(),
+            target_count: 1,
+            time_period: None,
+            allow_exceed_target: true,
+            requires_review: false,
+            reviewer_type: ReviewerType::AnyReviewer,
+            reviewer_id: None,
+            points_reward: None,
+            points_penalty: None,
+            due_time: due_time.map(|t| t.to_string()),
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
+            habit_type: HabitType::Good,
+            category_id: None,
+            category_name: None,
+            tag_ids: Vec::new(),
+            archived: false,
+            paused: false,
+            suggestion: None,
+            suggested_by: None,
+            postponed_from: None,
+            postponed_to: None,
+            version: 1,
+            created_at: created,
+            updated_at: created,
+        }
+    }
+
+    #[test]
+    fn test_reminder_due_date_within_lead_time() {
+        let task = create_task(Some("18:00"));
+        let now = Utc.with_ymd_and_hms(2024, 6, 3, 17, 45, 0).unwrap();
+
+        assert_eq!(
+            reminder_due_date(&task, "UTC", 0, now, Duration::minutes(30)),
+            NaiveDate::from_ymd_opt(2024, 6, 3)
+        );
+    }
+
+    #[test]
+    fn test_reminder_due_date_too_early_or_past() {
+        let task = create_task(Some("18:00"));
+        let early = Utc.with_ymd_and_hms(2024, 6, 3, 17, 0, 0).unwrap();
+        let late = Utc.with_ymd_and_hms(2024, 6, 3, 18, 1, 0).unwrap();
+
+        assert_eq!(reminder_due_date(&task, "UTC", 0, early, Duration::minutes(30)), None);
+        assert_eq!(reminder_due_date(&task, "UTC", 0, late, Duration::minutes(30)), None);
+    }
+
+    #[test]
+    fn test_reminder_due_date_respects_timezone() {
+        let task = create_task(Some("18:00"));
+        // 18:00 in Berlin (CEST) is 16:00 UTC
+        let now = Utc.with_ymd_and_hms(2024, 6, 3, 15, 50, 0).unwrap();
+
+        assert!(reminder_due_date(&task, "Europe/Berlin", 0, now, Duration::minutes(30)).is_some());
+        assert!(reminder_due_date(&task, "UTC", 0, now, Duration::minutes(30)).is_none());
+    }
+
+    #[test]
+    fn test_reminder_due_date_skips_tasks_without_due_time() {
+        let task = create_task(None);
+        let now = Utc.with_ymd_and_hms(2024, 6, 3, 23, 45, 0).unwrap();
+
+        assert_eq!(reminder_due_date(&task, "UTC", 0, now, Duration::minutes(30)), None);
+    }
+
+    #[test]
+    fn test_reminder_due_date_skips_paused_and_archived() {
+        let now = Utc.with_ymd_and_hms(2024, 6, 3, 17, 45, 0).unwrap();
+
+        let mut paused = create_task(Some("18:00"));
+        paused.paused = true;
+        assert_eq!(reminder_due_date(&paused, "UTC", 0, now, Duration::minutes(30)), None);
+
+        let mut archived = create_task(Some("18:00"));
+        archived.archived = true;
+        assert_eq!(reminder_due_date(&archived, "UTC", 0, now, Duration::minutes(30)), None);
+    }
+
+    #[test]
+    fn test_build_reminder_message() {
+        let task = create_task(Some("18:00"));
+        let message = build_reminder_message(&task);
+
+        assert_eq!(message.title, "Take out trash");
+        assert_eq!(message.body, "Due at 18:00");
+        assert_eq!(message.url, format!("/households/{}", task.household_id));
+    }
+
+    #[test]
+    fn test_build_chat_message_truncates_long_content() {
+        let household_id = Uuid::new_v4();
+        let message = build_chat_message(&household_id, "alice", &"a".repeat(200));
+
+        assert_eq!(message.title, "alice");
+        assert_eq!(message.body.chars().count(), 121);
+        assert!(message.body.ends_with('…'));
+        assert_eq!(message.url, format!("/households/{}/chat", household_id));
+        assert_eq!(build_chat_message(&household_id, "alice", "Dinner?").body, "Dinner?");
+    }
+
+    #[tokio::test]
+    async fn test_wants_notification_respects_preferences() {
+        let pool = crate::test_utils::create_test_pool().await;
+        let user_id = crate::test_utils::create_test_user(&pool, "quiet@test.com", shared::Role::Member).await;
+        let night = Utc.with_ymd_and_hms(2024, 6, 3, 23, 0, 0).unwrap();
+
+        // No settings row yet: everything is allowed
+        assert!(wants_notification(&pool, &user_id, NotificationKind::Chat, "UTC", night).await.unwrap());
+
+        let request = shared::UpdateUserSettingsRequest {
+            language: None,
+            theme: None,
+            notification_preferences: Some(NotificationPreferences {
+                chat: false,
+                quiet_hours_start: Some("22:00".to_string()),
+                quiet_hours_end: Some("07:00".to_string()),
+                ..Default::default()
+            }),
+        };
+        user_settings::update_settings(&pool, &user_id, &request).await.unwrap();
+
+        assert!(!wants_notification(&pool, &user_id, NotificationKind::Chat, "UTC", night).await.unwrap());
+        assert!(!wants_notification(&pool, &user_id, NotificationKind::Reminders, "UTC", night).await.unwrap());
+        // 23:00 UTC is already 08:00 the next morning in Tokyo
+        assert!(wants_notification(&pool, &user_id, NotificationKind::Reminders, "Asia/Tokyo", night).await.unwrap());
+    }
+
+    #[test]
+    fn test_notification_error_display() {
+        let error = NotificationError::InvalidSubscription("Push endpoint must use https".to_string());
+        assert_eq!(error.to_string(), "Invalid subscription: Push endpoint must use https");
+    }
+}
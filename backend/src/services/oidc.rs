@@ -0,0 +1,591 @@
+//! OpenID Connect login
+//!
+//! Implements the authorization code flow with PKCE against a single configured
+//! provider (e.g. a self-hosted Keycloak realm). Provider metadata is fetched via
+//! discovery, the code is exchanged at the token endpoint and the returned ID token
+//! is matched to a local account.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{Duration, Utc};
+use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
+use rand::RngCore;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::config::Config;
+use crate::models::UserRow;
+use crate::services::auth as auth_service;
+use shared::User;
+
+/// How long a started authorization may take before the callback is rejected
+const AUTH_STATE_TTL_MINUTES: i64 = 10;
+
+/// How long the frontend has to exchange a login code for tokens
+const LOGIN_CODE_TTL_MINUTES: i64 = 2;
+
+const SCOPES: &str = "openid email profile";
+
+#[derive(Debug, Error)]
+pub enum OidcError {
+    #[error("OIDC login is not configured")]
+    NotConfigured,
+    #[error("Provider discovery failed: {0}")]
+    Discovery(String),
+    #[error("HTTP error: {0}")]
+    Http(#[from] reqwest::Error),
+    #[error("Invalid or expired state")]
+    InvalidState,
+    #[error("Token endpoint responded with status {0}")]
+    TokenExchange(u16),
+    #[error("Invalid ID token: {0}")]
+    InvalidIdToken(String),
+    #[error("Provider did not return an email address")]
+    MissingEmail,
+    #[error("Email address is already used by another account")]
+    EmailInUse,
+    #[error("Provider account is already linked to another user")]
+    AlreadyLinked,
+    #[error("Invalid or expired login code")]
+    InvalidLoginCode,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+/// Provider settings taken from the environment
+#[derive(Debug, Clone)]
+pub struct OidcSettings {
+    pub issuer_url: String,
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    pub redirect_url: String,
+    pub provider_name: String,
+}
+
+impl OidcSettings {
+    /// Returns `None` unless issuer, client ID and redirect URL are all configured
+    pub fn from_config(config: &Config) -> Option<Self> {
+        Some(Self {
+            issuer_url: config.oidc_issuer_url.clone()?,
+            client_id: config.oidc_client_id.clone()?,
+            client_secret: config.oidc_client_secret.clone().filter(|s| !s.is_empty()),
+            redirect_url: config.oidc_redirect_url.clone()?,
+            provider_name: config.oidc_provider_name.clone(),
+        })
+    }
+}
+
+/// The subset of the discovery document needed for the code flow
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProviderMetadata {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    id_token: String,
+}
+
+/// Claims read from the ID token
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdTokenClaims {
+    pub sub: String,
+    pub nonce: Option<String>,
+    pub email: Option<String>,
+    #[serde(default)]
+    pub email_verified: bool,
+    pub preferred_username: Option<String>,
+}
+
+/// Result of a completed authorization
+#[derive(Debug)]
+pub enum OidcOutcome {
+    /// The user logged in (and may have been created or linked by email)
+    LoggedIn(User),
+    /// A logged-in user linked their account to the provider
+    Linked(User),
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct AuthStateRow {
+    nonce: String,
+    code_verifier: String,
+    link_user_id: Option<String>,
+    created_at: chrono::DateTime<Utc>,
+}
+
+fn random_token() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// PKCE `S256` challenge for a code verifier (RFC 7636)
+pub fn pkce_challenge(verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()))
+}
+
+fn same_issuer(a: &str, b: &str) -> bool {
+    a.trim_end_matches('/') == b.trim_end_matches('/')
+}
+
+/// Fetch the provider's discovery document
+pub async fn discover(client: &reqwest::Client, settings: &OidcSettings) -> Result<ProviderMetadata, OidcError> {
+    let url = format!(
+        "{}/.well-known/openid-configuration",
+        settings.issuer_url.trim_end_matches('/')
+    );
+    let response = client.get(&url).send().await?;
+    if !response.status().is_success() {
+        return Err(OidcError::Discovery(format!("status {}", response.status().as_u16())));
+    }
+    let metadata: ProviderMetadata = response.json().await?;
+
+    if !same_issuer(&metadata.issuer, &settings.issuer_url) {
+        return Err(OidcError::Discovery(format!(
+            "issuer mismatch: expected {}, got {}",
+            settings.issuer_url, metadata.issuer
+        )));
+    }
+
+    Ok(metadata)
+}
+
+pub fn build_authorization_url(
+    metadata: &ProviderMetadata,
+    settings: &OidcSettings,
+    state: &str,
+    nonce: &str,
+    code_verifier: &str,
+) -> Result<String, OidcError> {
+    let challenge = pkce_challenge(code_verifier);
+    let url = reqwest::Url::parse_with_params(
+        &metadata.authorization_endpoint,
+        &[
+            ("response_type", "code"),
+            ("client_id", settings.client_id.as_str()),
+            ("redirect_uri", settings.redirect_url.as_str()),
+            ("scope", SCOPES),
+            ("state", state),
+            ("nonce", nonce),
+            ("code_challenge", challenge.as_str()),
+            ("code_challenge_method", "S256"),
+        ],
+    )
+    .map_err(|e| OidcError::Discovery(format!("invalid authorization endpoint: {}", e)))?;
+
+    Ok(url.to_string())
+}
+
+/// Start an authorization and return the provider URL to redirect the browser to.
+/// With `link_user_id` set, the callback links the provider account to that user.
+pub async fn begin_authorization(
+    pool: &SqlitePool,
+    client: &reqwest::Client,
+    settings: &OidcSettings,
+    link_user_id: Option<&Uuid>,
+) -> Result<String, OidcError> {
+    let metadata = discover(client, settings).await?;
+
+    let state = random_token();
+    let nonce = random_token();
+    let code_verifier = random_token();
+
+    // Drop abandoned authorizations
+    sqlx::query("DELETE FROM oidc_auth_states WHERE created_at < ?")
+        .bind(Utc::now() - Duration::minutes(AUTH_STATE_TTL_MINUTES))
+        .execute(pool)
+        .await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO oidc_auth_states (state, nonce, code_verifier, link_user_id, created_at)
+        VALUES (?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(&state)
+    .bind(&nonce)
+    .bind(&code_verifier)
+    .bind(link_user_id.map(|id| id.to_string()))
+    .bind(Utc::now())
+    .execute(pool)
+    .await?;
+
+    build_authorization_url(&metadata, settings, &state, &nonce, &code_verifier)
+}
+
+/// Validate the ID token returned by the token endpoint.
+///
+/// The token is received directly from the provider over TLS, so the signature
+/// is not checked (OIDC Core 3.1.3.7); issuer, audience, expiry and nonce are.
+pub fn validate_id_token(
+    id_token: &str,
+    settings: &OidcSettings,
+    expected_nonce: &str,
+) -> Result<IdTokenClaims, OidcError> {
+    let mut validation = Validation::new(Algorithm::RS256);
+    validation.insecure_disable_signature_validation();
+    validation.set_audience(&[settings.client_id.as_str()]);
+    validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+
+    let data = decode::<serde_json::Value>(id_token, &DecodingKey::from_secret(&[]), &validation)
+        .map_err(|e| OidcError::InvalidIdToken(e.to_string()))?;
+
+    let issuer = data.claims.get("iss").and_then(|v| v.as_str()).unwrap_or_default();
+    if !same_issuer(issuer, &settings.issuer_url) {
+        return Err(OidcError::InvalidIdToken("issuer mismatch".to_string()));
+    }
+
+    let claims: IdTokenClaims = serde_json::from_value(data.claims)
+        .map_err(|e| OidcError::InvalidIdToken(e.to_string()))?;
+
+    if claims.nonce.as_deref() != Some(expected_nonce) {
+        return Err(OidcError::InvalidIdToken("nonce mismatch".to_string()));
+    }
+
+    Ok(claims)
+}
+
+/// Handle the provider callback: exchange the code and resolve the local user
+pub async fn complete_authorization(
+    pool: &SqlitePool,
+    client: &reqwest::Client,
+    settings: &OidcSettings,
+    code: &str,
+    state: &str,
+) -> Result<OidcOutcome, OidcError> {
+    // States are single use
+    let auth_state: AuthStateRow = sqlx::query_as(
+        "SELECT nonce, code_verifier, link_user_id, created_at FROM oidc_auth_states WHERE state = ?",
+    )
+    .bind(state)
+    .fetch_optional(pool)
+    .await?
+    .ok_or(OidcError::InvalidState)?;
+
+    sqlx::query("DELETE FROM oidc_auth_states WHERE state = ?")
+        .bind(state)
+        .execute(pool)
+        .await?;
+
+    if auth_state.created_at < Utc::now() - Duration::minutes(AUTH_STATE_TTL_MINUTES) {
+        return Err(OidcError::InvalidState);
+    }
+
+    let metadata = discover(client, settings).await?;
+
+    let mut form = vec![
+        ("grant_type", "authorization_code"),
+        ("code", code),
+        ("redirect_uri", settings.redirect_url.as_str()),
+        ("client_id", settings.client_id.as_str()),
+        ("code_verifier", auth_state.code_verifier.as_str()),
+    ];
+    if let Some(secret) = &settings.client_secret {
+        form.push(("client_secret", secret.as_str()));
+    }
+
+    let response = client.post(&metadata.token_endpoint).form(&form).send().await?;
+    if !response.status().is_success() {
+        return Err(OidcError::TokenExchange(response.status().as_u16()));
+    }
+    let tokens: TokenResponse = response.json().await?;
+
+    let claims = validate_id_token(&tokens.id_token, settings, &auth_state.nonce)?;
+    let link_user_id = auth_state
+        .link_user_id
+        .and_then(|id| Uuid::parse_str(&id).ok());
+
+    resolve_user(pool, &settings.issuer_url, &claims, link_user_id.as_ref()).await
+}
+
+/// Map the provider identity to a local user, linking or creating accounts as needed
+pub async fn resolve_user(
+    pool: &SqlitePool,
+    issuer: &str,
+    claims: &IdTokenClaims,
+    link_user_id: Option<&Uuid>,
+) -> Result<OidcOutcome, OidcError> {
+    let existing: Option<UserRow> = sqlx::query_as(
+        "SELECT * FROM users WHERE oidc_provider = ? AND oidc_subject = ?",
+    )
+    .bind(issuer)
+    .bind(&claims.sub)
+    .fetch_optional(pool)
+    .await?;
+
+    if let Some(user) = existing {
+        let user = user.to_shared();
+        return match link_user_id {
+            Some(id) if *id != user.id => Err(OidcError::AlreadyLinked),
+            Some(_) => Ok(OidcOutcome::Linked(user)),
+            None => Ok(OidcOutcome::LoggedIn(user)),
+        };
+    }
+
+    if let Some(user_id) = link_user_id {
+        let user = link_account(pool, user_id, issuer, &claims.sub).await?;
+        return Ok(OidcOutcome::Linked(user));
+    }
+
+    let email = claims
+        .email
+        .as_deref()
+        .map(str::trim)
+        .filter(|e| !e.is_empty())
+        .ok_or(OidcError::MissingEmail)?;
+
+    let by_email: Option<UserRow> = sqlx::query_as("SELECT * FROM users WHERE LOWER(email) = LOWER(?)")
+        .bind(email)
+        .fetch_optional(pool)
+        .await?;
+
+    if let Some(user) = by_email {
+        // Only adopt an existing account when the provider vouches for the address
+        if !claims.email_verified || user.oidc_subject.is_some() {
+            return Err(OidcError::EmailInUse);
+        }
+        let user_id = Uuid::parse_str(&user.id).unwrap();
+        let user = link_account(pool, &user_id, issuer, &claims.sub).await?;
+        return Ok(OidcOutcome::LoggedIn(user));
+    }
+
+    let user = create_user(pool, issuer, claims, email).await?;
+    Ok(OidcOutcome::LoggedIn(user))
+}
+
+async fn link_account(pool: &SqlitePool, user_id: &Uuid, issuer: &str, subject: &str) -> Result<User, OidcError> {
+    sqlx::query("UPDATE users SET oidc_provider = ?, oidc_subject = ?, updated_at = ? WHERE id = ?")
+        .bind(issuer)
+        .bind(subject)
+        .bind(Utc::now())
+        .bind(user_id.to_string())
+        .execute(pool)
+        .await?;
+
+    let user: UserRow = sqlx::query_as("SELECT * FROM users WHERE id = ?")
+        .bind(user_id.to_string())
+        .fetch_one(pool)
+        .await?;
+
+    Ok(user.to_shared())
+}
+
+/// Derive a username from the provider's preferred username or the email local part
+pub fn username_candidate(claims: &IdTokenClaims, email: &str) -> String {
+    let source = claims
+        .preferred_username
+        .as_deref()
+        .filter(|u| !u.trim().is_empty())
+        .unwrap_or_else(|| email.split('@').next().unwrap_or_default());
+
+    let sanitized: String = source
+        .trim()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        .take(32)
+        .collect();
+
+    if sanitized.is_empty() {
+        "user".to_string()
+    } else {
+        sanitized
+    }
+}
+
+async fn create_user(pool: &SqlitePool, issuer: &str, claims: &IdTokenClaims, email: &str) -> Result<User, OidcError> {
+    let base = username_candidate(claims, email);
+    let mut username = base.clone();
+    let mut suffix = 1;
+    while sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM users WHERE LOWER(username) = LOWER(?)")
+        .bind(&username)
+        .fetch_one(pool)
+        .await?
+        > 0
+    {
+        suffix += 1;
+        username = format!("{}{}", base, suffix);
+    }
+
+    let id = Uuid::new_v4();
+    let now = Utc::now();
+    let email = email.to_lowercase();
+
+    // Accounts created through OIDC have no password. The first account on the
+    // instance becomes its admin.
+    sqlx::query(
+        r#"
+        INSERT INTO users (id, username, email, password_hash, oidc_subject, oidc_provider, is_admin, created_at, updated_at)
+        SELECT ?, ?, ?, NULL, ?, ?, NOT EXISTS (SELECT 1 FROM users WHERE managed_by IS NULL), ?, ?
+        "#,
+    )
+    .bind(id.to_string())
+    .bind(&username)
+    .bind(&email)
+    .bind(&claims.sub)
+    .bind(issuer)
+    .bind(now)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    Ok(User {
+        id,
+        username,
+        email,
+        created_at: now,
+        updated_at: now,
+    })
+}
+
+/// Create a one-time code the frontend exchanges for access and refresh tokens.
+/// Keeps the tokens themselves out of the redirect URL.
+pub async fn create_login_code(pool: &SqlitePool, user_id: &Uuid) -> Result<String, OidcError> {
+    let code = random_token();
+
+    sqlx::query("DELETE FROM oidc_login_codes WHERE expires_at < ?")
+        .bind(Utc::now())
+        .execute(pool)
+        .await?;
+
+    sqlx::query("INSERT INTO oidc_login_codes (code_hash, user_id, expires_at) VALUES (?, ?, ?)")
+        .bind(auth_service::hash_refresh_token(&code))
+        .bind(user_id.to_string())
+        .bind(Utc::now() + Duration::minutes(LOGIN_CODE_TTL_MINUTES))
+        .execute(pool)
+        .await?;
+
+    Ok(code)
+}
+
+/// Redeem a login code once, returning the user it was issued for
+pub async fn redeem_login_code(pool: &SqlitePool, code: &str) -> Result<Uuid, OidcError> {
+    let code_hash = auth_service::hash_refresh_token(code);
+
+    let row: Option<(String, chrono::DateTime<Utc>)> =
+        sqlx::query_as("SELECT user_id, expires_at FROM oidc_login_codes WHERE code_hash = ?")
+            .bind(&code_hash)
+            .fetch_optional(pool)
+            .await?;
+
+    sqlx::query("DELETE FROM oidc_login_codes WHERE code_hash = ?")
+        .bind(&code_hash)
+        .execute(pool)
+        .await?;
+
+    match row {
+        Some((user_id, expires_at)) if expires_at > Utc::now() => {
+            Uuid::parse_str(&user_id).map_err(|_| OidcError::InvalidLoginCode)
+        }
+        _ => Err(OidcError::InvalidLoginCode),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonwebtoken::{encode, EncodingKey, Header};
+
+    fn settings() -> OidcSettings {
+        OidcSettings {
+            issuer_url: "https://keycloak.example.com/realms/family".to_string(),
+            client_id: "haushalt".to_string(),
+            client_secret: None,
+            redirect_url: "https://haushalt.example.com/api/auth/oidc/callback".to_string(),
+            provider_name: "Keycloak".to_string(),
+        }
+    }
+
+    fn id_token(claims: serde_json::Value) -> String {
+        encode(&Header::default(), &claims, &EncodingKey::from_secret(b"provider-key")).unwrap()
+    }
+
+    fn valid_claims() -> serde_json::Value {
+        serde_json::json!({
+            "iss": "https://keycloak.example.com/realms/family/",
+            "aud": "haushalt",
+            "sub": "abc-123",
+            "exp": (Utc::now() + Duration::minutes(5)).timestamp(),
+            "nonce": "n-1",
+            "email": "alice@example.com",
+            "email_verified": true,
+            "preferred_username": "alice",
+        })
+    }
+
+    #[test]
+    fn test_pkce_challenge_matches_rfc_example() {
+        // RFC 7636, Appendix B
+        assert_eq!(
+            pkce_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn test_build_authorization_url() {
+        let metadata = ProviderMetadata {
+            issuer: "https://keycloak.example.com/realms/family".to_string(),
+            authorization_endpoint: "https://keycloak.example.com/realms/family/protocol/openid-connect/auth"
+                .to_string(),
+            token_endpoint: "https://keycloak.example.com/realms/family/protocol/openid-connect/token".to_string(),
+        };
+
+        let url = build_authorization_url(&metadata, &settings(), "st", "no", "verifier").unwrap();
+        let parsed = reqwest::Url::parse(&url).unwrap();
+        let params: std::collections::HashMap<_, _> = parsed.query_pairs().into_owned().collect();
+
+        assert!(url.starts_with("https://keycloak.example.com/realms/family/protocol/openid-connect/auth?"));
+        assert_eq!(params["response_type"], "code");
+        assert_eq!(params["client_id"], "haushalt");
+        assert_eq!(params["scope"], "openid email profile");
+        assert_eq!(params["state"], "st");
+        assert_eq!(params["nonce"], "no");
+        assert_eq!(params["code_challenge"], pkce_challenge("verifier"));
+        assert_eq!(params["code_challenge_method"], "S256");
+    }
+
+    #[test]
+    fn test_validate_id_token() {
+        let claims = validate_id_token(&id_token(valid_claims()), &settings(), "n-1").unwrap();
+
+        assert_eq!(claims.sub, "abc-123");
+        assert_eq!(claims.email.as_deref(), Some("alice@example.com"));
+        assert!(claims.email_verified);
+    }
+
+    #[test]
+    fn test_validate_id_token_rejects_mismatches() {
+        let token = id_token(valid_claims());
+        assert!(validate_id_token(&token, &settings(), "other-nonce").is_err());
+
+        let mut wrong_audience = valid_claims();
+        wrong_audience["aud"] = "other-client".into();
+        assert!(validate_id_token(&id_token(wrong_audience), &settings(), "n-1").is_err());
+
+        let mut wrong_issuer = valid_claims();
+        wrong_issuer["iss"] = "https://evil.example.com".into();
+        assert!(validate_id_token(&id_token(wrong_issuer), &settings(), "n-1").is_err());
+
+        let mut expired = valid_claims();
+        expired["exp"] = (Utc::now() - Duration::hours(1)).timestamp().into();
+        assert!(validate_id_token(&id_token(expired), &settings(), "n-1").is_err());
+    }
+
+    #[test]
+    fn test_username_candidate() {
+        let mut claims: IdTokenClaims = serde_json::from_value(valid_claims()).unwrap();
+        assert_eq!(username_candidate(&claims, "alice@example.com"), "alice");
+
+        claims.preferred_username = None;
+        assert_eq!(username_candidate(&claims, "bob smith@example.com"), "bobsmith");
+
+        claims.preferred_username = Some("!!!".to_string());
+        assert_eq!(username_candidate(&claims, "x@example.com"), "user");
+    }
+}
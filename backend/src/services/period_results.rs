@@ -1,10 +1,12 @@
 use chrono::{DateTime, NaiveDate, Utc};
-use shared::{PeriodStatus, TaskPeriodResult};
+use shared::{HouseholdMembership, PeriodStatus, TaskPeriodResult};
 use sqlx::SqlitePool;
 use thiserror::Error;
 use uuid::Uuid;
 
 use crate::models::TaskPeriodResultRow;
+use crate::services::events;
+use crate::services::points::{self, PointsError};
 
 #[derive(Debug, Error)]
 pub enum PeriodResultError {
@@ -15,6 +17,18 @@ pub enum PeriodResultError {
     NotFound,
 }
 
+#[derive(Debug, Error)]
+pub enum StreakFreezeError {
+    #[error("Only missed periods can be repaired with a streak freeze")]
+    NotMissed,
+    #[error("You have no streak freeze left")]
+    NoneAvailable,
+    #[error("Points error: {0}")]
+    Points(#[from] PointsError),
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+}
+
 /// Create or update a period result for a task
 /// If a result already exists for the same task and period_start, it will be updated
 #[allow(clippy::too_many_arguments)]
@@ -61,6 +75,7 @@ pub async fn finalize_period(
         .bind(&existing_row.id)
         .execute(pool)
         .await?;
+        events::record_period_change(pool, task_id, period_start, existing_row.status.parse().ok(), Some(status)).await?;
 
         // Fetch updated record
         let updated: TaskPeriodResultRow = sqlx::query_as(
@@ -92,6 +107,7 @@ pub async fn finalize_period(
         .bind(notes)
         .execute(pool)
         .await?;
+        events::record_period_change(pool, task_id, period_start, None, Some(status)).await?;
 
         // Fetch inserted record
         let inserted: TaskPeriodResultRow = sqlx::query_as(
@@ -106,7 +122,6 @@ pub async fn finalize_period(
 }
 
 /// Get a single period result by task ID and period start date
-#[allow(dead_code)]
 pub async fn get_period_result(
     pool: &SqlitePool,
     task_id: &Uuid,
@@ -165,77 +180,47 @@ pub async fn get_recent_periods(
     .fetch_all(pool)
     .await?;
 
-    // Reverse to get oldest first (for left-to-right display)
-    let mut periods: Vec<shared::PeriodDisplay> = rows
-        .into_iter()
+    Ok(recent_period_displays(&rows))
+}
+
+/// Convert period results (newest first) into oldest-first entries for the habit tracker
+pub fn recent_period_displays(rows: &[TaskPeriodResultRow]) -> Vec<shared::PeriodDisplay> {
+    rows.iter()
+        .rev()
         .map(|r| shared::PeriodDisplay {
             period_start: r.period_start,
             status: r.status.parse().unwrap_or(shared::PeriodStatus::Failed),
         })
-        .collect();
-    periods.reverse();
-
-    Ok(periods)
+        .collect()
 }
 
-/// Count period results by status for a task within a date range
-#[derive(Debug, Clone)]
-pub struct PeriodCounts {
-    pub completed: i32,
-    pub failed: i32,
-    pub skipped: i32,
-}
-
-pub async fn count_period_results(
+/// Calculate current streak from period results
+/// Counts consecutive completed periods from most recent, skipped periods don't break streak
+pub async fn calculate_current_streak(
     pool: &SqlitePool,
     task_id: &Uuid,
-    start_date: NaiveDate,
-    end_date: NaiveDate,
-) -> Result<PeriodCounts, PeriodResultError> {
-    let completed: i64 = sqlx::query_scalar(
-        r#"SELECT COUNT(*) FROM task_period_results
-        WHERE task_id = ? AND period_start >= ? AND period_start <= ? AND status = 'completed'"#,
-    )
-    .bind(task_id.to_string())
-    .bind(start_date)
-    .bind(end_date)
-    .fetch_one(pool)
-    .await?;
-
-    let failed: i64 = sqlx::query_scalar(
-        r#"SELECT COUNT(*) FROM task_period_results
-        WHERE task_id = ? AND period_start >= ? AND period_start <= ? AND status = 'failed'"#,
-    )
-    .bind(task_id.to_string())
-    .bind(start_date)
-    .bind(end_date)
-    .fetch_one(pool)
-    .await?;
-
-    let skipped: i64 = sqlx::query_scalar(
-        r#"SELECT COUNT(*) FROM task_period_results
-        WHERE task_id = ? AND period_start >= ? AND period_start <= ? AND status = 'skipped'"#,
+) -> Result<i32, PeriodResultError> {
+    // Get all period results ordered by period_start DESC (most recent first)
+    let rows: Vec<TaskPeriodResultRow> = sqlx::query_as(
+        r#"SELECT * FROM task_period_results
+        WHERE task_id = ?
+        ORDER BY period_start DESC"#,
     )
     .bind(task_id.to_string())
-    .bind(start_date)
-    .bind(end_date)
-    .fetch_one(pool)
+    .fetch_all(pool)
     .await?;
 
-    Ok(PeriodCounts {
-        completed: completed as i32,
-        failed: failed as i32,
-        skipped: skipped as i32,
-    })
+    Ok(streak_from_periods(&rows, None))
 }
 
-/// Calculate current streak from period results
-/// Counts consecutive completed periods from most recent, skipped periods don't break streak
-pub async fn calculate_current_streak(
+/// Calculate a member's current streak from period results.
+/// Like `calculate_current_streak`, but failed periods overlapping the member's
+/// vacation count as skipped, so a vacation set after the fact still protects the streak.
+pub async fn calculate_member_streak(
     pool: &SqlitePool,
     task_id: &Uuid,
+    membership: &HouseholdMembership,
 ) -> Result<i32, PeriodResultError> {
-    // Get all period results ordered by period_start DESC (most recent first)
     let rows: Vec<TaskPeriodResultRow> = sqlx::query_as(
         r#"SELECT * FROM task_period_results
         WHERE task_id = ?
@@ -245,17 +230,27 @@ pub async fn calculate_current_streak(
     .fetch_all(pool)
     .await?;
 
+    Ok(streak_from_periods(&rows, Some(membership)))
+}
+
+/// Current streak from period results ordered newest first. Skipped periods don't
+/// break the streak; with a membership, neither do failures during its vacation.
+pub fn streak_from_periods(rows: &[TaskPeriodResultRow], membership: Option<&HouseholdMembership>) -> i32 {
     let mut streak = 0;
     for row in rows {
         let status: PeriodStatus = row.status.parse().unwrap_or(PeriodStatus::Failed);
         match status {
             PeriodStatus::Completed => streak += 1,
-            PeriodStatus::Skipped => continue, // Skip doesn't break streak
-            PeriodStatus::Failed => break,     // Failed breaks streak
+            PeriodStatus::Skipped => continue,
+            PeriodStatus::Failed
+                if membership.is_some_and(|m| m.is_on_vacation_during(row.period_start, row.period_end)) =>
+            {
+                continue
+            }
+            PeriodStatus::Failed => break,
         }
     }
-
-    Ok(streak)
+    streak
 }
 
 /// Calculate best (longest) streak from period results
@@ -304,7 +299,11 @@ pub async fn update_period_status(
 ) -> Result<TaskPeriodResult, PeriodResultError> {
     let now = Utc::now();
 
-    let result = sqlx::query(
+    let Some(old_status) = get_period_result(pool, task_id, period_start).await?.map(|r| r.status) else {
+        return Err(PeriodResultError::NotFound);
+    };
+
+    sqlx::query(
         r#"UPDATE task_period_results SET
             status = ?,
             finalized_at = ?,
@@ -320,10 +319,7 @@ pub async fn update_period_status(
     .bind(period_start)
     .execute(pool)
     .await?;
-
-    if result.rows_affected() == 0 {
-        return Err(PeriodResultError::NotFound);
-    }
+    events::record_period_change(pool, task_id, period_start, Some(old_status), Some(new_status)).await?;
 
     let updated: TaskPeriodResultRow = sqlx::query_as(
         "SELECT * FROM task_period_results WHERE task_id = ? AND period_start = ?",
@@ -336,6 +332,64 @@ pub async fn update_period_status(
     Ok(updated.to_shared())
 }
 
+/// Spend one of the member's streak freezes to turn a failed period into a skipped
+/// one, so it no longer breaks the streak. For tasks with individual completions
+/// the member's own result is repaired, otherwise the task's.
+pub async fn apply_streak_freeze(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    task_id: &Uuid,
+    user_id: &Uuid,
+    period_start: NaiveDate,
+    individual: bool,
+) -> Result<TaskPeriodResult, StreakFreezeError> {
+    let row: Option<TaskPeriodResultRow> = if individual {
+        sqlx::query_as("SELECT * FROM task_assignee_period_results WHERE task_id = ? AND user_id = ? AND period_start = ?")
+            .bind(task_id.to_string())
+            .bind(user_id.to_string())
+            .bind(period_start)
+            .fetch_optional(pool)
+            .await?
+    } else {
+        sqlx::query_as("SELECT * FROM task_period_results WHERE task_id = ? AND period_start = ?")
+            .bind(task_id.to_string())
+            .bind(period_start)
+            .fetch_optional(pool)
+            .await?
+    };
+    let Some(row) = row.filter(|r| r.status == PeriodStatus::Failed.as_str()) else {
+        return Err(StreakFreezeError::NotMissed);
+    };
+
+    if !points::consume_streak_freeze(pool, household_id, user_id).await? {
+        return Err(StreakFreezeError::NoneAvailable);
+    }
+
+    let update = if individual {
+        "UPDATE task_assignee_period_results SET status = 'skipped', finalized_at = ?, finalized_by = ?, notes = 'Streak freeze' WHERE id = ?"
+    } else {
+        "UPDATE task_period_results SET status = 'skipped', finalized_at = ?, finalized_by = ?, notes = 'Streak freeze' WHERE id = ?"
+    };
+    sqlx::query(update)
+        .bind(Utc::now())
+        .bind(user_id.to_string())
+        .bind(&row.id)
+        .execute(pool)
+        .await?;
+    if !individual {
+        events::record_period_change(pool, task_id, period_start, Some(PeriodStatus::Failed), Some(PeriodStatus::Skipped))
+            .await?;
+    }
+
+    Ok(TaskPeriodResultRow {
+        status: PeriodStatus::Skipped.as_str().to_string(),
+        finalized_by: user_id.to_string(),
+        notes: Some("Streak freeze".to_string()),
+        ..row
+    }
+    .to_shared())
+}
+
 /// Check if a period has already been finalized
 pub async fn is_period_finalized(
     pool: &SqlitePool,
@@ -358,11 +412,72 @@ pub async fn delete_period_result(
     pool: &SqlitePool,
     task_id: &Uuid,
     period_start: NaiveDate,
+) -> Result<bool, PeriodResultError> {
+    let deleted: Option<String> = sqlx::query_scalar(
+        "DELETE FROM task_period_results WHERE task_id = ? AND period_start = ? RETURNING status",
+    )
+    .bind(task_id.to_string())
+    .bind(period_start)
+    .fetch_optional(pool)
+    .await?;
+
+    let Some(status) = deleted else {
+        return Ok(false);
+    };
+    events::record_period_change(pool, task_id, period_start, status.parse().ok(), None).await?;
+    Ok(true)
+}
+
+/// Record one assignee's outcome for a period of a task with individual completions.
+/// An existing result for the same assignee and period is overwritten.
+#[allow(clippy::too_many_arguments)]
+pub async fn finalize_assignee_period(
+    pool: &SqlitePool,
+    task_id: &Uuid,
+    user_id: &Uuid,
+    period_start: NaiveDate,
+    period_end: NaiveDate,
+    status: PeriodStatus,
+    completions_count: i32,
+    target_count: i32,
+) -> Result<(), PeriodResultError> {
+    sqlx::query(
+        r#"INSERT INTO task_assignee_period_results
+            (id, task_id, user_id, period_start, period_end, status, completions_count, target_count, finalized_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+        ON CONFLICT (task_id, user_id, period_start) DO UPDATE SET
+            status = excluded.status,
+            completions_count = excluded.completions_count,
+            target_count = excluded.target_count,
+            finalized_at = excluded.finalized_at"#,
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(task_id.to_string())
+    .bind(user_id.to_string())
+    .bind(period_start)
+    .bind(period_end)
+    .bind(status.as_str())
+    .bind(completions_count)
+    .bind(target_count)
+    .bind(Utc::now())
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Delete an assignee's period result (used when uncompleting brings them below target)
+pub async fn delete_assignee_period_result(
+    pool: &SqlitePool,
+    task_id: &Uuid,
+    user_id: &Uuid,
+    period_start: NaiveDate,
 ) -> Result<bool, PeriodResultError> {
     let result = sqlx::query(
-        "DELETE FROM task_period_results WHERE task_id = ? AND period_start = ?",
+        "DELETE FROM task_assignee_period_results WHERE task_id = ? AND user_id = ? AND period_start = ?",
     )
     .bind(task_id.to_string())
+    .bind(user_id.to_string())
     .bind(period_start)
     .execute(pool)
     .await?;
@@ -370,6 +485,23 @@ pub async fn delete_period_result(
     Ok(result.rows_affected() > 0)
 }
 
+/// An assignee's own period results for a task, newest first
+pub async fn get_assignee_periods(
+    pool: &SqlitePool,
+    task_id: &Uuid,
+    user_id: &Uuid,
+) -> Result<Vec<TaskPeriodResultRow>, PeriodResultError> {
+    Ok(sqlx::query_as(
+        r#"SELECT * FROM task_assignee_period_results
+        WHERE task_id = ? AND user_id = ?
+        ORDER BY period_start DESC"#,
+    )
+    .bind(task_id.to_string())
+    .bind(user_id.to_string())
+    .fetch_all(pool)
+    .await?)
+}
+
 /// Get all unfinalized periods for a task that ended before a given date
 /// This is useful for the background job to find periods that need to be finalized
 #[allow(dead_code)]
@@ -428,6 +560,8 @@ mod tests {
         .await
         .unwrap();
 
+        crate::test_utils::create_event_tables(&pool).await;
+
         pool
     }
 
@@ -576,38 +710,6 @@ mod tests {
         assert_eq!(results.len(), 3); // Days 12, 13, 14
     }
 
-    #[tokio::test]
-    async fn test_count_period_results() {
-        let pool = setup_test_db().await;
-        let task_id = Uuid::new_v4();
-
-        // Create period results with different statuses
-        for day in 1..=10 {
-            let date = NaiveDate::from_ymd_opt(2024, 1, day).unwrap();
-            let status = if day <= 5 {
-                PeriodStatus::Completed
-            } else if day <= 8 {
-                PeriodStatus::Failed
-            } else {
-                PeriodStatus::Skipped
-            };
-            finalize_period(&pool, &task_id, date, date, status, 1, 1, "system", None)
-                .await
-                .unwrap();
-        }
-
-        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
-        let end = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
-
-        let counts = count_period_results(&pool, &task_id, start, end)
-            .await
-            .unwrap();
-
-        assert_eq!(counts.completed, 5);
-        assert_eq!(counts.failed, 3);
-        assert_eq!(counts.skipped, 2);
-    }
-
     #[tokio::test]
     async fn test_update_period_status() {
         let pool = setup_test_db().await;
@@ -793,6 +895,36 @@ mod tests {
         assert_eq!(streak, 2);
     }
 
+    #[tokio::test]
+    async fn test_calculate_member_streak_ignores_failures_during_vacation() {
+        let pool = setup_test_db().await;
+        let task_id = Uuid::new_v4();
+
+        // Create: completed, failed (vacation), failed (vacation), completed (most recent)
+        for (day, status) in [(1, PeriodStatus::Completed), (2, PeriodStatus::Failed), (3, PeriodStatus::Failed), (4, PeriodStatus::Completed)] {
+            let date = NaiveDate::from_ymd_opt(2024, 1, day).unwrap();
+            finalize_period(&pool, &task_id, date, date, status, 0, 1, "system", None)
+                .await
+                .unwrap();
+        }
+
+        let mut membership = HouseholdMembership {
+            id: Uuid::new_v4(),
+            household_id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            role: shared::Role::Member,
+            points: 0,
+            joined_at: Utc::now(),
+            vacation_start: None,
+            vacation_end: None,
+        };
+        assert_eq!(calculate_member_streak(&pool, &task_id, &membership).await.unwrap(), 1);
+
+        membership.vacation_start = NaiveDate::from_ymd_opt(2024, 1, 2);
+        membership.vacation_end = NaiveDate::from_ymd_opt(2024, 1, 3);
+        assert_eq!(calculate_member_streak(&pool, &task_id, &membership).await.unwrap(), 2);
+    }
+
     #[tokio::test]
     async fn test_calculate_best_streak() {
         let pool = setup_test_db().await;
@@ -836,4 +968,43 @@ mod tests {
         let streak = calculate_best_streak(&pool, &task_id).await.unwrap();
         assert_eq!(streak, 5);
     }
+
+    #[tokio::test]
+    async fn test_apply_streak_freeze_repairs_missed_period() {
+        use crate::test_utils;
+
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user_id = test_utils::create_test_user(&pool, "frozen@test.com", shared::Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &user_id, shared::Role::Member).await;
+        let task_id = test_utils::create_test_task(&pool, &household_id).build().await.id;
+
+        for (day, status) in [(1, PeriodStatus::Completed), (2, PeriodStatus::Failed), (3, PeriodStatus::Completed)] {
+            let date = NaiveDate::from_ymd_opt(2024, 1, day).unwrap();
+            finalize_period(&pool, &task_id, date, date, status, 1, 1, "system", None)
+                .await
+                .unwrap();
+        }
+        let day = |d| NaiveDate::from_ymd_opt(2024, 1, d).unwrap();
+
+        // Without a freeze nothing changes
+        assert!(matches!(
+            apply_streak_freeze(&pool, &household_id, &task_id, &user_id, day(2), false).await,
+            Err(StreakFreezeError::NoneAvailable)
+        ));
+        assert_eq!(calculate_current_streak(&pool, &task_id).await.unwrap(), 1);
+
+        crate::services::households::update_member_points(&pool, &household_id, &user_id, 10).await.unwrap();
+        points::purchase_streak_freeze(&pool, &household_id, &user_id, 10).await.unwrap();
+        assert!(matches!(
+            apply_streak_freeze(&pool, &household_id, &task_id, &user_id, day(3), false).await,
+            Err(StreakFreezeError::NotMissed)
+        ));
+
+        let result = apply_streak_freeze(&pool, &household_id, &task_id, &user_id, day(2), false).await.unwrap();
+        assert_eq!(result.status, PeriodStatus::Skipped);
+        assert_eq!(result.finalized_by, user_id.to_string());
+        assert_eq!(calculate_current_streak(&pool, &task_id).await.unwrap(), 2);
+        assert_eq!(points::available_streak_freezes(&pool, &household_id, &user_id).await.unwrap(), 0);
+    }
 }
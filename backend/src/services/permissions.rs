@@ -0,0 +1,194 @@
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::services::household_settings;
+use shared::{HouseholdSettings, MemberPermissions, Permission, Role};
+
+#[derive(Debug, Error)]
+pub enum PermissionsError {
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+    #[error("Invalid permissions data: {0}")]
+    InvalidData(#[from] serde_json::Error),
+    #[error("Membership not found")]
+    NotFound,
+}
+
+/// Combine a member's role, their stored matrix and the household settings.
+///
+/// Owners always hold every permission. In Solo Mode nobody may manage tasks,
+/// rewards or reviews, whatever their matrix says.
+pub fn effective_permissions(
+    role: &Role,
+    stored: Option<MemberPermissions>,
+    settings: &HouseholdSettings,
+) -> MemberPermissions {
+    let mut permissions = match (role, stored) {
+        (Role::Owner, _) | (_, None) => MemberPermissions::for_role(role, &settings.hierarchy_type),
+        (_, Some(stored)) => stored,
+    };
+    if settings.solo_mode {
+        permissions.manage_tasks = false;
+        permissions.manage_rewards = false;
+        permissions.review_completions = false;
+    }
+    permissions
+}
+
+/// Load a member's role and custom matrix; `None` when the user is not a member
+async fn load_membership(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+) -> Result<Option<(Role, Option<MemberPermissions>)>, PermissionsError> {
+    let row: Option<(String, Option<String>)> = sqlx::query_as(
+        "SELECT role, permissions FROM household_memberships WHERE household_id = ? AND user_id = ?",
+    )
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .fetch_optional(pool)
+    .await?;
+
+    let Some((role, permissions)) = row else {
+        return Ok(None);
+    };
+    let role = role.parse().unwrap_or(Role::Member);
+    let permissions = permissions.map(|p| serde_json::from_str(&p)).transpose()?;
+    Ok(Some((role, permissions)))
+}
+
+/// Get a member's effective permissions and whether a custom matrix is stored.
+/// Returns `None` when the user is not a member of the household.
+pub async fn get_member_permissions(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+) -> Result<Option<(MemberPermissions, bool)>, PermissionsError> {
+    let Some((role, stored)) = load_membership(pool, household_id, user_id).await? else {
+        return Ok(None);
+    };
+    let settings = household_settings::get_or_create_settings(pool, household_id)
+        .await
+        .unwrap_or_default();
+    let custom = stored.is_some() && role != Role::Owner;
+    Ok(Some((effective_permissions(&role, stored, &settings), custom)))
+}
+
+/// Authorization check shared by all handlers: does the user hold `permission`
+/// in the household? Non-members and lookup failures are denied.
+pub async fn has_permission(pool: &SqlitePool, household_id: &Uuid, user_id: &Uuid, permission: Permission) -> bool {
+    match get_member_permissions(pool, household_id, user_id).await {
+        Ok(Some((permissions, _))) => permissions.has(permission),
+        Ok(None) => false,
+        Err(e) => {
+            log::error!("Error checking permissions: {:?}", e);
+            false
+        }
+    }
+}
+
+/// Whether the user holds the review permission, for checks that also admit
+/// members a task's review was delegated to. `None` when the user is not a
+/// member or reviews are off entirely (Solo Mode).
+pub async fn review_permission(pool: &SqlitePool, household_id: &Uuid, user_id: &Uuid) -> Option<bool> {
+    let (role, stored) = match load_membership(pool, household_id, user_id).await {
+        Ok(membership) => membership?,
+        Err(e) => {
+            log::error!("Error checking permissions: {:?}", e);
+            return None;
+        }
+    };
+    let settings = household_settings::get_or_create_settings(pool, household_id)
+        .await
+        .unwrap_or_default();
+    if settings.solo_mode {
+        return None;
+    }
+    Some(effective_permissions(&role, stored, &settings).review_completions)
+}
+
+/// Store a custom matrix for a member, or restore the role defaults with `None`
+pub async fn set_member_permissions(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    permissions: Option<&MemberPermissions>,
+) -> Result<(), PermissionsError> {
+    let json = permissions.map(serde_json::to_string).transpose()?;
+    let result = sqlx::query("UPDATE household_memberships SET permissions = ? WHERE household_id = ? AND user_id = ?")
+        .bind(json)
+        .bind(household_id.to_string())
+        .bind(user_id.to_string())
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(PermissionsError::NotFound);
+    }
+    Ok(())
+}
+
+/// Comma-separated list of granted permissions for audit entries
+pub fn describe(permissions: &MemberPermissions) -> String {
+    let granted: Vec<&str> = permissions.granted().iter().map(|p| p.as_str()).collect();
+    if granted.is_empty() {
+        "none".to_string()
+    } else {
+        granted.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn test_effective_permissions() {
+        let settings = HouseholdSettings::default();
+        let teen = MemberPermissions::teen();
+
+        assert_eq!(effective_permissions(&Role::Member, None, &settings), MemberPermissions::default());
+        assert_eq!(effective_permissions(&Role::Member, Some(teen), &settings), teen);
+        assert_eq!(effective_permissions(&Role::Owner, Some(teen), &settings), MemberPermissions::all());
+
+        let solo = HouseholdSettings { solo_mode: true, ..HouseholdSettings::default() };
+        let permissions = effective_permissions(&Role::Admin, None, &solo);
+        assert!(!permissions.manage_tasks);
+        assert!(!permissions.review_completions);
+        assert!(permissions.adjust_points);
+    }
+
+    #[tokio::test]
+    async fn test_teen_can_manage_tasks_but_not_points() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let teen = test_utils::create_test_user(&pool, "teen@test.com", Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &teen, Role::Member).await;
+
+        assert!(!has_permission(&pool, &household_id, &teen, Permission::ManageTasks).await);
+
+        set_member_permissions(&pool, &household_id, &teen, Some(&MemberPermissions::teen())).await.unwrap();
+        assert!(has_permission(&pool, &household_id, &teen, Permission::ManageTasks).await);
+        assert!(!has_permission(&pool, &household_id, &teen, Permission::AdjustPoints).await);
+        let (_, custom) = get_member_permissions(&pool, &household_id, &teen).await.unwrap().unwrap();
+        assert!(custom);
+
+        set_member_permissions(&pool, &household_id, &teen, None).await.unwrap();
+        assert!(!has_permission(&pool, &household_id, &teen, Permission::ManageTasks).await);
+    }
+
+    #[tokio::test]
+    async fn test_non_member_has_no_permissions() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let stranger = test_utils::create_test_user(&pool, "stranger@test.com", Role::Member).await;
+
+        assert!(!has_permission(&pool, &household_id, &stranger, Permission::ManageTasks).await);
+        assert!(matches!(
+            set_member_permissions(&pool, &household_id, &stranger, None).await,
+            Err(PermissionsError::NotFound)
+        ));
+    }
+}
@@ -0,0 +1,454 @@
+//! Pets with feeding and medication schedules. Each schedule is a daily task whose
+//! target is the number of times per day, so completions earn points like any chore.
+//! Every completion is also written to a care log the whole household can see.
+
+use chrono::{DateTime, Duration, Utc};
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::{PetCareEntryRow, PetRow};
+use crate::services::tasks as task_service;
+use shared::{
+    CreatePetRequest, CreatePetScheduleRequest, CreateTaskRequest, Pet, PetCareEntry, PetCareKind, PetSchedule,
+    RecurrenceType,
+};
+
+#[derive(Debug, Error)]
+pub enum PetError {
+    #[error("Pet not found")]
+    NotFound,
+    #[error("Schedule not found")]
+    ScheduleNotFound,
+    #[error("A schedule needs to happen at least once a day")]
+    InvalidTimesPerDay,
+    #[error("Medication schedules need a title")]
+    MissingTitle,
+    #[error("The assigned user is not a member of this household")]
+    InvalidAssignee,
+    #[error("{title} was already logged by {username} {minutes} minutes ago")]
+    RecentlyLogged { title: String, username: String, minutes: i64 },
+    #[error("Task error: {0}")]
+    TaskError(#[from] task_service::TaskError),
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+const ENTRY_SELECT: &str = r#"
+    SELECT l.id, l.pet_id, l.task_id, l.kind, l.title, l.user_id, u.username, l.logged_at
+    FROM pet_care_log l
+    JOIN users u ON l.user_id = u.id
+"#;
+
+fn clean_text(value: &Option<String>) -> Option<String> {
+    value
+        .as_ref()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+async fn get_row(pool: &SqlitePool, household_id: &Uuid, pet_id: &Uuid) -> Result<PetRow, PetError> {
+    sqlx::query_as("SELECT * FROM pets WHERE id = ? AND household_id = ?")
+        .bind(pet_id.to_string())
+        .bind(household_id.to_string())
+        .fetch_optional(pool)
+        .await?
+        .ok_or(PetError::NotFound)
+}
+
+/// Schedules of a pet; tasks in the trash are left out
+async fn list_schedules(pool: &SqlitePool, pet_id: &str) -> Result<Vec<PetSchedule>, PetError> {
+    let rows: Vec<(String, String, String, i32)> = sqlx::query_as(
+        r#"
+        SELECT t.id, s.kind, t.title, s.times_per_day
+        FROM pet_schedules s
+        JOIN tasks t ON s.task_id = t.id
+        WHERE s.pet_id = ? AND t.deleted_at IS NULL
+        ORDER BY s.kind ASC, t.title COLLATE NOCASE ASC
+        "#,
+    )
+    .bind(pet_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|(task_id, kind, title, times_per_day)| {
+            Some(PetSchedule {
+                task_id: Uuid::parse_str(&task_id).ok()?,
+                kind: kind.parse().ok()?,
+                title,
+                times_per_day,
+            })
+        })
+        .collect())
+}
+
+async fn with_details(pool: &SqlitePool, row: &PetRow) -> Result<Pet, PetError> {
+    let mut pet = row.to_shared();
+    pet.schedules = list_schedules(pool, &row.id).await?;
+    pet.recent_log = list_log(pool, &pet.id, Some(Utc::now() - Duration::hours(24)), 100).await?;
+    Ok(pet)
+}
+
+pub async fn list_pets(pool: &SqlitePool, household_id: &Uuid) -> Result<Vec<Pet>, PetError> {
+    let rows: Vec<PetRow> = sqlx::query_as("SELECT * FROM pets WHERE household_id = ? ORDER BY name COLLATE NOCASE ASC")
+        .bind(household_id.to_string())
+        .fetch_all(pool)
+        .await?;
+
+    let mut pets = Vec::with_capacity(rows.len());
+    for row in &rows {
+        pets.push(with_details(pool, row).await?);
+    }
+    Ok(pets)
+}
+
+pub async fn get_pet(pool: &SqlitePool, household_id: &Uuid, pet_id: &Uuid) -> Result<Pet, PetError> {
+    let row = get_row(pool, household_id, pet_id).await?;
+    with_details(pool, &row).await
+}
+
+pub async fn create_pet(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    request: &CreatePetRequest,
+) -> Result<Pet, PetError> {
+    let now = Utc::now();
+    let row = PetRow {
+        id: Uuid::new_v4().to_string(),
+        household_id: household_id.to_string(),
+        name: request.name.trim().to_string(),
+        species: clean_text(&request.species),
+        notes: clean_text(&request.notes),
+        created_by: user_id.to_string(),
+        created_at: now,
+        updated_at: now,
+    };
+
+    sqlx::query(
+        r#"
+        INSERT INTO pets (id, household_id, name, species, notes, created_by, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(&row.id)
+    .bind(&row.household_id)
+    .bind(&row.name)
+    .bind(&row.species)
+    .bind(&row.notes)
+    .bind(&row.created_by)
+    .bind(now)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    Ok(row.to_shared())
+}
+
+/// Delete a pet and move its schedule tasks to the trash
+pub async fn delete_pet(pool: &SqlitePool, household_id: &Uuid, pet_id: &Uuid) -> Result<(), PetError> {
+    let row = get_row(pool, household_id, pet_id).await?;
+
+    for schedule in list_schedules(pool, &row.id).await? {
+        task_service::delete_task(pool, &schedule.task_id).await?;
+    }
+    sqlx::query("DELETE FROM pets WHERE id = ?")
+        .bind(&row.id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Add a feeding or medication schedule as a daily task
+pub async fn add_schedule(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    pet_id: &Uuid,
+    request: &CreatePetScheduleRequest,
+) -> Result<Pet, PetError> {
+    let row = get_row(pool, household_id, pet_id).await?;
+
+    if request.times_per_day < 1 {
+        return Err(PetError::InvalidTimesPerDay);
+    }
+    let title = match (request.kind, clean_text(&request.title)) {
+        (_, Some(title)) => format!("{}: {}", row.name, title),
+        (PetCareKind::Feeding, None) => format!("Feed {}", row.name),
+        (PetCareKind::Medication, None) => return Err(PetError::MissingTitle),
+    };
+    if let Some(assignee) = request.assigned_user_id {
+        let is_member: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM household_memberships WHERE household_id = ? AND user_id = ?)",
+        )
+        .bind(household_id.to_string())
+        .bind(assignee.to_string())
+        .fetch_one(pool)
+        .await?;
+        if !is_member {
+            return Err(PetError::InvalidAssignee);
+        }
+    }
+
+    let task_request = CreateTaskRequest {
+        title,
+        description: None,
+        recurrence_type: RecurrenceType::Daily,
+        recurrence_value: None,
+        assigned_user_id: request.assigned_user_id,
+        assignee_ids: None,
+        target_count: Some(i32::from(request.times_per_day)),
+        time_period: None,
+        allow_exceed_target: Some(false),
+        requires_review: None,
+        reviewer_type: None,
+        reviewer_id: None,
+        points_reward: None,
+        points_penalty: None,
+        due_time: None,
+        effort_minutes: None,
+        active_from: None,
+        active_until: None,
+        ends_on: None,
+        max_occurrences: None,
+        habit_type: None,
+        category_id: None,
+        tag_ids: None,
+        is_suggestion: None,
+    };
+    let task = task_service::create_task(pool, household_id, &task_request, None).await?;
+
+    sqlx::query("INSERT INTO pet_schedules (pet_id, task_id, kind, times_per_day) VALUES (?, ?, ?, ?)")
+        .bind(&row.id)
+        .bind(task.id.to_string())
+        .bind(request.kind.as_str())
+        .bind(i32::from(request.times_per_day))
+        .execute(pool)
+        .await?;
+
+    with_details(pool, &row).await
+}
+
+/// Stop a schedule; its task goes to the trash, the log is kept
+pub async fn remove_schedule(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    pet_id: &Uuid,
+    task_id: &Uuid,
+) -> Result<Pet, PetError> {
+    let row = get_row(pool, household_id, pet_id).await?;
+
+    let result = sqlx::query("DELETE FROM pet_schedules WHERE pet_id = ? AND task_id = ?")
+        .bind(&row.id)
+        .bind(task_id.to_string())
+        .execute(pool)
+        .await?;
+    if result.rows_affected() == 0 {
+        return Err(PetError::ScheduleNotFound);
+    }
+    task_service::delete_task(pool, task_id).await?;
+
+    with_details(pool, &row).await
+}
+
+/// Care logged for a pet, newest first
+pub async fn list_log(
+    pool: &SqlitePool,
+    pet_id: &Uuid,
+    since: Option<DateTime<Utc>>,
+    limit: i64,
+) -> Result<Vec<PetCareEntry>, PetError> {
+    let sql = format!(
+        "{} WHERE l.pet_id = ? AND (? IS NULL OR l.logged_at >= ?) ORDER BY l.logged_at DESC LIMIT ?",
+        ENTRY_SELECT
+    );
+    let rows: Vec<PetCareEntryRow> = sqlx::query_as(&sql)
+        .bind(pet_id.to_string())
+        .bind(since)
+        .bind(since)
+        .bind(limit)
+        .fetch_all(pool)
+        .await?;
+
+    Ok(rows.iter().map(|r| r.to_shared()).collect())
+}
+
+/// The most recent entry of a schedule, e.g. to broadcast it after a completion
+pub async fn latest_entry(pool: &SqlitePool, task_id: &Uuid) -> Result<Option<PetCareEntry>, sqlx::Error> {
+    let sql = format!("{} WHERE l.task_id = ? ORDER BY l.logged_at DESC LIMIT 1", ENTRY_SELECT);
+    let row: Option<PetCareEntryRow> = sqlx::query_as(&sql)
+        .bind(task_id.to_string())
+        .fetch_optional(pool)
+        .await?;
+    Ok(row.map(|r| r.to_shared()))
+}
+
+/// Feed the pet (or give its medication) by completing the schedule's task.
+/// Unless forced, this is refused when someone already did it only a moment ago.
+pub async fn log_care(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    pet_id: &Uuid,
+    task_id: &Uuid,
+    user_id: &Uuid,
+    force: bool,
+) -> Result<PetCareEntry, PetError> {
+    let row = get_row(pool, household_id, pet_id).await?;
+    let schedule = list_schedules(pool, &row.id)
+        .await?
+        .into_iter()
+        .find(|s| s.task_id == *task_id)
+        .ok_or(PetError::ScheduleNotFound)?;
+
+    if !force {
+        if let Some(last) = latest_entry(pool, task_id).await? {
+            let since = Utc::now() - last.logged_at;
+            if since < schedule.min_gap() {
+                return Err(PetError::RecentlyLogged {
+                    title: schedule.title,
+                    username: last.username,
+                    minutes: since.num_minutes(),
+                });
+            }
+        }
+    }
+
+    task_service::complete_task(pool, task_id, user_id, household_id).await?;
+    latest_entry(pool, task_id).await?.ok_or(PetError::ScheduleNotFound)
+}
+
+/// Called when a task is completed: if it is a pet's schedule, log who did it
+pub async fn record_care(
+    pool: &SqlitePool,
+    task_id: &Uuid,
+    user_id: &Uuid,
+    at: DateTime<Utc>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO pet_care_log (id, pet_id, task_id, kind, title, user_id, logged_at)
+        SELECT ?, s.pet_id, s.task_id, s.kind, t.title, ?, ?
+        FROM pet_schedules s
+        JOIN tasks t ON s.task_id = t.id
+        WHERE s.task_id = ?
+        "#,
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(user_id.to_string())
+    .bind(at)
+    .bind(task_id.to_string())
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Called when a completion is undone: drop the member's latest entry for the schedule
+pub async fn undo_care(pool: &SqlitePool, task_id: &Uuid, user_id: &Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        DELETE FROM pet_care_log
+        WHERE id = (
+            SELECT id FROM pet_care_log
+            WHERE task_id = ? AND user_id = ?
+            ORDER BY logged_at DESC
+            LIMIT 1
+        )
+        "#,
+    )
+    .bind(task_id.to_string())
+    .bind(user_id.to_string())
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use shared::Role;
+
+    fn bello() -> CreatePetRequest {
+        CreatePetRequest {
+            name: "Bello".to_string(),
+            species: Some("Dog".to_string()),
+            notes: Some(" ".to_string()),
+        }
+    }
+
+    fn feeding(times_per_day: u16) -> CreatePetScheduleRequest {
+        CreatePetScheduleRequest {
+            kind: PetCareKind::Feeding,
+            title: None,
+            times_per_day,
+            assigned_user_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_schedules_are_daily_tasks() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user = test_utils::create_test_user(&pool, "walker@test.com", Role::Member).await;
+
+        let pet = create_pet(&pool, &household_id, &user, &bello()).await.unwrap();
+        assert_eq!(pet.notes, None);
+
+        let with_feeding = add_schedule(&pool, &household_id, &pet.id, &feeding(2)).await.unwrap();
+        let schedule = &with_feeding.schedules[0];
+        assert_eq!(schedule.title, "Feed Bello");
+        let task = task_service::get_task(&pool, &schedule.task_id).await.unwrap().unwrap();
+        assert_eq!(task.recurrence_type, RecurrenceType::Daily);
+        assert_eq!(task.target_count, 2);
+
+        assert!(matches!(
+            add_schedule(&pool, &household_id, &pet.id, &feeding(0)).await,
+            Err(PetError::InvalidTimesPerDay)
+        ));
+        let medication = CreatePetScheduleRequest {
+            kind: PetCareKind::Medication,
+            ..feeding(1)
+        };
+        assert!(matches!(
+            add_schedule(&pool, &household_id, &pet.id, &medication).await,
+            Err(PetError::MissingTitle)
+        ));
+
+        delete_pet(&pool, &household_id, &pet.id).await.unwrap();
+        assert!(task_service::get_task(&pool, &schedule.task_id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_log_care_prevents_double_feeding() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let alice = test_utils::create_test_user(&pool, "alice@test.com", Role::Member).await;
+        let bob = test_utils::create_test_user(&pool, "bob@test.com", Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &alice, Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &bob, Role::Member).await;
+
+        let pet = create_pet(&pool, &household_id, &alice, &bello()).await.unwrap();
+        let pet = add_schedule(&pool, &household_id, &pet.id, &feeding(2)).await.unwrap();
+        let task_id = pet.schedules[0].task_id;
+
+        let entry = log_care(&pool, &household_id, &pet.id, &task_id, &alice, false).await.unwrap();
+        assert_eq!(entry.user_id, alice);
+        assert_eq!(entry.title, "Feed Bello");
+
+        // Bob doesn't know Alice just fed him
+        assert!(matches!(
+            log_care(&pool, &household_id, &pet.id, &task_id, &bob, false).await,
+            Err(PetError::RecentlyLogged { .. })
+        ));
+        log_care(&pool, &household_id, &pet.id, &task_id, &bob, true).await.unwrap();
+        assert_eq!(get_pet(&pool, &household_id, &pet.id).await.unwrap().recent_log.len(), 2);
+
+        // Undoing the completion removes the entry
+        task_service::uncomplete_task(&pool, &task_id, &bob).await.unwrap();
+        let log = list_log(&pool, &pet.id, None, 10).await.unwrap();
+        assert_eq!(log.len(), 1);
+        assert_eq!(log[0].user_id, alice);
+    }
+}
@@ -0,0 +1,396 @@
+//! Plants get their own every-N-days watering and fertilizing tasks, so each
+//! plant keeps its own completion history. Completing one of these tasks
+//! records the care time on the plant.
+
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::PlantRow;
+use crate::services::tasks as task_service;
+use shared::{
+    CreatePlantRequest, CreateTaskRequest, Plant, RecurrenceType, RecurrenceValue, UpdatePlantRequest,
+    UpdateTaskRequest,
+};
+
+#[derive(Debug, Error)]
+pub enum PlantError {
+    #[error("Plant not found")]
+    NotFound,
+    #[error("Care intervals must be at least one day")]
+    InvalidInterval,
+    #[error("Task error: {0}")]
+    TaskError(#[from] task_service::TaskError),
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+/// Which care task of a plant
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Care {
+    Water,
+    Fertilize,
+}
+
+impl Care {
+    fn task_title(&self, plant_name: &str) -> String {
+        match self {
+            Care::Water => format!("Water {}", plant_name),
+            Care::Fertilize => format!("Fertilize {}", plant_name),
+        }
+    }
+}
+
+fn clean_text(value: &Option<String>) -> Option<String> {
+    value
+        .as_ref()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+fn valid_interval(interval: Option<u16>) -> bool {
+    interval.is_none_or(|days| days >= 1)
+}
+
+async fn get_row(pool: &SqlitePool, household_id: &Uuid, plant_id: &Uuid) -> Result<PlantRow, PlantError> {
+    sqlx::query_as("SELECT * FROM plants WHERE id = ? AND household_id = ?")
+        .bind(plant_id.to_string())
+        .bind(household_id.to_string())
+        .fetch_optional(pool)
+        .await?
+        .ok_or(PlantError::NotFound)
+}
+
+/// Create, update or remove the care task so it matches the interval.
+/// Returns the id of the task that is left, if any.
+async fn sync_care_task(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    task_id: Option<&str>,
+    care: Care,
+    plant_name: &str,
+    interval: Option<u16>,
+) -> Result<Option<String>, PlantError> {
+    let task_id = task_id.and_then(|id| Uuid::parse_str(id).ok());
+
+    match (task_id, interval) {
+        (Some(task_id), Some(days)) => {
+            let request = UpdateTaskRequest {
+                title: Some(care.task_title(plant_name)),
+                recurrence_type: Some(RecurrenceType::Daily),
+                recurrence_value: Some(RecurrenceValue::DayInterval(days)),
+                ..Default::default()
+            };
+            match task_service::update_task(pool, &task_id, &request).await {
+                Ok(task) => Ok(Some(task.id.to_string())),
+                // The task was deleted by hand; generate a new one
+                Err(task_service::TaskError::NotFound) => {
+                    Box::pin(sync_care_task(pool, household_id, None, care, plant_name, interval)).await
+                }
+                Err(e) => Err(e.into()),
+            }
+        }
+        (None, Some(days)) => {
+            let request = CreateTaskRequest {
+                title: care.task_title(plant_name),
+                description: None,
+                recurrence_type: RecurrenceType::Daily,
+                recurrence_value: Some(RecurrenceValue::DayInterval(days)),
+                assigned_user_id: None,
+                assignee_ids: None,
+                target_count: None,
+                time_period: None,
+                allow_exceed_target: None,
+                requires_review: None,
+                reviewer_type: None,
+                reviewer_id: None,
+                points_reward: None,
+                points_penalty: None,
+                due_time: None,
+                effort_minutes: None,
+                active_from: None,
+                active_until: None,
+                ends_on: None,
+                max_occurrences: None,
+                habit_type: None,
+                category_id: None,
+                tag_ids: None,
+                is_suggestion: None,
+            };
+            let task = task_service::create_task(pool, household_id, &request, None).await?;
+            Ok(Some(task.id.to_string()))
+        }
+        (Some(task_id), None) => {
+            match task_service::delete_task(pool, &task_id).await {
+                Ok(()) | Err(task_service::TaskError::NotFound) => Ok(None),
+                Err(e) => Err(e.into()),
+            }
+        }
+        (None, None) => Ok(None),
+    }
+}
+
+async fn save_row(pool: &SqlitePool, row: &PlantRow) -> Result<(), PlantError> {
+    sqlx::query(
+        r#"
+        UPDATE plants
+        SET name = ?, location = ?, water_interval_days = ?, fertilize_interval_days = ?,
+            water_task_id = ?, fertilize_task_id = ?, updated_at = ?
+        WHERE id = ?
+        "#,
+    )
+    .bind(&row.name)
+    .bind(&row.location)
+    .bind(row.water_interval_days)
+    .bind(row.fertilize_interval_days)
+    .bind(&row.water_task_id)
+    .bind(&row.fertilize_task_id)
+    .bind(row.updated_at)
+    .bind(&row.id)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+pub async fn list_plants(pool: &SqlitePool, household_id: &Uuid) -> Result<Vec<Plant>, PlantError> {
+    let rows: Vec<PlantRow> =
+        sqlx::query_as("SELECT * FROM plants WHERE household_id = ? ORDER BY name COLLATE NOCASE ASC")
+            .bind(household_id.to_string())
+            .fetch_all(pool)
+            .await?;
+
+    Ok(rows.iter().map(|r| r.to_shared()).collect())
+}
+
+pub async fn get_plant(pool: &SqlitePool, household_id: &Uuid, plant_id: &Uuid) -> Result<Plant, PlantError> {
+    Ok(get_row(pool, household_id, plant_id).await?.to_shared())
+}
+
+pub async fn create_plant(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    request: &CreatePlantRequest,
+) -> Result<Plant, PlantError> {
+    if !valid_interval(request.water_interval_days) || !valid_interval(request.fertilize_interval_days) {
+        return Err(PlantError::InvalidInterval);
+    }
+
+    let name = request.name.trim().to_string();
+    let water_task_id =
+        sync_care_task(pool, household_id, None, Care::Water, &name, request.water_interval_days).await?;
+    let fertilize_task_id =
+        sync_care_task(pool, household_id, None, Care::Fertilize, &name, request.fertilize_interval_days).await?;
+
+    let now = Utc::now();
+    let row = PlantRow {
+        id: Uuid::new_v4().to_string(),
+        household_id: household_id.to_string(),
+        name,
+        location: clean_text(&request.location),
+        water_interval_days: request.water_interval_days.map(i32::from),
+        fertilize_interval_days: request.fertilize_interval_days.map(i32::from),
+        water_task_id,
+        fertilize_task_id,
+        last_watered_at: None,
+        last_fertilized_at: None,
+        created_by: user_id.to_string(),
+        created_at: now,
+        updated_at: now,
+    };
+
+    sqlx::query(
+        r#"
+        INSERT INTO plants (id, household_id, name, location, water_interval_days, fertilize_interval_days,
+            water_task_id, fertilize_task_id, created_by, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(&row.id)
+    .bind(&row.household_id)
+    .bind(&row.name)
+    .bind(&row.location)
+    .bind(row.water_interval_days)
+    .bind(row.fertilize_interval_days)
+    .bind(&row.water_task_id)
+    .bind(&row.fertilize_task_id)
+    .bind(&row.created_by)
+    .bind(now)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    Ok(row.to_shared())
+}
+
+/// Update a plant; renaming it or changing an interval updates its care tasks
+pub async fn update_plant(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    plant_id: &Uuid,
+    request: &UpdatePlantRequest,
+) -> Result<Plant, PlantError> {
+    if !valid_interval(request.water_interval_days.flatten()) || !valid_interval(request.fertilize_interval_days.flatten()) {
+        return Err(PlantError::InvalidInterval);
+    }
+
+    let mut row = get_row(pool, household_id, plant_id).await?;
+    let renamed = request.name.as_ref().is_some_and(|n| n.trim() != row.name);
+
+    if let Some(ref name) = request.name {
+        row.name = name.trim().to_string();
+    }
+    if request.location.is_some() {
+        row.location = clean_text(&request.location);
+    }
+
+    if renamed || request.water_interval_days.is_some() {
+        let interval = match request.water_interval_days {
+            Some(interval) => interval,
+            None => row.water_interval_days.and_then(|d| u16::try_from(d).ok()),
+        };
+        row.water_task_id =
+            sync_care_task(pool, household_id, row.water_task_id.as_deref(), Care::Water, &row.name, interval).await?;
+        row.water_interval_days = interval.map(i32::from);
+    }
+    if renamed || request.fertilize_interval_days.is_some() {
+        let interval = match request.fertilize_interval_days {
+            Some(interval) => interval,
+            None => row.fertilize_interval_days.and_then(|d| u16::try_from(d).ok()),
+        };
+        row.fertilize_task_id = sync_care_task(
+            pool,
+            household_id,
+            row.fertilize_task_id.as_deref(),
+            Care::Fertilize,
+            &row.name,
+            interval,
+        )
+        .await?;
+        row.fertilize_interval_days = interval.map(i32::from);
+    }
+
+    row.updated_at = Utc::now();
+    save_row(pool, &row).await?;
+    Ok(row.to_shared())
+}
+
+/// Delete a plant and move its care tasks to the trash
+pub async fn delete_plant(pool: &SqlitePool, household_id: &Uuid, plant_id: &Uuid) -> Result<(), PlantError> {
+    let row = get_row(pool, household_id, plant_id).await?;
+
+    sync_care_task(pool, household_id, row.water_task_id.as_deref(), Care::Water, &row.name, None).await?;
+    sync_care_task(pool, household_id, row.fertilize_task_id.as_deref(), Care::Fertilize, &row.name, None).await?;
+
+    sqlx::query("DELETE FROM plants WHERE id = ?")
+        .bind(&row.id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Called when a task is completed: if it is a plant's care task, remember when
+pub async fn record_care(pool: &SqlitePool, task_id: &Uuid, at: DateTime<Utc>) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE plants SET last_watered_at = ? WHERE water_task_id = ?")
+        .bind(at)
+        .bind(task_id.to_string())
+        .execute(pool)
+        .await?;
+    sqlx::query("UPDATE plants SET last_fertilized_at = ? WHERE fertilize_task_id = ?")
+        .bind(at)
+        .bind(task_id.to_string())
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use shared::Role;
+
+    fn monstera(water: Option<u16>, fertilize: Option<u16>) -> CreatePlantRequest {
+        CreatePlantRequest {
+            name: "Monstera".to_string(),
+            location: Some("Living room".to_string()),
+            water_interval_days: water,
+            fertilize_interval_days: fertilize,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_plant_generates_care_tasks() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user = test_utils::create_test_user(&pool, "gardener@test.com", Role::Member).await;
+
+        let plant = create_plant(&pool, &household_id, &user, &monstera(Some(7), None)).await.unwrap();
+        assert_eq!(plant.fertilize_task_id, None);
+        let water_task = task_service::get_task(&pool, &plant.water_task_id.unwrap()).await.unwrap().unwrap();
+        assert_eq!(water_task.title, "Water Monstera");
+        assert_eq!(water_task.recurrence_value, Some(RecurrenceValue::DayInterval(7)));
+
+        assert!(matches!(
+            create_plant(&pool, &household_id, &user, &monstera(Some(0), None)).await,
+            Err(PlantError::InvalidInterval)
+        ));
+
+        // Renaming renames the task; a new interval adds a fertilizing task
+        let updated = update_plant(
+            &pool,
+            &household_id,
+            &plant.id,
+            &UpdatePlantRequest {
+                name: Some("Swiss cheese plant".to_string()),
+                fertilize_interval_days: Some(Some(30)),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(updated.water_task_id, plant.water_task_id);
+        let water_task = task_service::get_task(&pool, &plant.water_task_id.unwrap()).await.unwrap().unwrap();
+        assert_eq!(water_task.title, "Water Swiss cheese plant");
+        let fertilize_task = task_service::get_task(&pool, &updated.fertilize_task_id.unwrap()).await.unwrap().unwrap();
+        assert_eq!(fertilize_task.recurrence_value, Some(RecurrenceValue::DayInterval(30)));
+
+        // Clearing an interval moves its task to the trash
+        let updated = update_plant(
+            &pool,
+            &household_id,
+            &plant.id,
+            &UpdatePlantRequest {
+                water_interval_days: Some(None),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(updated.water_task_id, None);
+        assert_eq!(updated.water_interval_days, None);
+        assert!(task_service::get_task(&pool, &plant.water_task_id.unwrap()).await.unwrap().is_none());
+
+        delete_plant(&pool, &household_id, &plant.id).await.unwrap();
+        assert!(task_service::get_task(&pool, &updated.fertilize_task_id.unwrap()).await.unwrap().is_none());
+        assert!(list_plants(&pool, &household_id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_completing_care_task_records_time() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user = test_utils::create_test_user(&pool, "gardener@test.com", Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &user, Role::Member).await;
+
+        let plant = create_plant(&pool, &household_id, &user, &monstera(Some(3), Some(30))).await.unwrap();
+        assert_eq!(plant.last_watered_at, None);
+
+        task_service::complete_task(&pool, &plant.water_task_id.unwrap(), &user, &household_id).await.unwrap();
+        let watered = get_plant(&pool, &household_id, &plant.id).await.unwrap();
+        assert!(watered.last_watered_at.is_some());
+        assert_eq!(watered.last_fertilized_at, None);
+    }
+}
@@ -0,0 +1,289 @@
+//! Weekly point decay
+//!
+//! Households can let points of inactive members shrink so old, hoarded
+//! points do not dominate the leaderboard forever. At the start of each
+//! household week every member who completed no task in the week that just
+//! ended loses a percentage or a fixed number of points, as configured by
+//! `HouseholdSettings::point_decay_mode` and `point_decay_amount`.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::SqlitePool;
+use std::collections::HashSet;
+use std::str::FromStr;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::services::points::{self, TransactionSource};
+use crate::services::{activity_logs, scheduler, statistics};
+use shared::{ActivityType, PointDecayMode, PointTransactionType};
+
+#[derive(Debug, Error)]
+pub enum PointDecayError {
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("Points error: {0}")]
+    Points(#[from] points::PointsError),
+}
+
+/// Report from applying point decay
+#[derive(Debug, Clone, Default)]
+pub struct PointDecayReport {
+    pub households_checked: u32,
+    pub weeks_processed: u32,
+    pub members_decayed: u32,
+    pub points_removed: i64,
+}
+
+/// Start of the last complete household week at local date `today`
+pub fn decay_week(today: NaiveDate, week_start_day: i32) -> NaiveDate {
+    statistics::get_week_start(today, week_start_day) - chrono::Duration::days(7)
+}
+
+/// Apply decay for the last complete week of every household with decay enabled.
+/// Each household week is decayed once; members who joined during that week,
+/// and households on vacation, are left alone.
+pub async fn process_point_decay(pool: &SqlitePool, now: DateTime<Utc>) -> Result<PointDecayReport, PointDecayError> {
+    let mut report = PointDecayReport::default();
+
+    let households: Vec<(String, String, i32, String, i32, String)> = sqlx::query_as(
+        r#"
+        SELECT s.household_id, s.timezone, s.week_start_day, s.point_decay_mode, s.point_decay_amount, h.owner_id
+        FROM household_settings s
+        JOIN households h ON s.household_id = h.id
+        WHERE s.point_decay_mode != 'none' AND s.point_decay_amount > 0 AND s.vacation_mode = 0
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    for (household_id, timezone, week_start_day, mode, amount, owner_id) in households {
+        report.households_checked += 1;
+
+        let (Ok(household_uuid), Ok(owner_uuid)) = (Uuid::parse_str(&household_id), Uuid::parse_str(&owner_id)) else {
+            continue;
+        };
+        let mode = PointDecayMode::from_str(&mode).unwrap_or_default();
+        let tz = scheduler::parse_timezone(&timezone);
+        let week_start = decay_week(now.with_timezone(&tz).date_naive(), week_start_day);
+
+        // The week claim and all deductions commit together, so a failed run
+        // leaves the week unclaimed and is retried on the next tick
+        let mut tx = pool.begin().await?;
+
+        let claimed = sqlx::query(
+            "INSERT OR IGNORE INTO point_decay_runs (household_id, week_start, created_at) VALUES (?, ?, ?)",
+        )
+        .bind(&household_id)
+        .bind(week_start)
+        .bind(now)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        if claimed == 0 {
+            continue;
+        }
+
+        let from = scheduler::local_midnight_utc(week_start, tz).format("%Y-%m-%d %H:%M:%S").to_string();
+        let until = scheduler::local_midnight_utc(week_start + chrono::Duration::days(7), tz)
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string();
+
+        let active: HashSet<String> = sqlx::query_scalar(
+            r#"
+            SELECT DISTINCT c.user_id
+            FROM task_completions c
+            JOIN tasks t ON c.task_id = t.id
+            WHERE t.household_id = ? AND c.status != 'rejected'
+              AND julianday(c.completed_at) >= julianday(?) AND julianday(c.completed_at) < julianday(?)
+            "#,
+        )
+        .bind(&household_id)
+        .bind(&from)
+        .bind(&until)
+        .fetch_all(&mut *tx)
+        .await?
+        .into_iter()
+        .collect();
+
+        let members: Vec<(String, i64)> = sqlx::query_as(
+            r#"
+            SELECT user_id, points
+            FROM household_memberships
+            WHERE household_id = ? AND points > 0 AND julianday(joined_at) < julianday(?)
+            "#,
+        )
+        .bind(&household_id)
+        .bind(&from)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let mut decayed = Vec::new();
+        for (user_id, balance) in members {
+            if active.contains(&user_id) {
+                continue;
+            }
+            let Ok(user_uuid) = Uuid::parse_str(&user_id) else {
+                continue;
+            };
+            let decay = mode.decay_for(amount, balance);
+            if decay == 0 {
+                continue;
+            }
+
+            points::apply_points_in(
+                &mut tx,
+                &household_uuid,
+                &user_uuid,
+                -decay,
+                PointTransactionType::PointDecay,
+                &TransactionSource {
+                    description: Some(format!("Inactive week of {}", week_start.format("%d.%m.%Y"))),
+                    ..Default::default()
+                },
+            )
+            .await?;
+            decayed.push((user_uuid, decay));
+        }
+
+        tx.commit().await?;
+        report.weeks_processed += 1;
+
+        for (user_uuid, decay) in decayed {
+            let details = serde_json::json!({ "points": -decay, "week_start": week_start }).to_string();
+            let _ = activity_logs::log_activity(
+                pool,
+                &household_uuid,
+                &owner_uuid, // Activity logs need an actor; decay is recorded in the owner's name
+                Some(&user_uuid),
+                ActivityType::PointsDecayed,
+                None,
+                None,
+                Some(&details),
+            )
+            .await;
+
+            report.members_decayed += 1;
+            report.points_removed += decay;
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use crate::test_utils::*;
+    use shared::Role;
+
+    #[test]
+    fn test_decay_week() {
+        // 2024-01-15 is a Monday
+        let monday = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        assert_eq!(decay_week(monday, 0), NaiveDate::from_ymd_opt(2024, 1, 8).unwrap());
+        assert_eq!(
+            decay_week(monday + chrono::Duration::days(6), 0),
+            NaiveDate::from_ymd_opt(2024, 1, 8).unwrap()
+        );
+        // Sunday-based weeks
+        assert_eq!(decay_week(monday, 6), NaiveDate::from_ymd_opt(2024, 1, 7).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_process_point_decay_only_hits_inactive_members_once() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let alice = create_test_user(&pool, "alice@test.com", Role::Member).await;
+        let bob = create_test_user(&pool, "bob@test.com", Role::Member).await;
+        create_test_membership(&pool, &household_id, &alice, Role::Member).await;
+        create_test_membership(&pool, &household_id, &bob, Role::Member).await;
+        sqlx::query("UPDATE household_memberships SET joined_at = '2024-01-01 00:00:00' WHERE household_id = ?")
+            .bind(household_id.to_string())
+            .execute(&pool)
+            .await
+            .unwrap();
+        set_user_points(&pool, &household_id, &alice, 100).await;
+        set_user_points(&pool, &household_id, &bob, 100).await;
+
+        // Alice completed a task during the week of 2024-01-08
+        let task = create_test_task(&pool, &household_id).with_title("Dishes").build().await;
+        sqlx::query(
+            "INSERT INTO task_completions (id, task_id, user_id, completed_at, due_date) VALUES (?, ?, ?, '2024-01-10 12:00:00', '2024-01-10')",
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(task.id.to_string())
+        .bind(alice.to_string())
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let monday = Utc.with_ymd_and_hms(2024, 1, 15, 8, 0, 0).unwrap();
+
+        // Decay disabled: nothing happens
+        let report = process_point_decay(&pool, monday).await.unwrap();
+        assert_eq!(report.households_checked, 0);
+
+        sqlx::query("UPDATE household_settings SET point_decay_mode = 'percent', point_decay_amount = 10 WHERE household_id = ?")
+            .bind(household_id.to_string())
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let report = process_point_decay(&pool, monday).await.unwrap();
+        assert_eq!(report.members_decayed, 1);
+        assert_eq!(report.points_removed, 10);
+
+        assert_points_balance(&pool, &household_id, &alice, 100).await;
+        assert_points_balance(&pool, &household_id, &bob, 90).await;
+        assert_activity_logged(&pool, &household_id, "points_decayed").await;
+
+        // The same week is never decayed twice
+        let report = process_point_decay(&pool, monday + chrono::Duration::hours(5)).await.unwrap();
+        assert_eq!(report.weeks_processed, 0);
+        assert_points_balance(&pool, &household_id, &bob, 90).await;
+    }
+
+    #[tokio::test]
+    async fn test_process_point_decay_failed_run_is_retried() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let alice = create_test_user(&pool, "alice@test.com", Role::Member).await;
+        let bob = create_test_user(&pool, "bob@test.com", Role::Member).await;
+        create_test_membership(&pool, &household_id, &alice, Role::Member).await;
+        create_test_membership(&pool, &household_id, &bob, Role::Member).await;
+        sqlx::query(
+            "UPDATE household_memberships SET joined_at = '2024-01-01 00:00:00', points = 100 WHERE household_id = ?",
+        )
+        .bind(household_id.to_string())
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("UPDATE household_settings SET point_decay_mode = 'fixed', point_decay_amount = 10 WHERE household_id = ?")
+            .bind(household_id.to_string())
+            .execute(&pool)
+            .await
+            .unwrap();
+        let monday = Utc.with_ymd_and_hms(2024, 1, 15, 8, 0, 0).unwrap();
+
+        // Decaying Bob's points fails, whether or not Alice was decayed first
+        sqlx::query(&format!(
+            "CREATE TRIGGER fail_decay BEFORE INSERT ON point_transactions WHEN NEW.user_id = '{}' BEGIN SELECT RAISE(ABORT, 'fail'); END",
+            bob
+        ))
+        .execute(&pool)
+        .await
+        .unwrap();
+        assert!(process_point_decay(&pool, monday).await.is_err());
+        assert_points_balance(&pool, &household_id, &alice, 100).await;
+        assert_points_balance(&pool, &household_id, &bob, 100).await;
+
+        // Nothing was claimed, so the next run decays the week
+        sqlx::query("DROP TRIGGER fail_decay").execute(&pool).await.unwrap();
+        let report = process_point_decay(&pool, monday).await.unwrap();
+        assert_eq!(report.weeks_processed, 1);
+        assert_eq!(report.members_decayed, 2);
+        assert_points_balance(&pool, &household_id, &alice, 90).await;
+        assert_points_balance(&pool, &household_id, &bob, 90).await;
+    }
+}
@@ -0,0 +1,258 @@
+//! Points targets members save up for, with progress taken from the point ledger
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::PointGoalRow;
+use crate::services::households as household_service;
+use shared::{CreatePointGoalRequest, PointGoalProgress, PointGoalStatus};
+
+#[derive(Debug, Error)]
+pub enum PointGoalError {
+    #[error("Goal title cannot be empty")]
+    EmptyTitle,
+    #[error("Target points must be positive")]
+    InvalidTarget,
+    #[error("Deadline cannot be in the past")]
+    DeadlineInPast,
+    #[error("User is not a member of this household")]
+    NotMember,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+/// Start of the day after the deadline; points earned before it count
+fn deadline_end(deadline: NaiveDate) -> DateTime<Utc> {
+    deadline.succ_opt().unwrap_or(deadline).and_hms_opt(0, 0, 0).unwrap().and_utc()
+}
+
+/// Points the member earned between setting the goal and the end of its deadline
+async fn earned_points(pool: &SqlitePool, goal: &PointGoalRow) -> Result<i64, PointGoalError> {
+    let earned: Option<i64> = sqlx::query_scalar(
+        r#"
+        SELECT SUM(amount) FROM point_transactions
+        WHERE household_id = ? AND user_id = ? AND amount > 0 AND created_at >= ? AND created_at < ?
+        "#,
+    )
+    .bind(&goal.household_id)
+    .bind(&goal.user_id)
+    .bind(goal.created_at)
+    .bind(deadline_end(goal.deadline))
+    .fetch_one(pool)
+    .await?;
+
+    Ok(earned.unwrap_or(0))
+}
+
+async fn to_progress(pool: &SqlitePool, goal: PointGoalRow, today: NaiveDate) -> Result<PointGoalProgress, PointGoalError> {
+    let earned_points = earned_points(pool, &goal).await?;
+    let status = if goal.completed_at.is_some() {
+        PointGoalStatus::Completed
+    } else if goal.deadline < today {
+        PointGoalStatus::Expired
+    } else {
+        PointGoalStatus::Active
+    };
+
+    Ok(PointGoalProgress {
+        goal: goal.to_shared(),
+        earned_points,
+        status,
+    })
+}
+
+/// Set a goal for a member, either by themselves or on their behalf
+pub async fn create_goal(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    created_by: &Uuid,
+    request: &CreatePointGoalRequest,
+    today: NaiveDate,
+) -> Result<PointGoalProgress, PointGoalError> {
+    let title = request.title.trim();
+    if title.is_empty() {
+        return Err(PointGoalError::EmptyTitle);
+    }
+    if request.target_points <= 0 {
+        return Err(PointGoalError::InvalidTarget);
+    }
+    if request.deadline < today {
+        return Err(PointGoalError::DeadlineInPast);
+    }
+    if !household_service::is_member(pool, household_id, user_id).await.unwrap_or(false) {
+        return Err(PointGoalError::NotMember);
+    }
+
+    let goal = PointGoalRow {
+        id: Uuid::new_v4().to_string(),
+        household_id: household_id.to_string(),
+        user_id: user_id.to_string(),
+        title: title.to_string(),
+        target_points: request.target_points,
+        deadline: request.deadline,
+        created_by: created_by.to_string(),
+        created_at: Utc::now(),
+        completed_at: None,
+    };
+
+    sqlx::query(
+        r#"
+        INSERT INTO point_goals (id, household_id, user_id, title, target_points, deadline, created_by, created_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(&goal.id)
+    .bind(&goal.household_id)
+    .bind(&goal.user_id)
+    .bind(&goal.title)
+    .bind(goal.target_points)
+    .bind(goal.deadline)
+    .bind(&goal.created_by)
+    .bind(goal.created_at)
+    .execute(pool)
+    .await?;
+
+    to_progress(pool, goal, today).await
+}
+
+/// The member's goals with their progress, nearest deadline first
+pub async fn list_goals(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    today: NaiveDate,
+) -> Result<Vec<PointGoalProgress>, PointGoalError> {
+    let rows: Vec<PointGoalRow> = sqlx::query_as(
+        "SELECT * FROM point_goals WHERE household_id = ? AND user_id = ? ORDER BY deadline ASC, created_at ASC",
+    )
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    let mut goals = Vec::with_capacity(rows.len());
+    for row in rows {
+        goals.push(to_progress(pool, row, today).await?);
+    }
+    Ok(goals)
+}
+
+/// Remove one of the member's goals; returns whether there was one
+pub async fn delete_goal(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    goal_id: &Uuid,
+) -> Result<bool, PointGoalError> {
+    let result = sqlx::query("DELETE FROM point_goals WHERE id = ? AND household_id = ? AND user_id = ?")
+        .bind(goal_id.to_string())
+        .bind(household_id.to_string())
+        .bind(user_id.to_string())
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Recalculate the household's running goals after points changed, completing the ones
+/// whose target is reached. Returns the fresh progress of every goal that was running.
+pub async fn refresh_progress(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    today: NaiveDate,
+) -> Result<Vec<PointGoalProgress>, PointGoalError> {
+    let rows: Vec<PointGoalRow> = sqlx::query_as(
+        "SELECT * FROM point_goals WHERE household_id = ? AND completed_at IS NULL AND deadline >= ?",
+    )
+    .bind(household_id.to_string())
+    .bind(today)
+    .fetch_all(pool)
+    .await?;
+
+    let mut updated = Vec::with_capacity(rows.len());
+    for mut row in rows {
+        if earned_points(pool, &row).await? >= row.target_points {
+            let now = Utc::now();
+            sqlx::query("UPDATE point_goals SET completed_at = ? WHERE id = ?")
+                .bind(now)
+                .bind(&row.id)
+                .execute(pool)
+                .await?;
+            row.completed_at = Some(now);
+        }
+        updated.push(to_progress(pool, row, today).await?);
+    }
+    Ok(updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::points::{self, TransactionSource};
+    use crate::test_utils::{create_test_household, create_test_membership, create_test_pool, create_test_user};
+    use shared::{PointTransactionType, Role};
+
+    #[tokio::test]
+    async fn test_goal_progress_counts_earned_points_only() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let kid = create_test_user(&pool, "kid@test.com", Role::Member).await;
+        create_test_membership(&pool, &household_id, &kid, Role::Member).await;
+        let today = Utc::now().date_naive();
+        let source = TransactionSource::default();
+
+        // Points from before the goal was set don't count
+        points::apply_points(&pool, &household_id, &kid, 40, PointTransactionType::ManualAdjustment, &source)
+            .await
+            .unwrap();
+
+        let request = CreatePointGoalRequest {
+            title: "  Cinema trip ".to_string(),
+            target_points: 100,
+            deadline: today + chrono::Duration::days(7),
+        };
+        let goal = create_goal(&pool, &household_id, &kid, &kid, &request, today).await.unwrap();
+        assert_eq!(goal.goal.title, "Cinema trip");
+        assert_eq!(goal.earned_points, 0);
+        assert_eq!(goal.status, PointGoalStatus::Active);
+
+        points::apply_points(&pool, &household_id, &kid, 60, PointTransactionType::ManualAdjustment, &source)
+            .await
+            .unwrap();
+        // Spending does not undo progress
+        points::apply_points(&pool, &household_id, &kid, -30, PointTransactionType::ManualAdjustment, &source)
+            .await
+            .unwrap();
+        let progress = refresh_progress(&pool, &household_id, today).await.unwrap();
+        assert_eq!(progress.len(), 1);
+        assert_eq!(progress[0].earned_points, 60);
+        assert_eq!(progress[0].status, PointGoalStatus::Active);
+
+        points::apply_points(&pool, &household_id, &kid, 45, PointTransactionType::ManualAdjustment, &source)
+            .await
+            .unwrap();
+        let progress = refresh_progress(&pool, &household_id, today).await.unwrap();
+        assert_eq!(progress[0].status, PointGoalStatus::Completed);
+        assert_eq!(progress[0].percent(), 100);
+        assert!(refresh_progress(&pool, &household_id, today).await.unwrap().is_empty());
+
+        // A goal still missing points once its deadline passed is expired; reached ones stay completed
+        let bike = CreatePointGoalRequest { title: "Bike".to_string(), target_points: 500, ..request.clone() };
+        create_goal(&pool, &household_id, &kid, &kid, &bike, today).await.unwrap();
+        let after_deadline = today + chrono::Duration::days(8);
+        let listed = list_goals(&pool, &household_id, &kid, after_deadline).await.unwrap();
+        let statuses: Vec<_> = listed.iter().map(|g| (g.goal.title.as_str(), g.status)).collect();
+        assert_eq!(statuses, vec![("Cinema trip", PointGoalStatus::Completed), ("Bike", PointGoalStatus::Expired)]);
+
+        let past = CreatePointGoalRequest { deadline: today - chrono::Duration::days(1), ..request };
+        assert!(matches!(
+            create_goal(&pool, &household_id, &kid, &kid, &past, today).await,
+            Err(PointGoalError::DeadlineInPast)
+        ));
+        assert!(delete_goal(&pool, &household_id, &kid, &goal.goal.id).await.unwrap());
+        assert!(!delete_goal(&pool, &household_id, &kid, &goal.goal.id).await.unwrap());
+    }
+}
@@ -1,16 +1,23 @@
 use chrono::Utc;
-use sqlx::SqlitePool;
+use sqlx::{SqliteConnection, SqlitePool};
 use thiserror::Error;
 use uuid::Uuid;
 
-use crate::models::PointConditionRow;
+use crate::models::{PointConditionRow, PointTransactionRow};
 use crate::services::households;
-use shared::{ConditionType, CreatePointConditionRequest, PointCondition, UpdatePointConditionRequest};
+use shared::{
+    ConditionType, CreatePointConditionRequest, PointCondition, PointHistoryPage, PointTransactionType,
+    UpdatePointConditionRequest,
+};
 
 #[derive(Debug, Error)]
 pub enum PointsError {
     #[error("Point condition not found")]
     NotFound,
+    #[error("Streak freezes are not for sale in this household")]
+    StreakFreezesDisabled,
+    #[error("Insufficient points")]
+    InsufficientPoints,
     #[error("Database error: {0}")]
     DatabaseError(#[from] sqlx::Error),
     #[error("Household error: {0}")]
@@ -142,6 +149,146 @@ pub async fn delete_point_condition(pool: &SqlitePool, condition_id: &Uuid) -> R
     Ok(())
 }
 
+// ============================================================================
+// Point Ledger
+// ============================================================================
+
+/// What caused a point change, stored alongside the ledger entry
+#[derive(Debug, Clone, Default)]
+pub struct TransactionSource {
+    pub task_id: Option<Uuid>,
+    pub reward_id: Option<Uuid>,
+    pub actor_id: Option<Uuid>,
+    pub description: Option<String>,
+}
+
+impl TransactionSource {
+    pub fn task(task_id: &Uuid, title: Option<String>) -> Self {
+        Self {
+            task_id: Some(*task_id),
+            description: title,
+            ..Default::default()
+        }
+    }
+}
+
+/// Change a member's points and record the change in the ledger, both in one
+/// transaction. Returns the new balance.
+#[tracing::instrument(level = "debug", skip_all, fields(%household_id, %user_id, amount))]
+pub async fn apply_points(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    amount: i64,
+    transaction_type: PointTransactionType,
+    source: &TransactionSource,
+) -> Result<i64, PointsError> {
+    let mut tx = pool.begin().await?;
+    let balance_after = apply_points_in(&mut tx, household_id, user_id, amount, transaction_type, source).await?;
+    tx.commit().await?;
+    Ok(balance_after)
+}
+
+/// Same as `apply_points`, on a given connection or open transaction
+pub async fn apply_points_in(
+    conn: &mut SqliteConnection,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    amount: i64,
+    transaction_type: PointTransactionType,
+    source: &TransactionSource,
+) -> Result<i64, PointsError> {
+    let balance_after = households::update_member_points_in(&mut *conn, household_id, user_id, amount).await?;
+    record_transaction_in(conn, household_id, user_id, amount, balance_after, transaction_type, source).await?;
+    Ok(balance_after)
+}
+
+/// Like `apply_points_in` with `-cost`, but fails with `InsufficientPoints`
+/// instead of taking the balance below zero
+pub async fn spend_points_in(
+    conn: &mut SqliteConnection,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    cost: i64,
+    transaction_type: PointTransactionType,
+    source: &TransactionSource,
+) -> Result<i64, PointsError> {
+    let balance_after = households::spend_member_points_in(&mut *conn, household_id, user_id, cost)
+        .await?
+        .ok_or(PointsError::InsufficientPoints)?;
+    record_transaction_in(conn, household_id, user_id, -cost, balance_after, transaction_type, source).await?;
+    Ok(balance_after)
+}
+
+async fn record_transaction_in(
+    conn: &mut SqliteConnection,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    amount: i64,
+    balance_after: i64,
+    transaction_type: PointTransactionType,
+    source: &TransactionSource,
+) -> Result<(), PointsError> {
+    sqlx::query(
+        r#"
+        INSERT INTO point_transactions (id, household_id, user_id, amount, balance_after, transaction_type, task_id, reward_id, actor_id, description, created_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .bind(amount)
+    .bind(balance_after)
+    .bind(transaction_type.as_str())
+    .bind(source.task_id.map(|id| id.to_string()))
+    .bind(source.reward_id.map(|id| id.to_string()))
+    .bind(source.actor_id.map(|id| id.to_string()))
+    .bind(&source.description)
+    .bind(Utc::now())
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+/// List a member's point transactions, newest first
+pub async fn list_point_history(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    limit: i64,
+    offset: i64,
+) -> Result<PointHistoryPage, PointsError> {
+    let rows: Vec<PointTransactionRow> = sqlx::query_as(
+        r#"
+        SELECT * FROM point_transactions
+        WHERE household_id = ? AND user_id = ?
+        ORDER BY created_at DESC, rowid DESC
+        LIMIT ? OFFSET ?
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
+    .await?;
+
+    let total: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM point_transactions WHERE household_id = ? AND user_id = ?",
+    )
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .fetch_one(pool)
+    .await?;
+
+    Ok(PointHistoryPage {
+        transactions: rows.into_iter().map(|r| r.to_shared()).collect(),
+        total,
+    })
+}
+
 /// Award points when a task is completed
 pub async fn award_task_completion_points(
     pool: &SqlitePool,
@@ -161,6 +308,7 @@ pub async fn award_task_completion_points(
             .fetch_optional(pool)
             .await?;
 
+    let task_title = task.as_ref().map(|t| t.title.clone());
     if let Some(task) = task {
         if let Some(reward) = task.points_reward {
             total_points += reward;
@@ -209,7 +357,15 @@ pub async fn award_task_completion_points(
     }
 
     if total_points != 0 {
-        households::update_member_points(pool, household_id, user_id, total_points).await?;
+        apply_points(
+            pool,
+            household_id,
+            user_id,
+            total_points,
+            PointTransactionType::TaskCompleted,
+            &TransactionSource::task(task_id, task_title),
+        )
+        .await?;
     }
 
     Ok(total_points)
@@ -234,6 +390,7 @@ pub async fn reverse_task_completion_points(
             .fetch_optional(pool)
             .await?;
 
+    let task_title = task.as_ref().map(|t| t.title.clone());
     if let Some(task) = task {
         if let Some(reward) = task.points_reward {
             total_points += reward;
@@ -262,7 +419,15 @@ pub async fn reverse_task_completion_points(
 
     // Deduct the points (negative adjustment)
     if total_points != 0 {
-        households::update_member_points(pool, household_id, user_id, -total_points).await?;
+        apply_points(
+            pool,
+            household_id,
+            user_id,
+            -total_points,
+            PointTransactionType::CompletionReversed,
+            &TransactionSource::task(task_id, task_title),
+        )
+        .await?;
     }
 
     Ok(total_points)
@@ -287,6 +452,7 @@ pub async fn deduct_missed_task_points(
             .fetch_optional(pool)
             .await?;
 
+    let task_title = task.as_ref().map(|t| t.title.clone());
     if let Some(task) = task {
         if let Some(penalty) = task.points_penalty {
             // Penalty is stored as positive, deduct it
@@ -304,13 +470,11 @@ pub async fn deduct_missed_task_points(
                 }
             }
 
-            ConditionType::StreakBroken => {
-                if streak_was_broken {
-                    let applies = condition.task_id.is_none() || condition.task_id == Some(*task_id);
+            ConditionType::StreakBroken if streak_was_broken => {
+                let applies = condition.task_id.is_none() || condition.task_id == Some(*task_id);
 
-                    if applies {
-                        total_points += condition.points_value; // Usually negative
-                    }
+                if applies {
+                    total_points += condition.points_value; // Usually negative
                 }
             }
 
@@ -319,7 +483,15 @@ pub async fn deduct_missed_task_points(
     }
 
     if total_points != 0 {
-        households::update_member_points(pool, household_id, user_id, total_points).await?;
+        apply_points(
+            pool,
+            household_id,
+            user_id,
+            total_points,
+            PointTransactionType::TaskMissed,
+            &TransactionSource::task(task_id, task_title),
+        )
+        .await?;
     }
 
     Ok(total_points)
@@ -343,7 +515,15 @@ pub async fn deduct_bad_habit_completion_points(
     }
 
     if total_points != 0 {
-        households::update_member_points(pool, household_id, user_id, total_points).await?;
+        apply_points(
+            pool,
+            household_id,
+            user_id,
+            total_points,
+            PointTransactionType::BadHabitCompleted,
+            &TransactionSource::task(&task.id, Some(task.title.clone())),
+        )
+        .await?;
     }
 
     Ok(total_points)
@@ -365,21 +545,247 @@ pub async fn award_bad_habit_avoided_points(
     }
 
     if total_points != 0 {
-        households::update_member_points(pool, household_id, user_id, total_points).await?;
+        apply_points(
+            pool,
+            household_id,
+            user_id,
+            total_points,
+            PointTransactionType::BadHabitAvoided,
+            &TransactionSource::task(&task.id, Some(task.title.clone())),
+        )
+        .await?;
     }
 
     Ok(total_points)
 }
 
+// ============================================================================
+// Streak Freezes
+// ============================================================================
+
+/// Number of streak freezes the member has bought and not used yet
+pub async fn available_streak_freezes(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+) -> Result<i32, PointsError> {
+    let available: Option<i32> =
+        sqlx::query_scalar("SELECT available FROM streak_freezes WHERE household_id = ? AND user_id = ?")
+            .bind(household_id.to_string())
+            .bind(user_id.to_string())
+            .fetch_optional(pool)
+            .await?;
+
+    Ok(available.unwrap_or(0))
+}
+
+/// Buy a streak freeze for `price` points. Returns the number now available.
+pub async fn purchase_streak_freeze(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    price: i64,
+) -> Result<i32, PointsError> {
+    if price <= 0 {
+        return Err(PointsError::StreakFreezesDisabled);
+    }
+
+    // The deduction and the new freeze commit together, and the balance check
+    // is part of the deduction, so concurrent purchases cannot overdraw
+    let mut tx = pool.begin().await?;
+    let source = TransactionSource {
+        description: Some("Streak freeze".to_string()),
+        ..Default::default()
+    };
+    spend_points_in(&mut tx, household_id, user_id, price, PointTransactionType::StreakFreezePurchased, &source).await?;
+
+    let available: i32 = sqlx::query_scalar(
+        r#"
+        INSERT INTO streak_freezes (household_id, user_id, available) VALUES (?, ?, 1)
+        ON CONFLICT (household_id, user_id) DO UPDATE SET available = available + 1
+        RETURNING available
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(available)
+}
+
+/// Use up one of the member's streak freezes; returns false when none is left
+pub async fn consume_streak_freeze(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+) -> Result<bool, PointsError> {
+    let result = sqlx::query(
+        "UPDATE streak_freezes SET available = available - 1 WHERE household_id = ? AND user_id = ? AND available > 0",
+    )
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils;
 
     #[test]
     fn test_points_error_display() {
         assert_eq!(PointsError::NotFound.to_string(), "Point condition not found");
     }
 
+    #[tokio::test]
+    async fn test_task_completion_points_are_recorded_in_ledger() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user_id = test_utils::create_test_user(&pool, "ledger@test.com", shared::Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &user_id, shared::Role::Member).await;
+
+        let task = test_utils::create_test_task(&pool, &household_id)
+            .with_title("Water plants")
+            .with_points(10, 4)
+            .build()
+            .await;
+
+        award_task_completion_points(&pool, &household_id, &user_id, &task.id, 0).await.unwrap();
+        deduct_missed_task_points(&pool, &household_id, &user_id, &task.id, false).await.unwrap();
+
+        let history = list_point_history(&pool, &household_id, &user_id, 50, 0).await.unwrap();
+        assert_eq!(history.total, 2);
+
+        let missed = &history.transactions[0];
+        assert_eq!(missed.transaction_type, PointTransactionType::TaskMissed);
+        assert_eq!(missed.amount, -4);
+        assert_eq!(missed.balance_after, 6);
+
+        let completed = &history.transactions[1];
+        assert_eq!(completed.transaction_type, PointTransactionType::TaskCompleted);
+        assert_eq!(completed.amount, 10);
+        assert_eq!(completed.task_id, Some(task.id));
+        assert_eq!(completed.description.as_deref(), Some("Water plants"));
+    }
+
+    #[tokio::test]
+    async fn test_point_history_pagination() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user_id = test_utils::create_test_user(&pool, "pages@test.com", shared::Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &user_id, shared::Role::Member).await;
+
+        for amount in 1..=5 {
+            apply_points(
+                &pool,
+                &household_id,
+                &user_id,
+                amount,
+                PointTransactionType::ManualAdjustment,
+                &TransactionSource::default(),
+            )
+            .await
+            .unwrap();
+        }
+
+        let page = list_point_history(&pool, &household_id, &user_id, 2, 2).await.unwrap();
+        assert_eq!(page.total, 5);
+        let amounts: Vec<i64> = page.transactions.iter().map(|t| t.amount).collect();
+        assert_eq!(amounts, vec![3, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_failed_ledger_entry_leaves_balance_unchanged() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user_id = test_utils::create_test_user(&pool, "atomic@test.com", shared::Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &user_id, shared::Role::Member).await;
+        households::update_member_points(&pool, &household_id, &user_id, 7).await.unwrap();
+
+        sqlx::query("DROP TABLE point_transactions").execute(&pool).await.unwrap();
+        let result = apply_points(
+            &pool,
+            &household_id,
+            &user_id,
+            5,
+            PointTransactionType::ManualAdjustment,
+            &TransactionSource::default(),
+        )
+        .await;
+        assert!(result.is_err());
+        test_utils::assert_points_balance(&pool, &household_id, &user_id, 7).await;
+    }
+
+    #[tokio::test]
+    async fn test_purchase_and_consume_streak_freezes() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user_id = test_utils::create_test_user(&pool, "freeze@test.com", shared::Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &user_id, shared::Role::Member).await;
+        households::update_member_points(&pool, &household_id, &user_id, 25).await.unwrap();
+
+        assert!(matches!(
+            purchase_streak_freeze(&pool, &household_id, &user_id, 0).await,
+            Err(PointsError::StreakFreezesDisabled)
+        ));
+        assert_eq!(purchase_streak_freeze(&pool, &household_id, &user_id, 10).await.unwrap(), 1);
+        assert_eq!(purchase_streak_freeze(&pool, &household_id, &user_id, 10).await.unwrap(), 2);
+        assert!(matches!(
+            purchase_streak_freeze(&pool, &household_id, &user_id, 10).await,
+            Err(PointsError::InsufficientPoints)
+        ));
+
+        let page = list_point_history(&pool, &household_id, &user_id, 1, 0).await.unwrap();
+        assert_eq!(page.transactions[0].transaction_type, PointTransactionType::StreakFreezePurchased);
+        assert_eq!(page.transactions[0].balance_after, 5);
+
+        assert!(consume_streak_freeze(&pool, &household_id, &user_id).await.unwrap());
+        assert!(consume_streak_freeze(&pool, &household_id, &user_id).await.unwrap());
+        assert!(!consume_streak_freeze(&pool, &household_id, &user_id).await.unwrap());
+        assert_eq!(available_streak_freezes(&pool, &household_id, &user_id).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_streak_freeze_purchases_cannot_overdraw() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user_id = test_utils::create_test_user(&pool, "freeze@test.com", shared::Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &user_id, shared::Role::Member).await;
+        households::update_member_points(&pool, &household_id, &user_id, 15).await.unwrap();
+
+        // Each purchase is affordable on its own, both together are not
+        let (a, b) = tokio::join!(
+            purchase_streak_freeze(&pool, &household_id, &user_id, 10),
+            purchase_streak_freeze(&pool, &household_id, &user_id, 10),
+        );
+        assert_eq!([a.is_ok(), b.is_ok()].iter().filter(|ok| **ok).count(), 1);
+        assert!(matches!(a.err().or(b.err()), Some(PointsError::InsufficientPoints)));
+        test_utils::assert_points_balance(&pool, &household_id, &user_id, 5).await;
+        assert_eq!(available_streak_freezes(&pool, &household_id, &user_id).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_streak_freeze_purchase_with_insufficient_balance_changes_nothing() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user_id = test_utils::create_test_user(&pool, "freeze@test.com", shared::Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &user_id, shared::Role::Member).await;
+        households::update_member_points(&pool, &household_id, &user_id, 9).await.unwrap();
+
+        assert!(matches!(
+            purchase_streak_freeze(&pool, &household_id, &user_id, 10).await,
+            Err(PointsError::InsufficientPoints)
+        ));
+        test_utils::assert_points_balance(&pool, &household_id, &user_id, 9).await;
+        assert_eq!(available_streak_freezes(&pool, &household_id, &user_id).await.unwrap(), 0);
+        assert_eq!(list_point_history(&pool, &household_id, &user_id, 10, 0).await.unwrap().total, 0);
+    }
+
     #[test]
     fn test_condition_type_as_str() {
         assert_eq!(ConditionType::TaskComplete.as_str(), "task_complete");
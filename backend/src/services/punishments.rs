@@ -1,11 +1,12 @@
 use chrono::Utc;
-use rand::seq::SliceRandom;
+use std::collections::HashMap;
 use sqlx::SqlitePool;
 use thiserror::Error;
 use uuid::Uuid;
 
 use crate::models::{PunishmentRow, UserPunishmentRow};
-use shared::{CreatePunishmentRequest, PendingPunishmentCompletion, Punishment, PunishmentType, RandomPickResult, UpdatePunishmentRequest, User, UserPunishment, UserPunishmentWithUser};
+use crate::services::random_choice;
+use shared::{CreatePunishmentRequest, PendingPunishmentCompletion, Punishment, PunishmentType, RandomPickResult, UpdatePunishmentRequest, User, UserPunishment, UserPunishmentWithUser, WeightedPunishmentOption, MAX_OPTION_WEIGHT};
 
 #[derive(Debug, Error)]
 pub enum PunishmentError {
@@ -25,6 +26,8 @@ pub enum PunishmentError {
     NotRandomChoice,
     #[error("No options available for random selection")]
     NoOptions,
+    #[error("Option weight must be between 1 and 100")]
+    InvalidWeight,
     #[error("Database error: {0}")]
     DatabaseError(#[from] sqlx::Error),
 }
@@ -38,6 +41,7 @@ pub async fn create_punishment(
     let now = Utc::now();
     let requires_confirmation = request.requires_confirmation.unwrap_or(false);
     let punishment_type = request.punishment_type.unwrap_or_default();
+    let no_repeat_picks = request.no_repeat_picks.unwrap_or(0).max(0);
 
     // Validate option_ids if random_choice
     if punishment_type.is_random_choice() {
@@ -52,8 +56,8 @@ pub async fn create_punishment(
 
     sqlx::query(
         r#"
-        INSERT INTO punishments (id, household_id, name, description, requires_confirmation, punishment_type, created_at)
-        VALUES (?, ?, ?, ?, ?, ?, ?)
+        INSERT INTO punishments (id, household_id, name, description, requires_confirmation, punishment_type, no_repeat_picks, created_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
         "#,
     )
     .bind(id.to_string())
@@ -62,6 +66,7 @@ pub async fn create_punishment(
     .bind(request.description.as_deref().unwrap_or(""))
     .bind(requires_confirmation)
     .bind(punishment_type.as_str())
+    .bind(no_repeat_picks)
     .bind(now)
     .execute(pool)
     .await?;
@@ -73,7 +78,7 @@ pub async fn create_punishment(
             let option = get_punishment(pool, option_id).await?;
             match option {
                 Some(p) if p.household_id == *household_id => {
-                    add_punishment_option(pool, &id, option_id).await?;
+                    add_punishment_option(pool, &id, option_id, 1).await?;
                 }
                 _ => return Err(PunishmentError::OptionNotFound),
             }
@@ -87,6 +92,7 @@ pub async fn create_punishment(
         description: request.description.clone().unwrap_or_default(),
         requires_confirmation,
         punishment_type,
+        no_repeat_picks,
         created_at: now,
     })
 }
@@ -142,6 +148,9 @@ pub async fn update_punishment(
     if let Some(punishment_type) = request.punishment_type {
         punishment.punishment_type = punishment_type.as_str().to_string();
     }
+    if let Some(no_repeat_picks) = request.no_repeat_picks {
+        punishment.no_repeat_picks = no_repeat_picks.max(0);
+    }
 
     let punishment_type: PunishmentType = punishment.punishment_type.parse().unwrap_or_default();
 
@@ -161,7 +170,16 @@ pub async fn update_punishment(
                     return Err(PunishmentError::InsufficientOptions);
                 }
 
-                // Replace all options
+                // Replace all options, keeping the weights of options that stay
+                let weights: HashMap<String, i32> = sqlx::query_as::<_, (String, i32)>(
+                    "SELECT option_punishment_id, weight FROM punishment_options WHERE parent_punishment_id = ?",
+                )
+                .bind(punishment_id.to_string())
+                .fetch_all(pool)
+                .await?
+                .into_iter()
+                .collect();
+
                 sqlx::query("DELETE FROM punishment_options WHERE parent_punishment_id = ?")
                     .bind(punishment_id.to_string())
                     .execute(pool)
@@ -173,7 +191,8 @@ pub async fn update_punishment(
                     let option = get_punishment(pool, option_id).await?;
                     match option {
                         Some(p) if p.household_id == household_id => {
-                            add_punishment_option(pool, punishment_id, option_id).await?;
+                            let weight = weights.get(&option_id.to_string()).copied().unwrap_or(1);
+                            add_punishment_option(pool, punishment_id, option_id, weight).await?;
                         }
                         _ => return Err(PunishmentError::OptionNotFound),
                     }
@@ -182,11 +201,12 @@ pub async fn update_punishment(
         }
     }
 
-    sqlx::query("UPDATE punishments SET name = ?, description = ?, requires_confirmation = ?, punishment_type = ? WHERE id = ?")
+    sqlx::query("UPDATE punishments SET name = ?, description = ?, requires_confirmation = ?, punishment_type = ?, no_repeat_picks = ? WHERE id = ?")
         .bind(&punishment.name)
         .bind(&punishment.description)
         .bind(punishment.requires_confirmation)
         .bind(&punishment.punishment_type)
+        .bind(punishment.no_repeat_picks)
         .bind(punishment_id.to_string())
         .execute(pool)
         .await?;
@@ -499,6 +519,7 @@ pub async fn list_pending_completions(
         p_description: String,
         p_requires_confirmation: bool,
         p_punishment_type: String,
+        p_no_repeat_picks: i32,
         p_created_at: chrono::DateTime<chrono::Utc>,
         // user fields
         u_id: String,
@@ -517,7 +538,7 @@ pub async fn list_pending_completions(
             up.updated_at as up_updated_at,
             p.id as p_id, p.household_id as p_household_id, p.name as p_name,
             p.description as p_description, p.requires_confirmation as p_requires_confirmation,
-            p.punishment_type as p_punishment_type, p.created_at as p_created_at,
+            p.punishment_type as p_punishment_type, p.no_repeat_picks as p_no_repeat_picks, p.created_at as p_created_at,
             u.id as u_id, u.username as u_username, u.email as u_email,
             u.created_at as u_created_at, u.updated_at as u_updated_at
         FROM user_punishments up
@@ -551,6 +572,7 @@ pub async fn list_pending_completions(
                 description: row.p_description,
                 requires_confirmation: row.p_requires_confirmation,
                 punishment_type: row.p_punishment_type.parse().unwrap_or_default(),
+                no_repeat_picks: row.p_no_repeat_picks,
                 created_at: row.p_created_at,
             },
             user: User {
@@ -638,6 +660,7 @@ pub async fn add_punishment_option(
     pool: &SqlitePool,
     parent_punishment_id: &Uuid,
     option_punishment_id: &Uuid,
+    weight: i32,
 ) -> Result<(), PunishmentError> {
     // Self-reference is allowed - user can include themselves as an option
     let id = Uuid::new_v4();
@@ -645,13 +668,14 @@ pub async fn add_punishment_option(
 
     sqlx::query(
         r#"
-        INSERT INTO punishment_options (id, parent_punishment_id, option_punishment_id, created_at)
-        VALUES (?, ?, ?, ?)
+        INSERT INTO punishment_options (id, parent_punishment_id, option_punishment_id, weight, created_at)
+        VALUES (?, ?, ?, ?, ?)
         "#,
     )
     .bind(id.to_string())
     .bind(parent_punishment_id.to_string())
     .bind(option_punishment_id.to_string())
+    .bind(weight)
     .bind(now)
     .execute(pool)
     .await?;
@@ -659,14 +683,21 @@ pub async fn add_punishment_option(
     Ok(())
 }
 
-/// Get all punishment options for a random choice punishment
+/// Get all punishment options for a random choice punishment with their weights
 pub async fn get_punishment_options(
     pool: &SqlitePool,
     punishment_id: &Uuid,
-) -> Result<Vec<Punishment>, PunishmentError> {
-    let rows: Vec<PunishmentRow> = sqlx::query_as(
+) -> Result<Vec<WeightedPunishmentOption>, PunishmentError> {
+    #[derive(sqlx::FromRow)]
+    struct OptionRow {
+        #[sqlx(flatten)]
+        punishment: PunishmentRow,
+        option_weight: i32,
+    }
+
+    let rows: Vec<OptionRow> = sqlx::query_as(
         r#"
-        SELECT p.*
+        SELECT p.*, po.weight as option_weight
         FROM punishments p
         JOIN punishment_options po ON p.id = po.option_punishment_id
         WHERE po.parent_punishment_id = ?
@@ -677,7 +708,46 @@ pub async fn get_punishment_options(
     .fetch_all(pool)
     .await?;
 
-    Ok(rows.into_iter().map(|r| r.to_shared()).collect())
+    Ok(rows
+        .into_iter()
+        .map(|row| WeightedPunishmentOption {
+            punishment: row.punishment.to_shared(),
+            weight: row.option_weight,
+        })
+        .collect())
+}
+
+/// Change how likely an option of a household's random choice punishment is picked
+pub async fn set_punishment_option_weight(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    parent_punishment_id: &Uuid,
+    option_punishment_id: &Uuid,
+    weight: i32,
+) -> Result<(), PunishmentError> {
+    if !(1..=MAX_OPTION_WEIGHT).contains(&weight) {
+        return Err(PunishmentError::InvalidWeight);
+    }
+
+    let updated = sqlx::query(
+        r#"
+        UPDATE punishment_options SET weight = ?
+        WHERE parent_punishment_id = ? AND option_punishment_id = ?
+          AND parent_punishment_id IN (SELECT id FROM punishments WHERE household_id = ?)
+        "#,
+    )
+    .bind(weight)
+    .bind(parent_punishment_id.to_string())
+    .bind(option_punishment_id.to_string())
+    .bind(household_id.to_string())
+    .execute(pool)
+    .await?
+    .rows_affected();
+
+    if updated == 0 {
+        return Err(PunishmentError::OptionNotFound);
+    }
+    Ok(())
 }
 
 /// Remove a punishment option from a random choice punishment
@@ -750,13 +820,20 @@ pub async fn pick_random_option(
         return Err(PunishmentError::NoOptions);
     }
 
-    // Randomly select one
-    let mut rng = rand::thread_rng();
-    let picked = options.choose(&mut rng).unwrap();
+    // Select one by weight, avoiding the member's recent picks
+    let recent = random_choice::recent_picks(pool, &punishment_id, user_id, punishment.no_repeat_picks).await?;
+    let weights: Vec<(Uuid, i32)> = options.iter().map(|o| (o.punishment.id, o.weight)).collect();
+    let picked_id = random_choice::pick_weighted(&mut rand::thread_rng(), &weights, &recent).ok_or(PunishmentError::NoOptions)?;
+    let picked = options
+        .into_iter()
+        .find(|o| o.punishment.id == picked_id)
+        .ok_or(PunishmentError::NoOptions)?
+        .punishment;
 
     // Assign the picked punishment to the user
     let household_id = Uuid::parse_str(&user_punishment.household_id).unwrap();
     let new_user_punishment = assign_punishment(pool, &picked.id, user_id, &household_id).await?;
+    random_choice::record_pick(pool, &household_id, user_id, &punishment_id, &picked.id).await?;
 
     // Mark the original random choice assignment as completed (decrement amount)
     let now = Utc::now();
@@ -778,7 +855,7 @@ pub async fn pick_random_option(
     }
 
     Ok(RandomPickResult {
-        picked_punishment: picked.clone(),
+        picked_punishment: picked,
         user_punishment: new_user_punishment,
     })
 }
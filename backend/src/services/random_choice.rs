@@ -0,0 +1,118 @@
+//! Weighted random choice
+//!
+//! Random choice rewards and punishments pick one of their options with a
+//! chance proportional to the option's weight. With `no_repeat_picks` set,
+//! options among a member's last N picks of the same parent are skipped as
+//! long as at least one other option is left.
+
+use chrono::Utc;
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::Rng;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+/// Pick one of `options` (id, weight), skipping those in `recent` unless all of them are recent
+pub fn pick_weighted<R: Rng + ?Sized>(rng: &mut R, options: &[(Uuid, i32)], recent: &[Uuid]) -> Option<Uuid> {
+    let fresh: Vec<&(Uuid, i32)> = options.iter().filter(|(id, _)| !recent.contains(id)).collect();
+    let candidates = if fresh.is_empty() { options.iter().collect() } else { fresh };
+    let index = WeightedIndex::new(candidates.iter().map(|(_, weight)| (*weight).max(1))).ok()?;
+    Some(candidates[index.sample(rng)].0)
+}
+
+/// Options of `parent_id` picked by the member in their last `limit` picks
+pub async fn recent_picks(
+    pool: &SqlitePool,
+    parent_id: &Uuid,
+    user_id: &Uuid,
+    limit: i32,
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    if limit <= 0 {
+        return Ok(Vec::new());
+    }
+
+    let ids: Vec<String> = sqlx::query_scalar(
+        r#"
+        SELECT option_id FROM random_choice_picks
+        WHERE parent_id = ? AND user_id = ?
+        ORDER BY created_at DESC, rowid DESC
+        LIMIT ?
+        "#,
+    )
+    .bind(parent_id.to_string())
+    .bind(user_id.to_string())
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(ids.iter().filter_map(|id| Uuid::parse_str(id).ok()).collect())
+}
+
+/// Remember a pick so later picks can avoid repeating it
+pub async fn record_pick(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    parent_id: &Uuid,
+    option_id: &Uuid,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO random_choice_picks (id, household_id, user_id, parent_id, option_id, created_at)
+        VALUES (?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .bind(parent_id.to_string())
+    .bind(option_id.to_string())
+    .bind(Utc::now())
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_pick_weighted_follows_weights() {
+        let heavy = Uuid::new_v4();
+        let light = Uuid::new_v4();
+        let options = [(heavy, 9), (light, 1)];
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let heavy_picks = (0..1000)
+            .filter(|_| pick_weighted(&mut rng, &options, &[]) == Some(heavy))
+            .count();
+        assert!((850..=950).contains(&heavy_picks), "heavy picked {} times", heavy_picks);
+    }
+
+    #[test]
+    fn test_pick_weighted_skips_recent() {
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let c = Uuid::new_v4();
+        let options = [(a, 1), (b, 100), (c, 1)];
+        let mut rng = StdRng::seed_from_u64(1);
+
+        for _ in 0..50 {
+            assert_eq!(pick_weighted(&mut rng, &options, &[b, c]), Some(a));
+        }
+    }
+
+    #[test]
+    fn test_pick_weighted_falls_back_when_all_recent() {
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let mut rng = StdRng::seed_from_u64(3);
+
+        let picked = pick_weighted(&mut rng, &[(a, 1), (b, 1)], &[a, b]);
+        assert!(picked == Some(a) || picked == Some(b));
+        assert_eq!(pick_weighted(&mut rng, &[], &[]), None);
+    }
+}
@@ -0,0 +1,269 @@
+//! Rewards members are saving up for, and the "you can afford it now" check
+
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::RewardRow;
+use shared::{Reward, WishlistItem};
+
+#[derive(Debug, Error)]
+pub enum WishlistError {
+    #[error("Reward not found")]
+    RewardNotFound,
+    #[error("Reward cannot be bought with points")]
+    NoPointCost,
+    #[error("Reward is not on the wishlist")]
+    NotOnWishlist,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+async fn member_points(pool: &SqlitePool, household_id: &Uuid, user_id: &Uuid) -> Result<i64, WishlistError> {
+    let points: Option<i64> =
+        sqlx::query_scalar("SELECT points FROM household_memberships WHERE household_id = ? AND user_id = ?")
+            .bind(household_id.to_string())
+            .bind(user_id.to_string())
+            .fetch_optional(pool)
+            .await?;
+
+    Ok(points.unwrap_or(0))
+}
+
+/// The member's wishlist, cheapest reward first
+pub async fn list_wishlist(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+) -> Result<Vec<WishlistItem>, WishlistError> {
+    #[derive(sqlx::FromRow)]
+    struct WishlistRow {
+        #[sqlx(flatten)]
+        reward: RewardRow,
+        w_created_at: DateTime<Utc>,
+    }
+
+    let rows: Vec<WishlistRow> = sqlx::query_as(
+        r#"
+        SELECT r.*, w.created_at as w_created_at
+        FROM reward_wishlist w
+        JOIN rewards r ON w.reward_id = r.id
+        WHERE w.household_id = ? AND w.user_id = ?
+        ORDER BY r.point_cost ASC, r.name COLLATE NOCASE ASC
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    let points = member_points(pool, household_id, user_id).await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let reward = row.reward.to_shared();
+            let points_needed = (reward.point_cost.unwrap_or(0) - points).max(0);
+            WishlistItem {
+                reward,
+                points_needed,
+                added_at: row.w_created_at,
+            }
+        })
+        .collect())
+}
+
+/// Put a reward of the household on the member's wishlist; adding it twice is not an error.
+/// A reward that is already affordable counts as notified, so the member is only told once it
+/// drops out of reach and comes back.
+pub async fn add_to_wishlist(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    reward_id: &Uuid,
+) -> Result<(), WishlistError> {
+    let reward: Option<RewardRow> = sqlx::query_as("SELECT * FROM rewards WHERE id = ? AND household_id = ?")
+        .bind(reward_id.to_string())
+        .bind(household_id.to_string())
+        .fetch_optional(pool)
+        .await?;
+    let reward = reward.ok_or(WishlistError::RewardNotFound)?;
+    let point_cost = reward.point_cost.ok_or(WishlistError::NoPointCost)?;
+
+    let points = member_points(pool, household_id, user_id).await?;
+    let notified_at = (reward.is_purchasable && points >= point_cost).then(Utc::now);
+
+    sqlx::query(
+        r#"
+        INSERT OR IGNORE INTO reward_wishlist (household_id, user_id, reward_id, notified_at, created_at)
+        VALUES (?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .bind(reward_id.to_string())
+    .bind(notified_at)
+    .bind(Utc::now())
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn remove_from_wishlist(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    reward_id: &Uuid,
+) -> Result<(), WishlistError> {
+    let result = sqlx::query("DELETE FROM reward_wishlist WHERE household_id = ? AND user_id = ? AND reward_id = ?")
+        .bind(household_id.to_string())
+        .bind(user_id.to_string())
+        .bind(reward_id.to_string())
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(WishlistError::NotOnWishlist);
+    }
+    Ok(())
+}
+
+/// Wishlist entries of the household that just became affordable, as (member, reward).
+///
+/// An entry is affordable when the reward can be bought and the member has enough points.
+/// Returned entries are marked as notified; entries that went out of reach again (points
+/// spent, price raised or the reward taken off sale) are reset so they notify next time.
+pub async fn take_newly_affordable(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+) -> Result<Vec<(Uuid, Reward)>, WishlistError> {
+    #[derive(sqlx::FromRow)]
+    struct AffordableRow {
+        #[sqlx(flatten)]
+        reward: RewardRow,
+        w_user_id: String,
+    }
+
+    sqlx::query(
+        r#"
+        UPDATE reward_wishlist SET notified_at = NULL
+        WHERE household_id = ? AND notified_at IS NOT NULL
+          AND NOT EXISTS (
+            SELECT 1 FROM rewards r
+            JOIN household_memberships m
+              ON m.household_id = reward_wishlist.household_id AND m.user_id = reward_wishlist.user_id
+            WHERE r.id = reward_wishlist.reward_id
+              AND r.is_purchasable = 1 AND r.point_cost IS NOT NULL AND m.points >= r.point_cost
+          )
+        "#,
+    )
+    .bind(household_id.to_string())
+    .execute(pool)
+    .await?;
+
+    let rows: Vec<AffordableRow> = sqlx::query_as(
+        r#"
+        SELECT r.*, w.user_id as w_user_id
+        FROM reward_wishlist w
+        JOIN rewards r ON w.reward_id = r.id
+        JOIN household_memberships m ON m.household_id = w.household_id AND m.user_id = w.user_id
+        WHERE w.household_id = ? AND w.notified_at IS NULL
+          AND r.is_purchasable = 1 AND r.point_cost IS NOT NULL AND m.points >= r.point_cost
+        "#,
+    )
+    .bind(household_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    let now = Utc::now();
+    let mut affordable = Vec::with_capacity(rows.len());
+    for row in rows {
+        sqlx::query("UPDATE reward_wishlist SET notified_at = ? WHERE user_id = ? AND reward_id = ?")
+            .bind(now)
+            .bind(&row.w_user_id)
+            .bind(&row.reward.id)
+            .execute(pool)
+            .await?;
+
+        if let Ok(user_id) = Uuid::parse_str(&row.w_user_id) {
+            affordable.push((user_id, row.reward.to_shared()));
+        }
+    }
+
+    Ok(affordable)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::{households as household_service, rewards as reward_service};
+    use crate::test_utils::{create_test_household, create_test_membership, create_test_pool, create_test_user};
+    use shared::{CreateRewardRequest, Role, UpdateRewardRequest};
+
+    #[tokio::test]
+    async fn test_wishlist_notifies_once_per_affordability() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let kid = create_test_user(&pool, "kid@test.com", Role::Member).await;
+        create_test_membership(&pool, &household_id, &kid, Role::Member).await;
+
+        let cinema = reward_service::create_reward(
+            &pool,
+            &household_id,
+            &CreateRewardRequest {
+                name: "Cinema trip".to_string(),
+                description: None,
+                point_cost: Some(50),
+                is_purchasable: true,
+                requires_confirmation: None,
+                reward_type: None,
+                option_ids: None,
+                no_repeat_picks: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        add_to_wishlist(&pool, &household_id, &kid, &cinema.id).await.unwrap();
+        household_service::update_member_points(&pool, &household_id, &kid, 30).await.unwrap();
+
+        let items = list_wishlist(&pool, &household_id, &kid).await.unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].points_needed, 20);
+        assert!(take_newly_affordable(&pool, &household_id).await.unwrap().is_empty());
+
+        household_service::update_member_points(&pool, &household_id, &kid, 25).await.unwrap();
+        let affordable = take_newly_affordable(&pool, &household_id).await.unwrap();
+        assert_eq!(affordable.len(), 1);
+        assert_eq!(affordable[0].0, kid);
+        assert_eq!(affordable[0].1.id, cinema.id);
+        assert_eq!(list_wishlist(&pool, &household_id, &kid).await.unwrap()[0].points_needed, 0);
+
+        // Already notified: no repeat until it drops out of reach
+        assert!(take_newly_affordable(&pool, &household_id).await.unwrap().is_empty());
+
+        // Taken off sale and back on sale counts as becoming available again
+        let set_purchasable = |is_purchasable| UpdateRewardRequest {
+            name: None,
+            description: None,
+            point_cost: None,
+            is_purchasable: Some(is_purchasable),
+            requires_confirmation: None,
+            reward_type: None,
+            option_ids: None,
+            no_repeat_picks: None,
+        };
+        reward_service::update_reward(&pool, &cinema.id, &set_purchasable(false)).await.unwrap();
+        assert!(take_newly_affordable(&pool, &household_id).await.unwrap().is_empty());
+        reward_service::update_reward(&pool, &cinema.id, &set_purchasable(true)).await.unwrap();
+        assert_eq!(take_newly_affordable(&pool, &household_id).await.unwrap().len(), 1);
+
+        remove_from_wishlist(&pool, &household_id, &kid, &cinema.id).await.unwrap();
+        assert!(matches!(
+            remove_from_wishlist(&pool, &household_id, &kid, &cinema.id).await,
+            Err(WishlistError::NotOnWishlist)
+        ));
+    }
+}
@@ -1,12 +1,12 @@
 use chrono::Utc;
-use rand::seq::SliceRandom;
-use sqlx::SqlitePool;
+use std::collections::HashMap;
+use sqlx::{SqliteConnection, SqlitePool};
 use thiserror::Error;
 use uuid::Uuid;
 
-use crate::models::{RewardRow, UserRewardRow};
-use crate::services::households;
-use shared::{CreateRewardRequest, PendingRewardRedemption, RandomRewardPickResult, Reward, RewardType, UpdateRewardRequest, User, UserReward, UserRewardWithUser};
+use crate::models::{RewardPurchaseRow, RewardRow, UserRewardRow, UserRow};
+use crate::services::{points, random_choice, webhooks};
+use shared::{CreateRewardRequest, PendingRewardPurchase, PendingRewardRedemption, PointTransactionType, PurchaseStatus, RandomRewardPickResult, Reward, RewardPurchase, RewardType, UpdateRewardRequest, User, UserReward, UserRewardWithUser, WebhookEvent, WeightedRewardOption, MAX_OPTION_WEIGHT};
 
 #[derive(Debug, Error)]
 pub enum RewardError {
@@ -22,16 +22,30 @@ pub enum RewardError {
     NothingToRedeem,
     #[error("No pending redemptions")]
     NothingPending,
+    #[error("No unredeemed rewards to gift")]
+    NothingToGift,
+    #[error("Cannot gift a reward to yourself")]
+    CannotGiftToSelf,
     #[error("Reward is not a random choice type")]
     NotRandomChoice,
     #[error("Random choice reward requires at least 2 options")]
     InsufficientOptions,
     #[error("Random choice reward has no options")]
     NoOptions,
+    #[error("Reward option not found")]
+    OptionNotFound,
+    #[error("Option weight must be between 1 and 100")]
+    InvalidWeight,
+    #[error("Purchase not found")]
+    PurchaseNotFound,
+    #[error("Purchase was already reviewed")]
+    PurchaseNotReviewable,
     #[error("Database error: {0}")]
     DatabaseError(#[from] sqlx::Error),
     #[error("Household error: {0}")]
     HouseholdError(#[from] super::households::HouseholdError),
+    #[error("Points error: {0}")]
+    PointsError(#[from] super::points::PointsError),
 }
 
 pub async fn create_reward(
@@ -43,6 +57,7 @@ pub async fn create_reward(
     let now = Utc::now();
     let requires_confirmation = request.requires_confirmation.unwrap_or(false);
     let reward_type = request.reward_type.unwrap_or_default();
+    let no_repeat_picks = request.no_repeat_picks.unwrap_or(0).max(0);
 
     // Validate random choice has at least 2 options
     if reward_type.is_random_choice() {
@@ -54,8 +69,8 @@ pub async fn create_reward(
 
     sqlx::query(
         r#"
-        INSERT INTO rewards (id, household_id, name, description, point_cost, is_purchasable, requires_confirmation, reward_type, created_at)
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+        INSERT INTO rewards (id, household_id, name, description, point_cost, is_purchasable, requires_confirmation, reward_type, no_repeat_picks, created_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
         "#,
     )
     .bind(id.to_string())
@@ -66,6 +81,7 @@ pub async fn create_reward(
     .bind(request.is_purchasable)
     .bind(requires_confirmation)
     .bind(reward_type.as_str())
+    .bind(no_repeat_picks)
     .bind(now)
     .execute(pool)
     .await?;
@@ -73,7 +89,7 @@ pub async fn create_reward(
     // Add options if provided
     if let Some(ref option_ids) = request.option_ids {
         for option_id in option_ids {
-            add_reward_option_internal(pool, &id, option_id, now).await?;
+            add_reward_option_internal(pool, &id, option_id, 1, now).await?;
         }
     }
 
@@ -86,6 +102,7 @@ pub async fn create_reward(
         is_purchasable: request.is_purchasable,
         requires_confirmation,
         reward_type,
+        no_repeat_picks,
         created_at: now,
     })
 }
@@ -139,6 +156,9 @@ pub async fn update_reward(
     if let Some(reward_type) = request.reward_type {
         reward.reward_type = reward_type.as_str().to_string();
     }
+    if let Some(no_repeat_picks) = request.no_repeat_picks {
+        reward.no_repeat_picks = no_repeat_picks.max(0);
+    }
 
     // Handle option_ids update
     if let Some(ref option_ids_opt) = request.option_ids {
@@ -157,7 +177,16 @@ pub async fn update_reward(
                     return Err(RewardError::InsufficientOptions);
                 }
 
-                // Clear existing and set new options
+                // Clear existing and set new options, keeping the weights of options that stay
+                let weights: HashMap<String, i32> = sqlx::query_as::<_, (String, i32)>(
+                    "SELECT option_reward_id, weight FROM reward_options WHERE parent_reward_id = ?",
+                )
+                .bind(reward_id.to_string())
+                .fetch_all(pool)
+                .await?
+                .into_iter()
+                .collect();
+
                 sqlx::query("DELETE FROM reward_options WHERE parent_reward_id = ?")
                     .bind(reward_id.to_string())
                     .execute(pool)
@@ -165,14 +194,15 @@ pub async fn update_reward(
 
                 let now = chrono::Utc::now();
                 for option_id in option_ids {
-                    add_reward_option_internal(pool, reward_id, option_id, now).await?;
+                    let weight = weights.get(&option_id.to_string()).copied().unwrap_or(1);
+                    add_reward_option_internal(pool, reward_id, option_id, weight, now).await?;
                 }
             }
         }
     }
 
     sqlx::query(
-        "UPDATE rewards SET name = ?, description = ?, point_cost = ?, is_purchasable = ?, requires_confirmation = ?, reward_type = ? WHERE id = ?",
+        "UPDATE rewards SET name = ?, description = ?, point_cost = ?, is_purchasable = ?, requires_confirmation = ?, reward_type = ?, no_repeat_picks = ? WHERE id = ?",
     )
     .bind(&reward.name)
     .bind(&reward.description)
@@ -180,6 +210,7 @@ pub async fn update_reward(
     .bind(reward.is_purchasable)
     .bind(reward.requires_confirmation)
     .bind(&reward.reward_type)
+    .bind(reward.no_repeat_picks)
     .bind(reward_id.to_string())
     .execute(pool)
     .await?;
@@ -215,12 +246,25 @@ pub async fn delete_reward(pool: &SqlitePool, reward_id: &Uuid) -> Result<(), Re
     Ok(())
 }
 
+#[tracing::instrument(level = "debug", skip_all, fields(%reward_id, %user_id))]
 pub async fn purchase_reward(
     pool: &SqlitePool,
     reward_id: &Uuid,
     user_id: &Uuid,
     household_id: &Uuid,
 ) -> Result<UserReward, RewardError> {
+    let (reward, point_cost) = purchasable_reward(pool, reward_id).await?;
+
+    let mut tx = pool.begin().await?;
+    let user_reward = deduct_and_assign_in(&mut tx, &reward, point_cost, user_id, household_id).await?;
+    tx.commit().await?;
+
+    notify_purchase(pool, &reward, point_cost, user_id, household_id).await;
+    Ok(user_reward)
+}
+
+/// The reward and its price, if it can be bought with points
+async fn purchasable_reward(pool: &SqlitePool, reward_id: &Uuid) -> Result<(Reward, i64), RewardError> {
     let reward = get_reward(pool, reward_id).await?.ok_or(RewardError::NotFound)?;
 
     if !reward.is_purchasable {
@@ -228,8 +272,15 @@ pub async fn purchase_reward(
     }
 
     let point_cost = reward.point_cost.ok_or(RewardError::NotPurchasable)?;
+    Ok((reward, point_cost))
+}
 
-    // Get user's current points
+async fn ensure_points(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    point_cost: i64,
+) -> Result<(), RewardError> {
     let current_points = sqlx::query_scalar::<_, i64>(
         "SELECT points FROM household_memberships WHERE household_id = ? AND user_id = ?",
     )
@@ -241,12 +292,199 @@ pub async fn purchase_reward(
     if current_points < point_cost {
         return Err(RewardError::InsufficientPoints);
     }
+    Ok(())
+}
 
-    // Deduct points
-    households::update_member_points(pool, household_id, user_id, -point_cost).await?;
+/// Take the price from the buyer and hand out the reward. Fails without
+/// deducting anything if the buyer cannot afford it.
+async fn deduct_and_assign_in(
+    conn: &mut SqliteConnection,
+    reward: &Reward,
+    point_cost: i64,
+    user_id: &Uuid,
+    household_id: &Uuid,
+) -> Result<UserReward, RewardError> {
+    let source = points::TransactionSource {
+        reward_id: Some(reward.id),
+        description: Some(reward.name.clone()),
+        ..Default::default()
+    };
+    match points::spend_points_in(&mut *conn, household_id, user_id, point_cost, PointTransactionType::RewardPurchased, &source).await {
+        Ok(_) => {}
+        Err(points::PointsError::InsufficientPoints) => return Err(RewardError::InsufficientPoints),
+        Err(e) => return Err(e.into()),
+    }
+
+    assign_reward_in(conn, &reward.id, user_id, household_id).await
+}
+
+async fn notify_purchase(pool: &SqlitePool, reward: &Reward, point_cost: i64, user_id: &Uuid, household_id: &Uuid) {
+    webhooks::dispatch(
+        pool,
+        household_id,
+        WebhookEvent::RewardPurchased,
+        serde_json::json!({ "reward_id": reward.id, "reward_name": reward.name, "user_id": user_id, "point_cost": point_cost }),
+    )
+    .await;
+}
 
-    // Use UPSERT to increment amount
-    assign_reward(pool, reward_id, user_id, household_id).await
+// ============================================================================
+// Purchase Approval Functions
+// ============================================================================
+
+/// Ask to buy a reward; nothing is deducted until the purchase is approved
+pub async fn request_purchase(
+    pool: &SqlitePool,
+    reward_id: &Uuid,
+    user_id: &Uuid,
+    household_id: &Uuid,
+) -> Result<RewardPurchase, RewardError> {
+    let (_, point_cost) = purchasable_reward(pool, reward_id).await?;
+    ensure_points(pool, household_id, user_id, point_cost).await?;
+
+    let purchase = RewardPurchase {
+        id: Uuid::new_v4(),
+        household_id: *household_id,
+        reward_id: *reward_id,
+        user_id: *user_id,
+        point_cost,
+        status: PurchaseStatus::Pending,
+        created_at: Utc::now(),
+        reviewed_by: None,
+        reviewed_at: None,
+    };
+
+    sqlx::query(
+        r#"
+        INSERT INTO reward_purchases (id, household_id, reward_id, user_id, point_cost, status, created_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(purchase.id.to_string())
+    .bind(household_id.to_string())
+    .bind(reward_id.to_string())
+    .bind(user_id.to_string())
+    .bind(point_cost)
+    .bind(purchase.status.as_str())
+    .bind(purchase.created_at)
+    .execute(pool)
+    .await?;
+
+    Ok(purchase)
+}
+
+/// Purchases in a household that are waiting for approval, oldest first
+pub async fn list_pending_purchases(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+) -> Result<Vec<PendingRewardPurchase>, RewardError> {
+    let rows: Vec<RewardPurchaseRow> = sqlx::query_as(
+        "SELECT * FROM reward_purchases WHERE household_id = ? AND status = 'pending' ORDER BY created_at ASC",
+    )
+    .bind(household_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    let mut pending = Vec::with_capacity(rows.len());
+    for row in rows {
+        let reward: Option<RewardRow> = sqlx::query_as("SELECT * FROM rewards WHERE id = ?")
+            .bind(&row.reward_id)
+            .fetch_optional(pool)
+            .await?;
+        let user: Option<UserRow> = sqlx::query_as("SELECT * FROM users WHERE id = ?")
+            .bind(&row.user_id)
+            .fetch_optional(pool)
+            .await?;
+        if let (Some(reward), Some(user)) = (reward, user) {
+            pending.push(PendingRewardPurchase {
+                purchase: row.to_shared(),
+                reward: reward.to_shared(),
+                user: user.to_shared(),
+            });
+        }
+    }
+    Ok(pending)
+}
+
+async fn get_pending_purchase(
+    pool: &SqlitePool,
+    purchase_id: &Uuid,
+    household_id: &Uuid,
+) -> Result<RewardPurchase, RewardError> {
+    let purchase: RewardPurchaseRow = sqlx::query_as("SELECT * FROM reward_purchases WHERE id = ? AND household_id = ?")
+        .bind(purchase_id.to_string())
+        .bind(household_id.to_string())
+        .fetch_optional(pool)
+        .await?
+        .ok_or(RewardError::PurchaseNotFound)?;
+
+    let purchase = purchase.to_shared();
+    if purchase.status != PurchaseStatus::Pending {
+        return Err(RewardError::PurchaseNotReviewable);
+    }
+    Ok(purchase)
+}
+
+/// Mark a pending purchase as reviewed; fails if someone else reviewed it first
+async fn set_purchase_status(
+    conn: &mut SqliteConnection,
+    purchase: &mut RewardPurchase,
+    status: PurchaseStatus,
+    reviewer_id: &Uuid,
+) -> Result<(), RewardError> {
+    let now = Utc::now();
+    let result = sqlx::query(
+        "UPDATE reward_purchases SET status = ?, reviewed_by = ?, reviewed_at = ? WHERE id = ? AND status = 'pending'",
+    )
+    .bind(status.as_str())
+    .bind(reviewer_id.to_string())
+    .bind(now)
+    .bind(purchase.id.to_string())
+    .execute(&mut *conn)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(RewardError::PurchaseNotReviewable);
+    }
+
+    purchase.status = status;
+    purchase.reviewed_by = Some(*reviewer_id);
+    purchase.reviewed_at = Some(now);
+    Ok(())
+}
+
+/// Approve a pending purchase: deduct the requested price and hand out the reward
+pub async fn approve_purchase(
+    pool: &SqlitePool,
+    purchase_id: &Uuid,
+    household_id: &Uuid,
+    reviewer_id: &Uuid,
+) -> Result<(RewardPurchase, UserReward), RewardError> {
+    let mut purchase = get_pending_purchase(pool, purchase_id, household_id).await?;
+    let reward = get_reward(pool, &purchase.reward_id).await?.ok_or(RewardError::NotFound)?;
+
+    // Points may have been spent elsewhere since the request, so the approval,
+    // the deduction and the reward commit together or not at all
+    let mut tx = pool.begin().await?;
+    set_purchase_status(&mut tx, &mut purchase, PurchaseStatus::Approved, reviewer_id).await?;
+    let user_reward = deduct_and_assign_in(&mut tx, &reward, purchase.point_cost, &purchase.user_id, household_id).await?;
+    tx.commit().await?;
+
+    notify_purchase(pool, &reward, purchase.point_cost, &purchase.user_id, household_id).await;
+    Ok((purchase, user_reward))
+}
+
+/// Reject a pending purchase; no points were deducted, so nothing is refunded
+pub async fn reject_purchase(
+    pool: &SqlitePool,
+    purchase_id: &Uuid,
+    household_id: &Uuid,
+    reviewer_id: &Uuid,
+) -> Result<RewardPurchase, RewardError> {
+    let mut purchase = get_pending_purchase(pool, purchase_id, household_id).await?;
+    let mut conn = pool.acquire().await?;
+    set_purchase_status(&mut conn, &mut purchase, PurchaseStatus::Rejected, reviewer_id).await?;
+    Ok(purchase)
 }
 
 /// Assign a reward to a user (or increment amount if already assigned)
@@ -257,6 +495,17 @@ pub async fn assign_reward(
     household_id: &Uuid,
 ) -> Result<UserReward, RewardError> {
     let _reward = get_reward(pool, reward_id).await?.ok_or(RewardError::NotFound)?;
+    let mut conn = pool.acquire().await?;
+    assign_reward_in(&mut conn, reward_id, user_id, household_id).await
+}
+
+/// Same as `assign_reward` for a known reward, on a given connection or open transaction
+async fn assign_reward_in(
+    conn: &mut SqliteConnection,
+    reward_id: &Uuid,
+    user_id: &Uuid,
+    household_id: &Uuid,
+) -> Result<UserReward, RewardError> {
     let now = Utc::now();
 
     // Try to update existing record first
@@ -271,7 +520,7 @@ pub async fn assign_reward(
     .bind(user_id.to_string())
     .bind(reward_id.to_string())
     .bind(household_id.to_string())
-    .execute(pool)
+    .execute(&mut *conn)
     .await?;
 
     if result.rows_affected() == 0 {
@@ -288,7 +537,7 @@ pub async fn assign_reward(
         .bind(reward_id.to_string())
         .bind(household_id.to_string())
         .bind(now)
-        .execute(pool)
+        .execute(&mut *conn)
         .await?;
     }
 
@@ -299,7 +548,7 @@ pub async fn assign_reward(
     .bind(user_id.to_string())
     .bind(reward_id.to_string())
     .bind(household_id.to_string())
-    .fetch_one(pool)
+    .fetch_one(&mut *conn)
     .await?;
 
     Ok(user_reward.to_shared())
@@ -500,6 +749,67 @@ pub async fn redeem_reward(
     }
 }
 
+/// Transfer one earned, unredeemed reward from its owner to another member.
+/// Returns the sender's updated reward (if any remain) and the recipient's reward.
+pub async fn gift_reward(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_reward_id: &Uuid,
+    from_user_id: &Uuid,
+    to_user_id: &Uuid,
+) -> Result<(Option<UserReward>, UserReward), RewardError> {
+    if from_user_id == to_user_id {
+        return Err(RewardError::CannotGiftToSelf);
+    }
+
+    let user_reward: UserRewardRow = sqlx::query_as("SELECT * FROM user_rewards WHERE id = ?")
+        .bind(user_reward_id.to_string())
+        .fetch_optional(pool)
+        .await?
+        .filter(|ur: &UserRewardRow| {
+            ur.user_id == from_user_id.to_string() && ur.household_id == household_id.to_string()
+        })
+        .ok_or(RewardError::UserRewardNotFound)?;
+
+    let now = Utc::now();
+
+    // The sender's loss and the recipient's gain commit together
+    let mut tx = pool.begin().await?;
+
+    // Only take from the available amount, never from redeemed or pending ones
+    let result = sqlx::query(
+        r#"
+        UPDATE user_rewards
+        SET amount = amount - 1, updated_at = ?
+        WHERE id = ? AND amount - redeemed_amount - pending_redemption > 0
+        "#,
+    )
+    .bind(now)
+    .bind(user_reward_id.to_string())
+    .execute(&mut *tx)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(RewardError::NothingToGift);
+    }
+
+    sqlx::query("DELETE FROM user_rewards WHERE id = ? AND amount <= 0")
+        .bind(user_reward_id.to_string())
+        .execute(&mut *tx)
+        .await?;
+
+    let remaining: Option<UserRewardRow> = sqlx::query_as("SELECT * FROM user_rewards WHERE id = ?")
+        .bind(user_reward_id.to_string())
+        .fetch_optional(&mut *tx)
+        .await?;
+
+    let reward_id = Uuid::parse_str(&user_reward.reward_id).unwrap();
+    let received = assign_reward_in(&mut tx, &reward_id, to_user_id, household_id).await?;
+    tx.commit().await?;
+
+    Ok((remaining.map(|r| r.to_shared()), received))
+}
+
 /// List all pending reward redemptions for a household
 pub async fn list_pending_redemptions(
     pool: &SqlitePool,
@@ -525,6 +835,7 @@ pub async fn list_pending_redemptions(
         r_is_purchasable: bool,
         r_requires_confirmation: bool,
         r_reward_type: String,
+        r_no_repeat_picks: i32,
         r_created_at: chrono::DateTime<chrono::Utc>,
         // user fields
         u_id: String,
@@ -544,7 +855,7 @@ pub async fn list_pending_redemptions(
             r.id as r_id, r.household_id as r_household_id, r.name as r_name,
             r.description as r_description, r.point_cost as r_point_cost,
             r.is_purchasable as r_is_purchasable, r.requires_confirmation as r_requires_confirmation,
-            r.reward_type as r_reward_type, r.created_at as r_created_at,
+            r.reward_type as r_reward_type, r.no_repeat_picks as r_no_repeat_picks, r.created_at as r_created_at,
             u.id as u_id, u.username as u_username, u.email as u_email,
             u.created_at as u_created_at, u.updated_at as u_updated_at
         FROM user_rewards ur
@@ -580,6 +891,7 @@ pub async fn list_pending_redemptions(
                 is_purchasable: row.r_is_purchasable,
                 requires_confirmation: row.r_requires_confirmation,
                 reward_type: row.r_reward_type.parse().unwrap_or_default(),
+                no_repeat_picks: row.r_no_repeat_picks,
                 created_at: row.r_created_at,
             },
             user: User {
@@ -665,19 +977,21 @@ async fn add_reward_option_internal(
     pool: &SqlitePool,
     parent_reward_id: &Uuid,
     option_reward_id: &Uuid,
+    weight: i32,
     created_at: chrono::DateTime<chrono::Utc>,
 ) -> Result<(), RewardError> {
     let id = Uuid::new_v4();
 
     sqlx::query(
         r#"
-        INSERT INTO reward_options (id, parent_reward_id, option_reward_id, created_at)
-        VALUES (?, ?, ?, ?)
+        INSERT INTO reward_options (id, parent_reward_id, option_reward_id, weight, created_at)
+        VALUES (?, ?, ?, ?, ?)
         "#,
     )
     .bind(id.to_string())
     .bind(parent_reward_id.to_string())
     .bind(option_reward_id.to_string())
+    .bind(weight)
     .bind(created_at)
     .execute(pool)
     .await?;
@@ -720,18 +1034,26 @@ pub async fn add_reward_option(
         id,
         parent_reward_id: *parent_reward_id,
         option_reward_id: *option_reward_id,
+        weight: 1,
         created_at: now,
     })
 }
 
-/// Get all reward options for a random choice reward
+/// Get all reward options for a random choice reward with their weights
 pub async fn get_reward_options(
     pool: &SqlitePool,
     reward_id: &Uuid,
-) -> Result<Vec<Reward>, RewardError> {
-    let rewards: Vec<RewardRow> = sqlx::query_as(
+) -> Result<Vec<WeightedRewardOption>, RewardError> {
+    #[derive(sqlx::FromRow)]
+    struct OptionRow {
+        #[sqlx(flatten)]
+        reward: RewardRow,
+        option_weight: i32,
+    }
+
+    let rows: Vec<OptionRow> = sqlx::query_as(
         r#"
-        SELECT r.*
+        SELECT r.*, ro.weight as option_weight
         FROM rewards r
         JOIN reward_options ro ON r.id = ro.option_reward_id
         WHERE ro.parent_reward_id = ?
@@ -742,7 +1064,46 @@ pub async fn get_reward_options(
     .fetch_all(pool)
     .await?;
 
-    Ok(rewards.into_iter().map(|r| r.to_shared()).collect())
+    Ok(rows
+        .into_iter()
+        .map(|row| WeightedRewardOption {
+            reward: row.reward.to_shared(),
+            weight: row.option_weight,
+        })
+        .collect())
+}
+
+/// Change how likely an option of a household's random choice reward is picked
+pub async fn set_reward_option_weight(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    parent_reward_id: &Uuid,
+    option_reward_id: &Uuid,
+    weight: i32,
+) -> Result<(), RewardError> {
+    if !(1..=MAX_OPTION_WEIGHT).contains(&weight) {
+        return Err(RewardError::InvalidWeight);
+    }
+
+    let updated = sqlx::query(
+        r#"
+        UPDATE reward_options SET weight = ?
+        WHERE parent_reward_id = ? AND option_reward_id = ?
+          AND parent_reward_id IN (SELECT id FROM rewards WHERE household_id = ?)
+        "#,
+    )
+    .bind(weight)
+    .bind(parent_reward_id.to_string())
+    .bind(option_reward_id.to_string())
+    .bind(household_id.to_string())
+    .execute(pool)
+    .await?
+    .rows_affected();
+
+    if updated == 0 {
+        return Err(RewardError::OptionNotFound);
+    }
+    Ok(())
 }
 
 /// Remove a reward option from a random choice reward
@@ -814,9 +1175,15 @@ pub async fn pick_random_reward(
         return Err(RewardError::NoOptions);
     }
 
-    // Randomly select one
-    let mut rng = rand::thread_rng();
-    let picked = options.choose(&mut rng).ok_or(RewardError::NoOptions)?;
+    // Select one by weight, avoiding the member's recent picks
+    let recent = random_choice::recent_picks(pool, &reward_id, user_id, reward.no_repeat_picks).await?;
+    let weights: Vec<(Uuid, i32)> = options.iter().map(|o| (o.reward.id, o.weight)).collect();
+    let picked_id = random_choice::pick_weighted(&mut rand::thread_rng(), &weights, &recent).ok_or(RewardError::NoOptions)?;
+    let picked = options
+        .into_iter()
+        .find(|o| o.reward.id == picked_id)
+        .ok_or(RewardError::NoOptions)?
+        .reward;
 
     let household_id = Uuid::parse_str(&user_reward.household_id).unwrap();
 
@@ -826,8 +1193,10 @@ pub async fn pick_random_reward(
     // Decrement the random choice reward assignment
     unassign_reward(pool, &reward_id, user_id, &household_id).await?;
 
+    random_choice::record_pick(pool, &household_id, user_id, &reward_id, &picked.id).await?;
+
     Ok(RandomRewardPickResult {
-        picked_reward: picked.clone(),
+        picked_reward: picked,
         user_reward: new_user_reward,
     })
 }
@@ -844,5 +1213,115 @@ mod tests {
         assert_eq!(RewardError::NotRandomChoice.to_string(), "Reward is not a random choice type");
         assert_eq!(RewardError::InsufficientOptions.to_string(), "Random choice reward requires at least 2 options");
         assert_eq!(RewardError::NoOptions.to_string(), "Random choice reward has no options");
+        assert_eq!(RewardError::NothingToGift.to_string(), "No unredeemed rewards to gift");
+        assert_eq!(RewardError::CannotGiftToSelf.to_string(), "Cannot gift a reward to yourself");
+    }
+
+    #[tokio::test]
+    async fn test_gift_reward_transfers_one_unredeemed() {
+        use crate::test_utils::*;
+        use shared::Role;
+
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let alice = create_test_user(&pool, "alice@test.com", Role::Member).await;
+        let bob = create_test_user(&pool, "bob@test.com", Role::Member).await;
+        let reward_id = create_test_reward(&pool, &household_id, "Ice cream", None).await;
+
+        assign_reward(&pool, &reward_id, &alice, &household_id).await.unwrap();
+        let alice_reward = assign_reward(&pool, &reward_id, &alice, &household_id).await.unwrap();
+        redeem_reward(&pool, &alice_reward.id, &alice).await.unwrap();
+
+        // One of two is redeemed, so exactly one can be gifted
+        let (remaining, received) = gift_reward(&pool, &household_id, &alice_reward.id, &alice, &bob).await.unwrap();
+        let remaining = remaining.unwrap();
+        assert_eq!(remaining.amount, 1);
+        assert_eq!(remaining.redeemed_amount, 1);
+        assert_eq!(received.user_id, bob);
+        assert_eq!(received.amount, 1);
+
+        let err = gift_reward(&pool, &household_id, &alice_reward.id, &alice, &bob).await.unwrap_err();
+        assert!(matches!(err, RewardError::NothingToGift));
+
+        let err = gift_reward(&pool, &household_id, &received.id, &bob, &bob).await.unwrap_err();
+        assert!(matches!(err, RewardError::CannotGiftToSelf));
+        let err = gift_reward(&pool, &household_id, &received.id, &alice, &bob).await.unwrap_err();
+        assert!(matches!(err, RewardError::UserRewardNotFound));
+
+        // Bob's only reward row is removed once he gifts it back
+        let (remaining, _) = gift_reward(&pool, &household_id, &received.id, &bob, &alice).await.unwrap();
+        assert!(remaining.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_purchase_approval_deducts_points_only_on_approval() {
+        use crate::test_utils::*;
+        use shared::Role;
+
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let parent = create_test_user(&pool, "parent@test.com", Role::Admin).await;
+        let child = create_test_user(&pool, "child@test.com", Role::Member).await;
+        create_test_membership(&pool, &household_id, &child, Role::Member).await;
+        let reward_id = create_test_reward(&pool, &household_id, "Movie night", Some(30)).await;
+        set_user_points(&pool, &household_id, &child, 50).await;
+
+        let first = request_purchase(&pool, &reward_id, &child, &household_id).await.unwrap();
+        let second = request_purchase(&pool, &reward_id, &child, &household_id).await.unwrap();
+        assert_eq!(first.status, PurchaseStatus::Pending);
+        assert_points_balance(&pool, &household_id, &child, 50).await;
+
+        let pending = list_pending_purchases(&pool, &household_id).await.unwrap();
+        assert_eq!(pending.len(), 2);
+        assert_eq!(pending[0].purchase.id, first.id);
+        assert_eq!(pending[0].user.id, child);
+
+        let (approved, user_reward) = approve_purchase(&pool, &first.id, &household_id, &parent).await.unwrap();
+        assert_eq!(approved.status, PurchaseStatus::Approved);
+        assert_eq!(approved.reviewed_by, Some(parent));
+        assert_eq!(user_reward.amount, 1);
+        assert_points_balance(&pool, &household_id, &child, 20).await;
+
+        // Reviewed purchases cannot be reviewed again
+        let err = reject_purchase(&pool, &first.id, &household_id, &parent).await.unwrap_err();
+        assert!(matches!(err, RewardError::PurchaseNotReviewable));
+
+        // The balance is re-checked on approval
+        let err = approve_purchase(&pool, &second.id, &household_id, &parent).await.unwrap_err();
+        assert!(matches!(err, RewardError::InsufficientPoints));
+        let rejected = reject_purchase(&pool, &second.id, &household_id, &parent).await.unwrap();
+        assert_eq!(rejected.status, PurchaseStatus::Rejected);
+        assert_points_balance(&pool, &household_id, &child, 20).await;
+        assert!(list_pending_purchases(&pool, &household_id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_approvals_cannot_overspend() {
+        use crate::test_utils::*;
+        use shared::Role;
+
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let parent = create_test_user(&pool, "parent@test.com", Role::Admin).await;
+        let child = create_test_user(&pool, "child@test.com", Role::Member).await;
+        create_test_membership(&pool, &household_id, &child, Role::Member).await;
+        let reward_id = create_test_reward(&pool, &household_id, "Movie night", Some(30)).await;
+        set_user_points(&pool, &household_id, &child, 50).await;
+
+        let first = request_purchase(&pool, &reward_id, &child, &household_id).await.unwrap();
+        let second = request_purchase(&pool, &reward_id, &child, &household_id).await.unwrap();
+
+        // Each purchase is affordable on its own, both together are not
+        let (a, b) = tokio::join!(
+            approve_purchase(&pool, &first.id, &household_id, &parent),
+            approve_purchase(&pool, &second.id, &household_id, &parent),
+        );
+        assert_eq!([a.is_ok(), b.is_ok()].iter().filter(|ok| **ok).count(), 1);
+        assert!(matches!(a.err().or(b.err()), Some(RewardError::InsufficientPoints)));
+        assert_points_balance(&pool, &household_id, &child, 20).await;
+
+        // The failed approval left its purchase pending
+        let pending = list_pending_purchases(&pool, &household_id).await.unwrap();
+        assert_eq!(pending.len(), 1);
     }
 }
@@ -2,9 +2,38 @@ use chrono::{Datelike, NaiveDate, NaiveTime, Weekday, DateTime, Utc, TimeZone};
 use chrono_tz::Tz;
 use shared::{RecurrenceType, RecurrenceValue, Task, TimePeriod};
 
+use crate::services::statistics;
+
+/// The postponed occurrence of a task as (original date, new date), if any
+fn postponement(task: &Task) -> Option<(NaiveDate, NaiveDate)> {
+    if task.recurrence_type == RecurrenceType::OneTime {
+        return None;
+    }
+    task.postponed_from.zip(task.postponed_to)
+}
+
 /// Check if a task is due on a specific date based on its recurrence settings.
-/// A task cannot be due before it was created.
-pub fn is_task_due_on_date(task: &Task, date: NaiveDate) -> bool {
+/// A task cannot be due before it was created, after its end date or outside
+/// of its season. A postponed occurrence is due on its new date instead of its
+/// original one. Every-N-weeks intervals count weeks from `week_start_day`
+/// (0 = Monday, 6 = Sunday), the household's week start.
+pub fn is_task_due_on_date(task: &Task, date: NaiveDate, week_start_day: i32) -> bool {
+    if !task.is_in_season(date) || task.ends_on.is_some_and(|end| date > end) {
+        return false;
+    }
+    if let Some((from, to)) = postponement(task) {
+        if date == to {
+            return true;
+        }
+        if date == from {
+            return false;
+        }
+    }
+    is_scheduled_on_date(task, date, week_start_day)
+}
+
+/// Whether the recurrence alone makes the task due on `date`
+fn is_scheduled_on_date(task: &Task, date: NaiveDate, week_start_day: i32) -> bool {
     // Task cannot be due before it was created
     let created_date = task.created_at.date_naive();
     if date < created_date {
@@ -17,9 +46,20 @@ pub fn is_task_due_on_date(task: &Task, date: NaiveDate) -> bool {
             true
         }
 
-        RecurrenceType::Daily => true,
+        RecurrenceType::Daily => match &task.recurrence_value {
+            Some(RecurrenceValue::DayInterval(days)) => {
+                (date - created_date).num_days() % i64::from((*days).max(1)) == 0
+            }
+            _ => true,
+        },
 
         RecurrenceType::Weekly => {
+            if let Some(RecurrenceValue::WeekInterval { weeks, weekday }) = &task.recurrence_value {
+                let week_start = |date| statistics::get_week_start(date, week_start_day);
+                let weeks_since_created = (week_start(date) - week_start(created_date)).num_days() / 7;
+                return date.weekday() == weekday_from_u8(*weekday)
+                    && weeks_since_created % i64::from((*weeks).max(1)) == 0;
+            }
             // Default to the day the task was created if no specific day set
             let target_weekday = match &task.recurrence_value {
                 Some(RecurrenceValue::WeekDay(day)) => weekday_from_u8(*day),
@@ -29,6 +69,9 @@ pub fn is_task_due_on_date(task: &Task, date: NaiveDate) -> bool {
         }
 
         RecurrenceType::Monthly => {
+            if let Some(RecurrenceValue::MonthWeekday { ordinal, weekday }) = &task.recurrence_value {
+                return nth_weekday_of_month(date, *ordinal, weekday_from_u8(*weekday)) == Some(date);
+            }
             // Default to the day of month the task was created
             let target_day = match &task.recurrence_value {
                 Some(RecurrenceValue::MonthDay(day)) => *day as u32,
@@ -67,16 +110,24 @@ pub fn get_previous_due_date(task: &Task, current_date: NaiveDate) -> NaiveDate
             current_date - chrono::Duration::days(1)
         }
 
-        RecurrenceType::Daily => current_date - chrono::Duration::days(1),
+        RecurrenceType::Daily => match &task.recurrence_value {
+            Some(RecurrenceValue::DayInterval(days)) => {
+                let days = i64::from((*days).max(1));
+                let created_date = task.created_at.date_naive();
+                let elapsed = (current_date - created_date).num_days() - 1;
+                created_date + chrono::Duration::days(elapsed.div_euclid(days) * days)
+            }
+            _ => current_date - chrono::Duration::days(1),
+        },
 
-        RecurrenceType::Weekly => current_date - chrono::Duration::days(7),
+        RecurrenceType::Weekly => match &task.recurrence_value {
+            Some(RecurrenceValue::WeekInterval { weeks, .. }) => {
+                current_date - chrono::Duration::weeks(i64::from((*weeks).max(1)))
+            }
+            _ => current_date - chrono::Duration::days(7),
+        },
 
         RecurrenceType::Monthly => {
-            let target_day = match &task.recurrence_value {
-                Some(RecurrenceValue::MonthDay(day)) => *day as u32,
-                _ => task.created_at.day(),
-            };
-
             // Go to previous month
             let prev_month = if current_date.month() == 1 {
                 NaiveDate::from_ymd_opt(current_date.year() - 1, 12, 1).unwrap()
@@ -84,6 +135,15 @@ pub fn get_previous_due_date(task: &Task, current_date: NaiveDate) -> NaiveDate
                 NaiveDate::from_ymd_opt(current_date.year(), current_date.month() - 1, 1).unwrap()
             };
 
+            let target_day = match &task.recurrence_value {
+                Some(RecurrenceValue::MonthDay(day)) => *day as u32,
+                Some(RecurrenceValue::MonthWeekday { ordinal, weekday }) => {
+                    return nth_weekday_of_month(prev_month, *ordinal, weekday_from_u8(*weekday))
+                        .unwrap_or(prev_month);
+                }
+                _ => task.created_at.day(),
+            };
+
             let last_day = get_last_day_of_month(prev_month);
             let effective_day = target_day.min(last_day);
 
@@ -125,17 +185,86 @@ pub fn get_previous_due_date(task: &Task, current_date: NaiveDate) -> NaiveDate
 }
 
 /// Get the next due date for a task on or after the given date
-/// Returns None for OneTime tasks (they have no schedule)
-pub fn get_next_due_date(task: &Task, from_date: NaiveDate) -> Option<NaiveDate> {
+/// Returns None for OneTime tasks (they have no schedule).
+/// A postponed occurrence is replaced by its new date, and occurrences outside
+/// of the task's season are skipped. There is none after the task's end date.
+/// `week_start_day` is the household's week start, as for `is_task_due_on_date`.
+pub fn get_next_due_date(task: &Task, from_date: NaiveDate, week_start_day: i32) -> Option<NaiveDate> {
+    let mut from_date = from_date;
+    // Each round jumps to the start of the next season, so a few cover any schedule
+    for _ in 0..3 {
+        let next = get_next_due_date_any_season(task, from_date, week_start_day)?;
+        if task.ends_on.is_some_and(|end| next > end) {
+            return None;
+        }
+        if task.is_in_season(next) {
+            return Some(next);
+        }
+        from_date = next_season_start(task, next);
+    }
+    None
+}
+
+/// Next due date ignoring the season
+fn get_next_due_date_any_season(task: &Task, from_date: NaiveDate, week_start_day: i32) -> Option<NaiveDate> {
+    let scheduled = get_scheduled_due_date(task, from_date, week_start_day);
+
+    let Some((from, to)) = postponement(task) else {
+        return scheduled;
+    };
+    if from_date > to {
+        return scheduled;
+    }
+
+    // Skip the original occurrence, then whichever comes first wins
+    let scheduled = if scheduled == Some(from) {
+        get_scheduled_due_date(task, from + chrono::Duration::days(1), week_start_day)
+    } else {
+        scheduled
+    };
+    Some(scheduled.map_or(to, |date| date.min(to)))
+}
+
+/// Next due date according to the recurrence alone, ignoring postponements
+fn get_scheduled_due_date(task: &Task, from_date: NaiveDate, week_start_day: i32) -> Option<NaiveDate> {
     match task.recurrence_type {
         RecurrenceType::OneTime => {
             // OneTime tasks don't have a recurring schedule
             None
         }
 
-        RecurrenceType::Daily => Some(from_date),
+        RecurrenceType::Daily => match &task.recurrence_value {
+            Some(RecurrenceValue::DayInterval(days)) => {
+                let days = i64::from((*days).max(1));
+                let created_date = task.created_at.date_naive();
+                if from_date <= created_date {
+                    return Some(created_date);
+                }
+                let intervals = ((from_date - created_date).num_days() + days - 1) / days;
+                Some(created_date + chrono::Duration::days(intervals * days))
+            }
+            _ => Some(from_date),
+        },
 
         RecurrenceType::Weekly => {
+            if let Some(RecurrenceValue::WeekInterval { weeks, weekday }) = &task.recurrence_value {
+                let weeks = i64::from((*weeks).max(1));
+                let created_date = task.created_at.date_naive();
+                let week_start = |date| statistics::get_week_start(date, week_start_day);
+                let first_week = week_start(created_date);
+                let days_into_week = (weekday_from_u8(*weekday).num_days_from_monday() as i64 - i64::from(week_start_day)).rem_euclid(7);
+                let offset = chrono::Duration::days(days_into_week);
+                // Start with the active week at or before from_date, then move on by whole intervals
+                let weeks_since_created = (week_start(from_date) - first_week).num_days().max(0) / 7;
+                let mut candidate = first_week
+                    + chrono::Duration::weeks(weeks_since_created / weeks * weeks)
+                    + offset;
+                while candidate < from_date || candidate < created_date {
+                    candidate += chrono::Duration::weeks(weeks);
+                }
+                return Some(candidate);
+            }
+
             let target_weekday = match &task.recurrence_value {
                 Some(RecurrenceValue::WeekDay(day)) => weekday_from_u8(*day),
                 _ => from_date.weekday(), // Default to current weekday
@@ -157,6 +286,16 @@ pub fn get_next_due_date(task: &Task, from_date: NaiveDate) -> Option<NaiveDate>
         }
 
         RecurrenceType::Monthly => {
+            if let Some(RecurrenceValue::MonthWeekday { ordinal, weekday }) = &task.recurrence_value {
+                let weekday = weekday_from_u8(*weekday);
+                let this_month = nth_weekday_of_month(from_date, *ordinal, weekday)
+                    .filter(|date| *date >= from_date);
+                return this_month.or_else(|| {
+                    let next_month = get_last_day_of_month(from_date) - from_date.day() + 1;
+                    nth_weekday_of_month(from_date + chrono::Duration::days(next_month as i64), *ordinal, weekday)
+                });
+            }
+
             let target_day = match &task.recurrence_value {
                 Some(RecurrenceValue::MonthDay(day)) => *day as u32,
                 _ => task.created_at.day(),
@@ -304,6 +443,27 @@ fn weekday_to_u8(weekday: Weekday) -> u8 {
     }
 }
 
+/// First day of the task's season after `date`
+fn next_season_start(task: &Task, date: NaiveDate) -> NaiveDate {
+    let (month, day) = task.active_from.as_deref().and_then(shared::parse_month_day).unwrap_or((1, 1));
+    // February 29th starts the season on March 1st in other years
+    let season_start = |year| NaiveDate::from_ymd_opt(year, month, day).or_else(|| NaiveDate::from_ymd_opt(year, 3, 1));
+    season_start(date.year())
+        .filter(|start| *start > date)
+        .or_else(|| season_start(date.year() + 1))
+        .unwrap_or(date + chrono::Duration::days(1))
+}
+
+/// The `ordinal`th `weekday` in the month of `date`, or the last one for -1
+fn nth_weekday_of_month(date: NaiveDate, ordinal: i8, weekday: Weekday) -> Option<NaiveDate> {
+    if ordinal < 0 {
+        let last = NaiveDate::from_ymd_opt(date.year(), date.month(), get_last_day_of_month(date))?;
+        let days_back = (last.weekday().num_days_from_monday() + 7 - weekday.num_days_from_monday()) % 7;
+        return Some(last - chrono::Duration::days(days_back as i64));
+    }
+    NaiveDate::from_weekday_of_month_opt(date.year(), date.month(), weekday, ordinal as u8)
+}
+
 fn get_last_day_of_month(date: NaiveDate) -> u32 {
     let (year, month) = (date.year(), date.month());
     let next_month = if month == 12 {
@@ -322,6 +482,14 @@ pub fn parse_timezone(tz_str: &str) -> Tz {
     tz_str.parse().unwrap_or(chrono_tz::UTC)
 }
 
+/// Local midnight of `date` in `tz` as UTC
+pub fn local_midnight_utc(date: NaiveDate, tz: Tz) -> DateTime<Utc> {
+    tz.from_local_datetime(&date.and_time(NaiveTime::MIN))
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|| date.and_time(NaiveTime::MIN).and_utc())
+}
+
 /// Get the current date in a specific timezone
 pub fn today_in_timezone(tz: Tz) -> NaiveDate {
     Utc::now().with_timezone(&tz).date_naive()
@@ -360,7 +528,6 @@ pub fn is_task_overdue(task: &Task, due_date: NaiveDate, timezone: &str, now_utc
 }
 
 /// Get the deadline DateTime in UTC for a task on a specific due date
-#[allow(dead_code)]
 pub fn get_task_deadline_utc(task: &Task, due_date: NaiveDate, timezone: &str) -> Option<DateTime<Utc>> {
     let tz = parse_timezone(timezone);
     let due_time = parse_due_time(task.due_time.as_deref());
@@ -391,20 +558,32 @@ mod tests {
             recurrence_type,
             recurrence_value,
             assigned_user_id: None,
+            assignee_ids: Vec::new(),
             target_count: 1,
             time_period: None,
             allow_exceed_target: true,
             requires_review: false,
+            reviewer_type: shared::ReviewerType::AnyReviewer,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: shared::HabitType::Good,
             category_id: None,
             category_name: None,
+            tag_ids: Vec::new(),
             archived: false,
             paused: false,
             suggestion: None,
             suggested_by: None,
+            postponed_from: None,
+            postponed_to: None,
+            version: 1,
             created_at: old_date,
             updated_at: old_date,
         }
@@ -423,20 +602,32 @@ mod tests {
             recurrence_type,
             recurrence_value,
             assigned_user_id: None,
+            assignee_ids: Vec::new(),
             target_count: 1,
             time_period: None,
             allow_exceed_target: true,
             requires_review: false,
+            reviewer_type: shared::ReviewerType::AnyReviewer,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: shared::HabitType::Good,
             category_id: None,
             category_name: None,
+            tag_ids: Vec::new(),
             archived: false,
             paused: false,
             suggestion: None,
             suggested_by: None,
+            postponed_from: None,
+            postponed_to: None,
+            version: 1,
             created_at,
             updated_at: created_at,
         }
@@ -457,15 +648,15 @@ mod tests {
         let jan13 = NaiveDate::from_ymd_opt(2024, 1, 13).unwrap();
         let jan1 = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
 
-        assert!(!is_task_due_on_date(&task, jan14), "Task should not be due day before creation");
-        assert!(!is_task_due_on_date(&task, jan13), "Task should not be due 2 days before creation");
-        assert!(!is_task_due_on_date(&task, jan1), "Task should not be due 2 weeks before creation");
+        assert!(!is_task_due_on_date(&task, jan14, 0), "Task should not be due day before creation");
+        assert!(!is_task_due_on_date(&task, jan13, 0), "Task should not be due 2 days before creation");
+        assert!(!is_task_due_on_date(&task, jan1, 0), "Task should not be due 2 weeks before creation");
 
         // Should be due on creation date and after
         let jan15 = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
         let jan16 = NaiveDate::from_ymd_opt(2024, 1, 16).unwrap();
-        assert!(is_task_due_on_date(&task, jan15), "Task should be due on creation date");
-        assert!(is_task_due_on_date(&task, jan16), "Task should be due day after creation");
+        assert!(is_task_due_on_date(&task, jan15, 0), "Task should be due on creation date");
+        assert!(is_task_due_on_date(&task, jan16, 0), "Task should be due day after creation");
     }
 
     #[test]
@@ -484,15 +675,15 @@ mod tests {
 
         // Previous Monday (Jan 8) should NOT be due
         let jan8 = NaiveDate::from_ymd_opt(2024, 1, 8).unwrap();
-        assert!(!is_task_due_on_date(&task, jan8), "Task should not be due on Monday before creation");
+        assert!(!is_task_due_on_date(&task, jan8, 0), "Task should not be due on Monday before creation");
 
         // Creation Monday (Jan 15) should be due
         let jan15 = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
-        assert!(is_task_due_on_date(&task, jan15), "Task should be due on creation Monday");
+        assert!(is_task_due_on_date(&task, jan15, 0), "Task should be due on creation Monday");
 
         // Next Monday (Jan 22) should be due
         let jan22 = NaiveDate::from_ymd_opt(2024, 1, 22).unwrap();
-        assert!(is_task_due_on_date(&task, jan22), "Task should be due on next Monday");
+        assert!(is_task_due_on_date(&task, jan22, 0), "Task should be due on next Monday");
     }
 
     #[test]
@@ -503,9 +694,9 @@ mod tests {
         let date2 = NaiveDate::from_ymd_opt(2024, 1, 16).unwrap();
         let date3 = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
 
-        assert!(is_task_due_on_date(&task, date1));
-        assert!(is_task_due_on_date(&task, date2));
-        assert!(is_task_due_on_date(&task, date3));
+        assert!(is_task_due_on_date(&task, date1, 0));
+        assert!(is_task_due_on_date(&task, date2, 0));
+        assert!(is_task_due_on_date(&task, date3, 0));
     }
 
     #[test]
@@ -516,9 +707,9 @@ mod tests {
         let tuesday = NaiveDate::from_ymd_opt(2024, 1, 16).unwrap();
         let next_monday = NaiveDate::from_ymd_opt(2024, 1, 22).unwrap();
 
-        assert!(is_task_due_on_date(&task, monday));
-        assert!(!is_task_due_on_date(&task, tuesday));
-        assert!(is_task_due_on_date(&task, next_monday));
+        assert!(is_task_due_on_date(&task, monday, 0));
+        assert!(!is_task_due_on_date(&task, tuesday, 0));
+        assert!(is_task_due_on_date(&task, next_monday, 0));
     }
 
     #[test]
@@ -529,9 +720,9 @@ mod tests {
         let jan16 = NaiveDate::from_ymd_opt(2024, 1, 16).unwrap();
         let feb15 = NaiveDate::from_ymd_opt(2024, 2, 15).unwrap();
 
-        assert!(is_task_due_on_date(&task, jan15));
-        assert!(!is_task_due_on_date(&task, jan16));
-        assert!(is_task_due_on_date(&task, feb15));
+        assert!(is_task_due_on_date(&task, jan15, 0));
+        assert!(!is_task_due_on_date(&task, jan16, 0));
+        assert!(is_task_due_on_date(&task, feb15, 0));
     }
 
     #[test]
@@ -542,8 +733,8 @@ mod tests {
         let feb29_2024 = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
         let feb28_2024 = NaiveDate::from_ymd_opt(2024, 2, 28).unwrap();
 
-        assert!(is_task_due_on_date(&task, feb29_2024)); // Last day of Feb
-        assert!(!is_task_due_on_date(&task, feb28_2024));
+        assert!(is_task_due_on_date(&task, feb29_2024, 0)); // Last day of Feb
+        assert!(!is_task_due_on_date(&task, feb28_2024, 0));
     }
 
     #[test]
@@ -559,11 +750,11 @@ mod tests {
         let thursday = NaiveDate::from_ymd_opt(2024, 1, 18).unwrap();
         let friday = NaiveDate::from_ymd_opt(2024, 1, 19).unwrap();
 
-        assert!(is_task_due_on_date(&task, monday));
-        assert!(!is_task_due_on_date(&task, tuesday));
-        assert!(is_task_due_on_date(&task, wednesday));
-        assert!(!is_task_due_on_date(&task, thursday));
-        assert!(is_task_due_on_date(&task, friday));
+        assert!(is_task_due_on_date(&task, monday, 0));
+        assert!(!is_task_due_on_date(&task, tuesday, 0));
+        assert!(is_task_due_on_date(&task, wednesday, 0));
+        assert!(!is_task_due_on_date(&task, thursday, 0));
+        assert!(is_task_due_on_date(&task, friday, 0));
     }
 
     #[test]
@@ -579,9 +770,9 @@ mod tests {
         let jan16 = NaiveDate::from_ymd_opt(2024, 1, 16).unwrap();
         let feb20 = NaiveDate::from_ymd_opt(2024, 2, 20).unwrap();
 
-        assert!(is_task_due_on_date(&task, jan15));
-        assert!(!is_task_due_on_date(&task, jan16));
-        assert!(is_task_due_on_date(&task, feb20));
+        assert!(is_task_due_on_date(&task, jan15, 0));
+        assert!(!is_task_due_on_date(&task, jan16, 0));
+        assert!(is_task_due_on_date(&task, feb20, 0));
     }
 
     #[test]
@@ -611,7 +802,7 @@ mod tests {
         let task = create_test_task(RecurrenceType::OneTime, None);
         let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
 
-        assert_eq!(get_next_due_date(&task, date), None);
+        assert_eq!(get_next_due_date(&task, date, 0), None);
     }
 
     #[test]
@@ -619,7 +810,7 @@ mod tests {
         let task = create_test_task(RecurrenceType::Daily, None);
         let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
 
-        assert_eq!(get_next_due_date(&task, date), Some(date));
+        assert_eq!(get_next_due_date(&task, date, 0), Some(date));
     }
 
     #[test]
@@ -628,7 +819,7 @@ mod tests {
         let task = create_test_task(RecurrenceType::Weekly, Some(RecurrenceValue::WeekDay(1)));
         let monday = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(); // Monday
 
-        assert_eq!(get_next_due_date(&task, monday), Some(monday));
+        assert_eq!(get_next_due_date(&task, monday, 0), Some(monday));
     }
 
     #[test]
@@ -638,7 +829,7 @@ mod tests {
         let wednesday = NaiveDate::from_ymd_opt(2024, 1, 17).unwrap(); // Wednesday
         let next_monday = NaiveDate::from_ymd_opt(2024, 1, 22).unwrap();
 
-        assert_eq!(get_next_due_date(&task, wednesday), Some(next_monday));
+        assert_eq!(get_next_due_date(&task, wednesday, 0), Some(next_monday));
     }
 
     #[test]
@@ -648,7 +839,7 @@ mod tests {
         let monday = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(); // Monday
         let friday = NaiveDate::from_ymd_opt(2024, 1, 19).unwrap();
 
-        assert_eq!(get_next_due_date(&task, monday), Some(friday));
+        assert_eq!(get_next_due_date(&task, monday, 0), Some(friday));
     }
 
     #[test]
@@ -656,7 +847,7 @@ mod tests {
         let task = create_test_task(RecurrenceType::Monthly, Some(RecurrenceValue::MonthDay(15)));
         let jan15 = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
 
-        assert_eq!(get_next_due_date(&task, jan15), Some(jan15));
+        assert_eq!(get_next_due_date(&task, jan15, 0), Some(jan15));
     }
 
     #[test]
@@ -665,7 +856,7 @@ mod tests {
         let jan15 = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
         let jan20 = NaiveDate::from_ymd_opt(2024, 1, 20).unwrap();
 
-        assert_eq!(get_next_due_date(&task, jan15), Some(jan20));
+        assert_eq!(get_next_due_date(&task, jan15, 0), Some(jan20));
     }
 
     #[test]
@@ -674,7 +865,7 @@ mod tests {
         let jan15 = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
         let feb10 = NaiveDate::from_ymd_opt(2024, 2, 10).unwrap();
 
-        assert_eq!(get_next_due_date(&task, jan15), Some(feb10));
+        assert_eq!(get_next_due_date(&task, jan15, 0), Some(feb10));
     }
 
     #[test]
@@ -684,7 +875,7 @@ mod tests {
         let feb1 = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
         let feb29 = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(); // Leap year
 
-        assert_eq!(get_next_due_date(&task, feb1), Some(feb29));
+        assert_eq!(get_next_due_date(&task, feb1, 0), Some(feb29));
     }
 
     #[test]
@@ -701,8 +892,8 @@ mod tests {
         let next_monday = NaiveDate::from_ymd_opt(2024, 1, 22).unwrap();
 
         // When completing on Monday, should get NEXT Monday, not today
-        assert_eq!(get_next_due_date(&task, monday), Some(next_monday));
-        assert_eq!(get_next_due_date(&task, tuesday), Some(wednesday));
+        assert_eq!(get_next_due_date(&task, monday, 0), Some(next_monday));
+        assert_eq!(get_next_due_date(&task, tuesday, 0), Some(wednesday));
     }
 
     #[test]
@@ -719,10 +910,10 @@ mod tests {
         let jan20 = NaiveDate::from_ymd_opt(2024, 1, 20).unwrap();
         let feb20 = NaiveDate::from_ymd_opt(2024, 2, 20).unwrap();
 
-        assert_eq!(get_next_due_date(&task, jan10), Some(jan15));
+        assert_eq!(get_next_due_date(&task, jan10, 0), Some(jan15));
         // When completing on jan15, should get NEXT custom date after jan15 (feb20), not jan15
-        assert_eq!(get_next_due_date(&task, jan15), Some(feb20));
-        assert_eq!(get_next_due_date(&task, jan20), Some(feb20));
+        assert_eq!(get_next_due_date(&task, jan15, 0), Some(feb20));
+        assert_eq!(get_next_due_date(&task, jan20, 0), Some(feb20));
     }
 
     #[test]
@@ -733,7 +924,7 @@ mod tests {
         let task = create_test_task(RecurrenceType::Custom, Some(RecurrenceValue::CustomDates(dates)));
 
         let jan20 = NaiveDate::from_ymd_opt(2024, 1, 20).unwrap();
-        assert_eq!(get_next_due_date(&task, jan20), None);
+        assert_eq!(get_next_due_date(&task, jan20, 0), None);
     }
 
     #[test]
@@ -747,7 +938,7 @@ mod tests {
         // Complete on Sunday (before Monday) - should return Monday
         let sunday = NaiveDate::from_ymd_opt(2024, 1, 14).unwrap();
         let monday = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
-        assert_eq!(get_next_due_date(&task, sunday), Some(monday));
+        assert_eq!(get_next_due_date(&task, sunday, 0), Some(monday));
     }
 
     #[test]
@@ -761,7 +952,7 @@ mod tests {
         // Complete on Monday (scheduled day) - should return next Monday
         let monday = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
         let next_monday = NaiveDate::from_ymd_opt(2024, 1, 22).unwrap();
-        assert_eq!(get_next_due_date(&task, monday), Some(next_monday));
+        assert_eq!(get_next_due_date(&task, monday, 0), Some(next_monday));
     }
 
     #[test]
@@ -777,7 +968,7 @@ mod tests {
         // Complete on Feb 24 (before Feb 25) - should return Feb 25
         let feb24 = NaiveDate::from_ymd_opt(2024, 2, 24).unwrap();
         let feb25 = NaiveDate::from_ymd_opt(2024, 2, 25).unwrap();
-        assert_eq!(get_next_due_date(&task, feb24), Some(feb25));
+        assert_eq!(get_next_due_date(&task, feb24, 0), Some(feb25));
     }
 
     #[test]
@@ -793,7 +984,7 @@ mod tests {
         // Complete on Feb 25 (scheduled date) - should return next custom date after Feb 25 (Feb 28)
         let feb25 = NaiveDate::from_ymd_opt(2024, 2, 25).unwrap();
         let feb28 = NaiveDate::from_ymd_opt(2024, 2, 28).unwrap();
-        assert_eq!(get_next_due_date(&task, feb25), Some(feb28));
+        assert_eq!(get_next_due_date(&task, feb25, 0), Some(feb28));
     }
 
     #[test]
@@ -807,7 +998,7 @@ mod tests {
         // Complete on Tuesday - should return next Monday (7 days later)
         let tuesday = NaiveDate::from_ymd_opt(2024, 1, 16).unwrap();
         let next_monday = NaiveDate::from_ymd_opt(2024, 1, 22).unwrap();
-        assert_eq!(get_next_due_date(&task, tuesday), Some(next_monday));
+        assert_eq!(get_next_due_date(&task, tuesday, 0), Some(next_monday));
     }
 
     #[test]
@@ -821,7 +1012,7 @@ mod tests {
 
         // All custom dates are in the past - should return None
         let feb1 = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
-        assert_eq!(get_next_due_date(&task, feb1), None);
+        assert_eq!(get_next_due_date(&task, feb1, 0), None);
     }
 
     #[test]
@@ -948,4 +1139,173 @@ mod tests {
         assert_eq!(start, feb28, "Different custom date = different period start");
         assert_eq!(end, feb28, "Different custom date = different period end");
     }
+
+    #[test]
+    fn test_postponed_occurrence_moves_due_date() {
+        // Monday task postponed to Wednesday
+        let mut task = create_test_task(RecurrenceType::Weekly, Some(RecurrenceValue::WeekDay(1)));
+        let monday = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let wednesday = NaiveDate::from_ymd_opt(2024, 1, 17).unwrap();
+        let thursday = NaiveDate::from_ymd_opt(2024, 1, 18).unwrap();
+        let next_monday = NaiveDate::from_ymd_opt(2024, 1, 22).unwrap();
+        task.postponed_from = Some(monday);
+        task.postponed_to = Some(wednesday);
+
+        assert_eq!(get_next_due_date(&task, monday, 0), Some(wednesday));
+        assert_eq!(get_next_due_date(&task, wednesday, 0), Some(wednesday));
+        assert_eq!(get_next_due_date(&task, thursday, 0), Some(next_monday));
+
+        assert!(!is_task_due_on_date(&task, monday, 0));
+        assert!(is_task_due_on_date(&task, wednesday, 0));
+        assert!(is_task_due_on_date(&task, next_monday, 0));
+    }
+
+    #[test]
+    fn test_postponed_past_next_occurrence_keeps_schedule() {
+        // Daily task postponed by two days: the following days stay due
+        let mut task = create_test_task(RecurrenceType::Daily, None);
+        let monday = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let tuesday = NaiveDate::from_ymd_opt(2024, 1, 16).unwrap();
+        task.postponed_from = Some(monday);
+        task.postponed_to = Some(NaiveDate::from_ymd_opt(2024, 1, 17).unwrap());
+
+        assert_eq!(get_next_due_date(&task, monday, 0), Some(tuesday));
+        assert!(!is_task_due_on_date(&task, monday, 0));
+        assert!(is_task_due_on_date(&task, tuesday, 0));
+    }
+
+    #[test]
+    fn test_day_interval_counts_from_creation() {
+        // Every 3 days, starting on the day of creation (Jan 15)
+        let created_at = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap().and_hms_opt(12, 0, 0).unwrap().and_utc();
+        let task = create_test_task_with_created_at(RecurrenceType::Daily, Some(RecurrenceValue::DayInterval(3)), created_at);
+        let jan = |day| NaiveDate::from_ymd_opt(2024, 1, day).unwrap();
+
+        assert!(is_task_due_on_date(&task, jan(15), 0));
+        assert!(!is_task_due_on_date(&task, jan(16), 0));
+        assert!(!is_task_due_on_date(&task, jan(17), 0));
+        assert!(is_task_due_on_date(&task, jan(18), 0));
+        assert!(is_task_due_on_date(&task, jan(21), 0));
+
+        assert_eq!(get_next_due_date(&task, jan(10), 0), Some(jan(15)));
+        assert_eq!(get_next_due_date(&task, jan(16), 0), Some(jan(18)));
+        assert_eq!(get_next_due_date(&task, jan(18), 0), Some(jan(18)));
+        assert_eq!(get_previous_due_date(&task, jan(20)), jan(18));
+        assert_eq!(get_period_bounds(&task, jan(18)), (jan(18), jan(18)));
+    }
+
+    #[test]
+    fn test_week_interval_every_other_tuesday() {
+        // Created on Wednesday Jan 17; the week of Jan 15 is the first active week
+        let created_at = NaiveDate::from_ymd_opt(2024, 1, 17).unwrap().and_hms_opt(12, 0, 0).unwrap().and_utc();
+        let task = create_test_task_with_created_at(
+            RecurrenceType::Weekly,
+            Some(RecurrenceValue::WeekInterval { weeks: 2, weekday: 2 }),
+            created_at,
+        );
+        let date = |month, day| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+
+        assert!(!is_task_due_on_date(&task, date(1, 16), 0), "Tuesday before creation");
+        assert!(!is_task_due_on_date(&task, date(1, 23), 0), "Tuesday of an off week");
+        assert!(is_task_due_on_date(&task, date(1, 30), 0));
+        assert!(!is_task_due_on_date(&task, date(1, 31), 0), "Wrong day of week");
+        assert!(is_task_due_on_date(&task, date(2, 13), 0));
+
+        assert_eq!(get_next_due_date(&task, date(1, 17), 0), Some(date(1, 30)));
+        assert_eq!(get_next_due_date(&task, date(1, 30), 0), Some(date(1, 30)));
+        assert_eq!(get_next_due_date(&task, date(1, 31), 0), Some(date(2, 13)));
+    }
+
+    #[test]
+    fn test_week_interval_follows_household_week_start() {
+        // Created on Monday Jan 15, every other Sunday
+        let created_at = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap().and_hms_opt(12, 0, 0).unwrap().and_utc();
+        let task = create_test_task_with_created_at(
+            RecurrenceType::Weekly,
+            Some(RecurrenceValue::WeekInterval { weeks: 2, weekday: 0 }),
+            created_at,
+        );
+        let date = |month, day| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+
+        // Monday weeks: Jan 21 ends the first active week
+        assert!(is_task_due_on_date(&task, date(1, 21), 0));
+        assert!(!is_task_due_on_date(&task, date(1, 28), 0));
+        assert_eq!(get_next_due_date(&task, date(1, 15), 0), Some(date(1, 21)));
+
+        // Sunday weeks: the first active week began on Jan 14, before the task existed
+        assert!(!is_task_due_on_date(&task, date(1, 21), 6));
+        assert!(is_task_due_on_date(&task, date(1, 28), 6));
+        assert_eq!(get_next_due_date(&task, date(1, 15), 6), Some(date(1, 28)));
+        assert_eq!(get_next_due_date(&task, date(1, 29), 6), Some(date(2, 11)));
+    }
+
+    #[test]
+    fn test_month_weekday_first_and_last() {
+        let first_saturday = create_test_task(
+            RecurrenceType::Monthly,
+            Some(RecurrenceValue::MonthWeekday { ordinal: 1, weekday: 6 }),
+        );
+        let date = |month, day| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+
+        assert!(is_task_due_on_date(&first_saturday, date(1, 6), 0));
+        assert!(!is_task_due_on_date(&first_saturday, date(1, 13), 0));
+        assert!(is_task_due_on_date(&first_saturday, date(2, 3), 0));
+        assert_eq!(get_next_due_date(&first_saturday, date(1, 6), 0), Some(date(1, 6)));
+        assert_eq!(get_next_due_date(&first_saturday, date(1, 7), 0), Some(date(2, 3)));
+        assert_eq!(get_previous_due_date(&first_saturday, date(2, 10)), date(1, 6));
+
+        // February 2024 ends on a Thursday, so its last Friday is the 23rd
+        let last_friday = create_test_task(
+            RecurrenceType::Monthly,
+            Some(RecurrenceValue::MonthWeekday { ordinal: -1, weekday: 5 }),
+        );
+        assert!(is_task_due_on_date(&last_friday, date(2, 23), 0));
+        assert!(!is_task_due_on_date(&last_friday, date(2, 16), 0));
+        assert_eq!(get_next_due_date(&last_friday, date(2, 24), 0), Some(date(3, 29)));
+    }
+
+    #[test]
+    fn test_seasonal_task_skips_winter() {
+        // Mow the lawn every Saturday from April to October
+        let mut task = create_test_task(RecurrenceType::Weekly, Some(RecurrenceValue::WeekDay(6)));
+        task.active_from = Some("04-01".to_string());
+        task.active_until = Some("10-31".to_string());
+        let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+
+        assert!(is_task_due_on_date(&task, date(2024, 4, 6), 0));
+        assert!(is_task_due_on_date(&task, date(2024, 10, 26), 0));
+        assert!(!is_task_due_on_date(&task, date(2024, 11, 2), 0));
+        assert!(!is_task_due_on_date(&task, date(2024, 3, 30), 0));
+
+        assert_eq!(get_next_due_date(&task, date(2024, 10, 20), 0), Some(date(2024, 10, 26)));
+        assert_eq!(get_next_due_date(&task, date(2024, 10, 27), 0), Some(date(2025, 4, 5)));
+        assert_eq!(get_next_due_date(&task, date(2025, 1, 15), 0), Some(date(2025, 4, 5)));
+    }
+
+    #[test]
+    fn test_seasonal_task_across_new_year() {
+        let mut task = create_test_task(RecurrenceType::Daily, None);
+        task.active_from = Some("12-01".to_string());
+        task.active_until = Some("02-29".to_string());
+        let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+
+        assert!(is_task_due_on_date(&task, date(2024, 12, 31), 0));
+        assert!(is_task_due_on_date(&task, date(2025, 1, 1), 0));
+        assert!(!is_task_due_on_date(&task, date(2025, 3, 1), 0));
+        assert_eq!(get_next_due_date(&task, date(2025, 2, 28), 0), Some(date(2025, 2, 28)));
+        assert_eq!(get_next_due_date(&task, date(2025, 3, 1), 0), Some(date(2025, 12, 1)));
+    }
+
+    #[test]
+    fn test_task_with_end_date() {
+        // Physiotherapy exercises every Monday until the end of March
+        let mut task = create_test_task(RecurrenceType::Weekly, Some(RecurrenceValue::WeekDay(1)));
+        task.ends_on = Some(NaiveDate::from_ymd_opt(2024, 3, 31).unwrap());
+        let date = |month, day| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+
+        assert!(is_task_due_on_date(&task, date(3, 25), 0));
+        assert!(!is_task_due_on_date(&task, date(4, 1), 0));
+        assert_eq!(get_next_due_date(&task, date(3, 20), 0), Some(date(3, 25)));
+        assert_eq!(get_next_due_date(&task, date(3, 26), 0), None);
+    }
 }
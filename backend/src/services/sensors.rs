@@ -0,0 +1,184 @@
+//! Flat, poll-friendly household summaries for wall displays and home automation
+//!
+//! Counts are derived from the same due-task view as the dashboard. Tasks with
+//! several assignees count as open as long as any assignee hasn't finished.
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::services::households::{self as household_service, HouseholdError};
+use crate::services::tasks::{self as task_service, TaskError};
+use shared::{HouseholdSensor, MemberSensor, MemberWithUser, SensorTask, Task};
+
+#[derive(Debug, Error)]
+pub enum SensorError {
+    #[error("Household not found")]
+    NotFound,
+    #[error(transparent)]
+    Household(#[from] HouseholdError),
+    #[error(transparent)]
+    Task(#[from] TaskError),
+}
+
+/// A task due today and who still has to do it
+struct DueTaskState {
+    task: Task,
+    completions: i32,
+    target: i32,
+    /// Assignees that haven't reached the target yet
+    open_for: Vec<Uuid>,
+    open: bool,
+}
+
+async fn due_task_states(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    members: &[MemberWithUser],
+) -> Result<Vec<DueTaskState>, SensorError> {
+    // Completions of tasks with several assignees are counted per member,
+    // so look at the due tasks from every member's point of view
+    let mut own_completions: HashMap<(Uuid, Uuid), i32> = HashMap::new();
+    let mut due_tasks = Vec::new();
+    for (index, member) in members.iter().enumerate() {
+        let statuses = task_service::get_due_tasks(pool, household_id, &member.user.id).await?;
+        for status in statuses {
+            own_completions.insert((status.task.id, member.user.id), status.completions_today);
+            if index == 0 {
+                due_tasks.push(status);
+            }
+        }
+    }
+
+    Ok(due_tasks
+        .into_iter()
+        .map(|status| {
+            let task = status.task;
+            let assignees = task.assignees();
+            let target = task.target_count;
+
+            let (completions, target, open_for) = if task.has_individual_completions() {
+                let counts: Vec<(Uuid, i32)> = assignees
+                    .iter()
+                    .map(|user| (*user, own_completions.get(&(task.id, *user)).copied().unwrap_or(0)))
+                    .collect();
+                let open_for = counts.iter().filter(|(_, count)| *count < target).map(|(user, _)| *user).collect();
+                (counts.iter().map(|(_, count)| count).sum(), target * assignees.len() as i32, open_for)
+            } else if status.completions_today < target {
+                (status.completions_today, target, assignees.clone())
+            } else {
+                (status.completions_today, target, Vec::new())
+            };
+            let open = if assignees.is_empty() { completions < target } else { !open_for.is_empty() };
+
+            DueTaskState { task, completions, target, open_for, open }
+        })
+        .collect())
+}
+
+/// Due and open task counts for the household and each member, for today
+pub async fn household_sensor(pool: &SqlitePool, household_id: &Uuid) -> Result<HouseholdSensor, SensorError> {
+    let household = household_service::get_household(pool, household_id)
+        .await?
+        .ok_or(SensorError::NotFound)?;
+    let members = household_service::list_members(pool, household_id).await?;
+    let states = due_task_states(pool, household_id, &members).await?;
+
+    let member_sensors = members
+        .iter()
+        .map(|member| {
+            let user_id = member.user.id;
+            let assigned: Vec<&DueTaskState> =
+                states.iter().filter(|state| state.task.assignees().contains(&user_id)).collect();
+            MemberSensor {
+                user_id,
+                username: member.user.username.clone(),
+                points: member.membership.points,
+                due_today: assigned.len() as i64,
+                open_today: assigned.iter().filter(|state| state.open_for.contains(&user_id)).count() as i64,
+            }
+        })
+        .collect();
+
+    Ok(HouseholdSensor {
+        household_id: *household_id,
+        household_name: household.name,
+        date: Utc::now().date_naive(),
+        due_today: states.len() as i64,
+        open_today: states.iter().filter(|state| state.open).count() as i64,
+        unassigned_open: states
+            .iter()
+            .filter(|state| state.open && state.task.assignees().is_empty())
+            .count() as i64,
+        members: member_sensors,
+    })
+}
+
+/// Tasks due today with their progress, open tasks first
+pub async fn due_tasks(pool: &SqlitePool, household_id: &Uuid) -> Result<Vec<SensorTask>, SensorError> {
+    let members = household_service::list_members(pool, household_id).await?;
+    let usernames: HashMap<Uuid, String> =
+        members.iter().map(|member| (member.user.id, member.user.username.clone())).collect();
+
+    let mut tasks: Vec<SensorTask> = due_task_states(pool, household_id, &members)
+        .await?
+        .into_iter()
+        .map(|state| SensorTask {
+            id: state.task.id,
+            title: state.task.title.clone(),
+            assignees: state
+                .task
+                .assignees()
+                .iter()
+                .filter_map(|user| usernames.get(user).cloned())
+                .collect(),
+            completions_today: state.completions,
+            target_count: state.target,
+            done: !state.open,
+        })
+        .collect();
+    tasks.sort_by_key(|task| task.done);
+
+    Ok(tasks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{create_test_household, create_test_membership, create_test_pool, create_test_task, create_test_user};
+    use shared::Role;
+
+    #[tokio::test]
+    async fn test_household_sensor_counts_open_tasks_per_member() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let alice = create_test_user(&pool, "alice@test.com", Role::Member).await;
+        let bob = create_test_user(&pool, "bob@test.com", Role::Member).await;
+        create_test_membership(&pool, &household_id, &alice, Role::Member).await;
+        create_test_membership(&pool, &household_id, &bob, Role::Member).await;
+
+        let dishes = create_test_task(&pool, &household_id).with_assigned_user(alice).build().await;
+        create_test_task(&pool, &household_id).with_assigned_user(bob).build().await;
+        create_test_task(&pool, &household_id).build().await;
+
+        task_service::complete_task(&pool, &dishes.id, &alice, &household_id).await.unwrap();
+
+        let sensor = household_sensor(&pool, &household_id).await.unwrap();
+        assert_eq!(sensor.due_today, 3);
+        assert_eq!(sensor.open_today, 2);
+        assert_eq!(sensor.unassigned_open, 1);
+
+        let alice_sensor = sensor.members.iter().find(|m| m.user_id == alice).unwrap();
+        assert_eq!((alice_sensor.due_today, alice_sensor.open_today), (1, 0));
+        let bob_sensor = sensor.members.iter().find(|m| m.user_id == bob).unwrap();
+        assert_eq!((bob_sensor.due_today, bob_sensor.open_today), (1, 1));
+
+        let tasks = due_tasks(&pool, &household_id).await.unwrap();
+        assert_eq!(tasks.len(), 3);
+        assert!(tasks.last().unwrap().done);
+        assert_eq!(tasks.last().unwrap().assignees, vec!["alice".to_string()]);
+    }
+}
@@ -0,0 +1,478 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::ExpenseSettlementRow;
+use crate::services::households as household_service;
+use shared::{
+    CreateSettlementRequest, ExpenseBalances, ExpenseSettlement, MemberBalance, SettlementTransfer,
+};
+
+#[derive(Debug, Error)]
+pub enum SettlementError {
+    #[error("Permission denied")]
+    PermissionDenied,
+    #[error("Amount must be greater than zero")]
+    InvalidAmount,
+    #[error("Settlement must be between two different household members")]
+    InvalidMembers,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+/// Compute each member's balance from what they paid, their equal share of all
+/// expenses and the settlements they sent or received.
+///
+/// Expenses are split equally between `members`. Leftover cents from the split
+/// go to the first members in the given order so the shares add up exactly.
+/// Payers or settlement parties that are no longer members keep their
+/// paid/settled amounts but carry no share.
+pub fn compute_balances(
+    members: &[(Uuid, String)],
+    expenses: &[(Uuid, i64)],
+    settlements: &[(Uuid, Uuid, i64)],
+    former_members: &[(Uuid, String)],
+) -> Vec<MemberBalance> {
+    let mut balances: Vec<MemberBalance> = members
+        .iter()
+        .map(|(user_id, username)| MemberBalance {
+            user_id: *user_id,
+            username: username.clone(),
+            paid_cents: 0,
+            share_cents: 0,
+            balance_cents: 0,
+        })
+        .collect();
+    let mut index: HashMap<Uuid, usize> = balances
+        .iter()
+        .enumerate()
+        .map(|(i, b)| (b.user_id, i))
+        .collect();
+
+    let mut entry = |user_id: Uuid, balances: &mut Vec<MemberBalance>| -> usize {
+        *index.entry(user_id).or_insert_with(|| {
+            let username = former_members
+                .iter()
+                .find(|(id, _)| *id == user_id)
+                .map(|(_, name)| name.clone())
+                .unwrap_or_default();
+            balances.push(MemberBalance {
+                user_id,
+                username,
+                paid_cents: 0,
+                share_cents: 0,
+                balance_cents: 0,
+            });
+            balances.len() - 1
+        })
+    };
+
+    let mut total = 0;
+    for (payer, amount) in expenses {
+        let i = entry(*payer, &mut balances);
+        balances[i].paid_cents += amount;
+        total += amount;
+    }
+
+    if !members.is_empty() {
+        let count = members.len() as i64;
+        let base = total.div_euclid(count);
+        let remainder = total.rem_euclid(count) as usize;
+        for (i, balance) in balances.iter_mut().take(members.len()).enumerate() {
+            balance.share_cents = base + i64::from(i < remainder);
+        }
+    }
+
+    for b in balances.iter_mut() {
+        b.balance_cents = b.paid_cents - b.share_cents;
+    }
+
+    for (from, to, amount) in settlements {
+        let i = entry(*from, &mut balances);
+        balances[i].balance_cents += amount;
+        let j = entry(*to, &mut balances);
+        balances[j].balance_cents -= amount;
+    }
+
+    balances
+}
+
+/// Greedily pair the largest debtor with the largest creditor until every
+/// balance is zero. This needs at most one transfer fewer than there are
+/// members with a non-zero balance.
+pub fn settlement_transfers(balances: &[MemberBalance]) -> Vec<SettlementTransfer> {
+    let mut creditors: Vec<(&MemberBalance, i64)> = balances
+        .iter()
+        .filter(|b| b.balance_cents > 0)
+        .map(|b| (b, b.balance_cents))
+        .collect();
+    let mut debtors: Vec<(&MemberBalance, i64)> = balances
+        .iter()
+        .filter(|b| b.balance_cents < 0)
+        .map(|b| (b, -b.balance_cents))
+        .collect();
+    creditors.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.username.cmp(&b.0.username)));
+    debtors.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.username.cmp(&b.0.username)));
+
+    let mut transfers = Vec::new();
+    let (mut c, mut d) = (0, 0);
+    while c < creditors.len() && d < debtors.len() {
+        let amount = creditors[c].1.min(debtors[d].1);
+        transfers.push(SettlementTransfer {
+            from_user_id: debtors[d].0.user_id,
+            from_username: debtors[d].0.username.clone(),
+            to_user_id: creditors[c].0.user_id,
+            to_username: creditors[c].0.username.clone(),
+            amount_cents: amount,
+        });
+        creditors[c].1 -= amount;
+        debtors[d].1 -= amount;
+        if creditors[c].1 == 0 {
+            c += 1;
+        }
+        if debtors[d].1 == 0 {
+            d += 1;
+        }
+    }
+
+    transfers
+}
+
+/// Current balances of all members and the transfers that would settle them
+pub async fn get_balances(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+) -> Result<ExpenseBalances, SettlementError> {
+    let member_rows: Vec<(String, String)> = sqlx::query_as(
+        r#"
+        SELECT u.id, u.username
+        FROM household_memberships m
+        JOIN users u ON m.user_id = u.id
+        WHERE m.household_id = ?
+        ORDER BY m.joined_at ASC, u.username ASC
+        "#,
+    )
+    .bind(household_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    let expense_rows: Vec<(String, i64)> = sqlx::query_as(
+        "SELECT paid_by, amount_cents FROM expenses WHERE household_id = ?",
+    )
+    .bind(household_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    let settlement_rows: Vec<(String, String, i64)> = sqlx::query_as(
+        "SELECT from_user_id, to_user_id, amount_cents FROM expense_settlements WHERE household_id = ?",
+    )
+    .bind(household_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    // Usernames of people who paid or settled but have since left
+    let former_rows: Vec<(String, String)> = sqlx::query_as(
+        r#"
+        SELECT u.id, u.username FROM users u
+        WHERE u.id IN (
+            SELECT paid_by FROM expenses WHERE household_id = ?
+            UNION SELECT from_user_id FROM expense_settlements WHERE household_id = ?
+            UNION SELECT to_user_id FROM expense_settlements WHERE household_id = ?
+        )
+        AND u.id NOT IN (SELECT user_id FROM household_memberships WHERE household_id = ?)
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(household_id.to_string())
+    .bind(household_id.to_string())
+    .bind(household_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    let parse = |id: &str| Uuid::parse_str(id).unwrap();
+    let members: Vec<(Uuid, String)> = member_rows
+        .into_iter()
+        .map(|(id, name)| (parse(&id), name))
+        .collect();
+    let former_members: Vec<(Uuid, String)> = former_rows
+        .into_iter()
+        .map(|(id, name)| (parse(&id), name))
+        .collect();
+    let expenses: Vec<(Uuid, i64)> = expense_rows
+        .into_iter()
+        .map(|(payer, amount)| (parse(&payer), amount))
+        .collect();
+    let settlements: Vec<(Uuid, Uuid, i64)> = settlement_rows
+        .into_iter()
+        .map(|(from, to, amount)| (parse(&from), parse(&to), amount))
+        .collect();
+
+    let balances = compute_balances(&members, &expenses, &settlements, &former_members);
+    let transfers = settlement_transfers(&balances);
+
+    Ok(ExpenseBalances { balances, transfers })
+}
+
+/// Record that one member paid another to settle shared expenses.
+///
+/// Only the two parties or a household manager may record a settlement.
+pub async fn record_settlement(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    can_manage: bool,
+    request: &CreateSettlementRequest,
+) -> Result<ExpenseSettlement, SettlementError> {
+    if request.amount_cents <= 0 {
+        return Err(SettlementError::InvalidAmount);
+    }
+    if request.from_user_id == request.to_user_id {
+        return Err(SettlementError::InvalidMembers);
+    }
+    for party in [&request.from_user_id, &request.to_user_id] {
+        if !household_service::is_member(pool, household_id, party)
+            .await
+            .unwrap_or(false)
+        {
+            return Err(SettlementError::InvalidMembers);
+        }
+    }
+    if !can_manage && *user_id != request.from_user_id && *user_id != request.to_user_id {
+        return Err(SettlementError::PermissionDenied);
+    }
+
+    let id = Uuid::new_v4();
+    let now = Utc::now();
+    let note = request
+        .note
+        .as_ref()
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty());
+
+    sqlx::query(
+        r#"
+        INSERT INTO expense_settlements (id, household_id, from_user_id, to_user_id, amount_cents, note, created_by, created_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(id.to_string())
+    .bind(household_id.to_string())
+    .bind(request.from_user_id.to_string())
+    .bind(request.to_user_id.to_string())
+    .bind(request.amount_cents)
+    .bind(&note)
+    .bind(user_id.to_string())
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    Ok(ExpenseSettlement {
+        id,
+        household_id: *household_id,
+        from_user_id: request.from_user_id,
+        to_user_id: request.to_user_id,
+        amount_cents: request.amount_cents,
+        note,
+        created_by: *user_id,
+        created_at: now,
+    })
+}
+
+/// List recorded settlements, newest first
+pub async fn list_settlements(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+) -> Result<Vec<ExpenseSettlement>, SettlementError> {
+    let rows: Vec<ExpenseSettlementRow> = sqlx::query_as(
+        "SELECT * FROM expense_settlements WHERE household_id = ? ORDER BY created_at DESC",
+    )
+    .bind(household_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.to_shared()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::expenses as expense_service;
+    use crate::test_utils;
+    use chrono::NaiveDate;
+    use shared::{CreateExpenseRequest, ExpenseCategory, Role};
+
+    fn member(name: &str) -> (Uuid, String) {
+        (Uuid::new_v4(), name.to_string())
+    }
+
+    fn balance_of(balances: &[MemberBalance], user_id: &Uuid) -> i64 {
+        balances.iter().find(|b| b.user_id == *user_id).unwrap().balance_cents
+    }
+
+    #[test]
+    fn test_compute_balances_equal_split() {
+        let alice = member("alice");
+        let bob = member("bob");
+        let carol = member("carol");
+        let members = vec![alice.clone(), bob.clone(), carol.clone()];
+
+        let balances = compute_balances(&members, &[(alice.0, 9000), (bob.0, 3000)], &[], &[]);
+
+        assert_eq!(balance_of(&balances, &alice.0), 5000);
+        assert_eq!(balance_of(&balances, &bob.0), -1000);
+        assert_eq!(balance_of(&balances, &carol.0), -4000);
+        assert_eq!(balances.iter().map(|b| b.balance_cents).sum::<i64>(), 0);
+    }
+
+    #[test]
+    fn test_compute_balances_distributes_remainder() {
+        let alice = member("alice");
+        let bob = member("bob");
+        let carol = member("carol");
+        let members = vec![alice.clone(), bob.clone(), carol.clone()];
+
+        let balances = compute_balances(&members, &[(alice.0, 100)], &[], &[]);
+
+        let shares: Vec<i64> = balances.iter().map(|b| b.share_cents).collect();
+        assert_eq!(shares, vec![34, 33, 33]);
+        assert_eq!(balances.iter().map(|b| b.balance_cents).sum::<i64>(), 0);
+    }
+
+    #[test]
+    fn test_compute_balances_applies_settlements() {
+        let alice = member("alice");
+        let bob = member("bob");
+        let members = vec![alice.clone(), bob.clone()];
+
+        let balances = compute_balances(&members, &[(alice.0, 1000)], &[(bob.0, alice.0, 500)], &[]);
+
+        assert_eq!(balance_of(&balances, &alice.0), 0);
+        assert_eq!(balance_of(&balances, &bob.0), 0);
+    }
+
+    #[test]
+    fn test_compute_balances_keeps_former_member_payments() {
+        let alice = member("alice");
+        let former = member("former");
+
+        let members = vec![alice.clone()];
+        let former_members = vec![former.clone()];
+        let balances = compute_balances(&members, &[(former.0, 600)], &[], &former_members);
+
+        assert_eq!(balance_of(&balances, &alice.0), -600);
+        assert_eq!(balance_of(&balances, &former.0), 600);
+        assert_eq!(balances[1].username, "former");
+        assert_eq!(balances[1].share_cents, 0);
+    }
+
+    #[test]
+    fn test_settlement_transfers() {
+        let alice = member("alice");
+        let bob = member("bob");
+        let carol = member("carol");
+        let members = vec![alice.clone(), bob.clone(), carol.clone()];
+        let balances = compute_balances(&members, &[(alice.0, 9000), (bob.0, 3000)], &[], &[]);
+
+        let transfers = settlement_transfers(&balances);
+
+        assert_eq!(transfers.len(), 2);
+        assert_eq!(transfers[0].from_user_id, carol.0);
+        assert_eq!(transfers[0].to_user_id, alice.0);
+        assert_eq!(transfers[0].amount_cents, 4000);
+        assert_eq!(transfers[1].from_user_id, bob.0);
+        assert_eq!(transfers[1].to_user_id, alice.0);
+        assert_eq!(transfers[1].amount_cents, 1000);
+    }
+
+    #[test]
+    fn test_settlement_transfers_none_when_settled() {
+        let alice = member("alice");
+        let balances = compute_balances(&[alice], &[], &[], &[]);
+        assert!(settlement_transfers(&balances).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_record_settlement_clears_balances() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let alice = test_utils::create_test_user(&pool, "alice@test.com", Role::Member).await;
+        let bob = test_utils::create_test_user(&pool, "bob@test.com", Role::Member).await;
+        let carol = test_utils::create_test_user(&pool, "carol@test.com", Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &alice, Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &bob, Role::Member).await;
+
+        expense_service::create_expense(
+            &pool,
+            &household_id,
+            &alice,
+            &CreateExpenseRequest {
+                amount_cents: 2000,
+                category: ExpenseCategory::Groceries,
+                description: "Groceries".to_string(),
+                paid_by: None,
+                receipt_note: None,
+                expense_date: NaiveDate::from_ymd_opt(2024, 3, 1),
+            },
+        )
+        .await
+        .unwrap();
+
+        let before = get_balances(&pool, &household_id).await.unwrap();
+        assert_eq!(before.transfers.len(), 1);
+        assert_eq!(before.transfers[0].from_user_id, bob);
+        assert_eq!(before.transfers[0].amount_cents, 1000);
+
+        let request = CreateSettlementRequest {
+            from_user_id: bob,
+            to_user_id: alice,
+            amount_cents: 1000,
+            note: Some("Cash".to_string()),
+        };
+        // Carol is not a party and cannot manage the household
+        assert!(matches!(
+            record_settlement(&pool, &household_id, &carol, false, &request).await,
+            Err(SettlementError::PermissionDenied)
+        ));
+        record_settlement(&pool, &household_id, &bob, false, &request).await.unwrap();
+
+        let after = get_balances(&pool, &household_id).await.unwrap();
+        assert!(after.transfers.is_empty());
+        assert!(after.balances.iter().all(|b| b.balance_cents == 0));
+        assert_eq!(list_settlements(&pool, &household_id).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_record_settlement_validation() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let alice = test_utils::create_test_user(&pool, "alice@test.com", Role::Member).await;
+        let outsider = test_utils::create_test_user(&pool, "outsider@test.com", Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &alice, Role::Member).await;
+
+        let mut request = CreateSettlementRequest {
+            from_user_id: alice,
+            to_user_id: alice,
+            amount_cents: 100,
+            note: None,
+        };
+        assert!(matches!(
+            record_settlement(&pool, &household_id, &alice, false, &request).await,
+            Err(SettlementError::InvalidMembers)
+        ));
+
+        request.to_user_id = outsider;
+        assert!(matches!(
+            record_settlement(&pool, &household_id, &alice, false, &request).await,
+            Err(SettlementError::InvalidMembers)
+        ));
+
+        request.amount_cents = 0;
+        assert!(matches!(
+            record_settlement(&pool, &household_id, &alice, false, &request).await,
+            Err(SettlementError::InvalidAmount)
+        ));
+    }
+}
@@ -0,0 +1,161 @@
+use chrono::{Duration, Utc};
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::ShareLinkRow;
+use shared::{CreateShareLinkRequest, ShareLink};
+
+#[derive(Debug, Error)]
+pub enum ShareLinkError {
+    #[error("Share link not found")]
+    NotFound,
+    #[error("Share links must be valid for at least one day")]
+    InvalidExpiry,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+/// Generate a new random share token
+fn generate_token() -> String {
+    format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+}
+
+pub async fn list_links(pool: &SqlitePool, household_id: &Uuid) -> Result<Vec<ShareLink>, ShareLinkError> {
+    let rows: Vec<ShareLinkRow> =
+        sqlx::query_as("SELECT * FROM share_links WHERE household_id = ? ORDER BY created_at DESC")
+            .bind(household_id.to_string())
+            .fetch_all(pool)
+            .await?;
+
+    Ok(rows.into_iter().map(|r| r.to_shared()).collect())
+}
+
+pub async fn create_link(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    created_by: &Uuid,
+    request: &CreateShareLinkRequest,
+) -> Result<ShareLink, ShareLinkError> {
+    if request.expires_in_days == Some(0) {
+        return Err(ShareLinkError::InvalidExpiry);
+    }
+
+    let now = Utc::now();
+    let row = ShareLinkRow {
+        id: Uuid::new_v4().to_string(),
+        household_id: household_id.to_string(),
+        resource: request.resource.as_str().to_string(),
+        token: generate_token(),
+        created_by: created_by.to_string(),
+        created_at: now,
+        expires_at: request.expires_in_days.map(|days| now + Duration::days(days as i64)),
+    };
+
+    sqlx::query(
+        r#"
+        INSERT INTO share_links (id, household_id, resource, token, created_by, created_at, expires_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(&row.id)
+    .bind(&row.household_id)
+    .bind(&row.resource)
+    .bind(&row.token)
+    .bind(&row.created_by)
+    .bind(row.created_at)
+    .bind(row.expires_at)
+    .execute(pool)
+    .await?;
+
+    Ok(row.to_shared())
+}
+
+/// Delete a link; anyone still holding its token loses access immediately
+pub async fn revoke_link(pool: &SqlitePool, household_id: &Uuid, link_id: &Uuid) -> Result<(), ShareLinkError> {
+    let result = sqlx::query("DELETE FROM share_links WHERE id = ? AND household_id = ?")
+        .bind(link_id.to_string())
+        .bind(household_id.to_string())
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(ShareLinkError::NotFound);
+    }
+    Ok(())
+}
+
+/// Look up the link for a token. Expired links are reported as not found.
+pub async fn resolve_token(pool: &SqlitePool, token: &str) -> Result<ShareLink, ShareLinkError> {
+    let row: Option<ShareLinkRow> = sqlx::query_as("SELECT * FROM share_links WHERE token = ?")
+        .bind(token)
+        .fetch_optional(pool)
+        .await?;
+
+    row.map(|r| r.to_shared())
+        .filter(|link| link.is_active(Utc::now()))
+        .ok_or(ShareLinkError::NotFound)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use shared::{Role, ShareResource};
+
+    #[tokio::test]
+    async fn test_share_link_lifecycle() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user = test_utils::create_test_user(&pool, "sharer@test.com", Role::Member).await;
+
+        let request = CreateShareLinkRequest {
+            resource: ShareResource::ShoppingList,
+            expires_in_days: Some(7),
+        };
+        let link = create_link(&pool, &household_id, &user, &request).await.unwrap();
+        assert_eq!(link.token.len(), 64);
+        assert!(link.expires_at.is_some());
+
+        let resolved = resolve_token(&pool, &link.token).await.unwrap();
+        assert_eq!(resolved.id, link.id);
+        assert_eq!(resolved.resource, ShareResource::ShoppingList);
+        assert_eq!(list_links(&pool, &household_id).await.unwrap().len(), 1);
+
+        revoke_link(&pool, &household_id, &link.id).await.unwrap();
+        assert!(matches!(resolve_token(&pool, &link.token).await, Err(ShareLinkError::NotFound)));
+        assert!(matches!(
+            revoke_link(&pool, &household_id, &link.id).await,
+            Err(ShareLinkError::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_expired_share_link_is_rejected() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user = test_utils::create_test_user(&pool, "sharer@test.com", Role::Member).await;
+
+        let request = CreateShareLinkRequest {
+            resource: ShareResource::MealPlan,
+            expires_in_days: Some(1),
+        };
+        let link = create_link(&pool, &household_id, &user, &request).await.unwrap();
+        sqlx::query("UPDATE share_links SET expires_at = ? WHERE id = ?")
+            .bind(Utc::now() - Duration::minutes(1))
+            .bind(link.id.to_string())
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(matches!(resolve_token(&pool, &link.token).await, Err(ShareLinkError::NotFound)));
+
+        let request = CreateShareLinkRequest {
+            resource: ShareResource::MealPlan,
+            expires_in_days: Some(0),
+        };
+        assert!(matches!(
+            create_link(&pool, &household_id, &user, &request).await,
+            Err(ShareLinkError::InvalidExpiry)
+        ));
+    }
+}
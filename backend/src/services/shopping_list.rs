@@ -0,0 +1,437 @@
+use chrono::Utc;
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::ShoppingListItemRow;
+use crate::services::meals as meal_service;
+use shared::{CreateShoppingListItemRequest, ShoppingListItem, UpdateShoppingListItemRequest};
+
+#[derive(Debug, Error)]
+pub enum ShoppingListError {
+    #[error("Shopping list item not found")]
+    NotFound,
+    #[error("Recipe not found")]
+    RecipeNotFound,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+impl From<meal_service::MealError> for ShoppingListError {
+    fn from(error: meal_service::MealError) -> Self {
+        match error {
+            meal_service::MealError::DatabaseError(e) => ShoppingListError::DatabaseError(e),
+            _ => ShoppingListError::RecipeNotFound,
+        }
+    }
+}
+
+/// Base unit and factor for units that can be converted into each other
+fn unit_base(unit: &str) -> Option<(&'static str, f64)> {
+    match unit.to_lowercase().as_str() {
+        "mg" => Some(("g", 0.001)),
+        "g" => Some(("g", 1.0)),
+        "kg" => Some(("g", 1000.0)),
+        "ml" => Some(("ml", 1.0)),
+        "cl" => Some(("ml", 10.0)),
+        "dl" => Some(("ml", 100.0)),
+        "l" => Some(("ml", 1000.0)),
+        _ => None,
+    }
+}
+
+fn round_quantity(quantity: f64) -> f64 {
+    (quantity * 1000.0).round() / 1000.0
+}
+
+/// Combine an incoming quantity with the one already on the list.
+///
+/// Quantities are added when the units match or can be converted (g/kg,
+/// ml/l, ...); the result keeps the existing unit. When the units are
+/// incompatible the existing quantity is kept as it is.
+pub fn merge_quantity(
+    existing: (Option<f64>, Option<&str>),
+    incoming: (Option<f64>, Option<&str>),
+) -> (Option<f64>, Option<String>) {
+    let (existing_qty, existing_unit) = existing;
+    let (incoming_qty, incoming_unit) = incoming;
+
+    let same_unit = match (existing_unit, incoming_unit) {
+        (None, None) => true,
+        (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+        _ => false,
+    };
+
+    if same_unit {
+        let quantity = match (existing_qty, incoming_qty) {
+            (Some(a), Some(b)) => Some(round_quantity(a + b)),
+            (a, b) => a.or(b),
+        };
+        return (quantity, existing_unit.map(str::to_string));
+    }
+
+    if let (Some(a), Some(b), Some(eu), Some(iu)) =
+        (existing_qty, incoming_qty, existing_unit, incoming_unit)
+    {
+        if let (Some((base_a, factor_a)), Some((base_b, factor_b))) = (unit_base(eu), unit_base(iu)) {
+            if base_a == base_b {
+                return (Some(round_quantity(a + b * factor_b / factor_a)), Some(eu.to_string()));
+            }
+        }
+    }
+
+    // Nothing known yet about the existing amount: take over the incoming one
+    if existing_qty.is_none() && existing_unit.is_none() {
+        return (incoming_qty, incoming_unit.map(str::to_string));
+    }
+
+    (existing_qty, existing_unit.map(str::to_string))
+}
+
+fn clean_unit(unit: &Option<String>) -> Option<String> {
+    unit.as_ref()
+        .map(|u| u.trim().to_string())
+        .filter(|u| !u.is_empty())
+}
+
+pub async fn list_items(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+) -> Result<Vec<ShoppingListItem>, ShoppingListError> {
+    let rows: Vec<ShoppingListItemRow> = sqlx::query_as(
+        r#"
+        SELECT * FROM shopping_list_items
+        WHERE household_id = ?
+        ORDER BY is_checked ASC, name COLLATE NOCASE ASC
+        "#,
+    )
+    .bind(household_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.to_shared()).collect())
+}
+
+/// Add an item to the list, merging it into an existing item with the same name.
+///
+/// An already checked-off item with the same name is put back on the list
+/// with the new quantity instead.
+pub async fn add_item(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    request: &CreateShoppingListItemRequest,
+) -> Result<ShoppingListItem, ShoppingListError> {
+    let name = request.name.trim();
+    let unit = clean_unit(&request.unit);
+    let quantity = request.quantity.filter(|q| q.is_finite() && *q > 0.0);
+    let now = Utc::now();
+
+    let existing: Option<ShoppingListItemRow> = sqlx::query_as(
+        r#"
+        SELECT * FROM shopping_list_items
+        WHERE household_id = ? AND LOWER(name) = LOWER(?)
+        ORDER BY is_checked ASC
+        LIMIT 1
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(name)
+    .fetch_optional(pool)
+    .await?;
+
+    let Some(mut item) = existing else {
+        let id = Uuid::new_v4();
+        sqlx::query(
+            r#"
+            INSERT INTO shopping_list_items (id, household_id, name, quantity, unit, is_checked, added_by, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, 0, ?, ?, ?)
+            "#,
+        )
+        .bind(id.to_string())
+        .bind(household_id.to_string())
+        .bind(name)
+        .bind(quantity)
+        .bind(&unit)
+        .bind(user_id.to_string())
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await?;
+
+        return Ok(ShoppingListItem {
+            id,
+            household_id: *household_id,
+            name: name.to_string(),
+            quantity,
+            unit,
+            is_checked: false,
+            added_by: *user_id,
+            created_at: now,
+            updated_at: now,
+        });
+    };
+
+    if item.is_checked {
+        item.quantity = quantity;
+        item.unit = unit;
+        item.is_checked = false;
+    } else {
+        let (merged_quantity, merged_unit) = merge_quantity(
+            (item.quantity, item.unit.as_deref()),
+            (quantity, unit.as_deref()),
+        );
+        item.quantity = merged_quantity;
+        item.unit = merged_unit;
+    }
+    item.updated_at = now;
+
+    sqlx::query(
+        "UPDATE shopping_list_items SET quantity = ?, unit = ?, is_checked = ?, updated_at = ? WHERE id = ?",
+    )
+    .bind(item.quantity)
+    .bind(&item.unit)
+    .bind(item.is_checked)
+    .bind(now)
+    .bind(&item.id)
+    .execute(pool)
+    .await?;
+
+    Ok(item.to_shared())
+}
+
+pub async fn update_item(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    item_id: &Uuid,
+    request: &UpdateShoppingListItemRequest,
+) -> Result<ShoppingListItem, ShoppingListError> {
+    let mut item: ShoppingListItemRow =
+        sqlx::query_as("SELECT * FROM shopping_list_items WHERE id = ? AND household_id = ?")
+            .bind(item_id.to_string())
+            .bind(household_id.to_string())
+            .fetch_optional(pool)
+            .await?
+            .ok_or(ShoppingListError::NotFound)?;
+
+    if let Some(ref name) = request.name {
+        item.name = name.trim().to_string();
+    }
+    if request.quantity.is_some() {
+        item.quantity = request.quantity.filter(|q| q.is_finite() && *q > 0.0);
+    }
+    if request.unit.is_some() {
+        item.unit = clean_unit(&request.unit);
+    }
+    if let Some(is_checked) = request.is_checked {
+        item.is_checked = is_checked;
+    }
+    item.updated_at = Utc::now();
+
+    sqlx::query(
+        r#"
+        UPDATE shopping_list_items
+        SET name = ?, quantity = ?, unit = ?, is_checked = ?, updated_at = ?
+        WHERE id = ?
+        "#,
+    )
+    .bind(&item.name)
+    .bind(item.quantity)
+    .bind(&item.unit)
+    .bind(item.is_checked)
+    .bind(item.updated_at)
+    .bind(item_id.to_string())
+    .execute(pool)
+    .await?;
+
+    Ok(item.to_shared())
+}
+
+pub async fn delete_item(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    item_id: &Uuid,
+) -> Result<(), ShoppingListError> {
+    let result = sqlx::query("DELETE FROM shopping_list_items WHERE id = ? AND household_id = ?")
+        .bind(item_id.to_string())
+        .bind(household_id.to_string())
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(ShoppingListError::NotFound);
+    }
+
+    Ok(())
+}
+
+/// Remove all checked-off items, returning how many were removed
+pub async fn clear_checked(pool: &SqlitePool, household_id: &Uuid) -> Result<u64, ShoppingListError> {
+    let result = sqlx::query("DELETE FROM shopping_list_items WHERE household_id = ? AND is_checked = 1")
+        .bind(household_id.to_string())
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected())
+}
+
+/// Merge all ingredients of a recipe into the shopping list and return the updated list
+pub async fn add_recipe_ingredients(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    recipe_id: &Uuid,
+) -> Result<Vec<ShoppingListItem>, ShoppingListError> {
+    let recipe = meal_service::get_recipe(pool, household_id, recipe_id)
+        .await?
+        .ok_or(ShoppingListError::RecipeNotFound)?;
+
+    for ingredient in &recipe.ingredients {
+        let request = CreateShoppingListItemRequest {
+            name: ingredient.name.clone(),
+            quantity: ingredient.quantity,
+            unit: ingredient.unit.clone(),
+        };
+        add_item(pool, household_id, user_id, &request).await?;
+    }
+
+    list_items(pool, household_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use shared::{CreateRecipeRequest, RecipeIngredient, Role};
+
+    fn item(name: &str, quantity: Option<f64>, unit: Option<&str>) -> CreateShoppingListItemRequest {
+        CreateShoppingListItemRequest {
+            name: name.to_string(),
+            quantity,
+            unit: unit.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_merge_quantity_same_unit() {
+        assert_eq!(merge_quantity((Some(200.0), Some("g")), (Some(100.0), Some("G"))), (Some(300.0), Some("g".to_string())));
+        assert_eq!(merge_quantity((Some(2.0), None), (Some(3.0), None)), (Some(5.0), None));
+        assert_eq!(merge_quantity((None, None), (None, None)), (None, None));
+        assert_eq!(merge_quantity((Some(0.1), None), (Some(0.2), None)), (Some(0.3), None));
+    }
+
+    #[test]
+    fn test_merge_quantity_converts_units() {
+        assert_eq!(merge_quantity((Some(1.0), Some("kg")), (Some(250.0), Some("g"))), (Some(1.25), Some("kg".to_string())));
+        assert_eq!(merge_quantity((Some(500.0), Some("ml")), (Some(1.0), Some("l"))), (Some(1500.0), Some("ml".to_string())));
+    }
+
+    #[test]
+    fn test_merge_quantity_incompatible_units_keep_existing() {
+        assert_eq!(merge_quantity((Some(1.0), Some("kg")), (Some(1.0), Some("l"))), (Some(1.0), Some("kg".to_string())));
+        assert_eq!(merge_quantity((Some(2.0), Some("cans")), (Some(1.0), None)), (Some(2.0), Some("cans".to_string())));
+        // A bare item takes over the incoming amount
+        assert_eq!(merge_quantity((None, None), (Some(3.0), Some("tbsp"))), (Some(3.0), Some("tbsp".to_string())));
+    }
+
+    #[tokio::test]
+    async fn test_add_item_deduplicates_by_name() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user = test_utils::create_test_user(&pool, "shopper@test.com", Role::Member).await;
+
+        let first = add_item(&pool, &household_id, &user, &item("Milk", Some(1.0), Some("l"))).await.unwrap();
+        let second = add_item(&pool, &household_id, &user, &item(" milk ", Some(500.0), Some("ml"))).await.unwrap();
+
+        assert_eq!(first.id, second.id);
+        assert_eq!(second.quantity, Some(1.5));
+        assert_eq!(list_items(&pool, &household_id).await.unwrap().len(), 1);
+
+        // Checked items come back with the new amount
+        update_item(
+            &pool,
+            &household_id,
+            &first.id,
+            &UpdateShoppingListItemRequest {
+                is_checked: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        let again = add_item(&pool, &household_id, &user, &item("Milk", Some(1.0), Some("l"))).await.unwrap();
+        assert_eq!(again.id, first.id);
+        assert!(!again.is_checked);
+        assert_eq!(again.quantity, Some(1.0));
+    }
+
+    #[tokio::test]
+    async fn test_add_recipe_ingredients_merges_into_list() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user = test_utils::create_test_user(&pool, "cook@test.com", Role::Member).await;
+
+        add_item(&pool, &household_id, &user, &item("Flour", Some(100.0), Some("g"))).await.unwrap();
+
+        let recipe = meal_service::create_recipe(
+            &pool,
+            &household_id,
+            &user,
+            &CreateRecipeRequest {
+                name: "Pancakes".to_string(),
+                ingredients: vec![
+                    RecipeIngredient { name: "flour".to_string(), quantity: Some(200.0), unit: Some("g".to_string()) },
+                    RecipeIngredient { name: "Eggs".to_string(), quantity: Some(2.0), unit: None },
+                ],
+                notes: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        let list = add_recipe_ingredients(&pool, &household_id, &user, &recipe.id).await.unwrap();
+        assert_eq!(list.len(), 2);
+        let flour = list.iter().find(|i| i.name == "Flour").unwrap();
+        assert_eq!(flour.quantity, Some(300.0));
+
+        // Adding the recipe twice doubles the quantities without duplicating items
+        let list = add_recipe_ingredients(&pool, &household_id, &user, &recipe.id).await.unwrap();
+        assert_eq!(list.len(), 2);
+        let eggs = list.iter().find(|i| i.name == "Eggs").unwrap();
+        assert_eq!(eggs.quantity, Some(4.0));
+
+        assert!(matches!(
+            add_recipe_ingredients(&pool, &household_id, &user, &Uuid::new_v4()).await,
+            Err(ShoppingListError::RecipeNotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_clear_checked_and_delete() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user = test_utils::create_test_user(&pool, "shopper@test.com", Role::Member).await;
+
+        let bread = add_item(&pool, &household_id, &user, &item("Bread", None, None)).await.unwrap();
+        let butter = add_item(&pool, &household_id, &user, &item("Butter", None, None)).await.unwrap();
+        update_item(
+            &pool,
+            &household_id,
+            &bread.id,
+            &UpdateShoppingListItemRequest {
+                is_checked: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(clear_checked(&pool, &household_id).await.unwrap(), 1);
+        delete_item(&pool, &household_id, &butter.id).await.unwrap();
+        assert!(list_items(&pool, &household_id).await.unwrap().is_empty());
+        assert!(matches!(
+            delete_item(&pool, &household_id, &butter.id).await,
+            Err(ShoppingListError::NotFound)
+        ));
+    }
+}
@@ -67,7 +67,8 @@ pub async fn activate_solo_mode(
         SET solo_mode = TRUE,
             solo_mode_exit_requested_at = NULL,
             solo_mode_previous_hierarchy_type = ?,
-            updated_at = ?
+            updated_at = ?,
+            version = version + 1
         WHERE household_id = ?
         "#,
     )
@@ -105,7 +106,8 @@ pub async fn request_solo_mode_exit(
         r#"
         UPDATE household_settings
         SET solo_mode_exit_requested_at = ?,
-            updated_at = ?
+            updated_at = ?,
+            version = version + 1
         WHERE household_id = ?
         "#,
     )
@@ -143,7 +145,8 @@ pub async fn cancel_solo_mode_exit(
         r#"
         UPDATE household_settings
         SET solo_mode_exit_requested_at = NULL,
-            updated_at = ?
+            updated_at = ?,
+            version = version + 1
         WHERE household_id = ?
         "#,
     )
@@ -184,7 +187,8 @@ async fn deactivate_solo_mode(
             solo_mode_exit_requested_at = NULL,
             solo_mode_previous_hierarchy_type = NULL,
             hierarchy_type = ?,
-            updated_at = ?
+            updated_at = ?,
+            version = version + 1
         WHERE household_id = ?
         "#,
     )
@@ -1,16 +1,31 @@
-use chrono::{Datelike, NaiveDate, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use chrono_tz::Tz;
 use sqlx::SqlitePool;
+use std::collections::HashMap;
 use thiserror::Error;
 use uuid::Uuid;
 
 use crate::models::{
     MonthlyStatisticsRow, MonthlyStatisticsTaskRow, WeeklyStatisticsRow, WeeklyStatisticsTaskRow,
 };
+use crate::services::{events, household_settings, scheduler, tasks as task_service};
+use shared::{
+    FailedTaskReport, HabitType, HouseholdReport, MemberReport, MemberWorkload, Task, WeekdayActivity,
+    WorkloadReport,
+};
+
+/// Longest range a household report may cover
+pub const MAX_REPORT_DAYS: i64 = 366;
+
+/// Number of tasks listed under "most failed" in a report
+const MOST_FAILED_LIMIT: usize = 5;
 
 #[derive(Debug, Error)]
 pub enum StatisticsError {
     #[error("Database error: {0}")]
     Database(#[from] sqlx::Error),
+    #[error("Task error: {0}")]
+    Task(#[from] task_service::TaskError),
     #[allow(dead_code)]
     #[error("Statistics not found")]
     NotFound,
@@ -73,13 +88,15 @@ pub async fn calculate_weekly_statistics(
         r#"
         SELECT id, title, assigned_user_id, habit_type
         FROM tasks
-        WHERE household_id = ? AND archived = FALSE
+        WHERE household_id = ? AND archived = FALSE AND deleted_at IS NULL
         "#,
     )
     .bind(household_id.to_string())
     .fetch_all(pool)
     .await?;
 
+    let totals = events::household_task_totals(pool, household_id, week_start, week_end).await?;
+
     // For each member, calculate their statistics
     for (user_id, _username) in &members {
         // Find tasks assigned to this user
@@ -99,35 +116,13 @@ pub async fn calculate_weekly_statistics(
         for (task_id, task_title, _, habit_type) in user_tasks {
             let is_bad_habit = habit_type == "bad";
 
-            // Count expected periods within the week (excluding skipped - paused/vacation)
-            let expected: i64 = sqlx::query_scalar(
-                r#"
-                SELECT COUNT(*) FROM task_period_results
-                WHERE task_id = ?
-                AND period_start >= ? AND period_start <= ?
-                AND status != 'skipped'
-                "#,
-            )
-            .bind(task_id)
-            .bind(week_start)
-            .bind(week_end)
-            .fetch_one(pool)
-            .await?;
-
-            // Count completed periods
-            let completed: i64 = sqlx::query_scalar(
-                r#"
-                SELECT COUNT(*) FROM task_period_results
-                WHERE task_id = ?
-                AND period_start >= ? AND period_start <= ?
-                AND status = 'completed'
-                "#,
-            )
-            .bind(task_id)
-            .bind(week_start)
-            .bind(week_end)
-            .fetch_one(pool)
-            .await?;
+            // Expected periods within the week exclude skipped ones (paused/vacation)
+            let counts = Uuid::parse_str(task_id)
+                .ok()
+                .and_then(|id| totals.get(&id).copied())
+                .unwrap_or_default();
+            let expected = counts.periods_completed + counts.periods_failed;
+            let completed = counts.periods_completed;
 
             // For bad habits, invert the logic: success = NOT completing the bad habit
             let successful = if is_bad_habit {
@@ -253,13 +248,15 @@ pub async fn calculate_monthly_statistics(
         r#"
         SELECT id, title, assigned_user_id, habit_type
         FROM tasks
-        WHERE household_id = ? AND archived = FALSE
+        WHERE household_id = ? AND archived = FALSE AND deleted_at IS NULL
         "#,
     )
     .bind(household_id.to_string())
     .fetch_all(pool)
     .await?;
 
+    let totals = events::household_task_totals(pool, household_id, month_start, month_end).await?;
+
     // For each member, calculate their statistics
     for (user_id, _username) in &members {
         // Find tasks assigned to this user
@@ -279,35 +276,13 @@ pub async fn calculate_monthly_statistics(
         for (task_id, task_title, _, habit_type) in user_tasks {
             let is_bad_habit = habit_type == "bad";
 
-            // Count expected periods within the month (excluding skipped - paused/vacation)
-            let expected: i64 = sqlx::query_scalar(
-                r#"
-                SELECT COUNT(*) FROM task_period_results
-                WHERE task_id = ?
-                AND period_start >= ? AND period_start <= ?
-                AND status != 'skipped'
-                "#,
-            )
-            .bind(task_id)
-            .bind(month_start)
-            .bind(month_end)
-            .fetch_one(pool)
-            .await?;
-
-            // Count completed periods
-            let completed: i64 = sqlx::query_scalar(
-                r#"
-                SELECT COUNT(*) FROM task_period_results
-                WHERE task_id = ?
-                AND period_start >= ? AND period_start <= ?
-                AND status = 'completed'
-                "#,
-            )
-            .bind(task_id)
-            .bind(month_start)
-            .bind(month_end)
-            .fetch_one(pool)
-            .await?;
+            // Expected periods within the month exclude skipped ones (paused/vacation)
+            let counts = Uuid::parse_str(task_id)
+                .ok()
+                .and_then(|id| totals.get(&id).copied())
+                .unwrap_or_default();
+            let expected = counts.periods_completed + counts.periods_failed;
+            let completed = counts.periods_completed;
 
             // For bad habits, invert the logic: success = NOT completing the bad habit
             let successful = if is_bad_habit {
@@ -538,6 +513,279 @@ pub async fn list_available_months(
     Ok(months)
 }
 
+/// Recalculate the stored weekly and monthly statistics overlapping `start..=end`,
+/// e.g. after a period result was corrected
+pub async fn refresh_stored_statistics(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Result<(), StatisticsError> {
+    for week_start in list_available_weeks(pool, household_id).await? {
+        if week_start <= end && get_week_end(week_start) >= start {
+            calculate_weekly_statistics(pool, household_id, week_start).await?;
+        }
+    }
+    for month in list_available_months(pool, household_id).await? {
+        if month <= end && get_month_end(month) >= start {
+            calculate_monthly_statistics(pool, household_id, month).await?;
+        }
+    }
+
+    Ok(())
+}
+
+fn rate(completed: i32, expected: i32) -> f32 {
+    if expected > 0 {
+        completed as f32 / expected as f32 * 100.0
+    } else {
+        0.0
+    }
+}
+
+/// Build a household-wide report for `start..=end` (inclusive, household-local dates).
+///
+/// Completion rates come from finalized periods, counting avoided bad habits as
+/// success like the weekly statistics do. Points are positive transactions and
+/// weekdays are derived from approved completions of good habits in `timezone`.
+pub async fn get_household_report(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    start: NaiveDate,
+    end: NaiveDate,
+    timezone: Tz,
+) -> Result<HouseholdReport, StatisticsError> {
+    let members: Vec<(String, String)> = sqlx::query_as(
+        r#"
+        SELECT m.user_id, u.username
+        FROM household_memberships m
+        JOIN users u ON m.user_id = u.id
+        WHERE m.household_id = ?
+        ORDER BY u.username
+        "#,
+    )
+    .bind(household_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    let totals = events::household_task_totals(pool, household_id, start, end).await?;
+    let tasks: Vec<(String, String, Option<String>, String)> = sqlx::query_as(
+        "SELECT id, title, assigned_user_id, habit_type FROM tasks WHERE household_id = ?",
+    )
+    .bind(household_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    let mut total_expected = 0;
+    let mut total_completed = 0;
+    let mut per_member: HashMap<String, (i32, i32)> = HashMap::new();
+    let mut per_task: HashMap<String, (String, i32, i32)> = HashMap::new();
+    for (task_id, title, assigned_user_id, habit_type) in tasks {
+        let counts = Uuid::parse_str(&task_id)
+            .ok()
+            .and_then(|id| totals.get(&id).copied())
+            .unwrap_or_default();
+        let expected = (counts.periods_completed + counts.periods_failed) as i32;
+        if expected == 0 {
+            continue;
+        }
+        // For bad habits, success means the habit was NOT completed
+        let successful = if habit_type == "bad" {
+            counts.periods_failed as i32
+        } else {
+            counts.periods_completed as i32
+        };
+
+        total_expected += expected;
+        total_completed += successful;
+        if let Some(user_id) = assigned_user_id {
+            let entry = per_member.entry(user_id).or_default();
+            entry.0 += expected;
+            entry.1 += successful;
+        }
+        per_task.insert(task_id, (title, expected - successful, expected));
+    }
+
+    // Timestamps are stored in UTC; fetch a day of slack on both sides and
+    // filter on the household-local date
+    let window_start = start.checked_sub_days(chrono::Days::new(1)).unwrap_or(start).format("%Y-%m-%d").to_string();
+    let window_end = end.checked_add_days(chrono::Days::new(2)).unwrap_or(end).format("%Y-%m-%d").to_string();
+    let in_range = |at: &DateTime<Utc>| {
+        let local = at.with_timezone(&timezone).date_naive();
+        local >= start && local <= end
+    };
+
+    let transactions: Vec<(String, i64, DateTime<Utc>)> = sqlx::query_as(
+        r#"
+        SELECT user_id, amount, created_at
+        FROM point_transactions
+        WHERE household_id = ? AND amount > 0
+        AND julianday(created_at) >= julianday(?) AND julianday(created_at) < julianday(?)
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(&window_start)
+    .bind(&window_end)
+    .fetch_all(pool)
+    .await?;
+
+    let mut points: HashMap<String, i64> = HashMap::new();
+    for (user_id, amount, _) in transactions.iter().filter(|(_, _, at)| in_range(at)) {
+        *points.entry(user_id.clone()).or_default() += amount;
+    }
+
+    let completions: Vec<(DateTime<Utc>,)> = sqlx::query_as(
+        r#"
+        SELECT c.completed_at
+        FROM task_completions c
+        JOIN tasks t ON c.task_id = t.id
+        WHERE t.household_id = ? AND c.status = 'approved' AND t.habit_type != 'bad'
+        AND julianday(c.completed_at) >= julianday(?) AND julianday(c.completed_at) < julianday(?)
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(&window_start)
+    .bind(&window_end)
+    .fetch_all(pool)
+    .await?;
+
+    let mut weekdays = [0i32; 7];
+    for (completed_at,) in completions.iter().filter(|(at,)| in_range(at)) {
+        let weekday = completed_at.with_timezone(&timezone).weekday().num_days_from_monday();
+        weekdays[weekday as usize] += 1;
+    }
+    let mut busiest_weekdays: Vec<WeekdayActivity> = weekdays
+        .iter()
+        .enumerate()
+        .map(|(weekday, &completions)| WeekdayActivity { weekday: weekday as u32, completions })
+        .collect();
+    busiest_weekdays.sort_by(|a, b| b.completions.cmp(&a.completions).then(a.weekday.cmp(&b.weekday)));
+
+    let mut most_failed_tasks: Vec<FailedTaskReport> = per_task
+        .into_iter()
+        .filter(|(_, (_, failed, _))| *failed > 0)
+        .map(|(task_id, (task_title, failed, expected))| FailedTaskReport {
+            task_id: Uuid::parse_str(&task_id).unwrap(),
+            task_title,
+            failed,
+            expected,
+        })
+        .collect();
+    most_failed_tasks.sort_by(|a, b| b.failed.cmp(&a.failed).then_with(|| a.task_title.cmp(&b.task_title)));
+    most_failed_tasks.truncate(MOST_FAILED_LIMIT);
+
+    let members: Vec<MemberReport> = members
+        .into_iter()
+        .map(|(user_id, username)| {
+            let (expected, completed) = per_member.get(&user_id).copied().unwrap_or_default();
+            MemberReport {
+                user_id: Uuid::parse_str(&user_id).unwrap(),
+                username,
+                expected,
+                completed,
+                completion_rate: rate(completed, expected),
+                points_earned: points.get(&user_id).copied().unwrap_or_default(),
+            }
+        })
+        .collect();
+
+    Ok(HouseholdReport {
+        start_date: start,
+        end_date: end,
+        total_expected,
+        total_completed,
+        completion_rate: rate(total_completed, total_expected),
+        total_points_earned: members.iter().map(|m| m.points_earned).sum(),
+        members,
+        most_failed_tasks,
+        busiest_weekdays,
+    })
+}
+
+/// Completions `task` asks for in `start..=end`: `target_count` for every period
+/// with at least one due date in the range
+pub fn expected_completions(task: &Task, start: NaiveDate, end: NaiveDate, week_start_day: i32) -> i32 {
+    let periods: std::collections::HashSet<(NaiveDate, NaiveDate)> = start
+        .iter_days()
+        .take_while(|date| *date <= end)
+        .filter(|date| scheduler::is_task_due_on_date(task, *date, week_start_day))
+        .map(|date| scheduler::get_period_bounds(task, date))
+        .collect();
+    periods.len() as i32 * task.target_count.max(0)
+}
+
+/// Estimate each member's chore load for `start..=end` from the active task schedules.
+///
+/// Every due period counts `target_count` completions for each assignee (tasks
+/// with several assignees are done by each of them). Paused tasks and bad habits
+/// are not chores and are left out.
+pub async fn get_workload_report(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Result<WorkloadReport, StatisticsError> {
+    let members: Vec<(String, String)> = sqlx::query_as(
+        r#"
+        SELECT m.user_id, u.username
+        FROM household_memberships m
+        JOIN users u ON m.user_id = u.id
+        WHERE m.household_id = ?
+        ORDER BY u.username
+        "#,
+    )
+    .bind(household_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    let tasks = task_service::list_tasks(pool, household_id).await?;
+    let week_start_day = household_settings::week_start_day(pool, household_id).await?;
+
+    // (task count, effort minutes, unestimated count) per user
+    let mut per_member: HashMap<Uuid, (i32, i64, i32)> = HashMap::new();
+    let mut unassigned_count = 0;
+    let mut unassigned_minutes = 0;
+    for task in tasks.iter().filter(|t| !t.paused && t.habit_type != HabitType::Bad) {
+        let count = expected_completions(task, start, end, week_start_day);
+        if count == 0 {
+            continue;
+        }
+        let minutes = task.effort_minutes.map(|m| m as i64 * count as i64);
+        let assignees = task.assignees();
+        if assignees.is_empty() {
+            unassigned_count += count;
+            unassigned_minutes += minutes.unwrap_or_default();
+            continue;
+        }
+        for user_id in assignees {
+            let entry = per_member.entry(user_id).or_default();
+            entry.0 += count;
+            match minutes {
+                Some(minutes) => entry.1 += minutes,
+                None => entry.2 += count,
+            }
+        }
+    }
+
+    let members = members
+        .into_iter()
+        .map(|(user_id, username)| {
+            let user_id = Uuid::parse_str(&user_id).unwrap();
+            let (task_count, effort_minutes, unestimated_count) =
+                per_member.get(&user_id).copied().unwrap_or_default();
+            MemberWorkload { user_id, username, task_count, effort_minutes, unestimated_count }
+        })
+        .collect();
+
+    Ok(WorkloadReport {
+        start_date: start,
+        end_date: end,
+        members,
+        unassigned_count,
+        unassigned_minutes,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -597,4 +845,122 @@ mod tests {
         let month_end = get_month_end(date);
         assert_eq!(month_end, NaiveDate::from_ymd_opt(2024, 12, 31).unwrap());
     }
+
+    #[tokio::test]
+    async fn test_household_report() {
+        use crate::services::period_results;
+        use crate::test_utils;
+        use shared::{PeriodStatus, Role};
+
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let alice = test_utils::create_test_user(&pool, "alice@test.com", Role::Owner).await;
+        let bob = test_utils::create_test_user(&pool, "bob@test.com", Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &alice, Role::Owner).await;
+        test_utils::create_test_membership(&pool, &household_id, &bob, Role::Member).await;
+        let dishes = test_utils::create_test_task(&pool, &household_id).with_title("Dishes").with_assigned_user(alice).build().await;
+        let trash = test_utils::create_test_task(&pool, &household_id).with_title("Trash").with_assigned_user(bob).build().await;
+
+        // Alice: 2 of 2 dishes, Bob: 1 of 3 trash (one skipped period is ignored)
+        for (task_id, day, status) in [
+            (dishes.id, 1, PeriodStatus::Completed),
+            (dishes.id, 2, PeriodStatus::Completed),
+            (trash.id, 1, PeriodStatus::Failed),
+            (trash.id, 2, PeriodStatus::Failed),
+            (trash.id, 3, PeriodStatus::Completed),
+            (trash.id, 4, PeriodStatus::Skipped),
+        ] {
+            let date = NaiveDate::from_ymd_opt(2024, 1, day).unwrap();
+            period_results::finalize_period(&pool, &task_id, date, date, status, 0, 1, "system", None)
+                .await
+                .unwrap();
+            // Earlier periods are read from the rollups, later ones from the event log
+            if day == 2 {
+                events::refresh_rollups(&pool).await.unwrap();
+            }
+        }
+
+        // Two completions on Monday 2024-01-01, one on Tuesday, one outside the range
+        for completed_at in ["2024-01-01T08:00:00Z", "2024-01-01T19:00:00Z", "2024-01-02T08:00:00Z", "2024-02-01T08:00:00Z"] {
+            let completed_at: DateTime<Utc> = completed_at.parse().unwrap();
+            sqlx::query("INSERT INTO task_completions (id, task_id, user_id, completed_at, due_date, status) VALUES (?, ?, ?, ?, ?, 'approved')")
+                .bind(Uuid::new_v4().to_string())
+                .bind(dishes.id.to_string())
+                .bind(alice.to_string())
+                .bind(completed_at)
+                .bind(completed_at.date_naive())
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        for (user_id, amount) in [(alice, 10), (alice, 5), (bob, 3), (bob, -4)] {
+            sqlx::query("INSERT INTO point_transactions (id, household_id, user_id, amount, balance_after, transaction_type, created_at) VALUES (?, ?, ?, ?, 0, 'manual_adjustment', ?)")
+                .bind(Uuid::new_v4().to_string())
+                .bind(household_id.to_string())
+                .bind(user_id.to_string())
+                .bind(amount)
+                .bind("2024-01-02T12:00:00Z".parse::<DateTime<Utc>>().unwrap())
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        let report = get_household_report(&pool, &household_id, start, end, chrono_tz::UTC).await.unwrap();
+
+        assert_eq!((report.total_completed, report.total_expected), (3, 5));
+        assert_eq!(report.total_points_earned, 18);
+
+        let alice_report = report.members.iter().find(|m| m.user_id == alice).unwrap();
+        assert_eq!((alice_report.completed, alice_report.expected, alice_report.points_earned), (2, 2, 15));
+        let bob_report = report.members.iter().find(|m| m.user_id == bob).unwrap();
+        assert_eq!((bob_report.completed, bob_report.expected, bob_report.points_earned), (1, 3, 3));
+
+        assert_eq!(report.most_failed_tasks.len(), 1);
+        assert_eq!(report.most_failed_tasks[0].task_title, "Trash");
+        assert_eq!(report.most_failed_tasks[0].failed, 2);
+
+        assert_eq!(report.busiest_weekdays.len(), 7);
+        assert_eq!(report.busiest_weekdays[0], WeekdayActivity { weekday: 0, completions: 2 });
+        assert_eq!(report.busiest_weekdays[1], WeekdayActivity { weekday: 1, completions: 1 });
+    }
+
+    #[tokio::test]
+    async fn test_workload_report() {
+        use crate::test_utils;
+        use shared::{RecurrenceType, RecurrenceValue, Role};
+
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let alice = test_utils::create_test_user(&pool, "alice@test.com", Role::Owner).await;
+        let bob = test_utils::create_test_user(&pool, "bob@test.com", Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &alice, Role::Owner).await;
+        test_utils::create_test_membership(&pool, &household_id, &bob, Role::Member).await;
+
+        // Tasks are created today, so look at the coming week
+        let start = Utc::now().date_naive();
+        let end = start + chrono::Duration::days(6);
+        let weekday = RecurrenceValue::WeekDay(start.weekday().num_days_from_sunday() as u8);
+        test_utils::create_test_task(&pool, &household_id).with_assigned_user(alice).with_effort_minutes(10).build().await;
+        test_utils::create_test_task(&pool, &household_id)
+            .with_assigned_user(bob)
+            .with_recurrence(RecurrenceType::Weekly)
+            .with_recurrence_value(weekday)
+            .build()
+            .await;
+        test_utils::create_test_task(&pool, &household_id).with_effort_minutes(5).build().await;
+        // Neither paused tasks nor bad habits are chores
+        test_utils::create_test_task(&pool, &household_id).with_assigned_user(bob).with_effort_minutes(30).with_paused(true).build().await;
+        test_utils::create_test_task(&pool, &household_id).with_assigned_user(bob).with_effort_minutes(30).with_habit_type(HabitType::Bad).build().await;
+
+        let report = get_workload_report(&pool, &household_id, start, end).await.unwrap();
+
+        let alice_load = report.members.iter().find(|m| m.user_id == alice).unwrap();
+        assert_eq!((alice_load.task_count, alice_load.effort_minutes, alice_load.unestimated_count), (7, 70, 0));
+        let bob_load = report.members.iter().find(|m| m.user_id == bob).unwrap();
+        assert_eq!((bob_load.task_count, bob_load.effort_minutes, bob_load.unestimated_count), (1, 0, 1));
+        assert_eq!((report.unassigned_count, report.unassigned_minutes), (7, 35));
+    }
 }
@@ -0,0 +1,205 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::services::{household_settings, scheduler, task_exclusions};
+use shared::{Task, TaskClaim};
+
+#[derive(Debug, Error)]
+pub enum TaskClaimError {
+    #[error("Only unassigned tasks can be claimed")]
+    Assigned,
+    #[error("Archived or paused tasks cannot be claimed")]
+    Inactive,
+    #[error("You are excluded from this task")]
+    Excluded,
+    #[error("{0} already claimed this task")]
+    AlreadyClaimed(String),
+    #[error("This task is not claimed")]
+    NotClaimed,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+type ClaimRow = (String, String, String, NaiveDate, DateTime<Utc>);
+
+fn to_claim((_, user_id, username, period_start, claimed_at): ClaimRow) -> Option<TaskClaim> {
+    Some(TaskClaim {
+        user_id: Uuid::parse_str(&user_id).ok()?,
+        username,
+        period_start,
+        claimed_at,
+    })
+}
+
+/// Start of the period a claim made on `today` covers; the same period
+/// completions currently count towards
+pub fn claim_period_start(task: &Task, today: NaiveDate, week_start_day: i32) -> NaiveDate {
+    let period_date = scheduler::get_next_due_date(task, today, week_start_day).unwrap_or(today);
+    scheduler::get_period_bounds(task, period_date).0
+}
+
+/// The claim on a task for the period starting at `period_start`
+pub async fn get_claim(
+    pool: &SqlitePool,
+    task_id: &Uuid,
+    period_start: NaiveDate,
+) -> Result<Option<TaskClaim>, TaskClaimError> {
+    let row: Option<ClaimRow> = sqlx::query_as(
+        r#"
+        SELECT c.task_id, c.user_id, u.username, c.period_start, c.claimed_at
+        FROM task_claims c
+        INNER JOIN users u ON c.user_id = u.id
+        WHERE c.task_id = ? AND c.period_start = ?
+        "#,
+    )
+    .bind(task_id.to_string())
+    .bind(period_start)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row.and_then(to_claim))
+}
+
+/// Claims on the household's tasks that have not expired yet, keyed by task id.
+/// Callers still need to match `period_start` against each task's current period.
+pub async fn list_open_claims(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    since: NaiveDate,
+) -> Result<HashMap<String, Vec<TaskClaim>>, TaskClaimError> {
+    let rows: Vec<ClaimRow> = sqlx::query_as(
+        r#"
+        SELECT c.task_id, c.user_id, u.username, c.period_start, c.claimed_at
+        FROM task_claims c
+        INNER JOIN users u ON c.user_id = u.id
+        INNER JOIN tasks t ON c.task_id = t.id
+        WHERE t.household_id = ? AND c.period_start >= ?
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(since)
+    .fetch_all(pool)
+    .await?;
+
+    let mut claims: HashMap<String, Vec<TaskClaim>> = HashMap::new();
+    for row in rows {
+        let task_id = row.0.clone();
+        if let Some(claim) = to_claim(row) {
+            claims.entry(task_id).or_default().push(claim);
+        }
+    }
+    Ok(claims)
+}
+
+/// Volunteer for an unassigned task for its current period.
+/// Claiming a task the user already claimed returns the existing claim.
+pub async fn claim_task(
+    pool: &SqlitePool,
+    task: &Task,
+    user_id: &Uuid,
+    today: NaiveDate,
+) -> Result<TaskClaim, TaskClaimError> {
+    if !task.assignees().is_empty() {
+        return Err(TaskClaimError::Assigned);
+    }
+    if task.archived || task.paused {
+        return Err(TaskClaimError::Inactive);
+    }
+    if !task_exclusions::excluded_members(pool, &task.id, &[*user_id]).await?.is_empty() {
+        return Err(TaskClaimError::Excluded);
+    }
+
+    let week_start_day = household_settings::week_start_day(pool, &task.household_id).await?;
+    let period_start = claim_period_start(task, today, week_start_day);
+    sqlx::query("INSERT OR IGNORE INTO task_claims (task_id, period_start, user_id, claimed_at) VALUES (?, ?, ?, ?)")
+        .bind(task.id.to_string())
+        .bind(period_start)
+        .bind(user_id.to_string())
+        .bind(Utc::now())
+        .execute(pool)
+        .await?;
+
+    let claim = get_claim(pool, &task.id, period_start)
+        .await?
+        .ok_or(TaskClaimError::NotClaimed)?;
+    if claim.user_id != *user_id {
+        return Err(TaskClaimError::AlreadyClaimed(claim.username));
+    }
+    Ok(claim)
+}
+
+/// Release the claim on a task for its current period, returning the released claim
+pub async fn release_claim(pool: &SqlitePool, task: &Task, today: NaiveDate) -> Result<TaskClaim, TaskClaimError> {
+    let week_start_day = household_settings::week_start_day(pool, &task.household_id).await?;
+    let period_start = claim_period_start(task, today, week_start_day);
+    let claim = get_claim(pool, &task.id, period_start)
+        .await?
+        .ok_or(TaskClaimError::NotClaimed)?;
+
+    sqlx::query("DELETE FROM task_claims WHERE task_id = ? AND period_start = ?")
+        .bind(task.id.to_string())
+        .bind(period_start)
+        .execute(pool)
+        .await?;
+
+    Ok(claim)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    #[tokio::test]
+    async fn test_claim_and_release_task() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let alice = create_test_user(&pool, "alice@test.com", shared::Role::Member).await;
+        let bob = create_test_user(&pool, "bob@test.com", shared::Role::Member).await;
+        let task = create_test_task(&pool, &household_id).build().await;
+        let assigned = create_test_task(&pool, &household_id).with_assigned_user(alice).build().await;
+        let today = Utc::now().date_naive();
+
+        let claim = claim_task(&pool, &task, &alice, today).await.unwrap();
+        assert_eq!(claim.user_id, alice);
+        assert_eq!(claim.username, "alice");
+
+        // Claiming again is a no-op, somebody else is turned away
+        assert_eq!(claim_task(&pool, &task, &alice, today).await.unwrap(), claim);
+        assert!(matches!(
+            claim_task(&pool, &task, &bob, today).await,
+            Err(TaskClaimError::AlreadyClaimed(name)) if name == "alice"
+        ));
+        assert!(matches!(
+            claim_task(&pool, &assigned, &bob, today).await,
+            Err(TaskClaimError::Assigned)
+        ));
+
+        let open = list_open_claims(&pool, &household_id, claim.period_start).await.unwrap();
+        assert_eq!(open.get(&task.id.to_string()), Some(&vec![claim.clone()]));
+
+        assert_eq!(release_claim(&pool, &task, today).await.unwrap(), claim);
+        assert!(matches!(release_claim(&pool, &task, today).await, Err(TaskClaimError::NotClaimed)));
+        assert_eq!(claim_task(&pool, &task, &bob, today).await.unwrap().user_id, bob);
+    }
+
+    #[tokio::test]
+    async fn test_excluded_member_cannot_claim() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let child = create_test_user(&pool, "child@test.com", shared::Role::Member).await;
+        create_test_membership(&pool, &household_id, &child, shared::Role::Member).await;
+        let task = create_test_task(&pool, &household_id).build().await;
+        crate::services::task_exclusions::add_exclusion(&pool, &household_id, &child, &task.id)
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            claim_task(&pool, &task, &child, Utc::now().date_naive()).await,
+            Err(TaskClaimError::Excluded)
+        ));
+    }
+}
@@ -0,0 +1,199 @@
+use chrono::Utc;
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::TaskCommentRow;
+use shared::{CreateTaskCommentRequest, TaskComment, TaskCommentWithUser, User};
+
+#[derive(Debug, Error)]
+pub enum TaskCommentError {
+    #[error("Comment not found")]
+    NotFound,
+    #[error("Permission denied")]
+    PermissionDenied,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+pub async fn create_comment(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    task_id: &Uuid,
+    user_id: &Uuid,
+    request: &CreateTaskCommentRequest,
+) -> Result<TaskComment, TaskCommentError> {
+    let id = Uuid::new_v4();
+    let now = Utc::now();
+    let content = request.content.trim();
+
+    sqlx::query(
+        r#"
+        INSERT INTO task_comments (id, task_id, household_id, user_id, content, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(id.to_string())
+    .bind(task_id.to_string())
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .bind(content)
+    .bind(now)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    Ok(TaskComment {
+        id,
+        task_id: *task_id,
+        household_id: *household_id,
+        user_id: *user_id,
+        content: content.to_string(),
+        created_at: now,
+        updated_at: now,
+    })
+}
+
+pub async fn get_comment(
+    pool: &SqlitePool,
+    comment_id: &Uuid,
+) -> Result<Option<TaskComment>, TaskCommentError> {
+    let comment: Option<TaskCommentRow> = sqlx::query_as("SELECT * FROM task_comments WHERE id = ?")
+        .bind(comment_id.to_string())
+        .fetch_optional(pool)
+        .await?;
+
+    Ok(comment.map(|c| c.to_shared()))
+}
+
+/// List all comments of a task, oldest first
+pub async fn list_comments(
+    pool: &SqlitePool,
+    task_id: &Uuid,
+) -> Result<Vec<TaskCommentWithUser>, TaskCommentError> {
+    #[derive(sqlx::FromRow)]
+    struct TaskCommentWithUserRow {
+        // Comment fields
+        c_id: String,
+        c_task_id: String,
+        c_household_id: String,
+        c_user_id: String,
+        c_content: String,
+        c_created_at: chrono::DateTime<chrono::Utc>,
+        c_updated_at: chrono::DateTime<chrono::Utc>,
+        // User fields
+        u_id: String,
+        u_username: String,
+        u_email: String,
+        u_created_at: chrono::DateTime<chrono::Utc>,
+        u_updated_at: chrono::DateTime<chrono::Utc>,
+    }
+
+    let rows: Vec<TaskCommentWithUserRow> = sqlx::query_as(
+        r#"
+        SELECT
+            c.id as c_id, c.task_id as c_task_id, c.household_id as c_household_id,
+            c.user_id as c_user_id, c.content as c_content,
+            c.created_at as c_created_at, c.updated_at as c_updated_at,
+            u.id as u_id, u.username as u_username, u.email as u_email,
+            u.created_at as u_created_at, u.updated_at as u_updated_at
+        FROM task_comments c
+        JOIN users u ON c.user_id = u.id
+        WHERE c.task_id = ?
+        ORDER BY c.created_at ASC
+        "#,
+    )
+    .bind(task_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| TaskCommentWithUser {
+            comment: TaskComment {
+                id: Uuid::parse_str(&row.c_id).unwrap(),
+                task_id: Uuid::parse_str(&row.c_task_id).unwrap(),
+                household_id: Uuid::parse_str(&row.c_household_id).unwrap(),
+                user_id: Uuid::parse_str(&row.c_user_id).unwrap(),
+                content: row.c_content,
+                created_at: row.c_created_at,
+                updated_at: row.c_updated_at,
+            },
+            user: User {
+                id: Uuid::parse_str(&row.u_id).unwrap(),
+                username: row.u_username,
+                email: row.u_email,
+                created_at: row.u_created_at,
+                updated_at: row.u_updated_at,
+            },
+        })
+        .collect())
+}
+
+/// Check if user can delete a comment
+/// Authors can delete their own comments, task managers can moderate all comments
+pub fn can_delete_comment(comment: &TaskComment, user_id: &Uuid, can_manage_tasks: bool) -> bool {
+    comment.user_id == *user_id || can_manage_tasks
+}
+
+pub async fn delete_comment(
+    pool: &SqlitePool,
+    comment_id: &Uuid,
+    user_id: &Uuid,
+    can_manage_tasks: bool,
+) -> Result<(), TaskCommentError> {
+    let comment = get_comment(pool, comment_id)
+        .await?
+        .ok_or(TaskCommentError::NotFound)?;
+
+    if !can_delete_comment(&comment, user_id, can_manage_tasks) {
+        return Err(TaskCommentError::PermissionDenied);
+    }
+
+    sqlx::query("DELETE FROM task_comments WHERE id = ?")
+        .bind(comment_id.to_string())
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_comment_for(user_id: Uuid) -> TaskComment {
+        TaskComment {
+            id: Uuid::new_v4(),
+            task_id: Uuid::new_v4(),
+            household_id: Uuid::new_v4(),
+            user_id,
+            content: "Dishwasher is broken".to_string(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_task_comment_error_display() {
+        assert_eq!(TaskCommentError::NotFound.to_string(), "Comment not found");
+        assert_eq!(TaskCommentError::PermissionDenied.to_string(), "Permission denied");
+    }
+
+    #[test]
+    fn test_can_delete_own_comment() {
+        let author = Uuid::new_v4();
+        let comment = create_comment_for(author);
+
+        assert!(can_delete_comment(&comment, &author, false));
+    }
+
+    #[test]
+    fn test_can_delete_comment_as_manager() {
+        let comment = create_comment_for(Uuid::new_v4());
+        let other_user = Uuid::new_v4();
+
+        assert!(can_delete_comment(&comment, &other_user, true));
+        assert!(!can_delete_comment(&comment, &other_user, false));
+    }
+}
@@ -0,0 +1,222 @@
+use chrono::NaiveDate;
+use sqlx::SqlitePool;
+use std::collections::HashSet;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::TaskRowWithCategory;
+use crate::services::scheduler;
+use shared::{RecurrenceType, Task};
+
+#[derive(Debug, Error)]
+pub enum TaskDependencyError {
+    #[error("Task not found")]
+    TaskNotFound,
+    #[error("A task cannot depend on itself")]
+    SelfDependency,
+    #[error("Prerequisite must belong to the same household")]
+    DifferentHousehold,
+    #[error("This prerequisite would create a circular dependency")]
+    Circular,
+    #[error("Dependency already exists")]
+    AlreadyExists,
+    #[error("Dependency not found")]
+    NotFound,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+/// Get the prerequisites of a task, ordered by title
+pub async fn get_prerequisites(pool: &SqlitePool, task_id: &Uuid) -> Result<Vec<Task>, TaskDependencyError> {
+    let rows: Vec<TaskRowWithCategory> = sqlx::query_as(
+        r#"
+        SELECT t.*, tc.name as category_name
+        FROM tasks t
+        INNER JOIN task_dependencies d ON t.id = d.prerequisite_id
+        LEFT JOIN task_categories tc ON t.category_id = tc.id
+        WHERE d.task_id = ? AND t.deleted_at IS NULL
+        ORDER BY t.title COLLATE NOCASE ASC
+        "#,
+    )
+    .bind(task_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|t| t.to_shared()).collect())
+}
+
+async fn get_task_household(pool: &SqlitePool, task_id: &Uuid) -> Result<Option<String>, TaskDependencyError> {
+    Ok(sqlx::query_scalar("SELECT household_id FROM tasks WHERE id = ?")
+        .bind(task_id.to_string())
+        .fetch_optional(pool)
+        .await?)
+}
+
+/// Whether `from` already (transitively) depends on `target`
+async fn depends_on(pool: &SqlitePool, from: &Uuid, target: &Uuid) -> Result<bool, TaskDependencyError> {
+    let target = target.to_string();
+    let mut visited = HashSet::new();
+    let mut queue = vec![from.to_string()];
+
+    while let Some(current) = queue.pop() {
+        if current == target {
+            return Ok(true);
+        }
+        if !visited.insert(current.clone()) {
+            continue;
+        }
+        let next: Vec<String> = sqlx::query_scalar("SELECT prerequisite_id FROM task_dependencies WHERE task_id = ?")
+            .bind(&current)
+            .fetch_all(pool)
+            .await?;
+        queue.extend(next);
+    }
+
+    Ok(false)
+}
+
+/// Require `prerequisite_id` to be completed before `task_id` in each period
+pub async fn add_dependency(
+    pool: &SqlitePool,
+    task_id: &Uuid,
+    prerequisite_id: &Uuid,
+) -> Result<(), TaskDependencyError> {
+    if task_id == prerequisite_id {
+        return Err(TaskDependencyError::SelfDependency);
+    }
+
+    let task_household = get_task_household(pool, task_id).await?.ok_or(TaskDependencyError::TaskNotFound)?;
+    let prerequisite_household = get_task_household(pool, prerequisite_id)
+        .await?
+        .ok_or(TaskDependencyError::TaskNotFound)?;
+    if task_household != prerequisite_household {
+        return Err(TaskDependencyError::DifferentHousehold);
+    }
+
+    if depends_on(pool, prerequisite_id, task_id).await? {
+        return Err(TaskDependencyError::Circular);
+    }
+
+    let result = sqlx::query("INSERT OR IGNORE INTO task_dependencies (task_id, prerequisite_id) VALUES (?, ?)")
+        .bind(task_id.to_string())
+        .bind(prerequisite_id.to_string())
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(TaskDependencyError::AlreadyExists);
+    }
+
+    Ok(())
+}
+
+pub async fn remove_dependency(
+    pool: &SqlitePool,
+    task_id: &Uuid,
+    prerequisite_id: &Uuid,
+) -> Result<(), TaskDependencyError> {
+    let result = sqlx::query("DELETE FROM task_dependencies WHERE task_id = ? AND prerequisite_id = ?")
+        .bind(task_id.to_string())
+        .bind(prerequisite_id.to_string())
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(TaskDependencyError::NotFound);
+    }
+
+    Ok(())
+}
+
+/// Prerequisites of a task that are not yet done for the period containing `date`.
+/// A prerequisite counts as done once its own target is met in its period around
+/// `date` (one-time tasks: ever). Archived and paused prerequisites never block.
+pub async fn unmet_prerequisites(
+    pool: &SqlitePool,
+    task_id: &Uuid,
+    date: NaiveDate,
+) -> Result<Vec<Task>, TaskDependencyError> {
+    let mut unmet = Vec::new();
+
+    for prerequisite in get_prerequisites(pool, task_id).await? {
+        if prerequisite.archived || prerequisite.paused {
+            continue;
+        }
+
+        let completions: i64 = if prerequisite.recurrence_type == RecurrenceType::OneTime {
+            sqlx::query_scalar("SELECT COUNT(*) FROM task_completions WHERE task_id = ? AND status != 'rejected'")
+                .bind(prerequisite.id.to_string())
+                .fetch_one(pool)
+                .await?
+        } else {
+            let (period_start, period_end) = scheduler::get_period_bounds(&prerequisite, date);
+            sqlx::query_scalar(
+                "SELECT COUNT(*) FROM task_completions WHERE task_id = ? AND status != 'rejected' AND due_date >= ? AND due_date <= ?",
+            )
+            .bind(prerequisite.id.to_string())
+            .bind(period_start)
+            .bind(period_end)
+            .fetch_one(pool)
+            .await?
+        };
+
+        if completions < prerequisite.target_count.max(1) as i64 {
+            unmet.push(prerequisite);
+        }
+    }
+
+    Ok(unmet)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    #[tokio::test]
+    async fn test_add_dependency_rejects_cycles_and_foreign_tasks() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let owner_id: String = sqlx::query_scalar("SELECT owner_id FROM households WHERE id = ?")
+            .bind(household_id.to_string())
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let other_household = Uuid::new_v4();
+        sqlx::query("INSERT INTO households (id, name, owner_id, created_at, updated_at) VALUES (?, 'Other', ?, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)")
+            .bind(other_household.to_string())
+            .bind(&owner_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let vacuum = create_test_task(&pool, &household_id).with_title("Vacuum").build().await;
+        let mop = create_test_task(&pool, &household_id).with_title("Mop").build().await;
+        let foreign = create_test_task(&pool, &other_household).build().await;
+
+        add_dependency(&pool, &mop.id, &vacuum.id).await.unwrap();
+
+        assert!(matches!(
+            add_dependency(&pool, &mop.id, &vacuum.id).await,
+            Err(TaskDependencyError::AlreadyExists)
+        ));
+        assert!(matches!(
+            add_dependency(&pool, &vacuum.id, &mop.id).await,
+            Err(TaskDependencyError::Circular)
+        ));
+        assert!(matches!(
+            add_dependency(&pool, &mop.id, &mop.id).await,
+            Err(TaskDependencyError::SelfDependency)
+        ));
+        assert!(matches!(
+            add_dependency(&pool, &mop.id, &foreign.id).await,
+            Err(TaskDependencyError::DifferentHousehold)
+        ));
+
+        let prerequisites = get_prerequisites(&pool, &mop.id).await.unwrap();
+        assert_eq!(prerequisites.len(), 1);
+        assert_eq!(prerequisites[0].id, vacuum.id);
+
+        remove_dependency(&pool, &mop.id, &vacuum.id).await.unwrap();
+        assert!(get_prerequisites(&pool, &mop.id).await.unwrap().is_empty());
+    }
+}
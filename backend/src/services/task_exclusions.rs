@@ -0,0 +1,194 @@
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::services::{households as household_service, tasks as task_service};
+use shared::{MemberTaskExclusion, TaskAssignmentExclusion};
+
+#[derive(Debug, Error)]
+pub enum TaskExclusionError {
+    #[error("Task not found")]
+    TaskNotFound,
+    #[error("User is not a member of this household")]
+    NotMember,
+    #[error("Task error: {0}")]
+    Task(#[from] task_service::TaskError),
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+type ExclusionRow = (String, String, String, DateTime<Utc>);
+
+fn to_exclusion((user_id, task_id, task_title, created_at): ExclusionRow) -> Option<MemberTaskExclusion> {
+    Some(MemberTaskExclusion {
+        user_id: Uuid::parse_str(&user_id).ok()?,
+        task_id: Uuid::parse_str(&task_id).ok()?,
+        task_title,
+        created_at,
+    })
+}
+
+/// Tasks the member must not be given, by task title
+pub async fn list_member_exclusions(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+) -> Result<Vec<MemberTaskExclusion>, TaskExclusionError> {
+    let rows: Vec<ExclusionRow> = sqlx::query_as(
+        r#"
+        SELECT e.user_id, e.task_id, t.title, e.created_at
+        FROM member_task_exclusions e
+        JOIN tasks t ON e.task_id = t.id
+        WHERE e.household_id = ? AND e.user_id = ? AND t.deleted_at IS NULL
+        ORDER BY t.title COLLATE NOCASE ASC
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().filter_map(to_exclusion).collect())
+}
+
+/// Every stored exclusion of the household, in the form the rebalancer takes
+pub async fn list_household_exclusions(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+) -> Result<Vec<TaskAssignmentExclusion>, TaskExclusionError> {
+    let rows: Vec<(String, String)> =
+        sqlx::query_as("SELECT user_id, task_id FROM member_task_exclusions WHERE household_id = ?")
+            .bind(household_id.to_string())
+            .fetch_all(pool)
+            .await?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|(user_id, task_id)| {
+            Some(TaskAssignmentExclusion {
+                user_id: Uuid::parse_str(&user_id).ok()?,
+                task_id: Some(Uuid::parse_str(&task_id).ok()?),
+            })
+        })
+        .collect())
+}
+
+/// Exclude the member from a task of the household; excluding twice is not an error
+pub async fn add_exclusion(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    task_id: &Uuid,
+) -> Result<MemberTaskExclusion, TaskExclusionError> {
+    if !household_service::is_member(pool, household_id, user_id).await.unwrap_or(false) {
+        return Err(TaskExclusionError::NotMember);
+    }
+    let task = task_service::get_task(pool, task_id)
+        .await?
+        .filter(|t| t.household_id == *household_id)
+        .ok_or(TaskExclusionError::TaskNotFound)?;
+
+    sqlx::query(
+        "INSERT OR IGNORE INTO member_task_exclusions (household_id, user_id, task_id, created_at) VALUES (?, ?, ?, ?)",
+    )
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .bind(task_id.to_string())
+    .bind(Utc::now())
+    .execute(pool)
+    .await?;
+
+    let (created_at,): (DateTime<Utc>,) =
+        sqlx::query_as("SELECT created_at FROM member_task_exclusions WHERE user_id = ? AND task_id = ?")
+            .bind(user_id.to_string())
+            .bind(task_id.to_string())
+            .fetch_one(pool)
+            .await?;
+
+    Ok(MemberTaskExclusion {
+        user_id: *user_id,
+        task_id: task.id,
+        task_title: task.title,
+        created_at,
+    })
+}
+
+/// Lift an exclusion; returns whether there was one
+pub async fn remove_exclusion(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    task_id: &Uuid,
+) -> Result<bool, TaskExclusionError> {
+    let result = sqlx::query("DELETE FROM member_task_exclusions WHERE household_id = ? AND user_id = ? AND task_id = ?")
+        .bind(household_id.to_string())
+        .bind(user_id.to_string())
+        .bind(task_id.to_string())
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// The members among `user_ids` who are excluded from the task
+pub async fn excluded_members(
+    pool: &SqlitePool,
+    task_id: &Uuid,
+    user_ids: &[Uuid],
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    let rows: Vec<(String,)> = sqlx::query_as("SELECT user_id FROM member_task_exclusions WHERE task_id = ?")
+        .bind(task_id.to_string())
+        .fetch_all(pool)
+        .await?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|(user_id,)| Uuid::parse_str(&user_id).ok())
+        .filter(|user_id| user_ids.contains(user_id))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use shared::Role;
+
+    #[tokio::test]
+    async fn test_add_list_and_remove_exclusions() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let child = create_test_user(&pool, "child@test.com", Role::Member).await;
+        create_test_membership(&pool, &household_id, &child, Role::Member).await;
+        let baking = create_test_task(&pool, &household_id).with_title("Bake bread").build().await;
+        let dusting = create_test_task(&pool, &household_id).with_title("Dusting").build().await;
+
+        let exclusion = add_exclusion(&pool, &household_id, &child, &baking.id).await.unwrap();
+        assert_eq!(exclusion.task_title, "Bake bread");
+        // Adding it again keeps the single exclusion
+        add_exclusion(&pool, &household_id, &child, &baking.id).await.unwrap();
+
+        let exclusions = list_member_exclusions(&pool, &household_id, &child).await.unwrap();
+        assert_eq!(exclusions.len(), 1);
+        assert_eq!(excluded_members(&pool, &baking.id, &[child]).await.unwrap(), vec![child]);
+        assert!(excluded_members(&pool, &dusting.id, &[child]).await.unwrap().is_empty());
+
+        assert!(remove_exclusion(&pool, &household_id, &child, &baking.id).await.unwrap());
+        assert!(!remove_exclusion(&pool, &household_id, &child, &baking.id).await.unwrap());
+        assert!(list_household_exclusions(&pool, &household_id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_add_exclusion_rejects_outsiders() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let outsider = create_test_user(&pool, "outsider@test.com", Role::Member).await;
+        let task = create_test_task(&pool, &household_id).build().await;
+
+        assert!(matches!(
+            add_exclusion(&pool, &household_id, &outsider, &task.id).await,
+            Err(TaskExclusionError::NotMember)
+        ));
+    }
+}
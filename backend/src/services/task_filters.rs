@@ -0,0 +1,223 @@
+use chrono::{Days, NaiveDate, Utc};
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::SavedTaskFilterRow;
+use crate::services::{scheduler, task_tags};
+use shared::{CreateSavedTaskFilterRequest, SavedTaskFilter, Task, TaskFilter};
+
+#[derive(Debug, Error)]
+pub enum SavedFilterError {
+    #[error("Saved filter not found")]
+    NotFound,
+    #[error("Filter name must not be empty")]
+    EmptyName,
+    #[error("A saved filter with this name already exists")]
+    DuplicateName,
+    #[error("Due window must be between 0 and {} days", shared::MAX_DUE_WITHIN_DAYS)]
+    InvalidDueWindow,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+/// Whether the task matches every criterion of the filter. The due window is
+/// measured from `today` using the task's next due date in a household whose
+/// weeks start on `week_start_day`.
+pub fn matches(filter: &TaskFilter, task: &Task, today: NaiveDate, week_start_day: i32) -> bool {
+    if let Some(assignee_id) = filter.assignee_id {
+        if !task.assignees().contains(&assignee_id) {
+            return false;
+        }
+    }
+    if !task_tags::has_all_tags(task, &filter.tag_ids) {
+        return false;
+    }
+    if filter.category_id.is_some() && task.category_id != filter.category_id {
+        return false;
+    }
+    if let Some(days) = filter.due_within_days {
+        // A window reaching past the last representable date has no upper bound
+        let last_day = today.checked_add_days(Days::new(days.max(0).unsigned_abs()));
+        let due_soon = scheduler::get_next_due_date(task, today, week_start_day)
+            .is_some_and(|due| last_day.is_none_or(|last_day| due <= last_day));
+        if !due_soon {
+            return false;
+        }
+    }
+    filter.habit_type.is_none_or(|habit_type| task.habit_type == habit_type)
+}
+
+/// Keep only the tasks matching the filter
+pub fn apply(filter: &TaskFilter, tasks: Vec<Task>, week_start_day: i32) -> Vec<Task> {
+    if filter.is_empty() {
+        return tasks;
+    }
+    let today = Utc::now().date_naive();
+    tasks.into_iter().filter(|task| matches(filter, task, today, week_start_day)).collect()
+}
+
+pub async fn list_saved_filters(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+) -> Result<Vec<SavedTaskFilter>, SavedFilterError> {
+    let rows: Vec<SavedTaskFilterRow> = sqlx::query_as(
+        "SELECT * FROM saved_task_filters WHERE household_id = ? AND user_id = ? ORDER BY name COLLATE NOCASE ASC",
+    )
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.to_shared()).collect())
+}
+
+pub async fn create_saved_filter(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    request: &CreateSavedTaskFilterRequest,
+) -> Result<SavedTaskFilter, SavedFilterError> {
+    let name = request.name.trim();
+    if name.is_empty() {
+        return Err(SavedFilterError::EmptyName);
+    }
+    if !request.filter.has_valid_due_window() {
+        return Err(SavedFilterError::InvalidDueWindow);
+    }
+
+    let id = Uuid::new_v4();
+    let now = Utc::now();
+    let filter = serde_json::to_string(&request.filter).unwrap_or_else(|_| "{}".to_string());
+
+    sqlx::query(
+        "INSERT INTO saved_task_filters (id, household_id, user_id, name, filter, created_at) VALUES (?, ?, ?, ?, ?, ?)",
+    )
+    .bind(id.to_string())
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .bind(name)
+    .bind(&filter)
+    .bind(now)
+    .execute(pool)
+    .await
+    .map_err(|e| match e {
+        sqlx::Error::Database(ref db) if db.message().contains("UNIQUE constraint failed") => {
+            SavedFilterError::DuplicateName
+        }
+        e => SavedFilterError::DatabaseError(e),
+    })?;
+
+    Ok(SavedTaskFilter {
+        id,
+        household_id: *household_id,
+        user_id: *user_id,
+        name: name.to_string(),
+        filter: request.filter.clone(),
+        created_at: now,
+    })
+}
+
+/// Delete one of the user's own saved filters
+pub async fn delete_saved_filter(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    filter_id: &Uuid,
+) -> Result<(), SavedFilterError> {
+    let result = sqlx::query("DELETE FROM saved_task_filters WHERE id = ? AND household_id = ? AND user_id = ?")
+        .bind(filter_id.to_string())
+        .bind(household_id.to_string())
+        .bind(user_id.to_string())
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(SavedFilterError::NotFound);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use shared::{HabitType, RecurrenceType, Role};
+
+    #[tokio::test]
+    async fn test_matches_filter_criteria() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let user_id = create_test_user(&pool, "alice@test.com", Role::Member).await;
+        let mut task = create_test_task(&pool, &household_id).build().await;
+        task.assigned_user_id = Some(user_id);
+        let tag_id = Uuid::new_v4();
+        task.tag_ids = vec![tag_id];
+        let today = task.created_at.date_naive();
+
+        assert!(matches(&TaskFilter::default(), &task, today, 0));
+        let filter = TaskFilter {
+            assignee_id: Some(user_id),
+            tag_ids: vec![tag_id],
+            due_within_days: Some(0),
+            habit_type: Some(HabitType::Good),
+            ..Default::default()
+        };
+        assert!(matches(&filter, &task, today, 0));
+
+        let other_assignee = TaskFilter { assignee_id: Some(Uuid::new_v4()), ..Default::default() };
+        assert!(!matches(&other_assignee, &task, today, 0));
+        let other_category = TaskFilter { category_id: Some(Uuid::new_v4()), ..Default::default() };
+        assert!(!matches(&other_category, &task, today, 0));
+        let bad_habits = TaskFilter { habit_type: Some(HabitType::Bad), ..Default::default() };
+        assert!(!matches(&bad_habits, &task, today, 0));
+
+        // One-time tasks have no next due date, so they are never due soon
+        task.recurrence_type = RecurrenceType::OneTime;
+        assert!(!matches(&TaskFilter { due_within_days: Some(7), ..Default::default() }, &task, today, 0));
+
+        // Windows past the last representable date don't overflow
+        task.recurrence_type = RecurrenceType::Daily;
+        assert!(matches(&TaskFilter { due_within_days: Some(i64::MAX), ..Default::default() }, &task, today, 0));
+    }
+
+    #[tokio::test]
+    async fn test_saved_filters_are_per_user() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let alice = create_test_user(&pool, "alice@test.com", Role::Member).await;
+        let bob = create_test_user(&pool, "bob@test.com", Role::Member).await;
+
+        let request = CreateSavedTaskFilterRequest {
+            name: " This week ".to_string(),
+            filter: TaskFilter { due_within_days: Some(7), ..Default::default() },
+        };
+        let saved = create_saved_filter(&pool, &household_id, &alice, &request).await.unwrap();
+        assert_eq!(saved.name, "This week");
+        assert!(matches!(
+            create_saved_filter(&pool, &household_id, &alice, &request).await,
+            Err(SavedFilterError::DuplicateName)
+        ));
+        // Names only need to be unique per user
+        create_saved_filter(&pool, &household_id, &bob, &request).await.unwrap();
+
+        let too_far = CreateSavedTaskFilterRequest {
+            name: "Forever".to_string(),
+            filter: TaskFilter { due_within_days: Some(i64::MAX), ..Default::default() },
+        };
+        assert!(matches!(
+            create_saved_filter(&pool, &household_id, &alice, &too_far).await,
+            Err(SavedFilterError::InvalidDueWindow)
+        ));
+
+        assert_eq!(list_saved_filters(&pool, &household_id, &alice).await.unwrap(), vec![saved.clone()]);
+        assert!(matches!(
+            delete_saved_filter(&pool, &household_id, &bob, &saved.id).await,
+            Err(SavedFilterError::NotFound)
+        ));
+        delete_saved_filter(&pool, &household_id, &alice, &saved.id).await.unwrap();
+        assert!(list_saved_filters(&pool, &household_id, &alice).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,518 @@
+use chrono::NaiveDate;
+use rand::distributions::WeightedIndex;
+use rand::prelude::*;
+use sqlx::SqlitePool;
+use std::collections::{HashMap, HashSet};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::services::households::{self as household_service, HouseholdError};
+use crate::services::{statistics, task_exclusions, tasks as task_service};
+use shared::{
+    AssignmentChange, AssignmentPlan, HabitType, HouseholdSettings, MemberAssignmentLoad, MemberLoadChange,
+    PlannedAssignment, RandomizeAssignmentsRequest, RebalanceSuggestion, Task, TaskAssignmentExclusion, UpdateTaskRequest,
+};
+
+/// Days the workload is estimated for when a rebalance request gives no end date
+pub const DEFAULT_REBALANCE_DAYS: i64 = 28;
+
+/// Days planned when a randomize request gives no end date
+pub const DEFAULT_RANDOMIZE_DAYS: i64 = 7;
+
+#[derive(Debug, Error)]
+pub enum RebalanceError {
+    #[error("Task not found")]
+    TaskNotFound,
+    #[error("The assignment of \"{0}\" was changed in the meantime")]
+    Stale(String),
+    #[error("The new assignee is excluded from \"{0}\"")]
+    Excluded(String),
+    #[error("None of the selected members can be given \"{0}\"")]
+    NoEligibleMember(String),
+    #[error("Exclusion error: {0}")]
+    Exclusion(#[from] task_exclusions::TaskExclusionError),
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("Task error: {0}")]
+    Task(#[from] task_service::TaskError),
+    #[error("Household error: {0}")]
+    Household(#[from] HouseholdError),
+}
+
+/// A single-assignee task the balancer may hand to someone else
+struct MovableTask {
+    current: Uuid,
+    /// Estimated minutes in the range
+    minutes: i64,
+    /// Members who may take the task
+    allowed: Vec<Uuid>,
+}
+
+/// Sum of squared loads; with a fixed total, lower means a more even distribution
+fn spread(loads: &HashMap<Uuid, i64>, members: &[Uuid]) -> i128 {
+    members
+        .iter()
+        .map(|m| loads.get(m).copied().unwrap_or_default() as i128)
+        .map(|load| load * load)
+        .sum()
+}
+
+/// Pick an assignee for every task so the members' loads end up as even as possible.
+///
+/// The largest tasks are placed first, each on the allowed member with the least
+/// load. Afterwards every move that does not make the distribution more even is
+/// undone, so tasks stay with their assignee where balancing gains nothing.
+fn balance(fixed: &HashMap<Uuid, i64>, members: &[Uuid], tasks: &[MovableTask]) -> Vec<Uuid> {
+    let mut loads = fixed.clone();
+    let mut assignment: Vec<Uuid> = tasks.iter().map(|t| t.current).collect();
+
+    let mut order: Vec<usize> = (0..tasks.len()).collect();
+    order.sort_by(|&a, &b| tasks[b].minutes.cmp(&tasks[a].minutes));
+    for i in order {
+        let task = &tasks[i];
+        // Ties go to the current assignee to avoid needless moves
+        if let Some(&best) = task
+            .allowed
+            .iter()
+            .min_by_key(|m| (loads.get(*m).copied().unwrap_or_default(), **m != task.current))
+        {
+            assignment[i] = best;
+        }
+        *loads.entry(assignment[i]).or_default() += task.minutes;
+    }
+
+    for (i, task) in tasks.iter().enumerate() {
+        let proposed = assignment[i];
+        if proposed == task.current || !task.allowed.contains(&task.current) {
+            continue;
+        }
+        let before = spread(&loads, members);
+        *loads.entry(proposed).or_default() -= task.minutes;
+        *loads.entry(task.current).or_default() += task.minutes;
+        if spread(&loads, members) <= before {
+            assignment[i] = task.current;
+        } else {
+            *loads.entry(task.current).or_default() -= task.minutes;
+            *loads.entry(proposed).or_default() += task.minutes;
+        }
+    }
+
+    assignment
+}
+
+/// Suggest reassignments that even out the estimated workload in `start..=end`.
+///
+/// Only active good-habit tasks with an effort estimate and exactly one assignee
+/// are moved; tasks with several assignees count towards each of them as they are.
+/// Tasks are only given to members who can be assigned tasks in the household's
+/// hierarchy and are not excluded from them, by the request or their stored exclusions.
+pub async fn suggest_rebalance(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    settings: &HouseholdSettings,
+    start: NaiveDate,
+    end: NaiveDate,
+    exclusions: &[TaskAssignmentExclusion],
+) -> Result<RebalanceSuggestion, RebalanceError> {
+    let mut members: Vec<(Uuid, String)> = household_service::list_members(pool, household_id)
+        .await?
+        .into_iter()
+        .filter(|m| settings.hierarchy_type.can_be_assigned(&m.membership.role))
+        .map(|m| (m.user.id, m.user.username))
+        .collect();
+    members.sort_by(|a, b| a.1.cmp(&b.1));
+    let member_ids: Vec<Uuid> = members.iter().map(|(id, _)| *id).collect();
+
+    let mut exclusions = exclusions.to_vec();
+    exclusions.extend(task_exclusions::list_household_exclusions(pool, household_id).await?);
+    let excluded_everywhere: HashSet<Uuid> = exclusions
+        .iter()
+        .filter(|e| e.task_id.is_none())
+        .map(|e| e.user_id)
+        .collect();
+    let is_excluded = |user_id: &Uuid, task_id: &Uuid| {
+        excluded_everywhere.contains(user_id)
+            || exclusions.iter().any(|e| e.user_id == *user_id && e.task_id == Some(*task_id))
+    };
+
+    let tasks = task_service::list_tasks(pool, household_id).await?;
+    let mut fixed: HashMap<Uuid, i64> = HashMap::new();
+    let mut movable_tasks: Vec<&Task> = Vec::new();
+    let mut movable: Vec<MovableTask> = Vec::new();
+    for task in tasks.iter().filter(|t| !t.paused && t.habit_type != HabitType::Bad) {
+        let Some(effort) = task.effort_minutes else {
+            continue;
+        };
+        let minutes = statistics::expected_completions(task, start, end, settings.week_start_day) as i64 * effort as i64;
+        if minutes == 0 {
+            continue;
+        }
+        match task.assignees().as_slice() {
+            [current] => {
+                movable_tasks.push(task);
+                movable.push(MovableTask {
+                    current: *current,
+                    minutes,
+                    allowed: member_ids.iter().copied().filter(|id| !is_excluded(id, &task.id)).collect(),
+                });
+            }
+            assignees => {
+                for user_id in assignees {
+                    *fixed.entry(*user_id).or_default() += minutes;
+                }
+            }
+        }
+    }
+
+    let proposed = balance(&fixed, &member_ids, &movable);
+
+    let mut current_loads = fixed.clone();
+    let mut proposed_loads = fixed;
+    let mut changes = Vec::new();
+    for ((task, entry), to_user_id) in movable_tasks.iter().zip(&movable).zip(proposed) {
+        *current_loads.entry(entry.current).or_default() += entry.minutes;
+        *proposed_loads.entry(to_user_id).or_default() += entry.minutes;
+        if to_user_id != entry.current {
+            changes.push(AssignmentChange {
+                task_id: task.id,
+                task_title: task.title.clone(),
+                from_user_id: entry.current,
+                to_user_id,
+            });
+        }
+    }
+
+    let members = members
+        .into_iter()
+        .map(|(user_id, username)| MemberLoadChange {
+            user_id,
+            username,
+            current_minutes: current_loads.get(&user_id).copied().unwrap_or_default(),
+            proposed_minutes: proposed_loads.get(&user_id).copied().unwrap_or_default(),
+        })
+        .collect();
+
+    Ok(RebalanceSuggestion {
+        start_date: start,
+        end_date: end,
+        changes,
+        members,
+    })
+}
+
+/// Apply reassignments from a suggestion. Every change is checked against the
+/// current assignee before any of them is written.
+pub async fn apply_rebalance(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    changes: &[AssignmentChange],
+) -> Result<Vec<Task>, RebalanceError> {
+    for change in changes {
+        let task = task_service::get_task(pool, &change.task_id)
+            .await?
+            .filter(|t| t.household_id == *household_id)
+            .ok_or(RebalanceError::TaskNotFound)?;
+        if task.assignees() != [change.from_user_id] {
+            return Err(RebalanceError::Stale(task.title));
+        }
+        if !task_exclusions::excluded_members(pool, &task.id, &[change.to_user_id]).await?.is_empty() {
+            return Err(RebalanceError::Excluded(task.title));
+        }
+    }
+
+    let mut updated = Vec::with_capacity(changes.len());
+    for change in changes {
+        let request = UpdateTaskRequest {
+            assignee_ids: Some(vec![change.to_user_id]),
+            ..Default::default()
+        };
+        updated.push(task_service::update_task(pool, &change.task_id, &request).await?);
+    }
+    Ok(updated)
+}
+
+/// A task to be drawn for one of the members
+struct WheelTask {
+    /// Completions the task asks for in the planned period, at least one
+    completions: i64,
+    /// Members who may take the task
+    allowed: Vec<Uuid>,
+}
+
+/// Draw an assignee for every task, going through the tasks in random order.
+///
+/// Without weighting the tasks are dealt out evenly: each goes to a random one of
+/// the allowed members who have been dealt the fewest tasks so far. With weighting
+/// members are drawn with a chance that grows the further their load (past plus
+/// planned completions) is below the highest load among the allowed members.
+fn spin<R: Rng>(rng: &mut R, past: &HashMap<Uuid, i64>, tasks: &[WheelTask], weighted: bool) -> Vec<Uuid> {
+    let mut loads = past.clone();
+    let mut dealt: HashMap<Uuid, i64> = HashMap::new();
+    let mut assignment = vec![Uuid::nil(); tasks.len()];
+
+    let mut order: Vec<usize> = (0..tasks.len()).collect();
+    order.shuffle(rng);
+    for i in order {
+        let task = &tasks[i];
+        let picked = if weighted {
+            let load = |m: &Uuid| loads.get(m).copied().unwrap_or_default();
+            let highest = task.allowed.iter().map(load).max().unwrap_or_default();
+            let weights: Vec<i64> = task.allowed.iter().map(|m| highest - load(m) + 1).collect();
+            WeightedIndex::new(&weights).ok().map(|index| task.allowed[index.sample(rng)])
+        } else {
+            let fewest = task.allowed.iter().map(|m| dealt.get(m).copied().unwrap_or_default()).min();
+            let candidates: Vec<Uuid> = task
+                .allowed
+                .iter()
+                .copied()
+                .filter(|m| Some(dealt.get(m).copied().unwrap_or_default()) == fewest)
+                .collect();
+            candidates.choose(rng).copied()
+        };
+        if let Some(user_id) = picked {
+            assignment[i] = user_id;
+            *loads.entry(user_id).or_default() += task.completions;
+            *dealt.entry(user_id).or_default() += 1;
+        }
+    }
+
+    assignment
+}
+
+/// Completions each member of the household did (or has pending) with a due date in `start..=end`
+async fn completions_by_member(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Result<HashMap<Uuid, i64>, RebalanceError> {
+    let rows: Vec<(String, i64)> = sqlx::query_as(
+        r#"
+        SELECT c.user_id, COUNT(*)
+        FROM task_completions c
+        JOIN tasks t ON c.task_id = t.id
+        WHERE t.household_id = ? AND c.status != 'rejected' AND c.due_date >= ? AND c.due_date <= ?
+        GROUP BY c.user_id
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(start)
+    .bind(end)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|(user_id, count)| Uuid::parse_str(&user_id).ok().map(|id| (id, count)))
+        .collect())
+}
+
+/// Draw a random assignee from the requested members for each requested task for `start..=end`.
+///
+/// No members in the request draws from every member who can be assigned tasks in the
+/// household's hierarchy. Members are never given a task they are excluded from.
+/// When weighted, the completions of the equally long period before `start`
+/// count against a member. Nothing is assigned until the plan is applied.
+pub async fn randomize_assignments(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    settings: &HouseholdSettings,
+    request: &RandomizeAssignmentsRequest,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Result<AssignmentPlan, RebalanceError> {
+    let mut members: Vec<(Uuid, String)> = household_service::list_members(pool, household_id)
+        .await?
+        .into_iter()
+        .filter(|m| settings.hierarchy_type.can_be_assigned(&m.membership.role))
+        .filter(|m| request.member_ids.is_empty() || request.member_ids.contains(&m.user.id))
+        .map(|m| (m.user.id, m.user.username))
+        .collect();
+    members.sort_by(|a, b| a.1.cmp(&b.1));
+
+    let exclusions = task_exclusions::list_household_exclusions(pool, household_id).await?;
+    let mut tasks: Vec<Task> = Vec::with_capacity(request.task_ids.len());
+    let mut wheel: Vec<WheelTask> = Vec::with_capacity(request.task_ids.len());
+    for task_id in &request.task_ids {
+        if tasks.iter().any(|t| t.id == *task_id) {
+            continue;
+        }
+        let task = task_service::get_task(pool, task_id)
+            .await?
+            .filter(|t| t.household_id == *household_id)
+            .ok_or(RebalanceError::TaskNotFound)?;
+        let allowed: Vec<Uuid> = members
+            .iter()
+            .map(|(id, _)| *id)
+            .filter(|id| {
+                !exclusions
+                    .iter()
+                    .any(|e| e.user_id == *id && e.task_id.is_none_or(|excluded| excluded == task.id))
+            })
+            .collect();
+        if allowed.is_empty() {
+            return Err(RebalanceError::NoEligibleMember(task.title));
+        }
+        wheel.push(WheelTask {
+            completions: (statistics::expected_completions(&task, start, end, settings.week_start_day) as i64).max(1),
+            allowed,
+        });
+        tasks.push(task);
+    }
+
+    let past = if request.weighted {
+        let days = (end - start).num_days() + 1;
+        completions_by_member(pool, household_id, start - chrono::Duration::days(days), start - chrono::Duration::days(1))
+            .await?
+    } else {
+        HashMap::new()
+    };
+
+    let drawn = spin(&mut rand::thread_rng(), &past, &wheel, request.weighted);
+
+    let mut planned: HashMap<Uuid, i64> = HashMap::new();
+    let mut assignments = Vec::with_capacity(tasks.len());
+    for ((task, entry), user_id) in tasks.iter().zip(&wheel).zip(drawn) {
+        *planned.entry(user_id).or_default() += entry.completions;
+        assignments.push(PlannedAssignment {
+            task_id: task.id,
+            task_title: task.title.clone(),
+            current_assignee_ids: task.assignees(),
+            user_id,
+        });
+    }
+
+    let members = members
+        .into_iter()
+        .map(|(user_id, username)| MemberAssignmentLoad {
+            user_id,
+            username,
+            past_completions: past.get(&user_id).copied().unwrap_or_default(),
+            planned_completions: planned.get(&user_id).copied().unwrap_or_default(),
+        })
+        .collect();
+
+    Ok(AssignmentPlan {
+        start_date: start,
+        end_date: end,
+        assignments,
+        members,
+    })
+}
+
+/// Apply the assignments of a randomized plan. Every task is checked against the
+/// assignees it had when the plan was made before any of them is written.
+pub async fn apply_assignment_plan(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    assignments: &[PlannedAssignment],
+) -> Result<Vec<Task>, RebalanceError> {
+    for assignment in assignments {
+        let task = task_service::get_task(pool, &assignment.task_id)
+            .await?
+            .filter(|t| t.household_id == *household_id)
+            .ok_or(RebalanceError::TaskNotFound)?;
+        let current: HashSet<Uuid> = task.assignees().into_iter().collect();
+        if current != assignment.current_assignee_ids.iter().copied().collect() {
+            return Err(RebalanceError::Stale(task.title));
+        }
+        if !task_exclusions::excluded_members(pool, &task.id, &[assignment.user_id]).await?.is_empty() {
+            return Err(RebalanceError::Excluded(task.title));
+        }
+    }
+
+    let mut updated = Vec::with_capacity(assignments.len());
+    for assignment in assignments {
+        let request = UpdateTaskRequest {
+            assignee_ids: Some(vec![assignment.user_id]),
+            ..Default::default()
+        };
+        updated.push(task_service::update_task(pool, &assignment.task_id, &request).await?);
+    }
+    Ok(updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(current: Uuid, minutes: i64, allowed: &[Uuid]) -> MovableTask {
+        MovableTask { current, minutes, allowed: allowed.to_vec() }
+    }
+
+    #[test]
+    fn test_balance_evens_out_loads() {
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+        let members = [alice, bob];
+        let tasks = [task(alice, 60, &members), task(alice, 40, &members), task(alice, 20, &members)];
+
+        let assignment = balance(&HashMap::new(), &members, &tasks);
+
+        // 60 vs 40 + 20
+        assert_eq!(assignment[0], alice);
+        assert_eq!(assignment[1], bob);
+        assert_eq!(assignment[2], bob);
+    }
+
+    #[test]
+    fn test_balance_keeps_assignees_without_gain() {
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+        let members = [alice, bob];
+        let tasks = [task(bob, 30, &members), task(alice, 30, &members)];
+
+        assert_eq!(balance(&HashMap::new(), &members, &tasks), vec![bob, alice]);
+    }
+
+    #[test]
+    fn test_balance_respects_exclusions_and_fixed_load() {
+        let (alice, bob, carol) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let members = [alice, bob, carol];
+        // Bob already has 100 minutes on a shared task; Carol may not take the task
+        let fixed = HashMap::from([(bob, 100)]);
+        let tasks = [task(carol, 50, &[alice, bob])];
+
+        assert_eq!(balance(&fixed, &members, &tasks), vec![alice]);
+    }
+
+    fn wheel_task(completions: i64, allowed: &[Uuid]) -> WheelTask {
+        WheelTask { completions, allowed: allowed.to_vec() }
+    }
+
+    #[test]
+    fn test_spin_deals_tasks_evenly() {
+        let (alice, bob, carol) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let members = [alice, bob, carol];
+        let tasks: Vec<WheelTask> = (0..6).map(|_| wheel_task(1, &members)).collect();
+
+        let assignment = spin(&mut rand::thread_rng(), &HashMap::new(), &tasks, false);
+
+        for member in members {
+            assert_eq!(assignment.iter().filter(|m| **m == member).count(), 2);
+        }
+    }
+
+    #[test]
+    fn test_spin_respects_allowed_members() {
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+        let tasks = [wheel_task(1, &[bob]), wheel_task(3, &[alice]), wheel_task(2, &[alice, bob])];
+
+        for weighted in [false, true] {
+            let assignment = spin(&mut rand::thread_rng(), &HashMap::new(), &tasks, weighted);
+            assert_eq!(assignment[0], bob);
+            assert_eq!(assignment[1], alice);
+            assert!(assignment[2] == alice || assignment[2] == bob);
+        }
+    }
+
+    #[test]
+    fn test_spin_weighted_favours_lighter_past_load() {
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+        // Alice did far more last period, so the single task nearly always goes to Bob
+        let past = HashMap::from([(alice, 1000), (bob, 0)]);
+        let tasks = [wheel_task(1, &[alice, bob])];
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let to_bob = (0..100).filter(|_| spin(&mut rng, &past, &tasks, true)[0] == bob).count();
+        assert!(to_bob >= 95, "{}", to_bob);
+    }
+}
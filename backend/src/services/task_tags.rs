@@ -0,0 +1,282 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::Utc;
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::TaskTagRow;
+use shared::{CreateTaskTagRequest, Task, TaskTag, UpdateTaskTagRequest};
+
+#[derive(Debug, Error)]
+pub enum TaskTagError {
+    #[error("Tag not found")]
+    NotFound,
+    #[error("Tag name must not be empty")]
+    EmptyName,
+    #[error("Tag name already exists in this household")]
+    DuplicateName,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+fn map_unique_violation(e: sqlx::Error) -> TaskTagError {
+    match e {
+        sqlx::Error::Database(ref db) if db.message().contains("UNIQUE constraint failed") => TaskTagError::DuplicateName,
+        e => TaskTagError::DatabaseError(e),
+    }
+}
+
+pub async fn create_tag(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    request: &CreateTaskTagRequest,
+) -> Result<TaskTag, TaskTagError> {
+    let name = request.name.trim();
+    if name.is_empty() {
+        return Err(TaskTagError::EmptyName);
+    }
+
+    let id = Uuid::new_v4();
+    let now = Utc::now();
+
+    sqlx::query("INSERT INTO task_tags (id, household_id, name, color, created_at) VALUES (?, ?, ?, ?, ?)")
+        .bind(id.to_string())
+        .bind(household_id.to_string())
+        .bind(name)
+        .bind(&request.color)
+        .bind(now)
+        .execute(pool)
+        .await
+        .map_err(map_unique_violation)?;
+
+    Ok(TaskTag {
+        id,
+        household_id: *household_id,
+        name: name.to_string(),
+        color: request.color.clone(),
+        created_at: now,
+    })
+}
+
+pub async fn get_tag(pool: &SqlitePool, tag_id: &Uuid) -> Result<Option<TaskTag>, TaskTagError> {
+    let tag: Option<TaskTagRow> = sqlx::query_as("SELECT * FROM task_tags WHERE id = ?")
+        .bind(tag_id.to_string())
+        .fetch_optional(pool)
+        .await?;
+
+    Ok(tag.map(|t| t.to_shared()))
+}
+
+pub async fn list_tags(pool: &SqlitePool, household_id: &Uuid) -> Result<Vec<TaskTag>, TaskTagError> {
+    let tags: Vec<TaskTagRow> =
+        sqlx::query_as("SELECT * FROM task_tags WHERE household_id = ? ORDER BY name COLLATE NOCASE ASC")
+            .bind(household_id.to_string())
+            .fetch_all(pool)
+            .await?;
+
+    Ok(tags.into_iter().map(|t| t.to_shared()).collect())
+}
+
+pub async fn update_tag(
+    pool: &SqlitePool,
+    tag_id: &Uuid,
+    request: &UpdateTaskTagRequest,
+) -> Result<TaskTag, TaskTagError> {
+    let mut tag: TaskTagRow = sqlx::query_as("SELECT * FROM task_tags WHERE id = ?")
+        .bind(tag_id.to_string())
+        .fetch_optional(pool)
+        .await?
+        .ok_or(TaskTagError::NotFound)?;
+
+    if let Some(ref name) = request.name {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(TaskTagError::EmptyName);
+        }
+        tag.name = name.to_string();
+    }
+    if let Some(ref color) = request.color {
+        tag.color = Some(color.clone());
+    }
+
+    sqlx::query("UPDATE task_tags SET name = ?, color = ? WHERE id = ?")
+        .bind(&tag.name)
+        .bind(&tag.color)
+        .bind(tag_id.to_string())
+        .execute(pool)
+        .await
+        .map_err(map_unique_violation)?;
+
+    Ok(tag.to_shared())
+}
+
+pub async fn delete_tag(pool: &SqlitePool, tag_id: &Uuid) -> Result<(), TaskTagError> {
+    // Assignments go with the tag (ON DELETE CASCADE)
+    let result = sqlx::query("DELETE FROM task_tags WHERE id = ?")
+        .bind(tag_id.to_string())
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(TaskTagError::NotFound);
+    }
+
+    Ok(())
+}
+
+/// Replace the tags of a task. Tags of other households are ignored.
+pub async fn set_task_tags(
+    pool: &SqlitePool,
+    task_id: &Uuid,
+    household_id: &Uuid,
+    tag_ids: &[Uuid],
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM task_tag_assignments WHERE task_id = ?")
+        .bind(task_id.to_string())
+        .execute(pool)
+        .await?;
+
+    for tag_id in tag_ids.iter().collect::<HashSet<_>>() {
+        sqlx::query(
+            r#"
+            INSERT INTO task_tag_assignments (task_id, tag_id)
+            SELECT ?, id FROM task_tags WHERE id = ? AND household_id = ?
+            "#,
+        )
+        .bind(task_id.to_string())
+        .bind(tag_id.to_string())
+        .bind(household_id.to_string())
+        .execute(pool)
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Fill in `tag_ids` of the tasks, sorted by tag name
+pub async fn load_tags(pool: &SqlitePool, tasks: &mut [Task]) -> Result<(), sqlx::Error> {
+    let households: HashSet<Uuid> = tasks.iter().map(|t| t.household_id).collect();
+    let mut by_task: HashMap<String, Vec<Uuid>> = HashMap::new();
+
+    for household_id in households {
+        let rows: Vec<(String, String)> = sqlx::query_as(
+            r#"
+            SELECT a.task_id, a.tag_id FROM task_tag_assignments a
+            INNER JOIN task_tags tt ON tt.id = a.tag_id
+            WHERE tt.household_id = ?
+            ORDER BY tt.name COLLATE NOCASE
+            "#,
+        )
+        .bind(household_id.to_string())
+        .fetch_all(pool)
+        .await?;
+        for (task_id, tag_id) in rows {
+            if let Ok(tag_id) = Uuid::parse_str(&tag_id) {
+                by_task.entry(task_id).or_default().push(tag_id);
+            }
+        }
+    }
+
+    for task in tasks.iter_mut() {
+        task.tag_ids = by_task.remove(&task.id.to_string()).unwrap_or_default();
+    }
+
+    Ok(())
+}
+
+/// Tag IDs from a comma-separated `tags` query parameter; invalid IDs are an error
+pub fn parse_tag_filter(value: Option<&str>) -> Result<Vec<Uuid>, String> {
+    value
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(|id| Uuid::parse_str(id).map_err(|_| format!("Invalid tag ID: {}", id)))
+        .collect()
+}
+
+/// Whether the task carries every one of the tags
+pub fn has_all_tags(task: &Task, tag_ids: &[Uuid]) -> bool {
+    tag_ids.iter().all(|tag_id| task.tag_ids.contains(tag_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::tasks as task_service;
+    use crate::test_utils::*;
+
+    fn tag_request(name: &str) -> CreateTaskTagRequest {
+        CreateTaskTagRequest {
+            name: name.to_string(),
+            color: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_tag_filter() {
+        let id = Uuid::new_v4();
+        assert_eq!(parse_tag_filter(None), Ok(vec![]));
+        assert_eq!(parse_tag_filter(Some(&format!("{}, ", id))), Ok(vec![id]));
+        assert!(parse_tag_filter(Some("outside")).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_tag_crud() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+
+        let tag = create_tag(&pool, &household_id, &tag_request(" outside ")).await.unwrap();
+        assert_eq!(tag.name, "outside");
+        assert!(matches!(
+            create_tag(&pool, &household_id, &tag_request("outside")).await,
+            Err(TaskTagError::DuplicateName)
+        ));
+        assert!(matches!(create_tag(&pool, &household_id, &tag_request("  ")).await, Err(TaskTagError::EmptyName)));
+
+        let update = UpdateTaskTagRequest {
+            name: Some("garden".to_string()),
+            color: Some("#22c55e".to_string()),
+        };
+        let updated = update_tag(&pool, &tag.id, &update).await.unwrap();
+        assert_eq!(updated.name, "garden");
+        assert_eq!(list_tags(&pool, &household_id).await.unwrap(), vec![updated]);
+
+        delete_tag(&pool, &tag.id).await.unwrap();
+        assert!(get_tag(&pool, &tag.id).await.unwrap().is_none());
+        assert!(matches!(delete_tag(&pool, &tag.id).await, Err(TaskTagError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_task_tags_are_loaded_and_replaced() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let other_household = Uuid::new_v4();
+        sqlx::query("INSERT INTO households (id, name, owner_id, created_at, updated_at) SELECT ?, 'Other', owner_id, created_at, updated_at FROM households WHERE id = ?")
+            .bind(other_household.to_string())
+            .bind(household_id.to_string())
+            .execute(&pool)
+            .await
+            .unwrap();
+        let task = create_test_task(&pool, &household_id).build().await;
+
+        let outside = create_tag(&pool, &household_id, &tag_request("outside")).await.unwrap();
+        let car = create_tag(&pool, &household_id, &tag_request("needs-car")).await.unwrap();
+        let foreign = create_tag(&pool, &other_household, &tag_request("foreign")).await.unwrap();
+
+        set_task_tags(&pool, &task.id, &household_id, &[outside.id, car.id, foreign.id, car.id])
+            .await
+            .unwrap();
+        let loaded = task_service::get_task(&pool, &task.id).await.unwrap().unwrap();
+        // Sorted by name; the other household's tag was ignored
+        assert_eq!(loaded.tag_ids, vec![car.id, outside.id]);
+        assert!(has_all_tags(&loaded, &[outside.id, car.id]));
+        assert!(!has_all_tags(&loaded, &[foreign.id]));
+
+        set_task_tags(&pool, &task.id, &household_id, &[outside.id]).await.unwrap();
+        delete_tag(&pool, &outside.id).await.unwrap();
+        let loaded = task_service::get_task(&pool, &task.id).await.unwrap().unwrap();
+        assert!(loaded.tag_ids.is_empty());
+    }
+}
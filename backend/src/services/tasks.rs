@@ -1,11 +1,13 @@
-use chrono::{Datelike, NaiveDate, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use std::collections::{HashMap, HashSet};
 use sqlx::SqlitePool;
 use thiserror::Error;
 use uuid::Uuid;
 
-use crate::models::{TaskCompletionRow, TaskRow, TaskRowWithCategory, UserRow};
-use crate::services::{households as household_service, period_results, points as points_service, scheduler, task_consequences};
-use shared::{CompletionStatus, CreateTaskRequest, PendingReview, PeriodStatus, SuggestionStatus, Task, TaskCompletion, TaskStatistics, TaskWithDetails, TaskWithStatus, UpdateTaskRequest};
+use crate::models::{TaskCompletionRow, TaskPeriodResultRow, TaskRow, TaskRowWithCategory, UserRow};
+use crate::services::events::{self, EventKind};
+use crate::services::{focus_sessions, household_settings, households as household_service, period_results, pets, plants, points as points_service, scheduler, task_claims, task_consequences, task_dependencies, task_filters, task_tags, webhooks};
+use shared::{CompletionStatus, CreateTaskRequest, Paginated, PendingReview, PeriodStatus, ReviewerType, SuggestionStatus, Task, TaskCompletion, TaskFilter, TaskPeriodResult, TaskStatistics, TaskWithDetails, TaskWithStatus, UpdateTaskRequest, WebhookEvent};
 
 #[derive(Debug, Error)]
 pub enum TaskError {
@@ -19,10 +21,29 @@ pub enum TaskError {
     NotCompleted,
     #[error("User is not assigned to this task")]
     NotAssigned,
+    #[error("Only scheduled tasks can be skipped")]
+    NotSkippable,
+    #[error("Only scheduled tasks can be postponed")]
+    NotPostponable,
+    #[error("The new date must be after the current due date")]
+    InvalidPostponeDate,
+    #[error("Only periods that have ended can be corrected")]
+    PeriodNotEnded,
+    #[error("Complete these tasks first: {0}")]
+    PrerequisitesNotMet(String),
+    #[error("Dependency error: {0}")]
+    DependencyError(#[from] task_dependencies::TaskDependencyError),
+    #[error("{0} has claimed this task")]
+    ClaimedByOther(String),
+    #[error("Claim error: {0}")]
+    ClaimError(#[from] task_claims::TaskClaimError),
+    #[error("Task was changed in the meantime")]
+    VersionConflict(Box<Task>),
     #[error("Database error: {0}")]
     DatabaseError(#[from] sqlx::Error),
 }
 
+#[tracing::instrument(level = "debug", skip_all, fields(%household_id))]
 pub async fn create_task(
     pool: &SqlitePool,
     household_id: &Uuid,
@@ -34,6 +55,9 @@ pub async fn create_task(
     let target_count = request.target_count.unwrap_or(1);
     let allow_exceed_target = request.allow_exceed_target.unwrap_or(true);
     let requires_review = request.requires_review.unwrap_or(false);
+    let reviewer_type = request.reviewer_type.unwrap_or_default();
+    // Only a Member reviewer type names a reviewer
+    let reviewer_id = request.reviewer_id.filter(|_| reviewer_type == ReviewerType::Member);
     let habit_type = request.habit_type.unwrap_or_default();
 
     let recurrence_value = request
@@ -43,6 +67,8 @@ pub async fn create_task(
 
     let time_period_str = request.time_period.as_ref().map(|p| p.as_str());
 
+    let assignees = requested_assignees(request.assigned_user_id, request.assignee_ids.as_ref()).unwrap_or_default();
+
     // Set suggestion status if this is a suggestion
     let suggestion_status = if suggested_by.is_some() {
         Some(SuggestionStatus::Suggested)
@@ -52,8 +78,8 @@ pub async fn create_task(
 
     sqlx::query(
         r#"
-        INSERT INTO tasks (id, household_id, title, description, recurrence_type, recurrence_value, assigned_user_id, target_count, time_period, allow_exceed_target, requires_review, points_reward, points_penalty, due_time, habit_type, category_id, suggestion, suggested_by, created_at, updated_at)
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        INSERT INTO tasks (id, household_id, title, description, recurrence_type, recurrence_value, assigned_user_id, target_count, time_period, allow_exceed_target, requires_review, reviewer_type, reviewer_id, points_reward, points_penalty, due_time, effort_minutes, active_from, active_until, ends_on, max_occurrences, habit_type, category_id, suggestion, suggested_by, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
         "#,
     )
     .bind(id.to_string())
@@ -62,14 +88,21 @@ pub async fn create_task(
     .bind(request.description.as_deref().unwrap_or(""))
     .bind(request.recurrence_type.as_str())
     .bind(&recurrence_value)
-    .bind(request.assigned_user_id.map(|u| u.to_string()))
+    .bind(assignees.first().map(|u| u.to_string()))
     .bind(target_count)
     .bind(time_period_str)
     .bind(allow_exceed_target)
     .bind(requires_review)
+    .bind(reviewer_type.as_str())
+    .bind(reviewer_id.map(|u| u.to_string()))
     .bind(request.points_reward)
     .bind(request.points_penalty)
     .bind(&request.due_time)
+    .bind(request.effort_minutes)
+    .bind(&request.active_from)
+    .bind(&request.active_until)
+    .bind(request.ends_on)
+    .bind(request.max_occurrences)
     .bind(habit_type.as_str())
     .bind(request.category_id.map(|c| c.to_string()))
     .bind(suggestion_status.as_ref().map(|s| s.as_str()))
@@ -79,31 +112,119 @@ pub async fn create_task(
     .execute(pool)
     .await?;
 
-    Ok(Task {
+    set_task_assignees(pool, &id, &assignees).await?;
+    if let Some(ref tag_ids) = request.tag_ids {
+        task_tags::set_task_tags(pool, &id, household_id, tag_ids).await?;
+    }
+
+    let mut task = Task {
         id,
         household_id: *household_id,
         title: request.title.clone(),
         description: request.description.clone().unwrap_or_default(),
         recurrence_type: request.recurrence_type.clone(),
         recurrence_value: request.recurrence_value.clone(),
-        assigned_user_id: request.assigned_user_id,
+        assigned_user_id: assignees.first().copied(),
+        assignee_ids: assignees,
         target_count,
         time_period: request.time_period,
         allow_exceed_target,
         requires_review,
+        reviewer_type,
+        reviewer_id,
         points_reward: request.points_reward,
         points_penalty: request.points_penalty,
         due_time: request.due_time.clone(),
+        effort_minutes: request.effort_minutes,
+        active_from: request.active_from.clone(),
+        active_until: request.active_until.clone(),
+        ends_on: request.ends_on,
+        max_occurrences: request.max_occurrences,
         habit_type,
         category_id: request.category_id,
         category_name: None,
+        tag_ids: Vec::new(),
         archived: false,
         paused: false,
         suggestion: suggestion_status,
         suggested_by: suggested_by.copied(),
+        postponed_from: None,
+        postponed_to: None,
+        version: 1,
         created_at: now,
         updated_at: now,
-    })
+    };
+    task_tags::load_tags(pool, std::slice::from_mut(&mut task)).await?;
+    Ok(task)
+}
+
+/// Assignees asked for by a create/update request: `assignee_ids` wins over
+/// `assigned_user_id`, duplicates are dropped. None means "not specified".
+pub fn requested_assignees(assigned_user_id: Option<Uuid>, assignee_ids: Option<&Vec<Uuid>>) -> Option<Vec<Uuid>> {
+    match assignee_ids {
+        Some(ids) => {
+            let mut seen = HashSet::new();
+            Some(ids.iter().copied().filter(|id| seen.insert(*id)).collect())
+        }
+        None => assigned_user_id.map(|id| vec![id]),
+    }
+}
+
+/// Replace the assignees of a task; the first one is mirrored into `tasks.assigned_user_id`
+async fn set_task_assignees(pool: &SqlitePool, task_id: &Uuid, assignees: &[Uuid]) -> Result<(), TaskError> {
+    sqlx::query("DELETE FROM task_assignees WHERE task_id = ?")
+        .bind(task_id.to_string())
+        .execute(pool)
+        .await?;
+
+    for user_id in assignees {
+        sqlx::query("INSERT INTO task_assignees (task_id, user_id) VALUES (?, ?)")
+            .bind(task_id.to_string())
+            .bind(user_id.to_string())
+            .execute(pool)
+            .await?;
+    }
+
+    sqlx::query("UPDATE tasks SET assigned_user_id = ? WHERE id = ?")
+        .bind(assignees.first().map(|u| u.to_string()))
+        .bind(task_id.to_string())
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Fill in `assignee_ids` from `task_assignees` (rows only carry the first assignee)
+pub async fn load_assignees(pool: &SqlitePool, tasks: &mut [Task]) -> Result<(), TaskError> {
+    let households: HashSet<Uuid> = tasks.iter().map(|t| t.household_id).collect();
+    let mut by_task: HashMap<String, Vec<Uuid>> = HashMap::new();
+
+    for household_id in households {
+        let rows: Vec<(String, String)> = sqlx::query_as(
+            r#"
+            SELECT a.task_id, a.user_id FROM task_assignees a
+            INNER JOIN tasks t ON t.id = a.task_id
+            WHERE t.household_id = ?
+            ORDER BY a.rowid
+            "#,
+        )
+        .bind(household_id.to_string())
+        .fetch_all(pool)
+        .await?;
+        for (task_id, user_id) in rows {
+            if let Ok(user_id) = Uuid::parse_str(&user_id) {
+                by_task.entry(task_id).or_default().push(user_id);
+            }
+        }
+    }
+
+    for task in tasks.iter_mut() {
+        if let Some(ids) = by_task.remove(&task.id.to_string()) {
+            task.assignee_ids = ids;
+        }
+    }
+
+    Ok(())
 }
 
 pub async fn get_task(pool: &SqlitePool, task_id: &Uuid) -> Result<Option<Task>, TaskError> {
@@ -112,14 +233,32 @@ pub async fn get_task(pool: &SqlitePool, task_id: &Uuid) -> Result<Option<Task>,
         SELECT t.*, tc.name as category_name
         FROM tasks t
         LEFT JOIN task_categories tc ON t.category_id = tc.id
-        WHERE t.id = ?
+        WHERE t.id = ? AND t.deleted_at IS NULL
         "#
     )
         .bind(task_id.to_string())
         .fetch_optional(pool)
         .await?;
 
-    Ok(task.map(|t| t.to_shared()))
+    let Some(mut task) = task.map(|t| t.to_shared()) else {
+        return Ok(None);
+    };
+    let assignees = get_assignee_ids(pool, task_id).await?;
+    if !assignees.is_empty() {
+        task.assignee_ids = assignees;
+    }
+    task_tags::load_tags(pool, std::slice::from_mut(&mut task)).await?;
+
+    Ok(Some(task))
+}
+
+/// Assignees of a single task, first assignee first
+pub async fn get_assignee_ids(pool: &SqlitePool, task_id: &Uuid) -> Result<Vec<Uuid>, sqlx::Error> {
+    let assignees: Vec<String> = sqlx::query_scalar("SELECT user_id FROM task_assignees WHERE task_id = ? ORDER BY rowid")
+        .bind(task_id.to_string())
+        .fetch_all(pool)
+        .await?;
+    Ok(assignees.iter().filter_map(|id| Uuid::parse_str(id).ok()).collect())
 }
 
 pub async fn get_task_with_status(
@@ -133,21 +272,26 @@ pub async fn get_task_with_status(
     };
 
     let today = Utc::now().date_naive();
+    let week_start_day = household_settings::week_start_day(pool, &task.household_id).await?;
 
     // Calculate next due date first
-    let next_due_date = scheduler::get_next_due_date(&task, today);
+    let next_due_date = scheduler::get_next_due_date(&task, today, week_start_day);
 
     // Get completion count for the current period
     // Use next_due_date for period calculation to match how completions are stored
     // This ensures completions made "early" for the next occurrence are counted correctly
     let period_date = next_due_date.unwrap_or(today);
     let (period_start, period_end) = scheduler::get_period_bounds(&task, period_date);
+    // With individual completions only the user's own completions count
+    let individual = task.has_individual_completions();
     let completions_today = sqlx::query_scalar::<_, i64>(
-        "SELECT COUNT(*) FROM task_completions WHERE task_id = ? AND due_date >= ? AND due_date <= ?",
+        "SELECT COUNT(*) FROM task_completions WHERE task_id = ? AND due_date >= ? AND due_date <= ? AND (? = 0 OR user_id = ?)",
     )
     .bind(task_id.to_string())
     .bind(period_start)
     .bind(period_end)
+    .bind(individual)
+    .bind(user_id.to_string())
     .fetch_one(pool)
     .await? as i32;
 
@@ -162,15 +306,31 @@ pub async fn get_task_with_status(
     // Calculate streak
     let current_streak = calculate_streak(pool, &task, user_id).await?;
 
-    // Check if user is assigned to this task
-    let is_user_assigned = task.assigned_user_id
-        .map(|assigned_id| assigned_id == *user_id)
-        .unwrap_or(true); // If no assignment, anyone can complete
+    // Another member's claim for this period blocks the task like an assignment
+    let claimed_by = task_claims::get_claim(pool, task_id, period_start).await?;
+
+    // Check if user is assigned to this task (if no assignment, anyone can complete)
+    let is_user_assigned =
+        task.is_assigned_to(user_id) && claimed_by.as_ref().is_none_or(|claim| claim.user_id == *user_id);
 
     // Get recent periods for habit tracker display (last 15)
-    let recent_periods = period_results::get_recent_periods(pool, task_id, 15)
-        .await
-        .unwrap_or_default();
+    let recent_periods = if individual {
+        let rows = period_results::get_assignee_periods(pool, task_id, user_id)
+            .await
+            .unwrap_or_default();
+        period_results::recent_period_displays(&rows[..rows.len().min(RECENT_PERIODS)])
+    } else {
+        period_results::get_recent_periods(pool, task_id, RECENT_PERIODS as i32)
+            .await
+            .unwrap_or_default()
+    };
+
+    // Prerequisites still open for the period this task would be completed in
+    let blocked_by = task_dependencies::unmet_prerequisites(pool, task_id, period_date)
+        .await?
+        .into_iter()
+        .map(|t| t.title)
+        .collect();
 
     Ok(Some(TaskWithStatus {
         task,
@@ -180,9 +340,193 @@ pub async fn get_task_with_status(
         next_due_date,
         is_user_assigned,
         recent_periods,
+        blocked_by,
+        claimed_by,
     }))
 }
 
+/// Number of periods shown in the habit tracker
+const RECENT_PERIODS: usize = 15;
+
+/// Same as `get_task_with_status` for many tasks of one household at once, using a
+/// fixed number of household-wide queries instead of several per task.
+/// The result keeps the order of `tasks`.
+pub async fn get_tasks_with_status(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    tasks: Vec<Task>,
+    user_id: &Uuid,
+) -> Result<Vec<TaskWithStatus>, TaskError> {
+    if tasks.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut tasks = tasks;
+    load_assignees(pool, &mut tasks).await?;
+    task_tags::load_tags(pool, &mut tasks).await?;
+
+    let today = Utc::now().date_naive();
+    let week_start_day = household_settings::week_start_day(pool, household_id).await?;
+    let household = household_id.to_string();
+    let user = user_id.to_string();
+
+    // Next due date and the period a completion would currently count towards
+    let schedules: Vec<(Option<NaiveDate>, NaiveDate, NaiveDate)> = tasks
+        .iter()
+        .map(|task| {
+            let next_due_date = scheduler::get_next_due_date(task, today, week_start_day);
+            let (period_start, period_end) = scheduler::get_period_bounds(task, next_due_date.unwrap_or(today));
+            (next_due_date, period_start, period_end)
+        })
+        .collect();
+    let range_start = schedules.iter().map(|(_, start, _)| *start).min().unwrap_or(today);
+    let range_end = schedules.iter().map(|(_, _, end)| *end).max().unwrap_or(today);
+
+    // Completions per task, due date and user within the union of all current periods
+    let daily_counts: Vec<(String, NaiveDate, String, i64)> = sqlx::query_as(
+        r#"
+        SELECT task_id, due_date, user_id, COUNT(*) FROM task_completions
+        WHERE task_id IN (SELECT id FROM tasks WHERE household_id = ?)
+        AND due_date >= ? AND due_date <= ?
+        GROUP BY task_id, due_date, user_id
+        "#,
+    )
+    .bind(&household)
+    .bind(range_start)
+    .bind(range_end)
+    .fetch_all(pool)
+    .await?;
+    let mut counts_by_task: HashMap<String, Vec<(NaiveDate, bool, i64)>> = HashMap::new();
+    for (task_id, due_date, completed_by, count) in daily_counts {
+        counts_by_task.entry(task_id).or_default().push((due_date, completed_by == user, count));
+    }
+
+    // Total and latest completion per task, and the user's own total
+    let totals: Vec<(String, i64, i64, DateTime<Utc>)> = sqlx::query_as(
+        r#"
+        SELECT task_id, COUNT(*), SUM(user_id = ?), MAX(completed_at) FROM task_completions
+        WHERE task_id IN (SELECT id FROM tasks WHERE household_id = ?)
+        GROUP BY task_id
+        "#,
+    )
+    .bind(&user)
+    .bind(&household)
+    .fetch_all(pool)
+    .await?;
+    let totals: HashMap<String, (i64, i64, DateTime<Utc>)> =
+        totals.into_iter().map(|(task_id, total, own, last)| (task_id, (total, own, last))).collect();
+
+    // Period results, newest first, for streaks and the habit tracker
+    let period_rows: Vec<TaskPeriodResultRow> = sqlx::query_as(
+        r#"
+        SELECT * FROM task_period_results
+        WHERE task_id IN (SELECT id FROM tasks WHERE household_id = ?)
+        ORDER BY period_start DESC
+        "#,
+    )
+    .bind(&household)
+    .fetch_all(pool)
+    .await?;
+    let mut periods_by_task: HashMap<String, Vec<TaskPeriodResultRow>> = HashMap::new();
+    for row in period_rows {
+        periods_by_task.entry(row.task_id.clone()).or_default().push(row);
+    }
+
+    // The user's own period results, for tasks where every assignee completes separately
+    let own_period_rows: Vec<TaskPeriodResultRow> = sqlx::query_as(
+        r#"
+        SELECT * FROM task_assignee_period_results
+        WHERE user_id = ? AND task_id IN (SELECT id FROM tasks WHERE household_id = ?)
+        ORDER BY period_start DESC
+        "#,
+    )
+    .bind(&user)
+    .bind(&household)
+    .fetch_all(pool)
+    .await?;
+    let mut own_periods_by_task: HashMap<String, Vec<TaskPeriodResultRow>> = HashMap::new();
+    for row in own_period_rows {
+        own_periods_by_task.entry(row.task_id.clone()).or_default().push(row);
+    }
+
+    // Only tasks with prerequisites need the per-task prerequisite check
+    let dependent_tasks: HashSet<String> = sqlx::query_scalar(
+        "SELECT DISTINCT task_id FROM task_dependencies WHERE task_id IN (SELECT id FROM tasks WHERE household_id = ?)",
+    )
+    .bind(&household)
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .collect();
+
+    let mut claims_by_task = task_claims::list_open_claims(pool, household_id, range_start).await?;
+
+    let membership = household_service::get_membership(pool, household_id, user_id)
+        .await
+        .ok()
+        .flatten();
+
+    let mut result = Vec::with_capacity(tasks.len());
+    for (task, (next_due_date, period_start, period_end)) in tasks.into_iter().zip(schedules) {
+        let key = task.id.to_string();
+        let individual = task.has_individual_completions();
+
+        let completions_today = counts_by_task
+            .get(&key)
+            .map(|days| {
+                days.iter()
+                    .filter(|(day, own, _)| *day >= period_start && *day <= period_end && (!individual || *own))
+                    .map(|(_, _, count)| count)
+                    .sum::<i64>()
+            })
+            .unwrap_or(0) as i32;
+        let (total_completions, last_completion) = match totals.get(&key) {
+            Some((total, own, last)) => (if individual { *own } else { *total }, Some(*last)),
+            None => (0, None),
+        };
+        let periods = if individual { &own_periods_by_task } else { &periods_by_task }
+            .get(&key)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        // Mirrors `calculate_streak`
+        let current_streak = if task.recurrence_type == shared::RecurrenceType::OneTime {
+            if task.target_count == 0 { 0 } else { total_completions as i32 }
+        } else {
+            period_results::streak_from_periods(periods, membership.as_ref())
+        };
+
+        let blocked_by = if dependent_tasks.contains(&key) {
+            task_dependencies::unmet_prerequisites(pool, &task.id, next_due_date.unwrap_or(today))
+                .await?
+                .into_iter()
+                .map(|t| t.title)
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let claimed_by = claims_by_task
+            .remove(&key)
+            .and_then(|claims| claims.into_iter().find(|claim| claim.period_start == period_start));
+        let is_user_assigned =
+            task.is_assigned_to(user_id) && claimed_by.as_ref().is_none_or(|claim| claim.user_id == *user_id);
+
+        result.push(TaskWithStatus {
+            task,
+            completions_today,
+            current_streak,
+            last_completion,
+            next_due_date,
+            is_user_assigned,
+            recent_periods: period_results::recent_period_displays(&periods[..periods.len().min(RECENT_PERIODS)]),
+            blocked_by,
+            claimed_by,
+        });
+    }
+
+    Ok(result)
+}
+
 /// Get full task details including statistics for the detail view
 pub async fn get_task_with_details(
     pool: &SqlitePool,
@@ -196,16 +540,8 @@ pub async fn get_task_with_details(
 
     let today = Utc::now().date_naive();
 
-    // Get all completions for this task (ordered by due_date)
-    let completions: Vec<TaskCompletionRow> = sqlx::query_as(
-        "SELECT * FROM task_completions WHERE task_id = ? ORDER BY due_date ASC",
-    )
-    .bind(task_id.to_string())
-    .fetch_all(pool)
-    .await?;
-
     // Calculate statistics
-    let statistics = calculate_task_statistics(pool, &task, &completions, today, user_id).await?;
+    let statistics = calculate_task_statistics(pool, &task, today, user_id).await?;
 
     // Get assigned user if any
     let assigned_user = if let Some(assigned_id) = task.assigned_user_id {
@@ -227,6 +563,9 @@ pub async fn get_task_with_details(
     let linked_punishments = task_consequences::get_task_punishments(pool, task_id)
         .await
         .unwrap_or_default();
+    let prerequisites = task_dependencies::get_prerequisites(pool, task_id)
+        .await
+        .unwrap_or_default();
 
     // Get recent periods for habit tracker display (last 15)
     let recent_periods = period_results::get_recent_periods(pool, task_id, 15)
@@ -240,125 +579,84 @@ pub async fn get_task_with_details(
         linked_rewards,
         linked_punishments,
         recent_periods,
+        prerequisites,
     }))
 }
 
 /// Calculate task statistics for the detail view
-/// Statistics are based on explicitly recorded period results only.
-/// If no period results exist, statistics will show 0/0.
+/// Statistics are based on explicitly recorded period results only, summed from
+/// the daily event rollups. If no period results exist, statistics will show 0/0.
 async fn calculate_task_statistics(
     pool: &SqlitePool,
     task: &Task,
-    completions: &[TaskCompletionRow],
     today: NaiveDate,
     user_id: &Uuid,
 ) -> Result<TaskStatistics, TaskError> {
     // Get last completion
-    let last_completed = completions.last().map(|c| c.completed_at);
+    let last_completed: Option<DateTime<Utc>> =
+        sqlx::query_scalar("SELECT MAX(completed_at) FROM task_completions WHERE task_id = ?")
+            .bind(task.id.to_string())
+            .fetch_one(pool)
+            .await?;
 
     // Calculate next due date
-    let next_due = scheduler::get_next_due_date(task, today);
+    let week_start_day = household_settings::week_start_day(pool, &task.household_id).await?;
+    let next_due = scheduler::get_next_due_date(task, today, week_start_day);
 
     // Get current streak
     let current_streak = calculate_streak(pool, task, user_id).await?;
 
+    // Total completions
+    let total_completions = events::task_totals(pool, &task.id, None, None).await?.completions;
+
     // Calculate best streak from period results
     let best_streak = if task.recurrence_type == shared::RecurrenceType::OneTime {
         // For one-time tasks, best streak is total completions
-        completions.len() as i32
+        total_completions as i32
     } else {
         period_results::calculate_best_streak(pool, &task.id)
             .await
             .unwrap_or(0)
     };
 
-    // Total completions
-    let total_completions = completions.len() as i64;
-
-    // Get period result counts from task_period_results table
-    // Statistics are now based only on explicitly recorded period results
-    let counts_week = period_results::count_period_results(
-        pool,
-        &task.id,
-        get_week_start(today),
-        today,
-    )
-    .await
-    .unwrap_or(period_results::PeriodCounts {
-        completed: 0,
-        failed: 0,
-        skipped: 0,
-    });
-
-    let counts_month = period_results::count_period_results(
-        pool,
-        &task.id,
-        get_month_start(today),
-        today,
-    )
-    .await
-    .unwrap_or(period_results::PeriodCounts {
-        completed: 0,
-        failed: 0,
-        skipped: 0,
-    });
+    let counts_week = events::task_totals(pool, &task.id, Some(get_week_start(today)), Some(today)).await?;
+    let counts_month = events::task_totals(pool, &task.id, Some(get_month_start(today)), Some(today)).await?;
+    let counts_all_time = events::task_totals(pool, &task.id, Some(task.created_at.date_naive()), Some(today)).await?;
 
-    let counts_all_time = period_results::count_period_results(
-        pool,
-        &task.id,
-        task.created_at.date_naive(),
-        today,
-    )
-    .await
-    .unwrap_or(period_results::PeriodCounts {
-        completed: 0,
-        failed: 0,
-        skipped: 0,
-    });
+    // Time of completed focus sessions
+    let week_start = get_week_start(today).and_time(chrono::NaiveTime::MIN).and_utc();
+    let focus_minutes_week = focus_sessions::focus_minutes(pool, &task.id, Some(week_start)).await?;
+    let focus_minutes_all_time = focus_sessions::focus_minutes(pool, &task.id, None).await?;
 
     // Calculate totals (completed + failed, excluding skipped)
-    let total_week = counts_week.completed + counts_week.failed;
-    let total_month = counts_month.completed + counts_month.failed;
-    let total_all_time = counts_all_time.completed + counts_all_time.failed;
+    let total_week = (counts_week.periods_completed + counts_week.periods_failed) as i32;
+    let total_month = (counts_month.periods_completed + counts_month.periods_failed) as i32;
+    let total_all_time = (counts_all_time.periods_completed + counts_all_time.periods_failed) as i32;
 
     // Calculate completion rates: completed / (completed + failed) * 100
     // Returns None if no period results exist (0/0)
-    let rate_week = if total_week > 0 {
-        Some((counts_week.completed as f64 / total_week as f64) * 100.0)
-    } else {
-        None
-    };
-
-    let rate_month = if total_month > 0 {
-        Some((counts_month.completed as f64 / total_month as f64) * 100.0)
-    } else {
-        None
-    };
-
-    let rate_all_time = if total_all_time > 0 {
-        Some((counts_all_time.completed as f64 / total_all_time as f64) * 100.0)
-    } else {
-        None
-    };
+    let rate = |completed: i64, total: i32| (total > 0).then(|| (completed as f64 / total as f64) * 100.0);
 
     Ok(TaskStatistics {
-        completion_rate_week: rate_week,
-        completion_rate_month: rate_month,
-        completion_rate_all_time: rate_all_time,
-        periods_completed_week: counts_week.completed,
+        completion_rate_week: rate(counts_week.periods_completed, total_week),
+        completion_rate_month: rate(counts_month.periods_completed, total_month),
+        completion_rate_all_time: rate(counts_all_time.periods_completed, total_all_time),
+        periods_completed_week: counts_week.periods_completed as i32,
         periods_total_week: total_week,
-        periods_completed_month: counts_month.completed,
+        periods_completed_month: counts_month.periods_completed as i32,
         periods_total_month: total_month,
-        periods_completed_all_time: counts_all_time.completed,
+        periods_completed_all_time: counts_all_time.periods_completed as i32,
         periods_total_all_time: total_all_time,
-        periods_skipped_week: counts_week.skipped,
-        periods_skipped_month: counts_month.skipped,
-        periods_skipped_all_time: counts_all_time.skipped,
+        periods_skipped_week: counts_week.periods_skipped as i32,
+        periods_skipped_month: counts_month.periods_skipped as i32,
+        periods_skipped_all_time: counts_all_time.periods_skipped as i32,
         current_streak,
         best_streak,
         total_completions,
         last_completed,
         next_due,
+        focus_minutes_week,
+        focus_minutes_all_time,
     })
 }
 
@@ -379,7 +677,7 @@ pub async fn list_tasks(pool: &SqlitePool, household_id: &Uuid) -> Result<Vec<Ta
         SELECT t.*, tc.name as category_name
         FROM tasks t
         LEFT JOIN task_categories tc ON t.category_id = tc.id
-        WHERE t.household_id = ? AND t.archived = 0
+        WHERE t.household_id = ? AND t.archived = 0 AND t.deleted_at IS NULL
         AND (t.suggestion IS NULL OR t.suggestion = 'approved')
         ORDER BY t.title COLLATE NOCASE ASC
         "#,
@@ -388,7 +686,77 @@ pub async fn list_tasks(pool: &SqlitePool, household_id: &Uuid) -> Result<Vec<Ta
     .fetch_all(pool)
     .await?;
 
-    Ok(tasks.into_iter().map(|t| t.to_shared()).collect())
+    let mut tasks: Vec<Task> = tasks.into_iter().map(|t| t.to_shared()).collect();
+    load_assignees(pool, &mut tasks).await?;
+    task_tags::load_tags(pool, &mut tasks).await?;
+    Ok(tasks)
+}
+
+/// One page of `list_tasks`; `limit: None` returns everything from `offset` on.
+/// Only tasks matching `filter` are counted and returned.
+pub async fn list_tasks_page(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    filter: &TaskFilter,
+    limit: Option<i64>,
+    offset: i64,
+) -> Result<Paginated<Task>, TaskError> {
+    if !filter.is_empty() {
+        let week_start_day = household_settings::week_start_day(pool, household_id).await?;
+        let tasks = task_filters::apply(filter, list_tasks(pool, household_id).await?, week_start_day);
+        let total = tasks.len() as i64;
+        let items = tasks
+            .into_iter()
+            .skip(offset.max(0) as usize)
+            .take(limit.map(|l| l.max(0) as usize).unwrap_or(usize::MAX))
+            .collect();
+        return Ok(Paginated {
+            items,
+            total,
+            limit: limit.unwrap_or(total),
+            offset,
+        });
+    }
+
+    let total: i64 = sqlx::query_scalar(
+        r#"
+        SELECT COUNT(*) FROM tasks
+        WHERE household_id = ? AND archived = 0 AND deleted_at IS NULL
+        AND (suggestion IS NULL OR suggestion = 'approved')
+        "#,
+    )
+    .bind(household_id.to_string())
+    .fetch_one(pool)
+    .await?;
+
+    // SQLite treats a negative LIMIT as "no limit"
+    let tasks: Vec<TaskRowWithCategory> = sqlx::query_as(
+        r#"
+        SELECT t.*, tc.name as category_name
+        FROM tasks t
+        LEFT JOIN task_categories tc ON t.category_id = tc.id
+        WHERE t.household_id = ? AND t.archived = 0 AND t.deleted_at IS NULL
+        AND (t.suggestion IS NULL OR t.suggestion = 'approved')
+        ORDER BY t.title COLLATE NOCASE ASC
+        LIMIT ? OFFSET ?
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(limit.unwrap_or(-1))
+    .bind(offset)
+    .fetch_all(pool)
+    .await?;
+
+    let mut items: Vec<Task> = tasks.into_iter().map(|t| t.to_shared()).collect();
+    load_assignees(pool, &mut items).await?;
+    task_tags::load_tags(pool, &mut items).await?;
+
+    Ok(Paginated {
+        items,
+        total,
+        limit: limit.unwrap_or(total),
+        offset,
+    })
 }
 
 pub async fn list_archived_tasks(pool: &SqlitePool, household_id: &Uuid) -> Result<Vec<Task>, TaskError> {
@@ -397,7 +765,7 @@ pub async fn list_archived_tasks(pool: &SqlitePool, household_id: &Uuid) -> Resu
         SELECT t.*, tc.name as category_name
         FROM tasks t
         LEFT JOIN task_categories tc ON t.category_id = tc.id
-        WHERE t.household_id = ? AND t.archived = 1
+        WHERE t.household_id = ? AND t.archived = 1 AND t.deleted_at IS NULL
         ORDER BY t.title COLLATE NOCASE ASC
         "#,
     )
@@ -405,7 +773,10 @@ pub async fn list_archived_tasks(pool: &SqlitePool, household_id: &Uuid) -> Resu
     .fetch_all(pool)
     .await?;
 
-    Ok(tasks.into_iter().map(|t| t.to_shared()).collect())
+    let mut tasks: Vec<Task> = tasks.into_iter().map(|t| t.to_shared()).collect();
+    load_assignees(pool, &mut tasks).await?;
+    task_tags::load_tags(pool, &mut tasks).await?;
+    Ok(tasks)
 }
 
 pub async fn list_user_assigned_tasks(
@@ -418,30 +789,52 @@ pub async fn list_user_assigned_tasks(
         SELECT t.*, tc.name as category_name
         FROM tasks t
         LEFT JOIN task_categories tc ON t.category_id = tc.id
-        WHERE t.household_id = ? AND t.assigned_user_id = ? AND t.archived = 0
+        WHERE t.household_id = ? AND t.archived = 0 AND t.deleted_at IS NULL
+        AND (t.assigned_user_id = ? OR t.id IN (SELECT task_id FROM task_assignees WHERE user_id = ?))
         AND (t.suggestion IS NULL OR t.suggestion = 'approved')
         ORDER BY t.title COLLATE NOCASE ASC
         "#,
     )
     .bind(household_id.to_string())
     .bind(user_id.to_string())
+    .bind(user_id.to_string())
     .fetch_all(pool)
     .await?;
 
-    Ok(tasks.into_iter().map(|t| t.to_shared()).collect())
+    let mut tasks: Vec<Task> = tasks.into_iter().map(|t| t.to_shared()).collect();
+    load_assignees(pool, &mut tasks).await?;
+    task_tags::load_tags(pool, &mut tasks).await?;
+    Ok(tasks)
 }
 
+/// Conflict error carrying the task as it is currently stored
+async fn version_conflict(pool: &SqlitePool, task_id: &Uuid) -> TaskError {
+    match get_task(pool, task_id).await {
+        Ok(Some(task)) => TaskError::VersionConflict(Box::new(task)),
+        Ok(None) => TaskError::NotFound,
+        Err(e) => e,
+    }
+}
+
+#[tracing::instrument(level = "debug", skip_all, fields(%task_id))]
 pub async fn update_task(
     pool: &SqlitePool,
     task_id: &Uuid,
     request: &UpdateTaskRequest,
 ) -> Result<Task, TaskError> {
-    let mut task: TaskRow = sqlx::query_as("SELECT * FROM tasks WHERE id = ?")
+    let mut task: TaskRow = sqlx::query_as("SELECT * FROM tasks WHERE id = ? AND deleted_at IS NULL")
         .bind(task_id.to_string())
         .fetch_optional(pool)
         .await?
         .ok_or(TaskError::NotFound)?;
 
+    if request.version.is_some_and(|version| version != task.version) {
+        return Err(version_conflict(pool, task_id).await);
+    }
+    let expected_version = task.version;
+
+    let original_schedule = (task.recurrence_type.clone(), task.recurrence_value.clone());
+
     if let Some(ref title) = request.title {
         task.title = title.clone();
     }
@@ -454,8 +847,9 @@ pub async fn update_task(
     if let Some(ref recurrence_value) = request.recurrence_value {
         task.recurrence_value = Some(serde_json::to_string(recurrence_value).unwrap_or_default());
     }
-    if let Some(assigned_user_id) = request.assigned_user_id {
-        task.assigned_user_id = Some(assigned_user_id.to_string());
+    let assignees = requested_assignees(request.assigned_user_id, request.assignee_ids.as_ref());
+    if let Some(ref assignees) = assignees {
+        task.assigned_user_id = assignees.first().map(|u| u.to_string());
     }
     if let Some(target_count) = request.target_count {
         task.target_count = target_count;
@@ -469,6 +863,16 @@ pub async fn update_task(
     if let Some(requires_review) = request.requires_review {
         task.requires_review = requires_review;
     }
+    if let Some(reviewer_type) = request.reviewer_type {
+        task.reviewer_type = reviewer_type.as_str().to_string();
+    }
+    if let Some(reviewer_id) = request.reviewer_id {
+        task.reviewer_id = reviewer_id.map(|id| id.to_string());
+    }
+    // Only a Member reviewer type names a reviewer
+    if task.reviewer_type != ReviewerType::Member.as_str() {
+        task.reviewer_id = None;
+    }
     if request.points_reward.is_some() {
         task.points_reward = request.points_reward;
     }
@@ -478,6 +882,21 @@ pub async fn update_task(
     if request.due_time.is_some() {
         task.due_time = request.due_time.clone();
     }
+    if let Some(effort_minutes) = request.effort_minutes {
+        task.effort_minutes = effort_minutes;
+    }
+    if let Some(active_from) = &request.active_from {
+        task.active_from = active_from.clone();
+    }
+    if let Some(active_until) = &request.active_until {
+        task.active_until = active_until.clone();
+    }
+    if let Some(ends_on) = request.ends_on {
+        task.ends_on = ends_on;
+    }
+    if let Some(max_occurrences) = request.max_occurrences {
+        task.max_occurrences = max_occurrences;
+    }
     if let Some(habit_type) = request.habit_type {
         task.habit_type = habit_type.as_str().to_string();
     }
@@ -491,13 +910,20 @@ pub async fn update_task(
         task.paused = paused;
     }
 
+    // A postponed occurrence belongs to the old schedule
+    if (task.recurrence_type.clone(), task.recurrence_value.clone()) != original_schedule {
+        task.postponed_from = None;
+        task.postponed_to = None;
+    }
+
     let now = Utc::now();
     task.updated_at = now;
+    task.version += 1;
 
-    sqlx::query(
+    let result = sqlx::query(
         r#"
-        UPDATE tasks SET title = ?, description = ?, recurrence_type = ?, recurrence_value = ?, assigned_user_id = ?, target_count = ?, time_period = ?, allow_exceed_target = ?, requires_review = ?, points_reward = ?, points_penalty = ?, due_time = ?, habit_type = ?, category_id = ?, archived = ?, paused = ?, updated_at = ?
-        WHERE id = ?
+        UPDATE tasks SET title = ?, description = ?, recurrence_type = ?, recurrence_value = ?, assigned_user_id = ?, target_count = ?, time_period = ?, allow_exceed_target = ?, requires_review = ?, reviewer_type = ?, reviewer_id = ?, points_reward = ?, points_penalty = ?, due_time = ?, effort_minutes = ?, active_from = ?, active_until = ?, ends_on = ?, max_occurrences = ?, habit_type = ?, category_id = ?, archived = ?, paused = ?, postponed_from = ?, postponed_to = ?, updated_at = ?, version = version + 1
+        WHERE id = ? AND version = ?
         "#,
     )
     .bind(&task.title)
@@ -509,25 +935,50 @@ pub async fn update_task(
     .bind(&task.time_period)
     .bind(task.allow_exceed_target)
     .bind(task.requires_review)
+    .bind(&task.reviewer_type)
+    .bind(&task.reviewer_id)
     .bind(task.points_reward)
     .bind(task.points_penalty)
     .bind(&task.due_time)
+    .bind(task.effort_minutes)
+    .bind(&task.active_from)
+    .bind(&task.active_until)
+    .bind(task.ends_on)
+    .bind(task.max_occurrences)
     .bind(&task.habit_type)
     .bind(&task.category_id)
     .bind(task.archived)
     .bind(task.paused)
+    .bind(task.postponed_from)
+    .bind(task.postponed_to)
     .bind(now)
     .bind(task_id.to_string())
+    .bind(expected_version)
     .execute(pool)
     .await?;
 
-    Ok(task.to_shared())
+    // Someone else saved between our read and write
+    if result.rows_affected() == 0 {
+        return Err(version_conflict(pool, task_id).await);
+    }
+
+    if let Some(ref assignees) = assignees {
+        set_task_assignees(pool, task_id, assignees).await?;
+    }
+
+    let mut task = task.to_shared();
+    if let Some(ref tag_ids) = request.tag_ids {
+        task_tags::set_task_tags(pool, task_id, &task.household_id, tag_ids).await?;
+    }
+    load_assignees(pool, std::slice::from_mut(&mut task)).await?;
+    task_tags::load_tags(pool, std::slice::from_mut(&mut task)).await?;
+    Ok(task)
 }
 
 pub async fn archive_task(pool: &SqlitePool, task_id: &Uuid) -> Result<Task, TaskError> {
     let now = Utc::now();
     let result = sqlx::query(
-        "UPDATE tasks SET archived = 1, updated_at = ? WHERE id = ?",
+        "UPDATE tasks SET archived = 1, updated_at = ?, version = version + 1 WHERE id = ?",
     )
     .bind(now)
     .bind(task_id.to_string())
@@ -544,7 +995,7 @@ pub async fn archive_task(pool: &SqlitePool, task_id: &Uuid) -> Result<Task, Tas
 pub async fn unarchive_task(pool: &SqlitePool, task_id: &Uuid) -> Result<Task, TaskError> {
     let now = Utc::now();
     let result = sqlx::query(
-        "UPDATE tasks SET archived = 0, updated_at = ? WHERE id = ?",
+        "UPDATE tasks SET archived = 0, updated_at = ?, version = version + 1 WHERE id = ?",
     )
     .bind(now)
     .bind(task_id.to_string())
@@ -561,7 +1012,7 @@ pub async fn unarchive_task(pool: &SqlitePool, task_id: &Uuid) -> Result<Task, T
 pub async fn pause_task(pool: &SqlitePool, task_id: &Uuid) -> Result<Task, TaskError> {
     let now = Utc::now();
     let result = sqlx::query(
-        "UPDATE tasks SET paused = 1, updated_at = ? WHERE id = ?",
+        "UPDATE tasks SET paused = 1, updated_at = ?, version = version + 1 WHERE id = ?",
     )
     .bind(now)
     .bind(task_id.to_string())
@@ -575,10 +1026,193 @@ pub async fn pause_task(pool: &SqlitePool, task_id: &Uuid) -> Result<Task, TaskE
     get_task(pool, task_id).await?.ok_or(TaskError::NotFound)
 }
 
+/// Skip the task's current period, recording it as skipped so the background job
+/// does not mark it failed. The period is attributed to the user who skipped it.
+pub async fn skip_current_period(
+    pool: &SqlitePool,
+    task_id: &Uuid,
+    user_id: &Uuid,
+    reason: Option<&str>,
+) -> Result<TaskPeriodResult, TaskError> {
+    let task = get_task(pool, task_id).await?.ok_or(TaskError::NotFound)?;
+
+    if task.recurrence_type == shared::RecurrenceType::OneTime {
+        return Err(TaskError::NotSkippable);
+    }
+
+    // Same period resolution as completing: the period of the next due date
+    let today = Utc::now().date_naive();
+    let week_start_day = household_settings::week_start_day(pool, &task.household_id).await?;
+    let Some(period_date) = scheduler::get_next_due_date(&task, today, week_start_day) else {
+        return Err(TaskError::NotDueToday);
+    };
+    let (period_start, period_end) = scheduler::get_period_bounds(&task, period_date);
+
+    let existing = period_results::get_period_result(pool, task_id, period_start)
+        .await
+        .map_err(period_error)?;
+    if existing.is_some_and(|r| r.status == PeriodStatus::Completed) {
+        return Err(TaskError::AlreadyCompleted);
+    }
+
+    let completions_count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM task_completions WHERE task_id = ? AND due_date >= ? AND due_date <= ?",
+    )
+    .bind(task_id.to_string())
+    .bind(period_start)
+    .bind(period_end)
+    .fetch_one(pool)
+    .await?;
+
+    // Assignees who already finished their own part keep their completed period
+    if task.has_individual_completions() {
+        let counts = completions_per_user(pool, task_id, Some((period_start, period_end))).await?;
+        for assignee in task.assignees() {
+            let own = counts.get(&assignee).copied().unwrap_or(0);
+            if own < task.target_count as i64 {
+                period_results::finalize_assignee_period(
+                    pool,
+                    task_id,
+                    &assignee,
+                    period_start,
+                    period_end,
+                    PeriodStatus::Skipped,
+                    own as i32,
+                    task.target_count,
+                )
+                .await
+                .map_err(period_error)?;
+            }
+        }
+    }
+
+    let reason = reason.map(str::trim).filter(|r| !r.is_empty());
+    period_results::finalize_period(
+        pool,
+        task_id,
+        period_start,
+        period_end,
+        PeriodStatus::Skipped,
+        completions_count as i32,
+        task.target_count,
+        &user_id.to_string(),
+        reason,
+    )
+    .await
+    .map_err(period_error)
+}
+
+/// Correct the recorded outcome of the past period containing `date`, e.g. when the
+/// background job missed it. Assignees of tasks with individual completions get the
+/// same outcome. Points and consequences are left alone.
+pub async fn override_period(
+    pool: &SqlitePool,
+    task_id: &Uuid,
+    date: NaiveDate,
+    status: PeriodStatus,
+    user_id: &Uuid,
+    reason: &str,
+    today: NaiveDate,
+) -> Result<TaskPeriodResult, TaskError> {
+    let task = get_task(pool, task_id).await?.ok_or(TaskError::NotFound)?;
+
+    let (period_start, period_end) = scheduler::get_period_bounds(&task, date);
+    if period_end >= today {
+        return Err(TaskError::PeriodNotEnded);
+    }
+
+    if task.has_individual_completions() {
+        let counts = completions_per_user(pool, task_id, Some((period_start, period_end))).await?;
+        for assignee in task.assignees() {
+            period_results::finalize_assignee_period(
+                pool,
+                task_id,
+                &assignee,
+                period_start,
+                period_end,
+                status,
+                counts.get(&assignee).copied().unwrap_or(0) as i32,
+                task.target_count,
+            )
+            .await
+            .map_err(period_error)?;
+        }
+    }
+
+    let completions_count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM task_completions WHERE task_id = ? AND due_date >= ? AND due_date <= ?",
+    )
+    .bind(task_id.to_string())
+    .bind(period_start)
+    .bind(period_end)
+    .fetch_one(pool)
+    .await?;
+
+    period_results::finalize_period(
+        pool,
+        task_id,
+        period_start,
+        period_end,
+        status,
+        completions_count as i32,
+        task.target_count,
+        &user_id.to_string(),
+        Some(reason.trim()),
+    )
+    .await
+    .map_err(period_error)
+}
+
+/// Move the task's current occurrence to `target_date` without touching the
+/// recurrence. Postponing an already postponed occurrence moves it again.
+pub async fn postpone_task(
+    pool: &SqlitePool,
+    task_id: &Uuid,
+    target_date: NaiveDate,
+    today: NaiveDate,
+) -> Result<Task, TaskError> {
+    let task = get_task(pool, task_id).await?.ok_or(TaskError::NotFound)?;
+
+    if task.recurrence_type == shared::RecurrenceType::OneTime {
+        return Err(TaskError::NotPostponable);
+    }
+
+    let week_start_day = household_settings::week_start_day(pool, &task.household_id).await?;
+    let Some(current) = scheduler::get_next_due_date(&task, today, week_start_day) else {
+        return Err(TaskError::NotDueToday);
+    };
+    if target_date <= current {
+        return Err(TaskError::InvalidPostponeDate);
+    }
+
+    // Keep the original date when moving an already postponed occurrence
+    let original = match (task.postponed_from, task.postponed_to) {
+        (Some(from), Some(to)) if to == current => from,
+        _ => current,
+    };
+
+    sqlx::query("UPDATE tasks SET postponed_from = ?, postponed_to = ?, updated_at = ?, version = version + 1 WHERE id = ?")
+        .bind(original)
+        .bind(target_date)
+        .bind(Utc::now())
+        .bind(task_id.to_string())
+        .execute(pool)
+        .await?;
+
+    get_task(pool, task_id).await?.ok_or(TaskError::NotFound)
+}
+
+fn period_error(error: period_results::PeriodResultError) -> TaskError {
+    match error {
+        period_results::PeriodResultError::Database(e) => TaskError::DatabaseError(e),
+        period_results::PeriodResultError::NotFound => TaskError::NotFound,
+    }
+}
+
 pub async fn unpause_task(pool: &SqlitePool, task_id: &Uuid) -> Result<Task, TaskError> {
     let now = Utc::now();
     let result = sqlx::query(
-        "UPDATE tasks SET paused = 0, updated_at = ? WHERE id = ?",
+        "UPDATE tasks SET paused = 0, updated_at = ?, version = version + 1 WHERE id = ?",
     )
     .bind(now)
     .bind(task_id.to_string())
@@ -592,7 +1226,25 @@ pub async fn unpause_task(pool: &SqlitePool, task_id: &Uuid) -> Result<Task, Tas
     get_task(pool, task_id).await?.ok_or(TaskError::NotFound)
 }
 
+/// Move a task to the household trash. Completions and point history are kept
+/// so the task can be restored with its history.
+#[tracing::instrument(level = "debug", skip_all, fields(%task_id))]
 pub async fn delete_task(pool: &SqlitePool, task_id: &Uuid) -> Result<(), TaskError> {
+    let now = Utc::now();
+    let result = sqlx::query("UPDATE tasks SET deleted_at = ?, updated_at = ? WHERE id = ? AND deleted_at IS NULL")
+        .bind(now)
+        .bind(now)
+        .bind(task_id.to_string())
+        .execute(pool)
+        .await?;
+    if result.rows_affected() == 0 {
+        return Err(TaskError::NotFound);
+    }
+    Ok(())
+}
+
+/// Permanently delete a task and its completion history
+pub async fn purge_task(pool: &SqlitePool, task_id: &Uuid) -> Result<(), TaskError> {
     // Delete related data first
     sqlx::query("DELETE FROM task_completions WHERE task_id = ?")
         .bind(task_id.to_string())
@@ -609,12 +1261,20 @@ pub async fn delete_task(pool: &SqlitePool, task_id: &Uuid) -> Result<(), TaskEr
         .execute(pool)
         .await?;
 
+    sqlx::query("DELETE FROM task_dependencies WHERE task_id = ? OR prerequisite_id = ?")
+        .bind(task_id.to_string())
+        .bind(task_id.to_string())
+        .execute(pool)
+        .await?;
+
     // Update point conditions to remove task reference
     sqlx::query("UPDATE point_conditions SET task_id = NULL WHERE task_id = ?")
         .bind(task_id.to_string())
         .execute(pool)
         .await?;
 
+    events::delete_for_task(pool, task_id).await?;
+
     sqlx::query("DELETE FROM tasks WHERE id = ?")
         .bind(task_id.to_string())
         .execute(pool)
@@ -623,6 +1283,7 @@ pub async fn delete_task(pool: &SqlitePool, task_id: &Uuid) -> Result<(), TaskEr
     Ok(())
 }
 
+#[tracing::instrument(level = "debug", skip_all, fields(%task_id, %user_id))]
 pub async fn complete_task(
     pool: &SqlitePool,
     task_id: &Uuid,
@@ -632,13 +1293,12 @@ pub async fn complete_task(
     let task = get_task(pool, task_id).await?.ok_or(TaskError::NotFound)?;
 
     // Check if user is allowed to complete this task based on assignment
-    if let Some(assigned_id) = task.assigned_user_id {
-        if assigned_id != *user_id {
-            return Err(TaskError::NotAssigned);
-        }
+    if !task.is_assigned_to(user_id) {
+        return Err(TaskError::NotAssigned);
     }
 
     let today = Utc::now().date_naive();
+    let week_start_day = household_settings::week_start_day(pool, household_id).await?;
 
     // Special handling for RecurrenceType::OneTime (free-form and one-time tasks)
     if task.recurrence_type == shared::RecurrenceType::OneTime {
@@ -660,7 +1320,7 @@ pub async fn complete_task(
     } else {
         // Scheduled tasks: allow completion within the current period
         // Use next_due_date for period calculation to allow "early" completions
-        let next_due = scheduler::get_next_due_date(&task, today);
+        let next_due = scheduler::get_next_due_date(&task, today, week_start_day);
 
         // If there's no next due date (e.g., Custom task with all dates passed), don't allow completion
         if next_due.is_none() && task.recurrence_type == shared::RecurrenceType::Custom {
@@ -696,9 +1356,24 @@ pub async fn complete_task(
     let completion_due_date = if task.recurrence_type == shared::RecurrenceType::OneTime {
         today
     } else {
-        scheduler::get_next_due_date(&task, today).unwrap_or(today)
+        scheduler::get_next_due_date(&task, today, week_start_day).unwrap_or(today)
     };
 
+    // Prerequisites must be done first in the same period (e.g. vacuum before mop)
+    let unmet = task_dependencies::unmet_prerequisites(pool, task_id, completion_due_date).await?;
+    if !unmet.is_empty() {
+        let titles: Vec<String> = unmet.into_iter().map(|t| t.title).collect();
+        return Err(TaskError::PrerequisitesNotMet(titles.join(", ")));
+    }
+
+    // A task claimed by another member is theirs for this period
+    let (claim_period_start, _) = scheduler::get_period_bounds(&task, completion_due_date);
+    if let Some(claim) = task_claims::get_claim(pool, task_id, claim_period_start).await? {
+        if claim.user_id != *user_id {
+            return Err(TaskError::ClaimedByOther(claim.username));
+        }
+    }
+
     // Determine status based on task's requires_review setting
     let status = if task.requires_review {
         CompletionStatus::Pending
@@ -720,6 +1395,9 @@ pub async fn complete_task(
     .bind(status.as_str())
     .execute(pool)
     .await?;
+    events::record(pool, task_id, Some(user_id), EventKind::Completion, completion_due_date, 1).await?;
+    plants::record_care(pool, task_id, now).await?;
+    pets::record_care(pool, task_id, user_id, now).await?;
 
     // Apply consequences based on habit type
     let streak = calculate_streak(pool, &task, user_id).await?;
@@ -746,7 +1424,9 @@ pub async fn complete_task(
     }
 
     // Check if period target is now met and finalize as completed
-    if task.target_count > 0 {
+    if task.target_count > 0 && task.has_individual_completions() {
+        finalize_individual_progress(pool, &task, user_id, completion_due_date).await?;
+    } else if task.target_count > 0 {
         if task.recurrence_type == shared::RecurrenceType::OneTime {
             // For OneTime tasks, count all completions regardless of date
             let total_completions: i64 = if task.assigned_user_id.is_some() {
@@ -825,16 +1505,101 @@ pub async fn complete_task(
         }
     }
 
-    Ok(TaskCompletion {
+    let completion = TaskCompletion {
         id,
         task_id: *task_id,
         user_id: *user_id,
         completed_at: now,
         due_date: completion_due_date,
         status,
-    })
+    };
+
+    webhooks::dispatch(
+        pool,
+        household_id,
+        WebhookEvent::TaskCompleted,
+        serde_json::json!({ "task_title": task.title, "completion": completion }),
+    )
+    .await;
+
+    Ok(completion)
+}
+
+/// Completions per user, optionally limited to a due date range
+async fn completions_per_user(
+    pool: &SqlitePool,
+    task_id: &Uuid,
+    range: Option<(NaiveDate, NaiveDate)>,
+) -> Result<HashMap<Uuid, i64>, TaskError> {
+    let (from, to) = range.unwrap_or((NaiveDate::MIN, NaiveDate::MAX));
+    let rows: Vec<(String, i64)> = sqlx::query_as(
+        "SELECT user_id, COUNT(*) FROM task_completions WHERE task_id = ? AND due_date >= ? AND due_date <= ? GROUP BY user_id",
+    )
+    .bind(task_id.to_string())
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|(user_id, count)| Uuid::parse_str(&user_id).ok().map(|id| (id, count)))
+        .collect())
+}
+
+/// After a completion on a task where every assignee completes separately: record the
+/// user's period as completed once they reach the target, and the task's period once
+/// all assignees have.
+async fn finalize_individual_progress(
+    pool: &SqlitePool,
+    task: &Task,
+    user_id: &Uuid,
+    completion_due_date: NaiveDate,
+) -> Result<(), TaskError> {
+    // One-time tasks count all completions and use the completion date as their period
+    let (period_start, period_end, range) = if task.recurrence_type == shared::RecurrenceType::OneTime {
+        (completion_due_date, completion_due_date, None)
+    } else {
+        let (start, end) = scheduler::get_period_bounds(task, completion_due_date);
+        (start, end, Some((start, end)))
+    };
+    let counts = completions_per_user(pool, &task.id, range).await?;
+    let target = task.target_count as i64;
+
+    let own = counts.get(user_id).copied().unwrap_or(0);
+    if own >= target {
+        let _ = period_results::finalize_assignee_period(
+            pool,
+            &task.id,
+            user_id,
+            period_start,
+            period_end,
+            PeriodStatus::Completed,
+            own as i32,
+            task.target_count,
+        )
+        .await;
+    }
+
+    if task.assignees().iter().all(|assignee| counts.get(assignee).copied().unwrap_or(0) >= target) {
+        let _ = period_results::finalize_period(
+            pool,
+            &task.id,
+            period_start,
+            period_end,
+            PeriodStatus::Completed,
+            counts.values().sum::<i64>() as i32,
+            task.target_count,
+            "system",
+            None,
+        )
+        .await;
+    }
+
+    Ok(())
 }
 
+#[tracing::instrument(level = "debug", skip_all, fields(%task_id, %user_id))]
 pub async fn uncomplete_task(
     pool: &SqlitePool,
     task_id: &Uuid,
@@ -843,17 +1608,17 @@ pub async fn uncomplete_task(
     let task = get_task(pool, task_id).await?.ok_or(TaskError::NotFound)?;
 
     // Check if user is allowed to uncomplete this task based on assignment
-    if let Some(assigned_id) = task.assigned_user_id {
-        if assigned_id != *user_id {
-            return Err(TaskError::NotAssigned);
-        }
+    if !task.is_assigned_to(user_id) {
+        return Err(TaskError::NotAssigned);
     }
+    let individual = task.has_individual_completions();
 
     let today = Utc::now().date_naive();
+    let week_start_day = household_settings::week_start_day(pool, &task.household_id).await?;
 
     if task.recurrence_type == shared::RecurrenceType::OneTime {
         // For OneTime tasks, delete the most recent completion regardless of date
-        let result = sqlx::query(
+        let deleted: Option<NaiveDate> = sqlx::query_scalar(
             r#"
             DELETE FROM task_completions
             WHERE id = (
@@ -862,38 +1627,52 @@ pub async fn uncomplete_task(
                 ORDER BY completed_at DESC
                 LIMIT 1
             )
+            RETURNING due_date
             "#,
         )
         .bind(task_id.to_string())
         .bind(user_id.to_string())
-        .execute(pool)
+        .fetch_optional(pool)
         .await?;
 
-        if result.rows_affected() == 0 {
+        let Some(due_date) = deleted else {
             return Err(TaskError::NotCompleted);
-        }
+        };
+        events::record(pool, task_id, Some(user_id), EventKind::Completion, due_date, -1).await?;
+        pets::undo_care(pool, task_id, user_id).await?;
 
-        // Check if we're now below target
+        // Check if we're now below target (the user's own target with individual completions)
         let total_completions: i64 =
-            sqlx::query_scalar("SELECT COUNT(*) FROM task_completions WHERE task_id = ?")
+            sqlx::query_scalar("SELECT COUNT(*) FROM task_completions WHERE task_id = ? AND (? = 0 OR user_id = ?)")
                 .bind(task_id.to_string())
+                .bind(individual)
+                .bind(user_id.to_string())
                 .fetch_one(pool)
                 .await?;
 
         if total_completions < task.target_count as i64 {
             // Delete any period result for this OneTime task
-            sqlx::query("DELETE FROM task_period_results WHERE task_id = ?")
+            let deleted: Vec<(NaiveDate, String)> =
+                sqlx::query_as("DELETE FROM task_period_results WHERE task_id = ? RETURNING period_start, status")
+                    .bind(task_id.to_string())
+                    .fetch_all(pool)
+                    .await?;
+            for (period_start, status) in deleted {
+                events::record_period_change(pool, task_id, period_start, status.parse().ok(), None).await?;
+            }
+            sqlx::query("DELETE FROM task_assignee_period_results WHERE task_id = ? AND user_id = ?")
                 .bind(task_id.to_string())
+                .bind(user_id.to_string())
                 .execute(pool)
                 .await?;
         }
     } else {
         // For recurring tasks, use period bounds
-        let period_date = scheduler::get_next_due_date(&task, today).unwrap_or(today);
+        let period_date = scheduler::get_next_due_date(&task, today, week_start_day).unwrap_or(today);
         let (period_start, period_end) = scheduler::get_period_bounds(&task, period_date);
 
         // Delete the most recent completion for this task/user in the current period
-        let result = sqlx::query(
+        let deleted: Option<NaiveDate> = sqlx::query_scalar(
             r#"
             DELETE FROM task_completions
             WHERE id = (
@@ -902,30 +1681,35 @@ pub async fn uncomplete_task(
                 ORDER BY completed_at DESC
                 LIMIT 1
             )
+            RETURNING due_date
             "#,
         )
         .bind(task_id.to_string())
         .bind(user_id.to_string())
         .bind(period_start)
         .bind(period_end)
-        .execute(pool)
+        .fetch_optional(pool)
         .await?;
 
-        if result.rows_affected() == 0 {
+        let Some(due_date) = deleted else {
             return Err(TaskError::NotCompleted);
-        }
+        };
+        events::record(pool, task_id, Some(user_id), EventKind::Completion, due_date, -1).await?;
+        pets::undo_care(pool, task_id, user_id).await?;
 
         // After deleting completion, check if we're now below target
         // If so, delete the period result so it can be re-evaluated
         let completions_for_period: i64 = sqlx::query_scalar(
             r#"
             SELECT COUNT(*) FROM task_completions
-            WHERE task_id = ? AND due_date >= ? AND due_date <= ?
+            WHERE task_id = ? AND due_date >= ? AND due_date <= ? AND (? = 0 OR user_id = ?)
             "#,
         )
         .bind(task_id.to_string())
         .bind(period_start)
         .bind(period_end)
+        .bind(individual)
+        .bind(user_id.to_string())
         .fetch_one(pool)
         .await?;
 
@@ -933,6 +1717,9 @@ pub async fn uncomplete_task(
             // Delete the period result - will be re-created when target is reached
             // or finalized as failed by background job when period ends
             let _ = period_results::delete_period_result(pool, task_id, period_start).await;
+            if individual {
+                let _ = period_results::delete_assignee_period_result(pool, task_id, user_id, period_start).await;
+            }
         }
     }
 
@@ -965,10 +1752,18 @@ pub async fn list_pending_reviews(
         t_time_period: Option<String>,
         t_allow_exceed_target: bool,
         t_requires_review: bool,
+        t_reviewer_type: String,
+        t_reviewer_id: Option<String>,
         t_points_reward: Option<i64>,
         t_points_penalty: Option<i64>,
         t_due_time: Option<String>,
+        t_effort_minutes: Option<i32>,
+        t_active_from: Option<String>,
+        t_active_until: Option<String>,
+        t_ends_on: Option<NaiveDate>,
+        t_max_occurrences: Option<i32>,
         t_habit_type: String,
+        t_version: i64,
         t_created_at: chrono::DateTime<chrono::Utc>,
         t_updated_at: chrono::DateTime<chrono::Utc>,
         // User fields
@@ -989,15 +1784,18 @@ pub async fn list_pending_reviews(
             t.recurrence_value as t_recurrence_value, t.assigned_user_id as t_assigned_user_id,
             t.target_count as t_target_count, t.time_period as t_time_period,
             t.allow_exceed_target as t_allow_exceed_target, t.requires_review as t_requires_review,
+            t.reviewer_type as t_reviewer_type, t.reviewer_id as t_reviewer_id,
             t.points_reward as t_points_reward, t.points_penalty as t_points_penalty,
-            t.due_time as t_due_time, t.habit_type as t_habit_type,
+            t.due_time as t_due_time, t.effort_minutes as t_effort_minutes,
+            t.active_from as t_active_from, t.active_until as t_active_until,
+            t.ends_on as t_ends_on, t.max_occurrences as t_max_occurrences, t.habit_type as t_habit_type, t.version as t_version,
             t.created_at as t_created_at, t.updated_at as t_updated_at,
             u.id as u_id, u.username as u_username, u.email as u_email,
             u.created_at as u_created_at, u.updated_at as u_updated_at
         FROM task_completions tc
         JOIN tasks t ON tc.task_id = t.id
         JOIN users u ON tc.user_id = u.id
-        WHERE t.household_id = ? AND tc.status = 'pending'
+        WHERE t.household_id = ? AND t.deleted_at IS NULL AND tc.status = 'pending'
         ORDER BY tc.completed_at DESC
         "#,
     )
@@ -1030,20 +1828,32 @@ pub async fn list_pending_reviews(
                     recurrence_type: row.t_recurrence_type.parse().unwrap_or(shared::RecurrenceType::Daily),
                     recurrence_value,
                     assigned_user_id: row.t_assigned_user_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+                    assignee_ids: Vec::new(),
                     target_count: row.t_target_count,
                     time_period,
                     allow_exceed_target: row.t_allow_exceed_target,
                     requires_review: row.t_requires_review,
+                    reviewer_type: row.t_reviewer_type.parse().unwrap_or_default(),
+                    reviewer_id: row.t_reviewer_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
                     points_reward: row.t_points_reward,
                     points_penalty: row.t_points_penalty,
                     due_time: row.t_due_time,
+                    effort_minutes: row.t_effort_minutes,
+                    active_from: row.t_active_from,
+                    active_until: row.t_active_until,
+                    ends_on: row.t_ends_on,
+                    max_occurrences: row.t_max_occurrences,
                     habit_type: row.t_habit_type.parse().unwrap_or(shared::HabitType::Good),
                     category_id: None,
                     category_name: None,
+                    tag_ids: Vec::new(),
                     archived: false, // Pending reviews are for active tasks
                     paused: false, // Pending reviews are for active tasks
                     suggestion: None,
                     suggested_by: None,
+                    postponed_from: None,
+                    postponed_to: None,
+                    version: row.t_version,
                     created_at: row.t_created_at,
                     updated_at: row.t_updated_at,
                 },
@@ -1054,6 +1864,7 @@ pub async fn list_pending_reviews(
                     created_at: row.u_created_at,
                     updated_at: row.u_updated_at,
                 },
+                attachments: Vec::new(),
             }
         })
         .collect())
@@ -1074,6 +1885,7 @@ pub async fn get_completion(
 }
 
 /// Approve a pending task completion
+#[tracing::instrument(level = "debug", skip_all, fields(%completion_id))]
 pub async fn approve_completion(
     pool: &SqlitePool,
     completion_id: &Uuid,
@@ -1089,12 +1901,29 @@ pub async fn approve_completion(
         return Err(TaskError::NotFound);
     }
 
-    get_completion(pool, completion_id)
+    let completion = get_completion(pool, completion_id)
         .await?
-        .ok_or(TaskError::NotFound)
+        .ok_or(TaskError::NotFound)?;
+
+    if let Some(task) = get_task(pool, &completion.task_id).await? {
+        dispatch_review(pool, &task, &completion, true).await;
+    }
+
+    Ok(completion)
+}
+
+async fn dispatch_review(pool: &SqlitePool, task: &Task, completion: &TaskCompletion, approved: bool) {
+    webhooks::dispatch(
+        pool,
+        &task.household_id,
+        WebhookEvent::TaskReviewed,
+        serde_json::json!({ "task_title": task.title, "completion": completion, "approved": approved }),
+    )
+    .await;
 }
 
 /// Reject a pending task completion (deletes it and reverses points/rewards)
+#[tracing::instrument(level = "debug", skip_all, fields(%completion_id))]
 pub async fn reject_completion(
     pool: &SqlitePool,
     completion_id: &Uuid,
@@ -1136,6 +1965,12 @@ pub async fn reject_completion(
         .bind(completion_id.to_string())
         .execute(pool)
         .await?;
+    events::record(pool, &completion.task_id, Some(&completion.user_id), EventKind::Completion, completion.due_date, -1)
+        .await?;
+
+    if let Some(task) = get_task(pool, &completion.task_id).await? {
+        dispatch_review(pool, &task, &completion, false).await;
+    }
 
     Ok(completion)
 }
@@ -1147,19 +1982,14 @@ pub async fn get_due_tasks(
 ) -> Result<Vec<TaskWithStatus>, TaskError> {
     let tasks = list_tasks(pool, household_id).await?;
     let today = Utc::now().date_naive();
+    let week_start_day = household_settings::week_start_day(pool, household_id).await?;
 
-    let mut due_tasks = Vec::new();
-
-    for task in tasks {
-        if scheduler::is_task_due_on_date(&task, today) {
-            let status = get_task_with_status(pool, &task.id, user_id).await?;
-            if let Some(s) = status {
-                due_tasks.push(s);
-            }
-        }
-    }
+    let due_tasks: Vec<Task> = tasks
+        .into_iter()
+        .filter(|task| scheduler::is_task_due_on_date(task, today, week_start_day))
+        .collect();
 
-    Ok(due_tasks)
+    get_tasks_with_status(pool, household_id, due_tasks, user_id).await
 }
 
 /// Get all tasks for a household with their status (not just due today)
@@ -1169,56 +1999,97 @@ pub async fn get_all_tasks_with_status(
     household_id: &Uuid,
     user_id: &Uuid,
 ) -> Result<Vec<TaskWithStatus>, TaskError> {
-    let tasks = list_tasks(pool, household_id).await?;
-
-    let mut tasks_with_status = Vec::new();
+    Ok(get_tasks_with_status_page(pool, household_id, user_id, &TaskFilter::default(), None, 0).await?.items)
+}
 
-    for task in tasks {
-        let status = get_task_with_status(pool, &task.id, user_id).await?;
-        if let Some(s) = status {
-            tasks_with_status.push(s);
-        }
-    }
+/// One page of `get_all_tasks_with_status`. Tasks are ordered before their status is
+/// loaded, so only the requested page pays for the per-task status queries.
+/// Only tasks matching `filter` are included.
+pub async fn get_tasks_with_status_page(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    filter: &TaskFilter,
+    limit: Option<i64>,
+    offset: i64,
+) -> Result<Paginated<TaskWithStatus>, TaskError> {
+    let today = Utc::now().date_naive();
+    let week_start_day = household_settings::week_start_day(pool, household_id).await?;
+    let mut tasks: Vec<(Option<NaiveDate>, Task)> = task_filters::apply(filter, list_tasks(pool, household_id).await?, week_start_day)
+        .into_iter()
+        .map(|task| (scheduler::get_next_due_date(&task, today, week_start_day), task))
+        .collect();
 
     // Sort by next_due_date: tasks with dates first (ascending), then tasks without dates
     // Secondary sort by title (alphabetical, case-insensitive)
-    tasks_with_status.sort_by(|a, b| {
-        match (&a.next_due_date, &b.next_due_date) {
+    tasks.sort_by(|(due_a, a), (due_b, b)| {
+        match (due_a, due_b) {
             (Some(date_a), Some(date_b)) => date_a
                 .cmp(date_b)
-                .then_with(|| a.task.title.to_lowercase().cmp(&b.task.title.to_lowercase())),
+                .then_with(|| a.title.to_lowercase().cmp(&b.title.to_lowercase())),
             (Some(_), None) => std::cmp::Ordering::Less,
             (None, Some(_)) => std::cmp::Ordering::Greater,
-            (None, None) => a.task.title.to_lowercase().cmp(&b.task.title.to_lowercase()),
+            (None, None) => a.title.to_lowercase().cmp(&b.title.to_lowercase()),
         }
     });
 
-    Ok(tasks_with_status)
+    let total = tasks.len() as i64;
+    let page = tasks
+        .into_iter()
+        .skip(offset.max(0) as usize)
+        .take(limit.map(|l| l.max(0) as usize).unwrap_or(usize::MAX));
+
+    let page: Vec<Task> = page.map(|(_, task)| task).collect();
+
+    Ok(Paginated {
+        items: get_tasks_with_status(pool, household_id, page, user_id).await?,
+        total,
+        limit: limit.unwrap_or(total),
+        offset,
+    })
 }
 
-async fn calculate_streak(pool: &SqlitePool, task: &Task, _user_id: &Uuid) -> Result<i32, TaskError> {
+async fn calculate_streak(pool: &SqlitePool, task: &Task, user_id: &Uuid) -> Result<i32, TaskError> {
     // Edge case: Free-form and one-time tasks don't have traditional streaks
     if task.recurrence_type == shared::RecurrenceType::OneTime {
         if task.target_count == 0 {
             // Free-form: no schedule, no streak concept
             return Ok(0);
         } else {
-            // One-time: return total completions (household-wide)
+            // One-time: return total completions (household-wide, or the user's own
+            // when every assignee completes separately)
             let completions = sqlx::query_scalar::<_, i64>(
-                "SELECT COUNT(*) FROM task_completions WHERE task_id = ?"
+                "SELECT COUNT(*) FROM task_completions WHERE task_id = ? AND (? = 0 OR user_id = ?)"
             )
             .bind(task.id.to_string())
+            .bind(task.has_individual_completions())
+            .bind(user_id.to_string())
             .fetch_one(pool)
             .await? as i32;
             return Ok(completions);
         }
     }
-
-    // Use period results for streak calculation
-    // Counts consecutive completed periods, skipped periods don't break streak
-    let streak = period_results::calculate_current_streak(pool, &task.id)
-        .await
-        .unwrap_or(0);
+
+    // Use period results for streak calculation
+    // Counts consecutive completed periods, skipped periods don't break streak.
+    // Failed periods during the member's vacation are treated as skipped.
+    let membership = household_service::get_membership(pool, &task.household_id, user_id)
+        .await
+        .ok()
+        .flatten();
+    if task.has_individual_completions() {
+        let rows = period_results::get_assignee_periods(pool, &task.id, user_id)
+            .await
+            .unwrap_or_default();
+        return Ok(period_results::streak_from_periods(&rows, membership.as_ref()));
+    }
+    let streak = match membership {
+        Some(ref m) if m.vacation_start.is_some() => {
+            period_results::calculate_member_streak(pool, &task.id, m).await
+        }
+        _ => period_results::calculate_current_streak(pool, &task.id).await,
+    }
+    .unwrap_or(0);
 
     Ok(streak)
 }
@@ -1232,17 +2103,47 @@ pub async fn get_dashboard_task_ids(
     pool: &SqlitePool,
     user_id: &str,
 ) -> Result<Vec<String>, TaskError> {
-    let rows: Vec<(String,)> = sqlx::query_as(
-        "SELECT udt.task_id FROM user_dashboard_tasks udt
+    Ok(dashboard_entries(pool, user_id).await?.into_iter().map(|(id, _)| id).collect())
+}
+
+/// Dashboard task IDs with their manual position, manually ordered ones first
+async fn dashboard_entries(pool: &SqlitePool, user_id: &str) -> Result<Vec<(String, Option<i64>)>, TaskError> {
+    let rows: Vec<(String, Option<i64>)> = sqlx::query_as(
+        "SELECT udt.task_id, udt.sort_order FROM user_dashboard_tasks udt
          JOIN tasks t ON udt.task_id = t.id
-         WHERE udt.user_id = ? AND t.archived = 0
-         AND (t.suggestion IS NULL OR t.suggestion = 'approved')",
+         WHERE udt.user_id = ? AND t.archived = 0 AND t.deleted_at IS NULL
+         AND (t.suggestion IS NULL OR t.suggestion = 'approved')
+         ORDER BY udt.sort_order IS NULL, udt.sort_order",
     )
     .bind(user_id)
     .fetch_all(pool)
     .await?;
 
-    Ok(rows.into_iter().map(|(id,)| id).collect())
+    Ok(rows)
+}
+
+/// Set the manual order of the user's dashboard. Listed tasks get their list
+/// position; all other dashboard tasks lose theirs. IDs of tasks that are not
+/// on the dashboard are ignored.
+pub async fn reorder_dashboard_tasks(pool: &SqlitePool, user_id: &str, task_ids: &[Uuid]) -> Result<(), TaskError> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("UPDATE user_dashboard_tasks SET sort_order = NULL WHERE user_id = ?")
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+
+    for (position, task_id) in task_ids.iter().enumerate() {
+        sqlx::query("UPDATE user_dashboard_tasks SET sort_order = ? WHERE user_id = ? AND task_id = ?")
+            .bind(position as i64)
+            .bind(user_id)
+            .bind(task_id.to_string())
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
 }
 
 /// Check if a task is on the user's dashboard
@@ -1301,36 +2202,42 @@ pub async fn get_dashboard_tasks_with_status(
     pool: &SqlitePool,
     user_id: &Uuid,
 ) -> Result<Vec<(TaskWithStatus, Uuid)>, TaskError> {
-    let dashboard_task_ids = get_dashboard_task_ids(pool, &user_id.to_string()).await?;
+    let entries = dashboard_entries(pool, &user_id.to_string()).await?;
 
-    if dashboard_task_ids.is_empty() {
+    if entries.is_empty() {
         return Ok(Vec::new());
     }
 
     let mut results = Vec::new();
 
-    for task_id_str in dashboard_task_ids {
+    for (task_id_str, sort_order) in entries {
         if let Ok(task_id) = Uuid::parse_str(&task_id_str) {
             if let Some(task_with_status) = get_task_with_status(pool, &task_id, user_id).await? {
                 let household_id = task_with_status.task.household_id;
-                results.push((task_with_status, household_id));
+                results.push((sort_order, task_with_status, household_id));
             }
         }
     }
 
-    // Sort by next_due_date (primary), then by title (secondary, case-insensitive)
-    results.sort_by(|(a, _), (b, _)| {
-        match (&a.next_due_date, &b.next_due_date) {
+    // Manually ordered tasks first, then by next_due_date, then by title (case-insensitive)
+    results.sort_by(|(order_a, a, _), (order_b, b, _)| {
+        let manual = match (order_a, order_b) {
+            (Some(x), Some(y)) => x.cmp(y),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        };
+        manual.then_with(|| match (&a.next_due_date, &b.next_due_date) {
             (Some(date_a), Some(date_b)) => date_a
                 .cmp(date_b)
                 .then_with(|| a.task.title.to_lowercase().cmp(&b.task.title.to_lowercase())),
             (Some(_), None) => std::cmp::Ordering::Less,
             (None, Some(_)) => std::cmp::Ordering::Greater,
             (None, None) => a.task.title.to_lowercase().cmp(&b.task.title.to_lowercase()),
-        }
+        })
     });
 
-    Ok(results)
+    Ok(results.into_iter().map(|(_, task, household_id)| (task, household_id)).collect())
 }
 
 /// Get all pending task suggestions for a household
@@ -1340,7 +2247,7 @@ pub async fn list_suggested_tasks(pool: &SqlitePool, household_id: &Uuid) -> Res
         SELECT t.*, tc.name as category_name
         FROM tasks t
         LEFT JOIN task_categories tc ON t.category_id = tc.id
-        WHERE t.household_id = ? AND t.suggestion = 'suggested'
+        WHERE t.household_id = ? AND t.suggestion = 'suggested' AND t.deleted_at IS NULL
         ORDER BY t.created_at DESC
         "#,
     )
@@ -1356,7 +2263,7 @@ pub async fn approve_suggestion(pool: &SqlitePool, task_id: &Uuid) -> Result<Tas
     let now = Utc::now();
 
     // Check if task exists and is a pending suggestion
-    let task: Option<TaskRow> = sqlx::query_as("SELECT * FROM tasks WHERE id = ?")
+    let task: Option<TaskRow> = sqlx::query_as("SELECT * FROM tasks WHERE id = ? AND deleted_at IS NULL")
         .bind(task_id.to_string())
         .fetch_optional(pool)
         .await?;
@@ -1367,7 +2274,7 @@ pub async fn approve_suggestion(pool: &SqlitePool, task_id: &Uuid) -> Result<Tas
         return Err(TaskError::NotFound); // Not a pending suggestion
     }
 
-    sqlx::query("UPDATE tasks SET suggestion = 'approved', updated_at = ? WHERE id = ?")
+    sqlx::query("UPDATE tasks SET suggestion = 'approved', updated_at = ?, version = version + 1 WHERE id = ?")
         .bind(now)
         .bind(task_id.to_string())
         .execute(pool)
@@ -1387,7 +2294,7 @@ pub async fn deny_suggestion(pool: &SqlitePool, task_id: &Uuid) -> Result<Task,
     let now = Utc::now();
 
     // Check if task exists and is a pending suggestion
-    let task: Option<TaskRow> = sqlx::query_as("SELECT * FROM tasks WHERE id = ?")
+    let task: Option<TaskRow> = sqlx::query_as("SELECT * FROM tasks WHERE id = ? AND deleted_at IS NULL")
         .bind(task_id.to_string())
         .fetch_optional(pool)
         .await?;
@@ -1398,7 +2305,7 @@ pub async fn deny_suggestion(pool: &SqlitePool, task_id: &Uuid) -> Result<Task,
         return Err(TaskError::NotFound); // Not a pending suggestion
     }
 
-    sqlx::query("UPDATE tasks SET suggestion = 'denied', updated_at = ? WHERE id = ?")
+    sqlx::query("UPDATE tasks SET suggestion = 'denied', updated_at = ?, version = version + 1 WHERE id = ?")
         .bind(now)
         .bind(task_id.to_string())
         .execute(pool)
@@ -1413,11 +2320,13 @@ pub async fn deny_suggestion(pool: &SqlitePool, task_id: &Uuid) -> Result<Task,
     Ok(updated.to_shared())
 }
 
-/// Get all tasks with status from all households the user is a member of
+/// Get all tasks with status from all households the user is a member of,
+/// or only from `household_ids` when given.
 /// Used by the "Show all" toggle on the dashboard
 pub async fn get_all_tasks_across_households(
     pool: &SqlitePool,
     user_id: &Uuid,
+    household_ids: &[Uuid],
 ) -> Result<Vec<(TaskWithStatus, Uuid)>, TaskError> {
     // Get all households for user
     let households = household_service::list_user_households(pool, user_id)
@@ -1427,6 +2336,9 @@ pub async fn get_all_tasks_across_households(
     let mut all_tasks = Vec::new();
 
     for household in households {
+        if !household_ids.is_empty() && !household_ids.contains(&household.id) {
+            continue;
+        }
         if let Ok(tasks) = get_all_tasks_with_status(pool, &household.id, user_id).await {
             for task in tasks {
                 all_tasks.push((task, household.id));
@@ -1501,6 +2413,18 @@ mod tests {
         .await
         .unwrap();
 
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS household_settings (
+                household_id TEXT PRIMARY KEY NOT NULL REFERENCES households(id),
+                week_start_day INTEGER NOT NULL DEFAULT 0
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
         sqlx::query(
             r#"
             CREATE TABLE IF NOT EXISTS task_categories (
@@ -1535,14 +2459,25 @@ mod tests {
                 points_reward INTEGER,
                 points_penalty INTEGER,
                 due_time TEXT,
+                effort_minutes INTEGER,
+                active_from TEXT,
+                active_until TEXT,
+                ends_on DATE,
+                max_occurrences INTEGER,
+                reviewer_type TEXT NOT NULL DEFAULT 'any_reviewer',
+                reviewer_id TEXT,
                 habit_type TEXT NOT NULL DEFAULT 'good',
                 category_id TEXT REFERENCES task_categories(id),
                 archived BOOLEAN NOT NULL DEFAULT 0,
                 paused BOOLEAN NOT NULL DEFAULT 0,
                 suggestion TEXT CHECK(suggestion IN ('suggested', 'approved', 'denied')),
                 suggested_by TEXT REFERENCES users(id),
+                postponed_from DATE,
+                postponed_to DATE,
                 created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
-                updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+                version INTEGER NOT NULL DEFAULT 1,
+                updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                deleted_at DATETIME
             )
             "#,
         )
@@ -1593,6 +2528,9 @@ mod tests {
                 role TEXT NOT NULL DEFAULT 'member',
                 points INTEGER NOT NULL DEFAULT 0,
                 joined_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                vacation_start DATE,
+                vacation_end DATE,
+                permissions TEXT,
                 UNIQUE(household_id, user_id)
             )
             "#,
@@ -1648,6 +2586,20 @@ mod tests {
         .await
         .unwrap();
 
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS task_dependencies (
+                task_id TEXT NOT NULL REFERENCES tasks(id),
+                prerequisite_id TEXT NOT NULL REFERENCES tasks(id),
+                created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                PRIMARY KEY (task_id, prerequisite_id)
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
         sqlx::query(
             r#"
             CREATE TABLE IF NOT EXISTS user_rewards (
@@ -1688,6 +2640,7 @@ mod tests {
                 user_id TEXT NOT NULL,
                 task_id TEXT NOT NULL,
                 created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                sort_order INTEGER,
                 PRIMARY KEY (user_id, task_id),
                 FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
                 FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE
@@ -1719,6 +2672,194 @@ mod tests {
         .await
         .unwrap();
 
+        crate::test_utils::create_event_tables(&pool).await;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS task_assignees (
+                task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+                user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+                PRIMARY KEY (task_id, user_id)
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS task_tags (
+                id TEXT PRIMARY KEY NOT NULL,
+                household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+                name TEXT NOT NULL,
+                color TEXT,
+                created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                UNIQUE(household_id, name)
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS task_tag_assignments (
+                task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+                tag_id TEXT NOT NULL REFERENCES task_tags(id) ON DELETE CASCADE,
+                PRIMARY KEY (task_id, tag_id)
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS task_assignee_period_results (
+                id TEXT PRIMARY KEY NOT NULL,
+                task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+                user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+                period_start DATE NOT NULL,
+                period_end DATE NOT NULL,
+                status TEXT NOT NULL CHECK(status IN ('completed', 'failed', 'skipped')),
+                completions_count INTEGER NOT NULL,
+                target_count INTEGER NOT NULL,
+                finalized_at DATETIME NOT NULL,
+                finalized_by TEXT NOT NULL DEFAULT 'system',
+                notes TEXT,
+                UNIQUE (task_id, user_id, period_start)
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS task_claims (
+                task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+                period_start DATE NOT NULL,
+                user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+                claimed_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                PRIMARY KEY (task_id, period_start)
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS member_task_exclusions (
+                household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+                user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+                task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+                created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                PRIMARY KEY (user_id, task_id)
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS focus_sessions (
+                id TEXT PRIMARY KEY NOT NULL,
+                household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+                task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+                user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+                planned_minutes INTEGER NOT NULL,
+                status TEXT NOT NULL DEFAULT 'running',
+                started_at DATETIME NOT NULL,
+                ended_at DATETIME,
+                focused_seconds INTEGER NOT NULL DEFAULT 0
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS plants (
+                id TEXT PRIMARY KEY NOT NULL,
+                household_id TEXT NOT NULL REFERENCES households(id),
+                name TEXT NOT NULL,
+                location TEXT,
+                water_interval_days INTEGER,
+                fertilize_interval_days INTEGER,
+                water_task_id TEXT REFERENCES tasks(id) ON DELETE SET NULL,
+                fertilize_task_id TEXT REFERENCES tasks(id) ON DELETE SET NULL,
+                last_watered_at DATETIME,
+                last_fertilized_at DATETIME,
+                created_by TEXT NOT NULL REFERENCES users(id),
+                created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS pets (
+                id TEXT PRIMARY KEY NOT NULL,
+                household_id TEXT NOT NULL REFERENCES households(id),
+                name TEXT NOT NULL,
+                species TEXT,
+                notes TEXT,
+                created_by TEXT NOT NULL REFERENCES users(id),
+                created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS pet_schedules (
+                pet_id TEXT NOT NULL REFERENCES pets(id) ON DELETE CASCADE,
+                task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+                kind TEXT NOT NULL,
+                times_per_day INTEGER NOT NULL,
+                PRIMARY KEY (pet_id, task_id)
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS pet_care_log (
+                id TEXT PRIMARY KEY NOT NULL,
+                pet_id TEXT NOT NULL REFERENCES pets(id) ON DELETE CASCADE,
+                task_id TEXT REFERENCES tasks(id) ON DELETE SET NULL,
+                kind TEXT NOT NULL,
+                title TEXT NOT NULL,
+                user_id TEXT NOT NULL REFERENCES users(id),
+                logged_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
         pool
     }
 
@@ -1761,58 +2902,335 @@ mod tests {
         .await
         .unwrap();
 
-        // Also add to household_memberships for household service
-        sqlx::query(
-            "INSERT INTO household_memberships (id, household_id, user_id, role) VALUES (?, ?, ?, ?)",
-        )
-        .bind(Uuid::new_v4().to_string())
-        .bind(household_id.to_string())
-        .bind(owner_id.to_string())
-        .bind("owner")
-        .execute(pool)
-        .await
-        .unwrap();
+        // Also add to household_memberships for household service
+        sqlx::query(
+            "INSERT INTO household_memberships (id, household_id, user_id, role) VALUES (?, ?, ?, ?)",
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(household_id.to_string())
+        .bind(owner_id.to_string())
+        .bind("owner")
+        .execute(pool)
+        .await
+        .unwrap();
+
+        household_id
+    }
+
+    #[tokio::test]
+    async fn test_complete_task_allow_exceed_true() {
+        let pool = setup_test_db().await;
+        let user_id = create_test_user(&pool).await;
+        let household_id = create_test_household(&pool, &user_id).await;
+
+        // Create task with allow_exceed_target = true and target_count = 1
+        let request = CreateTaskRequest {
+            title: "Test Task".to_string(),
+            description: None,
+            recurrence_type: RecurrenceType::Daily,
+            recurrence_value: None,
+            assigned_user_id: None,
+            assignee_ids: None,
+            target_count: Some(1),
+            time_period: None,
+            allow_exceed_target: Some(true),
+            requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
+            points_reward: None,
+            points_penalty: None,
+            due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
+            habit_type: None,
+            category_id: None,
+            tag_ids: None,
+            is_suggestion: None,
+        };
+        let task = create_task(&pool, &household_id, &request, None).await.unwrap();
+
+        // First completion should succeed
+        let result1 = complete_task(&pool, &task.id, &user_id, &household_id).await;
+        assert!(result1.is_ok());
+
+        // Second completion should also succeed (exceeding target)
+        let result2 = complete_task(&pool, &task.id, &user_id, &household_id).await;
+        assert!(result2.is_ok());
+
+        // Third completion should also succeed
+        let result3 = complete_task(&pool, &task.id, &user_id, &household_id).await;
+        assert!(result3.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_complete_task_requires_prerequisite_first() {
+        let pool = setup_test_db().await;
+        let user_id = create_test_user(&pool).await;
+        let household_id = create_test_household(&pool, &user_id).await;
+
+        let daily = |title: &str| CreateTaskRequest {
+            title: title.to_string(),
+            description: None,
+            recurrence_type: RecurrenceType::Daily,
+            recurrence_value: None,
+            assigned_user_id: None,
+            assignee_ids: None,
+            target_count: Some(1),
+            time_period: None,
+            allow_exceed_target: None,
+            requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
+            points_reward: None,
+            points_penalty: None,
+            due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
+            habit_type: None,
+            category_id: None,
+            tag_ids: None,
+            is_suggestion: None,
+        };
+        let vacuum = create_task(&pool, &household_id, &daily("Vacuum"), None).await.unwrap();
+        let mop = create_task(&pool, &household_id, &daily("Mop"), None).await.unwrap();
+        task_dependencies::add_dependency(&pool, &mop.id, &vacuum.id).await.unwrap();
+
+        let status = get_task_with_status(&pool, &mop.id, &user_id).await.unwrap().unwrap();
+        assert_eq!(status.blocked_by, vec!["Vacuum".to_string()]);
+        assert!(!status.can_complete());
 
-        household_id
+        let result = complete_task(&pool, &mop.id, &user_id, &household_id).await;
+        assert!(matches!(result, Err(TaskError::PrerequisitesNotMet(_))));
+
+        complete_task(&pool, &vacuum.id, &user_id, &household_id).await.unwrap();
+        let status = get_task_with_status(&pool, &mop.id, &user_id).await.unwrap().unwrap();
+        assert!(status.blocked_by.is_empty());
+        assert!(complete_task(&pool, &mop.id, &user_id, &household_id).await.is_ok());
     }
 
     #[tokio::test]
-    async fn test_complete_task_allow_exceed_true() {
+    async fn test_batched_status_matches_single_task_status() {
         let pool = setup_test_db().await;
         let user_id = create_test_user(&pool).await;
         let household_id = create_test_household(&pool, &user_id).await;
 
-        // Create task with allow_exceed_target = true and target_count = 1
-        let request = CreateTaskRequest {
-            title: "Test Task".to_string(),
+        let request = |title: &str, recurrence_type: RecurrenceType, target_count: i32| CreateTaskRequest {
+            title: title.to_string(),
             description: None,
-            recurrence_type: RecurrenceType::Daily,
+            recurrence_type,
             recurrence_value: None,
             assigned_user_id: None,
-            target_count: Some(1),
+            assignee_ids: None,
+            target_count: Some(target_count),
             time_period: None,
             allow_exceed_target: Some(true),
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
-        let task = create_task(&pool, &household_id, &request, None).await.unwrap();
+        let vacuum = create_task(&pool, &household_id, &request("Vacuum", RecurrenceType::Daily, 2), None).await.unwrap();
+        let mop = create_task(&pool, &household_id, &request("Mop", RecurrenceType::Daily, 1), None).await.unwrap();
+        let repair = create_task(&pool, &household_id, &request("Repair", RecurrenceType::OneTime, 1), None).await.unwrap();
+        create_task(&pool, &household_id, &request("Water", RecurrenceType::Weekly, 1), None).await.unwrap();
+        task_dependencies::add_dependency(&pool, &mop.id, &vacuum.id).await.unwrap();
+
+        complete_task(&pool, &vacuum.id, &user_id, &household_id).await.unwrap();
+        complete_task(&pool, &repair.id, &user_id, &household_id).await.unwrap();
+        let today = Utc::now().date_naive();
+        task_claims::claim_task(&pool, &mop, &user_id, today).await.unwrap();
+        for (days_ago, status) in [(3, PeriodStatus::Completed), (2, PeriodStatus::Failed), (1, PeriodStatus::Completed)] {
+            let day = today - chrono::Duration::days(days_ago);
+            period_results::finalize_period(&pool, &vacuum.id, day, day, status, 1, 1, "system", None)
+                .await
+                .unwrap();
+        }
 
-        // First completion should succeed
-        let result1 = complete_task(&pool, &task.id, &user_id, &household_id).await;
-        assert!(result1.is_ok());
+        let tasks = list_tasks(&pool, &household_id).await.unwrap();
+        let batched = get_tasks_with_status(&pool, &household_id, tasks.clone(), &user_id).await.unwrap();
+        assert_eq!(batched.len(), tasks.len());
+
+        for (task, status) in tasks.iter().zip(&batched) {
+            let single = get_task_with_status(&pool, &task.id, &user_id).await.unwrap().unwrap();
+            assert_eq!(status.task.id, task.id);
+            assert_eq!(status.completions_today, single.completions_today, "{}", task.title);
+            assert_eq!(status.current_streak, single.current_streak, "{}", task.title);
+            assert_eq!(status.last_completion, single.last_completion, "{}", task.title);
+            assert_eq!(status.next_due_date, single.next_due_date, "{}", task.title);
+            assert_eq!(status.is_user_assigned, single.is_user_assigned, "{}", task.title);
+            assert_eq!(status.recent_periods, single.recent_periods, "{}", task.title);
+            assert_eq!(status.blocked_by, single.blocked_by, "{}", task.title);
+            assert_eq!(status.claimed_by, single.claimed_by, "{}", task.title);
+        }
+    }
 
-        // Second completion should also succeed (exceeding target)
-        let result2 = complete_task(&pool, &task.id, &user_id, &household_id).await;
-        assert!(result2.is_ok());
+    #[tokio::test]
+    async fn test_claimed_task_blocks_other_members() {
+        let pool = setup_test_db().await;
+        let alice = create_test_user(&pool).await;
+        let household_id = create_test_household(&pool, &alice).await;
+        let bob = Uuid::new_v4();
+        sqlx::query("INSERT INTO users (id, username, email, password_hash) VALUES (?, 'bob', 'bob@example.com', 'hash')")
+            .bind(bob.to_string())
+            .execute(&pool)
+            .await
+            .unwrap();
 
-        // Third completion should also succeed
-        let result3 = complete_task(&pool, &task.id, &user_id, &household_id).await;
-        assert!(result3.is_ok());
+        let task = create_task(
+            &pool,
+            &household_id,
+            &CreateTaskRequest {
+                title: "Empty dishwasher".to_string(),
+                description: None,
+                recurrence_type: RecurrenceType::Daily,
+                recurrence_value: None,
+                assigned_user_id: None,
+                assignee_ids: None,
+                target_count: Some(1),
+                time_period: None,
+                allow_exceed_target: None,
+                requires_review: None,
+                reviewer_type: None,
+                reviewer_id: None,
+                points_reward: None,
+                points_penalty: None,
+                due_time: None,
+                effort_minutes: None,
+                active_from: None,
+                active_until: None,
+                ends_on: None,
+                max_occurrences: None,
+                habit_type: None,
+                category_id: None,
+                tag_ids: None,
+                is_suggestion: None,
+            },
+            None,
+        )
+        .await
+        .unwrap();
+        task_claims::claim_task(&pool, &task, &alice, Utc::now().date_naive()).await.unwrap();
+
+        let own = get_task_with_status(&pool, &task.id, &alice).await.unwrap().unwrap();
+        assert!(own.is_user_assigned);
+        let other = get_task_with_status(&pool, &task.id, &bob).await.unwrap().unwrap();
+        assert_eq!(other.claimed_by.as_ref().map(|claim| claim.user_id), Some(alice));
+        assert!(!other.can_complete());
+
+        let result = complete_task(&pool, &task.id, &bob, &household_id).await;
+        assert!(matches!(result, Err(TaskError::ClaimedByOther(_))));
+        assert!(complete_task(&pool, &task.id, &alice, &household_id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_multi_assignee_task_tracks_each_assignee() {
+        let pool = setup_test_db().await;
+        let alice = create_test_user(&pool).await;
+        let household_id = create_test_household(&pool, &alice).await;
+        let mut others = Vec::new();
+        for name in ["bob", "carol"] {
+            let id = Uuid::new_v4();
+            sqlx::query("INSERT INTO users (id, username, email, password_hash) VALUES (?, ?, ?, 'hash')")
+                .bind(id.to_string())
+                .bind(name)
+                .bind(format!("{}@example.com", name))
+                .execute(&pool)
+                .await
+                .unwrap();
+            others.push(id);
+        }
+        let (bob, carol) = (others[0], others[1]);
+
+        let task = create_task(
+            &pool,
+            &household_id,
+            &CreateTaskRequest {
+                title: "Brush teeth".to_string(),
+                description: None,
+                recurrence_type: RecurrenceType::Daily,
+                recurrence_value: None,
+                assigned_user_id: None,
+                assignee_ids: Some(vec![alice, bob, alice]),
+                target_count: Some(1),
+                time_period: None,
+                allow_exceed_target: Some(false),
+                requires_review: None,
+                reviewer_type: None,
+                reviewer_id: None,
+                points_reward: None,
+                points_penalty: None,
+                due_time: None,
+                effort_minutes: None,
+                active_from: None,
+                active_until: None,
+                ends_on: None,
+                max_occurrences: None,
+                habit_type: None,
+                category_id: None,
+                tag_ids: None,
+                is_suggestion: None,
+            },
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(task.assignee_ids, vec![alice, bob]);
+        assert_eq!(task.assigned_user_id, Some(alice));
+        assert_eq!(get_task(&pool, &task.id).await.unwrap().unwrap().assignee_ids, vec![alice, bob]);
+        assert_eq!(list_user_assigned_tasks(&pool, &household_id, &bob).await.unwrap().len(), 1);
+
+        // Only assignees may complete it, and each one completes it separately
+        assert!(matches!(
+            complete_task(&pool, &task.id, &carol, &household_id).await,
+            Err(TaskError::NotAssigned)
+        ));
+        complete_task(&pool, &task.id, &alice, &household_id).await.unwrap();
+        assert!(matches!(
+            complete_task(&pool, &task.id, &alice, &household_id).await,
+            Err(TaskError::AlreadyCompleted)
+        ));
+
+        let alice_status = get_task_with_status(&pool, &task.id, &alice).await.unwrap().unwrap();
+        let bob_status = get_task_with_status(&pool, &task.id, &bob).await.unwrap().unwrap();
+        assert_eq!(alice_status.completions_today, 1);
+        assert_eq!(alice_status.current_streak, 1);
+        assert_eq!(bob_status.completions_today, 0);
+        assert_eq!(bob_status.current_streak, 0);
+        assert!(!get_task_with_status(&pool, &task.id, &carol).await.unwrap().unwrap().is_user_assigned);
+
+        let batched = get_tasks_with_status(&pool, &household_id, vec![task.clone()], &bob).await.unwrap();
+        assert_eq!(batched[0].completions_today, bob_status.completions_today);
+        assert_eq!(batched[0].current_streak, bob_status.current_streak);
+
+        // The task's own period only counts as completed once everyone is done
+        let today = Utc::now().date_naive();
+        let period_date = scheduler::get_next_due_date(&task, today, 0).unwrap_or(today);
+        let (period_start, _) = scheduler::get_period_bounds(&task, period_date);
+        assert!(!period_results::is_period_finalized(&pool, &task.id, period_start).await.unwrap());
+        complete_task(&pool, &task.id, &bob, &household_id).await.unwrap();
+        assert!(period_results::is_period_finalized(&pool, &task.id, period_start).await.unwrap());
+
+        // Undoing one assignee's completion reopens the period
+        uncomplete_task(&pool, &task.id, &bob).await.unwrap();
+        assert!(!period_results::is_period_finalized(&pool, &task.id, period_start).await.unwrap());
+        assert_eq!(get_task_with_status(&pool, &task.id, &alice).await.unwrap().unwrap().current_streak, 1);
     }
 
     #[tokio::test]
@@ -1828,15 +3246,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: Some(false),
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None).await.unwrap();
@@ -1863,15 +3290,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: Some(2),
             time_period: None,
             allow_exceed_target: Some(false),
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None).await.unwrap();
@@ -1902,15 +3338,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: None, // Default
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None).await.unwrap();
@@ -1940,15 +3385,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None, // Default to Good
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task_good = create_task(&pool, &household_id, &request_good, None).await.unwrap();
@@ -1962,15 +3416,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: Some(shared::HabitType::Bad),
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task_bad = create_task(&pool, &household_id, &request_bad, None).await.unwrap();
@@ -2000,15 +3463,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None).await.unwrap();
@@ -2039,15 +3511,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let _task = create_task(&pool, &household_id, &request, None).await.unwrap();
@@ -2070,15 +3551,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task1 = create_task(&pool, &household_id, &request1, None).await.unwrap();
@@ -2089,15 +3579,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task2 = create_task(&pool, &household_id, &request2, None).await.unwrap();
@@ -2123,6 +3622,67 @@ mod tests {
         assert_eq!(dashboard_tasks[0].0.task.id, task1.id);
     }
 
+    #[tokio::test]
+    async fn test_dashboard_manual_order_comes_first() {
+        let pool = setup_test_db().await;
+        let user_id = create_test_user(&pool).await;
+        let household_id = create_test_household(&pool, &user_id).await;
+
+        let mut tasks = Vec::new();
+        for title in ["Alpha", "Bravo", "Charlie"] {
+            let request = CreateTaskRequest {
+                title: title.to_string(),
+                description: None,
+                recurrence_type: RecurrenceType::Daily,
+                recurrence_value: None,
+                assigned_user_id: None,
+                assignee_ids: None,
+                target_count: Some(1),
+                time_period: None,
+                allow_exceed_target: None,
+                requires_review: None,
+                reviewer_type: None,
+                reviewer_id: None,
+                points_reward: None,
+                points_penalty: None,
+                due_time: None,
+                effort_minutes: None,
+                active_from: None,
+                active_until: None,
+                ends_on: None,
+                max_occurrences: None,
+                habit_type: None,
+                category_id: None,
+                tag_ids: None,
+                is_suggestion: None,
+            };
+            let task = create_task(&pool, &household_id, &request, None).await.unwrap();
+            add_task_to_dashboard(&pool, &user_id.to_string(), &task.id.to_string())
+                .await
+                .unwrap();
+            tasks.push(task);
+        }
+        let titles = |list: Vec<(TaskWithStatus, Uuid)>| -> Vec<String> {
+            list.into_iter().map(|(t, _)| t.task.title).collect()
+        };
+
+        // Without a manual order the title decides
+        let dashboard_tasks = get_dashboard_tasks_with_status(&pool, &user_id).await.unwrap();
+        assert_eq!(titles(dashboard_tasks), vec!["Alpha", "Bravo", "Charlie"]);
+
+        // Pinned tasks come first in the given order, the rest follow
+        reorder_dashboard_tasks(&pool, &user_id.to_string(), &[tasks[2].id, tasks[1].id, Uuid::new_v4()])
+            .await
+            .unwrap();
+        let dashboard_tasks = get_dashboard_tasks_with_status(&pool, &user_id).await.unwrap();
+        assert_eq!(titles(dashboard_tasks), vec!["Charlie", "Bravo", "Alpha"]);
+
+        // A new order replaces the old one completely
+        reorder_dashboard_tasks(&pool, &user_id.to_string(), &[tasks[1].id]).await.unwrap();
+        let dashboard_tasks = get_dashboard_tasks_with_status(&pool, &user_id).await.unwrap();
+        assert_eq!(titles(dashboard_tasks), vec!["Bravo", "Alpha", "Charlie"]);
+    }
+
     #[tokio::test]
     async fn test_list_tasks_alphabetical_order() {
         let pool = setup_test_db().await;
@@ -2137,15 +3697,24 @@ mod tests {
                 recurrence_type: RecurrenceType::Daily,
                 recurrence_value: None,
                 assigned_user_id: None,
+                assignee_ids: None,
                 target_count: Some(1),
                 time_period: None,
                 allow_exceed_target: None,
                 requires_review: None,
+                reviewer_type: None,
+                reviewer_id: None,
                 points_reward: None,
                 points_penalty: None,
                 due_time: None,
+                effort_minutes: None,
+                active_from: None,
+                active_until: None,
+                ends_on: None,
+                max_occurrences: None,
                 habit_type: None,
                 category_id: None,
+                tag_ids: None,
                 is_suggestion: None,
             };
             create_task(&pool, &household_id, &request, None).await.unwrap();
@@ -2173,15 +3742,24 @@ mod tests {
                 recurrence_type: RecurrenceType::Daily,
                 recurrence_value: None,
                 assigned_user_id: None,
+                assignee_ids: None,
                 target_count: Some(1),
                 time_period: None,
                 allow_exceed_target: None,
                 requires_review: None,
+                reviewer_type: None,
+                reviewer_id: None,
                 points_reward: None,
                 points_penalty: None,
                 due_time: None,
+                effort_minutes: None,
+                active_from: None,
+                active_until: None,
+                ends_on: None,
+                max_occurrences: None,
                 habit_type: None,
                 category_id: None,
+                tag_ids: None,
                 is_suggestion: None,
             };
             create_task(&pool, &household_id, &request, None).await.unwrap();
@@ -2209,15 +3787,24 @@ mod tests {
                 recurrence_type: RecurrenceType::Daily,
                 recurrence_value: None,
                 assigned_user_id: None,
+                assignee_ids: None,
                 target_count: Some(1),
                 time_period: None,
                 allow_exceed_target: None,
                 requires_review: None,
+                reviewer_type: None,
+                reviewer_id: None,
                 points_reward: None,
                 points_penalty: None,
                 due_time: None,
+                effort_minutes: None,
+                active_from: None,
+                active_until: None,
+                ends_on: None,
+                max_occurrences: None,
                 habit_type: None,
                 category_id: None,
+                tag_ids: None,
                 is_suggestion: None,
             };
             create_task(&pool, &household_id, &request, None).await.unwrap();
@@ -2233,6 +3820,60 @@ mod tests {
         assert_eq!(tasks[2].task.title, "Zebra Daily");
     }
 
+    #[tokio::test]
+    async fn test_task_pages() {
+        let pool = setup_test_db().await;
+        let user_id = create_test_user(&pool).await;
+        let household_id = create_test_household(&pool, &user_id).await;
+
+        for title in ["Dust", "Cook", "Bake", "Air"] {
+            let request = CreateTaskRequest {
+                title: title.to_string(),
+                description: None,
+                recurrence_type: RecurrenceType::Daily,
+                recurrence_value: None,
+                assigned_user_id: None,
+                assignee_ids: None,
+                target_count: Some(1),
+                time_period: None,
+                allow_exceed_target: None,
+                requires_review: None,
+                reviewer_type: None,
+                reviewer_id: None,
+                points_reward: None,
+                points_penalty: None,
+                due_time: None,
+                effort_minutes: None,
+                active_from: None,
+                active_until: None,
+                ends_on: None,
+                max_occurrences: None,
+                habit_type: None,
+                category_id: None,
+                tag_ids: None,
+                is_suggestion: None,
+            };
+            create_task(&pool, &household_id, &request, None).await.unwrap();
+        }
+
+        let page = list_tasks_page(&pool, &household_id, &TaskFilter::default(), Some(2), 1).await.unwrap();
+        assert_eq!(page.total, 4);
+        let titles: Vec<_> = page.items.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, ["Bake", "Cook"]);
+        assert!(page.has_more());
+
+        let rest = list_tasks_page(&pool, &household_id, &TaskFilter::default(), None, 2).await.unwrap();
+        assert_eq!(rest.items.len(), 2);
+        assert!(!rest.has_more());
+
+        let status_page = get_tasks_with_status_page(&pool, &household_id, &user_id, &TaskFilter::default(), Some(3), 3)
+            .await
+            .unwrap();
+        assert_eq!(status_page.total, 4);
+        assert_eq!(status_page.items.len(), 1);
+        assert_eq!(status_page.items[0].task.title, "Dust");
+    }
+
     #[tokio::test]
     async fn test_archive_task() {
         let pool = setup_test_db().await;
@@ -2245,15 +3886,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None).await.unwrap();
@@ -2276,15 +3926,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None).await.unwrap();
@@ -2313,15 +3972,24 @@ mod tests {
                 recurrence_type: RecurrenceType::Daily,
                 recurrence_value: None,
                 assigned_user_id: None,
+                assignee_ids: None,
                 target_count: Some(1),
                 time_period: None,
                 allow_exceed_target: None,
                 requires_review: None,
+                reviewer_type: None,
+                reviewer_id: None,
                 points_reward: None,
                 points_penalty: None,
                 due_time: None,
+                effort_minutes: None,
+                active_from: None,
+                active_until: None,
+                ends_on: None,
+                max_occurrences: None,
                 habit_type: None,
                 category_id: None,
+                tag_ids: None,
                 is_suggestion: None,
             };
             create_task(&pool, &household_id, &request, None).await.unwrap();
@@ -2354,15 +4022,24 @@ mod tests {
                 recurrence_type: RecurrenceType::Daily,
                 recurrence_value: None,
                 assigned_user_id: None,
+                assignee_ids: None,
                 target_count: Some(1),
                 time_period: None,
                 allow_exceed_target: None,
                 requires_review: None,
+                reviewer_type: None,
+                reviewer_id: None,
                 points_reward: None,
                 points_penalty: None,
                 due_time: None,
+                effort_minutes: None,
+                active_from: None,
+                active_until: None,
+                ends_on: None,
+                max_occurrences: None,
                 habit_type: None,
                 category_id: None,
+                tag_ids: None,
                 is_suggestion: None,
             };
             create_task(&pool, &household_id, &request, None).await.unwrap();
@@ -2430,15 +4107,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         create_task(&pool, &household1_id, &request1, None).await.unwrap();
@@ -2450,21 +4136,30 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         create_task(&pool, &household2_id, &request2, None).await.unwrap();
 
         // Get all tasks across households
-        let all_tasks = get_all_tasks_across_households(&pool, &user_id).await.unwrap();
+        let all_tasks = get_all_tasks_across_households(&pool, &user_id, &[]).await.unwrap();
 
         // Should have 2 tasks (one from each household)
         assert_eq!(all_tasks.len(), 2);
@@ -2473,6 +4168,11 @@ mod tests {
         let household_ids: Vec<_> = all_tasks.iter().map(|(_, h_id)| *h_id).collect();
         assert!(household_ids.contains(&household1_id));
         assert!(household_ids.contains(&household2_id));
+
+        // Restricting to one household only loads its tasks
+        let second_only = get_all_tasks_across_households(&pool, &user_id, &[household2_id]).await.unwrap();
+        assert_eq!(second_only.len(), 1);
+        assert_eq!(second_only[0].0.task.title, "Task in Household 2");
     }
 
     #[tokio::test]
@@ -2488,15 +4188,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task1 = create_task(&pool, &household_id, &request1, None).await.unwrap();
@@ -2507,15 +4216,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task2 = create_task(&pool, &household_id, &request2, None).await.unwrap();
@@ -2524,7 +4242,7 @@ mod tests {
         archive_task(&pool, &task2.id).await.unwrap();
 
         // Get all tasks across households
-        let all_tasks = get_all_tasks_across_households(&pool, &user_id).await.unwrap();
+        let all_tasks = get_all_tasks_across_households(&pool, &user_id, &[]).await.unwrap();
 
         // Should only have 1 task (the active one)
         assert_eq!(all_tasks.len(), 1);
@@ -2545,15 +4263,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: Some(10),
             points_penalty: Some(5),
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None).await.unwrap();
@@ -2598,15 +4325,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: Some(true),
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None).await.unwrap();
@@ -2650,15 +4386,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: Some(user_id),
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None).await.unwrap();
@@ -2730,15 +4475,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: Some(user_id),
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: Some(true),
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None).await.unwrap();
@@ -2763,15 +4517,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None, // No assignment
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: Some(true),
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None).await.unwrap();
@@ -2799,15 +4562,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: Some(user1),
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: Some(true),
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None).await.unwrap();
@@ -2832,15 +4604,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: Some(user1),
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: Some(true),
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None).await.unwrap();
@@ -2866,15 +4647,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: Some(user_id),
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None).await.unwrap();
@@ -2903,15 +4693,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: Some(user1),
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None).await.unwrap();
@@ -2940,15 +4739,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None, // No assignment
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None).await.unwrap();
@@ -2980,15 +4788,24 @@ mod tests {
             recurrence_type: RecurrenceType::OneTime,
             recurrence_value: None,
             assigned_user_id: Some(user_id),
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None)
@@ -3029,15 +4846,24 @@ mod tests {
             recurrence_type: RecurrenceType::OneTime,
             recurrence_value: None,
             assigned_user_id: Some(user_id),
+            assignee_ids: None,
             target_count: Some(2),
             time_period: None,
             allow_exceed_target: Some(true),
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None)
@@ -3094,15 +4920,24 @@ mod tests {
             recurrence_type: RecurrenceType::OneTime,
             recurrence_value: None,
             assigned_user_id: Some(user_id),
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None)
@@ -3151,15 +4986,24 @@ mod tests {
             recurrence_type: RecurrenceType::Weekdays,
             recurrence_value: Some(RecurrenceValue::Weekdays(vec![1, 3, 5])), // Mon, Wed, Fri
             assigned_user_id: Some(user_id),
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: Some(false),
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None)
@@ -3170,7 +5014,7 @@ mod tests {
         let today = chrono::Utc::now().date_naive();
         let mut tuesday = today;
         while tuesday.weekday().num_days_from_monday() != 1 {
-            tuesday = tuesday + Duration::days(1);
+            tuesday += Duration::days(1);
         }
 
         // Mock completing on Tuesday by directly inserting completion
@@ -3218,15 +5062,24 @@ mod tests {
             recurrence_type: RecurrenceType::Weekdays,
             recurrence_value: Some(RecurrenceValue::Weekdays(vec![1, 3, 5])), // Mon, Wed, Fri
             assigned_user_id: Some(user_id),
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: Some(false),
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None)
@@ -3237,7 +5090,7 @@ mod tests {
         let today = chrono::Utc::now().date_naive();
         let mut monday = today;
         while monday.weekday().num_days_from_monday() != 0 {
-            monday = monday + Duration::days(1);
+            monday += Duration::days(1);
         }
         let next_monday = monday + Duration::days(7);
 
@@ -3287,15 +5140,24 @@ mod tests {
             recurrence_type: RecurrenceType::Custom,
             recurrence_value: Some(RecurrenceValue::CustomDates(vec![feb25, feb28, mar5])),
             assigned_user_id: Some(user_id),
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: Some(false),
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None)
@@ -3344,15 +5206,24 @@ mod tests {
             recurrence_type: RecurrenceType::Weekdays,
             recurrence_value: Some(RecurrenceValue::Weekdays(vec![1, 3, 5])), // Mon, Wed, Fri
             assigned_user_id: Some(user_id),
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: Some(false),
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None)
@@ -3363,7 +5234,7 @@ mod tests {
         let today = chrono::Utc::now().date_naive();
         let mut monday = today;
         while monday.weekday().num_days_from_monday() != 0 {
-            monday = monday + Duration::days(1);
+            monday += Duration::days(1);
         }
         let next_monday = monday + Duration::days(7);
 
@@ -3415,15 +5286,24 @@ mod tests {
             recurrence_type: RecurrenceType::Weekdays,
             recurrence_value: Some(RecurrenceValue::Weekdays(vec![1, 3, 5])), // Mon, Wed, Fri
             assigned_user_id: Some(user_id),
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: Some(false),
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
         let task = create_task(&pool, &household_id, &request, None)
@@ -3434,7 +5314,7 @@ mod tests {
         let today = chrono::Utc::now().date_naive();
         let mut monday = today;
         while monday.weekday().num_days_from_monday() != 0 {
-            monday = monday + Duration::days(1);
+            monday += Duration::days(1);
         }
         let next_monday = monday + Duration::days(7);
         let wednesday = monday + Duration::days(2);
@@ -3496,15 +5376,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: Some(1),
             time_period: None,
             allow_exceed_target: Some(true),
             requires_review: Some(false),
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: Some(10i64),
             points_penalty: Some(-5i64),
             due_time: Some("09:00".to_string()),
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: Some(shared::HabitType::Good),
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
 
@@ -3533,15 +5422,24 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: None,
             target_count: None,
             time_period: None,
             allow_exceed_target: None,
             requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: None,
             category_id: None,
+            tag_ids: None,
             is_suggestion: None,
         };
 
@@ -3552,8 +5450,8 @@ mod tests {
         assert_eq!(task.title, "Simple Task");
         assert_eq!(task.description, "");
         assert_eq!(task.target_count, 1); // Default
-        assert_eq!(task.allow_exceed_target, true); // Default
-        assert_eq!(task.requires_review, false); // Default
+        assert!(task.allow_exceed_target); // Default
+        assert!(!task.requires_review); // Default
         assert_eq!(task.habit_type, shared::HabitType::Good); // Default
     }
 
@@ -3969,4 +5867,187 @@ mod tests {
         // Alice's completion should still exist
         test_utils::assert_completion_exists(&pool, &task.id, &alice_id, CompletionStatus::Approved).await;
     }
+
+    #[tokio::test]
+    async fn test_skip_current_period_records_skipped_result() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user_id = test_utils::create_test_user(&pool, "rainy@test.com", shared::Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &user_id, shared::Role::Member).await;
+
+        let task = test_utils::create_test_task(&pool, &household_id)
+            .with_title("Mow the lawn")
+            .build()
+            .await;
+
+        let result = skip_current_period(&pool, &task.id, &user_id, Some("  Raining all day "))
+            .await
+            .unwrap();
+
+        assert_eq!(result.status, PeriodStatus::Skipped);
+        assert_eq!(result.finalized_by, user_id.to_string());
+        assert_eq!(result.notes.as_deref(), Some("Raining all day"));
+        assert!(period_results::is_period_finalized(&pool, &task.id, result.period_start)
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_skip_current_period_rejects_completed_and_onetime() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let user_id = test_utils::create_test_user(&pool, "done@test.com", shared::Role::Member).await;
+        test_utils::create_test_membership(&pool, &household_id, &user_id, shared::Role::Member).await;
+
+        let task = test_utils::create_test_task(&pool, &household_id).build().await;
+        complete_task(&pool, &task.id, &user_id, &household_id).await.unwrap();
+        assert!(matches!(
+            skip_current_period(&pool, &task.id, &user_id, None).await,
+            Err(TaskError::AlreadyCompleted)
+        ));
+
+        let one_time = test_utils::create_test_task(&pool, &household_id)
+            .with_recurrence(RecurrenceType::OneTime)
+            .build()
+            .await;
+        assert!(matches!(
+            skip_current_period(&pool, &one_time.id, &user_id, None).await,
+            Err(TaskError::NotSkippable)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_override_period_corrects_past_periods_only() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let owner = test_utils::create_test_user(&pool, "corrector@test.com", shared::Role::Owner).await;
+        let task = test_utils::create_test_task(&pool, &household_id)
+            .with_recurrence(RecurrenceType::Weekly)
+            .with_recurrence_value(shared::RecurrenceValue::WeekDay(1))
+            .build()
+            .await;
+
+        // The background job marked the week of Monday 2024-03-04 failed
+        let monday = NaiveDate::from_ymd_opt(2024, 3, 4).unwrap();
+        let (start, end) = scheduler::get_period_bounds(&task, monday);
+        period_results::finalize_period(&pool, &task.id, start, end, PeriodStatus::Failed, 0, 1, "system", None)
+            .await
+            .unwrap();
+
+        let today = NaiveDate::from_ymd_opt(2024, 3, 20).unwrap();
+        let result = override_period(&pool, &task.id, monday + chrono::Duration::days(2), PeriodStatus::Completed, &owner, " Done, forgot to tick ", today)
+            .await
+            .unwrap();
+        assert_eq!(result.period_start, start);
+        assert_eq!(result.status, PeriodStatus::Completed);
+        assert_eq!(result.finalized_by, owner.to_string());
+        assert_eq!(result.notes.as_deref(), Some("Done, forgot to tick"));
+
+        // Backfilling a period the job never recorded
+        let earlier = override_period(&pool, &task.id, monday - chrono::Duration::days(7), PeriodStatus::Skipped, &owner, "Vacation", today)
+            .await
+            .unwrap();
+        assert_eq!(earlier.status, PeriodStatus::Skipped);
+
+        assert!(matches!(
+            override_period(&pool, &task.id, today, PeriodStatus::Completed, &owner, "Too early", today).await,
+            Err(TaskError::PeriodNotEnded)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_postpone_task_moves_only_current_occurrence() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let task = test_utils::create_test_task(&pool, &household_id)
+            .with_recurrence(RecurrenceType::Weekly)
+            .with_recurrence_value(shared::RecurrenceValue::WeekDay(1))
+            .build()
+            .await;
+
+        // Monday 2024-03-04 is due; move it to Wednesday
+        let monday = NaiveDate::from_ymd_opt(2024, 3, 4).unwrap();
+        let wednesday = NaiveDate::from_ymd_opt(2024, 3, 6).unwrap();
+        let postponed = postpone_task(&pool, &task.id, wednesday, monday).await.unwrap();
+
+        assert_eq!(postponed.postponed_from, Some(monday));
+        assert_eq!(postponed.postponed_to, Some(wednesday));
+        assert_eq!(scheduler::get_next_due_date(&postponed, monday, 0), Some(wednesday));
+        // The following week is untouched
+        assert_eq!(
+            scheduler::get_next_due_date(&postponed, NaiveDate::from_ymd_opt(2024, 3, 7).unwrap(), 0),
+            NaiveDate::from_ymd_opt(2024, 3, 11)
+        );
+
+        assert!(matches!(
+            postpone_task(&pool, &task.id, monday, monday).await,
+            Err(TaskError::InvalidPostponeDate)
+        ));
+
+        let one_time = test_utils::create_test_task(&pool, &household_id)
+            .with_recurrence(RecurrenceType::OneTime)
+            .build()
+            .await;
+        assert!(matches!(
+            postpone_task(&pool, &one_time.id, wednesday, monday).await,
+            Err(TaskError::NotPostponable)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update_task_rejects_outdated_version() {
+        let pool = test_utils::create_test_pool().await;
+        let household_id = test_utils::create_test_household(&pool).await;
+        let task = test_utils::create_test_task(&pool, &household_id).build().await;
+        assert_eq!(task.version, 1);
+
+        let rename = |title: &str, version: Option<i64>| UpdateTaskRequest {
+            title: Some(title.to_string()),
+            description: None,
+            recurrence_type: None,
+            recurrence_value: None,
+            assigned_user_id: None,
+            assignee_ids: None,
+            target_count: None,
+            time_period: None,
+            allow_exceed_target: None,
+            requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
+            points_reward: None,
+            points_penalty: None,
+            due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
+            habit_type: None,
+            category_id: None,
+            tag_ids: None,
+            archived: None,
+            paused: None,
+            version,
+        };
+
+        let updated = update_task(&pool, &task.id, &rename("First", Some(1))).await.unwrap();
+        assert_eq!(updated.version, 2);
+        assert_eq!(get_task(&pool, &task.id).await.unwrap().unwrap().version, 2);
+
+        // A second edit based on the original version loses the race
+        match update_task(&pool, &task.id, &rename("Second", Some(1))).await {
+            Err(TaskError::VersionConflict(current)) => {
+                assert_eq!(current.title, "First");
+                assert_eq!(current.version, 2);
+            }
+            other => panic!("expected version conflict, got {:?}", other.map(|t| t.title)),
+        }
+
+        // Without a version the update is applied unconditionally
+        let updated = update_task(&pool, &task.id, &rename("Third", None)).await.unwrap();
+        assert_eq!(updated.version, 3);
+
+        // Other changes bump the version as well
+        assert_eq!(archive_task(&pool, &task.id).await.unwrap().version, 4);
+    }
 }
@@ -0,0 +1,318 @@
+//! Household trash for deleted tasks, notes and journal entries
+//!
+//! Deleting one of these only sets `deleted_at`, so an accidental delete can be
+//! undone with the item's full history. Items stay in the trash for
+//! `TRASH_RETENTION_DAYS`, after which the trash_purge job deletes them for
+//! good, including completions and attachment files.
+
+use chrono::{DateTime, Duration, Utc};
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::services::{attachments as attachment_service, tasks as task_service};
+use shared::{AttachmentEntity, TrashItem, TrashItemKind};
+
+/// Days a deleted item can be restored before it is purged
+pub const TRASH_RETENTION_DAYS: i64 = 30;
+
+#[derive(Debug, Error)]
+pub enum TrashError {
+    #[error("Item not found in trash")]
+    NotFound,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+    #[error("Task error: {0}")]
+    Task(#[from] task_service::TaskError),
+    #[error("Attachment error: {0}")]
+    Attachment(#[from] attachment_service::AttachmentError),
+}
+
+/// Summary of a trash_purge run
+#[derive(Debug, Default)]
+pub struct TrashPurgeReport {
+    pub tasks_purged: u32,
+    pub notes_purged: u32,
+    pub journal_entries_purged: u32,
+}
+
+fn table(kind: TrashItemKind) -> &'static str {
+    match kind {
+        TrashItemKind::Task => "tasks",
+        TrashItemKind::Note => "notes",
+        TrashItemKind::JournalEntry => "journal_entries",
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct TrashRow {
+    kind: String,
+    id: String,
+    title: String,
+    deleted_at: DateTime<Utc>,
+}
+
+/// Items in the household trash, most recently deleted first. Tasks are only
+/// listed for members who may manage tasks; notes and journal entries only to
+/// their author.
+pub async fn list_trash(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    include_tasks: bool,
+) -> Result<Vec<TrashItem>, TrashError> {
+    let rows: Vec<TrashRow> = sqlx::query_as(
+        r#"
+        SELECT 'task' AS kind, id, title, deleted_at FROM tasks
+        WHERE household_id = ? AND deleted_at IS NOT NULL AND ?
+        UNION ALL
+        SELECT 'note' AS kind, id, title, deleted_at FROM notes
+        WHERE household_id = ? AND user_id = ? AND deleted_at IS NOT NULL
+        UNION ALL
+        SELECT 'journal_entry' AS kind, id,
+               CASE WHEN title = '' THEN entry_date ELSE title END AS title, deleted_at
+        FROM journal_entries
+        WHERE household_id = ? AND user_id = ? AND deleted_at IS NOT NULL
+        ORDER BY deleted_at DESC
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(include_tasks)
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|row| {
+            Some(TrashItem {
+                kind: row.kind.parse().ok()?,
+                id: Uuid::parse_str(&row.id).ok()?,
+                title: row.title,
+                deleted_at: row.deleted_at,
+                purge_at: row.deleted_at + Duration::days(TRASH_RETENTION_DAYS),
+            })
+        })
+        .collect())
+}
+
+/// Make sure the item is in this household's trash and, for notes and journal
+/// entries, was written by the user
+async fn ensure_in_trash(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    kind: TrashItemKind,
+    id: &Uuid,
+) -> Result<(), TrashError> {
+    let owner_check = match kind {
+        TrashItemKind::Task => "? IS NOT NULL",
+        TrashItemKind::Note | TrashItemKind::JournalEntry => "user_id = ?",
+    };
+    let found: Option<String> = sqlx::query_scalar(&format!(
+        "SELECT id FROM {} WHERE id = ? AND household_id = ? AND {} AND deleted_at IS NOT NULL",
+        table(kind),
+        owner_check
+    ))
+    .bind(id.to_string())
+    .bind(household_id.to_string())
+    .bind(user_id.to_string())
+    .fetch_optional(pool)
+    .await?;
+    found.map(|_| ()).ok_or(TrashError::NotFound)
+}
+
+/// Take an item out of the trash
+pub async fn restore(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    kind: TrashItemKind,
+    id: &Uuid,
+) -> Result<(), TrashError> {
+    ensure_in_trash(pool, household_id, user_id, kind, id).await?;
+    sqlx::query(&format!("UPDATE {} SET deleted_at = NULL WHERE id = ?", table(kind)))
+        .bind(id.to_string())
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// Delete an item and everything that belongs to it
+async fn purge_item(pool: &SqlitePool, upload_dir: &str, kind: TrashItemKind, id: &Uuid) -> Result<(), TrashError> {
+    match kind {
+        TrashItemKind::Task => task_service::purge_task(pool, id).await?,
+        TrashItemKind::Note | TrashItemKind::JournalEntry => {
+            let entity = match kind {
+                TrashItemKind::Note => AttachmentEntity::Note,
+                _ => AttachmentEntity::JournalEntry,
+            };
+            attachment_service::delete_for_entity(pool, upload_dir, entity, id).await?;
+            sqlx::query(&format!("DELETE FROM {} WHERE id = ?", table(kind)))
+                .bind(id.to_string())
+                .execute(pool)
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+/// Permanently delete an item from the trash before its retention ends
+pub async fn purge(
+    pool: &SqlitePool,
+    upload_dir: &str,
+    household_id: &Uuid,
+    user_id: &Uuid,
+    kind: TrashItemKind,
+    id: &Uuid,
+) -> Result<(), TrashError> {
+    ensure_in_trash(pool, household_id, user_id, kind, id).await?;
+    purge_item(pool, upload_dir, kind, id).await
+}
+
+/// Purge every item that has been in the trash longer than the retention period
+pub async fn purge_expired(
+    pool: &SqlitePool,
+    upload_dir: &str,
+    now: DateTime<Utc>,
+) -> Result<TrashPurgeReport, TrashError> {
+    let cutoff = now - Duration::days(TRASH_RETENTION_DAYS);
+    let mut report = TrashPurgeReport::default();
+
+    for kind in [TrashItemKind::Task, TrashItemKind::Note, TrashItemKind::JournalEntry] {
+        let ids: Vec<String> = sqlx::query_scalar(&format!(
+            "SELECT id FROM {} WHERE deleted_at IS NOT NULL AND deleted_at < ?",
+            table(kind)
+        ))
+        .bind(cutoff)
+        .fetch_all(pool)
+        .await?;
+
+        for id in ids.iter().filter_map(|id| Uuid::parse_str(id).ok()) {
+            purge_item(pool, upload_dir, kind, &id).await?;
+            match kind {
+                TrashItemKind::Task => report.tasks_purged += 1,
+                TrashItemKind::Note => report.notes_purged += 1,
+                TrashItemKind::JournalEntry => report.journal_entries_purged += 1,
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::notes as notes_service;
+    use crate::test_utils::{create_test_household, create_test_membership, create_test_pool, create_test_task, create_test_user};
+    use shared::{CreateNoteRequest, Role};
+
+    #[tokio::test]
+    async fn test_deleted_task_keeps_history_and_can_be_restored() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let user_id = create_test_user(&pool, "alice@test.com", Role::Owner).await;
+        create_test_membership(&pool, &household_id, &user_id, Role::Owner).await;
+        let task = create_test_task(&pool, &household_id).build().await;
+        task_service::complete_task(&pool, &task.id, &user_id, &household_id).await.unwrap();
+
+        task_service::delete_task(&pool, &task.id).await.unwrap();
+        assert!(task_service::get_task(&pool, &task.id).await.unwrap().is_none());
+        assert!(task_service::list_tasks(&pool, &household_id).await.unwrap().is_empty());
+
+        let trash = list_trash(&pool, &household_id, &user_id, true).await.unwrap();
+        assert_eq!(trash.len(), 1);
+        assert_eq!(trash[0].kind, TrashItemKind::Task);
+        assert_eq!(trash[0].purge_at - trash[0].deleted_at, Duration::days(TRASH_RETENTION_DAYS));
+        // Without task permissions the task is not listed
+        assert!(list_trash(&pool, &household_id, &user_id, false).await.unwrap().is_empty());
+
+        restore(&pool, &household_id, &user_id, TrashItemKind::Task, &task.id).await.unwrap();
+        assert!(task_service::get_task(&pool, &task.id).await.unwrap().is_some());
+        let completions: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM task_completions WHERE task_id = ?")
+            .bind(task.id.to_string())
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(completions, 1);
+
+        // Restoring again fails since the task is no longer in the trash
+        assert!(matches!(
+            restore(&pool, &household_id, &user_id, TrashItemKind::Task, &task.id).await,
+            Err(TrashError::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_notes_in_trash_belong_to_their_author() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let author = create_test_user(&pool, "alice@test.com", Role::Owner).await;
+        let other = create_test_user(&pool, "bob@test.com", Role::Member).await;
+        create_test_membership(&pool, &household_id, &author, Role::Owner).await;
+        create_test_membership(&pool, &household_id, &other, Role::Member).await;
+        let request = CreateNoteRequest {
+            title: "Wifi password".to_string(),
+            content: None,
+            is_shared: true,
+            color: None,
+        };
+        let note = notes_service::create_note(&pool, &household_id, &author, &request).await.unwrap();
+
+        notes_service::delete_note(&pool, &note.id, &author).await.unwrap();
+        assert!(notes_service::get_note(&pool, &note.id).await.unwrap().is_none());
+
+        assert!(list_trash(&pool, &household_id, &other, true).await.unwrap().is_empty());
+        assert!(matches!(
+            purge(&pool, "uploads", &household_id, &other, TrashItemKind::Note, &note.id).await,
+            Err(TrashError::NotFound)
+        ));
+
+        let trash = list_trash(&pool, &household_id, &author, false).await.unwrap();
+        assert_eq!(trash.len(), 1);
+        assert_eq!(trash[0].title, "Wifi password");
+
+        purge(&pool, "uploads", &household_id, &author, TrashItemKind::Note, &note.id).await.unwrap();
+        assert!(list_trash(&pool, &household_id, &author, false).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_purge_expired_removes_old_items_only() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let user_id = create_test_user(&pool, "alice@test.com", Role::Owner).await;
+        create_test_membership(&pool, &household_id, &user_id, Role::Owner).await;
+        let old = create_test_task(&pool, &household_id).build().await;
+        let recent = create_test_task(&pool, &household_id).build().await;
+        task_service::complete_task(&pool, &old.id, &user_id, &household_id).await.unwrap();
+
+        let now = Utc::now();
+        for (task_id, deleted_at) in [(old.id, now - Duration::days(31)), (recent.id, now - Duration::days(29))] {
+            sqlx::query("UPDATE tasks SET deleted_at = ? WHERE id = ?")
+                .bind(deleted_at)
+                .bind(task_id.to_string())
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        let report = purge_expired(&pool, "uploads", now).await.unwrap();
+        assert_eq!(report.tasks_purged, 1);
+        assert_eq!(report.notes_purged, 0);
+
+        let remaining: Vec<String> = sqlx::query_scalar("SELECT id FROM tasks")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(remaining, vec![recent.id.to_string()]);
+        let completions: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM task_completions")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(completions, 0);
+    }
+}
@@ -4,7 +4,7 @@ use thiserror::Error;
 use uuid::Uuid;
 
 use crate::models::UserSettingsRow;
-use shared::{UpdateUserSettingsRequest, UserSettings};
+use shared::{NotificationPreferences, Theme, UpdateUserSettingsRequest, UserSettings};
 
 #[derive(Debug, Error)]
 pub enum UserSettingsError {
@@ -12,14 +12,21 @@ pub enum UserSettingsError {
     DatabaseError(#[from] sqlx::Error),
     #[error("Invalid language code")]
     InvalidLanguage,
+    #[error("Quiet hours need both a start and an end time (HH:MM)")]
+    InvalidQuietHours,
 }
 
-/// Supported language codes
-const SUPPORTED_LANGUAGES: &[&str] = &["en", "de"];
-
 /// Validate that a language code is supported
 fn validate_language(lang: &str) -> bool {
-    SUPPORTED_LANGUAGES.contains(&lang)
+    shared::SUPPORTED_LANGUAGES.iter().any(|(code, _)| *code == lang)
+}
+
+/// Quiet hours must either be unset or have a valid start and end time
+fn validate_quiet_hours(preferences: &NotificationPreferences) -> bool {
+    match (&preferences.quiet_hours_start, &preferences.quiet_hours_end) {
+        (None, None) => true,
+        _ => preferences.quiet_hours().is_some(),
+    }
 }
 
 /// Get settings for a user, creating defaults if they don't exist
@@ -57,10 +64,27 @@ pub async fn get_or_create_settings(
     Ok(UserSettings {
         user_id: *user_id,
         language: default_language.to_string(),
+        theme: Theme::default(),
+        notification_preferences: NotificationPreferences::default(),
         updated_at: now,
     })
 }
 
+/// Get a user's notification preferences without creating a settings row
+pub async fn get_notification_preferences(
+    pool: &SqlitePool,
+    user_id: &Uuid,
+) -> Result<NotificationPreferences, UserSettingsError> {
+    let existing: Option<UserSettingsRow> = sqlx::query_as("SELECT * FROM user_settings WHERE user_id = ?")
+        .bind(user_id.to_string())
+        .fetch_optional(pool)
+        .await?;
+
+    Ok(existing
+        .map(|settings| settings.to_shared().notification_preferences)
+        .unwrap_or_default())
+}
+
 /// Update user settings
 pub async fn update_settings(
     pool: &SqlitePool,
@@ -78,17 +102,40 @@ pub async fn update_settings(
         settings.language = language.clone();
     }
 
+    if let Some(theme) = request.theme {
+        settings.theme = theme;
+    }
+
+    if let Some(ref preferences) = request.notification_preferences {
+        let mut preferences = preferences.clone();
+        // Treat empty time inputs as "not set"
+        preferences.quiet_hours_start = preferences.quiet_hours_start.filter(|t| !t.trim().is_empty());
+        preferences.quiet_hours_end = preferences.quiet_hours_end.filter(|t| !t.trim().is_empty());
+        if !validate_quiet_hours(&preferences) {
+            return Err(UserSettingsError::InvalidQuietHours);
+        }
+        settings.notification_preferences = preferences;
+    }
+
     let now = Utc::now();
     settings.updated_at = now;
 
     sqlx::query(
         r#"
         UPDATE user_settings
-        SET language = ?, updated_at = ?
+        SET language = ?, theme = ?, notify_chat = ?, notify_reviews = ?, notify_assignments = ?, notify_reminders = ?,
+            quiet_hours_start = ?, quiet_hours_end = ?, updated_at = ?
         WHERE user_id = ?
         "#,
     )
     .bind(&settings.language)
+    .bind(settings.theme.as_str())
+    .bind(settings.notification_preferences.chat)
+    .bind(settings.notification_preferences.reviews)
+    .bind(settings.notification_preferences.assignments)
+    .bind(settings.notification_preferences.reminders)
+    .bind(&settings.notification_preferences.quiet_hours_start)
+    .bind(&settings.notification_preferences.quiet_hours_end)
     .bind(now)
     .bind(user_id.to_string())
     .execute(pool)
@@ -109,6 +156,41 @@ mod tests {
         assert!(!validate_language(""));
     }
 
+    #[test]
+    fn test_validate_quiet_hours() {
+        let mut preferences = NotificationPreferences::default();
+        assert!(validate_quiet_hours(&preferences));
+
+        preferences.quiet_hours_start = Some("22:00".to_string());
+        assert!(!validate_quiet_hours(&preferences));
+
+        preferences.quiet_hours_end = Some("7 am".to_string());
+        assert!(!validate_quiet_hours(&preferences));
+
+        preferences.quiet_hours_end = Some("07:00".to_string());
+        assert!(validate_quiet_hours(&preferences));
+    }
+
+    #[tokio::test]
+    async fn test_theme_defaults_to_system_and_is_persisted() {
+        let pool = crate::test_utils::create_test_pool().await;
+        let user_id = crate::test_utils::create_test_user(&pool, "theme@test.com", shared::Role::Member).await;
+
+        let settings = get_or_create_settings(&pool, &user_id).await.unwrap();
+        assert_eq!(settings.theme, Theme::System);
+
+        let request = UpdateUserSettingsRequest {
+            language: None,
+            theme: Some(Theme::Dark),
+            notification_preferences: None,
+        };
+        update_settings(&pool, &user_id, &request).await.unwrap();
+
+        let settings = get_or_create_settings(&pool, &user_id).await.unwrap();
+        assert_eq!(settings.theme, Theme::Dark);
+        assert_eq!(settings.language, "en");
+    }
+
     #[test]
     fn test_user_settings_error_display() {
         let error = UserSettingsError::InvalidLanguage;
@@ -0,0 +1,330 @@
+//! Minimal Web Push client
+//!
+//! Implements VAPID authentication (RFC 8292) and `aes128gcm` payload encryption
+//! (RFC 8291) so notifications can be delivered to browser push services.
+
+use aes_gcm::aead::Aead;
+use aes_gcm::{Aes128Gcm, KeyInit, Nonce};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Duration, Utc};
+use hkdf::Hkdf;
+use p256::ecdsa::signature::Signer;
+use p256::ecdsa::{Signature, SigningKey};
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use p256::{PublicKey, SecretKey};
+use rand::RngCore;
+use sha2::Sha256;
+use thiserror::Error;
+
+use crate::models::PushSubscriptionRow;
+
+/// Record size advertised in the encryption header; payloads always fit in one record
+const RECORD_SIZE: u32 = 4096;
+
+/// How long push services should keep undelivered messages
+const TTL_SECONDS: i64 = 12 * 60 * 60;
+
+#[derive(Debug, Error)]
+pub enum WebPushError {
+    #[error("Invalid key: {0}")]
+    InvalidKey(String),
+    #[error("Encryption failed")]
+    Encryption,
+    #[error("HTTP error: {0}")]
+    Http(#[from] reqwest::Error),
+    #[error("Subscription is no longer valid")]
+    SubscriptionGone,
+    #[error("Push service responded with status {0}")]
+    PushService(u16),
+}
+
+/// VAPID key pair identifying this server to push services
+#[derive(Clone)]
+pub struct VapidKeys {
+    signing_key: SigningKey,
+    public_key: Vec<u8>,
+}
+
+impl std::fmt::Debug for VapidKeys {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VapidKeys")
+            .field("public_key", &self.public_key_base64())
+            .finish_non_exhaustive()
+    }
+}
+
+impl VapidKeys {
+    /// Load keys from a base64url-encoded raw P-256 private key (32 bytes)
+    pub fn from_base64(private_key: &str) -> Result<Self, WebPushError> {
+        let bytes = decode_base64(private_key)?;
+        let secret = SecretKey::from_slice(&bytes)
+            .map_err(|_| WebPushError::InvalidKey("VAPID private key must be a P-256 scalar".to_string()))?;
+        let public_key = secret.public_key().to_encoded_point(false).as_bytes().to_vec();
+
+        Ok(Self {
+            signing_key: SigningKey::from(secret),
+            public_key,
+        })
+    }
+
+    /// Uncompressed public key, as expected by `PushManager.subscribe({ applicationServerKey })`
+    pub fn public_key_base64(&self) -> String {
+        URL_SAFE_NO_PAD.encode(&self.public_key)
+    }
+
+    /// Build the `Authorization` header value for a request to `endpoint`
+    pub fn authorization_header(
+        &self,
+        endpoint: &str,
+        subject: &str,
+        now: DateTime<Utc>,
+    ) -> Result<String, WebPushError> {
+        let url = reqwest::Url::parse(endpoint)
+            .map_err(|_| WebPushError::InvalidKey("Invalid push endpoint".to_string()))?;
+        let audience = url.origin().ascii_serialization();
+
+        let header = URL_SAFE_NO_PAD.encode(br#"{"typ":"JWT","alg":"ES256"}"#);
+        let claims = serde_json::json!({
+            "aud": audience,
+            "exp": (now + Duration::hours(12)).timestamp(),
+            "sub": subject,
+        });
+        let claims = URL_SAFE_NO_PAD.encode(claims.to_string());
+        let signing_input = format!("{}.{}", header, claims);
+        let signature: Signature = self.signing_key.sign(signing_input.as_bytes());
+
+        Ok(format!(
+            "vapid t={}.{}, k={}",
+            signing_input,
+            URL_SAFE_NO_PAD.encode(signature.to_bytes()),
+            self.public_key_base64()
+        ))
+    }
+}
+
+/// Encrypt a payload for a subscription using the `aes128gcm` content encoding
+pub fn encrypt_payload(p256dh: &str, auth: &str, payload: &[u8]) -> Result<Vec<u8>, WebPushError> {
+    let ua_public_bytes = decode_base64(p256dh)?;
+    let auth_secret = decode_base64(auth)?;
+
+    // Fresh ephemeral key and salt for every message
+    let server_secret = SecretKey::random(&mut rand::thread_rng());
+    let mut salt = [0u8; 16];
+    rand::thread_rng().fill_bytes(&mut salt);
+
+    encrypt_with(&ua_public_bytes, &auth_secret, payload, &server_secret, &salt)
+}
+
+/// `encrypt_payload` with a given ephemeral key and salt
+fn encrypt_with(
+    ua_public_bytes: &[u8],
+    auth_secret: &[u8],
+    payload: &[u8],
+    server_secret: &SecretKey,
+    salt: &[u8; 16],
+) -> Result<Vec<u8>, WebPushError> {
+    let ua_public = PublicKey::from_sec1_bytes(ua_public_bytes)
+        .map_err(|_| WebPushError::InvalidKey("Invalid p256dh key".to_string()))?;
+
+    let server_public = server_secret.public_key().to_encoded_point(false);
+    let shared = p256::ecdh::diffie_hellman(server_secret.to_nonzero_scalar(), ua_public.as_affine());
+
+    // Combine the ECDH secret with the subscription's auth secret
+    let mut key_info = b"WebPush: info\0".to_vec();
+    key_info.extend_from_slice(ua_public_bytes);
+    key_info.extend_from_slice(server_public.as_bytes());
+    let mut ikm = [0u8; 32];
+    Hkdf::<Sha256>::new(Some(auth_secret), shared.raw_secret_bytes())
+        .expand(&key_info, &mut ikm)
+        .map_err(|_| WebPushError::Encryption)?;
+
+    // Derive content encryption key and nonce
+    let hkdf = Hkdf::<Sha256>::new(Some(salt), &ikm);
+    let mut cek = [0u8; 16];
+    let mut nonce = [0u8; 12];
+    hkdf.expand(b"Content-Encoding: aes128gcm\0", &mut cek)
+        .map_err(|_| WebPushError::Encryption)?;
+    hkdf.expand(b"Content-Encoding: nonce\0", &mut nonce)
+        .map_err(|_| WebPushError::Encryption)?;
+
+    // Single record, terminated by the last-record delimiter
+    let mut plaintext = payload.to_vec();
+    plaintext.push(0x02);
+    let cipher = Aes128Gcm::new_from_slice(&cek).map_err(|_| WebPushError::Encryption)?;
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce), plaintext.as_ref())
+        .map_err(|_| WebPushError::Encryption)?;
+
+    let mut body = Vec::with_capacity(21 + server_public.len() + ciphertext.len());
+    body.extend_from_slice(salt);
+    body.extend_from_slice(&RECORD_SIZE.to_be_bytes());
+    body.push(server_public.len() as u8);
+    body.extend_from_slice(server_public.as_bytes());
+    body.extend_from_slice(&ciphertext);
+    Ok(body)
+}
+
+/// Deliver an encrypted payload to a subscription's push service
+pub async fn send(
+    client: &reqwest::Client,
+    keys: &VapidKeys,
+    subject: &str,
+    subscription: &PushSubscriptionRow,
+    payload: &[u8],
+) -> Result<(), WebPushError> {
+    let body = encrypt_payload(&subscription.p256dh, &subscription.auth, payload)?;
+    let authorization = keys.authorization_header(&subscription.endpoint, subject, Utc::now())?;
+
+    let response = client
+        .post(&subscription.endpoint)
+        .header("Authorization", authorization)
+        .header("Content-Encoding", "aes128gcm")
+        .header("Content-Type", "application/octet-stream")
+        .header("TTL", TTL_SECONDS.to_string())
+        .body(body)
+        .send()
+        .await?;
+
+    let status = response.status().as_u16();
+    match status {
+        200..=299 => Ok(()),
+        404 | 410 => Err(WebPushError::SubscriptionGone),
+        _ => Err(WebPushError::PushService(status)),
+    }
+}
+
+/// Browsers emit unpadded base64url, but tolerate padding as well
+fn decode_base64(value: &str) -> Result<Vec<u8>, WebPushError> {
+    URL_SAFE_NO_PAD
+        .decode(value.trim().trim_end_matches('='))
+        .map_err(|_| WebPushError::InvalidKey("Invalid base64url encoding".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p256::ecdsa::signature::Verifier;
+    use p256::ecdsa::VerifyingKey;
+
+    fn test_private_key() -> String {
+        URL_SAFE_NO_PAD.encode([7u8; 32])
+    }
+
+    /// Decrypt a single-record aes128gcm body the way a browser would
+    fn decrypt(body: &[u8], ua_secret: &SecretKey, auth: &[u8]) -> Vec<u8> {
+        let salt = &body[0..16];
+        let id_len = body[20] as usize;
+        let server_public = PublicKey::from_sec1_bytes(&body[21..21 + id_len]).unwrap();
+        let ciphertext = &body[21 + id_len..];
+
+        let ua_public = ua_secret.public_key().to_encoded_point(false);
+        let shared = p256::ecdh::diffie_hellman(ua_secret.to_nonzero_scalar(), server_public.as_affine());
+
+        let mut key_info = b"WebPush: info\0".to_vec();
+        key_info.extend_from_slice(ua_public.as_bytes());
+        key_info.extend_from_slice(&body[21..21 + id_len]);
+        let mut ikm = [0u8; 32];
+        Hkdf::<Sha256>::new(Some(auth), shared.raw_secret_bytes())
+            .expand(&key_info, &mut ikm)
+            .unwrap();
+
+        let hkdf = Hkdf::<Sha256>::new(Some(salt), &ikm);
+        let mut cek = [0u8; 16];
+        let mut nonce = [0u8; 12];
+        hkdf.expand(b"Content-Encoding: aes128gcm\0", &mut cek).unwrap();
+        hkdf.expand(b"Content-Encoding: nonce\0", &mut nonce).unwrap();
+
+        let cipher = Aes128Gcm::new_from_slice(&cek).unwrap();
+        let mut plaintext = cipher.decrypt(Nonce::from_slice(&nonce), ciphertext).unwrap();
+        assert_eq!(plaintext.pop(), Some(0x02));
+        plaintext
+    }
+
+    #[test]
+    fn test_vapid_keys_from_base64() {
+        let keys = VapidKeys::from_base64(&test_private_key()).unwrap();
+        let public_key = decode_base64(&keys.public_key_base64()).unwrap();
+
+        assert_eq!(public_key.len(), 65);
+        assert_eq!(public_key[0], 0x04);
+    }
+
+    #[test]
+    fn test_vapid_keys_rejects_invalid_key() {
+        assert!(VapidKeys::from_base64("not base64!").is_err());
+        assert!(VapidKeys::from_base64(&URL_SAFE_NO_PAD.encode([0u8; 32])).is_err());
+        assert!(VapidKeys::from_base64(&URL_SAFE_NO_PAD.encode([1u8; 16])).is_err());
+    }
+
+    #[test]
+    fn test_authorization_header_signature() {
+        let keys = VapidKeys::from_base64(&test_private_key()).unwrap();
+        let now = Utc::now();
+        let header = keys
+            .authorization_header("https://push.example.com/send/abc", "mailto:test@example.com", now)
+            .unwrap();
+
+        let rest = header.strip_prefix("vapid t=").unwrap();
+        let (token, k) = rest.split_once(", k=").unwrap();
+        assert_eq!(k, keys.public_key_base64());
+
+        let parts: Vec<&str> = token.split('.').collect();
+        assert_eq!(parts.len(), 3);
+
+        let claims: serde_json::Value =
+            serde_json::from_slice(&URL_SAFE_NO_PAD.decode(parts[1]).unwrap()).unwrap();
+        assert_eq!(claims["aud"], "https://push.example.com");
+        assert_eq!(claims["sub"], "mailto:test@example.com");
+        assert_eq!(claims["exp"], (now + Duration::hours(12)).timestamp());
+
+        let signature = Signature::from_slice(&URL_SAFE_NO_PAD.decode(parts[2]).unwrap()).unwrap();
+        let verifying_key = VerifyingKey::from_sec1_bytes(&decode_base64(k).unwrap()).unwrap();
+        let signing_input = format!("{}.{}", parts[0], parts[1]);
+        assert!(verifying_key.verify(signing_input.as_bytes(), &signature).is_ok());
+    }
+
+    #[test]
+    fn test_encrypt_payload_roundtrip() {
+        let ua_secret = SecretKey::random(&mut rand::thread_rng());
+        let p256dh = URL_SAFE_NO_PAD.encode(ua_secret.public_key().to_encoded_point(false).as_bytes());
+        let auth = [3u8; 16];
+
+        let body = encrypt_payload(&p256dh, &URL_SAFE_NO_PAD.encode(auth), b"hello push").unwrap();
+
+        assert_eq!(u32::from_be_bytes(body[16..20].try_into().unwrap()), RECORD_SIZE);
+        assert_eq!(body[20], 65);
+        assert_eq!(decrypt(&body, &ua_secret, &auth), b"hello push");
+    }
+
+    /// The worked example from RFC 8291, section 5
+    #[test]
+    fn test_encrypt_matches_rfc8291_example() {
+        let server_secret = SecretKey::from_slice(&decode_base64("yfWPiYE-n46HLnH0KqZOF1fJJU3MYrct3AELtAQ-oRw").unwrap()).unwrap();
+        let ua_public = decode_base64(
+            "BCVxsr7N_eNgVRqvHtD0zTZsEc6-VV-JvLexhqUzORcxaOzi6-AYWXvTBHm4bjyPjs7Vd8pZGH6SRpkNtoIAiw4",
+        )
+        .unwrap();
+        let auth_secret = decode_base64("BTBZMqHH6r4Tts7J_aSIgg").unwrap();
+        let salt: [u8; 16] = decode_base64("DGv6ra1nlYgDCS1FRnbzlw").unwrap().try_into().unwrap();
+
+        let body = encrypt_with(&ua_public, &auth_secret, b"When I grow up, I want to be a watermelon", &server_secret, &salt).unwrap();
+
+        assert_eq!(
+            URL_SAFE_NO_PAD.encode(body),
+            "DGv6ra1nlYgDCS1FRnbzlwAAEABBBP4z9KsN6nGRTbVYI_c7VJSPQTBtkgcy27mlmlMoZIIgDll6e3vCYLocInmYWAmS6TlzAC8wEqKK6PBru3jl7A_yl95bQpu6cVPTpK4Mqgkf1CXztLVBSt2Ks3oZwbuwXPXLWyouBWLVWGNWQexSgSxsj_Qulcy4a-fN"
+        );
+    }
+
+    #[test]
+    fn test_encrypt_payload_rejects_invalid_subscription_key() {
+        let result = encrypt_payload("AAAA", &URL_SAFE_NO_PAD.encode([3u8; 16]), b"hello");
+        assert!(matches!(result, Err(WebPushError::InvalidKey(_))));
+    }
+
+    #[test]
+    fn test_decode_base64_accepts_padding() {
+        assert_eq!(decode_base64("aGk=").unwrap(), b"hi");
+        assert_eq!(decode_base64("aGk").unwrap(), b"hi");
+    }
+}
@@ -0,0 +1,366 @@
+//! Outbound webhooks for household events
+//!
+//! Owners register URLs that receive a JSON `WebhookPayload` whenever one of
+//! the subscribed events happens (task completions, reviews, reward purchases,
+//! chat messages). Each request is signed with HMAC-SHA256 over the body using
+//! the webhook's secret, so receivers like Home Assistant or n8n can verify it.
+//! Deliveries run detached from the request and are retried with exponential
+//! backoff; the outcome of the last delivery is stored on the webhook.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::models::WebhookRow;
+use shared::{CreateWebhookRequest, UpdateWebhookRequest, Webhook, WebhookEvent, WebhookPayload};
+
+/// Delivery attempts per event, including the first one
+const MAX_ATTEMPTS: u32 = 4;
+/// Delay before the first retry; doubled for every further retry
+const INITIAL_BACKOFF_SECS: u64 = 5;
+const REQUEST_TIMEOUT_SECS: u64 = 10;
+
+pub const EVENT_HEADER: &str = "X-Haushalt-Event";
+pub const SIGNATURE_HEADER: &str = "X-Haushalt-Signature";
+pub const DELIVERY_HEADER: &str = "X-Haushalt-Delivery";
+
+#[derive(Debug, Error)]
+pub enum WebhookError {
+    #[error("Webhook not found")]
+    NotFound,
+    #[error("URL must be an absolute http or https URL")]
+    InvalidUrl,
+    #[error("Secret must not be empty")]
+    EmptySecret,
+    #[error("Select at least one event")]
+    NoEvents,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+}
+
+fn validate_url(url: &str) -> Result<String, WebhookError> {
+    let url = url.trim();
+    match reqwest::Url::parse(url) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") && parsed.host().is_some() => Ok(url.to_string()),
+        _ => Err(WebhookError::InvalidUrl),
+    }
+}
+
+fn validate_secret(secret: &str) -> Result<(), WebhookError> {
+    if secret.trim().is_empty() {
+        return Err(WebhookError::EmptySecret);
+    }
+    Ok(())
+}
+
+/// Comma-separated, de-duplicated event list as stored in the database
+fn encode_events(events: &[WebhookEvent]) -> Result<String, WebhookError> {
+    let mut names: Vec<&str> = Vec::new();
+    for event in events {
+        if !names.contains(&event.as_str()) {
+            names.push(event.as_str());
+        }
+    }
+    if names.is_empty() {
+        return Err(WebhookError::NoEvents);
+    }
+    Ok(names.join(","))
+}
+
+/// `sha256=<hex>` signature of `body` keyed with `secret`
+pub fn sign(secret: &str, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(body);
+    format!("sha256={:x}", mac.finalize().into_bytes())
+}
+
+/// Delay before retry number `retry` (0-based)
+fn backoff(retry: u32) -> Duration {
+    Duration::from_secs(INITIAL_BACKOFF_SECS << retry)
+}
+
+pub async fn create_webhook(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    created_by: &Uuid,
+    request: &CreateWebhookRequest,
+) -> Result<Webhook, WebhookError> {
+    let url = validate_url(&request.url)?;
+    validate_secret(&request.secret)?;
+    let events = encode_events(&request.events)?;
+
+    let row = WebhookRow {
+        id: Uuid::new_v4().to_string(),
+        household_id: household_id.to_string(),
+        url,
+        secret: request.secret.clone(),
+        events,
+        enabled: true,
+        created_by: created_by.to_string(),
+        created_at: Utc::now(),
+        last_delivery_at: None,
+        last_error: None,
+    };
+
+    sqlx::query(
+        r#"
+        INSERT INTO webhooks (id, household_id, url, secret, events, enabled, created_by, created_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(&row.id)
+    .bind(&row.household_id)
+    .bind(&row.url)
+    .bind(&row.secret)
+    .bind(&row.events)
+    .bind(row.enabled)
+    .bind(&row.created_by)
+    .bind(row.created_at)
+    .execute(pool)
+    .await?;
+
+    Ok(row.to_shared())
+}
+
+async fn get_webhook_row(pool: &SqlitePool, webhook_id: &Uuid) -> Result<Option<WebhookRow>, WebhookError> {
+    let row: Option<WebhookRow> = sqlx::query_as("SELECT * FROM webhooks WHERE id = ?")
+        .bind(webhook_id.to_string())
+        .fetch_optional(pool)
+        .await?;
+    Ok(row)
+}
+
+pub async fn get_webhook(pool: &SqlitePool, webhook_id: &Uuid) -> Result<Option<Webhook>, WebhookError> {
+    Ok(get_webhook_row(pool, webhook_id).await?.map(|row| row.to_shared()))
+}
+
+pub async fn list_webhooks(pool: &SqlitePool, household_id: &Uuid) -> Result<Vec<Webhook>, WebhookError> {
+    let rows: Vec<WebhookRow> = sqlx::query_as("SELECT * FROM webhooks WHERE household_id = ? ORDER BY created_at")
+        .bind(household_id.to_string())
+        .fetch_all(pool)
+        .await?;
+    Ok(rows.iter().map(|row| row.to_shared()).collect())
+}
+
+pub async fn update_webhook(
+    pool: &SqlitePool,
+    webhook_id: &Uuid,
+    request: &UpdateWebhookRequest,
+) -> Result<Webhook, WebhookError> {
+    let mut row = get_webhook_row(pool, webhook_id).await?.ok_or(WebhookError::NotFound)?;
+
+    if let Some(ref url) = request.url {
+        row.url = validate_url(url)?;
+    }
+    if let Some(ref secret) = request.secret {
+        validate_secret(secret)?;
+        row.secret = secret.clone();
+    }
+    if let Some(ref events) = request.events {
+        row.events = encode_events(events)?;
+    }
+    if let Some(enabled) = request.enabled {
+        row.enabled = enabled;
+    }
+
+    sqlx::query("UPDATE webhooks SET url = ?, secret = ?, events = ?, enabled = ? WHERE id = ?")
+        .bind(&row.url)
+        .bind(&row.secret)
+        .bind(&row.events)
+        .bind(row.enabled)
+        .bind(&row.id)
+        .execute(pool)
+        .await?;
+
+    Ok(row.to_shared())
+}
+
+pub async fn delete_webhook(pool: &SqlitePool, webhook_id: &Uuid) -> Result<(), WebhookError> {
+    let result = sqlx::query("DELETE FROM webhooks WHERE id = ?")
+        .bind(webhook_id.to_string())
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(WebhookError::NotFound);
+    }
+    Ok(())
+}
+
+/// Enabled webhooks of a household that subscribe to `event`
+async fn subscribed_webhooks(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    event: WebhookEvent,
+) -> Result<Vec<WebhookRow>, WebhookError> {
+    let rows: Vec<WebhookRow> = sqlx::query_as("SELECT * FROM webhooks WHERE household_id = ? AND enabled = 1")
+        .bind(household_id.to_string())
+        .fetch_all(pool)
+        .await?;
+    Ok(rows.into_iter().filter(|row| row.subscribes_to(event)).collect())
+}
+
+/// Send `event` to every subscribed webhook of the household.
+/// Deliveries run in the background; failures are logged and never reach the caller.
+pub async fn dispatch(pool: &SqlitePool, household_id: &Uuid, event: WebhookEvent, data: serde_json::Value) {
+    let webhooks = match subscribed_webhooks(pool, household_id, event).await {
+        Ok(webhooks) => webhooks,
+        Err(e) => {
+            log::warn!("Failed to load webhooks for {}: {}", event.as_str(), e);
+            return;
+        }
+    };
+    if webhooks.is_empty() {
+        return;
+    }
+
+    let payload = WebhookPayload {
+        id: Uuid::new_v4(),
+        event,
+        household_id: *household_id,
+        occurred_at: Utc::now(),
+        data,
+    };
+    let body = serde_json::to_vec(&payload).unwrap_or_default();
+
+    for webhook in webhooks {
+        let pool = pool.clone();
+        let body = body.clone();
+        tokio::spawn(async move {
+            let result = deliver_with_retries(&webhook, payload.id, event, &body).await;
+            record_delivery(&pool, &webhook.id, result.err()).await;
+        });
+    }
+}
+
+/// POST the signed body, retrying with exponential backoff on network errors and non-2xx responses
+async fn deliver_with_retries(
+    webhook: &WebhookRow,
+    delivery_id: Uuid,
+    event: WebhookEvent,
+    body: &[u8],
+) -> Result<(), String> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+        .build()
+        .map_err(|e| e.to_string())?;
+    let signature = sign(&webhook.secret, body);
+
+    let mut last_error = String::new();
+    for attempt in 0..MAX_ATTEMPTS {
+        if attempt > 0 {
+            tokio::time::sleep(backoff(attempt - 1)).await;
+        }
+
+        let response = client
+            .post(&webhook.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(EVENT_HEADER, event.as_str())
+            .header(SIGNATURE_HEADER, &signature)
+            .header(DELIVERY_HEADER, delivery_id.to_string())
+            .body(body.to_vec())
+            .send()
+            .await;
+
+        match response {
+            Ok(response) if response.status().is_success() => return Ok(()),
+            Ok(response) => last_error = format!("HTTP {}", response.status()),
+            Err(e) => last_error = e.to_string(),
+        }
+        log::warn!(
+            "Webhook {} delivery attempt {}/{} failed: {}",
+            webhook.id,
+            attempt + 1,
+            MAX_ATTEMPTS,
+            last_error
+        );
+    }
+
+    Err(last_error)
+}
+
+async fn record_delivery(pool: &SqlitePool, webhook_id: &str, error: Option<String>) {
+    if let Err(e) = sqlx::query("UPDATE webhooks SET last_delivery_at = ?, last_error = ? WHERE id = ?")
+        .bind(Utc::now())
+        .bind(error)
+        .bind(webhook_id)
+        .execute(pool)
+        .await
+    {
+        log::warn!("Failed to record webhook delivery: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{create_test_household, create_test_pool, create_test_user};
+    use shared::Role;
+
+    fn request(url: &str, events: Vec<WebhookEvent>) -> CreateWebhookRequest {
+        CreateWebhookRequest {
+            url: url.to_string(),
+            secret: "s3cret".to_string(),
+            events,
+        }
+    }
+
+    #[test]
+    fn test_sign_matches_rfc4231_vector() {
+        assert_eq!(
+            sign("Jefe", b"what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_backoff_doubles() {
+        assert_eq!(backoff(0), Duration::from_secs(5));
+        assert_eq!(backoff(1), Duration::from_secs(10));
+        assert_eq!(backoff(2), Duration::from_secs(20));
+    }
+
+    #[tokio::test]
+    async fn test_create_validates_and_filters_by_event() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let owner_id = create_test_user(&pool, "hooks@test.com", Role::Owner).await;
+
+        assert!(matches!(
+            create_webhook(&pool, &household_id, &owner_id, &request("ftp://example.com", vec![WebhookEvent::ChatMessage])).await,
+            Err(WebhookError::InvalidUrl)
+        ));
+        assert!(matches!(
+            create_webhook(&pool, &household_id, &owner_id, &request("https://example.com/hook", vec![])).await,
+            Err(WebhookError::NoEvents)
+        ));
+
+        let webhook = create_webhook(
+            &pool,
+            &household_id,
+            &owner_id,
+            &request(
+                "https://example.com/hook",
+                vec![WebhookEvent::TaskCompleted, WebhookEvent::TaskCompleted, WebhookEvent::ChatMessage],
+            ),
+        )
+        .await
+        .unwrap();
+        assert_eq!(webhook.events, vec![WebhookEvent::TaskCompleted, WebhookEvent::ChatMessage]);
+
+        let matching = subscribed_webhooks(&pool, &household_id, WebhookEvent::ChatMessage).await.unwrap();
+        assert_eq!(matching.len(), 1);
+        let none = subscribed_webhooks(&pool, &household_id, WebhookEvent::RewardPurchased).await.unwrap();
+        assert!(none.is_empty());
+
+        let update = UpdateWebhookRequest { enabled: Some(false), ..Default::default() };
+        update_webhook(&pool, &webhook.id, &update).await.unwrap();
+        let disabled = subscribed_webhooks(&pool, &household_id, WebhookEvent::ChatMessage).await.unwrap();
+        assert!(disabled.is_empty());
+    }
+}
@@ -1,9 +1,17 @@
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use tokio::sync::{mpsc, RwLock};
+use std::time::Duration;
+use tokio::sync::{mpsc, watch, RwLock};
+use tokio::time::Instant;
 use uuid::Uuid;
 
-use shared::{ChatMessageWithUser, WsServerMessage};
+use shared::{
+    ChatMessageWithUser, ChatReactionSummary, ChatReadMarker, LeaderboardEntry, PetCareEntry, PointGoalProgress, WsEventCategory,
+    WsServerMessage,
+};
+
+/// Typing notices of a session are relayed at most this often
+const TYPING_THROTTLE: Duration = Duration::from_secs(2);
 
 /// Sender for WebSocket messages
 pub type WsSender = mpsc::UnboundedSender<WsServerMessage>;
@@ -14,26 +22,52 @@ pub struct ClientSession {
     pub sender: WsSender,
     pub user_id: Option<Uuid>,
     pub username: Option<String>,
+    /// Chat room the session sends messages to
     pub household_id: Option<Uuid>,
+    /// When the session's last typing notice was relayed
+    pub last_typing: Option<Instant>,
 }
 
 /// WebSocket connection manager
-/// Manages all active WebSocket connections and chat rooms
+/// Manages all active WebSocket connections, chat rooms and event subscriptions
 pub struct WsManager {
     /// Map of session_id -> ClientSession
     sessions: RwLock<HashMap<Uuid, ClientSession>>,
-    /// Map of household_id -> set of session_ids
-    rooms: RwLock<HashMap<Uuid, HashSet<Uuid>>>,
+    /// Map of household_id -> session_id -> subscribed event categories
+    subscriptions: RwLock<HashMap<Uuid, HashMap<Uuid, HashSet<WsEventCategory>>>>,
+    /// Flips to true when the server shuts down; sessions then close themselves
+    shutdown: watch::Sender<bool>,
 }
 
 impl WsManager {
     pub fn new() -> Arc<Self> {
         Arc::new(Self {
             sessions: RwLock::new(HashMap::new()),
-            rooms: RwLock::new(HashMap::new()),
+            subscriptions: RwLock::new(HashMap::new()),
+            shutdown: watch::channel(false).0,
         })
     }
 
+    /// Receiver that changes to true once `close_all` is called
+    pub fn shutdown_signal(&self) -> watch::Receiver<bool> {
+        self.shutdown.subscribe()
+    }
+
+    /// Ask every session to close with a going-away frame and wait (up to
+    /// `timeout`) until they have disconnected
+    pub async fn close_all(&self, timeout: Duration) {
+        self.shutdown.send_replace(true);
+
+        let deadline = tokio::time::Instant::now() + timeout;
+        while !self.sessions.read().await.is_empty() {
+            if tokio::time::Instant::now() >= deadline {
+                log::warn!("{} WebSocket sessions did not close in time", self.sessions.read().await.len());
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    }
+
     /// Register a new WebSocket session
     pub async fn register(&self, session_id: Uuid, sender: WsSender) {
         let session = ClientSession {
@@ -41,6 +75,7 @@ impl WsManager {
             user_id: None,
             username: None,
             household_id: None,
+            last_typing: None,
         };
         self.sessions.write().await.insert(session_id, session);
         log::debug!("WebSocket session registered: {}", session_id);
@@ -101,18 +136,17 @@ impl WsManager {
             session.household_id = Some(household_id);
             drop(sessions);
 
-            // Add to room
-            let mut rooms = self.rooms.write().await;
-            rooms
+            // Joining a room subscribes to all of the household's events
+            self.subscriptions
+                .write()
+                .await
                 .entry(household_id)
-                .or_insert_with(HashSet::new)
-                .insert(*session_id);
+                .or_default()
+                .insert(*session_id, WsEventCategory::ALL.into_iter().collect());
 
             // Send joined response
-            let sessions = self.sessions.read().await;
-            if let Some(session) = sessions.get(session_id) {
-                let _ = session.sender.send(WsServerMessage::JoinedRoom { household_id });
-            }
+            self.send_to_session(session_id, WsServerMessage::JoinedRoom { household_id }).await;
+            self.broadcast_presence(&household_id).await;
 
             log::debug!("Session {} joined room {}", session_id, household_id);
             true
@@ -128,33 +162,107 @@ impl WsManager {
             if let Some(household_id) = session.household_id.take() {
                 drop(sessions);
 
-                // Remove from room
-                let mut rooms = self.rooms.write().await;
-                if let Some(room) = rooms.get_mut(&household_id) {
-                    room.remove(session_id);
-                    if room.is_empty() {
-                        rooms.remove(&household_id);
-                    }
-                }
+                self.remove_subscription(session_id, &household_id, &WsEventCategory::ALL).await;
 
                 // Send left response
-                let sessions = self.sessions.read().await;
-                if let Some(session) = sessions.get(session_id) {
-                    let _ = session.sender.send(WsServerMessage::LeftRoom);
-                }
+                self.send_to_session(session_id, WsServerMessage::LeftRoom).await;
+                self.broadcast_presence(&household_id).await;
 
                 log::debug!("Session {} left room {}", session_id, household_id);
             }
         }
     }
 
+    /// Receive the given event categories of a household (all if empty).
+    /// Returns false if the session is not authenticated.
+    pub async fn subscribe(&self, session_id: &Uuid, household_id: Uuid, categories: &[WsEventCategory]) -> bool {
+        if self.get_session_user(session_id).await.is_none() {
+            self.send_to_session(
+                session_id,
+                WsServerMessage::Error {
+                    code: "not_authenticated".to_string(),
+                    message: "You must authenticate before subscribing".to_string(),
+                },
+            )
+            .await;
+            return false;
+        }
+
+        let categories = if categories.is_empty() { &WsEventCategory::ALL[..] } else { categories };
+        let (current, joined) = {
+            let mut subscriptions = self.subscriptions.write().await;
+            let sessions = subscriptions.entry(household_id).or_default();
+            let joined = !sessions.contains_key(session_id);
+            let subscribed = sessions.entry(*session_id).or_default();
+            subscribed.extend(categories.iter().copied());
+            (sorted_categories(subscribed), joined)
+        };
+
+        self.send_to_session(session_id, WsServerMessage::SubscriptionUpdated { household_id, categories: current })
+            .await;
+        if joined || categories.contains(&WsEventCategory::Chat) {
+            self.broadcast_presence(&household_id).await;
+        }
+        log::debug!("Session {} subscribed to {:?} of {}", session_id, categories, household_id);
+        true
+    }
+
+    /// Stop receiving the given event categories of a household (all if empty)
+    pub async fn unsubscribe(&self, session_id: &Uuid, household_id: Uuid, categories: &[WsEventCategory]) {
+        let categories = if categories.is_empty() { &WsEventCategory::ALL[..] } else { categories };
+        let current = self.remove_subscription(session_id, &household_id, categories).await;
+        let left = current.is_empty();
+        self.send_to_session(session_id, WsServerMessage::SubscriptionUpdated { household_id, categories: current })
+            .await;
+        if left {
+            self.broadcast_presence(&household_id).await;
+        }
+    }
+
+    /// Remove categories from a session's subscription and return what is left
+    async fn remove_subscription(
+        &self,
+        session_id: &Uuid,
+        household_id: &Uuid,
+        categories: &[WsEventCategory],
+    ) -> Vec<WsEventCategory> {
+        let mut subscriptions = self.subscriptions.write().await;
+        let Some(sessions) = subscriptions.get_mut(household_id) else {
+            return Vec::new();
+        };
+        let Some(subscribed) = sessions.get_mut(session_id) else {
+            return Vec::new();
+        };
+        for category in categories {
+            subscribed.remove(category);
+        }
+        let current = sorted_categories(subscribed);
+        if current.is_empty() {
+            sessions.remove(session_id);
+            if sessions.is_empty() {
+                subscriptions.remove(household_id);
+            }
+        }
+        current
+    }
+
     /// Disconnect a session completely
     pub async fn disconnect(&self, session_id: &Uuid) {
         // Leave any room first
         self.leave_room(session_id).await;
+        let mut left = Vec::new();
+        self.subscriptions.write().await.retain(|household_id, sessions| {
+            if sessions.remove(session_id).is_some() {
+                left.push(*household_id);
+            }
+            !sessions.is_empty()
+        });
 
         // Remove session
         self.sessions.write().await.remove(session_id);
+        for household_id in left {
+            self.broadcast_presence(&household_id).await;
+        }
         log::debug!("WebSocket session disconnected: {}", session_id);
     }
 
@@ -166,12 +274,16 @@ impl WsManager {
         }
     }
 
-    /// Broadcast a message to all sessions in a room (household)
+    /// Broadcast a household event to the sessions subscribed to its category
     pub async fn broadcast_to_room(&self, household_id: &Uuid, message: WsServerMessage) {
-        let rooms = self.rooms.read().await;
-        if let Some(session_ids) = rooms.get(household_id) {
+        let subscriptions = self.subscriptions.read().await;
+        if let Some(subscribers) = subscriptions.get(household_id) {
+            let category = message.category();
             let sessions = self.sessions.read().await;
-            for session_id in session_ids {
+            for (session_id, categories) in subscribers {
+                if category.is_some_and(|category| !categories.contains(&category)) {
+                    continue;
+                }
                 if let Some(session) = sessions.get(session_id) {
                     let _ = session.sender.send(message.clone());
                 }
@@ -209,19 +321,180 @@ impl WsManager {
         .await;
     }
 
-    /// Get the number of sessions in a room
+    /// Broadcast changed reactions on a message to a room
+    pub async fn broadcast_reactions_updated(
+        &self,
+        household_id: &Uuid,
+        message_id: Uuid,
+        reactions: Vec<ChatReactionSummary>,
+    ) {
+        self.broadcast_to_room(
+            household_id,
+            WsServerMessage::ReactionsUpdated {
+                household_id: *household_id,
+                message_id,
+                reactions,
+            },
+        )
+        .await;
+    }
+
+    /// Broadcast a member's new read position to a room
+    pub async fn broadcast_messages_read(&self, household_id: &Uuid, marker: ChatReadMarker) {
+        self.broadcast_to_room(household_id, WsServerMessage::MessagesRead { marker })
+            .await;
+    }
+
+    /// Broadcast the household's updated leaderboard to a room
+    pub async fn broadcast_leaderboard_updated(&self, household_id: &Uuid, leaderboard: Vec<LeaderboardEntry>) {
+        self.broadcast_to_room(
+            household_id,
+            WsServerMessage::LeaderboardUpdated {
+                household_id: *household_id,
+                leaderboard,
+            },
+        )
+        .await;
+    }
+
+    /// Broadcast the progress of a member's point goal to a room
+    pub async fn broadcast_goal_progress_updated(&self, household_id: &Uuid, progress: PointGoalProgress) {
+        self.broadcast_to_room(
+            household_id,
+            WsServerMessage::GoalProgressUpdated {
+                household_id: *household_id,
+                progress,
+            },
+        )
+        .await;
+    }
+
+    /// Broadcast that a member gifted one of their rewards to another member
+    pub async fn broadcast_reward_gifted(
+        &self,
+        household_id: &Uuid,
+        reward_id: Uuid,
+        reward_name: String,
+        from_user_id: Uuid,
+        to_user_id: Uuid,
+    ) {
+        self.broadcast_to_room(
+            household_id,
+            WsServerMessage::RewardGifted {
+                household_id: *household_id,
+                reward_id,
+                reward_name,
+                from_user_id,
+                to_user_id,
+            },
+        )
+        .await;
+    }
+
+    /// Broadcast that a member fed a pet or gave it its medication
+    pub async fn broadcast_pet_care_logged(&self, household_id: &Uuid, entry: PetCareEntry) {
+        self.broadcast_to_room(
+            household_id,
+            WsServerMessage::PetCareLogged {
+                household_id: *household_id,
+                entry,
+            },
+        )
+        .await;
+    }
+
+    /// Tell the household's other chat subscribers that the session's user is typing.
+    /// Requires a subscription to the household; notices within the throttle interval are dropped.
+    /// Returns whether the notice was relayed.
+    pub async fn relay_typing(&self, session_id: &Uuid, household_id: Uuid) -> bool {
+        let subscriptions = self.subscriptions.read().await;
+        let Some(subscribers) = subscriptions.get(&household_id).filter(|s| s.contains_key(session_id)) else {
+            return false;
+        };
+
+        let mut sessions = self.sessions.write().await;
+        let Some(session) = sessions.get_mut(session_id) else {
+            return false;
+        };
+        let (Some(user_id), Some(username)) = (session.user_id, session.username.clone()) else {
+            return false;
+        };
+        if session.last_typing.is_some_and(|last| last.elapsed() < TYPING_THROTTLE) {
+            return false;
+        }
+        session.last_typing = Some(Instant::now());
+
+        let message = WsServerMessage::UserTyping { household_id, user_id, username };
+        for (subscriber, categories) in subscribers {
+            if !categories.contains(&WsEventCategory::Chat) {
+                continue;
+            }
+            // Other members only, including none of the typist's own devices
+            if let Some(session) = sessions.get(subscriber).filter(|s| s.user_id != Some(user_id)) {
+                let _ = session.sender.send(message.clone());
+            }
+        }
+        true
+    }
+
+    /// Users with at least one session subscribed to a household, sorted
+    pub async fn online_users(&self, household_id: &Uuid) -> Vec<Uuid> {
+        let subscriptions = self.subscriptions.read().await;
+        let Some(subscribers) = subscriptions.get(household_id) else {
+            return Vec::new();
+        };
+        let sessions = self.sessions.read().await;
+        let mut user_ids: Vec<Uuid> = subscribers
+            .keys()
+            .filter_map(|session_id| sessions.get(session_id).and_then(|s| s.user_id))
+            .collect();
+        user_ids.sort();
+        user_ids.dedup();
+        user_ids
+    }
+
+    /// Broadcast who is currently connected to a household; call when sessions join or leave it
+    async fn broadcast_presence(&self, household_id: &Uuid) {
+        let online_user_ids = self.online_users(household_id).await;
+        self.broadcast_to_room(
+            household_id,
+            WsServerMessage::PresenceUpdated {
+                household_id: *household_id,
+                online_user_ids,
+            },
+        )
+        .await;
+    }
+
+    /// Send a message to every authenticated session of a user, whichever room they are in
+    pub async fn send_to_user(&self, user_id: &Uuid, message: WsServerMessage) {
+        let sessions = self.sessions.read().await;
+        for session in sessions.values() {
+            if session.user_id.as_ref() == Some(user_id) {
+                let _ = session.sender.send(message.clone());
+            }
+        }
+    }
+
+    /// Get the number of sessions subscribed to a household
     #[allow(dead_code)]
     pub async fn room_size(&self, household_id: &Uuid) -> usize {
-        let rooms = self.rooms.read().await;
-        rooms.get(household_id).map(|s| s.len()).unwrap_or(0)
+        let subscriptions = self.subscriptions.read().await;
+        subscriptions.get(household_id).map(|s| s.len()).unwrap_or(0)
     }
 }
 
+/// Categories in a stable order for responses
+fn sorted_categories(categories: &HashSet<WsEventCategory>) -> Vec<WsEventCategory> {
+    WsEventCategory::ALL.into_iter().filter(|category| categories.contains(category)).collect()
+}
+
 impl Default for WsManager {
     fn default() -> Self {
         Self {
             sessions: RwLock::new(HashMap::new()),
-            rooms: RwLock::new(HashMap::new()),
+            subscriptions: RwLock::new(HashMap::new()),
+            shutdown: watch::channel(false).0,
         }
     }
 }
@@ -247,6 +520,25 @@ mod tests {
         assert!(!manager.sessions.read().await.contains_key(&session_id));
     }
 
+    #[tokio::test]
+    async fn test_ws_manager_close_all_signals_sessions() {
+        let manager = WsManager::new();
+        let session_id = Uuid::new_v4();
+        let (tx, _rx) = mpsc::unbounded_channel();
+        manager.register(session_id, tx).await;
+
+        // Stand-in for a connection task that disconnects once told to
+        let mut shutdown = manager.shutdown_signal();
+        let session_manager = manager.clone();
+        tokio::spawn(async move {
+            shutdown.wait_for(|down| *down).await.unwrap();
+            session_manager.disconnect(&session_id).await;
+        });
+
+        manager.close_all(Duration::from_secs(5)).await;
+        assert!(manager.sessions.read().await.is_empty());
+    }
+
     #[tokio::test]
     async fn test_ws_manager_authenticate() {
         let manager = WsManager::new();
@@ -286,4 +578,173 @@ mod tests {
         manager.leave_room(&session_id).await;
         assert_eq!(manager.room_size(&household_id).await, 0);
     }
+
+    #[tokio::test]
+    async fn test_ws_manager_broadcast_leaderboard_updated() {
+        let manager = WsManager::new();
+        let session_id = Uuid::new_v4();
+        let household_id = Uuid::new_v4();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        manager.register(session_id, tx).await;
+        manager.authenticate(&session_id, Uuid::new_v4(), "testuser".to_string()).await;
+        manager.join_room(&session_id, household_id).await;
+        while rx.try_recv().is_ok() {}
+
+        manager.broadcast_leaderboard_updated(&household_id, Vec::new()).await;
+
+        match rx.try_recv() {
+            Ok(WsServerMessage::LeaderboardUpdated { household_id: id, leaderboard }) => {
+                assert_eq!(id, household_id);
+                assert!(leaderboard.is_empty());
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ws_manager_subscriptions_filter_by_category() {
+        let manager = WsManager::new();
+        let household_id = Uuid::new_v4();
+        let other_household = Uuid::new_v4();
+        let session_id = Uuid::new_v4();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        manager.register(session_id, tx).await;
+        assert!(!manager.subscribe(&session_id, household_id, &[]).await);
+        manager.authenticate(&session_id, Uuid::new_v4(), "testuser".to_string()).await;
+        while rx.try_recv().is_ok() {}
+
+        assert!(manager.subscribe(&session_id, household_id, &[WsEventCategory::Points]).await);
+        assert!(manager.subscribe(&session_id, other_household, &[]).await);
+        match rx.try_recv() {
+            Ok(WsServerMessage::SubscriptionUpdated { household_id: id, categories }) => {
+                assert_eq!(id, household_id);
+                assert_eq!(categories, vec![WsEventCategory::Points]);
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+        while rx.try_recv().is_ok() {}
+
+        // Chat of a household subscribed for points only stays quiet
+        manager.broadcast_message_deleted(&household_id, Uuid::new_v4()).await;
+        assert!(rx.try_recv().is_err());
+        manager.broadcast_leaderboard_updated(&household_id, Vec::new()).await;
+        assert!(matches!(rx.try_recv(), Ok(WsServerMessage::LeaderboardUpdated { .. })));
+        manager.broadcast_message_deleted(&other_household, Uuid::new_v4()).await;
+        assert!(matches!(rx.try_recv(), Ok(WsServerMessage::MessageDeleted { .. })));
+
+        manager.unsubscribe(&session_id, other_household, &[WsEventCategory::Chat]).await;
+        match rx.try_recv() {
+            Ok(WsServerMessage::SubscriptionUpdated { categories, .. }) => {
+                assert_eq!(categories, vec![WsEventCategory::Points, WsEventCategory::Rewards, WsEventCategory::Pets]);
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+        manager.broadcast_message_deleted(&other_household, Uuid::new_v4()).await;
+        assert!(rx.try_recv().is_err());
+
+        manager.unsubscribe(&session_id, household_id, &[]).await;
+        assert_eq!(manager.room_size(&household_id).await, 0);
+        manager.disconnect(&session_id).await;
+        assert_eq!(manager.room_size(&other_household).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_ws_manager_broadcasts_presence() {
+        let manager = WsManager::new();
+        let household_id = Uuid::new_v4();
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+        let (alice_session, bob_session) = (Uuid::new_v4(), Uuid::new_v4());
+        let (alice_tx, mut alice_rx) = mpsc::unbounded_channel();
+        let (bob_tx, _bob_rx) = mpsc::unbounded_channel();
+
+        manager.register(alice_session, alice_tx).await;
+        manager.register(bob_session, bob_tx).await;
+        manager.authenticate(&alice_session, alice, "alice".to_string()).await;
+        manager.authenticate(&bob_session, bob, "bob".to_string()).await;
+        manager.subscribe(&alice_session, household_id, &[WsEventCategory::Chat]).await;
+        while alice_rx.try_recv().is_ok() {}
+
+        // Bob only follows points, but still counts as online
+        manager.subscribe(&bob_session, household_id, &[WsEventCategory::Points]).await;
+        let mut expected = vec![alice, bob];
+        expected.sort();
+        match alice_rx.try_recv() {
+            Ok(WsServerMessage::PresenceUpdated { household_id: id, online_user_ids }) => {
+                assert_eq!(id, household_id);
+                assert_eq!(online_user_ids, expected);
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+        assert_eq!(manager.online_users(&household_id).await, expected);
+
+        manager.disconnect(&bob_session).await;
+        match alice_rx.try_recv() {
+            Ok(WsServerMessage::PresenceUpdated { online_user_ids, .. }) => assert_eq!(online_user_ids, vec![alice]),
+            other => panic!("unexpected message: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ws_manager_relays_typing_throttled() {
+        let manager = WsManager::new();
+        let household_id = Uuid::new_v4();
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+        let (alice_session, bob_session) = (Uuid::new_v4(), Uuid::new_v4());
+        let (alice_tx, mut alice_rx) = mpsc::unbounded_channel();
+        let (bob_tx, mut bob_rx) = mpsc::unbounded_channel();
+
+        manager.register(alice_session, alice_tx).await;
+        manager.register(bob_session, bob_tx).await;
+        manager.authenticate(&alice_session, alice, "alice".to_string()).await;
+        manager.authenticate(&bob_session, bob, "bob".to_string()).await;
+
+        // Not subscribed to the household yet
+        assert!(!manager.relay_typing(&alice_session, household_id).await);
+
+        manager.subscribe(&alice_session, household_id, &[WsEventCategory::Chat]).await;
+        manager.subscribe(&bob_session, household_id, &[WsEventCategory::Chat]).await;
+        while alice_rx.try_recv().is_ok() {}
+        while bob_rx.try_recv().is_ok() {}
+
+        assert!(manager.relay_typing(&alice_session, household_id).await);
+        match bob_rx.try_recv() {
+            Ok(WsServerMessage::UserTyping { household_id: id, user_id, username }) => {
+                assert_eq!(id, household_id);
+                assert_eq!(user_id, alice);
+                assert_eq!(username, "alice");
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+        // The typist does not hear themselves
+        assert!(alice_rx.try_recv().is_err());
+
+        // A second notice right away is dropped
+        assert!(!manager.relay_typing(&alice_session, household_id).await);
+        assert!(bob_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_ws_manager_send_to_user() {
+        let manager = WsManager::new();
+        let user_id = Uuid::new_v4();
+        let (own_tx, mut own_rx) = mpsc::unbounded_channel();
+        let (other_tx, mut other_rx) = mpsc::unbounded_channel();
+        let own_session = Uuid::new_v4();
+        let other_session = Uuid::new_v4();
+
+        manager.register(own_session, own_tx).await;
+        manager.register(other_session, other_tx).await;
+        manager.authenticate(&own_session, user_id, "alice".to_string()).await;
+        manager.authenticate(&other_session, Uuid::new_v4(), "bob".to_string()).await;
+        // Skip the Authenticated responses
+        own_rx.try_recv().unwrap();
+        other_rx.try_recv().unwrap();
+
+        manager.send_to_user(&user_id, WsServerMessage::Pong).await;
+
+        assert!(matches!(own_rx.try_recv(), Ok(WsServerMessage::Pong)));
+        assert!(other_rx.try_recv().is_err());
+    }
 }
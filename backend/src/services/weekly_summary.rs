@@ -0,0 +1,424 @@
+//! Weekly household recap
+//!
+//! At the end of each household week the background scheduler posts a summary
+//! announcement with completions, running streaks and leaderboard movement.
+//! Households opt in via `HouseholdSettings::weekly_summary_enabled`; with
+//! `weekly_summary_email` the recap is also mailed to every member.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use chrono_tz::Tz;
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::services::{announcements, mail, period_results, scheduler, statistics};
+use shared::{is_child_account_email, CreateAnnouncementRequest, HouseholdReport};
+
+/// Local hour on the last day of the week from which the recap is posted
+pub const SUMMARY_HOUR: u32 = 18;
+
+/// Number of running streaks listed in a recap
+const STREAK_LIMIT: usize = 3;
+
+/// How long the recap announcement stays visible
+const ANNOUNCEMENT_DAYS: i64 = 7;
+
+#[derive(Debug, Error)]
+pub enum WeeklySummaryError {
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("Statistics error: {0}")]
+    Statistics(#[from] statistics::StatisticsError),
+    #[error("Period result error: {0}")]
+    PeriodResult(#[from] period_results::PeriodResultError),
+    #[error("Announcement error: {0}")]
+    Announcement(#[from] announcements::AnnouncementError),
+}
+
+/// Report from generating weekly summaries
+#[derive(Debug, Clone, Default)]
+pub struct WeeklySummaryReport {
+    pub households_checked: u32,
+    pub summaries_created: u32,
+    pub emails_sent: u32,
+}
+
+/// Leaderboard position of a member at the start and end of the week
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankChange {
+    pub username: String,
+    pub points: i64,
+    pub rank: usize,
+    pub previous_rank: usize,
+}
+
+/// Everything that goes into a recap
+#[derive(Debug, Clone)]
+pub struct WeeklySummary {
+    pub household_name: String,
+    pub report: HouseholdReport,
+    /// Tasks with a running streak, longest first
+    pub streaks: Vec<(String, i32)>,
+    pub leaderboard: Vec<RankChange>,
+}
+
+/// The week to summarize if a recap is due at `now_local`: from `SUMMARY_HOUR`
+/// on the last day of the household week
+pub fn summary_week_due(now_local: NaiveDateTime, week_start_day: i32) -> Option<NaiveDate> {
+    let today = now_local.date();
+    let week_start = statistics::get_week_start(today, week_start_day);
+    let due_from = NaiveTime::from_hms_opt(SUMMARY_HOUR, 0, 0).unwrap();
+    (statistics::get_week_end(week_start) == today && now_local.time() >= due_from).then_some(week_start)
+}
+
+/// Rank members by points, best first; ties keep the order of `points`
+fn ranks(points: &[(String, i64)]) -> HashMap<String, usize> {
+    let mut sorted: Vec<&(String, i64)> = points.iter().collect();
+    sorted.sort_by_key(|(_, points)| std::cmp::Reverse(*points));
+    sorted
+        .into_iter()
+        .enumerate()
+        .map(|(i, (user_id, _))| (user_id.clone(), i + 1))
+        .collect()
+}
+
+/// Collect the data for the recap of the week starting at `week_start`
+pub async fn build_summary(
+    pool: &SqlitePool,
+    household_id: &Uuid,
+    week_start: NaiveDate,
+    timezone: Tz,
+) -> Result<WeeklySummary, WeeklySummaryError> {
+    let week_end = statistics::get_week_end(week_start);
+
+    let household_name: String = sqlx::query_scalar("SELECT name FROM households WHERE id = ?")
+        .bind(household_id.to_string())
+        .fetch_one(pool)
+        .await?;
+
+    let report = statistics::get_household_report(pool, household_id, week_start, week_end, timezone).await?;
+
+    // Running streaks of active tasks
+    let task_rows: Vec<(String, String)> = sqlx::query_as(
+        "SELECT id, title FROM tasks WHERE household_id = ? AND archived = 0 AND paused = 0 AND deleted_at IS NULL",
+    )
+    .bind(household_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    let mut streaks = Vec::new();
+    for (task_id, title) in task_rows {
+        let Ok(task_id) = Uuid::parse_str(&task_id) else {
+            continue;
+        };
+        let streak = period_results::calculate_current_streak(pool, &task_id).await?;
+        if streak >= 2 {
+            streaks.push((title, streak));
+        }
+    }
+    streaks.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    streaks.truncate(STREAK_LIMIT);
+
+    // Leaderboard now vs. before this week's point changes
+    let members: Vec<(String, String, i64)> = sqlx::query_as(
+        r#"
+        SELECT m.user_id, u.username, m.points
+        FROM household_memberships m
+        JOIN users u ON m.user_id = u.id
+        WHERE m.household_id = ?
+        ORDER BY u.username
+        "#,
+    )
+    .bind(household_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    let week_start_utc = scheduler::local_midnight_utc(week_start, timezone);
+    let changes: Vec<(String, i64)> = sqlx::query_as(
+        r#"
+        SELECT user_id, COALESCE(SUM(amount), 0)
+        FROM point_transactions
+        WHERE household_id = ? AND julianday(created_at) >= julianday(?)
+        GROUP BY user_id
+        "#,
+    )
+    .bind(household_id.to_string())
+    .bind(week_start_utc.format("%Y-%m-%d %H:%M:%S").to_string())
+    .fetch_all(pool)
+    .await?;
+    let changes: HashMap<String, i64> = changes.into_iter().collect();
+
+    let current: Vec<(String, i64)> = members.iter().map(|(id, _, points)| (id.clone(), *points)).collect();
+    let previous: Vec<(String, i64)> = members
+        .iter()
+        .map(|(id, _, points)| (id.clone(), points - changes.get(id).copied().unwrap_or(0)))
+        .collect();
+    let current_ranks = ranks(&current);
+    let previous_ranks = ranks(&previous);
+
+    let mut leaderboard: Vec<RankChange> = members
+        .into_iter()
+        .map(|(id, username, points)| RankChange {
+            username,
+            points,
+            rank: current_ranks[&id],
+            previous_rank: previous_ranks[&id],
+        })
+        .collect();
+    leaderboard.sort_by_key(|r| r.rank);
+
+    Ok(WeeklySummary {
+        household_name,
+        report,
+        streaks,
+        leaderboard,
+    })
+}
+
+/// Title and plain text body of the recap
+pub fn render_summary(summary: &WeeklySummary) -> (String, String) {
+    let report = &summary.report;
+    let title = format!(
+        "Weekly summary {} - {}",
+        report.start_date.format("%d.%m.%Y"),
+        report.end_date.format("%d.%m.%Y")
+    );
+
+    let mut body = format!(
+        "{} of {} tasks done ({:.0}%), {} points earned.\n",
+        report.total_completed, report.total_expected, report.completion_rate, report.total_points_earned
+    );
+
+    if !report.members.is_empty() {
+        body.push_str("\nCompletions:\n");
+        for member in &report.members {
+            body.push_str(&format!(
+                "- {}: {} of {} ({:.0}%), +{} points\n",
+                member.username, member.completed, member.expected, member.completion_rate, member.points_earned
+            ));
+        }
+    }
+
+    if !summary.streaks.is_empty() {
+        body.push_str("\nStreaks:\n");
+        for (title, streak) in &summary.streaks {
+            body.push_str(&format!("- {}: {} in a row\n", title, streak));
+        }
+    }
+
+    if !summary.leaderboard.is_empty() {
+        body.push_str("\nLeaderboard:\n");
+        for entry in &summary.leaderboard {
+            let movement = match entry.previous_rank as i64 - entry.rank as i64 {
+                0 => String::new(),
+                up if up > 0 => format!(" (up {})", up),
+                down => format!(" (down {})", -down),
+            };
+            body.push_str(&format!(
+                "{}. {} - {} points{}\n",
+                entry.rank, entry.username, entry.points, movement
+            ));
+        }
+    }
+
+    (title, body)
+}
+
+/// Recap mail for one member
+pub fn summary_mail(settings: &mail::MailSettings, to: &str, summary: &WeeklySummary) -> mail::OutgoingMail {
+    let (title, body) = render_summary(summary);
+    mail::OutgoingMail {
+        to: to.to_string(),
+        subject: format!("{}: {}", summary.household_name, title),
+        body: format!(
+            "Hello,\n\nhere is the weekly summary of \"{}\".\n\n{}\n{}\n",
+            summary.household_name, body, settings.public_url
+        ),
+    }
+}
+
+/// Post the recap for every opted-in household whose week is ending.
+/// Each household week is summarized once; emails are sent when enabled and
+/// SMTP is configured.
+pub async fn process_weekly_summaries(
+    pool: &SqlitePool,
+    mail_settings: Option<&mail::MailSettings>,
+    now: DateTime<Utc>,
+) -> Result<WeeklySummaryReport, WeeklySummaryError> {
+    let mut report = WeeklySummaryReport::default();
+
+    let households: Vec<(String, String, i32, bool, String)> = sqlx::query_as(
+        r#"
+        SELECT s.household_id, s.timezone, s.week_start_day, s.weekly_summary_email, h.owner_id
+        FROM household_settings s
+        JOIN households h ON s.household_id = h.id
+        WHERE s.weekly_summary_enabled = 1
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    for (household_id, timezone, week_start_day, send_email, owner_id) in households {
+        report.households_checked += 1;
+
+        let tz = scheduler::parse_timezone(&timezone);
+        let Some(week_start) = summary_week_due(now.with_timezone(&tz).naive_local(), week_start_day) else {
+            continue;
+        };
+        let (Ok(household_uuid), Ok(owner_uuid)) = (Uuid::parse_str(&household_id), Uuid::parse_str(&owner_id)) else {
+            continue;
+        };
+
+        // Claim the week first so a slow run is never repeated by the next tick
+        let claimed = sqlx::query(
+            "INSERT OR IGNORE INTO weekly_summaries (household_id, week_start, created_at) VALUES (?, ?, ?)",
+        )
+        .bind(&household_id)
+        .bind(week_start)
+        .bind(now)
+        .execute(pool)
+        .await?
+        .rows_affected();
+        if claimed == 0 {
+            continue;
+        }
+
+        let summary = build_summary(pool, &household_uuid, week_start, tz).await?;
+        let (title, content) = render_summary(&summary);
+
+        // Announcements need an author; the recap is posted in the owner's name
+        let announcement = announcements::create_announcement(
+            pool,
+            &household_uuid,
+            &owner_uuid,
+            &CreateAnnouncementRequest {
+                title,
+                content: Some(content),
+                starts_at: Some(now),
+                ends_at: Some(now + chrono::Duration::days(ANNOUNCEMENT_DAYS)),
+            },
+        )
+        .await?;
+
+        sqlx::query("UPDATE weekly_summaries SET announcement_id = ? WHERE household_id = ? AND week_start = ?")
+            .bind(announcement.id.to_string())
+            .bind(&household_id)
+            .bind(week_start)
+            .execute(pool)
+            .await?;
+        report.summaries_created += 1;
+
+        if let (true, Some(settings)) = (send_email, mail_settings) {
+            let emails: Vec<String> = sqlx::query_scalar(
+                r#"
+                SELECT u.email
+                FROM household_memberships m
+                JOIN users u ON m.user_id = u.id
+                WHERE m.household_id = ?
+                "#,
+            )
+            .bind(&household_id)
+            .fetch_all(pool)
+            .await?;
+
+            for email in emails.into_iter().filter(|e| !is_child_account_email(e)) {
+                mail::send_in_background(settings.clone(), summary_mail(settings, &email, &summary));
+                report.emails_sent += 1;
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use crate::test_utils::*;
+    use shared::Role;
+
+    fn at(date: &str, time: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(&format!("{} {}", date, time), "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_summary_week_due() {
+        // 2024-01-14 is a Sunday, the last day of a Monday-based week
+        assert_eq!(
+            summary_week_due(at("2024-01-14", "18:00:00"), 0),
+            NaiveDate::from_ymd_opt(2024, 1, 8)
+        );
+        assert_eq!(summary_week_due(at("2024-01-14", "17:59:59"), 0), None);
+        assert_eq!(summary_week_due(at("2024-01-13", "20:00:00"), 0), None);
+        // Weeks starting on Sunday end on Saturday
+        assert_eq!(
+            summary_week_due(at("2024-01-13", "20:00:00"), 6),
+            NaiveDate::from_ymd_opt(2024, 1, 7)
+        );
+    }
+
+    #[test]
+    fn test_ranks() {
+        let ranks = ranks(&[("a".to_string(), 5), ("b".to_string(), 10), ("c".to_string(), 5)]);
+        assert_eq!(ranks["b"], 1);
+        assert_eq!(ranks["a"], 2);
+        assert_eq!(ranks["c"], 3);
+    }
+
+    #[tokio::test]
+    async fn test_process_weekly_summaries_posts_once() {
+        let pool = create_test_pool().await;
+        let household_id = create_test_household(&pool).await;
+        let alice = create_test_user(&pool, "alice@test.com", Role::Member).await;
+        let bob = create_test_user(&pool, "bob@test.com", Role::Member).await;
+        create_test_membership(&pool, &household_id, &alice, Role::Member).await;
+        create_test_membership(&pool, &household_id, &bob, Role::Member).await;
+
+        // Bob overtook Alice this week
+        set_user_points(&pool, &household_id, &alice, 10).await;
+        set_user_points(&pool, &household_id, &bob, 20).await;
+        sqlx::query(
+            r#"INSERT INTO point_transactions (id, household_id, user_id, amount, balance_after, transaction_type, created_at)
+            VALUES (?, ?, ?, 15, 20, 'task_completed', '2024-01-10 12:00:00')"#,
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(household_id.to_string())
+        .bind(bob.to_string())
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let sunday_evening = Utc.with_ymd_and_hms(2024, 1, 14, 19, 0, 0).unwrap();
+
+        // Not opted in: nothing happens
+        let report = process_weekly_summaries(&pool, None, sunday_evening).await.unwrap();
+        assert_eq!(report.households_checked, 0);
+
+        sqlx::query("UPDATE household_settings SET weekly_summary_enabled = TRUE WHERE household_id = ?")
+            .bind(household_id.to_string())
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let report = process_weekly_summaries(&pool, None, sunday_evening).await.unwrap();
+        assert_eq!(report.summaries_created, 1);
+        assert_eq!(report.emails_sent, 0);
+
+        let (title, content): (String, String) =
+            sqlx::query_as("SELECT title, content FROM announcements WHERE household_id = ?")
+                .bind(household_id.to_string())
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(title, "Weekly summary 08.01.2024 - 14.01.2024");
+        assert!(content.contains("1. bob - 20 points (up 1)"));
+        assert!(content.contains("2. alice - 10 points (down 1)"));
+
+        // The same week is not summarized twice
+        let later = sunday_evening + chrono::Duration::hours(1);
+        let report = process_weekly_summaries(&pool, None, later).await.unwrap();
+        assert_eq!(report.summaries_created, 0);
+    }
+}
@@ -0,0 +1,46 @@
+//! Log and span output. Spans and events, including `log` records, are written
+//! to stderr filtered by `RUST_LOG`, and exported via OTLP when `OTLP_ENDPOINT`
+//! is set.
+
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use opentelemetry_sdk::Resource;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::EnvFilter;
+
+use crate::config::Config;
+
+/// Service name reported to the tracing backend
+const SERVICE_NAME: &str = "haushalt";
+
+/// Install the global subscriber. The returned provider, if any, must be shut
+/// down before exit so spans still buffered get exported.
+pub fn init(config: &Config) -> Option<SdkTracerProvider> {
+    let provider = config.otlp_endpoint.as_deref().map(|endpoint| {
+        let exporter = SpanExporter::builder()
+            .with_http()
+            .with_endpoint(endpoint)
+            .build()
+            .expect("Failed to create OTLP exporter");
+        SdkTracerProvider::builder()
+            .with_batch_exporter(exporter)
+            .with_resource(Resource::builder().with_service_name(SERVICE_NAME).build())
+            .build()
+    });
+    let otel_layer = provider
+        .as_ref()
+        .map(|provider| tracing_opentelemetry::layer().with_tracer(provider.tracer(SERVICE_NAME)));
+
+    tracing_subscriber::registry()
+        .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
+        .with(tracing_subscriber::fmt::layer())
+        .with(otel_layer)
+        .init();
+
+    if let Some(endpoint) = &config.otlp_endpoint {
+        tracing::info!("Exporting spans to {}", endpoint);
+    }
+    provider
+}
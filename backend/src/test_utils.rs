@@ -1,10 +1,17 @@
 // Test utilities for service layer testing
 // Provides database setup, fixture creation, and assertion helpers
 
+use std::sync::Arc;
+
 use chrono::{NaiveDate, Utc};
 use sqlx::{SqlitePool, Sqlite, Pool};
 use uuid::Uuid;
 
+use crate::config::Config;
+use crate::middleware::RateLimiter;
+use crate::models::AppState;
+use crate::services::background_jobs::{JobConfig, JobRunner};
+
 use shared::{
     CompletionStatus, HabitType, PeriodStatus, RecurrenceType,
     RecurrenceValue, Role, Task, TimePeriod,
@@ -40,6 +47,9 @@ async fn create_test_schema(pool: &SqlitePool) {
             password_hash TEXT,
             oidc_subject TEXT,
             oidc_provider TEXT,
+            managed_by TEXT REFERENCES users(id),
+            disabled_at DATETIME,
+            is_admin BOOLEAN NOT NULL DEFAULT FALSE,
             created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
             updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
         )
@@ -54,15 +64,1007 @@ async fn create_test_schema(pool: &SqlitePool) {
         .await
         .unwrap();
 
-    // Households table
+    // Refresh tokens table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS refresh_tokens (
+            id TEXT PRIMARY KEY NOT NULL,
+            user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+            token_hash TEXT NOT NULL,
+            expires_at DATETIME NOT NULL,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    // Households table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS households (
+            id TEXT PRIMARY KEY NOT NULL,
+            name TEXT NOT NULL,
+            owner_id TEXT NOT NULL REFERENCES users(id),
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    // Household settings table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS household_settings (
+            household_id TEXT PRIMARY KEY NOT NULL REFERENCES households(id),
+            timezone TEXT NOT NULL DEFAULT 'UTC',
+            hierarchy_type TEXT NOT NULL DEFAULT 'democratic',
+            vacation_mode BOOLEAN NOT NULL DEFAULT FALSE,
+            auto_archive_days INTEGER NOT NULL DEFAULT 30,
+            week_start_day INTEGER NOT NULL DEFAULT 0,
+            weekly_summary_enabled BOOLEAN NOT NULL DEFAULT FALSE,
+            weekly_summary_email BOOLEAN NOT NULL DEFAULT FALSE,
+            point_decay_mode TEXT NOT NULL DEFAULT 'none',
+            point_decay_amount INTEGER NOT NULL DEFAULT 0,
+            purchase_approval_required BOOLEAN NOT NULL DEFAULT FALSE,
+            streak_freeze_price INTEGER NOT NULL DEFAULT 0,
+            default_requires_review BOOLEAN NOT NULL DEFAULT FALSE,
+            default_allow_exceed_target BOOLEAN NOT NULL DEFAULT TRUE,
+            good_habit_points_multiplier REAL NOT NULL DEFAULT 1.0,
+            bad_habit_points_multiplier REAL NOT NULL DEFAULT 1.0,
+            review_points_threshold INTEGER,
+            disabled_features TEXT NOT NULL DEFAULT '[]',
+            punishment_terminology TEXT NOT NULL DEFAULT 'punishments',
+            punishment_label_singular TEXT,
+            punishment_label_plural TEXT,
+            allowance_cents_per_point INTEGER NOT NULL DEFAULT 0,
+            allowance_point_handling TEXT NOT NULL DEFAULT 'reset',
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            version INTEGER NOT NULL DEFAULT 1,
+            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    // User settings table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS user_settings (
+            user_id TEXT PRIMARY KEY NOT NULL REFERENCES users(id),
+            language TEXT NOT NULL DEFAULT 'en',
+            theme TEXT NOT NULL DEFAULT 'system',
+            notifications_enabled BOOLEAN NOT NULL DEFAULT TRUE,
+            notify_chat BOOLEAN NOT NULL DEFAULT 1,
+            notify_reviews BOOLEAN NOT NULL DEFAULT 1,
+            notify_assignments BOOLEAN NOT NULL DEFAULT 1,
+            notify_reminders BOOLEAN NOT NULL DEFAULT 1,
+            quiet_hours_start TEXT,
+            quiet_hours_end TEXT,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    // Household memberships table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS household_memberships (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            user_id TEXT NOT NULL REFERENCES users(id),
+            role TEXT NOT NULL DEFAULT 'member' CHECK(role IN ('owner', 'admin', 'member')),
+            points INTEGER NOT NULL DEFAULT 0,
+            joined_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            vacation_start DATE,
+            vacation_end DATE,
+            permissions TEXT,
+            UNIQUE(household_id, user_id)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_memberships_household ON household_memberships(household_id)")
+        .execute(pool)
+        .await
+        .unwrap();
+
+    // Task categories table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS task_categories (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            name TEXT NOT NULL,
+            color TEXT NOT NULL DEFAULT '#3B82F6',
+            icon TEXT,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            UNIQUE(household_id, name)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    // Tasks table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS tasks (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            title TEXT NOT NULL,
+            description TEXT NOT NULL DEFAULT '',
+            recurrence_type TEXT NOT NULL DEFAULT 'daily' CHECK(recurrence_type IN ('daily', 'weekly', 'monthly', 'weekdays', 'custom', 'onetime')),
+            recurrence_value TEXT,
+            assigned_user_id TEXT REFERENCES users(id),
+            target_count INTEGER NOT NULL DEFAULT 1,
+            time_period TEXT CHECK(time_period IN ('day', 'week', 'month', 'year', 'none')),
+            allow_exceed_target BOOLEAN NOT NULL DEFAULT TRUE,
+            requires_review BOOLEAN NOT NULL DEFAULT FALSE,
+            points_reward INTEGER,
+            points_penalty INTEGER,
+            due_time TEXT,
+            effort_minutes INTEGER,
+            active_from TEXT,
+            active_until TEXT,
+            ends_on DATE,
+            max_occurrences INTEGER,
+            reviewer_type TEXT NOT NULL DEFAULT 'any_reviewer',
+            reviewer_id TEXT,
+            habit_type TEXT NOT NULL DEFAULT 'good' CHECK(habit_type IN ('good', 'bad')),
+            category_id TEXT REFERENCES task_categories(id),
+            archived BOOLEAN NOT NULL DEFAULT FALSE,
+            paused BOOLEAN NOT NULL DEFAULT FALSE,
+            suggestion TEXT CHECK(suggestion IN ('suggested', 'accepted', 'rejected')),
+            suggested_by TEXT REFERENCES users(id),
+            postponed_from DATE,
+            postponed_to DATE,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            version INTEGER NOT NULL DEFAULT 1,
+            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            deleted_at DATETIME
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_tasks_household ON tasks(household_id)")
+        .execute(pool)
+        .await
+        .unwrap();
+
+    // Task completions table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS task_completions (
+            id TEXT PRIMARY KEY NOT NULL,
+            task_id TEXT NOT NULL REFERENCES tasks(id),
+            user_id TEXT NOT NULL REFERENCES users(id),
+            completed_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            due_date DATE NOT NULL,
+            status TEXT NOT NULL DEFAULT 'approved' CHECK(status IN ('pending', 'approved', 'rejected'))
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_completions_task ON task_completions(task_id)")
+        .execute(pool)
+        .await
+        .unwrap();
+
+    // Task period results table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS task_period_results (
+            id TEXT PRIMARY KEY NOT NULL,
+            task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+            period_start DATE NOT NULL,
+            period_end DATE NOT NULL,
+            status TEXT NOT NULL CHECK(status IN ('completed', 'failed', 'skipped')),
+            completions_count INTEGER NOT NULL,
+            target_count INTEGER NOT NULL,
+            finalized_at DATETIME NOT NULL,
+            finalized_by TEXT NOT NULL DEFAULT 'system',
+            notes TEXT
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query("CREATE UNIQUE INDEX IF NOT EXISTS idx_period_results_task_date ON task_period_results(task_id, period_start)")
+        .execute(pool)
+        .await
+        .unwrap();
+
+    // Completion/period event log and its daily rollups
+    create_event_tables(pool).await;
+
+    // Point conditions table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS point_conditions (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            name TEXT NOT NULL,
+            description TEXT,
+            points INTEGER NOT NULL,
+            task_id TEXT REFERENCES tasks(id),
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    // Task consequences table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS task_consequences (
+            id TEXT PRIMARY KEY,
+            task_id TEXT NOT NULL,
+            consequence_type TEXT NOT NULL,
+            trigger_type TEXT NOT NULL,
+            consequence_id TEXT NOT NULL,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    // Rewards table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS rewards (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            name TEXT NOT NULL,
+            description TEXT NOT NULL DEFAULT '',
+            point_cost INTEGER,
+            is_purchasable BOOLEAN NOT NULL DEFAULT FALSE,
+            requires_confirmation BOOLEAN NOT NULL DEFAULT FALSE,
+            reward_type TEXT NOT NULL DEFAULT 'standard',
+            no_repeat_picks INTEGER NOT NULL DEFAULT 0,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    // Punishments table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS punishments (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            name TEXT NOT NULL,
+            description TEXT NOT NULL DEFAULT '',
+            requires_confirmation BOOLEAN NOT NULL DEFAULT FALSE,
+            punishment_type TEXT NOT NULL DEFAULT 'standard',
+            no_repeat_picks INTEGER NOT NULL DEFAULT 0,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    // Task-reward associations
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS task_rewards (
+            task_id TEXT NOT NULL REFERENCES tasks(id),
+            reward_id TEXT NOT NULL REFERENCES rewards(id),
+            amount INTEGER NOT NULL DEFAULT 1,
+            PRIMARY KEY (task_id, reward_id)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    // Task prerequisites
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS task_dependencies (
+            task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+            prerequisite_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (task_id, prerequisite_id),
+            CHECK (task_id != prerequisite_id)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    // Task-punishment associations
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS task_punishments (
+            task_id TEXT NOT NULL REFERENCES tasks(id),
+            punishment_id TEXT NOT NULL REFERENCES punishments(id),
+            amount INTEGER NOT NULL DEFAULT 1,
+            PRIMARY KEY (task_id, punishment_id)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    // User rewards table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS user_rewards (
+            id TEXT PRIMARY KEY NOT NULL,
+            user_id TEXT NOT NULL REFERENCES users(id),
+            reward_id TEXT NOT NULL REFERENCES rewards(id),
+            household_id TEXT NOT NULL REFERENCES households(id),
+            amount INTEGER NOT NULL DEFAULT 1,
+            redeemed_amount INTEGER NOT NULL DEFAULT 0,
+            pending_redemption INTEGER NOT NULL DEFAULT 0,
+            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            UNIQUE(user_id, reward_id, household_id)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    // Reward purchases awaiting approval
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS reward_purchases (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+            reward_id TEXT NOT NULL REFERENCES rewards(id) ON DELETE CASCADE,
+            user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+            point_cost INTEGER NOT NULL,
+            status TEXT NOT NULL DEFAULT 'pending',
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            reviewed_by TEXT REFERENCES users(id) ON DELETE SET NULL,
+            reviewed_at DATETIME
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    // User punishments table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS user_punishments (
+            id TEXT PRIMARY KEY NOT NULL,
+            user_id TEXT NOT NULL REFERENCES users(id),
+            punishment_id TEXT NOT NULL REFERENCES punishments(id),
+            household_id TEXT NOT NULL REFERENCES households(id),
+            assigned_by TEXT NOT NULL REFERENCES users(id),
+            task_completion_id TEXT REFERENCES task_completions(id),
+            completed BOOLEAN NOT NULL DEFAULT FALSE,
+            assigned_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    // Point transactions table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS point_transactions (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            user_id TEXT NOT NULL REFERENCES users(id),
+            amount INTEGER NOT NULL,
+            balance_after INTEGER NOT NULL,
+            transaction_type TEXT NOT NULL,
+            task_id TEXT,
+            reward_id TEXT,
+            actor_id TEXT,
+            description TEXT,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    // Chat tables
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS chat_messages (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            user_id TEXT NOT NULL REFERENCES users(id),
+            content TEXT NOT NULL,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            deleted_at DATETIME DEFAULT NULL,
+            reply_to_message_id TEXT REFERENCES chat_messages(id)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS chat_reactions (
+            message_id TEXT NOT NULL REFERENCES chat_messages(id),
+            user_id TEXT NOT NULL REFERENCES users(id),
+            emoji TEXT NOT NULL,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (message_id, user_id, emoji)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS chat_read_markers (
+            household_id TEXT NOT NULL REFERENCES households(id),
+            user_id TEXT NOT NULL REFERENCES users(id),
+            last_read_message_id TEXT NOT NULL,
+            last_read_at DATETIME NOT NULL,
+            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (household_id, user_id)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS expenses (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            paid_by TEXT NOT NULL REFERENCES users(id),
+            amount_cents INTEGER NOT NULL,
+            category TEXT NOT NULL DEFAULT 'other',
+            description TEXT NOT NULL,
+            receipt_note TEXT,
+            expense_date DATE NOT NULL,
+            created_by TEXT NOT NULL REFERENCES users(id),
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS expense_settlements (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            from_user_id TEXT NOT NULL REFERENCES users(id),
+            to_user_id TEXT NOT NULL REFERENCES users(id),
+            amount_cents INTEGER NOT NULL,
+            note TEXT,
+            created_by TEXT NOT NULL REFERENCES users(id),
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS recipes (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            name TEXT NOT NULL,
+            ingredients TEXT NOT NULL DEFAULT '[]',
+            notes TEXT,
+            created_by TEXT NOT NULL REFERENCES users(id),
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS meal_plan_entries (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            plan_date DATE NOT NULL,
+            slot TEXT NOT NULL,
+            recipe_id TEXT REFERENCES recipes(id) ON DELETE SET NULL,
+            title TEXT,
+            notes TEXT,
+            created_by TEXT NOT NULL REFERENCES users(id),
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            UNIQUE(household_id, plan_date, slot)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS shopping_list_items (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            name TEXT NOT NULL,
+            quantity REAL,
+            unit TEXT,
+            is_checked BOOLEAN NOT NULL DEFAULT 0,
+            added_by TEXT NOT NULL REFERENCES users(id),
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS inventory_items (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            name TEXT NOT NULL,
+            quantity REAL NOT NULL DEFAULT 0,
+            unit TEXT,
+            location TEXT,
+            min_quantity REAL,
+            created_by TEXT NOT NULL REFERENCES users(id),
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS appliances (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            name TEXT NOT NULL,
+            location TEXT,
+            purchased_on DATE,
+            warranty_until DATE,
+            manual_url TEXT,
+            notes TEXT,
+            created_by TEXT NOT NULL REFERENCES users(id),
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS appliance_maintenance (
+            appliance_id TEXT NOT NULL REFERENCES appliances(id) ON DELETE CASCADE,
+            task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+            interval_days INTEGER NOT NULL,
+            PRIMARY KEY (appliance_id, task_id)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS plants (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            name TEXT NOT NULL,
+            location TEXT,
+            water_interval_days INTEGER,
+            fertilize_interval_days INTEGER,
+            water_task_id TEXT REFERENCES tasks(id) ON DELETE SET NULL,
+            fertilize_task_id TEXT REFERENCES tasks(id) ON DELETE SET NULL,
+            last_watered_at DATETIME,
+            last_fertilized_at DATETIME,
+            created_by TEXT NOT NULL REFERENCES users(id),
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS share_links (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            resource TEXT NOT NULL,
+            token TEXT NOT NULL UNIQUE,
+            created_by TEXT NOT NULL REFERENCES users(id),
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            expires_at DATETIME
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS pets (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            name TEXT NOT NULL,
+            species TEXT,
+            notes TEXT,
+            created_by TEXT NOT NULL REFERENCES users(id),
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS pet_schedules (
+            pet_id TEXT NOT NULL REFERENCES pets(id) ON DELETE CASCADE,
+            task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+            kind TEXT NOT NULL,
+            times_per_day INTEGER NOT NULL,
+            PRIMARY KEY (pet_id, task_id)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS pet_care_log (
+            id TEXT PRIMARY KEY NOT NULL,
+            pet_id TEXT NOT NULL REFERENCES pets(id) ON DELETE CASCADE,
+            task_id TEXT REFERENCES tasks(id) ON DELETE SET NULL,
+            kind TEXT NOT NULL,
+            title TEXT NOT NULL,
+            user_id TEXT NOT NULL REFERENCES users(id),
+            logged_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS admin_audit_log (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            actor_id TEXT NOT NULL REFERENCES users(id),
+            target_user_id TEXT REFERENCES users(id),
+            action TEXT NOT NULL,
+            before_value TEXT,
+            after_value TEXT,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS completion_attachments (
+            id TEXT PRIMARY KEY NOT NULL,
+            completion_id TEXT NOT NULL REFERENCES task_completions(id) ON DELETE CASCADE,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            uploaded_by TEXT NOT NULL REFERENCES users(id),
+            content_type TEXT NOT NULL,
+            size_bytes INTEGER NOT NULL,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS attachments (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            entity_type TEXT NOT NULL CHECK(entity_type IN ('note', 'journal_entry', 'chat_message', 'plant')),
+            entity_id TEXT NOT NULL,
+            uploaded_by TEXT NOT NULL REFERENCES users(id),
+            file_name TEXT NOT NULL,
+            content_type TEXT NOT NULL,
+            size_bytes INTEGER NOT NULL,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS announcements (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            created_by TEXT NOT NULL REFERENCES users(id),
+            title TEXT NOT NULL,
+            content TEXT NOT NULL DEFAULT '',
+            starts_at DATETIME,
+            ends_at DATETIME,
+            published_at DATETIME,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS announcement_acknowledgments (
+            announcement_id TEXT NOT NULL REFERENCES announcements(id) ON DELETE CASCADE,
+            user_id TEXT NOT NULL REFERENCES users(id),
+            acknowledged_at DATETIME NOT NULL,
+            PRIMARY KEY (announcement_id, user_id)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS weekly_summaries (
+            household_id TEXT NOT NULL REFERENCES households(id),
+            week_start DATE NOT NULL,
+            announcement_id TEXT REFERENCES announcements(id),
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (household_id, week_start)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS point_decay_runs (
+            household_id TEXT NOT NULL REFERENCES households(id),
+            week_start DATE NOT NULL,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (household_id, week_start)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS allowance_payouts (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            user_id TEXT NOT NULL REFERENCES users(id),
+            month DATE NOT NULL,
+            points INTEGER NOT NULL,
+            amount_cents INTEGER NOT NULL,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            UNIQUE (household_id, user_id, month)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS allowance_runs (
+            household_id TEXT NOT NULL REFERENCES households(id),
+            month DATE NOT NULL,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (household_id, month)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS streak_freezes (
+            household_id TEXT NOT NULL REFERENCES households(id),
+            user_id TEXT NOT NULL REFERENCES users(id),
+            available INTEGER NOT NULL DEFAULT 0,
+            PRIMARY KEY (household_id, user_id)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS challenges (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+            title TEXT NOT NULL,
+            description TEXT NOT NULL DEFAULT '',
+            category_id TEXT REFERENCES task_categories(id) ON DELETE SET NULL,
+            start_date DATE NOT NULL,
+            end_date DATE NOT NULL,
+            reward_id TEXT REFERENCES rewards(id) ON DELETE SET NULL,
+            status TEXT NOT NULL DEFAULT 'active' CHECK(status IN ('active', 'closed')),
+            created_by TEXT NOT NULL REFERENCES users(id),
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            closed_at DATETIME
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS challenge_winners (
+            challenge_id TEXT NOT NULL REFERENCES challenges(id) ON DELETE CASCADE,
+            user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+            completions INTEGER NOT NULL,
+            PRIMARY KEY (challenge_id, user_id)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS automation_rules (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+            name TEXT NOT NULL,
+            trigger_type TEXT NOT NULL,
+            threshold INTEGER NOT NULL,
+            task_id TEXT REFERENCES tasks(id) ON DELETE CASCADE,
+            reward_id TEXT REFERENCES rewards(id) ON DELETE CASCADE,
+            punishment_id TEXT REFERENCES punishments(id) ON DELETE CASCADE,
+            enabled BOOLEAN NOT NULL DEFAULT TRUE,
+            created_by TEXT NOT NULL REFERENCES users(id),
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS automation_rule_runs (
+            id TEXT PRIMARY KEY NOT NULL,
+            rule_id TEXT NOT NULL REFERENCES automation_rules(id) ON DELETE CASCADE,
+            household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+            user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+            trigger_key TEXT NOT NULL,
+            observed_value INTEGER NOT NULL,
+            reward_id TEXT REFERENCES rewards(id) ON DELETE SET NULL,
+            punishment_id TEXT REFERENCES punishments(id) ON DELETE SET NULL,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            UNIQUE (rule_id, user_id, trigger_key)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS webhooks (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+            url TEXT NOT NULL,
+            secret TEXT NOT NULL,
+            events TEXT NOT NULL,
+            enabled BOOLEAN NOT NULL DEFAULT TRUE,
+            created_by TEXT NOT NULL REFERENCES users(id),
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            last_delivery_at DATETIME,
+            last_error TEXT
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
     sqlx::query(
         r#"
-        CREATE TABLE IF NOT EXISTS households (
+        CREATE TABLE IF NOT EXISTS api_tokens (
             id TEXT PRIMARY KEY NOT NULL,
+            user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
             name TEXT NOT NULL,
-            owner_id TEXT NOT NULL REFERENCES users(id),
+            token_hash TEXT NOT NULL UNIQUE,
+            read_only BOOLEAN NOT NULL DEFAULT TRUE,
             created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
-            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+            last_used_at DATETIME
         )
         "#,
     )
@@ -70,17 +1072,16 @@ async fn create_test_schema(pool: &SqlitePool) {
     .await
     .unwrap();
 
-    // Household settings table
     sqlx::query(
         r#"
-        CREATE TABLE IF NOT EXISTS household_settings (
-            household_id TEXT PRIMARY KEY NOT NULL REFERENCES households(id),
-            timezone TEXT NOT NULL DEFAULT 'UTC',
-            hierarchy_type TEXT NOT NULL DEFAULT 'democratic',
-            vacation_mode BOOLEAN NOT NULL DEFAULT FALSE,
-            auto_archive_days INTEGER NOT NULL DEFAULT 30,
+        CREATE TABLE IF NOT EXISTS idempotency_keys (
+            user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+            idempotency_key TEXT NOT NULL,
+            request_path TEXT NOT NULL,
+            status_code INTEGER,
+            response_body TEXT,
             created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
-            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+            PRIMARY KEY (user_id, idempotency_key)
         )
         "#,
     )
@@ -88,16 +1089,18 @@ async fn create_test_schema(pool: &SqlitePool) {
     .await
     .unwrap();
 
-    // User settings table
     sqlx::query(
         r#"
-        CREATE TABLE IF NOT EXISTS user_settings (
-            user_id TEXT PRIMARY KEY NOT NULL REFERENCES users(id),
-            language TEXT NOT NULL DEFAULT 'en',
-            theme TEXT NOT NULL DEFAULT 'light',
-            notifications_enabled BOOLEAN NOT NULL DEFAULT TRUE,
-            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
-            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        CREATE TABLE IF NOT EXISTS notifications (
+            id TEXT PRIMARY KEY NOT NULL,
+            user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+            household_id TEXT REFERENCES households(id) ON DELETE CASCADE,
+            notification_type TEXT NOT NULL,
+            title TEXT NOT NULL,
+            entity_type TEXT,
+            entity_id TEXT,
+            read_at DATETIME,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
         )
         "#,
     )
@@ -105,17 +1108,18 @@ async fn create_test_schema(pool: &SqlitePool) {
     .await
     .unwrap();
 
-    // Household memberships table
     sqlx::query(
         r#"
-        CREATE TABLE IF NOT EXISTS household_memberships (
+        CREATE TABLE IF NOT EXISTS household_invite_codes (
             id TEXT PRIMARY KEY NOT NULL,
-            household_id TEXT NOT NULL REFERENCES households(id),
-            user_id TEXT NOT NULL REFERENCES users(id),
-            role TEXT NOT NULL DEFAULT 'member' CHECK(role IN ('owner', 'admin', 'member')),
-            points INTEGER NOT NULL DEFAULT 0,
-            joined_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
-            UNIQUE(household_id, user_id)
+            household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+            code TEXT NOT NULL UNIQUE,
+            role TEXT NOT NULL,
+            created_by TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            expires_at DATETIME NOT NULL,
+            max_uses INTEGER,
+            use_count INTEGER NOT NULL DEFAULT 0
         )
         "#,
     )
@@ -123,22 +1127,28 @@ async fn create_test_schema(pool: &SqlitePool) {
     .await
     .unwrap();
 
-    sqlx::query("CREATE INDEX IF NOT EXISTS idx_memberships_household ON household_memberships(household_id)")
-        .execute(pool)
-        .await
-        .unwrap();
+    // Task assignees table
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS task_assignees (
+            task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+            user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+            PRIMARY KEY (task_id, user_id)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
 
-    // Task categories table
     sqlx::query(
         r#"
-        CREATE TABLE IF NOT EXISTS task_categories (
+        CREATE TABLE IF NOT EXISTS task_tags (
             id TEXT PRIMARY KEY NOT NULL,
-            household_id TEXT NOT NULL REFERENCES households(id),
+            household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
             name TEXT NOT NULL,
-            color TEXT NOT NULL DEFAULT '#3B82F6',
-            icon TEXT,
+            color TEXT,
             created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
-            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
             UNIQUE(household_id, name)
         )
         "#,
@@ -147,32 +1157,29 @@ async fn create_test_schema(pool: &SqlitePool) {
     .await
     .unwrap();
 
-    // Tasks table
     sqlx::query(
         r#"
-        CREATE TABLE IF NOT EXISTS tasks (
+        CREATE TABLE IF NOT EXISTS task_tag_assignments (
+            task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+            tag_id TEXT NOT NULL REFERENCES task_tags(id) ON DELETE CASCADE,
+            PRIMARY KEY (task_id, tag_id)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS saved_task_filters (
             id TEXT PRIMARY KEY NOT NULL,
-            household_id TEXT NOT NULL REFERENCES households(id),
-            title TEXT NOT NULL,
-            description TEXT NOT NULL DEFAULT '',
-            recurrence_type TEXT NOT NULL DEFAULT 'daily' CHECK(recurrence_type IN ('daily', 'weekly', 'monthly', 'weekdays', 'custom', 'onetime')),
-            recurrence_value TEXT,
-            assigned_user_id TEXT REFERENCES users(id),
-            target_count INTEGER NOT NULL DEFAULT 1,
-            time_period TEXT CHECK(time_period IN ('day', 'week', 'month', 'year', 'none')),
-            allow_exceed_target BOOLEAN NOT NULL DEFAULT TRUE,
-            requires_review BOOLEAN NOT NULL DEFAULT FALSE,
-            points_reward INTEGER,
-            points_penalty INTEGER,
-            due_time TEXT,
-            habit_type TEXT NOT NULL DEFAULT 'good' CHECK(habit_type IN ('good', 'bad')),
-            category_id TEXT REFERENCES task_categories(id),
-            archived BOOLEAN NOT NULL DEFAULT FALSE,
-            paused BOOLEAN NOT NULL DEFAULT FALSE,
-            suggestion TEXT CHECK(suggestion IN ('suggested', 'accepted', 'rejected')),
-            suggested_by TEXT REFERENCES users(id),
+            household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+            user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+            name TEXT NOT NULL,
+            filter TEXT NOT NULL,
             created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
-            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+            UNIQUE(household_id, user_id, name)
         )
         "#,
     )
@@ -180,21 +1187,18 @@ async fn create_test_schema(pool: &SqlitePool) {
     .await
     .unwrap();
 
-    sqlx::query("CREATE INDEX IF NOT EXISTS idx_tasks_household ON tasks(household_id)")
-        .execute(pool)
-        .await
-        .unwrap();
-
-    // Task completions table
     sqlx::query(
         r#"
-        CREATE TABLE IF NOT EXISTS task_completions (
+        CREATE TABLE IF NOT EXISTS focus_sessions (
             id TEXT PRIMARY KEY NOT NULL,
-            task_id TEXT NOT NULL REFERENCES tasks(id),
-            user_id TEXT NOT NULL REFERENCES users(id),
-            completed_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
-            due_date DATE NOT NULL,
-            status TEXT NOT NULL DEFAULT 'approved' CHECK(status IN ('pending', 'approved', 'rejected'))
+            household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+            task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+            user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+            planned_minutes INTEGER NOT NULL,
+            status TEXT NOT NULL DEFAULT 'running' CHECK(status IN ('running', 'completed', 'cancelled')),
+            started_at DATETIME NOT NULL,
+            ended_at DATETIME,
+            focused_seconds INTEGER NOT NULL DEFAULT 0
         )
         "#,
     )
@@ -202,25 +1206,23 @@ async fn create_test_schema(pool: &SqlitePool) {
     .await
     .unwrap();
 
-    sqlx::query("CREATE INDEX IF NOT EXISTS idx_completions_task ON task_completions(task_id)")
+    sqlx::query("CREATE UNIQUE INDEX IF NOT EXISTS idx_focus_sessions_running ON focus_sessions(user_id) WHERE status = 'running'")
         .execute(pool)
         .await
         .unwrap();
 
-    // Task period results table
     sqlx::query(
         r#"
-        CREATE TABLE IF NOT EXISTS task_period_results (
+        CREATE TABLE IF NOT EXISTS point_goals (
             id TEXT PRIMARY KEY NOT NULL,
-            task_id TEXT NOT NULL REFERENCES tasks(id),
-            period_start DATE NOT NULL,
-            period_end DATE NOT NULL,
-            status TEXT NOT NULL CHECK(status IN ('completed', 'failed', 'skipped')),
-            completions_count INTEGER NOT NULL,
-            target_count INTEGER NOT NULL,
-            finalized_at DATETIME NOT NULL,
-            finalized_by TEXT NOT NULL DEFAULT 'system',
-            notes TEXT
+            household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+            user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+            title TEXT NOT NULL,
+            target_points INTEGER NOT NULL,
+            deadline DATE NOT NULL,
+            created_by TEXT NOT NULL REFERENCES users(id),
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            completed_at DATETIME
         )
         "#,
     )
@@ -228,21 +1230,18 @@ async fn create_test_schema(pool: &SqlitePool) {
     .await
     .unwrap();
 
-    sqlx::query("CREATE UNIQUE INDEX IF NOT EXISTS idx_period_results_task_date ON task_period_results(task_id, period_start)")
-        .execute(pool)
-        .await
-        .unwrap();
-
-    // Point conditions table
     sqlx::query(
         r#"
-        CREATE TABLE IF NOT EXISTS point_conditions (
-            id TEXT PRIMARY KEY NOT NULL,
-            household_id TEXT NOT NULL REFERENCES households(id),
-            name TEXT NOT NULL,
-            description TEXT,
+        CREATE TABLE IF NOT EXISTS leaderboard_entries (
+            household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+            time_window TEXT NOT NULL CHECK(time_window IN ('week', 'month', 'all_time')),
+            user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+            rank INTEGER NOT NULL,
             points INTEGER NOT NULL,
-            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+            tasks_completed INTEGER NOT NULL,
+            window_start DATE,
+            computed_at DATETIME NOT NULL,
+            PRIMARY KEY (household_id, time_window, user_id)
         )
         "#,
     )
@@ -250,16 +1249,15 @@ async fn create_test_schema(pool: &SqlitePool) {
     .await
     .unwrap();
 
-    // Task consequences table
     sqlx::query(
         r#"
-        CREATE TABLE IF NOT EXISTS task_consequences (
-            id TEXT PRIMARY KEY,
-            task_id TEXT NOT NULL,
-            consequence_type TEXT NOT NULL,
-            trigger_type TEXT NOT NULL,
-            consequence_id TEXT NOT NULL,
-            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        CREATE TABLE IF NOT EXISTS reward_wishlist (
+            household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+            user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+            reward_id TEXT NOT NULL REFERENCES rewards(id) ON DELETE CASCADE,
+            notified_at DATETIME,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (user_id, reward_id)
         )
         "#,
     )
@@ -267,18 +1265,14 @@ async fn create_test_schema(pool: &SqlitePool) {
     .await
     .unwrap();
 
-    // Rewards table
     sqlx::query(
         r#"
-        CREATE TABLE IF NOT EXISTS rewards (
-            id TEXT PRIMARY KEY NOT NULL,
-            household_id TEXT NOT NULL REFERENCES households(id),
-            name TEXT NOT NULL,
-            description TEXT NOT NULL DEFAULT '',
-            point_cost INTEGER,
-            is_purchasable BOOLEAN NOT NULL DEFAULT FALSE,
-            requires_confirmation BOOLEAN NOT NULL DEFAULT FALSE,
-            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+        CREATE TABLE IF NOT EXISTS member_task_exclusions (
+            household_id TEXT NOT NULL REFERENCES households(id) ON DELETE CASCADE,
+            user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+            task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (user_id, task_id)
         )
         "#,
     )
@@ -286,16 +1280,22 @@ async fn create_test_schema(pool: &SqlitePool) {
     .await
     .unwrap();
 
-    // Punishments table
+    // Per-assignee period results table
     sqlx::query(
         r#"
-        CREATE TABLE IF NOT EXISTS punishments (
+        CREATE TABLE IF NOT EXISTS task_assignee_period_results (
             id TEXT PRIMARY KEY NOT NULL,
-            household_id TEXT NOT NULL REFERENCES households(id),
-            name TEXT NOT NULL,
-            description TEXT NOT NULL DEFAULT '',
-            requires_confirmation BOOLEAN NOT NULL DEFAULT FALSE,
-            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+            task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+            user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+            period_start DATE NOT NULL,
+            period_end DATE NOT NULL,
+            status TEXT NOT NULL CHECK(status IN ('completed', 'failed', 'skipped')),
+            completions_count INTEGER NOT NULL,
+            target_count INTEGER NOT NULL,
+            finalized_at DATETIME NOT NULL,
+            finalized_by TEXT NOT NULL DEFAULT 'system',
+            notes TEXT,
+            UNIQUE (task_id, user_id, period_start)
         )
         "#,
     )
@@ -303,14 +1303,15 @@ async fn create_test_schema(pool: &SqlitePool) {
     .await
     .unwrap();
 
-    // Task-reward associations
+    // Task claims table
     sqlx::query(
         r#"
-        CREATE TABLE IF NOT EXISTS task_rewards (
-            task_id TEXT NOT NULL REFERENCES tasks(id),
-            reward_id TEXT NOT NULL REFERENCES rewards(id),
-            amount INTEGER NOT NULL DEFAULT 1,
-            PRIMARY KEY (task_id, reward_id)
+        CREATE TABLE IF NOT EXISTS task_claims (
+            task_id TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+            period_start DATE NOT NULL,
+            user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+            claimed_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            PRIMARY KEY (task_id, period_start)
         )
         "#,
     )
@@ -318,33 +1319,42 @@ async fn create_test_schema(pool: &SqlitePool) {
     .await
     .unwrap();
 
-    // Task-punishment associations
+    // Activity logs table
     sqlx::query(
         r#"
-        CREATE TABLE IF NOT EXISTS task_punishments (
-            task_id TEXT NOT NULL REFERENCES tasks(id),
-            punishment_id TEXT NOT NULL REFERENCES punishments(id),
-            amount INTEGER NOT NULL DEFAULT 1,
-            PRIMARY KEY (task_id, punishment_id)
+        CREATE TABLE IF NOT EXISTS activity_logs (
+            id TEXT PRIMARY KEY NOT NULL,
+            household_id TEXT NOT NULL REFERENCES households(id),
+            actor_id TEXT NOT NULL REFERENCES users(id),
+            affected_user_id TEXT REFERENCES users(id),
+            activity_type TEXT NOT NULL,
+            entity_type TEXT,
+            entity_id TEXT,
+            details TEXT,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
         )
         "#,
     )
     .execute(pool)
     .await
     .unwrap();
-
-    // User rewards table
     sqlx::query(
         r#"
-        CREATE TABLE IF NOT EXISTS user_rewards (
+        CREATE TABLE IF NOT EXISTS notes (
             id TEXT PRIMARY KEY NOT NULL,
-            user_id TEXT NOT NULL REFERENCES users(id),
-            reward_id TEXT NOT NULL REFERENCES rewards(id),
             household_id TEXT NOT NULL REFERENCES households(id),
-            assigned_by TEXT REFERENCES users(id),
-            is_purchased BOOLEAN NOT NULL DEFAULT FALSE,
-            redeemed BOOLEAN NOT NULL DEFAULT FALSE,
-            assigned_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+            user_id TEXT NOT NULL REFERENCES users(id),
+            title TEXT NOT NULL,
+            content TEXT NOT NULL DEFAULT '',
+            is_shared BOOLEAN NOT NULL DEFAULT false,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            version INTEGER NOT NULL DEFAULT 1,
+            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            deleted_at DATETIME,
+            pinned BOOLEAN NOT NULL DEFAULT 0,
+            color TEXT,
+            sort_order INTEGER,
+            archived BOOLEAN NOT NULL DEFAULT 0
         )
         "#,
     )
@@ -352,36 +1362,39 @@ async fn create_test_schema(pool: &SqlitePool) {
     .await
     .unwrap();
 
-    // User punishments table
     sqlx::query(
         r#"
-        CREATE TABLE IF NOT EXISTS user_punishments (
+        CREATE TABLE IF NOT EXISTS journal_entries (
             id TEXT PRIMARY KEY NOT NULL,
-            user_id TEXT NOT NULL REFERENCES users(id),
-            punishment_id TEXT NOT NULL REFERENCES punishments(id),
             household_id TEXT NOT NULL REFERENCES households(id),
-            assigned_by TEXT NOT NULL REFERENCES users(id),
-            task_completion_id TEXT REFERENCES task_completions(id),
-            completed BOOLEAN NOT NULL DEFAULT FALSE,
-            assigned_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+            user_id TEXT NOT NULL REFERENCES users(id),
+            title TEXT NOT NULL DEFAULT '',
+            content TEXT NOT NULL,
+            entry_date DATE NOT NULL,
+            visibility TEXT NOT NULL DEFAULT 'private',
+            mood INTEGER,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            deleted_at DATETIME
         )
         "#,
     )
     .execute(pool)
     .await
     .unwrap();
+}
 
-    // Activity logs table
+/// Create the event log, rollup and cursor tables, also for modules with their own test schema
+pub async fn create_event_tables(pool: &SqlitePool) {
     sqlx::query(
         r#"
-        CREATE TABLE IF NOT EXISTS activity_logs (
-            id TEXT PRIMARY KEY NOT NULL,
-            household_id TEXT NOT NULL REFERENCES households(id),
-            user_id TEXT REFERENCES users(id),
-            activity_type TEXT NOT NULL,
-            entity_type TEXT,
-            entity_id TEXT,
-            metadata TEXT,
+        CREATE TABLE IF NOT EXISTS events (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            task_id TEXT NOT NULL,
+            user_id TEXT,
+            kind TEXT NOT NULL CHECK(kind IN ('completion', 'period_completed', 'period_failed', 'period_skipped')),
+            day DATE NOT NULL,
+            delta INTEGER NOT NULL,
             created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
         )
         "#,
@@ -389,6 +1402,116 @@ async fn create_test_schema(pool: &SqlitePool) {
     .execute(pool)
     .await
     .unwrap();
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_events_task ON events(task_id, id)")
+        .execute(pool)
+        .await
+        .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS task_daily_rollups (
+            task_id TEXT NOT NULL,
+            day DATE NOT NULL,
+            completions INTEGER NOT NULL DEFAULT 0,
+            periods_completed INTEGER NOT NULL DEFAULT 0,
+            periods_failed INTEGER NOT NULL DEFAULT 0,
+            periods_skipped INTEGER NOT NULL DEFAULT 0,
+            PRIMARY KEY (task_id, day)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    sqlx::query("CREATE TABLE IF NOT EXISTS event_rollup_cursor (id INTEGER PRIMARY KEY CHECK(id = 1), last_event_id INTEGER NOT NULL)")
+        .execute(pool)
+        .await
+        .unwrap();
+
+    sqlx::query("INSERT OR IGNORE INTO event_rollup_cursor (id, last_event_id) VALUES (1, 0)")
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+// ============================================================================
+// API Harness
+// ============================================================================
+
+/// JWT secret used by [`create_test_config`]
+pub const TEST_JWT_SECRET: &str = "test-jwt-secret";
+
+/// Create an in-memory SQLite database with the real migrations applied,
+/// for tests that exercise handlers against the production schema
+pub async fn create_migrated_pool() -> Pool<Sqlite> {
+    let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    pool
+}
+
+/// Configuration for handler tests: no mail, push, OIDC or chat bridge, and
+/// no rate limiting of mutating requests
+pub fn create_test_config() -> Config {
+    Config {
+        host: "127.0.0.1".to_string(),
+        port: 0,
+        database_url: "sqlite::memory:".to_string(),
+        jwt_secret: TEST_JWT_SECRET.to_string(),
+        access_token_expiration_minutes: 15,
+        refresh_token_expiration_days: 30,
+        static_files_path: None,
+        cors_origins: vec![],
+        legal_dir: None,
+        vapid_private_key: None,
+        vapid_subject: "mailto:admin@localhost".to_string(),
+        push_reminder_lead_minutes: 30,
+        oidc_issuer_url: None,
+        oidc_client_id: None,
+        oidc_client_secret: None,
+        oidc_redirect_url: None,
+        oidc_provider_name: "SSO".to_string(),
+        rate_limit_per_minute: 0,
+        rate_limit_burst: 0,
+        trusted_proxies: vec![],
+        smtp_host: None,
+        smtp_port: 587,
+        smtp_security: "none".to_string(),
+        smtp_username: None,
+        smtp_password: None,
+        smtp_from: "Haushalt <noreply@localhost>".to_string(),
+        public_url: "http://localhost:8080".to_string(),
+        upload_dir: std::env::temp_dir().join("haushalt-test-uploads").to_string_lossy().into_owned(),
+        max_upload_bytes: 5 * 1024 * 1024,
+        job_check_interval_minutes: 1,
+        jobs_disabled: vec![],
+        job_intervals: vec![],
+        telegram_bot_token: None,
+        telegram_chat_id: None,
+        matrix_homeserver_url: None,
+        matrix_access_token: None,
+        matrix_room_id: None,
+        chat_bridge_events: vec![],
+        backup_dir: std::env::temp_dir().join("haushalt-test-backups").to_string_lossy().into_owned(),
+        backup_retention: 7,
+        backup_nightly_hour: None,
+        otlp_endpoint: None,
+        openapi_ui_enabled: false,
+    }
+}
+
+/// Application state for handler tests, backed by `pool`
+pub fn create_test_app_state(pool: SqlitePool) -> AppState {
+    AppState {
+        db: pool.clone(),
+        config: create_test_config(),
+        login_rate_limiter: Arc::new(RateLimiter::new(100, 15 * 60)),
+        mutation_rate_limiter: None,
+        jobs: Arc::new(JobRunner::new(pool, JobConfig::default())),
+        push: None,
+        chat_bridge: None,
+    }
 }
 
 // ============================================================================
@@ -496,6 +1619,11 @@ pub struct TestTaskBuilder {
     points_reward: Option<i64>,
     points_penalty: Option<i64>,
     due_time: Option<String>,
+    effort_minutes: Option<i32>,
+    active_from: Option<String>,
+    active_until: Option<String>,
+    ends_on: Option<NaiveDate>,
+    max_occurrences: Option<i32>,
     habit_type: HabitType,
     category_id: Option<Uuid>,
     archived: bool,
@@ -569,6 +1697,27 @@ impl TestTaskBuilder {
         self
     }
 
+    pub fn with_effort_minutes(mut self, minutes: i32) -> Self {
+        self.effort_minutes = Some(minutes);
+        self
+    }
+
+    pub fn with_season(mut self, from: &str, until: &str) -> Self {
+        self.active_from = Some(from.to_string());
+        self.active_until = Some(until.to_string());
+        self
+    }
+
+    pub fn with_ends_on(mut self, ends_on: NaiveDate) -> Self {
+        self.ends_on = Some(ends_on);
+        self
+    }
+
+    pub fn with_max_occurrences(mut self, max_occurrences: i32) -> Self {
+        self.max_occurrences = Some(max_occurrences);
+        self
+    }
+
     pub fn with_habit_type(mut self, habit_type: HabitType) -> Self {
         self.habit_type = habit_type;
         self
@@ -605,10 +1754,11 @@ impl TestTaskBuilder {
             INSERT INTO tasks (
                 id, household_id, title, description, recurrence_type, recurrence_value,
                 assigned_user_id, target_count, time_period, allow_exceed_target,
-                requires_review, points_reward, points_penalty, due_time, habit_type,
-                category_id, archived, paused, created_at, updated_at
+                requires_review, points_reward, points_penalty, due_time, effort_minutes,
+                active_from, active_until, ends_on, max_occurrences, habit_type, category_id, archived, paused,
+                created_at, updated_at
             )
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(id.to_string())
@@ -625,6 +1775,11 @@ impl TestTaskBuilder {
         .bind(self.points_reward)
         .bind(self.points_penalty)
         .bind(&self.due_time)
+        .bind(self.effort_minutes)
+        .bind(&self.active_from)
+        .bind(&self.active_until)
+        .bind(self.ends_on)
+        .bind(self.max_occurrences)
         .bind(self.habit_type.as_str())
         .bind(self.category_id.map(|c| c.to_string()))
         .bind(self.archived)
@@ -635,6 +1790,15 @@ impl TestTaskBuilder {
         .await
         .unwrap();
 
+        if let Some(user_id) = self.assigned_user_id {
+            sqlx::query("INSERT INTO task_assignees (task_id, user_id) VALUES (?, ?)")
+                .bind(id.to_string())
+                .bind(user_id.to_string())
+                .execute(&self.pool)
+                .await
+                .unwrap();
+        }
+
         Task {
             id,
             household_id: self.household_id,
@@ -643,20 +1807,32 @@ impl TestTaskBuilder {
             recurrence_type: self.recurrence_type,
             recurrence_value: self.recurrence_value,
             assigned_user_id: self.assigned_user_id,
+            assignee_ids: self.assigned_user_id.into_iter().collect(),
             target_count: self.target_count,
             time_period: self.time_period,
             allow_exceed_target: self.allow_exceed_target,
             requires_review: self.requires_review,
+            reviewer_type: Default::default(),
+            reviewer_id: None,
             points_reward: self.points_reward,
             points_penalty: self.points_penalty,
             due_time: self.due_time,
+            effort_minutes: self.effort_minutes,
+            active_from: self.active_from,
+            active_until: self.active_until,
+            ends_on: self.ends_on,
+            max_occurrences: self.max_occurrences,
             habit_type: self.habit_type,
             category_id: self.category_id,
             category_name: None,
+            tag_ids: Vec::new(),
             archived: self.archived,
             paused: self.paused,
             suggestion: None,
             suggested_by: None,
+            postponed_from: None,
+            postponed_to: None,
+            version: 1,
             created_at: now,
             updated_at: now,
         }
@@ -680,6 +1856,11 @@ pub fn create_test_task(pool: &SqlitePool, household_id: &Uuid) -> TestTaskBuild
         points_reward: None,
         points_penalty: None,
         due_time: None,
+        effort_minutes: None,
+        active_from: None,
+        active_until: None,
+        ends_on: None,
+        max_occurrences: None,
         habit_type: HabitType::Good,
         category_id: None,
         archived: false,
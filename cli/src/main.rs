@@ -0,0 +1,249 @@
+//! Command-line client for Haushalt
+//!
+//! Covers the everyday actions that are handy from a terminal, a cron job or a
+//! kitchen display without a browser: logging in, listing due tasks,
+//! completing a task and posting to the household chat.
+
+mod session;
+
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+
+use client::{Client, ClientError, ReqwestTransport};
+use shared::LoginRequest;
+use uuid::Uuid;
+
+use session::Session;
+
+const USAGE: &str = "\
+Usage: haushalt <command>
+
+Commands:
+  login <username> [--server <url>]       Log in and remember the session
+  households                              List your households
+  tasks <household-id>                    List tasks due today
+  complete <household-id> <task-id>       Complete a task
+  chat <household-id> <message...>        Post a chat message
+
+Environment:
+  HAUSHALT_URL        Server URL (default http://localhost:8080)
+  HAUSHALT_TOKEN      Access or API token to use instead of the stored login
+  HAUSHALT_PASSWORD   Password for `login` instead of prompting";
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Login { username: String, server_url: Option<String> },
+    Households,
+    Tasks { household_id: Uuid },
+    Complete { household_id: Uuid, task_id: Uuid },
+    Chat { household_id: Uuid, message: String },
+    Help,
+}
+
+fn parse_id(value: Option<&String>, what: &str) -> Result<Uuid, String> {
+    let value = value.ok_or_else(|| format!("Missing {}", what))?;
+    Uuid::parse_str(value).map_err(|_| format!("Invalid {}: {}", what, value))
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let Some((command, rest)) = args.split_first() else {
+        return Ok(Command::Help);
+    };
+    match command.as_str() {
+        "login" => {
+            let mut username = None;
+            let mut server_url = None;
+            let mut rest = rest.iter();
+            while let Some(arg) = rest.next() {
+                match arg.as_str() {
+                    "--server" => server_url = Some(rest.next().ok_or("Missing URL after --server")?.clone()),
+                    _ if username.is_none() => username = Some(arg.clone()),
+                    _ => return Err(format!("Unexpected argument: {}", arg)),
+                }
+            }
+            let username = username.ok_or("Missing username")?;
+            Ok(Command::Login { username, server_url })
+        }
+        "households" => Ok(Command::Households),
+        "tasks" => Ok(Command::Tasks {
+            household_id: parse_id(rest.first(), "household ID")?,
+        }),
+        "complete" => Ok(Command::Complete {
+            household_id: parse_id(rest.first(), "household ID")?,
+            task_id: parse_id(rest.get(1), "task ID")?,
+        }),
+        "chat" => {
+            let household_id = parse_id(rest.first(), "household ID")?;
+            let message = rest[1..].join(" ");
+            if message.trim().is_empty() {
+                return Err("Missing message".to_string());
+            }
+            Ok(Command::Chat { household_id, message })
+        }
+        "help" | "--help" | "-h" => Ok(Command::Help),
+        other => Err(format!("Unknown command: {}", other)),
+    }
+}
+
+/// Password from `HAUSHALT_PASSWORD`, or read from stdin
+fn read_password() -> io::Result<String> {
+    if let Ok(password) = env::var("HAUSHALT_PASSWORD") {
+        return Ok(password);
+    }
+    eprint!("Password: ");
+    io::stderr().flush()?;
+    let mut password = String::new();
+    io::stdin().lock().read_line(&mut password)?;
+    Ok(password.trim_end_matches(['\r', '\n']).to_string())
+}
+
+fn describe(error: ClientError) -> String {
+    match error {
+        ClientError::NotLoggedIn => "Not logged in; run `haushalt login` or set HAUSHALT_TOKEN".to_string(),
+        ClientError::SessionExpired => "Session expired; run `haushalt login` again".to_string(),
+        other => other.to_string(),
+    }
+}
+
+async fn execute(client: &mut Client<ReqwestTransport, Session>, command: Command) -> Result<(), String> {
+    match command {
+        Command::Help => println!("{}", USAGE),
+        Command::Login { username, .. } => {
+            let password = read_password().map_err(|e| e.to_string())?;
+            let request = LoginRequest { username, password };
+            let auth = client.login(request).await.map_err(describe)?;
+            println!("Logged in as {}", auth.user.username);
+        }
+        Command::Households => {
+            for household in client.list_households().await.map_err(describe)? {
+                println!("{}  {}", household.id, household.name);
+            }
+        }
+        Command::Tasks { household_id } => {
+            let tasks = client.get_due_tasks(&household_id.to_string()).await.map_err(describe)?;
+            if tasks.is_empty() {
+                println!("Nothing due today");
+            }
+            for task in tasks {
+                let done = if task.completions_today >= task.task.target_count { "x" } else { " " };
+                println!(
+                    "[{}] {}  {} ({}/{})",
+                    done, task.task.id, task.task.title, task.completions_today, task.task.target_count
+                );
+            }
+        }
+        Command::Complete { household_id, task_id } => {
+            client.complete_task(&household_id.to_string(), &task_id.to_string()).await.map_err(describe)?;
+            println!("Task completed");
+        }
+        Command::Chat { household_id, message } => {
+            client
+                .send_chat_message(&household_id.to_string(), &message, None)
+                .await
+                .map_err(describe)?;
+        }
+    }
+    Ok(())
+}
+
+async fn run(command: Command) -> Result<(), String> {
+    let mut session = Session::load();
+    if let Command::Login {
+        server_url: Some(ref url),
+        ..
+    } = command
+    {
+        session.server_url = url.clone();
+    }
+
+    let original = session.clone();
+    let mut client = Client::new(ReqwestTransport::new(), session.server_url.clone(), session);
+    let result = execute(&mut client, command).await;
+
+    // Login and token refreshes replace the tokens; refresh tokens are
+    // single-use, so the new pair must be saved even if the command failed
+    let session = client.into_tokens();
+    if session != original {
+        session.save().map_err(|e| format!("Could not save session: {}", e))?;
+    }
+    result
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(command).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_parse_login() {
+        assert_eq!(
+            parse_args(&args("login alice --server https://h.example.com")),
+            Ok(Command::Login {
+                username: "alice".to_string(),
+                server_url: Some("https://h.example.com".to_string()),
+            })
+        );
+        assert!(parse_args(&args("login")).is_err());
+        assert!(parse_args(&args("login alice --server")).is_err());
+    }
+
+    #[test]
+    fn test_parse_task_commands() {
+        let household_id = Uuid::new_v4();
+        let task_id = Uuid::new_v4();
+        assert_eq!(
+            parse_args(&args(&format!("complete {} {}", household_id, task_id))),
+            Ok(Command::Complete { household_id, task_id })
+        );
+        assert_eq!(
+            parse_args(&args(&format!("tasks {}", household_id))),
+            Ok(Command::Tasks { household_id })
+        );
+        assert!(parse_args(&args("tasks not-a-uuid")).is_err());
+        assert!(parse_args(&args(&format!("complete {}", household_id))).is_err());
+    }
+
+    #[test]
+    fn test_parse_chat_joins_message_words() {
+        let household_id = Uuid::new_v4();
+        assert_eq!(
+            parse_args(&args(&format!("chat {} Dinner is ready", household_id))),
+            Ok(Command::Chat {
+                household_id,
+                message: "Dinner is ready".to_string(),
+            })
+        );
+        assert!(parse_args(&args(&format!("chat {}", household_id))).is_err());
+    }
+
+    #[test]
+    fn test_parse_help_and_unknown() {
+        assert_eq!(parse_args(&[]), Ok(Command::Help));
+        assert_eq!(parse_args(&args("--help")), Ok(Command::Help));
+        assert!(parse_args(&args("frobnicate")).is_err());
+    }
+}
@@ -0,0 +1,111 @@
+//! Login state kept between CLI invocations
+//!
+//! `haushalt login` stores the server URL and tokens in
+//! `$XDG_CONFIG_HOME/haushalt/cli.json` (or `~/.config/haushalt/cli.json`).
+//! Scripts and cron jobs can skip the login and set `HAUSHALT_URL` and
+//! `HAUSHALT_TOKEN` (for example a personal API token) instead.
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use client::TokenStore;
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_SERVER_URL: &str = "http://localhost:8080";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    pub server_url: String,
+    pub token: Option<String>,
+    pub refresh_token: Option<String>,
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Self {
+            server_url: DEFAULT_SERVER_URL.to_string(),
+            token: None,
+            refresh_token: None,
+        }
+    }
+}
+
+fn session_path() -> Option<PathBuf> {
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_dir.join("haushalt").join("cli.json"))
+}
+
+impl Session {
+    /// The stored session with `HAUSHALT_URL` and `HAUSHALT_TOKEN` applied on top.
+    /// A token from the environment is used as is and never refreshed.
+    pub fn load() -> Self {
+        let mut session: Session = session_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+
+        if let Ok(url) = env::var("HAUSHALT_URL") {
+            session.server_url = url;
+        }
+        if let Ok(token) = env::var("HAUSHALT_TOKEN") {
+            session.token = Some(token);
+            session.refresh_token = None;
+        }
+        session
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let path = session_path()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Neither XDG_CONFIG_HOME nor HOME is set"))?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(&path, json)?;
+
+        // The file holds a refresh token, so keep it private
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o600))?;
+        }
+        Ok(())
+    }
+}
+
+impl TokenStore for Session {
+    fn token(&self) -> Option<String> {
+        self.token.clone()
+    }
+
+    fn refresh_token(&self) -> Option<String> {
+        self.refresh_token.clone()
+    }
+
+    fn store(&mut self, token: String, refresh_token: String) {
+        self.token = Some(token);
+        self.refresh_token = Some(refresh_token);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stored_tokens_replace_the_old_pair() {
+        let mut session = Session {
+            token: Some("old".to_string()),
+            refresh_token: Some("old-refresh".to_string()),
+            ..Session::default()
+        };
+        session.store("new".to_string(), "new-refresh".to_string());
+        assert_eq!(session.token(), Some("new".to_string()));
+        assert_eq!(session.refresh_token(), Some("new-refresh".to_string()));
+        assert_eq!(session.server_url, DEFAULT_SERVER_URL);
+    }
+}
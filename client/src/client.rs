@@ -0,0 +1,436 @@
+use std::future::Future;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use shared::{ApiError, ApiSuccess, AuthResponse, RefreshTokenRequest};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::transport::{Method, Request, Response, Transport};
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ClientError {
+    #[error("Not logged in")]
+    NotLoggedIn,
+    #[error("Session expired. Please log in again.")]
+    SessionExpired,
+    /// Error response from the server. `request_id` is only set for server
+    /// errors (5xx), so a report can be matched with the server log.
+    #[error("{message}{}", request_id.as_ref().map(|id| format!(" (Request ID: {})", id)).unwrap_or_default())]
+    Api {
+        status: u16,
+        error: String,
+        message: String,
+        request_id: Option<String>,
+    },
+    #[error("Request failed: {0}")]
+    Transport(String),
+    #[error("Unexpected response: {0}")]
+    Decode(String),
+}
+
+impl From<ClientError> for String {
+    fn from(error: ClientError) -> Self {
+        error.to_string()
+    }
+}
+
+/// Where the client keeps its access and refresh tokens
+#[allow(async_fn_in_trait)]
+pub trait TokenStore {
+    fn token(&self) -> Option<String>;
+    fn refresh_token(&self) -> Option<String>;
+    /// Called after login and after every refresh. Refresh tokens are
+    /// single-use, so the new pair must replace the old one.
+    fn store(&mut self, token: String, refresh_token: String);
+
+    /// Called when the session cannot be refreshed any more
+    fn clear(&mut self) {}
+
+    /// Run `refresh` and store the tokens it returns. A store shared by
+    /// several clients at once, like the browser's local storage, overrides
+    /// this so only one of them refreshes and the others wait for its tokens:
+    /// a second refresh with the same single-use token would fail.
+    async fn refresh_with(
+        &mut self,
+        refresh: impl Future<Output = Result<AuthResponse, ClientError>>,
+    ) -> Result<(), ClientError> {
+        let auth = refresh.await?;
+        self.store(auth.token, auth.refresh_token);
+        Ok(())
+    }
+}
+
+/// Tokens held in memory only, for bots and tests
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MemoryTokens {
+    pub token: Option<String>,
+    pub refresh_token: Option<String>,
+}
+
+impl TokenStore for MemoryTokens {
+    fn token(&self) -> Option<String> {
+        self.token.clone()
+    }
+
+    fn refresh_token(&self) -> Option<String> {
+        self.refresh_token.clone()
+    }
+
+    fn store(&mut self, token: String, refresh_token: String) {
+        self.token = Some(token);
+        self.refresh_token = Some(refresh_token);
+    }
+
+    fn clear(&mut self) {
+        self.token = None;
+        self.refresh_token = None;
+    }
+}
+
+/// API URL for a path like `/households`. An empty `server_url` gives a
+/// relative URL, as used by the frontend.
+pub fn api_url(server_url: &str, path: &str) -> String {
+    format!("{}/api{}", server_url.trim_end_matches('/'), path)
+}
+
+/// Unwrap the `ApiSuccess` envelope of a 2xx response, or turn the `ApiError`
+/// of any other response into [`ClientError::Api`]
+pub fn decode_response<T: DeserializeOwned>(response: &Response) -> Result<T, ClientError> {
+    if (200..300).contains(&response.status) {
+        let success: ApiSuccess<T> =
+            serde_json::from_str(&response.body).map_err(|e| ClientError::Decode(e.to_string()))?;
+        return Ok(success.data);
+    }
+    Err(error_response(response))
+}
+
+fn error_response(response: &Response) -> ClientError {
+    let error: ApiError = serde_json::from_str(&response.body).unwrap_or(ApiError {
+        error: "unknown".to_string(),
+        message: "An unknown error occurred".to_string(),
+    });
+    ClientError::Api {
+        status: response.status,
+        error: error.error,
+        message: error.message,
+        request_id: response.request_id.clone().filter(|_| response.status >= 500),
+    }
+}
+
+fn to_json(body: Option<impl Serialize>) -> Result<Option<String>, ClientError> {
+    body.map(|b| serde_json::to_string(&b))
+        .transpose()
+        .map_err(|e| ClientError::Decode(e.to_string()))
+}
+
+async fn send_raw<T: Transport>(
+    transport: &T,
+    server_url: &str,
+    method: Method,
+    path: &str,
+    body: Option<String>,
+    token: Option<&str>,
+    idempotency_key: Option<&str>,
+) -> Result<Response, ClientError> {
+    let mut request = Request::new(method, api_url(server_url, path)).json_body(body);
+    if let Some(token) = token {
+        request = request.header("Authorization", format!("Bearer {}", token));
+    }
+    if let Some(key) = idempotency_key {
+        request = request.header("Idempotency-Key", key);
+    }
+    transport.send(request).await.map_err(ClientError::Transport)
+}
+
+/// Typed calls against the Haushalt API. The endpoints themselves are in
+/// `endpoints.rs`.
+pub struct Client<T: Transport, S: TokenStore> {
+    transport: T,
+    pub(crate) server_url: String,
+    pub(crate) tokens: S,
+}
+
+impl<T: Transport, S: TokenStore> Client<T, S> {
+    pub fn new(transport: T, server_url: impl Into<String>, tokens: S) -> Self {
+        Self {
+            transport,
+            server_url: server_url.into(),
+            tokens,
+        }
+    }
+
+    pub fn tokens(&self) -> &S {
+        &self.tokens
+    }
+
+    pub fn into_tokens(self) -> S {
+        self.tokens
+    }
+
+    /// Send an authenticated request to an API path, refreshing the tokens
+    /// once on 401. POST requests carry an Idempotency-Key that is reused for
+    /// the retry, so a completion or purchase never runs twice.
+    pub async fn request<R: DeserializeOwned>(
+        &mut self,
+        method: Method,
+        path: &str,
+        body: Option<impl Serialize>,
+    ) -> Result<R, ClientError> {
+        let body = to_json(body)?;
+        let idempotency_key = (method == Method::Post).then(|| Uuid::new_v4().to_string());
+
+        let token = self.tokens.token().ok_or(ClientError::NotLoggedIn)?;
+        let response = self
+            .send_raw(method, path, body.clone(), Some(&token), idempotency_key.as_deref())
+            .await?;
+        if response.status != 401 {
+            return decode_response(&response);
+        }
+
+        self.refresh_session().await?;
+        let token = self.tokens.token().ok_or(ClientError::NotLoggedIn)?;
+        let response = self
+            .send_raw(method, path, body, Some(&token), idempotency_key.as_deref())
+            .await?;
+        decode_response(&response)
+    }
+
+    /// Send a request without credentials, for login and other public endpoints
+    pub async fn request_public<R: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<impl Serialize>,
+    ) -> Result<R, ClientError> {
+        let response = self.send_raw(method, path, to_json(body)?, None, None).await?;
+        decode_response(&response)
+    }
+
+    /// GET a public page that is served as plain text rather than JSON
+    pub async fn request_text(&self, path: &str) -> Result<String, ClientError> {
+        let response = self.send_raw(Method::Get, path, None, None, None).await?;
+        if !(200..300).contains(&response.status) {
+            return Err(error_response(&response));
+        }
+        Ok(response.body)
+    }
+
+    /// Trade the refresh token for a new token pair. If the server rejects
+    /// it, the tokens are cleared and the session has expired.
+    pub async fn refresh_session(&mut self) -> Result<(), ClientError> {
+        let result = match self.tokens.refresh_token() {
+            Some(refresh_token) => {
+                let (transport, server_url) = (&self.transport, self.server_url.as_str());
+                let refresh = async move {
+                    let body = to_json(Some(RefreshTokenRequest { refresh_token }))?;
+                    let response =
+                        send_raw(transport, server_url, Method::Post, "/auth/refresh", body, None, None).await?;
+                    decode_response::<AuthResponse>(&response)
+                };
+                self.tokens.refresh_with(refresh).await
+            }
+            None => Err(ClientError::SessionExpired),
+        };
+
+        match result {
+            Err(ClientError::Transport(e)) => Err(ClientError::Transport(e)),
+            Err(_) => {
+                self.tokens.clear();
+                Err(ClientError::SessionExpired)
+            }
+            Ok(()) => Ok(()),
+        }
+    }
+
+    async fn send_raw(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<String>,
+        token: Option<&str>,
+        idempotency_key: Option<&str>,
+    ) -> Result<Response, ClientError> {
+        send_raw(&self.transport, &self.server_url, method, path, body, token, idempotency_key).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+
+    /// Answers requests from a queue and records what was sent
+    #[derive(Default)]
+    struct MockTransport {
+        responses: RefCell<VecDeque<Response>>,
+        sent: RefCell<Vec<Request>>,
+    }
+
+    impl MockTransport {
+        fn respond(self, status: u16, body: serde_json::Value) -> Self {
+            self.responses.borrow_mut().push_back(Response {
+                status,
+                body: body.to_string(),
+                request_id: Some("req-1".to_string()),
+            });
+            self
+        }
+    }
+
+    impl Transport for &MockTransport {
+        async fn send(&self, request: Request) -> Result<Response, String> {
+            self.sent.borrow_mut().push(request);
+            self.responses
+                .borrow_mut()
+                .pop_front()
+                .ok_or_else(|| "no response queued".to_string())
+        }
+    }
+
+    fn error_body(message: &str) -> serde_json::Value {
+        serde_json::json!({ "error": "some_error", "message": message })
+    }
+
+    #[test]
+    fn test_api_url_ignores_trailing_slash() {
+        assert_eq!(
+            api_url("https://haushalt.example.com/", "/households"),
+            "https://haushalt.example.com/api/households"
+        );
+        assert_eq!(api_url("http://localhost:8080", "/auth/login"), "http://localhost:8080/api/auth/login");
+        assert_eq!(api_url("", "/households"), "/api/households");
+    }
+
+    #[test]
+    fn test_decode_response_errors() {
+        let response = |status: u16, body: &str| Response {
+            status,
+            body: body.to_string(),
+            request_id: Some("req-1".to_string()),
+        };
+
+        let err = decode_response::<serde_json::Value>(&response(403, &error_body("Nope").to_string())).unwrap_err();
+        assert_eq!(
+            err,
+            ClientError::Api {
+                status: 403,
+                error: "some_error".to_string(),
+                message: "Nope".to_string(),
+                request_id: None,
+            }
+        );
+
+        match decode_response::<serde_json::Value>(&response(502, "Bad Gateway")).unwrap_err() {
+            ClientError::Api { message, request_id, .. } => {
+                assert_eq!(message, "An unknown error occurred");
+                assert_eq!(request_id.as_deref(), Some("req-1"));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+
+        let ok: i32 = decode_response(&response(200, r#"{"success":true,"data":7}"#)).unwrap();
+        assert_eq!(ok, 7);
+
+        let err = decode_response::<i32>(&response(500, &error_body("Boom").to_string())).unwrap_err();
+        assert_eq!(err.to_string(), "Boom (Request ID: req-1)");
+    }
+
+    #[tokio::test]
+    async fn test_login_keeps_tokens_and_builds_endpoint_urls() {
+        let transport = MockTransport::default()
+            .respond(
+                200,
+                serde_json::json!({ "success": true, "data": {
+                    "token": "token",
+                    "refresh_token": "refresh",
+                    "user": { "id": Uuid::new_v4(), "username": "alice", "email": "alice@example.com",
+                              "created_at": "2024-01-01T00:00:00Z", "updated_at": "2024-01-01T00:00:00Z" },
+                }}),
+            )
+            .respond(200, serde_json::json!({ "success": true, "data": [] }));
+        let mut client = Client::new(&transport, "http://localhost:8080/", MemoryTokens::default());
+
+        let request = shared::LoginRequest {
+            username: "alice".to_string(),
+            password: "secret".to_string(),
+        };
+        assert_eq!(client.login(request).await.unwrap().user.username, "alice");
+        assert_eq!(client.tokens().token.as_deref(), Some("token"));
+
+        let tasks = client.get_due_tasks("h1").await.unwrap();
+        assert!(tasks.is_empty());
+
+        let sent = transport.sent.borrow();
+        assert_eq!(sent[0].url, "http://localhost:8080/api/auth/login");
+        assert_eq!(sent[0].header_value("Authorization"), None);
+        assert_eq!(sent[1].method, Method::Get);
+        assert_eq!(sent[1].url, "http://localhost:8080/api/households/h1/tasks/due");
+        assert_eq!(sent[1].header_value("Authorization"), Some("Bearer token"));
+        assert_eq!(
+            client.calendar_feed_url("h1", "abc"),
+            "http://localhost:8080/api/households/h1/tasks/calendar.ics?token=abc"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_refreshes_once_and_reuses_idempotency_key() {
+        let household_id = Uuid::new_v4();
+        let task_id = Uuid::new_v4();
+        let transport = MockTransport::default()
+            .respond(401, error_body("Token expired"))
+            .respond(
+                200,
+                serde_json::json!({ "success": true, "data": {
+                    "token": "new-token",
+                    "refresh_token": "new-refresh",
+                    "user": { "id": Uuid::new_v4(), "username": "alice", "email": "alice@example.com",
+                              "created_at": "2024-01-01T00:00:00Z", "updated_at": "2024-01-01T00:00:00Z" },
+                }}),
+            )
+            .respond(200, serde_json::json!({ "success": true, "data": null }));
+        let tokens = MemoryTokens {
+            token: Some("old-token".to_string()),
+            refresh_token: Some("old-refresh".to_string()),
+        };
+        let mut client = Client::new(&transport, "http://localhost:8080", tokens);
+
+        let _: serde_json::Value = client
+            .request(
+                Method::Post,
+                &format!("/households/{}/tasks/{}/complete", household_id, task_id),
+                None::<&()>,
+            )
+            .await
+            .unwrap();
+
+        let sent = transport.sent.borrow();
+        assert_eq!(sent.len(), 3);
+        assert_eq!(sent[1].url, "http://localhost:8080/api/auth/refresh");
+        assert_eq!(sent[0].header_value("Authorization"), Some("Bearer old-token"));
+        assert_eq!(sent[2].header_value("Authorization"), Some("Bearer new-token"));
+        assert!(sent[0].header_value("Idempotency-Key").is_some());
+        assert_eq!(sent[0].header_value("Idempotency-Key"), sent[2].header_value("Idempotency-Key"));
+        assert_eq!(client.tokens().refresh_token.as_deref(), Some("new-refresh"));
+    }
+
+    #[tokio::test]
+    async fn test_failed_refresh_reports_expired_session() {
+        let transport = MockTransport::default()
+            .respond(401, error_body("Token expired"))
+            .respond(401, error_body("Invalid refresh token"));
+        let tokens = MemoryTokens {
+            token: Some("old-token".to_string()),
+            refresh_token: Some("old-refresh".to_string()),
+        };
+        let mut client = Client::new(&transport, "", tokens);
+
+        let err = client.list_households().await.unwrap_err();
+        assert_eq!(err, ClientError::SessionExpired);
+        assert_eq!(client.tokens(), &MemoryTokens::default());
+
+        let mut logged_out = Client::new(&transport, "", MemoryTokens::default());
+        assert_eq!(logged_out.list_households().await.unwrap_err(), ClientError::NotLoggedIn);
+    }
+}
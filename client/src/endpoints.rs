@@ -0,0 +1,3000 @@
+//! One typed method per JSON endpoint, grouped like the backend's handlers.
+//! Multipart uploads and binary downloads need browser types and stay in the
+//! frontend.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use shared::{
+    ActivityLogWithUsers, AdjustPointsRequest, AllowancePayout, AdminAuditLogPage, AdjustPointsResponse, Announcement, AnnouncementAcknowledgment, ApplyAssignmentPlanRequest, ApplyRebalanceRequest, AssignmentChange, AssignmentPlan, PlannedAssignment, RandomizeAssignmentsRequest, RebalanceRequest, RebalanceSuggestion,
+    Attachment, AttachmentEntity, AuthResponse, CalendarFeedToken, ChangePasswordRequest, CreateChildAccountRequest, DeleteAccountRequest, MemberPermissionsResponse, UpdateMemberPermissionsRequest, ChatMessageWithUser, ChatReactionRequest, ChatReactionSummary, ChatReadMarker, ChatUnreadCount, CreateAnnouncementRequest, CreateChatMessageRequest,
+    CreateExpenseRequest, CreateSettlementRequest, Expense, ExpenseBalances, ExpenseMonthlySummary, ExpenseSettlement,
+    ExpenseWithUser, UpdateExpenseRequest, CreateRecipeRequest, MealPlanEntry, Recipe, SetMealPlanEntryRequest, SetMemberVacationRequest, SkipTaskPeriodRequest, OverridePeriodRequest, TaskClaim, TaskPeriodResult, PostponeTaskRequest, Notification, NotificationList, Paginated, PaginationQuery, InviteCode, CreateInviteCodeRequest, RedeemInviteCodeRequest,
+    CreateShoppingListItemRequest, ShoppingListItem, UpdateShoppingListItemRequest,
+    AdjustInventoryRequest, CreateInventoryItemRequest, InventoryAdjustment, InventoryItem, UpdateInventoryItemRequest,
+    Appliance, CreateApplianceRequest, CreateMaintenanceRequest,
+    CreatePlantRequest, Plant, UpdatePlantRequest,
+    CreatePetRequest, CreatePetScheduleRequest, LogPetCareRequest, Pet, PetCareEntry,
+    CreateShareLinkRequest, ShareLink, SharedPage,
+    ChallengeWithStandings, CreateChallengeRequest,
+    AutomationRule, AutomationRuleRun, CreateAutomationRuleRequest, UpdateAutomationRuleRequest,
+    CreateWebhookRequest, UpdateWebhookRequest, Webhook, ApiToken, CreateApiTokenRequest, CreatedApiToken, AdminUser, HouseholdUsage, TrashItem, TrashItemKind,
+    WeeklyMealPlan,
+    CreateHouseholdRequest, CreateInvitationRequest, CreateJournalEntryRequest, CreateNoteRequest, UpdateHouseholdRequest,
+    CreatePointConditionRequest, CreatePunishmentRequest, CreateRewardRequest, CreateTaskCommentRequest, CreateTaskRequest,
+    CreateUserRequest, ForgotPasswordRequest, Household, HouseholdExport, HouseholdMembership, HouseholdReport, HouseholdSettings, Invitation, InvitationWithHousehold,
+    InviteUserRequest, JournalEntry, JournalEntryWithUser, LeaderboardEntry, LeaderboardWindow, MoodPoint, LoginRequest, MarkChatReadRequest, MemberWithUser,
+    MonthlyStatisticsResponse, Note, NoteWithUser, OidcAuthorizationUrl, OidcCodeExchangeRequest, OidcProviderInfo, PendingPunishmentCompletion, PendingRewardPurchase, PendingReview, PurchaseRewardResponse, RewardPurchase,
+    PendingRewardRedemption, PointCondition, PointHistoryPage, Punishment, PushConfig, PushSubscriptionRequest, RandomPickResult, ResetChildPinRequest, ResetPasswordRequest, RandomRewardPickResult,
+    RefreshTokenRequest, ReorderDashboardTasksRequest, Reward, Task, TaskComment, TaskCommentWithUser, TaskCompletion, TaskPunishmentLink, TaskRewardLink, TaskWithDetails,
+    TaskOccurrence, TaskWithStatus, UpdateAnnouncementRequest, UpdateChatMessageRequest, UpdateHouseholdSettingsRequest,
+    UpdateJournalEntryRequest, UpdateNoteRequest, UpdateOptionWeightRequest, UpdatePunishmentRequest, UpdateRewardRequest,
+    UnsubscribePushRequest, UpdateRoleRequest, UpdateTaskRequest, UpdateUserSettingsRequest, User, UserPunishment,
+    UserPunishmentWithUser, UserReward, UserRewardWithUser, UserSettings, WeeklyStatisticsResponse, WeightedPunishmentOption, WeightedRewardOption, WorkloadReport,
+};
+
+use crate::client::{api_url, Client, ClientError, TokenStore};
+use crate::transport::{Method, Transport};
+
+impl<T: Transport, S: TokenStore> Client<T, S> {
+    // Auth endpoints
+    /// Log in, register or finish an OIDC login, and keep the returned tokens
+    async fn authenticate(&mut self, path: &str, request: impl Serialize) -> Result<AuthResponse, ClientError> {
+        let auth: AuthResponse = self.request_public(Method::Post, path, Some(request)).await?;
+        self.tokens.store(auth.token.clone(), auth.refresh_token.clone());
+        Ok(auth)
+    }
+
+    pub async fn register(&mut self, request: CreateUserRequest) -> Result<AuthResponse, ClientError> {
+        self.authenticate("/auth/register", request).await
+    }
+
+    pub async fn login(&mut self, request: LoginRequest) -> Result<AuthResponse, ClientError> {
+        self.authenticate("/auth/login", request).await
+    }
+
+    pub async fn forgot_password(&mut self, request: ForgotPasswordRequest) -> Result<String, ClientError> {
+        self.request_public(Method::Post, "/auth/forgot-password", Some(request)).await
+    }
+
+    pub async fn reset_password(&mut self, request: ResetPasswordRequest) -> Result<String, ClientError> {
+        self.request_public(Method::Post, "/auth/reset-password", Some(request)).await
+    }
+
+    /// Change the password; sessions on other devices are signed out
+    pub async fn change_password(&mut self, current_password: String, new_password: String) -> Result<String, ClientError> {
+        let request = ChangePasswordRequest {
+            current_password,
+            new_password,
+            refresh_token: self.tokens.refresh_token(),
+        };
+        self.request(Method::Post, "/users/me/password", Some(request)).await
+    }
+
+    /// End the session of a refresh token on the server
+    pub async fn logout(&mut self, refresh_token: String) -> Result<(), ClientError> {
+        let _: serde::de::IgnoredAny = self
+            .request_public(Method::Post, "/auth/logout", Some(RefreshTokenRequest { refresh_token }))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn delete_account(&mut self, password: Option<String>) -> Result<(), ClientError> {
+        self.request::<()>(Method::Delete, "/users/me", Some(DeleteAccountRequest { password })).await
+    }
+
+    pub async fn get_current_user(&mut self) -> Result<User, ClientError> {
+        self.request::<User>(Method::Get, "/auth/me", None::<()>).await
+    }
+
+    // OpenID Connect endpoints
+    pub async fn get_oidc_config(&mut self) -> Result<OidcProviderInfo, ClientError> {
+        self.request_public::<OidcProviderInfo>(Method::Get, "/auth/oidc/config", None::<()>).await
+    }
+
+    pub async fn start_oidc_login(&mut self) -> Result<OidcAuthorizationUrl, ClientError> {
+        self.request_public::<OidcAuthorizationUrl>(Method::Get, "/auth/oidc/login", None::<()>).await
+    }
+
+    pub async fn start_oidc_link(&mut self) -> Result<OidcAuthorizationUrl, ClientError> {
+        self.request::<OidcAuthorizationUrl>(Method::Post, "/auth/oidc/link", None::<()>).await
+    }
+
+    pub async fn exchange_oidc_code(&mut self, code: String) -> Result<AuthResponse, ClientError> {
+        self.authenticate("/auth/oidc/exchange", OidcCodeExchangeRequest { code }).await
+    }
+
+    // Household endpoints
+    pub async fn list_households(&mut self) -> Result<Vec<Household>, ClientError> {
+        self.request::<Vec<Household>>(Method::Get, "/households", None::<()>).await
+    }
+
+    pub async fn create_household(&mut self, request: CreateHouseholdRequest) -> Result<Household, ClientError> {
+        self.request(Method::Post, "/households", Some(request)).await
+    }
+
+    pub async fn get_household(&mut self, id: &str) -> Result<Household, ClientError> {
+        self.request::<Household>(Method::Get, &format!("/households/{}", id), None::<()>).await
+    }
+
+    pub async fn delete_household(&mut self, id: &str) -> Result<(), ClientError> {
+        self.request::<()>(Method::Delete, &format!("/households/{}", id), None::<()>).await
+    }
+
+    pub async fn update_household(&mut self, id: &str, name: String) -> Result<Household, ClientError> {
+        let request = UpdateHouseholdRequest { name: Some(name) };
+        self.request(Method::Put, &format!("/households/{}", id), Some(request)).await
+    }
+
+    pub async fn export_household(&mut self, id: &str) -> Result<HouseholdExport, ClientError> {
+        self.request::<HouseholdExport>(Method::Get, &format!("/households/{}/export", id), None::<()>).await
+    }
+
+
+    pub async fn import_household(&mut self, data: HouseholdExport) -> Result<Household, ClientError> {
+        self.request(Method::Post, "/households/import", Some(data)).await
+    }
+
+    pub async fn list_members(&mut self, household_id: &str) -> Result<Vec<MemberWithUser>, ClientError> {
+        self.request::<Vec<MemberWithUser>>(
+            Method::Get,
+            &format!("/households/{}/members", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn invite_member(&mut self, household_id: &str, request: InviteUserRequest) -> Result<HouseholdMembership, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/invite", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn get_leaderboard(&mut self, household_id: &str, window: LeaderboardWindow) -> Result<Vec<LeaderboardEntry>, ClientError> {
+        self.request::<Vec<LeaderboardEntry>>(
+            Method::Get,
+            &format!("/households/{}/leaderboard?window={}", household_id, window.as_str()),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Household settings endpoints
+    pub async fn get_household_settings(&mut self, household_id: &str) -> Result<HouseholdSettings, ClientError> {
+        self.request::<HouseholdSettings>(
+            Method::Get,
+            &format!("/households/{}/settings", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn update_household_settings(
+        &mut self,
+        household_id: &str,
+        request: UpdateHouseholdSettingsRequest,
+    ) -> Result<HouseholdSettings, ClientError> {
+        self.request(
+            Method::Put,
+            &format!("/households/{}/settings", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    // Solo Mode endpoints
+    pub async fn activate_solo_mode(&mut self, household_id: &str) -> Result<HouseholdSettings, ClientError> {
+        self.request::<HouseholdSettings>(
+            Method::Post,
+            &format!("/households/{}/solo-mode/activate", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn request_solo_mode_exit(&mut self, household_id: &str) -> Result<HouseholdSettings, ClientError> {
+        self.request::<HouseholdSettings>(
+            Method::Post,
+            &format!("/households/{}/solo-mode/request-exit", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn cancel_solo_mode_exit(&mut self, household_id: &str) -> Result<HouseholdSettings, ClientError> {
+        self.request::<HouseholdSettings>(
+            Method::Post,
+            &format!("/households/{}/solo-mode/cancel-exit", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Task endpoints
+    pub async fn list_tasks(&mut self, household_id: &str) -> Result<Vec<Task>, ClientError> {
+        Ok(self.list_tasks_page(household_id, PaginationQuery::default()).await?.items)
+    }
+
+    pub async fn list_tasks_page(&mut self, household_id: &str, page: PaginationQuery) -> Result<Paginated<Task>, ClientError> {
+        self.request::<Paginated<Task>>(
+            Method::Get,
+            &format!("/households/{}/tasks{}", household_id, page.to_query_string()),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// All tasks matching the filter, see `TaskFilter::to_query_params`
+    pub async fn list_filtered_tasks(&mut self, household_id: &str, filter: &shared::TaskFilter) -> Result<Vec<Task>, ClientError> {
+        let params = filter.to_query_params();
+        let query = if params.is_empty() { String::new() } else { format!("?{}", params.join("&")) };
+        let page = self.request::<Paginated<Task>>(
+            Method::Get,
+            &format!("/households/{}/tasks{}", household_id, query),
+            None::<()>,
+        )
+        .await?;
+        Ok(page.items)
+    }
+
+    pub async fn get_due_tasks(&mut self, household_id: &str) -> Result<Vec<TaskWithStatus>, ClientError> {
+        self.request::<Vec<TaskWithStatus>>(
+            Method::Get,
+            &format!("/households/{}/tasks/due", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Due dates of the household's tasks between `from` and `to` (inclusive)
+    pub async fn get_task_occurrences(
+        &mut self,
+        household_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<TaskOccurrence>, ClientError> {
+        self.request::<Vec<TaskOccurrence>>(
+            Method::Get,
+            &format!("/households/{}/tasks/occurrences?from={}&to={}", household_id, from, to),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn get_all_tasks_with_status(&mut self, household_id: &str) -> Result<Vec<TaskWithStatus>, ClientError> {
+        Ok(self.get_tasks_with_status_page(household_id, PaginationQuery::default()).await?.items)
+    }
+
+    pub async fn get_tasks_with_status_page(
+        &mut self,
+        household_id: &str,
+        page: PaginationQuery,
+    ) -> Result<Paginated<TaskWithStatus>, ClientError> {
+        self.request::<Paginated<TaskWithStatus>>(
+            Method::Get,
+            &format!("/households/{}/tasks/all{}", household_id, page.to_query_string()),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn get_my_assigned_tasks(&mut self, household_id: &str) -> Result<Vec<Task>, ClientError> {
+        self.request::<Vec<Task>>(
+            Method::Get,
+            &format!("/households/{}/tasks/assigned-to-me", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Get full task details including statistics for the detail view
+    pub async fn get_task_details(
+        &mut self,
+        household_id: &str,
+        task_id: &str,
+    ) -> Result<TaskWithDetails, ClientError> {
+        self.request::<TaskWithDetails>(
+            Method::Get,
+            &format!("/households/{}/tasks/{}/details", household_id, task_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn create_task(&mut self, household_id: &str, request: CreateTaskRequest) -> Result<Task, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/tasks", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn complete_task(&mut self, household_id: &str, task_id: &str) -> Result<TaskCompletion, ClientError> {
+        self.request::<TaskCompletion>(
+            Method::Post,
+            &format!("/households/{}/tasks/{}/complete", household_id, task_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn uncomplete_task(&mut self, household_id: &str, task_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Post,
+            &format!("/households/{}/tasks/{}/uncomplete", household_id, task_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn delete_task(&mut self, household_id: &str, task_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/tasks/{}", household_id, task_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn update_task(
+        &mut self,
+        household_id: &str,
+        task_id: &str,
+        request: UpdateTaskRequest,
+    ) -> Result<Task, ClientError> {
+        self.request(
+            Method::Put,
+            &format!("/households/{}/tasks/{}", household_id, task_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn archive_task(&mut self, household_id: &str, task_id: &str) -> Result<Task, ClientError> {
+        self.request::<Task>(
+            Method::Post,
+            &format!("/households/{}/tasks/{}/archive", household_id, task_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn unarchive_task(&mut self, household_id: &str, task_id: &str) -> Result<Task, ClientError> {
+        self.request::<Task>(
+            Method::Post,
+            &format!("/households/{}/tasks/{}/unarchive", household_id, task_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn pause_task(&mut self, household_id: &str, task_id: &str) -> Result<Task, ClientError> {
+        self.request::<Task>(
+            Method::Post,
+            &format!("/households/{}/tasks/{}/pause", household_id, task_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn skip_task_period(
+        &mut self,
+        household_id: &str,
+        task_id: &str,
+        request: SkipTaskPeriodRequest,
+    ) -> Result<TaskPeriodResult, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/tasks/{}/skip", household_id, task_id),
+            Some(request),
+        )
+        .await
+    }
+
+    /// Correct the recorded outcome of a past period (owner only)
+    pub async fn override_task_period(
+        &mut self,
+        household_id: &str,
+        task_id: &str,
+        date: chrono::NaiveDate,
+        request: OverridePeriodRequest,
+    ) -> Result<TaskPeriodResult, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/tasks/{}/periods/{}/override", household_id, task_id, date),
+            Some(request),
+        )
+        .await
+    }
+
+    /// Spend a streak freeze on a missed period of one of your own tasks
+    pub async fn freeze_task_period(
+        &mut self,
+        household_id: &str,
+        task_id: &str,
+        date: chrono::NaiveDate,
+    ) -> Result<TaskPeriodResult, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/tasks/{}/periods/{}/freeze", household_id, task_id, date),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn get_streak_freezes(&mut self, household_id: &str) -> Result<shared::StreakFreezeStatus, ClientError> {
+        self.request(
+            Method::Get,
+            &format!("/households/{}/streak-freezes", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn purchase_streak_freeze(&mut self, household_id: &str) -> Result<shared::StreakFreezeStatus, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/streak-freezes/purchase", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn postpone_task(
+        &mut self,
+        household_id: &str,
+        task_id: &str,
+        request: PostponeTaskRequest,
+    ) -> Result<Task, ClientError> {
+        self.request::<Task>(
+            Method::Post,
+            &format!("/households/{}/tasks/{}/postpone", household_id, task_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn claim_task(&mut self, household_id: &str, task_id: &str) -> Result<TaskClaim, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/tasks/{}/claim", household_id, task_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn release_task(&mut self, household_id: &str, task_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/tasks/{}/claim", household_id, task_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn unpause_task(&mut self, household_id: &str, task_id: &str) -> Result<Task, ClientError> {
+        self.request::<Task>(
+            Method::Post,
+            &format!("/households/{}/tasks/{}/unpause", household_id, task_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn list_archived_tasks(&mut self, household_id: &str) -> Result<Vec<Task>, ClientError> {
+        self.request::<Vec<Task>>(
+            Method::Get,
+            &format!("/households/{}/tasks/archived", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Task prerequisite endpoints
+    pub async fn get_task_prerequisites(&mut self, household_id: &str, task_id: &str) -> Result<Vec<Task>, ClientError> {
+        self.request::<Vec<Task>>(
+            Method::Get,
+            &format!("/households/{}/tasks/{}/prerequisites", household_id, task_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn add_task_prerequisite(
+        &mut self,
+        household_id: &str,
+        task_id: &str,
+        prerequisite_id: &str,
+    ) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Post,
+            &format!(
+                "/households/{}/tasks/{}/prerequisites/{}",
+                household_id, task_id, prerequisite_id
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn remove_task_prerequisite(
+        &mut self,
+        household_id: &str,
+        task_id: &str,
+        prerequisite_id: &str,
+    ) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!(
+                "/households/{}/tasks/{}/prerequisites/{}",
+                household_id, task_id, prerequisite_id
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Task rewards/punishments endpoints
+    pub async fn get_task_rewards(&mut self, household_id: &str, task_id: &str) -> Result<Vec<TaskRewardLink>, ClientError> {
+        self.request::<Vec<TaskRewardLink>>(
+            Method::Get,
+            &format!("/households/{}/tasks/{}/rewards", household_id, task_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn add_task_reward(
+        &mut self,
+        household_id: &str,
+        task_id: &str,
+        reward_id: &str,
+        amount: i32,
+    ) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Post,
+            &format!(
+                "/households/{}/tasks/{}/rewards/{}?amount={}",
+                household_id, task_id, reward_id, amount
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn remove_task_reward(
+        &mut self,
+        household_id: &str,
+        task_id: &str,
+        reward_id: &str,
+    ) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!(
+                "/households/{}/tasks/{}/rewards/{}",
+                household_id, task_id, reward_id
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn get_task_punishments(
+        &mut self,
+        household_id: &str,
+        task_id: &str,
+    ) -> Result<Vec<TaskPunishmentLink>, ClientError> {
+        self.request::<Vec<TaskPunishmentLink>>(
+            Method::Get,
+            &format!("/households/{}/tasks/{}/punishments", household_id, task_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn add_task_punishment(
+        &mut self,
+        household_id: &str,
+        task_id: &str,
+        punishment_id: &str,
+        amount: i32,
+    ) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Post,
+            &format!(
+                "/households/{}/tasks/{}/punishments/{}?amount={}",
+                household_id, task_id, punishment_id, amount
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn remove_task_punishment(
+        &mut self,
+        household_id: &str,
+        task_id: &str,
+        punishment_id: &str,
+    ) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!(
+                "/households/{}/tasks/{}/punishments/{}",
+                household_id, task_id, punishment_id
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Task category endpoints
+    pub async fn list_categories(
+        &mut self,
+        household_id: &str,
+    ) -> Result<Vec<shared::TaskCategory>, ClientError> {
+        let response: shared::TaskCategoriesResponse = self.request(
+            Method::Get,
+            &format!("/households/{}/categories", household_id),
+            None::<()>,
+        )
+        .await?;
+        Ok(response.categories)
+    }
+
+    pub async fn create_category(
+        &mut self,
+        household_id: &str,
+        request: shared::CreateTaskCategoryRequest,
+    ) -> Result<shared::TaskCategory, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/categories", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn update_category(
+        &mut self,
+        household_id: &str,
+        category_id: &str,
+        request: shared::UpdateTaskCategoryRequest,
+    ) -> Result<shared::TaskCategory, ClientError> {
+        self.request(
+            Method::Put,
+            &format!("/households/{}/categories/{}", household_id, category_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn delete_category(&mut self, household_id: &str, category_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/categories/{}", household_id, category_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Task tag endpoints
+    pub async fn list_task_tags(&mut self, household_id: &str) -> Result<Vec<shared::TaskTag>, ClientError> {
+        self.request(
+            Method::Get,
+            &format!("/households/{}/tags", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn create_task_tag(
+        &mut self,
+        household_id: &str,
+        request: shared::CreateTaskTagRequest,
+    ) -> Result<shared::TaskTag, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/tags", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn update_task_tag(
+        &mut self,
+        household_id: &str,
+        tag_id: &str,
+        request: shared::UpdateTaskTagRequest,
+    ) -> Result<shared::TaskTag, ClientError> {
+        self.request(
+            Method::Put,
+            &format!("/households/{}/tags/{}", household_id, tag_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn delete_task_tag(&mut self, household_id: &str, tag_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/tags/{}", household_id, tag_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Focus timer endpoints
+    pub async fn get_current_focus_session(&mut self) -> Result<Option<shared::FocusSession>, ClientError> {
+        self.request(Method::Get, "/users/me/focus-session", None::<()>).await
+    }
+
+    pub async fn start_focus_session(
+        &mut self,
+        household_id: &str,
+        request: shared::StartFocusSessionRequest,
+    ) -> Result<shared::FocusSession, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/focus-sessions", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    /// Stop (`complete`) or cancel the running focus session
+    pub async fn end_focus_session(&mut self, household_id: &str, complete: bool) -> Result<shared::FocusSession, ClientError> {
+        let action = if complete { "stop" } else { "cancel" };
+        self.request(
+            Method::Post,
+            &format!("/households/{}/focus-sessions/current/{}", household_id, action),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Saved task filter endpoints
+    pub async fn list_saved_filters(&mut self, household_id: &str) -> Result<Vec<shared::SavedTaskFilter>, ClientError> {
+        self.request(
+            Method::Get,
+            &format!("/households/{}/saved-filters", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn create_saved_filter(
+        &mut self,
+        household_id: &str,
+        request: shared::CreateSavedTaskFilterRequest,
+    ) -> Result<shared::SavedTaskFilter, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/saved-filters", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn delete_saved_filter(&mut self, household_id: &str, filter_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/saved-filters/{}", household_id, filter_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Task review endpoints
+    pub async fn get_pending_reviews(&mut self, household_id: &str) -> Result<Vec<PendingReview>, ClientError> {
+        self.request::<Vec<PendingReview>>(
+            Method::Get,
+            &format!("/households/{}/tasks/pending-reviews", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn approve_completion(&mut self, household_id: &str, completion_id: &str) -> Result<TaskCompletion, ClientError> {
+        self.request::<TaskCompletion>(
+            Method::Post,
+            &format!(
+                "/households/{}/tasks/completions/{}/approve",
+                household_id, completion_id
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn reject_completion(&mut self, household_id: &str, completion_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Post,
+            &format!(
+                "/households/{}/tasks/completions/{}/reject",
+                household_id, completion_id
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+
+
+    // Note and journal entry attachments
+    pub async fn list_attachments(
+        &mut self,
+        household_id: &str,
+        entity_type: AttachmentEntity,
+        entity_id: &str,
+    ) -> Result<Vec<Attachment>, ClientError> {
+        self.request::<Vec<Attachment>>(
+            Method::Get,
+            &format!("/households/{}/attachments/{}/{}", household_id, entity_type.as_str(), entity_id),
+            None::<()>,
+        )
+        .await
+    }
+
+
+
+
+    pub async fn delete_attachment(&mut self, household_id: &str, attachment_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/attachments/{}", household_id, attachment_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Task suggestion endpoints
+    pub async fn list_suggestions(&mut self, household_id: &str) -> Result<Vec<Task>, ClientError> {
+        self.request::<Vec<Task>>(
+            Method::Get,
+            &format!("/households/{}/tasks/suggestions", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn approve_suggestion(&mut self, household_id: &str, task_id: &str) -> Result<Task, ClientError> {
+        self.request::<Task>(
+            Method::Post,
+            &format!("/households/{}/tasks/{}/approve", household_id, task_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn deny_suggestion(&mut self, household_id: &str, task_id: &str) -> Result<Task, ClientError> {
+        self.request::<Task>(
+            Method::Post,
+            &format!("/households/{}/tasks/{}/deny", household_id, task_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Point condition endpoints
+    pub async fn list_point_conditions(&mut self, household_id: &str) -> Result<Vec<PointCondition>, ClientError> {
+        self.request::<Vec<PointCondition>>(
+            Method::Get,
+            &format!("/households/{}/point-conditions", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn create_point_condition(
+        &mut self,
+        household_id: &str,
+        request: CreatePointConditionRequest,
+    ) -> Result<PointCondition, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/point-conditions", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn delete_point_condition(&mut self, household_id: &str, condition_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/point-conditions/{}", household_id, condition_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Reward endpoints
+    pub async fn list_rewards(&mut self, household_id: &str) -> Result<Vec<Reward>, ClientError> {
+        self.request::<Vec<Reward>>(
+            Method::Get,
+            &format!("/households/{}/rewards", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn create_reward(&mut self, household_id: &str, request: CreateRewardRequest) -> Result<Reward, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/rewards", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn update_reward(&mut self, household_id: &str, reward_id: &str, request: UpdateRewardRequest) -> Result<Reward, ClientError> {
+        self.request(
+            Method::Put,
+            &format!("/households/{}/rewards/{}", household_id, reward_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn purchase_reward(&mut self, household_id: &str, reward_id: &str) -> Result<PurchaseRewardResponse, ClientError> {
+        self.request::<PurchaseRewardResponse>(
+            Method::Post,
+            &format!("/households/{}/rewards/{}/purchase", household_id, reward_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn list_point_goals(&mut self, household_id: &str, user_id: &str) -> Result<Vec<shared::PointGoalProgress>, ClientError> {
+        self.request(
+            Method::Get,
+            &format!("/households/{}/members/{}/goals", household_id, user_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn create_point_goal(
+        &mut self,
+        household_id: &str,
+        user_id: &str,
+        request: shared::CreatePointGoalRequest,
+    ) -> Result<shared::PointGoalProgress, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/members/{}/goals", household_id, user_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn delete_point_goal(&mut self, household_id: &str, user_id: &str, goal_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/members/{}/goals/{}", household_id, user_id, goal_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn get_wishlist(&mut self, household_id: &str) -> Result<Vec<shared::WishlistItem>, ClientError> {
+        self.request(
+            Method::Get,
+            &format!("/households/{}/wishlist", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn add_to_wishlist(&mut self, household_id: &str, reward_id: &str) -> Result<Vec<shared::WishlistItem>, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/wishlist/{}", household_id, reward_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn remove_from_wishlist(&mut self, household_id: &str, reward_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/wishlist/{}", household_id, reward_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn delete_reward(&mut self, household_id: &str, reward_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/rewards/{}", household_id, reward_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn list_user_rewards(&mut self, household_id: &str) -> Result<Vec<UserReward>, ClientError> {
+        self.request::<Vec<UserReward>>(
+            Method::Get,
+            &format!("/households/{}/rewards/user-rewards", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn list_all_user_rewards(&mut self, household_id: &str) -> Result<Vec<UserRewardWithUser>, ClientError> {
+        self.request::<Vec<UserRewardWithUser>>(
+            Method::Get,
+            &format!("/households/{}/rewards/user-rewards/all", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn delete_user_reward(&mut self, household_id: &str, user_reward_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/rewards/user-rewards/{}", household_id, user_reward_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn redeem_reward(&mut self, household_id: &str, user_reward_id: &str) -> Result<UserReward, ClientError> {
+        self.request::<UserReward>(
+            Method::Post,
+            &format!("/households/{}/rewards/user-rewards/{}/redeem", household_id, user_reward_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Returns the caller's remaining reward, or `None` once the last one was given away
+    pub async fn gift_reward(&mut self, household_id: &str, user_reward_id: &str, to_user_id: &str) -> Result<Option<UserReward>, ClientError> {
+        self.request::<Option<UserReward>>(
+            Method::Post,
+            &format!("/households/{}/rewards/user-rewards/{}/gift/{}", household_id, user_reward_id, to_user_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Reward confirmation endpoints
+    pub async fn get_pending_reward_redemptions(&mut self, household_id: &str) -> Result<Vec<PendingRewardRedemption>, ClientError> {
+        self.request::<Vec<PendingRewardRedemption>>(
+            Method::Get,
+            &format!("/households/{}/rewards/pending-confirmations", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn approve_reward_redemption(&mut self, household_id: &str, user_reward_id: &str) -> Result<UserReward, ClientError> {
+        self.request::<UserReward>(
+            Method::Post,
+            &format!("/households/{}/rewards/user-rewards/{}/approve", household_id, user_reward_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn reject_reward_redemption(&mut self, household_id: &str, user_reward_id: &str) -> Result<UserReward, ClientError> {
+        self.request::<UserReward>(
+            Method::Post,
+            &format!("/households/{}/rewards/user-rewards/{}/reject", household_id, user_reward_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn get_pending_reward_purchases(&mut self, household_id: &str) -> Result<Vec<PendingRewardPurchase>, ClientError> {
+        self.request::<Vec<PendingRewardPurchase>>(
+            Method::Get,
+            &format!("/households/{}/rewards/purchases/pending", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn approve_reward_purchase(&mut self, household_id: &str, purchase_id: &str) -> Result<UserReward, ClientError> {
+        self.request::<UserReward>(
+            Method::Post,
+            &format!("/households/{}/rewards/purchases/{}/approve", household_id, purchase_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn reject_reward_purchase(&mut self, household_id: &str, purchase_id: &str) -> Result<RewardPurchase, ClientError> {
+        self.request::<RewardPurchase>(
+            Method::Post,
+            &format!("/households/{}/rewards/purchases/{}/reject", household_id, purchase_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Get the options linked to a random choice reward
+    pub async fn get_reward_options(&mut self, household_id: &str, reward_id: &str) -> Result<Vec<WeightedRewardOption>, ClientError> {
+        self.request::<Vec<WeightedRewardOption>>(
+            Method::Get,
+            &format!("/households/{}/rewards/{}/options", household_id, reward_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Change how likely an option of a random choice reward is picked
+    pub async fn update_reward_option_weight(
+        &mut self,
+        household_id: &str,
+        reward_id: &str,
+        option_id: &str,
+        weight: i32,
+    ) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Put,
+            &format!("/households/{}/rewards/{}/options/{}", household_id, reward_id, option_id),
+            Some(UpdateOptionWeightRequest { weight }),
+        )
+        .await
+    }
+
+    /// Pick a random reward from a user's random choice reward assignment
+    pub async fn pick_random_reward(&mut self, household_id: &str, user_reward_id: &str) -> Result<RandomRewardPickResult, ClientError> {
+        self.request::<RandomRewardPickResult>(
+            Method::Post,
+            &format!("/households/{}/rewards/user-rewards/{}/pick", household_id, user_reward_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Punishment endpoints
+    pub async fn list_punishments(&mut self, household_id: &str) -> Result<Vec<Punishment>, ClientError> {
+        self.request::<Vec<Punishment>>(
+            Method::Get,
+            &format!("/households/{}/punishments", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn create_punishment(
+        &mut self,
+        household_id: &str,
+        request: CreatePunishmentRequest,
+    ) -> Result<Punishment, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/punishments", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn update_punishment(
+        &mut self,
+        household_id: &str,
+        punishment_id: &str,
+        request: UpdatePunishmentRequest,
+    ) -> Result<Punishment, ClientError> {
+        self.request(
+            Method::Put,
+            &format!("/households/{}/punishments/{}", household_id, punishment_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn delete_punishment(&mut self, household_id: &str, punishment_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/punishments/{}", household_id, punishment_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn list_user_punishments(&mut self, household_id: &str) -> Result<Vec<UserPunishment>, ClientError> {
+        self.request::<Vec<UserPunishment>>(
+            Method::Get,
+            &format!("/households/{}/punishments/user-punishments", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn list_all_user_punishments(&mut self, household_id: &str) -> Result<Vec<UserPunishmentWithUser>, ClientError> {
+        self.request::<Vec<UserPunishmentWithUser>>(
+            Method::Get,
+            &format!("/households/{}/punishments/user-punishments/all", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn delete_user_punishment(&mut self, household_id: &str, user_punishment_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/punishments/user-punishments/{}", household_id, user_punishment_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Invitation endpoints (household admin)
+    pub async fn create_invitation(
+        &mut self,
+        household_id: &str,
+        request: CreateInvitationRequest,
+    ) -> Result<Invitation, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/invite", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn list_household_invitations(&mut self, household_id: &str) -> Result<Vec<Invitation>, ClientError> {
+        self.request::<Vec<Invitation>>(
+            Method::Get,
+            &format!("/households/{}/invitations", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn cancel_invitation(
+        &mut self,
+        household_id: &str,
+        invitation_id: &str,
+    ) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/invitations/{}", household_id, invitation_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // User invitation endpoints
+    pub async fn get_my_invitations(&mut self) -> Result<Vec<InvitationWithHousehold>, ClientError> {
+        self.request::<Vec<InvitationWithHousehold>>(
+            Method::Get,
+            "/invitations",
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn list_invite_codes(&mut self, household_id: &str) -> Result<Vec<InviteCode>, ClientError> {
+        self.request::<Vec<InviteCode>>(
+            Method::Get,
+            &format!("/households/{}/invite-codes", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn create_invite_code(&mut self, household_id: &str, request: CreateInviteCodeRequest) -> Result<InviteCode, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/invite-codes", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn revoke_invite_code(&mut self, household_id: &str, code_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/invite-codes/{}", household_id, code_id),
+            None::<()>,
+        )
+        .await
+    }
+
+
+    pub async fn redeem_invite_code(&mut self, code: &str) -> Result<HouseholdMembership, ClientError> {
+        self.request::<HouseholdMembership>(
+            Method::Post,
+            "/invitations/redeem-code",
+            Some(RedeemInviteCodeRequest { code: code.to_string() }),
+        )
+        .await
+    }
+
+    pub async fn accept_invitation(&mut self, invitation_id: &str) -> Result<HouseholdMembership, ClientError> {
+        self.request::<HouseholdMembership>(
+            Method::Post,
+            &format!("/invitations/{}/accept", invitation_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn decline_invitation(&mut self, invitation_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Post,
+            &format!("/invitations/{}/decline", invitation_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Member management endpoints
+    pub async fn adjust_member_points(
+        &mut self,
+        household_id: &str,
+        user_id: &str,
+        request: AdjustPointsRequest,
+    ) -> Result<AdjustPointsResponse, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/members/{}/points", household_id, user_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn get_points_history(
+        &mut self,
+        household_id: &str,
+        user_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<PointHistoryPage, ClientError> {
+        self.request::<PointHistoryPage>(
+            Method::Get,
+            &format!(
+                "/households/{}/members/{}/points/history?limit={}&offset={}",
+                household_id, user_id, limit, offset
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Monthly allowance payouts; members without AdjustPoints only get their own
+    pub async fn list_allowance_payouts(&mut self, household_id: &str) -> Result<Vec<AllowancePayout>, ClientError> {
+        self.request::<Vec<AllowancePayout>>(
+            Method::Get,
+            &format!("/households/{}/allowance/payouts", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn get_audit_log(&mut self, household_id: &str, limit: i64, offset: i64) -> Result<AdminAuditLogPage, ClientError> {
+        self.request::<AdminAuditLogPage>(
+            Method::Get,
+            &format!("/households/{}/audit-log?limit={}&offset={}", household_id, limit, offset),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn set_member_vacation(
+        &mut self,
+        household_id: &str,
+        user_id: &str,
+        request: SetMemberVacationRequest,
+    ) -> Result<HouseholdMembership, ClientError> {
+        self.request(
+            Method::Put,
+            &format!("/households/{}/members/{}/vacation", household_id, user_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn update_member_role(
+        &mut self,
+        household_id: &str,
+        user_id: &str,
+        request: UpdateRoleRequest,
+    ) -> Result<HouseholdMembership, ClientError> {
+        self.request(
+            Method::Put,
+            &format!("/households/{}/members/{}/role", household_id, user_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn transfer_ownership(&mut self, household_id: &str, user_id: &str) -> Result<HouseholdMembership, ClientError> {
+        self.request::<HouseholdMembership>(
+            Method::Post,
+            &format!("/households/{}/transfer-ownership/{}", household_id, user_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn leave_household(&mut self, household_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(Method::Post, &format!("/households/{}/leave", household_id), None::<()>).await
+    }
+
+    pub async fn create_child_account(
+        &mut self,
+        household_id: &str,
+        request: CreateChildAccountRequest,
+    ) -> Result<MemberWithUser, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/child-accounts", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn reset_child_pin(
+        &mut self,
+        household_id: &str,
+        user_id: &str,
+        request: ResetChildPinRequest,
+    ) -> Result<String, ClientError> {
+        self.request(
+            Method::Put,
+            &format!("/households/{}/child-accounts/{}/pin", household_id, user_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn get_member_permissions(&mut self, household_id: &str, user_id: &str) -> Result<MemberPermissionsResponse, ClientError> {
+        self.request::<MemberPermissionsResponse>(
+            Method::Get,
+            &format!("/households/{}/members/{}/permissions", household_id, user_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn update_member_permissions(
+        &mut self,
+        household_id: &str,
+        user_id: &str,
+        request: UpdateMemberPermissionsRequest,
+    ) -> Result<MemberPermissionsResponse, ClientError> {
+        self.request::<MemberPermissionsResponse>(
+            Method::Put,
+            &format!("/households/{}/members/{}/permissions", household_id, user_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn assign_reward(
+        &mut self,
+        household_id: &str,
+        reward_id: &str,
+        user_id: &str,
+    ) -> Result<UserReward, ClientError> {
+        self.request::<UserReward>(
+            Method::Post,
+            &format!("/households/{}/rewards/{}/assign/{}", household_id, reward_id, user_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn assign_punishment(
+        &mut self,
+        household_id: &str,
+        punishment_id: &str,
+        user_id: &str,
+    ) -> Result<UserPunishment, ClientError> {
+        self.request::<UserPunishment>(
+            Method::Post,
+            &format!("/households/{}/punishments/{}/assign/{}", household_id, punishment_id, user_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn unassign_reward(
+        &mut self,
+        household_id: &str,
+        reward_id: &str,
+        user_id: &str,
+    ) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Post,
+            &format!("/households/{}/rewards/{}/unassign/{}", household_id, reward_id, user_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn unassign_punishment(
+        &mut self,
+        household_id: &str,
+        punishment_id: &str,
+        user_id: &str,
+    ) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Post,
+            &format!("/households/{}/punishments/{}/unassign/{}", household_id, punishment_id, user_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn complete_punishment(
+        &mut self,
+        household_id: &str,
+        user_punishment_id: &str,
+    ) -> Result<UserPunishment, ClientError> {
+        self.request::<UserPunishment>(
+            Method::Post,
+            &format!("/households/{}/punishments/user-punishments/{}/complete", household_id, user_punishment_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Punishment confirmation endpoints
+    pub async fn get_pending_punishment_completions(&mut self, household_id: &str) -> Result<Vec<PendingPunishmentCompletion>, ClientError> {
+        self.request::<Vec<PendingPunishmentCompletion>>(
+            Method::Get,
+            &format!("/households/{}/punishments/pending-confirmations", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn approve_punishment_completion(&mut self, household_id: &str, user_punishment_id: &str) -> Result<UserPunishment, ClientError> {
+        self.request::<UserPunishment>(
+            Method::Post,
+            &format!("/households/{}/punishments/user-punishments/{}/approve", household_id, user_punishment_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn reject_punishment_completion(&mut self, household_id: &str, user_punishment_id: &str) -> Result<UserPunishment, ClientError> {
+        self.request::<UserPunishment>(
+            Method::Post,
+            &format!("/households/{}/punishments/user-punishments/{}/reject", household_id, user_punishment_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Get the options linked to a random choice punishment
+    pub async fn get_punishment_options(&mut self, household_id: &str, punishment_id: &str) -> Result<Vec<WeightedPunishmentOption>, ClientError> {
+        self.request::<Vec<WeightedPunishmentOption>>(
+            Method::Get,
+            &format!("/households/{}/punishments/{}/options", household_id, punishment_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Change how likely an option of a random choice punishment is picked
+    pub async fn update_punishment_option_weight(
+        &mut self,
+        household_id: &str,
+        punishment_id: &str,
+        option_id: &str,
+        weight: i32,
+    ) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Put,
+            &format!("/households/{}/punishments/{}/options/{}", household_id, punishment_id, option_id),
+            Some(UpdateOptionWeightRequest { weight }),
+        )
+        .await
+    }
+
+    /// Pick a random punishment from a user's random choice punishment assignment
+    pub async fn pick_random_punishment(&mut self, household_id: &str, user_punishment_id: &str) -> Result<RandomPickResult, ClientError> {
+        self.request::<RandomPickResult>(
+            Method::Post,
+            &format!("/households/{}/punishments/user-punishments/{}/pick", household_id, user_punishment_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Activity log endpoints
+    pub async fn list_activities(
+        &mut self,
+        household_id: &str,
+        page: PaginationQuery,
+    ) -> Result<Paginated<ActivityLogWithUsers>, ClientError> {
+        let url = format!("/households/{}/activities{}", household_id, page.to_query_string());
+        self.request::<Paginated<ActivityLogWithUsers>>(Method::Get, &url, None::<()>).await
+    }
+
+    // Chat endpoints
+    pub async fn list_chat_messages(
+        &mut self,
+        household_id: &str,
+        limit: Option<i64>,
+        before: Option<&str>,
+    ) -> Result<Vec<ChatMessageWithUser>, ClientError> {
+        let mut url = format!("/households/{}/chat", household_id);
+        let mut params = Vec::new();
+        if let Some(limit) = limit {
+            params.push(format!("limit={}", limit));
+        }
+        if let Some(before) = before {
+            params.push(format!("before={}", before));
+        }
+        if !params.is_empty() {
+            url = format!("{}?{}", url, params.join("&"));
+        }
+        self.request::<Vec<ChatMessageWithUser>>(Method::Get, &url, None::<()>).await
+    }
+
+    pub async fn send_chat_message(
+        &mut self,
+        household_id: &str,
+        content: &str,
+        reply_to_message_id: Option<uuid::Uuid>,
+    ) -> Result<ChatMessageWithUser, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/chat", household_id),
+            Some(CreateChatMessageRequest {
+                content: content.to_string(),
+                reply_to_message_id,
+            }),
+        )
+        .await
+    }
+
+    pub async fn list_chat_replies(
+        &mut self,
+        household_id: &str,
+        message_id: &str,
+    ) -> Result<Vec<ChatMessageWithUser>, ClientError> {
+        self.request::<Vec<ChatMessageWithUser>>(
+            Method::Get,
+            &format!("/households/{}/chat/{}/replies", household_id, message_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn add_chat_reaction(
+        &mut self,
+        household_id: &str,
+        message_id: &str,
+        emoji: &str,
+    ) -> Result<Vec<ChatReactionSummary>, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/chat/{}/reactions", household_id, message_id),
+            Some(ChatReactionRequest {
+                emoji: emoji.to_string(),
+            }),
+        )
+        .await
+    }
+
+    pub async fn remove_chat_reaction(
+        &mut self,
+        household_id: &str,
+        message_id: &str,
+        emoji: &str,
+    ) -> Result<Vec<ChatReactionSummary>, ClientError> {
+        self.request(
+            Method::Delete,
+            &format!("/households/{}/chat/{}/reactions", household_id, message_id),
+            Some(ChatReactionRequest {
+                emoji: emoji.to_string(),
+            }),
+        )
+        .await
+    }
+
+    pub async fn update_chat_message(
+        &mut self,
+        household_id: &str,
+        message_id: &str,
+        content: &str,
+    ) -> Result<ChatMessageWithUser, ClientError> {
+        self.request(
+            Method::Put,
+            &format!("/households/{}/chat/{}", household_id, message_id),
+            Some(UpdateChatMessageRequest {
+                content: content.to_string(),
+            }),
+        )
+        .await
+    }
+
+    pub async fn delete_chat_message(
+        &mut self,
+        household_id: &str,
+        message_id: &str,
+    ) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/chat/{}", household_id, message_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn list_chat_read_markers(&mut self, household_id: &str) -> Result<Vec<ChatReadMarker>, ClientError> {
+        self.request::<Vec<ChatReadMarker>>(
+            Method::Get,
+            &format!("/households/{}/chat/read", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn mark_chat_read(&mut self, household_id: &str, message_id: uuid::Uuid) -> Result<ChatReadMarker, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/chat/read", household_id),
+            Some(MarkChatReadRequest { message_id }),
+        )
+        .await
+    }
+
+    pub async fn get_chat_unread_counts(&mut self) -> Result<Vec<ChatUnreadCount>, ClientError> {
+        self.request::<Vec<ChatUnreadCount>>(Method::Get, "/users/me/chat/unread", None::<()>).await
+    }
+
+    // Notes endpoints
+    /// Active notes, or the archived ones with `archived`
+    pub async fn list_notes(&mut self, household_id: &str, archived: bool) -> Result<Vec<NoteWithUser>, ClientError> {
+        self.request::<Vec<NoteWithUser>>(
+            Method::Get,
+            &format!("/households/{}/notes?archived={}", household_id, archived),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn create_note(
+        &mut self,
+        household_id: &str,
+        request: CreateNoteRequest,
+    ) -> Result<Note, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/notes", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn get_note(&mut self, household_id: &str, note_id: &str) -> Result<Note, ClientError> {
+        self.request::<Note>(
+            Method::Get,
+            &format!("/households/{}/notes/{}", household_id, note_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn update_note(
+        &mut self,
+        household_id: &str,
+        note_id: &str,
+        request: UpdateNoteRequest,
+    ) -> Result<Note, ClientError> {
+        self.request(
+            Method::Put,
+            &format!("/households/{}/notes/{}", household_id, note_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn delete_note(&mut self, household_id: &str, note_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/notes/{}", household_id, note_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Trash endpoints
+    pub async fn list_trash(&mut self, household_id: &str) -> Result<Vec<TrashItem>, ClientError> {
+        self.request::<Vec<TrashItem>>(Method::Get, &format!("/households/{}/trash", household_id), None::<()>).await
+    }
+
+    pub async fn restore_trash_item(&mut self, household_id: &str, kind: TrashItemKind, item_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Post,
+            &format!("/households/{}/trash/{}/{}/restore", household_id, kind.as_str(), item_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn purge_trash_item(&mut self, household_id: &str, kind: TrashItemKind, item_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/trash/{}/{}", household_id, kind.as_str(), item_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Expense endpoints
+    pub async fn list_expenses(
+        &mut self,
+        household_id: &str,
+        year: i32,
+        month: u32,
+    ) -> Result<Vec<ExpenseWithUser>, ClientError> {
+        self.request::<Vec<ExpenseWithUser>>(
+            Method::Get,
+            &format!("/households/{}/expenses?year={}&month={}", household_id, year, month),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn get_expense_summary(
+        &mut self,
+        household_id: &str,
+        year: i32,
+        month: u32,
+    ) -> Result<ExpenseMonthlySummary, ClientError> {
+        self.request::<ExpenseMonthlySummary>(
+            Method::Get,
+            &format!("/households/{}/expenses/summary?year={}&month={}", household_id, year, month),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn create_expense(
+        &mut self,
+        household_id: &str,
+        request: CreateExpenseRequest,
+    ) -> Result<Expense, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/expenses", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn update_expense(
+        &mut self,
+        household_id: &str,
+        expense_id: &str,
+        request: UpdateExpenseRequest,
+    ) -> Result<Expense, ClientError> {
+        self.request(
+            Method::Put,
+            &format!("/households/{}/expenses/{}", household_id, expense_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn delete_expense(&mut self, household_id: &str, expense_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/expenses/{}", household_id, expense_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn get_expense_balances(&mut self, household_id: &str) -> Result<ExpenseBalances, ClientError> {
+        self.request::<ExpenseBalances>(
+            Method::Get,
+            &format!("/households/{}/expenses/balances", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn record_settlement(
+        &mut self,
+        household_id: &str,
+        request: CreateSettlementRequest,
+    ) -> Result<ExpenseSettlement, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/expenses/settlements", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    // Meal planning endpoints
+    pub async fn list_recipes(&mut self, household_id: &str) -> Result<Vec<Recipe>, ClientError> {
+        self.request::<Vec<Recipe>>(
+            Method::Get,
+            &format!("/households/{}/recipes", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn create_recipe(
+        &mut self,
+        household_id: &str,
+        request: CreateRecipeRequest,
+    ) -> Result<Recipe, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/recipes", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn delete_recipe(&mut self, household_id: &str, recipe_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/recipes/{}", household_id, recipe_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn get_meal_plan(&mut self, household_id: &str, week_start: &str) -> Result<WeeklyMealPlan, ClientError> {
+        self.request::<WeeklyMealPlan>(
+            Method::Get,
+            &format!("/households/{}/meals?week_start={}", household_id, week_start),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn set_meal_plan_entry(
+        &mut self,
+        household_id: &str,
+        request: SetMealPlanEntryRequest,
+    ) -> Result<MealPlanEntry, ClientError> {
+        self.request(
+            Method::Put,
+            &format!("/households/{}/meals", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn delete_meal_plan_entry(&mut self, household_id: &str, entry_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/meals/{}", household_id, entry_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn add_recipe_to_shopping_list(
+        &mut self,
+        household_id: &str,
+        recipe_id: &str,
+    ) -> Result<Vec<ShoppingListItem>, ClientError> {
+        self.request::<Vec<ShoppingListItem>>(
+            Method::Post,
+            &format!("/households/{}/recipes/{}/add-to-shopping-list", household_id, recipe_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Shopping list endpoints
+    pub async fn list_shopping_list(&mut self, household_id: &str) -> Result<Vec<ShoppingListItem>, ClientError> {
+        self.request::<Vec<ShoppingListItem>>(
+            Method::Get,
+            &format!("/households/{}/shopping-list", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn add_shopping_list_item(
+        &mut self,
+        household_id: &str,
+        request: CreateShoppingListItemRequest,
+    ) -> Result<ShoppingListItem, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/shopping-list", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn update_shopping_list_item(
+        &mut self,
+        household_id: &str,
+        item_id: &str,
+        request: UpdateShoppingListItemRequest,
+    ) -> Result<ShoppingListItem, ClientError> {
+        self.request(
+            Method::Put,
+            &format!("/households/{}/shopping-list/{}", household_id, item_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn delete_shopping_list_item(&mut self, household_id: &str, item_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/shopping-list/{}", household_id, item_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn clear_checked_shopping_items(&mut self, household_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/shopping-list/checked", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Inventory endpoints
+    pub async fn list_inventory(&mut self, household_id: &str) -> Result<Vec<InventoryItem>, ClientError> {
+        self.request::<Vec<InventoryItem>>(
+            Method::Get,
+            &format!("/households/{}/inventory", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn create_inventory_item(
+        &mut self,
+        household_id: &str,
+        request: CreateInventoryItemRequest,
+    ) -> Result<InventoryItem, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/inventory", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn update_inventory_item(
+        &mut self,
+        household_id: &str,
+        item_id: &str,
+        request: UpdateInventoryItemRequest,
+    ) -> Result<InventoryItem, ClientError> {
+        self.request(
+            Method::Put,
+            &format!("/households/{}/inventory/{}", household_id, item_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn adjust_inventory_item(
+        &mut self,
+        household_id: &str,
+        item_id: &str,
+        delta: f64,
+    ) -> Result<InventoryAdjustment, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/inventory/{}/adjust", household_id, item_id),
+            Some(AdjustInventoryRequest { delta }),
+        )
+        .await
+    }
+
+    pub async fn delete_inventory_item(&mut self, household_id: &str, item_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/inventory/{}", household_id, item_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Appliance endpoints
+    pub async fn list_appliances(&mut self, household_id: &str) -> Result<Vec<Appliance>, ClientError> {
+        self.request::<Vec<Appliance>>(
+            Method::Get,
+            &format!("/households/{}/appliances", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn create_appliance(&mut self, household_id: &str, request: CreateApplianceRequest) -> Result<Appliance, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/appliances", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn delete_appliance(&mut self, household_id: &str, appliance_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/appliances/{}", household_id, appliance_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn add_appliance_maintenance(
+        &mut self,
+        household_id: &str,
+        appliance_id: &str,
+        request: CreateMaintenanceRequest,
+    ) -> Result<Appliance, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/appliances/{}/maintenance", household_id, appliance_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn remove_appliance_maintenance(
+        &mut self,
+        household_id: &str,
+        appliance_id: &str,
+        task_id: &str,
+    ) -> Result<Appliance, ClientError> {
+        self.request::<Appliance>(
+            Method::Delete,
+            &format!("/households/{}/appliances/{}/maintenance/{}", household_id, appliance_id, task_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Plant endpoints
+    pub async fn list_plants(&mut self, household_id: &str) -> Result<Vec<Plant>, ClientError> {
+        self.request::<Vec<Plant>>(
+            Method::Get,
+            &format!("/households/{}/plants", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn create_plant(&mut self, household_id: &str, request: CreatePlantRequest) -> Result<Plant, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/plants", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn update_plant(&mut self, household_id: &str, plant_id: &str, request: UpdatePlantRequest) -> Result<Plant, ClientError> {
+        self.request(
+            Method::Put,
+            &format!("/households/{}/plants/{}", household_id, plant_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn delete_plant(&mut self, household_id: &str, plant_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/plants/{}", household_id, plant_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Pet endpoints
+    pub async fn list_pets(&mut self, household_id: &str) -> Result<Vec<Pet>, ClientError> {
+        self.request::<Vec<Pet>>(
+            Method::Get,
+            &format!("/households/{}/pets", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn create_pet(&mut self, household_id: &str, request: CreatePetRequest) -> Result<Pet, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/pets", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn delete_pet(&mut self, household_id: &str, pet_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/pets/{}", household_id, pet_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn add_pet_schedule(
+        &mut self,
+        household_id: &str,
+        pet_id: &str,
+        request: CreatePetScheduleRequest,
+    ) -> Result<Pet, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/pets/{}/schedules", household_id, pet_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn remove_pet_schedule(&mut self, household_id: &str, pet_id: &str, task_id: &str) -> Result<Pet, ClientError> {
+        self.request::<Pet>(
+            Method::Delete,
+            &format!("/households/{}/pets/{}/schedules/{}", household_id, pet_id, task_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn log_pet_care(&mut self, household_id: &str, pet_id: &str, task_id: &str, force: bool) -> Result<PetCareEntry, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/pets/{}/schedules/{}/log", household_id, pet_id, task_id),
+            Some(LogPetCareRequest { force }),
+        )
+        .await
+    }
+
+    // Share link endpoints
+    pub async fn list_share_links(&mut self, household_id: &str) -> Result<Vec<ShareLink>, ClientError> {
+        self.request(Method::Get, &format!("/households/{}/share-links", household_id), None::<()>).await
+    }
+
+    pub async fn create_share_link(&mut self, household_id: &str, request: CreateShareLinkRequest) -> Result<ShareLink, ClientError> {
+        self.request(Method::Post, &format!("/households/{}/share-links", household_id), Some(request)).await
+    }
+
+    pub async fn revoke_share_link(&mut self, household_id: &str, link_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/share-links/{}", household_id, link_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// The page behind a share link; works without being logged in
+    pub async fn get_shared_page(&mut self, token: &str) -> Result<SharedPage, ClientError> {
+        self.request_public(Method::Get, &format!("/shared/{}", token), None::<()>).await
+    }
+
+    // Challenge endpoints
+    pub async fn list_challenges(&mut self, household_id: &str) -> Result<Vec<ChallengeWithStandings>, ClientError> {
+        self.request::<Vec<ChallengeWithStandings>>(
+            Method::Get,
+            &format!("/households/{}/challenges", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn create_challenge(
+        &mut self,
+        household_id: &str,
+        request: CreateChallengeRequest,
+    ) -> Result<ChallengeWithStandings, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/challenges", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn close_challenge(&mut self, household_id: &str, challenge_id: &str) -> Result<ChallengeWithStandings, ClientError> {
+        self.request::<ChallengeWithStandings>(
+            Method::Post,
+            &format!("/households/{}/challenges/{}/close", household_id, challenge_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn delete_challenge(&mut self, household_id: &str, challenge_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/challenges/{}", household_id, challenge_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Automation rule endpoints
+    pub async fn list_automation_rules(&mut self, household_id: &str) -> Result<Vec<AutomationRule>, ClientError> {
+        self.request::<Vec<AutomationRule>>(
+            Method::Get,
+            &format!("/households/{}/automation-rules", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn create_automation_rule(
+        &mut self,
+        household_id: &str,
+        request: CreateAutomationRuleRequest,
+    ) -> Result<AutomationRule, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/automation-rules", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn update_automation_rule(
+        &mut self,
+        household_id: &str,
+        rule_id: &str,
+        request: UpdateAutomationRuleRequest,
+    ) -> Result<AutomationRule, ClientError> {
+        self.request(
+            Method::Put,
+            &format!("/households/{}/automation-rules/{}", household_id, rule_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn delete_automation_rule(&mut self, household_id: &str, rule_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/automation-rules/{}", household_id, rule_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn list_automation_rule_runs(&mut self, household_id: &str) -> Result<Vec<AutomationRuleRun>, ClientError> {
+        self.request::<Vec<AutomationRuleRun>>(
+            Method::Get,
+            &format!("/households/{}/automation-rules/runs", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn list_webhooks(&mut self, household_id: &str) -> Result<Vec<Webhook>, ClientError> {
+        self.request::<Vec<Webhook>>(
+            Method::Get,
+            &format!("/households/{}/webhooks", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn create_webhook(&mut self, household_id: &str, request: CreateWebhookRequest) -> Result<Webhook, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/webhooks", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn update_webhook(
+        &mut self,
+        household_id: &str,
+        webhook_id: &str,
+        request: UpdateWebhookRequest,
+    ) -> Result<Webhook, ClientError> {
+        self.request(
+            Method::Put,
+            &format!("/households/{}/webhooks/{}", household_id, webhook_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn delete_webhook(&mut self, household_id: &str, webhook_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/webhooks/{}", household_id, webhook_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Task comment endpoints
+    pub async fn list_task_comments(
+        &mut self,
+        household_id: &str,
+        task_id: &str,
+    ) -> Result<Vec<TaskCommentWithUser>, ClientError> {
+        self.request::<Vec<TaskCommentWithUser>>(
+            Method::Get,
+            &format!("/households/{}/tasks/{}/comments", household_id, task_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn create_task_comment(
+        &mut self,
+        household_id: &str,
+        task_id: &str,
+        request: CreateTaskCommentRequest,
+    ) -> Result<TaskComment, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/tasks/{}/comments", household_id, task_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn delete_task_comment(
+        &mut self,
+        household_id: &str,
+        task_id: &str,
+        comment_id: &str,
+    ) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/tasks/{}/comments/{}", household_id, task_id, comment_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Journal endpoints
+    pub async fn list_journal_entries(&mut self, household_id: &str) -> Result<Vec<JournalEntryWithUser>, ClientError> {
+        self.request::<Vec<JournalEntryWithUser>>(
+            Method::Get,
+            &format!("/households/{}/journal", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn create_journal_entry(
+        &mut self,
+        household_id: &str,
+        request: CreateJournalEntryRequest,
+    ) -> Result<JournalEntry, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/journal", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn get_journal_entry(&mut self, household_id: &str, entry_id: &str) -> Result<JournalEntry, ClientError> {
+        self.request::<JournalEntry>(
+            Method::Get,
+            &format!("/households/{}/journal/{}", household_id, entry_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn update_journal_entry(
+        &mut self,
+        household_id: &str,
+        entry_id: &str,
+        request: UpdateJournalEntryRequest,
+    ) -> Result<JournalEntry, ClientError> {
+        self.request(
+            Method::Put,
+            &format!("/households/{}/journal/{}", household_id, entry_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn delete_journal_entry(&mut self, household_id: &str, entry_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/journal/{}", household_id, entry_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// The current user's average mood per day (defaults to the last 30 days)
+    pub async fn get_mood_over_time(
+        &mut self,
+        household_id: &str,
+        start: Option<&str>,
+        end: Option<&str>,
+    ) -> Result<Vec<MoodPoint>, ClientError> {
+        let mut params = Vec::new();
+        if let Some(start) = start {
+            params.push(format!("start={}", start));
+        }
+        if let Some(end) = end {
+            params.push(format!("end={}", end));
+        }
+        let url = if params.is_empty() {
+            format!("/households/{}/journal/mood", household_id)
+        } else {
+            format!("/households/{}/journal/mood?{}", household_id, params.join("&"))
+        };
+        self.request::<Vec<MoodPoint>>(Method::Get, &url, None::<()>).await
+    }
+
+    // Announcement endpoints
+    pub async fn list_announcements(&mut self, household_id: &str) -> Result<Vec<Announcement>, ClientError> {
+        self.request::<Vec<Announcement>>(
+            Method::Get,
+            &format!("/households/{}/announcements", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn list_active_announcements(&mut self, household_id: &str) -> Result<Vec<Announcement>, ClientError> {
+        self.request::<Vec<Announcement>>(
+            Method::Get,
+            &format!("/households/{}/announcements/active", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn create_announcement(
+        &mut self,
+        household_id: &str,
+        request: CreateAnnouncementRequest,
+    ) -> Result<Announcement, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/announcements", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn update_announcement(
+        &mut self,
+        household_id: &str,
+        announcement_id: &str,
+        request: UpdateAnnouncementRequest,
+    ) -> Result<Announcement, ClientError> {
+        self.request(
+            Method::Put,
+            &format!("/households/{}/announcements/{}", household_id, announcement_id),
+            Some(request),
+        )
+        .await
+    }
+
+    pub async fn delete_announcement(&mut self, household_id: &str, announcement_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/announcements/{}", household_id, announcement_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Confirm that the current user has read an announcement
+    pub async fn acknowledge_announcement(&mut self, household_id: &str, announcement_id: &str) -> Result<DateTime<Utc>, ClientError> {
+        self.request::<DateTime<Utc>>(
+            Method::Post,
+            &format!("/households/{}/announcements/{}/acknowledge", household_id, announcement_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Which members have read an announcement (author and owners only)
+    pub async fn list_announcement_acknowledgments(
+        &mut self,
+        household_id: &str,
+        announcement_id: &str,
+    ) -> Result<Vec<AnnouncementAcknowledgment>, ClientError> {
+        self.request::<Vec<AnnouncementAcknowledgment>>(
+            Method::Get,
+            &format!("/households/{}/announcements/{}/acknowledgments", household_id, announcement_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // User settings endpoints
+    pub async fn get_user_settings(&mut self) -> Result<UserSettings, ClientError> {
+        self.request::<UserSettings>(Method::Get, "/users/me/settings", None::<()>).await
+    }
+
+    pub async fn update_user_settings(
+        &mut self,
+        request: UpdateUserSettingsRequest,
+    ) -> Result<UserSettings, ClientError> {
+        self.request(Method::Put, "/users/me/settings", Some(request)).await
+    }
+
+    // Notification center endpoints
+    pub async fn list_notifications(&mut self) -> Result<NotificationList, ClientError> {
+        self.request::<NotificationList>(Method::Get, "/notifications", None::<()>).await
+    }
+
+    pub async fn mark_notification_read(&mut self, notification_id: &str) -> Result<Notification, ClientError> {
+        self.request::<Notification>(
+            Method::Post,
+            &format!("/notifications/{}/read", notification_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn mark_all_notifications_read(&mut self) -> Result<(), ClientError> {
+        self.request::<()>(Method::Post, "/notifications/read-all", None::<()>).await
+    }
+
+    // Calendar feed endpoints
+    pub async fn get_calendar_feed_token(&mut self) -> Result<CalendarFeedToken, ClientError> {
+        self.request::<CalendarFeedToken>(Method::Get, "/users/me/calendar-token", None::<()>).await
+    }
+
+    pub async fn regenerate_calendar_feed_token(&mut self) -> Result<CalendarFeedToken, ClientError> {
+        self.request::<CalendarFeedToken>(Method::Post, "/users/me/calendar-token", None::<()>).await
+    }
+
+    // Personal API token endpoints
+    pub async fn list_api_tokens(&mut self) -> Result<Vec<ApiToken>, ClientError> {
+        self.request::<Vec<ApiToken>>(Method::Get, "/users/me/api-tokens", None::<()>).await
+    }
+
+    pub async fn create_api_token(&mut self, request: CreateApiTokenRequest) -> Result<CreatedApiToken, ClientError> {
+        self.request(Method::Post, "/users/me/api-tokens", Some(request)).await
+    }
+
+    pub async fn revoke_api_token(&mut self, token_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(Method::Delete, &format!("/users/me/api-tokens/{}", token_id), None::<()>).await
+    }
+
+    // Instance admin endpoints
+    pub async fn admin_list_users(&mut self) -> Result<Vec<AdminUser>, ClientError> {
+        self.request::<Vec<AdminUser>>(Method::Get, "/admin/users", None::<()>).await
+    }
+
+    pub async fn admin_set_user_disabled(&mut self, user_id: &str, disabled: bool) -> Result<AdminUser, ClientError> {
+        let action = if disabled { "disable" } else { "enable" };
+        self.request::<AdminUser>(Method::Post, &format!("/admin/users/{}/{}", user_id, action), None::<()>).await
+    }
+
+    pub async fn admin_set_user_admin(&mut self, user_id: &str, is_admin: bool) -> Result<AdminUser, ClientError> {
+        let action = if is_admin { "grant-admin" } else { "revoke-admin" };
+        self.request::<AdminUser>(Method::Post, &format!("/admin/users/{}/{}", user_id, action), None::<()>).await
+    }
+
+    pub async fn admin_household_usage(&mut self) -> Result<Vec<HouseholdUsage>, ClientError> {
+        self.request::<Vec<HouseholdUsage>>(Method::Get, "/admin/households", None::<()>).await
+    }
+
+    /// Build the subscribable iCalendar URL for a household; relative to the
+    /// origin when the client has no server URL
+    pub fn calendar_feed_url(&self, household_id: &str, token: &str) -> String {
+        api_url(
+            &self.server_url,
+            &format!("/households/{}/tasks/calendar.ics?token={}", household_id, token),
+        )
+    }
+
+    // Push notification endpoints
+    pub async fn get_push_config(&mut self) -> Result<PushConfig, ClientError> {
+        self.request::<PushConfig>(Method::Get, "/users/me/push/config", None::<()>).await
+    }
+
+    pub async fn subscribe_push(&mut self, request: PushSubscriptionRequest) -> Result<(), ClientError> {
+        self.request::<()>(Method::Post, "/users/me/push/subscriptions", Some(request)).await
+    }
+
+    pub async fn unsubscribe_push(&mut self, endpoint: String) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            "/users/me/push/subscriptions",
+            Some(UnsubscribePushRequest { endpoint }),
+        )
+        .await
+    }
+
+    // Dashboard task whitelist endpoints
+    pub async fn get_dashboard_task_ids(&mut self) -> Result<Vec<uuid::Uuid>, ClientError> {
+        let response: shared::DashboardTasksResponse =
+            self.request(Method::Get, "/dashboard/tasks", None::<()>).await?;
+        Ok(response.task_ids)
+    }
+
+    pub async fn is_task_on_dashboard(&mut self, task_id: &str) -> Result<bool, ClientError> {
+        let response: shared::IsTaskOnDashboardResponse =
+            self.request(Method::Get, &format!("/dashboard/tasks/{}", task_id), None::<()>).await?;
+        Ok(response.on_dashboard)
+    }
+
+    pub async fn add_task_to_dashboard(&mut self, task_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(Method::Post, &format!("/dashboard/tasks/{}", task_id), None::<()>).await
+    }
+
+    pub async fn remove_task_from_dashboard(&mut self, task_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(Method::Delete, &format!("/dashboard/tasks/{}", task_id), None::<()>).await
+    }
+
+    pub async fn reorder_dashboard_tasks(&mut self, task_ids: &[uuid::Uuid]) -> Result<(), ClientError> {
+        let body = ReorderDashboardTasksRequest {
+            task_ids: task_ids.to_vec(),
+        };
+        self.request::<()>(Method::Put, "/dashboard/tasks/order", Some(body)).await
+    }
+
+    pub async fn get_dashboard_tasks_with_status(
+    &mut self,
+    ) -> Result<Vec<shared::DashboardTaskWithHousehold>, ClientError> {
+        let response: shared::DashboardTasksWithStatusResponse =
+            self.request(Method::Get, "/dashboard/tasks/details", None::<()>).await?;
+        Ok(response.tasks)
+    }
+
+    /// Get the tasks from all households the user is a member of, filtered on the server
+    /// Used by the "Show all" toggle on the dashboard
+    pub async fn get_all_tasks_across_households(
+        &mut self,
+        query: &shared::DashboardTaskQuery,
+    ) -> Result<shared::AllDashboardTasksResponse, ClientError> {
+        self.request(
+            Method::Get,
+            &format!("/dashboard/tasks/all{}", query.to_query_string()),
+            None::<()>,
+        )
+        .await
+    }
+
+    // Statistics endpoints
+
+    /// Get weekly statistics for a household
+    pub async fn get_weekly_statistics(
+        &mut self,
+        household_id: &str,
+        week_start: Option<&str>,
+    ) -> Result<WeeklyStatisticsResponse, ClientError> {
+        let url = match week_start {
+            Some(date) => format!(
+                "/households/{}/statistics/weekly?week_start={}",
+                household_id, date
+            ),
+            None => format!("/households/{}/statistics/weekly", household_id),
+        };
+        self.request::<WeeklyStatisticsResponse>(Method::Get, &url, None::<()>).await
+    }
+
+    /// Calculate weekly statistics for a household
+    pub async fn calculate_weekly_statistics(
+        &mut self,
+        household_id: &str,
+        week_start: Option<&str>,
+    ) -> Result<WeeklyStatisticsResponse, ClientError> {
+        let url = match week_start {
+            Some(date) => format!(
+                "/households/{}/statistics/weekly/calculate?week_start={}",
+                household_id, date
+            ),
+            None => format!("/households/{}/statistics/weekly/calculate", household_id),
+        };
+        self.request::<WeeklyStatisticsResponse>(Method::Post, &url, None::<()>).await
+    }
+
+    /// List available weeks with statistics
+    pub async fn list_available_weeks(
+        &mut self,
+        household_id: &str,
+    ) -> Result<Vec<chrono::NaiveDate>, ClientError> {
+        self.request::<Vec<chrono::NaiveDate>>(
+            Method::Get,
+            &format!("/households/{}/statistics/weekly/available", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Get monthly statistics for a household
+    pub async fn get_monthly_statistics(
+        &mut self,
+        household_id: &str,
+        month: Option<&str>,
+    ) -> Result<MonthlyStatisticsResponse, ClientError> {
+        let url = match month {
+            Some(date) => format!(
+                "/households/{}/statistics/monthly?month={}",
+                household_id, date
+            ),
+            None => format!("/households/{}/statistics/monthly", household_id),
+        };
+        self.request::<MonthlyStatisticsResponse>(Method::Get, &url, None::<()>).await
+    }
+
+    /// Get an aggregated household report for a date range (defaults to the current month)
+    pub async fn get_household_report(
+        &mut self,
+        household_id: &str,
+        start: Option<&str>,
+        end: Option<&str>,
+    ) -> Result<HouseholdReport, ClientError> {
+        let mut params = Vec::new();
+        if let Some(start) = start {
+            params.push(format!("start={}", start));
+        }
+        if let Some(end) = end {
+            params.push(format!("end={}", end));
+        }
+        let url = if params.is_empty() {
+            format!("/households/{}/statistics/report", household_id)
+        } else {
+            format!("/households/{}/statistics/report?{}", household_id, params.join("&"))
+        };
+        self.request::<HouseholdReport>(Method::Get, &url, None::<()>).await
+    }
+
+    /// Estimated chore load per member over a date range (defaults to the current month)
+    pub async fn get_workload_report(
+        &mut self,
+        household_id: &str,
+        start: Option<&str>,
+        end: Option<&str>,
+    ) -> Result<WorkloadReport, ClientError> {
+        let mut params = Vec::new();
+        if let Some(start) = start {
+            params.push(format!("start={}", start));
+        }
+        if let Some(end) = end {
+            params.push(format!("end={}", end));
+        }
+        let url = if params.is_empty() {
+            format!("/households/{}/statistics/workload", household_id)
+        } else {
+            format!("/households/{}/statistics/workload?{}", household_id, params.join("&"))
+        };
+        self.request::<WorkloadReport>(Method::Get, &url, None::<()>).await
+    }
+
+    /// Suggest task reassignments that even out the members' workload
+    pub async fn suggest_rebalance(&mut self, household_id: &str, request: RebalanceRequest) -> Result<RebalanceSuggestion, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/tasks/rebalance-suggestions", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    /// Apply the changes of a rebalance suggestion
+    pub async fn apply_rebalance(&mut self, household_id: &str, changes: Vec<AssignmentChange>) -> Result<Vec<Task>, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/tasks/rebalance-suggestions/apply", household_id),
+            Some(ApplyRebalanceRequest { changes }),
+        )
+        .await
+    }
+
+    /// Draw random assignees for a set of tasks without applying them
+    pub async fn randomize_assignments(
+        &mut self,
+        household_id: &str,
+        request: RandomizeAssignmentsRequest,
+    ) -> Result<AssignmentPlan, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/tasks/randomize-assignments", household_id),
+            Some(request),
+        )
+        .await
+    }
+
+    /// Apply the assignments of a randomized plan
+    pub async fn apply_assignment_plan(&mut self, household_id: &str, assignments: Vec<PlannedAssignment>) -> Result<Vec<Task>, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/tasks/randomize-assignments/apply", household_id),
+            Some(ApplyAssignmentPlanRequest { assignments }),
+        )
+        .await
+    }
+
+    // Member task exclusion endpoints
+    pub async fn list_member_exclusions(
+        &mut self,
+        household_id: &str,
+        user_id: &str,
+    ) -> Result<Vec<shared::MemberTaskExclusion>, ClientError> {
+        self.request(
+            Method::Get,
+            &format!("/households/{}/members/{}/exclusions", household_id, user_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    pub async fn add_member_exclusion(
+        &mut self,
+        household_id: &str,
+        user_id: &str,
+        task_id: uuid::Uuid,
+    ) -> Result<shared::MemberTaskExclusion, ClientError> {
+        self.request(
+            Method::Post,
+            &format!("/households/{}/members/{}/exclusions", household_id, user_id),
+            Some(shared::AddTaskExclusionRequest { task_id }),
+        )
+        .await
+    }
+
+    pub async fn remove_member_exclusion(&mut self, household_id: &str, user_id: &str, task_id: &str) -> Result<(), ClientError> {
+        self.request::<()>(
+            Method::Delete,
+            &format!("/households/{}/members/{}/exclusions/{}", household_id, user_id, task_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Calculate monthly statistics for a household
+    pub async fn calculate_monthly_statistics(
+        &mut self,
+        household_id: &str,
+        month: Option<&str>,
+    ) -> Result<MonthlyStatisticsResponse, ClientError> {
+        let url = match month {
+            Some(date) => format!(
+                "/households/{}/statistics/monthly/calculate?month={}",
+                household_id, date
+            ),
+            None => format!("/households/{}/statistics/monthly/calculate", household_id),
+        };
+        self.request::<MonthlyStatisticsResponse>(Method::Post, &url, None::<()>).await
+    }
+
+    /// List available months with statistics
+    pub async fn list_available_months(
+        &mut self,
+        household_id: &str,
+    ) -> Result<Vec<chrono::NaiveDate>, ClientError> {
+        self.request::<Vec<chrono::NaiveDate>>(
+            Method::Get,
+            &format!("/households/{}/statistics/monthly/available", household_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    // =========================================================================
+    // Legal pages (public, no auth required)
+    // =========================================================================
+
+    /// Get Impressum content (raw markdown)
+    pub async fn get_impressum(&self) -> Result<String, ClientError> {
+        self.request_text("/legal/impressum").await
+    }
+
+    /// Get Datenschutz (Privacy Policy) content (raw markdown)
+    pub async fn get_datenschutz(&self) -> Result<String, ClientError> {
+        self.request_text("/legal/datenschutz").await
+    }
+
+    /// Get AGB (Terms of Service) content (raw markdown)
+    pub async fn get_agb(&self) -> Result<String, ClientError> {
+        self.request_text("/legal/agb").await
+    }
+}
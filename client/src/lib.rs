@@ -0,0 +1,18 @@
+//! Typed HTTP client for the Haushalt API
+//!
+//! The client owns URL formatting, the `ApiSuccess`/`ApiError` envelopes,
+//! token refresh and idempotency keys, so the CLI, bots and integration tests
+//! don't have to duplicate them. The HTTP layer is pluggable: enable the
+//! `reqwest` feature for native programs or `gloo` for the WASM frontend, or
+//! implement [`Transport`] yourself.
+
+mod client;
+mod endpoints;
+mod transport;
+
+pub use client::{api_url, decode_response, Client, ClientError, MemoryTokens, TokenStore};
+#[cfg(feature = "gloo")]
+pub use transport::GlooTransport;
+#[cfg(feature = "reqwest")]
+pub use transport::ReqwestTransport;
+pub use transport::{Method, Request, Response, Transport};
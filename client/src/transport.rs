@@ -0,0 +1,161 @@
+/// HTTP methods used by the API
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Delete,
+}
+
+impl Method {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Delete => "DELETE",
+        }
+    }
+}
+
+/// A request as handed to a [`Transport`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    /// JSON body; sent with `Content-Type: application/json`
+    pub body: Option<String>,
+}
+
+impl Request {
+    pub fn new(method: Method, url: impl Into<String>) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.headers.push((name.to_string(), value.into()));
+        self
+    }
+
+    pub fn json_body(mut self, body: Option<String>) -> Self {
+        self.body = body;
+        self
+    }
+
+    pub fn header_value(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Status and body of a completed request
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+    /// `X-Request-Id` header, if the server sent one
+    pub request_id: Option<String>,
+}
+
+/// Sends requests over HTTP. Errors are connection-level failures only;
+/// non-2xx responses are returned as a [`Response`].
+// No Send bound on the future: browser fetch futures are not Send
+#[allow(async_fn_in_trait)]
+pub trait Transport {
+    async fn send(&self, request: Request) -> Result<Response, String>;
+}
+
+#[cfg(feature = "reqwest")]
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    http: reqwest::Client,
+}
+
+#[cfg(feature = "reqwest")]
+impl ReqwestTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl Transport for ReqwestTransport {
+    async fn send(&self, request: Request) -> Result<Response, String> {
+        let method = match request.method {
+            Method::Get => reqwest::Method::GET,
+            Method::Post => reqwest::Method::POST,
+            Method::Put => reqwest::Method::PUT,
+            Method::Delete => reqwest::Method::DELETE,
+        };
+        let mut builder = self.http.request(method, &request.url);
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+        if let Some(body) = request.body {
+            builder = builder.header("Content-Type", "application/json").body(body);
+        }
+
+        let response = builder.send().await.map_err(|e| e.to_string())?;
+        let status = response.status().as_u16();
+        let request_id = response
+            .headers()
+            .get("X-Request-Id")
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let body = response.text().await.map_err(|e| e.to_string())?;
+        Ok(Response {
+            status,
+            body,
+            request_id,
+        })
+    }
+}
+
+#[cfg(feature = "gloo")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GlooTransport;
+
+#[cfg(feature = "gloo")]
+impl Transport for GlooTransport {
+    async fn send(&self, request: Request) -> Result<Response, String> {
+        use gloo_net::http::Request as GlooRequest;
+
+        let mut builder = match request.method {
+            Method::Get => GlooRequest::get(&request.url),
+            Method::Post => GlooRequest::post(&request.url),
+            Method::Put => GlooRequest::put(&request.url),
+            Method::Delete => GlooRequest::delete(&request.url),
+        };
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+
+        let response = match request.body {
+            Some(body) => builder
+                .header("Content-Type", "application/json")
+                .body(body)
+                .map_err(|e| e.to_string())?
+                .send()
+                .await,
+            None => builder.send().await,
+        }
+        .map_err(|e| e.to_string())?;
+
+        let status = response.status();
+        let request_id = response.headers().get("X-Request-Id");
+        let body = response.text().await.map_err(|e| e.to_string())?;
+        Ok(Response {
+            status,
+            body,
+            request_id,
+        })
+    }
+}
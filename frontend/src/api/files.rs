@@ -0,0 +1,154 @@
+//! Requests that need browser types: multipart uploads of a `File` and
+//! binary downloads turned into data URLs. Everything else goes through
+//! [`super::api_client`].
+
+use base64::Engine;
+use client::{api_url, TokenStore};
+use gloo_net::http::{Request, Response};
+use serde::de::DeserializeOwned;
+use shared::{ApiError, ApiSuccess, Attachment, AttachmentEntity, CompletionAttachment};
+
+use super::{api_client, BrowserTokens};
+
+/// Send a non-JSON request with the auth header, refreshing the token once on 401.
+/// `build` is called again for the retry so the request can be recreated.
+async fn send_authorized(build: impl Fn(Option<String>) -> Result<Request, String>) -> Result<Response, String> {
+    let response = build(BrowserTokens.token())?.send().await.map_err(|e| e.to_string())?;
+    if response.status() != 401 {
+        return Ok(response);
+    }
+    api_client().refresh_session().await?;
+    build(BrowserTokens.token())?.send().await.map_err(|e| e.to_string())
+}
+
+async fn error_message(response: &Response) -> String {
+    response
+        .json::<ApiError>()
+        .await
+        .map(|e| e.message)
+        .unwrap_or_else(|_| "An unknown error occurred".to_string())
+}
+
+/// Upload a file as the `file` field of a multipart form
+async fn upload_file<T: DeserializeOwned>(path: &str, file: &web_sys::File) -> Result<T, String> {
+    let url = api_url("", path);
+    let response = send_authorized(|token| {
+        // The browser sets the multipart content type and boundary itself
+        let form = web_sys::FormData::new().map_err(|_| "Failed to build upload".to_string())?;
+        form.append_with_blob_and_filename("file", file, &file.name())
+            .map_err(|_| "Failed to build upload".to_string())?;
+        let mut request = Request::post(&url);
+        if let Some(token) = token {
+            request = request.header("Authorization", &format!("Bearer {}", token));
+        }
+        request.body(form).map_err(|e| e.to_string())
+    })
+    .await?;
+
+    if !response.ok() {
+        return Err(error_message(&response).await);
+    }
+    let result: ApiSuccess<T> = response.json().await.map_err(|e| e.to_string())?;
+    Ok(result.data)
+}
+
+/// Download a file and return it as a data URL of the given content type
+async fn fetch_data_url(path: &str, content_type: &str) -> Result<String, String> {
+    let url = api_url("", path);
+    let response = send_authorized(|token| {
+        let mut request = Request::get(&url);
+        if let Some(token) = token {
+            request = request.header("Authorization", &format!("Bearer {}", token));
+        }
+        request.build().map_err(|e| e.to_string())
+    })
+    .await?;
+
+    if !response.ok() {
+        return Err(error_message(&response).await);
+    }
+    let data = response.binary().await.map_err(|e| e.to_string())?;
+    Ok(format!(
+        "data:{};base64,{}",
+        content_type,
+        base64::engine::general_purpose::STANDARD.encode(data)
+    ))
+}
+
+/// Download `completions` or `points` as CSV; returns a data URL. Empty dates are not filtered.
+pub async fn export_csv(id: &str, kind: &str, from: &str, to: &str) -> Result<String, String> {
+    let mut params = Vec::new();
+    if !from.is_empty() {
+        params.push(format!("from={}", from));
+    }
+    if !to.is_empty() {
+        params.push(format!("to={}", to));
+    }
+    let query = if params.is_empty() { String::new() } else { format!("?{}", params.join("&")) };
+    fetch_data_url(&format!("/households/{}/export/{}.csv{}", id, kind, query), "text/csv").await
+}
+
+/// Upload a photo as proof for a completion that awaits review
+pub async fn upload_completion_attachment(
+    household_id: &str,
+    completion_id: &str,
+    file: &web_sys::File,
+) -> Result<CompletionAttachment, String> {
+    upload_file(
+        &format!("/households/{}/tasks/completions/{}/attachments", household_id, completion_id),
+        file,
+    )
+    .await
+}
+
+/// Load a completion photo as a data URL; `<img>` cannot send the auth header itself
+pub async fn get_completion_attachment_url(
+    household_id: &str,
+    attachment: &CompletionAttachment,
+) -> Result<String, String> {
+    fetch_data_url(
+        &format!("/households/{}/tasks/attachments/{}", household_id, attachment.id),
+        &attachment.content_type,
+    )
+    .await
+}
+
+pub async fn upload_attachment(
+    household_id: &str,
+    entity_type: AttachmentEntity,
+    entity_id: &str,
+    file: &web_sys::File,
+) -> Result<Attachment, String> {
+    upload_file(
+        &format!("/households/{}/attachments/{}/{}", household_id, entity_type.as_str(), entity_id),
+        file,
+    )
+    .await
+}
+
+/// Load an attachment as a data URL, e.g. for a download link
+pub async fn get_attachment_url(household_id: &str, attachment: &Attachment) -> Result<String, String> {
+    fetch_data_url(
+        &format!("/households/{}/attachments/{}", household_id, attachment.id),
+        &attachment.content_type,
+    )
+    .await
+}
+
+/// Load the server-generated JPEG preview of an image attachment as a data URL
+pub async fn get_attachment_thumbnail_url(household_id: &str, attachment: &Attachment) -> Result<String, String> {
+    fetch_data_url(
+        &format!("/households/{}/attachments/{}/thumbnail", household_id, attachment.id),
+        "image/jpeg",
+    )
+    .await
+}
+
+/// Load the QR code of an invite link as a data URL for an `<img>`
+pub async fn get_invite_code_qr_url(household_id: &str, code_id: &str) -> Result<String, String> {
+    fetch_data_url(
+        &format!("/households/{}/invite-codes/{}/qr", household_id, code_id),
+        "image/svg+xml",
+    )
+    .await
+}
@@ -0,0 +1,101 @@
+//! Browser side of Web Push: subscribes the service worker and registers it with the backend
+
+use wasm_bindgen::JsCast;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{NotificationPermission, PushManager, PushSubscription, PushSubscriptionOptionsInit, ServiceWorkerRegistration};
+
+use shared::PushSubscriptionRequest;
+
+use super::api_client;
+
+fn js_error(e: JsValue) -> String {
+    e.as_string().unwrap_or_else(|| format!("{:?}", e))
+}
+
+/// Whether the browser supports service workers and the Push API
+pub fn is_push_supported() -> bool {
+    let Some(window) = web_sys::window() else {
+        return false;
+    };
+    js_sys::Reflect::has(&window, &JsValue::from_str("PushManager")).unwrap_or(false)
+        && js_sys::Reflect::has(&window.navigator(), &JsValue::from_str("serviceWorker")).unwrap_or(false)
+}
+
+async fn push_manager() -> Result<PushManager, String> {
+    let window = web_sys::window().ok_or("No window")?;
+    let ready = window.navigator().service_worker().ready().map_err(js_error)?;
+    let registration: ServiceWorkerRegistration = JsFuture::from(ready)
+        .await
+        .map_err(js_error)?
+        .unchecked_into();
+    registration.push_manager().map_err(js_error)
+}
+
+async fn current_subscription() -> Result<Option<PushSubscription>, String> {
+    let manager = push_manager().await?;
+    let subscription = JsFuture::from(manager.get_subscription().map_err(js_error)?)
+        .await
+        .map_err(js_error)?;
+    if subscription.is_null() || subscription.is_undefined() {
+        Ok(None)
+    } else {
+        Ok(Some(subscription.unchecked_into()))
+    }
+}
+
+/// Whether this browser currently has an active push subscription
+pub async fn is_push_enabled() -> bool {
+    is_push_supported() && matches!(current_subscription().await, Ok(Some(_)))
+}
+
+/// Ask for notification permission, subscribe to push and register the subscription with the backend
+pub async fn enable_push_notifications() -> Result<(), String> {
+    if !is_push_supported() {
+        return Err("Push notifications are not supported by this browser".to_string());
+    }
+
+    let public_key = api_client().get_push_config()
+        .await?
+        .public_key
+        .ok_or("Push notifications are not enabled on this server")?;
+
+    JsFuture::from(web_sys::Notification::request_permission().map_err(js_error)?)
+        .await
+        .map_err(js_error)?;
+    if web_sys::Notification::permission() != NotificationPermission::Granted {
+        return Err("Notification permission was denied".to_string());
+    }
+
+    let options = PushSubscriptionOptionsInit::new();
+    options.set_user_visible_only(true);
+    // applicationServerKey accepts the base64url-encoded key directly
+    options.set_application_server_key(&JsValue::from_str(&public_key));
+
+    let manager = push_manager().await?;
+    let subscription: PushSubscription = JsFuture::from(manager.subscribe_with_options(&options).map_err(js_error)?)
+        .await
+        .map_err(js_error)?
+        .unchecked_into();
+
+    let json: String = js_sys::JSON::stringify(&subscription)
+        .map_err(js_error)?
+        .into();
+    let request: PushSubscriptionRequest = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+
+    api_client().subscribe_push(request).await.map_err(String::from)
+}
+
+/// Unsubscribe this browser from push and remove the subscription on the backend
+pub async fn disable_push_notifications() -> Result<(), String> {
+    let Some(subscription) = current_subscription().await? else {
+        return Ok(());
+    };
+
+    let endpoint = subscription.endpoint();
+    JsFuture::from(subscription.unsubscribe().map_err(js_error)?)
+        .await
+        .map_err(js_error)?;
+
+    api_client().unsubscribe_push(endpoint).await.map_err(String::from)
+}
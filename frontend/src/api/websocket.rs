@@ -7,7 +7,7 @@ use uuid::Uuid;
 use wasm_bindgen::prelude::*;
 use web_sys::{CloseEvent, ErrorEvent, MessageEvent, WebSocket};
 
-use shared::{WsClientMessage, WsServerMessage};
+use shared::{WsClientMessage, WsEventCategory, WsServerMessage};
 
 const TOKEN_KEY: &str = "auth_token";
 
@@ -23,7 +23,7 @@ pub enum WsConnectionState {
     Error,
 }
 
-/// WebSocket client for chat functionality
+/// WebSocket client for chat and live household updates
 #[derive(Clone)]
 pub struct WsClient {
     ws: Rc<RefCell<Option<WebSocket>>>,
@@ -212,9 +212,27 @@ impl WsClient {
         self.send(WsClientMessage::LeaveRoom);
     }
 
-    /// Send a chat message
-    pub fn send_message(&self, content: String) {
-        self.send(WsClientMessage::SendMessage { content });
+    /// Receive the given event categories of a household (all if empty)
+    pub fn subscribe(&self, household_id: Uuid, categories: Vec<WsEventCategory>) {
+        self.send(WsClientMessage::Subscribe { household_id, categories });
+    }
+
+    /// Stop receiving the given event categories of a household (all if empty)
+    pub fn unsubscribe(&self, household_id: Uuid, categories: Vec<WsEventCategory>) {
+        self.send(WsClientMessage::Unsubscribe { household_id, categories });
+    }
+
+    /// Tell the household's other members that the user is typing
+    pub fn typing(&self, household_id: Uuid) {
+        self.send(WsClientMessage::Typing { household_id });
+    }
+
+    /// Send a chat message, optionally as a reply
+    pub fn send_message(&self, content: String, reply_to_message_id: Option<Uuid>) {
+        self.send(WsClientMessage::SendMessage {
+            content,
+            reply_to_message_id,
+        });
     }
 
     /// Edit a chat message
@@ -227,6 +245,11 @@ impl WsClient {
         self.send(WsClientMessage::DeleteMessage { message_id });
     }
 
+    /// Mark messages up to and including this one as read
+    pub fn mark_read(&self, message_id: Uuid) {
+        self.send(WsClientMessage::MarkRead { message_id });
+    }
+
     /// Disconnect from the server
     pub fn disconnect(&self) {
         // Cancel reconnection timeout
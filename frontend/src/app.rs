@@ -1,17 +1,20 @@
 use leptos::*;
 use leptos_router::*;
 
-use crate::api::{ApiClient, AuthState};
+use crate::api::{api_client, AuthState};
 use crate::components::household_layout::HouseholdLayout;
 use crate::components::navbar::Navbar;
 use crate::components::quick_task_fab::QuickTaskFab;
-use crate::i18n::{provide_i18n, use_i18n};
+use crate::components::theme::{provide_theme, use_theme};
+use crate::i18n::{initial_language, provide_i18n, use_i18n};
+use crate::utils::remember_login_redirect;
 use crate::pages::{
-    activity::ActivityPage, chat::ChatPage, dashboard::Dashboard, household::HouseholdPage,
+    activity::ActivityPage, admin::AdminPage, automation_rules::AutomationRulesPage, calendar::CalendarPage, challenges::ChallengesPage, chat::ChatPage, dashboard::Dashboard, expenses::ExpensesPage,
+    household::HouseholdPage, join::JoinHouseholdPage, meals::MealsPage, shopping_list::ShoppingListPage, inventory::InventoryPage, appliances::AppliancesPage, plants::PlantsPage, pets::PetsPage, shared::SharedPageView,
     household_settings::HouseholdSettingsPage, journal::JournalPage,
     legal::{AGBPage, DatenschutzPage, ImpressumPage},
-    login::Login, notes::NotesPage, punishments::PunishmentsPage, register::Register,
-    rewards::RewardsPage, settings::SettingsPage, statistics::StatisticsPage, tasks::TasksPage,
+    login::Login, notes::NotesPage, password_reset::{ForgotPasswordPage, ResetPasswordPage}, punishments::PunishmentsPage, register::Register,
+    rewards::RewardsPage, settings::SettingsPage, statistics::StatisticsPage, tasks::TasksPage, trash::TrashPage,
     user_settings::UserSettingsPage,
 };
 
@@ -20,9 +23,12 @@ pub fn App() -> impl IntoView {
     let auth_state = AuthState::new();
     provide_context(auth_state.clone());
 
-    // Provide i18n context with default language
+    // Start in the language last used on this device
     // The language will be updated when user settings are loaded
-    provide_i18n("en".to_string());
+    provide_i18n(initial_language());
+
+    // Follows the device preference until the user's theme is loaded
+    provide_theme();
 
     view! {
         <Router>
@@ -30,27 +36,44 @@ pub fn App() -> impl IntoView {
                 <Routes>
                     <Route path="/login" view=Login />
                     <Route path="/register" view=Register />
+                    <Route path="/forgot-password" view=ForgotPasswordPage />
+                    <Route path="/reset-password" view=ResetPasswordPage />
                     // Public legal pages
                     <Route path="/impressum" view=ImpressumPage />
                     <Route path="/datenschutz" view=DatenschutzPage />
                     <Route path="/agb" view=AGBPage />
+                    // Read-only share links for guests
+                    <Route path="/shared/:token" view=SharedPageView />
                     <Route path="/" view=AuthenticatedLayout>
                         <Route path="" view=Dashboard />
                         // Household routes - nested under HouseholdLayout for shared tabs
                         <Route path="/households/:id" view=HouseholdLayout>
                             <Route path="" view=HouseholdPage />
                             <Route path="tasks" view=TasksPage />
+                            <Route path="calendar" view=CalendarPage />
                             <Route path="rewards" view=RewardsPage />
                             <Route path="punishments" view=PunishmentsPage />
                             <Route path="notes" view=NotesPage />
                             <Route path="journal" view=JournalPage />
+                            <Route path="expenses" view=ExpensesPage />
+                            <Route path="meals" view=MealsPage />
+                            <Route path="shopping-list" view=ShoppingListPage />
+                            <Route path="inventory" view=InventoryPage />
+                            <Route path="appliances" view=AppliancesPage />
+                            <Route path="plants" view=PlantsPage />
+                            <Route path="pets" view=PetsPage />
                             <Route path="chat" view=ChatPage />
+                            <Route path="challenges" view=ChallengesPage />
+                            <Route path="rules" view=AutomationRulesPage />
                             <Route path="activity" view=ActivityPage />
                             <Route path="statistics" view=StatisticsPage />
+                            <Route path="trash" view=TrashPage />
                             <Route path="settings" view=HouseholdSettingsPage />
                         </Route>
                         <Route path="/settings" view=SettingsPage />
                         <Route path="/user-settings" view=UserSettingsPage />
+                        <Route path="/admin" view=AdminPage />
+                        <Route path="/join/:code" view=JoinHouseholdPage />
                     </Route>
                 </Routes>
             </main>
@@ -62,6 +85,7 @@ pub fn App() -> impl IntoView {
 fn AuthenticatedLayout() -> impl IntoView {
     let auth_state = expect_context::<AuthState>();
     let i18n = use_i18n();
+    let theme = use_theme();
 
     // Check for auth failure on each render
     let auth_state_check = auth_state.clone();
@@ -70,16 +94,17 @@ fn AuthenticatedLayout() -> impl IntoView {
         auth_state_check.check_and_clear_auth_failed();
     });
 
-    // Load user settings and update language on authentication
+    // Load user settings and update language and theme on authentication
     let auth_state_effect = auth_state.clone();
     create_effect(move |_| {
         if auth_state_effect.is_authenticated() {
             let i18n = i18n.clone();
             let auth_state_for_error = auth_state_effect.clone();
             wasm_bindgen_futures::spawn_local(async move {
-                match ApiClient::get_user_settings().await {
+                match api_client().get_user_settings().await {
                     Ok(settings) => {
                         i18n.set_language(&settings.language);
+                        theme.set_theme(settings.theme);
                     }
                     Err(_) => {
                         // Check if auth failed during the request
@@ -107,6 +132,8 @@ fn AuthenticatedLayout() -> impl IntoView {
 #[component]
 fn RedirectToLogin() -> impl IntoView {
     let navigate = use_navigate();
+    let location = use_location();
+    remember_login_redirect(&location.pathname.get_untracked());
     navigate("/login", Default::default());
     view! {}
 }
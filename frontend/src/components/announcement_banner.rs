@@ -1,17 +1,20 @@
 use leptos::*;
 use shared::Announcement;
 
+use crate::api::api_client;
 use crate::components::markdown::MarkdownView;
 
 /// Renders a list of announcements as banners
 #[component]
 pub fn AnnouncementBanner(
+    household_id: String,
     announcements: Vec<Announcement>,
     /// Optional callback when manage button is clicked (only shown if Some)
     #[prop(optional)]
     on_manage: Option<Callback<()>>,
 ) -> impl IntoView {
     let dismissed = create_rw_signal(std::collections::HashSet::<String>::new());
+    let household_id = store_value(household_id);
 
     view! {
         <div class="announcements-container">
@@ -20,6 +23,16 @@ pub fn AnnouncementBanner(
                 let id_for_check = id.clone();
                 let title = announcement.title.clone();
                 let content = announcement.content.clone();
+                let acknowledged = create_rw_signal(announcement.acknowledged_at.is_some());
+                let announcement_id = store_value(id.clone());
+                let on_acknowledge = move |_| {
+                    wasm_bindgen_futures::spawn_local(async move {
+                        let id = announcement_id.get_value();
+                        if api_client().acknowledge_announcement(&household_id.get_value(), &id).await.is_ok() {
+                            acknowledged.set(true);
+                        }
+                    });
+                };
 
                 view! {
                     <Show
@@ -50,6 +63,17 @@ pub fn AnnouncementBanner(
                                     } else {
                                         view! {}.into_view()
                                     }}
+                                    <div class="announcement-acknowledge">
+                                        {move || if acknowledged.get() {
+                                            view! { <span class="announcement-read">"✓ Read"</span> }.into_view()
+                                        } else {
+                                            view! {
+                                                <button class="btn btn-outline btn-sm" on:click=on_acknowledge>
+                                                    "Mark as read"
+                                                </button>
+                                            }.into_view()
+                                        }}
+                                    </div>
                                 </div>
                             }
                         }
@@ -1,7 +1,10 @@
 use leptos::*;
-use shared::{Announcement, CreateAnnouncementRequest, HouseholdSettings, UpdateAnnouncementRequest};
+use shared::{
+    Announcement, AnnouncementAcknowledgment, CreateAnnouncementRequest, HouseholdSettings, UpdateAnnouncementRequest,
+};
+use uuid::Uuid;
 
-use crate::api::ApiClient;
+use crate::api::api_client;
 use crate::components::markdown::MarkdownViewReactive;
 use crate::utils::{format_datetime, local_string_to_utc, utc_to_local_string};
 
@@ -17,6 +20,9 @@ pub fn AnnouncementModal(
     let error = create_rw_signal(Option::<String>::None);
     let success = create_rw_signal(Option::<String>::None);
 
+    // Read checklist of the announcement it is open for
+    let checklist = create_rw_signal(Option::<(Uuid, Vec<AnnouncementAcknowledgment>)>::None);
+
     // Edit state: None = list mode, Some(None) = create mode, Some(Some(ann)) = edit mode
     let edit_announcement = create_rw_signal(Option::<Option<Announcement>>::None);
 
@@ -28,19 +34,19 @@ pub fn AnnouncementModal(
         let household_id = household_id_signal.get_value();
         let household_id_settings = household_id.clone();
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::list_announcements(&household_id).await {
+            match api_client().list_announcements(&household_id).await {
                 Ok(anns) => {
                     announcements.set(anns);
                     loading.set(false);
                 }
                 Err(e) => {
-                    error.set(Some(e));
+                    error.set(Some(e.to_string()));
                     loading.set(false);
                 }
             }
         });
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(s) = ApiClient::get_household_settings(&household_id_settings).await {
+            if let Ok(s) = api_client().get_household_settings(&household_id_settings).await {
                 settings.set(Some(s));
             }
         });
@@ -49,7 +55,7 @@ pub fn AnnouncementModal(
     let reload_announcements = move || {
         let household_id = household_id_signal.get_value();
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(anns) = ApiClient::list_announcements(&household_id).await {
+            if let Ok(anns) = api_client().list_announcements(&household_id).await {
                 announcements.set(anns);
             }
         });
@@ -109,7 +115,21 @@ pub fn AnnouncementModal(
                                     key=|ann| ann.id
                                     children=move |ann| {
                                         let ann_id = ann.id.to_string();
+                                        let ann_uuid = ann.id;
                                         let ann_for_edit = ann.clone();
+                                        let toggle_checklist = move |_| {
+                                            if checklist.get_untracked().is_some_and(|(id, _)| id == ann_uuid) {
+                                                checklist.set(None);
+                                                return;
+                                            }
+                                            let household_id = household_id_signal.get_value();
+                                            wasm_bindgen_futures::spawn_local(async move {
+                                                match api_client().list_announcement_acknowledgments(&household_id, &ann_uuid.to_string()).await {
+                                                    Ok(list) => checklist.set(Some((ann_uuid, list))),
+                                                    Err(e) => error.set(Some(e.to_string())),
+                                                }
+                                            });
+                                        };
 
                                         let tz = settings.get().map(|s| s.timezone).unwrap_or_else(|| "UTC".to_string());
                                         let tz_start = tz.clone();
@@ -118,6 +138,9 @@ pub fn AnnouncementModal(
                                             <div class="announcement-list-item">
                                                 <div class="announcement-list-item-content">
                                                     <strong>{ann.title.clone()}</strong>
+                                                    {ann.published_at.is_none().then(|| view! {
+                                                        <span class="badge badge-private" style="margin-left: 0.5rem;">"Scheduled"</span>
+                                                    })}
                                                     {ann.starts_at.map(|dt| {
                                                         view! {
                                                             <span class="badge badge-info" style="margin-left: 0.5rem;">
@@ -134,6 +157,9 @@ pub fn AnnouncementModal(
                                                     })}
                                                 </div>
                                                 <div class="announcement-list-item-actions">
+                                                    <button class="btn btn-outline btn-sm" on:click=toggle_checklist>
+                                                        "Read by"
+                                                    </button>
                                                     <button
                                                         class="btn btn-outline btn-sm"
                                                         on:click=move |_| edit_announcement.set(Some(Some(ann_for_edit.clone())))
@@ -148,14 +174,14 @@ pub fn AnnouncementModal(
                                                                 let ann_id = ann_id.clone();
                                                                 let household_id = household_id_signal.get_value();
                                                                 wasm_bindgen_futures::spawn_local(async move {
-                                                                    match ApiClient::delete_announcement(&household_id, &ann_id).await {
+                                                                    match api_client().delete_announcement(&household_id, &ann_id).await {
                                                                         Ok(()) => {
                                                                             success.set(Some("Announcement deleted".to_string()));
-                                                                            if let Ok(anns) = ApiClient::list_announcements(&household_id).await {
+                                                                            if let Ok(anns) = api_client().list_announcements(&household_id).await {
                                                                                 announcements.set(anns);
                                                                             }
                                                                         }
-                                                                        Err(e) => error.set(Some(e)),
+                                                                        Err(e) => error.set(Some(e.to_string())),
                                                                     }
                                                                 });
                                                             }
@@ -164,6 +190,21 @@ pub fn AnnouncementModal(
                                                         "Delete"
                                                     </button>
                                                 </div>
+                                                {move || checklist.get().filter(|(id, _)| *id == ann_uuid).map(|(_, list)| {
+                                                    let read = list.iter().filter(|a| a.acknowledged_at.is_some()).count();
+                                                    let total = list.len();
+                                                    view! {
+                                                        <div class="announcement-checklist">
+                                                            <div class="text-muted">{format!("Read by {} of {}", read, total)}</div>
+                                                            {list.into_iter().map(|entry| view! {
+                                                                <div class="announcement-checklist-item" class:read=entry.acknowledged_at.is_some()>
+                                                                    {if entry.acknowledged_at.is_some() { "✓ " } else { "○ " }}
+                                                                    {entry.username}
+                                                                </div>
+                                                            }).collect_view()}
+                                                        </div>
+                                                    }
+                                                })}
                                             </div>
                                         }
                                     }
@@ -306,13 +347,13 @@ fn AnnouncementForm(
                     ends_at: Some(ends_at_val),
                 };
 
-                match ApiClient::update_announcement(&household_id, &ann_id, request).await {
+                match api_client().update_announcement(&household_id, &ann_id, request).await {
                     Ok(updated) => {
                         saving.set(false);
                         on_save.call(updated);
                     }
                     Err(e) => {
-                        error.set(Some(e));
+                        error.set(Some(e.to_string()));
                         saving.set(false);
                     }
                 }
@@ -324,13 +365,13 @@ fn AnnouncementForm(
                     ends_at: ends_at_val,
                 };
 
-                match ApiClient::create_announcement(&household_id, request).await {
+                match api_client().create_announcement(&household_id, request).await {
                     Ok(created) => {
                         saving.set(false);
                         on_save.call(created);
                     }
                     Err(e) => {
-                        error.set(Some(e));
+                        error.set(Some(e.to_string()));
                         saving.set(false);
                     }
                 }
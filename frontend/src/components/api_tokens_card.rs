@@ -0,0 +1,149 @@
+use leptos::*;
+use shared::{ApiToken, CreateApiTokenRequest};
+
+use crate::api::api_client;
+use crate::i18n::use_i18n;
+use crate::utils::format_datetime;
+
+/// User settings card to create and revoke personal API tokens for dashboards and home automation
+#[component]
+pub fn ApiTokensCard() -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let tokens = create_rw_signal(Vec::<ApiToken>::new());
+    let error = create_rw_signal(Option::<String>::None);
+    let saving = create_rw_signal(false);
+    let name = create_rw_signal(String::new());
+    let read_only = create_rw_signal(true);
+    // Secret of the token created last; it can't be fetched again
+    let new_secret = create_rw_signal(Option::<String>::None);
+
+    wasm_bindgen_futures::spawn_local(async move {
+        match api_client().list_api_tokens().await {
+            Ok(list) => tokens.set(list),
+            Err(e) => error.set(Some(e.to_string())),
+        }
+    });
+
+    let on_create = move |ev: web_sys::SubmitEvent| {
+        ev.prevent_default();
+        saving.set(true);
+        error.set(None);
+
+        let request = CreateApiTokenRequest {
+            name: name.get(),
+            read_only: read_only.get(),
+        };
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().create_api_token(request).await {
+                Ok(created) => {
+                    tokens.update(|list| list.insert(0, created.token));
+                    new_secret.set(Some(created.secret));
+                    name.set(String::new());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            saving.set(false);
+        });
+    };
+
+    let on_revoke = move |token_id: String| {
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().revoke_api_token(&token_id).await {
+                Ok(()) => tokens.update(|list| list.retain(|t| t.id.to_string() != token_id)),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    view! {
+        <div class="card">
+            <div class="card-header">
+                <h3 class="card-title">{i18n_stored.get_value().t("api_tokens.title")}</h3>
+            </div>
+            <div style="padding: 1rem;">
+                <p class="form-hint">{i18n_stored.get_value().t("api_tokens.hint")}</p>
+
+                {move || error.get().map(|e| view! {
+                    <div class="alert alert-error" style="margin-bottom: 1rem;">{e}</div>
+                })}
+
+                {move || new_secret.get().map(|secret| view! {
+                    <div class="alert alert-success" style="margin-bottom: 1rem;">
+                        <p>{i18n_stored.get_value().t("api_tokens.copy_now")}</p>
+                        <input
+                            type="text"
+                            class="form-input"
+                            readonly
+                            value=secret
+                            on:focus=move |ev| {
+                                use wasm_bindgen::JsCast;
+                                if let Some(input) = ev.target().and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok()) {
+                                    input.select();
+                                }
+                            }
+                        />
+                    </div>
+                })}
+
+                {move || {
+                    let i18n = i18n_stored.get_value();
+                    let list = tokens.get();
+                    if list.is_empty() {
+                        return view! { <p class="empty-state">{i18n.t("api_tokens.empty")}</p> }.into_view();
+                    }
+                    list.into_iter().map(|token| {
+                        let token_id = token.id.to_string();
+                        let scope = if token.read_only { i18n.t("api_tokens.read_only") } else { i18n.t("api_tokens.full_access") };
+                        let last_used = match token.last_used_at {
+                            Some(at) => i18n.t_with("api_tokens.last_used", &[("date", &format_datetime(at, "UTC"))]),
+                            None => i18n.t("api_tokens.never_used"),
+                        };
+                        view! {
+                            <div class="pending-review-item">
+                                <div class="pending-review-content">
+                                    <div class="pending-review-task">{token.name.clone()}</div>
+                                    <div class="pending-review-meta">{scope} " · " {last_used}</div>
+                                </div>
+                                <div class="pending-review-actions">
+                                    <button class="btn btn-danger btn-sm" on:click=move |_| on_revoke(token_id.clone())>
+                                        {i18n.t("api_tokens.revoke")}
+                                    </button>
+                                </div>
+                            </div>
+                        }
+                    }).collect_view()
+                }}
+
+                <form on:submit=on_create style="margin-top: 1rem;">
+                    <div class="form-group">
+                        <label class="form-label" for="api-token-name">{i18n_stored.get_value().t("api_tokens.name")}</label>
+                        <input
+                            type="text"
+                            id="api-token-name"
+                            class="form-input"
+                            required
+                            placeholder=i18n_stored.get_value().t("api_tokens.name_placeholder")
+                            prop:value=move || name.get()
+                            on:input=move |ev| name.set(event_target_value(&ev))
+                        />
+                    </div>
+                    <div class="form-group">
+                        <label style="display: flex; align-items: center; gap: 0.5rem; cursor: pointer;">
+                            <input
+                                type="checkbox"
+                                prop:checked=move || read_only.get()
+                                on:change=move |ev| read_only.set(event_target_checked(&ev))
+                            />
+                            {i18n_stored.get_value().t("api_tokens.read_only_label")}
+                        </label>
+                    </div>
+                    <button type="submit" class="btn btn-primary" disabled=move || saving.get()>
+                        {i18n_stored.get_value().t("api_tokens.create")}
+                    </button>
+                </form>
+            </div>
+        </div>
+    }
+}
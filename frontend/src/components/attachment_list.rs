@@ -0,0 +1,147 @@
+use leptos::*;
+use shared::{Attachment, AttachmentEntity};
+use wasm_bindgen::JsCast;
+
+use crate::api::{api_client, files};
+use crate::i18n::use_i18n;
+use crate::utils::format_size;
+
+/// Let the browser save a data URL under the given file name
+fn trigger_download(url: &str, file_name: &str) {
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+        return;
+    };
+    if let Ok(link) = document.create_element("a") {
+        let _ = link.set_attribute("href", url);
+        let _ = link.set_attribute("download", file_name);
+        if let Ok(link) = link.dyn_into::<web_sys::HtmlElement>() {
+            link.click();
+        }
+    }
+}
+
+/// Files attached to a note or journal entry; the author can add and remove them
+#[component]
+pub fn AttachmentList(
+    household_id: String,
+    entity_type: AttachmentEntity,
+    entity_id: String,
+    attachments: Vec<Attachment>,
+    can_modify: bool,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let attachments = create_rw_signal(attachments);
+    let uploading = create_rw_signal(false);
+    let error = create_rw_signal(Option::<String>::None);
+    let ids = store_value((household_id, entity_id));
+
+    let on_file_change = move |ev: web_sys::Event| {
+        let input = ev.target().and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok());
+        let Some(file) = input.as_ref().and_then(|i| i.files()).and_then(|files| files.get(0)) else {
+            return;
+        };
+        if let Some(input) = input {
+            input.set_value("");
+        }
+
+        uploading.set(true);
+        error.set(None);
+        let (household_id, entity_id) = ids.get_value();
+        wasm_bindgen_futures::spawn_local(async move {
+            match files::upload_attachment(&household_id, entity_type, &entity_id, &file).await {
+                Ok(attachment) => attachments.update(|list| list.push(attachment)),
+                Err(e) => error.set(Some(e)),
+            }
+            uploading.set(false);
+        });
+    };
+
+    let download = move |attachment: Attachment| {
+        let (household_id, _) = ids.get_value();
+        wasm_bindgen_futures::spawn_local(async move {
+            match files::get_attachment_url(&household_id, &attachment).await {
+                Ok(url) => trigger_download(&url, &attachment.file_name),
+                Err(e) => error.set(Some(e)),
+            }
+        });
+    };
+
+    let remove = move |attachment_id: String| {
+        let (household_id, _) = ids.get_value();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().delete_attachment(&household_id, &attachment_id).await {
+                Ok(_) => attachments.update(|list| list.retain(|a| a.id.to_string() != attachment_id)),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    view! {
+        <div class="attachment-list">
+            {move || error.get().map(|e| view! {
+                <div class="alert alert-error" style="margin-bottom: 0.5rem;">{e}</div>
+            })}
+
+            <For
+                each=move || attachments.get()
+                key=|a| a.id
+                children=move |attachment| {
+                    let attachment_id = attachment.id.to_string();
+                    let label = format!("📎 {} ({})", attachment.file_name, format_size(attachment.size_bytes));
+                    view! {
+                        <div class="attachment-item">
+                            <button
+                                type="button"
+                                class="attachment-name"
+                                on:click=move |_| download(attachment.clone())
+                            >
+                                {label}
+                            </button>
+                            {can_modify.then(|| view! {
+                                <button
+                                    type="button"
+                                    class="btn btn-outline btn-sm"
+                                    title=i18n_stored.get_value().t("attachments.remove")
+                                    on:click=move |_| remove(attachment_id.clone())
+                                >
+                                    "✕"
+                                </button>
+                            })}
+                        </div>
+                    }
+                }
+            />
+
+            {can_modify.then(|| view! {
+                <label class="btn btn-outline btn-sm attachment-upload">
+                    {move || if uploading.get() {
+                        i18n_stored.get_value().t("attachments.uploading")
+                    } else {
+                        i18n_stored.get_value().t("attachments.add")
+                    }}
+                    <input
+                        type="file"
+                        style="display: none;"
+                        accept="image/*,application/pdf,text/plain"
+                        disabled=move || uploading.get()
+                        on:change=on_file_change
+                    />
+                </label>
+            })}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(2048), "2 KB");
+        assert_eq!(format_size(5 * 1024 * 1024), "5.0 MB");
+    }
+}
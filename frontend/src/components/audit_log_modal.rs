@@ -0,0 +1,129 @@
+use leptos::*;
+use shared::{AdminAuditEntryWithUsers, AuditAction};
+
+use crate::api::api_client;
+use crate::components::household_layout::HouseholdContext;
+use crate::components::modal::Modal;
+use crate::i18n::use_i18n;
+use crate::utils::format_datetime;
+
+const PAGE_SIZE: i64 = 20;
+
+/// Translation key for an audit action
+fn action_key(action: AuditAction) -> String {
+    format!("audit_log.action.{}", action.as_str())
+}
+
+/// Render a before/after pair, e.g. "member → admin"; a missing side is shown as "–"
+fn format_change(before: Option<&str>, after: Option<&str>) -> Option<String> {
+    if before.is_none() && after.is_none() {
+        return None;
+    }
+    Some(format!("{} → {}", before.unwrap_or("–"), after.unwrap_or("–")))
+}
+
+/// Owner-only modal listing the household's admin audit log
+#[component]
+pub fn AuditLogModal(household_id: String, #[prop(into)] on_close: Callback<()>) -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let entries = create_rw_signal(Vec::<AdminAuditEntryWithUsers>::new());
+    let total = create_rw_signal(0i64);
+    let loading = create_rw_signal(true);
+    let error = create_rw_signal(Option::<String>::None);
+
+    let timezone = use_context::<HouseholdContext>()
+        .and_then(|ctx| ctx.settings.get_untracked())
+        .map(|s| s.timezone)
+        .unwrap_or_else(|| "UTC".to_string());
+    let timezone = store_value(timezone);
+
+    let household_id = store_value(household_id);
+
+    let load_page = move || {
+        loading.set(true);
+        let offset = entries.with_untracked(|e| e.len() as i64);
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().get_audit_log(&household_id.get_value(), PAGE_SIZE, offset).await {
+                Ok(page) => {
+                    total.set(page.total);
+                    entries.update(|e| e.extend(page.entries));
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            loading.set(false);
+        });
+    };
+
+    load_page();
+
+    view! {
+        <Modal title=i18n_stored.get_value().t("audit_log.title") on_close=on_close>
+            {move || error.get().map(|e| view! { <div class="alert alert-error">{e}</div> })}
+
+            {move || {
+                let i18n = i18n_stored.get_value();
+                let list = entries.get();
+                if list.is_empty() && !loading.get() {
+                    view! { <p class="empty-state">{i18n.t("audit_log.empty")}</p> }.into_view()
+                } else {
+                    view! {
+                        <ul class="audit-log-list">
+                            {list.into_iter().map(|item| {
+                                let change = format_change(
+                                    item.entry.before_value.as_deref(),
+                                    item.entry.after_value.as_deref(),
+                                );
+                                view! {
+                                    <li class="audit-log-item">
+                                        <div class="audit-log-action">{i18n.t(&action_key(item.entry.action))}</div>
+                                        <div class="audit-log-users">
+                                            {item.actor.username}
+                                            {item.target_user.map(|u| format!(" → {}", u.username))}
+                                        </div>
+                                        {change.map(|c| view! { <div class="audit-log-change">{c}</div> })}
+                                        <div class="audit-log-time">
+                                            {format_datetime(item.entry.created_at, &timezone.get_value())}
+                                        </div>
+                                    </li>
+                                }
+                            }).collect_view()}
+                        </ul>
+                    }.into_view()
+                }
+            }}
+
+            <div class="modal-footer">
+                <Show when=move || loading.get() fallback=|| ()>
+                    <span class="loading">{move || i18n_stored.get_value().t("common.loading")}</span>
+                </Show>
+                <Show
+                    when=move || !loading.get() && (entries.with(|e| e.len()) as i64) < total.get()
+                    fallback=|| ()
+                >
+                    <button type="button" class="btn btn-outline" on:click=move |_| load_page()>
+                        {move || i18n_stored.get_value().t("audit_log.load_more")}
+                    </button>
+                </Show>
+            </div>
+        </Modal>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_change() {
+        assert_eq!(format_change(Some("member"), Some("admin")), Some("member → admin".to_string()));
+        assert_eq!(format_change(Some("admin"), None), Some("admin → –".to_string()));
+        assert_eq!(format_change(None, None), None);
+    }
+
+    #[test]
+    fn test_action_key() {
+        assert_eq!(action_key(AuditAction::RoleChanged), "audit_log.action.role_changed");
+    }
+}
@@ -1,7 +1,7 @@
 use leptos::*;
 use shared::TaskCategory;
 
-use crate::api::ApiClient;
+use crate::api::api_client;
 use crate::i18n::use_i18n;
 
 #[component]
@@ -27,13 +27,13 @@ pub fn CategoryModal(
     create_effect(move |_| {
         let hid = hid.clone();
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::list_categories(&hid).await {
+            match api_client().list_categories(&hid).await {
                 Ok(cats) => {
                     categories.set(cats);
                     loading.set(false);
                 }
                 Err(e) => {
-                    error.set(Some(e));
+                    error.set(Some(e.to_string()));
                     loading.set(false);
                 }
             }
@@ -60,7 +60,7 @@ pub fn CategoryModal(
                     color: Some(color),
                     sort_order: None,
                 };
-                ApiClient::update_category(&hid, &cat.id.to_string(), request).await
+                api_client().update_category(&hid, &cat.id.to_string(), request).await
             } else {
                 // Create new
                 let request = shared::CreateTaskCategoryRequest {
@@ -68,7 +68,7 @@ pub fn CategoryModal(
                     color: Some(color),
                     sort_order: None,
                 };
-                ApiClient::create_category(&hid, request).await
+                api_client().create_category(&hid, request).await
             };
 
             match result {
@@ -85,7 +85,7 @@ pub fn CategoryModal(
                     editing_category.set(None);
                 }
                 Err(e) => {
-                    error.set(Some(e));
+                    error.set(Some(e.to_string()));
                 }
             }
             saving.set(false);
@@ -96,7 +96,7 @@ pub fn CategoryModal(
     let on_delete = move |category_id: String| {
         let hid = hid_for_delete.get_value();
         wasm_bindgen_futures::spawn_local(async move {
-            if ApiClient::delete_category(&hid, &category_id).await.is_ok() {
+            if api_client().delete_category(&hid, &category_id).await.is_ok() {
                 categories.update(|cats| cats.retain(|c| c.id.to_string() != category_id));
             }
         });
@@ -1,9 +1,87 @@
 use leptos::*;
-use shared::ChatMessageWithUser;
+use shared::{Attachment, ChatMessageWithUser, ChatReactionSummary};
+use std::cell::RefCell;
+use std::collections::HashMap;
 use uuid::Uuid;
 
+use crate::api::{api_client, files};
+use crate::components::markdown::MarkdownView;
+use crate::i18n::use_i18n;
 use crate::utils::format_time;
 
+/// Reactions offered in the quick picker
+pub const QUICK_REACTIONS: [&str; 5] = ["👍", "❤️", "😂", "🎉", "✅"];
+
+/// Longest quoted excerpt of the message being replied to
+const QUOTE_MAX_CHARS: usize = 80;
+
+/// Shorten a message for the reply quote
+pub fn quote_excerpt(content: &str) -> String {
+    if content.chars().count() > QUOTE_MAX_CHARS {
+        let short: String = content.chars().take(QUOTE_MAX_CHARS).collect();
+        format!("{}…", short.trim_end())
+    } else {
+        content.to_string()
+    }
+}
+
+thread_local! {
+    /// Thumbnails already loaded; the chat list is rebuilt on every refresh
+    static THUMBNAILS: RefCell<HashMap<Uuid, String>> = RefCell::new(HashMap::new());
+}
+
+/// Photo shared in the chat: the thumbnail, replaced by the full image on click
+#[component]
+fn ChatPhoto(household_id: String, attachment: Attachment) -> impl IntoView {
+    let cached = THUMBNAILS.with(|cache| cache.borrow().get(&attachment.id).cloned());
+    let thumbnail = create_rw_signal(cached.clone());
+    let full = create_rw_signal(Option::<String>::None);
+    let expanded = create_rw_signal(false);
+    let target = store_value((household_id, attachment));
+
+    if cached.is_none() {
+        wasm_bindgen_futures::spawn_local(async move {
+            let (household_id, attachment) = target.get_value();
+            if let Ok(url) = files::get_attachment_thumbnail_url(&household_id, &attachment).await {
+                THUMBNAILS.with(|cache| cache.borrow_mut().insert(attachment.id, url.clone()));
+                thumbnail.set(Some(url));
+            }
+        });
+    }
+
+    let toggle = move |_| {
+        expanded.update(|e| *e = !*e);
+        if expanded.get_untracked() && full.get_untracked().is_none() {
+            wasm_bindgen_futures::spawn_local(async move {
+                let (household_id, attachment) = target.get_value();
+                if let Ok(url) = files::get_attachment_url(&household_id, &attachment).await {
+                    full.set(Some(url));
+                }
+            });
+        }
+    };
+
+    view! {
+        {move || {
+            let src = if expanded.get() { full.get().or_else(|| thumbnail.get()) } else { thumbnail.get() };
+            src.map(|url| view! {
+                <img
+                    src=url
+                    alt=target.with_value(|(_, attachment)| attachment.file_name.clone())
+                    class="chat-photo"
+                    class:chat-photo-expanded=move || expanded.get()
+                    on:click=toggle
+                />
+            })
+        }}
+    }
+}
+
+/// Whether the user is among those who reacted with this emoji
+fn has_reacted(reaction: &ChatReactionSummary, user_id: Uuid) -> bool {
+    reaction.user_ids.contains(&user_id)
+}
+
 /// A single chat message display
 #[component]
 pub fn ChatMessage(
@@ -11,9 +89,25 @@ pub fn ChatMessage(
     current_user_id: Uuid,
     on_edit: Callback<(Uuid, String)>,
     on_delete: Callback<Uuid>,
+    /// Reply to this message
+    on_reply: Callback<ChatMessageWithUser>,
+    /// Toggle a reaction: (message, emoji, add)
+    on_react: Callback<(Uuid, String, bool)>,
+    household_id: String,
+    /// The message this one replies to, when it is loaded
+    #[prop(default = None)] reply_to: Option<ChatMessageWithUser>,
     #[prop(default = "UTC".to_string())] timezone: String,
 ) -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+    let message_stored = store_value(message.clone());
+    let household_id = store_value(household_id);
     let (editing, set_editing) = create_signal(false);
+    let (show_picker, set_show_picker) = create_signal(false);
+    let replies = create_rw_signal(Option::<Vec<ChatMessageWithUser>>::None);
+    let reply_count = message.reply_count;
+    let reactions = message.reactions.clone();
+    let is_reply = message.message.reply_to_message_id.is_some();
     let original_content = store_value(message.message.content.clone());
     let (edit_content, set_edit_content) = create_signal(message.message.content.clone());
 
@@ -22,6 +116,9 @@ pub fn ChatMessage(
     let message_id = message.message.id;
     let content_display = message.message.content.clone();
     let username = message.user.username.clone();
+    let photos: Vec<Attachment> = message.attachments.iter().filter(|a| a.is_image()).cloned().collect();
+
+    let timezone_stored = store_value(timezone.clone());
 
     let formatted_time = {
         let created = message.message.created_at;
@@ -57,6 +154,60 @@ pub fn ChatMessage(
         set_editing.set(true);
     };
 
+    let toggle_replies = move |_: web_sys::MouseEvent| {
+        if replies.get_untracked().is_some() {
+            replies.set(None);
+            return;
+        }
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Ok(list) =
+                api_client().list_chat_replies(&household_id.get_value(), &message_id.to_string()).await
+            {
+                replies.set(Some(list));
+            }
+        });
+    };
+
+    let quote = is_reply.then(|| {
+        let text = match reply_to {
+            Some(parent) if !parent.message.is_deleted => {
+                format!("{}: {}", parent.user.username, quote_excerpt(&parent.message.content))
+            }
+            _ => i18n_stored.get_value().t("chat.original_unavailable"),
+        };
+        view! { <div class="chat-reply-quote">{text}</div> }
+    });
+
+    let reaction_chips = (!reactions.is_empty()).then(|| {
+        view! {
+            <div class="chat-reactions">
+                {reactions.into_iter().map(|reaction| {
+                    let reacted = has_reacted(&reaction, current_user_id);
+                    let emoji = reaction.emoji.clone();
+                    let class = if reacted { "chat-reaction chat-reaction-own" } else { "chat-reaction" };
+                    view! {
+                        <button
+                            class=class
+                            on:click=move |_| on_react.call((message_id, emoji.clone(), !reacted))
+                        >
+                            {reaction.emoji.clone()} " " {reaction.user_ids.len()}
+                        </button>
+                    }
+                }).collect_view()}
+            </div>
+        }
+    });
+
+    let photo_view = (!is_deleted && !photos.is_empty()).then(|| {
+        view! {
+            <div class="chat-photos">
+                {photos.into_iter().map(|attachment| view! {
+                    <ChatPhoto household_id=household_id.get_value() attachment=attachment />
+                }).collect_view()}
+            </div>
+        }
+    });
+
     view! {
         <div class=move || {
             if is_own_message {
@@ -70,6 +221,8 @@ pub fn ChatMessage(
                 <span class="chat-message-time">{formatted_time}</span>
             </div>
 
+            {quote}
+
             {move || {
                 if editing.get() {
                     view! {
@@ -93,37 +246,140 @@ pub fn ChatMessage(
                             {if is_deleted {
                                 view! { <em class="chat-message-deleted">"[Message deleted]"</em> }.into_view()
                             } else {
-                                view! { <span>{content}</span> }.into_view()
+                                view! { <MarkdownView content=content /> }.into_view()
                             }}
                         </div>
                     }.into_view()
                 }
             }}
 
+            {photo_view}
+
+            {reaction_chips}
+
             {move || {
-                if is_own_message && !is_deleted && !editing.get() {
+                if !is_deleted && !editing.get() {
                     view! {
                         <div class="chat-message-actions">
                             <button
                                 class="btn-icon"
-                                title="Edit"
-                                on:click=handle_start_edit
+                                on:click=move |_| on_reply.call(message_stored.get_value())
                             >
-                                "Edit"
+                                {i18n_stored.get_value().t("chat.reply")}
                             </button>
                             <button
-                                class="btn-icon btn-danger-text"
-                                title="Delete"
-                                on:click=handle_delete
+                                class="btn-icon"
+                                title=i18n_stored.get_value().t("chat.react")
+                                on:click=move |_| set_show_picker.update(|v| *v = !*v)
                             >
-                                "Delete"
+                                "🙂"
                             </button>
+                            {is_own_message.then(|| view! {
+                                <button
+                                    class="btn-icon"
+                                    title="Edit"
+                                    on:click=handle_start_edit
+                                >
+                                    "Edit"
+                                </button>
+                                <button
+                                    class="btn-icon btn-danger-text"
+                                    title="Delete"
+                                    on:click=handle_delete
+                                >
+                                    "Delete"
+                                </button>
+                            })}
                         </div>
                     }.into_view()
                 } else {
                     view! {}.into_view()
                 }
             }}
+
+            {move || show_picker.get().then(|| view! {
+                <div class="chat-reaction-picker">
+                    {QUICK_REACTIONS.iter().map(|emoji| {
+                        let emoji = emoji.to_string();
+                        let label = emoji.clone();
+                        view! {
+                            <button
+                                class="chat-reaction-option"
+                                on:click=move |_| {
+                                    set_show_picker.set(false);
+                                    let already = message_stored.with_value(|m| {
+                                        m.reactions.iter().any(|r| r.emoji == emoji && has_reacted(r, current_user_id))
+                                    });
+                                    on_react.call((message_id, emoji.clone(), !already));
+                                }
+                            >
+                                {label}
+                            </button>
+                        }
+                    }).collect_view()}
+                </div>
+            })}
+
+            {(reply_count > 0).then(|| view! {
+                <button class="chat-replies-toggle" on:click=toggle_replies>
+                    {move || if replies.get().is_some() {
+                        i18n_stored.get_value().t("chat.hide_replies")
+                    } else {
+                        i18n_stored.get_value().t_plural("chat.show_replies", reply_count, &[])
+                    }}
+                </button>
+            })}
+
+            {move || replies.get().map(|list| {
+                let tz = timezone_stored.get_value();
+                view! {
+                    <div class="chat-thread">
+                        {list.into_iter().map(|reply| {
+                            let time = format_time(reply.message.created_at, &tz);
+                            view! {
+                                <div class="chat-thread-reply">
+                                    <span class="chat-message-author">{reply.user.username}</span>
+                                    " "
+                                    <span class="chat-message-time">{time}</span>
+                                    <div class="chat-message-content"><MarkdownView content=reply.message.content /></div>
+                                </div>
+                            }
+                        }).collect_view()}
+                    </div>
+                }
+            })}
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn test_quote_excerpt_short() {
+        assert_eq!(quote_excerpt("buy milk"), "buy milk");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_quote_excerpt_truncates() {
+        let long = "a".repeat(100);
+        let quote = quote_excerpt(&long);
+        assert_eq!(quote.chars().count(), QUOTE_MAX_CHARS + 1);
+        assert!(quote.ends_with('…'));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_has_reacted() {
+        let user = Uuid::new_v4();
+        let reaction = ChatReactionSummary {
+            emoji: "👍".to_string(),
+            user_ids: vec![user],
+        };
+        assert!(has_reacted(&reaction, user));
+        assert!(!has_reacted(&reaction, Uuid::new_v4()));
+    }
+}
@@ -0,0 +1,184 @@
+use leptos::*;
+use shared::{is_valid_child_pin, CreateChildAccountRequest, MemberWithUser, ResetChildPinRequest};
+
+use crate::api::api_client;
+use crate::components::modal::Modal;
+use crate::i18n::use_i18n;
+
+/// Owner-only modal that creates a child account (username and PIN, no email)
+#[component]
+pub fn ChildAccountModal(
+    household_id: String,
+    #[prop(into)] on_close: Callback<()>,
+    #[prop(into)] on_created: Callback<MemberWithUser>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let username = create_rw_signal(String::new());
+    let pin = create_rw_signal(String::new());
+    let error = create_rw_signal(Option::<String>::None);
+    let saving = create_rw_signal(false);
+    let household_id = store_value(household_id);
+
+    let on_submit = move |ev: web_sys::SubmitEvent| {
+        ev.prevent_default();
+
+        if !is_valid_child_pin(&pin.get()) {
+            error.set(Some(i18n_stored.get_value().t("child_accounts.pin_invalid")));
+            return;
+        }
+
+        saving.set(true);
+        error.set(None);
+
+        let request = CreateChildAccountRequest {
+            username: username.get().trim().to_string(),
+            pin: pin.get(),
+        };
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().create_child_account(&household_id.get_value(), request).await {
+                Ok(member) => {
+                    on_created.call(member);
+                    on_close.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            saving.set(false);
+        });
+    };
+
+    view! {
+        <Modal title=i18n_stored.get_value().t("child_accounts.create") on_close=move |_| on_close.call(())>
+            {move || error.get().map(|e| view! {
+                <div class="alert alert-error" style="margin-bottom: 1rem;">{e}</div>
+            })}
+
+            <form on:submit=on_submit>
+                <p class="form-hint" style="margin-bottom: 1rem;">{i18n_stored.get_value().t("child_accounts.hint")}</p>
+
+                <div class="form-group">
+                    <label class="form-label" for="child-username">{i18n_stored.get_value().t("child_accounts.username")}</label>
+                    <input
+                        type="text"
+                        id="child-username"
+                        class="form-input"
+                        maxlength="50"
+                        prop:value=move || username.get()
+                        on:input=move |ev| username.set(event_target_value(&ev))
+                        required
+                    />
+                </div>
+
+                <div class="form-group">
+                    <label class="form-label" for="child-pin">{i18n_stored.get_value().t("child_accounts.pin")}</label>
+                    <input
+                        type="password"
+                        id="child-pin"
+                        class="form-input"
+                        inputmode="numeric"
+                        pattern="[0-9]{4,8}"
+                        maxlength="8"
+                        prop:value=move || pin.get()
+                        on:input=move |ev| pin.set(event_target_value(&ev))
+                        required
+                    />
+                    <small class="form-hint">{i18n_stored.get_value().t("child_accounts.pin_hint")}</small>
+                </div>
+
+                <div class="modal-footer">
+                    <button type="button" class="btn btn-outline" on:click=move |_| on_close.call(())>
+                        {i18n_stored.get_value().t("common.cancel")}
+                    </button>
+                    <button type="submit" class="btn btn-primary" disabled=move || saving.get()>
+                        {move || if saving.get() {
+                            i18n_stored.get_value().t("common.saving")
+                        } else {
+                            i18n_stored.get_value().t("child_accounts.create")
+                        }}
+                    </button>
+                </div>
+            </form>
+        </Modal>
+    }
+}
+
+/// Owner-only modal that sets a new PIN for a child account
+#[component]
+pub fn ChildPinModal(
+    household_id: String,
+    user_id: String,
+    username: String,
+    #[prop(into)] on_close: Callback<()>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let pin = create_rw_signal(String::new());
+    let error = create_rw_signal(Option::<String>::None);
+    let saving = create_rw_signal(false);
+    let ids = store_value((household_id, user_id));
+
+    let on_submit = move |ev: web_sys::SubmitEvent| {
+        ev.prevent_default();
+
+        if !is_valid_child_pin(&pin.get()) {
+            error.set(Some(i18n_stored.get_value().t("child_accounts.pin_invalid")));
+            return;
+        }
+
+        saving.set(true);
+        error.set(None);
+
+        let request = ResetChildPinRequest { pin: pin.get() };
+        let (household_id, user_id) = ids.get_value();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().reset_child_pin(&household_id, &user_id, request).await {
+                Ok(_) => on_close.call(()),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            saving.set(false);
+        });
+    };
+
+    let title = i18n_stored.get_value().t_with("child_accounts.reset_pin_for", &[("username", &username)]);
+
+    view! {
+        <Modal title=title on_close=move |_| on_close.call(())>
+            {move || error.get().map(|e| view! {
+                <div class="alert alert-error" style="margin-bottom: 1rem;">{e}</div>
+            })}
+
+            <form on:submit=on_submit>
+                <div class="form-group">
+                    <label class="form-label" for="child-new-pin">{i18n_stored.get_value().t("child_accounts.new_pin")}</label>
+                    <input
+                        type="password"
+                        id="child-new-pin"
+                        class="form-input"
+                        inputmode="numeric"
+                        pattern="[0-9]{4,8}"
+                        maxlength="8"
+                        prop:value=move || pin.get()
+                        on:input=move |ev| pin.set(event_target_value(&ev))
+                        required
+                    />
+                    <small class="form-hint">{i18n_stored.get_value().t("child_accounts.pin_hint")}</small>
+                </div>
+
+                <div class="modal-footer">
+                    <button type="button" class="btn btn-outline" on:click=move |_| on_close.call(())>
+                        {i18n_stored.get_value().t("common.cancel")}
+                    </button>
+                    <button type="submit" class="btn btn-primary" disabled=move || saving.get()>
+                        {move || if saving.get() {
+                            i18n_stored.get_value().t("common.saving")
+                        } else {
+                            i18n_stored.get_value().t("child_accounts.reset_pin")
+                        }}
+                    </button>
+                </div>
+            </form>
+        </Modal>
+    }
+}
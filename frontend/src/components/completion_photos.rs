@@ -0,0 +1,114 @@
+use leptos::*;
+use shared::CompletionAttachment;
+use wasm_bindgen::JsCast;
+
+use crate::api::files;
+use crate::components::modal::Modal;
+use crate::i18n::use_i18n;
+
+/// Thumbnail of a completion photo; click to enlarge
+#[component]
+pub fn CompletionPhoto(household_id: String, attachment: CompletionAttachment) -> impl IntoView {
+    let src = create_rw_signal(Option::<String>::None);
+    let expanded = create_rw_signal(false);
+
+    wasm_bindgen_futures::spawn_local(async move {
+        if let Ok(url) = files::get_completion_attachment_url(&household_id, &attachment).await {
+            src.set(Some(url));
+        }
+    });
+
+    view! {
+        {move || src.get().map(|url| view! {
+            <img
+                src=url
+                alt=""
+                class="completion-photo"
+                class:completion-photo-expanded=move || expanded.get()
+                on:click=move |_| expanded.update(|e| *e = !*e)
+            />
+        })}
+    }
+}
+
+/// Modal offered after completing a task that needs review, to attach photo proof
+#[component]
+pub fn AttachPhotoModal(
+    household_id: String,
+    completion_id: String,
+    #[prop(into)] on_close: Callback<()>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let file = create_rw_signal(Option::<web_sys::File>::None);
+    let error = create_rw_signal(Option::<String>::None);
+    let saving = create_rw_signal(false);
+    let ids = store_value((household_id, completion_id));
+
+    let on_file_change = move |ev: web_sys::Event| {
+        let selected = ev
+            .target()
+            .and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok())
+            .and_then(|input| input.files())
+            .and_then(|files| files.get(0));
+        file.set(selected);
+    };
+
+    let on_submit = move |ev: web_sys::SubmitEvent| {
+        ev.prevent_default();
+
+        let Some(selected) = file.get() else {
+            error.set(Some(i18n_stored.get_value().t("completion_photos.no_file")));
+            return;
+        };
+
+        saving.set(true);
+        error.set(None);
+
+        let (household_id, completion_id) = ids.get_value();
+        wasm_bindgen_futures::spawn_local(async move {
+            match files::upload_completion_attachment(&household_id, &completion_id, &selected).await {
+                Ok(_) => on_close.call(()),
+                Err(e) => error.set(Some(e)),
+            }
+            saving.set(false);
+        });
+    };
+
+    view! {
+        <Modal title=i18n_stored.get_value().t("completion_photos.title") on_close=move |_| on_close.call(())>
+            {move || error.get().map(|e| view! {
+                <div class="alert alert-error" style="margin-bottom: 1rem;">{e}</div>
+            })}
+
+            <form on:submit=on_submit>
+                <p class="form-hint" style="margin-bottom: 1rem;">{i18n_stored.get_value().t("completion_photos.hint")}</p>
+
+                <div class="form-group">
+                    <input
+                        type="file"
+                        id="completion-photo"
+                        class="form-input"
+                        accept="image/jpeg,image/png,image/gif,image/webp"
+                        capture="environment"
+                        on:change=on_file_change
+                    />
+                </div>
+
+                <div class="modal-footer">
+                    <button type="button" class="btn btn-outline" on:click=move |_| on_close.call(())>
+                        {i18n_stored.get_value().t("completion_photos.skip")}
+                    </button>
+                    <button type="submit" class="btn btn-primary" disabled=move || saving.get() || file.get().is_none()>
+                        {move || if saving.get() {
+                            i18n_stored.get_value().t("common.saving")
+                        } else {
+                            i18n_stored.get_value().t("completion_photos.upload")
+                        }}
+                    </button>
+                </div>
+            </form>
+        </Modal>
+    }
+}
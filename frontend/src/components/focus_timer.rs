@@ -0,0 +1,83 @@
+use leptos::*;
+use shared::FocusSession;
+
+use crate::api::api_client;
+use crate::i18n::use_i18n;
+
+/// "MM:SS" left of a focus session, or "+MM:SS" once it runs over
+pub fn format_countdown(remaining_seconds: i64) -> String {
+    let sign = if remaining_seconds < 0 { "+" } else { "" };
+    let seconds = remaining_seconds.abs();
+    format!("{}{:02}:{:02}", sign, seconds / 60, seconds % 60)
+}
+
+/// Dashboard card with the running focus session, ticking every second.
+/// `session` is owned by the page, which starts sessions from the task menu.
+#[component]
+pub fn FocusTimer(session: RwSignal<Option<FocusSession>>) -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let now = create_rw_signal(chrono::Utc::now());
+    let error = create_rw_signal(Option::<String>::None);
+    let busy = create_rw_signal(false);
+
+    if let Ok(handle) = set_interval_with_handle(move || now.set(chrono::Utc::now()), std::time::Duration::from_secs(1)) {
+        on_cleanup(move || handle.clear());
+    }
+
+    // Stop counts the focused time toward the task, cancel throws it away
+    let end_session = move |complete: bool| {
+        let Some(current) = session.get_untracked() else { return };
+        busy.set(true);
+        error.set(None);
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().end_focus_session(&current.household_id.to_string(), complete).await {
+                Ok(_) => session.set(None),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            busy.set(false);
+        });
+    };
+
+    view! {
+        {move || session.get().map(|current| {
+            let i18n = i18n_stored.get_value();
+            let task_title = current.task_title.clone();
+            let current = store_value(current);
+            let remaining = move || current.with_value(|c| c.remaining_seconds(now.get()));
+            view! {
+                <div class="card focus-timer">
+                    <div class="focus-timer-label">{i18n.t("focus_timer.title")}</div>
+                    <div class="focus-timer-task">{task_title}</div>
+                    <div class="focus-timer-time" class:overtime=move || remaining() < 0>
+                        {move || format_countdown(remaining())}
+                    </div>
+                    {move || error.get().map(|e| view! { <div class="alert alert-error">{e}</div> })}
+                    <div class="focus-timer-actions">
+                        <button class="btn btn-primary" disabled=move || busy.get() on:click=move |_| end_session(true)>
+                            {i18n.t("focus_timer.stop")}
+                        </button>
+                        <button class="btn btn-outline" disabled=move || busy.get() on:click=move |_| end_session(false)>
+                            {i18n.t("focus_timer.cancel")}
+                        </button>
+                    </div>
+                </div>
+            }
+        })}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(1500), "25:00");
+        assert_eq!(format_countdown(59), "00:59");
+        assert_eq!(format_countdown(0), "00:00");
+        assert_eq!(format_countdown(-65), "+01:05");
+    }
+}
@@ -2,8 +2,10 @@ use leptos::*;
 use leptos_router::*;
 use shared::HouseholdSettings;
 
-use crate::api::ApiClient;
+use crate::api::api_client;
 use crate::components::household_tabs::{HouseholdTab, HouseholdTabs};
+use crate::components::theme::use_theme;
+use crate::i18n::{use_i18n, PunishmentTerms};
 
 /// Context for sharing household data across child routes.
 /// This avoids duplicate API calls for settings across pages.
@@ -18,6 +20,7 @@ pub struct HouseholdContext {
 /// This prevents tab bar re-renders when navigating between tabs.
 #[component]
 pub fn HouseholdLayout() -> impl IntoView {
+    let theme = use_theme();
     let params = use_params_map();
     let location = use_location();
 
@@ -29,6 +32,15 @@ pub fn HouseholdLayout() -> impl IntoView {
     // Settings signal - loaded once, shared with child routes via context
     let settings = create_rw_signal(Option::<HouseholdSettings>::None);
 
+    // Household wording for punishments, back to the default when leaving the household
+    let i18n = use_i18n();
+    let i18n_for_terms = i18n.clone();
+    create_effect(move |_| {
+        let terms = settings.with(|s| s.as_ref().map(PunishmentTerms::from_settings).unwrap_or_default());
+        i18n_for_terms.set_punishment_terms(terms);
+    });
+    on_cleanup(move || i18n.set_punishment_terms(PunishmentTerms::default()));
+
     // Load settings when household_id changes
     create_effect(move |_| {
         let id = household_id.get();
@@ -37,34 +49,81 @@ pub fn HouseholdLayout() -> impl IntoView {
         }
 
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(s) = ApiClient::get_household_settings(&id).await {
+            if let Ok(s) = api_client().get_household_settings(&id).await {
                 // Apply dark mode
-                apply_dark_mode(s.dark_mode);
+                theme.set_household_dark_mode(s.dark_mode);
                 settings.set(Some(s));
             }
         });
     });
 
+    // Unread chat messages, refreshed on every navigation within the household
+    let chat_unread = create_rw_signal(0i64);
+    create_effect(move |_| {
+        let id = household_id.get();
+        let on_chat = location.pathname.get().ends_with("/chat");
+        if id.is_empty() {
+            return;
+        }
+        if on_chat {
+            chat_unread.set(0);
+            return;
+        }
+
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Ok(counts) = api_client().get_chat_unread_counts().await {
+                let count = counts
+                    .into_iter()
+                    .find(|c| c.household_id.to_string() == id)
+                    .map(|c| c.unread_count)
+                    .unwrap_or(0);
+                chat_unread.set(count);
+            }
+        });
+    });
+
     // Determine active tab from current URL path
     let active_tab = Signal::derive(move || {
         let path = location.pathname.get();
 
         if path.ends_with("/tasks") {
             HouseholdTab::Tasks
+        } else if path.ends_with("/calendar") {
+            HouseholdTab::Calendar
         } else if path.ends_with("/notes") {
             HouseholdTab::Notes
         } else if path.ends_with("/journal") {
             HouseholdTab::Journal
+        } else if path.ends_with("/expenses") {
+            HouseholdTab::Expenses
+        } else if path.ends_with("/meals") {
+            HouseholdTab::Meals
+        } else if path.ends_with("/shopping-list") {
+            HouseholdTab::ShoppingList
+        } else if path.ends_with("/inventory") {
+            HouseholdTab::Inventory
+        } else if path.ends_with("/appliances") {
+            HouseholdTab::Appliances
+        } else if path.ends_with("/plants") {
+            HouseholdTab::Plants
+        } else if path.ends_with("/pets") {
+            HouseholdTab::Pets
         } else if path.ends_with("/rewards") {
             HouseholdTab::Rewards
         } else if path.ends_with("/punishments") {
             HouseholdTab::Punishments
         } else if path.ends_with("/chat") {
             HouseholdTab::Chat
+        } else if path.ends_with("/challenges") {
+            HouseholdTab::Challenges
+        } else if path.ends_with("/rules") {
+            HouseholdTab::Rules
         } else if path.ends_with("/activity") {
             HouseholdTab::Activity
         } else if path.ends_with("/statistics") {
             HouseholdTab::Statistics
+        } else if path.ends_with("/trash") {
+            HouseholdTab::Trash
         } else if path.ends_with("/settings") {
             HouseholdTab::Settings
         } else {
@@ -86,6 +145,7 @@ pub fn HouseholdLayout() -> impl IntoView {
             household_id=household_id
             active_tab=active_tab
             settings=settings
+            chat_unread=chat_unread
         />
 
         // Child route content renders here
@@ -93,21 +153,6 @@ pub fn HouseholdLayout() -> impl IntoView {
     }
 }
 
-/// Apply dark mode class to document body
-fn apply_dark_mode(enabled: bool) {
-    if let Some(window) = web_sys::window() {
-        if let Some(document) = window.document() {
-            if let Some(body) = document.body() {
-                if enabled {
-                    let _ = body.class_list().add_1("dark-mode");
-                } else {
-                    let _ = body.class_list().remove_1("dark-mode");
-                }
-            }
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use wasm_bindgen_test::*;
@@ -1,5 +1,5 @@
 use leptos::*;
-use shared::HouseholdSettings;
+use shared::{HouseholdFeature, HouseholdSettings};
 
 use super::solo_mode_banner::SoloModeBanner;
 use crate::i18n::use_i18n;
@@ -8,13 +8,24 @@ use crate::i18n::use_i18n;
 pub enum HouseholdTab {
     Overview,
     Tasks,
+    Calendar,
     Notes,
     Journal,
+    Expenses,
+    Meals,
+    ShoppingList,
+    Inventory,
+    Appliances,
+    Plants,
+    Pets,
     Rewards,
     Punishments,
     Chat,
+    Challenges,
+    Rules,
     Activity,
     Statistics,
+    Trash,
     Settings,
 }
 
@@ -23,13 +34,24 @@ impl HouseholdTab {
         match self {
             HouseholdTab::Overview => "tabs.overview",
             HouseholdTab::Tasks => "tabs.tasks",
+            HouseholdTab::Calendar => "tabs.calendar",
             HouseholdTab::Notes => "tabs.notes",
             HouseholdTab::Journal => "tabs.journal",
+            HouseholdTab::Expenses => "tabs.expenses",
+            HouseholdTab::Meals => "tabs.meals",
+            HouseholdTab::ShoppingList => "tabs.shopping_list",
+            HouseholdTab::Inventory => "tabs.inventory",
+            HouseholdTab::Appliances => "tabs.appliances",
+            HouseholdTab::Plants => "tabs.plants",
+            HouseholdTab::Pets => "tabs.pets",
             HouseholdTab::Rewards => "tabs.rewards",
             HouseholdTab::Punishments => "tabs.punishments",
             HouseholdTab::Chat => "tabs.chat",
+            HouseholdTab::Challenges => "tabs.challenges",
+            HouseholdTab::Rules => "tabs.rules",
             HouseholdTab::Activity => "tabs.activity",
             HouseholdTab::Statistics => "tabs.statistics",
+            HouseholdTab::Trash => "tabs.trash",
             HouseholdTab::Settings => "tabs.settings",
         }
     }
@@ -38,49 +60,102 @@ impl HouseholdTab {
         match self {
             HouseholdTab::Overview => format!("/households/{}", household_id),
             HouseholdTab::Tasks => format!("/households/{}/tasks", household_id),
+            HouseholdTab::Calendar => format!("/households/{}/calendar", household_id),
             HouseholdTab::Notes => format!("/households/{}/notes", household_id),
             HouseholdTab::Journal => format!("/households/{}/journal", household_id),
+            HouseholdTab::Expenses => format!("/households/{}/expenses", household_id),
+            HouseholdTab::Meals => format!("/households/{}/meals", household_id),
+            HouseholdTab::ShoppingList => format!("/households/{}/shopping-list", household_id),
+            HouseholdTab::Inventory => format!("/households/{}/inventory", household_id),
+            HouseholdTab::Appliances => format!("/households/{}/appliances", household_id),
+            HouseholdTab::Plants => format!("/households/{}/plants", household_id),
+            HouseholdTab::Pets => format!("/households/{}/pets", household_id),
             HouseholdTab::Rewards => format!("/households/{}/rewards", household_id),
             HouseholdTab::Punishments => format!("/households/{}/punishments", household_id),
             HouseholdTab::Chat => format!("/households/{}/chat", household_id),
+            HouseholdTab::Challenges => format!("/households/{}/challenges", household_id),
+            HouseholdTab::Rules => format!("/households/{}/rules", household_id),
             HouseholdTab::Activity => format!("/households/{}/activity", household_id),
             HouseholdTab::Statistics => format!("/households/{}/statistics", household_id),
+            HouseholdTab::Trash => format!("/households/{}/trash", household_id),
             HouseholdTab::Settings => format!("/households/{}/settings", household_id),
         }
     }
+
+    /// The module behind this tab, for tabs a household can switch off
+    fn feature(&self) -> Option<HouseholdFeature> {
+        match self {
+            HouseholdTab::Rewards => Some(HouseholdFeature::Rewards),
+            HouseholdTab::Punishments => Some(HouseholdFeature::Punishments),
+            HouseholdTab::Chat => Some(HouseholdFeature::Chat),
+            HouseholdTab::Notes => Some(HouseholdFeature::Notes),
+            HouseholdTab::Journal => Some(HouseholdFeature::Journal),
+            HouseholdTab::Expenses => Some(HouseholdFeature::Expenses),
+            HouseholdTab::Meals => Some(HouseholdFeature::Meals),
+            HouseholdTab::ShoppingList => Some(HouseholdFeature::ShoppingList),
+            HouseholdTab::Inventory => Some(HouseholdFeature::Inventory),
+            HouseholdTab::Appliances => Some(HouseholdFeature::Appliances),
+            HouseholdTab::Plants => Some(HouseholdFeature::Plants),
+            HouseholdTab::Pets => Some(HouseholdFeature::Pets),
+            HouseholdTab::Challenges => Some(HouseholdFeature::Challenges),
+            HouseholdTab::Statistics => Some(HouseholdFeature::Statistics),
+            _ => None,
+        }
+    }
 }
 
 /// Build the list of tabs based on household settings.
-/// Conditional tabs (Rewards, Punishments, Chat) only appear when enabled in settings.
+/// Modules the household switched off are left out; modules that start off
+/// (Rewards, Punishments, Chat) only appear once the settings are loaded.
 fn build_tabs(settings: &Option<HouseholdSettings>) -> Vec<HouseholdTab> {
     let mut tabs = vec![
         HouseholdTab::Overview,
         HouseholdTab::Tasks,
+        HouseholdTab::Calendar,
         HouseholdTab::Notes,
         HouseholdTab::Journal,
+        HouseholdTab::Expenses,
+        HouseholdTab::Meals,
+        HouseholdTab::ShoppingList,
+        HouseholdTab::Inventory,
+        HouseholdTab::Appliances,
+        HouseholdTab::Plants,
+        HouseholdTab::Pets,
     ];
-    if let Some(ref s) = settings {
-        if s.rewards_enabled {
-            tabs.push(HouseholdTab::Rewards);
-        }
-        if s.punishments_enabled {
-            tabs.push(HouseholdTab::Punishments);
-        }
-        if s.chat_enabled {
-            tabs.push(HouseholdTab::Chat);
-        }
+    if settings.is_some() {
+        tabs.extend([HouseholdTab::Rewards, HouseholdTab::Punishments, HouseholdTab::Chat]);
+    }
+    tabs.push(HouseholdTab::Challenges);
+    if settings.as_ref().is_some_and(|s| {
+        s.is_feature_enabled(HouseholdFeature::Rewards) || s.is_feature_enabled(HouseholdFeature::Punishments)
+    }) {
+        tabs.push(HouseholdTab::Rules);
     }
     tabs.push(HouseholdTab::Activity);
     tabs.push(HouseholdTab::Statistics);
+    tabs.push(HouseholdTab::Trash);
     tabs.push(HouseholdTab::Settings);
+    if let Some(ref s) = settings {
+        tabs.retain(|tab| tab.feature().is_none_or(|feature| s.is_feature_enabled(feature)));
+    }
     tabs
 }
 
+/// Text for the unread badge, capped so the tab keeps its width
+fn unread_badge(count: i64) -> Option<String> {
+    match count {
+        c if c <= 0 => None,
+        c if c > 99 => Some("99+".to_string()),
+        c => Some(c.to_string()),
+    }
+}
+
 #[component]
 pub fn HouseholdTabs(
     household_id: Signal<String>,
     active_tab: Signal<HouseholdTab>,
     settings: RwSignal<Option<HouseholdSettings>>,
+    chat_unread: RwSignal<i64>,
 ) -> impl IntoView {
     let i18n = use_i18n();
     let i18n_stored = store_value(i18n);
@@ -137,9 +212,14 @@ pub fn HouseholdTabs(
                     let is_active = tab == current_active;
                     let class = if is_active { "tab-link active" } else { "tab-link" };
                     let label = i18n_stored.get_value().t(tab.translation_key());
+                    let badge = (tab == HouseholdTab::Chat)
+                        .then(|| unread_badge(chat_unread.get()))
+                        .flatten()
+                        .map(|text| view! { <span class="tab-badge">{text}</span> });
                     view! {
                         <a href=href class=class>
                             {label}
+                            {badge}
                         </a>
                     }
                 }).collect_view()
@@ -167,6 +247,12 @@ mod tests {
         assert_eq!(path, "/households/abc-123/tasks");
     }
 
+    #[wasm_bindgen_test]
+    fn test_tab_path_calendar() {
+        let path = HouseholdTab::Calendar.path("abc-123");
+        assert_eq!(path, "/households/abc-123/calendar");
+    }
+
     #[wasm_bindgen_test]
     fn test_tab_path_rewards() {
         let path = HouseholdTab::Rewards.path("abc-123");
@@ -185,6 +271,77 @@ mod tests {
         assert_eq!(path, "/households/abc-123/activity");
     }
 
+    #[wasm_bindgen_test]
+    fn test_tab_path_expenses() {
+        let path = HouseholdTab::Expenses.path("abc-123");
+        assert_eq!(path, "/households/abc-123/expenses");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_tab_path_meals() {
+        let path = HouseholdTab::Meals.path("abc-123");
+        assert_eq!(path, "/households/abc-123/meals");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_tab_path_shopping_list() {
+        let path = HouseholdTab::ShoppingList.path("abc-123");
+        assert_eq!(path, "/households/abc-123/shopping-list");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_tab_path_inventory() {
+        let path = HouseholdTab::Inventory.path("abc-123");
+        assert_eq!(path, "/households/abc-123/inventory");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_tab_path_appliances() {
+        let path = HouseholdTab::Appliances.path("abc-123");
+        assert_eq!(path, "/households/abc-123/appliances");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_tab_path_plants() {
+        let path = HouseholdTab::Plants.path("abc-123");
+        assert_eq!(path, "/households/abc-123/plants");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_tab_path_pets() {
+        let path = HouseholdTab::Pets.path("abc-123");
+        assert_eq!(path, "/households/abc-123/pets");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_tab_path_challenges() {
+        let path = HouseholdTab::Challenges.path("abc-123");
+        assert_eq!(path, "/households/abc-123/challenges");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_build_tabs_hides_disabled_modules() {
+        let settings = HouseholdSettings {
+            disabled_features: vec![HouseholdFeature::Journal, HouseholdFeature::Statistics],
+            ..Default::default()
+        };
+        let tabs = build_tabs(&Some(settings));
+        assert!(!tabs.contains(&HouseholdTab::Journal));
+        assert!(!tabs.contains(&HouseholdTab::Statistics));
+        assert!(tabs.contains(&HouseholdTab::Notes));
+        assert!(tabs.contains(&HouseholdTab::Settings));
+        // Rewards, punishments and chat are no longer switched off here
+        assert!(tabs.contains(&HouseholdTab::Rewards));
+        assert!(tabs.contains(&HouseholdTab::Chat));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_unread_badge() {
+        assert_eq!(unread_badge(0), None);
+        assert_eq!(unread_badge(3), Some("3".to_string()));
+        assert_eq!(unread_badge(150), Some("99+".to_string()));
+    }
+
     #[wasm_bindgen_test]
     fn test_tab_equality() {
         assert_eq!(HouseholdTab::Overview, HouseholdTab::Overview);
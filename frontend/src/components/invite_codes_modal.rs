@@ -0,0 +1,237 @@
+use leptos::*;
+use shared::{CreateInviteCodeRequest, InviteCode, Role};
+
+use crate::api::{api_client, files};
+use crate::components::household_layout::HouseholdContext;
+use crate::components::modal::Modal;
+use crate::i18n::use_i18n;
+use crate::utils::format_datetime;
+
+/// Link that opens the join page for a code
+fn invite_link(origin: &str, code: &str) -> String {
+    format!("{}/join/{}", origin.trim_end_matches('/'), code)
+}
+
+/// Split a code into two groups of four for reading aloud, e.g. `ABCD-2345`
+fn display_code(code: &str) -> String {
+    if code.len() == 8 {
+        format!("{}-{}", &code[..4], &code[4..])
+    } else {
+        code.to_string()
+    }
+}
+
+/// Modal for members with "manage members" permission to create, share and revoke invite codes
+#[component]
+pub fn InviteCodesModal(
+    household_id: String,
+    /// Only owners may hand out admin codes
+    can_invite_admin: bool,
+    #[prop(into)] on_close: Callback<()>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let codes = create_rw_signal(Vec::<InviteCode>::new());
+    let error = create_rw_signal(Option::<String>::None);
+    let saving = create_rw_signal(false);
+    let role = create_rw_signal("member".to_string());
+    let valid_hours = create_rw_signal("168".to_string());
+    let max_uses = create_rw_signal(String::new());
+    // (code id, data URL) of the QR code currently shown
+    let qr_code = create_rw_signal(Option::<(String, String)>::None);
+    let household_id = store_value(household_id);
+
+    let origin = web_sys::window()
+        .and_then(|w| w.location().origin().ok())
+        .unwrap_or_default();
+    let origin = store_value(origin);
+    let timezone = use_context::<HouseholdContext>()
+        .and_then(|ctx| ctx.settings.get_untracked())
+        .map(|s| s.timezone)
+        .unwrap_or_else(|| "UTC".to_string());
+    let timezone = store_value(timezone);
+
+    wasm_bindgen_futures::spawn_local(async move {
+        match api_client().list_invite_codes(&household_id.get_value()).await {
+            Ok(list) => codes.set(list),
+            Err(e) => error.set(Some(e.to_string())),
+        }
+    });
+
+    let on_create = move |ev: web_sys::SubmitEvent| {
+        ev.prevent_default();
+        saving.set(true);
+        error.set(None);
+
+        let request = CreateInviteCodeRequest {
+            role: Some(if role.get() == "admin" { Role::Admin } else { Role::Member }),
+            expires_in_hours: valid_hours.get().parse().ok(),
+            max_uses: max_uses.get().trim().parse().ok(),
+        };
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().create_invite_code(&household_id.get_value(), request).await {
+                Ok(code) => {
+                    codes.update(|list| list.insert(0, code));
+                    max_uses.set(String::new());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            saving.set(false);
+        });
+    };
+
+    let on_revoke = move |code_id: String| {
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().revoke_invite_code(&household_id.get_value(), &code_id).await {
+                Ok(()) => codes.update(|list| list.retain(|c| c.id.to_string() != code_id)),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_toggle_qr = move |code_id: String| {
+        if qr_code.with(|qr| qr.as_ref().is_some_and(|(id, _)| *id == code_id)) {
+            qr_code.set(None);
+            return;
+        }
+        wasm_bindgen_futures::spawn_local(async move {
+            match files::get_invite_code_qr_url(&household_id.get_value(), &code_id).await {
+                Ok(url) => qr_code.set(Some((code_id, url))),
+                Err(e) => error.set(Some(e)),
+            }
+        });
+    };
+
+    view! {
+        <Modal title=i18n_stored.get_value().t("invite_codes.title") on_close=move |_| on_close.call(())>
+            {move || error.get().map(|e| view! {
+                <div class="alert alert-error" style="margin-bottom: 1rem;">{e}</div>
+            })}
+
+            <p class="form-hint" style="margin-bottom: 1rem;">{i18n_stored.get_value().t("invite_codes.hint")}</p>
+
+            <form class="invite-code-form" on:submit=on_create>
+                <div class="form-group">
+                    <label class="form-label" for="invite-code-role">{i18n_stored.get_value().t("members.role")}</label>
+                    <select
+                        id="invite-code-role"
+                        class="form-select"
+                        on:change=move |ev| role.set(event_target_value(&ev))
+                    >
+                        <option value="member">{i18n_stored.get_value().t("roles.member")}</option>
+                        {can_invite_admin.then(|| view! {
+                            <option value="admin">{i18n_stored.get_value().t("roles.admin")}</option>
+                        })}
+                    </select>
+                </div>
+                <div class="form-group">
+                    <label class="form-label" for="invite-code-valid">{i18n_stored.get_value().t("invite_codes.valid_for")}</label>
+                    <select
+                        id="invite-code-valid"
+                        class="form-select"
+                        prop:value=move || valid_hours.get()
+                        on:change=move |ev| valid_hours.set(event_target_value(&ev))
+                    >
+                        <option value="24">{i18n_stored.get_value().t("invite_codes.valid_day")}</option>
+                        <option value="168">{i18n_stored.get_value().t("invite_codes.valid_week")}</option>
+                        <option value="720">{i18n_stored.get_value().t("invite_codes.valid_month")}</option>
+                    </select>
+                </div>
+                <div class="form-group">
+                    <label class="form-label" for="invite-code-max-uses">{i18n_stored.get_value().t("invite_codes.max_uses")}</label>
+                    <input
+                        type="number"
+                        id="invite-code-max-uses"
+                        class="form-input"
+                        min="1"
+                        placeholder=i18n_stored.get_value().t("invite_codes.unlimited")
+                        prop:value=move || max_uses.get()
+                        on:input=move |ev| max_uses.set(event_target_value(&ev))
+                    />
+                </div>
+                <button type="submit" class="btn btn-primary" disabled=move || saving.get()>
+                    {i18n_stored.get_value().t("invite_codes.create")}
+                </button>
+            </form>
+
+            {move || {
+                let i18n = i18n_stored.get_value();
+                let list = codes.get();
+                if list.is_empty() {
+                    return view! { <p class="empty-state">{i18n.t("invite_codes.empty")}</p> }.into_view();
+                }
+                view! {
+                    <ul class="invite-code-list">
+                        {list.into_iter().map(|code| {
+                            let code_id = code.id.to_string();
+                            let qr_code_id = code_id.clone();
+                            let toggle_code_id = code_id.clone();
+                            let link = invite_link(&origin.get_value(), &code.code);
+                            let uses = match code.max_uses {
+                                Some(max) => format!("{} / {}", code.use_count, max),
+                                None => code.use_count.to_string(),
+                            };
+                            let expires = i18n.t_with("invite_codes.expires", &[("date", &format_datetime(code.expires_at, &timezone.get_value()))]);
+                            view! {
+                                <li class="invite-code-item">
+                                    <div class="invite-code-value">{display_code(&code.code)}</div>
+                                    <input
+                                        type="text"
+                                        class="form-input invite-code-link"
+                                        readonly
+                                        value=link
+                                        on:focus=move |ev| {
+                                            use wasm_bindgen::JsCast;
+                                            if let Some(input) = ev.target().and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok()) {
+                                                input.select();
+                                            }
+                                        }
+                                    />
+                                    <div class="invite-code-meta">
+                                        <span>{expires}</span>
+                                        <span>{i18n.t("invite_codes.uses")} ": " {uses}</span>
+                                    </div>
+                                    {move || qr_code.get()
+                                        .filter(|(id, _)| *id == qr_code_id)
+                                        .map(|(_, url)| view! {
+                                            <img class="invite-code-qr" src=url alt=i18n_stored.get_value().t("invite_codes.qr") />
+                                        })}
+                                    <div class="invite-code-actions">
+                                        <button
+                                            type="button"
+                                            class="btn btn-outline btn-sm"
+                                            on:click=move |_| on_toggle_qr(toggle_code_id.clone())
+                                        >
+                                            {i18n.t("invite_codes.qr")}
+                                        </button>
+                                        <button
+                                            type="button"
+                                            class="btn btn-outline btn-sm"
+                                            on:click=move |_| on_revoke(code_id.clone())
+                                        >
+                                            {i18n.t("invite_codes.revoke")}
+                                        </button>
+                                    </div>
+                                </li>
+                            }
+                        }).collect_view()}
+                    </ul>
+                }.into_view()
+            }}
+        </Modal>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_invite_link_and_display_code() {
+        assert_eq!(invite_link("https://home.example/", "ABCD2345"), "https://home.example/join/ABCD2345");
+        assert_eq!(display_code("ABCD2345"), "ABCD-2345");
+        assert_eq!(display_code("ABC"), "ABC");
+    }
+}
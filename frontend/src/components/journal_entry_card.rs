@@ -1,10 +1,19 @@
 use leptos::*;
-use shared::JournalEntryWithUser;
+use shared::{AttachmentEntity, JournalEntryWithUser, JournalVisibility, MOOD_MIN};
 use uuid::Uuid;
 
+use crate::components::attachment_list::AttachmentList;
 use crate::components::markdown::MarkdownView;
 use crate::i18n::I18nContext;
 
+/// Faces for the mood scores MOOD_MIN..=MOOD_MAX, from bad to great
+pub const MOOD_EMOJIS: [&str; 5] = ["😞", "🙁", "😐", "🙂", "😄"];
+
+/// The face for a mood score, if it is in range
+pub fn mood_emoji(mood: i32) -> Option<&'static str> {
+    usize::try_from(mood - MOOD_MIN).ok().and_then(|i| MOOD_EMOJIS.get(i).copied())
+}
+
 /// A card displaying a single journal entry
 #[component]
 pub fn JournalEntryCard(
@@ -17,7 +26,11 @@ pub fn JournalEntryCard(
     let entry_for_edit = entry.clone();
     let entry_id = entry.entry.id;
     let can_modify = entry.entry.user_id == current_user_id;
-    let is_private = !entry.entry.is_shared;
+    let household_id = entry.entry.household_id.to_string();
+    let attachment_entity_id = entry.entry.id.to_string();
+    let attachments = entry.attachments.clone();
+    let is_private = entry.entry.visibility == JournalVisibility::Private;
+    let mood = entry.entry.mood.and_then(mood_emoji);
     let title = entry.entry.title.clone();
     let content = entry.entry.content.clone();
     let author = entry.user.username.clone();
@@ -48,6 +61,7 @@ pub fn JournalEntryCard(
                     <span class="journal-entry-date">{entry_date}</span>
                 </div>
                 <div class="note-badges">
+                    {mood.map(|face| view! { <span class="journal-mood">{face}</span> })}
                     {if is_private {
                         view! { <span class="badge badge-private">{private_label}</span> }.into_view()
                     } else {
@@ -60,6 +74,14 @@ pub fn JournalEntryCard(
                 <MarkdownView content=content />
             </div>
 
+            <AttachmentList
+                household_id=household_id
+                entity_type=AttachmentEntity::JournalEntry
+                entity_id=attachment_entity_id
+                attachments=attachments
+                can_modify=can_modify
+            />
+
             <div class="note-footer">
                 <div class="note-meta">
                     <span class="note-author">{author}</span>
@@ -89,3 +111,21 @@ pub fn JournalEntryCard(
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::MOOD_MAX;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn test_mood_emoji_covers_the_scale() {
+        assert_eq!(MOOD_EMOJIS.len() as i32, MOOD_MAX - MOOD_MIN + 1);
+        assert_eq!(mood_emoji(MOOD_MIN), Some("😞"));
+        assert_eq!(mood_emoji(MOOD_MAX), Some("😄"));
+        assert_eq!(mood_emoji(0), None);
+        assert_eq!(mood_emoji(6), None);
+    }
+}
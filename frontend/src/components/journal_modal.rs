@@ -1,8 +1,9 @@
 use chrono::NaiveDate;
 use leptos::*;
-use shared::{CreateJournalEntryRequest, JournalEntry, UpdateJournalEntryRequest};
+use shared::{CreateJournalEntryRequest, JournalEntry, JournalVisibility, UpdateJournalEntryRequest, MOOD_MIN};
 
-use crate::api::ApiClient;
+use crate::api::api_client;
+use crate::components::journal_entry_card::MOOD_EMOJIS;
 use crate::components::markdown::MarkdownViewReactive;
 use crate::i18n::I18nContext;
 
@@ -32,7 +33,8 @@ pub fn JournalModal(
             .map(|e| e.entry_date.format("%Y-%m-%d").to_string())
             .unwrap_or_else(|| today_str.clone()),
     );
-    let is_shared = create_rw_signal(entry.as_ref().map(|e| e.is_shared).unwrap_or(false));
+    let visibility = create_rw_signal(entry.as_ref().map(|e| e.visibility).unwrap_or_default());
+    let mood = create_rw_signal(entry.as_ref().and_then(|e| e.mood));
 
     let entry_id = entry.as_ref().map(|e| e.id.to_string());
 
@@ -70,16 +72,17 @@ pub fn JournalModal(
                         title: Some(title.get()),
                         content: Some(content.get()),
                         entry_date: Some(parsed_date),
-                        is_shared: Some(is_shared.get()),
+                        visibility: Some(visibility.get()),
+                        mood: Some(mood.get()),
                     };
 
-                    match ApiClient::update_journal_entry(&household_id, &entry_id, request).await {
+                    match api_client().update_journal_entry(&household_id, &entry_id, request).await {
                         Ok(updated_entry) => {
                             saving.set(false);
                             on_save.call(updated_entry);
                         }
                         Err(e) => {
-                            error.set(Some(e));
+                            error.set(Some(e.to_string()));
                             saving.set(false);
                         }
                     }
@@ -94,16 +97,17 @@ pub fn JournalModal(
                         },
                         content: content.get(),
                         entry_date: Some(parsed_date),
-                        is_shared: is_shared.get(),
+                        visibility: visibility.get(),
+                        mood: mood.get(),
                     };
 
-                    match ApiClient::create_journal_entry(&household_id, request).await {
+                    match api_client().create_journal_entry(&household_id, request).await {
                         Ok(created_entry) => {
                             saving.set(false);
                             on_save.call(created_entry);
                         }
                         Err(e) => {
-                            error.set(Some(e));
+                            error.set(Some(e.to_string()));
                             saving.set(false);
                         }
                     }
@@ -130,7 +134,10 @@ pub fn JournalModal(
     let title_label = i18n.get_value().t("journal.entry_title");
     let content_label = i18n.get_value().t("journal.entry_content");
     let date_label = i18n.get_value().t("journal.entry_date");
-    let share_label = i18n.get_value().t("journal.share_with_household");
+    let visibility_label = i18n.get_value().t("journal.visibility");
+    let private_label = i18n.get_value().t("journal.visibility_private");
+    let household_label = i18n.get_value().t("journal.visibility_household");
+    let mood_label = i18n.get_value().t("journal.mood");
     let preview_text = i18n.get_value().t("common.preview");
     let edit_text = i18n.get_value().t("common.edit");
 
@@ -207,14 +214,42 @@ pub fn JournalModal(
                         </div>
 
                         <div class="form-group">
-                            <label style="display: flex; align-items: center; gap: 0.5rem; cursor: pointer;">
-                                <input
-                                    type="checkbox"
-                                    prop:checked=move || is_shared.get()
-                                    on:change=move |ev| is_shared.set(event_target_checked(&ev))
-                                />
-                                <span>{share_label.clone()}</span>
-                            </label>
+                            <label class="form-label">{mood_label.clone()}</label>
+                            <div class="mood-picker">
+                                {MOOD_EMOJIS.iter().zip(MOOD_MIN..).map(|(face, score)| view! {
+                                    <button
+                                        type="button"
+                                        class="mood-option"
+                                        class:selected=move || mood.get() == Some(score)
+                                        // Clicking the selected mood again clears it
+                                        on:click=move |_| mood.update(|m| *m = if *m == Some(score) { None } else { Some(score) })
+                                    >
+                                        {*face}
+                                    </button>
+                                }).collect_view()}
+                            </div>
+                        </div>
+
+                        <div class="form-group">
+                            <label class="form-label" for="journal-visibility">{visibility_label.clone()}</label>
+                            <select
+                                id="journal-visibility"
+                                class="form-select"
+                                on:change=move |ev| visibility.set(event_target_value(&ev).parse().unwrap_or_default())
+                            >
+                                <option
+                                    value=JournalVisibility::Private.as_str()
+                                    selected=move || visibility.get() == JournalVisibility::Private
+                                >
+                                    {private_label.clone()}
+                                </option>
+                                <option
+                                    value=JournalVisibility::Household.as_str()
+                                    selected=move || visibility.get() == JournalVisibility::Household
+                                >
+                                    {household_label.clone()}
+                                </option>
+                            </select>
                         </div>
                     </div>
 
@@ -1,5 +1,44 @@
 use leptos::*;
-use pulldown_cmark::{html, Parser};
+use pulldown_cmark::{html, CowStr, Event, Options, Parser, Tag};
+
+/// URL schemes allowed in links and images; anything else (e.g. `javascript:`) is dropped
+const SAFE_SCHEMES: [&str; 4] = ["http:", "https:", "mailto:", "tel:"];
+
+fn is_safe_url(url: &str) -> bool {
+    let lower = url.trim().to_ascii_lowercase();
+    match lower.find([':', '/', '?', '#']) {
+        // Relative URLs and fragments have no scheme
+        Some(i) if lower.as_bytes()[i] == b':' => SAFE_SCHEMES.iter().any(|s| lower.starts_with(s)),
+        _ => true,
+    }
+}
+
+fn sanitize_url(url: CowStr<'_>) -> CowStr<'_> {
+    if is_safe_url(&url) {
+        url
+    } else {
+        CowStr::Borrowed("")
+    }
+}
+
+/// Render markdown to HTML that is safe to insert into the page.
+///
+/// Raw HTML in the source is shown as text instead of being passed through, and
+/// links or images with unsafe URL schemes lose their target.
+pub fn render_markdown(content: &str) -> String {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let parser = Parser::new_ext(content, options).map(|event| match event {
+        Event::Html(raw) => Event::Text(raw),
+        Event::Start(Tag::Link(kind, url, title)) => Event::Start(Tag::Link(kind, sanitize_url(url), title)),
+        Event::End(Tag::Link(kind, url, title)) => Event::End(Tag::Link(kind, sanitize_url(url), title)),
+        Event::Start(Tag::Image(kind, url, title)) => Event::Start(Tag::Image(kind, sanitize_url(url), title)),
+        Event::End(Tag::Image(kind, url, title)) => Event::End(Tag::Image(kind, sanitize_url(url), title)),
+        event => event,
+    });
+    let mut html_output = String::new();
+    html::push_html(&mut html_output, parser);
+    html_output
+}
 
 /// Renders markdown content as HTML
 #[component]
@@ -7,12 +46,7 @@ pub fn MarkdownView(
     /// The markdown content to render
     content: String,
 ) -> impl IntoView {
-    let html_content = {
-        let parser = Parser::new(&content);
-        let mut html_output = String::new();
-        html::push_html(&mut html_output, parser);
-        html_output
-    };
+    let html_content = render_markdown(&content);
 
     view! {
         <div class="markdown-content" inner_html=html_content></div>
@@ -26,12 +60,41 @@ pub fn MarkdownViewReactive(
     content: Signal<String>,
 ) -> impl IntoView {
     view! {
-        <div class="markdown-content" inner_html=move || {
-            let text = content.get();
-            let parser = Parser::new(&text);
-            let mut html_output = String::new();
-            html::push_html(&mut html_output, parser);
-            html_output
-        }></div>
+        <div class="markdown-content" inner_html=move || render_markdown(&content.get())></div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn test_render_markdown_formats_text() {
+        let html = render_markdown("# Manual\n\n- **Heating** is in the ~~attic~~ cellar");
+        assert!(html.contains("<h1>Manual</h1>"));
+        assert!(html.contains("<strong>Heating</strong>"));
+        assert!(html.contains("<del>attic</del>"));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_render_markdown_escapes_raw_html() {
+        let html = render_markdown("<script>alert(1)</script>\n\nText <img src=x onerror=alert(1)>");
+        assert!(!html.contains("<script"));
+        assert!(!html.contains("<img"));
+        assert!(html.contains("&lt;script&gt;"));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_render_markdown_drops_unsafe_links() {
+        let html = render_markdown("[click](javascript:alert(1)) [site](https://example.com) [anchor](#top)");
+        assert!(!html.contains("javascript:"));
+        assert!(html.contains("href=\"https://example.com\""));
+        assert!(html.contains("href=\"#top\""));
+        assert!(is_safe_url("/relative/path"));
+        assert!(!is_safe_url(" JavaScript:alert(1)"));
+        assert!(!is_safe_url("data:text/html,hi"));
     }
 }
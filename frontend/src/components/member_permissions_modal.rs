@@ -0,0 +1,142 @@
+use leptos::*;
+use shared::{MemberPermissions, Permission, UpdateMemberPermissionsRequest};
+
+use crate::api::api_client;
+use crate::components::modal::Modal;
+use crate::i18n::use_i18n;
+
+/// Translation key for a permission's label
+fn permission_key(permission: Permission) -> String {
+    format!("permissions.{}", permission.as_str())
+}
+
+/// Owner-only modal that edits a member's permission matrix
+#[component]
+pub fn MemberPermissionsModal(
+    household_id: String,
+    user_id: String,
+    username: String,
+    #[prop(into)] on_close: Callback<()>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let permissions = create_rw_signal(MemberPermissions::default());
+    let custom = create_rw_signal(false);
+    let loading = create_rw_signal(true);
+    let saving = create_rw_signal(false);
+    let error = create_rw_signal(Option::<String>::None);
+    let ids = store_value((household_id, user_id));
+
+    {
+        let (household_id, user_id) = ids.get_value();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().get_member_permissions(&household_id, &user_id).await {
+                Ok(response) => {
+                    permissions.set(response.permissions);
+                    custom.set(response.custom);
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            loading.set(false);
+        });
+    }
+
+    let save = move |matrix: Option<MemberPermissions>| {
+        saving.set(true);
+        error.set(None);
+        let (household_id, user_id) = ids.get_value();
+        let request = UpdateMemberPermissionsRequest { permissions: matrix };
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().update_member_permissions(&household_id, &user_id, request).await {
+                Ok(_) => on_close.call(()),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            saving.set(false);
+        });
+    };
+
+    let title = i18n_stored.get_value().t_with("permissions.title_for", &[("username", &username)]);
+
+    view! {
+        <Modal title=title on_close=move |_| on_close.call(())>
+            {move || error.get().map(|e| view! {
+                <div class="alert alert-error" style="margin-bottom: 1rem;">{e}</div>
+            })}
+
+            <Show when=move || !loading.get() fallback=move || view! {
+                <span class="loading">{move || i18n_stored.get_value().t("common.loading")}</span>
+            }>
+                <p class="form-hint" style="margin-bottom: 1rem;">
+                    {move || if custom.get() {
+                        i18n_stored.get_value().t("permissions.custom_hint")
+                    } else {
+                        i18n_stored.get_value().t("permissions.default_hint")
+                    }}
+                </p>
+
+                {Permission::ALL.into_iter().map(|permission| view! {
+                    <div class="form-group">
+                        <label style="display: flex; align-items: center; gap: 0.5rem; cursor: pointer;">
+                            <input
+                                type="checkbox"
+                                prop:checked=move || permissions.get().has(permission)
+                                on:change=move |ev| {
+                                    let granted = event_target_checked(&ev);
+                                    permissions.update(|p| p.set(permission, granted));
+                                }
+                            />
+                            <span>{i18n_stored.get_value().t(&permission_key(permission))}</span>
+                        </label>
+                    </div>
+                }).collect_view()}
+
+                <div style="display: flex; gap: 0.5rem; margin-bottom: 1rem;">
+                    <button
+                        type="button"
+                        class="btn btn-outline"
+                        on:click=move |_| permissions.set(MemberPermissions::teen())
+                    >
+                        {i18n_stored.get_value().t("permissions.teen_preset")}
+                    </button>
+                    <button
+                        type="button"
+                        class="btn btn-outline"
+                        disabled=move || saving.get()
+                        on:click=move |_| save(None)
+                    >
+                        {i18n_stored.get_value().t("permissions.reset_defaults")}
+                    </button>
+                </div>
+
+                <div class="modal-footer">
+                    <button type="button" class="btn btn-outline" on:click=move |_| on_close.call(())>
+                        {i18n_stored.get_value().t("common.cancel")}
+                    </button>
+                    <button
+                        type="button"
+                        class="btn btn-primary"
+                        disabled=move || saving.get()
+                        on:click=move |_| save(Some(permissions.get()))
+                    >
+                        {move || if saving.get() {
+                            i18n_stored.get_value().t("common.saving")
+                        } else {
+                            i18n_stored.get_value().t("common.save")
+                        }}
+                    </button>
+                </div>
+            </Show>
+        </Modal>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_permission_key() {
+        assert_eq!(permission_key(Permission::AdjustPoints), "permissions.adjust_points");
+    }
+}
@@ -1,17 +1,26 @@
 pub mod navbar;
 pub mod task_card;
 pub mod points_display;
+pub mod points_history_modal;
+pub mod audit_log_modal;
+pub mod invite_codes_modal;
+pub mod share_links_modal;
+pub mod child_account_modal;
+pub mod member_permissions_modal;
 pub mod loading;
 pub mod modal;
 pub mod task_modal;
 pub mod task_fields;
 pub mod task_detail_modal;
+pub mod task_filter_bar;
+pub mod task_comments;
 pub mod household_tabs;
 pub mod household_layout;
 
 pub use household_layout::HouseholdContext;
 pub mod calendar_picker;
 pub mod pending_reviews;
+pub mod completion_photos;
 pub mod pending_suggestions;
 pub mod pending_confirmations;
 pub mod reward_modal;
@@ -19,6 +28,7 @@ pub mod punishment_modal;
 pub mod chat_message;
 pub mod markdown;
 pub mod note_card;
+pub mod attachment_list;
 pub mod note_modal;
 pub mod journal_entry_card;
 pub mod journal_modal;
@@ -32,6 +42,12 @@ pub mod quick_task_fab;
 pub mod set_date_modal;
 pub mod period_tracker;
 pub mod text_filter_input;
+pub mod notification_bell;
+pub mod webhook_settings;
+pub mod api_tokens_card;
+pub mod point_goals_card;
+pub mod focus_timer;
+pub mod theme;
 
 // Primitive UI components
 pub mod button;
@@ -2,6 +2,7 @@ use leptos::*;
 use leptos_router::*;
 
 use crate::api::AuthState;
+use crate::components::notification_bell::NotificationBell;
 use crate::i18n::use_i18n;
 
 #[component]
@@ -37,6 +38,8 @@ pub fn Navbar() -> impl IntoView {
             <div class="container navbar-content">
                 <a href="/" class="navbar-brand">{move || i18n_brand.t("nav.app_name")}</a>
 
+                <NotificationBell />
+
                 // Hamburger button (mobile only)
                 <button class="navbar-toggle" on:click=toggle_menu aria-label="Toggle menu">
                     <span class="hamburger-line"></span>
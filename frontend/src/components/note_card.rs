@@ -1,9 +1,13 @@
 use leptos::*;
-use shared::NoteWithUser;
+use shared::{AttachmentEntity, NoteWithUser};
 use uuid::Uuid;
 
+use crate::components::attachment_list::AttachmentList;
 use crate::components::markdown::MarkdownView;
 
+/// Card colors offered in the note editor; the empty string means no color
+pub const NOTE_COLORS: [&str; 6] = ["", "#f59e0b", "#22c55e", "#3b82f6", "#ec4899", "#8b5cf6"];
+
 /// A card displaying a single note
 #[component]
 pub fn NoteCard(
@@ -11,14 +15,28 @@ pub fn NoteCard(
     current_user_id: Uuid,
     #[prop(into)] on_edit: Callback<NoteWithUser>,
     #[prop(into)] on_delete: Callback<Uuid>,
+    /// Toggle the pin of the note
+    #[prop(into)] on_pin: Callback<NoteWithUser>,
+    /// Toggle whether the note is archived
+    #[prop(into)] on_archive: Callback<NoteWithUser>,
+    /// Move the note one place up (`true`) or down (`false`)
+    #[prop(into)] on_move: Callback<(Uuid, bool)>,
 ) -> impl IntoView {
     let note_for_edit = note.clone();
+    let note_for_pin = note.clone();
+    let note_for_archive = note.clone();
     let note_id = note.note.id;
     let can_modify = note.note.user_id == current_user_id;
+    let household_id = note.note.household_id.to_string();
+    let attachment_entity_id = note.note.id.to_string();
+    let attachments = note.attachments.clone();
     let is_private = !note.note.is_shared;
+    let is_pinned = note.note.pinned;
+    let is_archived = note.note.archived;
     let title = note.note.title.clone();
     let content = note.note.content.clone();
     let author = note.user.username.clone();
+    let accent = note.note.color.clone().map(|c| format!("border-left: 4px solid {}", c));
 
     let format_time = {
         let updated = note.note.updated_at;
@@ -34,10 +52,11 @@ pub fn NoteCard(
     };
 
     view! {
-        <div class="note-card">
+        <div class="note-card" class:pinned=is_pinned style=accent>
             <div class="note-header">
                 <h3 class="note-title">{title}</h3>
                 <div class="note-badges">
+                    {is_pinned.then(|| view! { <span class="badge badge-pinned">"Pinned"</span> })}
                     {if is_private {
                         view! { <span class="badge badge-private">"Private"</span> }.into_view()
                     } else {
@@ -50,6 +69,14 @@ pub fn NoteCard(
                 <MarkdownView content=content />
             </div>
 
+            <AttachmentList
+                household_id=household_id
+                entity_type=AttachmentEntity::Note
+                entity_id=attachment_entity_id
+                attachments=attachments
+                can_modify=can_modify
+            />
+
             <div class="note-footer">
                 <div class="note-meta">
                     <span class="note-author">"By: " {author}</span>
@@ -59,6 +86,20 @@ pub fn NoteCard(
                 {if can_modify {
                     view! {
                         <div class="note-actions">
+                            {(!is_archived).then(|| view! {
+                                <button class="btn btn-outline btn-sm" title="Move up" on:click=move |_| on_move.call((note_id, true))>
+                                    "↑"
+                                </button>
+                                <button class="btn btn-outline btn-sm" title="Move down" on:click=move |_| on_move.call((note_id, false))>
+                                    "↓"
+                                </button>
+                                <button class="btn btn-outline btn-sm" on:click=move |_| on_pin.call(note_for_pin.clone())>
+                                    {if is_pinned { "Unpin" } else { "Pin" }}
+                                </button>
+                            })}
+                            <button class="btn btn-outline btn-sm" on:click=move |_| on_archive.call(note_for_archive.clone())>
+                                {if is_archived { "Restore" } else { "Archive" }}
+                            </button>
                             <button
                                 class="btn btn-outline btn-sm"
                                 on:click=handle_edit
@@ -1,8 +1,9 @@
 use leptos::*;
 use shared::{CreateNoteRequest, Note, UpdateNoteRequest};
 
-use crate::api::ApiClient;
+use crate::api::api_client;
 use crate::components::markdown::MarkdownViewReactive;
+use crate::components::note_card::NOTE_COLORS;
 
 #[component]
 pub fn NoteModal(
@@ -20,8 +21,10 @@ pub fn NoteModal(
     let title = create_rw_signal(note.as_ref().map(|n| n.title.clone()).unwrap_or_default());
     let content = create_rw_signal(note.as_ref().map(|n| n.content.clone()).unwrap_or_default());
     let is_shared = create_rw_signal(note.as_ref().map(|n| n.is_shared).unwrap_or(false));
+    let color = create_rw_signal(note.as_ref().and_then(|n| n.color.clone()).unwrap_or_default());
 
     let note_id = note.as_ref().map(|n| n.id.to_string());
+    let note_version = note.as_ref().map(|n| n.version);
 
     let on_submit = {
         let note_id = note_id.clone();
@@ -48,15 +51,20 @@ pub fn NoteModal(
                         title: Some(title.get()),
                         content: Some(content.get()),
                         is_shared: Some(is_shared.get()),
+                        pinned: None,
+                        color: Some(color.get()),
+                        sort_order: None,
+                        archived: None,
+                        version: note_version,
                     };
 
-                    match ApiClient::update_note(&household_id, &note_id, request).await {
+                    match api_client().update_note(&household_id, &note_id, request).await {
                         Ok(updated_note) => {
                             saving.set(false);
                             on_save.call(updated_note);
                         }
                         Err(e) => {
-                            error.set(Some(e));
+                            error.set(Some(e.to_string()));
                             saving.set(false);
                         }
                     }
@@ -66,15 +74,16 @@ pub fn NoteModal(
                         title: title.get(),
                         content: Some(content.get()),
                         is_shared: is_shared.get(),
+                        color: Some(color.get()).filter(|c| !c.is_empty()),
                     };
 
-                    match ApiClient::create_note(&household_id, request).await {
+                    match api_client().create_note(&household_id, request).await {
                         Ok(created_note) => {
                             saving.set(false);
                             on_save.call(created_note);
                         }
                         Err(e) => {
-                            error.set(Some(e));
+                            error.set(Some(e.to_string()));
                             saving.set(false);
                         }
                     }
@@ -157,6 +166,25 @@ pub fn NoteModal(
                             </Show>
                         </div>
 
+                        <div class="form-group">
+                            <label class="form-label">"Color"</label>
+                            <div class="note-colors">
+                                {NOTE_COLORS.iter().map(|swatch| {
+                                    let swatch = swatch.to_string();
+                                    let swatch_for_class = swatch.clone();
+                                    let background = if swatch.is_empty() { "var(--card-color)".to_string() } else { swatch.clone() };
+                                    view! {
+                                        <button
+                                            type="button"
+                                            class=move || if color.get() == swatch_for_class { "note-color selected" } else { "note-color" }
+                                            style=format!("background-color: {}", background)
+                                            on:click=move |_| color.set(swatch.clone())
+                                        />
+                                    }
+                                }).collect_view()}
+                            </div>
+                        </div>
+
                         <div class="form-group">
                             <label style="display: flex; align-items: center; gap: 0.5rem; cursor: pointer;">
                                 <input
@@ -0,0 +1,171 @@
+use leptos::*;
+use shared::{Notification, NotificationType, WsServerMessage};
+
+use crate::api::websocket::WsClient;
+use crate::api::api_client;
+use crate::i18n::use_i18n;
+
+/// Translation key describing a notification
+fn notification_key(notification_type: NotificationType) -> &'static str {
+    match notification_type {
+        NotificationType::TaskAssigned => "notifications.task_assigned",
+        NotificationType::CompletionApproved => "notifications.completion_approved",
+        NotificationType::CompletionRejected => "notifications.completion_rejected",
+        NotificationType::RewardConfirmed => "notifications.reward_confirmed",
+        NotificationType::PurchaseApproved => "notifications.purchase_approved",
+        NotificationType::PurchaseRejected => "notifications.purchase_rejected",
+        NotificationType::RewardAffordable => "notifications.reward_affordable",
+    }
+}
+
+/// Where clicking a notification leads
+fn notification_link(notification: &Notification) -> String {
+    match (notification.household_id, notification.entity_type.as_deref()) {
+        (Some(household_id), Some("reward")) => format!("/households/{}/rewards", household_id),
+        (Some(household_id), _) => format!("/households/{}/tasks", household_id),
+        (None, _) => "/".to_string(),
+    }
+}
+
+/// Bell in the navbar showing the personal notification inbox
+#[component]
+pub fn NotificationBell() -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let notifications = create_rw_signal(Vec::<Notification>::new());
+    let unread_count = create_rw_signal(0i64);
+    let is_open = create_rw_signal(false);
+
+    wasm_bindgen_futures::spawn_local(async move {
+        if let Ok(list) = api_client().list_notifications().await {
+            notifications.set(list.notifications);
+            unread_count.set(list.unread_count);
+        }
+    });
+
+    // New notifications arrive over the WebSocket
+    let ws_client = WsClient::new();
+    ws_client.connect();
+    let ws_messages = ws_client.last_message();
+    create_effect(move |_| {
+        if let Some(WsServerMessage::NotificationCreated { notification }) = ws_messages.get() {
+            notifications.update(|list| list.insert(0, notification));
+            unread_count.update(|count| *count += 1);
+        }
+    });
+    on_cleanup(move || ws_client.disconnect());
+
+    let mark_read = move |notification: Notification| {
+        is_open.set(false);
+        if notification.is_read() {
+            return;
+        }
+        let id = notification.id;
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Ok(updated) = api_client().mark_notification_read(&id.to_string()).await {
+                notifications.update(|list| {
+                    if let Some(entry) = list.iter_mut().find(|n| n.id == id) {
+                        *entry = updated;
+                    }
+                });
+                unread_count.update(|count| *count = (*count - 1).max(0));
+            }
+        });
+    };
+
+    let mark_all_read = move |_| {
+        wasm_bindgen_futures::spawn_local(async move {
+            if api_client().mark_all_notifications_read().await.is_ok() {
+                let now = chrono::Utc::now();
+                notifications.update(|list| {
+                    for notification in list.iter_mut().filter(|n| n.read_at.is_none()) {
+                        notification.read_at = Some(now);
+                    }
+                });
+                unread_count.set(0);
+            }
+        });
+    };
+
+    view! {
+        <div class="notification-bell">
+            <button
+                class="notification-bell-button"
+                aria-label=move || i18n_stored.get_value().t("notifications.title")
+                on:click=move |_| is_open.update(|open| *open = !*open)
+            >
+                "🔔"
+                <Show when=move || { unread_count.get() > 0 } fallback=|| ()>
+                    <span class="notification-badge">{move || unread_count.get()}</span>
+                </Show>
+            </button>
+            <Show when=move || is_open.get() fallback=|| ()>
+                <div class="notification-dropdown">
+                    <div class="notification-dropdown-header">
+                        <strong>{i18n_stored.get_value().t("notifications.title")}</strong>
+                        <button class="btn btn-outline btn-sm" on:click=mark_all_read disabled=move || unread_count.get() == 0>
+                            {i18n_stored.get_value().t("notifications.mark_all_read")}
+                        </button>
+                    </div>
+                    {move || {
+                        let list = notifications.get();
+                        if list.is_empty() {
+                            return view! {
+                                <p class="notification-empty">{i18n_stored.get_value().t("notifications.empty")}</p>
+                            }.into_view();
+                        }
+                        list.into_iter().map(|notification| {
+                            let text = i18n_stored.get_value()
+                                .t_with(notification_key(notification.notification_type), &[("title", &notification.title)]);
+                            let href = notification_link(&notification);
+                            let time = notification.created_at.format("%d.%m. %H:%M").to_string();
+                            let unread = !notification.is_read();
+                            view! {
+                                <a
+                                    href=href
+                                    class="notification-item"
+                                    class:notification-unread=unread
+                                    on:click=move |_| mark_read(notification.clone())
+                                >
+                                    <span>{text}</span>
+                                    <small class="notification-time">{time}</small>
+                                </a>
+                            }
+                        }).collect_view()
+                    }}
+                </div>
+            </Show>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_notification_link() {
+        let household_id = Uuid::new_v4();
+        let mut notification = Notification {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            household_id: Some(household_id),
+            notification_type: NotificationType::RewardConfirmed,
+            title: "Movie night".to_string(),
+            entity_type: Some("reward".to_string()),
+            entity_id: None,
+            read_at: None,
+            created_at: chrono::Utc::now(),
+        };
+        assert_eq!(notification_link(&notification), format!("/households/{}/rewards", household_id));
+
+        notification.entity_type = Some("task".to_string());
+        assert_eq!(notification_link(&notification), format!("/households/{}/tasks", household_id));
+
+        notification.household_id = None;
+        assert_eq!(notification_link(&notification), "/");
+    }
+}
@@ -1,7 +1,7 @@
 use leptos::*;
-use shared::{PendingPunishmentCompletion, PendingRewardRedemption};
+use shared::{PendingPunishmentCompletion, PendingRewardPurchase, PendingRewardRedemption};
 
-use crate::api::ApiClient;
+use crate::api::api_client;
 use crate::i18n::use_i18n;
 
 #[component]
@@ -14,6 +14,7 @@ pub fn PendingConfirmations(
 
     let pending_rewards = create_rw_signal(Vec::<PendingRewardRedemption>::new());
     let pending_punishments = create_rw_signal(Vec::<PendingPunishmentCompletion>::new());
+    let pending_purchases = create_rw_signal(Vec::<PendingRewardPurchase>::new());
     let loading = create_rw_signal(true);
     let error = create_rw_signal(Option::<String>::None);
     let processing = create_rw_signal(Option::<String>::None);
@@ -24,28 +25,41 @@ pub fn PendingConfirmations(
         create_effect(move |_| {
             let household_id = household_id.clone();
             let household_id2 = household_id.clone();
+            let household_id3 = household_id.clone();
 
             // Fetch pending reward redemptions
             wasm_bindgen_futures::spawn_local(async move {
-                match ApiClient::get_pending_reward_redemptions(&household_id).await {
+                match api_client().get_pending_reward_redemptions(&household_id).await {
                     Ok(data) => {
                         pending_rewards.set(data);
                     }
                     Err(e) => {
-                        error.set(Some(e));
+                        error.set(Some(e.to_string()));
+                    }
+                }
+            });
+
+            // Fetch reward purchases awaiting approval
+            wasm_bindgen_futures::spawn_local(async move {
+                match api_client().get_pending_reward_purchases(&household_id3).await {
+                    Ok(data) => {
+                        pending_purchases.set(data);
+                    }
+                    Err(e) => {
+                        error.set(Some(e.to_string()));
                     }
                 }
             });
 
             // Fetch pending punishment completions
             wasm_bindgen_futures::spawn_local(async move {
-                match ApiClient::get_pending_punishment_completions(&household_id2).await {
+                match api_client().get_pending_punishment_completions(&household_id2).await {
                     Ok(data) => {
                         pending_punishments.set(data);
                         loading.set(false);
                     }
                     Err(e) => {
-                        error.set(Some(e));
+                        error.set(Some(e.to_string()));
                         loading.set(false);
                     }
                 }
@@ -60,7 +74,7 @@ pub fn PendingConfirmations(
             processing.set(Some(user_reward_id.clone()));
 
             wasm_bindgen_futures::spawn_local(async move {
-                match ApiClient::approve_reward_redemption(&household_id, &user_reward_id).await {
+                match api_client().approve_reward_redemption(&household_id, &user_reward_id).await {
                     Ok(updated) => {
                         // Update local list - reduce pending count or remove if none left
                         pending_rewards.update(|items| {
@@ -74,7 +88,7 @@ pub fn PendingConfirmations(
                         on_confirmation_complete.call(());
                     }
                     Err(e) => {
-                        error.set(Some(e));
+                        error.set(Some(e.to_string()));
                         processing.set(None);
                     }
                 }
@@ -89,7 +103,7 @@ pub fn PendingConfirmations(
             processing.set(Some(user_reward_id.clone()));
 
             wasm_bindgen_futures::spawn_local(async move {
-                match ApiClient::reject_reward_redemption(&household_id, &user_reward_id).await {
+                match api_client().reject_reward_redemption(&household_id, &user_reward_id).await {
                     Ok(updated) => {
                         // Update local list
                         pending_rewards.update(|items| {
@@ -102,7 +116,51 @@ pub fn PendingConfirmations(
                         on_confirmation_complete.call(());
                     }
                     Err(e) => {
-                        error.set(Some(e));
+                        error.set(Some(e.to_string()));
+                        processing.set(None);
+                    }
+                }
+            });
+        }
+    };
+
+    let approve_purchase = {
+        let household_id = household_id.clone();
+        move |purchase_id: String| {
+            let household_id = household_id.clone();
+            processing.set(Some(purchase_id.clone()));
+
+            wasm_bindgen_futures::spawn_local(async move {
+                match api_client().approve_reward_purchase(&household_id, &purchase_id).await {
+                    Ok(_) => {
+                        pending_purchases.update(|items| items.retain(|p| p.purchase.id.to_string() != purchase_id));
+                        processing.set(None);
+                        on_confirmation_complete.call(());
+                    }
+                    Err(e) => {
+                        error.set(Some(e.to_string()));
+                        processing.set(None);
+                    }
+                }
+            });
+        }
+    };
+
+    let reject_purchase = {
+        let household_id = household_id.clone();
+        move |purchase_id: String| {
+            let household_id = household_id.clone();
+            processing.set(Some(purchase_id.clone()));
+
+            wasm_bindgen_futures::spawn_local(async move {
+                match api_client().reject_reward_purchase(&household_id, &purchase_id).await {
+                    Ok(_) => {
+                        pending_purchases.update(|items| items.retain(|p| p.purchase.id.to_string() != purchase_id));
+                        processing.set(None);
+                        on_confirmation_complete.call(());
+                    }
+                    Err(e) => {
+                        error.set(Some(e.to_string()));
                         processing.set(None);
                     }
                 }
@@ -117,7 +175,7 @@ pub fn PendingConfirmations(
             processing.set(Some(user_punishment_id.clone()));
 
             wasm_bindgen_futures::spawn_local(async move {
-                match ApiClient::approve_punishment_completion(&household_id, &user_punishment_id).await {
+                match api_client().approve_punishment_completion(&household_id, &user_punishment_id).await {
                     Ok(updated) => {
                         // Update local list
                         pending_punishments.update(|items| {
@@ -131,7 +189,7 @@ pub fn PendingConfirmations(
                         on_confirmation_complete.call(());
                     }
                     Err(e) => {
-                        error.set(Some(e));
+                        error.set(Some(e.to_string()));
                         processing.set(None);
                     }
                 }
@@ -146,7 +204,7 @@ pub fn PendingConfirmations(
             processing.set(Some(user_punishment_id.clone()));
 
             wasm_bindgen_futures::spawn_local(async move {
-                match ApiClient::reject_punishment_completion(&household_id, &user_punishment_id).await {
+                match api_client().reject_punishment_completion(&household_id, &user_punishment_id).await {
                     Ok(updated) => {
                         // Update local list
                         pending_punishments.update(|items| {
@@ -159,7 +217,7 @@ pub fn PendingConfirmations(
                         on_confirmation_complete.call(());
                     }
                     Err(e) => {
-                        error.set(Some(e));
+                        error.set(Some(e.to_string()));
                         processing.set(None);
                     }
                 }
@@ -169,6 +227,8 @@ pub fn PendingConfirmations(
 
     let approve_reward = std::rc::Rc::new(approve_reward);
     let reject_reward = std::rc::Rc::new(reject_reward);
+    let approve_purchase = std::rc::Rc::new(approve_purchase);
+    let reject_purchase = std::rc::Rc::new(reject_purchase);
     let approve_punishment = std::rc::Rc::new(approve_punishment);
     let reject_punishment = std::rc::Rc::new(reject_punishment);
 
@@ -176,6 +236,8 @@ pub fn PendingConfirmations(
         {
             let approve_reward = approve_reward.clone();
             let reject_reward = reject_reward.clone();
+            let approve_purchase = approve_purchase.clone();
+            let reject_purchase = reject_purchase.clone();
             let approve_punishment = approve_punishment.clone();
             let reject_punishment = reject_punishment.clone();
             move || {
@@ -184,13 +246,16 @@ pub fn PendingConfirmations(
             let is_feature_disabled = error.get().as_ref().is_some_and(|e| e.contains("not enabled"));
             let is_forbidden = error.get().as_ref().is_some_and(|e| e.to_lowercase().contains("forbidden") || e.contains("permission"));
             let should_hide_error = is_feature_disabled || is_forbidden;
-            if !loading.get() && (pending_rewards.get().is_empty() && pending_punishments.get().is_empty() || should_hide_error)
+            let nothing_pending = pending_rewards.get().is_empty() && pending_purchases.get().is_empty() && pending_punishments.get().is_empty();
+            if !loading.get() && (nothing_pending || should_hide_error)
                 && (error.get().is_none() || should_hide_error) {
                 return ().into_view();
             }
 
             let approve_reward = approve_reward.clone();
             let reject_reward = reject_reward.clone();
+            let approve_purchase = approve_purchase.clone();
+            let reject_purchase = reject_purchase.clone();
             let approve_punishment = approve_punishment.clone();
             let reject_punishment = reject_punishment.clone();
             view! {
@@ -208,11 +273,15 @@ pub fn PendingConfirmations(
                             view! { <div class="empty-state"><p>{i18n_stored.get_value().t("common.loading")}</p></div> }.into_view()
                         } else {
                             let rewards = pending_rewards.get();
+                            let purchases = pending_purchases.get();
                             let punishments = pending_punishments.get();
                         let reward_label = i18n_stored.get_value().t("pending_confirmations.reward");
                         let punishment_label = i18n_stored.get_value().t("pending_confirmations.punishment");
                         let redemption_by_label = i18n_stored.get_value().t("pending_confirmations.redemption_requested_by");
                         let completion_by_label = i18n_stored.get_value().t("pending_confirmations.completion_marked_by");
+                        let purchase_label = i18n_stored.get_value().t("pending_confirmations.purchase");
+                        let purchase_by_label = i18n_stored.get_value().t("pending_confirmations.purchase_requested_by");
+                        let points_label = i18n_stored.get_value().t("common.points");
                         let approve_label = i18n_stored.get_value().t("pending_confirmations.approve");
                         let reject_label = i18n_stored.get_value().t("pending_confirmations.reject");
 
@@ -278,6 +347,63 @@ pub fn PendingConfirmations(
                                     }
                                 }).collect_view()}
 
+                                // Reward purchases awaiting approval
+                                {purchases.into_iter().map(|item| {
+                                    let id = item.purchase.id.to_string();
+                                    let id_for_approve = id.clone();
+                                    let id_for_reject = id.clone();
+                                    let id_check_1 = id.clone();
+                                    let id_check_2 = id.clone();
+                                    let id_check_3 = id.clone();
+                                    let id_check_4 = id.clone();
+                                    let approve = approve_purchase.clone();
+                                    let reject = reject_purchase.clone();
+                                    let purchase_label = purchase_label.clone();
+                                    let purchase_by_label = purchase_by_label.clone();
+                                    let points_label = points_label.clone();
+                                    let approve_label = approve_label.clone();
+                                    let reject_label = reject_label.clone();
+
+                                    view! {
+                                        <div class="pending-review-item">
+                                            <div class="pending-review-content">
+                                                <div class="pending-review-task">
+                                                    {purchase_label.clone()} " " {item.reward.name.clone()}
+                                                    {format!(" ({} {})", item.purchase.point_cost, points_label)}
+                                                </div>
+                                                <div class="pending-review-meta">
+                                                    {purchase_by_label.clone()} " "
+                                                    <strong>{item.user.username.clone()}</strong>
+                                                </div>
+                                            </div>
+                                            <div class="pending-review-actions">
+                                                <button
+                                                    class="btn btn-success"
+                                                    style="padding: 0.25rem 0.75rem; font-size: 0.875rem;"
+                                                    disabled=move || processing.get() == Some(id_check_1.clone())
+                                                    on:click=move |_| approve(id_for_approve.clone())
+                                                >
+                                                    {
+                                                        let approve_label = approve_label.clone();
+                                                        move || if processing.get() == Some(id_check_2.clone()) { "...".to_string() } else { approve_label.clone() }
+                                                    }
+                                                </button>
+                                                <button
+                                                    class="btn btn-danger"
+                                                    style="padding: 0.25rem 0.75rem; font-size: 0.875rem;"
+                                                    disabled=move || processing.get() == Some(id_check_3.clone())
+                                                    on:click=move |_| reject(id_for_reject.clone())
+                                                >
+                                                    {
+                                                        let reject_label = reject_label.clone();
+                                                        move || if processing.get() == Some(id_check_4.clone()) { "...".to_string() } else { reject_label.clone() }
+                                                    }
+                                                </button>
+                                            </div>
+                                        </div>
+                                    }
+                                }).collect_view()}
+
                                 // Pending punishment completions
                                 {punishments.into_iter().map(|item| {
                                     let id = item.user_punishment.id.to_string();
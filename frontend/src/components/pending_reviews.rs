@@ -1,7 +1,9 @@
 use leptos::*;
-use shared::PendingReview;
+use shared::{PendingReview, ReviewerType};
+use uuid::Uuid;
 
-use crate::api::ApiClient;
+use crate::api::api_client;
+use crate::components::completion_photos::CompletionPhoto;
 use crate::i18n::use_i18n;
 use crate::utils::create_remove_action_handler;
 
@@ -17,6 +19,7 @@ pub fn PendingReviews(
     let loading = create_rw_signal(true);
     let error = create_rw_signal(Option::<String>::None);
     let processing = create_rw_signal(Option::<String>::None); // Track which completion is being processed
+    let current_user_id = create_rw_signal(Option::<Uuid>::None);
 
     // Fetch pending reviews
     {
@@ -24,13 +27,16 @@ pub fn PendingReviews(
         create_effect(move |_| {
             let household_id = household_id.clone();
             wasm_bindgen_futures::spawn_local(async move {
-                match ApiClient::get_pending_reviews(&household_id).await {
+                if let Ok(user) = api_client().get_current_user().await {
+                    current_user_id.set(Some(user.id));
+                }
+                match api_client().get_pending_reviews(&household_id).await {
                     Ok(data) => {
                         reviews.set(data);
                         loading.set(false);
                     }
                     Err(e) => {
-                        error.set(Some(e));
+                        error.set(Some(e.to_string()));
                         loading.set(false);
                     }
                 }
@@ -38,6 +44,7 @@ pub fn PendingReviews(
         });
     }
 
+    let household_id_for_photos = store_value(household_id.clone());
     let id_matcher = |r: &PendingReview| r.completion.id.to_string();
 
     let approve_completion = create_remove_action_handler(
@@ -48,7 +55,7 @@ pub fn PendingReviews(
         on_review_complete,
         id_matcher,
         |hid, cid| async move {
-            ApiClient::approve_completion(&hid, &cid).await.map(|_| ())
+            api_client().approve_completion(&hid, &cid).await.map(|_| ()).map_err(String::from)
         },
     );
 
@@ -60,7 +67,7 @@ pub fn PendingReviews(
         on_review_complete,
         id_matcher,
         |hid, cid| async move {
-            ApiClient::reject_completion(&hid, &cid).await.map(|_| ())
+            api_client().reject_completion(&hid, &cid).await.map(|_| ()).map_err(String::from)
         },
     );
 
@@ -111,6 +118,26 @@ pub fn PendingReviews(
                             let completed_by_label = completed_by_label.clone();
                             let approve_label = approve_label.clone();
                             let reject_label = reject_label.clone();
+                            // Reviews of a member who left or completed the task fall back to
+                            // the other reviewers, who should not read them as delegated
+                            let reviewer_label = match review.task.reviewer_type {
+                                ReviewerType::AnyReviewer => None,
+                                ReviewerType::Member if review.task.reviewer_id.is_some() && review.task.reviewer_id == current_user_id.get() => {
+                                    Some(i18n_stored.get_value().t("pending_reviews.reviewer_you"))
+                                }
+                                ReviewerType::Member => None,
+                                ReviewerType::AnyoneExceptCompleter => Some(i18n_stored.get_value().t("pending_reviews.reviewer_except_completer")),
+                            };
+                            let photos = (!review.attachments.is_empty()).then(|| {
+                                let household_id = household_id_for_photos.get_value();
+                                view! {
+                                    <div class="pending-review-photos">
+                                        {review.attachments.clone().into_iter().map(|attachment| view! {
+                                            <CompletionPhoto household_id=household_id.clone() attachment=attachment />
+                                        }).collect_view()}
+                                    </div>
+                                }
+                            });
 
                             view! {
                                 <div class="pending-review-item">
@@ -121,6 +148,10 @@ pub fn PendingReviews(
                                             <strong>{review.user.username.clone()}</strong>
                                             " - "{completed_at}
                                         </div>
+                                        {reviewer_label.map(|label| view! {
+                                            <div class="pending-review-meta">{label}</div>
+                                        })}
+                                        {photos}
                                     </div>
                                     <div class="pending-review-actions">
                                         <button
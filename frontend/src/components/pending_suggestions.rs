@@ -1,7 +1,7 @@
 use leptos::*;
 use shared::Task;
 
-use crate::api::ApiClient;
+use crate::api::api_client;
 use crate::i18n::use_i18n;
 use crate::utils::create_remove_action_handler;
 
@@ -27,13 +27,13 @@ pub fn PendingSuggestions(
         create_effect(move |_| {
             let household_id = household_id.clone();
             wasm_bindgen_futures::spawn_local(async move {
-                match ApiClient::list_suggestions(&household_id).await {
+                match api_client().list_suggestions(&household_id).await {
                     Ok(data) => {
                         suggestions.set(data);
                         loading.set(false);
                     }
                     Err(e) => {
-                        error.set(Some(e));
+                        error.set(Some(e.to_string()));
                         loading.set(false);
                     }
                 }
@@ -51,7 +51,7 @@ pub fn PendingSuggestions(
         on_suggestion_handled,
         id_matcher,
         |hid, tid| async move {
-            ApiClient::approve_suggestion(&hid, &tid).await.map(|_| ())
+            api_client().approve_suggestion(&hid, &tid).await.map(|_| ()).map_err(String::from)
         },
     );
 
@@ -63,7 +63,7 @@ pub fn PendingSuggestions(
         on_suggestion_handled,
         id_matcher,
         |hid, tid| async move {
-            ApiClient::deny_suggestion(&hid, &tid).await.map(|_| ())
+            api_client().deny_suggestion(&hid, &tid).await.map(|_| ()).map_err(String::from)
         },
     );
 
@@ -0,0 +1,149 @@
+use leptos::*;
+use shared::{CreatePointGoalRequest, PointGoalProgress, PointGoalStatus};
+
+use crate::api::api_client;
+use crate::components::progress_bar::{ProgressBar, ProgressVariant};
+use crate::i18n::use_i18n;
+
+/// Dashboard card with the current member's point goals as progress bars.
+/// `goals` is owned by the page so pushed progress updates can be applied to it.
+#[component]
+pub fn PointGoalsCard(
+    #[prop(into)] household_id: String,
+    #[prop(into)] user_id: String,
+    goals: RwSignal<Vec<PointGoalProgress>>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+    let household_id = store_value(household_id);
+    let user_id = store_value(user_id);
+
+    let error = create_rw_signal(Option::<String>::None);
+    let saving = create_rw_signal(false);
+    let title = create_rw_signal(String::new());
+    let target = create_rw_signal(String::new());
+    let deadline = create_rw_signal(String::new());
+
+    wasm_bindgen_futures::spawn_local(async move {
+        match api_client().list_point_goals(&household_id.get_value(), &user_id.get_value()).await {
+            Ok(list) => goals.set(list),
+            Err(e) => error.set(Some(e.to_string())),
+        }
+    });
+
+    let on_create = move |ev: web_sys::SubmitEvent| {
+        ev.prevent_default();
+        let (Ok(target_points), Ok(deadline)) = (
+            target.get().trim().parse::<i64>(),
+            chrono::NaiveDate::parse_from_str(&deadline.get(), "%Y-%m-%d"),
+        ) else {
+            error.set(Some(i18n_stored.get_value().t("goals.invalid")));
+            return;
+        };
+        saving.set(true);
+        error.set(None);
+
+        let request = CreatePointGoalRequest {
+            title: title.get(),
+            target_points,
+            deadline,
+        };
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().create_point_goal(&household_id.get_value(), &user_id.get_value(), request).await {
+                Ok(goal) => {
+                    goals.update(|list| list.push(goal));
+                    title.set(String::new());
+                    target.set(String::new());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            saving.set(false);
+        });
+    };
+
+    let on_delete = move |goal_id: String| {
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().delete_point_goal(&household_id.get_value(), &user_id.get_value(), &goal_id).await {
+                Ok(()) => goals.update(|list| list.retain(|g| g.goal.id.to_string() != goal_id)),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    view! {
+        <div class="card">
+            <div class="card-header">
+                <h3 class="card-title">{i18n_stored.get_value().t("goals.title")}</h3>
+            </div>
+            <div style="padding: 0 1rem 1rem;">
+                {move || error.get().map(|e| view! {
+                    <div class="alert alert-error" style="margin-bottom: 1rem;">{e}</div>
+                })}
+
+                {move || {
+                    let i18n = i18n_stored.get_value();
+                    let list = goals.get();
+                    if list.is_empty() {
+                        return view! { <p class="empty-state">{i18n.t("goals.empty")}</p> }.into_view();
+                    }
+                    list.into_iter().map(|progress| {
+                        let goal_id = progress.goal.id.to_string();
+                        let percent = progress.percent();
+                        let (variant, status) = match progress.status {
+                            PointGoalStatus::Active => (ProgressVariant::Primary, i18n.t_with("goals.deadline", &[("date", &progress.goal.deadline.format("%d.%m.%Y").to_string())])),
+                            PointGoalStatus::Completed => (ProgressVariant::Success, i18n.t("goals.completed")),
+                            PointGoalStatus::Expired => (ProgressVariant::Danger, i18n.t("goals.expired")),
+                        };
+                        view! {
+                            <div style="margin-bottom: 0.75rem;">
+                                <div style="display: flex; justify-content: space-between; align-items: center; font-size: 0.875rem;">
+                                    <strong>{progress.goal.title.clone()}</strong>
+                                    <button class="btn btn-outline btn-sm" on:click=move |_| on_delete(goal_id.clone())>"×"</button>
+                                </div>
+                                <ProgressBar value=percent as f32 variant=variant />
+                                <div style="display: flex; justify-content: space-between; font-size: 0.75rem; color: var(--text-muted);">
+                                    <span>{progress.earned_points} " / " {progress.goal.target_points} " pts"</span>
+                                    <span>{status}</span>
+                                </div>
+                            </div>
+                        }
+                    }).collect_view()
+                }}
+
+                <form on:submit=on_create style="margin-top: 1rem;">
+                    <div class="form-group">
+                        <input
+                            type="text"
+                            class="form-input"
+                            required
+                            placeholder=i18n_stored.get_value().t("goals.name_placeholder")
+                            prop:value=move || title.get()
+                            on:input=move |ev| title.set(event_target_value(&ev))
+                        />
+                    </div>
+                    <div style="display: flex; gap: 0.5rem;">
+                        <input
+                            type="number"
+                            class="form-input"
+                            min="1"
+                            required
+                            placeholder=i18n_stored.get_value().t("goals.target")
+                            prop:value=move || target.get()
+                            on:input=move |ev| target.set(event_target_value(&ev))
+                        />
+                        <input
+                            type="date"
+                            class="form-input"
+                            required
+                            prop:value=move || deadline.get()
+                            on:input=move |ev| deadline.set(event_target_value(&ev))
+                        />
+                    </div>
+                    <button type="submit" class="btn btn-primary btn-sm" style="margin-top: 0.5rem;" disabled=move || saving.get()>
+                        {i18n_stored.get_value().t("goals.add")}
+                    </button>
+                </form>
+            </div>
+        </div>
+    }
+}
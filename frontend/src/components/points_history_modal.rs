@@ -0,0 +1,150 @@
+use leptos::*;
+use shared::{PointTransaction, PointTransactionType};
+
+use crate::api::api_client;
+use crate::components::household_layout::HouseholdContext;
+use crate::components::modal::Modal;
+use crate::i18n::use_i18n;
+use crate::utils::format_datetime;
+
+const PAGE_SIZE: i64 = 20;
+
+/// Translation key for a transaction type
+fn transaction_type_key(transaction_type: PointTransactionType) -> String {
+    format!("points_history.type.{}", transaction_type.as_str())
+}
+
+/// Format a signed point amount, e.g. "+5" or "-3"
+fn format_amount(amount: i64) -> String {
+    if amount > 0 {
+        format!("+{}", amount)
+    } else {
+        amount.to_string()
+    }
+}
+
+/// Modal listing where a member's points came from
+#[component]
+pub fn PointsHistoryModal(
+    household_id: String,
+    user_id: String,
+    username: String,
+    #[prop(into)] on_close: Callback<()>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let transactions = create_rw_signal(Vec::<PointTransaction>::new());
+    let total = create_rw_signal(0i64);
+    let loading = create_rw_signal(true);
+    let error = create_rw_signal(Option::<String>::None);
+
+    let timezone = use_context::<HouseholdContext>()
+        .and_then(|ctx| ctx.settings.get_untracked())
+        .map(|s| s.timezone)
+        .unwrap_or_else(|| "UTC".to_string());
+    let timezone = store_value(timezone);
+
+    let household_id = store_value(household_id);
+    let user_id = store_value(user_id);
+
+    let load_page = move || {
+        loading.set(true);
+        let offset = transactions.with_untracked(|t| t.len() as i64);
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().get_points_history(&household_id.get_value(), &user_id.get_value(), PAGE_SIZE, offset)
+                .await
+            {
+                Ok(page) => {
+                    total.set(page.total);
+                    transactions.update(|t| t.extend(page.transactions));
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            loading.set(false);
+        });
+    };
+
+    load_page();
+
+    let title = format!("{} – {}", i18n_stored.get_value().t("points_history.title"), username);
+
+    view! {
+        <Modal title=title on_close=on_close>
+            {move || error.get().map(|e| view! { <div class="alert alert-error">{e}</div> })}
+
+            {move || {
+                let i18n = i18n_stored.get_value();
+                let list = transactions.get();
+                if list.is_empty() && !loading.get() {
+                    view! { <p class="empty-state">{i18n.t("points_history.empty")}</p> }.into_view()
+                } else {
+                    view! {
+                        <ul class="points-history-list">
+                            {list.into_iter().map(|tx| {
+                                let amount_class = if tx.amount >= 0 {
+                                    "points-history-amount positive"
+                                } else {
+                                    "points-history-amount negative"
+                                };
+                                view! {
+                                    <li class="points-history-item">
+                                        <div class="points-history-details">
+                                            <div class="points-history-type">{i18n.t(&transaction_type_key(tx.transaction_type))}</div>
+                                            {tx.description.map(|d| view! {
+                                                <div class="points-history-description">{d}</div>
+                                            })}
+                                            <div class="points-history-time">
+                                                {format_datetime(tx.created_at, &timezone.get_value())}
+                                            </div>
+                                        </div>
+                                        <div class="points-history-values">
+                                            <span class=amount_class>{format_amount(tx.amount)}</span>
+                                            <span class="points-history-balance">
+                                                {i18n.t("points_history.balance")} " " {tx.balance_after}
+                                            </span>
+                                        </div>
+                                    </li>
+                                }
+                            }).collect_view()}
+                        </ul>
+                    }.into_view()
+                }
+            }}
+
+            <div class="modal-footer">
+                <Show when=move || loading.get() fallback=|| ()>
+                    <span class="loading">{move || i18n_stored.get_value().t("common.loading")}</span>
+                </Show>
+                <Show
+                    when=move || !loading.get() && (transactions.with(|t| t.len()) as i64) < total.get()
+                    fallback=|| ()
+                >
+                    <button type="button" class="btn btn-outline" on:click=move |_| load_page()>
+                        {move || i18n_stored.get_value().t("points_history.load_more")}
+                    </button>
+                </Show>
+            </div>
+        </Modal>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_amount() {
+        assert_eq!(format_amount(5), "+5");
+        assert_eq!(format_amount(-3), "-3");
+        assert_eq!(format_amount(0), "0");
+    }
+
+    #[test]
+    fn test_transaction_type_key() {
+        assert_eq!(
+            transaction_type_key(PointTransactionType::RewardPurchased),
+            "points_history.type.reward_purchased"
+        );
+    }
+}
@@ -1,8 +1,9 @@
 use leptos::*;
-use shared::{CreatePunishmentRequest, Punishment, PunishmentType, UpdatePunishmentRequest};
+use std::collections::HashMap;
+use shared::{CreatePunishmentRequest, Punishment, PunishmentType, UpdatePunishmentRequest, MAX_OPTION_WEIGHT};
 use uuid::Uuid;
 
-use crate::api::ApiClient;
+use crate::api::api_client;
 use crate::i18n::use_i18n;
 
 #[component]
@@ -29,6 +30,10 @@ pub fn PunishmentModal(
     let requires_confirmation = create_rw_signal(punishment.as_ref().map(|p| p.requires_confirmation).unwrap_or(false));
     let punishment_type = create_rw_signal(punishment.as_ref().map(|p| p.punishment_type).unwrap_or_default());
     let selected_options = create_rw_signal(Vec::<Uuid>::new());
+    let no_repeat_picks = create_rw_signal(punishment.as_ref().map(|p| p.no_repeat_picks).unwrap_or(0).to_string());
+    // Weights as edited and as last saved, so only changed ones are sent
+    let option_weights = create_rw_signal(HashMap::<Uuid, i32>::new());
+    let saved_weights = create_rw_signal(HashMap::<Uuid, i32>::new());
 
     let punishment_id = punishment.as_ref().map(|p| p.id.to_string());
 
@@ -39,8 +44,11 @@ pub fn PunishmentModal(
             let hid = household_id.clone();
             options_loading.set(true);
             wasm_bindgen_futures::spawn_local(async move {
-                if let Ok(options) = ApiClient::get_punishment_options(&hid, &pid).await {
-                    selected_options.set(options.iter().map(|p| p.id).collect());
+                if let Ok(options) = api_client().get_punishment_options(&hid, &pid).await {
+                    selected_options.set(options.iter().map(|o| o.punishment.id).collect());
+                    let weights: HashMap<Uuid, i32> = options.iter().map(|o| (o.punishment.id, o.weight)).collect();
+                    option_weights.set(weights.clone());
+                    saved_weights.set(weights);
                 }
                 options_loading.set(false);
             });
@@ -81,15 +89,31 @@ pub fn PunishmentModal(
                         requires_confirmation: Some(requires_confirmation.get()),
                         punishment_type: Some(punishment_type.get()),
                         option_ids,
+                        no_repeat_picks: no_repeat_picks.get().parse().ok(),
                     };
 
-                    match ApiClient::update_punishment(&household_id, &punishment_id, request).await {
+                    match api_client().update_punishment(&household_id, &punishment_id, request).await {
                         Ok(updated_punishment) => {
+                            let weights_saved = if updated_punishment.punishment_type.is_random_choice() {
+                                save_option_weights(
+                                    &household_id,
+                                    &updated_punishment.id.to_string(),
+                                    &selected_options.get(),
+                                    &option_weights.get(),
+                                    &saved_weights.get(),
+                                )
+                                .await
+                            } else {
+                                Ok(())
+                            };
                             saving.set(false);
-                            on_save.call(updated_punishment);
+                            match weights_saved {
+                                Ok(()) => on_save.call(updated_punishment),
+                                Err(e) => error.set(Some(e)),
+                            }
                         }
                         Err(e) => {
-                            error.set(Some(e));
+                            error.set(Some(e.to_string()));
                             saving.set(false);
                         }
                     }
@@ -107,15 +131,31 @@ pub fn PunishmentModal(
                         requires_confirmation: Some(requires_confirmation.get()),
                         punishment_type: Some(punishment_type.get()),
                         option_ids,
+                        no_repeat_picks: no_repeat_picks.get().parse().ok(),
                     };
 
-                    match ApiClient::create_punishment(&household_id, request).await {
+                    match api_client().create_punishment(&household_id, request).await {
                         Ok(created_punishment) => {
+                            let weights_saved = if created_punishment.punishment_type.is_random_choice() {
+                                save_option_weights(
+                                    &household_id,
+                                    &created_punishment.id.to_string(),
+                                    &selected_options.get(),
+                                    &option_weights.get(),
+                                    &saved_weights.get(),
+                                )
+                                .await
+                            } else {
+                                Ok(())
+                            };
                             saving.set(false);
-                            on_save.call(created_punishment);
+                            match weights_saved {
+                                Ok(()) => on_save.call(created_punishment),
+                                Err(e) => error.set(Some(e)),
+                            }
                         }
                         Err(e) => {
-                            error.set(Some(e));
+                            error.set(Some(e.to_string()));
                             saving.set(false);
                         }
                     }
@@ -227,15 +267,37 @@ pub fn PunishmentModal(
                                                 } else {
                                                     String::new()
                                                 };
+                                                let weight = move || option_weights.get().get(&option_id).copied().unwrap_or(1);
                                                 view! {
-                                                    <label style="display: flex; align-items: center; gap: 0.5rem; padding: 0.25rem 0; cursor: pointer;">
-                                                        <input
-                                                            type="checkbox"
-                                                            prop:checked=is_selected
-                                                            on:change=toggle
-                                                        />
-                                                        <span>{p.name.clone()}{random_badge}</span>
-                                                    </label>
+                                                    <div style="display: flex; align-items: center; gap: 0.5rem;">
+                                                        <label style="display: flex; flex: 1; align-items: center; gap: 0.5rem; padding: 0.25rem 0; cursor: pointer;">
+                                                            <input
+                                                                type="checkbox"
+                                                                prop:checked=is_selected
+                                                                on:change=toggle
+                                                            />
+                                                            <span>{p.name.clone()}{random_badge}</span>
+                                                        </label>
+                                                        <Show when=is_selected fallback=|| ()>
+                                                            <input
+                                                                type="number"
+                                                                class="form-input"
+                                                                style="width: 4.5rem;"
+                                                                min="1"
+                                                                max=MAX_OPTION_WEIGHT
+                                                                title=i18n_stored.get_value().t("punishments.weight_title")
+                                                                prop:value=move || weight().to_string()
+                                                                on:input=move |ev| {
+                                                                    if let Ok(value) = event_target_value(&ev).parse::<i32>() {
+                                                                        let value = value.clamp(1, MAX_OPTION_WEIGHT);
+                                                                        option_weights.update(|weights| {
+                                                                            weights.insert(option_id, value);
+                                                                        });
+                                                                    }
+                                                                }
+                                                            />
+                                                        </Show>
+                                                    </div>
                                                 }
                                             })
                                             .collect_view()
@@ -245,6 +307,18 @@ pub fn PunishmentModal(
                                     {move || format!("{} {}", selected_options.get().len(), i18n_stored.get_value().t("punishments.selected"))}
                                 </p>
                             </div>
+
+                            <div class="form-group">
+                                <label class="form-label" for="punishment-no-repeat">{i18n_stored.get_value().t("punishments.no_repeat_picks_label")}</label>
+                                <input
+                                    type="number"
+                                    id="punishment-no-repeat"
+                                    class="form-input"
+                                    min="0"
+                                    prop:value=move || no_repeat_picks.get()
+                                    on:input=move |ev| no_repeat_picks.set(event_target_value(&ev))
+                                />
+                            </div>
                         </Show>
                     </div>
 
@@ -271,6 +345,23 @@ pub fn PunishmentModal(
     }
 }
 
+/// Send the option weights that differ from the saved ones; new options start at weight 1
+async fn save_option_weights(
+    household_id: &str,
+    punishment_id: &str,
+    selected: &[Uuid],
+    weights: &HashMap<Uuid, i32>,
+    saved: &HashMap<Uuid, i32>,
+) -> Result<(), String> {
+    for option_id in selected {
+        let weight = weights.get(option_id).copied().unwrap_or(1);
+        if weight != saved.get(option_id).copied().unwrap_or(1) {
+            api_client().update_punishment_option_weight(household_id, punishment_id, &option_id.to_string(), weight).await?;
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use wasm_bindgen_test::*;
@@ -1,7 +1,7 @@
 use leptos::*;
-use shared::{MemberWithUser, Punishment, Reward, Task, TaskCategory};
+use shared::{HabitType, MemberWithUser, Punishment, Reward, Task, TaskCategory};
 
-use crate::api::ApiClient;
+use crate::api::api_client;
 use crate::components::household_picker_modal::{EligibleHousehold, HouseholdPickerModal, TaskAction};
 use crate::components::task_modal::TaskModal;
 use crate::i18n::use_i18n;
@@ -41,8 +41,8 @@ pub fn QuickTaskFab() -> impl IntoView {
 
         wasm_bindgen_futures::spawn_local(async move {
             // Fetch current user and all households
-            let user_result = ApiClient::get_current_user().await;
-            let households_result = ApiClient::list_households().await;
+            let user_result = api_client().get_current_user().await;
+            let households_result = api_client().list_households().await;
 
             let (user, households) = match (user_result, households_result) {
                 (Ok(u), Ok(h)) => (u, h),
@@ -59,8 +59,8 @@ pub fn QuickTaskFab() -> impl IntoView {
                 let household_id = household.id.to_string();
 
                 // Fetch settings and members in parallel
-                let settings_result = ApiClient::get_household_settings(&household_id).await;
-                let members_result = ApiClient::list_members(&household_id).await;
+                let settings_result = api_client().get_household_settings(&household_id).await;
+                let members_result = api_client().list_members(&household_id).await;
 
                 if let (Ok(settings), Ok(members)) = (settings_result, members_result) {
                     // Find current user's role in this household
@@ -186,8 +186,10 @@ pub fn QuickTaskFab() -> impl IntoView {
                         .filter_assignable_members(data.members.clone());
 
                     // Extract defaults from household settings
-                    let default_points_reward = eh.settings.default_points_reward;
-                    let default_points_penalty = eh.settings.default_points_penalty;
+                    let (default_points_reward, default_points_penalty) =
+                        eh.settings.default_points_for(HabitType::Good);
+                    let default_allow_exceed_target = eh.settings.default_allow_exceed_target;
+                    let default_requires_review = eh.settings.default_requires_review_for(default_points_reward);
                     let default_rewards: Vec<(String, i32)> = eh.settings.default_rewards.iter()
                         .map(|r| (r.reward.id.to_string(), r.amount))
                         .collect();
@@ -208,6 +210,8 @@ pub fn QuickTaskFab() -> impl IntoView {
                             is_suggestion=is_suggestion
                             default_points_reward=default_points_reward
                             default_points_penalty=default_points_penalty
+                            default_allow_exceed_target=default_allow_exceed_target
+                            default_requires_review=default_requires_review
                             default_rewards=default_rewards
                             default_punishments=default_punishments
                             on_close=on_task_modal_close
@@ -232,10 +236,10 @@ fn load_household_data_and_open_modal(
         let id = household_id;
 
         // Fetch all required data
-        let members = ApiClient::list_members(&id).await.unwrap_or_default();
-        let rewards = ApiClient::list_rewards(&id).await.unwrap_or_default();
-        let punishments = ApiClient::list_punishments(&id).await.unwrap_or_default();
-        let categories = ApiClient::list_categories(&id).await.unwrap_or_default();
+        let members = api_client().list_members(&id).await.unwrap_or_default();
+        let rewards = api_client().list_rewards(&id).await.unwrap_or_default();
+        let punishments = api_client().list_punishments(&id).await.unwrap_or_default();
+        let categories = api_client().list_categories(&id).await.unwrap_or_default();
 
         let data = HouseholdData {
             members,
@@ -354,6 +358,8 @@ mod tests {
                 role: Role::Owner,
                 points: 0,
                 joined_at: chrono::Utc::now(),
+                vacation_start: None,
+                vacation_end: None,
             },
             user: User {
                 id: uuid::Uuid::new_v4(),
@@ -371,6 +377,8 @@ mod tests {
                 role: Role::Member,
                 points: 0,
                 joined_at: chrono::Utc::now(),
+                vacation_start: None,
+                vacation_end: None,
             },
             user: User {
                 id: uuid::Uuid::new_v4(),
@@ -402,6 +410,8 @@ mod tests {
                 role: Role::Owner,
                 points: 0,
                 joined_at: chrono::Utc::now(),
+                vacation_start: None,
+                vacation_end: None,
             },
             user: User {
                 id: uuid::Uuid::new_v4(),
@@ -419,6 +429,8 @@ mod tests {
                 role: Role::Member,
                 points: 0,
                 joined_at: chrono::Utc::now(),
+                vacation_start: None,
+                vacation_end: None,
             },
             user: User {
                 id: uuid::Uuid::new_v4(),
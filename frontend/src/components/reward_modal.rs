@@ -1,8 +1,9 @@
 use leptos::*;
-use shared::{CreateRewardRequest, Reward, RewardType, UpdateRewardRequest};
+use std::collections::HashMap;
+use shared::{CreateRewardRequest, Reward, RewardType, UpdateRewardRequest, MAX_OPTION_WEIGHT};
 use uuid::Uuid;
 
-use crate::api::ApiClient;
+use crate::api::api_client;
 use crate::i18n::use_i18n;
 
 #[component]
@@ -36,6 +37,10 @@ pub fn RewardModal(
     let requires_confirmation = create_rw_signal(reward.as_ref().map(|r| r.requires_confirmation).unwrap_or(false));
     let reward_type = create_rw_signal(reward.as_ref().map(|r| r.reward_type).unwrap_or_default());
     let selected_options = create_rw_signal(Vec::<Uuid>::new());
+    let no_repeat_picks = create_rw_signal(reward.as_ref().map(|r| r.no_repeat_picks).unwrap_or(0).to_string());
+    // Weights as edited and as last saved, so only changed ones are sent
+    let option_weights = create_rw_signal(HashMap::<Uuid, i32>::new());
+    let saved_weights = create_rw_signal(HashMap::<Uuid, i32>::new());
 
     let reward_id = reward.as_ref().map(|r| r.id.to_string());
 
@@ -46,8 +51,11 @@ pub fn RewardModal(
             let hid = household_id.clone();
             options_loading.set(true);
             wasm_bindgen_futures::spawn_local(async move {
-                if let Ok(options) = ApiClient::get_reward_options(&hid, &rid).await {
-                    selected_options.set(options.iter().map(|r| r.id).collect());
+                if let Ok(options) = api_client().get_reward_options(&hid, &rid).await {
+                    selected_options.set(options.iter().map(|o| o.reward.id).collect());
+                    let weights: HashMap<Uuid, i32> = options.iter().map(|o| (o.reward.id, o.weight)).collect();
+                    option_weights.set(weights.clone());
+                    saved_weights.set(weights);
                 }
                 options_loading.set(false);
             });
@@ -92,15 +100,31 @@ pub fn RewardModal(
                         requires_confirmation: Some(requires_confirmation.get()),
                         reward_type: Some(reward_type.get()),
                         option_ids,
+                        no_repeat_picks: no_repeat_picks.get().parse().ok(),
                     };
 
-                    match ApiClient::update_reward(&household_id, &reward_id, request).await {
+                    match api_client().update_reward(&household_id, &reward_id, request).await {
                         Ok(updated_reward) => {
+                            let weights_saved = if updated_reward.reward_type.is_random_choice() {
+                                save_option_weights(
+                                    &household_id,
+                                    &updated_reward.id.to_string(),
+                                    &selected_options.get(),
+                                    &option_weights.get(),
+                                    &saved_weights.get(),
+                                )
+                                .await
+                            } else {
+                                Ok(())
+                            };
                             saving.set(false);
-                            on_save.call(updated_reward);
+                            match weights_saved {
+                                Ok(()) => on_save.call(updated_reward),
+                                Err(e) => error.set(Some(e)),
+                            }
                         }
                         Err(e) => {
-                            error.set(Some(e));
+                            error.set(Some(e.to_string()));
                             saving.set(false);
                         }
                     }
@@ -120,15 +144,31 @@ pub fn RewardModal(
                         requires_confirmation: Some(requires_confirmation.get()),
                         reward_type: Some(reward_type.get()),
                         option_ids,
+                        no_repeat_picks: no_repeat_picks.get().parse().ok(),
                     };
 
-                    match ApiClient::create_reward(&household_id, request).await {
+                    match api_client().create_reward(&household_id, request).await {
                         Ok(created_reward) => {
+                            let weights_saved = if created_reward.reward_type.is_random_choice() {
+                                save_option_weights(
+                                    &household_id,
+                                    &created_reward.id.to_string(),
+                                    &selected_options.get(),
+                                    &option_weights.get(),
+                                    &saved_weights.get(),
+                                )
+                                .await
+                            } else {
+                                Ok(())
+                            };
                             saving.set(false);
-                            on_save.call(created_reward);
+                            match weights_saved {
+                                Ok(()) => on_save.call(created_reward),
+                                Err(e) => error.set(Some(e)),
+                            }
                         }
                         Err(e) => {
-                            error.set(Some(e));
+                            error.set(Some(e.to_string()));
                             saving.set(false);
                         }
                     }
@@ -266,15 +306,37 @@ pub fn RewardModal(
                                                 } else {
                                                     String::new()
                                                 };
+                                                let weight = move || option_weights.get().get(&option_id).copied().unwrap_or(1);
                                                 view! {
-                                                    <label style="display: flex; align-items: center; gap: 0.5rem; padding: 0.25rem 0; cursor: pointer;">
-                                                        <input
-                                                            type="checkbox"
-                                                            prop:checked=is_selected
-                                                            on:change=toggle
-                                                        />
-                                                        <span>{r.name.clone()}{random_badge}</span>
-                                                    </label>
+                                                    <div style="display: flex; align-items: center; gap: 0.5rem;">
+                                                        <label style="display: flex; flex: 1; align-items: center; gap: 0.5rem; padding: 0.25rem 0; cursor: pointer;">
+                                                            <input
+                                                                type="checkbox"
+                                                                prop:checked=is_selected
+                                                                on:change=toggle
+                                                            />
+                                                            <span>{r.name.clone()}{random_badge}</span>
+                                                        </label>
+                                                        <Show when=is_selected fallback=|| ()>
+                                                            <input
+                                                                type="number"
+                                                                class="form-input"
+                                                                style="width: 4.5rem;"
+                                                                min="1"
+                                                                max=MAX_OPTION_WEIGHT
+                                                                title=i18n_stored.get_value().t("rewards.weight_title")
+                                                                prop:value=move || weight().to_string()
+                                                                on:input=move |ev| {
+                                                                    if let Ok(value) = event_target_value(&ev).parse::<i32>() {
+                                                                        let value = value.clamp(1, MAX_OPTION_WEIGHT);
+                                                                        option_weights.update(|weights| {
+                                                                            weights.insert(option_id, value);
+                                                                        });
+                                                                    }
+                                                                }
+                                                            />
+                                                        </Show>
+                                                    </div>
                                                 }
                                             })
                                             .collect_view()
@@ -284,6 +346,18 @@ pub fn RewardModal(
                                     {move || format!("{} {}", selected_options.get().len(), i18n_stored.get_value().t("rewards.selected"))}
                                 </p>
                             </div>
+
+                            <div class="form-group">
+                                <label class="form-label" for="reward-no-repeat">{i18n_stored.get_value().t("rewards.no_repeat_picks_label")}</label>
+                                <input
+                                    type="number"
+                                    id="reward-no-repeat"
+                                    class="form-input"
+                                    min="0"
+                                    prop:value=move || no_repeat_picks.get()
+                                    on:input=move |ev| no_repeat_picks.set(event_target_value(&ev))
+                                />
+                            </div>
                         </Show>
                     </div>
 
@@ -310,6 +384,23 @@ pub fn RewardModal(
     }
 }
 
+/// Send the option weights that differ from the saved ones; new options start at weight 1
+async fn save_option_weights(
+    household_id: &str,
+    reward_id: &str,
+    selected: &[Uuid],
+    weights: &HashMap<Uuid, i32>,
+    saved: &HashMap<Uuid, i32>,
+) -> Result<(), String> {
+    for option_id in selected {
+        let weight = weights.get(option_id).copied().unwrap_or(1);
+        if weight != saved.get(option_id).copied().unwrap_or(1) {
+            api_client().update_reward_option_weight(household_id, reward_id, &option_id.to_string(), weight).await?;
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use wasm_bindgen_test::*;
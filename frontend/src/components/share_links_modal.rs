@@ -0,0 +1,164 @@
+use leptos::*;
+use shared::{CreateShareLinkRequest, ShareLink, ShareResource};
+
+use crate::api::api_client;
+use crate::components::household_layout::HouseholdContext;
+use crate::components::modal::Modal;
+use crate::i18n::use_i18n;
+use crate::utils::format_datetime;
+
+/// Link that opens the read-only page for a share token
+fn share_link_url(origin: &str, token: &str) -> String {
+    format!("{}/shared/{}", origin.trim_end_matches('/'), token)
+}
+
+/// Modal to create and revoke read-only guest links for one page of the household
+#[component]
+pub fn ShareLinksModal(
+    household_id: String,
+    resource: ShareResource,
+    #[prop(into)] on_close: Callback<()>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let links = create_rw_signal(Vec::<ShareLink>::new());
+    let error = create_rw_signal(Option::<String>::None);
+    let saving = create_rw_signal(false);
+    let valid_days = create_rw_signal("7".to_string());
+    let household_id = store_value(household_id);
+
+    let origin = web_sys::window()
+        .and_then(|w| w.location().origin().ok())
+        .unwrap_or_default();
+    let origin = store_value(origin);
+    let timezone = use_context::<HouseholdContext>()
+        .and_then(|ctx| ctx.settings.get_untracked())
+        .map(|s| s.timezone)
+        .unwrap_or_else(|| "UTC".to_string());
+    let timezone = store_value(timezone);
+
+    wasm_bindgen_futures::spawn_local(async move {
+        match api_client().list_share_links(&household_id.get_value()).await {
+            Ok(list) => links.set(list.into_iter().filter(|l| l.resource == resource).collect()),
+            Err(e) => error.set(Some(e.to_string())),
+        }
+    });
+
+    let on_create = move |ev: web_sys::SubmitEvent| {
+        ev.prevent_default();
+        saving.set(true);
+        error.set(None);
+
+        let request = CreateShareLinkRequest {
+            resource,
+            // An empty value means "until revoked"
+            expires_in_days: valid_days.get().parse().ok(),
+        };
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().create_share_link(&household_id.get_value(), request).await {
+                Ok(link) => links.update(|list| list.insert(0, link)),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            saving.set(false);
+        });
+    };
+
+    let on_revoke = move |link_id: String| {
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().revoke_share_link(&household_id.get_value(), &link_id).await {
+                Ok(()) => links.update(|list| list.retain(|l| l.id.to_string() != link_id)),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    view! {
+        <Modal title=i18n_stored.get_value().t("share_links.title") on_close=move |_| on_close.call(())>
+            {move || error.get().map(|e| view! {
+                <div class="alert alert-error" style="margin-bottom: 1rem;">{e}</div>
+            })}
+
+            <p class="form-hint" style="margin-bottom: 1rem;">{i18n_stored.get_value().t("share_links.hint")}</p>
+
+            <form class="invite-code-form" on:submit=on_create>
+                <div class="form-group">
+                    <label class="form-label" for="share-link-valid">{i18n_stored.get_value().t("invite_codes.valid_for")}</label>
+                    <select
+                        id="share-link-valid"
+                        class="form-select"
+                        prop:value=move || valid_days.get()
+                        on:change=move |ev| valid_days.set(event_target_value(&ev))
+                    >
+                        <option value="1">{i18n_stored.get_value().t("invite_codes.valid_day")}</option>
+                        <option value="7">{i18n_stored.get_value().t("invite_codes.valid_week")}</option>
+                        <option value="30">{i18n_stored.get_value().t("invite_codes.valid_month")}</option>
+                        <option value="">{i18n_stored.get_value().t("share_links.until_revoked")}</option>
+                    </select>
+                </div>
+                <button type="submit" class="btn btn-primary" disabled=move || saving.get()>
+                    {i18n_stored.get_value().t("share_links.create")}
+                </button>
+            </form>
+
+            {move || {
+                let i18n = i18n_stored.get_value();
+                let list = links.get();
+                if list.is_empty() {
+                    return view! { <p class="empty-state">{i18n.t("share_links.empty")}</p> }.into_view();
+                }
+                view! {
+                    <ul class="invite-code-list">
+                        {list.into_iter().map(|link| {
+                            let link_id = link.id.to_string();
+                            let url = share_link_url(&origin.get_value(), &link.token);
+                            let expires = match link.expires_at {
+                                Some(at) => i18n.t_with("invite_codes.expires", &[("date", &format_datetime(at, &timezone.get_value()))]),
+                                None => i18n.t("share_links.until_revoked"),
+                            };
+                            view! {
+                                <li class="invite-code-item">
+                                    <input
+                                        type="text"
+                                        class="form-input invite-code-link"
+                                        readonly
+                                        value=url
+                                        on:focus=move |ev| {
+                                            use wasm_bindgen::JsCast;
+                                            if let Some(input) = ev.target().and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok()) {
+                                                input.select();
+                                            }
+                                        }
+                                    />
+                                    <div class="invite-code-meta">
+                                        <span>{expires}</span>
+                                    </div>
+                                    <div class="invite-code-actions">
+                                        <button
+                                            type="button"
+                                            class="btn btn-outline btn-sm"
+                                            on:click=move |_| on_revoke(link_id.clone())
+                                        >
+                                            {i18n.t("invite_codes.revoke")}
+                                        </button>
+                                    </div>
+                                </li>
+                            }
+                        }).collect_view()}
+                    </ul>
+                }.into_view()
+            }}
+        </Modal>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_share_link_url() {
+        assert_eq!(share_link_url("https://home.example/", "abc123"), "https://home.example/shared/abc123");
+    }
+}
@@ -1,7 +1,7 @@
 use leptos::*;
 use shared::HouseholdSettings;
 
-use crate::api::ApiClient;
+use crate::api::api_client;
 use crate::i18n::use_i18n;
 
 /// Solo Mode Banner Component
@@ -83,12 +83,12 @@ pub fn SoloModeBanner(
         error_message.set(None);
 
         spawn_local(async move {
-            match ApiClient::request_solo_mode_exit(&hid).await {
+            match api_client().request_solo_mode_exit(&hid).await {
                 Ok(new_settings) => {
                     settings.set(Some(new_settings));
                 }
                 Err(e) => {
-                    error_message.set(Some(e));
+                    error_message.set(Some(e.to_string()));
                 }
             }
             is_loading.set(false);
@@ -101,12 +101,12 @@ pub fn SoloModeBanner(
         error_message.set(None);
 
         spawn_local(async move {
-            match ApiClient::cancel_solo_mode_exit(&hid).await {
+            match api_client().cancel_solo_mode_exit(&hid).await {
                 Ok(new_settings) => {
                     settings.set(Some(new_settings));
                 }
                 Err(e) => {
-                    error_message.set(Some(e));
+                    error_message.set(Some(e.to_string()));
                 }
             }
             is_loading.set(false);
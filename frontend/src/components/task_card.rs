@@ -68,6 +68,8 @@ pub fn TaskCard(
     let is_target_met = task.is_target_met();
     let can_complete = task.can_complete();
     let is_user_assigned = task.is_user_assigned;
+    let blocked_by = task.blocked_by.join(", ");
+    let claimed_by = task.claimed_by.as_ref().map(|claim| claim.username.clone());
     let task_id = task.task.id.to_string();
     let task_id_for_minus = task_id.clone();
     let task_id_for_dashboard = task_id.clone();
@@ -207,6 +209,16 @@ pub fn TaskCard(
                     {due_display}
                     {streak_display}
                 </div>
+                {if blocked_by.is_empty() {
+                    ().into_view()
+                } else {
+                    let hint = i18n_stored.get_value().t_with("tasks.blocked_by", &[("tasks", &blocked_by)]);
+                    view! { <div class="task-blocked-hint">{hint}</div> }.into_view()
+                }}
+                {claimed_by.map(|name| {
+                    let hint = i18n_stored.get_value().t_with("tasks.claimed_by", &[("user", &name)]);
+                    view! { <div class="task-claimed-hint">{hint}</div> }
+                })}
                 {if has_recent_periods {
                     view! {
                         <PeriodTrackerCompact periods=recent_periods.clone() show_in_progress=true is_bad_habit=is_bad_habit />
@@ -423,11 +435,19 @@ pub fn GroupedTaskList(
     #[prop(optional, into)] on_set_date: Option<Callback<(String, String)>>,
     /// Callback for pause/unpause: (task_id, household_id, is_currently_paused)
     #[prop(optional, into)] on_pause: Option<Callback<(String, String, bool)>>,
+    /// Callback for claim/release: (task_id, household_id, is_claimed_by_user)
+    #[prop(optional, into)] on_claim: Option<Callback<(String, String, bool)>>,
+    /// Callback to start a focus timer on the task: (task_id, household_id)
+    #[prop(optional, into)] on_focus: Option<Callback<(String, String)>>,
     /// When true, hides the Edit action (Solo Mode - only Set Date allowed)
     #[prop(default = false)] solo_mode: bool,
+    /// Enables manual ordering: tasks keep the given order within each date group
+    /// and can be dragged onto another task: (dragged_task_id, target_task_id)
+    #[prop(default = None, into)] on_reorder: Option<Callback<(String, String)>>,
 ) -> impl IntoView {
     let i18n = use_i18n();
     let i18n_stored = store_value(i18n);
+    let dragged_task = create_rw_signal(Option::<String>::None);
 
     let today = today_in_tz(&timezone);
     let other_label = i18n_stored.get_value().t("categories.other");
@@ -465,8 +485,12 @@ pub fn GroupedTaskList(
                             let tz_inner = tz.clone();
                             let dashboard_ids_inner = dashboard_ids.clone();
                             let other_label_inner = other_label_view.clone();
-                            // Sub-group by category
-                            let category_groups = group_tasks_by_category(group_tasks, &other_label_inner);
+                            // Sub-group by category, unless the order was chosen by hand
+                            let category_groups = if on_reorder.is_some() {
+                                vec![(other_label_inner.clone(), group_tasks)]
+                            } else {
+                                group_tasks_by_category(group_tasks, &other_label_inner)
+                            };
                             let has_multiple_categories = category_groups.len() > 1 || (category_groups.len() == 1 && category_groups[0].0 != other_label_inner);
                             view! {
                                 <div class="task-group" style=if is_today { "margin-bottom: 1.5rem;" } else { "margin-bottom: 1rem;" }>
@@ -558,11 +582,42 @@ pub fn GroupedTaskList(
                                                             }
                                                         }
 
+                                                        // Claim/Release action (only unassigned tasks; claims by others can't be taken over)
+                                                        if let (Some(claim_cb), Some(ref hid)) = (on_claim, &hh_id) {
+                                                            let status = &twh.task;
+                                                            let is_claimed_by_user = status.claimed_by.is_some() && status.is_user_assigned;
+                                                            if status.task.assignees().is_empty() && (status.claimed_by.is_none() || is_claimed_by_user) {
+                                                                let claim_label = if is_claimed_by_user {
+                                                                    i18n_stored.get_value().t("task_card.release")
+                                                                } else {
+                                                                    i18n_stored.get_value().t("task_card.claim")
+                                                                };
+                                                                let tid = task_id.clone();
+                                                                let hid_clone = hid.clone();
+                                                                ctx_actions.push(ContextMenuAction {
+                                                                    label: claim_label,
+                                                                    on_click: Callback::new(move |_| claim_cb.call((tid.clone(), hid_clone.clone(), is_claimed_by_user))),
+                                                                    danger: false,
+                                                                });
+                                                            }
+                                                        }
+
+                                                        if let (Some(focus_cb), Some(ref hid)) = (on_focus, &hh_id) {
+                                                            let focus_label = i18n_stored.get_value().t("task_card.focus");
+                                                            let tid = task_id.clone();
+                                                            let hid_clone = hid.clone();
+                                                            ctx_actions.push(ContextMenuAction {
+                                                                label: focus_label,
+                                                                on_click: Callback::new(move |_| focus_cb.call((tid.clone(), hid_clone.clone()))),
+                                                                danger: false,
+                                                            });
+                                                        }
+
                                                         let context_actions = ctx_actions;
 
                                                         // Render TaskCard with appropriate props based on available data
                                                         // Match on household info (both must be Some to display household)
-                                                        match (on_toggle_dashboard, on_click_title, hh_id, hh_name) {
+                                                        let card = match (on_toggle_dashboard, on_click_title, hh_id, hh_name) {
                                                             // With household info
                                                             (Some(toggle_cb), Some(title_cb), Some(hid), Some(name)) => {
                                                                 view! { <TaskCard task=twh.task on_complete=on_complete on_uncomplete=on_uncomplete timezone=tz_task household_name=name household_id=hid on_dashboard=is_on_dashboard on_toggle_dashboard=toggle_cb on_click_title=title_cb context_actions=context_actions /> }.into_view()
@@ -596,6 +651,38 @@ pub fn GroupedTaskList(
                                                             _ => {
                                                                 view! { <TaskCard task=twh.task on_complete=on_complete on_uncomplete=on_uncomplete timezone=tz_task context_actions=context_actions /> }.into_view()
                                                             }
+                                                        };
+
+                                                        match on_reorder {
+                                                            Some(reorder_cb) => {
+                                                                let drag_id = task_id.clone();
+                                                                view! {
+                                                                    <div
+                                                                        class="draggable-task"
+                                                                        draggable="true"
+                                                                        on:dragstart=move |ev: ev::DragEvent| {
+                                                                            // Firefox only starts a drag when data is set
+                                                                            if let Some(data) = ev.data_transfer() {
+                                                                                let _ = data.set_data("text/plain", &drag_id);
+                                                                            }
+                                                                            dragged_task.set(Some(drag_id.clone()));
+                                                                        }
+                                                                        on:dragover=|ev: ev::DragEvent| ev.prevent_default()
+                                                                        on:drop=move |ev: ev::DragEvent| {
+                                                                            ev.prevent_default();
+                                                                            if let Some(dragged) = dragged_task.get_untracked() {
+                                                                                if dragged != task_id {
+                                                                                    reorder_cb.call((dragged, task_id.clone()));
+                                                                                }
+                                                                            }
+                                                                            dragged_task.set(None);
+                                                                        }
+                                                                    >
+                                                                        {card}
+                                                                    </div>
+                                                                }.into_view()
+                                                            }
+                                                            None => card,
                                                         }
                                                     }).collect_view()}
                                                 </div>
@@ -680,7 +767,7 @@ pub type DashboardGroupedTaskList = ();
 mod tests {
     use super::*;
     use chrono::Utc;
-    use shared::{HabitType, RecurrenceType, Task};
+    use shared::{HabitType, RecurrenceType, ReviewerType, Task};
     use uuid::Uuid;
     use wasm_bindgen_test::*;
 
@@ -700,22 +787,34 @@ mod tests {
                 recurrence_type: RecurrenceType::Daily,
                 recurrence_value: None,
                 assigned_user_id: None,
+                assignee_ids: Vec::new(),
                 target_count: target,
                 time_period: None,
                 allow_exceed_target: allow_exceed,
                 requires_review: false,
+                reviewer_type: ReviewerType::AnyReviewer,
+                reviewer_id: None,
                 points_reward: None,
                 points_penalty: None,
                 due_time: None,
+                effort_minutes: None,
+                active_from: None,
+                active_until: None,
+                ends_on: None,
+                max_occurrences: None,
                 habit_type: HabitType::Good,
                 category_id: None,
                 category_name: None,
+                tag_ids: Vec::new(),
                 archived: false,
                 paused: false,
+                version: 1,
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 suggestion: None,
                 suggested_by: None,
+                postponed_from: None,
+                postponed_to: None,
             },
             completions_today: completions,
             current_streak: 0,
@@ -723,6 +822,8 @@ mod tests {
             next_due_date: None,
             is_user_assigned: true,
             recent_periods: Vec::new(),
+            blocked_by: Vec::new(),
+            claimed_by: None,
         }
     }
 
@@ -813,22 +914,34 @@ mod tests {
                 recurrence_type: RecurrenceType::Daily,
                 recurrence_value: None,
                 assigned_user_id: None,
+                assignee_ids: Vec::new(),
                 target_count: 1,
                 time_period: None,
                 allow_exceed_target: true,
                 requires_review: false,
+                reviewer_type: ReviewerType::AnyReviewer,
+                reviewer_id: None,
                 points_reward: None,
                 points_penalty: None,
                 due_time: None,
+                effort_minutes: None,
+                active_from: None,
+                active_until: None,
+                ends_on: None,
+                max_occurrences: None,
                 habit_type: HabitType::Good,
                 category_id: None,
                 category_name: None,
+                tag_ids: Vec::new(),
                 archived: false,
                 paused: false,
+                version: 1,
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 suggestion: None,
                 suggested_by: None,
+                postponed_from: None,
+                postponed_to: None,
             },
             completions_today: 0,
             current_streak: 5,
@@ -836,6 +949,8 @@ mod tests {
             next_due_date: None,
             is_user_assigned: true,
             recent_periods: Vec::new(),
+            blocked_by: Vec::new(),
+            claimed_by: None,
         };
         let streak_text = if task.current_streak > 0 {
             format!(" | Streak: {}", task.current_streak)
@@ -0,0 +1,147 @@
+use leptos::*;
+use shared::{CreateTaskCommentRequest, TaskCommentWithUser};
+use uuid::Uuid;
+
+use crate::api::api_client;
+use crate::components::household_layout::HouseholdContext;
+use crate::i18n::use_i18n;
+use crate::utils::format_datetime;
+
+/// Comment thread shown in the task detail view
+#[component]
+pub fn TaskComments(household_id: String, task_id: String) -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let comments = create_rw_signal(Vec::<TaskCommentWithUser>::new());
+    let loading = create_rw_signal(true);
+    let error = create_rw_signal(Option::<String>::None);
+    let new_comment = create_rw_signal(String::new());
+    let posting = create_rw_signal(false);
+    let current_user_id = create_rw_signal(Option::<Uuid>::None);
+
+    let timezone = use_context::<HouseholdContext>()
+        .and_then(|ctx| ctx.settings.get_untracked())
+        .map(|s| s.timezone)
+        .unwrap_or_else(|| "UTC".to_string());
+    let timezone = store_value(timezone);
+
+    let household_id = store_value(household_id);
+    let task_id = store_value(task_id);
+
+    // Load comments and the current user on mount
+    wasm_bindgen_futures::spawn_local(async move {
+        if let Ok(user) = api_client().get_current_user().await {
+            current_user_id.set(Some(user.id));
+        }
+        match api_client().list_task_comments(&household_id.get_value(), &task_id.get_value()).await {
+            Ok(list) => comments.set(list),
+            Err(e) => error.set(Some(e.to_string())),
+        }
+        loading.set(false);
+    });
+
+    let on_submit = move |ev: web_sys::SubmitEvent| {
+        ev.prevent_default();
+        let content = new_comment.get();
+        if content.trim().is_empty() {
+            return;
+        }
+        posting.set(true);
+        error.set(None);
+
+        wasm_bindgen_futures::spawn_local(async move {
+            let request = CreateTaskCommentRequest { content };
+            match api_client().create_task_comment(&household_id.get_value(), &task_id.get_value(), request).await {
+                Ok(_) => {
+                    new_comment.set(String::new());
+                    // Reload to get the author details
+                    if let Ok(list) =
+                        api_client().list_task_comments(&household_id.get_value(), &task_id.get_value()).await
+                    {
+                        comments.set(list);
+                    }
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            posting.set(false);
+        });
+    };
+
+    let on_delete = move |comment_id: Uuid| {
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().delete_task_comment(
+                &household_id.get_value(),
+                &task_id.get_value(),
+                &comment_id.to_string(),
+            )
+            .await
+            {
+                Ok(()) => comments.update(|c| c.retain(|item| item.comment.id != comment_id)),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    view! {
+        <section class="detail-section task-comments">
+            <h4>{move || i18n_stored.get_value().t("tasks.comments.title")}</h4>
+
+            {move || error.get().map(|e| view! { <div class="error-message">{e}</div> })}
+
+            {move || {
+                let i18n = i18n_stored.get_value();
+                if loading.get() {
+                    view! { <div class="loading">{i18n.t("common.loading")}</div> }.into_view()
+                } else if comments.get().is_empty() {
+                    view! { <p class="task-comments-empty">{i18n.t("tasks.comments.empty")}</p> }.into_view()
+                } else {
+                    let own_id = current_user_id.get();
+                    view! {
+                        <ul class="task-comment-list">
+                            {comments.get().into_iter().map(|item| {
+                                let comment_id = item.comment.id;
+                                let is_own = own_id == Some(item.comment.user_id);
+                                let created = format_datetime(item.comment.created_at, &timezone.get_value());
+                                view! {
+                                    <li class="task-comment">
+                                        <div class="task-comment-header">
+                                            <span class="task-comment-author">{item.user.username}</span>
+                                            <span class="task-comment-time">{created}</span>
+                                            {is_own.then(|| view! {
+                                                <button
+                                                    class="btn btn-outline btn-sm task-comment-delete"
+                                                    on:click=move |_| on_delete(comment_id)
+                                                >
+                                                    {i18n_stored.get_value().t("common.delete")}
+                                                </button>
+                                            })}
+                                        </div>
+                                        <div class="task-comment-content">{item.comment.content}</div>
+                                    </li>
+                                }
+                            }).collect_view()}
+                        </ul>
+                    }.into_view()
+                }
+            }}
+
+            <form class="task-comment-form" on:submit=on_submit>
+                <textarea
+                    class="form-input"
+                    rows="2"
+                    placeholder=move || i18n_stored.get_value().t("tasks.comments.placeholder")
+                    prop:value=move || new_comment.get()
+                    on:input=move |ev| new_comment.set(event_target_value(&ev))
+                ></textarea>
+                <button
+                    type="submit"
+                    class="btn btn-primary btn-sm"
+                    disabled=move || posting.get() || new_comment.get().trim().is_empty()
+                >
+                    {move || i18n_stored.get_value().t("tasks.comments.post")}
+                </button>
+            </form>
+        </section>
+    }
+}
@@ -1,41 +1,67 @@
 use leptos::*;
-use shared::{HabitType, PeriodDisplay, RecurrenceType, RecurrenceValue, Task, TaskStatistics, TaskWithDetails};
+use shared::{HabitType, PeriodDisplay, PeriodStatus, PostponeTaskRequest, RecurrenceType, RecurrenceValue, SkipTaskPeriodRequest, StreakFreezeStatus, Task, TaskStatistics, TaskWithDetails};
 
-use crate::api::ApiClient;
+use crate::api::api_client;
 use crate::components::markdown::MarkdownView;
 use crate::components::modal::Modal;
 use crate::components::period_tracker::PeriodTracker;
+use crate::components::task_comments::TaskComments;
+use crate::components::task_modal::MONTH_KEYS;
 use crate::i18n::use_i18n;
 
+/// Full name of a day of week (0 = Sunday)
+fn weekday_name(day: u8, i18n: &crate::i18n::I18nContext) -> String {
+    match day {
+        1 => i18n.t("dates.monday"),
+        2 => i18n.t("dates.tuesday"),
+        3 => i18n.t("dates.wednesday"),
+        4 => i18n.t("dates.thursday"),
+        5 => i18n.t("dates.friday"),
+        6 => i18n.t("dates.saturday"),
+        0 | 7 => i18n.t("dates.sunday"),
+        _ => "".to_string(),
+    }
+}
+
 /// Format a recurrence pattern as human-readable text
 fn format_recurrence(task: &Task, i18n: &crate::i18n::I18nContext) -> String {
     match task.recurrence_type {
         RecurrenceType::OneTime => i18n.t("tasks.recurrence.onetime"),
-        RecurrenceType::Daily => i18n.t("tasks.recurrence.daily"),
-        RecurrenceType::Weekly => {
-            if let Some(RecurrenceValue::WeekDay(day)) = &task.recurrence_value {
-                let day_name = match day {
-                    1 => i18n.t("dates.monday"),
-                    2 => i18n.t("dates.tuesday"),
-                    3 => i18n.t("dates.wednesday"),
-                    4 => i18n.t("dates.thursday"),
-                    5 => i18n.t("dates.friday"),
-                    6 => i18n.t("dates.saturday"),
-                    0 | 7 => i18n.t("dates.sunday"),
-                    _ => "".to_string(),
-                };
-                format!("{} {}", i18n.t("tasks.recurrence.every"), day_name)
-            } else {
-                i18n.t("tasks.recurrence.weekly")
+        RecurrenceType::Daily => match &task.recurrence_value {
+            Some(RecurrenceValue::DayInterval(days)) if *days > 1 => {
+                i18n.t_with("tasks.recurrence.every_n_days", &[("count", &days.to_string())])
             }
-        }
-        RecurrenceType::Monthly => {
-            if let Some(RecurrenceValue::MonthDay(day)) = &task.recurrence_value {
+            _ => i18n.t("tasks.recurrence.daily"),
+        },
+        RecurrenceType::Weekly => match &task.recurrence_value {
+            Some(RecurrenceValue::WeekDay(day)) => {
+                format!("{} {}", i18n.t("tasks.recurrence.every"), weekday_name(*day, i18n))
+            }
+            Some(RecurrenceValue::WeekInterval { weeks, weekday }) if *weeks > 1 => i18n.t_with(
+                "tasks.recurrence.every_n_weeks",
+                &[("count", &weeks.to_string()), ("day", &weekday_name(*weekday, i18n))],
+            ),
+            Some(RecurrenceValue::WeekInterval { weekday, .. }) => {
+                format!("{} {}", i18n.t("tasks.recurrence.every"), weekday_name(*weekday, i18n))
+            }
+            _ => i18n.t("tasks.recurrence.weekly"),
+        },
+        RecurrenceType::Monthly => match &task.recurrence_value {
+            Some(RecurrenceValue::MonthDay(day)) => {
                 format!("{} {}", i18n.t("tasks.recurrence.monthly_on"), day)
-            } else {
-                i18n.t("tasks.recurrence.monthly")
             }
-        }
+            Some(RecurrenceValue::MonthWeekday { ordinal, weekday }) => {
+                let ordinal = match ordinal {
+                    1..=4 => i18n.t(&format!("tasks.recurrence.ordinal_{}", ordinal)),
+                    _ => i18n.t("tasks.recurrence.ordinal_last"),
+                };
+                i18n.t_with(
+                    "tasks.recurrence.month_weekday",
+                    &[("ordinal", &ordinal), ("day", &weekday_name(*weekday, i18n))],
+                )
+            }
+            _ => i18n.t("tasks.recurrence.monthly"),
+        },
         RecurrenceType::Weekdays => {
             if let Some(RecurrenceValue::Weekdays(days)) = &task.recurrence_value {
                 let day_names: Vec<String> = days
@@ -60,6 +86,37 @@ fn format_recurrence(task: &Task, i18n: &crate::i18n::I18nContext) -> String {
     }
 }
 
+/// Format the season of a task, e.g. "April 1 – October 31", or None for all-year tasks
+fn format_season(task: &Task, i18n: &crate::i18n::I18nContext) -> Option<String> {
+    if !task.is_seasonal() {
+        return None;
+    }
+    let format_bound = |bound: Option<&str>, default: (u32, u32)| {
+        let (month, day) = bound.and_then(shared::parse_month_day).unwrap_or(default);
+        let month = i18n.t(MONTH_KEYS[month as usize - 1]);
+        i18n.t_with("tasks.detail.season_day", &[("month", &month), ("day", &day.to_string())])
+    };
+    Some(format!(
+        "{} – {}",
+        format_bound(task.active_from.as_deref(), (1, 1)),
+        format_bound(task.active_until.as_deref(), (12, 31))
+    ))
+}
+
+/// Format when a task ends, e.g. "2024-06-30, after 20 completed periods", or None if it doesn't
+fn format_task_end(task: &Task, i18n: &crate::i18n::I18nContext) -> Option<String> {
+    let parts: Vec<String> = task
+        .ends_on
+        .map(|date| date.to_string())
+        .into_iter()
+        .chain(
+            task.max_occurrences
+                .map(|max| i18n.t_with("tasks.detail.ends_after", &[("count", &max.to_string())])),
+        )
+        .collect();
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
 /// Format a completion rate as a percentage string
 fn format_rate(rate: Option<f64>, completed: i32, total: i32) -> String {
     if let Some(r) = rate {
@@ -81,6 +138,8 @@ fn TaskDetailContent(
     let i18n = use_i18n();
 
     let recurrence_text = format_recurrence(&task, &i18n);
+    let season_text = format_season(&task, &i18n);
+    let end_text = format_task_end(&task, &i18n);
     let is_bad_habit = task.habit_type == HabitType::Bad;
     let has_description = !task.description.is_empty();
     let has_due_time = task.due_time.is_some();
@@ -149,6 +208,18 @@ fn TaskDetailContent(
                     <span class="detail-label">{i18n.t("tasks.detail.recurrence")}</span>
                     <span class="detail-value">{recurrence_text}</span>
                 </div>
+                {season_text.map(|season| view! {
+                    <div class="detail-item">
+                        <span class="detail-label">{i18n.t("tasks.detail.season")}</span>
+                        <span class="detail-value">{season}</span>
+                    </div>
+                })}
+                {end_text.map(|end| view! {
+                    <div class="detail-item">
+                        <span class="detail-label">{i18n.t("tasks.detail.ends")}</span>
+                        <span class="detail-value">{end}</span>
+                    </div>
+                })}
                 {if has_due_time {
                     Some(view! {
                         <div class="detail-item">
@@ -227,6 +298,17 @@ fn TaskDetailContent(
                     <span class="detail-label">{i18n.t("tasks.detail.total_completions")}</span>
                     <span class="detail-value">{stats.total_completions}</span>
                 </div>
+                {(stats.focus_minutes_all_time > 0).then(|| view! {
+                    <div class="detail-item">
+                        <span class="detail-label">{i18n.t("tasks.detail.focus_time")}</span>
+                        <span class="detail-value">
+                            {i18n.t_with("tasks.detail.focus_minutes", &[
+                                ("week", &stats.focus_minutes_week.to_string()),
+                                ("total", &stats.focus_minutes_all_time.to_string()),
+                            ])}
+                        </span>
+                    </div>
+                })}
                 {if has_last_completed {
                     Some(view! {
                         <div class="detail-item">
@@ -319,6 +401,100 @@ fn TaskDetailContent(
     }
 }
 
+/// Prerequisites section: tasks that must be completed earlier in the same period
+#[component]
+fn TaskPrerequisites(
+    household_id: String,
+    task_id: String,
+    prerequisites: Vec<Task>,
+    #[prop(into)] on_change: Callback<()>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+    let household_tasks = create_rw_signal(Vec::<Task>::new());
+    let selected = create_rw_signal(String::new());
+    let error = create_rw_signal(Option::<String>::None);
+    let ids = store_value((household_id.clone(), task_id.clone()));
+    let linked_ids: Vec<String> = prerequisites.iter().map(|t| t.id.to_string()).collect();
+
+    wasm_bindgen_futures::spawn_local(async move {
+        if let Ok(tasks) = api_client().list_tasks(&household_id).await {
+            household_tasks.set(tasks);
+        }
+    });
+
+    let on_add = move |_| {
+        let prerequisite_id = selected.get();
+        if prerequisite_id.is_empty() {
+            return;
+        }
+        let (household_id, task_id) = ids.get_value();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().add_task_prerequisite(&household_id, &task_id, &prerequisite_id).await {
+                Ok(()) => {
+                    selected.set(String::new());
+                    on_change.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_remove = move |prerequisite_id: String| {
+        let (household_id, task_id) = ids.get_value();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().remove_task_prerequisite(&household_id, &task_id, &prerequisite_id).await {
+                Ok(()) => on_change.call(()),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    view! {
+        <section class="detail-section">
+            <h4>{i18n_stored.get_value().t("tasks.detail.prerequisites")}</h4>
+            <p class="form-hint">{i18n_stored.get_value().t("tasks.detail.prerequisites_hint")}</p>
+            {move || error.get().map(|e| view! {
+                <div class="alert alert-error">{e}</div>
+            })}
+            <ul class="linked-items">
+                {prerequisites.into_iter().map(|prerequisite| {
+                    let prerequisite_id = prerequisite.id.to_string();
+                    view! {
+                        <li class="linked-item">
+                            <span class="item-name">{prerequisite.title}</span>
+                            <button
+                                class="btn-icon"
+                                title=i18n_stored.get_value().t("common.remove")
+                                on:click=move |_| on_remove(prerequisite_id.clone())
+                            >"×"</button>
+                        </li>
+                    }
+                }).collect_view()}
+            </ul>
+            <div class="prerequisite-add">
+                <select
+                    class="form-select"
+                    prop:value=move || selected.get()
+                    on:change=move |ev| selected.set(event_target_value(&ev))
+                >
+                    <option value="">{i18n_stored.get_value().t("tasks.detail.select_prerequisite")}</option>
+                    {move || {
+                        let (_, own_id) = ids.get_value();
+                        household_tasks.get().into_iter()
+                            .filter(|t| t.id.to_string() != own_id && !linked_ids.contains(&t.id.to_string()))
+                            .map(|t| view! { <option value=t.id.to_string()>{t.title}</option> })
+                            .collect_view()
+                    }}
+                </select>
+                <button class="btn btn-outline btn-sm" on:click=on_add disabled=move || selected.get().is_empty()>
+                    {i18n_stored.get_value().t("common.add")}
+                </button>
+            </div>
+        </section>
+    }
+}
+
 #[component]
 pub fn TaskDetailModal(
     task_id: String,
@@ -331,22 +507,37 @@ pub fn TaskDetailModal(
     let loading = create_rw_signal(true);
     let error = create_rw_signal(Option::<String>::None);
     let details = create_rw_signal(Option::<TaskWithDetails>::None);
+    let comment_household_id = store_value(household_id.clone());
+    let comment_task_id = store_value(task_id.clone());
+    let reload = create_rw_signal(0u32);
+    let show_skip_form = create_rw_signal(false);
+    let skip_reason = create_rw_signal(String::new());
+    let skip_error = create_rw_signal(Option::<String>::None);
+    let skipping = create_rw_signal(false);
+    let show_postpone_form = create_rw_signal(false);
+    let postpone_date = create_rw_signal(String::new());
+    let postpone_error = create_rw_signal(Option::<String>::None);
+    let postponing = create_rw_signal(false);
+    let freeze_status = create_rw_signal(Option::<StreakFreezeStatus>::None);
+    let freeze_error = create_rw_signal(Option::<String>::None);
+    let freezing = create_rw_signal(false);
 
-    // Load task details on mount
+    // Load task details on mount and after skipping or postponing a period
     {
         let task_id = task_id.clone();
         let household_id = household_id.clone();
         create_effect(move |_| {
+            reload.track();
             let task_id = task_id.clone();
             let household_id = household_id.clone();
             wasm_bindgen_futures::spawn_local(async move {
-                match ApiClient::get_task_details(&household_id, &task_id).await {
+                match api_client().get_task_details(&household_id, &task_id).await {
                     Ok(d) => {
                         details.set(Some(d));
                         loading.set(false);
                     }
                     Err(e) => {
-                        error.set(Some(e));
+                        error.set(Some(e.to_string()));
                         loading.set(false);
                     }
                 }
@@ -354,6 +545,119 @@ pub fn TaskDetailModal(
         });
     }
 
+    // Streak freezes are only offered when the household sells them
+    {
+        let household_id = household_id.clone();
+        create_effect(move |_| {
+            let household_id = household_id.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                if let Ok(status) = api_client().get_streak_freezes(&household_id).await {
+                    freeze_status.set(Some(status));
+                }
+            });
+        });
+    }
+
+    // Most recent missed period a streak freeze could repair
+    let last_missed = move || {
+        details.with(|d| {
+            d.as_ref()
+                .filter(|d| d.task.habit_type != HabitType::Bad)
+                .and_then(|d| d.recent_periods.iter().rev().find(|p| p.status == PeriodStatus::Failed))
+                .map(|p| p.period_start)
+        })
+    };
+
+    let on_buy_freeze = move |_| {
+        let household_id = comment_household_id.get_value();
+        freezing.set(true);
+        freeze_error.set(None);
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().purchase_streak_freeze(&household_id).await {
+                Ok(status) => freeze_status.set(Some(status)),
+                Err(e) => freeze_error.set(Some(e.to_string())),
+            }
+            freezing.set(false);
+        });
+    };
+
+    let on_use_freeze = move |_| {
+        let Some(date) = last_missed() else {
+            return;
+        };
+        let household_id = comment_household_id.get_value();
+        let task_id = comment_task_id.get_value();
+        freezing.set(true);
+        freeze_error.set(None);
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().freeze_task_period(&household_id, &task_id, date).await {
+                Ok(_) => {
+                    freeze_status.update(|s| {
+                        if let Some(s) = s {
+                            s.available -= 1;
+                        }
+                    });
+                    reload.update(|r| *r += 1);
+                }
+                Err(e) => freeze_error.set(Some(e.to_string())),
+            }
+            freezing.set(false);
+        });
+    };
+
+    let on_skip_submit = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        let household_id = comment_household_id.get_value();
+        let task_id = comment_task_id.get_value();
+        let reason = skip_reason.get();
+        let request = SkipTaskPeriodRequest {
+            reason: (!reason.trim().is_empty()).then_some(reason),
+        };
+        skipping.set(true);
+        skip_error.set(None);
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().skip_task_period(&household_id, &task_id, request).await {
+                Ok(_) => {
+                    show_skip_form.set(false);
+                    skip_reason.set(String::new());
+                    reload.update(|r| *r += 1);
+                }
+                Err(e) => skip_error.set(Some(e.to_string())),
+            }
+            skipping.set(false);
+        });
+    };
+
+    let on_postpone_submit = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        let Ok(target_date) = chrono::NaiveDate::parse_from_str(&postpone_date.get(), "%Y-%m-%d") else {
+            postpone_error.set(Some(i18n_stored.get_value().t("tasks.postpone.date_required")));
+            return;
+        };
+        let household_id = comment_household_id.get_value();
+        let task_id = comment_task_id.get_value();
+        postponing.set(true);
+        postpone_error.set(None);
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().postpone_task(&household_id, &task_id, PostponeTaskRequest { target_date }).await {
+                Ok(_) => {
+                    show_postpone_form.set(false);
+                    postpone_date.set(String::new());
+                    reload.update(|r| *r += 1);
+                }
+                Err(e) => postpone_error.set(Some(e.to_string())),
+            }
+            postponing.set(false);
+        });
+    };
+
+    let is_scheduled = move || {
+        details.with(|d| {
+            d.as_ref()
+                .is_some_and(|d| d.task.recurrence_type != RecurrenceType::OneTime && !d.task.archived)
+        })
+    };
+
     let handle_edit = move |_| {
         if let Some(d) = details.get() {
             on_edit.call(d.task);
@@ -383,6 +687,105 @@ pub fn TaskDetailModal(
                                 linked_punishments=d.linked_punishments
                                 recent_periods=d.recent_periods
                             />
+                            <TaskPrerequisites
+                                household_id=comment_household_id.get_value()
+                                task_id=comment_task_id.get_value()
+                                prerequisites=d.prerequisites
+                                on_change=move |_| reload.update(|r| *r += 1)
+                            />
+                            <Show when=move || show_skip_form.get() fallback=|| ()>
+                                <form class="detail-section task-skip-form" on:submit=on_skip_submit>
+                                    <h4>{i18n_stored.get_value().t("tasks.skip.title")}</h4>
+                                    <p class="form-hint">{i18n_stored.get_value().t("tasks.skip.hint")}</p>
+                                    {move || skip_error.get().map(|e| view! {
+                                        <div class="alert alert-error">{e}</div>
+                                    })}
+                                    <input
+                                        type="text"
+                                        class="form-input"
+                                        placeholder=i18n_stored.get_value().t("tasks.skip.reason_placeholder")
+                                        prop:value=move || skip_reason.get()
+                                        on:input=move |ev| skip_reason.set(event_target_value(&ev))
+                                    />
+                                    <div class="task-skip-actions">
+                                        <button
+                                            type="button"
+                                            class="btn btn-outline btn-sm"
+                                            on:click=move |_| show_skip_form.set(false)
+                                            disabled=move || skipping.get()
+                                        >
+                                            {i18n_stored.get_value().t("common.cancel")}
+                                        </button>
+                                        <button type="submit" class="btn btn-primary btn-sm" disabled=move || skipping.get()>
+                                            {i18n_stored.get_value().t("tasks.skip.confirm")}
+                                        </button>
+                                    </div>
+                                </form>
+                            </Show>
+                            <Show when=move || show_postpone_form.get() fallback=|| ()>
+                                <form class="detail-section task-skip-form" on:submit=on_postpone_submit>
+                                    <h4>{i18n_stored.get_value().t("tasks.postpone.title")}</h4>
+                                    <p class="form-hint">{i18n_stored.get_value().t("tasks.postpone.hint")}</p>
+                                    {move || postpone_error.get().map(|e| view! {
+                                        <div class="alert alert-error">{e}</div>
+                                    })}
+                                    <input
+                                        type="date"
+                                        class="form-input"
+                                        required
+                                        prop:value=move || postpone_date.get()
+                                        on:input=move |ev| postpone_date.set(event_target_value(&ev))
+                                    />
+                                    <div class="task-skip-actions">
+                                        <button
+                                            type="button"
+                                            class="btn btn-outline btn-sm"
+                                            on:click=move |_| show_postpone_form.set(false)
+                                            disabled=move || postponing.get()
+                                        >
+                                            {i18n_stored.get_value().t("common.cancel")}
+                                        </button>
+                                        <button type="submit" class="btn btn-primary btn-sm" disabled=move || postponing.get()>
+                                            {i18n_stored.get_value().t("tasks.postpone.confirm")}
+                                        </button>
+                                    </div>
+                                </form>
+                            </Show>
+                            <Show
+                                when=move || freeze_status.with(|s| s.as_ref().is_some_and(|s| s.price > 0)) && last_missed().is_some()
+                                fallback=|| ()
+                            >
+                                <section class="detail-section task-streak-freeze">
+                                    <h4>{i18n_stored.get_value().t("tasks.streak_freeze.title")}</h4>
+                                    <p class="form-hint">
+                                        {move || {
+                                            let date = last_missed().map(|d| d.format("%d.%m.%Y").to_string()).unwrap_or_default();
+                                            let available = freeze_status.with(|s| s.as_ref().map_or(0, |s| s.available));
+                                            i18n_stored.get_value().t_plural("tasks.streak_freeze.hint", available as i64, &[("date", &date)])
+                                        }}
+                                    </p>
+                                    {move || freeze_error.get().map(|e| view! {
+                                        <div class="alert alert-error">{e}</div>
+                                    })}
+                                    <div class="task-skip-actions">
+                                        <button type="button" class="btn btn-outline btn-sm" on:click=on_buy_freeze disabled=move || freezing.get()>
+                                            {move || {
+                                                let price = freeze_status.with(|s| s.as_ref().map_or(0, |s| s.price)).to_string();
+                                                i18n_stored.get_value().t_with("tasks.streak_freeze.buy", &[("price", &price)])
+                                            }}
+                                        </button>
+                                        <button
+                                            type="button"
+                                            class="btn btn-primary btn-sm"
+                                            on:click=on_use_freeze
+                                            disabled=move || freezing.get() || freeze_status.with(|s| s.as_ref().map_or(0, |s| s.available)) == 0
+                                        >
+                                            {i18n_stored.get_value().t("tasks.streak_freeze.use")}
+                                        </button>
+                                    </div>
+                                </section>
+                            </Show>
+                            <TaskComments household_id=comment_household_id.get_value() task_id=comment_task_id.get_value() />
                         }.into_view()
                     } else {
                         view! { <div class="error-message">"No data"</div> }.into_view()
@@ -393,6 +796,16 @@ pub fn TaskDetailModal(
                 <button class="btn btn-secondary" on:click=move |_| on_close.call(())>
                     {close_text.clone()}
                 </button>
+                <Show when=move || is_scheduled() && !show_skip_form.get() fallback=|| ()>
+                    <button class="btn btn-outline" on:click=move |_| show_skip_form.set(true)>
+                        {i18n_stored.get_value().t("tasks.skip.button")}
+                    </button>
+                </Show>
+                <Show when=move || is_scheduled() && !show_postpone_form.get() fallback=|| ()>
+                    <button class="btn btn-outline" on:click=move |_| show_postpone_form.set(true)>
+                        {i18n_stored.get_value().t("tasks.postpone.button")}
+                    </button>
+                </Show>
                 <button class="btn btn-primary" on:click=handle_edit>
                     {edit_text.clone()}
                 </button>
@@ -0,0 +1,230 @@
+use leptos::*;
+use shared::{CreateSavedTaskFilterRequest, HabitType, MemberWithUser, SavedTaskFilter, TaskCategory, TaskFilter, TaskTag};
+use uuid::Uuid;
+
+use crate::api::api_client;
+use crate::i18n::use_i18n;
+
+/// Due window choices of the filter editor: (days, translation key)
+const DUE_WINDOWS: [(i64, &str); 3] = [
+    (0, "smart_lists.due_today"),
+    (7, "smart_lists.due_week"),
+    (30, "smart_lists.due_month"),
+];
+
+fn parse_id(value: &str) -> Option<Uuid> {
+    Uuid::parse_str(value).ok()
+}
+
+fn id_value(id: Option<Uuid>) -> String {
+    id.map(|id| id.to_string()).unwrap_or_default()
+}
+
+/// Smart lists for the tasks page: the user's saved filters as one-click chips,
+/// and an editor to build a filter and save it under a name
+#[component]
+pub fn TaskFilterBar(
+    household_id: String,
+    members: RwSignal<Vec<MemberWithUser>>,
+    categories: RwSignal<Vec<TaskCategory>>,
+    /// The active filter; the page reloads its tasks when it changes
+    filter: RwSignal<TaskFilter>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+    let household_id = store_value(household_id);
+
+    let saved_filters = create_rw_signal(Vec::<SavedTaskFilter>::new());
+    let tags = create_rw_signal(Vec::<TaskTag>::new());
+    let show_editor = create_rw_signal(false);
+    let new_name = create_rw_signal(String::new());
+    let error = create_rw_signal(Option::<String>::None);
+
+    wasm_bindgen_futures::spawn_local(async move {
+        let id = household_id.get_value();
+        if let Ok(list) = api_client().list_saved_filters(&id).await {
+            saved_filters.set(list);
+        }
+        if let Ok(list) = api_client().list_task_tags(&id).await {
+            tags.set(list);
+        }
+    });
+
+    let on_save = move |_| {
+        let name = new_name.get().trim().to_string();
+        if name.is_empty() {
+            return;
+        }
+        let request = CreateSavedTaskFilterRequest { name, filter: filter.get() };
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().create_saved_filter(&household_id.get_value(), request).await {
+                Ok(saved) => {
+                    saved_filters.update(|list| {
+                        list.push(saved);
+                        list.sort_by_key(|s| s.name.to_lowercase());
+                    });
+                    new_name.set(String::new());
+                    show_editor.set(false);
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_delete = move |filter_id: Uuid| {
+        wasm_bindgen_futures::spawn_local(async move {
+            if api_client().delete_saved_filter(&household_id.get_value(), &filter_id.to_string()).await.is_ok() {
+                saved_filters.update(|list| list.retain(|s| s.id != filter_id));
+            }
+        });
+    };
+
+    view! {
+        <div class="smart-lists">
+            <button
+                class=move || if filter.get().is_empty() { "tag-chip selected" } else { "tag-chip" }
+                on:click=move |_| filter.set(TaskFilter::default())
+            >
+                {i18n_stored.get_value().t("common.all")}
+            </button>
+            {move || saved_filters.get().into_iter().map(|saved| {
+                let saved_filter = saved.filter.clone();
+                let active_filter = saved.filter.clone();
+                let saved_id = saved.id;
+                view! {
+                    <span class="smart-list">
+                        <button
+                            class=move || if filter.get() == active_filter { "tag-chip selected" } else { "tag-chip" }
+                            on:click=move |_| filter.set(saved_filter.clone())
+                        >
+                            {saved.name}
+                        </button>
+                        <button
+                            class="smart-list-remove"
+                            title=i18n_stored.get_value().t("common.delete")
+                            on:click=move |_| on_delete(saved_id)
+                        >
+                            "×"
+                        </button>
+                    </span>
+                }
+            }).collect_view()}
+            <button class="btn btn-outline btn-sm" on:click=move |_| show_editor.update(|v| *v = !*v)>
+                {i18n_stored.get_value().t("smart_lists.edit")}
+            </button>
+        </div>
+
+        <Show when=move || show_editor.get() fallback=|| ()>
+            {move || {
+                let i18n = i18n_stored.get_value();
+                let any = i18n.t("smart_lists.any");
+                view! {
+                    <div class="card task-filter-editor">
+                        <div class="task-filter-fields">
+                            <label class="form-label">
+                                {i18n.t("smart_lists.assignee")}
+                                <select
+                                    class="form-select"
+                                    on:change=move |ev| filter.update(|f| f.assignee_id = parse_id(&event_target_value(&ev)))
+                                >
+                                    <option value="">{any.clone()}</option>
+                                    {members.get().into_iter().map(|m| {
+                                        let id = m.user.id.to_string();
+                                        let selected = id_value(filter.get_untracked().assignee_id) == id;
+                                        view! { <option value=id selected=selected>{m.user.username}</option> }
+                                    }).collect_view()}
+                                </select>
+                            </label>
+                            <label class="form-label">
+                                {i18n.t("task_modal.tags")}
+                                <select
+                                    class="form-select"
+                                    on:change=move |ev| filter.update(|f| f.tag_ids = parse_id(&event_target_value(&ev)).into_iter().collect())
+                                >
+                                    <option value="">{any.clone()}</option>
+                                    {tags.get().into_iter().map(|tag| {
+                                        let selected = filter.get_untracked().tag_ids.contains(&tag.id);
+                                        view! { <option value=tag.id.to_string() selected=selected>{tag.name}</option> }
+                                    }).collect_view()}
+                                </select>
+                            </label>
+                            <label class="form-label">
+                                {i18n.t("task_modal.category")}
+                                <select
+                                    class="form-select"
+                                    on:change=move |ev| filter.update(|f| f.category_id = parse_id(&event_target_value(&ev)))
+                                >
+                                    <option value="">{any.clone()}</option>
+                                    {categories.get().into_iter().map(|cat| {
+                                        let selected = filter.get_untracked().category_id == Some(cat.id);
+                                        view! { <option value=cat.id.to_string() selected=selected>{cat.name}</option> }
+                                    }).collect_view()}
+                                </select>
+                            </label>
+                            <label class="form-label">
+                                {i18n.t("smart_lists.due_within")}
+                                <select
+                                    class="form-select"
+                                    on:change=move |ev| filter.update(|f| f.due_within_days = event_target_value(&ev).parse().ok())
+                                >
+                                    <option value="">{any.clone()}</option>
+                                    {DUE_WINDOWS.iter().map(|(days, key)| {
+                                        let selected = filter.get_untracked().due_within_days == Some(*days);
+                                        view! { <option value=days.to_string() selected=selected>{i18n.t(key)}</option> }
+                                    }).collect_view()}
+                                </select>
+                            </label>
+                            <label class="form-label">
+                                {i18n.t("task_modal.habit_type_label")}
+                                <select
+                                    class="form-select"
+                                    on:change=move |ev| filter.update(|f| f.habit_type = event_target_value(&ev).parse().ok())
+                                >
+                                    <option value="">{any.clone()}</option>
+                                    {[HabitType::Good, HabitType::Bad].into_iter().map(|habit_type| {
+                                        let selected = filter.get_untracked().habit_type == Some(habit_type);
+                                        let label = i18n.t(&format!("habit_type.{}", habit_type.as_str()));
+                                        view! { <option value=habit_type.as_str() selected=selected>{label}</option> }
+                                    }).collect_view()}
+                                </select>
+                            </label>
+                        </div>
+                        {move || error.get().map(|e| view! { <div class="alert alert-error">{e}</div> })}
+                        <div class="tag-add">
+                            <input
+                                type="text"
+                                class="form-input"
+                                placeholder=i18n.t("smart_lists.name_placeholder")
+                                prop:value=move || new_name.get()
+                                on:input=move |ev| new_name.set(event_target_value(&ev))
+                            />
+                            <button
+                                class="btn btn-primary btn-sm"
+                                disabled=move || new_name.get().trim().is_empty() || filter.get().is_empty()
+                                on:click=on_save
+                            >
+                                {i18n.t("smart_lists.save")}
+                            </button>
+                        </div>
+                    </div>
+                }
+            }}
+        </Show>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn test_select_values_round_trip() {
+        let id = Uuid::new_v4();
+        assert_eq!(parse_id(&id_value(Some(id))), Some(id));
+        assert_eq!(parse_id(&id_value(None)), None);
+    }
+}
@@ -1,9 +1,10 @@
 use leptos::*;
-use shared::{CreateTaskRequest, HabitType, MemberWithUser, Punishment, RecurrenceType, RecurrenceValue, Reward, Task, TaskCategory, TaskPunishmentLink, TaskRewardLink, UpdateTaskRequest};
+use shared::{CreateTaskRequest, CreateTaskTagRequest, HabitType, MemberWithUser, Punishment, RecurrenceType, RecurrenceValue, ReviewerType, Reward, Task, TaskCategory, TaskPunishmentLink, TaskRewardLink, TaskTag, UpdateTaskRequest};
 use uuid::Uuid;
 
-use crate::api::ApiClient;
+use crate::api::api_client;
 use crate::components::calendar_picker::CalendarPicker;
+use crate::components::date_input::DateInput;
 use crate::components::task_fields::*;
 use crate::i18n::use_i18n;
 
@@ -28,6 +29,10 @@ pub fn TaskModal(
     #[prop(default = None)] default_points_reward: Option<i64>,
     /// Default points penalty from household settings (for create mode)
     #[prop(default = None)] default_points_penalty: Option<i64>,
+    /// Default for allowing completions beyond the target (for create mode)
+    #[prop(default = true)] default_allow_exceed_target: bool,
+    /// Default for requiring review (for create mode)
+    #[prop(default = false)] default_requires_review: bool,
     /// Default rewards from household settings (for create mode) - Vec of (reward_id, amount)
     #[prop(default = vec![])] default_rewards: Vec<(String, i32)>,
     /// Default punishments from household settings (for create mode) - Vec of (punishment_id, amount)
@@ -60,19 +65,22 @@ pub fn TaskModal(
             .unwrap_or(default_recurrence)
     );
     // Auto-select if only one member can be assigned (create mode only, not duplicate)
-    let initial_assigned_user_id = source_task
-        .and_then(|t| t.assigned_user_id.map(|id| id.to_string()))
+    let initial_assignees: Vec<String> = source_task
+        .map(|t| t.assignees().iter().map(|id| id.to_string()).collect::<Vec<_>>())
+        .filter(|ids| !ids.is_empty())
         .or_else(|| {
             // In create mode with exactly one assignable member, auto-select them
             // But not if we're in duplicate mode (prefill_from is set)
             let members_val = members_stored.get_value();
             if task.is_none() && prefill_from.is_none() && members_val.len() == 1 {
-                Some(members_val[0].user.id.to_string())
+                Some(vec![members_val[0].user.id.to_string()])
             } else {
                 None
             }
-        });
-    let assigned_user = create_rw_signal(initial_assigned_user_id.clone().unwrap_or_default());
+        })
+        .unwrap_or_default();
+    let assigned_user = create_rw_signal(initial_assignees.first().cloned().unwrap_or_default());
+    let assignees = create_rw_signal(initial_assignees);
     let target_count = create_rw_signal(
         source_task
             .map(|t| t.target_count.to_string())
@@ -81,12 +89,22 @@ pub fn TaskModal(
     let allow_exceed_target = create_rw_signal(
         source_task
             .map(|t| t.allow_exceed_target)
-            .unwrap_or(true)  // Default to true for new tasks
+            .unwrap_or(default_allow_exceed_target)
     );
     let requires_review = create_rw_signal(
         source_task
             .map(|t| t.requires_review)
-            .unwrap_or(false)  // Default to false for new tasks
+            .unwrap_or(default_requires_review)
+    );
+    let reviewer_type = create_rw_signal(
+        source_task
+            .map(|t| t.reviewer_type.as_str().to_string())
+            .unwrap_or_else(|| ReviewerType::default().as_str().to_string())
+    );
+    let reviewer_id = create_rw_signal(
+        source_task
+            .and_then(|t| t.reviewer_id.map(|id| id.to_string()))
+            .unwrap_or_default()
     );
 
     // Habit type signal (good = normal, bad = inverted consequences)
@@ -104,6 +122,15 @@ pub fn TaskModal(
     );
     let categories_stored = store_value(categories);
 
+    // Tag signals: the household's tags are loaded by the modal itself
+    let selected_tag_ids = create_rw_signal(
+        source_task
+            .map(|t| t.tag_ids.iter().map(|id| id.to_string()).collect::<Vec<_>>())
+            .unwrap_or_default()
+    );
+    let household_tags = create_rw_signal(Vec::<TaskTag>::new());
+    let new_tag_name = create_rw_signal(String::new());
+
     // Direct points signals - use defaults from household settings in create mode
     let is_create_mode = task.is_none() && prefill_from.is_none();
     let points_reward = create_rw_signal(
@@ -128,6 +155,38 @@ pub fn TaskModal(
             .unwrap_or_default()
     );
 
+    // Estimated effort in minutes (empty = no estimate)
+    let effort_minutes = create_rw_signal(
+        source_task
+            .and_then(|t| t.effort_minutes)
+            .map(|m| m.to_string())
+            .unwrap_or_default()
+    );
+
+    // Season the task is active in as (month, day) bounds; disabled means all year
+    let season_enabled = create_rw_signal(source_task.is_some_and(|t| t.is_seasonal()));
+    let season_from = create_rw_signal(
+        source_task
+            .and_then(|t| t.active_from.as_deref())
+            .and_then(shared::parse_month_day)
+            .unwrap_or((1, 1))
+    );
+    let season_until = create_rw_signal(
+        source_task
+            .and_then(|t| t.active_until.as_deref())
+            .and_then(shared::parse_month_day)
+            .unwrap_or((12, 31))
+    );
+
+    // Optional end of the task: a last day and/or a number of completed periods
+    let ends_on = create_rw_signal(source_task.and_then(|t| t.ends_on));
+    let max_occurrences = create_rw_signal(
+        source_task
+            .and_then(|t| t.max_occurrences)
+            .map(|m| m.to_string())
+            .unwrap_or_default()
+    );
+
     // Recurrence value signals
     let selected_weekdays = create_rw_signal(
         source_task
@@ -143,11 +202,30 @@ pub fn TaskModal(
         source_task
             .and_then(|t| match &t.recurrence_value {
                 Some(RecurrenceValue::WeekDay(day)) => Some(*day),
+                Some(RecurrenceValue::WeekInterval { weekday, .. }) => Some(*weekday),
                 _ => None,
             })
             .unwrap_or(1) // Default to Monday
     );
 
+    // Interval for Daily (every N days) and Weekly (every N weeks) recurrence, 1 = no gaps
+    let interval_days = create_rw_signal(
+        source_task
+            .and_then(|t| match &t.recurrence_value {
+                Some(RecurrenceValue::DayInterval(days)) => Some(*days),
+                _ => None,
+            })
+            .unwrap_or(1)
+    );
+    let interval_weeks = create_rw_signal(
+        source_task
+            .and_then(|t| match &t.recurrence_value {
+                Some(RecurrenceValue::WeekInterval { weeks, .. }) => Some(*weeks),
+                _ => None,
+            })
+            .unwrap_or(1)
+    );
+
     // Day of month for Monthly recurrence (1-31)
     let selected_month_day = create_rw_signal(
         source_task
@@ -158,6 +236,15 @@ pub fn TaskModal(
             .unwrap_or(1) // Default to 1st of month
     );
 
+    // Nth day of week for Monthly recurrence (ordinal 1-4, -1 = last), e.g. first Saturday
+    let month_weekday = source_task.and_then(|t| match &t.recurrence_value {
+        Some(RecurrenceValue::MonthWeekday { ordinal, weekday }) => Some((*ordinal, *weekday)),
+        _ => None,
+    });
+    let monthly_by_weekday = create_rw_signal(month_weekday.is_some());
+    let selected_ordinal = create_rw_signal(month_weekday.map(|(ordinal, _)| ordinal).unwrap_or(1));
+    let selected_month_weekday = create_rw_signal(month_weekday.map(|(_, weekday)| weekday).unwrap_or(6));
+
     let selected_custom_dates = create_rw_signal(
         source_task
             .and_then(|t| match &t.recurrence_value {
@@ -226,6 +313,7 @@ pub fn TaskModal(
     let bulk_errors = create_rw_signal(Vec::<String>::new());
 
     let task_id = task.as_ref().map(|t| t.id.to_string());
+    let task_version = task.as_ref().map(|t| t.version);
 
     // Load initial dashboard status for existing tasks
     {
@@ -234,7 +322,7 @@ pub fn TaskModal(
             if let Some(ref task_id) = task_id_for_effect {
                 let task_id = task_id.clone();
                 wasm_bindgen_futures::spawn_local(async move {
-                    if let Ok(is_on_dashboard) = ApiClient::is_task_on_dashboard(&task_id).await {
+                    if let Ok(is_on_dashboard) = api_client().is_task_on_dashboard(&task_id).await {
                         on_dashboard.set(is_on_dashboard);
                         initial_on_dashboard.set(is_on_dashboard);
                     }
@@ -243,6 +331,39 @@ pub fn TaskModal(
         });
     }
 
+    // Load the household's tags
+    {
+        let household_id = household_id.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Ok(tags) = api_client().list_task_tags(&household_id).await {
+                household_tags.set(tags);
+            }
+        });
+    }
+
+    let on_add_tag = {
+        let household_id = household_id.clone();
+        move || {
+            let name = new_tag_name.get().trim().to_string();
+            if name.is_empty() {
+                return;
+            }
+            let household_id = household_id.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let request = CreateTaskTagRequest { name, color: None };
+                match api_client().create_task_tag(&household_id, request).await {
+                    Ok(tag) => {
+                        selected_tag_ids.update(|ids| ids.push(tag.id.to_string()));
+                        household_tags.update(|tags| tags.push(tag));
+                        new_tag_name.set(String::new());
+                    }
+                    Err(e) => error.set(Some(e.to_string())),
+                }
+            });
+        }
+    };
+    let on_add_tag = store_value(on_add_tag);
+
     let on_submit = {
         let task_id = task_id.clone();
         let household_id = household_id.clone();
@@ -269,21 +390,39 @@ pub fn TaskModal(
                 _ => RecurrenceType::Daily,
             };
 
+            let (active_from, active_until) = if season_enabled.get() {
+                (Some(format_month_day(season_from.get())), Some(format_month_day(season_until.get())))
+            } else {
+                (None, None)
+            };
+
+            let ends_on = ends_on.get();
+            let max_occurrences = max_occurrences.get().parse::<i32>().ok().filter(|max| *max > 0);
+
             // Build recurrence value based on type
             let rec_value = match recurrence_type.get().as_str() {
+                "daily" if interval_days.get() > 1 => Some(RecurrenceValue::DayInterval(interval_days.get())),
+                "weekly" if interval_weeks.get() > 1 => Some(RecurrenceValue::WeekInterval {
+                    weeks: interval_weeks.get(),
+                    weekday: selected_weekday.get(),
+                }),
                 "weekly" => Some(RecurrenceValue::WeekDay(selected_weekday.get())),
+                "monthly" if monthly_by_weekday.get() => Some(RecurrenceValue::MonthWeekday {
+                    ordinal: selected_ordinal.get(),
+                    weekday: selected_month_weekday.get(),
+                }),
                 "monthly" => Some(RecurrenceValue::MonthDay(selected_month_day.get())),
                 "weekdays" => Some(RecurrenceValue::Weekdays(selected_weekdays.get())),
                 "custom" => Some(RecurrenceValue::CustomDates(selected_custom_dates.get())),
                 _ => None,
             };
 
-            let assigned = assigned_user.get();
-            let assigned_user_id = if assigned.is_empty() {
-                None
-            } else {
-                Uuid::parse_str(&assigned).ok()
-            };
+            let assignee_ids: Vec<Uuid> = assignees
+                .get()
+                .iter()
+                .filter_map(|id| Uuid::parse_str(id).ok())
+                .collect();
+            let assigned_user_id = assignee_ids.first().copied();
 
             let target = target_count.get().parse::<i32>().unwrap_or(1).max(0);
             let new_rewards = selected_rewards.get(); // Vec<(String, i32)>
@@ -310,26 +449,37 @@ pub fn TaskModal(
                             Some(Uuid::parse_str(&cat_id).ok())
                         }
                     };
+                    let (reviewer_type_val, reviewer_id_val) = parse_reviewer(&reviewer_type.get(), &reviewer_id.get());
                     let request = UpdateTaskRequest {
                         title: Some(title.get()),
                         description: Some(description.get()),
                         recurrence_type: Some(rec_type),
                         recurrence_value: rec_value,
                         assigned_user_id,
+                        assignee_ids: Some(assignee_ids),
                         target_count: Some(target),
                         time_period: None,
                         allow_exceed_target: Some(allow_exceed_target.get()),
                         requires_review: Some(requires_review.get()),
+                        reviewer_type: Some(reviewer_type_val),
+                        reviewer_id: Some(reviewer_id_val),
                         points_reward: pts_reward,
                         points_penalty: pts_penalty,
                         due_time: due_time_val,
+                        effort_minutes: Some(effort_minutes.get().parse::<i32>().ok()),
+                        active_from: Some(active_from),
+                        active_until: Some(active_until),
+                        ends_on: Some(ends_on),
+                        max_occurrences: Some(max_occurrences),
                         habit_type: Some(habit_type_val),
                         category_id: category_id_val,
+                        tag_ids: Some(parse_tag_ids(&selected_tag_ids.get())),
                         archived: None,
                         paused: None,
+                        version: task_version,
                     };
 
-                    match ApiClient::update_task(&household_id, &task_id, request).await {
+                    match api_client().update_task(&household_id, &task_id, request).await {
                         Ok(updated_task) => {
                             // Update reward links - compare by ID
                             let new_reward_ids: Vec<&String> = new_rewards.iter().map(|(id, _)| id).collect();
@@ -338,13 +488,13 @@ pub fn TaskModal(
                             // Add new rewards
                             for (reward_id, amount) in &new_rewards {
                                 if !original_reward_ids.contains(&reward_id) {
-                                    let _ = ApiClient::add_task_reward(&household_id, &task_id, reward_id, *amount).await;
+                                    let _ = api_client().add_task_reward(&household_id, &task_id, reward_id, *amount).await;
                                 }
                             }
                             // Remove rewards that were unlinked
                             for (reward_id, _) in &original_rewards {
                                 if !new_reward_ids.contains(&reward_id) {
-                                    let _ = ApiClient::remove_task_reward(&household_id, &task_id, reward_id).await;
+                                    let _ = api_client().remove_task_reward(&household_id, &task_id, reward_id).await;
                                 }
                             }
 
@@ -355,13 +505,13 @@ pub fn TaskModal(
                             // Add new punishments
                             for (punishment_id, amount) in &new_punishments {
                                 if !original_punishment_ids.contains(&punishment_id) {
-                                    let _ = ApiClient::add_task_punishment(&household_id, &task_id, punishment_id, *amount).await;
+                                    let _ = api_client().add_task_punishment(&household_id, &task_id, punishment_id, *amount).await;
                                 }
                             }
                             // Remove punishments that were unlinked
                             for (punishment_id, _) in &original_punishments {
                                 if !new_punishment_ids.contains(&punishment_id) {
-                                    let _ = ApiClient::remove_task_punishment(&household_id, &task_id, punishment_id).await;
+                                    let _ = api_client().remove_task_punishment(&household_id, &task_id, punishment_id).await;
                                 }
                             }
 
@@ -370,9 +520,9 @@ pub fn TaskModal(
                             let was_on_dashboard = initial_on_dashboard.get();
                             if current_on_dashboard != was_on_dashboard {
                                 if current_on_dashboard {
-                                    let _ = ApiClient::add_task_to_dashboard(&task_id).await;
+                                    let _ = api_client().add_task_to_dashboard(&task_id).await;
                                 } else {
-                                    let _ = ApiClient::remove_task_from_dashboard(&task_id).await;
+                                    let _ = api_client().remove_task_from_dashboard(&task_id).await;
                                 }
                             }
 
@@ -380,7 +530,7 @@ pub fn TaskModal(
                             on_save.call(updated_task);
                         }
                         Err(e) => {
-                            error.set(Some(e));
+                            error.set(Some(e.to_string()));
                             saving.set(false);
                         }
                     }
@@ -400,48 +550,58 @@ pub fn TaskModal(
                         let cat_id = selected_category_id.get();
                         if cat_id.is_empty() { None } else { Uuid::parse_str(&cat_id).ok() }
                     };
+                    let (reviewer_type_val, reviewer_id_val) = parse_reviewer(&reviewer_type.get(), &reviewer_id.get());
                     let request = CreateTaskRequest {
                         title: title.get(),
                         description: Some(description.get()),
                         recurrence_type: rec_type,
                         recurrence_value: rec_value,
                         assigned_user_id,
+                        assignee_ids: Some(assignee_ids),
                         target_count: Some(target),
                         time_period: None,
                         allow_exceed_target: Some(allow_exceed_target.get()),
                         requires_review: Some(requires_review.get()),
+                        reviewer_type: Some(reviewer_type_val),
+                        reviewer_id: reviewer_id_val,
                         points_reward: pts_reward,
                         points_penalty: pts_penalty,
                         due_time: due_time_val,
+                        effort_minutes: effort_minutes.get().parse::<i32>().ok(),
+                        active_from,
+                        active_until,
+                        ends_on,
+                        max_occurrences,
                         habit_type: Some(habit_type_val),
                         category_id: category_id_val,
+                        tag_ids: Some(parse_tag_ids(&selected_tag_ids.get())),
                         is_suggestion: if is_suggestion { Some(true) } else { None },
                     };
 
-                    match ApiClient::create_task(&household_id, request).await {
+                    match api_client().create_task(&household_id, request).await {
                         Ok(created_task) => {
                             let task_id = created_task.id.to_string();
 
                             // Add reward links with amounts
                             for (reward_id, amount) in &new_rewards {
-                                let _ = ApiClient::add_task_reward(&household_id, &task_id, reward_id, *amount).await;
+                                let _ = api_client().add_task_reward(&household_id, &task_id, reward_id, *amount).await;
                             }
 
                             // Add punishment links with amounts
                             for (punishment_id, amount) in &new_punishments {
-                                let _ = ApiClient::add_task_punishment(&household_id, &task_id, punishment_id, *amount).await;
+                                let _ = api_client().add_task_punishment(&household_id, &task_id, punishment_id, *amount).await;
                             }
 
                             // Add to dashboard if enabled
                             if on_dashboard.get() {
-                                let _ = ApiClient::add_task_to_dashboard(&task_id).await;
+                                let _ = api_client().add_task_to_dashboard(&task_id).await;
                             }
 
                             saving.set(false);
                             on_save.call(created_task);
                         }
                         Err(e) => {
-                            error.set(Some(e));
+                            error.set(Some(e.to_string()));
                             saving.set(false);
                         }
                     }
@@ -526,6 +686,7 @@ pub fn TaskModal(
                         recurrence_type: bulk_rec_type,
                         recurrence_value: bulk_rec_value,
                         assigned_user_id: assigned_user_id_val.flatten(),
+                        assignee_ids: None,
                         target_count: if apply_target_count.get() {
                             Some(target_count.get().parse::<i32>().unwrap_or(1).max(0))
                         } else {
@@ -542,6 +703,8 @@ pub fn TaskModal(
                         } else {
                             None
                         },
+                        reviewer_type: None,
+                        reviewer_id: None,
                         points_reward: if apply_points_reward.get() {
                             points_reward.get().parse::<i64>().ok()
                         } else {
@@ -558,6 +721,11 @@ pub fn TaskModal(
                         } else {
                             None
                         }.flatten(),
+                        effort_minutes: None,
+                        active_from: None,
+                        active_until: None,
+                        ends_on: None,
+                        max_occurrences: None,
                         habit_type: if apply_habit_type.get() {
                             Some(match habit_type.get().as_str() {
                                 "bad" => HabitType::Bad,
@@ -567,23 +735,25 @@ pub fn TaskModal(
                             None
                         },
                         category_id: category_id_val,
+                        tag_ids: None,
                         archived: None,
                         paused: if apply_paused.get() {
                             Some(paused.get())
                         } else {
                             None
                         },
+                        version: None,
                     };
 
-                    match ApiClient::update_task(&hid, task_id, request).await {
+                    match api_client().update_task(&hid, task_id, request).await {
                         Ok(_) => {
                             // Handle dashboard updates if apply_on_dashboard is checked
                             if apply_on_dashboard.get() {
                                 let should_be_on_dashboard = on_dashboard.get();
                                 if should_be_on_dashboard {
-                                    let _ = ApiClient::add_task_to_dashboard(task_id).await;
+                                    let _ = api_client().add_task_to_dashboard(task_id).await;
                                 } else {
-                                    let _ = ApiClient::remove_task_from_dashboard(task_id).await;
+                                    let _ = api_client().remove_task_from_dashboard(task_id).await;
                                 }
                             }
                             success_count += 1;
@@ -614,7 +784,7 @@ pub fn TaskModal(
     let i18n_stored = store_value(i18n.clone());
 
     let modal_title = if is_bulk_edit {
-        i18n.t("tasks.bulk_edit_title").replace("{count}", &bulk_task_count.to_string())
+        i18n.t_with("tasks.bulk_edit_title", &[("count", &bulk_task_count.to_string())])
     } else if is_edit {
         i18n.t("task_modal.edit_title")
     } else if is_suggestion {
@@ -676,13 +846,11 @@ pub fn TaskModal(
                 {move || {
                     if is_bulk_edit && saving.get() {
                         let (completed, total) = bulk_progress.get();
-                        let percent = if total > 0 { (completed * 100) / total } else { 0 };
+                        let percent = (completed * 100).checked_div(total).unwrap_or(0);
                         Some(view! {
                             <div class="bulk-edit-progress" style="margin: 1rem;">
                                 <div style="margin-bottom: 0.5rem;">
-                                    {i18n_stored.get_value().t("tasks.bulk_edit_progress")
-                                        .replace("{current}", &completed.to_string())
-                                        .replace("{total}", &total.to_string())}
+                                    {i18n_stored.get_value().t_with("tasks.bulk_edit_progress", &[("current", &completed.to_string()), ("total", &total.to_string())])}
                                 </div>
                                 <div class="bulk-edit-progress-bar">
                                     <div class="bulk-edit-progress-fill" style=format!("width: {}%", percent)></div>
@@ -701,10 +869,11 @@ pub fn TaskModal(
                         Some(view! {
                             <div class="alert alert-error" style="margin: 1rem;">
                                 <div style="font-weight: 500; margin-bottom: 0.5rem;">
-                                    {i18n_stored.get_value().t("tasks.bulk_edit_partial")
-                                        .replace("{success}", &(bulk_task_count - errors.len()).to_string())
-                                        .replace("{total}", &bulk_task_count.to_string())
-                                        .replace("{failed}", &errors.len().to_string())}
+                                    {i18n_stored.get_value().t_with("tasks.bulk_edit_partial", &[
+                                        ("success", &(bulk_task_count - errors.len()).to_string()),
+                                        ("total", &bulk_task_count.to_string()),
+                                        ("failed", &errors.len().to_string()),
+                                    ])}
                                 </div>
                                 <ul style="margin: 0; padding-left: 1rem;">
                                     {errors.iter().map(|e| view! { <li>{e}</li> }).collect_view()}
@@ -794,6 +963,51 @@ pub fn TaskModal(
                             }
                         </Show>
 
+                        // Tag chips
+                        <div class="form-group">
+                            <label class="form-label" for="task-new-tag">{i18n_stored.get_value().t("task_modal.tags")}</label>
+                            <div class="tag-chips">
+                                {move || household_tags.get().into_iter().map(|tag| {
+                                    let tag_id = tag.id.to_string();
+                                    let tag_id_for_class = tag_id.clone();
+                                    view! {
+                                        <button
+                                            type="button"
+                                            class=move || if selected_tag_ids.get().contains(&tag_id_for_class) { "tag-chip selected" } else { "tag-chip" }
+                                            style=tag.color.map(|c| format!("--tag-color: {}", c))
+                                            on:click=move |_| selected_tag_ids.update(|ids| toggle_tag(ids, &tag_id))
+                                        >
+                                            {tag.name}
+                                        </button>
+                                    }
+                                }).collect_view()}
+                            </div>
+                            <div class="tag-add">
+                                <input
+                                    type="text"
+                                    id="task-new-tag"
+                                    class="form-input"
+                                    placeholder=i18n_stored.get_value().t("task_modal.new_tag_placeholder")
+                                    prop:value=move || new_tag_name.get()
+                                    on:input=move |ev| new_tag_name.set(event_target_value(&ev))
+                                    on:keydown=move |ev: web_sys::KeyboardEvent| {
+                                        if ev.key() == "Enter" {
+                                            ev.prevent_default();
+                                            on_add_tag.with_value(|add| add());
+                                        }
+                                    }
+                                />
+                                <button
+                                    type="button"
+                                    class="btn btn-outline btn-sm"
+                                    disabled=move || new_tag_name.get().trim().is_empty()
+                                    on:click=move |_| on_add_tag.with_value(|add| add())
+                                >
+                                    {i18n_stored.get_value().t("task_modal.add_tag")}
+                                </button>
+                            </div>
+                        </div>
+
                         <div class="form-group">
                             <label class="form-label" for="task-recurrence">{i18n_stored.get_value().t("task_modal.recurrence_label")}</label>
                             {
@@ -821,11 +1035,40 @@ pub fn TaskModal(
                             }
                         </div>
 
+                        // Interval in days (shown when recurrence_type == "daily")
+                        <Show when=move || recurrence_type.get() == "daily" fallback=|| ()>
+                            {
+                                let interval_days_label = i18n_stored.get_value().t("task_modal.interval_days");
+                                let interval_days_hint = i18n_stored.get_value().t("task_modal.interval_days_hint");
+                                view! {
+                                    <div class="form-group">
+                                        <label class="form-label" for="task-interval-days">{interval_days_label}</label>
+                                        <input
+                                            type="number"
+                                            id="task-interval-days"
+                                            class="form-input"
+                                            min="1"
+                                            max="365"
+                                            prop:value=move || interval_days.get().to_string()
+                                            on:input=move |ev| {
+                                                if let Ok(days) = event_target_value(&ev).parse::<u16>() {
+                                                    interval_days.set(days.clamp(1, 365));
+                                                }
+                                            }
+                                        />
+                                        <small class="form-hint">{interval_days_hint}</small>
+                                    </div>
+                                }
+                            }
+                        </Show>
+
                         // Single weekday selection (shown when recurrence_type == "weekly")
                         <Show when=move || recurrence_type.get() == "weekly" fallback=|| ()>
                             {
                                 let day_of_week_label = i18n_stored.get_value().t("task_modal.day_of_week");
                                 let weekly_hint = i18n_stored.get_value().t("task_modal.weekly_hint");
+                                let interval_weeks_label = i18n_stored.get_value().t("task_modal.interval_weeks");
+                                let interval_weeks_hint = i18n_stored.get_value().t("task_modal.interval_weeks_hint");
                                 let sunday = i18n_stored.get_value().t("weekday.sunday");
                                 let monday = i18n_stored.get_value().t("weekday.monday");
                                 let tuesday = i18n_stored.get_value().t("weekday.tuesday");
@@ -855,38 +1098,134 @@ pub fn TaskModal(
                                         </select>
                                         <small class="form-hint">{weekly_hint}</small>
                                     </div>
-                                }
-                            }
-                        </Show>
-
-                        // Day of month selection (shown when recurrence_type == "monthly")
-                        <Show when=move || recurrence_type.get() == "monthly" fallback=|| ()>
-                            {
-                                let day_of_month_label = i18n_stored.get_value().t("task_modal.day_of_month");
-                                let monthly_hint = i18n_stored.get_value().t("task_modal.monthly_hint");
-                                view! {
                                     <div class="form-group">
-                                        <label class="form-label" for="task-monthday">{day_of_month_label}</label>
+                                        <label class="form-label" for="task-interval-weeks">{interval_weeks_label}</label>
                                         <input
                                             type="number"
-                                            id="task-monthday"
+                                            id="task-interval-weeks"
                                             class="form-input"
                                             min="1"
-                                            max="31"
-                                            prop:value=move || selected_month_day.get().to_string()
+                                            max="52"
+                                            prop:value=move || interval_weeks.get().to_string()
                                             on:input=move |ev| {
-                                                if let Ok(day) = event_target_value(&ev).parse::<u8>() {
-                                                    let clamped = day.clamp(1, 31);
-                                                    selected_month_day.set(clamped);
+                                                if let Ok(weeks) = event_target_value(&ev).parse::<u16>() {
+                                                    interval_weeks.set(weeks.clamp(1, 52));
                                                 }
                                             }
                                         />
-                                        <small class="form-hint">{monthly_hint}</small>
+                                        <small class="form-hint">{interval_weeks_hint}</small>
                                     </div>
                                 }
                             }
                         </Show>
 
+                        // Day of month or nth day of week (shown when recurrence_type == "monthly")
+                        <Show when=move || recurrence_type.get() == "monthly" fallback=|| ()>
+                            {
+                                let monthly_mode_label = i18n_stored.get_value().t("task_modal.monthly_mode");
+                                let mode_day_label = i18n_stored.get_value().t("task_modal.monthly_mode_day");
+                                let mode_weekday_label = i18n_stored.get_value().t("task_modal.monthly_mode_weekday");
+                                view! {
+                                    <div class="form-group">
+                                        <label class="form-label" for="task-monthly-mode">{monthly_mode_label}</label>
+                                        <select
+                                            id="task-monthly-mode"
+                                            class="form-select"
+                                            on:change=move |ev| monthly_by_weekday.set(event_target_value(&ev) == "weekday")
+                                        >
+                                            <option value="day" selected=move || !monthly_by_weekday.get()>{mode_day_label}</option>
+                                            <option value="weekday" selected=move || monthly_by_weekday.get()>{mode_weekday_label}</option>
+                                        </select>
+                                    </div>
+                                }
+                            }
+                            <Show when=move || !monthly_by_weekday.get() fallback=|| ()>
+                                {
+                                    let day_of_month_label = i18n_stored.get_value().t("task_modal.day_of_month");
+                                    let monthly_hint = i18n_stored.get_value().t("task_modal.monthly_hint");
+                                    view! {
+                                        <div class="form-group">
+                                            <label class="form-label" for="task-monthday">{day_of_month_label}</label>
+                                            <input
+                                                type="number"
+                                                id="task-monthday"
+                                                class="form-input"
+                                                min="1"
+                                                max="31"
+                                                prop:value=move || selected_month_day.get().to_string()
+                                                on:input=move |ev| {
+                                                    if let Ok(day) = event_target_value(&ev).parse::<u8>() {
+                                                        let clamped = day.clamp(1, 31);
+                                                        selected_month_day.set(clamped);
+                                                    }
+                                                }
+                                            />
+                                            <small class="form-hint">{monthly_hint}</small>
+                                        </div>
+                                    }
+                                }
+                            </Show>
+                            <Show when=move || monthly_by_weekday.get() fallback=|| ()>
+                                {
+                                    let i18n = i18n_stored.get_value();
+                                    let ordinal_label = i18n.t("task_modal.ordinal");
+                                    let day_of_week_label = i18n.t("task_modal.day_of_week");
+                                    let ordinals: Vec<(i8, String)> = vec![
+                                        (1, i18n.t("task_modal.ordinal_1")),
+                                        (2, i18n.t("task_modal.ordinal_2")),
+                                        (3, i18n.t("task_modal.ordinal_3")),
+                                        (4, i18n.t("task_modal.ordinal_4")),
+                                        (-1, i18n.t("task_modal.ordinal_last")),
+                                    ];
+                                    let weekdays: Vec<(u8, String)> = vec![
+                                        (1, i18n.t("weekday.monday")),
+                                        (2, i18n.t("weekday.tuesday")),
+                                        (3, i18n.t("weekday.wednesday")),
+                                        (4, i18n.t("weekday.thursday")),
+                                        (5, i18n.t("weekday.friday")),
+                                        (6, i18n.t("weekday.saturday")),
+                                        (0, i18n.t("weekday.sunday")),
+                                    ];
+                                    view! {
+                                        <div class="form-group" style="display: flex; gap: 0.5rem;">
+                                            <div style="flex: 1;">
+                                                <label class="form-label" for="task-ordinal">{ordinal_label}</label>
+                                                <select
+                                                    id="task-ordinal"
+                                                    class="form-select"
+                                                    on:change=move |ev| {
+                                                        if let Ok(ordinal) = event_target_value(&ev).parse::<i8>() {
+                                                            selected_ordinal.set(ordinal);
+                                                        }
+                                                    }
+                                                >
+                                                    {ordinals.into_iter().map(|(ordinal, label)| view! {
+                                                        <option value=ordinal.to_string() selected=move || selected_ordinal.get() == ordinal>{label}</option>
+                                                    }).collect_view()}
+                                                </select>
+                                            </div>
+                                            <div style="flex: 1;">
+                                                <label class="form-label" for="task-month-weekday">{day_of_week_label}</label>
+                                                <select
+                                                    id="task-month-weekday"
+                                                    class="form-select"
+                                                    on:change=move |ev| {
+                                                        if let Ok(day) = event_target_value(&ev).parse::<u8>() {
+                                                            selected_month_weekday.set(day);
+                                                        }
+                                                    }
+                                                >
+                                                    {weekdays.into_iter().map(|(day, label)| view! {
+                                                        <option value=day.to_string() selected=move || selected_month_weekday.get() == day>{label}</option>
+                                                    }).collect_view()}
+                                                </select>
+                                            </div>
+                                        </div>
+                                    }
+                                }
+                            </Show>
+                        </Show>
+
                         // Multiple weekday selection (shown when recurrence_type == "weekdays")
                         <Show when=move || recurrence_type.get() == "weekdays" fallback=|| ()>
                             {
@@ -980,6 +1319,54 @@ pub fn TaskModal(
                             <small class="form-hint">{i18n_stored.get_value().t("task_modal.require_review_hint")}</small>
                         </div>
 
+                        // Reviewer Section
+                        <Show when=move || requires_review.get() fallback=|| ()>
+                            <div class="form-group">
+                                <label class="form-label" for="task-reviewer-type">{i18n_stored.get_value().t("task_modal.reviewer_label")}</label>
+                                {
+                                    let initial_reviewer_type = reviewer_type.get_untracked();
+                                    let any_label = i18n_stored.get_value().t("task_modal.reviewer_any");
+                                    let member_label = i18n_stored.get_value().t("task_modal.reviewer_member");
+                                    let except_label = i18n_stored.get_value().t("task_modal.reviewer_except_completer");
+                                    view! {
+                                        <select
+                                            id="task-reviewer-type"
+                                            class="form-select"
+                                            on:change=move |ev| reviewer_type.set(event_target_value(&ev))
+                                        >
+                                            <option value="any_reviewer" selected=initial_reviewer_type == "any_reviewer">{any_label}</option>
+                                            <option value="member" selected=initial_reviewer_type == "member">{member_label}</option>
+                                            <option value="anyone_except_completer" selected=initial_reviewer_type == "anyone_except_completer">{except_label}</option>
+                                        </select>
+                                    }
+                                }
+                                <Show when=move || reviewer_type.get() == "member" fallback=|| ()>
+                                    {
+                                        let select_label = i18n_stored.get_value().t("task_modal.reviewer_select_member");
+                                        let initial_reviewer_id = reviewer_id.get_untracked();
+                                        view! {
+                                            <select
+                                                id="task-reviewer-id"
+                                                class="form-select"
+                                                style="margin-top: 0.5rem;"
+                                                on:change=move |ev| reviewer_id.set(event_target_value(&ev))
+                                            >
+                                                <option value="" selected=initial_reviewer_id.is_empty()>{select_label}</option>
+                                                {members_stored.get_value().into_iter().map(|m| {
+                                                    let user_id = m.user.id.to_string();
+                                                    let selected = user_id == initial_reviewer_id;
+                                                    view! {
+                                                        <option value=user_id selected=selected>{m.user.username.clone()}</option>
+                                                    }
+                                                }).collect_view()}
+                                            </select>
+                                        }
+                                    }
+                                </Show>
+                                <small class="form-hint">{i18n_stored.get_value().t("task_modal.reviewer_hint")}</small>
+                            </div>
+                        </Show>
+
                         <div class="form-group">
                             <label style="display: flex; align-items: center; gap: 0.5rem; cursor: pointer;">
                                 <input
@@ -1055,29 +1442,94 @@ pub fn TaskModal(
                             <small class="form-hint">{i18n_stored.get_value().t("task_modal.due_time_hint")}</small>
                         </div>
 
+                        // Effort Section
+                        <div class="form-group">
+                            <label class="form-label" for="task-effort-minutes">{i18n_stored.get_value().t("task_modal.effort_minutes")}</label>
+                            <input
+                                type="number"
+                                id="task-effort-minutes"
+                                class="form-input"
+                                min="1"
+                                max=shared::MAX_EFFORT_MINUTES.to_string()
+                                prop:value=move || effort_minutes.get()
+                                on:input=move |ev| effort_minutes.set(event_target_value(&ev))
+                            />
+                            <small class="form-hint">{i18n_stored.get_value().t("task_modal.effort_minutes_hint")}</small>
+                        </div>
+
+                        // Season Section
+                        <div class="form-group">
+                            <label style="display: flex; align-items: center; gap: 0.5rem; cursor: pointer;">
+                                <input
+                                    type="checkbox"
+                                    prop:checked=move || season_enabled.get()
+                                    on:change=move |ev| season_enabled.set(event_target_checked(&ev))
+                                />
+                                <span>{i18n_stored.get_value().t("task_modal.season")}</span>
+                            </label>
+                            <small class="form-hint">{i18n_stored.get_value().t("task_modal.season_hint")}</small>
+                        </div>
+                        <Show when=move || season_enabled.get() fallback=|| ()>
+                            {
+                                let i18n = i18n_stored.get_value();
+                                let months: Vec<String> = MONTH_KEYS.iter().map(|key| i18n.t(key)).collect();
+                                view! {
+                                    {season_bound_input("task-season-from", i18n.t("task_modal.season_from"), season_from, months.clone())}
+                                    {season_bound_input("task-season-until", i18n.t("task_modal.season_until"), season_until, months)}
+                                }
+                            }
+                        </Show>
+
+                        // End Section
+                        <div class="form-group">
+                            <label class="form-label" for="task-ends-on">{i18n_stored.get_value().t("task_modal.ends_on")}</label>
+                            <DateInput value=ends_on id="task-ends-on" />
+                        </div>
+                        <div class="form-group">
+                            <label class="form-label" for="task-max-occurrences">{i18n_stored.get_value().t("task_modal.max_occurrences")}</label>
+                            <input
+                                type="number"
+                                id="task-max-occurrences"
+                                class="form-input"
+                                min="1"
+                                prop:value=move || max_occurrences.get()
+                                on:input=move |ev| max_occurrences.set(event_target_value(&ev))
+                            />
+                            <small class="form-hint">{i18n_stored.get_value().t("task_modal.task_end_hint")}</small>
+                        </div>
+
                         // Assignment Section
                         <div class="form-group">
-                            <label class="form-label" for="task-assigned">{i18n_stored.get_value().t("task_modal.assigned_to")}</label>
+                            <label class="form-label">{i18n_stored.get_value().t("task_modal.assigned_to")}</label>
                             {
-                                let not_assigned_label = i18n_stored.get_value().t("task_modal.not_assigned");
                                 let assigned_hint = i18n_stored.get_value().t("task_modal.assigned_hint");
                                 view! {
-                                    <select
-                                        id="task-assigned"
-                                        class="form-select"
-                                        prop:value=move || assigned_user.get()
-                                        on:change=move |ev| assigned_user.set(event_target_value(&ev))
-                                    >
-                                        <option value="" selected=initial_assigned_user_id.is_none()>{not_assigned_label}</option>
+                                    <div>
                                         {members_stored.get_value().into_iter().map(|m| {
                                             let user_id = m.user.id.to_string();
-                                            let is_selected = initial_assigned_user_id.as_ref() == Some(&user_id);
+                                            let user_id_for_check = user_id.clone();
                                             let name = m.user.username.clone();
                                             view! {
-                                                <option value=user_id selected=is_selected>{name}</option>
+                                                <label style="display: flex; align-items: center; gap: 0.5rem; cursor: pointer;">
+                                                    <input
+                                                        type="checkbox"
+                                                        prop:checked=move || assignees.with(|ids| ids.contains(&user_id_for_check))
+                                                        on:change=move |ev| {
+                                                            let checked = event_target_checked(&ev);
+                                                            let user_id = user_id.clone();
+                                                            assignees.update(|ids| {
+                                                                ids.retain(|id| *id != user_id);
+                                                                if checked {
+                                                                    ids.push(user_id);
+                                                                }
+                                                            });
+                                                        }
+                                                    />
+                                                    <span>{name}</span>
+                                                </label>
                                             }
                                         }).collect_view()}
-                                    </select>
+                                    </div>
                                     <small class="form-hint">{assigned_hint}</small>
                                 }
                             }
@@ -1455,6 +1907,91 @@ pub fn TaskModal(
     }
 }
 
+pub(crate) const MONTH_KEYS: [&str; 12] = [
+    "month.january",
+    "month.february",
+    "month.march",
+    "month.april",
+    "month.may",
+    "month.june",
+    "month.july",
+    "month.august",
+    "month.september",
+    "month.october",
+    "month.november",
+    "month.december",
+];
+
+/// Month select and day input for one end of a task's season
+fn season_bound_input(id: &'static str, label: String, value: RwSignal<(u32, u32)>, months: Vec<String>) -> impl IntoView {
+    view! {
+        <div class="form-group">
+            <label class="form-label" for=id>{label}</label>
+            <div style="display: flex; gap: 0.5rem;">
+                <select
+                    id=id
+                    class="form-select"
+                    style="flex: 2;"
+                    on:change=move |ev| {
+                        if let Ok(month) = event_target_value(&ev).parse::<u32>() {
+                            value.update(|(m, _)| *m = month);
+                        }
+                    }
+                >
+                    {months.into_iter().enumerate().map(|(index, name)| {
+                        let month = index as u32 + 1;
+                        view! { <option value=month.to_string() selected=move || value.get().0 == month>{name}</option> }
+                    }).collect_view()}
+                </select>
+                <input
+                    type="number"
+                    class="form-input"
+                    style="flex: 1;"
+                    min="1"
+                    max="31"
+                    prop:value=move || value.get().1.to_string()
+                    on:input=move |ev| {
+                        if let Ok(day) = event_target_value(&ev).parse::<u32>() {
+                            value.update(|(_, d)| *d = day.clamp(1, 31));
+                        }
+                    }
+                />
+            </div>
+        </div>
+    }
+}
+
+/// "MM-DD" of a season bound; days past the end of the month move back to its last day
+fn format_month_day((month, day): (u32, u32)) -> String {
+    let day = (1..=day)
+        .rev()
+        .find(|d| shared::parse_month_day(&format!("{:02}-{:02}", month, d)).is_some())
+        .unwrap_or(1);
+    format!("{:02}-{:02}", month, day)
+}
+
+/// Select the tag if it isn't yet, otherwise deselect it
+fn toggle_tag(ids: &mut Vec<String>, tag_id: &str) {
+    if let Some(pos) = ids.iter().position(|id| id == tag_id) {
+        ids.remove(pos);
+    } else {
+        ids.push(tag_id.to_string());
+    }
+}
+
+fn parse_tag_ids(ids: &[String]) -> Vec<Uuid> {
+    ids.iter().filter_map(|id| Uuid::parse_str(id).ok()).collect()
+}
+
+/// The reviewer type and, for a specific member, the reviewer chosen in the form
+fn parse_reviewer(reviewer_type: &str, reviewer_id: &str) -> (ReviewerType, Option<Uuid>) {
+    let reviewer_type: ReviewerType = reviewer_type.parse().unwrap_or_default();
+    let reviewer_id = Uuid::parse_str(reviewer_id)
+        .ok()
+        .filter(|_| reviewer_type == ReviewerType::Member);
+    (reviewer_type, reviewer_id)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1462,6 +1999,30 @@ mod tests {
 
     wasm_bindgen_test_configure!(run_in_browser);
 
+    #[wasm_bindgen_test]
+    fn test_toggle_tag() {
+        let mut ids = vec!["a".to_string()];
+        toggle_tag(&mut ids, "b");
+        assert_eq!(ids, vec!["a", "b"]);
+        toggle_tag(&mut ids, "a");
+        assert_eq!(ids, vec!["b"]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_parse_reviewer() {
+        let member = Uuid::new_v4();
+        assert_eq!(parse_reviewer("member", &member.to_string()), (ReviewerType::Member, Some(member)));
+        assert_eq!(parse_reviewer("anyone_except_completer", &member.to_string()), (ReviewerType::AnyoneExceptCompleter, None));
+        assert_eq!(parse_reviewer("", ""), (ReviewerType::AnyReviewer, None));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_format_month_day() {
+        assert_eq!(format_month_day((4, 1)), "04-01");
+        assert_eq!(format_month_day((2, 31)), "02-29");
+        assert_eq!(format_month_day((11, 31)), "11-30");
+    }
+
     #[wasm_bindgen_test]
     fn test_recurrence_type_to_string_daily() {
         assert_eq!(RecurrenceType::Daily.as_str(), "daily");
@@ -1648,22 +2209,34 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: Vec::new(),
             target_count: 3,
             time_period: None,
             allow_exceed_target: true,
             requires_review: false,
+            reviewer_type: ReviewerType::AnyReviewer,
+            reviewer_id: None,
             points_reward: Some(10),
             points_penalty: None,
             due_time: Some("14:00".to_string()),
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: HabitType::Good,
             category_id: None,
             category_name: None,
+            tag_ids: Vec::new(),
             archived: false,
             paused: false,
+            version: 1,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             suggestion: None,
             suggested_by: None,
+            postponed_from: None,
+            postponed_to: None,
         };
         let prefill_from = Some(prefill_task);
         let source_task = task.as_ref().or(prefill_from.as_ref());
@@ -1685,22 +2258,34 @@ mod tests {
             recurrence_type: RecurrenceType::Weekly,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: Vec::new(),
             target_count: 1,
             time_period: None,
             allow_exceed_target: false,
             requires_review: true,
+            reviewer_type: ReviewerType::AnyReviewer,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: HabitType::Good,
             category_id: None,
             category_name: None,
+            tag_ids: Vec::new(),
             archived: false,
             paused: false,
+            version: 1,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             suggestion: None,
             suggested_by: None,
+            postponed_from: None,
+            postponed_to: None,
         };
         let task = Some(edit_task);
         let prefill_task = Task {
@@ -1711,22 +2296,34 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: Vec::new(),
             target_count: 5,
             time_period: None,
             allow_exceed_target: true,
             requires_review: false,
+            reviewer_type: ReviewerType::AnyReviewer,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: HabitType::Good,
             category_id: None,
             category_name: None,
+            tag_ids: Vec::new(),
             archived: false,
             paused: false,
+            version: 1,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             suggestion: None,
             suggested_by: None,
+            postponed_from: None,
+            postponed_to: None,
         };
         let prefill_from = Some(prefill_task);
         let source_task = task.as_ref().or(prefill_from.as_ref());
@@ -1747,22 +2344,34 @@ mod tests {
             recurrence_type: RecurrenceType::Daily,
             recurrence_value: None,
             assigned_user_id: None,
+            assignee_ids: Vec::new(),
             target_count: 1,
             time_period: None,
             allow_exceed_target: true,
             requires_review: false,
+            reviewer_type: ReviewerType::AnyReviewer,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: HabitType::Good,
             category_id: None,
             category_name: None,
+            tag_ids: Vec::new(),
             archived: false,
             paused: false,
+            version: 1,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             suggestion: None,
             suggested_by: None,
+            postponed_from: None,
+            postponed_to: None,
         });
         let task_none: Option<Task> = None;
 
@@ -0,0 +1,117 @@
+//! App-wide colour theme
+//!
+//! The user's [`Theme`] from their settings decides between the light and the
+//! dark palette (the CSS custom properties under `body.dark-mode`). With
+//! `System`, the device's `prefers-color-scheme` is followed, and a household
+//! that enables dark mode in its settings switches it on as well.
+
+use gloo_storage::{LocalStorage, Storage};
+use leptos::*;
+use shared::Theme;
+use wasm_bindgen::prelude::*;
+
+/// Last chosen theme, so the right palette is shown before settings are loaded
+const THEME_KEY: &str = "theme";
+const DARK_MODE_CLASS: &str = "dark-mode";
+const PREFERS_DARK_QUERY: &str = "(prefers-color-scheme: dark)";
+
+#[derive(Clone, Copy)]
+pub struct ThemeContext {
+    theme: RwSignal<Theme>,
+    household_dark_mode: RwSignal<bool>,
+    system_dark: RwSignal<bool>,
+}
+
+impl ThemeContext {
+    pub fn theme(&self) -> Theme {
+        self.theme.get()
+    }
+
+    /// Switch to the user's theme and remember it on this device
+    pub fn set_theme(&self, theme: Theme) {
+        let _ = LocalStorage::set(THEME_KEY, theme.as_str());
+        self.theme.set(theme);
+    }
+
+    /// Dark mode setting of the household being viewed
+    pub fn set_household_dark_mode(&self, enabled: bool) {
+        self.household_dark_mode.set(enabled);
+    }
+}
+
+/// Whether the dark palette is shown. An explicit light or dark choice wins;
+/// with `System`, a household with dark mode or a device in dark mode turns it on.
+pub fn is_dark(theme: Theme, household_dark_mode: bool, system_dark: bool) -> bool {
+    match theme {
+        Theme::Light => false,
+        Theme::Dark => true,
+        Theme::System => household_dark_mode || system_dark,
+    }
+}
+
+/// Provide the theme context and keep the body class in sync with it
+pub fn provide_theme() -> ThemeContext {
+    let stored = LocalStorage::get::<String>(THEME_KEY)
+        .ok()
+        .and_then(|theme| theme.parse().ok())
+        .unwrap_or_default();
+    let ctx = ThemeContext {
+        theme: create_rw_signal(stored),
+        household_dark_mode: create_rw_signal(false),
+        system_dark: create_rw_signal(false),
+    };
+
+    watch_system_preference(ctx.system_dark);
+    create_effect(move |_| {
+        apply_dark_mode(is_dark(ctx.theme.get(), ctx.household_dark_mode.get(), ctx.system_dark.get()));
+    });
+
+    provide_context(ctx);
+    ctx
+}
+
+pub fn use_theme() -> ThemeContext {
+    expect_context::<ThemeContext>()
+}
+
+/// Track `prefers-color-scheme`, including changes while the app is open
+/// (e.g. the OS switching to dark mode at sunset)
+fn watch_system_preference(system_dark: RwSignal<bool>) {
+    let Some(query) = web_sys::window().and_then(|w| w.match_media(PREFERS_DARK_QUERY).ok().flatten()) else {
+        return;
+    };
+    system_dark.set(query.matches());
+
+    let query_for_change = query.clone();
+    let on_change = Closure::<dyn FnMut()>::new(move || system_dark.set(query_for_change.matches()));
+    query.set_onchange(Some(on_change.as_ref().unchecked_ref()));
+    on_change.forget();
+}
+
+fn apply_dark_mode(enabled: bool) {
+    if let Some(body) = web_sys::window().and_then(|w| w.document()).and_then(|d| d.body()) {
+        let _ = if enabled {
+            body.class_list().add_1(DARK_MODE_CLASS)
+        } else {
+            body.class_list().remove_1(DARK_MODE_CLASS)
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explicit_theme_wins() {
+        assert!(!is_dark(Theme::Light, true, true));
+        assert!(is_dark(Theme::Dark, false, false));
+    }
+
+    #[test]
+    fn test_system_theme_follows_device_and_household() {
+        assert!(!is_dark(Theme::System, false, false));
+        assert!(is_dark(Theme::System, false, true));
+        assert!(is_dark(Theme::System, true, false));
+    }
+}
@@ -0,0 +1,203 @@
+use leptos::*;
+use shared::{CreateWebhookRequest, UpdateWebhookRequest, Webhook, WebhookEvent};
+
+use crate::api::api_client;
+use crate::components::{Card, SectionHeader};
+use crate::i18n::use_i18n;
+use crate::utils::format_datetime;
+
+fn event_label_key(event: WebhookEvent) -> String {
+    format!("webhooks.event_{}", event.as_str())
+}
+
+/// Owner-only settings section for outbound webhooks (e.g. Home Assistant, n8n)
+#[component]
+pub fn WebhookSettings(household_id: String, timezone: String) -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+    let household_id = store_value(household_id);
+    let timezone = store_value(timezone);
+
+    let webhooks = create_rw_signal(Vec::<Webhook>::new());
+    let error = create_rw_signal(Option::<String>::None);
+    let saving = create_rw_signal(false);
+    let url = create_rw_signal(String::new());
+    let secret = create_rw_signal(String::new());
+    let events = create_rw_signal(WebhookEvent::ALL.to_vec());
+
+    wasm_bindgen_futures::spawn_local(async move {
+        match api_client().list_webhooks(&household_id.get_value()).await {
+            Ok(list) => webhooks.set(list),
+            Err(e) => error.set(Some(e.to_string())),
+        }
+    });
+
+    let on_create = move |ev: web_sys::SubmitEvent| {
+        ev.prevent_default();
+        saving.set(true);
+        error.set(None);
+
+        let request = CreateWebhookRequest {
+            url: url.get(),
+            secret: secret.get(),
+            events: events.get(),
+        };
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().create_webhook(&household_id.get_value(), request).await {
+                Ok(webhook) => {
+                    webhooks.update(|list| list.push(webhook));
+                    url.set(String::new());
+                    secret.set(String::new());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            saving.set(false);
+        });
+    };
+
+    let on_toggle = move |webhook_id: String, enabled: bool| {
+        let request = UpdateWebhookRequest { enabled: Some(enabled), ..Default::default() };
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().update_webhook(&household_id.get_value(), &webhook_id, request).await {
+                Ok(updated) => webhooks.update(|list| {
+                    if let Some(entry) = list.iter_mut().find(|w| w.id == updated.id) {
+                        *entry = updated;
+                    }
+                }),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_delete = move |webhook_id: String| {
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().delete_webhook(&household_id.get_value(), &webhook_id).await {
+                Ok(()) => webhooks.update(|list| list.retain(|w| w.id.to_string() != webhook_id)),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    view! {
+        <Card>
+            <SectionHeader>{i18n_stored.get_value().t("webhooks.title")}</SectionHeader>
+            <p class="form-hint" style="margin-bottom: 1rem;">{i18n_stored.get_value().t("webhooks.hint")}</p>
+
+            {move || error.get().map(|e| view! {
+                <div class="alert alert-error" style="margin-bottom: 1rem;">{e}</div>
+            })}
+
+            {move || {
+                let i18n = i18n_stored.get_value();
+                let list = webhooks.get();
+                if list.is_empty() {
+                    return view! { <p class="empty-state">{i18n.t("webhooks.empty")}</p> }.into_view();
+                }
+                list.into_iter().map(|webhook| {
+                    let toggle_id = webhook.id.to_string();
+                    let delete_id = webhook.id.to_string();
+                    let enabled = webhook.enabled;
+                    let event_names = webhook.events.iter()
+                        .map(|event| i18n.t(&event_label_key(*event)))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    let status = match (webhook.last_delivery_at, webhook.last_error) {
+                        (None, _) => i18n.t("webhooks.never_delivered"),
+                        (Some(at), None) => i18n.t_with("webhooks.last_delivery_ok", &[("date", &format_datetime(at, &timezone.get_value()))]),
+                        (Some(at), Some(err)) => i18n.t_with("webhooks.last_delivery_failed", &[("date", &format_datetime(at, &timezone.get_value())), ("error", &err)]),
+                    };
+                    view! {
+                        <div class="pending-review-item">
+                            <div class="pending-review-content">
+                                <div class="pending-review-task" style="word-break: break-all;">{webhook.url.clone()}</div>
+                                <div class="pending-review-meta">{event_names}</div>
+                                <div class="pending-review-meta">{status}</div>
+                            </div>
+                            <div class="pending-review-actions">
+                                <label style="display: flex; align-items: center; gap: 0.5rem; cursor: pointer;">
+                                    <input
+                                        type="checkbox"
+                                        prop:checked=enabled
+                                        on:change=move |ev| on_toggle(toggle_id.clone(), event_target_checked(&ev))
+                                    />
+                                    {i18n.t("webhooks.enabled")}
+                                </label>
+                                <button class="btn btn-danger btn-sm" on:click=move |_| on_delete(delete_id.clone())>
+                                    {i18n.t("common.delete")}
+                                </button>
+                            </div>
+                        </div>
+                    }
+                }).collect_view()
+            }}
+
+            <form on:submit=on_create style="margin-top: 1rem;">
+                <div class="form-group">
+                    <label class="form-label" for="webhook-url">{i18n_stored.get_value().t("webhooks.url")}</label>
+                    <input
+                        type="url"
+                        id="webhook-url"
+                        class="form-input"
+                        required
+                        placeholder="https://"
+                        prop:value=move || url.get()
+                        on:input=move |ev| url.set(event_target_value(&ev))
+                    />
+                </div>
+                <div class="form-group">
+                    <label class="form-label" for="webhook-secret">{i18n_stored.get_value().t("webhooks.secret")}</label>
+                    <input
+                        type="password"
+                        id="webhook-secret"
+                        class="form-input"
+                        required
+                        autocomplete="new-password"
+                        prop:value=move || secret.get()
+                        on:input=move |ev| secret.set(event_target_value(&ev))
+                    />
+                    <small class="form-hint">{i18n_stored.get_value().t("webhooks.secret_hint")}</small>
+                </div>
+                <div class="form-group">
+                    <label class="form-label">{i18n_stored.get_value().t("webhooks.events")}</label>
+                    {WebhookEvent::ALL.into_iter().map(|event| view! {
+                        <label style="display: flex; align-items: center; gap: 0.5rem; cursor: pointer;">
+                            <input
+                                type="checkbox"
+                                prop:checked=move || events.with(|list| list.contains(&event))
+                                on:change=move |ev| {
+                                    let checked = event_target_checked(&ev);
+                                    events.update(|list| {
+                                        list.retain(|e| *e != event);
+                                        if checked {
+                                            list.push(event);
+                                        }
+                                    });
+                                }
+                            />
+                            {i18n_stored.get_value().t(&event_label_key(event))}
+                        </label>
+                    }).collect_view()}
+                </div>
+                <button
+                    type="submit"
+                    class="btn btn-primary"
+                    disabled=move || saving.get() || events.with(|list| list.is_empty())
+                >
+                    {i18n_stored.get_value().t("webhooks.add")}
+                </button>
+            </form>
+        </Card>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_event_label_keys() {
+        assert_eq!(event_label_key(WebhookEvent::TaskCompleted), "webhooks.event_task_completed");
+        assert_eq!(event_label_key(WebhookEvent::ChatMessage), "webhooks.event_chat_message");
+    }
+}
@@ -1,41 +1,148 @@
 use std::collections::HashMap;
+
+use gloo_net::http::Request;
+use gloo_storage::{LocalStorage, Storage};
 use leptos::*;
+use shared::{HouseholdSettings, PunishmentTerminology};
 
 /// Translation data loaded from JSON files
 type Translations = HashMap<String, String>;
 
+/// Where translations other than the embedded English ones are served from
+const TRANSLATIONS_BASE: &str = "/assets/i18n";
+/// Last used language, so the app starts in it before settings are loaded
+const LANGUAGE_KEY: &str = "language";
+const DEFAULT_LANGUAGE: &str = "en";
+
+/// What the current household calls punishments. Swapped into every
+/// translation, so the wording changes without separate translation files.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PunishmentTerms {
+    pub terminology: PunishmentTerminology,
+    pub singular: Option<String>,
+    pub plural: Option<String>,
+}
+
+impl PunishmentTerms {
+    pub fn from_settings(settings: &HouseholdSettings) -> Self {
+        Self {
+            terminology: settings.punishment_terminology,
+            singular: settings.punishment_label_singular.clone(),
+            plural: settings.punishment_label_plural.clone(),
+        }
+    }
+}
+
 /// I18n context that provides translation functions
 #[derive(Clone)]
 pub struct I18nContext {
     pub language: RwSignal<String>,
     translations: RwSignal<Translations>,
+    /// Languages fetched so far, merged over English
+    loaded: StoredValue<HashMap<String, Translations>>,
+    punishment_terms: RwSignal<PunishmentTerms>,
 }
 
 impl I18nContext {
     /// Create a new I18nContext with the specified language
     pub fn new(language: String) -> Self {
-        let translations = load_translations(&language);
-        Self {
-            language: create_rw_signal(language),
-            translations: create_rw_signal(translations),
-        }
+        let ctx = Self {
+            language: create_rw_signal(DEFAULT_LANGUAGE.to_string()),
+            translations: create_rw_signal(embedded_translations()),
+            loaded: store_value(HashMap::new()),
+            punishment_terms: create_rw_signal(PunishmentTerms::default()),
+        };
+        ctx.set_language(&language);
+        ctx
     }
 
     /// Translate a key to the current language
     /// Returns the key itself if translation is not found
     pub fn t(&self, key: &str) -> String {
-        self.translations
-            .get()
-            .get(key)
+        let translations = self.translations.get();
+        let text = translations.get(key).cloned().unwrap_or_else(|| key.to_string());
+        self.apply_punishment_terms(&translations, text)
+    }
+
+    /// Translate a key and fill in its `{name}` placeholders
+    pub fn t_with(&self, key: &str, args: &[(&str, &str)]) -> String {
+        interpolate(&self.t(key), args)
+    }
+
+    /// Translate a key whose wording depends on a number, using the `_one` or
+    /// `_other` variant of the key. `{count}` and the given placeholders are filled in.
+    pub fn t_plural(&self, key: &str, count: i64, args: &[(&str, &str)]) -> String {
+        let translations = self.translations.get();
+        let variant = format!("{}_{}", key, plural_category(&self.language.get(), count));
+        let text = translations
+            .get(&variant)
+            .or_else(|| translations.get(key))
             .cloned()
-            .unwrap_or_else(|| key.to_string())
+            .unwrap_or(variant);
+        let text = self.apply_punishment_terms(&translations, text);
+        let count = count.to_string();
+        let mut args = args.to_vec();
+        args.push(("count", &count));
+        interpolate(&text, &args)
     }
 
-    /// Change the current language
+    /// Change the current language. English is built in; other languages are
+    /// fetched once and keep showing English until they have arrived.
     pub fn set_language(&self, lang: &str) {
-        let translations = load_translations(lang);
+        let _ = LocalStorage::set(LANGUAGE_KEY, lang);
         self.language.set(lang.to_string());
-        self.translations.set(translations);
+
+        if lang == DEFAULT_LANGUAGE {
+            self.translations.set(embedded_translations());
+            return;
+        }
+        if let Some(translations) = self.loaded.with_value(|loaded| loaded.get(lang).cloned()) {
+            self.translations.set(translations);
+            return;
+        }
+
+        let ctx = self.clone();
+        let lang = lang.to_string();
+        wasm_bindgen_futures::spawn_local(async move {
+            let Some(overlay) = fetch_translations(&lang).await else {
+                return;
+            };
+            let mut translations = embedded_translations();
+            translations.extend(overlay);
+            ctx.loaded.update_value(|loaded| {
+                loaded.insert(lang.clone(), translations.clone());
+            });
+            // The language may have changed again while this one was loading
+            if ctx.language.get_untracked() == lang {
+                ctx.translations.set(translations);
+            }
+        });
+    }
+
+    /// Use the household's wording for punishments from now on
+    pub fn set_punishment_terms(&self, terms: PunishmentTerms) {
+        if self.punishment_terms.get_untracked() != terms {
+            self.punishment_terms.set(terms);
+        }
+    }
+
+    fn apply_punishment_terms(&self, translations: &Translations, text: String) -> String {
+        let terms = self.punishment_terms.get();
+        let lookup = |key: &str| translations.get(key).cloned().unwrap_or_default();
+        let (singular, plural) = match terms.terminology {
+            PunishmentTerminology::Punishments => return text,
+            PunishmentTerminology::Consequences => (lookup("terms.consequence"), lookup("terms.consequences")),
+            PunishmentTerminology::Custom => match (terms.singular, terms.plural) {
+                (Some(singular), plural) => (singular.clone(), plural.unwrap_or(singular)),
+                (None, Some(plural)) => (plural.clone(), plural),
+                (None, None) => return text,
+            },
+        };
+        replace_term(
+            &text,
+            (&lookup("terms.punishment"), &lookup("terms.punishments")),
+            (&singular, &plural),
+        )
     }
 
     /// Get the current language code
@@ -44,14 +151,64 @@ impl I18nContext {
     }
 }
 
-/// Load translations for a language from embedded JSON
-fn load_translations(lang: &str) -> Translations {
-    let json = match lang {
-        "de" => include_str!("../translations/de.json"),
-        _ => include_str!("../translations/en.json"),
-    };
+/// English translations, compiled into the app as the fallback for every language
+fn embedded_translations() -> Translations {
+    serde_json::from_str(include_str!("../../i18n/en.json")).unwrap_or_default()
+}
+
+async fn fetch_translations(lang: &str) -> Option<Translations> {
+    let response = Request::get(&format!("{}/{}.json", TRANSLATIONS_BASE, lang))
+        .send()
+        .await
+        .ok()
+        .filter(|r| r.ok())?;
+    response.json().await.ok()
+}
+
+/// Replace `{name}` placeholders with their values
+fn interpolate(text: &str, args: &[(&str, &str)]) -> String {
+    args.iter()
+        .fold(text.to_string(), |text, (name, value)| text.replace(&format!("{{{}}}", name), value))
+}
+
+fn with_first_char(word: &str, upper: bool) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) if upper => first.to_uppercase().chain(chars).collect(),
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Replace a term given as (singular, plural) in a translated text, keeping
+/// whether it starts upper or lower case. The plural goes first because it
+/// usually contains the singular.
+fn replace_term(text: &str, from: (&str, &str), to: (&str, &str)) -> String {
+    let mut text = text.to_string();
+    for (from, to) in [(from.1, to.1), (from.0, to.0)] {
+        if from.is_empty() || to.is_empty() {
+            continue;
+        }
+        for upper in [true, false] {
+            text = text.replace(&with_first_char(from, upper), &with_first_char(to, upper));
+        }
+    }
+    text
+}
 
-    serde_json::from_str(json).unwrap_or_default()
+/// Plural category of `count` in a language. English and German only
+/// distinguish one from everything else.
+fn plural_category(_lang: &str, count: i64) -> &'static str {
+    if count == 1 {
+        "one"
+    } else {
+        "other"
+    }
+}
+
+/// Language to start with: the last one used on this device
+pub fn initial_language() -> String {
+    LocalStorage::get::<String>(LANGUAGE_KEY).unwrap_or_else(|_| DEFAULT_LANGUAGE.to_string())
 }
 
 /// Provide I18n context to the application
@@ -67,36 +224,42 @@ pub fn use_i18n() -> I18nContext {
 
 /// Get the list of supported languages
 pub fn supported_languages() -> Vec<(&'static str, &'static str)> {
-    vec![
-        ("en", "English"),
-        ("de", "Deutsch"),
-    ]
+    shared::SUPPORTED_LANGUAGES.to_vec()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn load(lang: &str) -> Translations {
+        let json = match lang {
+            "de" => include_str!("../../i18n/de.json"),
+            _ => include_str!("../../i18n/en.json"),
+        };
+        serde_json::from_str(json).unwrap()
+    }
+
     #[test]
     fn test_load_translations_en() {
-        let translations = load_translations("en");
+        let translations = embedded_translations();
         assert!(!translations.is_empty());
         assert_eq!(translations.get("common.save").unwrap(), "Save");
     }
 
     #[test]
     fn test_load_translations_de() {
-        let translations = load_translations("de");
+        let translations = load("de");
         assert!(!translations.is_empty());
         assert_eq!(translations.get("common.save").unwrap(), "Speichern");
     }
 
     #[test]
-    fn test_load_translations_fallback() {
-        let translations = load_translations("invalid");
-        assert!(!translations.is_empty());
-        // Should fallback to English
-        assert_eq!(translations.get("common.save").unwrap(), "Save");
+    fn test_every_supported_language_has_a_translation_file() {
+        let english = load("en");
+        for (code, _) in supported_languages() {
+            let translations = load(code);
+            assert_eq!(translations.len(), english.len(), "{} is missing keys", code);
+        }
     }
 
     #[test]
@@ -106,4 +269,45 @@ mod tests {
         assert!(langs.iter().any(|(code, _)| *code == "en"));
         assert!(langs.iter().any(|(code, _)| *code == "de"));
     }
+
+    #[test]
+    fn test_interpolate() {
+        assert_eq!(
+            interpolate("{success} of {total} saved", &[("success", "3"), ("total", "4")]),
+            "3 of 4 saved"
+        );
+        assert_eq!(interpolate("No placeholders", &[("count", "1")]), "No placeholders");
+    }
+
+    #[test]
+    fn test_replace_term() {
+        let from = ("Punishment", "Punishments");
+        let to = ("Consequence", "Consequences");
+        assert_eq!(replace_term("Create Punishment", from, to), "Create Consequence");
+        assert_eq!(replace_term("No punishments yet", from, to), "No consequences yet");
+        assert_eq!(replace_term("Assign a punishment to {user}", from, to), "Assign a consequence to {user}");
+        assert_eq!(replace_term("Zufalls-Strafe", ("Strafe", "Strafen"), ("Auszeit", "Auszeiten")), "Zufalls-Auszeit");
+        assert_eq!(replace_term("Rewards", from, to), "Rewards");
+    }
+
+    #[test]
+    fn test_plural_category() {
+        assert_eq!(plural_category("en", 1), "one");
+        assert_eq!(plural_category("de", 0), "other");
+        assert_eq!(plural_category("de", 5), "other");
+    }
+
+    #[test]
+    fn test_plural_keys_have_both_forms() {
+        for lang in ["en", "de"] {
+            let translations = load(lang);
+            let plural_keys = translations
+                .iter()
+                .filter(|(_, text)| text.contains("{count}"))
+                .filter_map(|(key, _)| key.strip_suffix("_one"));
+            for key in plural_keys {
+                assert!(translations.contains_key(&format!("{}_other", key)), "{}: {}_other", lang, key);
+            }
+        }
+    }
 }
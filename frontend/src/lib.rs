@@ -1,3 +1,9 @@
+// Component tests assert on literal values and build small vecs, which newer clippy flags
+#![cfg_attr(
+    test,
+    allow(clippy::eq_op, clippy::assertions_on_constants, clippy::useless_vec)
+)]
+
 pub mod api;
 pub mod app;
 pub mod components;
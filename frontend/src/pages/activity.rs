@@ -1,14 +1,18 @@
 use leptos::*;
 use leptos_router::*;
-use shared::{ActivityLogWithUsers, ActivityType, HouseholdSettings};
+use shared::{ActivityLogWithUsers, ActivityType, HouseholdSettings, PaginationQuery};
 
-use crate::api::ApiClient;
+use crate::api::api_client;
 use crate::components::loading::Loading;
+use crate::components::theme::use_theme;
 use crate::i18n::{use_i18n, I18nContext};
 use crate::utils::format_datetime;
 
+const PAGE_SIZE: i64 = 100;
+
 #[component]
 pub fn ActivityPage() -> impl IntoView {
+    let theme = use_theme();
     let i18n = use_i18n();
     let i18n_stored = store_value(i18n);
 
@@ -18,6 +22,8 @@ pub fn ActivityPage() -> impl IntoView {
     let activities = create_rw_signal(Vec::<ActivityLogWithUsers>::new());
     let settings = create_rw_signal(Option::<HouseholdSettings>::None);
     let loading = create_rw_signal(true);
+    let loading_more = create_rw_signal(false);
+    let has_more = create_rw_signal(false);
     let error = create_rw_signal(Option::<String>::None);
 
     // Load activities and settings
@@ -32,13 +38,14 @@ pub fn ActivityPage() -> impl IntoView {
 
         // Load activities
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::list_activities(&id_for_activities, Some(100)).await {
-                Ok(a) => {
-                    activities.set(a);
+            match api_client().list_activities(&id_for_activities, PaginationQuery::new(PAGE_SIZE, 0)).await {
+                Ok(page) => {
+                    has_more.set(page.has_more());
+                    activities.set(page.items);
                     loading.set(false);
                 }
                 Err(e) => {
-                    error.set(Some(e));
+                    error.set(Some(e.to_string()));
                     loading.set(false);
                 }
             }
@@ -46,13 +53,29 @@ pub fn ActivityPage() -> impl IntoView {
 
         // Load settings for dark mode
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(s) = ApiClient::get_household_settings(&id_for_settings).await {
-                apply_dark_mode(s.dark_mode);
+            if let Ok(s) = api_client().get_household_settings(&id_for_settings).await {
+                theme.set_household_dark_mode(s.dark_mode);
                 settings.set(Some(s));
             }
         });
     });
 
+    let load_more = move |_| {
+        loading_more.set(true);
+        let id = household_id();
+        let offset = activities.with_untracked(|a| a.len() as i64);
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().list_activities(&id, PaginationQuery::new(PAGE_SIZE, offset)).await {
+                Ok(page) => {
+                    has_more.set(page.has_more());
+                    activities.update(|a| a.extend(page.items));
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            loading_more.set(false);
+        });
+    };
+
     view! {
         <div class="dashboard-header">
             <h1 class="dashboard-title">{i18n_stored.get_value().t("activity.title")}</h1>
@@ -97,6 +120,16 @@ pub fn ActivityPage() -> impl IntoView {
                     }.into_view()
                 }
             }}
+            <Show when=move || has_more.get() fallback=|| ()>
+                <button
+                    type="button"
+                    class="btn btn-outline"
+                    disabled=move || loading_more.get()
+                    on:click=load_more
+                >
+                    {i18n_stored.get_value().t("activity.load_more")}
+                </button>
+            </Show>
         </Show>
     }
 }
@@ -216,6 +249,20 @@ fn format_activity_description(activity: &ActivityLogWithUsers, i18n: &I18nConte
                 replace_placeholders(&i18n.t("activity.task_auto_archived"), &[("{name}", entity_name)])
             }
         }
+        ActivityType::TaskClaimed => {
+            if entity_name.is_empty() {
+                replace_placeholders(&i18n.t("activity.task_claimed_no_name"), &[("{actor}", actor)])
+            } else {
+                replace_placeholders(&i18n.t("activity.task_claimed"), &[("{actor}", actor), ("{name}", entity_name)])
+            }
+        }
+        ActivityType::TaskReleased => {
+            if entity_name.is_empty() {
+                replace_placeholders(&i18n.t("activity.task_released_no_name"), &[("{actor}", actor)])
+            } else {
+                replace_placeholders(&i18n.t("activity.task_released"), &[("{actor}", actor), ("{name}", entity_name)])
+            }
+        }
 
         // Reward events
         ActivityType::RewardCreated => {
@@ -335,6 +382,38 @@ fn format_activity_description(activity: &ActivityLogWithUsers, i18n: &I18nConte
                 replace_placeholders(&i18n.t("activity.reward_random_picked"), &[("{actor}", actor), ("{picked_name}", picked_name)])
             }
         }
+        ActivityType::RewardGifted => {
+            let to = affected.unwrap_or("?");
+            if entity_name.is_empty() {
+                replace_placeholders(&i18n.t("activity.reward_gifted_no_name"), &[("{actor}", actor), ("{user}", to)])
+            } else {
+                replace_placeholders(&i18n.t("activity.reward_gifted"), &[("{actor}", actor), ("{name}", entity_name), ("{user}", to)])
+            }
+        }
+
+        ActivityType::RewardPurchaseRequested => {
+            if entity_name.is_empty() {
+                replace_placeholders(&i18n.t("activity.reward_purchase_requested_no_name"), &[("{actor}", actor)])
+            } else {
+                replace_placeholders(&i18n.t("activity.reward_purchase_requested"), &[("{actor}", actor), ("{name}", entity_name)])
+            }
+        }
+        ActivityType::RewardPurchaseApproved => {
+            let user = affected.unwrap_or("?");
+            if entity_name.is_empty() {
+                replace_placeholders(&i18n.t("activity.reward_purchase_approved_no_name"), &[("{actor}", actor), ("{user}", user)])
+            } else {
+                replace_placeholders(&i18n.t("activity.reward_purchase_approved"), &[("{actor}", actor), ("{user}", user), ("{name}", entity_name)])
+            }
+        }
+        ActivityType::RewardPurchaseRejected => {
+            let user = affected.unwrap_or("?");
+            if entity_name.is_empty() {
+                replace_placeholders(&i18n.t("activity.reward_purchase_rejected_no_name"), &[("{actor}", actor), ("{user}", user)])
+            } else {
+                replace_placeholders(&i18n.t("activity.reward_purchase_rejected"), &[("{actor}", actor), ("{user}", user), ("{name}", entity_name)])
+            }
+        }
 
         // Punishment confirmation events
         ActivityType::PunishmentCompletionApproved => {
@@ -413,6 +492,32 @@ fn format_activity_description(activity: &ActivityLogWithUsers, i18n: &I18nConte
                 replace_placeholders(&i18n.t("activity.points_adjusted_no_user"), &[("{actor}", actor)])
             }
         }
+        ActivityType::PointsDecayed => {
+            let user = affected.unwrap_or("?");
+            let points = activity.log.details.as_ref()
+                .and_then(|d| serde_json::from_str::<serde_json::Value>(d).ok())
+                .and_then(|v| v.get("points").and_then(|p| p.as_i64()));
+            match points {
+                Some(pts) => replace_placeholders(&i18n.t("activity.points_decayed"), &[("{user}", user), ("{points}", &pts.abs().to_string())]),
+                None => replace_placeholders(&i18n.t("activity.points_decayed_no_amount"), &[("{user}", user)]),
+            }
+        }
+        ActivityType::StreakFreezeUsed => {
+            replace_placeholders(&i18n.t("activity.streak_freeze_used"), &[("{actor}", actor), ("{name}", entity_name)])
+        }
+
+        // Challenge events
+        ActivityType::ChallengeCreated => {
+            replace_placeholders(&i18n.t("activity.challenge_created"), &[("{actor}", actor), ("{name}", entity_name)])
+        }
+        ActivityType::ChallengeWon => {
+            let user = affected.unwrap_or("?");
+            replace_placeholders(&i18n.t("activity.challenge_won"), &[("{user}", user), ("{name}", entity_name)])
+        }
+        ActivityType::AutomationRuleApplied => {
+            let user = affected.unwrap_or("?");
+            replace_placeholders(&i18n.t("activity.automation_rule_applied"), &[("{user}", user), ("{name}", entity_name)])
+        }
 
         // Membership events
         ActivityType::MemberJoined => {
@@ -460,18 +565,3 @@ fn format_activity_description(activity: &ActivityLogWithUsers, i18n: &I18nConte
     }
 }
 
-/// Apply dark mode class to document body
-fn apply_dark_mode(enabled: bool) {
-    if let Some(window) = web_sys::window() {
-        if let Some(document) = window.document() {
-            if let Some(body) = document.body() {
-                if enabled {
-                    let _ = body.class_list().add_1("dark-mode");
-                } else {
-                    let _ = body.class_list().remove_1("dark-mode");
-                }
-            }
-        }
-    }
-}
-
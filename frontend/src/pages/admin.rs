@@ -0,0 +1,176 @@
+use leptos::*;
+use shared::{is_child_account_email, AdminUser, HouseholdUsage};
+
+use crate::api::api_client;
+use crate::components::loading::Loading;
+use crate::i18n::use_i18n;
+use crate::utils::{format_date, format_datetime, format_size};
+
+/// Instance admin page: all accounts with disable/enable and the admin role, and
+/// per-household usage.
+/// Not linked from the navigation; the backend answers 403 for non-admins.
+#[component]
+pub fn AdminPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let users = create_rw_signal(Vec::<AdminUser>::new());
+    let households = create_rw_signal(Vec::<HouseholdUsage>::new());
+    let loading = create_rw_signal(true);
+    let error = create_rw_signal(Option::<String>::None);
+
+    wasm_bindgen_futures::spawn_local(async move {
+        match api_client().admin_list_users().await {
+            Ok(list) => users.set(list),
+            Err(e) => error.set(Some(e.to_string())),
+        }
+        match api_client().admin_household_usage().await {
+            Ok(list) => households.set(list),
+            Err(e) => error.set(Some(e.to_string())),
+        }
+        loading.set(false);
+    });
+
+    let on_toggle = move |user_id: String, disabled: bool| {
+        error.set(None);
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().admin_set_user_disabled(&user_id, disabled).await {
+                Ok(updated) => users.update(|list| {
+                    if let Some(user) = list.iter_mut().find(|u| u.id == updated.id) {
+                        *user = updated;
+                    }
+                }),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_set_admin = move |user_id: String, is_admin: bool| {
+        error.set(None);
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().admin_set_user_admin(&user_id, is_admin).await {
+                Ok(updated) => users.update(|list| {
+                    if let Some(user) = list.iter_mut().find(|u| u.id == updated.id) {
+                        *user = updated;
+                    }
+                }),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    view! {
+        <div class="dashboard-header">
+            <h1 class="dashboard-title">{i18n_stored.get_value().t("admin.title")}</h1>
+        </div>
+
+        {move || error.get().map(|e| view! {
+            <div class="alert alert-error" style="margin-bottom: 1rem;">{e}</div>
+        })}
+
+        <Show when=move || !loading.get() fallback=|| view! { <Loading /> }>
+            <div class="card">
+                <div class="card-header">
+                    <h3 class="card-title">
+                        {move || i18n_stored.get_value().t_with("admin.users", &[("count", &users.get().len().to_string())])}
+                    </h3>
+                </div>
+                <div style="padding: 1rem;">
+                    {move || {
+                        let i18n = i18n_stored.get_value();
+                        users.get().into_iter().map(|user| {
+                            let user_id = user.id.to_string();
+                            let admin_user_id = user_id.clone();
+                            let disabled = user.disabled_at.is_some();
+                            let is_admin = user.is_admin;
+                            let mut meta = vec![
+                                user.email.clone(),
+                                i18n.t_with("admin.joined", &[("date", &format_date(user.created_at, "UTC"))]),
+                                i18n.t_plural("admin.households_count", user.household_count, &[]),
+                            ];
+                            if let Some(at) = user.disabled_at {
+                                meta.push(i18n.t_with("admin.disabled_since", &[("date", &format_datetime(at, "UTC"))]));
+                            }
+                            view! {
+                                <div class="pending-review-item">
+                                    <div class="pending-review-content">
+                                        <div class="pending-review-task">
+                                            {user.username.clone()}
+                                            {user.is_admin.then(|| view! {
+                                                <span class="task-badge" style="margin-left: 0.5rem;">{i18n.t("admin.admin_badge")}</span>
+                                            })}
+                                        </div>
+                                        <div class="pending-review-meta">{meta.join(" · ")}</div>
+                                    </div>
+                                    <div class="pending-review-actions">
+                                        {(!is_child_account_email(&user.email)).then(|| view! {
+                                            <button class="btn btn-outline btn-sm" on:click=move |_| on_set_admin(admin_user_id.clone(), !is_admin)>
+                                                {i18n.t(if is_admin { "admin.revoke_admin" } else { "admin.grant_admin" })}
+                                            </button>
+                                        })}
+                                        {if disabled {
+                                            view! {
+                                                <button class="btn btn-outline btn-sm" on:click=move |_| on_toggle(user_id.clone(), false)>
+                                                    {i18n.t("admin.enable")}
+                                                </button>
+                                            }
+                                        } else {
+                                            view! {
+                                                <button class="btn btn-danger btn-sm" on:click=move |_| on_toggle(user_id.clone(), true)>
+                                                    {i18n.t("admin.disable")}
+                                                </button>
+                                            }
+                                        }}
+                                    </div>
+                                </div>
+                            }
+                        }).collect_view()
+                    }}
+                </div>
+            </div>
+
+            <div class="card">
+                <div class="card-header">
+                    <h3 class="card-title">{i18n_stored.get_value().t("admin.households")}</h3>
+                </div>
+                <div class="admin-usage" style="padding: 1rem;">
+                    {move || {
+                        let i18n = i18n_stored.get_value();
+                        let list = households.get();
+                        if list.is_empty() {
+                            return view! { <p class="empty-state">{i18n.t("admin.no_households")}</p> }.into_view();
+                        }
+                        view! {
+                            <table class="admin-usage-table">
+                                <thead>
+                                    <tr>
+                                        <th>{i18n.t("admin.household")}</th>
+                                        <th>{i18n.t("admin.members")}</th>
+                                        <th>{i18n.t("admin.tasks")}</th>
+                                        <th>{i18n.t("admin.completions")}</th>
+                                        <th>{i18n.t("admin.point_transactions")}</th>
+                                        <th>{i18n.t("admin.chat_messages")}</th>
+                                        <th>{i18n.t("admin.attachments")}</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    {list.into_iter().map(|usage| view! {
+                                        <tr>
+                                            <td>{usage.name}</td>
+                                            <td>{usage.member_count}</td>
+                                            <td>{usage.task_count}</td>
+                                            <td>{usage.completion_count}</td>
+                                            <td>{usage.point_transaction_count}</td>
+                                            <td>{usage.chat_message_count}</td>
+                                            <td>{format!("{} ({})", usage.attachment_count, format_size(usage.attachment_bytes))}</td>
+                                        </tr>
+                                    }).collect_view()}
+                                </tbody>
+                            </table>
+                        }.into_view()
+                    }}
+                </div>
+            </div>
+        </Show>
+    }
+}
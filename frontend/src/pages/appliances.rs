@@ -0,0 +1,329 @@
+use chrono::NaiveDate;
+use leptos::*;
+use leptos_router::*;
+use shared::{Appliance, CreateApplianceRequest, CreateMaintenanceRequest};
+use uuid::Uuid;
+
+use crate::api::api_client;
+use crate::components::date_input::DateInput;
+use crate::components::household_layout::HouseholdContext;
+use crate::components::loading::Loading;
+use crate::i18n::use_i18n;
+use crate::utils::today_in_tz;
+
+/// Only http(s) links are rendered as links; anything else is shown as a note
+fn manual_link(manual_url: &str) -> Option<&str> {
+    (manual_url.starts_with("https://") || manual_url.starts_with("http://")).then_some(manual_url)
+}
+
+#[component]
+fn ApplianceCard(
+    appliance: Appliance,
+    today: NaiveDate,
+    on_changed: Callback<Appliance>,
+    on_deleted: Callback<Uuid>,
+    error: RwSignal<Option<String>>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+    let household_id = appliance.household_id.to_string();
+    let household_id_stored = store_value(household_id);
+    let appliance_id = appliance.id;
+
+    let title = create_rw_signal(String::new());
+    let interval = create_rw_signal(String::new());
+
+    let on_add_maintenance = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        let Ok(interval_days) = interval.get().trim().parse::<u16>() else {
+            error.set(Some(i18n_stored.get_value().t("appliances.invalid_interval")));
+            return;
+        };
+        if title.get().trim().is_empty() || interval_days == 0 {
+            error.set(Some(i18n_stored.get_value().t("appliances.invalid_interval")));
+            return;
+        }
+        let request = CreateMaintenanceRequest {
+            title: title.get(),
+            interval_days,
+            assigned_user_id: None,
+        };
+        let id = household_id_stored.get_value();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().add_appliance_maintenance(&id, &appliance_id.to_string(), request).await {
+                Ok(updated) => {
+                    title.set(String::new());
+                    interval.set(String::new());
+                    error.set(None);
+                    on_changed.call(updated);
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_remove_maintenance = move |task_id: Uuid| {
+        let id = household_id_stored.get_value();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().remove_appliance_maintenance(&id, &appliance_id.to_string(), &task_id.to_string()).await {
+                Ok(updated) => on_changed.call(updated),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_delete = move |_| {
+        let id = household_id_stored.get_value();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().delete_appliance(&id, &appliance_id.to_string()).await {
+                Ok(()) => on_deleted.call(appliance_id),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let under_warranty = appliance.under_warranty(today);
+    let i18n = i18n_stored.get_value();
+    let warranty = appliance.warranty_until.map(|until| {
+        let key = if under_warranty { "appliances.warranty_until" } else { "appliances.warranty_expired" };
+        i18n.t_with(key, &[("date", &until.format("%Y-%m-%d").to_string())])
+    });
+    let purchased = appliance
+        .purchased_on
+        .map(|d| i18n.t_with("appliances.purchased_on", &[("date", &d.format("%Y-%m-%d").to_string())]));
+
+    view! {
+        <div class="card appliance-card">
+            <div class="appliance-header">
+                <h3 class="card-title">{appliance.name.clone()}</h3>
+                {appliance.location.clone().map(|l| view! { <span class="appliance-location">{l}</span> })}
+                <button
+                    class="shopping-list-remove"
+                    title=i18n.t("common.delete")
+                    on:click=on_delete
+                >"×"</button>
+            </div>
+            <div class="appliance-details">
+                {purchased.map(|p| view! { <span>{p}</span> })}
+                {warranty.map(|w| view! {
+                    <span class="badge" class:badge-warning=!under_warranty>{w}</span>
+                })}
+                {appliance.manual_url.clone().map(|m| match manual_link(&m) {
+                    Some(url) => view! {
+                        <a href=url.to_string() target="_blank" rel="noopener noreferrer">{i18n.t("appliances.manual")}</a>
+                    }.into_view(),
+                    None => view! { <span>{m}</span> }.into_view(),
+                })}
+            </div>
+            {appliance.notes.clone().map(|n| view! { <p class="appliance-notes">{n}</p> })}
+
+            <h4 class="appliance-maintenance-title">{i18n.t("appliances.maintenance")}</h4>
+            <ul class="appliance-maintenance">
+                {appliance.maintenance.iter().map(|m| {
+                    let task_id = m.task_id;
+                    let every = i18n.t_plural("appliances.every_days", m.interval_days as i64, &[]);
+                    view! {
+                        <li>
+                            <span>{m.title.clone()}</span>
+                            <span class="appliance-location">{every}</span>
+                            <button
+                                class="shopping-list-remove"
+                                title=i18n.t("common.delete")
+                                on:click=move |_| on_remove_maintenance(task_id)
+                            >"×"</button>
+                        </li>
+                    }
+                }).collect_view()}
+            </ul>
+            <form class="inventory-form" on:submit=on_add_maintenance>
+                <input
+                    type="text"
+                    class="form-input"
+                    placeholder=i18n.t("appliances.maintenance_placeholder")
+                    prop:value=move || title.get()
+                    on:input=move |ev| title.set(event_target_value(&ev))
+                />
+                <input
+                    type="number"
+                    min="1"
+                    class="form-input inventory-amount-input"
+                    placeholder=i18n.t("appliances.interval_days")
+                    prop:value=move || interval.get()
+                    on:input=move |ev| interval.set(event_target_value(&ev))
+                />
+                <button type="submit" class="btn btn-outline btn-sm">
+                    {i18n.t("appliances.add_maintenance")}
+                </button>
+            </form>
+        </div>
+    }
+}
+
+#[component]
+pub fn AppliancesPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let params = use_params_map();
+    let household_id = move || params.with(|p| p.get("id").cloned().unwrap_or_default());
+
+    let settings = use_context::<HouseholdContext>().map(|ctx| ctx.settings);
+    let today = move || {
+        let timezone = settings
+            .and_then(|s| s.get())
+            .map(|s| s.timezone)
+            .unwrap_or_else(|| "UTC".to_string());
+        today_in_tz(&timezone)
+    };
+
+    let appliances = create_rw_signal(Vec::<Appliance>::new());
+    let loading = create_rw_signal(true);
+    let error = create_rw_signal(Option::<String>::None);
+
+    let name = create_rw_signal(String::new());
+    let location = create_rw_signal(String::new());
+    let purchased_on = create_rw_signal(Option::<NaiveDate>::None);
+    let warranty_until = create_rw_signal(Option::<NaiveDate>::None);
+    let manual_url = create_rw_signal(String::new());
+
+    create_effect(move |_| {
+        let id = household_id();
+        if id.is_empty() {
+            return;
+        }
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().list_appliances(&id).await {
+                Ok(list) => appliances.set(list),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            loading.set(false);
+        });
+    });
+
+    let on_add = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        if name.get().trim().is_empty() {
+            return;
+        }
+        let request = CreateApplianceRequest {
+            name: name.get(),
+            location: Some(location.get()),
+            purchased_on: purchased_on.get(),
+            warranty_until: warranty_until.get(),
+            manual_url: Some(manual_url.get()),
+            notes: None,
+        };
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().create_appliance(&id, request).await {
+                Ok(appliance) => {
+                    appliances.update(|list| list.push(appliance));
+                    name.set(String::new());
+                    location.set(String::new());
+                    purchased_on.set(None);
+                    warranty_until.set(None);
+                    manual_url.set(String::new());
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_changed = Callback::new(move |updated: Appliance| {
+        appliances.update(|list| {
+            if let Some(appliance) = list.iter_mut().find(|a| a.id == updated.id) {
+                *appliance = updated;
+            }
+        })
+    });
+    let on_deleted = Callback::new(move |appliance_id: Uuid| {
+        appliances.update(|list| list.retain(|a| a.id != appliance_id))
+    });
+
+    view! {
+        <div class="dashboard-header">
+            <h1 class="dashboard-title">{i18n_stored.get_value().t("appliances.title")}</h1>
+        </div>
+
+        {move || error.get().map(|e| view! {
+            <div class="alert alert-error">{e}</div>
+        })}
+
+        <form class="card expense-form" on:submit=on_add>
+            <h3 class="card-title">{i18n_stored.get_value().t("appliances.add")}</h3>
+            <div class="expense-form-grid">
+                <div class="form-group">
+                    <label class="form-label" for="appliance-name">{i18n_stored.get_value().t("appliances.name")}</label>
+                    <input
+                        id="appliance-name"
+                        type="text"
+                        class="form-input"
+                        prop:value=move || name.get()
+                        on:input=move |ev| name.set(event_target_value(&ev))
+                    />
+                </div>
+                <div class="form-group">
+                    <label class="form-label" for="appliance-location">{i18n_stored.get_value().t("inventory.location")}</label>
+                    <input
+                        id="appliance-location"
+                        type="text"
+                        class="form-input"
+                        prop:value=move || location.get()
+                        on:input=move |ev| location.set(event_target_value(&ev))
+                    />
+                </div>
+                <div class="form-group">
+                    <label class="form-label" for="appliance-purchased">{i18n_stored.get_value().t("appliances.purchase_date")}</label>
+                    <DateInput value=purchased_on id="appliance-purchased" />
+                </div>
+                <div class="form-group">
+                    <label class="form-label" for="appliance-warranty">{i18n_stored.get_value().t("appliances.warranty_end")}</label>
+                    <DateInput value=warranty_until id="appliance-warranty" />
+                </div>
+                <div class="form-group">
+                    <label class="form-label" for="appliance-manual">{i18n_stored.get_value().t("appliances.manual")}</label>
+                    <input
+                        id="appliance-manual"
+                        type="text"
+                        class="form-input"
+                        placeholder="https://"
+                        prop:value=move || manual_url.get()
+                        on:input=move |ev| manual_url.set(event_target_value(&ev))
+                    />
+                </div>
+            </div>
+            <button type="submit" class="btn btn-primary">{i18n_stored.get_value().t("appliances.add")}</button>
+        </form>
+
+        <Show when=move || loading.get() fallback=|| ()>
+            <Loading />
+        </Show>
+
+        {move || {
+            let list = appliances.get();
+            if list.is_empty() && !loading.get() {
+                return view! {
+                    <p class="empty-state">{i18n_stored.get_value().t("appliances.empty")}</p>
+                }.into_view();
+            }
+            let today = today();
+            list.into_iter().map(|appliance| view! {
+                <ApplianceCard appliance=appliance today=today on_changed=on_changed on_deleted=on_deleted error=error />
+            }).collect_view()
+        }}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_manual_link() {
+        assert_eq!(manual_link("https://example.com/manual.pdf"), Some("https://example.com/manual.pdf"));
+        assert_eq!(manual_link("In the kitchen drawer"), None);
+        assert_eq!(manual_link("javascript:alert(1)"), None);
+    }
+}
@@ -0,0 +1,305 @@
+use leptos::*;
+use leptos_router::*;
+use shared::{
+    AutomationRule, AutomationRuleRun, CreateAutomationRuleRequest, Punishment, Reward, RuleTrigger, Task,
+    UpdateAutomationRuleRequest,
+};
+use uuid::Uuid;
+
+use crate::api::api_client;
+use crate::components::loading::Loading;
+use crate::i18n::{use_i18n, I18nContext};
+
+/// Translation key describing a trigger, with `{n}` for the threshold
+fn trigger_key(trigger: RuleTrigger) -> &'static str {
+    match trigger {
+        RuleTrigger::StreakReached => "rules.trigger_streak",
+        RuleTrigger::FailuresInWeek => "rules.trigger_failures",
+        RuleTrigger::PointsBelow => "rules.trigger_points",
+    }
+}
+
+fn describe_trigger(i18n: &I18nContext, trigger: RuleTrigger, threshold: i64) -> String {
+    i18n.t_with(trigger_key(trigger), &[("n", &threshold.to_string())])
+}
+
+#[component]
+pub fn AutomationRulesPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let params = use_params_map();
+    let household_id = move || params.with(|p| p.get("id").cloned().unwrap_or_default());
+
+    let rules = create_rw_signal(Vec::<AutomationRule>::new());
+    let runs = create_rw_signal(Vec::<AutomationRuleRun>::new());
+    let tasks = create_rw_signal(Vec::<Task>::new());
+    let rewards = create_rw_signal(Vec::<Reward>::new());
+    let punishments = create_rw_signal(Vec::<Punishment>::new());
+    let loading = create_rw_signal(true);
+    let error = create_rw_signal(Option::<String>::None);
+
+    let name = create_rw_signal(String::new());
+    let trigger = create_rw_signal(RuleTrigger::StreakReached);
+    let threshold = create_rw_signal(String::from("3"));
+    let task_id = create_rw_signal(String::new());
+    // "reward:<id>" or "punishment:<id>"
+    let action = create_rw_signal(String::new());
+
+    create_effect(move |_| {
+        let id = household_id();
+        if id.is_empty() {
+            return;
+        }
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().list_automation_rules(&id).await {
+                Ok(list) => rules.set(list),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            if let Ok(list) = api_client().list_automation_rule_runs(&id).await {
+                runs.set(list);
+            }
+            if let Ok(list) = api_client().list_tasks(&id).await {
+                tasks.set(list.into_iter().filter(|t| !t.archived).collect());
+            }
+            if let Ok(list) = api_client().list_rewards(&id).await {
+                rewards.set(list);
+            }
+            if let Ok(list) = api_client().list_punishments(&id).await {
+                punishments.set(list);
+            }
+            loading.set(false);
+        });
+    });
+
+    let name_for = move |rule: &AutomationRule| -> String {
+        if let Some(reward_id) = rule.reward_id {
+            rewards.with(|list| list.iter().find(|r| r.id == reward_id).map(|r| format!("🎁 {}", r.name)))
+        } else if let Some(punishment_id) = rule.punishment_id {
+            punishments.with(|list| list.iter().find(|p| p.id == punishment_id).map(|p| format!("⚠️ {}", p.name)))
+        } else {
+            None
+        }
+        .unwrap_or_default()
+    };
+
+    let on_create = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        let Ok(threshold_value) = threshold.get().trim().parse::<i64>() else {
+            error.set(Some(i18n_stored.get_value().t("rules.threshold_invalid")));
+            return;
+        };
+        let action_value = action.get();
+        let (reward_id, punishment_id) = match action_value.split_once(':') {
+            Some(("reward", id)) => (Uuid::parse_str(id).ok(), None),
+            Some(("punishment", id)) => (None, Uuid::parse_str(id).ok()),
+            _ => (None, None),
+        };
+        let request = CreateAutomationRuleRequest {
+            name: name.get(),
+            trigger: trigger.get(),
+            threshold: threshold_value,
+            task_id: Uuid::parse_str(&task_id.get()).ok(),
+            reward_id,
+            punishment_id,
+        };
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().create_automation_rule(&id, request).await {
+                Ok(created) => {
+                    rules.update(|list| list.insert(0, created));
+                    name.set(String::new());
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_toggle = move |rule_id: Uuid, enabled: bool| {
+        let id = household_id();
+        let request = UpdateAutomationRuleRequest { enabled: Some(enabled), ..Default::default() };
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().update_automation_rule(&id, &rule_id.to_string(), request).await {
+                Ok(updated) => rules.update(|list| {
+                    if let Some(entry) = list.iter_mut().find(|r| r.id == rule_id) {
+                        *entry = updated;
+                    }
+                }),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_delete = move |rule_id: Uuid| {
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().delete_automation_rule(&id, &rule_id.to_string()).await {
+                Ok(()) => {
+                    rules.update(|list| list.retain(|r| r.id != rule_id));
+                    runs.update(|list| list.retain(|r| r.rule_id != rule_id));
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    view! {
+        <div class="dashboard-header">
+            <h1 class="dashboard-title">{i18n_stored.get_value().t("rules.title")}</h1>
+        </div>
+        <p class="form-hint">{i18n_stored.get_value().t("rules.description")}</p>
+
+        {move || error.get().map(|e| view! {
+            <div class="alert alert-error">{e}</div>
+        })}
+
+        <div class="card">
+            <h3 class="card-title">{i18n_stored.get_value().t("rules.create")}</h3>
+            <form on:submit=on_create>
+                <div class="form-group">
+                    <label class="form-label">{i18n_stored.get_value().t("rules.name")}</label>
+                    <input
+                        type="text"
+                        class="form-input"
+                        required
+                        prop:value=move || name.get()
+                        on:input=move |ev| name.set(event_target_value(&ev))
+                    />
+                </div>
+                <div class="form-group">
+                    <label class="form-label">{i18n_stored.get_value().t("rules.trigger")}</label>
+                    <select class="form-select" on:change=move |ev| {
+                        trigger.set(event_target_value(&ev).parse().unwrap_or_default());
+                    }>
+                        {[RuleTrigger::StreakReached, RuleTrigger::FailuresInWeek, RuleTrigger::PointsBelow]
+                            .into_iter()
+                            .map(|t| view! {
+                                <option value=t.as_str() selected=move || trigger.get() == t>
+                                    {i18n_stored.get_value().t(&format!("rules.trigger_option_{}", t.as_str()))}
+                                </option>
+                            })
+                            .collect_view()}
+                    </select>
+                </div>
+                <div class="form-group">
+                    <label class="form-label">{i18n_stored.get_value().t("rules.threshold")}</label>
+                    <input
+                        type="number"
+                        class="form-input"
+                        required
+                        prop:value=move || threshold.get()
+                        on:input=move |ev| threshold.set(event_target_value(&ev))
+                    />
+                </div>
+                <Show when=move || trigger.get() != RuleTrigger::PointsBelow fallback=|| ()>
+                    <div class="form-group">
+                        <label class="form-label">{i18n_stored.get_value().t("rules.task")}</label>
+                        <select class="form-select" on:change=move |ev| task_id.set(event_target_value(&ev))>
+                            <option value="">{i18n_stored.get_value().t("rules.all_tasks")}</option>
+                            {move || tasks.get().into_iter().map(|t| view! {
+                                <option value=t.id.to_string()>{t.title}</option>
+                            }).collect_view()}
+                        </select>
+                    </div>
+                </Show>
+                <div class="form-group">
+                    <label class="form-label">{i18n_stored.get_value().t("rules.action")}</label>
+                    <select class="form-select" required on:change=move |ev| action.set(event_target_value(&ev))>
+                        <option value="">{i18n_stored.get_value().t("rules.choose_action")}</option>
+                        {move || rewards.get().into_iter().map(|r| view! {
+                            <option value=format!("reward:{}", r.id)>{format!("🎁 {}", r.name)}</option>
+                        }).collect_view()}
+                        {move || punishments.get().into_iter().map(|p| view! {
+                            <option value=format!("punishment:{}", p.id)>{format!("⚠️ {}", p.name)}</option>
+                        }).collect_view()}
+                    </select>
+                </div>
+                <button type="submit" class="btn btn-primary">
+                    {i18n_stored.get_value().t("rules.create")}
+                </button>
+            </form>
+        </div>
+
+        <Show when=move || loading.get() fallback=|| ()>
+            <Loading />
+        </Show>
+
+        <div class="card">
+            <h3 class="card-title">{i18n_stored.get_value().t("rules.list_title")}</h3>
+            {move || {
+                let list = rules.get();
+                if list.is_empty() && !loading.get() {
+                    return view! {
+                        <p class="empty-state">{i18n_stored.get_value().t("rules.empty")}</p>
+                    }.into_view();
+                }
+                list.into_iter().map(|rule| {
+                    let rule_id = rule.id;
+                    let enabled = rule.enabled;
+                    let trigger_text = describe_trigger(&i18n_stored.get_value(), rule.trigger, rule.threshold);
+                    let consequence = name_for(&rule);
+                    view! {
+                        <div class="pending-review-item">
+                            <div class="pending-review-content">
+                                <div class="pending-review-task">{rule.name.clone()}</div>
+                                <div class="pending-review-meta">{trigger_text} " → " {consequence}</div>
+                            </div>
+                            <div class="pending-review-actions">
+                                <label style="display: flex; align-items: center; gap: 0.5rem; cursor: pointer;">
+                                    <input
+                                        type="checkbox"
+                                        prop:checked=enabled
+                                        on:change=move |ev| on_toggle(rule_id, event_target_checked(&ev))
+                                    />
+                                    {i18n_stored.get_value().t("rules.enabled")}
+                                </label>
+                                <button class="btn btn-danger btn-sm" on:click=move |_| on_delete(rule_id)>
+                                    {i18n_stored.get_value().t("common.delete")}
+                                </button>
+                            </div>
+                        </div>
+                    }
+                }).collect_view()
+            }}
+        </div>
+
+        <div class="card">
+            <h3 class="card-title">{i18n_stored.get_value().t("rules.history")}</h3>
+            {move || {
+                let list = runs.get();
+                if list.is_empty() {
+                    return view! {
+                        <p class="empty-state">{i18n_stored.get_value().t("rules.history_empty")}</p>
+                    }.into_view();
+                }
+                list.into_iter().map(|run| {
+                    let text = i18n_stored.get_value().t_with("rules.history_entry", &[
+                        ("rule", &run.rule_name),
+                        ("user", &run.username),
+                        ("value", &run.observed_value.to_string()),
+                    ]);
+                    view! {
+                        <div class="activity-item">
+                            <span>{text}</span>
+                            " "
+                            <span class="activity-time">{run.created_at.format("%d.%m.%Y %H:%M").to_string()}</span>
+                        </div>
+                    }
+                }).collect_view()
+            }}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_trigger_keys_are_distinct() {
+        assert_eq!(trigger_key(RuleTrigger::StreakReached), "rules.trigger_streak");
+        assert_ne!(trigger_key(RuleTrigger::FailuresInWeek), trigger_key(RuleTrigger::PointsBelow));
+    }
+}
@@ -0,0 +1,239 @@
+use chrono::{Datelike, Duration, Months, NaiveDate};
+use leptos::*;
+use leptos_router::*;
+use shared::TaskOccurrence;
+
+use crate::api::api_client;
+use crate::components::household_layout::HouseholdContext;
+use crate::components::loading::Loading;
+use crate::i18n::use_i18n;
+use crate::utils::today_in_tz;
+
+const WEEKDAY_KEYS: [&str; 7] = [
+    "weekday.monday",
+    "weekday.tuesday",
+    "weekday.wednesday",
+    "weekday.thursday",
+    "weekday.friday",
+    "weekday.saturday",
+    "weekday.sunday",
+];
+
+const MONTH_KEYS: [&str; 12] = [
+    "month.january",
+    "month.february",
+    "month.march",
+    "month.april",
+    "month.may",
+    "month.june",
+    "month.july",
+    "month.august",
+    "month.september",
+    "month.october",
+    "month.november",
+    "month.december",
+];
+
+/// First day of the month containing `date`
+fn month_start(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+/// Days shown in the grid for the month starting at `first`: whole weeks from
+/// Monday to Sunday, including the days of the neighbouring months that fill them
+fn month_grid(first: NaiveDate) -> Vec<NaiveDate> {
+    let start = first - Duration::days(first.weekday().num_days_from_monday() as i64);
+    let last = first + Months::new(1) - Duration::days(1);
+    let end = last + Duration::days(6 - last.weekday().num_days_from_monday() as i64);
+    start.iter_days().take_while(|d| *d <= end).collect()
+}
+
+/// CSS modifier for an occurrence: done, still open, or missed if its day has passed
+fn occurrence_class(occurrence: &TaskOccurrence, today: NaiveDate) -> &'static str {
+    if occurrence.is_completed() {
+        "calendar-occurrence done"
+    } else if occurrence.date < today {
+        "calendar-occurrence missed"
+    } else {
+        "calendar-occurrence"
+    }
+}
+
+/// Month view of the household's task schedule with the completions of past days
+#[component]
+pub fn CalendarPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let params = use_params_map();
+    let household_id = move || params.with(|p| p.get("id").cloned().unwrap_or_default());
+
+    let settings = use_context::<HouseholdContext>().map(|ctx| ctx.settings);
+    let today = move || {
+        let timezone = settings
+            .and_then(|s| s.get())
+            .map(|s| s.timezone)
+            .unwrap_or_else(|| "UTC".to_string());
+        today_in_tz(&timezone)
+    };
+
+    let month = create_rw_signal(month_start(today()));
+    let occurrences = create_rw_signal(Vec::<TaskOccurrence>::new());
+    let loading = create_rw_signal(true);
+    let error = create_rw_signal(Option::<String>::None);
+
+    create_effect(move |_| {
+        let id = household_id();
+        let first = month.get();
+        if id.is_empty() {
+            return;
+        }
+        let grid = month_grid(first);
+        let (from, to) = (grid[0], grid[grid.len() - 1]);
+        loading.set(true);
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().get_task_occurrences(&id, from, to).await {
+                Ok(list) => {
+                    error.set(None);
+                    occurrences.set(list);
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            loading.set(false);
+        });
+    });
+
+    let title = move || {
+        let first = month.get();
+        format!("{} {}", i18n_stored.get_value().t(MONTH_KEYS[first.month0() as usize]), first.year())
+    };
+
+    view! {
+        <div class="dashboard-header">
+            <h1 class="dashboard-title">{i18n_stored.get_value().t("calendar.title")}</h1>
+        </div>
+
+        {move || error.get().map(|e| view! {
+            <div class="alert alert-error" style="margin-bottom: 1rem;">{e}</div>
+        })}
+
+        <div class="card">
+            <div class="calendar-toolbar">
+                <button
+                    class="btn btn-outline btn-sm"
+                    title=i18n_stored.get_value().t("calendar.previous_month")
+                    on:click=move |_| month.update(|m| *m = *m - Months::new(1))
+                >
+                    "‹"
+                </button>
+                <h3 class="calendar-month">{title}</h3>
+                <button
+                    class="btn btn-outline btn-sm"
+                    title=i18n_stored.get_value().t("calendar.next_month")
+                    on:click=move |_| month.update(|m| *m = *m + Months::new(1))
+                >
+                    "›"
+                </button>
+                <button class="btn btn-outline btn-sm" on:click=move |_| month.set(month_start(today()))>
+                    {i18n_stored.get_value().t("dates.today")}
+                </button>
+            </div>
+
+            <Show when=move || !loading.get() fallback=|| view! { <Loading /> }>
+                {move || {
+                    let i18n = i18n_stored.get_value();
+                    let first = month.get();
+                    let today = today();
+                    let list = occurrences.get();
+                    let in_month = list.iter().any(|o| o.date.month() == first.month());
+
+                    view! {
+                        <div class="calendar-grid">
+                            {WEEKDAY_KEYS.iter().map(|key| {
+                                let name: String = i18n.t(key).chars().take(2).collect();
+                                view! { <div class="calendar-weekday">{name}</div> }
+                            }).collect_view()}
+                            {month_grid(first).into_iter().map(|day| {
+                                let mut class = String::from("calendar-day");
+                                if day.month() != first.month() {
+                                    class.push_str(" outside");
+                                }
+                                if day == today {
+                                    class.push_str(" today");
+                                }
+                                let entries = list
+                                    .iter()
+                                    .filter(|o| o.date == day)
+                                    .map(|o| {
+                                        let label = match &o.due_time {
+                                            Some(time) => format!("{} {}", time, o.title),
+                                            None => o.title.clone(),
+                                        };
+                                        view! { <div class=occurrence_class(o, today) title=label.clone()>{label}</div> }
+                                    })
+                                    .collect_view();
+                                view! {
+                                    <div class=class>
+                                        <div class="calendar-day-number">{day.day()}</div>
+                                        {entries}
+                                    </div>
+                                }
+                            }).collect_view()}
+                        </div>
+                        <div class="calendar-legend">
+                            <span class="calendar-occurrence done">{i18n.t("calendar.done")}</span>
+                            <span class="calendar-occurrence">{i18n.t("calendar.open")}</span>
+                            <span class="calendar-occurrence missed">{i18n.t("calendar.missed")}</span>
+                        </div>
+                        {(!in_month).then(|| view! {
+                            <p class="task-meta" style="padding: 0 1rem 1rem;">{i18n.t("calendar.empty")}</p>
+                        })}
+                    }
+                }}
+            </Show>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_month_grid_covers_whole_weeks() {
+        // February 2024 starts on a Thursday and ends on a Thursday
+        let grid = month_grid(date(2024, 2, 1));
+        assert_eq!(grid.len(), 35);
+        assert_eq!(grid[0], date(2024, 1, 29));
+        assert_eq!(grid[grid.len() - 1], date(2024, 3, 3));
+    }
+
+    #[test]
+    fn test_month_grid_aligned_month() {
+        // April 2024 starts on a Monday and ends on a Tuesday
+        let grid = month_grid(date(2024, 4, 1));
+        assert_eq!(grid[0], date(2024, 4, 1));
+        assert_eq!(grid[grid.len() - 1], date(2024, 5, 5));
+    }
+
+    #[test]
+    fn test_occurrence_class() {
+        let today = date(2024, 4, 10);
+        let occurrence = |day: u32, completions: i32| TaskOccurrence {
+            task_id: uuid::Uuid::nil(),
+            title: "Dishes".to_string(),
+            date: date(2024, 4, day),
+            due_time: None,
+            habit_type: shared::HabitType::Good,
+            target_count: 1,
+            completions,
+        };
+        assert_eq!(occurrence_class(&occurrence(9, 1), today), "calendar-occurrence done");
+        assert_eq!(occurrence_class(&occurrence(9, 0), today), "calendar-occurrence missed");
+        assert_eq!(occurrence_class(&occurrence(10, 0), today), "calendar-occurrence");
+    }
+}
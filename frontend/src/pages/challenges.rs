@@ -0,0 +1,252 @@
+use chrono::NaiveDate;
+use leptos::*;
+use leptos_router::*;
+use shared::{ChallengeStatus, ChallengeWithStandings, CreateChallengeRequest, Reward, TaskCategory};
+use uuid::Uuid;
+
+use crate::api::api_client;
+use crate::components::loading::Loading;
+use crate::i18n::use_i18n;
+
+/// Parse the value of an `<input type="date">`
+fn parse_date_input(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").ok()
+}
+
+#[component]
+pub fn ChallengesPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let params = use_params_map();
+    let household_id = move || params.with(|p| p.get("id").cloned().unwrap_or_default());
+
+    let challenges = create_rw_signal(Vec::<ChallengeWithStandings>::new());
+    let categories = create_rw_signal(Vec::<TaskCategory>::new());
+    let rewards = create_rw_signal(Vec::<Reward>::new());
+    let loading = create_rw_signal(true);
+    let error = create_rw_signal(Option::<String>::None);
+
+    let title = create_rw_signal(String::new());
+    let description = create_rw_signal(String::new());
+    let category_id = create_rw_signal(String::new());
+    let reward_id = create_rw_signal(String::new());
+    let start_date = create_rw_signal(String::new());
+    let end_date = create_rw_signal(String::new());
+
+    create_effect(move |_| {
+        let id = household_id();
+        if id.is_empty() {
+            return;
+        }
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().list_challenges(&id).await {
+                Ok(list) => challenges.set(list),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            if let Ok(list) = api_client().list_categories(&id).await {
+                categories.set(list);
+            }
+            if let Ok(list) = api_client().list_rewards(&id).await {
+                rewards.set(list);
+            }
+            loading.set(false);
+        });
+    });
+
+    let on_create = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        let (Some(start), Some(end)) = (parse_date_input(&start_date.get()), parse_date_input(&end_date.get())) else {
+            error.set(Some(i18n_stored.get_value().t("challenges.dates_required")));
+            return;
+        };
+        let description_value = description.get();
+        let request = CreateChallengeRequest {
+            title: title.get(),
+            description: (!description_value.trim().is_empty()).then_some(description_value),
+            category_id: Uuid::parse_str(&category_id.get()).ok(),
+            start_date: start,
+            end_date: end,
+            reward_id: Uuid::parse_str(&reward_id.get()).ok(),
+        };
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().create_challenge(&id, request).await {
+                Ok(created) => {
+                    challenges.update(|list| list.insert(0, created));
+                    title.set(String::new());
+                    description.set(String::new());
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_close = move |challenge_id: Uuid| {
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().close_challenge(&id, &challenge_id.to_string()).await {
+                Ok(closed) => challenges.update(|list| {
+                    if let Some(entry) = list.iter_mut().find(|c| c.challenge.id == challenge_id) {
+                        *entry = closed;
+                    }
+                }),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_delete = move |challenge_id: Uuid| {
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().delete_challenge(&id, &challenge_id.to_string()).await {
+                Ok(()) => challenges.update(|list| list.retain(|c| c.challenge.id != challenge_id)),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    view! {
+        <div class="dashboard-header">
+            <h1 class="dashboard-title">{i18n_stored.get_value().t("challenges.title")}</h1>
+        </div>
+
+        {move || error.get().map(|e| view! {
+            <div class="alert alert-error">{e}</div>
+        })}
+
+        <div class="card">
+            <h3 class="card-title">{i18n_stored.get_value().t("challenges.create")}</h3>
+            <form on:submit=on_create>
+                <div class="form-group">
+                    <label class="form-label">{i18n_stored.get_value().t("challenges.name")}</label>
+                    <input
+                        type="text"
+                        class="form-input"
+                        required
+                        prop:value=move || title.get()
+                        on:input=move |ev| title.set(event_target_value(&ev))
+                    />
+                </div>
+                <div class="form-group">
+                    <label class="form-label">{i18n_stored.get_value().t("challenges.description")}</label>
+                    <input
+                        type="text"
+                        class="form-input"
+                        prop:value=move || description.get()
+                        on:input=move |ev| description.set(event_target_value(&ev))
+                    />
+                </div>
+                <div class="form-group">
+                    <label class="form-label">{i18n_stored.get_value().t("challenges.category")}</label>
+                    <select class="form-select" on:change=move |ev| category_id.set(event_target_value(&ev))>
+                        <option value="">{i18n_stored.get_value().t("challenges.all_tasks")}</option>
+                        {move || categories.get().into_iter().map(|c| view! {
+                            <option value=c.id.to_string()>{c.name}</option>
+                        }).collect_view()}
+                    </select>
+                </div>
+                <div class="form-group">
+                    <label class="form-label">{i18n_stored.get_value().t("challenges.start_date")}</label>
+                    <input type="date" class="form-input" required
+                        on:input=move |ev| start_date.set(event_target_value(&ev)) />
+                </div>
+                <div class="form-group">
+                    <label class="form-label">{i18n_stored.get_value().t("challenges.end_date")}</label>
+                    <input type="date" class="form-input" required
+                        on:input=move |ev| end_date.set(event_target_value(&ev)) />
+                </div>
+                <div class="form-group">
+                    <label class="form-label">{i18n_stored.get_value().t("challenges.reward")}</label>
+                    <select class="form-select" on:change=move |ev| reward_id.set(event_target_value(&ev))>
+                        <option value="">{i18n_stored.get_value().t("challenges.no_reward")}</option>
+                        {move || rewards.get().into_iter().map(|r| view! {
+                            <option value=r.id.to_string()>{r.name}</option>
+                        }).collect_view()}
+                    </select>
+                </div>
+                <button type="submit" class="btn btn-primary">
+                    {i18n_stored.get_value().t("challenges.create")}
+                </button>
+            </form>
+        </div>
+
+        <Show when=move || loading.get() fallback=|| ()>
+            <Loading />
+        </Show>
+
+        {move || {
+            let list = challenges.get();
+            if list.is_empty() && !loading.get() {
+                return view! {
+                    <p class="empty-state">{i18n_stored.get_value().t("challenges.empty")}</p>
+                }.into_view();
+            }
+            list.into_iter().map(|entry| {
+                let challenge = entry.challenge;
+                let challenge_id = challenge.id;
+                let is_active = challenge.status == ChallengeStatus::Active;
+                let winner_ids = challenge.winner_ids.clone();
+                let dates = format!(
+                    "{} – {}",
+                    challenge.start_date.format("%d.%m.%Y"),
+                    challenge.end_date.format("%d.%m.%Y")
+                );
+                let status_label = if is_active {
+                    i18n_stored.get_value().t("challenges.active")
+                } else {
+                    i18n_stored.get_value().t("challenges.closed")
+                };
+                view! {
+                    <div class="card challenge-card">
+                        <div class="challenge-header">
+                            <h3 class="card-title">{challenge.title}</h3>
+                            <span class="badge">{status_label}</span>
+                        </div>
+                        <p class="challenge-dates">{dates}</p>
+                        {(!challenge.description.is_empty()).then(|| view! {
+                            <p class="challenge-description">{challenge.description.clone()}</p>
+                        })}
+                        <ol class="challenge-standings">
+                            {entry.standings.into_iter().map(|standing| {
+                                let won = winner_ids.contains(&standing.user_id);
+                                view! {
+                                    <li class:challenge-winner=won>
+                                        <span>{standing.username}</span>
+                                        " "
+                                        <span class="challenge-count">{standing.completions}</span>
+                                        {won.then_some(" 🏆")}
+                                    </li>
+                                }
+                            }).collect_view()}
+                        </ol>
+                        <div class="challenge-actions">
+                            <Show when=move || is_active fallback=|| ()>
+                                <button class="btn btn-outline btn-sm" on:click=move |_| on_close(challenge_id)>
+                                    {i18n_stored.get_value().t("challenges.close")}
+                                </button>
+                            </Show>
+                            <button class="btn btn-danger btn-sm" on:click=move |_| on_delete(challenge_id)>
+                                {i18n_stored.get_value().t("common.delete")}
+                            </button>
+                        </div>
+                    </div>
+                }
+            }).collect_view()
+        }}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_parse_date_input() {
+        assert_eq!(parse_date_input("2024-03-01"), NaiveDate::from_ymd_opt(2024, 3, 1));
+        assert_eq!(parse_date_input(""), None);
+        assert_eq!(parse_date_input("01.03.2024"), None);
+    }
+}
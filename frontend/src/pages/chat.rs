@@ -1,15 +1,29 @@
 use leptos::*;
 use leptos_router::*;
-use shared::{ChatMessageWithUser, HouseholdSettings, User};
+use shared::{
+    AttachmentEntity, ChatMessageWithUser, ChatReadMarker, HouseholdSettings, MemberWithUser, User, WsEventCategory,
+    WsServerMessage,
+};
 use uuid::Uuid;
+use wasm_bindgen::JsCast;
 
-use crate::api::ApiClient;
-use crate::components::chat_message::ChatMessage;
+use crate::api::websocket::{WsClient, WsConnectionState};
+use crate::api::{api_client, files};
+use crate::components::chat_message::{quote_excerpt, ChatMessage};
 use crate::components::loading::Loading;
+use crate::components::theme::use_theme;
 use crate::i18n::use_i18n;
 
+/// How long a typing notice stays visible unless it is renewed
+const TYPING_VISIBLE_MS: f64 = 5000.0;
+/// Minimum time between two typing notices sent by this page
+const TYPING_SEND_INTERVAL_MS: f64 = 3000.0;
+/// Text of a message that only carries a photo; messages cannot be empty
+const PHOTO_ONLY_CONTENT: &str = "📷";
+
 #[component]
 pub fn ChatPage() -> impl IntoView {
+    let theme = use_theme();
     let i18n = use_i18n();
     let i18n_stored = store_value(i18n);
 
@@ -26,42 +40,79 @@ pub fn ChatPage() -> impl IntoView {
     let error = create_rw_signal(Option::<String>::None);
     let new_message = create_rw_signal(String::new());
     let sending = create_rw_signal(false);
+    let read_markers = create_rw_signal(Vec::<ChatReadMarker>::new());
+    let members = create_rw_signal(Vec::<MemberWithUser>::new());
+    let last_marked = store_value(Option::<Uuid>::None);
+    let replying_to = create_rw_signal(Option::<ChatMessageWithUser>::None);
+    let online_user_ids = create_rw_signal(Vec::<Uuid>::new());
+    // Members typing right now with the time (ms) their notice expires
+    let typing_users = create_rw_signal(Vec::<(Uuid, String, f64)>::new());
+    let last_typing_sent = store_value(0.0_f64);
+    // Image picked or pasted to go with the next message
+    let pending_photo = create_rw_signal(Option::<web_sys::File>::None);
 
     // Store household_id for use in closures
     let household_id = store_value(household_id_initial.clone());
 
+    // Mark the newest message as read and refresh everyone's read position
+    let sync_read_state = move || {
+        let id = household_id.get_value();
+        let newest = messages.with_untracked(|msgs| msgs.last().map(|m| m.message.id));
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Some(message_id) = newest {
+                if last_marked.get_value() != Some(message_id)
+                    && api_client().mark_chat_read(&id, message_id).await.is_ok()
+                {
+                    last_marked.set_value(Some(message_id));
+                }
+            }
+            if let Ok(markers) = api_client().list_chat_read_markers(&id).await {
+                read_markers.set(markers);
+            }
+        });
+    };
+
     // Load initial data
     if !household_id_initial.is_empty() {
         let id_for_user = household_id_initial.clone();
         let id_for_settings = household_id_initial.clone();
         let id_for_messages = household_id_initial.clone();
+        let id_for_members = household_id_initial.clone();
+
+        // Load members to show who has read a message
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Ok(list) = api_client().list_members(&id_for_members).await {
+                members.set(list);
+            }
+        });
 
         // Load current user
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(user) = ApiClient::get_current_user().await {
+            if let Ok(user) = api_client().get_current_user().await {
                 current_user.set(Some(user));
             }
         });
 
         // Load settings for dark mode
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(s) = ApiClient::get_household_settings(&id_for_settings).await {
-                apply_dark_mode(s.dark_mode);
+            if let Ok(s) = api_client().get_household_settings(&id_for_settings).await {
+                theme.set_household_dark_mode(s.dark_mode);
                 settings.set(Some(s));
             }
         });
 
         // Load initial messages
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::list_chat_messages(&id_for_messages, Some(50), None).await {
+            match api_client().list_chat_messages(&id_for_messages, Some(50), None).await {
                 Ok(mut msgs) => {
                     // Reverse to show oldest first
                     msgs.reverse();
                     messages.set(msgs);
                     loading.set(false);
+                    sync_read_state();
                 }
                 Err(e) => {
-                    error.set(Some(e));
+                    error.set(Some(e.to_string()));
                     loading.set(false);
                 }
             }
@@ -73,9 +124,10 @@ pub fn ChatPage() -> impl IntoView {
             move || {
                 let id = id_for_polling.clone();
                 wasm_bindgen_futures::spawn_local(async move {
-                    if let Ok(mut new_msgs) = ApiClient::list_chat_messages(&id, Some(50), None).await {
+                    if let Ok(mut new_msgs) = api_client().list_chat_messages(&id, Some(50), None).await {
                         new_msgs.reverse();
                         messages.set(new_msgs);
+                        sync_read_state();
                     }
                 });
             },
@@ -83,29 +135,103 @@ pub fn ChatPage() -> impl IntoView {
         );
     }
 
+    // Presence and typing: subscribe to the household's chat events
+    let ws_client = WsClient::new();
+    ws_client.connect();
+    let ws_state = ws_client.state();
+    let ws_messages = ws_client.last_message();
+    {
+        let ws_client = ws_client.clone();
+        create_effect(move |_| {
+            if ws_state.get() == WsConnectionState::Authenticated {
+                if let Ok(uuid) = Uuid::parse_str(&household_id.get_value()) {
+                    ws_client.subscribe(uuid, vec![WsEventCategory::Chat]);
+                }
+            }
+        });
+    }
+    create_effect(move |_| {
+        match ws_messages.get() {
+            Some(WsServerMessage::PresenceUpdated { household_id: updated_id, online_user_ids: ids })
+                if updated_id.to_string() == household_id.get_value() =>
+            {
+                online_user_ids.set(ids);
+            }
+            Some(WsServerMessage::UserTyping { household_id: typed_in, user_id, username })
+                if typed_in.to_string() == household_id.get_value() =>
+            {
+                let expires = js_sys::Date::now() + TYPING_VISIBLE_MS;
+                typing_users.update(|users| {
+                    users.retain(|(id, _, _)| *id != user_id);
+                    users.push((user_id, username, expires));
+                });
+            }
+            _ => {}
+        }
+    });
+    // Drop typing notices that were not renewed
+    set_interval(
+        move || {
+            let now = js_sys::Date::now();
+            if typing_users.with_untracked(|users| users.iter().any(|(_, _, expires)| *expires <= now)) {
+                typing_users.update(|users| users.retain(|(_, _, expires)| *expires > now));
+            }
+        },
+        std::time::Duration::from_secs(1),
+    );
+    let ws_client = store_value(ws_client);
+    on_cleanup(move || ws_client.get_value().disconnect());
+
+    // Let the others know we are typing, at most every few seconds
+    let notify_typing = move || {
+        let now = js_sys::Date::now();
+        if now - last_typing_sent.get_value() < TYPING_SEND_INTERVAL_MS {
+            return;
+        }
+        if let Ok(uuid) = Uuid::parse_str(&household_id.get_value()) {
+            last_typing_sent.set_value(now);
+            ws_client.get_value().typing(uuid);
+        }
+    };
+
     // Send message handler
     let do_send_message = move || {
         let content = new_message.get();
-        if content.trim().is_empty() || sending.get() {
+        let photo = pending_photo.get_untracked();
+        if (content.trim().is_empty() && photo.is_none()) || sending.get() {
             return;
         }
 
         sending.set(true);
         let id = household_id.get_value();
-        let content_clone = content.clone();
+        let content_clone = if content.trim().is_empty() { PHOTO_ONLY_CONTENT.to_string() } else { content.clone() };
+        let reply_to = replying_to.get_untracked().map(|m| m.message.id);
 
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::send_chat_message(&id, &content_clone).await {
-                Ok(msg) => {
+            match api_client().send_chat_message(&id, &content_clone, reply_to).await {
+                Ok(mut msg) => {
+                    if let Some(file) = photo {
+                        match files::upload_attachment(&id, AttachmentEntity::ChatMessage, &msg.message.id.to_string(), &file).await {
+                            Ok(attachment) => msg.attachments.push(attachment),
+                            Err(e) => error.set(Some(e)),
+                        }
+                        pending_photo.set(None);
+                    }
                     messages.update(|msgs| {
+                        if let Some(parent_id) = msg.message.reply_to_message_id {
+                            if let Some(parent) = msgs.iter_mut().find(|m| m.message.id == parent_id) {
+                                parent.reply_count += 1;
+                            }
+                        }
                         if !msgs.iter().any(|m| m.message.id == msg.message.id) {
                             msgs.push(msg);
                         }
                     });
                     new_message.set(String::new());
+                    replying_to.set(None);
                 }
                 Err(e) => {
-                    error.set(Some(e));
+                    error.set(Some(e.to_string()));
                 }
             }
             sending.set(false);
@@ -116,6 +242,30 @@ pub fn ChatPage() -> impl IntoView {
         do_send_message();
     };
 
+    // Pasting an image attaches it instead of inserting text
+    let handle_paste = move |ev: web_sys::Event| {
+        let file = ev
+            .dyn_ref::<web_sys::ClipboardEvent>()
+            .and_then(|ev| ev.clipboard_data())
+            .and_then(|data| data.files())
+            .and_then(|files| files.get(0));
+        if let Some(file) = file.filter(|f| f.type_().starts_with("image/")) {
+            ev.prevent_default();
+            pending_photo.set(Some(file));
+        }
+    };
+
+    let on_photo_selected = move |ev: web_sys::Event| {
+        let input = ev.target().and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok());
+        if let Some(input) = input {
+            if let Some(file) = input.files().and_then(|files| files.get(0)) {
+                pending_photo.set(Some(file));
+            }
+            // Allow picking the same file again after removing it
+            input.set_value("");
+        }
+    };
+
     let handle_keydown = move |ev: web_sys::KeyboardEvent| {
         if ev.key() == "Enter" && !ev.shift_key() {
             ev.prevent_default();
@@ -128,7 +278,7 @@ pub fn ChatPage() -> impl IntoView {
         let msg_id = message_id.to_string();
 
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::update_chat_message(&id, &msg_id, &content).await {
+            match api_client().update_chat_message(&id, &msg_id, &content).await {
                 Ok(updated_msg) => {
                     messages.update(|msgs| {
                         if let Some(m) = msgs.iter_mut().find(|m| m.message.id == message_id) {
@@ -137,7 +287,7 @@ pub fn ChatPage() -> impl IntoView {
                     });
                 }
                 Err(e) => {
-                    error.set(Some(e));
+                    error.set(Some(e.to_string()));
                 }
             }
         });
@@ -148,7 +298,7 @@ pub fn ChatPage() -> impl IntoView {
         let msg_id = message_id.to_string();
 
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::delete_chat_message(&id, &msg_id).await {
+            match api_client().delete_chat_message(&id, &msg_id).await {
                 Ok(()) => {
                     messages.update(|msgs| {
                         if let Some(m) = msgs.iter_mut().find(|m| m.message.id == message_id) {
@@ -157,7 +307,36 @@ pub fn ChatPage() -> impl IntoView {
                     });
                 }
                 Err(e) => {
-                    error.set(Some(e));
+                    error.set(Some(e.to_string()));
+                }
+            }
+        });
+    });
+
+    let on_reply = Callback::new(move |message: ChatMessageWithUser| {
+        replying_to.set(Some(message));
+    });
+
+    let on_react = Callback::new(move |(message_id, emoji, add): (Uuid, String, bool)| {
+        let id = household_id.get_value();
+        let msg_id = message_id.to_string();
+
+        wasm_bindgen_futures::spawn_local(async move {
+            let result = if add {
+                api_client().add_chat_reaction(&id, &msg_id, &emoji).await
+            } else {
+                api_client().remove_chat_reaction(&id, &msg_id, &emoji).await
+            };
+            match result {
+                Ok(reactions) => {
+                    messages.update(|msgs| {
+                        if let Some(m) = msgs.iter_mut().find(|m| m.message.id == message_id) {
+                            m.reactions = reactions;
+                        }
+                    });
+                }
+                Err(e) => {
+                    error.set(Some(e.to_string()));
                 }
             }
         });
@@ -166,6 +345,19 @@ pub fn ChatPage() -> impl IntoView {
     view! {
         <div class="dashboard-header">
             <h1 class="dashboard-title">{i18n_stored.get_value().t("chat.title")}</h1>
+            {move || {
+                let user_id = current_user.get().map(|u| u.id);
+                let names = online_names(&online_user_ids.get(), &members.get(), user_id);
+                (!names.is_empty()).then(|| {
+                    let text = i18n_stored.get_value().t_with("chat.online", &[("names", &names.join(", "))]);
+                    view! {
+                        <div class="chat-presence">
+                            <span class="chat-presence-dot"></span>
+                            {text}
+                        </div>
+                    }
+                })
+            }}
         </div>
 
         {move || error.get().map(|e| view! {
@@ -191,35 +383,115 @@ pub fn ChatPage() -> impl IntoView {
                             }.into_view()
                         } else {
                             let tz = settings.get().map(|s| s.timezone).unwrap_or_else(|| "UTC".to_string());
+                            // Read receipts are shown below the newest own message only
+                            let receipt_for = msgs
+                                .iter()
+                                .rev()
+                                .find(|m| m.message.user_id == user_id && !m.message.is_deleted)
+                                .map(|m| m.message.id);
+                            let markers = read_markers.get();
+                            let member_list = members.get();
+                            let all_msgs = msgs.clone();
                             msgs.into_iter().map(|msg| {
                                 let tz = tz.clone();
+                                let parent = msg.message.reply_to_message_id.and_then(|parent_id| {
+                                    all_msgs.iter().find(|m| m.message.id == parent_id).cloned()
+                                });
+                                let receipt = (receipt_for == Some(msg.message.id))
+                                    .then(|| read_by(&msg, &markers, &member_list))
+                                    .filter(|names| !names.is_empty())
+                                    .map(|names| {
+                                        let text = i18n_stored.get_value().t_with("chat.seen_by", &[("names", &names.join(", "))]);
+                                        view! { <div class="chat-read-receipt">{text}</div> }
+                                    });
                                 view! {
                                     <ChatMessage
                                         message=msg
                                         current_user_id=user_id
                                         on_edit=on_edit
                                         on_delete=on_delete
+                                        on_reply=on_reply
+                                        on_react=on_react
+                                        household_id=household_id.get_value()
+                                        reply_to=parent
                                         timezone=tz
                                     />
+                                    {receipt}
                                 }
                             }).collect_view()
                         }
                     }}
                 </div>
 
+                {move || {
+                    let names: Vec<String> = typing_users.get().into_iter().map(|(_, name, _)| name).collect();
+                    let i18n = i18n_stored.get_value();
+                    let text = match names.as_slice() {
+                        [] => return None,
+                        [name] => i18n.t_with("chat.typing_one", &[("name", name)]),
+                        _ => i18n.t_with("chat.typing_many", &[("names", &names.join(", "))]),
+                    };
+                    Some(view! { <div class="chat-typing">{text}</div> })
+                }}
+
+                {move || replying_to.get().map(|parent| {
+                    let text = format!("{}: {}", parent.user.username, quote_excerpt(&parent.message.content));
+                    view! {
+                        <div class="chat-replying-to">
+                            <span>{i18n_stored.get_value().t("chat.replying_to")} " " {text}</span>
+                            <button
+                                class="btn-icon"
+                                title=i18n_stored.get_value().t("chat.cancel_reply")
+                                on:click=move |_| replying_to.set(None)
+                            >
+                                "×"
+                            </button>
+                        </div>
+                    }
+                })}
+
+                {move || pending_photo.get().map(|file| view! {
+                    <div class="chat-pending-photo">
+                        <span>"📷 " {file.name()}</span>
+                        <button
+                            class="btn-icon"
+                            title=i18n_stored.get_value().t("chat.remove_photo")
+                            on:click=move |_| pending_photo.set(None)
+                        >
+                            "×"
+                        </button>
+                    </div>
+                })}
+
                 <div class="chat-input-area">
+                    <label class="btn btn-outline chat-attach-btn" title=i18n_stored.get_value().t("chat.attach_photo")>
+                        "📎"
+                        <input
+                            type="file"
+                            accept="image/jpeg,image/png,image/gif,image/webp"
+                            style="display: none;"
+                            on:change=on_photo_selected
+                        />
+                    </label>
                     <textarea
                         class="chat-input"
                         placeholder=i18n_stored.get_value().t("chat.placeholder")
                         prop:value=move || new_message.get()
-                        on:input=move |ev| new_message.set(event_target_value(&ev))
+                        on:input=move |ev| {
+                            let value = event_target_value(&ev);
+                            if !value.trim().is_empty() {
+                                notify_typing();
+                            }
+                            new_message.set(value);
+                        }
                         on:keydown=handle_keydown
+                        on:paste=handle_paste
                         rows="2"
                     />
                     <button
                         class="btn btn-primary chat-send-btn"
                         on:click=send_message
-                        disabled=move || sending.get() || new_message.get().trim().is_empty()
+                        disabled=move || sending.get() || (new_message.get().trim().is_empty() && pending_photo.get().is_none())
                     >
                         {move || if sending.get() { i18n_stored.get_value().t("chat.sending") } else { i18n_stored.get_value().t("chat.send") }}
                     </button>
@@ -229,17 +501,26 @@ pub fn ChatPage() -> impl IntoView {
     }
 }
 
-/// Apply dark mode class to document body
-fn apply_dark_mode(enabled: bool) {
-    if let Some(window) = web_sys::window() {
-        if let Some(document) = window.document() {
-            if let Some(body) = document.body() {
-                if enabled {
-                    let _ = body.class_list().add_1("dark-mode");
-                } else {
-                    let _ = body.class_list().remove_1("dark-mode");
-                }
-            }
-        }
-    }
+/// Usernames of the other members who are currently connected
+fn online_names(online_user_ids: &[Uuid], members: &[MemberWithUser], current_user_id: Option<Uuid>) -> Vec<String> {
+    members
+        .iter()
+        .filter(|member| Some(member.user.id) != current_user_id && online_user_ids.contains(&member.user.id))
+        .map(|member| member.user.username.clone())
+        .collect()
+}
+
+/// Usernames of other members whose read marker has reached the message
+fn read_by(message: &ChatMessageWithUser, markers: &[ChatReadMarker], members: &[MemberWithUser]) -> Vec<String> {
+    markers
+        .iter()
+        .filter(|m| m.user_id != message.message.user_id && m.last_read_at >= message.message.created_at)
+        .filter_map(|m| {
+            members
+                .iter()
+                .find(|member| member.user.id == m.user_id)
+                .map(|member| member.user.username.clone())
+        })
+        .collect()
 }
+
@@ -2,12 +2,15 @@ use std::collections::HashSet;
 
 use chrono::NaiveDate;
 use leptos::*;
-use shared::{CreateHouseholdRequest, Household, InvitationWithHousehold, MemberWithUser, Punishment, RecurrenceType, RecurrenceValue, Reward, Role, Task, TaskCategory, TaskPunishmentLink, TaskRewardLink, UpdateTaskRequest};
+use leptos_router::use_navigate;
+use shared::{CompletionStatus, CreateHouseholdRequest, DashboardTaskQuery, DashboardTaskSummary, Household, HouseholdExport, InvitationWithHousehold, MemberWithUser, Punishment, RecurrenceType, RecurrenceValue, Reward, Role, Task, TaskCategory, TaskPunishmentLink, TaskRewardLink, UpdateTaskRequest};
 use uuid::Uuid;
 
-use crate::api::ApiClient;
+use crate::api::api_client;
+use crate::components::completion_photos::AttachPhotoModal;
+use crate::components::focus_timer::FocusTimer;
 use crate::components::loading::Loading;
-use crate::utils::{matches_text_filter, TaskModalData};
+use crate::utils::{matches_text_filter, read_selected_file, TaskModalData};
 use crate::components::modal::Modal;
 use crate::components::set_date_modal::SetDateModal;
 use crate::components::task_card::{GroupedTaskList, TaskWithHousehold};
@@ -16,6 +19,29 @@ use crate::components::task_modal::TaskModal;
 use crate::components::text_filter_input::TextFilterInput;
 use crate::i18n::use_i18n;
 
+/// The whitelisted dashboard tasks, or with a query the matching tasks of all households
+/// together with their summary counts
+async fn fetch_dashboard_tasks(
+    query: Option<DashboardTaskQuery>,
+) -> Result<(Vec<TaskWithHousehold>, Option<DashboardTaskSummary>), String> {
+    let (tasks, summary) = match query {
+        Some(query) => {
+            let response = api_client().get_all_tasks_across_households(&query).await?;
+            (response.tasks, Some(response.summary))
+        }
+        None => (api_client().get_dashboard_tasks_with_status().await?, None),
+    };
+    let tasks = tasks
+        .into_iter()
+        .map(|t| TaskWithHousehold::with_household(
+            t.task_with_status,
+            t.household_id.to_string(),
+            t.household_name,
+        ))
+        .collect();
+    Ok((tasks, summary))
+}
+
 #[component]
 pub fn Dashboard() -> impl IntoView {
     let i18n = use_i18n();
@@ -27,8 +53,10 @@ pub fn Dashboard() -> impl IntoView {
     let loading = create_rw_signal(true);
     let error = create_rw_signal(Option::<String>::None);
     let show_create_modal = create_rw_signal(false);
+    let join_code = create_rw_signal(String::new());
     let new_household_name = create_rw_signal(String::new());
     let show_all = create_rw_signal(false);
+    let all_tasks_summary = create_rw_signal(Option::<DashboardTaskSummary>::None);
 
     // Household filter state
     let enabled_households = create_rw_signal(HashSet::<String>::new());
@@ -37,9 +65,13 @@ pub fn Dashboard() -> impl IntoView {
     // Dashboard task whitelist
     let dashboard_task_ids = create_rw_signal(HashSet::<String>::new());
 
+    // Running focus session, kept on the server so it survives reloads
+    let focus_session = create_rw_signal(Option::<shared::FocusSession>::None);
+
     // Task detail modal state
     let detail_task_id = create_rw_signal(Option::<String>::None);
     let detail_household_id = create_rw_signal(Option::<String>::None);
+    let photo_target = create_rw_signal(Option::<(String, String)>::None);
 
     // Task edit modal state
     let editing_task = create_rw_signal(Option::<Task>::None);
@@ -85,10 +117,7 @@ pub fn Dashboard() -> impl IntoView {
             .filter(|t| t.household_id.as_ref().map(|id| enabled_households.get().contains(id)).unwrap_or(false))
             .filter(|t| {
                 if only_assigned {
-                    match (user_id, t.task.task.assigned_user_id) {
-                        (Some(uid), Some(assigned_uid)) => uid == assigned_uid,
-                        _ => false,
-                    }
+                    user_id.map(|uid| t.task.task.assignees().contains(&uid)).unwrap_or(false)
                 } else {
                     true
                 }
@@ -97,11 +126,29 @@ pub fn Dashboard() -> impl IntoView {
             .collect::<Vec<_>>()
     });
 
+    // In "show all" mode the household and assignment filters are applied by the server
+    let all_tasks_query = move || -> Option<DashboardTaskQuery> {
+        if !show_all.get() {
+            return None;
+        }
+        let enabled = enabled_households.get();
+        let household_ids = if enabled.len() == households.get().len() {
+            Vec::new()
+        } else {
+            enabled.iter().filter_map(|id| Uuid::parse_str(id).ok()).collect()
+        };
+        Some(DashboardTaskQuery {
+            household_ids,
+            assigned_to_me: show_only_assigned.get() && current_user_id.get().is_some(),
+            ..Default::default()
+        })
+    };
+
     // Load households, invitations, and tasks on mount
     create_effect(move |_| {
         wasm_bindgen_futures::spawn_local(async move {
             // Load households
-            if let Ok(data) = ApiClient::list_households().await {
+            if let Ok(data) = api_client().list_households().await {
                 // Initialize enabled_households with all household IDs
                 let all_ids: HashSet<String> = data.iter().map(|h| h.id.to_string()).collect();
                 enabled_households.set(all_ids);
@@ -109,17 +156,21 @@ pub fn Dashboard() -> impl IntoView {
             }
 
             // Load pending invitations
-            if let Ok(inv) = ApiClient::get_my_invitations().await {
+            if let Ok(inv) = api_client().get_my_invitations().await {
                 invitations.set(inv);
             }
 
             // Load dashboard task whitelist
-            if let Ok(ids) = ApiClient::get_dashboard_task_ids().await {
+            if let Ok(ids) = api_client().get_dashboard_task_ids().await {
                 dashboard_task_ids.set(ids.into_iter().map(|id| id.to_string()).collect());
             }
 
+            if let Ok(session) = api_client().get_current_focus_session().await {
+                focus_session.set(session);
+            }
+
             // Load current user ID for assignment filtering
-            if let Ok(user) = ApiClient::get_current_user().await {
+            if let Ok(user) = api_client().get_current_user().await {
                 current_user_id.set(Some(user.id));
             }
 
@@ -127,27 +178,14 @@ pub fn Dashboard() -> impl IntoView {
         });
     });
 
-    // Load tasks based on show_all toggle (reactive)
+    // Load tasks based on show_all toggle and, in that mode, the filters (reactive)
     create_effect(move |_| {
-        let show_all_mode = show_all.get();
+        let query = all_tasks_query();
         wasm_bindgen_futures::spawn_local(async move {
-            let result = if show_all_mode {
-                ApiClient::get_all_tasks_across_households().await
-            } else {
-                ApiClient::get_dashboard_tasks_with_status().await
-            };
-
-            match result {
-                Ok(dashboard_tasks) => {
-                    let tasks_with_households: Vec<TaskWithHousehold> = dashboard_tasks
-                        .into_iter()
-                        .map(|t| TaskWithHousehold::with_household(
-                            t.task_with_status,
-                            t.household_id.to_string(),
-                            t.household_name,
-                        ))
-                        .collect();
-                    all_tasks.set(tasks_with_households);
+            match fetch_dashboard_tasks(query).await {
+                Ok((tasks, summary)) => {
+                    all_tasks.set(tasks);
+                    all_tasks_summary.set(summary);
                 }
                 Err(e) => {
                     error.set(Some(e));
@@ -166,22 +204,54 @@ pub fn Dashboard() -> impl IntoView {
 
         wasm_bindgen_futures::spawn_local(async move {
             let request = CreateHouseholdRequest { name };
-            match ApiClient::create_household(request).await {
+            match api_client().create_household(request).await {
                 Ok(household) => {
                     households.update(|h| h.push(household));
                     show_create_modal.set(false);
                     new_household_name.set(String::new());
                 }
+                Err(e) => {
+                    error.set(Some(e.to_string()));
+                }
+            }
+        });
+    };
+
+    let on_import = move |ev: web_sys::Event| {
+        let input = event_target::<web_sys::HtmlInputElement>(&ev);
+
+        wasm_bindgen_futures::spawn_local(async move {
+            let content = match read_selected_file(&input).await {
+                Ok(Some(content)) => content,
+                Ok(None) => return,
                 Err(e) => {
                     error.set(Some(e));
+                    return;
+                }
+            };
+            input.set_value("");
+
+            let Ok(data) = serde_json::from_str::<HouseholdExport>(&content) else {
+                error.set(Some(i18n_stored.get_value().t("household.import_invalid")));
+                show_create_modal.set(false);
+                return;
+            };
+
+            match api_client().import_household(data).await {
+                Ok(household) => {
+                    households.update(|h| h.push(household));
+                }
+                Err(e) => {
+                    error.set(Some(e.to_string()));
                 }
             }
+            show_create_modal.set(false);
         });
     };
 
     let on_accept_invitation = move |invitation_id: String, household: Household| {
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::accept_invitation(&invitation_id).await {
+            match api_client().accept_invitation(&invitation_id).await {
                 Ok(_) => {
                     // Remove from invitations
                     invitations.update(|inv| inv.retain(|i| i.invitation.id.to_string() != invitation_id));
@@ -189,38 +259,44 @@ pub fn Dashboard() -> impl IntoView {
                     households.update(|h| h.push(household));
                 }
                 Err(e) => {
-                    error.set(Some(e));
+                    error.set(Some(e.to_string()));
                 }
             }
         });
     };
 
+    let navigate = store_value(use_navigate());
+    let on_join_with_code = move |ev: web_sys::SubmitEvent| {
+        ev.prevent_default();
+        let code = join_code.get();
+        if code.trim().is_empty() {
+            return;
+        }
+        let navigate = navigate.get_value();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().redeem_invite_code(&code).await {
+                Ok(membership) => {
+                    join_code.set(String::new());
+                    navigate(&format!("/households/{}", membership.household_id), Default::default());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
     let on_decline_invitation = move |invitation_id: String| {
         wasm_bindgen_futures::spawn_local(async move {
-            if ApiClient::decline_invitation(&invitation_id).await.is_ok() {
+            if api_client().decline_invitation(&invitation_id).await.is_ok() {
                 invitations.update(|inv| inv.retain(|i| i.invitation.id.to_string() != invitation_id));
             }
         });
     };
 
     // Helper to reload tasks based on show_all mode
-    let reload_tasks = move |show_all_mode: bool| async move {
-        let result = if show_all_mode {
-            ApiClient::get_all_tasks_across_households().await
-        } else {
-            ApiClient::get_dashboard_tasks_with_status().await
-        };
-
-        if let Ok(dashboard_tasks) = result {
-            let tasks_with_households: Vec<TaskWithHousehold> = dashboard_tasks
-                .into_iter()
-                .map(|t| TaskWithHousehold::with_household(
-                    t.task_with_status,
-                    t.household_id.to_string(),
-                    t.household_name,
-                ))
-                .collect();
-            all_tasks.set(tasks_with_households);
+    let reload_tasks = move |query: Option<DashboardTaskQuery>| async move {
+        if let Ok((tasks, summary)) = fetch_dashboard_tasks(query).await {
+            all_tasks.set(tasks);
+            all_tasks_summary.set(summary);
         }
     };
 
@@ -231,10 +307,14 @@ pub fn Dashboard() -> impl IntoView {
         if let Some(twh) = tasks.iter().find(|t| t.task.task.id.to_string() == task_id) {
             if let Some(household_id) = twh.household_id.clone() {
                 let task_id_clone = task_id.clone();
-                let show_all_mode = show_all.get();
+                let query = all_tasks_query();
                 wasm_bindgen_futures::spawn_local(async move {
-                    if ApiClient::complete_task(&household_id, &task_id_clone).await.is_ok() {
-                        reload_tasks(show_all_mode).await;
+                    if let Ok(completion) = api_client().complete_task(&household_id, &task_id_clone).await {
+                        // Completions that await review can carry photo proof
+                        if completion.status == CompletionStatus::Pending {
+                            photo_target.set(Some((household_id.clone(), completion.id.to_string())));
+                        }
+                        reload_tasks(query).await;
                     }
                 });
             }
@@ -248,10 +328,10 @@ pub fn Dashboard() -> impl IntoView {
         if let Some(twh) = tasks.iter().find(|t| t.task.task.id.to_string() == task_id) {
             if let Some(household_id) = twh.household_id.clone() {
                 let task_id_clone = task_id.clone();
-                let show_all_mode = show_all.get();
+                let query = all_tasks_query();
                 wasm_bindgen_futures::spawn_local(async move {
-                    if ApiClient::uncomplete_task(&household_id, &task_id_clone).await.is_ok() {
-                        reload_tasks(show_all_mode).await;
+                    if api_client().uncomplete_task(&household_id, &task_id_clone).await.is_ok() {
+                        reload_tasks(query).await;
                     }
                 });
             }
@@ -268,12 +348,12 @@ pub fn Dashboard() -> impl IntoView {
     let on_toggle_dashboard = Callback::new(move |(task_id, add_to_dashboard): (String, bool)| {
         wasm_bindgen_futures::spawn_local(async move {
             if add_to_dashboard {
-                if ApiClient::add_task_to_dashboard(&task_id).await.is_ok() {
+                if api_client().add_task_to_dashboard(&task_id).await.is_ok() {
                     dashboard_task_ids.update(|ids| {
                         ids.insert(task_id);
                     });
                 }
-            } else if ApiClient::remove_task_from_dashboard(&task_id).await.is_ok() {
+            } else if api_client().remove_task_from_dashboard(&task_id).await.is_ok() {
                 dashboard_task_ids.update(|ids| {
                     ids.remove(&task_id);
                 });
@@ -281,13 +361,27 @@ pub fn Dashboard() -> impl IntoView {
         });
     });
 
+    // Drag-and-drop: move the dragged task in front of the one it was dropped on
+    // and save the whole dashboard order
+    let on_reorder_task = Callback::new(move |(dragged, target): (String, String)| {
+        let ids: Vec<String> = all_tasks.get_untracked().iter().map(|t| t.task_id()).collect();
+        let order = move_before(ids, &dragged, &target);
+        all_tasks.update(|tasks| tasks.sort_by_key(|t| order.iter().position(|id| *id == t.task_id())));
+        let task_ids: Vec<Uuid> = order.iter().filter_map(|id| Uuid::parse_str(id).ok()).collect();
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Err(e) = api_client().reorder_dashboard_tasks(&task_ids).await {
+                error.set(Some(e.to_string()));
+            }
+        });
+    });
+
     // Edit task from context menu - fetch task and open edit modal
     let on_context_edit = Callback::new(move |(task_id, household_id): (String, String)| {
         let tid = task_id.clone();
         let hid = household_id.clone();
         wasm_bindgen_futures::spawn_local(async move {
             // Fetch the task details
-            if let Ok(details) = ApiClient::get_task_details(&hid, &tid).await {
+            if let Ok(details) = api_client().get_task_details(&hid, &tid).await {
                 editing_task.set(Some(details.task));
                 editing_household_id.set(Some(hid.clone()));
 
@@ -299,10 +393,10 @@ pub fn Dashboard() -> impl IntoView {
                 edit_categories.set(modal_data.categories);
 
                 // Load task-specific linked rewards/punishments
-                if let Ok(tr) = ApiClient::get_task_rewards(&hid, &tid).await {
+                if let Ok(tr) = api_client().get_task_rewards(&hid, &tid).await {
                     task_linked_rewards.set(tr);
                 }
-                if let Ok(tp) = ApiClient::get_task_punishments(&hid, &tid).await {
+                if let Ok(tp) = api_client().get_task_punishments(&hid, &tid).await {
                     task_linked_punishments.set(tp);
                 }
             }
@@ -323,7 +417,7 @@ pub fn Dashboard() -> impl IntoView {
     // Handle save from set date modal
     let on_save_date = Callback::new(move |(task_id, date): (String, NaiveDate)| {
         if let Some(household_id) = set_date_household_id.get() {
-            let show_all_mode = show_all.get();
+            let query = all_tasks_query();
             wasm_bindgen_futures::spawn_local(async move {
                 // Update task to Custom recurrence with the selected date
                 let request = UpdateTaskRequest {
@@ -332,20 +426,30 @@ pub fn Dashboard() -> impl IntoView {
                     recurrence_type: Some(RecurrenceType::Custom),
                     recurrence_value: Some(RecurrenceValue::CustomDates(vec![date])),
                     assigned_user_id: None,
+                    assignee_ids: None,
                     target_count: None,
                     time_period: None,
                     allow_exceed_target: None,
                     requires_review: None,
+                    reviewer_type: None,
+                    reviewer_id: None,
                     points_reward: None,
                     points_penalty: None,
                     due_time: None,
+                    effort_minutes: None,
+                    active_from: None,
+                    active_until: None,
+                    ends_on: None,
+                    max_occurrences: None,
                     habit_type: None,
                     category_id: None,
+                    tag_ids: None,
                     archived: None,
                     paused: None,
+                    version: None,
                 };
-                if ApiClient::update_task(&household_id, &task_id, request).await.is_ok() {
-                    reload_tasks(show_all_mode).await;
+                if api_client().update_task(&household_id, &task_id, request).await.is_ok() {
+                    reload_tasks(query).await;
                 }
             });
         }
@@ -357,31 +461,43 @@ pub fn Dashboard() -> impl IntoView {
 
     // Pause/unpause from context menu
     let on_context_pause = Callback::new(move |(task_id, household_id, is_paused): (String, String, bool)| {
-        let show_all_mode = show_all.get();
+        let query = all_tasks_query();
         wasm_bindgen_futures::spawn_local(async move {
             let result = if is_paused {
-                ApiClient::unpause_task(&household_id, &task_id).await
+                api_client().unpause_task(&household_id, &task_id).await
             } else {
-                ApiClient::pause_task(&household_id, &task_id).await
+                api_client().pause_task(&household_id, &task_id).await
             };
             if result.is_ok() {
                 // Refresh tasks
-                let api_result = if show_all_mode {
-                    ApiClient::get_all_tasks_across_households().await
-                } else {
-                    ApiClient::get_dashboard_tasks_with_status().await
-                };
-                if let Ok(t) = api_result {
-                    let tasks_with_households: Vec<TaskWithHousehold> = t
-                        .into_iter()
-                        .map(|t| TaskWithHousehold::new(
-                            t.task_with_status,
-                            Some(t.household_id.to_string()),
-                            Some(t.household_name),
-                        ))
-                        .collect();
-                    all_tasks.set(tasks_with_households);
-                }
+                reload_tasks(query).await;
+            }
+        });
+    });
+
+    let on_context_claim = Callback::new(move |(task_id, household_id, is_claimed): (String, String, bool)| {
+        let query = all_tasks_query();
+        wasm_bindgen_futures::spawn_local(async move {
+            let result = if is_claimed {
+                api_client().release_task(&household_id, &task_id).await
+            } else {
+                api_client().claim_task(&household_id, &task_id).await.map(|_| ())
+            };
+            if let Err(e) = result {
+                error.set(Some(e.to_string()));
+                return;
+            }
+            reload_tasks(query).await;
+        });
+    });
+
+    let on_context_focus = Callback::new(move |(task_id, household_id): (String, String)| {
+        let Ok(task_id) = Uuid::parse_str(&task_id) else { return };
+        wasm_bindgen_futures::spawn_local(async move {
+            let request = shared::StartFocusSessionRequest { task_id, planned_minutes: None };
+            match api_client().start_focus_session(&household_id, request).await {
+                Ok(session) => focus_session.set(Some(session)),
+                Err(e) => error.set(Some(e.to_string())),
             }
         });
     });
@@ -416,10 +532,10 @@ pub fn Dashboard() -> impl IntoView {
             edit_punishments.set(modal_data.punishments);
             edit_categories.set(modal_data.categories);
 
-            if let Ok(tr) = ApiClient::get_task_rewards(&hid, &tid).await {
+            if let Ok(tr) = api_client().get_task_rewards(&hid, &tid).await {
                 task_linked_rewards.set(tr);
             }
-            if let Ok(tp) = ApiClient::get_task_punishments(&hid, &tid).await {
+            if let Ok(tp) = api_client().get_task_punishments(&hid, &tid).await {
                 task_linked_punishments.set(tp);
             }
         });
@@ -428,9 +544,9 @@ pub fn Dashboard() -> impl IntoView {
     // Handle task save from edit modal
     let on_task_save = move |_saved_task: Task| {
         // Reload tasks to reflect changes
-        let show_all_mode = show_all.get();
+        let query = all_tasks_query();
         wasm_bindgen_futures::spawn_local(async move {
-            reload_tasks(show_all_mode).await;
+            reload_tasks(query).await;
         });
         clear_edit_state();
     };
@@ -453,6 +569,8 @@ pub fn Dashboard() -> impl IntoView {
             <div class="dashboard-grid">
                 // Left column: Tasks (wider)
                 <div class="dashboard-column-left">
+                    <FocusTimer session=focus_session />
+
                     // Show all toggle
                     <div>
                         <button
@@ -461,6 +579,16 @@ pub fn Dashboard() -> impl IntoView {
                         >
                             {move || i18n_stored.get_value().t("dashboard.show_all")}
                         </button>
+                        {move || all_tasks_summary.get().map(|summary| view! {
+                            <span style="margin-left: 0.75rem; font-size: 0.875rem; color: var(--text-muted);">
+                                {i18n_stored.get_value().t_with("dashboard.all_tasks_summary", &[
+                                    ("total", &summary.total.to_string()),
+                                    ("households", &summary.households.to_string()),
+                                    ("due_today", &summary.due_today.to_string()),
+                                    ("completed", &summary.completed_today.to_string()),
+                                ])}
+                            </span>
+                        })}
                     </div>
 
                     // Household filter controls
@@ -469,7 +597,7 @@ pub fn Dashboard() -> impl IntoView {
                         if h.len() > 1 {
                             // Only show filter when there are multiple households
                             let mut sorted_households = h.clone();
-                            sorted_households.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+                            sorted_households.sort_by_key(|a| a.name.to_lowercase());
 
                             view! {
                                 <div class="filter-controls">
@@ -557,6 +685,9 @@ pub fn Dashboard() -> impl IntoView {
                                         on_edit=on_context_edit
                                         on_set_date=on_context_set_date
                                         on_pause=on_context_pause
+                                        on_claim=on_context_claim
+                                        on_focus=on_context_focus
+                                        on_reorder=(!show_all.get()).then_some(on_reorder_task)
                                     />
                                 </div>
                             }.into_view()
@@ -621,16 +752,29 @@ pub fn Dashboard() -> impl IntoView {
                         </div>
                     </Show>
 
-                    <div>
+                    <div class="dashboard-household-actions">
                         <button class="btn btn-primary" on:click=move |_| show_create_modal.set(true)>
                             {move || i18n_stored.get_value().t("dashboard.create_household")}
                         </button>
+                        <form class="join-code-form" on:submit=on_join_with_code>
+                            <input
+                                type="text"
+                                class="form-input"
+                                autocapitalize="characters"
+                                placeholder=move || i18n_stored.get_value().t("invite_codes.code_placeholder")
+                                prop:value=move || join_code.get()
+                                on:input=move |ev| join_code.set(event_target_value(&ev))
+                            />
+                            <button type="submit" class="btn btn-outline">
+                                {move || i18n_stored.get_value().t("invite_codes.join")}
+                            </button>
+                        </form>
                     </div>
 
                     // Households section
                     {move || {
                         let mut h = households.get();
-                        h.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+                        h.sort_by_key(|a| a.name.to_lowercase());
                         if h.is_empty() {
                             view! {
                                 <div class="card empty-state">
@@ -662,6 +806,14 @@ pub fn Dashboard() -> impl IntoView {
             </div>
         </Show>
 
+        {move || photo_target.get().map(|(household_id, completion_id)| view! {
+            <AttachPhotoModal
+                household_id=household_id
+                completion_id=completion_id
+                on_close=move |_| photo_target.set(None)
+            />
+        })}
+
         // Task detail modal
         {move || {
             if let (Some(task_id), Some(household_id)) = (detail_task_id.get(), detail_household_id.get()) {
@@ -747,11 +899,33 @@ pub fn Dashboard() -> impl IntoView {
                         </button>
                     </div>
                 </form>
+                <div class="form-group" style="margin-top: 1.5rem;">
+                    <label class="form-label" for="household-import">{i18n_stored.get_value().t("household.import")}</label>
+                    <input
+                        type="file"
+                        id="household-import"
+                        class="form-input"
+                        accept="application/json,.json"
+                        on:change=on_import
+                    />
+                    <small class="form-hint">{i18n_stored.get_value().t("household.import_hint")}</small>
+                </div>
             </Modal>
         </Show>
     }
 }
 
+/// Move `dragged` to the position of `target`, in front of it
+fn move_before(mut ids: Vec<String>, dragged: &str, target: &str) -> Vec<String> {
+    let Some(from) = ids.iter().position(|id| id == dragged) else {
+        return ids;
+    };
+    let id = ids.remove(from);
+    let to = ids.iter().position(|id| id == target).unwrap_or(ids.len());
+    ids.insert(to, id);
+    ids
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -936,4 +1110,12 @@ mod tests {
         };
         assert!(should_show);
     }
+
+    #[wasm_bindgen_test]
+    fn test_move_before() {
+        let ids = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(move_before(ids(&["a", "b", "c"]), "c", "a"), ids(&["c", "a", "b"]));
+        assert_eq!(move_before(ids(&["a", "b", "c"]), "a", "c"), ids(&["b", "a", "c"]));
+        assert_eq!(move_before(ids(&["a", "b"]), "x", "a"), ids(&["a", "b"]));
+    }
 }
@@ -0,0 +1,510 @@
+use chrono::{Datelike, NaiveDate};
+use leptos::*;
+use leptos_router::*;
+use shared::{
+    CreateExpenseRequest, CreateSettlementRequest, ExpenseBalances, ExpenseCategory,
+    ExpenseMonthlySummary, ExpenseWithUser, MemberWithUser, SettlementTransfer, User,
+};
+use uuid::Uuid;
+
+use crate::api::api_client;
+use crate::components::loading::Loading;
+use crate::i18n::use_i18n;
+
+/// Format an amount in cents as "12.34"
+pub fn format_cents(cents: i64) -> String {
+    let sign = if cents < 0 { "-" } else { "" };
+    let abs = cents.abs();
+    format!("{}{}.{:02}", sign, abs / 100, abs % 100)
+}
+
+/// Parse user input such as "12", "12.5" or "12,50" into cents
+pub fn parse_amount_to_cents(input: &str) -> Option<i64> {
+    let normalized = input.trim().replace(',', ".");
+    let (whole, fraction) = match normalized.split_once('.') {
+        Some((w, f)) => (w, f),
+        None => (normalized.as_str(), ""),
+    };
+    if whole.is_empty() && fraction.is_empty() {
+        return None;
+    }
+    if fraction.len() > 2 || !whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let whole: i64 = if whole.is_empty() { 0 } else { whole.parse().ok()? };
+    let fraction: i64 = format!("{:0<2}", fraction).parse().ok()?;
+    let cents = whole.checked_mul(100)?.checked_add(fraction)?;
+    (cents > 0).then_some(cents)
+}
+
+/// Move a (year, month) pair by the given number of months
+fn shift_month(year: i32, month: u32, delta: i32) -> (i32, u32) {
+    let index = year * 12 + month as i32 - 1 + delta;
+    (index.div_euclid(12), index.rem_euclid(12) as u32 + 1)
+}
+
+/// CSS class highlighting whether a member is owed money or owes money
+fn balance_class(balance_cents: i64) -> &'static str {
+    match balance_cents {
+        b if b > 0 => "expense-balance-positive",
+        b if b < 0 => "expense-balance-negative",
+        _ => "",
+    }
+}
+
+fn category_key(category: ExpenseCategory) -> String {
+    format!("expenses.category_{}", category.as_str())
+}
+
+#[component]
+pub fn ExpensesPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let params = use_params_map();
+    let household_id = move || params.with(|p| p.get("id").cloned().unwrap_or_default());
+
+    let today = chrono::Utc::now().date_naive();
+    let selected_month = create_rw_signal((today.year(), today.month()));
+
+    let expenses = create_rw_signal(Vec::<ExpenseWithUser>::new());
+    let summary = create_rw_signal(Option::<ExpenseMonthlySummary>::None);
+    let balances = create_rw_signal(Option::<ExpenseBalances>::None);
+    let members = create_rw_signal(Vec::<MemberWithUser>::new());
+    let current_user = create_rw_signal(Option::<User>::None);
+    let loading = create_rw_signal(true);
+    let error = create_rw_signal(Option::<String>::None);
+    let success = create_rw_signal(Option::<String>::None);
+
+    // Form state
+    let amount = create_rw_signal(String::new());
+    let description = create_rw_signal(String::new());
+    let category = create_rw_signal(ExpenseCategory::Groceries);
+    let paid_by = create_rw_signal(String::new());
+    let expense_date = create_rw_signal(today.format("%Y-%m-%d").to_string());
+    let receipt_note = create_rw_signal(String::new());
+    let saving = create_rw_signal(false);
+
+    // Load members and current user once per household
+    create_effect(move |_| {
+        let id = household_id();
+        if id.is_empty() {
+            return;
+        }
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Ok(m) = api_client().list_members(&id).await {
+                members.set(m);
+            }
+        });
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Ok(user) = api_client().get_current_user().await {
+                paid_by.set(user.id.to_string());
+                current_user.set(Some(user));
+            }
+        });
+    });
+
+    let reload = move || {
+        let id = household_id();
+        if id.is_empty() {
+            return;
+        }
+        let (year, month) = selected_month.get_untracked();
+        let id_for_summary = id.clone();
+        let id_for_balances = id.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().list_expenses(&id, year, month).await {
+                Ok(e) => expenses.set(e),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            loading.set(false);
+        });
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Ok(s) = api_client().get_expense_summary(&id_for_summary, year, month).await {
+                summary.set(Some(s));
+            }
+        });
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Ok(b) = api_client().get_expense_balances(&id_for_balances).await {
+                balances.set(Some(b));
+            }
+        });
+    };
+
+    // Reload whenever the household or the selected month changes
+    create_effect(move |_| {
+        let _ = household_id();
+        let _ = selected_month.get();
+        reload();
+    });
+
+    let on_submit = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        let i18n = i18n_stored.get_value();
+
+        let Some(amount_cents) = parse_amount_to_cents(&amount.get()) else {
+            error.set(Some(i18n.t("expenses.invalid_amount")));
+            return;
+        };
+        if description.get().trim().is_empty() {
+            error.set(Some(i18n.t("expenses.description_required")));
+            return;
+        }
+
+        let note = receipt_note.get();
+        let request = CreateExpenseRequest {
+            amount_cents,
+            category: category.get(),
+            description: description.get(),
+            paid_by: Uuid::parse_str(&paid_by.get()).ok(),
+            receipt_note: (!note.trim().is_empty()).then_some(note),
+            expense_date: NaiveDate::parse_from_str(&expense_date.get(), "%Y-%m-%d").ok(),
+        };
+
+        saving.set(true);
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().create_expense(&id, request).await {
+                Ok(_) => {
+                    amount.set(String::new());
+                    description.set(String::new());
+                    receipt_note.set(String::new());
+                    error.set(None);
+                    success.set(Some(i18n_stored.get_value().t("expenses.saved")));
+                    reload();
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            saving.set(false);
+        });
+    };
+
+    let on_delete = move |expense_id: Uuid| {
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().delete_expense(&id, &expense_id.to_string()).await {
+                Ok(()) => {
+                    success.set(Some(i18n_stored.get_value().t("expenses.deleted")));
+                    reload();
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_settle = move |transfer: SettlementTransfer| {
+        let id = household_id();
+        let request = CreateSettlementRequest {
+            from_user_id: transfer.from_user_id,
+            to_user_id: transfer.to_user_id,
+            amount_cents: transfer.amount_cents,
+            note: None,
+        };
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().record_settlement(&id, request).await {
+                Ok(_) => {
+                    success.set(Some(i18n_stored.get_value().t("expenses.settled")));
+                    reload();
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let month_label = move || {
+        let (year, month) = selected_month.get();
+        format!("{:02}/{}", month, year)
+    };
+
+    view! {
+        <div class="dashboard-header">
+            <h1 class="dashboard-title">{i18n_stored.get_value().t("expenses.title")}</h1>
+        </div>
+
+        {move || error.get().map(|e| view! {
+            <div class="alert alert-error">{e}</div>
+        })}
+
+        {move || success.get().map(|s| view! {
+            <div class="alert alert-success">{s}
+                <button
+                    class="alert-dismiss"
+                    on:click=move |_| success.set(None)
+                >"×"</button>
+            </div>
+        })}
+
+        <div class="expense-month-nav">
+            <button
+                class="btn btn-outline btn-sm"
+                on:click=move |_| selected_month.update(|(y, m)| (*y, *m) = shift_month(*y, *m, -1))
+            >"‹"</button>
+            <span class="expense-month-label">{month_label}</span>
+            <button
+                class="btn btn-outline btn-sm"
+                on:click=move |_| selected_month.update(|(y, m)| (*y, *m) = shift_month(*y, *m, 1))
+            >"›"</button>
+        </div>
+
+        // Monthly summary
+        <div class="card expense-summary">
+            <h3 class="card-title">{i18n_stored.get_value().t("expenses.summary")}</h3>
+            {move || summary.get().map(|s| {
+                let i18n = i18n_stored.get_value();
+                view! {
+                    <div class="expense-summary-total">
+                        {i18n.t("expenses.total")} ": " {format_cents(s.total_cents)}
+                    </div>
+                    <div class="expense-summary-columns">
+                        <div>
+                            <h4>{i18n.t("expenses.by_member")}</h4>
+                            <ul class="expense-summary-list">
+                                {s.by_member.into_iter().map(|m| view! {
+                                    <li>
+                                        <span>{m.username}</span>
+                                        <span>{format_cents(m.total_cents)}</span>
+                                    </li>
+                                }).collect_view()}
+                            </ul>
+                        </div>
+                        <div>
+                            <h4>{i18n.t("expenses.by_category")}</h4>
+                            <ul class="expense-summary-list">
+                                {s.by_category.into_iter().map(|c| view! {
+                                    <li>
+                                        <span>{i18n.t(&category_key(c.category))}</span>
+                                        <span>{format_cents(c.total_cents)}</span>
+                                    </li>
+                                }).collect_view()}
+                            </ul>
+                        </div>
+                    </div>
+                }
+            })}
+        </div>
+
+        // Balances and settle-up suggestions across all months
+        <div class="card expense-balances">
+            <h3 class="card-title">{i18n_stored.get_value().t("expenses.balances")}</h3>
+            {move || balances.get().map(|b| {
+                let i18n = i18n_stored.get_value();
+                let user_id = current_user.get().map(|u| u.id).unwrap_or(Uuid::nil());
+                view! {
+                    <ul class="expense-summary-list">
+                        {b.balances.into_iter().map(|m| {
+                            let class = balance_class(m.balance_cents);
+                            view! {
+                                <li>
+                                    <span>{m.username}</span>
+                                    <span class=class>{format_cents(m.balance_cents)}</span>
+                                </li>
+                            }
+                        }).collect_view()}
+                    </ul>
+                    <h4>{i18n.t("expenses.settle_up")}</h4>
+                    {if b.transfers.is_empty() {
+                        view! { <p class="expense-all-settled">{i18n.t("expenses.all_settled")}</p> }.into_view()
+                    } else {
+                        b.transfers.into_iter().map(|t| {
+                            let text = i18n
+                                .t_with("expenses.owes", &[("from", &t.from_username), ("to", &t.to_username), ("amount", &format_cents(t.amount_cents))]);
+                            let involved = t.from_user_id == user_id || t.to_user_id == user_id;
+                            let transfer = t.clone();
+                            view! {
+                                <div class="expense-transfer">
+                                    <span>{text}</span>
+                                    {involved.then(|| view! {
+                                        <button
+                                            class="btn btn-outline btn-sm"
+                                            on:click=move |_| on_settle(transfer.clone())
+                                        >
+                                            {i18n_stored.get_value().t("expenses.mark_settled")}
+                                        </button>
+                                    })}
+                                </div>
+                            }
+                        }).collect_view()
+                    }}
+                }
+            })}
+        </div>
+
+        // Add expense form
+        <form class="card expense-form" on:submit=on_submit>
+            <h3 class="card-title">{i18n_stored.get_value().t("expenses.add")}</h3>
+            <div class="expense-form-grid">
+                <div class="form-group">
+                    <label class="form-label" for="expense-amount">{i18n_stored.get_value().t("expenses.amount")}</label>
+                    <input
+                        id="expense-amount"
+                        type="text"
+                        inputmode="decimal"
+                        class="form-input"
+                        placeholder="0.00"
+                        prop:value=move || amount.get()
+                        on:input=move |ev| amount.set(event_target_value(&ev))
+                    />
+                </div>
+                <div class="form-group">
+                    <label class="form-label" for="expense-description">{i18n_stored.get_value().t("expenses.description")}</label>
+                    <input
+                        id="expense-description"
+                        type="text"
+                        class="form-input"
+                        prop:value=move || description.get()
+                        on:input=move |ev| description.set(event_target_value(&ev))
+                    />
+                </div>
+                <div class="form-group">
+                    <label class="form-label" for="expense-category">{i18n_stored.get_value().t("expenses.category")}</label>
+                    <select
+                        id="expense-category"
+                        class="form-input"
+                        on:change=move |ev| {
+                            if let Ok(c) = event_target_value(&ev).parse() {
+                                category.set(c);
+                            }
+                        }
+                    >
+                        {ExpenseCategory::ALL.into_iter().map(|c| view! {
+                            <option value=c.as_str() selected=move || category.get() == c>
+                                {i18n_stored.get_value().t(&category_key(c))}
+                            </option>
+                        }).collect_view()}
+                    </select>
+                </div>
+                <div class="form-group">
+                    <label class="form-label" for="expense-payer">{i18n_stored.get_value().t("expenses.paid_by")}</label>
+                    <select
+                        id="expense-payer"
+                        class="form-input"
+                        on:change=move |ev| paid_by.set(event_target_value(&ev))
+                    >
+                        {move || members.get().into_iter().map(|m| {
+                            let value = m.user.id.to_string();
+                            let is_selected = value == paid_by.get();
+                            view! {
+                                <option value=value selected=is_selected>{m.user.username}</option>
+                            }
+                        }).collect_view()}
+                    </select>
+                </div>
+                <div class="form-group">
+                    <label class="form-label" for="expense-date">{i18n_stored.get_value().t("expenses.date")}</label>
+                    <input
+                        id="expense-date"
+                        type="date"
+                        class="form-input"
+                        prop:value=move || expense_date.get()
+                        on:input=move |ev| expense_date.set(event_target_value(&ev))
+                    />
+                </div>
+                <div class="form-group">
+                    <label class="form-label" for="expense-receipt">{i18n_stored.get_value().t("expenses.receipt_note")}</label>
+                    <input
+                        id="expense-receipt"
+                        type="text"
+                        class="form-input"
+                        prop:value=move || receipt_note.get()
+                        on:input=move |ev| receipt_note.set(event_target_value(&ev))
+                    />
+                </div>
+            </div>
+            <button type="submit" class="btn btn-primary" disabled=move || saving.get()>
+                {i18n_stored.get_value().t("expenses.add")}
+            </button>
+        </form>
+
+        <Show when=move || loading.get() fallback=|| ()>
+            <Loading />
+        </Show>
+
+        <Show when=move || !loading.get() fallback=|| ()>
+            <div class="expense-list">
+                {move || {
+                    let list = expenses.get();
+                    let user_id = current_user.get().map(|u| u.id).unwrap_or(Uuid::nil());
+                    if list.is_empty() {
+                        return view! {
+                            <div class="empty-state">
+                                <p>{i18n_stored.get_value().t("expenses.no_expenses")}</p>
+                            </div>
+                        }.into_view();
+                    }
+                    list.into_iter().map(|item| {
+                        let i18n = i18n_stored.get_value();
+                        let expense = item.expense;
+                        let expense_id = expense.id;
+                        let is_own = expense.created_by == user_id || expense.paid_by == user_id;
+                        view! {
+                            <div class="card expense-item">
+                                <div class="expense-item-main">
+                                    <span class="expense-item-description">{expense.description}</span>
+                                    <span class="expense-item-amount">{format_cents(expense.amount_cents)}</span>
+                                </div>
+                                <div class="expense-item-meta">
+                                    <span>{expense.expense_date.format("%d.%m.%Y").to_string()}</span>
+                                    <span>{i18n.t(&category_key(expense.category))}</span>
+                                    <span>{i18n.t("expenses.paid_by")} ": " {item.payer.username}</span>
+                                </div>
+                                {expense.receipt_note.map(|note| view! {
+                                    <div class="expense-item-note">{note}</div>
+                                })}
+                                {is_own.then(|| view! {
+                                    <button
+                                        class="btn btn-outline btn-sm"
+                                        on:click=move |_| on_delete(expense_id)
+                                    >
+                                        {i18n_stored.get_value().t("common.delete")}
+                                    </button>
+                                })}
+                            </div>
+                        }
+                    }).collect_view()
+                }}
+            </div>
+        </Show>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn test_format_cents() {
+        assert_eq!(format_cents(0), "0.00");
+        assert_eq!(format_cents(5), "0.05");
+        assert_eq!(format_cents(1234), "12.34");
+        assert_eq!(format_cents(-250), "-2.50");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_parse_amount_to_cents() {
+        assert_eq!(parse_amount_to_cents("12"), Some(1200));
+        assert_eq!(parse_amount_to_cents("12.5"), Some(1250));
+        assert_eq!(parse_amount_to_cents("12,05"), Some(1205));
+        assert_eq!(parse_amount_to_cents(".99"), Some(99));
+        assert_eq!(parse_amount_to_cents("0"), None);
+        assert_eq!(parse_amount_to_cents("1.234"), None);
+        assert_eq!(parse_amount_to_cents("abc"), None);
+        assert_eq!(parse_amount_to_cents(""), None);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_balance_class() {
+        assert_eq!(balance_class(100), "expense-balance-positive");
+        assert_eq!(balance_class(-1), "expense-balance-negative");
+        assert_eq!(balance_class(0), "");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_shift_month() {
+        assert_eq!(shift_month(2024, 1, -1), (2023, 12));
+        assert_eq!(shift_month(2024, 12, 1), (2025, 1));
+        assert_eq!(shift_month(2024, 6, 0), (2024, 6));
+    }
+}
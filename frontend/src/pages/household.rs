@@ -3,12 +3,17 @@ use std::collections::HashSet;
 use chrono::NaiveDate;
 use leptos::*;
 use leptos_router::*;
-use shared::{AdjustPointsRequest, Announcement, CreateInvitationRequest, Household, HouseholdSettings, Invitation, LeaderboardEntry, MemberWithUser, Punishment, RecurrenceType, RecurrenceValue, Reward, Role, Task, TaskCategory, TaskPunishmentLink, TaskRewardLink, TaskWithStatus, UpdateRoleRequest, UpdateTaskRequest};
+use shared::{AdjustPointsRequest, Announcement, CompletionStatus, CreateInvitationRequest, Household, HouseholdSettings, Invitation, LeaderboardEntry, LeaderboardWindow, MemberWithUser, PaginationQuery, PointGoalProgress, Punishment, RecurrenceType, RecurrenceValue, Reward, Role, SetMemberVacationRequest, Task, TaskCategory, TaskPunishmentLink, TaskRewardLink, TaskWithStatus, UpdateRoleRequest, UpdateTaskRequest, WsEventCategory, WsServerMessage};
 use uuid::Uuid;
 
-use crate::api::ApiClient;
+use crate::api::websocket::{WsClient, WsConnectionState};
+use crate::api::api_client;
 use crate::components::announcement_banner::AnnouncementBanner;
 use crate::components::announcement_modal::AnnouncementModal;
+use crate::components::child_account_modal::{ChildAccountModal, ChildPinModal};
+use crate::components::invite_codes_modal::InviteCodesModal;
+use crate::components::member_permissions_modal::MemberPermissionsModal;
+use crate::components::completion_photos::AttachPhotoModal;
 use crate::components::loading::Loading;
 use crate::utils::matches_text_filter;
 use crate::components::modal::Modal;
@@ -16,15 +21,33 @@ use crate::components::pending_confirmations::PendingConfirmations;
 use crate::components::pending_reviews::PendingReviews;
 use crate::components::pending_suggestions::PendingSuggestions;
 use crate::components::points_display::PointsBadge;
+use crate::components::point_goals_card::PointGoalsCard;
+use crate::components::points_history_modal::PointsHistoryModal;
 use crate::components::set_date_modal::SetDateModal;
 use crate::components::task_card::{GroupedTaskList, TaskWithHousehold};
 use crate::components::task_detail_modal::TaskDetailModal;
 use crate::components::task_modal::TaskModal;
 use crate::components::text_filter_input::TextFilterInput;
+use crate::components::theme::use_theme;
 use crate::i18n::use_i18n;
 
+/// Describe a member's current or upcoming vacation, e.g. "01.07. – 14.07.2024".
+/// Returns None when no vacation is set or it already ended.
+fn vacation_range_label(start: Option<NaiveDate>, end: Option<NaiveDate>, today: NaiveDate) -> Option<String> {
+    let start = start?;
+    match end {
+        Some(end) if end < today => None,
+        Some(end) => Some(format!("{} – {}", start.format("%d.%m."), end.format("%d.%m.%Y"))),
+        None => Some(format!("{} –", start.format("%d.%m.%Y"))),
+    }
+}
+
+/// Tasks loaded before the rest of the household page; the remainder streams in afterwards
+const INITIAL_TASK_PAGE: i64 = 50;
+
 #[component]
 pub fn HouseholdPage() -> impl IntoView {
+    let theme = use_theme();
     let i18n = use_i18n();
     let i18n_stored = store_value(i18n);
 
@@ -35,10 +58,13 @@ pub fn HouseholdPage() -> impl IntoView {
     let members = create_rw_signal(Vec::<MemberWithUser>::new());
     let tasks = create_rw_signal(Vec::<TaskWithStatus>::new());
     let leaderboard = create_rw_signal(Vec::<LeaderboardEntry>::new());
+    let leaderboard_window = create_rw_signal(LeaderboardWindow::default());
+    let point_goals = create_rw_signal(Vec::<PointGoalProgress>::new());
     let invitations = create_rw_signal(Vec::<Invitation>::new());
     let settings = create_rw_signal(Option::<HouseholdSettings>::None);
     let loading = create_rw_signal(true);
     let error = create_rw_signal(Option::<String>::None);
+    let gift_notice = create_rw_signal(Option::<String>::None);
 
     // Invite modal state
     let show_invite_modal = create_rw_signal(false);
@@ -94,10 +120,7 @@ pub fn HouseholdPage() -> impl IntoView {
             .into_iter()
             .filter(|t| {
                 if only_assigned {
-                    match (user_id, t.task.assigned_user_id) {
-                        (Some(uid), Some(assigned_uid)) => uid == assigned_uid,
-                        _ => false,
-                    }
+                    user_id.map(|uid| t.task.assignees().contains(&uid)).unwrap_or(false)
                 } else {
                     true
                 }
@@ -107,6 +130,7 @@ pub fn HouseholdPage() -> impl IntoView {
     });
 
     // Adjust points modal state
+    let points_history_member = create_rw_signal(Option::<(String, String)>::None);
     let show_adjust_points_modal = create_rw_signal(false);
     let adjust_points_user_id = create_rw_signal(String::new());
     let adjust_points_username = create_rw_signal(String::new());
@@ -131,12 +155,97 @@ pub fn HouseholdPage() -> impl IntoView {
     let assign_punishment_error = create_rw_signal(Option::<String>::None);
     let assigning_punishment = create_rw_signal(false);
 
+    // Member vacation modal state
+    let show_vacation_modal = create_rw_signal(false);
+    let vacation_user_id = create_rw_signal(String::new());
+    let vacation_username = create_rw_signal(String::new());
+    let vacation_start = create_rw_signal(String::new());
+    let vacation_end = create_rw_signal(String::new());
+    let vacation_error = create_rw_signal(Option::<String>::None);
+    let saving_vacation = create_rw_signal(false);
+
     // Owner transfer confirmation modal state
     let show_owner_transfer_modal = create_rw_signal(false);
     let owner_transfer_user_id = create_rw_signal(String::new());
     let owner_transfer_username = create_rw_signal(String::new());
     let transferring_ownership = create_rw_signal(false);
 
+    // Child account modals: creation, and PIN reset for (user_id, username)
+    let show_child_account_modal = create_rw_signal(false);
+    let show_invite_codes_modal = create_rw_signal(false);
+    let child_pin_target = create_rw_signal(Option::<(String, String)>::None);
+    let permissions_target = create_rw_signal(Option::<(String, String)>::None);
+    let photo_completion_id = create_rw_signal(Option::<String>::None);
+
+    // Live leaderboard: subscribe to the household's points and rewards events (not its chat)
+    // and apply pushed updates
+    let ws_client = WsClient::new();
+    ws_client.connect();
+    let ws_state = ws_client.state();
+    let ws_state = create_memo(move |_| ws_state.get());
+    let ws_messages = ws_client.last_message();
+    {
+        let ws_client = ws_client.clone();
+        // Returns the subscribed household, so a change of household moves the subscription
+        create_effect(move |subscribed: Option<Option<String>>| {
+            let id = household_id();
+            if ws_state.get() != WsConnectionState::Authenticated {
+                return None;
+            }
+            let subscribed = subscribed.flatten();
+            if subscribed.as_deref() != Some(id.as_str()) {
+                if let Some(previous) = subscribed.and_then(|previous| Uuid::parse_str(&previous).ok()) {
+                    ws_client.unsubscribe(previous, Vec::new());
+                }
+                if let Ok(uuid) = Uuid::parse_str(&id) {
+                    ws_client.subscribe(uuid, vec![WsEventCategory::Points, WsEventCategory::Rewards]);
+                }
+            }
+            Some(id)
+        });
+    }
+    create_effect(move |_| {
+        match ws_messages.get() {
+            Some(WsServerMessage::LeaderboardUpdated { household_id: updated_id, leaderboard: entries })
+                if updated_id.to_string() == household_id() =>
+            {
+                // Pushed updates carry the all-time leaderboard; other windows are reloaded
+                match leaderboard_window.get_untracked() {
+                    LeaderboardWindow::AllTime => leaderboard.set(entries),
+                    window => {
+                        let id = household_id();
+                        wasm_bindgen_futures::spawn_local(async move {
+                            if let Ok(l) = api_client().get_leaderboard(&id, window).await {
+                                leaderboard.set(l);
+                            }
+                        });
+                    }
+                }
+            }
+            Some(WsServerMessage::GoalProgressUpdated { household_id: updated_id, progress })
+                if updated_id.to_string() == household_id() =>
+            {
+                point_goals.update(|goals| {
+                    if let Some(goal) = goals.iter_mut().find(|g| g.goal.id == progress.goal.id) {
+                        *goal = progress;
+                    }
+                });
+            }
+            Some(WsServerMessage::RewardGifted { household_id: gifted_in, reward_name, from_user_id, to_user_id, .. })
+                if gifted_in.to_string() == household_id() && current_user_id.get_untracked() == Some(to_user_id) =>
+            {
+                let from = members.with_untracked(|m| {
+                    m.iter().find(|member| member.user.id == from_user_id).map(|member| member.user.username.clone())
+                }).unwrap_or_default();
+                gift_notice.set(Some(
+                    i18n_stored.get_value().t_with("rewards.gift_received", &[("user", &from), ("name", &reward_name)]),
+                ));
+            }
+            _ => {}
+        }
+    });
+    on_cleanup(move || ws_client.disconnect());
+
     // Load data on mount
     create_effect(move |_| {
         let id = household_id();
@@ -146,15 +255,15 @@ pub fn HouseholdPage() -> impl IntoView {
 
         wasm_bindgen_futures::spawn_local(async move {
             // Load household
-            match ApiClient::get_household(&id).await {
+            match api_client().get_household(&id).await {
                 Ok(h) => household.set(Some(h)),
-                Err(e) => error.set(Some(e)),
+                Err(e) => error.set(Some(e.to_string())),
             }
 
             // Load members and store current user role
-            if let Ok(m) = ApiClient::list_members(&id).await {
+            if let Ok(m) = api_client().list_members(&id).await {
                 // Find current user's role
-                if let Ok(current_user) = ApiClient::get_current_user().await {
+                if let Ok(current_user) = api_client().get_current_user().await {
                     current_user_id.set(Some(current_user.id));
                     if let Some(member) = m.iter().find(|member| member.user.id == current_user.id) {
                         current_user_role.set(Some(member.membership.role));
@@ -163,56 +272,59 @@ pub fn HouseholdPage() -> impl IntoView {
                 members.set(m);
             }
 
-            // Load all tasks with status
-            if let Ok(t) = ApiClient::get_all_tasks_with_status(&id).await {
-                tasks.set(t);
+            // Load the first tasks with status; the rest follows without blocking the page
+            if let Ok(page) = api_client().get_tasks_with_status_page(&id, PaginationQuery::new(INITIAL_TASK_PAGE, 0)).await {
+                let loaded = page.items.len() as i64;
+                let has_more = page.has_more();
+                tasks.set(page.items);
+                if has_more {
+                    let id = id.clone();
+                    wasm_bindgen_futures::spawn_local(async move {
+                        let rest = PaginationQuery { limit: None, offset: Some(loaded) };
+                        if let Ok(page) = api_client().get_tasks_with_status_page(&id, rest).await {
+                            // A refresh in the meantime already loaded everything
+                            if tasks.with_untracked(|t| t.len() as i64) == loaded {
+                                tasks.update(|t| t.extend(page.items));
+                            }
+                        }
+                    });
+                }
             }
 
             // Load leaderboard
-            if let Ok(l) = ApiClient::get_leaderboard(&id).await {
+            if let Ok(l) = api_client().get_leaderboard(&id, leaderboard_window.get_untracked()).await {
                 leaderboard.set(l);
             }
 
             // Load invitations (only for admins/owners, will fail silently for members)
-            if let Ok(inv) = ApiClient::list_household_invitations(&id).await {
+            if let Ok(inv) = api_client().list_household_invitations(&id).await {
                 invitations.set(inv);
             }
 
             // Load rewards, punishments, and categories for assignment/edit modals
-            if let Ok(r) = ApiClient::list_rewards(&id).await {
+            if let Ok(r) = api_client().list_rewards(&id).await {
                 rewards.set(r);
             }
-            if let Ok(p) = ApiClient::list_punishments(&id).await {
+            if let Ok(p) = api_client().list_punishments(&id).await {
                 punishments.set(p);
             }
-            if let Ok(c) = ApiClient::list_categories(&id).await {
+            if let Ok(c) = api_client().list_categories(&id).await {
                 categories.set(c);
             }
 
             // Load active announcements
-            if let Ok(anns) = ApiClient::list_active_announcements(&id).await {
+            if let Ok(anns) = api_client().list_active_announcements(&id).await {
                 active_announcements.set(anns);
             }
 
             // Load dashboard task IDs (user's whitelist)
-            if let Ok(ids) = ApiClient::get_dashboard_task_ids().await {
+            if let Ok(ids) = api_client().get_dashboard_task_ids().await {
                 dashboard_task_ids.set(ids.into_iter().map(|id| id.to_string()).collect());
             }
 
             // Load settings and apply dark mode
-            if let Ok(s) = ApiClient::get_household_settings(&id).await {
-                // Apply dark mode
-                if let Some(window) = web_sys::window() {
-                    if let Some(document) = window.document() {
-                        if let Some(body) = document.body() {
-                            if s.dark_mode {
-                                let _ = body.class_list().add_1("dark-mode");
-                            } else {
-                                let _ = body.class_list().remove_1("dark-mode");
-                            }
-                        }
-                    }
-                }
+            if let Ok(s) = api_client().get_household_settings(&id).await {
+                theme.set_household_dark_mode(s.dark_mode);
                 // Update can_manage based on hierarchy type
                 if let Some(role) = current_user_role.get() {
                     current_user_can_manage.set(s.hierarchy_type.can_manage(&role));
@@ -227,13 +339,17 @@ pub fn HouseholdPage() -> impl IntoView {
     let on_complete_task = Callback::new(move |task_id: String| {
         let id = household_id();
         wasm_bindgen_futures::spawn_local(async move {
-            if ApiClient::complete_task(&id, &task_id).await.is_ok() {
+            if let Ok(completion) = api_client().complete_task(&id, &task_id).await {
+                // Completions that await review can carry photo proof
+                if completion.status == CompletionStatus::Pending {
+                    photo_completion_id.set(Some(completion.id.to_string()));
+                }
                 // Refresh tasks
-                if let Ok(t) = ApiClient::get_all_tasks_with_status(&id).await {
+                if let Ok(t) = api_client().get_all_tasks_with_status(&id).await {
                     tasks.set(t);
                 }
                 // Refresh leaderboard
-                if let Ok(l) = ApiClient::get_leaderboard(&id).await {
+                if let Ok(l) = api_client().get_leaderboard(&id, leaderboard_window.get_untracked()).await {
                     leaderboard.set(l);
                 }
             }
@@ -243,13 +359,13 @@ pub fn HouseholdPage() -> impl IntoView {
     let on_uncomplete_task = Callback::new(move |task_id: String| {
         let id = household_id();
         wasm_bindgen_futures::spawn_local(async move {
-            if ApiClient::uncomplete_task(&id, &task_id).await.is_ok() {
+            if api_client().uncomplete_task(&id, &task_id).await.is_ok() {
                 // Refresh tasks
-                if let Ok(t) = ApiClient::get_all_tasks_with_status(&id).await {
+                if let Ok(t) = api_client().get_all_tasks_with_status(&id).await {
                     tasks.set(t);
                 }
                 // Refresh leaderboard
-                if let Ok(l) = ApiClient::get_leaderboard(&id).await {
+                if let Ok(l) = api_client().get_leaderboard(&id, leaderboard_window.get_untracked()).await {
                     leaderboard.set(l);
                 }
             }
@@ -266,14 +382,14 @@ pub fn HouseholdPage() -> impl IntoView {
         let id_for_rewards = id.clone();
         let task_id_for_rewards = task_id.clone();
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(r) = ApiClient::get_task_rewards(&id_for_rewards, &task_id_for_rewards).await {
+            if let Ok(r) = api_client().get_task_rewards(&id_for_rewards, &task_id_for_rewards).await {
                 task_linked_rewards.set(r);
             }
         });
 
         // Load linked punishments
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(p) = ApiClient::get_task_punishments(&id, &task_id).await {
+            if let Ok(p) = api_client().get_task_punishments(&id, &task_id).await {
                 task_linked_punishments.set(p);
             }
         });
@@ -309,7 +425,7 @@ pub fn HouseholdPage() -> impl IntoView {
 
         wasm_bindgen_futures::spawn_local(async move {
             let request = CreateInvitationRequest { email, role };
-            match ApiClient::create_invitation(&id, request).await {
+            match api_client().create_invitation(&id, request).await {
                 Ok(invitation) => {
                     invitations.update(|inv| inv.push(invitation));
                     show_invite_modal.set(false);
@@ -317,7 +433,7 @@ pub fn HouseholdPage() -> impl IntoView {
                     invite_role.set("member".to_string());
                 }
                 Err(e) => {
-                    invite_error.set(Some(e));
+                    invite_error.set(Some(e.to_string()));
                 }
             }
             inviting.set(false);
@@ -327,7 +443,7 @@ pub fn HouseholdPage() -> impl IntoView {
     let on_cancel_invitation = move |invitation_id: String| {
         let id = household_id();
         wasm_bindgen_futures::spawn_local(async move {
-            if ApiClient::cancel_invitation(&id, &invitation_id).await.is_ok() {
+            if api_client().cancel_invitation(&id, &invitation_id).await.is_ok() {
                 invitations.update(|inv| inv.retain(|i| i.id.to_string() != invitation_id));
             }
         });
@@ -372,25 +488,76 @@ pub fn HouseholdPage() -> impl IntoView {
                 points,
                 reason: if reason.is_empty() { None } else { Some(reason) },
             };
-            match ApiClient::adjust_member_points(&id, &user_id, request).await {
+            match api_client().adjust_member_points(&id, &user_id, request).await {
                 Ok(_) => {
                     show_adjust_points_modal.set(false);
                     // Refresh members and leaderboard
-                    if let Ok(m) = ApiClient::list_members(&id).await {
+                    if let Ok(m) = api_client().list_members(&id).await {
                         members.set(m);
                     }
-                    if let Ok(l) = ApiClient::get_leaderboard(&id).await {
+                    if let Ok(l) = api_client().get_leaderboard(&id, leaderboard_window.get_untracked()).await {
                         leaderboard.set(l);
                     }
                 }
                 Err(e) => {
-                    adjust_points_error.set(Some(e));
+                    adjust_points_error.set(Some(e.to_string()));
                 }
             }
             adjusting_points.set(false);
         });
     };
 
+    // Open vacation modal for a specific member
+    let open_vacation_modal = move |user_id: String, username: String, start: Option<NaiveDate>, end: Option<NaiveDate>| {
+        vacation_user_id.set(user_id);
+        vacation_username.set(username);
+        vacation_start.set(start.map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or_default());
+        vacation_end.set(end.map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or_default());
+        vacation_error.set(None);
+        show_vacation_modal.set(true);
+    };
+
+    let save_vacation = move |request: SetMemberVacationRequest| {
+        let id = household_id();
+        let user_id = vacation_user_id.get();
+        saving_vacation.set(true);
+        vacation_error.set(None);
+
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().set_member_vacation(&id, &user_id, request).await {
+                Ok(_) => {
+                    show_vacation_modal.set(false);
+                    if let Ok(m) = api_client().list_members(&id).await {
+                        members.set(m);
+                    }
+                }
+                Err(e) => {
+                    vacation_error.set(Some(e.to_string()));
+                }
+            }
+            saving_vacation.set(false);
+        });
+    };
+
+    let on_vacation_submit = move |ev: web_sys::SubmitEvent| {
+        ev.prevent_default();
+
+        let Ok(start) = NaiveDate::parse_from_str(&vacation_start.get(), "%Y-%m-%d") else {
+            vacation_error.set(Some(i18n_stored.get_value().t("members.vacation_start_required")));
+            return;
+        };
+        let end = NaiveDate::parse_from_str(&vacation_end.get(), "%Y-%m-%d").ok();
+        if end.is_some_and(|end| end < start) {
+            vacation_error.set(Some(i18n_stored.get_value().t("members.vacation_end_before_start")));
+            return;
+        }
+
+        save_vacation(SetMemberVacationRequest {
+            vacation_start: Some(start),
+            vacation_end: end,
+        });
+    };
+
     // Open assign reward modal for a specific member
     let open_assign_reward_modal = move |user_id: String, username: String| {
         assign_reward_user_id.set(user_id);
@@ -416,19 +583,19 @@ pub fn HouseholdPage() -> impl IntoView {
         assign_reward_error.set(None);
 
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::assign_reward(&id, &reward_id, &user_id).await {
+            match api_client().assign_reward(&id, &reward_id, &user_id).await {
                 Ok(_) => {
                     show_assign_reward_modal.set(false);
                     // Refresh members and leaderboard
-                    if let Ok(m) = ApiClient::list_members(&id).await {
+                    if let Ok(m) = api_client().list_members(&id).await {
                         members.set(m);
                     }
-                    if let Ok(l) = ApiClient::get_leaderboard(&id).await {
+                    if let Ok(l) = api_client().get_leaderboard(&id, leaderboard_window.get_untracked()).await {
                         leaderboard.set(l);
                     }
                 }
                 Err(e) => {
-                    assign_reward_error.set(Some(e));
+                    assign_reward_error.set(Some(e.to_string()));
                 }
             }
             assigning_reward.set(false);
@@ -460,19 +627,19 @@ pub fn HouseholdPage() -> impl IntoView {
         assign_punishment_error.set(None);
 
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::assign_punishment(&id, &punishment_id, &user_id).await {
+            match api_client().assign_punishment(&id, &punishment_id, &user_id).await {
                 Ok(_) => {
                     show_assign_punishment_modal.set(false);
                     // Refresh members and leaderboard
-                    if let Ok(m) = ApiClient::list_members(&id).await {
+                    if let Ok(m) = api_client().list_members(&id).await {
                         members.set(m);
                     }
-                    if let Ok(l) = ApiClient::get_leaderboard(&id).await {
+                    if let Ok(l) = api_client().get_leaderboard(&id, leaderboard_window.get_untracked()).await {
                         leaderboard.set(l);
                     }
                 }
                 Err(e) => {
-                    assign_punishment_error.set(Some(e));
+                    assign_punishment_error.set(Some(e.to_string()));
                 }
             }
             assigning_punishment.set(false);
@@ -483,12 +650,12 @@ pub fn HouseholdPage() -> impl IntoView {
     let on_toggle_dashboard = Callback::new(move |(task_id, add_to_dashboard): (String, bool)| {
         wasm_bindgen_futures::spawn_local(async move {
             if add_to_dashboard {
-                if ApiClient::add_task_to_dashboard(&task_id).await.is_ok() {
+                if api_client().add_task_to_dashboard(&task_id).await.is_ok() {
                     dashboard_task_ids.update(|ids| {
                         ids.insert(task_id);
                     });
                 }
-            } else if ApiClient::remove_task_from_dashboard(&task_id).await.is_ok() {
+            } else if api_client().remove_task_from_dashboard(&task_id).await.is_ok() {
                 dashboard_task_ids.update(|ids| {
                     ids.remove(&task_id);
                 });
@@ -507,14 +674,14 @@ pub fn HouseholdPage() -> impl IntoView {
         let tid = task_id.clone();
         wasm_bindgen_futures::spawn_local(async move {
             // Fetch the task details
-            if let Ok(details) = ApiClient::get_task_details(&id, &tid).await {
+            if let Ok(details) = api_client().get_task_details(&id, &tid).await {
                 editing_task.set(Some(details.task));
 
                 // Load linked rewards and punishments
-                if let Ok(r) = ApiClient::get_task_rewards(&id, &tid).await {
+                if let Ok(r) = api_client().get_task_rewards(&id, &tid).await {
                     task_linked_rewards.set(r);
                 }
-                if let Ok(p) = ApiClient::get_task_punishments(&id, &tid).await {
+                if let Ok(p) = api_client().get_task_punishments(&id, &tid).await {
                     task_linked_punishments.set(p);
                 }
             }
@@ -542,21 +709,31 @@ pub fn HouseholdPage() -> impl IntoView {
                 recurrence_type: Some(RecurrenceType::Custom),
                 recurrence_value: Some(RecurrenceValue::CustomDates(vec![date])),
                 assigned_user_id: None,
+                assignee_ids: None,
                 target_count: None,
                 time_period: None,
                 allow_exceed_target: None,
                 requires_review: None,
+                reviewer_type: None,
+                reviewer_id: None,
                 points_reward: None,
                 points_penalty: None,
                 due_time: None,
+                effort_minutes: None,
+                active_from: None,
+                active_until: None,
+                ends_on: None,
+                max_occurrences: None,
                 habit_type: None,
                 category_id: None,
+                tag_ids: None,
                 archived: None,
                 paused: None,
+                version: None,
             };
-            if ApiClient::update_task(&id, &task_id, request).await.is_ok() {
+            if api_client().update_task(&id, &task_id, request).await.is_ok() {
                 // Refresh tasks
-                if let Ok(t) = ApiClient::get_all_tasks_with_status(&id).await {
+                if let Ok(t) = api_client().get_all_tasks_with_status(&id).await {
                     tasks.set(t);
                 }
             }
@@ -571,19 +748,38 @@ pub fn HouseholdPage() -> impl IntoView {
         let id = household_id();
         wasm_bindgen_futures::spawn_local(async move {
             let result = if is_paused {
-                ApiClient::unpause_task(&id, &task_id).await
+                api_client().unpause_task(&id, &task_id).await
             } else {
-                ApiClient::pause_task(&id, &task_id).await
+                api_client().pause_task(&id, &task_id).await
             };
             if result.is_ok() {
                 // Refresh tasks
-                if let Ok(t) = ApiClient::get_all_tasks_with_status(&id).await {
+                if let Ok(t) = api_client().get_all_tasks_with_status(&id).await {
                     tasks.set(t);
                 }
             }
         });
     });
 
+    let on_context_claim = Callback::new(move |(task_id, _hh_id, is_claimed): (String, String, bool)| {
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            let result = if is_claimed {
+                api_client().release_task(&id, &task_id).await
+            } else {
+                api_client().claim_task(&id, &task_id).await.map(|_| ())
+            };
+            match result {
+                Ok(()) => {
+                    if let Ok(t) = api_client().get_all_tasks_with_status(&id).await {
+                        tasks.set(t);
+                    }
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    });
+
     view! {
         <Show when=move || loading.get() fallback=|| ()>
             <Loading />
@@ -593,6 +789,9 @@ pub fn HouseholdPage() -> impl IntoView {
             {move || error.get().map(|e| view! {
                 <div class="alert alert-error">{e}</div>
             })}
+            {move || gift_notice.get().map(|n| view! {
+                <div class="alert alert-success">{n}</div>
+            })}
 
             <Show when=move || household.get().is_some() fallback=|| ()>
                     // Announcement Banner
@@ -604,13 +803,14 @@ pub fn HouseholdPage() -> impl IntoView {
                         if has_announcements && is_owner {
                             view! {
                                 <AnnouncementBanner
+                                    household_id=household_id()
                                     announcements=announcements
                                     on_manage=Callback::new(move |_| show_announcement_modal.set(true))
                                 />
                             }.into_view()
                         } else if has_announcements {
                             view! {
-                                <AnnouncementBanner announcements=announcements />
+                                <AnnouncementBanner household_id=household_id() announcements=announcements />
                             }.into_view()
                         } else if is_owner {
                             // Show just the manage button if owner and no announcements
@@ -648,10 +848,10 @@ pub fn HouseholdPage() -> impl IntoView {
                                                     // Refresh tasks and leaderboard after review
                                                     let hid = household_id();
                                                     wasm_bindgen_futures::spawn_local(async move {
-                                                        if let Ok(t) = ApiClient::get_all_tasks_with_status(&hid).await {
+                                                        if let Ok(t) = api_client().get_all_tasks_with_status(&hid).await {
                                                             tasks.set(t);
                                                         }
-                                                        if let Ok(l) = ApiClient::get_leaderboard(&hid).await {
+                                                        if let Ok(l) = api_client().get_leaderboard(&hid, leaderboard_window.get_untracked()).await {
                                                             leaderboard.set(l);
                                                         }
                                                     });
@@ -666,7 +866,7 @@ pub fn HouseholdPage() -> impl IntoView {
                                                     // Refresh tasks after suggestion is approved
                                                     let hid = household_id();
                                                     wasm_bindgen_futures::spawn_local(async move {
-                                                        if let Ok(t) = ApiClient::get_all_tasks_with_status(&hid).await {
+                                                        if let Ok(t) = api_client().get_all_tasks_with_status(&hid).await {
                                                             tasks.set(t);
                                                         }
                                                     });
@@ -680,7 +880,7 @@ pub fn HouseholdPage() -> impl IntoView {
                                                     // Refresh leaderboard after confirmation
                                                     let hid = household_id();
                                                     wasm_bindgen_futures::spawn_local(async move {
-                                                        if let Ok(l) = ApiClient::get_leaderboard(&hid).await {
+                                                        if let Ok(l) = api_client().get_leaderboard(&hid, leaderboard_window.get_untracked()).await {
                                                             leaderboard.set(l);
                                                         }
                                                     });
@@ -724,14 +924,36 @@ pub fn HouseholdPage() -> impl IntoView {
                                     .into_iter()
                                     .map(|t| TaskWithHousehold::new(t, Some(hh_id.clone()), None))
                                     .collect();
-                                view! { <GroupedTaskList tasks=tasks_with_household on_complete=on_complete_task on_uncomplete=on_uncomplete_task timezone=tz dashboard_task_ids=dashboard_ids on_toggle_dashboard=on_toggle_dashboard on_click_title=on_click_task_title on_edit=on_context_edit on_set_date=on_context_set_date on_pause=on_context_pause solo_mode=is_solo_mode /> }
+                                view! { <GroupedTaskList tasks=tasks_with_household on_complete=on_complete_task on_uncomplete=on_uncomplete_task timezone=tz dashboard_task_ids=dashboard_ids on_toggle_dashboard=on_toggle_dashboard on_click_title=on_click_task_title on_edit=on_context_edit on_set_date=on_context_set_date on_pause=on_context_pause on_claim=on_context_claim solo_mode=is_solo_mode /> }
                             }}
                         </div>
 
                         <div>
                             <div class="card">
-                                <div class="card-header">
+                                <div class="card-header" style="display: flex; justify-content: space-between; align-items: center;">
                                     <h3 class="card-title">{i18n_stored.get_value().t("leaderboard.title")}</h3>
+                                    <select
+                                        class="form-select"
+                                        style="width: auto;"
+                                        on:change=move |ev| {
+                                            let Ok(window) = event_target_value(&ev).parse::<LeaderboardWindow>() else {
+                                                return;
+                                            };
+                                            leaderboard_window.set(window);
+                                            let id = household_id();
+                                            wasm_bindgen_futures::spawn_local(async move {
+                                                if let Ok(l) = api_client().get_leaderboard(&id, window).await {
+                                                    leaderboard.set(l);
+                                                }
+                                            });
+                                        }
+                                    >
+                                        {LeaderboardWindow::ALL.into_iter().map(|window| view! {
+                                            <option value=window.as_str() selected=move || leaderboard_window.get() == window>
+                                                {i18n_stored.get_value().t(&format!("leaderboard.window_{}", window.as_str()))}
+                                            </option>
+                                        }).collect_view()}
+                                    </select>
                                 </div>
                                 {move || {
                                     let lb = leaderboard.get();
@@ -751,11 +973,19 @@ pub fn HouseholdPage() -> impl IntoView {
                                                         3 => "leaderboard-rank third",
                                                         _ => "leaderboard-rank",
                                                     };
+                                                    let history_user_id = entry.user.id.to_string();
+                                                    let history_username = entry.user.username.clone();
                                                     view! {
                                                         <div class="leaderboard-item">
                                                             <span class=rank_class>{entry.rank}</span>
                                                             <div class="leaderboard-user">
-                                                                <div style="font-weight: 500;">{entry.user.username}</div>
+                                                                <button
+                                                                    class="leaderboard-user-button"
+                                                                    title=i18n_stored.get_value().t("points_history.show")
+                                                                    on:click=move |_| points_history_member.set(Some((history_user_id.clone(), history_username.clone())))
+                                                                >
+                                                                    {entry.user.username}
+                                                                </button>
                                                                 <div style="font-size: 0.75rem; color: var(--text-muted);">
                                                                     {entry.tasks_completed} " " {i18n_stored.get_value().t("leaderboard.tasks_completed_count")}
                                                                 </div>
@@ -770,18 +1000,40 @@ pub fn HouseholdPage() -> impl IntoView {
                                 }}
                             </div>
 
+                            {move || current_user_id.get().map(|user_id| view! {
+                                <PointGoalsCard household_id=household_id() user_id=user_id.to_string() goals=point_goals />
+                            })}
+
                             <div class="card">
                                 <div class="card-header" style="display: flex; justify-content: space-between; align-items: center;">
                                     <h3 class="card-title">{i18n_stored.get_value().t("members.title")}</h3>
-                                    <Show when=move || current_user_can_manage.get() fallback=|| ()>
-                                        <button
-                                            class="btn btn-primary"
-                                            style="padding: 0.25rem 0.75rem; font-size: 0.875rem;"
-                                            on:click=move |_| show_invite_modal.set(true)
-                                        >
-                                            "+ " {i18n_stored.get_value().t("household.invite")}
-                                        </button>
-                                    </Show>
+                                    <div style="display: flex; gap: 0.5rem;">
+                                        <Show when=move || current_user_role.get() == Some(Role::Owner) fallback=|| ()>
+                                            <button
+                                                class="btn btn-outline"
+                                                style="padding: 0.25rem 0.75rem; font-size: 0.875rem;"
+                                                on:click=move |_| show_child_account_modal.set(true)
+                                            >
+                                                "+ " {i18n_stored.get_value().t("child_accounts.create")}
+                                            </button>
+                                        </Show>
+                                        <Show when=move || current_user_can_manage.get() fallback=|| ()>
+                                            <button
+                                                class="btn btn-outline"
+                                                style="padding: 0.25rem 0.75rem; font-size: 0.875rem;"
+                                                on:click=move |_| show_invite_codes_modal.set(true)
+                                            >
+                                                {i18n_stored.get_value().t("invite_codes.button")}
+                                            </button>
+                                            <button
+                                                class="btn btn-primary"
+                                                style="padding: 0.25rem 0.75rem; font-size: 0.875rem;"
+                                                on:click=move |_| show_invite_modal.set(true)
+                                            >
+                                                "+ " {i18n_stored.get_value().t("household.invite")}
+                                            </button>
+                                        </Show>
+                                    </div>
                                 </div>
                                 {move || {
                                     let m = members.get();
@@ -792,6 +1044,11 @@ pub fn HouseholdPage() -> impl IntoView {
                                     let adjust_points_title = i18n_stored.get_value().t("buttons.adjust_points");
                                     let assign_reward_title = i18n_stored.get_value().t("buttons.assign_reward");
                                     let assign_punishment_title = i18n_stored.get_value().t("buttons.assign_punishment");
+                                    let vacation_title = i18n_stored.get_value().t("members.vacation");
+                                    let child_badge = i18n_stored.get_value().t("child_accounts.badge");
+                                    let reset_pin_title = i18n_stored.get_value().t("child_accounts.reset_pin");
+                                    let permissions_title = i18n_stored.get_value().t("permissions.edit");
+                                    let today = chrono::Utc::now().date_naive();
                                     let role_owner_default = i18n_stored.get_value().t("roles.owner");
                                     let role_admin_default = i18n_stored.get_value().t("roles.admin");
                                     let role_member_default = i18n_stored.get_value().t("roles.member");
@@ -800,7 +1057,9 @@ pub fn HouseholdPage() -> impl IntoView {
                                             {m.into_iter().map(|member| {
                                                 let is_member_owner = member.membership.role == Role::Owner;
                                                 let is_self = curr_user_id == Some(member.user.id);
-                                                let can_change_role = is_current_user_owner && !is_member_owner && !is_self;
+                                                let is_child = member.user.is_child_account();
+                                                // Child accounts are always plain members
+                                                let can_change_role = is_current_user_owner && !is_member_owner && !is_self && !is_child;
                                                 let badge_class = match member.membership.role {
                                                     shared::Role::Owner => "badge badge-owner",
                                                     shared::Role::Admin => "badge badge-admin",
@@ -854,6 +1113,21 @@ pub fn HouseholdPage() -> impl IntoView {
                                                     .unwrap_or_else(|| role_owner_default.clone());
                                                 let member_username = username.clone();
                                                 let member_user_id_for_transfer = user_id.clone();
+                                                let can_set_vacation = can_manage || is_self;
+                                                let user_id_vacation = user_id.clone();
+                                                let username_vacation = username.clone();
+                                                let member_vacation_start = member.membership.vacation_start;
+                                                let member_vacation_end = member.membership.vacation_end;
+                                                let vacation_label = vacation_range_label(member_vacation_start, member_vacation_end, today);
+                                                let is_on_vacation = member.membership.is_on_vacation(today);
+                                                let vacation_title = vacation_title.clone();
+                                                let child_badge = child_badge.clone();
+                                                let reset_pin_title = reset_pin_title.clone();
+                                                let user_id_pin = user_id.clone();
+                                                let username_pin = username.clone();
+                                                let permissions_title = permissions_title.clone();
+                                                let user_id_permissions = user_id.clone();
+                                                let username_permissions = username.clone();
                                                 view! {
                                                     <div style="display: flex; justify-content: space-between; align-items: center; padding: 0.75rem 0; border-bottom: 1px solid var(--border-color);">
                                                         <div>
@@ -875,7 +1149,7 @@ pub fn HouseholdPage() -> impl IntoView {
                                                                                 show_owner_transfer_modal.set(true);
                                                                                 // Reset dropdown to current value by reloading members
                                                                                 wasm_bindgen_futures::spawn_local(async move {
-                                                                                    if let Ok(m) = ApiClient::list_members(&hh_id).await {
+                                                                                    if let Ok(m) = api_client().list_members(&hh_id).await {
                                                                                         members.set(m);
                                                                                     }
                                                                                 });
@@ -886,16 +1160,16 @@ pub fn HouseholdPage() -> impl IntoView {
                                                                                 _ => Role::Member,
                                                                             };
                                                                             wasm_bindgen_futures::spawn_local(async move {
-                                                                                match ApiClient::update_member_role(&hh_id, &user_id_role, UpdateRoleRequest { role: new_role }).await {
+                                                                                match api_client().update_member_role(&hh_id, &user_id_role, UpdateRoleRequest { role: new_role }).await {
                                                                                     Ok(_) => {
                                                                                         // Reload members
-                                                                                        if let Ok(m) = ApiClient::list_members(&hh_id).await {
+                                                                                        if let Ok(m) = api_client().list_members(&hh_id).await {
                                                                                             members.set(m);
                                                                                         }
                                                                                     }
                                                                                     Err(_) => {
                                                                                         // Error handling - reload members to restore UI state
-                                                                                        if let Ok(m) = ApiClient::list_members(&hh_id).await {
+                                                                                        if let Ok(m) = api_client().list_members(&hh_id).await {
                                                                                             members.set(m);
                                                                                         }
                                                                                     }
@@ -913,8 +1187,58 @@ pub fn HouseholdPage() -> impl IntoView {
                                                                     <span class=badge_class style="margin-left: 0.5rem;">{role_text}</span>
                                                                 }.into_view()
                                                             }}
+                                                            {is_child.then(|| view! {
+                                                                <span class="badge badge-child" style="margin-left: 0.5rem;">{child_badge}</span>
+                                                            })}
+                                                            {vacation_label.map(|label| view! {
+                                                                <span class="badge badge-vacation" class:badge-vacation-active=is_on_vacation style="margin-left: 0.5rem;">
+                                                                    "🏖 " {label}
+                                                                </span>
+                                                            })}
                                                         </div>
                                                         <div style="display: flex; align-items: center; gap: 0.5rem;">
+                                                            {if is_current_user_owner && is_child {
+                                                                view! {
+                                                                    <button
+                                                                        class="btn btn-outline"
+                                                                        style="padding: 0.125rem 0.5rem; font-size: 0.75rem;"
+                                                                        title=reset_pin_title
+                                                                        on:click=move |_| child_pin_target.set(Some((user_id_pin.clone(), username_pin.clone())))
+                                                                    >
+                                                                        "🔑"
+                                                                    </button>
+                                                                }.into_view()
+                                                            } else {
+                                                                ().into_view()
+                                                            }}
+                                                            {if is_current_user_owner && !is_member_owner {
+                                                                view! {
+                                                                    <button
+                                                                        class="btn btn-outline"
+                                                                        style="padding: 0.125rem 0.5rem; font-size: 0.75rem;"
+                                                                        title=permissions_title
+                                                                        on:click=move |_| permissions_target.set(Some((user_id_permissions.clone(), username_permissions.clone())))
+                                                                    >
+                                                                        "🛡"
+                                                                    </button>
+                                                                }.into_view()
+                                                            } else {
+                                                                ().into_view()
+                                                            }}
+                                                            {if can_set_vacation {
+                                                                view! {
+                                                                    <button
+                                                                        class="btn btn-outline"
+                                                                        style="padding: 0.125rem 0.5rem; font-size: 0.75rem;"
+                                                                        title=vacation_title.clone()
+                                                                        on:click=move |_| open_vacation_modal(user_id_vacation.clone(), username_vacation.clone(), member_vacation_start, member_vacation_end)
+                                                                    >
+                                                                        "🏖"
+                                                                    </button>
+                                                                }.into_view()
+                                                            } else {
+                                                                ().into_view()
+                                                            }}
                                                             {if can_manage {
                                                                 view! {
                                                                     <div style="display: flex; gap: 0.25rem;">
@@ -1011,6 +1335,45 @@ pub fn HouseholdPage() -> impl IntoView {
                     </div>
             </Show>
 
+            // Child Account Modals
+            <Show when=move || show_child_account_modal.get() fallback=|| ()>
+                <ChildAccountModal
+                    household_id=household_id()
+                    on_close=move |_| show_child_account_modal.set(false)
+                    on_created=move |member: MemberWithUser| members.update(|m| m.push(member))
+                />
+            </Show>
+            <Show when=move || show_invite_codes_modal.get() fallback=|| ()>
+                <InviteCodesModal
+                    household_id=household_id()
+                    can_invite_admin=current_user_role.get_untracked() == Some(Role::Owner)
+                    on_close=move |_| show_invite_codes_modal.set(false)
+                />
+            </Show>
+            {move || child_pin_target.get().map(|(user_id, username)| view! {
+                <ChildPinModal
+                    household_id=household_id()
+                    user_id=user_id
+                    username=username
+                    on_close=move |_| child_pin_target.set(None)
+                />
+            })}
+            {move || permissions_target.get().map(|(user_id, username)| view! {
+                <MemberPermissionsModal
+                    household_id=household_id()
+                    user_id=user_id
+                    username=username
+                    on_close=move |_| permissions_target.set(None)
+                />
+            })}
+            {move || photo_completion_id.get().map(|completion_id| view! {
+                <AttachPhotoModal
+                    household_id=household_id()
+                    completion_id=completion_id
+                    on_close=move |_| photo_completion_id.set(None)
+                />
+            })}
+
             // Invite Modal
             <Show when=move || show_invite_modal.get() fallback=|| ()>
                 <Modal title=i18n_stored.get_value().t("members.invite") on_close=move |_| show_invite_modal.set(false)>
@@ -1073,6 +1436,15 @@ pub fn HouseholdPage() -> impl IntoView {
             </Show>
 
             // Adjust Points Modal
+            {move || points_history_member.get().map(|(user_id, username)| view! {
+                <PointsHistoryModal
+                    household_id=household_id()
+                    user_id=user_id
+                    username=username
+                    on_close=move |_| points_history_member.set(None)
+                />
+            })}
+
             <Show when=move || show_adjust_points_modal.get() fallback=|| ()>
                 <Modal title=i18n_stored.get_value().t("members.adjust_points_title") on_close=move |_| show_adjust_points_modal.set(false)>
                     {move || adjust_points_error.get().map(|e| view! {
@@ -1127,6 +1499,73 @@ pub fn HouseholdPage() -> impl IntoView {
                 </Modal>
             </Show>
 
+            // Member Vacation Modal
+            <Show when=move || show_vacation_modal.get() fallback=|| ()>
+                <Modal title=i18n_stored.get_value().t("members.vacation_title") on_close=move |_| show_vacation_modal.set(false)>
+                    {move || vacation_error.get().map(|e| view! {
+                        <div class="alert alert-error" style="margin-bottom: 1rem;">{e}</div>
+                    })}
+
+                    <p style="margin-bottom: 1rem;">
+                        {move || i18n_stored.get_value().t_with("members.vacation_description", &[("username", &vacation_username.get())])}
+                    </p>
+
+                    <form on:submit=on_vacation_submit>
+                        <div class="form-group">
+                            <label class="form-label" for="vacation-start">{i18n_stored.get_value().t("members.vacation_start")}</label>
+                            <input
+                                type="date"
+                                id="vacation-start"
+                                class="form-input"
+                                prop:value=move || vacation_start.get()
+                                on:input=move |ev| vacation_start.set(event_target_value(&ev))
+                                required
+                            />
+                        </div>
+
+                        <div class="form-group">
+                            <label class="form-label" for="vacation-end">{i18n_stored.get_value().t("members.vacation_end")}</label>
+                            <input
+                                type="date"
+                                id="vacation-end"
+                                class="form-input"
+                                prop:value=move || vacation_end.get()
+                                on:input=move |ev| vacation_end.set(event_target_value(&ev))
+                            />
+                            <small class="form-hint">{i18n_stored.get_value().t("members.vacation_end_hint")}</small>
+                        </div>
+
+                        <div class="modal-footer">
+                            <Show when=move || !vacation_start.get().is_empty() fallback=|| ()>
+                                <button
+                                    type="button"
+                                    class="btn btn-outline"
+                                    on:click=move |_| save_vacation(SetMemberVacationRequest::default())
+                                    disabled=move || saving_vacation.get()
+                                >
+                                    {i18n_stored.get_value().t("members.end_vacation")}
+                                </button>
+                            </Show>
+                            <button
+                                type="button"
+                                class="btn btn-outline"
+                                on:click=move |_| show_vacation_modal.set(false)
+                                disabled=move || saving_vacation.get()
+                            >
+                                {i18n_stored.get_value().t("common.cancel")}
+                            </button>
+                            <button
+                                type="submit"
+                                class="btn btn-primary"
+                                disabled=move || saving_vacation.get()
+                            >
+                                {move || if saving_vacation.get() { i18n_stored.get_value().t("common.processing") } else { i18n_stored.get_value().t("common.save") }}
+                            </button>
+                        </div>
+                    </form>
+                </Modal>
+            </Show>
+
             // Assign Reward Modal
             <Show when=move || show_assign_reward_modal.get() fallback=|| ()>
                 <Modal title=i18n_stored.get_value().t("rewards.assign") on_close=move |_| show_assign_reward_modal.set(false)>
@@ -1233,7 +1672,7 @@ pub fn HouseholdPage() -> impl IntoView {
                         <p style="margin-bottom: 0.5rem;">
                             {move || {
                                 let username = owner_transfer_username.get();
-                                i18n_stored.get_value().t("members.transfer_ownership_confirm").replace("{username}", &username)
+                                i18n_stored.get_value().t_with("members.transfer_ownership_confirm", &[("username", &username)])
                             }}
                         </p>
                         <p style="color: var(--warning-color); font-weight: 500;">
@@ -1259,12 +1698,12 @@ pub fn HouseholdPage() -> impl IntoView {
                                 let target_user_id = owner_transfer_user_id.get();
                                 transferring_ownership.set(true);
                                 wasm_bindgen_futures::spawn_local(async move {
-                                    match ApiClient::update_member_role(&hh_id, &target_user_id, UpdateRoleRequest { role: Role::Owner }).await {
+                                    match api_client().transfer_ownership(&hh_id, &target_user_id).await {
                                         Ok(_) => {
                                             // Reload members and update current user role
-                                            if let Ok(m) = ApiClient::list_members(&hh_id).await {
+                                            if let Ok(m) = api_client().list_members(&hh_id).await {
                                                 // Find current user's new role
-                                                if let Ok(current_user) = ApiClient::get_current_user().await {
+                                                if let Ok(current_user) = api_client().get_current_user().await {
                                                     if let Some(member) = m.iter().find(|member| member.user.id == current_user.id) {
                                                         current_user_role.set(Some(member.membership.role));
                                                     }
@@ -1275,7 +1714,7 @@ pub fn HouseholdPage() -> impl IntoView {
                                         }
                                         Err(_) => {
                                             // Error - reload members to restore UI state
-                                            if let Ok(m) = ApiClient::list_members(&hh_id).await {
+                                            if let Ok(m) = api_client().list_members(&hh_id).await {
                                                 members.set(m);
                                             }
                                         }
@@ -1299,7 +1738,7 @@ pub fn HouseholdPage() -> impl IntoView {
                         // Refresh active announcements
                         let hid = household_id();
                         wasm_bindgen_futures::spawn_local(async move {
-                            if let Ok(anns) = ApiClient::list_active_announcements(&hid).await {
+                            if let Ok(anns) = api_client().list_active_announcements(&hid).await {
                                 active_announcements.set(anns);
                             }
                         });
@@ -1493,6 +1932,20 @@ mod tests {
         assert!(is_zero);
     }
 
+    #[wasm_bindgen_test]
+    fn test_vacation_range_label() {
+        let today = NaiveDate::from_ymd_opt(2024, 7, 10).unwrap();
+        let start = NaiveDate::from_ymd_opt(2024, 7, 1);
+        assert_eq!(vacation_range_label(None, None, today), None);
+        assert_eq!(
+            vacation_range_label(start, NaiveDate::from_ymd_opt(2024, 7, 14), today),
+            Some("01.07. – 14.07.2024".to_string())
+        );
+        assert_eq!(vacation_range_label(start, None, today), Some("01.07.2024 –".to_string()));
+        // Past vacations are not shown
+        assert_eq!(vacation_range_label(start, NaiveDate::from_ymd_opt(2024, 7, 5), today), None);
+    }
+
     #[wasm_bindgen_test]
     fn test_invite_role_admin() {
         let role_str = "admin";
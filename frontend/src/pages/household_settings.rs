@@ -1,19 +1,25 @@
 use chrono::NaiveDate;
 use leptos::*;
 use leptos_router::*;
-use shared::{DefaultPunishmentEntry, DefaultRewardEntry, HierarchyType, Household, HouseholdSettings, Punishment, Reward, Role, UpdateHouseholdSettingsRequest};
+use shared::{AllowancePointHandling, DefaultPunishmentEntry, DefaultRewardEntry, HierarchyType, Household, HouseholdFeature, HouseholdSettings, PointDecayMode, Punishment, PunishmentTerminology, Reward, Role, UpdateHouseholdSettingsRequest};
 
-use crate::api::ApiClient;
+use crate::api::{api_client, files};
+use crate::components::audit_log_modal::AuditLogModal;
+use crate::components::household_layout::HouseholdContext;
 use crate::components::loading::Loading;
 use crate::components::modal::Modal;
+use crate::components::webhook_settings::WebhookSettings;
 use crate::components::{
     Alert, AlertVariant, Button, ButtonVariant, Card, Divider, SectionHeader,
 };
+use crate::components::theme::use_theme;
 use crate::i18n::use_i18n;
-use crate::utils::COMMON_TIMEZONES;
+use crate::pages::expenses::{format_cents, parse_amount_to_cents};
+use crate::utils::{csv_file_name, download_json, download_url, export_file_name, COMMON_TIMEZONES};
 
 #[component]
 pub fn HouseholdSettingsPage() -> impl IntoView {
+    let theme = use_theme();
     let i18n = use_i18n();
     let i18n_stored = store_value(i18n);
 
@@ -34,11 +40,20 @@ pub fn HouseholdSettingsPage() -> impl IntoView {
     let household = create_rw_signal(Option::<Household>::None);
     let household_name = create_rw_signal(String::new());
     let name_saving = create_rw_signal(false);
+    let exporting = create_rw_signal(false);
+    let csv_from = create_rw_signal(String::new());
+    let csv_to = create_rw_signal(String::new());
+    let csv_exporting = create_rw_signal(false);
 
     // Solo Mode state
     let solo_mode_confirm_open = create_rw_signal(false);
     let solo_mode_activating = create_rw_signal(false);
 
+    let audit_log_open = create_rw_signal(false);
+    let leave_confirm_open = create_rw_signal(false);
+    let leaving = create_rw_signal(false);
+    let navigate = store_value(use_navigate());
+
     // Form state
     let dark_mode = create_rw_signal(false);
     let role_label_owner = create_rw_signal(String::new());
@@ -46,19 +61,42 @@ pub fn HouseholdSettingsPage() -> impl IntoView {
     let role_label_member = create_rw_signal(String::new());
     let hierarchy_type = create_rw_signal(HierarchyType::Organized);
     let timezone = create_rw_signal("UTC".to_string());
-    let rewards_enabled = create_rw_signal(false);
-    let punishments_enabled = create_rw_signal(false);
-    let chat_enabled = create_rw_signal(false);
+    let punishment_terminology = create_rw_signal(PunishmentTerminology::default());
+    let punishment_label_singular = create_rw_signal(String::new());
+    let punishment_label_plural = create_rw_signal(String::new());
+    let disabled_features = create_rw_signal(HouseholdFeature::OFF_BY_DEFAULT.to_vec());
+    let feature_enabled = move |feature: HouseholdFeature| disabled_features.with(|d| !d.contains(&feature));
+    let set_feature_enabled = move |feature: HouseholdFeature, enabled: bool| {
+        disabled_features.update(|d| {
+            d.retain(|f| *f != feature);
+            if !enabled {
+                d.push(feature);
+            }
+        });
+    };
     let vacation_mode = create_rw_signal(false);
     let vacation_start = create_rw_signal(Option::<NaiveDate>::None);
     let vacation_end = create_rw_signal(Option::<NaiveDate>::None);
     let auto_archive_days = create_rw_signal(Option::<i32>::Some(7));
     let allow_task_suggestions = create_rw_signal(true);
     let week_start_day = create_rw_signal(0i32); // 0 = Monday
+    let weekly_summary_enabled = create_rw_signal(false);
+    let weekly_summary_email = create_rw_signal(false);
+    let point_decay_mode = create_rw_signal(PointDecayMode::None);
+    let point_decay_amount = create_rw_signal(10i32);
+    let purchase_approval_required = create_rw_signal(false);
+    let streak_freeze_price = create_rw_signal(0i64);
+    let allowance_rate = create_rw_signal(String::new());
+    let allowance_point_handling = create_rw_signal(AllowancePointHandling::Reset);
 
     // Task defaults
     let default_points_reward = create_rw_signal(Option::<i64>::None);
     let default_points_penalty = create_rw_signal(Option::<i64>::None);
+    let default_requires_review = create_rw_signal(false);
+    let default_allow_exceed_target = create_rw_signal(true);
+    let good_habit_points_multiplier = create_rw_signal(1.0f64);
+    let bad_habit_points_multiplier = create_rw_signal(1.0f64);
+    let review_points_threshold = create_rw_signal(Option::<i64>::None);
     // Vec of (reward_id, amount)
     let default_rewards = create_rw_signal(Vec::<(String, i32)>::new());
     let default_punishments = create_rw_signal(Vec::<(String, i32)>::new());
@@ -85,7 +123,7 @@ pub fn HouseholdSettingsPage() -> impl IntoView {
 
         // Load settings
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::get_household_settings(&id_for_settings).await {
+            match api_client().get_household_settings(&id_for_settings).await {
                 Ok(s) => {
                     dark_mode.set(s.dark_mode);
                     role_label_owner.set(s.role_label_owner.clone());
@@ -93,17 +131,37 @@ pub fn HouseholdSettingsPage() -> impl IntoView {
                     role_label_member.set(s.role_label_member.clone());
                     hierarchy_type.set(s.hierarchy_type);
                     timezone.set(s.timezone.clone());
-                    rewards_enabled.set(s.rewards_enabled);
-                    punishments_enabled.set(s.punishments_enabled);
-                    chat_enabled.set(s.chat_enabled);
+                    punishment_terminology.set(s.punishment_terminology);
+                    punishment_label_singular.set(s.punishment_label_singular.clone().unwrap_or_default());
+                    punishment_label_plural.set(s.punishment_label_plural.clone().unwrap_or_default());
+                    disabled_features.set(s.disabled_features.clone());
                     vacation_mode.set(s.vacation_mode);
                     vacation_start.set(s.vacation_start);
                     vacation_end.set(s.vacation_end);
                     auto_archive_days.set(s.auto_archive_days);
                     allow_task_suggestions.set(s.allow_task_suggestions);
                     week_start_day.set(s.week_start_day);
+                    weekly_summary_enabled.set(s.weekly_summary_enabled);
+                    weekly_summary_email.set(s.weekly_summary_email);
+                    point_decay_mode.set(s.point_decay_mode);
+                    if s.point_decay_amount > 0 {
+                        point_decay_amount.set(s.point_decay_amount);
+                    }
+                    purchase_approval_required.set(s.purchase_approval_required);
+                    streak_freeze_price.set(s.streak_freeze_price);
+                    allowance_rate.set(if s.allowance_cents_per_point > 0 {
+                        format_cents(s.allowance_cents_per_point)
+                    } else {
+                        String::new()
+                    });
+                    allowance_point_handling.set(s.allowance_point_handling);
                     default_points_reward.set(s.default_points_reward);
                     default_points_penalty.set(s.default_points_penalty);
+                    default_requires_review.set(s.default_requires_review);
+                    default_allow_exceed_target.set(s.default_allow_exceed_target);
+                    good_habit_points_multiplier.set(s.good_habit_points_multiplier);
+                    bad_habit_points_multiplier.set(s.bad_habit_points_multiplier);
+                    review_points_threshold.set(s.review_points_threshold);
                     default_rewards.set(
                         s.default_rewards.iter()
                             .map(|r| (r.reward.id.to_string(), r.amount))
@@ -116,14 +174,14 @@ pub fn HouseholdSettingsPage() -> impl IntoView {
                     );
                     settings.set(Some(s));
                 }
-                Err(e) => error.set(Some(e)),
+                Err(e) => error.set(Some(e.to_string())),
             }
             loading.set(false);
         });
 
         // Load household data for name
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(hh) = ApiClient::get_household(&id_for_household).await {
+            if let Ok(hh) = api_client().get_household(&id_for_household).await {
                 household_name.set(hh.name.clone());
                 household.set(Some(hh));
             }
@@ -131,8 +189,8 @@ pub fn HouseholdSettingsPage() -> impl IntoView {
 
         // Check if current user is owner and get role
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(members) = ApiClient::list_members(&id_for_members).await {
-                if let Ok(current_user) = ApiClient::get_current_user().await {
+            if let Ok(members) = api_client().list_members(&id_for_members).await {
+                if let Ok(current_user) = api_client().get_current_user().await {
                     for m in &members {
                         if m.user.id == current_user.id {
                             current_role.set(Some(m.membership.role));
@@ -148,12 +206,12 @@ pub fn HouseholdSettingsPage() -> impl IntoView {
         let id_for_rewards = id.clone();
         let id_for_punishments = id;
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(r) = ApiClient::list_rewards(&id_for_rewards).await {
+            if let Ok(r) = api_client().list_rewards(&id_for_rewards).await {
                 rewards.set(r);
             }
         });
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(p) = ApiClient::list_punishments(&id_for_punishments).await {
+            if let Ok(p) = api_client().list_punishments(&id_for_punishments).await {
                 punishments.set(p);
             }
         });
@@ -174,9 +232,9 @@ pub fn HouseholdSettingsPage() -> impl IntoView {
             role_label_member: Some(role_label_member.get()),
             hierarchy_type: Some(hierarchy_type.get()),
             timezone: Some(timezone.get()),
-            rewards_enabled: Some(rewards_enabled.get()),
-            punishments_enabled: Some(punishments_enabled.get()),
-            chat_enabled: Some(chat_enabled.get()),
+            punishment_terminology: Some(punishment_terminology.get()),
+            punishment_label_singular: Some(Some(punishment_label_singular.get()).filter(|l| !l.trim().is_empty())),
+            punishment_label_plural: Some(Some(punishment_label_plural.get()).filter(|l| !l.trim().is_empty())),
             vacation_mode: Some(vacation_mode.get()),
             vacation_start: Some(vacation_start.get()),
             vacation_end: Some(vacation_end.get()),
@@ -199,19 +257,39 @@ pub fn HouseholdSettingsPage() -> impl IntoView {
                     })
                     .collect()
             ),
+            weekly_summary_enabled: Some(weekly_summary_enabled.get()),
+            weekly_summary_email: Some(weekly_summary_email.get()),
+            point_decay_mode: Some(point_decay_mode.get()),
+            point_decay_amount: Some(point_decay_amount.get()),
+            purchase_approval_required: Some(purchase_approval_required.get()),
+            streak_freeze_price: Some(streak_freeze_price.get()),
+            allowance_cents_per_point: Some(parse_amount_to_cents(&allowance_rate.get()).unwrap_or(0)),
+            allowance_point_handling: Some(allowance_point_handling.get()),
+            default_requires_review: Some(default_requires_review.get()),
+            default_allow_exceed_target: Some(default_allow_exceed_target.get()),
+            good_habit_points_multiplier: Some(good_habit_points_multiplier.get()),
+            bad_habit_points_multiplier: Some(bad_habit_points_multiplier.get()),
+            review_points_threshold: Some(review_points_threshold.get()),
+            disabled_features: Some(disabled_features.get()),
+            version: settings.get_untracked().map(|s| s.version),
         };
 
+        // The tab bar reads the layout's copy of the settings
+        let layout_settings = use_context::<HouseholdContext>().map(|ctx| ctx.settings);
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::update_household_settings(&id, request).await {
+            match api_client().update_household_settings(&id, request).await {
                 Ok(s) => {
                     settings.set(Some(s.clone()));
+                    if let Some(layout_settings) = layout_settings {
+                        layout_settings.set(Some(s.clone()));
+                    }
                     success.set(Some(i18n_stored.get_value().t("settings.saved")));
 
                     // Apply dark mode immediately
-                    apply_dark_mode(s.dark_mode);
+                    theme.set_household_dark_mode(s.dark_mode);
                 }
                 Err(e) => {
-                    error.set(Some(e));
+                    error.set(Some(e.to_string()));
                 }
             }
             saving.set(false);
@@ -230,19 +308,57 @@ pub fn HouseholdSettingsPage() -> impl IntoView {
         success.set(None);
 
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::update_household(&id, name).await {
+            match api_client().update_household(&id, name).await {
                 Ok(hh) => {
                     household.set(Some(hh));
                     success.set(Some(i18n_stored.get_value().t("household.settings.name_updated")));
                 }
                 Err(e) => {
-                    error.set(Some(e));
+                    error.set(Some(e.to_string()));
                 }
             }
             name_saving.set(false);
         });
     };
 
+    let on_export = move |_| {
+        let id = household_id();
+        exporting.set(true);
+        error.set(None);
+        success.set(None);
+
+        wasm_bindgen_futures::spawn_local(async move {
+            let result = match api_client().export_household(&id).await {
+                Ok(data) => serde_json::to_string_pretty(&data)
+                    .map_err(|e| e.to_string())
+                    .and_then(|json| download_json(&export_file_name(&data.household_name), &json)),
+                Err(e) => Err(e.to_string()),
+            };
+            if let Err(e) = result {
+                error.set(Some(e));
+            }
+            exporting.set(false);
+        });
+    };
+
+    let on_export_csv = move |kind: &'static str| {
+        let id = household_id();
+        let (from, to) = (csv_from.get(), csv_to.get());
+        csv_exporting.set(true);
+        error.set(None);
+        success.set(None);
+
+        wasm_bindgen_futures::spawn_local(async move {
+            let result = files::export_csv(&id, kind, &from, &to)
+                .await
+                .and_then(|href| download_url(&csv_file_name(kind, &from, &to), &href));
+            if let Err(e) = result {
+                error.set(Some(e));
+            }
+            csv_exporting.set(false);
+        });
+    };
+
     view! {
         <div class="dashboard-header">
             <h1 class="dashboard-title">{i18n_stored.get_value().t("settings.household_settings")}</h1>
@@ -286,6 +402,58 @@ pub fn HouseholdSettingsPage() -> impl IntoView {
                             </Button>
                         </div>
                     </div>
+                    <div class="form-group">
+                        <label class="form-label">{i18n_stored.get_value().t("household.settings.backup")}</label>
+                        <div>
+                            <Button
+                                variant=ButtonVariant::Outline
+                                on_click=Callback::new(on_export)
+                                disabled=MaybeSignal::derive(move || exporting.get())
+                            >
+                                {move || if exporting.get() { i18n_stored.get_value().t("household.settings.exporting") } else { i18n_stored.get_value().t("household.settings.export") }}
+                            </Button>
+                        </div>
+                        <small class="form-hint">{i18n_stored.get_value().t("household.settings.export_hint")}</small>
+                    </div>
+                    <div class="form-group">
+                        <label class="form-label">{i18n_stored.get_value().t("household.settings.csv_export")}</label>
+                        <div style="display: flex; gap: 0.5rem; flex-wrap: wrap; align-items: center; margin-bottom: 0.5rem;">
+                            <input
+                                type="date"
+                                class="form-input"
+                                style="width: auto;"
+                                aria-label=i18n_stored.get_value().t("household.settings.csv_from")
+                                prop:value=move || csv_from.get()
+                                on:input=move |ev| csv_from.set(event_target_value(&ev))
+                            />
+                            <span>"–"</span>
+                            <input
+                                type="date"
+                                class="form-input"
+                                style="width: auto;"
+                                aria-label=i18n_stored.get_value().t("household.settings.csv_to")
+                                prop:value=move || csv_to.get()
+                                on:input=move |ev| csv_to.set(event_target_value(&ev))
+                            />
+                        </div>
+                        <div style="display: flex; gap: 0.5rem; flex-wrap: wrap;">
+                            <Button
+                                variant=ButtonVariant::Outline
+                                on_click=Callback::new(move |_| on_export_csv("completions"))
+                                disabled=MaybeSignal::derive(move || csv_exporting.get())
+                            >
+                                {i18n_stored.get_value().t("household.settings.csv_completions")}
+                            </Button>
+                            <Button
+                                variant=ButtonVariant::Outline
+                                on_click=Callback::new(move |_| on_export_csv("points"))
+                                disabled=MaybeSignal::derive(move || csv_exporting.get())
+                            >
+                                {i18n_stored.get_value().t("household.settings.csv_points")}
+                            </Button>
+                        </div>
+                        <small class="form-hint">{i18n_stored.get_value().t("household.settings.csv_export_hint")}</small>
+                    </div>
                     <Divider />
                 </Show>
 
@@ -404,9 +572,9 @@ pub fn HouseholdSettingsPage() -> impl IntoView {
                                 <input
                                     type="checkbox"
                                     id="rewards-enabled"
-                                    prop:checked=move || rewards_enabled.get()
+                                    prop:checked=move || feature_enabled(HouseholdFeature::Rewards)
                                     on:change=move |ev| {
-                                        rewards_enabled.set(event_target_checked(&ev));
+                                        set_feature_enabled(HouseholdFeature::Rewards, event_target_checked(&ev));
                                     }
                                 />
                                 <label for="rewards-enabled">{i18n_stored.get_value().t("settings.enable_rewards")}</label>
@@ -414,14 +582,31 @@ pub fn HouseholdSettingsPage() -> impl IntoView {
                             <small class="form-hint">{i18n_stored.get_value().t("settings.rewards_hint")}</small>
                         </div>
 
+                        <Show when=move || feature_enabled(HouseholdFeature::Rewards) fallback=|| ()>
+                            <div class="form-group">
+                                <div style="display: flex; align-items: center; gap: 0.5rem;">
+                                    <input
+                                        type="checkbox"
+                                        id="purchase-approval-required"
+                                        prop:checked=move || purchase_approval_required.get()
+                                        on:change=move |ev| {
+                                            purchase_approval_required.set(event_target_checked(&ev));
+                                        }
+                                    />
+                                    <label for="purchase-approval-required">{i18n_stored.get_value().t("settings.purchase_approval_required")}</label>
+                                </div>
+                                <small class="form-hint">{i18n_stored.get_value().t("settings.purchase_approval_hint")}</small>
+                            </div>
+                        </Show>
+
                         <div class="form-group">
                             <div style="display: flex; align-items: center; gap: 0.5rem;">
                                 <input
                                     type="checkbox"
                                     id="punishments-enabled"
-                                    prop:checked=move || punishments_enabled.get()
+                                    prop:checked=move || feature_enabled(HouseholdFeature::Punishments)
                                     on:change=move |ev| {
-                                        punishments_enabled.set(event_target_checked(&ev));
+                                        set_feature_enabled(HouseholdFeature::Punishments, event_target_checked(&ev));
                                     }
                                 />
                                 <label for="punishments-enabled">{i18n_stored.get_value().t("settings.enable_punishments")}</label>
@@ -429,14 +614,60 @@ pub fn HouseholdSettingsPage() -> impl IntoView {
                             <small class="form-hint">{i18n_stored.get_value().t("settings.punishments_hint")}</small>
                         </div>
 
+                        <Show when=move || feature_enabled(HouseholdFeature::Punishments) fallback=|| ()>
+                            <div class="form-group">
+                                <label class="form-label" for="punishment-terminology">{i18n_stored.get_value().t("settings.punishment_terminology")}</label>
+                                <select
+                                    id="punishment-terminology"
+                                    class="form-select"
+                                    on:change=move |ev| {
+                                        if let Ok(terminology) = event_target_value(&ev).parse::<PunishmentTerminology>() {
+                                            punishment_terminology.set(terminology);
+                                        }
+                                    }
+                                >
+                                    <option value="punishments" selected=move || punishment_terminology.get() == PunishmentTerminology::Punishments>
+                                        {i18n_stored.get_value().t("settings.terminology_default")}
+                                    </option>
+                                    <option value="consequences" selected=move || punishment_terminology.get() == PunishmentTerminology::Consequences>
+                                        {i18n_stored.get_value().t("settings.terminology_consequences")}
+                                    </option>
+                                    <option value="custom" selected=move || punishment_terminology.get() == PunishmentTerminology::Custom>
+                                        {i18n_stored.get_value().t("settings.terminology_custom")}
+                                    </option>
+                                </select>
+                                <Show when=move || punishment_terminology.get() == PunishmentTerminology::Custom fallback=|| ()>
+                                    <div style="display: flex; gap: 0.5rem; margin-top: 0.5rem;">
+                                        <input
+                                            type="text"
+                                            class="form-input"
+                                            maxlength="40"
+                                            placeholder=i18n_stored.get_value().t("settings.terminology_singular")
+                                            prop:value=move || punishment_label_singular.get()
+                                            on:input=move |ev| punishment_label_singular.set(event_target_value(&ev))
+                                        />
+                                        <input
+                                            type="text"
+                                            class="form-input"
+                                            maxlength="40"
+                                            placeholder=i18n_stored.get_value().t("settings.terminology_plural")
+                                            prop:value=move || punishment_label_plural.get()
+                                            on:input=move |ev| punishment_label_plural.set(event_target_value(&ev))
+                                        />
+                                    </div>
+                                </Show>
+                                <small class="form-hint">{i18n_stored.get_value().t("settings.terminology_hint")}</small>
+                            </div>
+                        </Show>
+
                         <div class="form-group">
                             <div style="display: flex; align-items: center; gap: 0.5rem;">
                                 <input
                                     type="checkbox"
                                     id="chat-enabled"
-                                    prop:checked=move || chat_enabled.get()
+                                    prop:checked=move || feature_enabled(HouseholdFeature::Chat)
                                     on:change=move |ev| {
-                                        chat_enabled.set(event_target_checked(&ev));
+                                        set_feature_enabled(HouseholdFeature::Chat, event_target_checked(&ev));
                                     }
                                 />
                                 <label for="chat-enabled">{i18n_stored.get_value().t("settings.enable_chat")}</label>
@@ -444,6 +675,28 @@ pub fn HouseholdSettingsPage() -> impl IntoView {
                             <small class="form-hint">{i18n_stored.get_value().t("settings.chat_hint")}</small>
                         </div>
 
+                        <div class="form-group">
+                            <label class="form-label">{i18n_stored.get_value().t("settings.modules")}</label>
+                            <div class="settings-modules">
+                                // Rewards, punishments and chat have their own switches above
+                                {HouseholdFeature::ALL.into_iter().filter(|feature| !HouseholdFeature::OFF_BY_DEFAULT.contains(feature)).map(|feature| {
+                                    let id = format!("module-{}", feature.as_str());
+                                    view! {
+                                        <div style="display: flex; align-items: center; gap: 0.5rem;">
+                                            <input
+                                                type="checkbox"
+                                                id=id.clone()
+                                                prop:checked=move || feature_enabled(feature)
+                                                on:change=move |ev| set_feature_enabled(feature, event_target_checked(&ev))
+                                            />
+                                            <label for=id>{i18n_stored.get_value().t(&format!("tabs.{}", feature.as_str()))}</label>
+                                        </div>
+                                    }
+                                }).collect_view()}
+                            </div>
+                            <small class="form-hint">{i18n_stored.get_value().t("settings.modules_hint")}</small>
+                        </div>
+
                         <div class="form-group">
                             <div style="display: flex; align-items: center; gap: 0.5rem;">
                                 <input
@@ -459,6 +712,37 @@ pub fn HouseholdSettingsPage() -> impl IntoView {
                             <small class="form-hint">{i18n_stored.get_value().t("settings.task_suggestions_hint")}</small>
                         </div>
 
+                        <div class="form-group">
+                            <div style="display: flex; align-items: center; gap: 0.5rem;">
+                                <input
+                                    type="checkbox"
+                                    id="weekly-summary-enabled"
+                                    prop:checked=move || weekly_summary_enabled.get()
+                                    on:change=move |ev| {
+                                        weekly_summary_enabled.set(event_target_checked(&ev));
+                                    }
+                                />
+                                <label for="weekly-summary-enabled">{i18n_stored.get_value().t("settings.weekly_summary")}</label>
+                            </div>
+                            <small class="form-hint">{i18n_stored.get_value().t("settings.weekly_summary_hint")}</small>
+                        </div>
+
+                        <Show when=move || weekly_summary_enabled.get() fallback=|| ()>
+                            <div class="form-group">
+                                <div style="display: flex; align-items: center; gap: 0.5rem;">
+                                    <input
+                                        type="checkbox"
+                                        id="weekly-summary-email"
+                                        prop:checked=move || weekly_summary_email.get()
+                                        on:change=move |ev| {
+                                            weekly_summary_email.set(event_target_checked(&ev));
+                                        }
+                                    />
+                                    <label for="weekly-summary-email">{i18n_stored.get_value().t("settings.weekly_summary_email")}</label>
+                                </div>
+                            </div>
+                        </Show>
+
                         <Divider />
 
                         <SectionHeader>{i18n_stored.get_value().t("settings.vacation_mode")}</SectionHeader>
@@ -595,13 +879,13 @@ pub fn HouseholdSettingsPage() -> impl IntoView {
                                             error.set(None);
 
                                             wasm_bindgen_futures::spawn_local(async move {
-                                                match ApiClient::activate_solo_mode(&id).await {
+                                                match api_client().activate_solo_mode(&id).await {
                                                     Ok(new_settings) => {
                                                         settings.set(Some(new_settings));
                                                         solo_mode_confirm_open.set(false);
                                                     }
                                                     Err(e) => {
-                                                        error.set(Some(e));
+                                                        error.set(Some(e.to_string()));
                                                     }
                                                 }
                                                 solo_mode_activating.set(false);
@@ -620,6 +904,28 @@ pub fn HouseholdSettingsPage() -> impl IntoView {
 
                         <Divider />
 
+                        <SectionHeader>{i18n_stored.get_value().t("audit_log.section_title")}</SectionHeader>
+
+                        <div class="form-group">
+                            <p style="color: var(--text-muted); margin-bottom: 1rem; font-size: 0.875rem;">
+                                {i18n_stored.get_value().t("audit_log.description")}
+                            </p>
+                            <Button
+                                variant=ButtonVariant::Secondary
+                                on_click=Callback::new(move |_| audit_log_open.set(true))
+                            >
+                                {i18n_stored.get_value().t("audit_log.view")}
+                            </Button>
+                        </div>
+
+                        <Show when=move || audit_log_open.get() fallback=|| ()>
+                            <AuditLogModal
+                                household_id=household_id()
+                                on_close=move |_| audit_log_open.set(false)
+                            />
+                        </Show>
+                        <Divider />
+
                         <SectionHeader>{i18n_stored.get_value().t("settings.task_cleanup")}</SectionHeader>
 
                         <div class="form-group">
@@ -669,6 +975,114 @@ pub fn HouseholdSettingsPage() -> impl IntoView {
 
                         <Divider />
 
+                        <SectionHeader>{i18n_stored.get_value().t("settings.point_decay")}</SectionHeader>
+
+                        <div class="form-group">
+                            <label class="form-label" for="point-decay-mode">{i18n_stored.get_value().t("settings.point_decay_mode")}</label>
+                            <select
+                                id="point-decay-mode"
+                                class="form-select"
+                                on:change=move |ev| {
+                                    if let Ok(mode) = event_target_value(&ev).parse::<PointDecayMode>() {
+                                        point_decay_mode.set(mode);
+                                    }
+                                }
+                            >
+                                <option value="none" selected=move || point_decay_mode.get() == PointDecayMode::None>{i18n_stored.get_value().t("settings.point_decay_none")}</option>
+                                <option value="percent" selected=move || point_decay_mode.get() == PointDecayMode::Percent>{i18n_stored.get_value().t("settings.point_decay_percent")}</option>
+                                <option value="fixed" selected=move || point_decay_mode.get() == PointDecayMode::Fixed>{i18n_stored.get_value().t("settings.point_decay_fixed")}</option>
+                            </select>
+                            <small class="form-hint">{i18n_stored.get_value().t("settings.point_decay_hint")}</small>
+                        </div>
+
+                        <Show when=move || point_decay_mode.get() != PointDecayMode::None fallback=|| ()>
+                            <div style="margin-left: 1.5rem; padding-left: 1rem; border-left: 2px solid var(--border-color);">
+                                <div class="form-group">
+                                    <label class="form-label" for="point-decay-amount">
+                                        {move || if point_decay_mode.get() == PointDecayMode::Percent {
+                                            i18n_stored.get_value().t("settings.point_decay_amount_percent")
+                                        } else {
+                                            i18n_stored.get_value().t("settings.point_decay_amount_points")
+                                        }}
+                                    </label>
+                                    <input
+                                        type="number"
+                                        id="point-decay-amount"
+                                        class="form-input"
+                                        min="1"
+                                        max=move || if point_decay_mode.get() == PointDecayMode::Percent { "100" } else { "" }
+                                        prop:value=move || point_decay_amount.get().to_string()
+                                        on:input=move |ev| {
+                                            if let Ok(amount) = event_target_value(&ev).parse::<i32>() {
+                                                if amount >= 1 {
+                                                    point_decay_amount.set(amount);
+                                                }
+                                            }
+                                        }
+                                    />
+                                </div>
+                            </div>
+                        </Show>
+
+                        <SectionHeader>{i18n_stored.get_value().t("settings.streak_freezes")}</SectionHeader>
+
+                        <div class="form-group">
+                            <label class="form-label" for="streak-freeze-price">{i18n_stored.get_value().t("settings.streak_freeze_price")}</label>
+                            <input
+                                type="number"
+                                id="streak-freeze-price"
+                                class="form-input"
+                                min="0"
+                                prop:value=move || streak_freeze_price.get().to_string()
+                                on:input=move |ev| {
+                                    if let Ok(price) = event_target_value(&ev).parse::<i64>() {
+                                        if price >= 0 {
+                                            streak_freeze_price.set(price);
+                                        }
+                                    }
+                                }
+                            />
+                            <small class="form-hint">{i18n_stored.get_value().t("settings.streak_freeze_price_hint")}</small>
+                        </div>
+
+                        <SectionHeader>{i18n_stored.get_value().t("settings.allowance")}</SectionHeader>
+
+                        <div class="form-group">
+                            <label class="form-label" for="allowance-rate">{i18n_stored.get_value().t("settings.allowance_rate")}</label>
+                            <input
+                                type="text"
+                                id="allowance-rate"
+                                class="form-input"
+                                inputmode="decimal"
+                                placeholder="0.10"
+                                prop:value=move || allowance_rate.get()
+                                on:input=move |ev| allowance_rate.set(event_target_value(&ev))
+                            />
+                            <small class="form-hint">{i18n_stored.get_value().t("settings.allowance_rate_hint")}</small>
+                        </div>
+
+                        <Show when=move || parse_amount_to_cents(&allowance_rate.get()).is_some() fallback=|| ()>
+                            <div class="form-group">
+                                <label class="form-label" for="allowance-point-handling">{i18n_stored.get_value().t("settings.allowance_points")}</label>
+                                <select
+                                    id="allowance-point-handling"
+                                    class="form-select"
+                                    on:change=move |ev| {
+                                        if let Ok(handling) = event_target_value(&ev).parse::<AllowancePointHandling>() {
+                                            allowance_point_handling.set(handling);
+                                        }
+                                    }
+                                >
+                                    <option value="reset" selected=move || allowance_point_handling.get() == AllowancePointHandling::Reset>
+                                        {i18n_stored.get_value().t("settings.allowance_reset")}
+                                    </option>
+                                    <option value="carry_over" selected=move || allowance_point_handling.get() == AllowancePointHandling::CarryOver>
+                                        {i18n_stored.get_value().t("settings.allowance_carry_over")}
+                                    </option>
+                                </select>
+                            </div>
+                        </Show>
+
                         <SectionHeader>{i18n_stored.get_value().t("settings.custom_role_labels")}</SectionHeader>
                         <p style="color: var(--text-muted); margin-bottom: 1rem; font-size: 0.875rem;">
                             {i18n_stored.get_value().t("settings.role_labels_hint")}
@@ -759,8 +1173,96 @@ pub fn HouseholdSettingsPage() -> impl IntoView {
                             />
                         </div>
 
+                        <div class="form-group">
+                            <label class="form-label" for="good-habit-points-multiplier">{i18n_stored.get_value().t("settings.good_habit_points_multiplier")}</label>
+                            <input
+                                type="number"
+                                id="good-habit-points-multiplier"
+                                class="form-input"
+                                min="0"
+                                step="0.1"
+                                prop:value=move || good_habit_points_multiplier.get().to_string()
+                                on:input=move |ev| {
+                                    if let Ok(multiplier) = event_target_value(&ev).parse::<f64>() {
+                                        if multiplier >= 0.0 {
+                                            good_habit_points_multiplier.set(multiplier);
+                                        }
+                                    }
+                                }
+                            />
+                        </div>
+
+                        <div class="form-group">
+                            <label class="form-label" for="bad-habit-points-multiplier">{i18n_stored.get_value().t("settings.bad_habit_points_multiplier")}</label>
+                            <input
+                                type="number"
+                                id="bad-habit-points-multiplier"
+                                class="form-input"
+                                min="0"
+                                step="0.1"
+                                prop:value=move || bad_habit_points_multiplier.get().to_string()
+                                on:input=move |ev| {
+                                    if let Ok(multiplier) = event_target_value(&ev).parse::<f64>() {
+                                        if multiplier >= 0.0 {
+                                            bad_habit_points_multiplier.set(multiplier);
+                                        }
+                                    }
+                                }
+                            />
+                        </div>
+
+                        <div class="form-group">
+                            <div style="display: flex; align-items: center; gap: 0.5rem;">
+                                <input
+                                    type="checkbox"
+                                    id="default-allow-exceed-target"
+                                    prop:checked=move || default_allow_exceed_target.get()
+                                    on:change=move |ev| {
+                                        default_allow_exceed_target.set(event_target_checked(&ev));
+                                    }
+                                />
+                                <label for="default-allow-exceed-target">{i18n_stored.get_value().t("settings.default_allow_exceed_target")}</label>
+                            </div>
+                        </div>
+
+                        <div class="form-group">
+                            <div style="display: flex; align-items: center; gap: 0.5rem;">
+                                <input
+                                    type="checkbox"
+                                    id="default-requires-review"
+                                    prop:checked=move || default_requires_review.get()
+                                    on:change=move |ev| {
+                                        default_requires_review.set(event_target_checked(&ev));
+                                    }
+                                />
+                                <label for="default-requires-review">{i18n_stored.get_value().t("settings.default_requires_review")}</label>
+                            </div>
+                        </div>
+
+                        <div class="form-group">
+                            <label class="form-label" for="review-points-threshold">{i18n_stored.get_value().t("settings.review_points_threshold")}</label>
+                            <input
+                                type="number"
+                                id="review-points-threshold"
+                                class="form-input"
+                                min="0"
+                                prop:value=move || review_points_threshold.get().map(|p| p.to_string()).unwrap_or_default()
+                                on:input=move |ev| {
+                                    let value = event_target_value(&ev);
+                                    if value.is_empty() {
+                                        review_points_threshold.set(None);
+                                    } else if let Ok(points) = value.parse::<i64>() {
+                                        if points >= 0 {
+                                            review_points_threshold.set(Some(points));
+                                        }
+                                    }
+                                }
+                            />
+                            <small class="form-hint">{i18n_stored.get_value().t("settings.review_points_threshold_hint")}</small>
+                        </div>
+
                         // Show default rewards list only if rewards are enabled
-                        <Show when=move || rewards_enabled.get() fallback=|| ()>
+                        <Show when=move || feature_enabled(HouseholdFeature::Rewards) fallback=|| ()>
                             <div class="form-group">
                                 <label class="form-label">{i18n_stored.get_value().t("settings.default_rewards")}</label>
                                 <div style="border: 1px solid var(--card-border); border-radius: var(--border-radius); padding: 0.75rem;">
@@ -869,7 +1371,7 @@ pub fn HouseholdSettingsPage() -> impl IntoView {
                         </Show>
 
                         // Show default punishments list only if punishments are enabled
-                        <Show when=move || punishments_enabled.get() fallback=|| ()>
+                        <Show when=move || feature_enabled(HouseholdFeature::Punishments) fallback=|| ()>
                             <div class="form-group">
                                 <label class="form-label">{i18n_stored.get_value().t("settings.default_punishments")}</label>
                                 <div style="border: 1px solid var(--card-border); border-radius: var(--border-radius); padding: 0.75rem;">
@@ -989,22 +1491,76 @@ pub fn HouseholdSettingsPage() -> impl IntoView {
                     </form>
                 </Show>
             </Card>
-        </Show>
-    }
-}
 
-/// Apply dark mode class to document body
-fn apply_dark_mode(enabled: bool) {
-    if let Some(window) = web_sys::window() {
-        if let Some(document) = window.document() {
-            if let Some(body) = document.body() {
-                if enabled {
-                    let _ = body.class_list().add_1("dark-mode");
-                } else {
-                    let _ = body.class_list().remove_1("dark-mode");
-                }
-            }
-        }
+            <Show when=move || is_owner.get() fallback=|| ()>
+                <WebhookSettings household_id=household_id() timezone=timezone.get() />
+            </Show>
+
+            // Leaving - owners have to hand over the household first
+            <Show when=move || current_role.get().is_some() fallback=|| ()>
+                <Card>
+                    <SectionHeader>{i18n_stored.get_value().t("household.settings.leave")}</SectionHeader>
+                    <div class="form-group">
+                        <p style="color: var(--text-muted); margin-bottom: 1rem; font-size: 0.875rem;">
+                            {move || if is_owner.get() {
+                                i18n_stored.get_value().t("household.settings.leave_owner_hint")
+                            } else {
+                                i18n_stored.get_value().t("household.settings.leave_hint")
+                            }}
+                        </p>
+                        <Button
+                            variant=ButtonVariant::Danger
+                            disabled=MaybeSignal::derive(move || is_owner.get())
+                            on_click=Callback::new(move |_| leave_confirm_open.set(true))
+                        >
+                            {i18n_stored.get_value().t("household.settings.leave")}
+                        </Button>
+                    </div>
+                </Card>
+            </Show>
+
+            <Show when=move || leave_confirm_open.get() fallback=|| ()>
+                <Modal
+                    on_close=move |_| leave_confirm_open.set(false)
+                    title=i18n_stored.get_value().t("household.settings.leave")
+                >
+                    <p>{i18n_stored.get_value().t("household.settings.leave_confirm")}</p>
+                    <div style="display: flex; gap: 0.5rem; margin-top: 1rem; justify-content: flex-end;">
+                        <Button
+                            variant=ButtonVariant::Secondary
+                            on_click=Callback::new(move |_| leave_confirm_open.set(false))
+                        >
+                            {i18n_stored.get_value().t("common.cancel")}
+                        </Button>
+                        <Button
+                            variant=ButtonVariant::Danger
+                            disabled=MaybeSignal::derive(move || leaving.get())
+                            on_click=Callback::new(move |_| {
+                                let id = household_id();
+                                leaving.set(true);
+                                error.set(None);
+                                wasm_bindgen_futures::spawn_local(async move {
+                                    match api_client().leave_household(&id).await {
+                                        Ok(()) => navigate.get_value()("/", Default::default()),
+                                        Err(e) => {
+                                            error.set(Some(e.to_string()));
+                                            leave_confirm_open.set(false);
+                                        }
+                                    }
+                                    leaving.set(false);
+                                });
+                            })
+                        >
+                            {move || if leaving.get() {
+                                i18n_stored.get_value().t("common.processing")
+                            } else {
+                                i18n_stored.get_value().t("household.settings.leave")
+                            }}
+                        </Button>
+                    </div>
+                </Modal>
+            </Show>
+        </Show>
     }
 }
 
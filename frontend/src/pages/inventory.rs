@@ -0,0 +1,272 @@
+use leptos::*;
+use leptos_router::*;
+use shared::{CreateInventoryItemRequest, InventoryItem, UpdateInventoryItemRequest};
+use uuid::Uuid;
+
+use crate::api::api_client;
+use crate::components::loading::Loading;
+use crate::i18n::use_i18n;
+use crate::pages::shopping_list::format_amount;
+
+/// Parse an optional amount from a form field; blank means none
+fn parse_amount(value: &str) -> Result<Option<f64>, ()> {
+    let value = value.trim().replace(',', ".");
+    if value.is_empty() {
+        return Ok(None);
+    }
+    match value.parse::<f64>() {
+        Ok(q) if q.is_finite() && q >= 0.0 => Ok(Some(q)),
+        _ => Err(()),
+    }
+}
+
+#[component]
+pub fn InventoryPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let params = use_params_map();
+    let household_id = move || params.with(|p| p.get("id").cloned().unwrap_or_default());
+
+    let items = create_rw_signal(Vec::<InventoryItem>::new());
+    let loading = create_rw_signal(true);
+    let error = create_rw_signal(Option::<String>::None);
+    let success = create_rw_signal(Option::<String>::None);
+
+    let name = create_rw_signal(String::new());
+    let quantity = create_rw_signal(String::new());
+    let unit = create_rw_signal(String::new());
+    let location = create_rw_signal(String::new());
+    let min_quantity = create_rw_signal(String::new());
+
+    create_effect(move |_| {
+        let id = household_id();
+        if id.is_empty() {
+            return;
+        }
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().list_inventory(&id).await {
+                Ok(list) => items.set(list),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            loading.set(false);
+        });
+    });
+
+    let replace_item = move |updated: InventoryItem| {
+        items.update(|list| {
+            if let Some(item) = list.iter_mut().find(|i| i.id == updated.id) {
+                *item = updated;
+            }
+        })
+    };
+
+    let on_add = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        let i18n = i18n_stored.get_value();
+        if name.get().trim().is_empty() {
+            return;
+        }
+        let (Ok(amount), Ok(min)) = (parse_amount(&quantity.get()), parse_amount(&min_quantity.get())) else {
+            error.set(Some(i18n.t("inventory.invalid_amount")));
+            return;
+        };
+        let request = CreateInventoryItemRequest {
+            name: name.get(),
+            quantity: amount.unwrap_or(0.0),
+            unit: Some(unit.get()),
+            location: Some(location.get()),
+            min_quantity: min,
+        };
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().create_inventory_item(&id, request).await {
+                Ok(item) => {
+                    items.update(|list| list.push(item));
+                    name.set(String::new());
+                    quantity.set(String::new());
+                    min_quantity.set(String::new());
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_adjust = move |item_id: Uuid, delta: f64| {
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().adjust_inventory_item(&id, &item_id.to_string(), delta).await {
+                Ok(adjustment) => {
+                    if adjustment.added_to_shopping_list {
+                        success.set(Some(
+                            i18n_stored
+                                .get_value()
+                                .t_with("inventory.added_to_shopping_list", &[("name", &adjustment.item.name)]),
+                        ));
+                    }
+                    replace_item(adjustment.item);
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_set_min = move |item_id: Uuid, value: String| {
+        let Ok(min) = parse_amount(&value) else {
+            error.set(Some(i18n_stored.get_value().t("inventory.invalid_amount")));
+            return;
+        };
+        let id = household_id();
+        let request = UpdateInventoryItemRequest {
+            min_quantity: Some(min),
+            ..Default::default()
+        };
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().update_inventory_item(&id, &item_id.to_string(), request).await {
+                Ok(updated) => replace_item(updated),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_delete = move |item_id: Uuid| {
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().delete_inventory_item(&id, &item_id.to_string()).await {
+                Ok(()) => items.update(|list| list.retain(|i| i.id != item_id)),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    view! {
+        <div class="dashboard-header">
+            <h1 class="dashboard-title">{i18n_stored.get_value().t("inventory.title")}</h1>
+        </div>
+
+        {move || error.get().map(|e| view! {
+            <div class="alert alert-error">{e}</div>
+        })}
+        {move || success.get().map(|s| view! {
+            <div class="alert alert-success">{s}</div>
+        })}
+
+        <div class="card inventory">
+            <form class="inventory-form" on:submit=on_add>
+                <input
+                    type="text"
+                    class="form-input"
+                    placeholder=i18n_stored.get_value().t("inventory.name")
+                    prop:value=move || name.get()
+                    on:input=move |ev| name.set(event_target_value(&ev))
+                />
+                <input
+                    type="text"
+                    inputmode="decimal"
+                    class="form-input inventory-amount-input"
+                    placeholder=i18n_stored.get_value().t("inventory.quantity")
+                    prop:value=move || quantity.get()
+                    on:input=move |ev| quantity.set(event_target_value(&ev))
+                />
+                <input
+                    type="text"
+                    class="form-input inventory-amount-input"
+                    placeholder=i18n_stored.get_value().t("inventory.unit")
+                    prop:value=move || unit.get()
+                    on:input=move |ev| unit.set(event_target_value(&ev))
+                />
+                <input
+                    type="text"
+                    class="form-input"
+                    placeholder=i18n_stored.get_value().t("inventory.location")
+                    prop:value=move || location.get()
+                    on:input=move |ev| location.set(event_target_value(&ev))
+                />
+                <input
+                    type="text"
+                    inputmode="decimal"
+                    class="form-input inventory-amount-input"
+                    placeholder=i18n_stored.get_value().t("inventory.min_quantity")
+                    prop:value=move || min_quantity.get()
+                    on:input=move |ev| min_quantity.set(event_target_value(&ev))
+                />
+                <button type="submit" class="btn btn-primary">
+                    {i18n_stored.get_value().t("inventory.add")}
+                </button>
+            </form>
+
+            <Show when=move || loading.get() fallback=|| ()>
+                <Loading />
+            </Show>
+
+            {move || {
+                let list = items.get();
+                if list.is_empty() && !loading.get() {
+                    return view! {
+                        <p class="empty-state">{i18n_stored.get_value().t("inventory.empty")}</p>
+                    }.into_view();
+                }
+                view! {
+                    <ul class="inventory-items">
+                        {list.into_iter().map(|item| {
+                            let item_id = item.id;
+                            let low = item.is_low();
+                            let amount = format_amount(Some(item.quantity), item.unit.as_deref()).unwrap_or_default();
+                            let min = item.min_quantity.map(|m| m.to_string()).unwrap_or_default();
+                            view! {
+                                <li class="inventory-item" class:inventory-low=low>
+                                    <div class="inventory-info">
+                                        <span class="inventory-name">{item.name}</span>
+                                        {item.location.map(|l| view! {
+                                            <span class="inventory-location">{l}</span>
+                                        })}
+                                        <Show when=move || low fallback=|| ()>
+                                            <span class="badge badge-warning">
+                                                {i18n_stored.get_value().t("inventory.low")}
+                                            </span>
+                                        </Show>
+                                    </div>
+                                    <div class="inventory-controls">
+                                        <button class="btn btn-outline btn-sm" on:click=move |_| on_adjust(item_id, -1.0)>"−"</button>
+                                        <span class="inventory-quantity">{amount}</span>
+                                        <button class="btn btn-outline btn-sm" on:click=move |_| on_adjust(item_id, 1.0)>"+"</button>
+                                        <input
+                                            type="text"
+                                            inputmode="decimal"
+                                            class="form-input inventory-amount-input"
+                                            title=i18n_stored.get_value().t("inventory.min_quantity")
+                                            placeholder=i18n_stored.get_value().t("inventory.min_quantity")
+                                            prop:value=min
+                                            on:change=move |ev| on_set_min(item_id, event_target_value(&ev))
+                                        />
+                                        <button
+                                            class="shopping-list-remove"
+                                            title=i18n_stored.get_value().t("common.delete")
+                                            on:click=move |_| on_delete(item_id)
+                                        >"×"</button>
+                                    </div>
+                                </li>
+                            }
+                        }).collect_view()}
+                    </ul>
+                }.into_view()
+            }}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_parse_amount() {
+        assert_eq!(parse_amount(""), Ok(None));
+        assert_eq!(parse_amount(" 2,5 "), Ok(Some(2.5)));
+        assert_eq!(parse_amount("12"), Ok(Some(12.0)));
+        assert_eq!(parse_amount("-1"), Err(()));
+        assert_eq!(parse_amount("a lot"), Err(()));
+    }
+}
@@ -0,0 +1,40 @@
+use leptos::*;
+use leptos_router::*;
+
+use crate::api::api_client;
+use crate::components::loading::Loading;
+use crate::i18n::use_i18n;
+
+/// Target of shareable invite links (`/join/{code}`): redeems the code and opens the household
+#[component]
+pub fn JoinHouseholdPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let params = use_params_map();
+    let navigate = use_navigate();
+    let error = create_rw_signal(Option::<String>::None);
+
+    let code = params.with_untracked(|p| p.get("code").cloned().unwrap_or_default());
+    wasm_bindgen_futures::spawn_local(async move {
+        match api_client().redeem_invite_code(&code).await {
+            Ok(membership) => navigate(&format!("/households/{}", membership.household_id), Default::default()),
+            Err(e) => error.set(Some(e.to_string())),
+        }
+    });
+
+    view! {
+        <div class="dashboard-header">
+            <h1 class="dashboard-title">{i18n_stored.get_value().t("invite_codes.join_title")}</h1>
+        </div>
+        {move || match error.get() {
+            Some(e) => view! {
+                <div class="card">
+                    <div class="alert alert-error">{e}</div>
+                    <A href="/" class="btn btn-outline">{i18n_stored.get_value().t("invite_codes.back_to_dashboard")}</A>
+                </div>
+            }.into_view(),
+            None => view! { <Loading /> }.into_view(),
+        }}
+    }
+}
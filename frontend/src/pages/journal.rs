@@ -1,16 +1,18 @@
 use leptos::*;
 use leptos_router::*;
-use shared::{HouseholdSettings, JournalEntry, JournalEntryWithUser, User};
+use shared::{HouseholdSettings, JournalEntry, JournalEntryWithUser, JournalVisibility, User};
 use uuid::Uuid;
 
-use crate::api::ApiClient;
+use crate::api::api_client;
 use crate::components::journal_entry_card::JournalEntryCard;
 use crate::components::journal_modal::JournalModal;
 use crate::components::loading::Loading;
+use crate::components::theme::use_theme;
 use crate::i18n::use_i18n;
 
 #[component]
 pub fn JournalPage() -> impl IntoView {
+    let theme = use_theme();
     let i18n = use_i18n();
     let i18n_stored = store_value(i18n);
 
@@ -44,13 +46,13 @@ pub fn JournalPage() -> impl IntoView {
 
         // Load journal entries
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::list_journal_entries(&id_for_entries).await {
+            match api_client().list_journal_entries(&id_for_entries).await {
                 Ok(e) => {
                     entries.set(e);
                     loading.set(false);
                 }
                 Err(e) => {
-                    error.set(Some(e));
+                    error.set(Some(e.to_string()));
                     loading.set(false);
                 }
             }
@@ -59,15 +61,15 @@ pub fn JournalPage() -> impl IntoView {
         // Load current user
         wasm_bindgen_futures::spawn_local(async move {
             let _ = id_for_user;
-            if let Ok(user) = ApiClient::get_current_user().await {
+            if let Ok(user) = api_client().get_current_user().await {
                 current_user.set(Some(user));
             }
         });
 
         // Load settings for dark mode
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(s) = ApiClient::get_household_settings(&id_for_settings).await {
-                apply_dark_mode(s.dark_mode);
+            if let Ok(s) = api_client().get_household_settings(&id_for_settings).await {
+                theme.set_household_dark_mode(s.dark_mode);
                 settings.set(Some(s));
             }
         });
@@ -79,7 +81,7 @@ pub fn JournalPage() -> impl IntoView {
             return;
         }
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(e) = ApiClient::list_journal_entries(&id).await {
+            if let Ok(e) = api_client().list_journal_entries(&id).await {
                 entries.set(e);
             }
         });
@@ -93,12 +95,12 @@ pub fn JournalPage() -> impl IntoView {
         let id = household_id();
         let entry_id_str = entry_id.to_string();
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::delete_journal_entry(&id, &entry_id_str).await {
+            match api_client().delete_journal_entry(&id, &entry_id_str).await {
                 Ok(()) => {
                     entries.update(|e| e.retain(|entry| entry.entry.id != entry_id));
                     success.set(Some(i18n_stored.get_value().t("journal.deleted")));
                 }
-                Err(e) => error.set(Some(e)),
+                Err(e) => error.set(Some(e.to_string())),
             }
         });
     };
@@ -124,7 +126,7 @@ pub fn JournalPage() -> impl IntoView {
             .get()
             .into_iter()
             .filter(|entry| {
-                if entry.entry.is_shared {
+                if entry.entry.visibility == JournalVisibility::Household {
                     show_shared.get()
                 } else {
                     show_private.get()
@@ -223,17 +225,3 @@ pub fn JournalPage() -> impl IntoView {
     }
 }
 
-/// Apply dark mode class to document body
-fn apply_dark_mode(enabled: bool) {
-    if let Some(window) = web_sys::window() {
-        if let Some(document) = window.document() {
-            if let Some(body) = document.body() {
-                if enabled {
-                    let _ = body.class_list().add_1("dark-mode");
-                } else {
-                    let _ = body.class_list().remove_1("dark-mode");
-                }
-            }
-        }
-    }
-}
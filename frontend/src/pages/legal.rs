@@ -2,7 +2,7 @@
 
 use leptos::*;
 
-use crate::api::ApiClient;
+use crate::api::api_client;
 use crate::components::loading::Loading;
 use crate::components::markdown::MarkdownView;
 
@@ -51,9 +51,9 @@ pub fn ImpressumPage() -> impl IntoView {
 
     create_effect(move |_| {
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::get_impressum().await {
+            match api_client().get_impressum().await {
                 Ok(md) => content.set(Some(md)),
-                Err(e) => error.set(Some(e)),
+                Err(e) => error.set(Some(e.to_string())),
             }
             loading.set(false);
         });
@@ -77,9 +77,9 @@ pub fn DatenschutzPage() -> impl IntoView {
 
     create_effect(move |_| {
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::get_datenschutz().await {
+            match api_client().get_datenschutz().await {
                 Ok(md) => content.set(Some(md)),
-                Err(e) => error.set(Some(e)),
+                Err(e) => error.set(Some(e.to_string())),
             }
             loading.set(false);
         });
@@ -103,9 +103,9 @@ pub fn AGBPage() -> impl IntoView {
 
     create_effect(move |_| {
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::get_agb().await {
+            match api_client().get_agb().await {
                 Ok(md) => content.set(Some(md)),
-                Err(e) => error.set(Some(e)),
+                Err(e) => error.set(Some(e.to_string())),
             }
             loading.set(false);
         });
@@ -2,8 +2,9 @@ use leptos::*;
 use leptos_router::*;
 use shared::LoginRequest;
 
-use crate::api::{ApiClient, AuthState};
+use crate::api::{api_client, AuthState};
 use crate::i18n::use_i18n;
+use crate::utils::take_login_redirect;
 
 #[component]
 pub fn Login() -> impl IntoView {
@@ -16,6 +17,58 @@ pub fn Login() -> impl IntoView {
     let password = create_rw_signal(String::new());
     let error = create_rw_signal(Option::<String>::None);
     let loading = create_rw_signal(false);
+    let oidc_provider = create_rw_signal(Option::<String>::None);
+    let oidc_redirecting = create_rw_signal(false);
+
+    // Only offer single sign-on when the server has a provider configured
+    wasm_bindgen_futures::spawn_local(async move {
+        if let Ok(info) = api_client().get_oidc_config().await {
+            if info.enabled {
+                oidc_provider.set(Some(info.provider_name));
+            }
+        }
+    });
+
+    // Returning from the identity provider: exchange the one-time code or show why it failed
+    let query = use_query_map().get_untracked();
+    if let Some(reason) = query.get("oidc_error") {
+        error.set(Some(i18n_stored.get_value().t(oidc_error_key(reason))));
+    }
+    if let Some(code) = query.get("oidc_code").cloned() {
+        let nav = navigate.clone();
+        let auth = auth_state.clone();
+        loading.set(true);
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().exchange_oidc_code(code).await {
+                Ok(response) => {
+                    auth.set_auth(response);
+                    nav(&take_login_redirect(), Default::default());
+                }
+                Err(e) => {
+                    error.set(Some(e.to_string()));
+                    loading.set(false);
+                }
+            }
+        });
+    }
+
+    let on_oidc_login = move |_| {
+        oidc_redirecting.set(true);
+        error.set(None);
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().start_oidc_login().await {
+                Ok(response) => {
+                    if let Some(window) = web_sys::window() {
+                        let _ = window.location().set_href(&response.url);
+                    }
+                }
+                Err(e) => {
+                    error.set(Some(e.to_string()));
+                    oidc_redirecting.set(false);
+                }
+            }
+        });
+    };
 
     let on_submit = move |ev: web_sys::SubmitEvent| {
         ev.prevent_default();
@@ -32,13 +85,13 @@ pub fn Login() -> impl IntoView {
         };
 
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::login(request).await {
+            match api_client().login(request).await {
                 Ok(response) => {
                     auth.set_auth(response);
-                    nav("/", Default::default());
+                    nav(&take_login_redirect(), Default::default());
                 }
                 Err(e) => {
-                    error.set(Some(e));
+                    error.set(Some(e.to_string()));
                     loading.set(false);
                 }
             }
@@ -98,6 +151,27 @@ pub fn Login() -> impl IntoView {
                     </button>
                 </form>
 
+                {move || oidc_provider.get().map(|provider| view! {
+                    <div class="auth-divider">{i18n_stored.get_value().t("auth.or")}</div>
+                    <button
+                        type="button"
+                        class="btn btn-outline"
+                        style="width: 100%;"
+                        disabled=move || oidc_redirecting.get() || loading.get()
+                        on:click=on_oidc_login
+                    >
+                        {move || if oidc_redirecting.get() {
+                            i18n_stored.get_value().t("auth.oidc_redirecting")
+                        } else {
+                            i18n_stored.get_value().t_with("auth.oidc_sign_in", &[("provider", &provider)])
+                        }}
+                    </button>
+                })}
+
+                <p style="text-align: center; margin-top: 1rem;">
+                    <a href="/forgot-password" style="color: var(--primary-color);">{move || i18n_stored.get_value().t("auth.forgot_password")}</a>
+                </p>
+
                 <p style="text-align: center; margin-top: 1rem; color: var(--text-muted);">
                     {move || i18n_stored.get_value().t("auth.no_account")}
                     " "
@@ -114,8 +188,20 @@ pub fn Login() -> impl IntoView {
     }
 }
 
+/// Translation key for an `oidc_error` reason passed back by the callback
+fn oidc_error_key(reason: &str) -> &'static str {
+    match reason {
+        "access_denied" => "auth.oidc_error_access_denied",
+        "invalid_state" => "auth.oidc_error_invalid_state",
+        "already_linked" => "auth.oidc_error_already_linked",
+        "email_in_use" => "auth.oidc_error_email_in_use",
+        _ => "auth.oidc_error_login_failed",
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
     use wasm_bindgen_test::*;
 
     wasm_bindgen_test_configure!(run_in_browser);
@@ -134,6 +220,13 @@ mod tests {
         assert_eq!(text, "Signing in...");
     }
 
+    #[wasm_bindgen_test]
+    fn test_oidc_error_key() {
+        assert_eq!(oidc_error_key("access_denied"), "auth.oidc_error_access_denied");
+        assert_eq!(oidc_error_key("email_in_use"), "auth.oidc_error_email_in_use");
+        assert_eq!(oidc_error_key("something_else"), "auth.oidc_error_login_failed");
+    }
+
     #[wasm_bindgen_test]
     fn test_css_classes() {
         assert_eq!("auth-container", "auth-container");
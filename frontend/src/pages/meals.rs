@@ -0,0 +1,538 @@
+use chrono::{Datelike, Duration, NaiveDate};
+use leptos::*;
+use leptos_router::*;
+use shared::{CreateRecipeRequest, MealPlanEntry, MealSlot, Recipe, RecipeIngredient, SetMealPlanEntryRequest, ShareResource};
+use uuid::Uuid;
+
+use crate::api::api_client;
+use crate::components::loading::Loading;
+use crate::components::share_links_modal::ShareLinksModal;
+use crate::i18n::use_i18n;
+
+/// Units recognised after a leading quantity, e.g. "200 g flour"
+const KNOWN_UNITS: &[&str] = &[
+    "g", "kg", "mg", "ml", "l", "cl", "dl", "tsp", "tbsp", "cup", "cups", "pcs", "pinch", "can",
+    "cans", "el", "tl", "stk", "prise", "dose", "pck", "bund",
+];
+
+/// Parse one ingredient line such as "200 g flour", "2 eggs" or "salt"
+pub(crate) fn parse_ingredient_line(line: &str) -> Option<RecipeIngredient> {
+    let line = line.trim();
+    if line.is_empty() {
+        return None;
+    }
+
+    let mut parts = line.splitn(2, char::is_whitespace);
+    let first = parts.next().unwrap_or_default();
+    let rest = parts.next().unwrap_or_default().trim();
+
+    let Some(quantity) = first.replace(',', ".").parse::<f64>().ok().filter(|q| *q > 0.0) else {
+        return Some(RecipeIngredient {
+            name: line.to_string(),
+            quantity: None,
+            unit: None,
+        });
+    };
+    if rest.is_empty() {
+        return Some(RecipeIngredient {
+            name: line.to_string(),
+            quantity: None,
+            unit: None,
+        });
+    }
+
+    let mut rest_parts = rest.splitn(2, char::is_whitespace);
+    let maybe_unit = rest_parts.next().unwrap_or_default();
+    let after_unit = rest_parts.next().unwrap_or_default().trim();
+    if !after_unit.is_empty() && KNOWN_UNITS.contains(&maybe_unit.to_lowercase().as_str()) {
+        Some(RecipeIngredient {
+            name: after_unit.to_string(),
+            quantity: Some(quantity),
+            unit: Some(maybe_unit.to_string()),
+        })
+    } else {
+        Some(RecipeIngredient {
+            name: rest.to_string(),
+            quantity: Some(quantity),
+            unit: None,
+        })
+    }
+}
+
+/// Render an ingredient back into the "200 g flour" form
+fn format_ingredient(ingredient: &RecipeIngredient) -> String {
+    let mut parts = Vec::new();
+    if let Some(q) = ingredient.quantity {
+        parts.push(format!("{}", q));
+    }
+    if let Some(ref unit) = ingredient.unit {
+        parts.push(unit.clone());
+    }
+    parts.push(ingredient.name.clone());
+    parts.join(" ")
+}
+
+pub fn slot_key(slot: MealSlot) -> String {
+    format!("meals.slot_{}", slot.as_str())
+}
+
+/// Label of a planned meal: the recipe name if it still exists, otherwise the free-text title
+fn entry_label(entry: &MealPlanEntry, recipes: &[Recipe]) -> String {
+    entry
+        .recipe_id
+        .and_then(|id| recipes.iter().find(|r| r.id == id))
+        .map(|r| r.name.clone())
+        .or_else(|| entry.title.clone())
+        .unwrap_or_default()
+}
+
+#[component]
+pub fn MealsPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let params = use_params_map();
+    let household_id = move || params.with(|p| p.get("id").cloned().unwrap_or_default());
+
+    let today = chrono::Utc::now().date_naive();
+    let monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    let week_start = create_rw_signal(monday);
+
+    let recipes = create_rw_signal(Vec::<Recipe>::new());
+    let entries = create_rw_signal(Vec::<MealPlanEntry>::new());
+    let loading = create_rw_signal(true);
+    let error = create_rw_signal(Option::<String>::None);
+    let show_share_modal = create_rw_signal(false);
+    let success = create_rw_signal(Option::<String>::None);
+
+    // Plan form state
+    let plan_date = create_rw_signal(today);
+    let plan_slot = create_rw_signal(MealSlot::Dinner);
+    let plan_recipe = create_rw_signal(String::new());
+    let plan_title = create_rw_signal(String::new());
+
+    // Recipe form state
+    let recipe_name = create_rw_signal(String::new());
+    let recipe_ingredients = create_rw_signal(String::new());
+    let recipe_notes = create_rw_signal(String::new());
+
+    let load_recipes = move || {
+        let id = household_id();
+        if id.is_empty() {
+            return;
+        }
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().list_recipes(&id).await {
+                Ok(r) => recipes.set(r),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let load_plan = move || {
+        let id = household_id();
+        if id.is_empty() {
+            return;
+        }
+        let week = week_start.get_untracked().format("%Y-%m-%d").to_string();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().get_meal_plan(&id, &week).await {
+                Ok(plan) => entries.set(plan.entries),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            loading.set(false);
+        });
+    };
+
+    create_effect(move |_| {
+        let _ = household_id();
+        load_recipes();
+    });
+
+    create_effect(move |_| {
+        let _ = household_id();
+        let _ = week_start.get();
+        load_plan();
+    });
+
+    let on_plan_submit = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        let recipe_id = Uuid::parse_str(&plan_recipe.get()).ok();
+        let title = plan_title.get();
+        if recipe_id.is_none() && title.trim().is_empty() {
+            error.set(Some(i18n_stored.get_value().t("meals.meal_required")));
+            return;
+        }
+        let request = SetMealPlanEntryRequest {
+            plan_date: plan_date.get(),
+            slot: plan_slot.get(),
+            recipe_id,
+            title: (!title.trim().is_empty()).then_some(title),
+            notes: None,
+        };
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().set_meal_plan_entry(&id, request).await {
+                Ok(_) => {
+                    plan_title.set(String::new());
+                    error.set(None);
+                    load_plan();
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_remove_entry = move |entry_id: Uuid| {
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().delete_meal_plan_entry(&id, &entry_id.to_string()).await {
+                Ok(()) => entries.update(|e| e.retain(|entry| entry.id != entry_id)),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_recipe_submit = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        let name = recipe_name.get();
+        if name.trim().is_empty() {
+            error.set(Some(i18n_stored.get_value().t("meals.recipe_name_required")));
+            return;
+        }
+        let notes = recipe_notes.get();
+        let request = CreateRecipeRequest {
+            name,
+            ingredients: recipe_ingredients
+                .get()
+                .lines()
+                .filter_map(parse_ingredient_line)
+                .collect(),
+            notes: (!notes.trim().is_empty()).then_some(notes),
+        };
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().create_recipe(&id, request).await {
+                Ok(recipe) => {
+                    recipe_name.set(String::new());
+                    recipe_ingredients.set(String::new());
+                    recipe_notes.set(String::new());
+                    error.set(None);
+                    success.set(Some(i18n_stored.get_value().t("meals.recipe_saved")));
+                    recipes.update(|r| {
+                        r.push(recipe);
+                        r.sort_by_key(|r| r.name.to_lowercase());
+                    });
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_delete_recipe = move |recipe_id: Uuid| {
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().delete_recipe(&id, &recipe_id.to_string()).await {
+                Ok(()) => {
+                    recipes.update(|r| r.retain(|recipe| recipe.id != recipe_id));
+                    success.set(Some(i18n_stored.get_value().t("meals.recipe_deleted")));
+                    load_plan();
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_add_to_shopping_list = move |recipe_id: Uuid| {
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().add_recipe_to_shopping_list(&id, &recipe_id.to_string()).await {
+                Ok(_) => {
+                    error.set(None);
+                    success.set(Some(i18n_stored.get_value().t("meals.added_to_shopping_list")));
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let week_days = move || {
+        let start = week_start.get();
+        (0..7).map(|i| start + Duration::days(i)).collect::<Vec<NaiveDate>>()
+    };
+
+    view! {
+        <div class="dashboard-header">
+            <h1 class="dashboard-title">{i18n_stored.get_value().t("meals.title")}</h1>
+            <button class="btn btn-outline btn-sm" on:click=move |_| show_share_modal.set(true)>
+                {i18n_stored.get_value().t("share_links.button")}
+            </button>
+        </div>
+
+        <Show when=move || show_share_modal.get() fallback=|| ()>
+            <ShareLinksModal
+                household_id=household_id()
+                resource=ShareResource::MealPlan
+                on_close=move |_| show_share_modal.set(false)
+            />
+        </Show>
+
+        {move || error.get().map(|e| view! {
+            <div class="alert alert-error">{e}</div>
+        })}
+
+        {move || success.get().map(|s| view! {
+            <div class="alert alert-success">{s}
+                <button
+                    class="alert-dismiss"
+                    on:click=move |_| success.set(None)
+                >"×"</button>
+            </div>
+        })}
+
+        <div class="meal-week-nav">
+            <button
+                class="btn btn-outline btn-sm"
+                on:click=move |_| week_start.update(|w| *w -= Duration::days(7))
+            >"‹"</button>
+            <span class="meal-week-label">
+                {move || {
+                    let start = week_start.get();
+                    let end = start + Duration::days(6);
+                    format!("{} – {}", start.format("%d.%m."), end.format("%d.%m.%Y"))
+                }}
+            </span>
+            <button
+                class="btn btn-outline btn-sm"
+                on:click=move |_| week_start.update(|w| *w += Duration::days(7))
+            >"›"</button>
+        </div>
+
+        <Show when=move || loading.get() fallback=|| ()>
+            <Loading />
+        </Show>
+
+        // Weekly plan grid: one row per day, one column per slot
+        <div class="card meal-plan">
+            <table class="meal-plan-grid">
+                <thead>
+                    <tr>
+                        <th></th>
+                        {MealSlot::ALL.into_iter().map(|slot| view! {
+                            <th>{i18n_stored.get_value().t(&slot_key(slot))}</th>
+                        }).collect_view()}
+                    </tr>
+                </thead>
+                <tbody>
+                    {move || {
+                        let all_entries = entries.get();
+                        let all_recipes = recipes.get();
+                        week_days().into_iter().map(|day| {
+                            let is_today = day == today;
+                            view! {
+                                <tr class:meal-plan-today=is_today>
+                                    <th>{day.format("%a %d.%m.").to_string()}</th>
+                                    {MealSlot::ALL.into_iter().map(|slot| {
+                                        let entry = all_entries
+                                            .iter()
+                                            .find(|e| e.plan_date == day && e.slot == slot)
+                                            .cloned();
+                                        match entry {
+                                            Some(entry) => {
+                                                let label = entry_label(&entry, &all_recipes);
+                                                let entry_id = entry.id;
+                                                view! {
+                                                    <td class="meal-plan-cell">
+                                                        <span>{label}</span>
+                                                        <button
+                                                            class="meal-plan-remove"
+                                                            title=i18n_stored.get_value().t("common.delete")
+                                                            on:click=move |_| on_remove_entry(entry_id)
+                                                        >"×"</button>
+                                                    </td>
+                                                }.into_view()
+                                            }
+                                            None => view! {
+                                                <td
+                                                    class="meal-plan-cell meal-plan-empty"
+                                                    on:click=move |_| {
+                                                        plan_date.set(day);
+                                                        plan_slot.set(slot);
+                                                    }
+                                                >"+"</td>
+                                            }.into_view(),
+                                        }
+                                    }).collect_view()}
+                                </tr>
+                            }
+                        }).collect_view()
+                    }}
+                </tbody>
+            </table>
+
+            <form class="meal-plan-form" on:submit=on_plan_submit>
+                <select
+                    class="form-input"
+                    on:change=move |ev| {
+                        if let Ok(d) = NaiveDate::parse_from_str(&event_target_value(&ev), "%Y-%m-%d") {
+                            plan_date.set(d);
+                        }
+                    }
+                >
+                    {move || week_days().into_iter().map(|day| {
+                        let value = day.format("%Y-%m-%d").to_string();
+                        view! {
+                            <option value=value selected=move || plan_date.get() == day>
+                                {day.format("%a %d.%m.").to_string()}
+                            </option>
+                        }
+                    }).collect_view()}
+                </select>
+                <select
+                    class="form-input"
+                    on:change=move |ev| {
+                        if let Ok(s) = event_target_value(&ev).parse() {
+                            plan_slot.set(s);
+                        }
+                    }
+                >
+                    {MealSlot::ALL.into_iter().map(|slot| view! {
+                        <option value=slot.as_str() selected=move || plan_slot.get() == slot>
+                            {i18n_stored.get_value().t(&slot_key(slot))}
+                        </option>
+                    }).collect_view()}
+                </select>
+                <select
+                    class="form-input"
+                    on:change=move |ev| plan_recipe.set(event_target_value(&ev))
+                >
+                    <option value="">{i18n_stored.get_value().t("meals.no_recipe")}</option>
+                    {move || recipes.get().into_iter().map(|r| view! {
+                        <option value=r.id.to_string()>{r.name}</option>
+                    }).collect_view()}
+                </select>
+                <input
+                    type="text"
+                    class="form-input"
+                    placeholder=i18n_stored.get_value().t("meals.meal_title")
+                    prop:value=move || plan_title.get()
+                    on:input=move |ev| plan_title.set(event_target_value(&ev))
+                />
+                <button type="submit" class="btn btn-primary">
+                    {i18n_stored.get_value().t("meals.plan_meal")}
+                </button>
+            </form>
+        </div>
+
+        // Recipes
+        <div class="card meal-recipes">
+            <h3 class="card-title">{i18n_stored.get_value().t("meals.recipes")}</h3>
+            {move || {
+                let list = recipes.get();
+                if list.is_empty() {
+                    return view! {
+                        <p class="empty-state">{i18n_stored.get_value().t("meals.no_recipes")}</p>
+                    }.into_view();
+                }
+                list.into_iter().map(|recipe| {
+                    let recipe_id = recipe.id;
+                    view! {
+                        <div class="meal-recipe">
+                            <div class="meal-recipe-header">
+                                <strong>{recipe.name}</strong>
+                                <button
+                                    class="btn btn-outline btn-sm"
+                                    on:click=move |_| on_add_to_shopping_list(recipe_id)
+                                >
+                                    {i18n_stored.get_value().t("meals.add_to_shopping_list")}
+                                </button>
+                                <button
+                                    class="btn btn-outline btn-sm"
+                                    on:click=move |_| on_delete_recipe(recipe_id)
+                                >
+                                    {i18n_stored.get_value().t("common.delete")}
+                                </button>
+                            </div>
+                            <ul class="meal-recipe-ingredients">
+                                {recipe.ingredients.iter().map(|i| view! {
+                                    <li>{format_ingredient(i)}</li>
+                                }).collect_view()}
+                            </ul>
+                            {recipe.notes.map(|n| view! { <p class="meal-recipe-notes">{n}</p> })}
+                        </div>
+                    }
+                }).collect_view()
+            }}
+
+            <form class="meal-recipe-form" on:submit=on_recipe_submit>
+                <h4>{i18n_stored.get_value().t("meals.new_recipe")}</h4>
+                <div class="form-group">
+                    <label class="form-label" for="recipe-name">{i18n_stored.get_value().t("meals.recipe_name")}</label>
+                    <input
+                        id="recipe-name"
+                        type="text"
+                        class="form-input"
+                        prop:value=move || recipe_name.get()
+                        on:input=move |ev| recipe_name.set(event_target_value(&ev))
+                    />
+                </div>
+                <div class="form-group">
+                    <label class="form-label" for="recipe-ingredients">{i18n_stored.get_value().t("meals.ingredients")}</label>
+                    <textarea
+                        id="recipe-ingredients"
+                        class="form-input"
+                        rows="5"
+                        placeholder=i18n_stored.get_value().t("meals.ingredients_hint")
+                        prop:value=move || recipe_ingredients.get()
+                        on:input=move |ev| recipe_ingredients.set(event_target_value(&ev))
+                    ></textarea>
+                </div>
+                <div class="form-group">
+                    <label class="form-label" for="recipe-notes">{i18n_stored.get_value().t("meals.notes")}</label>
+                    <textarea
+                        id="recipe-notes"
+                        class="form-input"
+                        rows="3"
+                        prop:value=move || recipe_notes.get()
+                        on:input=move |ev| recipe_notes.set(event_target_value(&ev))
+                    ></textarea>
+                </div>
+                <button type="submit" class="btn btn-primary">
+                    {i18n_stored.get_value().t("meals.save_recipe")}
+                </button>
+            </form>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn ingredient(name: &str, quantity: Option<f64>, unit: Option<&str>) -> RecipeIngredient {
+        RecipeIngredient {
+            name: name.to_string(),
+            quantity,
+            unit: unit.map(str::to_string),
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_parse_ingredient_line() {
+        assert_eq!(parse_ingredient_line("200 g flour"), Some(ingredient("flour", Some(200.0), Some("g"))));
+        assert_eq!(parse_ingredient_line("2 eggs"), Some(ingredient("eggs", Some(2.0), None)));
+        assert_eq!(parse_ingredient_line("0,5 l Milch"), Some(ingredient("Milch", Some(0.5), Some("l"))));
+        assert_eq!(parse_ingredient_line("salt"), Some(ingredient("salt", None, None)));
+        assert_eq!(parse_ingredient_line("   "), None);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_format_ingredient() {
+        assert_eq!(format_ingredient(&ingredient("flour", Some(200.0), Some("g"))), "200 g flour");
+        assert_eq!(format_ingredient(&ingredient("eggs", Some(2.0), None)), "2 eggs");
+        assert_eq!(format_ingredient(&ingredient("salt", None, None)), "salt");
+    }
+}
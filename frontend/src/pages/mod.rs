@@ -1,16 +1,31 @@
+pub mod admin;
 pub mod login;
 pub mod register;
+pub mod password_reset;
 pub mod dashboard;
 pub mod household;
 pub mod household_settings;
+pub mod join;
 pub mod tasks;
+pub mod calendar;
 pub mod rewards;
 pub mod punishments;
 pub mod activity;
 pub mod chat;
 pub mod notes;
 pub mod journal;
+pub mod expenses;
+pub mod meals;
+pub mod shopping_list;
+pub mod inventory;
+pub mod appliances;
+pub mod plants;
+pub mod pets;
+pub mod shared;
+pub mod challenges;
+pub mod automation_rules;
 pub mod legal;
 pub mod settings;
 pub mod user_settings;
 pub mod statistics;
+pub mod trash;
@@ -1,16 +1,18 @@
 use leptos::*;
 use leptos_router::*;
-use shared::{HouseholdSettings, Note, NoteWithUser, User};
+use shared::{HouseholdSettings, Note, NoteWithUser, UpdateNoteRequest, User};
 use uuid::Uuid;
 
-use crate::api::ApiClient;
+use crate::api::api_client;
 use crate::components::loading::Loading;
 use crate::components::note_card::NoteCard;
 use crate::components::note_modal::NoteModal;
+use crate::components::theme::use_theme;
 use crate::i18n::use_i18n;
 
 #[component]
 pub fn NotesPage() -> impl IntoView {
+    let theme = use_theme();
     let i18n = use_i18n();
     let i18n_stored = store_value(i18n);
 
@@ -27,47 +29,53 @@ pub fn NotesPage() -> impl IntoView {
     // Filter state
     let show_shared = create_rw_signal(true);
     let show_private = create_rw_signal(true);
+    let show_archived = create_rw_signal(false);
 
     // Modal state: None = closed, Some(None) = create mode, Some(Some(note)) = edit mode
     let modal_note = create_rw_signal(Option::<Option<Note>>::None);
 
-    // Load notes
+    // Load notes, again when switching between active and archived notes
     create_effect(move |_| {
         let id = household_id();
+        let archived = show_archived.get();
         if id.is_empty() {
             return;
         }
-
-        let id_for_notes = id.clone();
-        let id_for_user = id.clone();
-        let id_for_settings = id.clone();
-
-        // Load notes
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::list_notes(&id_for_notes).await {
+            match api_client().list_notes(&id, archived).await {
                 Ok(n) => {
                     notes.set(n);
                     loading.set(false);
                 }
                 Err(e) => {
-                    error.set(Some(e));
+                    error.set(Some(e.to_string()));
                     loading.set(false);
                 }
             }
         });
+    });
+
+    create_effect(move |_| {
+        let id = household_id();
+        if id.is_empty() {
+            return;
+        }
+
+        let id_for_user = id.clone();
+        let id_for_settings = id.clone();
 
         // Load current user
         wasm_bindgen_futures::spawn_local(async move {
             let _ = id_for_user;
-            if let Ok(user) = ApiClient::get_current_user().await {
+            if let Ok(user) = api_client().get_current_user().await {
                 current_user.set(Some(user));
             }
         });
 
         // Load settings for dark mode
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(s) = ApiClient::get_household_settings(&id_for_settings).await {
-                apply_dark_mode(s.dark_mode);
+            if let Ok(s) = api_client().get_household_settings(&id_for_settings).await {
+                theme.set_household_dark_mode(s.dark_mode);
                 settings.set(Some(s));
             }
         });
@@ -78,13 +86,52 @@ pub fn NotesPage() -> impl IntoView {
         if id.is_empty() {
             return;
         }
+        let archived = show_archived.get_untracked();
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(n) = ApiClient::list_notes(&id).await {
+            if let Ok(n) = api_client().list_notes(&id, archived).await {
                 notes.set(n);
             }
         });
     };
 
+    // Pin, archive and position changes; these don't take part in version checks
+    let change_note = move |note_id: Uuid, request: UpdateNoteRequest, on_done: Box<dyn FnOnce()>| {
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().update_note(&id, &note_id.to_string(), request).await {
+                Ok(_) => on_done(),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_pin = move |note: NoteWithUser| {
+        let request = UpdateNoteRequest { pinned: Some(!note.note.pinned), ..empty_update() };
+        change_note(note.note.id, request, Box::new(reload_notes));
+    };
+
+    let on_archive = move |note: NoteWithUser| {
+        let note_id = note.note.id;
+        let request = UpdateNoteRequest { archived: Some(!note.note.archived), ..empty_update() };
+        change_note(note_id, request, Box::new(move || notes.update(|n| n.retain(|note| note.note.id != note_id))));
+    };
+
+    let on_move = move |(note_id, up): (Uuid, bool)| {
+        let user_id = current_user.get_untracked().map(|u| u.id).unwrap_or(Uuid::nil());
+        let list: Vec<Note> = notes.get_untracked().into_iter().map(|n| n.note).collect();
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            for (moved_id, position) in reorder_positions(&list, user_id, note_id, up) {
+                let request = UpdateNoteRequest { sort_order: Some(position), ..empty_update() };
+                if let Err(e) = api_client().update_note(&id, &moved_id.to_string(), request).await {
+                    error.set(Some(e.to_string()));
+                    break;
+                }
+            }
+            reload_notes();
+        });
+    };
+
     let on_edit = move |note: NoteWithUser| {
         modal_note.set(Some(Some(note.note)));
     };
@@ -93,12 +140,12 @@ pub fn NotesPage() -> impl IntoView {
         let id = household_id();
         let note_id_str = note_id.to_string();
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::delete_note(&id, &note_id_str).await {
+            match api_client().delete_note(&id, &note_id_str).await {
                 Ok(()) => {
                     notes.update(|n| n.retain(|note| note.note.id != note_id));
                     success.set(Some(i18n_stored.get_value().t("notes.deleted")));
                 }
-                Err(e) => error.set(Some(e)),
+                Err(e) => error.set(Some(e.to_string())),
             }
         });
     };
@@ -172,6 +219,14 @@ pub fn NotesPage() -> impl IntoView {
                 />
                 <span>{i18n_stored.get_value().t("notes.private")}</span>
             </label>
+            <label class="filter-checkbox">
+                <input
+                    type="checkbox"
+                    prop:checked=move || show_archived.get()
+                    on:change=move |ev| show_archived.set(event_target_checked(&ev))
+                />
+                <span>{i18n_stored.get_value().t("notes.archived")}</span>
+            </label>
         </div>
 
         <Show when=move || loading.get() fallback=|| ()>
@@ -198,6 +253,9 @@ pub fn NotesPage() -> impl IntoView {
                                     current_user_id=user_id
                                     on_edit=Callback::new(on_edit)
                                     on_delete=Callback::new(on_delete)
+                                    on_pin=Callback::new(on_pin)
+                                    on_archive=Callback::new(on_archive)
+                                    on_move=Callback::new(on_move)
                                 />
                             }
                         }).collect_view()
@@ -220,17 +278,87 @@ pub fn NotesPage() -> impl IntoView {
     }
 }
 
-/// Apply dark mode class to document body
-fn apply_dark_mode(enabled: bool) {
-    if let Some(window) = web_sys::window() {
-        if let Some(document) = window.document() {
-            if let Some(body) = document.body() {
-                if enabled {
-                    let _ = body.class_list().add_1("dark-mode");
-                } else {
-                    let _ = body.class_list().remove_1("dark-mode");
-                }
-            }
+/// An update request that changes nothing
+fn empty_update() -> UpdateNoteRequest {
+    UpdateNoteRequest {
+        title: None,
+        content: None,
+        is_shared: None,
+        pinned: None,
+        color: None,
+        sort_order: None,
+        archived: None,
+        version: None,
+    }
+}
+
+/// New positions after moving a note one place up or down among the user's own
+/// notes with the same pin state. Only notes whose position changes are returned.
+fn reorder_positions(notes: &[Note], user_id: Uuid, note_id: Uuid, up: bool) -> Vec<(Uuid, i64)> {
+    let Some(pinned) = notes.iter().find(|n| n.id == note_id).map(|n| n.pinned) else {
+        return Vec::new();
+    };
+    let mut group: Vec<&Note> = notes.iter().filter(|n| n.user_id == user_id && n.pinned == pinned).collect();
+    let Some(pos) = group.iter().position(|n| n.id == note_id) else {
+        return Vec::new();
+    };
+    let target = if up { pos.checked_sub(1) } else { Some(pos + 1).filter(|t| *t < group.len()) };
+    let Some(target) = target else {
+        return Vec::new();
+    };
+    group.swap(pos, target);
+    group
+        .iter()
+        .enumerate()
+        .filter(|(i, n)| n.sort_order != Some(*i as i64))
+        .map(|(i, n)| (n.id, i as i64))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn note(user_id: Uuid, pinned: bool, sort_order: Option<i64>) -> Note {
+        Note {
+            id: Uuid::new_v4(),
+            household_id: Uuid::nil(),
+            user_id,
+            title: "Note".to_string(),
+            content: String::new(),
+            is_shared: true,
+            pinned,
+            color: None,
+            sort_order,
+            archived: false,
+            version: 1,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
         }
     }
+
+    #[wasm_bindgen_test]
+    fn test_reorder_positions_swaps_within_own_group() {
+        let me = Uuid::new_v4();
+        let other = Uuid::new_v4();
+        let notes = vec![
+            note(me, true, None),
+            note(me, false, Some(0)),
+            note(other, false, None),
+            note(me, false, None),
+        ];
+
+        // Moving the last note up skips the other user's note and numbers the group
+        assert_eq!(
+            reorder_positions(&notes, me, notes[3].id, true),
+            vec![(notes[3].id, 0), (notes[1].id, 1)]
+        );
+        // The pinned note is first in its own group already
+        assert!(reorder_positions(&notes, me, notes[0].id, true).is_empty());
+        assert!(reorder_positions(&notes, me, notes[3].id, false).is_empty());
+    }
 }
@@ -0,0 +1,216 @@
+use leptos::*;
+use leptos_router::*;
+use shared::{ForgotPasswordRequest, ResetPasswordRequest};
+
+use crate::api::api_client;
+use crate::i18n::use_i18n;
+
+/// Check a new password against the same rules as registration.
+/// Returns the translation key of the first failing rule.
+fn validate_new_password(password: &str, confirm_password: &str) -> Option<&'static str> {
+    if password != confirm_password {
+        Some("auth.password_mismatch")
+    } else if password.len() < 8 {
+        Some("auth.password_min_length")
+    } else {
+        None
+    }
+}
+
+#[component]
+pub fn ForgotPasswordPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let email = create_rw_signal(String::new());
+    let error = create_rw_signal(Option::<String>::None);
+    let sent = create_rw_signal(false);
+    let loading = create_rw_signal(false);
+
+    let on_submit = move |ev: web_sys::SubmitEvent| {
+        ev.prevent_default();
+
+        loading.set(true);
+        error.set(None);
+
+        let request = ForgotPasswordRequest { email: email.get() };
+
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().forgot_password(request).await {
+                Ok(_) => sent.set(true),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            loading.set(false);
+        });
+    };
+
+    view! {
+        <div class="auth-container">
+            <div class="auth-card card">
+                <div class="auth-header">
+                    <h1 class="auth-title">{move || i18n_stored.get_value().t("auth.forgot_password_title")}</h1>
+                    <p class="auth-subtitle">{move || i18n_stored.get_value().t("auth.forgot_password_subtitle")}</p>
+                </div>
+
+                {move || error.get().map(|e| view! {
+                    <div class="alert alert-error">{e}</div>
+                })}
+
+                <Show
+                    when=move || sent.get()
+                    fallback=move || view! {
+                        <form on:submit=on_submit>
+                            <div class="form-group">
+                                <label class="form-label" for="email">{move || i18n_stored.get_value().t("auth.email")}</label>
+                                <input
+                                    type="email"
+                                    id="email"
+                                    class="form-input"
+                                    placeholder=move || i18n_stored.get_value().t("auth.enter_email")
+                                    prop:value=move || email.get()
+                                    on:input=move |ev| email.set(event_target_value(&ev))
+                                    required
+                                />
+                            </div>
+
+                            <button
+                                type="submit"
+                                class="btn btn-primary"
+                                style="width: 100%; margin-top: 1rem;"
+                                disabled=move || loading.get()
+                            >
+                                {move || if loading.get() { i18n_stored.get_value().t("auth.sending_reset_link") } else { i18n_stored.get_value().t("auth.send_reset_link") }}
+                            </button>
+                        </form>
+                    }
+                >
+                    <div class="alert alert-success">{move || i18n_stored.get_value().t("auth.reset_link_sent")}</div>
+                </Show>
+
+                <p style="text-align: center; margin-top: 1rem; color: var(--text-muted);">
+                    <a href="/login" style="color: var(--primary-color);">{move || i18n_stored.get_value().t("auth.back_to_sign_in")}</a>
+                </p>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+pub fn ResetPasswordPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let token = use_query_map().get_untracked().get("token").cloned().unwrap_or_default();
+    let token_stored = store_value(token);
+
+    let password = create_rw_signal(String::new());
+    let confirm_password = create_rw_signal(String::new());
+    let error = create_rw_signal(Option::<String>::None);
+    let done = create_rw_signal(false);
+    let loading = create_rw_signal(false);
+
+    if token_stored.get_value().is_empty() {
+        error.set(Some(i18n_stored.get_value().t("auth.reset_link_invalid")));
+    }
+
+    let on_submit = move |ev: web_sys::SubmitEvent| {
+        ev.prevent_default();
+
+        if let Some(key) = validate_new_password(&password.get(), &confirm_password.get()) {
+            error.set(Some(i18n_stored.get_value().t(key)));
+            return;
+        }
+
+        loading.set(true);
+        error.set(None);
+
+        let request = ResetPasswordRequest {
+            token: token_stored.get_value(),
+            new_password: password.get(),
+        };
+
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().reset_password(request).await {
+                Ok(_) => done.set(true),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            loading.set(false);
+        });
+    };
+
+    view! {
+        <div class="auth-container">
+            <div class="auth-card card">
+                <div class="auth-header">
+                    <h1 class="auth-title">{move || i18n_stored.get_value().t("auth.reset_password_title")}</h1>
+                    <p class="auth-subtitle">{move || i18n_stored.get_value().t("auth.reset_password_subtitle")}</p>
+                </div>
+
+                {move || error.get().map(|e| view! {
+                    <div class="alert alert-error">{e}</div>
+                })}
+
+                <Show
+                    when=move || done.get()
+                    fallback=move || view! {
+                        <form on:submit=on_submit>
+                            <div class="form-group">
+                                <label class="form-label" for="password">{move || i18n_stored.get_value().t("auth.new_password")}</label>
+                                <input
+                                    type="password"
+                                    id="password"
+                                    class="form-input"
+                                    placeholder=move || i18n_stored.get_value().t("auth.create_password")
+                                    prop:value=move || password.get()
+                                    on:input=move |ev| password.set(event_target_value(&ev))
+                                    required
+                                    minlength="8"
+                                />
+                            </div>
+
+                            <div class="form-group">
+                                <label class="form-label" for="confirm-password">{move || i18n_stored.get_value().t("auth.confirm_password")}</label>
+                                <input
+                                    type="password"
+                                    id="confirm-password"
+                                    class="form-input"
+                                    placeholder=move || i18n_stored.get_value().t("auth.confirm_your_password")
+                                    prop:value=move || confirm_password.get()
+                                    on:input=move |ev| confirm_password.set(event_target_value(&ev))
+                                    required
+                                />
+                            </div>
+
+                            <button
+                                type="submit"
+                                class="btn btn-primary"
+                                style="width: 100%; margin-top: 1rem;"
+                                disabled=move || loading.get() || token_stored.get_value().is_empty()
+                            >
+                                {move || if loading.get() { i18n_stored.get_value().t("auth.resetting_password") } else { i18n_stored.get_value().t("auth.reset_password") }}
+                            </button>
+                        </form>
+                    }
+                >
+                    <div class="alert alert-success">{move || i18n_stored.get_value().t("auth.password_reset_done")}</div>
+                </Show>
+
+                <p style="text-align: center; margin-top: 1rem; color: var(--text-muted);">
+                    <a href="/login" style="color: var(--primary-color);">{move || i18n_stored.get_value().t("auth.back_to_sign_in")}</a>
+                </p>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_new_password() {
+        assert_eq!(validate_new_password("password123", "password456"), Some("auth.password_mismatch"));
+        assert_eq!(validate_new_password("short", "short"), Some("auth.password_min_length"));
+        assert_eq!(validate_new_password("password123", "password123"), None);
+    }
+}
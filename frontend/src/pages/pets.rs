@@ -0,0 +1,406 @@
+use leptos::*;
+use leptos_router::*;
+use shared::{CreatePetRequest, CreatePetScheduleRequest, Pet, PetCareEntry, PetCareKind, WsEventCategory, WsServerMessage};
+use uuid::Uuid;
+
+use crate::api::websocket::{WsClient, WsConnectionState};
+use crate::api::api_client;
+use crate::components::household_layout::HouseholdContext;
+use crate::components::loading::Loading;
+use crate::i18n::use_i18n;
+use crate::utils::format_time;
+
+/// Add a logged entry to its pet, newest first. Our own entries arrive twice
+/// (response and broadcast), so known entries are skipped.
+fn apply_entry(pets: &mut [Pet], entry: PetCareEntry) {
+    if let Some(pet) = pets.iter_mut().find(|p| p.id == entry.pet_id) {
+        if !pet.recent_log.iter().any(|e| e.id == entry.id) {
+            pet.recent_log.insert(0, entry);
+        }
+    }
+}
+
+/// A refused log attempt that can be repeated with `force`
+#[derive(Clone)]
+struct RecentlyLogged {
+    pet_id: Uuid,
+    task_id: Uuid,
+    message: String,
+}
+
+#[component]
+fn PetCard(
+    pet: Pet,
+    timezone: String,
+    on_log: Callback<(Uuid, Uuid, bool)>,
+    on_changed: Callback<Pet>,
+    on_deleted: Callback<Uuid>,
+    error: RwSignal<Option<String>>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+    let household_id_stored = store_value(pet.household_id.to_string());
+    let pet_id = pet.id;
+
+    let kind = create_rw_signal(PetCareKind::Feeding);
+    let title = create_rw_signal(String::new());
+    let times = create_rw_signal("2".to_string());
+
+    let on_add_schedule = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        let Ok(times_per_day) = times.get().trim().parse::<u16>() else {
+            error.set(Some(i18n_stored.get_value().t("pets.invalid_times")));
+            return;
+        };
+        let request = CreatePetScheduleRequest {
+            kind: kind.get(),
+            title: Some(title.get()),
+            times_per_day,
+            assigned_user_id: None,
+        };
+        let id = household_id_stored.get_value();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().add_pet_schedule(&id, &pet_id.to_string(), request).await {
+                Ok(updated) => {
+                    title.set(String::new());
+                    error.set(None);
+                    on_changed.call(updated);
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_remove_schedule = move |task_id: Uuid| {
+        let id = household_id_stored.get_value();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().remove_pet_schedule(&id, &pet_id.to_string(), &task_id.to_string()).await {
+                Ok(updated) => on_changed.call(updated),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_delete = move |_| {
+        let id = household_id_stored.get_value();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().delete_pet(&id, &pet_id.to_string()).await {
+                Ok(()) => on_deleted.call(pet_id),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let i18n = i18n_stored.get_value();
+    let header = match pet.species.clone() {
+        Some(species) => format!("{} ({})", pet.name, species),
+        None => pet.name.clone(),
+    };
+
+    view! {
+        <div class="card appliance-card">
+            <div class="appliance-header">
+                <h3 class="card-title">{header}</h3>
+                <button
+                    class="shopping-list-remove"
+                    title=i18n.t("common.delete")
+                    on:click=on_delete
+                >"×"</button>
+            </div>
+            {pet.notes.clone().map(|n| view! { <p class="appliance-notes">{n}</p> })}
+
+            <ul class="appliance-maintenance">
+                {pet.schedules.iter().map(|s| {
+                    let task_id = s.task_id;
+                    let (icon, label) = match s.kind {
+                        PetCareKind::Feeding => ("🍽", i18n.t("pets.fed")),
+                        PetCareKind::Medication => ("💊", i18n.t("pets.given")),
+                    };
+                    let per_day = i18n.t_plural("pets.times_per_day", s.times_per_day as i64, &[]);
+                    view! {
+                        <li>
+                            <span>{icon} " " {s.title.clone()}</span>
+                            <span class="appliance-location">{per_day}</span>
+                            <button class="btn btn-primary btn-sm" on:click=move |_| on_log.call((pet_id, task_id, false))>
+                                {label}
+                            </button>
+                            <button
+                                class="shopping-list-remove"
+                                title=i18n.t("common.delete")
+                                on:click=move |_| on_remove_schedule(task_id)
+                            >"×"</button>
+                        </li>
+                    }
+                }).collect_view()}
+            </ul>
+
+            <h4 class="appliance-maintenance-title">{i18n.t("pets.last_24_hours")}</h4>
+            {if pet.recent_log.is_empty() {
+                view! { <p class="empty-state">{i18n.t("pets.nothing_logged")}</p> }.into_view()
+            } else {
+                view! {
+                    <ul class="pet-log">
+                        {pet.recent_log.iter().map(|entry| {
+                            let line = i18n.t_with(
+                                "pets.logged_by",
+                                &[
+                                    ("time", &format_time(entry.logged_at, &timezone)),
+                                    ("title", &entry.title),
+                                    ("user", &entry.username),
+                                ],
+                            );
+                            view! { <li>{line}</li> }
+                        }).collect_view()}
+                    </ul>
+                }.into_view()
+            }}
+
+            <form class="inventory-form" on:submit=on_add_schedule>
+                <select
+                    class="form-input inventory-amount-input"
+                    on:change=move |ev| {
+                        let value = event_target_value(&ev);
+                        kind.set(value.parse().unwrap_or(PetCareKind::Feeding));
+                    }
+                >
+                    <option value="feeding" selected=true>{i18n.t("pets.feeding")}</option>
+                    <option value="medication">{i18n.t("pets.medication")}</option>
+                </select>
+                <input
+                    type="text"
+                    class="form-input"
+                    placeholder=i18n.t("pets.schedule_title")
+                    prop:value=move || title.get()
+                    on:input=move |ev| title.set(event_target_value(&ev))
+                />
+                <input
+                    type="number"
+                    min="1"
+                    class="form-input inventory-amount-input"
+                    title=i18n.t("pets.per_day")
+                    prop:value=move || times.get()
+                    on:input=move |ev| times.set(event_target_value(&ev))
+                />
+                <button type="submit" class="btn btn-outline btn-sm">{i18n.t("pets.add_schedule")}</button>
+            </form>
+        </div>
+    }
+}
+
+#[component]
+pub fn PetsPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let params = use_params_map();
+    let household_id = move || params.with(|p| p.get("id").cloned().unwrap_or_default());
+
+    let settings = use_context::<HouseholdContext>().map(|ctx| ctx.settings);
+    let timezone = move || {
+        settings
+            .and_then(|s| s.get())
+            .map(|s| s.timezone)
+            .unwrap_or_else(|| "UTC".to_string())
+    };
+
+    let pets = create_rw_signal(Vec::<Pet>::new());
+    let loading = create_rw_signal(true);
+    let error = create_rw_signal(Option::<String>::None);
+    let recently_logged = create_rw_signal(Option::<RecentlyLogged>::None);
+
+    let name = create_rw_signal(String::new());
+    let species = create_rw_signal(String::new());
+
+    create_effect(move |_| {
+        let id = household_id();
+        if id.is_empty() {
+            return;
+        }
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().list_pets(&id).await {
+                Ok(list) => pets.set(list),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            loading.set(false);
+        });
+    });
+
+    // Live feeding log: everyone sees right away when a pet was fed
+    let ws_client = WsClient::new();
+    ws_client.connect();
+    let ws_state = ws_client.state();
+    let ws_messages = ws_client.last_message();
+    {
+        let ws_client = ws_client.clone();
+        create_effect(move |subscribed: Option<bool>| {
+            if subscribed == Some(true) || ws_state.get() != WsConnectionState::Authenticated {
+                return subscribed.unwrap_or(false);
+            }
+            match Uuid::parse_str(&household_id()) {
+                Ok(uuid) => {
+                    ws_client.subscribe(uuid, vec![WsEventCategory::Pets]);
+                    true
+                }
+                Err(_) => false,
+            }
+        });
+    }
+    create_effect(move |_| {
+        if let Some(WsServerMessage::PetCareLogged { household_id: logged_in, entry }) = ws_messages.get() {
+            if logged_in.to_string() == household_id() {
+                pets.update(|list| apply_entry(list, entry));
+            }
+        }
+    });
+    on_cleanup(move || ws_client.disconnect());
+
+    let on_log = Callback::new(move |(pet_id, task_id, force): (Uuid, Uuid, bool)| {
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().log_pet_care(&id, &pet_id.to_string(), &task_id.to_string(), force).await {
+                Ok(entry) => {
+                    recently_logged.set(None);
+                    error.set(None);
+                    pets.update(|list| apply_entry(list, entry));
+                }
+                Err(message) if !force => recently_logged.set(Some(RecentlyLogged { pet_id, task_id, message: message.to_string() })),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    });
+
+    let on_add = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        if name.get().trim().is_empty() {
+            return;
+        }
+        let request = CreatePetRequest {
+            name: name.get(),
+            species: Some(species.get()),
+            notes: None,
+        };
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().create_pet(&id, request).await {
+                Ok(pet) => {
+                    pets.update(|list| list.push(pet));
+                    name.set(String::new());
+                    species.set(String::new());
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_changed = Callback::new(move |updated: Pet| {
+        pets.update(|list| {
+            if let Some(pet) = list.iter_mut().find(|p| p.id == updated.id) {
+                *pet = updated;
+            }
+        })
+    });
+    let on_deleted = Callback::new(move |pet_id: Uuid| pets.update(|list| list.retain(|p| p.id != pet_id)));
+
+    view! {
+        <div class="dashboard-header">
+            <h1 class="dashboard-title">{i18n_stored.get_value().t("pets.title")}</h1>
+        </div>
+
+        {move || error.get().map(|e| view! {
+            <div class="alert alert-error">{e}</div>
+        })}
+        {move || recently_logged.get().map(|r| view! {
+            <div class="alert alert-warning">
+                <span>{r.message}</span>
+                " "
+                <button
+                    class="btn btn-outline btn-sm"
+                    on:click=move |_| on_log.call((r.pet_id, r.task_id, true))
+                >{i18n_stored.get_value().t("pets.log_anyway")}</button>
+                <button class="btn btn-outline btn-sm" on:click=move |_| recently_logged.set(None)>
+                    {i18n_stored.get_value().t("common.cancel")}
+                </button>
+            </div>
+        })}
+
+        <form class="card inventory-form" on:submit=on_add>
+            <input
+                type="text"
+                class="form-input"
+                placeholder=i18n_stored.get_value().t("pets.name")
+                prop:value=move || name.get()
+                on:input=move |ev| name.set(event_target_value(&ev))
+            />
+            <input
+                type="text"
+                class="form-input"
+                placeholder=i18n_stored.get_value().t("pets.species")
+                prop:value=move || species.get()
+                on:input=move |ev| species.set(event_target_value(&ev))
+            />
+            <button type="submit" class="btn btn-primary">{i18n_stored.get_value().t("pets.add")}</button>
+        </form>
+
+        <Show when=move || loading.get() fallback=|| ()>
+            <Loading />
+        </Show>
+
+        {move || {
+            let list = pets.get();
+            if list.is_empty() && !loading.get() {
+                return view! {
+                    <p class="empty-state">{i18n_stored.get_value().t("pets.empty")}</p>
+                }.into_view();
+            }
+            let timezone = timezone();
+            list.into_iter().map(|pet| view! {
+                <PetCard
+                    pet=pet
+                    timezone=timezone.clone()
+                    on_log=on_log
+                    on_changed=on_changed
+                    on_deleted=on_deleted
+                    error=error
+                />
+            }).collect_view()
+        }}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_apply_entry() {
+        let pet = Pet {
+            id: Uuid::new_v4(),
+            household_id: Uuid::new_v4(),
+            name: "Bello".to_string(),
+            species: None,
+            notes: None,
+            created_by: Uuid::new_v4(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            schedules: Vec::new(),
+            recent_log: Vec::new(),
+        };
+        let entry = PetCareEntry {
+            id: Uuid::new_v4(),
+            pet_id: pet.id,
+            task_id: None,
+            kind: PetCareKind::Feeding,
+            title: "Feed Bello".to_string(),
+            user_id: Uuid::new_v4(),
+            username: "alice".to_string(),
+            logged_at: Utc::now(),
+        };
+        let mut pets = vec![pet];
+        apply_entry(&mut pets, entry.clone());
+        apply_entry(&mut pets, entry);
+        assert_eq!(pets[0].recent_log.len(), 1);
+    }
+}
@@ -0,0 +1,337 @@
+use leptos::*;
+use leptos_router::*;
+use shared::{AttachmentEntity, CreatePlantRequest, Plant, UpdatePlantRequest};
+use uuid::Uuid;
+use wasm_bindgen::JsCast;
+
+use crate::api::{api_client, files};
+use crate::components::household_layout::HouseholdContext;
+use crate::components::loading::Loading;
+use crate::i18n::use_i18n;
+use crate::utils::format_date;
+
+/// Parse a care interval from a form field; blank means no reminder
+fn parse_interval(value: &str) -> Result<Option<u16>, ()> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    match value.parse::<u16>() {
+        Ok(days) if days >= 1 => Ok(Some(days)),
+        _ => Err(()),
+    }
+}
+
+#[component]
+fn PlantCard(
+    plant: Plant,
+    timezone: String,
+    on_changed: Callback<Plant>,
+    on_deleted: Callback<Uuid>,
+    error: RwSignal<Option<String>>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+    let household_id_stored = store_value(plant.household_id.to_string());
+    let plant_stored = store_value(plant.clone());
+    let plant_id = plant.id;
+
+    let thumbnail = create_rw_signal(Option::<String>::None);
+    if let Some(photo) = plant.photo.clone() {
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Ok(url) = files::get_attachment_thumbnail_url(&household_id_stored.get_value(), &photo).await {
+                thumbnail.set(Some(url));
+            }
+        });
+    }
+
+    let on_photo_selected = move |ev: web_sys::Event| {
+        let input = ev.target().and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok());
+        let Some(input) = input else { return };
+        let Some(file) = input.files().and_then(|files| files.get(0)) else { return };
+        input.set_value("");
+        let id = household_id_stored.get_value();
+        wasm_bindgen_futures::spawn_local(async move {
+            match files::upload_attachment(&id, AttachmentEntity::Plant, &plant_id.to_string(), &file).await {
+                Ok(attachment) => {
+                    // Only the latest photo is shown, so the previous one can go
+                    let mut updated = plant_stored.get_value();
+                    if let Some(previous) = updated.photo.replace(attachment) {
+                        let _ = api_client().delete_attachment(&id, &previous.id.to_string()).await;
+                    }
+                    error.set(None);
+                    on_changed.call(updated);
+                }
+                Err(e) => error.set(Some(e)),
+            }
+        });
+    };
+
+    let on_set_interval = move |water: bool, value: String| {
+        let Ok(interval) = parse_interval(&value) else {
+            error.set(Some(i18n_stored.get_value().t("plants.invalid_interval")));
+            return;
+        };
+        let request = if water {
+            UpdatePlantRequest {
+                water_interval_days: Some(interval),
+                ..Default::default()
+            }
+        } else {
+            UpdatePlantRequest {
+                fertilize_interval_days: Some(interval),
+                ..Default::default()
+            }
+        };
+        let id = household_id_stored.get_value();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().update_plant(&id, &plant_id.to_string(), request).await {
+                Ok(updated) => {
+                    error.set(None);
+                    on_changed.call(updated);
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_delete = move |_| {
+        let id = household_id_stored.get_value();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().delete_plant(&id, &plant_id.to_string()).await {
+                Ok(()) => on_deleted.call(plant_id),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let i18n = i18n_stored.get_value();
+    let last_care = |at: Option<chrono::DateTime<chrono::Utc>>, key: &str| {
+        at.map(|at| i18n.t_with(key, &[("date", &format_date(at, &timezone))]))
+            .unwrap_or_else(|| i18n.t("plants.never"))
+    };
+    let last_watered = last_care(plant.last_watered_at, "plants.last_watered");
+    let last_fertilized = last_care(plant.last_fertilized_at, "plants.last_fertilized");
+    let water_days = plant.water_interval_days.map(|d| d.to_string()).unwrap_or_default();
+    let fertilize_days = plant.fertilize_interval_days.map(|d| d.to_string()).unwrap_or_default();
+    let days_unit = |days: Option<i32>| i18n.t_plural("plants.days", days.unwrap_or(0) as i64, &[]);
+    let water_unit = days_unit(plant.water_interval_days);
+    let fertilize_unit = days_unit(plant.fertilize_interval_days);
+
+    view! {
+        <div class="card plant-card">
+            <label class="plant-photo" title=i18n.t("plants.change_photo")>
+                {move || match thumbnail.get() {
+                    Some(url) => view! { <img src=url alt="" /> }.into_view(),
+                    None => view! { <span class="plant-photo-placeholder">"🪴"</span> }.into_view(),
+                }}
+                <input
+                    type="file"
+                    accept="image/jpeg,image/png,image/gif,image/webp"
+                    style="display: none;"
+                    on:change=on_photo_selected
+                />
+            </label>
+            <div class="plant-info">
+                <div class="appliance-header">
+                    <h3 class="card-title">{plant.name.clone()}</h3>
+                    {plant.location.clone().map(|l| view! { <span class="appliance-location">{l}</span> })}
+                    <button
+                        class="shopping-list-remove"
+                        title=i18n.t("common.delete")
+                        on:click=on_delete
+                    >"×"</button>
+                </div>
+                <div class="plant-care">
+                    <span>"💧 " {last_watered}</span>
+                    <label class="plant-interval">
+                        {i18n.t("plants.water_every")}
+                        <input
+                            type="number"
+                            min="1"
+                            class="form-input inventory-amount-input"
+                            prop:value=water_days
+                            on:change=move |ev| on_set_interval(true, event_target_value(&ev))
+                        />
+                        {water_unit}
+                    </label>
+                </div>
+                <div class="plant-care">
+                    <span>"🌱 " {last_fertilized}</span>
+                    <label class="plant-interval">
+                        {i18n.t("plants.fertilize_every")}
+                        <input
+                            type="number"
+                            min="1"
+                            class="form-input inventory-amount-input"
+                            prop:value=fertilize_days
+                            on:change=move |ev| on_set_interval(false, event_target_value(&ev))
+                        />
+                        {fertilize_unit}
+                    </label>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+pub fn PlantsPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let params = use_params_map();
+    let household_id = move || params.with(|p| p.get("id").cloned().unwrap_or_default());
+
+    let settings = use_context::<HouseholdContext>().map(|ctx| ctx.settings);
+    let timezone = move || {
+        settings
+            .and_then(|s| s.get())
+            .map(|s| s.timezone)
+            .unwrap_or_else(|| "UTC".to_string())
+    };
+
+    let plants = create_rw_signal(Vec::<Plant>::new());
+    let loading = create_rw_signal(true);
+    let error = create_rw_signal(Option::<String>::None);
+
+    let name = create_rw_signal(String::new());
+    let location = create_rw_signal(String::new());
+    let water_days = create_rw_signal(String::new());
+    let fertilize_days = create_rw_signal(String::new());
+
+    create_effect(move |_| {
+        let id = household_id();
+        if id.is_empty() {
+            return;
+        }
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().list_plants(&id).await {
+                Ok(list) => plants.set(list),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            loading.set(false);
+        });
+    });
+
+    let on_add = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        if name.get().trim().is_empty() {
+            return;
+        }
+        let (Ok(water), Ok(fertilize)) = (parse_interval(&water_days.get()), parse_interval(&fertilize_days.get()))
+        else {
+            error.set(Some(i18n_stored.get_value().t("plants.invalid_interval")));
+            return;
+        };
+        let request = CreatePlantRequest {
+            name: name.get(),
+            location: Some(location.get()),
+            water_interval_days: water,
+            fertilize_interval_days: fertilize,
+        };
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().create_plant(&id, request).await {
+                Ok(plant) => {
+                    plants.update(|list| list.push(plant));
+                    name.set(String::new());
+                    water_days.set(String::new());
+                    fertilize_days.set(String::new());
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_changed = Callback::new(move |updated: Plant| {
+        plants.update(|list| {
+            if let Some(plant) = list.iter_mut().find(|p| p.id == updated.id) {
+                *plant = updated;
+            }
+        })
+    });
+    let on_deleted = Callback::new(move |plant_id: Uuid| plants.update(|list| list.retain(|p| p.id != plant_id)));
+
+    view! {
+        <div class="dashboard-header">
+            <h1 class="dashboard-title">{i18n_stored.get_value().t("plants.title")}</h1>
+        </div>
+
+        {move || error.get().map(|e| view! {
+            <div class="alert alert-error">{e}</div>
+        })}
+
+        <form class="card inventory-form" on:submit=on_add>
+            <input
+                type="text"
+                class="form-input"
+                placeholder=i18n_stored.get_value().t("plants.name")
+                prop:value=move || name.get()
+                on:input=move |ev| name.set(event_target_value(&ev))
+            />
+            <input
+                type="text"
+                class="form-input"
+                placeholder=i18n_stored.get_value().t("inventory.location")
+                prop:value=move || location.get()
+                on:input=move |ev| location.set(event_target_value(&ev))
+            />
+            <input
+                type="number"
+                min="1"
+                class="form-input inventory-amount-input"
+                placeholder=i18n_stored.get_value().t("plants.water_days")
+                prop:value=move || water_days.get()
+                on:input=move |ev| water_days.set(event_target_value(&ev))
+            />
+            <input
+                type="number"
+                min="1"
+                class="form-input inventory-amount-input"
+                placeholder=i18n_stored.get_value().t("plants.fertilize_days")
+                prop:value=move || fertilize_days.get()
+                on:input=move |ev| fertilize_days.set(event_target_value(&ev))
+            />
+            <button type="submit" class="btn btn-primary">{i18n_stored.get_value().t("plants.add")}</button>
+        </form>
+
+        <Show when=move || loading.get() fallback=|| ()>
+            <Loading />
+        </Show>
+
+        {move || {
+            let list = plants.get();
+            if list.is_empty() && !loading.get() {
+                return view! {
+                    <p class="empty-state">{i18n_stored.get_value().t("plants.empty")}</p>
+                }.into_view();
+            }
+            let timezone = timezone();
+            list.into_iter().map(|plant| view! {
+                <PlantCard
+                    plant=plant
+                    timezone=timezone.clone()
+                    on_changed=on_changed
+                    on_deleted=on_deleted
+                    error=error
+                />
+            }).collect_view()
+        }}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_parse_interval() {
+        assert_eq!(parse_interval(""), Ok(None));
+        assert_eq!(parse_interval(" 7 "), Ok(Some(7)));
+        assert_eq!(parse_interval("0"), Err(()));
+        assert_eq!(parse_interval("weekly"), Err(()));
+    }
+}
@@ -2,14 +2,16 @@ use leptos::*;
 use leptos_router::*;
 use shared::{HouseholdSettings, MemberWithUser, Punishment, UserPunishment, UserPunishmentWithUser};
 
-use crate::api::ApiClient;
+use crate::api::api_client;
 use crate::components::loading::Loading;
 use crate::components::markdown::MarkdownView;
 use crate::components::punishment_modal::PunishmentModal;
+use crate::components::theme::use_theme;
 use crate::i18n::use_i18n;
 
 #[component]
 pub fn PunishmentsPage() -> impl IntoView {
+    let theme = use_theme();
     let i18n = use_i18n();
     let i18n_stored = store_value(i18n);
 
@@ -42,13 +44,13 @@ pub fn PunishmentsPage() -> impl IntoView {
         let id_for_settings = id.clone();
 
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::list_punishments(&id_for_punishments).await {
+            match api_client().list_punishments(&id_for_punishments).await {
                 Ok(p) => {
                     punishments.set(p);
                     loading.set(false);
                 }
                 Err(e) => {
-                    error.set(Some(e));
+                    error.set(Some(e.to_string()));
                     loading.set(false);
                 }
             }
@@ -56,29 +58,29 @@ pub fn PunishmentsPage() -> impl IntoView {
 
         // Load my punishments
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(p) = ApiClient::list_user_punishments(&id_for_my_punishments).await {
+            if let Ok(p) = api_client().list_user_punishments(&id_for_my_punishments).await {
                 my_punishments.set(p);
             }
         });
 
         // Load all user punishments in household
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(p) = ApiClient::list_all_user_punishments(&id_for_all_user_punishments).await {
+            if let Ok(p) = api_client().list_all_user_punishments(&id_for_all_user_punishments).await {
                 all_user_punishments.set(p);
             }
         });
 
         // Load members
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(m) = ApiClient::list_members(&id_for_members).await {
+            if let Ok(m) = api_client().list_members(&id_for_members).await {
                 members.set(m);
             }
         });
 
         // Load settings for dark mode
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(s) = ApiClient::get_household_settings(&id_for_settings).await {
-                apply_dark_mode(s.dark_mode);
+            if let Ok(s) = api_client().get_household_settings(&id_for_settings).await {
+                theme.set_household_dark_mode(s.dark_mode);
                 settings.set(Some(s));
             }
         });
@@ -87,7 +89,7 @@ pub fn PunishmentsPage() -> impl IntoView {
     let on_delete = move |punishment_id: String| {
         let id = household_id();
         wasm_bindgen_futures::spawn_local(async move {
-            if ApiClient::delete_punishment(&id, &punishment_id).await.is_ok() {
+            if api_client().delete_punishment(&id, &punishment_id).await.is_ok() {
                 punishments.update(|p| p.retain(|punishment| punishment.id.to_string() != punishment_id));
             }
         });
@@ -96,7 +98,7 @@ pub fn PunishmentsPage() -> impl IntoView {
     let on_assign = move |punishment_id: String, user_id: String| {
         let id = household_id();
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::assign_punishment(&id, &punishment_id, &user_id).await {
+            match api_client().assign_punishment(&id, &punishment_id, &user_id).await {
                 Ok(user_punishment) => {
                     // Check if user already has this punishment assigned
                     let existing_idx = all_user_punishments.get().iter().position(|up| {
@@ -122,7 +124,7 @@ pub fn PunishmentsPage() -> impl IntoView {
                         }
                     }
                 }
-                Err(e) => error.set(Some(e)),
+                Err(e) => error.set(Some(e.to_string())),
             }
         });
     };
@@ -130,7 +132,7 @@ pub fn PunishmentsPage() -> impl IntoView {
     let on_unassign = move |punishment_id: String, user_id: String| {
         let id = household_id();
         wasm_bindgen_futures::spawn_local(async move {
-            if ApiClient::unassign_punishment(&id, &punishment_id, &user_id).await.is_ok() {
+            if api_client().unassign_punishment(&id, &punishment_id, &user_id).await.is_ok() {
                 // Decrement amount or remove if amount becomes 0
                 all_user_punishments.update(|p| {
                     if let Some(pos) = p.iter().position(|up| {
@@ -151,7 +153,7 @@ pub fn PunishmentsPage() -> impl IntoView {
     let on_complete = move |user_punishment_id: String| {
         let id = household_id();
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::complete_punishment(&id, &user_punishment_id).await {
+            match api_client().complete_punishment(&id, &user_punishment_id).await {
                 Ok(updated) => {
                     my_punishments.update(|p| {
                         if let Some(pos) = p.iter().position(|up| up.id.to_string() == user_punishment_id) {
@@ -160,7 +162,7 @@ pub fn PunishmentsPage() -> impl IntoView {
                     });
                     success.set(Some(i18n_stored.get_value().t("punishments.completed_success")));
                 }
-                Err(e) => error.set(Some(e)),
+                Err(e) => error.set(Some(e.to_string())),
             }
         });
     };
@@ -168,7 +170,7 @@ pub fn PunishmentsPage() -> impl IntoView {
     let on_pick_random = move |user_punishment_id: String| {
         let id = household_id();
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::pick_random_punishment(&id, &user_punishment_id).await {
+            match api_client().pick_random_punishment(&id, &user_punishment_id).await {
                 Ok(result) => {
                     // Remove or update the original random choice assignment
                     my_punishments.update(|p| {
@@ -195,7 +197,7 @@ pub fn PunishmentsPage() -> impl IntoView {
                     let msg = format!("{}: {}", i18n_stored.get_value().t("punishments.picked_success"), picked_name);
                     success.set(Some(msg));
                 }
-                Err(e) => error.set(Some(e)),
+                Err(e) => error.set(Some(e.to_string())),
             }
         });
     };
@@ -504,21 +506,6 @@ pub fn PunishmentsPage() -> impl IntoView {
     }
 }
 
-/// Apply dark mode class to document body
-fn apply_dark_mode(enabled: bool) {
-    if let Some(window) = web_sys::window() {
-        if let Some(document) = window.document() {
-            if let Some(body) = document.body() {
-                if enabled {
-                    let _ = body.class_list().add_1("dark-mode");
-                } else {
-                    let _ = body.class_list().remove_1("dark-mode");
-                }
-            }
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use uuid::Uuid;
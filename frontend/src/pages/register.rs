@@ -2,8 +2,9 @@ use leptos::*;
 use leptos_router::*;
 use shared::CreateUserRequest;
 
-use crate::api::{ApiClient, AuthState};
+use crate::api::{api_client, AuthState};
 use crate::i18n::use_i18n;
+use crate::utils::take_login_redirect;
 
 #[component]
 pub fn Register() -> impl IntoView {
@@ -14,7 +15,10 @@ pub fn Register() -> impl IntoView {
     let navigate = use_navigate();
 
     let username = create_rw_signal(String::new());
-    let email = create_rw_signal(String::new());
+    // Invitation mails link here with the invited address prefilled
+    let email = create_rw_signal(
+        use_query_map().get_untracked().get("email").cloned().unwrap_or_default(),
+    );
     let password = create_rw_signal(String::new());
     let confirm_password = create_rw_signal(String::new());
     let agb_accepted = create_rw_signal(false);
@@ -52,13 +56,13 @@ pub fn Register() -> impl IntoView {
         };
 
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::register(request).await {
+            match api_client().register(request).await {
                 Ok(response) => {
                     auth.set_auth(response);
-                    nav("/", Default::default());
+                    nav(&take_login_redirect(), Default::default());
                 }
                 Err(e) => {
-                    error.set(Some(e));
+                    error.set(Some(e.to_string()));
                     loading.set(false);
                 }
             }
@@ -1,15 +1,17 @@
 use leptos::*;
 use leptos_router::*;
-use shared::{HouseholdSettings, MemberWithUser, Reward, UserReward, UserRewardWithUser};
+use shared::{HouseholdSettings, MemberWithUser, PurchaseRewardResponse, Reward, UserReward, UserRewardWithUser, WishlistItem};
 
-use crate::api::ApiClient;
+use crate::api::api_client;
 use crate::components::loading::Loading;
 use crate::components::markdown::MarkdownView;
 use crate::components::reward_modal::RewardModal;
+use crate::components::theme::use_theme;
 use crate::i18n::use_i18n;
 
 #[component]
 pub fn RewardsPage() -> impl IntoView {
+    let theme = use_theme();
     let i18n = use_i18n();
     let i18n_stored = store_value(i18n);
 
@@ -20,6 +22,7 @@ pub fn RewardsPage() -> impl IntoView {
     let my_rewards = create_rw_signal(Vec::<UserReward>::new());
     let all_user_rewards = create_rw_signal(Vec::<UserRewardWithUser>::new());
     let members = create_rw_signal(Vec::<MemberWithUser>::new());
+    let wishlist = create_rw_signal(Vec::<WishlistItem>::new());
     let settings = create_rw_signal(Option::<HouseholdSettings>::None);
     let loading = create_rw_signal(true);
     let error = create_rw_signal(Option::<String>::None);
@@ -40,15 +43,16 @@ pub fn RewardsPage() -> impl IntoView {
         let id_for_all_user_rewards = id.clone();
         let id_for_members = id.clone();
         let id_for_settings = id.clone();
+        let id_for_wishlist = id.clone();
 
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::list_rewards(&id_for_rewards).await {
+            match api_client().list_rewards(&id_for_rewards).await {
                 Ok(r) => {
                     rewards.set(r);
                     loading.set(false);
                 }
                 Err(e) => {
-                    error.set(Some(e));
+                    error.set(Some(e.to_string()));
                     loading.set(false);
                 }
             }
@@ -56,29 +60,36 @@ pub fn RewardsPage() -> impl IntoView {
 
         // Load my rewards
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(r) = ApiClient::list_user_rewards(&id_for_my_rewards).await {
+            if let Ok(r) = api_client().list_user_rewards(&id_for_my_rewards).await {
                 my_rewards.set(r);
             }
         });
 
         // Load all user rewards in household
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(r) = ApiClient::list_all_user_rewards(&id_for_all_user_rewards).await {
+            if let Ok(r) = api_client().list_all_user_rewards(&id_for_all_user_rewards).await {
                 all_user_rewards.set(r);
             }
         });
 
         // Load members
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(m) = ApiClient::list_members(&id_for_members).await {
+            if let Ok(m) = api_client().list_members(&id_for_members).await {
                 members.set(m);
             }
         });
 
+        // Load my wishlist
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Ok(w) = api_client().get_wishlist(&id_for_wishlist).await {
+                wishlist.set(w);
+            }
+        });
+
         // Load settings for dark mode
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(s) = ApiClient::get_household_settings(&id_for_settings).await {
-                apply_dark_mode(s.dark_mode);
+            if let Ok(s) = api_client().get_household_settings(&id_for_settings).await {
+                theme.set_household_dark_mode(s.dark_mode);
                 settings.set(Some(s));
             }
         });
@@ -87,12 +98,32 @@ pub fn RewardsPage() -> impl IntoView {
     let on_purchase = move |reward_id: String| {
         let id = household_id();
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::purchase_reward(&id, &reward_id).await {
-                Ok(user_reward) => {
+            match api_client().purchase_reward(&id, &reward_id).await {
+                Ok(PurchaseRewardResponse::Purchased(user_reward)) => {
                     my_rewards.update(|r| r.push(user_reward));
                     success.set(Some(i18n_stored.get_value().t("rewards.purchased_success")));
                 }
-                Err(e) => error.set(Some(e)),
+                Ok(PurchaseRewardResponse::PendingApproval(_)) => {
+                    success.set(Some(i18n_stored.get_value().t("rewards.purchase_pending_approval")));
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_toggle_wishlist = move |reward_id: String, on_wishlist: bool| {
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            if on_wishlist {
+                match api_client().remove_from_wishlist(&id, &reward_id).await {
+                    Ok(()) => wishlist.update(|w| w.retain(|item| item.reward.id.to_string() != reward_id)),
+                    Err(e) => error.set(Some(e.to_string())),
+                }
+            } else {
+                match api_client().add_to_wishlist(&id, &reward_id).await {
+                    Ok(items) => wishlist.set(items),
+                    Err(e) => error.set(Some(e.to_string())),
+                }
             }
         });
     };
@@ -100,7 +131,7 @@ pub fn RewardsPage() -> impl IntoView {
     let on_redeem = move |user_reward_id: String| {
         let id = household_id();
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::redeem_reward(&id, &user_reward_id).await {
+            match api_client().redeem_reward(&id, &user_reward_id).await {
                 Ok(updated) => {
                     my_rewards.update(|r| {
                         if let Some(pos) = r.iter().position(|ur| ur.id.to_string() == user_reward_id) {
@@ -109,7 +140,32 @@ pub fn RewardsPage() -> impl IntoView {
                     });
                     success.set(Some(i18n_stored.get_value().t("rewards.redeemed_success")));
                 }
-                Err(e) => error.set(Some(e)),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_gift = move |user_reward_id: String, to_user_id: String| {
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().gift_reward(&id, &user_reward_id, &to_user_id).await {
+                Ok(remaining) => {
+                    my_rewards.update(|r| {
+                        if let Some(pos) = r.iter().position(|ur| ur.id.to_string() == user_reward_id) {
+                            match remaining {
+                                Some(updated) => r[pos] = updated,
+                                None => {
+                                    r.remove(pos);
+                                }
+                            }
+                        }
+                    });
+                    if let Ok(r) = api_client().list_all_user_rewards(&id).await {
+                        all_user_rewards.set(r);
+                    }
+                    success.set(Some(i18n_stored.get_value().t("rewards.gifted_success")));
+                }
+                Err(e) => error.set(Some(e.to_string())),
             }
         });
     };
@@ -117,7 +173,7 @@ pub fn RewardsPage() -> impl IntoView {
     let on_pick_random = move |user_reward_id: String| {
         let id = household_id();
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::pick_random_reward(&id, &user_reward_id).await {
+            match api_client().pick_random_reward(&id, &user_reward_id).await {
                 Ok(result) => {
                     // Remove or update the original random choice assignment
                     my_rewards.update(|r| {
@@ -142,7 +198,7 @@ pub fn RewardsPage() -> impl IntoView {
 
                     success.set(Some(format!("{}: {}", i18n_stored.get_value().t("rewards.random_picked"), picked_name)));
                 }
-                Err(e) => error.set(Some(e)),
+                Err(e) => error.set(Some(e.to_string())),
             }
         });
     };
@@ -150,7 +206,7 @@ pub fn RewardsPage() -> impl IntoView {
     let on_delete = move |reward_id: String| {
         let id = household_id();
         wasm_bindgen_futures::spawn_local(async move {
-            if ApiClient::delete_reward(&id, &reward_id).await.is_ok() {
+            if api_client().delete_reward(&id, &reward_id).await.is_ok() {
                 rewards.update(|r| r.retain(|reward| reward.id.to_string() != reward_id));
             }
         });
@@ -159,7 +215,7 @@ pub fn RewardsPage() -> impl IntoView {
     let on_assign = move |reward_id: String, user_id: String| {
         let id = household_id();
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::assign_reward(&id, &reward_id, &user_id).await {
+            match api_client().assign_reward(&id, &reward_id, &user_id).await {
                 Ok(user_reward) => {
                     // Check if user already has this reward assigned
                     let existing_idx = all_user_rewards.get().iter().position(|ur| {
@@ -185,7 +241,7 @@ pub fn RewardsPage() -> impl IntoView {
                         }
                     }
                 }
-                Err(e) => error.set(Some(e)),
+                Err(e) => error.set(Some(e.to_string())),
             }
         });
     };
@@ -193,7 +249,7 @@ pub fn RewardsPage() -> impl IntoView {
     let on_unassign = move |reward_id: String, user_id: String| {
         let id = household_id();
         wasm_bindgen_futures::spawn_local(async move {
-            if ApiClient::unassign_reward(&id, &reward_id, &user_id).await.is_ok() {
+            if api_client().unassign_reward(&id, &reward_id, &user_id).await.is_ok() {
                 // Decrement amount or remove if amount becomes 0
                 all_user_rewards.update(|r| {
                     if let Some(pos) = r.iter().position(|ur| {
@@ -255,6 +311,12 @@ pub fn RewardsPage() -> impl IntoView {
                                 let ur_id = user_reward.id.to_string();
                                 let redeem_id = ur_id.clone();
                                 let pick_id = ur_id.clone();
+                                let gift_id = ur_id.clone();
+                                let owner_id = user_reward.user_id;
+                                let gift_targets: Vec<_> = members.get().into_iter()
+                                    .filter(|m| m.user.id != owner_id)
+                                    .map(|m| (m.user.id.to_string(), m.user.username))
+                                    .collect();
                                 let available = user_reward.amount - user_reward.redeemed_amount - user_reward.pending_redemption;
                                 let pending = user_reward.pending_redemption;
                                 view! {
@@ -282,6 +344,28 @@ pub fn RewardsPage() -> impl IntoView {
                                                 ().into_view()
                                             }}
                                         </div>
+                                        <div style="display: flex; gap: 0.5rem; align-items: center;">
+                                        {if available > 0 && !gift_targets.is_empty() {
+                                            view! {
+                                                <select
+                                                    class="form-select"
+                                                    style="padding: 0.25rem 0.5rem; font-size: 0.875rem; width: auto;"
+                                                    on:change=move |ev| {
+                                                        let to_user_id = event_target_value(&ev);
+                                                        if !to_user_id.is_empty() {
+                                                            on_gift(gift_id.clone(), to_user_id);
+                                                        }
+                                                    }
+                                                >
+                                                    <option value="" selected=true>{i18n_stored.get_value().t("rewards.gift_to")}</option>
+                                                    {gift_targets.into_iter().map(|(user_id, username)| view! {
+                                                        <option value=user_id>{username}</option>
+                                                    }).collect_view()}
+                                                </select>
+                                            }.into_view()
+                                        } else {
+                                            ().into_view()
+                                        }}
                                         {if pending > 0 {
                                             view! {
                                                 <span class="badge" style="background: var(--warning-color); color: white;">{i18n_stored.get_value().t("rewards.awaiting_confirmation")}</span>
@@ -307,6 +391,7 @@ pub fn RewardsPage() -> impl IntoView {
                                                 </button>
                                             }.into_view()
                                         }}
+                                        </div>
                                     </div>
                                 }
                             }).collect_view()
@@ -343,6 +428,7 @@ pub fn RewardsPage() -> impl IntoView {
                                 let reward_id = reward.id;
                                 let reward_id_str = reward_id.to_string();
                                 let purchase_id = reward_id_str.clone();
+                                let wishlist_id = reward_id_str.clone();
                                 let delete_id = reward_id_str.clone();
 
                                 // Get user assignments for this reward - show only open (unredeemed) count
@@ -396,6 +482,29 @@ pub fn RewardsPage() -> impl IntoView {
                                                         {i18n_stored.get_value().t("rewards.purchase")}
                                                     </button>
                                                 </div>
+                                                {move || {
+                                                    let item = wishlist.with(|w| w.iter().find(|item| item.reward.id == reward_id).cloned());
+                                                    let on_wishlist = item.is_some();
+                                                    let toggle_id = wishlist_id.clone();
+                                                    let i18n = i18n_stored.get_value();
+                                                    view! {
+                                                        <div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 0.5rem; font-size: 0.75rem;">
+                                                            <span style="color: var(--text-muted);">
+                                                                {item.map(|item| if item.points_needed > 0 {
+                                                                    i18n.t_with("rewards.wishlist_points_needed", &[("points", &item.points_needed.to_string())])
+                                                                } else {
+                                                                    i18n.t("rewards.wishlist_affordable")
+                                                                })}
+                                                            </span>
+                                                            <button
+                                                                class="btn btn-outline btn-sm"
+                                                                on:click=move |_| on_toggle_wishlist(toggle_id.clone(), on_wishlist)
+                                                            >
+                                                                {if on_wishlist { i18n.t("rewards.wishlist_remove") } else { i18n.t("rewards.wishlist_add") }}
+                                                            </button>
+                                                        </div>
+                                                    }
+                                                }}
                                             }.into_view()
                                         } else {
                                             view! {
@@ -540,21 +649,6 @@ pub fn RewardsPage() -> impl IntoView {
     }
 }
 
-/// Apply dark mode class to document body
-fn apply_dark_mode(enabled: bool) {
-    if let Some(window) = web_sys::window() {
-        if let Some(document) = window.document() {
-            if let Some(body) = document.body() {
-                if enabled {
-                    let _ = body.class_list().add_1("dark-mode");
-                } else {
-                    let _ = body.class_list().remove_1("dark-mode");
-                }
-            }
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use uuid::Uuid;
@@ -0,0 +1,127 @@
+//! Read-only page behind a share link (`/shared/{token}`), for guests without an account
+
+use chrono::Duration;
+use leptos::*;
+use leptos_router::*;
+use shared::{MealSlot, SharedContent, SharedPage, ShoppingListItem, WeeklyMealPlan};
+
+use crate::api::api_client;
+use crate::components::loading::Loading;
+use crate::i18n::use_i18n;
+use crate::pages::meals::slot_key;
+use crate::pages::shopping_list::format_amount;
+
+#[component]
+fn SharedShoppingList(items: Vec<ShoppingListItem>) -> impl IntoView {
+    let i18n = use_i18n();
+    if items.is_empty() {
+        return view! { <p class="empty-state">{i18n.t("shopping.empty")}</p> }.into_view();
+    }
+    view! {
+        <ul class="shopping-list-items">
+            {items.into_iter().map(|item| {
+                let amount = format_amount(item.quantity, item.unit.as_deref());
+                view! {
+                    <li class="shopping-list-item" class:shopping-list-checked=item.is_checked>
+                        <label>
+                            <span class="shopping-list-name">{item.name}</span>
+                            {amount.map(|a| view! { <span class="shopping-list-amount">{a}</span> })}
+                        </label>
+                    </li>
+                }
+            }).collect_view()}
+        </ul>
+    }
+    .into_view()
+}
+
+#[component]
+fn SharedMealPlan(plan: WeeklyMealPlan) -> impl IntoView {
+    let i18n = use_i18n();
+    view! {
+        <table class="meal-plan-grid">
+            <thead>
+                <tr>
+                    <th></th>
+                    {MealSlot::ALL.into_iter().map(|slot| view! { <th>{i18n.t(&slot_key(slot))}</th> }).collect_view()}
+                </tr>
+            </thead>
+            <tbody>
+                {(0..7).map(|offset| {
+                    let day = plan.week_start + Duration::days(offset);
+                    view! {
+                        <tr>
+                            <th>{day.format("%a %d.%m.").to_string()}</th>
+                            {MealSlot::ALL.into_iter().map(|slot| {
+                                let label = plan
+                                    .entries
+                                    .iter()
+                                    .find(|e| e.plan_date == day && e.slot == slot)
+                                    .and_then(|e| e.title.clone())
+                                    .unwrap_or_default();
+                                view! { <td class="meal-plan-cell">{label}</td> }
+                            }).collect_view()}
+                        </tr>
+                    }
+                }).collect_view()}
+            </tbody>
+        </table>
+    }
+}
+
+#[component]
+pub fn SharedPageView() -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let params = use_params_map();
+    let token = move || params.with(|p| p.get("token").cloned().unwrap_or_default());
+
+    let page = create_rw_signal(Option::<SharedPage>::None);
+    let error = create_rw_signal(Option::<String>::None);
+    let loading = create_rw_signal(true);
+
+    create_effect(move |_| {
+        let token = token();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().get_shared_page(&token).await {
+                Ok(shared) => page.set(Some(shared)),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            loading.set(false);
+        });
+    });
+
+    view! {
+        <div class="legal-page">
+            <div class="legal-page-content card">
+                {move || {
+                    let i18n = i18n_stored.get_value();
+                    if loading.get() {
+                        return view! { <Loading /> }.into_view();
+                    }
+                    if let Some(e) = error.get() {
+                        return view! { <div class="alert alert-error">{e}</div> }.into_view();
+                    }
+                    let Some(shared) = page.get() else {
+                        return ().into_view();
+                    };
+                    let (title_key, content) = match shared.content {
+                        SharedContent::ShoppingList(items) => {
+                            ("shopping.title", view! { <SharedShoppingList items=items /> }.into_view())
+                        }
+                        SharedContent::MealPlan(plan) => {
+                            ("meals.title", view! { <SharedMealPlan plan=plan /> }.into_view())
+                        }
+                    };
+                    view! {
+                        <p class="shared-page-household">{shared.household_name}</p>
+                        <h1 class="legal-page-title">{i18n.t(title_key)}</h1>
+                        {content}
+                        <p class="form-hint shared-page-hint">{i18n.t("share_links.read_only")}</p>
+                    }.into_view()
+                }}
+            </div>
+        </div>
+    }
+}
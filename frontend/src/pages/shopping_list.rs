@@ -0,0 +1,225 @@
+use leptos::*;
+use leptos_router::*;
+use shared::{CreateShoppingListItemRequest, ShareResource, ShoppingListItem, UpdateShoppingListItemRequest};
+use uuid::Uuid;
+
+use crate::api::api_client;
+use crate::components::loading::Loading;
+use crate::components::share_links_modal::ShareLinksModal;
+use crate::i18n::use_i18n;
+use crate::pages::meals::parse_ingredient_line;
+
+/// Render an item's amount, e.g. "1.5 kg" or "3"; merged quantities are rounded to two decimals
+pub fn format_amount(quantity: Option<f64>, unit: Option<&str>) -> Option<String> {
+    let quantity = quantity.map(|q| {
+        let rounded = format!("{:.2}", q);
+        rounded
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    });
+    match (quantity, unit) {
+        (Some(q), Some(u)) => Some(format!("{} {}", q, u)),
+        (Some(q), None) => Some(q),
+        (None, Some(u)) => Some(u.to_string()),
+        (None, None) => None,
+    }
+}
+
+#[component]
+pub fn ShoppingListPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let params = use_params_map();
+    let household_id = move || params.with(|p| p.get("id").cloned().unwrap_or_default());
+
+    let items = create_rw_signal(Vec::<ShoppingListItem>::new());
+    let loading = create_rw_signal(true);
+    let error = create_rw_signal(Option::<String>::None);
+    let new_item = create_rw_signal(String::new());
+    let show_share_modal = create_rw_signal(false);
+
+    let load_items = move || {
+        let id = household_id();
+        if id.is_empty() {
+            return;
+        }
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().list_shopping_list(&id).await {
+                Ok(list) => items.set(list),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            loading.set(false);
+        });
+    };
+
+    create_effect(move |_| {
+        let _ = household_id();
+        load_items();
+    });
+
+    let on_add = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        let Some(parsed) = parse_ingredient_line(&new_item.get()) else {
+            return;
+        };
+        let request = CreateShoppingListItemRequest {
+            name: parsed.name,
+            quantity: parsed.quantity,
+            unit: parsed.unit,
+        };
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().add_shopping_list_item(&id, request).await {
+                Ok(_) => {
+                    new_item.set(String::new());
+                    error.set(None);
+                    // Reload since the item may have been merged into an existing one
+                    load_items();
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_toggle = move |item_id: Uuid, checked: bool| {
+        let id = household_id();
+        let request = UpdateShoppingListItemRequest {
+            is_checked: Some(checked),
+            ..Default::default()
+        };
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().update_shopping_list_item(&id, &item_id.to_string(), request).await {
+                Ok(updated) => items.update(|list| {
+                    if let Some(item) = list.iter_mut().find(|i| i.id == item_id) {
+                        *item = updated;
+                    }
+                }),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_delete = move |item_id: Uuid| {
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().delete_shopping_list_item(&id, &item_id.to_string()).await {
+                Ok(()) => items.update(|list| list.retain(|i| i.id != item_id)),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_clear_checked = move |_| {
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().clear_checked_shopping_items(&id).await {
+                Ok(()) => items.update(|list| list.retain(|i| !i.is_checked)),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let has_checked = move || items.with(|list| list.iter().any(|i| i.is_checked));
+
+    view! {
+        <div class="dashboard-header">
+            <h1 class="dashboard-title">{i18n_stored.get_value().t("shopping.title")}</h1>
+            <div style="display: flex; gap: 0.5rem;">
+                <Show when=has_checked fallback=|| ()>
+                    <button class="btn btn-outline btn-sm" on:click=on_clear_checked>
+                        {i18n_stored.get_value().t("shopping.clear_checked")}
+                    </button>
+                </Show>
+                <button class="btn btn-outline btn-sm" on:click=move |_| show_share_modal.set(true)>
+                    {i18n_stored.get_value().t("share_links.button")}
+                </button>
+            </div>
+        </div>
+
+        <Show when=move || show_share_modal.get() fallback=|| ()>
+            <ShareLinksModal
+                household_id=household_id()
+                resource=ShareResource::ShoppingList
+                on_close=move |_| show_share_modal.set(false)
+            />
+        </Show>
+
+        {move || error.get().map(|e| view! {
+            <div class="alert alert-error">{e}</div>
+        })}
+
+        <div class="card shopping-list">
+            <form class="shopping-list-form" on:submit=on_add>
+                <input
+                    type="text"
+                    class="form-input"
+                    placeholder=i18n_stored.get_value().t("shopping.add_placeholder")
+                    prop:value=move || new_item.get()
+                    on:input=move |ev| new_item.set(event_target_value(&ev))
+                />
+                <button type="submit" class="btn btn-primary">
+                    {i18n_stored.get_value().t("shopping.add")}
+                </button>
+            </form>
+
+            <Show when=move || loading.get() fallback=|| ()>
+                <Loading />
+            </Show>
+
+            {move || {
+                let list = items.get();
+                if list.is_empty() && !loading.get() {
+                    return view! {
+                        <p class="empty-state">{i18n_stored.get_value().t("shopping.empty")}</p>
+                    }.into_view();
+                }
+                view! {
+                    <ul class="shopping-list-items">
+                        {list.into_iter().map(|item| {
+                            let item_id = item.id;
+                            let checked = item.is_checked;
+                            let amount = format_amount(item.quantity, item.unit.as_deref());
+                            view! {
+                                <li class="shopping-list-item" class:shopping-list-checked=checked>
+                                    <label>
+                                        <input
+                                            type="checkbox"
+                                            prop:checked=checked
+                                            on:change=move |_| on_toggle(item_id, !checked)
+                                        />
+                                        <span class="shopping-list-name">{item.name}</span>
+                                        {amount.map(|a| view! {
+                                            <span class="shopping-list-amount">{a}</span>
+                                        })}
+                                    </label>
+                                    <button
+                                        class="shopping-list-remove"
+                                        title=i18n_stored.get_value().t("common.delete")
+                                        on:click=move |_| on_delete(item_id)
+                                    >"×"</button>
+                                </li>
+                            }
+                        }).collect_view()}
+                    </ul>
+                }.into_view()
+            }}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_format_amount() {
+        assert_eq!(format_amount(Some(1.5), Some("kg")), Some("1.5 kg".to_string()));
+        assert_eq!(format_amount(Some(3.0), None), Some("3".to_string()));
+        assert_eq!(format_amount(Some(0.1 + 0.2), Some("l")), Some("0.3 l".to_string()));
+        assert_eq!(format_amount(None, Some("pinch")), Some("pinch".to_string()));
+        assert_eq!(format_amount(None, None), None);
+    }
+}
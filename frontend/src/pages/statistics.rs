@@ -1,23 +1,30 @@
 use chrono::NaiveDate;
 use leptos::*;
 use leptos_router::*;
-use shared::{HouseholdSettings, MemberStatistic, MonthlyStatisticsResponse, WeeklyStatisticsResponse};
+use shared::{
+    HouseholdReport, HouseholdSettings, MemberStatistic, MonthlyStatisticsResponse, MoodPoint, RebalanceRequest,
+    RebalanceSuggestion, WeeklyStatisticsResponse, WorkloadReport, MOOD_MAX, MOOD_MIN,
+};
 
-use crate::api::ApiClient;
+use crate::api::api_client;
+use crate::components::journal_entry_card::mood_emoji;
 use crate::components::loading::Loading;
 use crate::components::{
     Accordion, Alert, AlertVariant, Button, ButtonVariant, Card, ProgressBar,
 };
+use crate::components::theme::use_theme;
 use crate::i18n::use_i18n;
 
 #[derive(Clone, Copy, PartialEq)]
 enum StatisticsView {
     Weekly,
     Monthly,
+    Report,
 }
 
 #[component]
 pub fn StatisticsPage() -> impl IntoView {
+    let theme = use_theme();
     let i18n = use_i18n();
     let i18n_stored = store_value(i18n);
 
@@ -29,7 +36,7 @@ pub fn StatisticsPage() -> impl IntoView {
     let calculating = create_rw_signal(false);
     let error = create_rw_signal(Option::<String>::None);
 
-    // Current view (weekly or monthly)
+    // Current view (weekly, monthly or report)
     let current_view = create_rw_signal(StatisticsView::Weekly);
 
     // Weekly state
@@ -42,6 +49,14 @@ pub fn StatisticsPage() -> impl IntoView {
     let available_months = create_rw_signal(Vec::<NaiveDate>::new());
     let selected_month = create_rw_signal(Option::<NaiveDate>::None);
 
+    // Report state; empty dates let the backend pick the current month
+    let report = create_rw_signal(Option::<HouseholdReport>::None);
+    let report_start = create_rw_signal(String::new());
+    let report_end = create_rw_signal(String::new());
+    let report_loading = create_rw_signal(false);
+    let mood = create_rw_signal(Vec::<MoodPoint>::new());
+    let workload = create_rw_signal(Option::<WorkloadReport>::None);
+
     // Load settings
     create_effect(move |_| {
         let id = household_id();
@@ -51,8 +66,8 @@ pub fn StatisticsPage() -> impl IntoView {
 
         let id_clone = id.clone();
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(s) = ApiClient::get_household_settings(&id_clone).await {
-                apply_dark_mode(s.dark_mode);
+            if let Ok(s) = api_client().get_household_settings(&id_clone).await {
+                theme.set_household_dark_mode(s.dark_mode);
                 settings.set(Some(s));
             }
         });
@@ -62,7 +77,7 @@ pub fn StatisticsPage() -> impl IntoView {
         let id_for_months = id.clone();
 
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(weeks) = ApiClient::list_available_weeks(&id_for_weeks).await {
+            if let Ok(weeks) = api_client().list_available_weeks(&id_for_weeks).await {
                 available_weeks.set(weeks.clone());
                 if let Some(first) = weeks.first() {
                     selected_week.set(Some(*first));
@@ -72,7 +87,7 @@ pub fn StatisticsPage() -> impl IntoView {
         });
 
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(months) = ApiClient::list_available_months(&id_for_months).await {
+            if let Ok(months) = api_client().list_available_months(&id_for_months).await {
                 available_months.set(months.clone());
                 if let Some(first) = months.first() {
                     selected_month.set(Some(*first));
@@ -92,9 +107,9 @@ pub fn StatisticsPage() -> impl IntoView {
             let id_clone = id.clone();
             let week_str = week.format("%Y-%m-%d").to_string();
             wasm_bindgen_futures::spawn_local(async move {
-                match ApiClient::get_weekly_statistics(&id_clone, Some(&week_str)).await {
+                match api_client().get_weekly_statistics(&id_clone, Some(&week_str)).await {
                     Ok(stats) => weekly_stats.set(Some(stats)),
-                    Err(e) => error.set(Some(e)),
+                    Err(e) => error.set(Some(e.to_string())),
                 }
             });
         }
@@ -110,14 +125,50 @@ pub fn StatisticsPage() -> impl IntoView {
             let id_clone = id.clone();
             let month_str = month.format("%Y-%m-%d").to_string();
             wasm_bindgen_futures::spawn_local(async move {
-                match ApiClient::get_monthly_statistics(&id_clone, Some(&month_str)).await {
+                match api_client().get_monthly_statistics(&id_clone, Some(&month_str)).await {
                     Ok(stats) => monthly_stats.set(Some(stats)),
-                    Err(e) => error.set(Some(e)),
+                    Err(e) => error.set(Some(e.to_string())),
                 }
             });
         }
     });
 
+    let load_report = move || {
+        let id = household_id();
+        let start = report_start.get_untracked();
+        let end = report_end.get_untracked();
+        report_loading.set(true);
+        error.set(None);
+        wasm_bindgen_futures::spawn_local(async move {
+            let start = (!start.is_empty()).then_some(start);
+            let end = (!end.is_empty()).then_some(end);
+            match api_client().get_household_report(&id, start.as_deref(), end.as_deref()).await {
+                Ok(r) => {
+                    let start = r.start_date.format("%Y-%m-%d").to_string();
+                    let end = r.end_date.format("%Y-%m-%d").to_string();
+                    // The caller's mood from the journal, over the same range
+                    mood.set(api_client().get_mood_over_time(&id, Some(&start), Some(&end)).await.unwrap_or_default());
+                    workload.set(api_client().get_workload_report(&id, Some(&start), Some(&end)).await.ok());
+                    report_start.set(start);
+                    report_end.set(end);
+                    report.set(Some(r));
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            report_loading.set(false);
+        });
+    };
+
+    // Load the default report the first time the tab is opened
+    create_effect(move |_| {
+        if current_view.get() == StatisticsView::Report
+            && report.get_untracked().is_none()
+            && !household_id().is_empty()
+        {
+            load_report();
+        }
+    });
+
     // Calculate statistics action
     let on_calculate = move |_| {
         let id = household_id();
@@ -129,15 +180,15 @@ pub fn StatisticsPage() -> impl IntoView {
             StatisticsView::Weekly => {
                 let week = selected_week.get().map(|w| w.format("%Y-%m-%d").to_string());
                 wasm_bindgen_futures::spawn_local(async move {
-                    match ApiClient::calculate_weekly_statistics(&id, week.as_deref()).await {
+                    match api_client().calculate_weekly_statistics(&id, week.as_deref()).await {
                         Ok(stats) => {
                             weekly_stats.set(Some(stats));
                             // Refresh available weeks
-                            if let Ok(weeks) = ApiClient::list_available_weeks(&id).await {
+                            if let Ok(weeks) = api_client().list_available_weeks(&id).await {
                                 available_weeks.set(weeks);
                             }
                         }
-                        Err(e) => error.set(Some(e)),
+                        Err(e) => error.set(Some(e.to_string())),
                     }
                     calculating.set(false);
                 });
@@ -145,19 +196,21 @@ pub fn StatisticsPage() -> impl IntoView {
             StatisticsView::Monthly => {
                 let month = selected_month.get().map(|m| m.format("%Y-%m-%d").to_string());
                 wasm_bindgen_futures::spawn_local(async move {
-                    match ApiClient::calculate_monthly_statistics(&id, month.as_deref()).await {
+                    match api_client().calculate_monthly_statistics(&id, month.as_deref()).await {
                         Ok(stats) => {
                             monthly_stats.set(Some(stats));
                             // Refresh available months
-                            if let Ok(months) = ApiClient::list_available_months(&id).await {
+                            if let Ok(months) = api_client().list_available_months(&id).await {
                                 available_months.set(months);
                             }
                         }
-                        Err(e) => error.set(Some(e)),
+                        Err(e) => error.set(Some(e.to_string())),
                     }
                     calculating.set(false);
                 });
             }
+            // Reports are computed on request, nothing to store
+            StatisticsView::Report => calculating.set(false),
         }
     };
 
@@ -191,11 +244,46 @@ pub fn StatisticsPage() -> impl IntoView {
                         >
                             {i18n_stored.get_value().t("statistics.monthly")}
                         </Button>
+                        <Button
+                            variant=MaybeSignal::derive(move || if current_view.get() == StatisticsView::Report { ButtonVariant::Primary } else { ButtonVariant::Secondary })
+                            on_click=Callback::new(move |_| current_view.set(StatisticsView::Report))
+                        >
+                            {i18n_stored.get_value().t("statistics.report")}
+                        </Button>
                     </div>
 
                     // Period selector
                     {move || {
-                        if current_view.get() == StatisticsView::Weekly {
+                        if current_view.get() == StatisticsView::Report {
+                            view! {
+                                <label style="display: flex; gap: 0.5rem; align-items: center;">
+                                    {i18n_stored.get_value().t("statistics.report_from")}
+                                    <input
+                                        type="date"
+                                        class="form-input"
+                                        style="width: auto;"
+                                        prop:value=move || report_start.get()
+                                        on:change=move |ev| report_start.set(event_target_value(&ev))
+                                    />
+                                </label>
+                                <label style="display: flex; gap: 0.5rem; align-items: center;">
+                                    {i18n_stored.get_value().t("statistics.report_to")}
+                                    <input
+                                        type="date"
+                                        class="form-input"
+                                        style="width: auto;"
+                                        prop:value=move || report_end.get()
+                                        on:change=move |ev| report_end.set(event_target_value(&ev))
+                                    />
+                                </label>
+                                <Button
+                                    disabled=MaybeSignal::derive(move || report_loading.get())
+                                    on_click=Callback::new(move |_| load_report())
+                                >
+                                    {i18n_stored.get_value().t("statistics.report_load")}
+                                </Button>
+                            }.into_view()
+                        } else if current_view.get() == StatisticsView::Weekly {
                             let weeks = available_weeks.get();
                             view! {
                                 <select
@@ -250,22 +338,37 @@ pub fn StatisticsPage() -> impl IntoView {
                         }
                     }}
 
-                    <Button
-                        disabled=MaybeSignal::derive(move || calculating.get())
-                        on_click=Callback::new(on_calculate)
-                    >
-                        {move || if calculating.get() {
-                            i18n_stored.get_value().t("statistics.calculating")
-                        } else {
-                            i18n_stored.get_value().t("statistics.calculate")
-                        }}
-                    </Button>
+                    <Show when=move || current_view.get() != StatisticsView::Report fallback=|| ()>
+                        <Button
+                            disabled=MaybeSignal::derive(move || calculating.get())
+                            on_click=Callback::new(on_calculate)
+                        >
+                            {move || if calculating.get() {
+                                i18n_stored.get_value().t("statistics.calculating")
+                            } else {
+                                i18n_stored.get_value().t("statistics.calculate")
+                            }}
+                        </Button>
+                    </Show>
                 </div>
             </Card>
 
             // Statistics display
             {move || {
-                if current_view.get() == StatisticsView::Weekly {
+                if current_view.get() == StatisticsView::Report {
+                    if let Some(r) = report.get() {
+                        view! {
+                            <ReportView report=r mood=mood.get() workload=workload.get() i18n=i18n_stored />
+                            <RebalancePanel
+                                household_id=household_id()
+                                i18n=i18n_stored
+                                on_applied=Callback::new(move |_| load_report())
+                            />
+                        }.into_view()
+                    } else {
+                        view! { <Loading /> }.into_view()
+                    }
+                } else if current_view.get() == StatisticsView::Weekly {
                     if let Some(stats) = weekly_stats.get() {
                         view! { <WeeklyStatsView stats=stats i18n=i18n_stored /> }.into_view()
                     } else {
@@ -345,6 +448,246 @@ fn MonthlyStatsView(
     }
 }
 
+#[component]
+fn ReportView(
+    report: HouseholdReport,
+    mood: Vec<MoodPoint>,
+    workload: Option<WorkloadReport>,
+    i18n: StoredValue<crate::i18n::I18nContext>,
+) -> impl IntoView {
+    let title = format!(
+        "{} - {}",
+        report.start_date.format("%d.%m.%Y"),
+        report.end_date.format("%d.%m.%Y")
+    );
+    let t = move |key: &str| i18n.get_value().t(key);
+
+    view! {
+        <Card title=title>
+            <div style="display: flex; justify-content: space-between; margin-bottom: 0.5rem;">
+                <strong>{t("statistics.report_overall")}</strong>
+                <span style=format!("color: {}; font-weight: bold;", rate_color(report.completion_rate))>
+                    {format!("{:.1}%", report.completion_rate)}
+                </span>
+            </div>
+            <div style="font-size: 0.9em; color: var(--text-muted); margin-bottom: 0.5rem;">
+                {t("statistics.completed")} ": "
+                {report.total_completed} " / " {report.total_expected}
+            </div>
+            <div style="margin-bottom: 1rem;">
+                <ProgressBar value=report.completion_rate />
+            </div>
+            <div style="display: flex; justify-content: space-between;">
+                <strong>{t("statistics.report_points")}</strong>
+                <span>{report.total_points_earned}</span>
+            </div>
+        </Card>
+
+        <Card title=t("statistics.report_members") style="margin-top: 1rem;">
+            {if report.members.is_empty() {
+                view! { <p>{t("statistics.no_member_data")}</p> }.into_view()
+            } else {
+                report.members.into_iter().map(|member| view! {
+                    <div style="padding: 0.5rem 0; border-bottom: 1px solid var(--border-color);">
+                        <div style="display: flex; justify-content: space-between;">
+                            <strong>{&member.username}</strong>
+                            <span style=format!("color: {}; font-weight: bold;", rate_color(member.completion_rate))>
+                                {format!("{:.1}%", member.completion_rate)}
+                            </span>
+                        </div>
+                        <div style="font-size: 0.9em; color: var(--text-muted);">
+                            {member.completed} " / " {member.expected}
+                            " · " {t("statistics.report_points")} ": " {member.points_earned}
+                        </div>
+                    </div>
+                }).collect_view()
+            }}
+        </Card>
+
+        <Card title=t("statistics.report_most_failed") style="margin-top: 1rem;">
+            {if report.most_failed_tasks.is_empty() {
+                view! { <p>{t("statistics.report_no_failures")}</p> }.into_view()
+            } else {
+                report.most_failed_tasks.into_iter().map(|task| view! {
+                    <div style="display: flex; justify-content: space-between; padding: 0.5rem 0; border-bottom: 1px solid var(--border-color);">
+                        <span>{&task.task_title}</span>
+                        <span style="color: var(--danger-color);">{task.failed} " / " {task.expected}</span>
+                    </div>
+                }).collect_view()
+            }}
+        </Card>
+
+        <Card title=t("statistics.report_busiest_days") style="margin-top: 1rem;">
+            {report.busiest_weekdays.into_iter().map(|day| view! {
+                <div style="display: flex; justify-content: space-between; padding: 0.5rem 0; border-bottom: 1px solid var(--border-color);">
+                    <span>{t(weekday_key(day.weekday))}</span>
+                    <span>{day.completions} " " {t("statistics.report_completions")}</span>
+                </div>
+            }).collect_view()}
+        </Card>
+
+        {workload.map(|workload| {
+            let max_minutes = workload.members.iter().map(|m| m.effort_minutes).max().unwrap_or(0);
+            view! {
+                <Card title=t("statistics.workload") style="margin-top: 1rem;">
+                    {workload.members.into_iter().map(|member| view! {
+                        <div style="padding: 0.5rem 0; border-bottom: 1px solid var(--border-color);">
+                            <div style="display: flex; justify-content: space-between;">
+                                <strong>{&member.username}</strong>
+                                <span>{format_minutes(member.effort_minutes)}</span>
+                            </div>
+                            <ProgressBar value=workload_percent(member.effort_minutes, max_minutes) />
+                            <div style="font-size: 0.9em; color: var(--text-muted);">
+                                {member.task_count} " " {t("statistics.workload_tasks")}
+                                {(member.unestimated_count > 0).then(|| view! {
+                                    " · " {member.unestimated_count} " " {t("statistics.workload_unestimated")}
+                                })}
+                            </div>
+                        </div>
+                    }).collect_view()}
+                    {(workload.unassigned_count > 0).then(|| view! {
+                        <div style="font-size: 0.9em; color: var(--text-muted); margin-top: 0.5rem;">
+                            {t("statistics.workload_unassigned")} ": "
+                            {workload.unassigned_count} " " {t("statistics.workload_tasks")}
+                            " · " {format_minutes(workload.unassigned_minutes)}
+                        </div>
+                    })}
+                </Card>
+            }
+        })}
+
+        <Card title=t("statistics.mood") style="margin-top: 1rem;">
+            {if mood.is_empty() {
+                view! { <p>{t("statistics.mood_empty")}</p> }.into_view()
+            } else {
+                mood.into_iter().map(|point| view! {
+                    <div class="mood-row">
+                        <span class="mood-date">{point.date.format("%d.%m.").to_string()}</span>
+                        <div class="mood-bar">
+                            <ProgressBar value=mood_percent(point.average_mood) />
+                        </div>
+                        <span>{mood_emoji(point.average_mood.round() as i32)} " " {format!("{:.1}", point.average_mood)}</span>
+                    </div>
+                }).collect_view()
+            }}
+        </Card>
+    }
+}
+
+/// Suggests fairer task assignments for the coming weeks and applies them on request
+#[component]
+fn RebalancePanel(
+    household_id: String,
+    i18n: StoredValue<crate::i18n::I18nContext>,
+    on_applied: Callback<()>,
+) -> impl IntoView {
+    let household_id = store_value(household_id);
+    let suggestion = create_rw_signal(Option::<RebalanceSuggestion>::None);
+    let busy = create_rw_signal(false);
+    let error = create_rw_signal(Option::<String>::None);
+    let t = move |key: &str| i18n.get_value().t(key);
+
+    let suggest = move |_| {
+        busy.set(true);
+        error.set(None);
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().suggest_rebalance(&household_id.get_value(), RebalanceRequest::default()).await {
+                Ok(s) => suggestion.set(Some(s)),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            busy.set(false);
+        });
+    };
+
+    let apply = move |_| {
+        let Some(s) = suggestion.get_untracked() else {
+            return;
+        };
+        busy.set(true);
+        error.set(None);
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().apply_rebalance(&household_id.get_value(), s.changes).await {
+                Ok(_) => {
+                    suggestion.set(None);
+                    on_applied.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            busy.set(false);
+        });
+    };
+
+    view! {
+        <Card title=t("statistics.rebalance") style="margin-top: 1rem;">
+            <p style="font-size: 0.9em; color: var(--text-muted);">{t("statistics.rebalance_hint")}</p>
+            {move || error.get().map(|e| view! { <Alert variant=AlertVariant::Error>{e}</Alert> })}
+            {move || match suggestion.get() {
+                None => view! {
+                    <Button disabled=MaybeSignal::derive(move || busy.get()) on_click=Callback::new(suggest)>
+                        {t("statistics.rebalance_suggest")}
+                    </Button>
+                }.into_view(),
+                Some(s) if s.changes.is_empty() => view! { <p>{t("statistics.rebalance_balanced")}</p> }.into_view(),
+                Some(s) => {
+                    let username = |user_id| {
+                        s.members
+                            .iter()
+                            .find(|m| m.user_id == user_id)
+                            .map(|m| m.username.clone())
+                            .unwrap_or_default()
+                    };
+                    let changes = s.changes.iter().map(|change| view! {
+                        <div style="padding: 0.25rem 0;">
+                            <strong>{change.task_title.clone()}</strong> ": "
+                            {username(change.from_user_id)} " → " {username(change.to_user_id)}
+                        </div>
+                    }).collect_view();
+                    view! {
+                        {changes}
+                        {s.members.iter().map(|member| view! {
+                            <div style="display: flex; justify-content: space-between; font-size: 0.9em; color: var(--text-muted);">
+                                <span>{member.username.clone()}</span>
+                                <span>{format_minutes(member.current_minutes)} " → " {format_minutes(member.proposed_minutes)}</span>
+                            </div>
+                        }).collect_view()}
+                        <Button
+                            variant=ButtonVariant::Primary
+                            disabled=MaybeSignal::derive(move || busy.get())
+                            on_click=Callback::new(apply)
+                        >
+                            {t("statistics.rebalance_apply")}
+                        </Button>
+                    }.into_view()
+                }
+            }}
+        </Card>
+    }
+}
+
+/// A member's effort relative to the busiest member, as a percentage for a progress bar
+fn workload_percent(minutes: i64, max_minutes: i64) -> f32 {
+    if max_minutes > 0 {
+        minutes as f32 / max_minutes as f32 * 100.0
+    } else {
+        0.0
+    }
+}
+
+/// Minutes as "2 h 05 min", or "45 min" below an hour
+fn format_minutes(minutes: i64) -> String {
+    if minutes >= 60 {
+        format!("{} h {:02} min", minutes / 60, minutes % 60)
+    } else {
+        format!("{} min", minutes)
+    }
+}
+
+/// Position of an average mood on the scale, as a percentage for a progress bar
+fn mood_percent(average_mood: f32) -> f32 {
+    let range = (MOOD_MAX - MOOD_MIN) as f32;
+    ((average_mood - MOOD_MIN as f32) / range * 100.0).clamp(0.0, 100.0)
+}
+
 #[component]
 fn MemberStatsCard(
     member: MemberStatistic,
@@ -413,6 +756,29 @@ fn MemberStatsCard(
     }
 }
 
+fn rate_color(rate: f32) -> &'static str {
+    if rate >= 80.0 {
+        "var(--success-color)"
+    } else if rate >= 50.0 {
+        "var(--warning-color)"
+    } else {
+        "var(--danger-color)"
+    }
+}
+
+/// Translation key for a weekday number (0 = Monday)
+fn weekday_key(weekday: u32) -> &'static str {
+    match weekday {
+        0 => "weekday.monday",
+        1 => "weekday.tuesday",
+        2 => "weekday.wednesday",
+        3 => "weekday.thursday",
+        4 => "weekday.friday",
+        5 => "weekday.saturday",
+        _ => "weekday.sunday",
+    }
+}
+
 fn format_week_display(week_start: &NaiveDate) -> String {
     let week_end = *week_start + chrono::Duration::days(6);
     format!(
@@ -426,16 +792,3 @@ fn format_month_display(month: &NaiveDate) -> String {
     month.format("%B %Y").to_string()
 }
 
-fn apply_dark_mode(enabled: bool) {
-    if let Some(window) = web_sys::window() {
-        if let Some(document) = window.document() {
-            if let Some(body) = document.body() {
-                if enabled {
-                    let _ = body.class_list().add_1("dark-mode");
-                } else {
-                    let _ = body.class_list().remove_1("dark-mode");
-                }
-            }
-        }
-    }
-}
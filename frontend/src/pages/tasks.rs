@@ -2,9 +2,9 @@ use std::collections::HashSet;
 
 use leptos::*;
 use leptos_router::*;
-use shared::{HouseholdSettings, MemberWithUser, Punishment, Reward, Task, TaskCategory, TaskPunishmentLink, TaskRewardLink};
+use shared::{HabitType, HouseholdSettings, MemberWithUser, Punishment, Reward, Task, TaskCategory, TaskFilter, TaskPunishmentLink, TaskRewardLink};
 
-use crate::api::ApiClient;
+use crate::api::api_client;
 use crate::components::category_modal::CategoryModal;
 use crate::utils::TaskModalData;
 use crate::components::context_menu::{ContextMenu, ContextMenuAction};
@@ -13,11 +13,14 @@ use crate::components::markdown::MarkdownView;
 use crate::components::pending_reviews::PendingReviews;
 use crate::components::pending_suggestions::PendingSuggestions;
 use crate::components::task_detail_modal::TaskDetailModal;
+use crate::components::task_filter_bar::TaskFilterBar;
 use crate::components::task_modal::TaskModal;
+use crate::components::theme::use_theme;
 use crate::i18n::use_i18n;
 
 #[component]
 pub fn TasksPage() -> impl IntoView {
+    let theme = use_theme();
     let i18n = use_i18n();
     let i18n_stored = store_value(i18n);
 
@@ -59,30 +62,39 @@ pub fn TasksPage() -> impl IntoView {
     let selected_task_ids = create_rw_signal(HashSet::<String>::new());
     let show_bulk_edit_modal = create_rw_signal(false);
 
-    // Load tasks and supporting data
+    // Smart list state: the task list is filtered server-side
+    let active_filter = create_rw_signal(TaskFilter::default());
+
+    // Load tasks, again whenever the filter changes
     create_effect(move |_| {
         let id = household_id();
+        let filter = active_filter.get();
         if id.is_empty() {
             return;
         }
-
-        let id_for_tasks = id.clone();
-        let id_for_modal_data = id.clone();
-        let id_for_settings = id.clone();
-
-        // Load tasks
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::list_tasks(&id_for_tasks).await {
+            match api_client().list_filtered_tasks(&id, &filter).await {
                 Ok(t) => {
                     tasks.set(t);
                     loading.set(false);
                 }
                 Err(e) => {
-                    error.set(Some(e));
+                    error.set(Some(e.to_string()));
                     loading.set(false);
                 }
             }
         });
+    });
+
+    // Load supporting data
+    create_effect(move |_| {
+        let id = household_id();
+        if id.is_empty() {
+            return;
+        }
+
+        let id_for_modal_data = id.clone();
+        let id_for_settings = id.clone();
 
         // Load members, rewards, punishments, categories
         wasm_bindgen_futures::spawn_local(async move {
@@ -96,16 +108,16 @@ pub fn TasksPage() -> impl IntoView {
         // Load archived tasks
         let id_for_archived = id.clone();
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(t) = ApiClient::list_archived_tasks(&id_for_archived).await {
+            if let Ok(t) = api_client().list_archived_tasks(&id_for_archived).await {
                 archived_tasks.set(t);
             }
         });
 
         // Load settings for hierarchy-aware member filtering and dark mode
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(s) = ApiClient::get_household_settings(&id_for_settings).await {
+            if let Ok(s) = api_client().get_household_settings(&id_for_settings).await {
                 // Apply dark mode
-                apply_dark_mode(s.dark_mode);
+                theme.set_household_dark_mode(s.dark_mode);
                 // Check if current user can manage based on hierarchy
                 // For now, we'll determine this from member role
                 settings.set(Some(s));
@@ -115,8 +127,8 @@ pub fn TasksPage() -> impl IntoView {
         // Check if user can manage (has Owner or Admin role)
         let id_for_role = id.clone();
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(user) = ApiClient::get_current_user().await {
-                if let Ok(members_list) = ApiClient::list_members(&id_for_role).await {
+            if let Ok(user) = api_client().get_current_user().await {
+                if let Ok(members_list) = api_client().list_members(&id_for_role).await {
                     if let Some(member) = members_list.iter().find(|m| m.user.id == user.id) {
                         let user_can_manage = member.membership.role.can_manage_tasks();
                         can_manage.set(user_can_manage);
@@ -129,7 +141,7 @@ pub fn TasksPage() -> impl IntoView {
     let on_delete = move |task_id: String| {
         let id = household_id();
         wasm_bindgen_futures::spawn_local(async move {
-            if ApiClient::delete_task(&id, &task_id).await.is_ok() {
+            if api_client().delete_task(&id, &task_id).await.is_ok() {
                 tasks.update(|t| t.retain(|task| task.id.to_string() != task_id));
             }
         });
@@ -138,7 +150,7 @@ pub fn TasksPage() -> impl IntoView {
     let on_archive = move |task_id: String| {
         let id = household_id();
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(archived) = ApiClient::archive_task(&id, &task_id).await {
+            if let Ok(archived) = api_client().archive_task(&id, &task_id).await {
                 // Remove from active tasks
                 tasks.update(|t| t.retain(|task| task.id.to_string() != task_id));
                 // Add to archived tasks
@@ -150,7 +162,7 @@ pub fn TasksPage() -> impl IntoView {
     let on_unarchive = move |task_id: String| {
         let id = household_id();
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(unarchived) = ApiClient::unarchive_task(&id, &task_id).await {
+            if let Ok(unarchived) = api_client().unarchive_task(&id, &task_id).await {
                 // Remove from archived tasks
                 archived_tasks.update(|t| t.retain(|task| task.id.to_string() != task_id));
                 // Add to active tasks
@@ -162,7 +174,7 @@ pub fn TasksPage() -> impl IntoView {
     let on_pause = move |task_id: String| {
         let id = household_id();
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(paused_task) = ApiClient::pause_task(&id, &task_id).await {
+            if let Ok(paused_task) = api_client().pause_task(&id, &task_id).await {
                 // Update task in list
                 tasks.update(|t| {
                     if let Some(pos) = t.iter().position(|task| task.id.to_string() == task_id) {
@@ -176,7 +188,7 @@ pub fn TasksPage() -> impl IntoView {
     let on_unpause = move |task_id: String| {
         let id = household_id();
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(unpaused_task) = ApiClient::unpause_task(&id, &task_id).await {
+            if let Ok(unpaused_task) = api_client().unpause_task(&id, &task_id).await {
                 // Update task in list
                 tasks.update(|t| {
                     if let Some(pos) = t.iter().position(|task| task.id.to_string() == task_id) {
@@ -200,13 +212,13 @@ pub fn TasksPage() -> impl IntoView {
         editing_task.set(Some(task));
 
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(r) = ApiClient::get_task_rewards(&id_for_rewards, &task_id_for_rewards).await {
+            if let Ok(r) = api_client().get_task_rewards(&id_for_rewards, &task_id_for_rewards).await {
                 task_linked_rewards.set(r);
             }
         });
 
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(p) = ApiClient::get_task_punishments(&id_for_punishments, &task_id_for_punishments).await {
+            if let Ok(p) = api_client().get_task_punishments(&id_for_punishments, &task_id_for_punishments).await {
                 task_linked_punishments.set(p);
             }
         });
@@ -225,13 +237,13 @@ pub fn TasksPage() -> impl IntoView {
         duplicating_task.set(Some(task));
 
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(r) = ApiClient::get_task_rewards(&id_for_rewards, &task_id_for_rewards).await {
+            if let Ok(r) = api_client().get_task_rewards(&id_for_rewards, &task_id_for_rewards).await {
                 duplicate_linked_rewards.set(r);
             }
         });
 
         wasm_bindgen_futures::spawn_local(async move {
-            if let Ok(p) = ApiClient::get_task_punishments(&id_for_punishments, &task_id_for_punishments).await {
+            if let Ok(p) = api_client().get_task_punishments(&id_for_punishments, &task_id_for_punishments).await {
                 duplicate_linked_punishments.set(p);
             }
         });
@@ -271,24 +283,23 @@ pub fn TasksPage() -> impl IntoView {
         </Show>
 
         <Show when=move || !loading.get() fallback=|| ()>
-            // Pending Reviews Section (only for managers/owners)
-            <Show when=move || can_manage.get() fallback=|| ()>
-                {
-                    let hid = household_id();
-                    let _ = pending_reviews_version.get(); // Subscribe to version changes
-                    view! {
-                        <div style="margin-bottom: 1.5rem;">
-                            <PendingReviews
-                                household_id=hid
-                                on_review_complete=move |_| {
-                                    // Trigger refresh
-                                    pending_reviews_version.update(|v| *v += 1);
-                                }
-                            />
-                        </div>
-                    }
+            // Pending Reviews Section (reviewers and members a review was delegated to;
+            // the list hides itself when there is nothing to review)
+            {move || {
+                let hid = household_id();
+                let _ = pending_reviews_version.get(); // Subscribe to version changes
+                view! {
+                    <div style="margin-bottom: 1.5rem;">
+                        <PendingReviews
+                            household_id=hid
+                            on_review_complete=move |_| {
+                                // Trigger refresh
+                                pending_reviews_version.update(|v| *v += 1);
+                            }
+                        />
+                    </div>
                 }
-            </Show>
+            }}
 
             // Pending Suggestions Section (only for managers/owners)
             <Show when=move || can_manage.get() fallback=|| ()>
@@ -305,7 +316,7 @@ pub fn TasksPage() -> impl IntoView {
                                     // Refresh the task list when a suggestion is approved
                                     let id = household_id();
                                     wasm_bindgen_futures::spawn_local(async move {
-                                        if let Ok(t) = ApiClient::list_tasks(&id).await {
+                                        if let Ok(t) = api_client().list_filtered_tasks(&id, &active_filter.get_untracked()).await {
                                             tasks.set(t);
                                         }
                                     });
@@ -377,6 +388,13 @@ pub fn TasksPage() -> impl IntoView {
 
             <h3 style="margin-bottom: 1rem; color: var(--text-muted);">{i18n_stored.get_value().t("tasks.all_tasks")}</h3>
 
+            {move || {
+                let hid = household_id();
+                (!hid.is_empty()).then(|| view! {
+                    <TaskFilterBar household_id=hid members=members categories=categories filter=active_filter />
+                })
+            }}
+
             {move || {
                 let t = tasks.get();
                 if t.is_empty() {
@@ -395,9 +413,7 @@ pub fn TasksPage() -> impl IntoView {
                                 let edit_task = task.clone();
                                 let duplicate_task = task.clone();
                                 let is_paused = task.paused;
-                                let assigned_name = task.assigned_user_id.and_then(|uid| {
-                                    members.get().iter().find(|m| m.user.id == uid).map(|m| m.user.username.clone())
-                                });
+                                let assigned_name = assignee_names(&task, &members.get());
 
                                 let edit_label = i18n_stored.get_value().t("common.edit");
                                 let duplicate_label = i18n_stored.get_value().t("common.duplicate");
@@ -538,9 +554,7 @@ pub fn TasksPage() -> impl IntoView {
                                 let task_id = task.id.to_string();
                                 let unarchive_id = task_id.clone();
                                 let delete_id = task_id.clone();
-                                let assigned_name = task.assigned_user_id.and_then(|uid| {
-                                    members.get().iter().find(|m| m.user.id == uid).map(|m| m.user.username.clone())
-                                });
+                                let assigned_name = assignee_names(&task, &members.get());
 
                                 let unarchive_label = i18n_stored.get_value().t("tasks.unarchive");
                                 let delete_label = i18n_stored.get_value().t("common.delete");
@@ -548,7 +562,7 @@ pub fn TasksPage() -> impl IntoView {
                                 let on_delete_archived = move |task_id: String| {
                                     let id = household_id();
                                     wasm_bindgen_futures::spawn_local(async move {
-                                        if ApiClient::delete_task(&id, &task_id).await.is_ok() {
+                                        if api_client().delete_task(&id, &task_id).await.is_ok() {
                                             archived_tasks.update(|t| t.retain(|task| task.id.to_string() != task_id));
                                         }
                                     });
@@ -611,8 +625,12 @@ pub fn TasksPage() -> impl IntoView {
                     .map(|s| s.hierarchy_type.filter_assignable_members(members.get()))
                     .unwrap_or_else(|| members.get());
                 // Extract defaults from settings
-                let default_points_reward = current_settings.as_ref().and_then(|s| s.default_points_reward);
-                let default_points_penalty = current_settings.as_ref().and_then(|s| s.default_points_penalty);
+                let (default_points_reward, default_points_penalty) = current_settings.as_ref()
+                    .map(|s| s.default_points_for(HabitType::Good))
+                    .unwrap_or_default();
+                let default_allow_exceed_target = current_settings.as_ref().map(|s| s.default_allow_exceed_target).unwrap_or(true);
+                let default_requires_review = current_settings.as_ref()
+                    .is_some_and(|s| s.default_requires_review_for(default_points_reward));
                 let default_rewards: Vec<(String, i32)> = current_settings.as_ref()
                     .map(|s| s.default_rewards.iter().map(|r| (r.reward.id.to_string(), r.amount)).collect())
                     .unwrap_or_default();
@@ -631,6 +649,8 @@ pub fn TasksPage() -> impl IntoView {
                         categories=categories.get()
                         default_points_reward=default_points_reward
                         default_points_penalty=default_points_penalty
+                        default_allow_exceed_target=default_allow_exceed_target
+                        default_requires_review=default_requires_review
                         default_rewards=default_rewards
                         default_punishments=default_punishments
                         on_close=move |_| show_create_modal.set(false)
@@ -707,7 +727,7 @@ pub fn TasksPage() -> impl IntoView {
                             // Reload categories after modal closes
                             let hid = household_id();
                             wasm_bindgen_futures::spawn_local(async move {
-                                if let Ok(cats) = ApiClient::list_categories(&hid).await {
+                                if let Ok(cats) = api_client().list_categories(&hid).await {
                                     categories.set(cats);
                                 }
                             });
@@ -765,7 +785,7 @@ pub fn TasksPage() -> impl IntoView {
                             // Reload tasks
                             let id = household_id();
                             wasm_bindgen_futures::spawn_local(async move {
-                                if let Ok(t) = ApiClient::list_tasks(&id).await {
+                                if let Ok(t) = api_client().list_filtered_tasks(&id, &active_filter.get_untracked()).await {
                                     tasks.set(t);
                                 }
                             });
@@ -777,19 +797,14 @@ pub fn TasksPage() -> impl IntoView {
     }
 }
 
-/// Apply dark mode class to document body
-fn apply_dark_mode(enabled: bool) {
-    if let Some(window) = web_sys::window() {
-        if let Some(document) = window.document() {
-            if let Some(body) = document.body() {
-                if enabled {
-                    let _ = body.class_list().add_1("dark-mode");
-                } else {
-                    let _ = body.class_list().remove_1("dark-mode");
-                }
-            }
-        }
-    }
+/// Usernames of all assignees of a task, comma separated
+fn assignee_names(task: &Task, members: &[MemberWithUser]) -> Option<String> {
+    let names: Vec<String> = task
+        .assignees()
+        .iter()
+        .filter_map(|uid| members.iter().find(|m| m.user.id == *uid).map(|m| m.user.username.clone()))
+        .collect();
+    (!names.is_empty()).then(|| names.join(", "))
 }
 
 #[cfg(test)]
@@ -812,19 +827,31 @@ mod tests {
             time_period: None,
             allow_exceed_target: true,
             requires_review: false,
+            reviewer_type: shared::ReviewerType::AnyReviewer,
+            reviewer_id: None,
             points_reward: None,
             points_penalty: None,
             due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
             habit_type: shared::HabitType::Good,
             category_id: None,
             category_name: None,
+            tag_ids: Vec::new(),
             archived: false,
             paused: false,
             assigned_user_id: None,
+            assignee_ids: Vec::new(),
+            version: 1,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             suggestion: None,
             suggested_by: None,
+            postponed_from: None,
+            postponed_to: None,
         }
     }
 
@@ -0,0 +1,135 @@
+use leptos::*;
+use leptos_router::*;
+use shared::{TrashItem, TrashItemKind};
+
+use crate::api::api_client;
+use crate::components::household_layout::HouseholdContext;
+use crate::components::loading::Loading;
+use crate::i18n::use_i18n;
+use crate::utils::{format_date, format_datetime};
+
+fn kind_key(kind: TrashItemKind) -> &'static str {
+    match kind {
+        TrashItemKind::Task => "trash.kind_task",
+        TrashItemKind::Note => "trash.kind_note",
+        TrashItemKind::JournalEntry => "trash.kind_journal_entry",
+    }
+}
+
+/// Deleted tasks, notes and journal entries of the household. Items can be
+/// restored or deleted for good until they are purged automatically.
+#[component]
+pub fn TrashPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let i18n_stored = store_value(i18n);
+
+    let params = use_params_map();
+    let household_id = move || params.with(|p| p.get("id").cloned().unwrap_or_default());
+
+    let settings = use_context::<HouseholdContext>().map(|ctx| ctx.settings);
+    let timezone = move || {
+        settings
+            .and_then(|s| s.get())
+            .map(|s| s.timezone)
+            .unwrap_or_else(|| "UTC".to_string())
+    };
+
+    let items = create_rw_signal(Vec::<TrashItem>::new());
+    let loading = create_rw_signal(true);
+    let error = create_rw_signal(Option::<String>::None);
+
+    create_effect(move |_| {
+        let id = household_id();
+        if id.is_empty() {
+            return;
+        }
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().list_trash(&id).await {
+                Ok(list) => items.set(list),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            loading.set(false);
+        });
+    });
+
+    let remove_item = move |kind: TrashItemKind, item_id: uuid::Uuid| {
+        items.update(|list| list.retain(|item| !(item.kind == kind && item.id == item_id)));
+    };
+
+    let on_restore = move |kind: TrashItemKind, item_id: uuid::Uuid| {
+        error.set(None);
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().restore_trash_item(&id, kind, &item_id.to_string()).await {
+                Ok(()) => remove_item(kind, item_id),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_purge = move |kind: TrashItemKind, item_id: uuid::Uuid| {
+        error.set(None);
+        let id = household_id();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().purge_trash_item(&id, kind, &item_id.to_string()).await {
+                Ok(()) => remove_item(kind, item_id),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    view! {
+        <div class="dashboard-header">
+            <h1 class="dashboard-title">{i18n_stored.get_value().t("trash.title")}</h1>
+        </div>
+
+        {move || error.get().map(|e| view! {
+            <div class="alert alert-error" style="margin-bottom: 1rem;">{e}</div>
+        })}
+
+        <Show when=move || !loading.get() fallback=|| view! { <Loading /> }>
+            {move || {
+                let i18n = i18n_stored.get_value();
+                let list = items.get();
+                if list.is_empty() {
+                    return view! {
+                        <div class="card empty-state">
+                            <p>{i18n.t("trash.empty")}</p>
+                            <p>{i18n.t("trash.retention_hint")}</p>
+                        </div>
+                    }.into_view();
+                }
+                let tz = timezone();
+                view! {
+                    <div class="card">
+                        <p class="task-meta" style="padding: 1rem 1rem 0;">{i18n.t("trash.retention_hint")}</p>
+                        {list.into_iter().map(|item| {
+                            let (kind, item_id) = (item.kind, item.id);
+                            let meta = [
+                                i18n.t(kind_key(kind)),
+                                i18n.t_with("trash.deleted_at", &[("date", &format_datetime(item.deleted_at, &tz))]),
+                                i18n.t_with("trash.purge_at", &[("date", &format_date(item.purge_at, &tz))]),
+                            ];
+                            view! {
+                                <div class="pending-review-item">
+                                    <div class="pending-review-content">
+                                        <div class="pending-review-task">{item.title}</div>
+                                        <div class="pending-review-meta">{meta.join(" · ")}</div>
+                                    </div>
+                                    <div class="pending-review-actions">
+                                        <button class="btn btn-outline btn-sm" on:click=move |_| on_restore(kind, item_id)>
+                                            {i18n.t("trash.restore")}
+                                        </button>
+                                        <button class="btn btn-danger btn-sm" on:click=move |_| on_purge(kind, item_id)>
+                                            {i18n.t("trash.purge")}
+                                        </button>
+                                    </div>
+                                </div>
+                            }
+                        }).collect_view()}
+                    </div>
+                }.into_view()
+            }}
+        </Show>
+    }
+}
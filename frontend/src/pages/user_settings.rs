@@ -1,30 +1,88 @@
 use leptos::*;
-use shared::UpdateUserSettingsRequest;
+use leptos_router::{use_navigate, use_query_map};
+use shared::{NotificationPreferences, Theme, UpdateUserSettingsRequest};
 
-use crate::api::ApiClient;
+use crate::api::{push, api_client, AuthState};
+use crate::components::api_tokens_card::ApiTokensCard;
 use crate::components::loading::Loading;
+use crate::components::modal::Modal;
+use crate::components::theme::use_theme;
+use crate::components::{Button, ButtonVariant};
 use crate::i18n::{supported_languages, use_i18n};
 
 #[component]
 pub fn UserSettingsPage() -> impl IntoView {
     let i18n = use_i18n();
+    let theme = use_theme();
 
     let loading = create_rw_signal(true);
     let saving = create_rw_signal(false);
     let error = create_rw_signal(Option::<String>::None);
     let success = create_rw_signal(Option::<String>::None);
     let selected_language = create_rw_signal(String::new());
+    let selected_theme = create_rw_signal(Theme::default());
+    let push_supported = push::is_push_supported();
+    let push_enabled = create_rw_signal(false);
+    let push_busy = create_rw_signal(false);
+    let preferences = create_rw_signal(NotificationPreferences::default());
+    let preferences_busy = create_rw_signal(false);
+    let oidc_provider = create_rw_signal(Option::<String>::None);
+    let oidc_busy = create_rw_signal(false);
+    let current_password = create_rw_signal(String::new());
+    let new_password = create_rw_signal(String::new());
+    let confirm_password = create_rw_signal(String::new());
+    let password_busy = create_rw_signal(false);
+    let delete_password = create_rw_signal(String::new());
+    let delete_busy = create_rw_signal(false);
+    let delete_confirm_open = create_rw_signal(false);
+
+    let is_child_account = create_rw_signal(false);
+
+    let auth_state = expect_context::<AuthState>();
+    let navigate = use_navigate();
+
+    // Account linking is only offered when the server has a provider configured
+    let i18n_linked = i18n.clone();
+    let oidc_linked = use_query_map().get_untracked().get("oidc").map(|s| s == "linked").unwrap_or(false);
+    wasm_bindgen_futures::spawn_local(async move {
+        if let Ok(info) = api_client().get_oidc_config().await {
+            if info.enabled {
+                if oidc_linked {
+                    success.set(Some(
+                        i18n_linked.t_with("settings.oidc_linked", &[("provider", &info.provider_name)]),
+                    ));
+                }
+                oidc_provider.set(Some(info.provider_name));
+            }
+        }
+    });
+
+    // Child accounts have a PIN managed by the household owner
+    wasm_bindgen_futures::spawn_local(async move {
+        if let Ok(user) = api_client().get_current_user().await {
+            is_child_account.set(user.is_child_account());
+        }
+    });
+
+    // Check whether this device is already subscribed to push notifications
+    if push_supported {
+        wasm_bindgen_futures::spawn_local(async move {
+            push_enabled.set(push::is_push_enabled().await);
+        });
+    }
 
     // Load user settings
     create_effect(move |_| {
         wasm_bindgen_futures::spawn_local(async move {
-            match ApiClient::get_user_settings().await {
+            match api_client().get_user_settings().await {
                 Ok(settings) => {
                     selected_language.set(settings.language);
+                    selected_theme.set(settings.theme);
+                    preferences.set(settings.notification_preferences);
                     loading.set(false);
                 }
                 Err(e) => {
-                    error.set(Some(e));
+                    error.set(Some(e.to_string()));
                     loading.set(false);
                 }
             }
@@ -34,6 +92,98 @@ pub fn UserSettingsPage() -> impl IntoView {
     // Store i18n context for use in closures
     let i18n_stored = store_value(i18n);
 
+    let on_change_password = move |ev: web_sys::SubmitEvent| {
+        ev.prevent_default();
+        error.set(None);
+        success.set(None);
+
+        if new_password.get() != confirm_password.get() {
+            error.set(Some(i18n_stored.get_value().t("auth.password_mismatch")));
+            return;
+        }
+        if new_password.get().len() < 8 {
+            error.set(Some(i18n_stored.get_value().t("auth.password_min_length")));
+            return;
+        }
+
+        password_busy.set(true);
+        let current = current_password.get();
+        let new = new_password.get();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().change_password(current, new).await {
+                Ok(_) => {
+                    current_password.set(String::new());
+                    new_password.set(String::new());
+                    confirm_password.set(String::new());
+                    success.set(Some(i18n_stored.get_value().t("settings.password_changed")));
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            password_busy.set(false);
+        });
+    };
+
+    let on_delete_account = Callback::new(move |_: ev::MouseEvent| {
+        delete_busy.set(true);
+        error.set(None);
+        let password = Some(delete_password.get()).filter(|p| !p.is_empty());
+        let auth = auth_state.clone();
+        let nav = navigate.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().delete_account(password).await {
+                Ok(()) => {
+                    auth.logout();
+                    nav("/login", Default::default());
+                }
+                Err(e) => {
+                    error.set(Some(e.to_string()));
+                    delete_busy.set(false);
+                    delete_confirm_open.set(false);
+                }
+            }
+        });
+    });
+
+    let on_save_preferences = move |ev: web_sys::SubmitEvent| {
+        ev.prevent_default();
+        preferences_busy.set(true);
+        error.set(None);
+        success.set(None);
+        let request = UpdateUserSettingsRequest {
+            language: None,
+            theme: None,
+            notification_preferences: Some(preferences.get()),
+        };
+        wasm_bindgen_futures::spawn_local(async move {
+            match api_client().update_user_settings(request).await {
+                Ok(settings) => {
+                    preferences.set(settings.notification_preferences);
+                    success.set(Some(i18n_stored.get_value().t("settings.saved")));
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            preferences_busy.set(false);
+        });
+    };
+
+    // One checkbox per notification kind
+    let preference_toggle = move |id: &'static str, label_key: &'static str, get: fn(&NotificationPreferences) -> bool, set: fn(&mut NotificationPreferences, bool)| {
+        view! {
+            <div class="form-group" style="display: flex; align-items: center; gap: 0.5rem;">
+                <input
+                    type="checkbox"
+                    id=id
+                    prop:checked=move || preferences.with(get)
+                    on:change=move |ev| {
+                        let checked = event_target_checked(&ev);
+                        preferences.update(|p| set(p, checked));
+                    }
+                />
+                <label for=id>{move || i18n_stored.get_value().t(label_key)}</label>
+            </div>
+        }
+    };
+
     view! {
         <div class="dashboard-header">
             <h1 class="dashboard-title">{move || i18n_stored.get_value().t("settings.user_settings")}</h1>
@@ -64,29 +214,33 @@ pub fn UserSettingsPage() -> impl IntoView {
                     success.set(None);
 
                     let language = selected_language.get();
+                    let selected = selected_theme.get();
                     let i18n_clone = i18n_stored.get_value();
 
                     wasm_bindgen_futures::spawn_local(async move {
                         let request = UpdateUserSettingsRequest {
                             language: Some(language.clone()),
+                            theme: Some(selected),
+                            notification_preferences: None,
                         };
 
-                        match ApiClient::update_user_settings(request).await {
+                        match api_client().update_user_settings(request).await {
                             Ok(settings) => {
                                 // Update i18n context with the new language
                                 i18n_clone.set_language(&settings.language);
+                                theme.set_theme(settings.theme);
                                 success.set(Some(i18n_clone.t("settings.saved")));
                                 saving.set(false);
                             }
                             Err(e) => {
-                                error.set(Some(e));
+                                error.set(Some(e.to_string()));
                                 saving.set(false);
                             }
                         }
                     });
                 }>
                     <div class="card-header">
-                        <h3 class="card-title">{move || i18n_stored.get_value().t("settings.language")}</h3>
+                        <h3 class="card-title">{move || i18n_stored.get_value().t("settings.appearance")}</h3>
                     </div>
                     <div style="padding: 1rem;">
                         <div class="form-group">
@@ -111,6 +265,26 @@ pub fn UserSettingsPage() -> impl IntoView {
                                 }).collect_view()}
                             </select>
                         </div>
+                        <div class="form-group">
+                            <label class="form-label" for="theme">{move || i18n_stored.get_value().t("settings.theme")}</label>
+                            <select
+                                id="theme"
+                                class="form-input"
+                                on:change=move |ev| {
+                                    if let Ok(t) = event_target_value(&ev).parse() {
+                                        selected_theme.set(t);
+                                    }
+                                }
+                            >
+                                {[Theme::System, Theme::Light, Theme::Dark].into_iter().map(|t| {
+                                    view! {
+                                        <option value=t.as_str() selected=move || selected_theme.get() == t>
+                                            {move || i18n_stored.get_value().t(&format!("settings.theme_{}", t.as_str()))}
+                                        </option>
+                                    }
+                                }).collect_view()}
+                            </select>
+                        </div>
                     </div>
                     <div class="card-footer" style="padding: 1rem; border-top: 1px solid var(--border-color);">
                         <button
@@ -127,6 +301,237 @@ pub fn UserSettingsPage() -> impl IntoView {
                     </div>
                 </form>
             </div>
+
+            <div class="card">
+                <div class="card-header">
+                    <h3 class="card-title">{move || i18n_stored.get_value().t("settings.push_notifications")}</h3>
+                </div>
+                <div style="padding: 1rem;">
+                    <p class="form-hint">{move || i18n_stored.get_value().t("settings.push_hint")}</p>
+                    {if push_supported {
+                        view! {
+                            <button
+                                type="button"
+                                class="btn btn-outline"
+                                disabled=move || push_busy.get()
+                                on:click=move |_| {
+                                    push_busy.set(true);
+                                    error.set(None);
+                                    success.set(None);
+                                    let enable = !push_enabled.get();
+                                    let i18n_clone = i18n_stored.get_value();
+
+                                    wasm_bindgen_futures::spawn_local(async move {
+                                        let result = if enable {
+                                            push::enable_push_notifications().await
+                                        } else {
+                                            push::disable_push_notifications().await
+                                        };
+                                        match result {
+                                            Ok(()) => {
+                                                push_enabled.set(enable);
+                                                success.set(Some(i18n_clone.t(if enable {
+                                                    "settings.push_enabled"
+                                                } else {
+                                                    "settings.push_disabled"
+                                                })));
+                                            }
+                                            Err(e) => error.set(Some(e)),
+                                        }
+                                        push_busy.set(false);
+                                    });
+                                }
+                            >
+                                {move || if push_enabled.get() {
+                                    i18n_stored.get_value().t("settings.disable_push")
+                                } else {
+                                    i18n_stored.get_value().t("settings.enable_push")
+                                }}
+                            </button>
+                        }.into_view()
+                    } else {
+                        view! {
+                            <p class="form-hint">{move || i18n_stored.get_value().t("settings.push_unsupported")}</p>
+                        }.into_view()
+                    }}
+                </div>
+                <form on:submit=on_save_preferences style="padding: 0 1rem 1rem;">
+                    <h4>{move || i18n_stored.get_value().t("settings.notify_about")}</h4>
+                    {preference_toggle("notify-chat", "settings.notify_chat", |p| p.chat, |p, v| p.chat = v)}
+                    {preference_toggle("notify-reviews", "settings.notify_reviews", |p| p.reviews, |p, v| p.reviews = v)}
+                    {preference_toggle("notify-assignments", "settings.notify_assignments", |p| p.assignments, |p, v| p.assignments = v)}
+                    {preference_toggle("notify-reminders", "settings.notify_reminders", |p| p.reminders, |p, v| p.reminders = v)}
+                    <h4>{move || i18n_stored.get_value().t("settings.quiet_hours")}</h4>
+                    <p class="form-hint">{move || i18n_stored.get_value().t("settings.quiet_hours_hint")}</p>
+                    <div class="form-group" style="display: flex; align-items: center; gap: 0.5rem;">
+                        <input
+                            type="time"
+                            class="form-input"
+                            prop:value=move || preferences.with(|p| p.quiet_hours_start.clone().unwrap_or_default())
+                            on:input=move |ev| {
+                                let value = event_target_value(&ev);
+                                preferences.update(|p| p.quiet_hours_start = (!value.is_empty()).then_some(value));
+                            }
+                        />
+                        <span>"–"</span>
+                        <input
+                            type="time"
+                            class="form-input"
+                            prop:value=move || preferences.with(|p| p.quiet_hours_end.clone().unwrap_or_default())
+                            on:input=move |ev| {
+                                let value = event_target_value(&ev);
+                                preferences.update(|p| p.quiet_hours_end = (!value.is_empty()).then_some(value));
+                            }
+                        />
+                    </div>
+                    <button type="submit" class="btn btn-primary" disabled=move || preferences_busy.get()>
+                        {move || i18n_stored.get_value().t("common.save")}
+                    </button>
+                </form>
+            </div>
+
+            {move || oidc_provider.get().map(|provider| {
+                let hint = i18n_stored.get_value().t_with("settings.oidc_hint", &[("provider", &provider)]);
+                let label = i18n_stored.get_value().t_with("settings.oidc_link", &[("provider", &provider)]);
+                view! {
+                    <div class="card">
+                        <div class="card-header">
+                            <h3 class="card-title">{i18n_stored.get_value().t("settings.linked_accounts")}</h3>
+                        </div>
+                        <div style="padding: 1rem;">
+                            <p class="form-hint">{hint}</p>
+                            <button
+                                type="button"
+                                class="btn btn-outline"
+                                disabled=move || oidc_busy.get()
+                                on:click=move |_| {
+                                    oidc_busy.set(true);
+                                    error.set(None);
+                                    wasm_bindgen_futures::spawn_local(async move {
+                                        match api_client().start_oidc_link().await {
+                                            Ok(response) => {
+                                                if let Some(window) = web_sys::window() {
+                                                    let _ = window.location().set_href(&response.url);
+                                                }
+                                            }
+                                            Err(e) => {
+                                                error.set(Some(e.to_string()));
+                                                oidc_busy.set(false);
+                                            }
+                                        }
+                                    });
+                                }
+                            >
+                                {label}
+                            </button>
+                        </div>
+                    </div>
+                }
+            })}
+
+            <ApiTokensCard />
+
+            <div class="card">
+                <div class="card-header">
+                    <h3 class="card-title">{move || i18n_stored.get_value().t("settings.change_password")}</h3>
+                </div>
+                <Show when=move || !is_child_account.get() fallback=move || view! {
+                    <p class="form-hint" style="padding: 1rem;">{i18n_stored.get_value().t("child_accounts.settings_hint")}</p>
+                }>
+                    <form on:submit=on_change_password style="padding: 1rem;">
+                        <p class="form-hint">{move || i18n_stored.get_value().t("settings.change_password_hint")}</p>
+                        <div class="form-group">
+                            <label class="form-label" for="current-password">{move || i18n_stored.get_value().t("settings.current_password")}</label>
+                            <input
+                                type="password"
+                                id="current-password"
+                                class="form-input"
+                                prop:value=move || current_password.get()
+                                on:input=move |ev| current_password.set(event_target_value(&ev))
+                                required
+                            />
+                        </div>
+                        <div class="form-group">
+                            <label class="form-label" for="new-password">{move || i18n_stored.get_value().t("auth.new_password")}</label>
+                            <input
+                                type="password"
+                                id="new-password"
+                                class="form-input"
+                                placeholder=move || i18n_stored.get_value().t("auth.create_password")
+                                prop:value=move || new_password.get()
+                                on:input=move |ev| new_password.set(event_target_value(&ev))
+                                required
+                                minlength="8"
+                            />
+                        </div>
+                        <div class="form-group">
+                            <label class="form-label" for="confirm-new-password">{move || i18n_stored.get_value().t("auth.confirm_password")}</label>
+                            <input
+                                type="password"
+                                id="confirm-new-password"
+                                class="form-input"
+                                prop:value=move || confirm_password.get()
+                                on:input=move |ev| confirm_password.set(event_target_value(&ev))
+                                required
+                            />
+                        </div>
+                        <button type="submit" class="btn btn-primary" disabled=move || password_busy.get()>
+                            {move || if password_busy.get() {
+                                i18n_stored.get_value().t("common.saving")
+                            } else {
+                                i18n_stored.get_value().t("settings.change_password")
+                            }}
+                        </button>
+                    </form>
+                </Show>
+            </div>
+
+            <div class="card">
+                <div class="card-header">
+                    <h3 class="card-title">{move || i18n_stored.get_value().t("settings.delete_account")}</h3>
+                </div>
+                <div style="padding: 1rem;">
+                    <p class="form-hint">{move || i18n_stored.get_value().t("settings.delete_account_hint")}</p>
+                    <div class="form-group">
+                        <label class="form-label" for="delete-password">{move || i18n_stored.get_value().t("auth.password")}</label>
+                        <input
+                            type="password"
+                            id="delete-password"
+                            class="form-input"
+                            placeholder=move || i18n_stored.get_value().t("settings.delete_account_password_hint")
+                            prop:value=move || delete_password.get()
+                            on:input=move |ev| delete_password.set(event_target_value(&ev))
+                        />
+                    </div>
+                    <button type="button" class="btn btn-danger" on:click=move |_| delete_confirm_open.set(true)>
+                        {move || i18n_stored.get_value().t("settings.delete_account")}
+                    </button>
+                </div>
+            </div>
+
+            <Show when=move || delete_confirm_open.get() fallback=|| ()>
+                <Modal
+                    on_close=move |_| delete_confirm_open.set(false)
+                    title=i18n_stored.get_value().t("settings.delete_account")
+                >
+                    <p>{i18n_stored.get_value().t("settings.delete_account_confirm")}</p>
+                    <div style="display: flex; gap: 0.5rem; margin-top: 1rem; justify-content: flex-end;">
+                        <Button
+                            variant=ButtonVariant::Secondary
+                            on_click=Callback::new(move |_| delete_confirm_open.set(false))
+                        >
+                            {i18n_stored.get_value().t("common.cancel")}
+                        </Button>
+                        <Button
+                            variant=ButtonVariant::Danger
+                            disabled=MaybeSignal::derive(move || delete_busy.get())
+                            on_click=on_delete_account
+                        >
+                            {i18n_stored.get_value().t("settings.delete_account")}
+                        </Button>
+                    </div>
+                </Modal>
+            </Show>
         </Show>
     }
 }
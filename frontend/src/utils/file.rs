@@ -0,0 +1,108 @@
+//! Helpers for downloading and uploading files in the browser.
+
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+
+/// Offers `content` to the user as a JSON file download.
+pub fn download_json(filename: &str, content: &str) -> Result<(), String> {
+    let href = format!(
+        "data:application/json;charset=utf-8,{}",
+        js_sys::encode_uri_component(content)
+    );
+    download_url(filename, &href)
+}
+
+/// Offers a URL (usually a data URL) to the user as a file download.
+pub fn download_url(filename: &str, href: &str) -> Result<(), String> {
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or("No document available")?;
+
+    let link = document
+        .create_element("a")
+        .map_err(|_| "Failed to create download link")?
+        .unchecked_into::<web_sys::HtmlElement>();
+    link.set_attribute("href", href)
+        .and_then(|_| link.set_attribute("download", filename))
+        .map_err(|_| "Failed to prepare download link")?;
+    link.click();
+
+    Ok(())
+}
+
+/// Human-readable file size
+pub fn format_size(bytes: i64) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else if bytes < 1024 * 1024 {
+        format!("{:.0} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    }
+}
+
+/// Reads the first file selected in a file input as text.
+pub async fn read_selected_file(input: &web_sys::HtmlInputElement) -> Result<Option<String>, String> {
+    let Some(file) = input.files().and_then(|files| files.get(0)) else {
+        return Ok(None);
+    };
+
+    let text = JsFuture::from(file.text())
+        .await
+        .map_err(|_| "Failed to read file")?;
+
+    Ok(text.as_string())
+}
+
+/// Builds a file name for a household export, e.g. `haushalt-smith-family.json`.
+pub fn export_file_name(household_name: &str) -> String {
+    let slug: String = household_name
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect();
+    let slug = slug
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+
+    if slug.is_empty() {
+        "haushalt-export.json".to_string()
+    } else {
+        format!("haushalt-{}.json", slug)
+    }
+}
+
+/// File name for a CSV export, e.g. `haushalt-completions-2024-01-01-2024-03-31.csv`
+pub fn csv_file_name(kind: &str, from: &str, to: &str) -> String {
+    let range: Vec<&str> = [from, to].into_iter().filter(|d| !d.is_empty()).collect();
+    if range.is_empty() {
+        format!("haushalt-{}.csv", kind)
+    } else {
+        format!("haushalt-{}-{}.csv", kind, range.join("-"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_file_name() {
+        assert_eq!(export_file_name("Smith Family"), "haushalt-smith-family.json");
+        assert_eq!(export_file_name("  WG / Küche!  "), "haushalt-wg-küche.json");
+        assert_eq!(export_file_name("???"), "haushalt-export.json");
+    }
+
+    #[test]
+    fn test_csv_file_name() {
+        assert_eq!(csv_file_name("points", "", ""), "haushalt-points.csv");
+        assert_eq!(
+            csv_file_name("completions", "2024-01-01", "2024-03-31"),
+            "haushalt-completions-2024-01-01-2024-03-31.csv"
+        );
+        assert_eq!(csv_file_name("points", "", "2024-03-31"), "haushalt-points-2024-03-31.csv");
+    }
+}
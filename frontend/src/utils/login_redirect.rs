@@ -0,0 +1,37 @@
+//! Remembers the page a signed-out visitor opened (e.g. an invite link),
+//! so logging in or registering continues there instead of the dashboard.
+
+use gloo_storage::{SessionStorage, Storage};
+
+const LOGIN_REDIRECT_KEY: &str = "login_redirect";
+
+/// Only same-origin paths are followed after login
+fn is_safe_redirect(path: &str) -> bool {
+    path.starts_with('/') && !path.starts_with("//") && path != "/login"
+}
+
+pub fn remember_login_redirect(path: &str) {
+    if is_safe_redirect(path) {
+        SessionStorage::set(LOGIN_REDIRECT_KEY, path).ok();
+    }
+}
+
+/// The remembered path (cleared on read), or `/`
+pub fn take_login_redirect() -> String {
+    let path: Option<String> = SessionStorage::get(LOGIN_REDIRECT_KEY).ok();
+    SessionStorage::delete(LOGIN_REDIRECT_KEY);
+    path.filter(|p| is_safe_redirect(p)).unwrap_or_else(|| "/".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_safe_redirect() {
+        assert!(is_safe_redirect("/join/ABCD2345"));
+        assert!(!is_safe_redirect("//evil.example.com"));
+        assert!(!is_safe_redirect("https://evil.example.com"));
+        assert!(!is_safe_redirect("/login"));
+    }
+}
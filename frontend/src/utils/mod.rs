@@ -1,9 +1,13 @@
+pub mod file;
 pub mod filters;
+pub mod login_redirect;
 pub mod pending_action;
 pub mod task_modal;
 pub mod timezone;
 
+pub use file::{csv_file_name, download_json, download_url, export_file_name, format_size, read_selected_file};
 pub use filters::matches_text_filter;
+pub use login_redirect::{remember_login_redirect, take_login_redirect};
 pub use pending_action::create_remove_action_handler;
 pub use task_modal::TaskModalData;
 pub use timezone::{
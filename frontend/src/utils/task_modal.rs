@@ -5,7 +5,7 @@
 
 use shared::{MemberWithUser, Punishment, Reward, TaskCategory};
 
-use crate::api::ApiClient;
+use crate::api::api_client;
 
 /// Data required for task create/edit modals
 #[derive(Clone, Default)]
@@ -22,16 +22,16 @@ impl TaskModalData {
     /// Fetches members, rewards, punishments, and categories in sequence.
     /// Returns default empty vectors for any failed requests.
     pub async fn load(household_id: &str) -> Self {
-        let members = ApiClient::list_members(household_id)
+        let members = api_client().list_members(household_id)
             .await
             .unwrap_or_default();
-        let rewards = ApiClient::list_rewards(household_id)
+        let rewards = api_client().list_rewards(household_id)
             .await
             .unwrap_or_default();
-        let punishments = ApiClient::list_punishments(household_id)
+        let punishments = api_client().list_punishments(household_id)
             .await
             .unwrap_or_default();
-        let categories = ApiClient::list_categories(household_id)
+        let categories = api_client().list_categories(household_id)
             .await
             .unwrap_or_default();
 
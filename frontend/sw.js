@@ -2,16 +2,49 @@
 // Falls back to extracting hash from index.html if placeholder not replaced
 let CACHE_NAME = 'household-__BUILD_HASH__';
 
+// API responses survive app updates; the app deletes this cache on logout
+const API_CACHE = 'household-api';
+
 // Assets to always try to cache
 const SHELL_URLS = [
   '/',
   '/index.html',
   '/manifest.json',
+  '/favicon.svg',
   '/icons/icon.svg',
-  '/icons/icon-maskable.svg'
+  '/icons/icon-maskable.svg',
+  '/icons/icon-192.png',
+  '/icons/icon-512.png',
+  '/icons/apple-touch-icon.png'
 ];
 
-// Install: extract version from index.html and precache app shell
+// Trunk adds a content hash to the JS glue, the WASM bundle and the CSS,
+// so these files never change and can be served from cache
+const HASHED_ASSET = /-[a-f0-9]{8,}(_bg)?\.(js|wasm|css)$/;
+
+// Collect the hashed bundle files referenced by index.html
+function bundleUrls(html) {
+  const urls = new Set();
+  for (const match of html.matchAll(/["'](\/[^"']+\.(?:js|wasm|css))["']/g)) {
+    if (HASHED_ASSET.test(match[1])) {
+      urls.add(match[1]);
+    }
+  }
+  return [...urls];
+}
+
+// Store successful responses in the given cache and pass them through
+function cacheResponse(cacheName, request) {
+  return response => {
+    if (response.ok) {
+      const clone = response.clone();
+      caches.open(cacheName).then(cache => cache.put(request, clone));
+    }
+    return response;
+  };
+}
+
+// Install: extract version from index.html and precache app shell and bundle
 self.addEventListener('install', event => {
   console.log('SW: Installing new version');
 
@@ -26,9 +59,9 @@ self.addEventListener('install', event => {
           CACHE_NAME = `household-${match[1]}`;
           console.log('SW: Cache version:', CACHE_NAME);
         }
-        return caches.open(CACHE_NAME);
+        return caches.open(CACHE_NAME)
+          .then(cache => cache.addAll([...SHELL_URLS, ...bundleUrls(html)]));
       })
-      .then(cache => cache.addAll(SHELL_URLS))
       .then(() => {
         // Force immediate activation
         console.log('SW: Skip waiting');
@@ -42,7 +75,7 @@ self.addEventListener('activate', event => {
   console.log('SW: Activating, taking control');
   event.waitUntil(
     caches.keys().then(keys =>
-      Promise.all(keys.filter(k => k !== CACHE_NAME).map(k => {
+      Promise.all(keys.filter(k => k !== CACHE_NAME && k !== API_CACHE).map(k => {
         console.log('SW: Deleting old cache:', k);
         return caches.delete(k);
       }))
@@ -53,12 +86,30 @@ self.addEventListener('activate', event => {
   );
 });
 
-// Fetch: network-first for app files, cache-first for static assets
+// Fetch: network-first for the app shell and API reads, cache-first for hashed and static assets
 self.addEventListener('fetch', event => {
   const url = new URL(event.request.url);
 
-  // Skip API requests - always go to network
+  if (url.origin !== self.location.origin) {
+    return;
+  }
+
   if (url.pathname.startsWith('/api/')) {
+    // Writes, auth and the WebSocket always go to the network
+    if (event.request.method !== 'GET' ||
+        url.pathname.startsWith('/api/ws') ||
+        url.pathname.startsWith('/api/auth/')) {
+      return;
+    }
+    // Last known data when offline
+    event.respondWith(
+      fetch(event.request)
+        .then(cacheResponse(API_CACHE, event.request))
+        .catch(() => caches.open(API_CACHE)
+          .then(cache => cache.match(event.request))
+          .then(cached => cached || Response.error())
+        )
+    );
     return;
   }
 
@@ -67,21 +118,25 @@ self.addEventListener('fetch', event => {
     return;
   }
 
-  // Network-first for HTML, JS/WASM, and CSS files (app updates)
-  if (url.pathname.endsWith('.html') ||
+  // Hashed bundle files are immutable
+  if (HASHED_ASSET.test(url.pathname)) {
+    event.respondWith(
+      caches.match(event.request)
+        .then(cached => cached || fetch(event.request).then(cacheResponse(CACHE_NAME, event.request)))
+    );
+    return;
+  }
+
+  // Network-first for navigations, HTML, JS/WASM, and CSS files (app updates)
+  if (event.request.mode === 'navigate' ||
+      url.pathname.endsWith('.html') ||
       url.pathname.endsWith('.js') ||
       url.pathname.endsWith('.wasm') ||
       url.pathname.endsWith('.css') ||
       url.pathname === '/') {
     event.respondWith(
       fetch(event.request)
-        .then(response => {
-          if (response.ok) {
-            const clone = response.clone();
-            caches.open(CACHE_NAME).then(cache => cache.put(event.request, clone));
-          }
-          return response;
-        })
+        .then(cacheResponse(CACHE_NAME, event.request))
         .catch(() => caches.match(event.request)
           .then(cached => cached || caches.match('/index.html'))
         )
@@ -92,15 +147,46 @@ self.addEventListener('fetch', event => {
   // Cache-first for static assets (icons, manifest)
   event.respondWith(
     caches.match(event.request)
-      .then(cached => cached || fetch(event.request)
-        .then(response => {
-          if (response.ok) {
-            const clone = response.clone();
-            caches.open(CACHE_NAME).then(cache => cache.put(event.request, clone));
-          }
-          return response;
-        })
-      )
+      .then(cached => cached || fetch(event.request).then(cacheResponse(CACHE_NAME, event.request)))
       .catch(() => caches.match('/index.html'))
   );
 });
+
+// Push: show task reminders sent by the backend
+self.addEventListener('push', event => {
+  let data = { title: 'Household', body: '', url: '/' };
+  if (event.data) {
+    try {
+      data = Object.assign(data, event.data.json());
+    } catch (e) {
+      data.body = event.data.text();
+    }
+  }
+
+  event.waitUntil(
+    self.registration.showNotification(data.title, {
+      body: data.body,
+      icon: '/icons/icon.svg',
+      badge: '/icons/icon.svg',
+      data: { url: data.url }
+    })
+  );
+});
+
+// Notification click: focus an open window or open the linked page
+self.addEventListener('notificationclick', event => {
+  event.notification.close();
+  const url = (event.notification.data && event.notification.data.url) || '/';
+
+  event.waitUntil(
+    self.clients.matchAll({ type: 'window', includeUncontrolled: true }).then(windows => {
+      for (const client of windows) {
+        if ('focus' in client) {
+          client.navigate(url);
+          return client.focus();
+        }
+      }
+      return self.clients.openWindow(url);
+    })
+  );
+});
@@ -1,4 +1,4 @@
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 use uuid::Uuid;
@@ -8,6 +8,7 @@ use uuid::Uuid;
 // ============================================================================
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct User {
     pub id: Uuid,
     pub username: String,
@@ -16,7 +17,27 @@ pub struct User {
     pub updated_at: DateTime<Utc>,
 }
 
+/// Domain of the placeholder addresses given to child accounts, which have no email.
+/// `.invalid` is a reserved TLD, so no real address can collide with it.
+pub const CHILD_ACCOUNT_EMAIL_DOMAIN: &str = "child.invalid";
+
+/// Whether an address is a child account placeholder (case insensitive)
+pub fn is_child_account_email(email: &str) -> bool {
+    email
+        .rsplit_once('@')
+        .map(|(_, domain)| domain.eq_ignore_ascii_case(CHILD_ACCOUNT_EMAIL_DOMAIN))
+        .unwrap_or(false)
+}
+
+impl User {
+    /// Managed child accounts sign in with username and PIN and have no email features
+    pub fn is_child_account(&self) -> bool {
+        is_child_account_email(&self.email)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CreateUserRequest {
     pub username: String,
     pub email: String,
@@ -24,12 +45,14 @@ pub struct CreateUserRequest {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct LoginRequest {
     pub username: String,
     pub password: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct AuthResponse {
     pub token: String,
     pub refresh_token: String,
@@ -37,11 +60,85 @@ pub struct AuthResponse {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct RefreshTokenRequest {
     pub refresh_token: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ForgotPasswordRequest {
+    pub email: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ResetPasswordRequest {
+    /// Token from the emailed reset link
+    pub token: String,
+    pub new_password: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ChangePasswordRequest {
+    pub current_password: String,
+    pub new_password: String,
+    /// Refresh token of the calling session; it stays valid while all others are revoked
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct DeleteAccountRequest {
+    /// Required for accounts that have a password (not needed for OIDC-only accounts)
+    #[serde(default)]
+    pub password: Option<String>,
+}
+
+/// A child account PIN is 4 to 8 digits
+pub fn is_valid_child_pin(pin: &str) -> bool {
+    (4..=8).contains(&pin.len()) && pin.chars().all(|c| c.is_ascii_digit())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreateChildAccountRequest {
+    pub username: String,
+    pub pin: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ResetChildPinRequest {
+    pub pin: String,
+}
+
+/// Whether OpenID Connect login is available, and the name to show for it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct OidcProviderInfo {
+    pub enabled: bool,
+    pub provider_name: String,
+}
+
+/// Provider URL the browser is sent to for OIDC login or account linking
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct OidcAuthorizationUrl {
+    pub url: String,
+}
+
+/// One-time code handed to the frontend after an OIDC login
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct OidcCodeExchangeRequest {
+    pub code: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UpdateUserRequest {
     pub username: Option<String>,
     pub email: Option<String>,
@@ -52,6 +149,7 @@ pub struct UpdateUserRequest {
 // ============================================================================
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Household {
     pub id: Uuid,
     pub name: String,
@@ -61,11 +159,13 @@ pub struct Household {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CreateHouseholdRequest {
     pub name: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UpdateHouseholdRequest {
     pub name: Option<String>,
 }
@@ -75,6 +175,7 @@ pub struct UpdateHouseholdRequest {
 // ============================================================================
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum HierarchyType {
     /// Everyone can manage tasks, rewards, and punishments
@@ -141,7 +242,231 @@ impl FromStr for HierarchyType {
     }
 }
 
+/// How inactive members lose points each week
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PointDecayMode {
+    /// Points never decay (default)
+    #[default]
+    None,
+    /// Lose `point_decay_amount` percent of the balance
+    Percent,
+    /// Lose a fixed `point_decay_amount` of points
+    Fixed,
+}
+
+impl PointDecayMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PointDecayMode::None => "none",
+            PointDecayMode::Percent => "percent",
+            PointDecayMode::Fixed => "fixed",
+        }
+    }
+
+    /// Points an inactive member with `balance` loses for one week.
+    /// Only positive balances decay, and never below zero; a percentage
+    /// always takes at least one point so small balances still decay.
+    pub fn decay_for(&self, amount: i32, balance: i64) -> i64 {
+        if balance <= 0 || amount <= 0 {
+            return 0;
+        }
+        let decay = match self {
+            PointDecayMode::None => 0,
+            PointDecayMode::Percent => (balance * i64::from(amount.min(100)) / 100).max(1),
+            PointDecayMode::Fixed => i64::from(amount),
+        };
+        decay.min(balance)
+    }
+}
+
+impl FromStr for PointDecayMode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(PointDecayMode::None),
+            "percent" => Ok(PointDecayMode::Percent),
+            "fixed" => Ok(PointDecayMode::Fixed),
+            _ => Err(()),
+        }
+    }
+}
+
+/// What happens to a member's points once their monthly allowance is paid out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum AllowancePointHandling {
+    /// The whole balance is paid out and the points are taken away (default)
+    #[default]
+    Reset,
+    /// Only the points earned during the month are paid out; the balance stays
+    CarryOver,
+}
+
+impl AllowancePointHandling {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AllowancePointHandling::Reset => "reset",
+            AllowancePointHandling::CarryOver => "carry_over",
+        }
+    }
+}
+
+impl FromStr for AllowancePointHandling {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "reset" => Ok(AllowancePointHandling::Reset),
+            "carry_over" => Ok(AllowancePointHandling::CarryOver),
+            _ => Err(()),
+        }
+    }
+}
+
+/// What the punishment system is called in a household
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PunishmentTerminology {
+    #[default]
+    Punishments,
+    /// Softer wording, translated like the default
+    Consequences,
+    /// The household's own `punishment_label_singular` and `punishment_label_plural`
+    Custom,
+}
+
+impl PunishmentTerminology {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PunishmentTerminology::Punishments => "punishments",
+            PunishmentTerminology::Consequences => "consequences",
+            PunishmentTerminology::Custom => "custom",
+        }
+    }
+}
+
+impl FromStr for PunishmentTerminology {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "punishments" => Ok(PunishmentTerminology::Punishments),
+            "consequences" => Ok(PunishmentTerminology::Consequences),
+            "custom" => Ok(PunishmentTerminology::Custom),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Optional household modules that can be switched off
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum HouseholdFeature {
+    Rewards,
+    Punishments,
+    Chat,
+    Notes,
+    Journal,
+    Expenses,
+    Meals,
+    ShoppingList,
+    Inventory,
+    Appliances,
+    Plants,
+    Pets,
+    Challenges,
+    Statistics,
+}
+
+impl HouseholdFeature {
+    pub const ALL: [HouseholdFeature; 14] = [
+        HouseholdFeature::Rewards,
+        HouseholdFeature::Punishments,
+        HouseholdFeature::Chat,
+        HouseholdFeature::Notes,
+        HouseholdFeature::Journal,
+        HouseholdFeature::Expenses,
+        HouseholdFeature::Meals,
+        HouseholdFeature::ShoppingList,
+        HouseholdFeature::Inventory,
+        HouseholdFeature::Appliances,
+        HouseholdFeature::Plants,
+        HouseholdFeature::Pets,
+        HouseholdFeature::Challenges,
+        HouseholdFeature::Statistics,
+    ];
+
+    /// Modules new households start without
+    pub const OFF_BY_DEFAULT: [HouseholdFeature; 3] = [
+        HouseholdFeature::Rewards,
+        HouseholdFeature::Punishments,
+        HouseholdFeature::Chat,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HouseholdFeature::Rewards => "rewards",
+            HouseholdFeature::Punishments => "punishments",
+            HouseholdFeature::Chat => "chat",
+            HouseholdFeature::Notes => "notes",
+            HouseholdFeature::Journal => "journal",
+            HouseholdFeature::Expenses => "expenses",
+            HouseholdFeature::Meals => "meals",
+            HouseholdFeature::ShoppingList => "shopping_list",
+            HouseholdFeature::Inventory => "inventory",
+            HouseholdFeature::Appliances => "appliances",
+            HouseholdFeature::Plants => "plants",
+            HouseholdFeature::Pets => "pets",
+            HouseholdFeature::Challenges => "challenges",
+            HouseholdFeature::Statistics => "statistics",
+        }
+    }
+
+    /// The module served under `/households/{id}/{segment}`, if any. Routes shared
+    /// by several modules, such as attachments, check the feature in the handler.
+    pub fn from_path_segment(segment: &str) -> Option<Self> {
+        match segment {
+            "rewards" | "wishlist" => Some(HouseholdFeature::Rewards),
+            "punishments" => Some(HouseholdFeature::Punishments),
+            "chat" => Some(HouseholdFeature::Chat),
+            "notes" => Some(HouseholdFeature::Notes),
+            "journal" => Some(HouseholdFeature::Journal),
+            "expenses" => Some(HouseholdFeature::Expenses),
+            "meals" | "recipes" => Some(HouseholdFeature::Meals),
+            "shopping-list" => Some(HouseholdFeature::ShoppingList),
+            "inventory" => Some(HouseholdFeature::Inventory),
+            "appliances" => Some(HouseholdFeature::Appliances),
+            "plants" => Some(HouseholdFeature::Plants),
+            "pets" => Some(HouseholdFeature::Pets),
+            "challenges" => Some(HouseholdFeature::Challenges),
+            "statistics" => Some(HouseholdFeature::Statistics),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for HouseholdFeature {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        HouseholdFeature::ALL
+            .into_iter()
+            .find(|feature| feature.as_str() == s.to_lowercase())
+            .ok_or(())
+    }
+}
+
+/// Highest allowance rate per point, in cents
+pub const MAX_ALLOWANCE_CENTS_PER_POINT: i64 = 10_000;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct HouseholdSettings {
     pub household_id: Uuid,
     pub dark_mode: bool,
@@ -150,9 +475,6 @@ pub struct HouseholdSettings {
     pub role_label_member: String,
     pub hierarchy_type: HierarchyType,
     pub timezone: String,
-    pub rewards_enabled: bool,
-    pub punishments_enabled: bool,
-    pub chat_enabled: bool,
     /// Whether the household is in vacation mode (all tasks paused)
     pub vacation_mode: bool,
     /// Optional start date for vacation mode (None = immediate)
@@ -179,6 +501,61 @@ pub struct HouseholdSettings {
     pub solo_mode_exit_requested_at: Option<DateTime<Utc>>,
     /// Previous hierarchy type to restore after Solo Mode ends
     pub solo_mode_previous_hierarchy_type: Option<HierarchyType>,
+    /// Post a weekly recap as an announcement at the end of each week
+    #[serde(default)]
+    pub weekly_summary_enabled: bool,
+    /// Also email the weekly recap to all members
+    #[serde(default)]
+    pub weekly_summary_email: bool,
+    /// How members without a task completion in a week lose points
+    #[serde(default)]
+    pub point_decay_mode: PointDecayMode,
+    /// Percent or points lost per inactive week, depending on `point_decay_mode`
+    #[serde(default)]
+    pub point_decay_amount: i32,
+    /// Reward purchases by members without review permission wait for approval
+    /// before any points are deducted
+    #[serde(default)]
+    pub purchase_approval_required: bool,
+    /// Points a streak freeze costs (0 = members can't buy streak freezes)
+    #[serde(default)]
+    pub streak_freeze_price: i64,
+    /// Whether new tasks require review unless the creator says otherwise
+    #[serde(default)]
+    pub default_requires_review: bool,
+    /// Whether new tasks allow completions beyond the target unless the creator says otherwise
+    #[serde(default = "default_true")]
+    pub default_allow_exceed_target: bool,
+    /// Factor applied to the default points of new good habits
+    #[serde(default = "default_points_multiplier")]
+    pub good_habit_points_multiplier: f64,
+    /// Factor applied to the default points of new bad habits
+    #[serde(default = "default_points_multiplier")]
+    pub bad_habit_points_multiplier: f64,
+    /// New tasks rewarding more points than this require review (None = no threshold)
+    #[serde(default)]
+    pub review_points_threshold: Option<i64>,
+    /// Modules switched off for this household; they are hidden and their endpoints refuse requests
+    #[serde(default)]
+    pub disabled_features: Vec<HouseholdFeature>,
+    /// What punishments are called in this household
+    #[serde(default)]
+    pub punishment_terminology: PunishmentTerminology,
+    /// Custom name for one punishment, used with `PunishmentTerminology::Custom`
+    #[serde(default)]
+    pub punishment_label_singular: Option<String>,
+    /// Custom name for several punishments, used with `PunishmentTerminology::Custom`
+    #[serde(default)]
+    pub punishment_label_plural: Option<String>,
+    /// Allowance paid per point, in cents (0 = no monthly allowance)
+    #[serde(default)]
+    pub allowance_cents_per_point: i64,
+    /// Whether paid-out points are taken away or kept
+    #[serde(default)]
+    pub allowance_point_handling: AllowancePointHandling,
+    /// Incremented on every update, for optimistic locking
+    #[serde(default)]
+    pub version: i64,
     pub updated_at: DateTime<Utc>,
 }
 
@@ -192,9 +569,6 @@ impl Default for HouseholdSettings {
             role_label_member: "Member".to_string(),
             hierarchy_type: HierarchyType::default(),
             timezone: "UTC".to_string(),
-            rewards_enabled: false,
-            punishments_enabled: false,
-            chat_enabled: false,
             vacation_mode: false,
             vacation_start: None,
             vacation_end: None,
@@ -208,12 +582,38 @@ impl Default for HouseholdSettings {
             solo_mode: false,
             solo_mode_exit_requested_at: None,
             solo_mode_previous_hierarchy_type: None,
+            weekly_summary_enabled: false,
+            weekly_summary_email: false,
+            point_decay_mode: PointDecayMode::None,
+            point_decay_amount: 0,
+            purchase_approval_required: false,
+            streak_freeze_price: 0,
+            default_requires_review: false,
+            default_allow_exceed_target: true,
+            good_habit_points_multiplier: 1.0,
+            bad_habit_points_multiplier: 1.0,
+            review_points_threshold: None,
+            disabled_features: HouseholdFeature::OFF_BY_DEFAULT.to_vec(),
+            punishment_terminology: PunishmentTerminology::Punishments,
+            punishment_label_singular: None,
+            punishment_label_plural: None,
+            allowance_cents_per_point: 0,
+            allowance_point_handling: AllowancePointHandling::Reset,
+            version: 1,
             updated_at: Utc::now(),
         }
     }
 }
 
+fn default_points_multiplier() -> f64 {
+    1.0
+}
+
 impl HouseholdSettings {
+    pub fn is_feature_enabled(&self, feature: HouseholdFeature) -> bool {
+        !self.disabled_features.contains(&feature)
+    }
+
     /// Check if a Solo Mode exit is pending
     pub fn is_solo_mode_exit_pending(&self) -> bool {
         self.solo_mode && self.solo_mode_exit_requested_at.is_some()
@@ -236,9 +636,46 @@ impl HouseholdSettings {
             .map(|s| s <= 0)
             .unwrap_or(false)
     }
+
+    /// Default (reward, penalty) points for a new task of the given habit type
+    pub fn default_points_for(&self, habit_type: HabitType) -> (Option<i64>, Option<i64>) {
+        let multiplier = match habit_type {
+            HabitType::Good => self.good_habit_points_multiplier,
+            HabitType::Bad => self.bad_habit_points_multiplier,
+        };
+        let scale = |points: i64| (points as f64 * multiplier).round() as i64;
+        (
+            self.default_points_reward.map(scale),
+            self.default_points_penalty.map(scale),
+        )
+    }
+
+    /// Whether a new task rewarding `points_reward` requires review by default
+    pub fn default_requires_review_for(&self, points_reward: Option<i64>) -> bool {
+        self.default_requires_review
+            || matches!((self.review_points_threshold, points_reward), (Some(threshold), Some(points)) if points > threshold)
+    }
+
+    /// Fill in everything a new task leaves open with the household defaults
+    pub fn apply_task_defaults(&self, request: &mut CreateTaskRequest) {
+        let (points_reward, points_penalty) = self.default_points_for(request.habit_type.unwrap_or_default());
+        if request.points_reward.is_none() {
+            request.points_reward = points_reward;
+        }
+        if request.points_penalty.is_none() {
+            request.points_penalty = points_penalty;
+        }
+        if request.allow_exceed_target.is_none() {
+            request.allow_exceed_target = Some(self.default_allow_exceed_target);
+        }
+        if request.requires_review.is_none() {
+            request.requires_review = Some(self.default_requires_review_for(request.points_reward));
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UpdateHouseholdSettingsRequest {
     pub dark_mode: Option<bool>,
     pub role_label_owner: Option<String>,
@@ -246,9 +683,6 @@ pub struct UpdateHouseholdSettingsRequest {
     pub role_label_member: Option<String>,
     pub hierarchy_type: Option<HierarchyType>,
     pub timezone: Option<String>,
-    pub rewards_enabled: Option<bool>,
-    pub punishments_enabled: Option<bool>,
-    pub chat_enabled: Option<bool>,
     /// Enable/disable vacation mode
     pub vacation_mode: Option<bool>,
     /// Set vacation start date (Some(None) to clear)
@@ -269,6 +703,58 @@ pub struct UpdateHouseholdSettingsRequest {
     pub default_rewards: Option<Vec<DefaultRewardEntry>>,
     /// Default punishments to link to new tasks (replaces all existing)
     pub default_punishments: Option<Vec<DefaultPunishmentEntry>>,
+    /// Enable/disable the weekly recap announcement
+    pub weekly_summary_enabled: Option<bool>,
+    /// Enable/disable emailing the weekly recap
+    pub weekly_summary_email: Option<bool>,
+    /// Point decay mode for inactive members
+    #[serde(default)]
+    pub point_decay_mode: Option<PointDecayMode>,
+    /// Percent or points lost per inactive week
+    #[serde(default)]
+    pub point_decay_amount: Option<i32>,
+    /// Require approval for reward purchases
+    #[serde(default)]
+    pub purchase_approval_required: Option<bool>,
+    /// Points a streak freeze costs (0 disables streak freezes)
+    #[serde(default)]
+    pub streak_freeze_price: Option<i64>,
+    /// Require review for new tasks by default
+    #[serde(default)]
+    pub default_requires_review: Option<bool>,
+    /// Allow exceeding the target for new tasks by default
+    #[serde(default)]
+    pub default_allow_exceed_target: Option<bool>,
+    /// Factor for the default points of new good habits
+    #[serde(default)]
+    pub good_habit_points_multiplier: Option<f64>,
+    /// Factor for the default points of new bad habits
+    #[serde(default)]
+    pub bad_habit_points_multiplier: Option<f64>,
+    /// Points above which new tasks require review (Some(None) to clear)
+    #[serde(default)]
+    pub review_points_threshold: Option<Option<i64>>,
+    /// Modules to switch off (replaces the current list)
+    #[serde(default)]
+    pub disabled_features: Option<Vec<HouseholdFeature>>,
+    /// What punishments are called
+    #[serde(default)]
+    pub punishment_terminology: Option<PunishmentTerminology>,
+    /// Custom name for one punishment (Some(None) to clear)
+    #[serde(default)]
+    pub punishment_label_singular: Option<Option<String>>,
+    /// Custom name for several punishments (Some(None) to clear)
+    #[serde(default)]
+    pub punishment_label_plural: Option<Option<String>>,
+    /// Allowance paid per point, in cents (0 disables the monthly allowance)
+    #[serde(default)]
+    pub allowance_cents_per_point: Option<i64>,
+    /// Whether paid-out points are taken away or kept
+    #[serde(default)]
+    pub allowance_point_handling: Option<AllowancePointHandling>,
+    /// Version the change is based on; a mismatch is rejected with 409
+    #[serde(default)]
+    pub version: Option<i64>,
 }
 
 // ============================================================================
@@ -276,9 +762,14 @@ pub struct UpdateHouseholdSettingsRequest {
 // ============================================================================
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UserSettings {
     pub user_id: Uuid,
     pub language: String,
+    #[serde(default)]
+    pub theme: Theme,
+    #[serde(default)]
+    pub notification_preferences: NotificationPreferences,
     pub updated_at: DateTime<Utc>,
 }
 
@@ -287,14 +778,139 @@ impl Default for UserSettings {
         Self {
             user_id: Uuid::nil(),
             language: "en".to_string(),
+            theme: Theme::default(),
+            notification_preferences: NotificationPreferences::default(),
             updated_at: Utc::now(),
         }
     }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UpdateUserSettingsRequest {
     pub language: Option<String>,
+    #[serde(default)]
+    pub theme: Option<Theme>,
+    #[serde(default)]
+    pub notification_preferences: Option<NotificationPreferences>,
+}
+
+/// Languages the app is translated into, as code and native name. English is
+/// built into the frontend; the others are loaded from `/assets/i18n/{code}.json`.
+pub const SUPPORTED_LANGUAGES: &[(&str, &str)] = &[("en", "English"), ("de", "Deutsch")];
+
+/// Colour theme of the app for one user
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Theme {
+    /// Follow the device's light/dark preference
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+impl Theme {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Theme::System => "system",
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+        }
+    }
+}
+
+impl FromStr for Theme {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "system" => Ok(Theme::System),
+            "light" => Ok(Theme::Light),
+            "dark" => Ok(Theme::Dark),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Kinds of push notifications a user can switch off individually
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKind {
+    /// New chat messages
+    Chat,
+    /// Completions waiting for review
+    Reviews,
+    /// Tasks assigned to the user
+    Assignments,
+    /// Tasks whose due time is approaching
+    Reminders,
+}
+
+/// Which notifications a user wants and when they should stay silent
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct NotificationPreferences {
+    pub chat: bool,
+    pub reviews: bool,
+    pub assignments: bool,
+    pub reminders: bool,
+    /// Start of the quiet hours ("HH:MM", household time)
+    #[serde(default)]
+    pub quiet_hours_start: Option<String>,
+    /// End of the quiet hours ("HH:MM"); may be earlier than the start to span midnight
+    #[serde(default)]
+    pub quiet_hours_end: Option<String>,
+}
+
+impl Default for NotificationPreferences {
+    fn default() -> Self {
+        Self {
+            chat: true,
+            reviews: true,
+            assignments: true,
+            reminders: true,
+            quiet_hours_start: None,
+            quiet_hours_end: None,
+        }
+    }
+}
+
+impl NotificationPreferences {
+    pub fn is_enabled(&self, kind: NotificationKind) -> bool {
+        match kind {
+            NotificationKind::Chat => self.chat,
+            NotificationKind::Reviews => self.reviews,
+            NotificationKind::Assignments => self.assignments,
+            NotificationKind::Reminders => self.reminders,
+        }
+    }
+
+    /// The quiet hours window, if both ends are set and valid
+    pub fn quiet_hours(&self) -> Option<(NaiveTime, NaiveTime)> {
+        let parse = |value: &Option<String>| {
+            value
+                .as_deref()
+                .and_then(|v| NaiveTime::parse_from_str(v, "%H:%M").ok())
+        };
+        Some((parse(&self.quiet_hours_start)?, parse(&self.quiet_hours_end)?))
+    }
+
+    /// Whether `time` falls into the quiet hours (start inclusive, end exclusive)
+    pub fn is_quiet_at(&self, time: NaiveTime) -> bool {
+        match self.quiet_hours() {
+            Some((start, end)) if start <= end => time >= start && time < end,
+            Some((start, end)) => time >= start || time < end,
+            None => false,
+        }
+    }
+
+    /// Whether a notification of `kind` may be delivered at the given local time
+    pub fn allows(&self, kind: NotificationKind, local_time: NaiveTime) -> bool {
+        self.is_enabled(kind) && !self.is_quiet_at(local_time)
+    }
 }
 
 // ============================================================================
@@ -302,6 +918,7 @@ pub struct UpdateUserSettingsRequest {
 // ============================================================================
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum Role {
     Owner,
@@ -352,40 +969,226 @@ impl FromStr for Role {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct HouseholdMembership {
-    pub id: Uuid,
-    pub household_id: Uuid,
-    pub user_id: Uuid,
-    pub role: Role,
-    pub points: i64,
-    pub joined_at: DateTime<Utc>,
-}
+/// A single capability that can be granted to a household member
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Permission {
+    /// Create, edit, archive and delete tasks, categories and suggestions
+    ManageTasks,
+    /// Manage rewards and punishments and hand them out
+    ManageRewards,
+    /// Adjust member points and edit point conditions
+    AdjustPoints,
+    /// Approve or reject task completions, redemptions and punishment completions
+    ReviewCompletions,
+    /// Invite and remove members
+    ManageMembers,
+}
+
+impl Permission {
+    pub const ALL: [Permission; 5] = [
+        Permission::ManageTasks,
+        Permission::ManageRewards,
+        Permission::AdjustPoints,
+        Permission::ReviewCompletions,
+        Permission::ManageMembers,
+    ];
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MemberWithUser {
-    pub membership: HouseholdMembership,
-    pub user: User,
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Permission::ManageTasks => "manage_tasks",
+            Permission::ManageRewards => "manage_rewards",
+            Permission::AdjustPoints => "adjust_points",
+            Permission::ReviewCompletions => "review_completions",
+            Permission::ManageMembers => "manage_members",
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct InviteUserRequest {
-    pub email: String,
-    pub role: Option<Role>,
-}
+impl FromStr for Permission {
+    type Err = ();
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct UpdateRoleRequest {
-    pub role: Role,
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "manage_tasks" => Ok(Permission::ManageTasks),
+            "manage_rewards" => Ok(Permission::ManageRewards),
+            "adjust_points" => Ok(Permission::AdjustPoints),
+            "review_completions" => Ok(Permission::ReviewCompletions),
+            "manage_members" => Ok(Permission::ManageMembers),
+            _ => Err(()),
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct AdjustPointsRequest {
-    pub points: i64,
-    pub reason: Option<String>,
+/// Permission matrix of a single membership
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MemberPermissions {
+    #[serde(default)]
+    pub manage_tasks: bool,
+    #[serde(default)]
+    pub manage_rewards: bool,
+    #[serde(default)]
+    pub adjust_points: bool,
+    #[serde(default)]
+    pub review_completions: bool,
+    #[serde(default)]
+    pub manage_members: bool,
+}
+
+impl MemberPermissions {
+    /// Every permission granted
+    pub fn all() -> Self {
+        Self {
+            manage_tasks: true,
+            manage_rewards: true,
+            adjust_points: true,
+            review_completions: true,
+            manage_members: true,
+        }
+    }
+
+    /// Preset for teenagers: may create and edit tasks but never touch points
+    pub fn teen() -> Self {
+        Self {
+            manage_tasks: true,
+            ..Self::default()
+        }
+    }
+
+    /// Permissions a role gets when no custom matrix is stored for the membership.
+    ///
+    /// In the `Equals` hierarchy everyone gets every permission except managing
+    /// members, which stays with owners and admins.
+    pub fn for_role(role: &Role, hierarchy: &HierarchyType) -> Self {
+        let mut permissions = match role {
+            Role::Owner | Role::Admin => Self::all(),
+            Role::Member => Self::default(),
+        };
+        if hierarchy.can_manage(role) {
+            permissions.manage_tasks = true;
+            permissions.manage_rewards = true;
+            permissions.adjust_points = true;
+            permissions.review_completions = true;
+        }
+        permissions
+    }
+
+    pub fn has(&self, permission: Permission) -> bool {
+        match permission {
+            Permission::ManageTasks => self.manage_tasks,
+            Permission::ManageRewards => self.manage_rewards,
+            Permission::AdjustPoints => self.adjust_points,
+            Permission::ReviewCompletions => self.review_completions,
+            Permission::ManageMembers => self.manage_members,
+        }
+    }
+
+    pub fn set(&mut self, permission: Permission, granted: bool) {
+        match permission {
+            Permission::ManageTasks => self.manage_tasks = granted,
+            Permission::ManageRewards => self.manage_rewards = granted,
+            Permission::AdjustPoints => self.adjust_points = granted,
+            Permission::ReviewCompletions => self.review_completions = granted,
+            Permission::ManageMembers => self.manage_members = granted,
+        }
+    }
+
+    /// Granted permissions in their canonical order
+    pub fn granted(&self) -> Vec<Permission> {
+        Permission::ALL.into_iter().filter(|p| self.has(*p)).collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct HouseholdMembership {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub user_id: Uuid,
+    pub role: Role,
+    pub points: i64,
+    pub joined_at: DateTime<Utc>,
+    /// First day of the member's vacation; streaks and punishments pause while it lasts
+    #[serde(default)]
+    pub vacation_start: Option<NaiveDate>,
+    /// Last day of the member's vacation (open-ended when unset)
+    #[serde(default)]
+    pub vacation_end: Option<NaiveDate>,
+}
+
+impl HouseholdMembership {
+    /// Check whether the member is on vacation on the given date
+    pub fn is_on_vacation(&self, date: NaiveDate) -> bool {
+        self.is_on_vacation_during(date, date)
+    }
+
+    /// Check whether the member's vacation overlaps the inclusive range `start..=end`
+    pub fn is_on_vacation_during(&self, start: NaiveDate, end: NaiveDate) -> bool {
+        match (self.vacation_start, self.vacation_end) {
+            (Some(vacation_start), Some(vacation_end)) => vacation_start <= end && vacation_end >= start,
+            (Some(vacation_start), None) => vacation_start <= end,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MemberWithUser {
+    pub membership: HouseholdMembership,
+    pub user: User,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct InviteUserRequest {
+    pub email: String,
+    pub role: Option<Role>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UpdateRoleRequest {
+    pub role: Role,
 }
 
+/// A member's effective permissions and whether they were customised by the owner
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MemberPermissionsResponse {
+    pub user_id: Uuid,
+    pub permissions: MemberPermissions,
+    /// False when the permissions are the defaults of the member's role
+    pub custom: bool,
+}
+
+/// Store a custom permission matrix for a member; `None` restores the role defaults
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UpdateMemberPermissionsRequest {
+    #[serde(default)]
+    pub permissions: Option<MemberPermissions>,
+}
+
+/// Set or clear a member's vacation; sending no start date ends the vacation
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SetMemberVacationRequest {
+    pub vacation_start: Option<NaiveDate>,
+    pub vacation_end: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AdjustPointsRequest {
+    pub points: i64,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct AdjustPointsResponse {
     pub new_points: i64,
 }
@@ -395,6 +1198,7 @@ pub struct AdjustPointsResponse {
 // ============================================================================
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TaskCategory {
     pub id: Uuid,
     pub household_id: Uuid,
@@ -405,6 +1209,7 @@ pub struct TaskCategory {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CreateTaskCategoryRequest {
     pub name: String,
     pub color: Option<String>,
@@ -412,6 +1217,7 @@ pub struct CreateTaskCategoryRequest {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UpdateTaskCategoryRequest {
     pub name: Option<String>,
     pub color: Option<String>,
@@ -419,15 +1225,215 @@ pub struct UpdateTaskCategoryRequest {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TaskCategoriesResponse {
     pub categories: Vec<TaskCategory>,
 }
 
+// ============================================================================
+// Task Tag Types
+// ============================================================================
+
+/// Free label for tasks. Unlike categories, a task can have many tags, so
+/// orthogonal dimensions like "outside" and "15-minutes" can be combined.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TaskTag {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub name: String,
+    pub color: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreateTaskTagRequest {
+    pub name: String,
+    pub color: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UpdateTaskTagRequest {
+    pub name: Option<String>,
+    pub color: Option<String>,
+}
+
+// ============================================================================
+// Saved Task Filter Types
+// ============================================================================
+
+/// Longest due window a task filter accepts, in days (about ten years)
+pub const MAX_DUE_WITHIN_DAYS: i64 = 3650;
+
+/// Years of the dates the API accepts from clients. Dates further out have no
+/// use in a household and would let date arithmetic on them overflow.
+pub const SUPPORTED_YEARS: std::ops::RangeInclusive<i32> = 1900..=9999;
+
+/// Whether a client-supplied date lies within `SUPPORTED_YEARS`
+pub fn is_supported_date(date: NaiveDate) -> bool {
+    SUPPORTED_YEARS.contains(&date.year())
+}
+
+/// Criteria for the task list endpoints; a task must match every criterion that is set.
+/// Sent as query parameters (see `to_query_params`) and stored in saved filters.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TaskFilter {
+    #[serde(default)]
+    pub assignee_id: Option<Uuid>,
+    /// Tasks must carry all of these tags
+    #[serde(default)]
+    pub tag_ids: Vec<Uuid>,
+    #[serde(default)]
+    pub category_id: Option<Uuid>,
+    /// Only tasks whose next due date is at most this many days away
+    #[serde(default)]
+    pub due_within_days: Option<i64>,
+    #[serde(default)]
+    pub habit_type: Option<HabitType>,
+}
+
+impl TaskFilter {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Whether the due window is unset or within `0..=MAX_DUE_WITHIN_DAYS`
+    pub fn has_valid_due_window(&self) -> bool {
+        self.due_within_days
+            .is_none_or(|days| (0..=MAX_DUE_WITHIN_DAYS).contains(&days))
+    }
+
+    /// Query parameters for the task list endpoints, e.g. `["tags=<id>,<id>", "due_within=7"]`
+    pub fn to_query_params(&self) -> Vec<String> {
+        let mut params = Vec::new();
+        if let Some(id) = self.assignee_id {
+            params.push(format!("assignee={}", id));
+        }
+        if !self.tag_ids.is_empty() {
+            let ids: Vec<String> = self.tag_ids.iter().map(|id| id.to_string()).collect();
+            params.push(format!("tags={}", ids.join(",")));
+        }
+        if let Some(id) = self.category_id {
+            params.push(format!("category={}", id));
+        }
+        if let Some(days) = self.due_within_days {
+            params.push(format!("due_within={}", days));
+        }
+        if let Some(habit_type) = &self.habit_type {
+            params.push(format!("habit_type={}", habit_type.as_str()));
+        }
+        params
+    }
+}
+
+/// A user's named task filter, offered as a "smart list" on the tasks page
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SavedTaskFilter {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub filter: TaskFilter,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreateSavedTaskFilterRequest {
+    pub name: String,
+    pub filter: TaskFilter,
+}
+
+// ============================================================================
+// Focus Timer Types
+// ============================================================================
+
+/// Length of a focus session when none is requested (one pomodoro)
+pub const DEFAULT_FOCUS_MINUTES: i32 = 25;
+
+/// Longest focus session that can be planned
+pub const MAX_FOCUS_MINUTES: i32 = 180;
+
+/// State of a focus session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum FocusSessionStatus {
+    /// The timer is ticking
+    Running,
+    /// Stopped by the member; its focused time counts toward the task
+    Completed,
+    /// Abandoned; its time is not counted
+    Cancelled,
+}
+
+impl FocusSessionStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FocusSessionStatus::Running => "running",
+            FocusSessionStatus::Completed => "completed",
+            FocusSessionStatus::Cancelled => "cancelled",
+        }
+    }
+}
+
+impl FromStr for FocusSessionStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "running" => Ok(FocusSessionStatus::Running),
+            "completed" => Ok(FocusSessionStatus::Completed),
+            "cancelled" => Ok(FocusSessionStatus::Cancelled),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A focus timer session of a member working on a task. A member has at most
+/// one running session, which is kept on the server so it survives reloads.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FocusSession {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub task_id: Uuid,
+    pub task_title: String,
+    pub user_id: Uuid,
+    pub planned_minutes: i32,
+    pub status: FocusSessionStatus,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+    /// Time counted toward the task: the elapsed time when stopped, at most the planned length
+    pub focused_seconds: i64,
+}
+
+impl FocusSession {
+    /// Seconds until the planned end at `now`; negative once the session runs over
+    pub fn remaining_seconds(&self, now: DateTime<Utc>) -> i64 {
+        i64::from(self.planned_minutes) * 60 - (now - self.started_at).num_seconds()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct StartFocusSessionRequest {
+    pub task_id: Uuid,
+    /// Planned length in minutes, defaults to `DEFAULT_FOCUS_MINUTES`
+    #[serde(default)]
+    pub planned_minutes: Option<i32>,
+}
+
 // ============================================================================
 // Task Types
 // ============================================================================
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum RecurrenceType {
     Daily,
@@ -469,6 +1475,7 @@ impl FromStr for RecurrenceType {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub enum RecurrenceValue {
     /// For weekly: day of week (0 = Sunday, 1 = Monday, etc.)
     WeekDay(u8),
@@ -478,11 +1485,32 @@ pub enum RecurrenceValue {
     Weekdays(Vec<u8>),
     /// For custom: array of specific dates
     CustomDates(Vec<NaiveDate>),
+    /// For daily: every N days, counted from the day the task was created
+    DayInterval(u16),
+    /// For weekly: on a day of week every N weeks, counted from the week the task was created
+    WeekInterval { weeks: u16, weekday: u8 },
+    /// For monthly: the Nth day of week of the month (1-4, or -1 for the last one)
+    MonthWeekday { ordinal: i8, weekday: u8 },
     /// For daily: no value needed
     None,
 }
 
+impl RecurrenceValue {
+    /// Whether the interval is at least 1 and the day of week and ordinal are in range
+    pub fn is_valid(&self) -> bool {
+        match self {
+            RecurrenceValue::DayInterval(days) => *days >= 1,
+            RecurrenceValue::WeekInterval { weeks, weekday } => *weeks >= 1 && *weekday <= 6,
+            RecurrenceValue::MonthWeekday { ordinal, weekday } => {
+                (*ordinal == -1 || (1..=4).contains(ordinal)) && *weekday <= 6
+            }
+            _ => true,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum TimePeriod {
     Day,
@@ -521,6 +1549,7 @@ impl FromStr for TimePeriod {
 
 /// Type of habit determining reward/punishment behavior
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum HabitType {
     /// Normal habit: completion = reward, missed = punishment
@@ -556,8 +1585,46 @@ impl FromStr for HabitType {
     }
 }
 
+/// Who may approve or reject the completions of a task that requires review
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewerType {
+    /// Everyone allowed to review completions (owners by default)
+    #[default]
+    AnyReviewer,
+    /// Only the member named in `reviewer_id`, even without the review permission
+    Member,
+    /// Everyone allowed to review completions, except the member who completed the task
+    AnyoneExceptCompleter,
+}
+
+impl ReviewerType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReviewerType::AnyReviewer => "any_reviewer",
+            ReviewerType::Member => "member",
+            ReviewerType::AnyoneExceptCompleter => "anyone_except_completer",
+        }
+    }
+}
+
+impl FromStr for ReviewerType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "any_reviewer" => Ok(ReviewerType::AnyReviewer),
+            "member" => Ok(ReviewerType::Member),
+            "anyone_except_completer" => Ok(ReviewerType::AnyoneExceptCompleter),
+            _ => Err(()),
+        }
+    }
+}
+
 /// Status of a task suggestion
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum SuggestionStatus {
     /// Task has been suggested but not yet reviewed
@@ -591,7 +1658,28 @@ impl FromStr for SuggestionStatus {
     }
 }
 
+/// Largest effort estimate a task may have (one day)
+pub const MAX_EFFORT_MINUTES: i32 = 24 * 60;
+
+/// Parse a "MM-DD" month-day, accepting February 29
+pub fn parse_month_day(value: &str) -> Option<(u32, u32)> {
+    let (month, day) = value.split_once('-')?;
+    let (month, day) = (month.parse().ok()?, day.parse().ok()?);
+    // 2000 is a leap year, so every valid month-day exists in it
+    NaiveDate::from_ymd_opt(2000, month, day).map(|_| (month, day))
+}
+
+/// Deserialize a field that is present as `Some`, so an explicit `null` becomes `Some(None)`
+fn deserialize_some<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: serde::Deserializer<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Task {
     pub id: Uuid,
     pub household_id: Uuid,
@@ -599,7 +1687,12 @@ pub struct Task {
     pub description: String,
     pub recurrence_type: RecurrenceType,
     pub recurrence_value: Option<RecurrenceValue>,
+    /// First assignee; kept for clients that only know a single assignee
     pub assigned_user_id: Option<Uuid>,
+    /// Everyone assigned to the task. With more than one assignee, each of them
+    /// has to complete the task on their own in every period.
+    #[serde(default)]
+    pub assignee_ids: Vec<Uuid>,
     pub target_count: i32,
     pub time_period: Option<TimePeriod>,
     /// When true, users can track completions beyond the target count.
@@ -607,18 +1700,43 @@ pub struct Task {
     pub allow_exceed_target: bool,
     /// When true, task completions require owner/admin approval before being finalized.
     pub requires_review: bool,
+    /// Who may review completions when `requires_review` is set
+    #[serde(default)]
+    pub reviewer_type: ReviewerType,
+    /// The reviewer when `reviewer_type` is `Member`
+    #[serde(default)]
+    pub reviewer_id: Option<Uuid>,
     /// Points awarded when this task is completed
     pub points_reward: Option<i64>,
     /// Points deducted when this task is missed
     pub points_penalty: Option<i64>,
     /// Due time in "HH:MM" format. None means end of day (23:59)
     pub due_time: Option<String>,
+    /// Estimated minutes one completion takes, used for workload balancing
+    #[serde(default)]
+    pub effort_minutes: Option<i32>,
+    /// First day of the season the task is active in, as "MM-DD". None means January 1st.
+    #[serde(default)]
+    pub active_from: Option<String>,
+    /// Last day of the season the task is active in, as "MM-DD". None means December 31st.
+    /// A season may wrap around the new year (e.g. "11-01" to "02-28").
+    #[serde(default)]
+    pub active_until: Option<String>,
+    /// Last day the task is scheduled on; it is archived once this day is over
+    #[serde(default)]
+    pub ends_on: Option<NaiveDate>,
+    /// Number of completed periods after which the task is archived
+    #[serde(default)]
+    pub max_occurrences: Option<i32>,
     /// Type of habit: Good (normal) or Bad (inverted consequences)
     pub habit_type: HabitType,
     /// Optional category for grouping tasks
     pub category_id: Option<Uuid>,
     /// Category name (populated when loading task with category)
     pub category_name: Option<String>,
+    /// Tags of the task; unlike the category, a task can have any number of them
+    #[serde(default)]
+    pub tag_ids: Vec<Uuid>,
     /// Whether the task is archived (hidden from active lists)
     pub archived: bool,
     /// Whether the task is paused (no automated punishments while paused)
@@ -627,66 +1745,191 @@ pub struct Task {
     pub suggestion: Option<SuggestionStatus>,
     /// User who suggested this task (only set when suggestion is Some)
     pub suggested_by: Option<Uuid>,
+    /// Scheduled date of an occurrence that was postponed to `postponed_to`
+    #[serde(default)]
+    pub postponed_from: Option<NaiveDate>,
+    /// Date the postponed occurrence is due instead
+    #[serde(default)]
+    pub postponed_to: Option<NaiveDate>,
+    /// Incremented on every update, for optimistic locking
+    #[serde(default)]
+    pub version: i64,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+impl Task {
+    /// Everyone assigned to the task, falling back to `assigned_user_id`
+    pub fn assignees(&self) -> Vec<Uuid> {
+        if self.assignee_ids.is_empty() {
+            self.assigned_user_id.into_iter().collect()
+        } else {
+            self.assignee_ids.clone()
+        }
+    }
+
+    /// Whether the user may complete the task; unassigned tasks are open to everyone
+    pub fn is_assigned_to(&self, user_id: &Uuid) -> bool {
+        let assignees = self.assignees();
+        assignees.is_empty() || assignees.contains(user_id)
+    }
+
+    /// Whether every assignee completes the task separately (more than one assignee)
+    pub fn has_individual_completions(&self) -> bool {
+        self.assignees().len() > 1
+    }
+
+    /// Whether the user may review a completion by `completer_id`; `is_reviewer`
+    /// tells whether the user holds the review permission in the household.
+    /// A named reviewer who left the household (`reviewer_is_member` is false)
+    /// hands the review back to everyone with the permission, and one who
+    /// completed the task themselves hands it to everyone else with it.
+    pub fn can_review(&self, user_id: &Uuid, completer_id: &Uuid, is_reviewer: bool, reviewer_is_member: bool) -> bool {
+        match self.reviewer_type {
+            ReviewerType::AnyReviewer => is_reviewer,
+            ReviewerType::Member => match self.reviewer_id {
+                Some(reviewer_id) if reviewer_is_member && reviewer_id != *completer_id => reviewer_id == *user_id,
+                Some(_) if reviewer_is_member => is_reviewer && user_id != completer_id,
+                _ => is_reviewer,
+            },
+            ReviewerType::AnyoneExceptCompleter => is_reviewer && user_id != completer_id,
+        }
+    }
+
+    /// Whether the task has a season limiting the days it is active on
+    pub fn is_seasonal(&self) -> bool {
+        self.active_from.is_some() || self.active_until.is_some()
+    }
+
+    /// Whether `date` lies in the task's season; tasks without a season are always active
+    pub fn is_in_season(&self, date: NaiveDate) -> bool {
+        let from = self.active_from.as_deref().and_then(parse_month_day).unwrap_or((1, 1));
+        let until = self.active_until.as_deref().and_then(parse_month_day).unwrap_or((12, 31));
+        let day = (date.month(), date.day());
+        if from <= until {
+            from <= day && day <= until
+        } else {
+            day >= from || day <= until
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CreateTaskRequest {
     pub title: String,
     pub description: Option<String>,
     pub recurrence_type: RecurrenceType,
     pub recurrence_value: Option<RecurrenceValue>,
     pub assigned_user_id: Option<Uuid>,
+    /// All assignees; takes precedence over `assigned_user_id` when set
+    #[serde(default)]
+    pub assignee_ids: Option<Vec<Uuid>>,
     pub target_count: Option<i32>,
     pub time_period: Option<TimePeriod>,
     /// When true (default), users can track completions beyond the target count.
     pub allow_exceed_target: Option<bool>,
     /// When true, completions require owner/admin approval.
     pub requires_review: Option<bool>,
+    /// Who may review completions (defaults to any reviewer)
+    #[serde(default)]
+    pub reviewer_type: Option<ReviewerType>,
+    /// The reviewer when `reviewer_type` is `Member`
+    #[serde(default)]
+    pub reviewer_id: Option<Uuid>,
     /// Points awarded when this task is completed
     pub points_reward: Option<i64>,
     /// Points deducted when this task is missed
     pub points_penalty: Option<i64>,
     /// Due time in "HH:MM" format. None means end of day (23:59)
     pub due_time: Option<String>,
+    /// Estimated minutes one completion takes
+    #[serde(default)]
+    pub effort_minutes: Option<i32>,
+    /// Start of the season as "MM-DD"
+    #[serde(default)]
+    pub active_from: Option<String>,
+    /// End of the season as "MM-DD"
+    #[serde(default)]
+    pub active_until: Option<String>,
+    /// Last day the task is scheduled on
+    #[serde(default)]
+    pub ends_on: Option<NaiveDate>,
+    /// Number of completed periods after which the task is archived
+    #[serde(default)]
+    pub max_occurrences: Option<i32>,
     /// Type of habit: Good (normal) or Bad (inverted consequences)
     pub habit_type: Option<HabitType>,
     /// Optional category for grouping tasks
     pub category_id: Option<Uuid>,
+    /// Tags to attach to the task
+    #[serde(default)]
+    pub tag_ids: Option<Vec<Uuid>>,
     /// If true, this is a task suggestion from a member without create permission
     pub is_suggestion: Option<bool>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UpdateTaskRequest {
     pub title: Option<String>,
     pub description: Option<String>,
     pub recurrence_type: Option<RecurrenceType>,
     pub recurrence_value: Option<RecurrenceValue>,
     pub assigned_user_id: Option<Uuid>,
+    /// Replace all assignees (an empty list unassigns the task); takes precedence over `assigned_user_id`
+    #[serde(default)]
+    pub assignee_ids: Option<Vec<Uuid>>,
     pub target_count: Option<i32>,
     pub time_period: Option<TimePeriod>,
     pub allow_exceed_target: Option<bool>,
     pub requires_review: Option<bool>,
+    /// Who may review completions
+    #[serde(default)]
+    pub reviewer_type: Option<ReviewerType>,
+    /// The reviewer when `reviewer_type` is `Member` (use Some(None) to clear it)
+    #[serde(default, deserialize_with = "deserialize_some", skip_serializing_if = "Option::is_none")]
+    pub reviewer_id: Option<Option<Uuid>>,
     /// Points awarded when this task is completed
     pub points_reward: Option<i64>,
     /// Points deducted when this task is missed
     pub points_penalty: Option<i64>,
     /// Due time in "HH:MM" format. None means end of day (23:59)
     pub due_time: Option<String>,
+    /// Estimated minutes one completion takes (use Some(None) to clear the estimate)
+    #[serde(default)]
+    pub effort_minutes: Option<Option<i32>>,
+    /// Start of the season as "MM-DD" (use Some(None) to clear it)
+    #[serde(default, deserialize_with = "deserialize_some", skip_serializing_if = "Option::is_none")]
+    pub active_from: Option<Option<String>>,
+    /// End of the season as "MM-DD" (use Some(None) to clear it)
+    #[serde(default, deserialize_with = "deserialize_some", skip_serializing_if = "Option::is_none")]
+    pub active_until: Option<Option<String>>,
+    /// Last day the task is scheduled on (use Some(None) to clear it)
+    #[serde(default, deserialize_with = "deserialize_some", skip_serializing_if = "Option::is_none")]
+    pub ends_on: Option<Option<NaiveDate>>,
+    /// Completed periods after which the task is archived (use Some(None) to clear it)
+    #[serde(default, deserialize_with = "deserialize_some", skip_serializing_if = "Option::is_none")]
+    pub max_occurrences: Option<Option<i32>>,
     /// Type of habit: Good (normal) or Bad (inverted consequences)
     pub habit_type: Option<HabitType>,
     /// Optional category for grouping tasks (use Some(None) to clear the category)
     pub category_id: Option<Option<Uuid>>,
+    /// Replace all tags (an empty list removes them)
+    #[serde(default)]
+    pub tag_ids: Option<Vec<Uuid>>,
     /// Whether the task is archived
     pub archived: Option<bool>,
     /// Whether the task is paused (no automated punishments while paused)
     pub paused: Option<bool>,
+    /// Version the change is based on; a mismatch is rejected with 409
+    #[serde(default)]
+    pub version: Option<i64>,
 }
 
 /// Status of a task completion
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum CompletionStatus {
     /// Completion is approved (default for tasks without review, or after owner approval)
@@ -718,6 +1961,7 @@ impl FromStr for CompletionStatus {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TaskCompletion {
     pub id: Uuid,
     pub task_id: Uuid,
@@ -732,6 +1976,7 @@ fn default_true() -> bool {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TaskWithStatus {
     pub task: Task,
     pub completions_today: i32,
@@ -740,12 +1985,29 @@ pub struct TaskWithStatus {
     /// Next date when this task is due (None for OneTime tasks)
     pub next_due_date: Option<NaiveDate>,
     /// Whether the current user can complete this task based on assignment.
-    /// True if the task has no assigned user OR the current user is the assigned user.
+    /// True if the task has no assignees OR the current user is one of them,
+    /// unless another member has claimed it for the current period.
     #[serde(default = "default_true")]
     pub is_user_assigned: bool,
     /// Recent period results for habit tracker display (last 15 periods, oldest first)
     #[serde(default)]
     pub recent_periods: Vec<PeriodDisplay>,
+    /// Titles of prerequisite tasks not yet completed in the current period
+    #[serde(default)]
+    pub blocked_by: Vec<String>,
+    /// Member who volunteered for the task in the current period
+    #[serde(default)]
+    pub claimed_by: Option<TaskClaim>,
+}
+
+/// A member volunteering for an unassigned task for one period
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TaskClaim {
+    pub user_id: Uuid,
+    pub username: String,
+    pub period_start: NaiveDate,
+    pub claimed_at: DateTime<Utc>,
 }
 
 impl TaskWithStatus {
@@ -764,8 +2026,9 @@ impl TaskWithStatus {
     /// This is false when:
     /// - User is not assigned to the task (when task has an assigned user)
     /// - Target is met AND allow_exceed_target is false
+    /// - A prerequisite task has not been completed yet this period
     pub fn can_complete(&self) -> bool {
-        self.is_user_assigned && (self.task.allow_exceed_target || !self.is_target_met())
+        self.is_user_assigned && self.blocked_by.is_empty() && (self.task.allow_exceed_target || !self.is_target_met())
     }
 }
 
@@ -774,6 +2037,7 @@ impl TaskWithStatus {
 // ============================================================================
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum ConditionType {
     TaskComplete,
@@ -808,6 +2072,7 @@ impl FromStr for ConditionType {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct PointCondition {
     pub id: Uuid,
     pub household_id: Uuid,
@@ -821,6 +2086,7 @@ pub struct PointCondition {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CreatePointConditionRequest {
     pub name: String,
     pub condition_type: ConditionType,
@@ -831,6 +2097,7 @@ pub struct CreatePointConditionRequest {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UpdatePointConditionRequest {
     pub name: Option<String>,
     pub condition_type: Option<ConditionType>,
@@ -840,12 +2107,133 @@ pub struct UpdatePointConditionRequest {
     pub task_id: Option<Uuid>,
 }
 
+// ============================================================================
+// Point Transaction Types
+// ============================================================================
+
+/// Reason a member's point balance changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PointTransactionType {
+    /// Points awarded for completing a task
+    TaskCompleted,
+    /// Points taken back because a pending completion was rejected
+    CompletionReversed,
+    /// Points deducted for a missed task
+    TaskMissed,
+    /// Points deducted because a bad habit occurred
+    BadHabitCompleted,
+    /// Points awarded for avoiding a bad habit
+    BadHabitAvoided,
+    /// Points spent on a reward
+    RewardPurchased,
+    /// Manual adjustment by an owner or admin
+    ManualAdjustment,
+    /// Points lost to weekly decay for inactivity
+    PointDecay,
+    /// Points spent on a streak freeze
+    StreakFreezePurchased,
+    /// Points exchanged for the monthly allowance
+    AllowancePayout,
+}
+
+impl PointTransactionType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PointTransactionType::TaskCompleted => "task_completed",
+            PointTransactionType::CompletionReversed => "completion_reversed",
+            PointTransactionType::TaskMissed => "task_missed",
+            PointTransactionType::BadHabitCompleted => "bad_habit_completed",
+            PointTransactionType::BadHabitAvoided => "bad_habit_avoided",
+            PointTransactionType::RewardPurchased => "reward_purchased",
+            PointTransactionType::ManualAdjustment => "manual_adjustment",
+            PointTransactionType::PointDecay => "point_decay",
+            PointTransactionType::StreakFreezePurchased => "streak_freeze_purchased",
+            PointTransactionType::AllowancePayout => "allowance_payout",
+        }
+    }
+}
+
+impl FromStr for PointTransactionType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "task_completed" => Ok(PointTransactionType::TaskCompleted),
+            "completion_reversed" => Ok(PointTransactionType::CompletionReversed),
+            "task_missed" => Ok(PointTransactionType::TaskMissed),
+            "bad_habit_completed" => Ok(PointTransactionType::BadHabitCompleted),
+            "bad_habit_avoided" => Ok(PointTransactionType::BadHabitAvoided),
+            "reward_purchased" => Ok(PointTransactionType::RewardPurchased),
+            "manual_adjustment" => Ok(PointTransactionType::ManualAdjustment),
+            "point_decay" => Ok(PointTransactionType::PointDecay),
+            "streak_freeze_purchased" => Ok(PointTransactionType::StreakFreezePurchased),
+            "allowance_payout" => Ok(PointTransactionType::AllowancePayout),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A member's allowance for one month, computed from their points
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AllowancePayout {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub user_id: Uuid,
+    /// First day of the paid month
+    pub month: NaiveDate,
+    /// Points the payout was computed from
+    pub points: i64,
+    pub amount_cents: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A single entry in a member's points history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PointTransaction {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub user_id: Uuid,
+    /// Signed point change (negative for deductions)
+    pub amount: i64,
+    /// Member's balance right after this transaction
+    pub balance_after: i64,
+    pub transaction_type: PointTransactionType,
+    pub task_id: Option<Uuid>,
+    pub reward_id: Option<Uuid>,
+    /// User who made a manual adjustment
+    pub actor_id: Option<Uuid>,
+    /// Task title, reward name or adjustment reason at the time of the transaction
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema, utoipa::IntoParams), into_params(parameter_in = Query))]
+pub struct ListPointHistoryRequest {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+/// One page of a member's points history, newest first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PointHistoryPage {
+    pub transactions: Vec<PointTransaction>,
+    /// Total number of transactions for the member
+    pub total: i64,
+}
+
 // ============================================================================
 // Reward Types
 // ============================================================================
 
 /// Type of reward determining its behavior
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum RewardType {
     /// Standard reward: describes what the reward is
@@ -881,6 +2269,7 @@ impl FromStr for RewardType {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Reward {
     pub id: Uuid,
     pub household_id: Uuid,
@@ -890,10 +2279,14 @@ pub struct Reward {
     pub is_purchasable: bool,
     pub requires_confirmation: bool,
     pub reward_type: RewardType,
+    /// Random choice only: options among the member's last N picks are not picked again (0 = repeats allowed)
+    #[serde(default)]
+    pub no_repeat_picks: i32,
     pub created_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CreateRewardRequest {
     pub name: String,
     pub description: Option<String>,
@@ -902,9 +2295,12 @@ pub struct CreateRewardRequest {
     pub requires_confirmation: Option<bool>,
     pub reward_type: Option<RewardType>,
     pub option_ids: Option<Vec<Uuid>>,
+    #[serde(default)]
+    pub no_repeat_picks: Option<i32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UpdateRewardRequest {
     pub name: Option<String>,
     pub description: Option<String>,
@@ -914,9 +2310,12 @@ pub struct UpdateRewardRequest {
     pub reward_type: Option<RewardType>,
     /// None = no change, Some(None) = clear all options, Some(vec) = set options
     pub option_ids: Option<Option<Vec<Uuid>>>,
+    #[serde(default)]
+    pub no_repeat_picks: Option<i32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UserReward {
     pub id: Uuid,
     pub user_id: Uuid,
@@ -929,27 +2328,44 @@ pub struct UserReward {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UserRewardWithDetails {
     pub user_reward: UserReward,
     pub reward: Reward,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UserRewardWithUser {
     pub user_reward: UserReward,
     pub user: User,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct RewardOption {
     pub id: Uuid,
     pub parent_reward_id: Uuid,
     pub option_reward_id: Uuid,
+    /// Relative chance of being picked
+    #[serde(default = "default_option_weight")]
+    pub weight: i32,
     pub created_at: DateTime<Utc>,
 }
 
+/// An option of a random choice reward together with its weight
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct WeightedRewardOption {
+    #[serde(flatten)]
+    pub reward: Reward,
+    #[serde(default = "default_option_weight")]
+    pub weight: i32,
+}
+
 /// Result of picking a random reward option
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct RandomRewardPickResult {
     pub picked_reward: Reward,
     pub user_reward: UserReward,
@@ -961,6 +2377,7 @@ pub struct RandomRewardPickResult {
 
 /// Type of punishment determining its behavior
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum PunishmentType {
     /// Standard punishment: describes what the punishment is
@@ -996,6 +2413,7 @@ impl FromStr for PunishmentType {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Punishment {
     pub id: Uuid,
     pub household_id: Uuid,
@@ -1003,19 +2421,26 @@ pub struct Punishment {
     pub description: String,
     pub requires_confirmation: bool,
     pub punishment_type: PunishmentType,
+    /// Random choice only: options among the member's last N picks are not picked again (0 = repeats allowed)
+    #[serde(default)]
+    pub no_repeat_picks: i32,
     pub created_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CreatePunishmentRequest {
     pub name: String,
     pub description: Option<String>,
     pub requires_confirmation: Option<bool>,
     pub punishment_type: Option<PunishmentType>,
     pub option_ids: Option<Vec<Uuid>>,
+    #[serde(default)]
+    pub no_repeat_picks: Option<i32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UpdatePunishmentRequest {
     pub name: Option<String>,
     pub description: Option<String>,
@@ -1023,9 +2448,12 @@ pub struct UpdatePunishmentRequest {
     pub punishment_type: Option<PunishmentType>,
     /// None = no change, Some(None) = clear all options, Some(vec) = set options
     pub option_ids: Option<Option<Vec<Uuid>>>,
+    #[serde(default)]
+    pub no_repeat_picks: Option<i32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UserPunishment {
     pub id: Uuid,
     pub user_id: Uuid,
@@ -1038,27 +2466,59 @@ pub struct UserPunishment {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UserPunishmentWithDetails {
     pub user_punishment: UserPunishment,
     pub punishment: Punishment,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UserPunishmentWithUser {
     pub user_punishment: UserPunishment,
     pub user: User,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct PunishmentOption {
     pub id: Uuid,
     pub parent_punishment_id: Uuid,
     pub option_punishment_id: Uuid,
+    /// Relative chance of being picked
+    #[serde(default = "default_option_weight")]
+    pub weight: i32,
     pub created_at: DateTime<Utc>,
 }
 
+/// An option of a random choice punishment together with its weight
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct WeightedPunishmentOption {
+    #[serde(flatten)]
+    pub punishment: Punishment,
+    #[serde(default = "default_option_weight")]
+    pub weight: i32,
+}
+
+/// Highest weight a random choice option can have
+pub const MAX_OPTION_WEIGHT: i32 = 100;
+
+fn default_option_weight() -> i32 {
+    1
+}
+
+/// Change how likely an option of a random choice reward or punishment is picked
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UpdateOptionWeightRequest {
+    /// 1 to `MAX_OPTION_WEIGHT`
+    pub weight: i32,
+}
+
 /// Result of picking a random punishment option
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct RandomPickResult {
     pub picked_punishment: Punishment,
     pub user_punishment: UserPunishment,
@@ -1070,6 +2530,7 @@ pub struct RandomPickResult {
 
 /// A pending reward redemption awaiting confirmation
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct PendingRewardRedemption {
     pub user_reward: UserReward,
     pub reward: Reward,
@@ -1078,17 +2539,97 @@ pub struct PendingRewardRedemption {
 
 /// A pending punishment completion awaiting confirmation
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct PendingPunishmentCompletion {
     pub user_punishment: UserPunishment,
     pub punishment: Punishment,
     pub user: User,
 }
 
+/// Review state of a reward purchase that needs approval
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum PurchaseStatus {
+    #[default]
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl PurchaseStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PurchaseStatus::Pending => "pending",
+            PurchaseStatus::Approved => "approved",
+            PurchaseStatus::Rejected => "rejected",
+        }
+    }
+}
+
+impl FromStr for PurchaseStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pending" => Ok(PurchaseStatus::Pending),
+            "approved" => Ok(PurchaseStatus::Approved),
+            "rejected" => Ok(PurchaseStatus::Rejected),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A reward purchase requested by a member; points are only deducted once approved
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RewardPurchase {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub reward_id: Uuid,
+    pub user_id: Uuid,
+    /// Price at the time of the request
+    pub point_cost: i64,
+    pub status: PurchaseStatus,
+    pub created_at: DateTime<Utc>,
+    pub reviewed_by: Option<Uuid>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+/// A reward purchase awaiting approval
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PendingRewardPurchase {
+    pub purchase: RewardPurchase,
+    pub reward: Reward,
+    pub user: User,
+}
+
+/// Outcome of buying a reward: either it was bought right away or it waits for approval
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(tag = "status", content = "data", rename_all = "snake_case")]
+pub enum PurchaseRewardResponse {
+    Purchased(UserReward),
+    PendingApproval(RewardPurchase),
+}
+
+/// A reward a member is saving up for
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct WishlistItem {
+    pub reward: Reward,
+    /// Points still missing before the reward can be bought; 0 once affordable
+    pub points_needed: i64,
+    pub added_at: DateTime<Utc>,
+}
+
 // ============================================================================
 // Invitation Types
 // ============================================================================
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum InvitationStatus {
     Pending,
@@ -1123,6 +2664,7 @@ impl FromStr for InvitationStatus {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Invitation {
     pub id: Uuid,
     pub household_id: Uuid,
@@ -1136,6 +2678,7 @@ pub struct Invitation {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct InvitationWithHousehold {
     pub invitation: Invitation,
     pub household: Household,
@@ -1143,22 +2686,71 @@ pub struct InvitationWithHousehold {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CreateInvitationRequest {
     pub email: String,
     pub role: Option<Role>,
 }
 
+/// Shareable code that lets anyone with it join a household until it expires or is used up
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct InviteCode {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub code: String,
+    pub role: Role,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    /// `None` means unlimited uses until expiry
+    pub max_uses: Option<i32>,
+    pub use_count: i32,
+}
+
+impl InviteCode {
+    /// Whether the code can still be redeemed at `now`
+    pub fn is_usable(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at > now && self.max_uses.map(|max| self.use_count < max).unwrap_or(true)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreateInviteCodeRequest {
+    pub role: Option<Role>,
+    /// Validity in hours, defaults to 7 days
+    pub expires_in_hours: Option<i64>,
+    pub max_uses: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RedeemInviteCodeRequest {
+    pub code: String,
+}
+
+/// Normalize a typed invite code: case-insensitive, ignoring spaces and dashes
+pub fn normalize_invite_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
 // ============================================================================
 // Task-Reward/Punishment Association Types
 // ============================================================================
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TaskReward {
     pub task_id: Uuid,
     pub reward_id: Uuid,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TaskPunishment {
     pub task_id: Uuid,
     pub punishment_id: Uuid,
@@ -1166,6 +2758,7 @@ pub struct TaskPunishment {
 
 /// Reward linked to a task with amount (how many times to apply)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TaskRewardLink {
     pub reward: Reward,
     pub amount: i32,
@@ -1173,6 +2766,7 @@ pub struct TaskRewardLink {
 
 /// Punishment linked to a task with amount (how many times to apply)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TaskPunishmentLink {
     pub punishment: Punishment,
     pub amount: i32,
@@ -1180,6 +2774,7 @@ pub struct TaskPunishmentLink {
 
 /// Default reward linked to a household with amount
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct HouseholdDefaultRewardLink {
     pub reward: Reward,
     pub amount: i32,
@@ -1187,6 +2782,7 @@ pub struct HouseholdDefaultRewardLink {
 
 /// Default punishment linked to a household with amount
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct HouseholdDefaultPunishmentLink {
     pub punishment: Punishment,
     pub amount: i32,
@@ -1194,6 +2790,7 @@ pub struct HouseholdDefaultPunishmentLink {
 
 /// Entry for setting a default reward in UpdateHouseholdSettingsRequest
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct DefaultRewardEntry {
     pub reward_id: Uuid,
     pub amount: i32,
@@ -1201,6 +2798,7 @@ pub struct DefaultRewardEntry {
 
 /// Entry for setting a default punishment in UpdateHouseholdSettingsRequest
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct DefaultPunishmentEntry {
     pub punishment_id: Uuid,
     pub amount: i32,
@@ -1212,6 +2810,7 @@ pub struct DefaultPunishmentEntry {
 
 /// Task with linked rewards and punishments
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TaskWithConfig {
     pub task: Task,
     pub linked_rewards: Vec<TaskRewardLink>,
@@ -1220,6 +2819,7 @@ pub struct TaskWithConfig {
 
 /// Task completion statistics for the detail view
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TaskStatistics {
     /// Completion rate for current week (0.0 to 100.0)
     pub completion_rate_week: Option<f64>,
@@ -1255,6 +2855,12 @@ pub struct TaskStatistics {
     pub last_completed: Option<DateTime<Utc>>,
     /// Next due date for the task
     pub next_due: Option<NaiveDate>,
+    /// Minutes of completed focus sessions on the task this week
+    #[serde(default)]
+    pub focus_minutes_week: i64,
+    /// Minutes of completed focus sessions on the task all time
+    #[serde(default)]
+    pub focus_minutes_all_time: i64,
 }
 
 // ============================================================================
@@ -1263,6 +2869,7 @@ pub struct TaskStatistics {
 
 /// Status of a task period (day/week/month)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum PeriodStatus {
     /// Target was reached within the period
@@ -1298,6 +2905,7 @@ impl FromStr for PeriodStatus {
 
 /// Record of a task period's outcome (frozen at finalization)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TaskPeriodResult {
     pub id: Uuid,
     pub task_id: Uuid,
@@ -1309,13 +2917,48 @@ pub struct TaskPeriodResult {
     /// Target count at the time of finalization (frozen)
     pub target_count: i32,
     pub finalized_at: DateTime<Utc>,
-    /// Who finalized: 'system', 'user', or 'migration'
+    /// Who finalized: 'system', 'user', 'migration', or the ID of the user who skipped the period
     pub finalized_by: String,
     pub notes: Option<String>,
 }
 
+/// Request to skip a task's current period
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SkipTaskPeriodRequest {
+    /// Why the period is skipped, e.g. "Raining all day"
+    pub reason: Option<String>,
+}
+
+/// A member's streak freezes: each turns one missed period into a skipped one
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct StreakFreezeStatus {
+    /// Streak freezes bought and not used yet
+    pub available: i32,
+    /// Points a streak freeze costs (0 = not for sale in this household)
+    pub price: i64,
+}
+
+/// Request to correct the recorded outcome of a past period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct OverridePeriodRequest {
+    pub status: PeriodStatus,
+    /// Why the recorded outcome is corrected, e.g. "Background job missed the completion"
+    pub reason: String,
+}
+
+/// Request to move a task's current occurrence to another date
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PostponeTaskRequest {
+    pub target_date: NaiveDate,
+}
+
 /// Simplified period info for habit tracker display
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct PeriodDisplay {
     pub period_start: NaiveDate,
     pub status: PeriodStatus,
@@ -1323,6 +2966,7 @@ pub struct PeriodDisplay {
 
 /// Full task details for the detail view modal
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TaskWithDetails {
     /// The task itself
     pub task: Task,
@@ -1337,10 +2981,14 @@ pub struct TaskWithDetails {
     /// Recent period results for habit tracker display (last 15 periods, oldest first)
     #[serde(default)]
     pub recent_periods: Vec<PeriodDisplay>,
+    /// Tasks that must be completed in the same period before this one
+    #[serde(default)]
+    pub prerequisites: Vec<Task>,
 }
 
 /// Result of task completion including points and rewards assigned
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TaskCompletionResult {
     pub completion: TaskCompletion,
     pub points_awarded: i64,
@@ -1349,6 +2997,7 @@ pub struct TaskCompletionResult {
 
 /// Report from missed task processing
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct MissedTaskReport {
     pub processed_at: DateTime<Utc>,
     pub tasks_checked: i64,
@@ -1361,16 +3010,66 @@ pub struct MissedTaskReport {
 // Leaderboard Types
 // ============================================================================
 
+/// An entry of the household leaderboard. In the week and month windows
+/// `points` and `tasks_completed` only count what happened within the window.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct LeaderboardEntry {
     pub user: User,
     pub points: i64,
     pub rank: i32,
     pub tasks_completed: i64,
-    pub current_streak: i32,
+}
+
+/// Time range a leaderboard ranks members over, in the household's timezone
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum LeaderboardWindow {
+    /// Since the start of the week (per `week_start_day`)
+    Week,
+    /// Since the first of the month
+    Month,
+    /// Current point balances and all completions
+    #[default]
+    AllTime,
+}
+
+impl LeaderboardWindow {
+    pub const ALL: [LeaderboardWindow; 3] = [LeaderboardWindow::Week, LeaderboardWindow::Month, LeaderboardWindow::AllTime];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LeaderboardWindow::Week => "week",
+            LeaderboardWindow::Month => "month",
+            LeaderboardWindow::AllTime => "all_time",
+        }
+    }
+}
+
+impl FromStr for LeaderboardWindow {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "week" => Ok(LeaderboardWindow::Week),
+            "month" => Ok(LeaderboardWindow::Month),
+            "all_time" => Ok(LeaderboardWindow::AllTime),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Query of `GET /households/{id}/leaderboard`; defaults to all time
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema, utoipa::IntoParams), into_params(parameter_in = Query))]
+pub struct LeaderboardQuery {
+    #[serde(default)]
+    pub window: LeaderboardWindow,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct PointsHistoryEntry {
     pub id: Uuid,
     pub points_change: i64,
@@ -1379,11 +3078,73 @@ pub struct PointsHistoryEntry {
     pub created_at: DateTime<Utc>,
 }
 
+// ============================================================================
+// Point Goal Types
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PointGoalStatus {
+    /// The deadline has not passed and the target is not reached yet
+    #[default]
+    Active,
+    /// The target was reached by the deadline
+    Completed,
+    /// The deadline passed before the target was reached
+    Expired,
+}
+
+/// A points target a member wants to earn by a deadline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PointGoal {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub user_id: Uuid,
+    pub title: String,
+    pub target_points: i64,
+    /// Last day (household time) that counts towards the goal
+    pub deadline: NaiveDate,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// A goal with the points earned towards it since it was set
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PointGoalProgress {
+    pub goal: PointGoal,
+    /// Points earned since the goal was set; spending points does not lower it
+    pub earned_points: i64,
+    pub status: PointGoalStatus,
+}
+
+impl PointGoalProgress {
+    /// Progress in percent, capped at 100
+    pub fn percent(&self) -> u8 {
+        if self.goal.target_points <= 0 {
+            return 100;
+        }
+        (self.earned_points.max(0) * 100 / self.goal.target_points).min(100) as u8
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreatePointGoalRequest {
+    pub title: String,
+    pub target_points: i64,
+    pub deadline: NaiveDate,
+}
+
 // ============================================================================
 // Activity Log Types
 // ============================================================================
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum ActivityType {
     // Task events
@@ -1396,6 +3157,8 @@ pub enum ActivityType {
     TaskCompletionApproved,
     TaskCompletionRejected,
     TaskAutoArchived,
+    TaskClaimed,
+    TaskReleased,
 
     // Reward events
     RewardCreated,
@@ -1406,6 +3169,10 @@ pub enum ActivityType {
     RewardRedemptionApproved,
     RewardRedemptionRejected,
     RewardRandomPicked,
+    RewardGifted,
+    RewardPurchaseRequested,
+    RewardPurchaseApproved,
+    RewardPurchaseRejected,
 
     // Punishment events
     PunishmentCreated,
@@ -1418,6 +3185,15 @@ pub enum ActivityType {
 
     // Points events
     PointsAdjusted,
+    PointsDecayed,
+    StreakFreezeUsed,
+
+    // Challenge events
+    ChallengeCreated,
+    ChallengeWon,
+
+    // Automation rule events
+    AutomationRuleApplied,
 
     // Membership events
     MemberJoined,
@@ -1441,6 +3217,8 @@ impl ActivityType {
             ActivityType::TaskCompletionApproved => "task_completion_approved",
             ActivityType::TaskCompletionRejected => "task_completion_rejected",
             ActivityType::TaskAutoArchived => "task_auto_archived",
+            ActivityType::TaskClaimed => "task_claimed",
+            ActivityType::TaskReleased => "task_released",
             ActivityType::RewardCreated => "reward_created",
             ActivityType::RewardDeleted => "reward_deleted",
             ActivityType::RewardAssigned => "reward_assigned",
@@ -1449,6 +3227,10 @@ impl ActivityType {
             ActivityType::RewardRedemptionApproved => "reward_redemption_approved",
             ActivityType::RewardRedemptionRejected => "reward_redemption_rejected",
             ActivityType::RewardRandomPicked => "reward_random_picked",
+            ActivityType::RewardGifted => "reward_gifted",
+            ActivityType::RewardPurchaseRequested => "reward_purchase_requested",
+            ActivityType::RewardPurchaseApproved => "reward_purchase_approved",
+            ActivityType::RewardPurchaseRejected => "reward_purchase_rejected",
             ActivityType::PunishmentCreated => "punishment_created",
             ActivityType::PunishmentDeleted => "punishment_deleted",
             ActivityType::PunishmentAssigned => "punishment_assigned",
@@ -1457,6 +3239,11 @@ impl ActivityType {
             ActivityType::PunishmentCompletionRejected => "punishment_completion_rejected",
             ActivityType::PunishmentRandomPicked => "punishment_random_picked",
             ActivityType::PointsAdjusted => "points_adjusted",
+            ActivityType::PointsDecayed => "points_decayed",
+            ActivityType::StreakFreezeUsed => "streak_freeze_used",
+            ActivityType::ChallengeCreated => "challenge_created",
+            ActivityType::ChallengeWon => "challenge_won",
+            ActivityType::AutomationRuleApplied => "automation_rule_applied",
             ActivityType::MemberJoined => "member_joined",
             ActivityType::MemberLeft => "member_left",
             ActivityType::MemberRoleChanged => "member_role_changed",
@@ -1480,6 +3267,8 @@ impl FromStr for ActivityType {
             "task_completion_approved" => Ok(ActivityType::TaskCompletionApproved),
             "task_completion_rejected" => Ok(ActivityType::TaskCompletionRejected),
             "task_auto_archived" => Ok(ActivityType::TaskAutoArchived),
+            "task_claimed" => Ok(ActivityType::TaskClaimed),
+            "task_released" => Ok(ActivityType::TaskReleased),
             "reward_created" => Ok(ActivityType::RewardCreated),
             "reward_deleted" => Ok(ActivityType::RewardDeleted),
             "reward_assigned" => Ok(ActivityType::RewardAssigned),
@@ -1488,6 +3277,10 @@ impl FromStr for ActivityType {
             "reward_redemption_approved" => Ok(ActivityType::RewardRedemptionApproved),
             "reward_redemption_rejected" => Ok(ActivityType::RewardRedemptionRejected),
             "reward_random_picked" => Ok(ActivityType::RewardRandomPicked),
+            "reward_gifted" => Ok(ActivityType::RewardGifted),
+            "reward_purchase_requested" => Ok(ActivityType::RewardPurchaseRequested),
+            "reward_purchase_approved" => Ok(ActivityType::RewardPurchaseApproved),
+            "reward_purchase_rejected" => Ok(ActivityType::RewardPurchaseRejected),
             "punishment_created" => Ok(ActivityType::PunishmentCreated),
             "punishment_deleted" => Ok(ActivityType::PunishmentDeleted),
             "punishment_assigned" => Ok(ActivityType::PunishmentAssigned),
@@ -1496,6 +3289,11 @@ impl FromStr for ActivityType {
             "punishment_completion_rejected" => Ok(ActivityType::PunishmentCompletionRejected),
             "punishment_random_picked" => Ok(ActivityType::PunishmentRandomPicked),
             "points_adjusted" => Ok(ActivityType::PointsAdjusted),
+            "points_decayed" => Ok(ActivityType::PointsDecayed),
+            "streak_freeze_used" => Ok(ActivityType::StreakFreezeUsed),
+            "challenge_created" => Ok(ActivityType::ChallengeCreated),
+            "challenge_won" => Ok(ActivityType::ChallengeWon),
+            "automation_rule_applied" => Ok(ActivityType::AutomationRuleApplied),
             "member_joined" => Ok(ActivityType::MemberJoined),
             "member_left" => Ok(ActivityType::MemberLeft),
             "member_role_changed" => Ok(ActivityType::MemberRoleChanged),
@@ -1507,6 +3305,7 @@ impl FromStr for ActivityType {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ActivityLog {
     pub id: Uuid,
     pub household_id: Uuid,
@@ -1520,6 +3319,7 @@ pub struct ActivityLog {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ActivityLogWithUsers {
     pub log: ActivityLog,
     pub actor: User,
@@ -1532,10 +3332,96 @@ pub struct ActivityLogWithUsers {
 
 /// A pending task completion awaiting review, with task and user details
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct PendingReview {
     pub completion: TaskCompletion,
     pub task: Task,
     pub user: User,
+    /// Photos the member attached as proof
+    #[serde(default)]
+    pub attachments: Vec<CompletionAttachment>,
+}
+
+/// Photo attached to a task completion
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CompletionAttachment {
+    pub id: Uuid,
+    pub completion_id: Uuid,
+    pub uploaded_by: Uuid,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Kind of record a file attachment belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum AttachmentEntity {
+    Note,
+    JournalEntry,
+    /// Photos shared in the household chat
+    ChatMessage,
+    /// Photo of a plant
+    Plant,
+}
+
+impl AttachmentEntity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AttachmentEntity::Note => "note",
+            AttachmentEntity::JournalEntry => "journal_entry",
+            AttachmentEntity::ChatMessage => "chat_message",
+            AttachmentEntity::Plant => "plant",
+        }
+    }
+
+    /// Module the owning entity belongs to; its attachments are gone while it is switched off
+    pub fn feature(&self) -> HouseholdFeature {
+        match self {
+            AttachmentEntity::Note => HouseholdFeature::Notes,
+            AttachmentEntity::JournalEntry => HouseholdFeature::Journal,
+            AttachmentEntity::ChatMessage => HouseholdFeature::Chat,
+            AttachmentEntity::Plant => HouseholdFeature::Plants,
+        }
+    }
+}
+
+impl FromStr for AttachmentEntity {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "note" => Ok(AttachmentEntity::Note),
+            "journal_entry" => Ok(AttachmentEntity::JournalEntry),
+            "chat_message" => Ok(AttachmentEntity::ChatMessage),
+            "plant" => Ok(AttachmentEntity::Plant),
+            _ => Err(()),
+        }
+    }
+}
+
+/// File attached to a note, journal entry or chat message, e.g. a warranty or manual
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Attachment {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub entity_type: AttachmentEntity,
+    pub entity_id: Uuid,
+    pub uploaded_by: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Attachment {
+    /// Images get a server-side thumbnail and are shown inline
+    pub fn is_image(&self) -> bool {
+        self.content_type.starts_with("image/")
+    }
 }
 
 // ============================================================================
@@ -1543,12 +3429,14 @@ pub struct PendingReview {
 // ============================================================================
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ApiError {
     pub error: String,
     pub message: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ApiSuccess<T> {
     pub data: T,
 }
@@ -1559,49 +3447,171 @@ impl<T> ApiSuccess<T> {
     }
 }
 
-// ============================================================================
-// Chat Message Types
-// ============================================================================
-
+/// Body of a 409 response when an update was based on an outdated `version`
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ChatMessage {
-    pub id: Uuid,
-    pub household_id: Uuid,
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct VersionConflict<T> {
+    pub error: String,
+    pub message: String,
+    /// The state saved in the meantime, including its current version
+    pub current: T,
+}
+
+/// Query parameters for paginated list endpoints
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema, utoipa::IntoParams), into_params(parameter_in = Query))]
+pub struct PaginationQuery {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+impl PaginationQuery {
+    pub fn new(limit: i64, offset: i64) -> Self {
+        Self {
+            limit: Some(limit),
+            offset: Some(offset),
+        }
+    }
+
+    /// Requested page size, clamped to `1..=max`
+    pub fn capped_limit(&self, max: i64) -> Option<i64> {
+        self.limit.map(|limit| limit.clamp(1, max))
+    }
+
+    /// Requested offset, never negative
+    pub fn offset_or_zero(&self) -> i64 {
+        self.offset.unwrap_or(0).max(0)
+    }
+
+    /// Build the query string for this page, e.g. `?limit=50&offset=100`
+    pub fn to_query_string(&self) -> String {
+        let params: Vec<String> = [("limit", self.limit), ("offset", self.offset)]
+            .into_iter()
+            .filter_map(|(key, value)| value.map(|v| format!("{}={}", key, v)))
+            .collect();
+        if params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", params.join("&"))
+        }
+    }
+}
+
+/// One page of a list together with the total number of items
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Paginated<T> {
+    pub items: Vec<T>,
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+impl<T> Paginated<T> {
+    /// Whether there are items beyond this page
+    pub fn has_more(&self) -> bool {
+        self.offset + (self.items.len() as i64) < self.total
+    }
+}
+
+// ============================================================================
+// Chat Message Types
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ChatMessage {
+    pub id: Uuid,
+    pub household_id: Uuid,
     pub user_id: Uuid,
     pub content: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub is_deleted: bool,
+    /// The message this one replies to
+    #[serde(default)]
+    pub reply_to_message_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ChatMessageWithUser {
     pub message: ChatMessage,
     pub user: User,
+    #[serde(default)]
+    pub reactions: Vec<ChatReactionSummary>,
+    #[serde(default)]
+    pub reply_count: i64,
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+}
+
+/// All reactions with the same emoji on a message
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ChatReactionSummary {
+    pub emoji: String,
+    pub user_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ChatReactionRequest {
+    pub emoji: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CreateChatMessageRequest {
     pub content: String,
+    #[serde(default)]
+    pub reply_to_message_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UpdateChatMessageRequest {
     pub content: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema, utoipa::IntoParams), into_params(parameter_in = Query))]
 pub struct ListChatMessagesRequest {
     pub limit: Option<i64>,
     pub before: Option<Uuid>,
 }
 
+/// How far a member has read the household chat
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ChatReadMarker {
+    pub household_id: Uuid,
+    pub user_id: Uuid,
+    pub last_read_message_id: Uuid,
+    /// Creation time of the last read message
+    pub last_read_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MarkChatReadRequest {
+    pub message_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ChatUnreadCount {
+    pub household_id: Uuid,
+    pub unread_count: i64,
+}
+
 // ============================================================================
 // WebSocket Message Types
 // ============================================================================
 
 /// Messages sent from client to server via WebSocket
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(tag = "type", content = "payload")]
 pub enum WsClientMessage {
     /// Authenticate with JWT token
@@ -1610,18 +3620,59 @@ pub enum WsClientMessage {
     JoinRoom { household_id: Uuid },
     /// Leave the current chat room
     LeaveRoom,
-    /// Send a new chat message
-    SendMessage { content: String },
+    /// Send a new chat message, optionally as a reply
+    SendMessage {
+        content: String,
+        #[serde(default)]
+        reply_to_message_id: Option<Uuid>,
+    },
     /// Edit an existing message
     EditMessage { message_id: Uuid, content: String },
     /// Delete a message
     DeleteMessage { message_id: Uuid },
+    /// Mark messages up to and including this one as read
+    MarkRead { message_id: Uuid },
+    /// Receive events of a household; no categories means all of them
+    Subscribe {
+        household_id: Uuid,
+        #[serde(default)]
+        categories: Vec<WsEventCategory>,
+    },
+    /// Stop receiving events of a household; no categories means all of them
+    Unsubscribe {
+        household_id: Uuid,
+        #[serde(default)]
+        categories: Vec<WsEventCategory>,
+    },
+    /// The user is typing in the household's chat; send at most every few seconds
+    Typing { household_id: Uuid },
     /// Ping to keep connection alive
     Ping,
 }
 
+/// Kinds of household events a WebSocket session can subscribe to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum WsEventCategory {
+    /// Chat messages, edits, reactions and read markers
+    Chat,
+    /// Leaderboard and point goal updates
+    Points,
+    /// Rewards changing hands
+    Rewards,
+    /// Pets being fed or given their medication
+    Pets,
+}
+
+impl WsEventCategory {
+    pub const ALL: [WsEventCategory; 4] =
+        [WsEventCategory::Chat, WsEventCategory::Points, WsEventCategory::Rewards, WsEventCategory::Pets];
+}
+
 /// Messages sent from server to client via WebSocket
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(tag = "type", content = "payload")]
 pub enum WsServerMessage {
     /// Authentication successful
@@ -1638,15 +3689,89 @@ pub enum WsServerMessage {
     MessageEdited { message: ChatMessageWithUser },
     /// Message was deleted
     MessageDeleted { message_id: Uuid, household_id: Uuid },
+    /// Reactions on a message changed
+    ReactionsUpdated {
+        household_id: Uuid,
+        message_id: Uuid,
+        reactions: Vec<ChatReactionSummary>,
+    },
+    /// A member read messages up to the given marker
+    MessagesRead { marker: ChatReadMarker },
+    /// Member points changed; carries the fresh leaderboard
+    LeaderboardUpdated {
+        household_id: Uuid,
+        leaderboard: Vec<LeaderboardEntry>,
+    },
+    /// A member gave one of their earned rewards to another member
+    RewardGifted {
+        household_id: Uuid,
+        reward_id: Uuid,
+        reward_name: String,
+        from_user_id: Uuid,
+        to_user_id: Uuid,
+    },
+    /// Points earned towards a member's goal changed
+    GoalProgressUpdated {
+        household_id: Uuid,
+        progress: PointGoalProgress,
+    },
+    /// A member fed a pet or gave it its medication
+    PetCareLogged { household_id: Uuid, entry: PetCareEntry },
+    /// A personal notification was created for the connected user
+    NotificationCreated { notification: Notification },
+    /// Categories the session now receives for a household (empty = unsubscribed)
+    SubscriptionUpdated {
+        household_id: Uuid,
+        categories: Vec<WsEventCategory>,
+    },
+    /// Members connected to a household changed; carries everyone currently online
+    PresenceUpdated {
+        household_id: Uuid,
+        online_user_ids: Vec<Uuid>,
+    },
+    /// Another member is typing in the household's chat
+    UserTyping {
+        household_id: Uuid,
+        user_id: Uuid,
+        username: String,
+    },
     /// Pong response to ping
     Pong,
 }
 
+impl WsServerMessage {
+    /// Category of a household event; None for messages meant for a single session or user
+    pub fn category(&self) -> Option<WsEventCategory> {
+        match self {
+            WsServerMessage::NewMessage { .. }
+            | WsServerMessage::MessageEdited { .. }
+            | WsServerMessage::MessageDeleted { .. }
+            | WsServerMessage::ReactionsUpdated { .. }
+            | WsServerMessage::MessagesRead { .. }
+            | WsServerMessage::PresenceUpdated { .. }
+            | WsServerMessage::UserTyping { .. } => Some(WsEventCategory::Chat),
+            WsServerMessage::LeaderboardUpdated { .. } | WsServerMessage::GoalProgressUpdated { .. } => {
+                Some(WsEventCategory::Points)
+            }
+            WsServerMessage::RewardGifted { .. } => Some(WsEventCategory::Rewards),
+            WsServerMessage::PetCareLogged { .. } => Some(WsEventCategory::Pets),
+            WsServerMessage::Authenticated { .. }
+            | WsServerMessage::Error { .. }
+            | WsServerMessage::JoinedRoom { .. }
+            | WsServerMessage::LeftRoom
+            | WsServerMessage::NotificationCreated { .. }
+            | WsServerMessage::SubscriptionUpdated { .. }
+            | WsServerMessage::Pong => None,
+        }
+    }
+}
+
 // ============================================================================
 // Note Types
 // ============================================================================
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Note {
     pub id: Uuid,
     pub household_id: Uuid,
@@ -1654,173 +3779,2317 @@ pub struct Note {
     pub title: String,
     pub content: String,
     pub is_shared: bool,
+    /// Pinned notes are listed first
+    #[serde(default)]
+    pub pinned: bool,
+    /// Card color, e.g. `#fef3c7`
+    #[serde(default)]
+    pub color: Option<String>,
+    /// Manual position within the pinned and unpinned notes; notes without one follow, newest first
+    #[serde(default)]
+    pub sort_order: Option<i64>,
+    /// Archived notes are only listed on request
+    #[serde(default)]
+    pub archived: bool,
+    /// Incremented on every update, for optimistic locking
+    #[serde(default)]
+    pub version: i64,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct NoteWithUser {
     pub note: Note,
     pub user: User,
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CreateNoteRequest {
     pub title: String,
     pub content: Option<String>,
     pub is_shared: bool,
+    #[serde(default)]
+    pub color: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UpdateNoteRequest {
     pub title: Option<String>,
     pub content: Option<String>,
     pub is_shared: Option<bool>,
+    #[serde(default)]
+    pub pinned: Option<bool>,
+    /// Set the card color; an empty string removes it
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub sort_order: Option<i64>,
+    #[serde(default)]
+    pub archived: Option<bool>,
+    /// Version the change is based on; a mismatch is rejected with 409
+    #[serde(default)]
+    pub version: Option<i64>,
 }
 
 // ============================================================================
-// Journal Entry Types
+// Expense Types
 // ============================================================================
 
+/// Category a household expense is booked under
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ExpenseCategory {
+    Groceries,
+    Household,
+    Utilities,
+    Rent,
+    Transport,
+    Leisure,
+    #[default]
+    Other,
+}
+
+impl ExpenseCategory {
+    pub const ALL: [ExpenseCategory; 7] = [
+        ExpenseCategory::Groceries,
+        ExpenseCategory::Household,
+        ExpenseCategory::Utilities,
+        ExpenseCategory::Rent,
+        ExpenseCategory::Transport,
+        ExpenseCategory::Leisure,
+        ExpenseCategory::Other,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExpenseCategory::Groceries => "groceries",
+            ExpenseCategory::Household => "household",
+            ExpenseCategory::Utilities => "utilities",
+            ExpenseCategory::Rent => "rent",
+            ExpenseCategory::Transport => "transport",
+            ExpenseCategory::Leisure => "leisure",
+            ExpenseCategory::Other => "other",
+        }
+    }
+}
+
+impl FromStr for ExpenseCategory {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "groceries" => Ok(ExpenseCategory::Groceries),
+            "household" => Ok(ExpenseCategory::Household),
+            "utilities" => Ok(ExpenseCategory::Utilities),
+            "rent" => Ok(ExpenseCategory::Rent),
+            "transport" => Ok(ExpenseCategory::Transport),
+            "leisure" => Ok(ExpenseCategory::Leisure),
+            "other" => Ok(ExpenseCategory::Other),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A purchase paid by one member on behalf of the household
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct JournalEntry {
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Expense {
     pub id: Uuid,
     pub household_id: Uuid,
+    /// Member who paid for the purchase
+    pub paid_by: Uuid,
+    /// Amount in cents
+    pub amount_cents: i64,
+    pub category: ExpenseCategory,
+    pub description: String,
+    pub receipt_note: Option<String>,
+    pub expense_date: NaiveDate,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ExpenseWithUser {
+    pub expense: Expense,
+    /// The paying member
+    pub payer: User,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreateExpenseRequest {
+    pub amount_cents: i64,
+    #[serde(default)]
+    pub category: ExpenseCategory,
+    pub description: String,
+    /// Defaults to the requesting user
+    pub paid_by: Option<Uuid>,
+    pub receipt_note: Option<String>,
+    /// Defaults to today
+    pub expense_date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UpdateExpenseRequest {
+    pub amount_cents: Option<i64>,
+    pub category: Option<ExpenseCategory>,
+    pub description: Option<String>,
+    pub paid_by: Option<Uuid>,
+    pub receipt_note: Option<String>,
+    pub expense_date: Option<NaiveDate>,
+}
+
+/// Month filter for expense listings and summaries; defaults to the current month
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema, utoipa::IntoParams), into_params(parameter_in = Query))]
+pub struct ExpenseMonthQuery {
+    pub year: Option<i32>,
+    pub month: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ExpenseMemberTotal {
     pub user_id: Uuid,
-    pub title: String,
-    pub content: String,
-    pub entry_date: NaiveDate,
-    pub is_shared: bool,
+    pub username: String,
+    pub total_cents: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ExpenseCategoryTotal {
+    pub category: ExpenseCategory,
+    pub total_cents: i64,
+}
+
+/// Spending totals for one calendar month
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ExpenseMonthlySummary {
+    pub year: i32,
+    pub month: u32,
+    pub total_cents: i64,
+    pub by_member: Vec<ExpenseMemberTotal>,
+    pub by_category: Vec<ExpenseCategoryTotal>,
+}
+
+/// A recorded payment from one member to another that settles shared expenses
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ExpenseSettlement {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub from_user_id: Uuid,
+    pub to_user_id: Uuid,
+    pub amount_cents: i64,
+    pub note: Option<String>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreateSettlementRequest {
+    pub from_user_id: Uuid,
+    pub to_user_id: Uuid,
+    pub amount_cents: i64,
+    pub note: Option<String>,
+}
+
+/// A member's position across all expenses and settlements
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MemberBalance {
+    pub user_id: Uuid,
+    pub username: String,
+    /// Total the member paid for shared expenses
+    pub paid_cents: i64,
+    /// The member's equal share of all expenses
+    pub share_cents: i64,
+    /// Positive when the member is owed money, negative when they owe
+    pub balance_cents: i64,
+}
+
+/// A suggested payment that moves balances towards zero
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SettlementTransfer {
+    pub from_user_id: Uuid,
+    pub from_username: String,
+    pub to_user_id: Uuid,
+    pub to_username: String,
+    pub amount_cents: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ExpenseBalances {
+    pub balances: Vec<MemberBalance>,
+    pub transfers: Vec<SettlementTransfer>,
+}
+
+// ============================================================================
+// Meal Planning Types
+// ============================================================================
+
+/// Time of day a meal is planned for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum MealSlot {
+    Breakfast,
+    Lunch,
+    #[default]
+    Dinner,
+    Snack,
+}
+
+impl MealSlot {
+    pub const ALL: [MealSlot; 4] = [
+        MealSlot::Breakfast,
+        MealSlot::Lunch,
+        MealSlot::Dinner,
+        MealSlot::Snack,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MealSlot::Breakfast => "breakfast",
+            MealSlot::Lunch => "lunch",
+            MealSlot::Dinner => "dinner",
+            MealSlot::Snack => "snack",
+        }
+    }
+}
+
+impl FromStr for MealSlot {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "breakfast" => Ok(MealSlot::Breakfast),
+            "lunch" => Ok(MealSlot::Lunch),
+            "dinner" => Ok(MealSlot::Dinner),
+            "snack" => Ok(MealSlot::Snack),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RecipeIngredient {
+    pub name: String,
+    pub quantity: Option<f64>,
+    /// Free-form unit such as "g", "ml" or "pcs"
+    pub unit: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Recipe {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub name: String,
+    pub ingredients: Vec<RecipeIngredient>,
+    pub notes: Option<String>,
+    pub created_by: Uuid,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct JournalEntryWithUser {
-    pub entry: JournalEntry,
-    pub user: User,
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreateRecipeRequest {
+    pub name: String,
+    #[serde(default)]
+    pub ingredients: Vec<RecipeIngredient>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UpdateRecipeRequest {
+    pub name: Option<String>,
+    pub ingredients: Option<Vec<RecipeIngredient>>,
+    pub notes: Option<String>,
 }
 
+/// A meal planned for one day and slot, either from a recipe or as free text
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CreateJournalEntryRequest {
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MealPlanEntry {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub plan_date: NaiveDate,
+    pub slot: MealSlot,
+    pub recipe_id: Option<Uuid>,
     pub title: Option<String>,
-    pub content: String,
-    pub entry_date: Option<NaiveDate>,
-    pub is_shared: bool,
+    pub notes: Option<String>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
 }
 
+/// Create or replace the meal for a day and slot
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct UpdateJournalEntryRequest {
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SetMealPlanEntryRequest {
+    pub plan_date: NaiveDate,
+    pub slot: MealSlot,
+    pub recipe_id: Option<Uuid>,
     pub title: Option<String>,
-    pub content: Option<String>,
-    pub entry_date: Option<NaiveDate>,
-    pub is_shared: Option<bool>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema, utoipa::IntoParams), into_params(parameter_in = Query))]
+pub struct MealPlanQuery {
+    /// Any day of the requested week; defaults to the current week
+    pub week_start: Option<NaiveDate>,
+}
+
+/// The meals of one Monday-to-Sunday week
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct WeeklyMealPlan {
+    pub week_start: NaiveDate,
+    pub entries: Vec<MealPlanEntry>,
+}
+
+// ============================================================================
+// Shopping List Types
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ShoppingListItem {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub name: String,
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+    pub is_checked: bool,
+    pub added_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreateShoppingListItemRequest {
+    pub name: String,
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UpdateShoppingListItemRequest {
+    pub name: Option<String>,
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+    pub is_checked: Option<bool>,
+}
+
+// ============================================================================
+// Inventory Types
+// ============================================================================
+
+/// Something the household keeps in stock, like toilet paper or dishwasher tabs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct InventoryItem {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub name: String,
+    pub quantity: f64,
+    pub unit: Option<String>,
+    pub location: Option<String>,
+    /// Below this quantity the item is put on the shopping list
+    pub min_quantity: Option<f64>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl InventoryItem {
+    pub fn is_low(&self) -> bool {
+        self.min_quantity.is_some_and(|min| self.quantity < min)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreateInventoryItemRequest {
+    pub name: String,
+    #[serde(default)]
+    pub quantity: f64,
+    pub unit: Option<String>,
+    pub location: Option<String>,
+    pub min_quantity: Option<f64>,
+}
+
+/// An empty unit or location clears it, `min_quantity: null` removes the threshold
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UpdateInventoryItemRequest {
+    pub name: Option<String>,
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+    pub location: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_some", skip_serializing_if = "Option::is_none")]
+    pub min_quantity: Option<Option<f64>>,
+}
+
+/// Use up (negative delta) or restock (positive delta) an item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AdjustInventoryRequest {
+    pub delta: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct InventoryAdjustment {
+    pub item: InventoryItem,
+    /// The item just fell below its minimum and was put on the shopping list
+    pub added_to_shopping_list: bool,
+}
+
+// ============================================================================
+// Appliance Types
+// ============================================================================
+
+/// A registered appliance or fixture with its purchase and warranty details
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Appliance {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub name: String,
+    pub location: Option<String>,
+    pub purchased_on: Option<NaiveDate>,
+    pub warranty_until: Option<NaiveDate>,
+    /// Link to the manual or a note about where it is kept
+    pub manual_url: Option<String>,
+    pub notes: Option<String>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Recurring maintenance tasks generated for this appliance
+    #[serde(default)]
+    pub maintenance: Vec<ApplianceMaintenance>,
+}
+
+impl Appliance {
+    pub fn under_warranty(&self, today: NaiveDate) -> bool {
+        self.warranty_until.is_some_and(|until| today <= until)
+    }
+}
+
+/// A maintenance reminder, backed by an every-N-days task
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ApplianceMaintenance {
+    pub task_id: Uuid,
+    pub title: String,
+    pub interval_days: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreateApplianceRequest {
+    pub name: String,
+    pub location: Option<String>,
+    pub purchased_on: Option<NaiveDate>,
+    pub warranty_until: Option<NaiveDate>,
+    pub manual_url: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// Empty texts clear a field, dates are cleared with `null`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UpdateApplianceRequest {
+    pub name: Option<String>,
+    pub location: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_some", skip_serializing_if = "Option::is_none")]
+    pub purchased_on: Option<Option<NaiveDate>>,
+    #[serde(default, deserialize_with = "deserialize_some", skip_serializing_if = "Option::is_none")]
+    pub warranty_until: Option<Option<NaiveDate>>,
+    pub manual_url: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// Generate a maintenance task, e.g. "Descale" every 90 days
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreateMaintenanceRequest {
+    pub title: String,
+    pub interval_days: u16,
+    #[serde(default)]
+    pub assigned_user_id: Option<Uuid>,
+}
+
+// ============================================================================
+// Plant Types
+// ============================================================================
+
+/// A house plant with its own watering and fertilizing tasks
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Plant {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub name: String,
+    pub location: Option<String>,
+    pub water_interval_days: Option<i32>,
+    pub fertilize_interval_days: Option<i32>,
+    /// Generated every-N-days task; its completions are the plant's care history
+    pub water_task_id: Option<Uuid>,
+    pub fertilize_task_id: Option<Uuid>,
+    pub last_watered_at: Option<DateTime<Utc>>,
+    pub last_fertilized_at: Option<DateTime<Utc>>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Latest photo, if any
+    #[serde(default)]
+    pub photo: Option<Attachment>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreatePlantRequest {
+    pub name: String,
+    pub location: Option<String>,
+    pub water_interval_days: Option<u16>,
+    pub fertilize_interval_days: Option<u16>,
+}
+
+/// An empty location clears it; a `null` interval removes the care task
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UpdatePlantRequest {
+    pub name: Option<String>,
+    pub location: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_some", skip_serializing_if = "Option::is_none")]
+    pub water_interval_days: Option<Option<u16>>,
+    #[serde(default, deserialize_with = "deserialize_some", skip_serializing_if = "Option::is_none")]
+    pub fertilize_interval_days: Option<Option<u16>>,
+}
+
+// ============================================================================
+// Pet Types
+// ============================================================================
+
+/// What a pet schedule is for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PetCareKind {
+    Feeding,
+    Medication,
+}
+
+impl PetCareKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PetCareKind::Feeding => "feeding",
+            PetCareKind::Medication => "medication",
+        }
+    }
+}
+
+impl FromStr for PetCareKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "feeding" => Ok(PetCareKind::Feeding),
+            "medication" => Ok(PetCareKind::Medication),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A daily feeding or medication task of a pet
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PetSchedule {
+    pub task_id: Uuid,
+    pub kind: PetCareKind,
+    pub title: String,
+    pub times_per_day: i32,
+}
+
+impl PetSchedule {
+    /// Logging again within this time of the last entry is likely a double feeding
+    pub fn min_gap(&self) -> chrono::Duration {
+        chrono::Duration::minutes(12 * 60 / i64::from(self.times_per_day.max(1)))
+    }
+}
+
+/// Someone fed the pet or gave it its medication
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PetCareEntry {
+    pub id: Uuid,
+    pub pet_id: Uuid,
+    /// None once the schedule's task was deleted
+    pub task_id: Option<Uuid>,
+    pub kind: PetCareKind,
+    pub title: String,
+    pub user_id: Uuid,
+    pub username: String,
+    pub logged_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Pet {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub name: String,
+    pub species: Option<String>,
+    pub notes: Option<String>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(default)]
+    pub schedules: Vec<PetSchedule>,
+    /// Care logged in the last 24 hours, newest first
+    #[serde(default)]
+    pub recent_log: Vec<PetCareEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreatePetRequest {
+    pub name: String,
+    pub species: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// Feeding schedules default to "Feed {pet}"; medication needs a title
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreatePetScheduleRequest {
+    pub kind: PetCareKind,
+    pub title: Option<String>,
+    pub times_per_day: u16,
+    pub assigned_user_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct LogPetCareRequest {
+    /// Log even though someone else did it only a moment ago
+    #[serde(default)]
+    pub force: bool,
+}
+
+// ============================================================================
+// Share Link Types
+// ============================================================================
+
+/// Page of a household that can be shared read-only with people without an account
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ShareResource {
+    ShoppingList,
+    /// The current week's meal plan
+    MealPlan,
+}
+
+impl ShareResource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ShareResource::ShoppingList => "shopping_list",
+            ShareResource::MealPlan => "meal_plan",
+        }
+    }
+}
+
+impl FromStr for ShareResource {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "shopping_list" => Ok(ShareResource::ShoppingList),
+            "meal_plan" => Ok(ShareResource::MealPlan),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Read-only link to a single page of a household; whoever has the token can view it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ShareLink {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub resource: ShareResource,
+    pub token: String,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    /// `None` means valid until revoked
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl ShareLink {
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_none_or(|expires_at| now < expires_at)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreateShareLinkRequest {
+    pub resource: ShareResource,
+    /// `None` keeps the link valid until it is revoked
+    pub expires_in_days: Option<u16>,
+}
+
+/// What a share link shows
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(tag = "resource", content = "data", rename_all = "snake_case")]
+pub enum SharedContent {
+    ShoppingList(Vec<ShoppingListItem>),
+    MealPlan(WeeklyMealPlan),
+}
+
+/// A shared page as seen by a guest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SharedPage {
+    pub household_name: String,
+    pub content: SharedContent,
+}
+
+// ============================================================================
+// Admin Audit Log Types
+// ============================================================================
+
+/// Security-relevant action recorded in the owner-only audit log
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    /// A member's role was changed
+    RoleChanged,
+    /// Ownership was handed to another member
+    OwnershipTransferred,
+    /// A member's points were adjusted manually
+    PointsAdjusted,
+    /// Household settings were changed
+    SettingsChanged,
+    /// A member was removed or left the household
+    MemberRemoved,
+    /// A member's permission matrix was changed
+    PermissionsChanged,
+    /// The outcome of a past task period was corrected manually
+    PeriodOverridden,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::RoleChanged => "role_changed",
+            AuditAction::OwnershipTransferred => "ownership_transferred",
+            AuditAction::PointsAdjusted => "points_adjusted",
+            AuditAction::SettingsChanged => "settings_changed",
+            AuditAction::MemberRemoved => "member_removed",
+            AuditAction::PermissionsChanged => "permissions_changed",
+            AuditAction::PeriodOverridden => "period_overridden",
+        }
+    }
+}
+
+impl FromStr for AuditAction {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "role_changed" => Ok(AuditAction::RoleChanged),
+            "ownership_transferred" => Ok(AuditAction::OwnershipTransferred),
+            "points_adjusted" => Ok(AuditAction::PointsAdjusted),
+            "settings_changed" => Ok(AuditAction::SettingsChanged),
+            "member_removed" => Ok(AuditAction::MemberRemoved),
+            "permissions_changed" => Ok(AuditAction::PermissionsChanged),
+            "period_overridden" => Ok(AuditAction::PeriodOverridden),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AdminAuditEntry {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub actor_id: Uuid,
+    pub target_user_id: Option<Uuid>,
+    pub action: AuditAction,
+    /// Value before the change (a role, a balance, or a JSON object of changed settings)
+    pub before_value: Option<String>,
+    /// Value after the change, in the same format as `before_value`
+    pub after_value: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AdminAuditEntryWithUsers {
+    pub entry: AdminAuditEntry,
+    pub actor: User,
+    pub target_user: Option<User>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema, utoipa::IntoParams), into_params(parameter_in = Query))]
+pub struct ListAdminAuditLogRequest {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+/// One page of the household's audit log, newest first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AdminAuditLogPage {
+    pub entries: Vec<AdminAuditEntryWithUsers>,
+    /// Total number of audit entries for the household
+    pub total: i64,
+}
+
+// ============================================================================
+// Task Comment Types
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TaskComment {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub household_id: Uuid,
+    pub user_id: Uuid,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TaskCommentWithUser {
+    pub comment: TaskComment,
+    pub user: User,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreateTaskCommentRequest {
+    pub content: String,
+}
+
+// ============================================================================
+// Journal Entry Types
+// ============================================================================
+
+/// Lowest mood score of a journal entry
+pub const MOOD_MIN: i32 = 1;
+/// Highest mood score of a journal entry
+pub const MOOD_MAX: i32 = 5;
+
+/// Who can read a journal entry besides its author
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JournalVisibility {
+    /// Only the author
+    #[default]
+    Private,
+    /// Every member of the household
+    Household,
+}
+
+impl JournalVisibility {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JournalVisibility::Private => "private",
+            JournalVisibility::Household => "household",
+        }
+    }
+}
+
+impl FromStr for JournalVisibility {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "private" => Ok(JournalVisibility::Private),
+            "household" => Ok(JournalVisibility::Household),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct JournalEntry {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub user_id: Uuid,
+    pub title: String,
+    pub content: String,
+    pub entry_date: NaiveDate,
+    pub visibility: JournalVisibility,
+    /// Mood from MOOD_MIN to MOOD_MAX, if the author recorded one
+    pub mood: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct JournalEntryWithUser {
+    pub entry: JournalEntry,
+    pub user: User,
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreateJournalEntryRequest {
+    pub title: Option<String>,
+    pub content: String,
+    pub entry_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub visibility: JournalVisibility,
+    #[serde(default)]
+    pub mood: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UpdateJournalEntryRequest {
+    pub title: Option<String>,
+    pub content: Option<String>,
+    pub entry_date: Option<NaiveDate>,
+    pub visibility: Option<JournalVisibility>,
+    /// None = no change, Some(None) = clear, Some(Some(mood)) = set
+    #[serde(default)]
+    pub mood: Option<Option<i32>>,
+}
+
+/// Average mood of the caller's journal entries on one day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MoodPoint {
+    pub date: NaiveDate,
+    pub average_mood: f32,
+    pub entries: i32,
+}
+
+// ============================================================================
+// Trash Types
+// ============================================================================
+
+/// Kind of record that can be moved to the household trash
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TrashItemKind {
+    Task,
+    Note,
+    JournalEntry,
+}
+
+impl TrashItemKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrashItemKind::Task => "task",
+            TrashItemKind::Note => "note",
+            TrashItemKind::JournalEntry => "journal_entry",
+        }
+    }
+}
+
+impl FromStr for TrashItemKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "task" => Ok(TrashItemKind::Task),
+            "note" => Ok(TrashItemKind::Note),
+            "journal_entry" => Ok(TrashItemKind::JournalEntry),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Deleted task, note or journal entry that can still be restored
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TrashItem {
+    pub kind: TrashItemKind,
+    pub id: Uuid,
+    pub title: String,
+    pub deleted_at: DateTime<Utc>,
+    /// When the trash_purge job deletes the item for good
+    pub purge_at: DateTime<Utc>,
+}
+
+// ============================================================================
+// Announcement Types
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Announcement {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub created_by: Uuid,
+    pub title: String,
+    pub content: String,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    /// When it went live; None while waiting for `starts_at`
+    #[serde(default)]
+    pub published_at: Option<DateTime<Utc>>,
+    /// When the requesting member acknowledged it; only set in the active list
+    #[serde(default)]
+    pub acknowledged_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// One member's line in an announcement's read checklist
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AnnouncementAcknowledgment {
+    pub user_id: Uuid,
+    pub username: String,
+    /// None when the member has not confirmed reading it yet
+    pub acknowledged_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreateAnnouncementRequest {
+    pub title: String,
+    pub content: Option<String>,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UpdateAnnouncementRequest {
+    pub title: Option<String>,
+    pub content: Option<String>,
+    /// None = no change, Some(None) = clear, Some(Some(dt)) = set
+    pub starts_at: Option<Option<DateTime<Utc>>>,
+    /// None = no change, Some(None) = clear, Some(Some(dt)) = set
+    pub ends_at: Option<Option<DateTime<Utc>>>,
+}
+
+// ============================================================================
+// Challenge Types
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ChallengeStatus {
+    /// Progress is being tracked
+    #[default]
+    Active,
+    /// Winners have been determined and rewarded
+    Closed,
+}
+
+impl ChallengeStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChallengeStatus::Active => "active",
+            ChallengeStatus::Closed => "closed",
+        }
+    }
+}
+
+impl FromStr for ChallengeStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "active" => Ok(ChallengeStatus::Active),
+            "closed" => Ok(ChallengeStatus::Closed),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A time-boxed competition: whoever completes the most matching tasks
+/// between `start_date` and `end_date` wins
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Challenge {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub title: String,
+    pub description: String,
+    /// Only tasks in this category count (None = all tasks)
+    pub category_id: Option<Uuid>,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    /// Reward assigned to every winner when the challenge closes
+    pub reward_id: Option<Uuid>,
+    pub status: ChallengeStatus,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub closed_at: Option<DateTime<Utc>>,
+    /// Members with the most completions (ties share the win), set on close
+    pub winner_ids: Vec<Uuid>,
+}
+
+/// Completions of a member counting towards a challenge
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ChallengeStanding {
+    pub user_id: Uuid,
+    pub username: String,
+    pub completions: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ChallengeWithStandings {
+    pub challenge: Challenge,
+    /// Every member, most completions first
+    pub standings: Vec<ChallengeStanding>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreateChallengeRequest {
+    pub title: String,
+    pub description: Option<String>,
+    pub category_id: Option<Uuid>,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub reward_id: Option<Uuid>,
+}
+
+// ============================================================================
+// Automation Rules
+// ============================================================================
+
+/// What makes an automation rule fire for a member
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum RuleTrigger {
+    /// The streak of a task assigned to the member reaches `threshold`
+    #[default]
+    StreakReached,
+    /// At least `threshold` of the member's task periods failed in the last household week
+    FailuresInWeek,
+    /// The member's points are below `threshold`
+    PointsBelow,
+}
+
+impl RuleTrigger {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RuleTrigger::StreakReached => "streak_reached",
+            RuleTrigger::FailuresInWeek => "failures_in_week",
+            RuleTrigger::PointsBelow => "points_below",
+        }
+    }
+}
+
+impl FromStr for RuleTrigger {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "streak_reached" => Ok(RuleTrigger::StreakReached),
+            "failures_in_week" => Ok(RuleTrigger::FailuresInWeek),
+            "points_below" => Ok(RuleTrigger::PointsBelow),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A rule that automatically assigns a reward or punishment when its trigger fires.
+/// Exactly one of `reward_id` and `punishment_id` is set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AutomationRule {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub name: String,
+    pub trigger: RuleTrigger,
+    /// Streak length, failure count or point balance, depending on the trigger
+    pub threshold: i64,
+    /// Limit streak and failure triggers to one task (None = all tasks)
+    pub task_id: Option<Uuid>,
+    pub reward_id: Option<Uuid>,
+    pub punishment_id: Option<Uuid>,
+    pub enabled: bool,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreateAutomationRuleRequest {
+    pub name: String,
+    pub trigger: RuleTrigger,
+    pub threshold: i64,
+    pub task_id: Option<Uuid>,
+    pub reward_id: Option<Uuid>,
+    pub punishment_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UpdateAutomationRuleRequest {
+    pub name: Option<String>,
+    pub threshold: Option<i64>,
+    pub enabled: Option<bool>,
+}
+
+/// Audit trail entry: a rule fired for a member and assigned its reward or punishment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AutomationRuleRun {
+    pub id: Uuid,
+    pub rule_id: Uuid,
+    pub rule_name: String,
+    pub user_id: Uuid,
+    pub username: String,
+    pub trigger: RuleTrigger,
+    /// What the trigger saw, e.g. the streak length or the point balance
+    pub observed_value: i64,
+    pub reward_id: Option<Uuid>,
+    pub punishment_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+// ============================================================================
+// Webhooks
+// ============================================================================
+
+/// Household events that can be delivered to a webhook
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEvent {
+    TaskCompleted,
+    /// A pending completion was approved or rejected
+    TaskReviewed,
+    /// A reward was bought with points
+    RewardPurchased,
+    ChatMessage,
+}
+
+impl WebhookEvent {
+    pub const ALL: [WebhookEvent; 4] = [
+        WebhookEvent::TaskCompleted,
+        WebhookEvent::TaskReviewed,
+        WebhookEvent::RewardPurchased,
+        WebhookEvent::ChatMessage,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEvent::TaskCompleted => "task_completed",
+            WebhookEvent::TaskReviewed => "task_reviewed",
+            WebhookEvent::RewardPurchased => "reward_purchased",
+            WebhookEvent::ChatMessage => "chat_message",
+        }
+    }
+}
+
+impl FromStr for WebhookEvent {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "task_completed" => Ok(WebhookEvent::TaskCompleted),
+            "task_reviewed" => Ok(WebhookEvent::TaskReviewed),
+            "reward_purchased" => Ok(WebhookEvent::RewardPurchased),
+            "chat_message" => Ok(WebhookEvent::ChatMessage),
+            _ => Err(()),
+        }
+    }
+}
+
+/// An outbound webhook. The signing secret is write-only and never returned.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Webhook {
+    pub id: Uuid,
+    pub household_id: Uuid,
+    pub url: String,
+    /// Events delivered to this webhook
+    pub events: Vec<WebhookEvent>,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_delivery_at: Option<DateTime<Utc>>,
+    /// Error of the last delivery after all retries, None if it succeeded
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreateWebhookRequest {
+    pub url: String,
+    pub secret: String,
+    pub events: Vec<WebhookEvent>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UpdateWebhookRequest {
+    pub url: Option<String>,
+    pub secret: Option<String>,
+    pub events: Option<Vec<WebhookEvent>>,
+    pub enabled: Option<bool>,
+}
+
+/// Body POSTed to webhook URLs. The `X-Haushalt-Signature` header carries
+/// `sha256=<hex HMAC-SHA256 of the body keyed with the webhook secret>`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct WebhookPayload {
+    pub id: Uuid,
+    pub event: WebhookEvent,
+    pub household_id: Uuid,
+    pub occurred_at: DateTime<Utc>,
+    pub data: serde_json::Value,
+}
+
+// ============================================================================
+// Dashboard Tasks
+// ============================================================================
+
+/// Response containing the list of task IDs that should appear on the user's dashboard
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct DashboardTasksResponse {
+    pub task_ids: Vec<Uuid>,
+}
+
+/// New manual order of the dashboard, top first. Dashboard tasks that are not
+/// listed lose their position and are sorted by due date after the listed ones.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ReorderDashboardTasksRequest {
+    pub task_ids: Vec<Uuid>,
+}
+
+/// Check if a specific task is on the user's dashboard
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct IsTaskOnDashboardResponse {
+    pub on_dashboard: bool,
+}
+
+/// A dashboard task with its household information
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct DashboardTaskWithHousehold {
+    pub task_with_status: TaskWithStatus,
+    pub household_id: Uuid,
+    pub household_name: String,
+}
+
+/// Response containing dashboard tasks with their full status information
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct DashboardTasksWithStatusResponse {
+    pub tasks: Vec<DashboardTaskWithHousehold>,
+}
+
+/// How the tasks of all households are grouped on the dashboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum DashboardGroupBy {
+    Household,
+    DueWindow,
+}
+
+impl DashboardGroupBy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DashboardGroupBy::Household => "household",
+            DashboardGroupBy::DueWindow => "due_window",
+        }
+    }
+}
+
+impl FromStr for DashboardGroupBy {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "household" => Ok(DashboardGroupBy::Household),
+            "due_window" => Ok(DashboardGroupBy::DueWindow),
+            _ => Err(()),
+        }
+    }
+}
+
+/// When a task is next due, relative to today
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum DueWindow {
+    /// Due today or earlier
+    Today,
+    /// Due within the next seven days
+    ThisWeek,
+    Later,
+    /// No upcoming due date, e.g. completed one-time tasks
+    Unscheduled,
+}
+
+impl DueWindow {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DueWindow::Today => "today",
+            DueWindow::ThisWeek => "this_week",
+            DueWindow::Later => "later",
+            DueWindow::Unscheduled => "unscheduled",
+        }
+    }
+
+    pub fn for_due_date(next_due_date: Option<NaiveDate>, today: NaiveDate) -> Self {
+        match next_due_date {
+            Some(due) if due <= today => DueWindow::Today,
+            Some(due) if due <= today + chrono::Duration::days(7) => DueWindow::ThisWeek,
+            Some(_) => DueWindow::Later,
+            None => DueWindow::Unscheduled,
+        }
+    }
+}
+
+/// Filtering and grouping of `/dashboard/tasks/all`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct DashboardTaskQuery {
+    /// Only tasks of these households (empty = all of the user's households)
+    #[serde(default)]
+    pub household_ids: Vec<Uuid>,
+    /// Only tasks whose next due date is at most this many days away
+    #[serde(default)]
+    pub due_within_days: Option<i64>,
+    /// Only tasks the current user is an assignee of
+    #[serde(default)]
+    pub assigned_to_me: bool,
+    #[serde(default)]
+    pub group_by: Option<DashboardGroupBy>,
+}
+
+impl DashboardTaskQuery {
+    /// Query string for the endpoint, e.g. `?households=<id>,<id>&mine=true`
+    pub fn to_query_string(&self) -> String {
+        let mut params = Vec::new();
+        if !self.household_ids.is_empty() {
+            let ids: Vec<String> = self.household_ids.iter().map(|id| id.to_string()).collect();
+            params.push(format!("households={}", ids.join(",")));
+        }
+        if let Some(days) = self.due_within_days {
+            params.push(format!("due_within={}", days));
+        }
+        if self.assigned_to_me {
+            params.push("mine=true".to_string());
+        }
+        if let Some(group_by) = self.group_by {
+            params.push(format!("group_by={}", group_by.as_str()));
+        }
+        if params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", params.join("&"))
+        }
+    }
+}
+
+/// Tasks of one group, in the order of the flat task list
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct DashboardTaskGroup {
+    /// Household ID or due window, depending on the grouping
+    pub key: String,
+    /// Household name, or the due window again
+    pub label: String,
+    pub task_ids: Vec<Uuid>,
+}
+
+/// Counts over the filtered tasks of all households
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct DashboardTaskSummary {
+    pub total: i32,
+    pub due_today: i32,
+    pub completed_today: i32,
+    pub assigned_to_me: i32,
+    /// Households that have at least one of the tasks
+    pub households: i32,
+}
+
+/// The tasks of all households, filtered, with optional groups and summary counts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AllDashboardTasksResponse {
+    pub tasks: Vec<DashboardTaskWithHousehold>,
+    /// Empty unless a grouping was requested
+    #[serde(default)]
+    pub groups: Vec<DashboardTaskGroup>,
+    #[serde(default)]
+    pub summary: DashboardTaskSummary,
+}
+
+// ============================================================================
+// Statistics Types
+// ============================================================================
+
+/// Statistics for a single task within a period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TaskStatistic {
+    pub task_id: Uuid,
+    pub task_title: String,
+    pub expected: i32,
+    pub completed: i32,
+    pub completion_rate: f32,
+}
+
+/// Statistics for a household member within a period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MemberStatistic {
+    pub user_id: Uuid,
+    pub username: String,
+    pub total_expected: i32,
+    pub total_completed: i32,
+    pub completion_rate: f32,
+    /// Per-task breakdown (may be empty for summary view)
+    pub task_stats: Vec<TaskStatistic>,
+}
+
+/// Response containing weekly statistics for a household
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct WeeklyStatisticsResponse {
+    pub week_start: NaiveDate,
+    pub week_end: NaiveDate,
+    pub members: Vec<MemberStatistic>,
+}
+
+/// Response containing monthly statistics for a household
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MonthlyStatisticsResponse {
+    pub month: NaiveDate,
+    pub members: Vec<MemberStatistic>,
+}
+
+/// One member's share of a household report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MemberReport {
+    pub user_id: Uuid,
+    pub username: String,
+    /// Periods of tasks assigned to the member that were due in the range
+    pub expected: i32,
+    /// Of those, periods the member fulfilled (bad habits count when avoided)
+    pub completed: i32,
+    pub completion_rate: f32,
+    /// Sum of positive point transactions in the range
+    pub points_earned: i64,
+}
+
+/// A task that was missed in the report range
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FailedTaskReport {
+    pub task_id: Uuid,
+    pub task_title: String,
+    pub failed: i32,
+    pub expected: i32,
+}
+
+/// Number of completions on one weekday (0 = Monday ... 6 = Sunday)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct WeekdayActivity {
+    pub weekday: u32,
+    pub completions: i32,
+}
+
+/// Household-wide report over an arbitrary date range, computed on request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct HouseholdReport {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub total_expected: i32,
+    pub total_completed: i32,
+    pub completion_rate: f32,
+    pub total_points_earned: i64,
+    pub members: Vec<MemberReport>,
+    /// Tasks with the most missed periods, worst first
+    pub most_failed_tasks: Vec<FailedTaskReport>,
+    /// All seven weekdays, busiest first
+    pub busiest_weekdays: Vec<WeekdayActivity>,
+}
+
+/// Estimated chore load of one member over a date range
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MemberWorkload {
+    pub user_id: Uuid,
+    pub username: String,
+    /// Completions the member is expected to do in the range
+    pub task_count: i32,
+    /// Sum of the effort estimates of those completions
+    pub effort_minutes: i64,
+    /// Completions of tasks without an effort estimate (not part of `effort_minutes`)
+    pub unestimated_count: i32,
+}
+
+/// Estimated chore load per member, computed from the task schedules
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct WorkloadReport {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub members: Vec<MemberWorkload>,
+    /// Completions of tasks nobody is assigned to
+    pub unassigned_count: i32,
+    pub unassigned_minutes: i64,
+}
+
+/// A member who must not be given a task when rebalancing
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TaskAssignmentExclusion {
+    pub user_id: Uuid,
+    /// The excluded task; None excludes the member from all rebalanced tasks
+    #[serde(default)]
+    pub task_id: Option<Uuid>,
+}
+
+/// A task a member must not be given, stored per member
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MemberTaskExclusion {
+    pub user_id: Uuid,
+    pub task_id: Uuid,
+    pub task_title: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AddTaskExclusionRequest {
+    pub task_id: Uuid,
+}
+
+/// Request for assignment suggestions that even out the workload
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RebalanceRequest {
+    /// First day the workload is estimated for; defaults to today
+    #[serde(default)]
+    pub start: Option<NaiveDate>,
+    /// Last day the workload is estimated for; defaults to four weeks after the start
+    #[serde(default)]
+    pub end: Option<NaiveDate>,
+    /// Exclusions for this suggestion only, on top of the members' stored exclusions
+    #[serde(default)]
+    pub exclusions: Vec<TaskAssignmentExclusion>,
+}
+
+/// Moving a task from one assignee to another
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AssignmentChange {
+    pub task_id: Uuid,
+    #[serde(default)]
+    pub task_title: String,
+    /// The current assignee; the change is rejected if this no longer matches
+    pub from_user_id: Uuid,
+    pub to_user_id: Uuid,
+}
+
+/// A member's estimated workload before and after the suggested changes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MemberLoadChange {
+    pub user_id: Uuid,
+    pub username: String,
+    pub current_minutes: i64,
+    pub proposed_minutes: i64,
+}
+
+/// Suggested reassignments, ready to be passed to the apply endpoint as they are
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RebalanceSuggestion {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub changes: Vec<AssignmentChange>,
+    pub members: Vec<MemberLoadChange>,
+}
+
+/// Apply reassignments from a rebalance suggestion in one step
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ApplyRebalanceRequest {
+    pub changes: Vec<AssignmentChange>,
+}
+
+/// Request to deal tasks out to members at random, like a chore wheel
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RandomizeAssignmentsRequest {
+    pub task_ids: Vec<Uuid>,
+    /// Members to draw from; empty means every member who can be assigned tasks
+    #[serde(default)]
+    pub member_ids: Vec<Uuid>,
+    /// Favour members who did fewer completions in the period before `start`
+    #[serde(default)]
+    pub weighted: bool,
+    /// First day of the planned period; defaults to today
+    #[serde(default)]
+    pub start: Option<NaiveDate>,
+    /// Last day of the planned period; defaults to a week after the start
+    #[serde(default)]
+    pub end: Option<NaiveDate>,
+}
+
+/// A task drawn for a member
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PlannedAssignment {
+    pub task_id: Uuid,
+    #[serde(default)]
+    pub task_title: String,
+    /// The assignees when the plan was made; the plan is rejected if they changed
+    #[serde(default)]
+    pub current_assignee_ids: Vec<Uuid>,
+    pub user_id: Uuid,
+}
+
+/// A member's completions in the previous period and in the planned one
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MemberAssignmentLoad {
+    pub user_id: Uuid,
+    pub username: String,
+    pub past_completions: i64,
+    pub planned_completions: i64,
+}
+
+/// Randomly drawn assignments, ready to be passed to the apply endpoint as they are
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AssignmentPlan {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub assignments: Vec<PlannedAssignment>,
+    pub members: Vec<MemberAssignmentLoad>,
+}
+
+/// Apply the assignments of a randomized plan in one step
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ApplyAssignmentPlanRequest {
+    pub assignments: Vec<PlannedAssignment>,
+}
+
+// ============================================================================
+// Calendar Feed Types
+// ============================================================================
+
+/// Secret token used to subscribe to the iCalendar task feed without a JWT
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CalendarFeedToken {
+    pub token: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A single date on which a task is due, with the completions recorded for it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TaskOccurrence {
+    pub task_id: Uuid,
+    pub title: String,
+    pub date: NaiveDate,
+    /// Due time in "HH:MM" format, None for the end of the day
+    pub due_time: Option<String>,
+    pub habit_type: HabitType,
+    pub target_count: i32,
+    /// Approved and pending completions for this date
+    pub completions: i32,
+}
+
+impl TaskOccurrence {
+    pub fn is_completed(&self) -> bool {
+        self.completions >= self.target_count
+    }
+}
+
+// ============================================================================
+// API Tokens & Sensors
+// ============================================================================
+
+/// Long-lived personal API token for dashboards and home automation.
+/// The secret itself is only returned once, when the token is created.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ApiToken {
+    pub id: Uuid,
+    pub name: String,
+    /// Read-only tokens may only be used for GET requests
+    pub read_only: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreateApiTokenRequest {
+    pub name: String,
+    #[serde(default = "default_true")]
+    pub read_only: bool,
+}
+
+/// A freshly created token together with its secret, which is not stored in plain text
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CreatedApiToken {
+    pub token: ApiToken,
+    pub secret: String,
+}
+
+/// Open and due task counts of one member for today
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MemberSensor {
+    pub user_id: Uuid,
+    pub username: String,
+    pub points: i64,
+    /// Tasks assigned to the member that are due today
+    pub due_today: i64,
+    /// Of those, the ones the member has not finished yet
+    pub open_today: i64,
+}
+
+/// Flat household summary meant for polling, e.g. by a Home Assistant REST sensor
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct HouseholdSensor {
+    pub household_id: Uuid,
+    pub household_name: String,
+    /// The day the counts refer to
+    pub date: NaiveDate,
+    pub due_today: i64,
+    pub open_today: i64,
+    /// Open tasks due today without assignees
+    pub unassigned_open: i64,
+    pub members: Vec<MemberSensor>,
+}
+
+/// A task due today, reduced to what a wall display needs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SensorTask {
+    pub id: Uuid,
+    pub title: String,
+    pub assignees: Vec<String>,
+    pub completions_today: i32,
+    pub target_count: i32,
+    pub done: bool,
+}
+
+// ============================================================================
+// Push Notification Types
+// ============================================================================
+
+/// Server configuration needed by the browser to subscribe to Web Push
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PushConfig {
+    /// Base64url-encoded VAPID public key, None if push is disabled on the server
+    pub public_key: Option<String>,
+}
+
+/// Encryption keys of a browser push subscription
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PushSubscriptionKeys {
+    pub p256dh: String,
+    pub auth: String,
+}
+
+/// Push subscription as produced by `PushSubscription.toJSON()` in the browser
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PushSubscriptionRequest {
+    pub endpoint: String,
+    pub keys: PushSubscriptionKeys,
+}
+
+/// Request to remove a push subscription
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UnsubscribePushRequest {
+    pub endpoint: String,
+}
+
+/// Payload delivered to the service worker
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PushMessage {
+    pub title: String,
+    pub body: String,
+    pub url: String,
+}
+
+// ============================================================================
+// Notification Center Types
+// ============================================================================
+
+/// What a personal in-app notification is about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationType {
+    /// A task was assigned to the recipient
+    #[default]
+    TaskAssigned,
+    /// The recipient's task completion was approved
+    CompletionApproved,
+    /// The recipient's task completion was rejected
+    CompletionRejected,
+    /// The recipient's reward redemption was confirmed
+    RewardConfirmed,
+    /// The recipient's reward purchase was approved
+    PurchaseApproved,
+    /// The recipient's reward purchase was rejected
+    PurchaseRejected,
+    /// A reward on the recipient's wishlist can now be bought
+    RewardAffordable,
+}
+
+impl NotificationType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationType::TaskAssigned => "task_assigned",
+            NotificationType::CompletionApproved => "completion_approved",
+            NotificationType::CompletionRejected => "completion_rejected",
+            NotificationType::RewardConfirmed => "reward_confirmed",
+            NotificationType::PurchaseApproved => "purchase_approved",
+            NotificationType::PurchaseRejected => "purchase_rejected",
+            NotificationType::RewardAffordable => "reward_affordable",
+        }
+    }
+}
+
+impl FromStr for NotificationType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "task_assigned" => Ok(NotificationType::TaskAssigned),
+            "completion_approved" => Ok(NotificationType::CompletionApproved),
+            "completion_rejected" => Ok(NotificationType::CompletionRejected),
+            "reward_confirmed" => Ok(NotificationType::RewardConfirmed),
+            "purchase_approved" => Ok(NotificationType::PurchaseApproved),
+            "purchase_rejected" => Ok(NotificationType::PurchaseRejected),
+            "reward_affordable" => Ok(NotificationType::RewardAffordable),
+            _ => Err(()),
+        }
+    }
 }
 
-// ============================================================================
-// Announcement Types
-// ============================================================================
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Announcement {
+/// A personal notification in a user's inbox
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Notification {
     pub id: Uuid,
-    pub household_id: Uuid,
-    pub created_by: Uuid,
+    pub user_id: Uuid,
+    pub household_id: Option<Uuid>,
+    pub notification_type: NotificationType,
+    /// Name of the task or reward the notification refers to
     pub title: String,
-    pub content: String,
-    pub starts_at: Option<DateTime<Utc>>,
-    pub ends_at: Option<DateTime<Utc>>,
+    pub entity_type: Option<String>,
+    pub entity_id: Option<Uuid>,
+    pub read_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
-    pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CreateAnnouncementRequest {
-    pub title: String,
-    pub content: Option<String>,
-    pub starts_at: Option<DateTime<Utc>>,
-    pub ends_at: Option<DateTime<Utc>>,
+impl Notification {
+    pub fn is_read(&self) -> bool {
+        self.read_at.is_some()
+    }
 }
 
+/// A user's most recent notifications plus how many are unread
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct UpdateAnnouncementRequest {
-    pub title: Option<String>,
-    pub content: Option<String>,
-    /// None = no change, Some(None) = clear, Some(Some(dt)) = set
-    pub starts_at: Option<Option<DateTime<Utc>>>,
-    /// None = no change, Some(None) = clear, Some(Some(dt)) = set
-    pub ends_at: Option<Option<DateTime<Utc>>>,
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct NotificationList {
+    pub notifications: Vec<Notification>,
+    pub unread_count: i64,
 }
 
 // ============================================================================
-// Dashboard Tasks
+// Household Export Types
 // ============================================================================
 
-/// Response containing the list of task IDs that should appear on the user's dashboard
+/// Current version of the household export file format
+pub const HOUSEHOLD_EXPORT_VERSION: u32 = 1;
+
+/// Link between a random choice reward/punishment and one of its options
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DashboardTasksResponse {
-    pub task_ids: Vec<Uuid>,
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ExportOptionLink {
+    pub parent_id: Uuid,
+    pub option_id: Uuid,
+    #[serde(default = "default_option_weight")]
+    pub weight: i32,
 }
 
-/// Check if a specific task is on the user's dashboard
+/// Link between a task and a reward or punishment with amount
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct IsTaskOnDashboardResponse {
-    pub on_dashboard: bool,
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ExportTaskLink {
+    pub task_id: Uuid,
+    pub target_id: Uuid,
+    pub amount: i32,
 }
 
-/// A dashboard task with its household information
+/// Complete household configuration as written to and read from a JSON export.
+/// IDs are only used to resolve references within the file; an import always
+/// creates fresh entities in a new household.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DashboardTaskWithHousehold {
-    pub task_with_status: TaskWithStatus,
-    pub household_id: Uuid,
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct HouseholdExport {
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
     pub household_name: String,
+    pub settings: HouseholdSettings,
+    pub categories: Vec<TaskCategory>,
+    pub tasks: Vec<Task>,
+    pub rewards: Vec<Reward>,
+    pub reward_options: Vec<ExportOptionLink>,
+    pub punishments: Vec<Punishment>,
+    pub punishment_options: Vec<ExportOptionLink>,
+    pub task_rewards: Vec<ExportTaskLink>,
+    pub task_punishments: Vec<ExportTaskLink>,
+    pub point_conditions: Vec<PointCondition>,
 }
 
-/// Response containing dashboard tasks with their full status information
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DashboardTasksWithStatusResponse {
-    pub tasks: Vec<DashboardTaskWithHousehold>,
+/// Inclusive date range for the CSV exports of completions and points
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema, utoipa::IntoParams), into_params(parameter_in = Query))]
+pub struct CsvExportQuery {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
 }
 
 // ============================================================================
-// Statistics Types
+// Background Job Types
 // ============================================================================
 
-/// Statistics for a single task within a period
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TaskStatistic {
-    pub task_id: Uuid,
-    pub task_title: String,
-    pub expected: i32,
-    pub completed: i32,
-    pub completion_rate: f32,
+/// Periodic job run by the backend scheduler
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum BackgroundJob {
+    /// Deduct points and assign punishments for missed tasks
+    MissedTasks,
+    /// Archive completed one-time tasks after the grace period
+    AutoArchive,
+    /// Record completed/failed/skipped results for ended periods
+    PeriodFinalization,
+    /// End Solo Mode once the exit cooldown is over
+    SoloModeExpiry,
+    /// Web Push reminders for tasks that are due soon
+    PushReminders,
+    /// Weekly recap announcements and emails
+    WeeklySummary,
+    /// Weekly point decay for inactive members
+    PointDecay,
+    /// Close ended challenges and reward their winners
+    ChallengeClosing,
+    /// Evaluate automation rules and assign their rewards and punishments
+    AutomationRules,
+    /// Nightly SQLite snapshot into the backup directory
+    DatabaseBackup,
+    /// Permanently delete trash items older than 30 days
+    TrashPurge,
+    /// Publish scheduled announcements once their start time is reached
+    AnnouncementPublishing,
+    /// Sum new completion and period events into the daily statistics rollups
+    EventRollup,
+    /// Recompute the stored week, month and all-time leaderboards
+    LeaderboardRefresh,
+    /// Archive tasks past their end date or maximum number of occurrences
+    TaskEnd,
+    /// Pay out the monthly allowance for members' points
+    AllowancePayout,
+}
+
+impl BackgroundJob {
+    pub const ALL: [BackgroundJob; 16] = [
+        BackgroundJob::MissedTasks,
+        BackgroundJob::AutoArchive,
+        BackgroundJob::PeriodFinalization,
+        BackgroundJob::SoloModeExpiry,
+        BackgroundJob::PushReminders,
+        BackgroundJob::WeeklySummary,
+        BackgroundJob::PointDecay,
+        BackgroundJob::ChallengeClosing,
+        BackgroundJob::AutomationRules,
+        BackgroundJob::DatabaseBackup,
+        BackgroundJob::TrashPurge,
+        BackgroundJob::AnnouncementPublishing,
+        BackgroundJob::EventRollup,
+        BackgroundJob::LeaderboardRefresh,
+        BackgroundJob::TaskEnd,
+        BackgroundJob::AllowancePayout,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BackgroundJob::MissedTasks => "missed_tasks",
+            BackgroundJob::AutoArchive => "auto_archive",
+            BackgroundJob::PeriodFinalization => "period_finalization",
+            BackgroundJob::SoloModeExpiry => "solo_mode_expiry",
+            BackgroundJob::PushReminders => "push_reminders",
+            BackgroundJob::WeeklySummary => "weekly_summary",
+            BackgroundJob::PointDecay => "point_decay",
+            BackgroundJob::ChallengeClosing => "challenge_closing",
+            BackgroundJob::AutomationRules => "automation_rules",
+            BackgroundJob::DatabaseBackup => "database_backup",
+            BackgroundJob::TrashPurge => "trash_purge",
+            BackgroundJob::AnnouncementPublishing => "announcement_publishing",
+            BackgroundJob::EventRollup => "event_rollup",
+            BackgroundJob::LeaderboardRefresh => "leaderboard_refresh",
+            BackgroundJob::TaskEnd => "task_end",
+            BackgroundJob::AllowancePayout => "allowance_payout",
+        }
+    }
 }
 
-/// Statistics for a household member within a period
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MemberStatistic {
-    pub user_id: Uuid,
-    pub username: String,
-    pub total_expected: i32,
-    pub total_completed: i32,
-    pub completion_rate: f32,
-    /// Per-task breakdown (may be empty for summary view)
-    pub task_stats: Vec<TaskStatistic>,
+impl FromStr for BackgroundJob {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "missed_tasks" => Ok(BackgroundJob::MissedTasks),
+            "auto_archive" => Ok(BackgroundJob::AutoArchive),
+            "period_finalization" => Ok(BackgroundJob::PeriodFinalization),
+            "solo_mode_expiry" => Ok(BackgroundJob::SoloModeExpiry),
+            "push_reminders" => Ok(BackgroundJob::PushReminders),
+            "weekly_summary" => Ok(BackgroundJob::WeeklySummary),
+            "point_decay" => Ok(BackgroundJob::PointDecay),
+            "challenge_closing" => Ok(BackgroundJob::ChallengeClosing),
+            "automation_rules" => Ok(BackgroundJob::AutomationRules),
+            "database_backup" => Ok(BackgroundJob::DatabaseBackup),
+            "trash_purge" => Ok(BackgroundJob::TrashPurge),
+            "announcement_publishing" => Ok(BackgroundJob::AnnouncementPublishing),
+            "event_rollup" => Ok(BackgroundJob::EventRollup),
+            "leaderboard_refresh" => Ok(BackgroundJob::LeaderboardRefresh),
+            "task_end" => Ok(BackgroundJob::TaskEnd),
+            "allowance_payout" => Ok(BackgroundJob::AllowancePayout),
+            _ => Err(()),
+        }
+    }
 }
 
-/// Response containing weekly statistics for a household
+/// Schedule and last run of a background job, as shown to server admins
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct WeeklyStatisticsResponse {
-    pub week_start: NaiveDate,
-    pub week_end: NaiveDate,
-    pub members: Vec<MemberStatistic>,
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct BackgroundJobStatus {
+    pub job: BackgroundJob,
+    /// Whether the scheduler runs the job; disabled jobs can still be triggered manually
+    pub enabled: bool,
+    pub interval_minutes: u32,
+    pub running: bool,
+    pub last_started_at: Option<DateTime<Utc>>,
+    pub last_finished_at: Option<DateTime<Utc>>,
+    pub last_duration_ms: Option<i64>,
+    /// None until the job has finished once
+    pub last_success: Option<bool>,
+    /// Summary of the last run, or the error it failed with
+    pub last_message: Option<String>,
+    pub run_count: u64,
+}
+
+/// Snapshot written by `POST /api/admin/backup` or the nightly backup job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct DatabaseBackupInfo {
+    pub file_name: String,
+    pub size_bytes: u64,
+    pub created_at: DateTime<Utc>,
+    /// Older snapshots deleted to stay within `BACKUP_RETENTION`
+    pub removed_backups: u32,
 }
 
-/// Response containing monthly statistics for a household
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MonthlyStatisticsResponse {
-    pub month: NaiveDate,
-    pub members: Vec<MemberStatistic>,
+/// An account as listed to instance admins
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AdminUser {
+    pub id: Uuid,
+    pub username: String,
+    pub email: String,
+    pub created_at: DateTime<Utc>,
+    /// Set while the account is disabled
+    pub disabled_at: Option<DateTime<Utc>>,
+    pub household_count: i64,
+    pub is_admin: bool,
+}
+
+/// Size of a household, as shown to instance admins
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct HouseholdUsage {
+    pub id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub member_count: i64,
+    pub task_count: i64,
+    pub completion_count: i64,
+    pub point_transaction_count: i64,
+    pub chat_message_count: i64,
+    /// Completion photos and note/journal attachments
+    pub attachment_count: i64,
+    pub attachment_bytes: i64,
 }
 
 // ============================================================================
@@ -1916,6 +6185,8 @@ mod tests {
                 role: Role::Owner,
                 points: 0,
                 joined_at: Utc::now(),
+                vacation_start: None,
+                vacation_end: None,
             },
             user: User {
                 id: Uuid::new_v4(),
@@ -1933,6 +6204,8 @@ mod tests {
                 role: Role::Member,
                 points: 0,
                 joined_at: Utc::now(),
+                vacation_start: None,
+                vacation_end: None,
             },
             user: User {
                 id: Uuid::new_v4(),
@@ -1970,6 +6243,41 @@ mod tests {
         assert!("invalid".parse::<RecurrenceType>().is_err());
     }
 
+    #[test]
+    fn test_parse_month_day() {
+        assert_eq!(parse_month_day("04-01"), Some((4, 1)));
+        assert_eq!(parse_month_day("02-29"), Some((2, 29)));
+        assert_eq!(parse_month_day("02-30"), None);
+        assert_eq!(parse_month_day("13-01"), None);
+        assert_eq!(parse_month_day("April"), None);
+    }
+
+    #[test]
+    fn test_update_request_clears_season_with_null() {
+        let request: UpdateTaskRequest = serde_json::from_str(r#"{"active_from": null}"#).unwrap();
+        assert_eq!(request.active_from, Some(None));
+        assert_eq!(request.active_until, None);
+
+        let json = serde_json::to_string(&UpdateTaskRequest::default()).unwrap();
+        assert!(!json.contains("active_from"));
+    }
+
+    #[test]
+    fn test_recurrence_value_intervals() {
+        let every_two_weeks = RecurrenceValue::WeekInterval { weeks: 2, weekday: 2 };
+        let json = serde_json::to_string(&every_two_weeks).unwrap();
+        assert_eq!(json, r#"{"WeekInterval":{"weeks":2,"weekday":2}}"#);
+        assert_eq!(serde_json::from_str::<RecurrenceValue>(&json).unwrap(), every_two_weeks);
+        assert_eq!(serde_json::to_string(&RecurrenceValue::DayInterval(3)).unwrap(), r#"{"DayInterval":3}"#);
+
+        assert!(RecurrenceValue::DayInterval(3).is_valid());
+        assert!(!RecurrenceValue::DayInterval(0).is_valid());
+        assert!(!RecurrenceValue::WeekInterval { weeks: 0, weekday: 2 }.is_valid());
+        assert!(RecurrenceValue::MonthWeekday { ordinal: -1, weekday: 6 }.is_valid());
+        assert!(!RecurrenceValue::MonthWeekday { ordinal: 5, weekday: 6 }.is_valid());
+        assert!(!RecurrenceValue::MonthWeekday { ordinal: 1, weekday: 7 }.is_valid());
+    }
+
     #[test]
     fn test_condition_type_from_str() {
         assert_eq!("task_complete".parse(), Ok(ConditionType::TaskComplete));
@@ -1994,6 +6302,34 @@ mod tests {
         assert!("invalid".parse::<InvitationStatus>().is_err());
     }
 
+    #[test]
+    fn test_task_season() {
+        let date = |month, day| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+        let mut task = create_task_with_status(0, 1, true).task;
+        assert!(!task.is_seasonal());
+        assert!(task.is_in_season(date(1, 1)));
+
+        // Mow the lawn from April to October
+        task.active_from = Some("04-01".to_string());
+        task.active_until = Some("10-31".to_string());
+        assert!(task.is_in_season(date(4, 1)));
+        assert!(task.is_in_season(date(10, 31)));
+        assert!(!task.is_in_season(date(3, 31)));
+        assert!(!task.is_in_season(date(11, 1)));
+
+        // Winter season across the new year
+        task.active_from = Some("11-15".to_string());
+        task.active_until = Some("02-28".to_string());
+        assert!(task.is_in_season(date(12, 24)));
+        assert!(task.is_in_season(date(1, 10)));
+        assert!(!task.is_in_season(date(6, 1)));
+
+        // An open end runs until the end of the year
+        task.active_until = None;
+        assert!(task.is_in_season(date(12, 31)));
+        assert!(!task.is_in_season(date(1, 10)));
+    }
+
     fn create_task_with_status(completions: i32, target: i32, allow_exceed: bool) -> TaskWithStatus {
         create_task_with_status_assigned(completions, target, allow_exceed, true)
     }
@@ -2008,20 +6344,32 @@ mod tests {
                 recurrence_type: RecurrenceType::Daily,
                 recurrence_value: None,
                 assigned_user_id: None,
+                assignee_ids: Vec::new(),
                 target_count: target,
                 time_period: None,
                 allow_exceed_target: allow_exceed,
                 requires_review: false,
+                reviewer_type: ReviewerType::AnyReviewer,
+                reviewer_id: None,
                 points_reward: None,
                 points_penalty: None,
                 due_time: None,
+                effort_minutes: None,
+                active_from: None,
+                active_until: None,
+                ends_on: None,
+                max_occurrences: None,
                 habit_type: HabitType::Good,
                 category_id: None,
                 category_name: None,
+                tag_ids: Vec::new(),
                 archived: false,
                 paused: false,
                 suggestion: None,
                 suggested_by: None,
+                postponed_from: None,
+                postponed_to: None,
+                version: 1,
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
             },
@@ -2031,9 +6379,66 @@ mod tests {
             next_due_date: None,
             is_user_assigned,
             recent_periods: Vec::new(),
+            blocked_by: Vec::new(),
+            claimed_by: None,
         }
     }
 
+    #[test]
+    fn test_task_assignees() {
+        let mut task = create_task_with_status(0, 1, false).task;
+        let alice = Uuid::new_v4();
+        let bob = Uuid::new_v4();
+        assert!(task.assignees().is_empty());
+        assert!(task.is_assigned_to(&alice));
+
+        task.assigned_user_id = Some(alice);
+        assert_eq!(task.assignees(), vec![alice]);
+        assert!(!task.is_assigned_to(&bob));
+        assert!(!task.has_individual_completions());
+
+        task.assignee_ids = vec![alice, bob];
+        assert!(task.is_assigned_to(&bob));
+        assert!(task.has_individual_completions());
+    }
+
+    #[test]
+    fn test_is_supported_date() {
+        assert!(is_supported_date(NaiveDate::from_ymd_opt(2026, 3, 1).unwrap()));
+        assert!(is_supported_date(NaiveDate::from_ymd_opt(9999, 12, 31).unwrap()));
+        assert!(!is_supported_date(NaiveDate::MAX));
+        assert!(!is_supported_date(NaiveDate::MIN));
+    }
+
+    #[test]
+    fn test_task_can_review() {
+        let mut task = create_task_with_status(0, 1, false).task;
+        let owner = Uuid::new_v4();
+        let member = Uuid::new_v4();
+        assert!(task.can_review(&owner, &member, true, true));
+        assert!(task.can_review(&owner, &owner, true, true));
+        assert!(!task.can_review(&member, &owner, false, true));
+
+        task.reviewer_type = ReviewerType::AnyoneExceptCompleter;
+        assert!(task.can_review(&owner, &member, true, true));
+        assert!(!task.can_review(&owner, &owner, true, true));
+
+        task.reviewer_type = ReviewerType::Member;
+        task.reviewer_id = Some(member);
+        let other = Uuid::new_v4();
+        assert!(task.can_review(&member, &other, false, true));
+        assert!(!task.can_review(&owner, &other, true, true));
+
+        // The named reviewer never reviews their own completion; the others do
+        assert!(!task.can_review(&member, &member, true, true));
+        assert!(!task.can_review(&member, &member, false, true));
+        assert!(task.can_review(&owner, &member, true, true));
+
+        // A reviewer who left falls back to the review permission
+        assert!(task.can_review(&owner, &member, true, false));
+        assert!(!task.can_review(&member, &owner, false, false));
+    }
+
     #[test]
     fn test_task_with_status_is_target_met() {
         // Not met
@@ -2102,6 +6507,13 @@ mod tests {
         assert!(!task.can_complete());
     }
 
+    #[test]
+    fn test_task_with_status_can_complete_blocked_by_prerequisite() {
+        let mut task = create_task_with_status(0, 1, true);
+        task.blocked_by = vec!["Vacuum".to_string()];
+        assert!(!task.can_complete());
+    }
+
     #[test]
     fn test_task_with_status_can_complete_not_assigned() {
         // Cannot complete when user is not assigned to the task
@@ -2209,6 +6621,25 @@ mod tests {
         assert_eq!(RewardType::RandomChoice.as_str(), "random_choice");
     }
 
+    #[test]
+    fn test_point_transaction_type_roundtrip() {
+        let types = [
+            PointTransactionType::TaskCompleted,
+            PointTransactionType::CompletionReversed,
+            PointTransactionType::TaskMissed,
+            PointTransactionType::BadHabitCompleted,
+            PointTransactionType::BadHabitAvoided,
+            PointTransactionType::RewardPurchased,
+            PointTransactionType::ManualAdjustment,
+            PointTransactionType::PointDecay,
+            PointTransactionType::StreakFreezePurchased,
+        ];
+        for t in types {
+            assert_eq!(t.as_str().parse(), Ok(t));
+        }
+        assert!("invalid".parse::<PointTransactionType>().is_err());
+    }
+
     #[test]
     fn test_reward_type_is_random_choice() {
         assert!(!RewardType::Standard.is_random_choice());
@@ -2255,4 +6686,523 @@ mod tests {
         assert_eq!(SuggestionStatus::Approved.as_str(), "approved");
         assert_eq!(SuggestionStatus::Denied.as_str(), "denied");
     }
+
+    #[test]
+    fn test_expense_category_roundtrip() {
+        for c in ExpenseCategory::ALL {
+            assert_eq!(c.as_str().parse(), Ok(c));
+        }
+        assert_eq!("GROCERIES".parse(), Ok(ExpenseCategory::Groceries));
+        assert!("invalid".parse::<ExpenseCategory>().is_err());
+        assert_eq!(ExpenseCategory::default(), ExpenseCategory::Other);
+    }
+
+    #[test]
+    fn test_meal_slot_roundtrip() {
+        for slot in MealSlot::ALL {
+            assert_eq!(slot.as_str().parse(), Ok(slot));
+        }
+        assert_eq!("LUNCH".parse(), Ok(MealSlot::Lunch));
+        assert!("brunch".parse::<MealSlot>().is_err());
+        assert_eq!(MealSlot::default(), MealSlot::Dinner);
+    }
+
+    #[test]
+    fn test_household_membership_vacation() {
+        use chrono::Utc;
+
+        let date = |d| NaiveDate::from_ymd_opt(2024, 7, d).unwrap();
+        let mut membership = HouseholdMembership {
+            id: Uuid::new_v4(),
+            household_id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            role: Role::Member,
+            points: 0,
+            joined_at: Utc::now(),
+            vacation_start: None,
+            vacation_end: None,
+        };
+        assert!(!membership.is_on_vacation(date(5)));
+
+        membership.vacation_start = Some(date(3));
+        membership.vacation_end = Some(date(10));
+        assert!(!membership.is_on_vacation(date(2)));
+        assert!(membership.is_on_vacation(date(3)));
+        assert!(membership.is_on_vacation(date(10)));
+        assert!(!membership.is_on_vacation(date(11)));
+        assert!(membership.is_on_vacation_during(date(1), date(3)));
+        assert!(!membership.is_on_vacation_during(date(11), date(17)));
+
+        // Open-ended vacation
+        membership.vacation_end = None;
+        assert!(membership.is_on_vacation(date(30)));
+    }
+
+    #[test]
+    fn test_audit_action_roundtrip() {
+        for action in [
+            AuditAction::RoleChanged,
+            AuditAction::OwnershipTransferred,
+            AuditAction::PointsAdjusted,
+            AuditAction::SettingsChanged,
+            AuditAction::MemberRemoved,
+        ] {
+            assert_eq!(action.as_str().parse(), Ok(action));
+        }
+        assert!("deleted".parse::<AuditAction>().is_err());
+    }
+
+    #[test]
+    fn test_child_account_email_and_pin() {
+        assert!(is_child_account_email("0a1b2c@child.invalid"));
+        assert!(is_child_account_email("kid@CHILD.invalid"));
+        assert!(!is_child_account_email("parent@example.com"));
+        assert!(!is_child_account_email("child.invalid"));
+
+        assert!(is_valid_child_pin("1234"));
+        assert!(is_valid_child_pin("12345678"));
+        assert!(!is_valid_child_pin("123"));
+        assert!(!is_valid_child_pin("123456789"));
+        assert!(!is_valid_child_pin("12a4"));
+    }
+
+    #[test]
+    fn test_member_permissions_for_role() {
+        let organized = HierarchyType::Organized;
+        assert_eq!(MemberPermissions::for_role(&Role::Owner, &organized), MemberPermissions::all());
+        assert_eq!(MemberPermissions::for_role(&Role::Admin, &organized), MemberPermissions::all());
+        assert_eq!(MemberPermissions::for_role(&Role::Member, &organized), MemberPermissions::default());
+
+        let equals = MemberPermissions::for_role(&Role::Member, &HierarchyType::Equals);
+        assert!(equals.manage_tasks && equals.manage_rewards && equals.review_completions);
+        assert!(equals.adjust_points);
+        assert!(!equals.manage_members);
+    }
+
+    #[test]
+    fn test_member_permissions_teen_preset() {
+        let teen = MemberPermissions::teen();
+        assert!(teen.has(Permission::ManageTasks));
+        assert!(!teen.has(Permission::AdjustPoints));
+        assert_eq!(teen.granted(), vec![Permission::ManageTasks]);
+
+        let mut permissions = teen;
+        permissions.set(Permission::ReviewCompletions, true);
+        assert!(permissions.review_completions);
+        assert_eq!("adjust_points".parse(), Ok(Permission::AdjustPoints));
+    }
+
+    #[test]
+    fn test_attachment_entity_roundtrip() {
+        for entity in [AttachmentEntity::Note, AttachmentEntity::JournalEntry, AttachmentEntity::ChatMessage] {
+            assert_eq!(entity.as_str().parse::<AttachmentEntity>(), Ok(entity));
+        }
+        assert_eq!(serde_json::to_string(&AttachmentEntity::JournalEntry).unwrap(), "\"journal_entry\"");
+        assert!("task".parse::<AttachmentEntity>().is_err());
+    }
+
+    #[test]
+    fn test_journal_visibility_roundtrip() {
+        for visibility in [JournalVisibility::Private, JournalVisibility::Household] {
+            assert_eq!(visibility.as_str().parse::<JournalVisibility>(), Ok(visibility));
+        }
+        assert_eq!(JournalVisibility::default(), JournalVisibility::Private);
+        // Requests without a visibility stay private
+        let request: CreateJournalEntryRequest =
+            serde_json::from_str(r#"{"title":null,"content":"Hi","entry_date":null}"#).unwrap();
+        assert_eq!(request.visibility, JournalVisibility::Private);
+        assert_eq!(request.mood, None);
+    }
+
+    #[test]
+    fn test_trash_item_kind_roundtrip() {
+        for kind in [TrashItemKind::Task, TrashItemKind::Note, TrashItemKind::JournalEntry] {
+            assert_eq!(kind.as_str().parse::<TrashItemKind>(), Ok(kind));
+        }
+        assert_eq!(serde_json::to_string(&TrashItemKind::JournalEntry).unwrap(), "\"journal_entry\"");
+        assert!("reward".parse::<TrashItemKind>().is_err());
+    }
+
+    #[test]
+    fn test_leaderboard_window_roundtrip() {
+        for window in LeaderboardWindow::ALL {
+            assert_eq!(window.as_str().parse::<LeaderboardWindow>(), Ok(window));
+            assert_eq!(serde_json::to_string(&window).unwrap(), format!("\"{}\"", window.as_str()));
+        }
+        assert_eq!(LeaderboardWindow::default(), LeaderboardWindow::AllTime);
+        assert!("year".parse::<LeaderboardWindow>().is_err());
+    }
+
+    #[test]
+    fn test_background_job_roundtrip() {
+        for job in BackgroundJob::ALL {
+            assert_eq!(job.as_str().parse::<BackgroundJob>(), Ok(job));
+            let json = serde_json::to_string(&job).unwrap();
+            assert_eq!(json, format!("\"{}\"", job.as_str()));
+        }
+        assert!("unknown".parse::<BackgroundJob>().is_err());
+    }
+
+    #[test]
+    fn test_allowance_point_handling() {
+        assert_eq!("carry_over".parse(), Ok(AllowancePointHandling::CarryOver));
+        assert_eq!(AllowancePointHandling::Reset.as_str(), "reset");
+        assert_eq!(AllowancePointHandling::default(), AllowancePointHandling::Reset);
+        assert_eq!(serde_json::to_string(&AllowancePointHandling::CarryOver).unwrap(), r#""carry_over""#);
+    }
+
+    #[test]
+    fn test_point_decay_mode() {
+        assert_eq!("percent".parse(), Ok(PointDecayMode::Percent));
+        assert_eq!(PointDecayMode::Fixed.as_str(), "fixed");
+        assert_eq!(PointDecayMode::default(), PointDecayMode::None);
+
+        assert_eq!(PointDecayMode::None.decay_for(10, 100), 0);
+        assert_eq!(PointDecayMode::Percent.decay_for(10, 105), 10);
+        // Small balances still lose a point
+        assert_eq!(PointDecayMode::Percent.decay_for(10, 5), 1);
+        assert_eq!(PointDecayMode::Fixed.decay_for(20, 15), 15);
+        assert_eq!(PointDecayMode::Fixed.decay_for(20, -5), 0);
+        assert_eq!(PointDecayMode::Fixed.decay_for(0, 50), 0);
+    }
+
+    #[test]
+    fn test_punishment_terminology_roundtrip() {
+        for terminology in [
+            PunishmentTerminology::Punishments,
+            PunishmentTerminology::Consequences,
+            PunishmentTerminology::Custom,
+        ] {
+            assert_eq!(terminology.as_str().parse(), Ok(terminology));
+        }
+        assert!("penalties".parse::<PunishmentTerminology>().is_err());
+        assert_eq!(HouseholdSettings::default().punishment_terminology, PunishmentTerminology::Punishments);
+    }
+
+    #[test]
+    fn test_household_features() {
+        for feature in HouseholdFeature::ALL {
+            assert_eq!(feature.as_str().parse(), Ok(feature));
+        }
+        assert_eq!(HouseholdFeature::from_path_segment("recipes"), Some(HouseholdFeature::Meals));
+        assert_eq!(HouseholdFeature::from_path_segment("shopping-list"), Some(HouseholdFeature::ShoppingList));
+        assert_eq!(HouseholdFeature::from_path_segment("wishlist"), Some(HouseholdFeature::Rewards));
+        assert_eq!(HouseholdFeature::from_path_segment("tasks"), None);
+
+        let settings = HouseholdSettings {
+            disabled_features: vec![HouseholdFeature::Journal],
+            ..Default::default()
+        };
+        assert!(!settings.is_feature_enabled(HouseholdFeature::Journal));
+        assert!(settings.is_feature_enabled(HouseholdFeature::Notes));
+        assert!(!HouseholdSettings::default().is_feature_enabled(HouseholdFeature::Chat));
+    }
+
+    #[test]
+    fn test_challenge_status_roundtrip() {
+        for status in [ChallengeStatus::Active, ChallengeStatus::Closed] {
+            assert_eq!(status.as_str().parse(), Ok(status));
+        }
+        assert!("open".parse::<ChallengeStatus>().is_err());
+        assert_eq!(ChallengeStatus::default(), ChallengeStatus::Active);
+    }
+
+    #[test]
+    fn test_notification_preferences_quiet_hours() {
+        let at = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        let mut prefs = NotificationPreferences::default();
+        assert!(prefs.allows(NotificationKind::Chat, at(3, 0)));
+
+        // Window spanning midnight
+        prefs.quiet_hours_start = Some("22:00".to_string());
+        prefs.quiet_hours_end = Some("07:00".to_string());
+        assert!(prefs.is_quiet_at(at(23, 30)));
+        assert!(prefs.is_quiet_at(at(6, 59)));
+        assert!(!prefs.is_quiet_at(at(7, 0)));
+        assert!(!prefs.allows(NotificationKind::Reminders, at(22, 0)));
+
+        // Daytime window
+        prefs.quiet_hours_start = Some("13:00".to_string());
+        prefs.quiet_hours_end = Some("15:00".to_string());
+        assert!(prefs.is_quiet_at(at(14, 0)));
+        assert!(!prefs.is_quiet_at(at(23, 0)));
+
+        prefs.quiet_hours_end = None;
+        assert!(!prefs.is_quiet_at(at(14, 0)));
+
+        prefs.chat = false;
+        assert!(!prefs.allows(NotificationKind::Chat, at(9, 0)));
+        assert!(prefs.allows(NotificationKind::Reviews, at(9, 0)));
+    }
+
+    #[test]
+    fn test_point_goal_percent() {
+        let progress = |earned_points, target_points| PointGoalProgress {
+            goal: PointGoal {
+                id: Uuid::nil(),
+                household_id: Uuid::nil(),
+                user_id: Uuid::nil(),
+                title: "Cinema".to_string(),
+                target_points,
+                deadline: NaiveDate::from_ymd_opt(2026, 1, 31).unwrap(),
+                created_by: Uuid::nil(),
+                created_at: Utc::now(),
+                completed_at: None,
+            },
+            earned_points,
+            status: PointGoalStatus::Active,
+        };
+        assert_eq!(progress(0, 200).percent(), 0);
+        assert_eq!(progress(50, 200).percent(), 25);
+        assert_eq!(progress(250, 200).percent(), 100);
+    }
+
+    #[test]
+    fn test_apply_task_defaults() {
+        let settings = HouseholdSettings {
+            default_points_reward: Some(10),
+            default_points_penalty: Some(4),
+            bad_habit_points_multiplier: 1.5,
+            review_points_threshold: Some(12),
+            default_allow_exceed_target: false,
+            ..Default::default()
+        };
+        let mut request = CreateTaskRequest {
+            title: "Dishes".to_string(),
+            description: None,
+            recurrence_type: RecurrenceType::Daily,
+            recurrence_value: None,
+            assigned_user_id: None,
+            assignee_ids: None,
+            target_count: None,
+            time_period: None,
+            allow_exceed_target: None,
+            requires_review: None,
+            reviewer_type: None,
+            reviewer_id: None,
+            points_reward: None,
+            points_penalty: None,
+            due_time: None,
+            effort_minutes: None,
+            active_from: None,
+            active_until: None,
+            ends_on: None,
+            max_occurrences: None,
+            habit_type: None,
+            category_id: None,
+            tag_ids: None,
+            is_suggestion: None,
+        };
+
+        let mut good = request.clone();
+        settings.apply_task_defaults(&mut good);
+        assert_eq!((good.points_reward, good.points_penalty), (Some(10), Some(4)));
+        assert_eq!(good.allow_exceed_target, Some(false));
+        assert_eq!(good.requires_review, Some(false));
+
+        request.habit_type = Some(HabitType::Bad);
+        let mut bad = request.clone();
+        settings.apply_task_defaults(&mut bad);
+        assert_eq!((bad.points_reward, bad.points_penalty), (Some(15), Some(6)));
+        assert_eq!(bad.requires_review, Some(true));
+
+        // Explicit values win over the defaults
+        request.points_reward = Some(50);
+        request.requires_review = Some(false);
+        request.allow_exceed_target = Some(true);
+        settings.apply_task_defaults(&mut request);
+        assert_eq!(request.points_reward, Some(50));
+        assert_eq!(request.requires_review, Some(false));
+        assert_eq!(request.allow_exceed_target, Some(true));
+    }
+
+    #[test]
+    fn test_notification_type_roundtrip() {
+        for notification_type in [
+            NotificationType::TaskAssigned,
+            NotificationType::CompletionApproved,
+            NotificationType::CompletionRejected,
+            NotificationType::RewardConfirmed,
+            NotificationType::PurchaseApproved,
+            NotificationType::PurchaseRejected,
+            NotificationType::RewardAffordable,
+        ] {
+            assert_eq!(notification_type.as_str().parse::<NotificationType>(), Ok(notification_type));
+        }
+        assert!("mentioned".parse::<NotificationType>().is_err());
+    }
+
+    #[test]
+    fn test_ws_subscribe_message_and_categories() {
+        let subscribe: WsClientMessage =
+            serde_json::from_str(&format!(r#"{{"type":"Subscribe","payload":{{"household_id":"{}"}}}}"#, Uuid::nil()))
+                .unwrap();
+        assert!(matches!(subscribe, WsClientMessage::Subscribe { categories, .. } if categories.is_empty()));
+
+        let leaderboard = WsServerMessage::LeaderboardUpdated { household_id: Uuid::nil(), leaderboard: Vec::new() };
+        assert_eq!(leaderboard.category(), Some(WsEventCategory::Points));
+        let deleted = WsServerMessage::MessageDeleted { message_id: Uuid::nil(), household_id: Uuid::nil() };
+        assert_eq!(deleted.category(), Some(WsEventCategory::Chat));
+        assert_eq!(WsServerMessage::Pong.category(), None);
+    }
+
+    #[test]
+    fn test_dashboard_task_query_string_and_due_window() {
+        assert_eq!(DashboardTaskQuery::default().to_query_string(), "");
+        let query = DashboardTaskQuery {
+            household_ids: vec![Uuid::nil(), Uuid::nil()],
+            due_within_days: Some(3),
+            assigned_to_me: true,
+            group_by: Some(DashboardGroupBy::DueWindow),
+        };
+        assert_eq!(
+            query.to_query_string(),
+            format!("?households={0},{0}&due_within=3&mine=true&group_by=due_window", Uuid::nil())
+        );
+        assert_eq!("household".parse(), Ok(DashboardGroupBy::Household));
+
+        let today = NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+        let window = |days| DueWindow::for_due_date(Some(today + chrono::Duration::days(days)), today);
+        assert_eq!(window(-2), DueWindow::Today);
+        assert_eq!(window(0), DueWindow::Today);
+        assert_eq!(window(7), DueWindow::ThisWeek);
+        assert_eq!(window(8), DueWindow::Later);
+        assert_eq!(DueWindow::for_due_date(None, today), DueWindow::Unscheduled);
+    }
+
+    #[test]
+    fn test_pagination_query_string_and_has_more() {
+        assert_eq!(PaginationQuery::default().to_query_string(), "");
+        assert_eq!(PaginationQuery::new(50, 100).to_query_string(), "?limit=50&offset=100");
+        let limit_only = PaginationQuery { limit: Some(20), offset: None };
+        assert_eq!(limit_only.to_query_string(), "?limit=20");
+        assert_eq!(limit_only.offset_or_zero(), 0);
+        assert_eq!(PaginationQuery::new(1000, -5).capped_limit(200), Some(200));
+        assert_eq!(PaginationQuery::new(0, -5).capped_limit(200), Some(1));
+        assert_eq!(PaginationQuery::new(0, -5).offset_or_zero(), 0);
+
+        let page = Paginated { items: vec![1, 2], total: 5, limit: 2, offset: 2 };
+        assert!(page.has_more());
+        let last = Paginated { items: vec![5], total: 5, limit: 2, offset: 4 };
+        assert!(!last.has_more());
+    }
+
+    #[test]
+    fn test_task_filter_query_params() {
+        assert!(TaskFilter::default().is_empty());
+        assert!(TaskFilter::default().to_query_params().is_empty());
+
+        let tag_a = Uuid::new_v4();
+        let tag_b = Uuid::new_v4();
+        let filter = TaskFilter {
+            tag_ids: vec![tag_a, tag_b],
+            due_within_days: Some(7),
+            habit_type: Some(HabitType::Bad),
+            ..Default::default()
+        };
+        assert!(!filter.is_empty());
+        assert_eq!(
+            filter.to_query_params(),
+            vec![format!("tags={},{}", tag_a, tag_b), "due_within=7".to_string(), "habit_type=bad".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_invite_code_usability_and_normalization() {
+        let now = Utc::now();
+        let mut code = InviteCode {
+            id: Uuid::new_v4(),
+            household_id: Uuid::new_v4(),
+            code: "ABCD2345".to_string(),
+            role: Role::Member,
+            created_by: Uuid::new_v4(),
+            created_at: now,
+            expires_at: now + chrono::Duration::hours(1),
+            max_uses: Some(2),
+            use_count: 1,
+        };
+        assert!(code.is_usable(now));
+        code.use_count = 2;
+        assert!(!code.is_usable(now));
+        code.max_uses = None;
+        assert!(code.is_usable(now));
+        assert!(!code.is_usable(now + chrono::Duration::hours(2)));
+
+        assert_eq!(normalize_invite_code(" abcd-2345 "), "ABCD2345");
+    }
+
+    #[test]
+    fn test_focus_session_remaining_seconds() {
+        let started_at = Utc::now();
+        let session = FocusSession {
+            id: Uuid::new_v4(),
+            household_id: Uuid::new_v4(),
+            task_id: Uuid::new_v4(),
+            task_title: "Study".to_string(),
+            user_id: Uuid::new_v4(),
+            planned_minutes: 25,
+            status: FocusSessionStatus::Running,
+            started_at,
+            ended_at: None,
+            focused_seconds: 0,
+        };
+        assert_eq!(session.remaining_seconds(started_at), 1500);
+        assert_eq!(session.remaining_seconds(started_at + chrono::Duration::seconds(90)), 1410);
+        assert_eq!(session.remaining_seconds(started_at + chrono::Duration::minutes(26)), -60);
+        assert_eq!("cancelled".parse::<FocusSessionStatus>(), Ok(FocusSessionStatus::Cancelled));
+    }
+
+    #[test]
+    fn test_inventory_item_is_low() {
+        let mut item = InventoryItem {
+            id: Uuid::new_v4(),
+            household_id: Uuid::new_v4(),
+            name: "Dishwasher tabs".to_string(),
+            quantity: 5.0,
+            unit: None,
+            location: Some("Kitchen".to_string()),
+            min_quantity: None,
+            created_by: Uuid::new_v4(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+        assert!(!item.is_low());
+        item.min_quantity = Some(5.0);
+        assert!(!item.is_low());
+        item.quantity = 4.0;
+        assert!(item.is_low());
+    }
+
+    #[test]
+    fn test_share_link_is_active() {
+        let now = Utc::now();
+        let mut link = ShareLink {
+            id: Uuid::new_v4(),
+            household_id: Uuid::new_v4(),
+            resource: ShareResource::ShoppingList,
+            token: "token".to_string(),
+            created_by: Uuid::new_v4(),
+            created_at: now,
+            expires_at: None,
+        };
+        assert!(link.is_active(now));
+        link.expires_at = Some(now + chrono::Duration::days(1));
+        assert!(link.is_active(now));
+        assert!(!link.is_active(now + chrono::Duration::days(2)));
+        assert_eq!("meal_plan".parse::<ShareResource>(), Ok(ShareResource::MealPlan));
+    }
+
+    #[test]
+    fn test_pet_schedule_min_gap() {
+        let mut schedule = PetSchedule {
+            task_id: Uuid::new_v4(),
+            kind: PetCareKind::Feeding,
+            title: "Feed Bello".to_string(),
+            times_per_day: 2,
+        };
+        assert_eq!(schedule.min_gap(), chrono::Duration::hours(6));
+        schedule.times_per_day = 3;
+        assert_eq!(schedule.min_gap(), chrono::Duration::hours(4));
+        assert_eq!("Medication".parse::<PetCareKind>(), Ok(PetCareKind::Medication));
+    }
 }